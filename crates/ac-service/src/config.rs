@@ -3,13 +3,24 @@ use base64::{engine::general_purpose, Engine as _};
 pub use common::jwt::{
     DEFAULT_CLOCK_SKEW as DEFAULT_JWT_CLOCK_SKEW, MAX_CLOCK_SKEW as MAX_JWT_CLOCK_SKEW,
 };
-use common::secret::{ExposeSecret, SecretBox};
+use common::listen::UnixSocketConfig;
+use common::secret::{ExposeSecret, SecretBox, SecretString};
 use std::collections::HashMap;
 use std::env;
 use std::fmt;
+use std::path::PathBuf;
 use thiserror::Error;
 use tracing::warn;
 
+use crate::services::secret_policy::{
+    SecretPolicy, DEFAULT_SECRET_MIN_ENTROPY_BITS, DEFAULT_SECRET_MIN_LENGTH,
+    MAX_SECRET_MIN_ENTROPY_BITS, MAX_SECRET_MIN_LENGTH, MIN_SECRET_MIN_ENTROPY_BITS,
+    MIN_SECRET_MIN_LENGTH,
+};
+
+/// Master key version recorded when `AC_MASTER_KEY_VERSION` is unset.
+pub const DEFAULT_MASTER_KEY_VERSION: i32 = 1;
+
 /// Default bcrypt cost factor (12 per ADR-0003).
 ///
 /// Cost 12 = 2^12 = 4,096 iterations, providing appropriate security
@@ -60,9 +71,253 @@ pub const MIN_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS: i64 = 1;
 /// Maximum registration rate limit max attempts (100).
 pub const MAX_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS: i64 = 100;
 
+/// Default per-client token request limit (requests per minute, also the burst size).
+pub const DEFAULT_CLIENT_RATE_LIMIT_PER_MINUTE: u32 = 60;
+/// Minimum per-client token request limit (1 per minute).
+pub const MIN_CLIENT_RATE_LIMIT_PER_MINUTE: u32 = 1;
+/// Maximum per-client token request limit (10,000 per minute).
+pub const MAX_CLIENT_RATE_LIMIT_PER_MINUTE: u32 = 10_000;
+
+/// Per-client token endpoint limits by limit class.
+///
+/// Service tokens are limited by the credential's service type
+/// (`global-controller`, `meeting-controller`, `media-handler`); user tokens
+/// use the `user` class. Classes without an override use `default_per_minute`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientRateLimits {
+    pub default_per_minute: u32,
+    pub per_class: HashMap<String, u32>,
+}
+
+impl Default for ClientRateLimits {
+    fn default() -> Self {
+        Self {
+            default_per_minute: DEFAULT_CLIENT_RATE_LIMIT_PER_MINUTE,
+            per_class: HashMap::new(),
+        }
+    }
+}
+
+impl ClientRateLimits {
+    /// Requests per minute allowed for a client in `class`.
+    pub fn per_minute(&self, class: &str) -> u32 {
+        self.per_class
+            .get(class)
+            .copied()
+            .unwrap_or(self.default_per_minute)
+    }
+}
+
+// =============================================================================
+// Token Lifetime Configuration Defaults & Bounds
+// =============================================================================
+
+/// Default service token lifetime in seconds (1 hour).
+pub const DEFAULT_SERVICE_TOKEN_TTL_SECONDS: u32 = 3600;
+/// Default user token lifetime in seconds (1 hour).
+pub const DEFAULT_USER_TOKEN_TTL_SECONDS: u32 = 3600;
+/// Default cap on guest token lifetimes in seconds (15 minutes).
+pub const DEFAULT_GUEST_TOKEN_TTL_SECONDS: u32 = 900;
+/// Default delegated (token exchange) token lifetime in seconds (5 minutes).
+pub const DEFAULT_DELEGATED_TOKEN_TTL_SECONDS: u32 = 300;
+/// Default cap on every issued token lifetime in seconds (1 hour).
+pub const DEFAULT_MAX_TOKEN_TTL_SECONDS: u32 = 3600;
+/// Minimum configurable token lifetime (1 minute).
+pub const MIN_TOKEN_TTL_SECONDS: u32 = 60;
+/// Maximum configurable token lifetime (24 hours).
+pub const MAX_TOKEN_TTL_SECONDS: u32 = 86_400;
+
+/// Access token lifetimes by token type.
+///
+/// Service, user and delegated token lifetimes are set by AC. Meeting and
+/// guest tokens are issued for the lifetime GC requests; guest tokens are
+/// capped at `guest_seconds`. Every lifetime, including per-service-type
+/// and per-scope overrides, is capped at `max_ttl_seconds`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenTtlPolicy {
+    pub service_seconds: u32,
+    /// Service token lifetimes by the credential's service type
+    /// (`global-controller`, `meeting-controller`, `media-handler`).
+    /// Types without an override use `service_seconds`.
+    pub per_service_type: HashMap<String, u32>,
+    /// Service token lifetimes by granted scope. A token carrying any of
+    /// these scopes gets the shortest of their lifetimes, overriding the
+    /// service type lifetime.
+    pub per_scope: HashMap<String, u32>,
+    pub user_seconds: u32,
+    pub guest_seconds: u32,
+    pub delegated_seconds: u32,
+    pub max_ttl_seconds: u32,
+}
+
+impl Default for TokenTtlPolicy {
+    fn default() -> Self {
+        Self {
+            service_seconds: DEFAULT_SERVICE_TOKEN_TTL_SECONDS,
+            per_service_type: HashMap::new(),
+            per_scope: HashMap::new(),
+            user_seconds: DEFAULT_USER_TOKEN_TTL_SECONDS,
+            guest_seconds: DEFAULT_GUEST_TOKEN_TTL_SECONDS,
+            delegated_seconds: DEFAULT_DELEGATED_TOKEN_TTL_SECONDS,
+            max_ttl_seconds: DEFAULT_MAX_TOKEN_TTL_SECONDS,
+        }
+    }
+}
+
+impl TokenTtlPolicy {
+    /// Lifetime of a service token granting `scopes` to a credential of
+    /// `service_type`.
+    pub fn service_ttl(&self, service_type: &str, scopes: &[String]) -> u32 {
+        scopes
+            .iter()
+            .filter_map(|scope| self.per_scope.get(scope).copied())
+            .min()
+            .unwrap_or_else(|| {
+                self.per_service_type
+                    .get(service_type)
+                    .copied()
+                    .unwrap_or(self.service_seconds)
+            })
+            .min(self.max_ttl_seconds)
+    }
+
+    /// Lifetime of a user token.
+    pub fn user_ttl(&self) -> u32 {
+        self.user_seconds.min(self.max_ttl_seconds)
+    }
+
+    /// Longest lifetime of a delegated token; it never outlives the tokens
+    /// it was exchanged for either.
+    pub fn delegated_ttl(&self) -> u32 {
+        self.delegated_seconds.min(self.max_ttl_seconds)
+    }
+
+    /// Lifetime of a meeting token for which the caller requested `requested`.
+    pub fn meeting_ttl(&self, requested: u32) -> u32 {
+        requested.min(self.max_ttl_seconds)
+    }
+
+    /// Lifetime of a guest token for which the caller requested `requested`.
+    pub fn guest_ttl(&self, requested: u32) -> u32 {
+        requested.min(self.guest_seconds).min(self.max_ttl_seconds)
+    }
+}
+
+/// Where the master key comes from (`AC_MASTER_KEY_PROVIDER`).
+///
+/// See [`crate::crypto::master_key`].
+#[derive(Debug, Clone, Default)]
+pub enum MasterKeySource {
+    /// `env` (default): the raw key in `AC_MASTER_KEY`.
+    #[default]
+    Env,
+    /// `aws-kms`: `AC_MASTER_KEY_CIPHERTEXT` decrypted with AWS KMS.
+    AwsKms(AwsKmsConfig),
+    /// `vault-transit`: `AC_MASTER_KEY_CIPHERTEXT` decrypted with the Vault
+    /// transit secrets engine.
+    VaultTransit(VaultTransitConfig),
+}
+
+/// AWS KMS settings for the `aws-kms` master key provider.
+///
+/// Credentials come from the standard `AWS_ACCESS_KEY_ID`,
+/// `AWS_SECRET_ACCESS_KEY`, and `AWS_SESSION_TOKEN` variables.
+#[derive(Debug)]
+pub struct AwsKmsConfig {
+    /// `AWS_REGION`
+    pub region: String,
+    /// `AC_KMS_KEY_ID`; optional for symmetric keys, recommended so a
+    /// ciphertext from another key is rejected.
+    pub key_id: Option<String>,
+    /// `AC_KMS_ENDPOINT`; defaults to `https://kms.{region}.amazonaws.com`.
+    pub endpoint: Option<String>,
+    /// The wrapped master key (`CiphertextBlob`).
+    pub ciphertext: Vec<u8>,
+    pub access_key_id: String,
+    pub secret_access_key: SecretString,
+    pub session_token: Option<SecretString>,
+}
+
+impl Clone for AwsKmsConfig {
+    fn clone(&self) -> Self {
+        Self {
+            region: self.region.clone(),
+            key_id: self.key_id.clone(),
+            endpoint: self.endpoint.clone(),
+            ciphertext: self.ciphertext.clone(),
+            access_key_id: self.access_key_id.clone(),
+            secret_access_key: SecretString::from(self.secret_access_key.expose_secret()),
+            session_token: self
+                .session_token
+                .as_ref()
+                .map(|t| SecretString::from(t.expose_secret())),
+        }
+    }
+}
+
+/// Vault settings for the `vault-transit` master key provider.
+#[derive(Debug)]
+pub struct VaultTransitConfig {
+    /// `VAULT_ADDR`, e.g. `https://vault.vault.svc:8200`
+    pub addr: String,
+    /// `VAULT_TOKEN`
+    pub token: SecretString,
+    /// `AC_VAULT_TRANSIT_MOUNT` (default `transit`)
+    pub mount: String,
+    /// `AC_VAULT_TRANSIT_KEY`
+    pub key_name: String,
+    /// The wrapped master key (`vault:v1:...`).
+    pub ciphertext: String,
+}
+
+impl Clone for VaultTransitConfig {
+    fn clone(&self) -> Self {
+        Self {
+            addr: self.addr.clone(),
+            token: SecretString::from(self.token.expose_secret()),
+            mount: self.mount.clone(),
+            key_name: self.key_name.clone(),
+            ciphertext: self.ciphertext.clone(),
+        }
+    }
+}
+
+/// How service clients may authenticate with a TLS client certificate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MtlsMode {
+    /// Plain HTTP; service clients authenticate with `client_secret`.
+    #[default]
+    Disabled,
+    /// HTTPS with optional client certificates. A client may present a
+    /// pinned certificate, a secret, or both; whatever is presented must match.
+    Optional,
+    /// HTTPS; service tokens require a certificate matching the credential's
+    /// pinned fingerprint. A secret is not needed, but is checked if sent.
+    Required,
+}
+
+impl MtlsMode {
+    /// Whether the listeners serve TLS.
+    pub fn is_enabled(self) -> bool {
+        self != MtlsMode::Disabled
+    }
+}
+
+/// TLS listener and client certificate settings (`AC_MTLS_MODE`,
+/// `AC_TLS_CERT_PATH`, `AC_TLS_KEY_PATH`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MtlsConfig {
+    pub mode: MtlsMode,
+    /// PEM server certificate chain. Set whenever `mode` is not `Disabled`.
+    pub cert_path: Option<PathBuf>,
+    /// PEM server private key. Set whenever `mode` is not `Disabled`.
+    pub key_path: Option<PathBuf>,
+}
+
 /// Application configuration with secure handling of sensitive fields.
 ///
-/// Sensitive fields (`master_key`, `hash_secret`) are wrapped in `SecretBox`
+/// Sensitive fields (`master_key`, `hash_secret`, `key_replication_key`) are
+/// wrapped in `SecretBox`
 /// which provides:
 /// - Automatic redaction in Debug output (prevents accidental logging)
 /// - Explicit `.expose_secret()` required to access values
@@ -74,14 +329,31 @@ pub const MAX_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS: i64 = 100;
 /// Clone is manually implemented since SecretBox requires explicit cloning.
 pub struct Config {
     pub database_url: String,
+    /// Bind addresses, comma-separated (e.g. "0.0.0.0:8082,[::]:8082").
     pub bind_address: String,
+    /// Unix socket that also serves `/health`, `/ready`, and `/metrics`
+    /// (`AC_HEALTH_SOCKET_PATH`, `AC_HEALTH_SOCKET_MODE`).
+    pub health_socket: Option<UnixSocketConfig>,
     /// AES-256 master key for encrypting private keys at rest.
     /// Must be exactly 32 bytes. Use `.expose_secret()` to access.
+    /// Empty unless `master_key_source` is `Env`; use the provider built by
+    /// [`crate::crypto::master_key::load`] rather than this field.
     pub master_key: SecretBox<Vec<u8>>,
+    /// Where the master key comes from (`AC_MASTER_KEY_PROVIDER`).
+    pub master_key_source: MasterKeySource,
+    /// Version of the master key (`AC_MASTER_KEY_VERSION`, default 1),
+    /// recorded on every signing key it encrypts. Bump it with each new
+    /// master key.
+    pub master_key_version: i32,
     /// HMAC-SHA256 secret for correlation ID hashing.
     /// Must be at least 32 bytes. Use `.expose_secret()` to access.
     pub hash_secret: SecretBox<Vec<u8>>,
-    #[allow(dead_code)] // Will be used in Phase 4 for observability
+    /// AES-256 key shared by all regions, encrypting signing keys in transit
+    /// between them (`AC_KEY_REPLICATION_KEY`, 32 bytes base64).
+    /// `None` disables `/internal/keys/export` and `/internal/keys/import`.
+    pub key_replication_key: Option<SecretBox<Vec<u8>>>,
+    /// OpenTelemetry collector (OTLP/gRPC) to export traces to
+    /// (`OTLP_ENDPOINT`). `None` disables trace export.
     pub otlp_endpoint: Option<String>,
     /// JWT clock skew tolerance in seconds for `iat` validation.
     /// Per NIST SP 800-63B: Clock synchronization should be maintained within
@@ -105,6 +377,26 @@ pub struct Config {
     /// Registration rate limit max attempts per IP per window.
     /// Default: 5. Range: 1-100.
     pub registration_rate_limit_max_attempts: i64,
+    /// Per-client token bucket limits for the token endpoints.
+    /// `AC_CLIENT_RATE_LIMIT_PER_MINUTE` sets the default (60, range 1-10000);
+    /// `AC_CLIENT_RATE_LIMITS` overrides it per class, e.g.
+    /// `global-controller=600,user=20`.
+    pub client_rate_limits: ClientRateLimits,
+    /// Access token lifetimes. `AC_SERVICE_TOKEN_TTL_SECONDS` and
+    /// `AC_USER_TOKEN_TTL_SECONDS` default to 3600, `AC_GUEST_TOKEN_TTL_SECONDS`
+    /// to 900, `AC_DELEGATED_TOKEN_TTL_SECONDS` to 300 and the cap on all of
+    /// them, `AC_MAX_TOKEN_TTL_SECONDS`, to 3600 (range 60-86400);
+    /// `AC_SERVICE_TOKEN_TTLS` overrides the service lifetime per service
+    /// type, e.g. `media-handler=900`, and `AC_SCOPE_TOKEN_TTLS` per granted
+    /// scope, e.g. `internal:meeting-token=300`.
+    pub token_ttls: TokenTtlPolicy,
+    /// Strength policy for client secrets supplied at service registration.
+    /// `AC_CLIENT_SECRET_MIN_LENGTH` defaults to 32 (range 16-64),
+    /// `AC_CLIENT_SECRET_MIN_ENTROPY_BITS` to 128 (range 64-256).
+    pub secret_policy: SecretPolicy,
+    /// Client certificate authentication for service tokens.
+    /// Default: disabled (plain HTTP, `client_secret` only).
+    pub mtls: MtlsConfig,
 }
 
 /// Clone implementation that explicitly clones SecretBox fields.
@@ -113,8 +405,15 @@ impl Clone for Config {
         Self {
             database_url: self.database_url.clone(),
             bind_address: self.bind_address.clone(),
+            health_socket: self.health_socket.clone(),
             master_key: SecretBox::new(Box::new(self.master_key.expose_secret().clone())),
+            master_key_source: self.master_key_source.clone(),
+            master_key_version: self.master_key_version,
             hash_secret: SecretBox::new(Box::new(self.hash_secret.expose_secret().clone())),
+            key_replication_key: self
+                .key_replication_key
+                .as_ref()
+                .map(|key| SecretBox::new(Box::new(key.expose_secret().clone()))),
             otlp_endpoint: self.otlp_endpoint.clone(),
             jwt_clock_skew_seconds: self.jwt_clock_skew_seconds,
             bcrypt_cost: self.bcrypt_cost,
@@ -122,6 +421,10 @@ impl Clone for Config {
             rate_limit_max_attempts: self.rate_limit_max_attempts,
             registration_rate_limit_window_minutes: self.registration_rate_limit_window_minutes,
             registration_rate_limit_max_attempts: self.registration_rate_limit_max_attempts,
+            client_rate_limits: self.client_rate_limits.clone(),
+            token_ttls: self.token_ttls.clone(),
+            secret_policy: self.secret_policy.clone(),
+            mtls: self.mtls.clone(),
         }
     }
 }
@@ -131,14 +434,22 @@ impl Clone for Config {
 /// Redacted fields:
 /// - `master_key`: Cryptographic key material
 /// - `hash_secret`: HMAC secret key
+/// - `key_replication_key`: Cryptographic key material
 /// - `database_url`: May contain credentials in connection string
 impl fmt::Debug for Config {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Config")
             .field("database_url", &"[REDACTED]")
             .field("bind_address", &self.bind_address)
+            .field("health_socket", &self.health_socket)
             .field("master_key", &"[REDACTED]")
+            .field("master_key_source", &self.master_key_source)
+            .field("master_key_version", &self.master_key_version)
             .field("hash_secret", &"[REDACTED]")
+            .field(
+                "key_replication_key",
+                &self.key_replication_key.as_ref().map(|_| "[REDACTED]"),
+            )
             .field("otlp_endpoint", &self.otlp_endpoint)
             .field("jwt_clock_skew_seconds", &self.jwt_clock_skew_seconds)
             .field("bcrypt_cost", &self.bcrypt_cost)
@@ -152,6 +463,10 @@ impl fmt::Debug for Config {
                 "registration_rate_limit_max_attempts",
                 &self.registration_rate_limit_max_attempts,
             )
+            .field("client_rate_limits", &self.client_rate_limits)
+            .field("token_ttls", &self.token_ttls)
+            .field("secret_policy", &self.secret_policy)
+            .field("mtls", &self.mtls)
             .finish()
     }
 }
@@ -164,9 +479,15 @@ pub enum ConfigError {
     #[error("Invalid master key format: {0}")]
     InvalidMasterKey(String),
 
+    #[error("Invalid master key provider configuration: {0}")]
+    InvalidMasterKeyProvider(String),
+
     #[error("Invalid hash secret format: {0}")]
     InvalidHashSecret(String),
 
+    #[error("Invalid key replication key format: {0}")]
+    InvalidKeyReplicationKey(String),
+
     #[error("Base64 decode error: {0}")]
     Base64Error(#[from] base64::DecodeError),
 
@@ -178,6 +499,29 @@ pub enum ConfigError {
 
     #[error("Invalid rate limit configuration: {0}")]
     InvalidRateLimitConfig(String),
+
+    #[error("Invalid token TTL configuration: {0}")]
+    InvalidTokenTtl(String),
+
+    #[error("Invalid client secret policy configuration: {0}")]
+    InvalidSecretPolicy(String),
+
+    #[error("Invalid health socket configuration: {0}")]
+    InvalidHealthSocket(String),
+
+    #[error("Invalid mTLS configuration: {0}")]
+    InvalidMtlsConfig(String),
+}
+
+/// `OTLP_ENDPOINT`, ignoring an empty value.
+///
+/// `main` reads this on its own before the rest of the configuration:
+/// trace export has to be part of the subscriber installed at startup, and
+/// the subscriber must exist before configuration loading logs anything.
+pub fn otlp_endpoint_from_vars(vars: &HashMap<String, String>) -> Option<String> {
+    vars.get("OTLP_ENDPOINT")
+        .filter(|endpoint| !endpoint.trim().is_empty())
+        .cloned()
 }
 
 impl Config {
@@ -198,20 +542,44 @@ impl Config {
             .cloned()
             .unwrap_or_else(|| "0.0.0.0:8082".to_string());
 
-        let master_key_base64 = vars
-            .get("AC_MASTER_KEY")
-            .ok_or_else(|| ConfigError::MissingEnvVar("AC_MASTER_KEY".to_string()))?;
+        let master_key_source = Self::parse_master_key_source(vars)?;
 
-        let master_key = general_purpose::STANDARD
-            .decode(master_key_base64)
-            .map_err(ConfigError::Base64Error)?;
+        // With a KMS provider only the wrapped key is configured
+        let master_key = if let MasterKeySource::Env = master_key_source {
+            let master_key_base64 = vars
+                .get("AC_MASTER_KEY")
+                .ok_or_else(|| ConfigError::MissingEnvVar("AC_MASTER_KEY".to_string()))?;
 
-        if master_key.len() != 32 {
-            return Err(ConfigError::InvalidMasterKey(format!(
-                "Expected 32 bytes, got {}",
-                master_key.len()
-            )));
-        }
+            let master_key = general_purpose::STANDARD
+                .decode(master_key_base64)
+                .map_err(ConfigError::Base64Error)?;
+
+            if master_key.len() != 32 {
+                return Err(ConfigError::InvalidMasterKey(format!(
+                    "Expected 32 bytes, got {}",
+                    master_key.len()
+                )));
+            }
+
+            master_key
+        } else {
+            Vec::new()
+        };
+
+        let master_key_version = match vars.get("AC_MASTER_KEY_VERSION") {
+            Some(value) => value
+                .trim()
+                .parse::<i32>()
+                .ok()
+                .filter(|version| *version >= 1)
+                .ok_or_else(|| {
+                    ConfigError::InvalidMasterKey(format!(
+                        "AC_MASTER_KEY_VERSION must be a positive integer, got '{}'",
+                        value
+                    ))
+                })?,
+            None => DEFAULT_MASTER_KEY_VERSION,
+        };
 
         // ADR-0011: Load hash secret for HMAC-SHA256 correlation hashing
         // Default to 32 zero bytes for tests to avoid requiring env var in test environment
@@ -234,7 +602,24 @@ impl Config {
             vec![0u8; 32]
         };
 
-        let otlp_endpoint = vars.get("OTLP_ENDPOINT").cloned();
+        // Only set for multi-region deployments
+        let key_replication_key = match vars.get("AC_KEY_REPLICATION_KEY") {
+            Some(key_base64) => {
+                let key = general_purpose::STANDARD
+                    .decode(key_base64)
+                    .map_err(ConfigError::Base64Error)?;
+                if key.len() != 32 {
+                    return Err(ConfigError::InvalidKeyReplicationKey(format!(
+                        "Expected 32 bytes, got {}",
+                        key.len()
+                    )));
+                }
+                Some(SecretBox::new(Box::new(key)))
+            }
+            None => None,
+        };
+
+        let otlp_endpoint = otlp_endpoint_from_vars(vars);
 
         // Parse JWT clock skew tolerance with validation
         // Default: 300 seconds (5 minutes) per NIST SP 800-63B
@@ -353,6 +738,10 @@ impl Config {
             MAX_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
         )?;
 
+        let client_rate_limits = Self::parse_client_rate_limits(vars)?;
+        let token_ttls = Self::parse_token_ttls(vars)?;
+        let secret_policy = Self::parse_secret_policy(vars)?;
+
         // Warn on non-default rate limit values
         if rate_limit_window_minutes != DEFAULT_RATE_LIMIT_WINDOW_MINUTES {
             warn!(
@@ -396,11 +785,20 @@ impl Config {
         // Allow non-TLS for local development but warn
         Self::validate_tls_config(&database_url);
 
+        let health_socket = UnixSocketConfig::from_vars(vars, "AC_HEALTH")
+            .map_err(ConfigError::InvalidHealthSocket)?;
+
+        let mtls = Self::parse_mtls_config(vars)?;
+
         Ok(Config {
             database_url,
             bind_address,
+            health_socket,
             master_key: SecretBox::new(Box::new(master_key)),
+            master_key_source,
+            master_key_version,
             hash_secret: SecretBox::new(Box::new(hash_secret)),
+            key_replication_key,
             otlp_endpoint,
             jwt_clock_skew_seconds,
             bcrypt_cost,
@@ -408,6 +806,10 @@ impl Config {
             rate_limit_max_attempts,
             registration_rate_limit_window_minutes,
             registration_rate_limit_max_attempts,
+            client_rate_limits,
+            token_ttls,
+            secret_policy,
+            mtls,
         })
     }
 
@@ -472,6 +874,294 @@ impl Config {
 
         Ok(value)
     }
+
+    /// Parse `AC_CLIENT_SECRET_MIN_LENGTH` and `AC_CLIENT_SECRET_MIN_ENTROPY_BITS`.
+    fn parse_secret_policy(vars: &HashMap<String, String>) -> Result<SecretPolicy, ConfigError> {
+        let parse = |name: &str, default: u32, min: u32, max: u32| {
+            let Some(value_str) = vars.get(name) else {
+                return Ok(default);
+            };
+            let value: u32 = value_str.parse().map_err(|e| {
+                ConfigError::InvalidSecretPolicy(format!(
+                    "{} must be a valid positive integer, got '{}': {}",
+                    name, value_str, e
+                ))
+            })?;
+            if !(min..=max).contains(&value) {
+                return Err(ConfigError::InvalidSecretPolicy(format!(
+                    "{} must be between {} and {}, got {}",
+                    name, min, max, value
+                )));
+            }
+            Ok(value)
+        };
+
+        let min_length = parse(
+            "AC_CLIENT_SECRET_MIN_LENGTH",
+            DEFAULT_SECRET_MIN_LENGTH as u32,
+            MIN_SECRET_MIN_LENGTH as u32,
+            MAX_SECRET_MIN_LENGTH as u32,
+        )?;
+        let min_entropy_bits = parse(
+            "AC_CLIENT_SECRET_MIN_ENTROPY_BITS",
+            DEFAULT_SECRET_MIN_ENTROPY_BITS,
+            MIN_SECRET_MIN_ENTROPY_BITS,
+            MAX_SECRET_MIN_ENTROPY_BITS,
+        )?;
+
+        Ok(SecretPolicy {
+            min_length: min_length as usize,
+            min_entropy_bits,
+        })
+    }
+
+    /// Parse `AC_CLIENT_RATE_LIMIT_PER_MINUTE` and `AC_CLIENT_RATE_LIMITS`.
+    fn parse_client_rate_limits(
+        vars: &HashMap<String, String>,
+    ) -> Result<ClientRateLimits, ConfigError> {
+        let default_per_minute = match vars.get("AC_CLIENT_RATE_LIMIT_PER_MINUTE") {
+            Some(value) => Self::parse_client_rate(value, "AC_CLIENT_RATE_LIMIT_PER_MINUTE")?,
+            None => DEFAULT_CLIENT_RATE_LIMIT_PER_MINUTE,
+        };
+
+        let mut per_class = HashMap::new();
+        if let Some(list) = vars.get("AC_CLIENT_RATE_LIMITS") {
+            for entry in list.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+                let (class, value) = entry.split_once('=').ok_or_else(|| {
+                    ConfigError::InvalidRateLimitConfig(format!(
+                        "AC_CLIENT_RATE_LIMITS entries must be class=per_minute, got '{}'",
+                        entry
+                    ))
+                })?;
+                let class = class.trim();
+                if class.is_empty() {
+                    return Err(ConfigError::InvalidRateLimitConfig(format!(
+                        "AC_CLIENT_RATE_LIMITS entry has an empty class: '{}'",
+                        entry
+                    )));
+                }
+                let name = format!("AC_CLIENT_RATE_LIMITS[{}]", class);
+                per_class.insert(
+                    class.to_string(),
+                    Self::parse_client_rate(value.trim(), &name)?,
+                );
+            }
+        }
+
+        Ok(ClientRateLimits {
+            default_per_minute,
+            per_class,
+        })
+    }
+
+    /// Parse the `AC_*_TOKEN_TTL_SECONDS` lifetimes, `AC_SERVICE_TOKEN_TTLS`
+    /// and `AC_SCOPE_TOKEN_TTLS`.
+    fn parse_token_ttls(vars: &HashMap<String, String>) -> Result<TokenTtlPolicy, ConfigError> {
+        let ttl = |name: &str, default: u32| match vars.get(name) {
+            Some(value) => Self::parse_token_ttl(value, name),
+            None => Ok(default),
+        };
+
+        Ok(TokenTtlPolicy {
+            service_seconds: ttl(
+                "AC_SERVICE_TOKEN_TTL_SECONDS",
+                DEFAULT_SERVICE_TOKEN_TTL_SECONDS,
+            )?,
+            per_service_type: Self::parse_token_ttl_overrides(
+                vars,
+                "AC_SERVICE_TOKEN_TTLS",
+                "service type",
+            )?,
+            per_scope: Self::parse_token_ttl_overrides(vars, "AC_SCOPE_TOKEN_TTLS", "scope")?,
+            user_seconds: ttl("AC_USER_TOKEN_TTL_SECONDS", DEFAULT_USER_TOKEN_TTL_SECONDS)?,
+            guest_seconds: ttl(
+                "AC_GUEST_TOKEN_TTL_SECONDS",
+                DEFAULT_GUEST_TOKEN_TTL_SECONDS,
+            )?,
+            delegated_seconds: ttl(
+                "AC_DELEGATED_TOKEN_TTL_SECONDS",
+                DEFAULT_DELEGATED_TOKEN_TTL_SECONDS,
+            )?,
+            max_ttl_seconds: ttl("AC_MAX_TOKEN_TTL_SECONDS", DEFAULT_MAX_TOKEN_TTL_SECONDS)?,
+        })
+    }
+
+    /// Parse a comma-separated `key=seconds` lifetime override list.
+    fn parse_token_ttl_overrides(
+        vars: &HashMap<String, String>,
+        var: &str,
+        key_kind: &str,
+    ) -> Result<HashMap<String, u32>, ConfigError> {
+        let mut overrides = HashMap::new();
+        let Some(list) = vars.get(var) else {
+            return Ok(overrides);
+        };
+        for entry in list.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (key, value) = entry.split_once('=').ok_or_else(|| {
+                ConfigError::InvalidTokenTtl(format!(
+                    "{} entries must be {}=seconds, got '{}'",
+                    var,
+                    key_kind.replace(' ', "_"),
+                    entry
+                ))
+            })?;
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(ConfigError::InvalidTokenTtl(format!(
+                    "{} entry has an empty {}: '{}'",
+                    var, key_kind, entry
+                )));
+            }
+            let name = format!("{}[{}]", var, key);
+            overrides.insert(key.to_string(), Self::parse_token_ttl(value.trim(), &name)?);
+        }
+        Ok(overrides)
+    }
+
+    /// Parse `AC_MASTER_KEY_PROVIDER` and the settings of the chosen provider.
+    fn parse_master_key_source(
+        vars: &HashMap<String, String>,
+    ) -> Result<MasterKeySource, ConfigError> {
+        let required = |name: &str| {
+            vars.get(name)
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+                .ok_or_else(|| ConfigError::MissingEnvVar(name.to_string()))
+        };
+        let optional = |name: &str| {
+            vars.get(name)
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        };
+
+        let provider = optional("AC_MASTER_KEY_PROVIDER").map(|p| p.to_ascii_lowercase());
+        match provider.as_deref() {
+            None | Some("env") => Ok(MasterKeySource::Env),
+            Some("aws-kms") => {
+                let ciphertext = general_purpose::STANDARD
+                    .decode(required("AC_MASTER_KEY_CIPHERTEXT")?)
+                    .map_err(ConfigError::Base64Error)?;
+                Ok(MasterKeySource::AwsKms(AwsKmsConfig {
+                    region: required("AWS_REGION")?,
+                    key_id: optional("AC_KMS_KEY_ID"),
+                    endpoint: optional("AC_KMS_ENDPOINT"),
+                    ciphertext,
+                    access_key_id: required("AWS_ACCESS_KEY_ID")?,
+                    secret_access_key: SecretString::from(required("AWS_SECRET_ACCESS_KEY")?),
+                    session_token: optional("AWS_SESSION_TOKEN").map(SecretString::from),
+                }))
+            }
+            Some("vault-transit") => {
+                let ciphertext = required("AC_MASTER_KEY_CIPHERTEXT")?;
+                if !ciphertext.starts_with("vault:") {
+                    return Err(ConfigError::InvalidMasterKeyProvider(
+                        "AC_MASTER_KEY_CIPHERTEXT must be a Vault transit ciphertext (vault:v1:...)"
+                            .to_string(),
+                    ));
+                }
+                Ok(MasterKeySource::VaultTransit(VaultTransitConfig {
+                    addr: required("VAULT_ADDR")?,
+                    token: SecretString::from(required("VAULT_TOKEN")?),
+                    mount: optional("AC_VAULT_TRANSIT_MOUNT")
+                        .unwrap_or_else(|| "transit".to_string()),
+                    key_name: required("AC_VAULT_TRANSIT_KEY")?,
+                    ciphertext,
+                }))
+            }
+            Some(other) => Err(ConfigError::InvalidMasterKeyProvider(format!(
+                "AC_MASTER_KEY_PROVIDER must be env, aws-kms, or vault-transit, got '{}'",
+                other
+            ))),
+        }
+    }
+
+    /// Parse `AC_MTLS_MODE`, `AC_TLS_CERT_PATH`, and `AC_TLS_KEY_PATH`.
+    fn parse_mtls_config(vars: &HashMap<String, String>) -> Result<MtlsConfig, ConfigError> {
+        let mode = match vars
+            .get("AC_MTLS_MODE")
+            .map(|v| v.trim().to_ascii_lowercase())
+        {
+            None => MtlsMode::Disabled,
+            Some(v) if v.is_empty() || v == "disabled" => MtlsMode::Disabled,
+            Some(v) if v == "optional" => MtlsMode::Optional,
+            Some(v) if v == "required" => MtlsMode::Required,
+            Some(v) => {
+                return Err(ConfigError::InvalidMtlsConfig(format!(
+                    "AC_MTLS_MODE must be disabled, optional, or required, got '{}'",
+                    v
+                )))
+            }
+        };
+
+        let path = |name: &str| {
+            vars.get(name)
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+                .map(PathBuf::from)
+        };
+        let cert_path = path("AC_TLS_CERT_PATH");
+        let key_path = path("AC_TLS_KEY_PATH");
+
+        if mode.is_enabled() {
+            for (name, value) in [
+                ("AC_TLS_CERT_PATH", &cert_path),
+                ("AC_TLS_KEY_PATH", &key_path),
+            ] {
+                if value.is_none() {
+                    return Err(ConfigError::InvalidMtlsConfig(format!(
+                        "{} is required when AC_MTLS_MODE is enabled",
+                        name
+                    )));
+                }
+            }
+        }
+
+        Ok(MtlsConfig {
+            mode,
+            cert_path,
+            key_path,
+        })
+    }
+
+    /// Parse one per-minute client rate with bounds validation.
+    fn parse_client_rate(value: &str, name: &str) -> Result<u32, ConfigError> {
+        let v: u32 = value.parse().map_err(|e| {
+            ConfigError::InvalidRateLimitConfig(format!(
+                "{} must be a valid integer, got '{}': {}",
+                name, value, e
+            ))
+        })?;
+
+        if !(MIN_CLIENT_RATE_LIMIT_PER_MINUTE..=MAX_CLIENT_RATE_LIMIT_PER_MINUTE).contains(&v) {
+            return Err(ConfigError::InvalidRateLimitConfig(format!(
+                "{} must be between {} and {}, got {}",
+                name, MIN_CLIENT_RATE_LIMIT_PER_MINUTE, MAX_CLIENT_RATE_LIMIT_PER_MINUTE, v
+            )));
+        }
+
+        Ok(v)
+    }
+
+    /// Parse one token lifetime in seconds with bounds validation.
+    fn parse_token_ttl(value: &str, name: &str) -> Result<u32, ConfigError> {
+        let v: u32 = value.parse().map_err(|e| {
+            ConfigError::InvalidTokenTtl(format!(
+                "{} must be a valid integer, got '{}': {}",
+                name, value, e
+            ))
+        })?;
+
+        if !(MIN_TOKEN_TTL_SECONDS..=MAX_TOKEN_TTL_SECONDS).contains(&v) {
+            return Err(ConfigError::InvalidTokenTtl(format!(
+                "{} must be between {} and {}, got {}",
+                name, MIN_TOKEN_TTL_SECONDS, MAX_TOKEN_TTL_SECONDS, v
+            )));
+        }
+
+        Ok(v)
+    }
 }
 
 #[cfg(test)]
@@ -620,6 +1310,12 @@ mod tests {
 
         let config = Config::from_vars(&vars).expect("Config should load successfully");
         assert_eq!(config.otlp_endpoint, None);
+
+        // An empty value (e.g. an unset Helm value) also disables export
+        let mut vars = vars;
+        vars.insert("OTLP_ENDPOINT".to_string(), String::new());
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(config.otlp_endpoint, None);
     }
 
     #[test]
@@ -1382,6 +2078,497 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_health_socket_config() {
+        let mut vars = HashMap::from([
+            (
+                "DATABASE_URL".to_string(),
+                "postgresql://localhost/test".to_string(),
+            ),
+            ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+        ]);
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert!(config.health_socket.is_none());
+
+        vars.insert(
+            "AC_HEALTH_SOCKET_PATH".to_string(),
+            "/run/ac/health.sock".to_string(),
+        );
+        vars.insert("AC_HEALTH_SOCKET_MODE".to_string(), "0640".to_string());
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(config.health_socket.map(|s| s.mode), Some(0o640));
+
+        vars.insert("AC_HEALTH_SOCKET_MODE".to_string(), "8".to_string());
+        let result = Config::from_vars(&vars);
+        assert!(matches!(result, Err(ConfigError::InvalidHealthSocket(_))));
+    }
+
+    #[test]
+    fn test_client_rate_limits() {
+        let mut vars = HashMap::from([
+            (
+                "DATABASE_URL".to_string(),
+                "postgresql://localhost/test".to_string(),
+            ),
+            ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+        ]);
+
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(config.client_rate_limits, ClientRateLimits::default());
+        assert_eq!(
+            config.client_rate_limits.per_minute("user"),
+            DEFAULT_CLIENT_RATE_LIMIT_PER_MINUTE
+        );
+
+        vars.insert(
+            "AC_CLIENT_RATE_LIMIT_PER_MINUTE".to_string(),
+            "30".to_string(),
+        );
+        vars.insert(
+            "AC_CLIENT_RATE_LIMITS".to_string(),
+            "global-controller=600, user=10".to_string(),
+        );
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        let limits = &config.client_rate_limits;
+        assert_eq!(limits.per_minute("global-controller"), 600);
+        assert_eq!(limits.per_minute("user"), 10);
+        assert_eq!(limits.per_minute("media-handler"), 30);
+    }
+
+    #[test]
+    fn test_master_key_version() {
+        let mut vars = HashMap::from([
+            (
+                "DATABASE_URL".to_string(),
+                "postgresql://localhost/test".to_string(),
+            ),
+            ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+        ]);
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(config.master_key_version, DEFAULT_MASTER_KEY_VERSION);
+
+        vars.insert("AC_MASTER_KEY_VERSION".to_string(), "3".to_string());
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(config.master_key_version, 3);
+
+        for value in ["0", "-1", "v2"] {
+            vars.insert("AC_MASTER_KEY_VERSION".to_string(), value.to_string());
+            assert!(
+                matches!(
+                    Config::from_vars(&vars),
+                    Err(ConfigError::InvalidMasterKey(_))
+                ),
+                "AC_MASTER_KEY_VERSION={value} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_master_key_provider_config() {
+        let mut vars = HashMap::from([
+            (
+                "DATABASE_URL".to_string(),
+                "postgresql://localhost/test".to_string(),
+            ),
+            ("AC_MASTER_KEY_PROVIDER".to_string(), "aws-kms".to_string()),
+            (
+                "AC_MASTER_KEY_CIPHERTEXT".to_string(),
+                general_purpose::STANDARD.encode(b"wrapped"),
+            ),
+            ("AWS_REGION".to_string(), "us-east-1".to_string()),
+            ("AWS_ACCESS_KEY_ID".to_string(), "AKIDEXAMPLE".to_string()),
+        ]);
+
+        // AC_MASTER_KEY is not needed, but KMS credentials are
+        let result = Config::from_vars(&vars);
+        assert!(
+            matches!(result, Err(ConfigError::MissingEnvVar(v)) if v == "AWS_SECRET_ACCESS_KEY")
+        );
+
+        vars.insert("AWS_SECRET_ACCESS_KEY".to_string(), "secret".to_string());
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert!(config.master_key.expose_secret().is_empty());
+        match &config.master_key_source {
+            MasterKeySource::AwsKms(kms) => {
+                assert_eq!(kms.region, "us-east-1");
+                assert_eq!(kms.ciphertext, b"wrapped");
+                assert!(kms.session_token.is_none());
+            }
+            other => panic!("expected aws-kms, got {other:?}"),
+        }
+        assert!(!format!("{config:?}").contains("secret\""));
+
+        vars.insert(
+            "AC_MASTER_KEY_PROVIDER".to_string(),
+            "vault-transit".to_string(),
+        );
+        vars.insert("VAULT_ADDR".to_string(), "https://vault:8200".to_string());
+        vars.insert("VAULT_TOKEN".to_string(), "s.token".to_string());
+        vars.insert("AC_VAULT_TRANSIT_KEY".to_string(), "ac-master".to_string());
+        let result = Config::from_vars(&vars);
+        assert!(
+            matches!(result, Err(ConfigError::InvalidMasterKeyProvider(_))),
+            "base64 ciphertext is not a Vault ciphertext"
+        );
+
+        vars.insert(
+            "AC_MASTER_KEY_CIPHERTEXT".to_string(),
+            "vault:v1:abcd".to_string(),
+        );
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        match &config.master_key_source {
+            MasterKeySource::VaultTransit(vault) => {
+                assert_eq!(vault.mount, "transit");
+                assert_eq!(vault.key_name, "ac-master");
+            }
+            other => panic!("expected vault-transit, got {other:?}"),
+        }
+
+        vars.insert("AC_MASTER_KEY_PROVIDER".to_string(), "hsm".to_string());
+        let result = Config::from_vars(&vars);
+        assert!(matches!(
+            result,
+            Err(ConfigError::InvalidMasterKeyProvider(_))
+        ));
+    }
+
+    #[test]
+    fn test_mtls_config() {
+        let mut vars = HashMap::from([
+            (
+                "DATABASE_URL".to_string(),
+                "postgresql://localhost/test".to_string(),
+            ),
+            ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+        ]);
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(config.mtls, MtlsConfig::default());
+        assert!(!config.mtls.mode.is_enabled());
+
+        vars.insert("AC_MTLS_MODE".to_string(), "Required".to_string());
+        let result = Config::from_vars(&vars);
+        assert!(matches!(result, Err(ConfigError::InvalidMtlsConfig(_))));
+
+        vars.insert(
+            "AC_TLS_CERT_PATH".to_string(),
+            "/etc/ac/tls/tls.crt".to_string(),
+        );
+        vars.insert(
+            "AC_TLS_KEY_PATH".to_string(),
+            "/etc/ac/tls/tls.key".to_string(),
+        );
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(config.mtls.mode, MtlsMode::Required);
+        assert_eq!(
+            config.mtls.cert_path,
+            Some(PathBuf::from("/etc/ac/tls/tls.crt"))
+        );
+
+        vars.insert("AC_MTLS_MODE".to_string(), "sometimes".to_string());
+        let result = Config::from_vars(&vars);
+        assert!(matches!(result, Err(ConfigError::InvalidMtlsConfig(_))));
+    }
+
+    #[test]
+    fn test_client_rate_limits_rejects_invalid() {
+        for (var, value) in [
+            ("AC_CLIENT_RATE_LIMIT_PER_MINUTE", "0"),
+            ("AC_CLIENT_RATE_LIMIT_PER_MINUTE", "10001"),
+            ("AC_CLIENT_RATE_LIMITS", "user"),
+            ("AC_CLIENT_RATE_LIMITS", "=5"),
+            ("AC_CLIENT_RATE_LIMITS", "user=fast"),
+        ] {
+            let vars = HashMap::from([
+                (
+                    "DATABASE_URL".to_string(),
+                    "postgresql://localhost/test".to_string(),
+                ),
+                ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+                (var.to_string(), value.to_string()),
+            ]);
+
+            let result = Config::from_vars(&vars);
+            assert!(
+                matches!(result, Err(ConfigError::InvalidRateLimitConfig(_))),
+                "{var}={value} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_token_ttls() {
+        let mut vars = HashMap::from([
+            (
+                "DATABASE_URL".to_string(),
+                "postgresql://localhost/test".to_string(),
+            ),
+            ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+        ]);
+
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(config.token_ttls, TokenTtlPolicy::default());
+        assert_eq!(
+            config.token_ttls.service_ttl("global-controller", &[]),
+            DEFAULT_SERVICE_TOKEN_TTL_SECONDS
+        );
+        assert_eq!(config.token_ttls.meeting_ttl(600), 600);
+        assert_eq!(config.token_ttls.meeting_ttl(u32::MAX), 3600);
+        assert_eq!(config.token_ttls.delegated_ttl(), 300);
+
+        vars.insert(
+            "AC_SERVICE_TOKEN_TTL_SECONDS".to_string(),
+            "1800".to_string(),
+        );
+        vars.insert(
+            "AC_SERVICE_TOKEN_TTLS".to_string(),
+            "media-handler=300, global-controller=7200".to_string(),
+        );
+        vars.insert("AC_USER_TOKEN_TTL_SECONDS".to_string(), "600".to_string());
+        vars.insert("AC_GUEST_TOKEN_TTL_SECONDS".to_string(), "300".to_string());
+        vars.insert(
+            "AC_DELEGATED_TOKEN_TTL_SECONDS".to_string(),
+            "120".to_string(),
+        );
+        vars.insert("AC_MAX_TOKEN_TTL_SECONDS".to_string(), "1200".to_string());
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        let ttls = &config.token_ttls;
+        assert_eq!(ttls.service_ttl("media-handler", &[]), 300);
+        assert_eq!(
+            ttls.service_ttl("global-controller", &[]),
+            1200,
+            "capped at max_ttl"
+        );
+        assert_eq!(
+            ttls.service_ttl("meeting-controller", &[]),
+            1200,
+            "capped at max_ttl"
+        );
+        assert_eq!(ttls.user_ttl(), 600);
+        assert_eq!(ttls.delegated_ttl(), 120);
+        assert_eq!(ttls.meeting_ttl(3600), 1200, "capped at max_ttl");
+        assert_eq!(ttls.guest_ttl(900), 300, "capped at the guest lifetime");
+        assert_eq!(ttls.guest_ttl(120), 120);
+    }
+
+    #[test]
+    fn test_scope_token_ttls() {
+        let vars = HashMap::from([
+            (
+                "DATABASE_URL".to_string(),
+                "postgresql://localhost/test".to_string(),
+            ),
+            ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+            (
+                "AC_SERVICE_TOKEN_TTLS".to_string(),
+                "global-controller=7200".to_string(),
+            ),
+            (
+                "AC_SCOPE_TOKEN_TTLS".to_string(),
+                "internal:meeting-token=300, meeting:create=600".to_string(),
+            ),
+        ]);
+
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        let ttls = &config.token_ttls;
+        assert_eq!(ttls.per_scope.len(), 2);
+        let scopes = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        // No overridden scope: the service type lifetime applies
+        assert_eq!(
+            ttls.service_ttl("global-controller", &scopes(&["meeting:read"])),
+            7200
+        );
+        // The scope override wins over the service type
+        assert_eq!(
+            ttls.service_ttl("global-controller", &scopes(&["meeting:create"])),
+            600
+        );
+        // Several overridden scopes: the shortest lifetime wins
+        assert_eq!(
+            ttls.service_ttl(
+                "global-controller",
+                &scopes(&["meeting:create", "internal:meeting-token", "meeting:read"])
+            ),
+            300
+        );
+    }
+
+    #[test]
+    fn test_token_ttl_overrides_capped_at_max() {
+        let vars = HashMap::from([
+            (
+                "DATABASE_URL".to_string(),
+                "postgresql://localhost/test".to_string(),
+            ),
+            ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+            (
+                "AC_SERVICE_TOKEN_TTLS".to_string(),
+                "global-controller=7200".to_string(),
+            ),
+            (
+                "AC_SCOPE_TOKEN_TTLS".to_string(),
+                "meeting:create=7200".to_string(),
+            ),
+            ("AC_USER_TOKEN_TTL_SECONDS".to_string(), "7200".to_string()),
+            (
+                "AC_DELEGATED_TOKEN_TTL_SECONDS".to_string(),
+                "7200".to_string(),
+            ),
+            ("AC_MAX_TOKEN_TTL_SECONDS".to_string(), "1800".to_string()),
+        ]);
+
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        let ttls = &config.token_ttls;
+        let scopes = vec!["meeting:create".to_string()];
+        assert_eq!(ttls.service_ttl("media-handler", &scopes), 1800);
+        assert_eq!(ttls.service_ttl("global-controller", &[]), 1800);
+        assert_eq!(ttls.user_ttl(), 1800);
+        assert_eq!(ttls.delegated_ttl(), 1800);
+    }
+
+    #[test]
+    fn test_token_ttls_rejects_invalid() {
+        for (var, value) in [
+            ("AC_SERVICE_TOKEN_TTL_SECONDS", "59"),
+            ("AC_USER_TOKEN_TTL_SECONDS", "86401"),
+            ("AC_GUEST_TOKEN_TTL_SECONDS", "-1"),
+            ("AC_DELEGATED_TOKEN_TTL_SECONDS", "0"),
+            ("AC_MAX_TOKEN_TTL_SECONDS", "forever"),
+            ("AC_SERVICE_TOKEN_TTLS", "media-handler"),
+            ("AC_SERVICE_TOKEN_TTLS", "=300"),
+            ("AC_SERVICE_TOKEN_TTLS", "media-handler=0"),
+            ("AC_SCOPE_TOKEN_TTLS", "internal:meeting-token"),
+            ("AC_SCOPE_TOKEN_TTLS", "=300"),
+            ("AC_SCOPE_TOKEN_TTLS", "internal:meeting-token=86401"),
+        ] {
+            let vars = HashMap::from([
+                (
+                    "DATABASE_URL".to_string(),
+                    "postgresql://localhost/test".to_string(),
+                ),
+                ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+                (var.to_string(), value.to_string()),
+            ]);
+
+            let result = Config::from_vars(&vars);
+            assert!(
+                matches!(result, Err(ConfigError::InvalidTokenTtl(_))),
+                "{var}={value} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_secret_policy() {
+        let mut vars = HashMap::from([
+            (
+                "DATABASE_URL".to_string(),
+                "postgresql://localhost/test".to_string(),
+            ),
+            ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+        ]);
+
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(config.secret_policy, SecretPolicy::default());
+
+        vars.insert("AC_CLIENT_SECRET_MIN_LENGTH".to_string(), "48".to_string());
+        vars.insert(
+            "AC_CLIENT_SECRET_MIN_ENTROPY_BITS".to_string(),
+            "192".to_string(),
+        );
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(
+            config.secret_policy,
+            SecretPolicy {
+                min_length: 48,
+                min_entropy_bits: 192,
+            }
+        );
+    }
+
+    #[test]
+    fn test_secret_policy_rejects_invalid() {
+        for (var, value) in [
+            ("AC_CLIENT_SECRET_MIN_LENGTH", "15"),
+            ("AC_CLIENT_SECRET_MIN_LENGTH", "65"),
+            ("AC_CLIENT_SECRET_MIN_LENGTH", "long"),
+            ("AC_CLIENT_SECRET_MIN_ENTROPY_BITS", "63"),
+            ("AC_CLIENT_SECRET_MIN_ENTROPY_BITS", "257"),
+            ("AC_CLIENT_SECRET_MIN_ENTROPY_BITS", "-1"),
+        ] {
+            let vars = HashMap::from([
+                (
+                    "DATABASE_URL".to_string(),
+                    "postgresql://localhost/test".to_string(),
+                ),
+                ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+                (var.to_string(), value.to_string()),
+            ]);
+
+            let result = Config::from_vars(&vars);
+            assert!(
+                matches!(result, Err(ConfigError::InvalidSecretPolicy(_))),
+                "{var}={value} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_key_replication_key() {
+        let mut vars = HashMap::from([
+            (
+                "DATABASE_URL".to_string(),
+                "postgresql://localhost/test".to_string(),
+            ),
+            ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+        ]);
+
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert!(config.key_replication_key.is_none());
+
+        vars.insert(
+            "AC_KEY_REPLICATION_KEY".to_string(),
+            general_purpose::STANDARD.encode([7u8; 32]),
+        );
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        let key = config
+            .key_replication_key
+            .as_ref()
+            .expect("replication key should be set");
+        assert_eq!(key.expose_secret().as_slice(), &[7u8; 32]);
+        assert!(!format!("{:?}", config).contains("[7, 7"));
+    }
+
+    #[test]
+    fn test_key_replication_key_rejects_invalid() {
+        for (value, short) in [
+            (general_purpose::STANDARD.encode([7u8; 16]), true),
+            ("not base64!".to_string(), false),
+        ] {
+            let vars = HashMap::from([
+                (
+                    "DATABASE_URL".to_string(),
+                    "postgresql://localhost/test".to_string(),
+                ),
+                ("AC_MASTER_KEY".to_string(), test_master_key_base64()),
+                ("AC_KEY_REPLICATION_KEY".to_string(), value.clone()),
+            ]);
+
+            let result = Config::from_vars(&vars);
+            if short {
+                assert!(
+                    matches!(result, Err(ConfigError::InvalidKeyReplicationKey(_))),
+                    "{value} should be rejected"
+                );
+            } else {
+                assert!(
+                    matches!(result, Err(ConfigError::Base64Error(_))),
+                    "{value} should be rejected"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_rate_limit_constants_are_valid() {
         // Verify ordering: MIN <= DEFAULT <= MAX for all rate limit constants
@@ -0,0 +1,312 @@
+//! AWS KMS envelope encryption.
+//!
+//! `AC_MASTER_KEY_CIPHERTEXT` is the master key wrapped by a KMS key, e.g.
+//! from `aws kms encrypt --plaintext fileb://master.key`. At startup it is
+//! unwrapped with a SigV4-signed `TrentService.Decrypt` call.
+//!
+//! Credentials are read from `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`,
+//! and `AWS_SESSION_TOKEN`. Web identity (IRSA) and instance metadata
+//! credentials are not supported.
+
+use super::{check_unwrapped_key, http_client, MasterKeyProvider};
+use crate::config::{AwsKmsConfig, DEFAULT_MASTER_KEY_VERSION};
+use crate::crypto::{self, EncryptedKey};
+use crate::errors::AcError;
+use base64::{engine::general_purpose, Engine as _};
+use chrono::Utc;
+use common::secret::{ExposeSecret, SecretBox};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tracing::instrument;
+
+const SERVICE: &str = "kms";
+const TARGET: &str = "TrentService.Decrypt";
+const CONTENT_TYPE: &str = "application/x-amz-json-1.1";
+
+/// The master key unwrapped by AWS KMS.
+pub struct AwsKmsMasterKey {
+    key: SecretBox<Vec<u8>>,
+    version: i32,
+}
+
+#[derive(Deserialize)]
+struct DecryptResponse {
+    #[serde(rename = "Plaintext")]
+    plaintext: String,
+}
+
+impl AwsKmsMasterKey {
+    /// Unwrap `config.ciphertext` with KMS `Decrypt`.
+    #[instrument(skip_all, fields(region = %config.region))]
+    pub async fn unwrap_key(config: &AwsKmsConfig) -> Result<Self, AcError> {
+        let endpoint = config
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| format!("https://kms.{}.amazonaws.com/", config.region));
+        let url = reqwest::Url::parse(&endpoint)
+            .map_err(|e| AcError::Crypto(format!("Invalid KMS endpoint: {}", e)))?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(AcError::Crypto("KMS endpoint has no host".to_string())),
+        };
+
+        let mut body = serde_json::json!({
+            "CiphertextBlob": general_purpose::STANDARD.encode(&config.ciphertext),
+        });
+        if let Some(key_id) = &config.key_id {
+            body["KeyId"] = serde_json::Value::String(key_id.clone());
+        }
+        let payload = body.to_string();
+
+        let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let mut headers = vec![
+            ("content-type", CONTENT_TYPE.to_string()),
+            ("host", host),
+            ("x-amz-date", amz_date.clone()),
+            ("x-amz-target", TARGET.to_string()),
+        ];
+        if let Some(token) = &config.session_token {
+            headers.push(("x-amz-security-token", token.expose_secret().to_string()));
+        }
+        headers.sort();
+
+        let credentials = Credentials {
+            access_key_id: &config.access_key_id,
+            secret_access_key: config.secret_access_key.expose_secret(),
+        };
+        let authorization = sign_v4(
+            &credentials,
+            &config.region,
+            SERVICE,
+            "POST",
+            url.path(),
+            &headers,
+            payload.as_bytes(),
+            &amz_date,
+        )?;
+
+        let mut request = http_client()?
+            .post(url)
+            .header("authorization", authorization)
+            .body(payload);
+        for (name, value) in headers.iter().filter(|(name, _)| *name != "host") {
+            request = request.header(*name, value);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| AcError::Crypto(format!("KMS Decrypt request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            // Error bodies carry only the error type and message
+            let detail = response.text().await.unwrap_or_default();
+            return Err(AcError::Crypto(format!(
+                "KMS Decrypt failed with {}: {}",
+                status, detail
+            )));
+        }
+
+        let decrypted: DecryptResponse = response
+            .json()
+            .await
+            .map_err(|e| AcError::Crypto(format!("Invalid KMS Decrypt response: {}", e)))?;
+        let key = general_purpose::STANDARD
+            .decode(decrypted.plaintext)
+            .map_err(|e| AcError::Crypto(format!("Invalid KMS plaintext encoding: {}", e)))?;
+
+        Ok(Self {
+            key: check_unwrapped_key("AWS KMS", key)?,
+            version: DEFAULT_MASTER_KEY_VERSION,
+        })
+    }
+
+    /// Set the master key version (default [`DEFAULT_MASTER_KEY_VERSION`]).
+    pub fn with_version(mut self, version: i32) -> Self {
+        self.version = version;
+        self
+    }
+}
+
+impl MasterKeyProvider for AwsKmsMasterKey {
+    fn kind(&self) -> &'static str {
+        "aws-kms"
+    }
+
+    fn version(&self) -> i32 {
+        self.version
+    }
+
+    fn encrypt_private_key(&self, private_key: &[u8]) -> Result<EncryptedKey, AcError> {
+        crypto::encrypt_private_key(private_key, self.key.expose_secret())
+    }
+
+    fn decrypt_private_key(&self, encrypted: &EncryptedKey) -> Result<Vec<u8>, AcError> {
+        crypto::decrypt_private_key(encrypted, self.key.expose_secret())
+    }
+}
+
+struct Credentials<'a> {
+    access_key_id: &'a str,
+    secret_access_key: &'a str,
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Result<Vec<u8>, AcError> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key)
+        .map_err(|e| AcError::Crypto(format!("HMAC init failed: {}", e)))?;
+    mac.update(data);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+/// Build a SigV4 `Authorization` header value.
+///
+/// `headers` are the signed headers with lowercase names, sorted by name.
+/// The request has no query string.
+#[allow(clippy::too_many_arguments)]
+fn sign_v4(
+    credentials: &Credentials<'_>,
+    region: &str,
+    service: &str,
+    method: &str,
+    path: &str,
+    headers: &[(&str, String)],
+    payload: &[u8],
+    amz_date: &str,
+) -> Result<String, AcError> {
+    let date = amz_date
+        .get(..8)
+        .ok_or_else(|| AcError::Crypto("Invalid SigV4 timestamp".to_string()))?;
+
+    let canonical_headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+        .collect();
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(";");
+    let canonical_request = format!(
+        "{}\n{}\n\n{}\n{}\n{}",
+        method,
+        path,
+        canonical_headers,
+        signed_headers,
+        hex::encode(Sha256::digest(payload))
+    );
+
+    let scope = format!("{}/{}/{}/aws4_request", date, region, service);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        scope,
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let k_date = hmac_sha256(
+        format!("AWS4{}", credentials.secret_access_key).as_bytes(),
+        date.as_bytes(),
+    )?;
+    let k_region = hmac_sha256(&k_date, region.as_bytes())?;
+    let k_service = hmac_sha256(&k_region, service.as_bytes())?;
+    let k_signing = hmac_sha256(&k_service, b"aws4_request")?;
+    let signature = hex::encode(hmac_sha256(&k_signing, string_to_sign.as_bytes())?);
+
+    Ok(format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        credentials.access_key_id, scope, signed_headers, signature
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderMap;
+    use axum::routing::post;
+    use axum::{Json, Router};
+    use common::secret::SecretString;
+
+    /// `get-vanilla` from the AWS SigV4 test suite.
+    #[test]
+    fn test_sign_v4_reference_vector() {
+        let credentials = Credentials {
+            access_key_id: "AKIDEXAMPLE",
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+        };
+        let headers = vec![
+            ("host", "example.amazonaws.com".to_string()),
+            ("x-amz-date", "20150830T123600Z".to_string()),
+        ];
+
+        let authorization = sign_v4(
+            &credentials,
+            "us-east-1",
+            "service",
+            "GET",
+            "/",
+            &headers,
+            b"",
+            "20150830T123600Z",
+        )
+        .unwrap();
+
+        assert_eq!(
+            authorization,
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_key_calls_decrypt() {
+        async fn decrypt(
+            headers: HeaderMap,
+            Json(body): Json<serde_json::Value>,
+        ) -> Json<serde_json::Value> {
+            assert_eq!(headers["x-amz-target"], TARGET);
+            assert_eq!(headers["x-amz-security-token"], "session");
+            assert!(headers["x-amz-date"].to_str().unwrap().ends_with('Z'));
+            assert!(headers["authorization"]
+                .to_str()
+                .unwrap()
+                .contains("/eu-west-1/kms/aws4_request, SignedHeaders=content-type;host;"));
+            assert_eq!(body["KeyId"], "alias/ac-master");
+            assert_eq!(
+                body["CiphertextBlob"],
+                general_purpose::STANDARD.encode(b"wrapped")
+            );
+            Json(serde_json::json!({
+                "KeyId": "arn:aws:kms:eu-west-1:111122223333:key/test",
+                "Plaintext": general_purpose::STANDARD.encode([9u8; 32]),
+            }))
+        }
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, Router::new().route("/", post(decrypt)))
+                .await
+                .unwrap();
+        });
+
+        let config = AwsKmsConfig {
+            region: "eu-west-1".to_string(),
+            key_id: Some("alias/ac-master".to_string()),
+            endpoint: Some(format!("http://{}/", addr)),
+            ciphertext: b"wrapped".to_vec(),
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: SecretString::from("secret"),
+            session_token: Some(SecretString::from("session")),
+        };
+        let provider = AwsKmsMasterKey::unwrap_key(&config).await.unwrap();
+
+        let encrypted = provider.encrypt_private_key(b"pkcs8").unwrap();
+        assert_eq!(
+            vec![9u8; 32].decrypt_private_key(&encrypted).unwrap(),
+            b"pkcs8"
+        );
+    }
+}
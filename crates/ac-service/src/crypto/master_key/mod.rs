@@ -0,0 +1,183 @@
+//! Master key providers.
+//!
+//! Signing keys are stored encrypted with an AES-256 master key. A
+//! [`MasterKeyProvider`] owns that key and performs the encryption, so the
+//! key management and token services never see where it came from:
+//!
+//! - `env` ([`EnvMasterKey`]): the raw key in `AC_MASTER_KEY` (default).
+//! - `aws-kms` ([`AwsKmsMasterKey`]): envelope encryption; the Kubernetes
+//!   secret holds the key wrapped by an AWS KMS key, unwrapped at startup.
+//! - `vault-transit` ([`VaultTransitMasterKey`]): as above, with the Vault
+//!   transit secrets engine.
+//!
+//! With a KMS provider the raw key only exists in process memory. It is
+//! unwrapped once by [`load`]; a KMS outage after startup does not affect
+//! token issuance.
+
+mod aws_kms;
+mod vault_transit;
+
+pub use aws_kms::AwsKmsMasterKey;
+pub use vault_transit::VaultTransitMasterKey;
+
+use crate::config::{Config, MasterKeySource, DEFAULT_MASTER_KEY_VERSION};
+use crate::crypto::{self, EncryptedKey};
+use crate::errors::AcError;
+use common::secret::{ExposeSecret, SecretBox};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+
+/// Timeout for unwrap requests to a KMS.
+const KMS_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Encrypts and decrypts signing keys with the master key.
+pub trait MasterKeyProvider: Send + Sync {
+    /// Provider name for logs (`env`, `aws-kms`, `vault-transit`).
+    fn kind(&self) -> &'static str;
+
+    /// Version of the master key (`AC_MASTER_KEY_VERSION`), stored as the
+    /// `master_key_version` of every signing key it encrypts.
+    fn version(&self) -> i32;
+
+    /// Encrypt a signing key's PKCS#8 bytes for storage.
+    fn encrypt_private_key(&self, private_key: &[u8]) -> Result<EncryptedKey, AcError>;
+
+    /// Decrypt a stored signing key.
+    fn decrypt_private_key(&self, encrypted: &EncryptedKey) -> Result<Vec<u8>, AcError>;
+}
+
+/// A raw 32-byte key, as used by tests and tooling.
+impl MasterKeyProvider for Vec<u8> {
+    fn kind(&self) -> &'static str {
+        "raw"
+    }
+
+    fn version(&self) -> i32 {
+        DEFAULT_MASTER_KEY_VERSION
+    }
+
+    fn encrypt_private_key(&self, private_key: &[u8]) -> Result<EncryptedKey, AcError> {
+        crypto::encrypt_private_key(private_key, self)
+    }
+
+    fn decrypt_private_key(&self, encrypted: &EncryptedKey) -> Result<Vec<u8>, AcError> {
+        crypto::decrypt_private_key(encrypted, self)
+    }
+}
+
+/// The master key from `AC_MASTER_KEY`.
+pub struct EnvMasterKey {
+    key: SecretBox<Vec<u8>>,
+    version: i32,
+}
+
+impl EnvMasterKey {
+    pub fn new(key: &SecretBox<Vec<u8>>) -> Self {
+        Self {
+            key: SecretBox::new(Box::new(key.expose_secret().clone())),
+            version: DEFAULT_MASTER_KEY_VERSION,
+        }
+    }
+
+    /// Set the master key version (default [`DEFAULT_MASTER_KEY_VERSION`]).
+    pub fn with_version(mut self, version: i32) -> Self {
+        self.version = version;
+        self
+    }
+}
+
+impl MasterKeyProvider for EnvMasterKey {
+    fn kind(&self) -> &'static str {
+        "env"
+    }
+
+    fn version(&self) -> i32 {
+        self.version
+    }
+
+    fn encrypt_private_key(&self, private_key: &[u8]) -> Result<EncryptedKey, AcError> {
+        crypto::encrypt_private_key(private_key, self.key.expose_secret())
+    }
+
+    fn decrypt_private_key(&self, encrypted: &EncryptedKey) -> Result<Vec<u8>, AcError> {
+        crypto::decrypt_private_key(encrypted, self.key.expose_secret())
+    }
+}
+
+/// Build the provider selected by `config.master_key_source`.
+///
+/// KMS providers unwrap the master key here; errors mean the service cannot
+/// read its signing keys and should not start.
+pub async fn load(config: &Config) -> Result<Arc<dyn MasterKeyProvider>, AcError> {
+    let version = config.master_key_version;
+    let provider: Arc<dyn MasterKeyProvider> = match &config.master_key_source {
+        MasterKeySource::Env => {
+            Arc::new(EnvMasterKey::new(&config.master_key).with_version(version))
+        }
+        MasterKeySource::AwsKms(kms) => Arc::new(
+            AwsKmsMasterKey::unwrap_key(kms)
+                .await?
+                .with_version(version),
+        ),
+        MasterKeySource::VaultTransit(vault) => Arc::new(
+            VaultTransitMasterKey::unwrap_key(vault)
+                .await?
+                .with_version(version),
+        ),
+    };
+
+    info!(
+        target: "ac.crypto",
+        provider = provider.kind(),
+        version = provider.version(),
+        "Master key loaded"
+    );
+    Ok(provider)
+}
+
+/// HTTP client for unwrap requests.
+fn http_client() -> Result<reqwest::Client, AcError> {
+    reqwest::Client::builder()
+        .timeout(KMS_REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| AcError::Crypto(format!("Failed to build KMS client: {}", e)))
+}
+
+/// Check an unwrapped key is usable for AES-256.
+fn check_unwrapped_key(provider: &str, key: Vec<u8>) -> Result<SecretBox<Vec<u8>>, AcError> {
+    if key.len() != 32 {
+        return Err(AcError::Crypto(format!(
+            "{} returned a {}-byte master key (expected 32)",
+            provider,
+            key.len()
+        )));
+    }
+    Ok(SecretBox::new(Box::new(key)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_provider_matches_raw_key() {
+        let raw = vec![7u8; 32];
+        let env = EnvMasterKey::new(&SecretBox::new(Box::new(raw.clone())));
+
+        let encrypted = env.encrypt_private_key(b"pkcs8").unwrap();
+        assert_eq!(raw.decrypt_private_key(&encrypted).unwrap(), b"pkcs8");
+
+        let other = vec![8u8; 32];
+        assert!(other.decrypt_private_key(&encrypted).is_err());
+
+        assert_eq!(env.version(), DEFAULT_MASTER_KEY_VERSION);
+        assert_eq!(env.with_version(4).version(), 4);
+    }
+
+    #[test]
+    fn test_unwrapped_key_must_be_32_bytes() {
+        assert!(check_unwrapped_key("test", vec![0u8; 16]).is_err());
+        assert!(check_unwrapped_key("test", vec![0u8; 32]).is_ok());
+    }
+}
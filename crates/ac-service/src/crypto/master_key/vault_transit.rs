@@ -0,0 +1,163 @@
+//! HashiCorp Vault transit envelope encryption.
+//!
+//! `AC_MASTER_KEY_CIPHERTEXT` is the master key wrapped by a transit key,
+//! e.g. from `vault write transit/encrypt/ac-master plaintext=@master.b64`.
+//! At startup it is unwrapped with `POST /v1/{mount}/decrypt/{key}` using
+//! `VAULT_TOKEN`.
+
+use super::{check_unwrapped_key, http_client, MasterKeyProvider};
+use crate::config::{VaultTransitConfig, DEFAULT_MASTER_KEY_VERSION};
+use crate::crypto::{self, EncryptedKey};
+use crate::errors::AcError;
+use base64::{engine::general_purpose, Engine as _};
+use common::secret::{ExposeSecret, SecretBox};
+use serde::Deserialize;
+use tracing::instrument;
+
+/// The master key unwrapped by Vault transit.
+pub struct VaultTransitMasterKey {
+    key: SecretBox<Vec<u8>>,
+    version: i32,
+}
+
+#[derive(Deserialize)]
+struct DecryptResponse {
+    data: DecryptData,
+}
+
+#[derive(Deserialize)]
+struct DecryptData {
+    plaintext: String,
+}
+
+impl VaultTransitMasterKey {
+    /// Unwrap `config.ciphertext` with the transit `decrypt` endpoint.
+    #[instrument(skip_all, fields(mount = %config.mount, key = %config.key_name))]
+    pub async fn unwrap_key(config: &VaultTransitConfig) -> Result<Self, AcError> {
+        let url = format!(
+            "{}/v1/{}/decrypt/{}",
+            config.addr.trim_end_matches('/'),
+            config.mount.trim_matches('/'),
+            config.key_name
+        );
+
+        let response = http_client()?
+            .post(&url)
+            .header("X-Vault-Token", config.token.expose_secret())
+            .json(&serde_json::json!({ "ciphertext": config.ciphertext }))
+            .send()
+            .await
+            .map_err(|e| AcError::Crypto(format!("Vault decrypt request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            // Error bodies carry only an `errors` list
+            let detail = response.text().await.unwrap_or_default();
+            return Err(AcError::Crypto(format!(
+                "Vault decrypt failed with {}: {}",
+                status, detail
+            )));
+        }
+
+        let decrypted: DecryptResponse = response
+            .json()
+            .await
+            .map_err(|e| AcError::Crypto(format!("Invalid Vault decrypt response: {}", e)))?;
+        let key = general_purpose::STANDARD
+            .decode(decrypted.data.plaintext)
+            .map_err(|e| AcError::Crypto(format!("Invalid Vault plaintext encoding: {}", e)))?;
+
+        Ok(Self {
+            key: check_unwrapped_key("Vault transit", key)?,
+            version: DEFAULT_MASTER_KEY_VERSION,
+        })
+    }
+
+    /// Set the master key version (default [`DEFAULT_MASTER_KEY_VERSION`]).
+    pub fn with_version(mut self, version: i32) -> Self {
+        self.version = version;
+        self
+    }
+}
+
+impl MasterKeyProvider for VaultTransitMasterKey {
+    fn kind(&self) -> &'static str {
+        "vault-transit"
+    }
+
+    fn version(&self) -> i32 {
+        self.version
+    }
+
+    fn encrypt_private_key(&self, private_key: &[u8]) -> Result<EncryptedKey, AcError> {
+        crypto::encrypt_private_key(private_key, self.key.expose_secret())
+    }
+
+    fn decrypt_private_key(&self, encrypted: &EncryptedKey) -> Result<Vec<u8>, AcError> {
+        crypto::decrypt_private_key(encrypted, self.key.expose_secret())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use axum::{Json, Router};
+    use common::secret::SecretString;
+
+    async fn decrypt(
+        headers: HeaderMap,
+        Json(body): Json<serde_json::Value>,
+    ) -> Result<Json<serde_json::Value>, StatusCode> {
+        if headers["x-vault-token"] != "s.test" {
+            return Err(StatusCode::FORBIDDEN);
+        }
+        assert_eq!(body["ciphertext"], "vault:v1:wrapped");
+        Ok(Json(serde_json::json!({
+            "data": { "plaintext": general_purpose::STANDARD.encode([5u8; 32]) }
+        })))
+    }
+
+    async fn spawn_vault() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new().route("/v1/transit/decrypt/ac-master", post(decrypt));
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{}/", addr)
+    }
+
+    fn config(addr: String, token: &str) -> VaultTransitConfig {
+        VaultTransitConfig {
+            addr,
+            token: SecretString::from(token),
+            mount: "transit".to_string(),
+            key_name: "ac-master".to_string(),
+            ciphertext: "vault:v1:wrapped".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_key_calls_decrypt() {
+        let addr = spawn_vault().await;
+
+        let provider = VaultTransitMasterKey::unwrap_key(&config(addr, "s.test"))
+            .await
+            .unwrap();
+
+        let encrypted = provider.encrypt_private_key(b"pkcs8").unwrap();
+        assert_eq!(
+            vec![5u8; 32].decrypt_private_key(&encrypted).unwrap(),
+            b"pkcs8"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_key_rejected_token() {
+        let addr = spawn_vault().await;
+
+        let result = VaultTransitMasterKey::unwrap_key(&config(addr, "s.wrong")).await;
+        assert!(matches!(result, Err(AcError::Crypto(msg)) if msg.contains("403")));
+    }
+}
@@ -12,10 +12,14 @@ use ring::{
     rand::{SecureRandom, SystemRandom},
     signature::{Ed25519KeyPair, KeyPair},
 };
+use serde::Serialize;
 use std::fmt;
 use std::time::Instant;
 use tracing::instrument;
 
+pub mod master_key;
+pub mod totp;
+
 /// Re-export ServiceClaims as Claims for backwards compatibility within AC.
 ///
 /// The original AC Claims struct has been consolidated into `common::jwt::ServiceClaims`
@@ -28,6 +32,9 @@ pub type Claims = ServiceClaims;
 /// other services (GC, MC) can import it for user token validation.
 pub use common::jwt::UserClaims;
 
+/// Claims of delegated tokens issued by token exchange (RFC 8693).
+pub use common::jwt::DelegatedClaims;
+
 /// Encrypted key structure (AES-256-GCM)
 ///
 /// The `encrypted_data` field is wrapped in `SecretBox` to prevent accidental
@@ -75,26 +82,42 @@ pub fn generate_signing_key() -> Result<(String, Vec<u8>), AcError> {
     let pkcs8_bytes = Ed25519KeyPair::generate_pkcs8(&rng)
         .map_err(|e| AcError::Crypto(format!("Keypair generation failed: {}", e)))?;
 
-    let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8_bytes.as_ref())
+    let public_key_pem = public_key_pem(pkcs8_bytes.as_ref())?;
+
+    Ok((public_key_pem, pkcs8_bytes.as_ref().to_vec()))
+}
+
+/// PEM public key (as stored in `signing_keys`) for an Ed25519 PKCS8 key
+pub fn public_key_pem(private_key_pkcs8: &[u8]) -> Result<String, AcError> {
+    let key_pair = Ed25519KeyPair::from_pkcs8(private_key_pkcs8)
         .map_err(|e| AcError::Crypto(format!("Keypair parsing failed: {}", e)))?;
 
     // Get public key bytes
     let public_key_bytes = key_pair.public_key().as_ref();
 
     // Convert public key to PEM format (base64 encoded)
-    let public_key_pem = format!(
+    Ok(format!(
         "-----BEGIN PUBLIC KEY-----\n{}\n-----END PUBLIC KEY-----",
         general_purpose::STANDARD.encode(public_key_bytes)
-    );
-
-    Ok((public_key_pem, pkcs8_bytes.as_ref().to_vec()))
+    ))
 }
 
 /// Encrypt private key with AES-256-GCM
 ///
 /// Uses a 96-bit random nonce and produces a 128-bit authentication tag
-#[instrument(skip_all)]
 pub fn encrypt_private_key(private_key: &[u8], master_key: &[u8]) -> Result<EncryptedKey, AcError> {
+    encrypt_private_key_with_aad(private_key, master_key, &[])
+}
+
+/// Encrypt private key with AES-256-GCM, authenticating `aad` with it
+///
+/// Decryption fails unless it is given the same `aad`.
+#[instrument(skip_all)]
+pub fn encrypt_private_key_with_aad(
+    private_key: &[u8],
+    master_key: &[u8],
+    aad: &[u8],
+) -> Result<EncryptedKey, AcError> {
     if master_key.len() != 32 {
         return Err(AcError::Crypto(format!(
             "Invalid master key length: {} (expected 32)",
@@ -119,7 +142,7 @@ pub fn encrypt_private_key(private_key: &[u8], master_key: &[u8]) -> Result<Encr
     // Encrypt the private key (in-place operation requires mutable buffer)
     let mut in_out = private_key.to_vec();
     sealing_key
-        .seal_in_place_append_tag(nonce, Aad::empty(), &mut in_out)
+        .seal_in_place_append_tag(nonce, Aad::from(aad), &mut in_out)
         .map_err(|e| AcError::Crypto(format!("Encryption operation failed: {}", e)))?;
 
     // Split ciphertext and tag (last 16 bytes are the tag)
@@ -145,10 +168,20 @@ pub fn encrypt_private_key(private_key: &[u8], master_key: &[u8]) -> Result<Encr
 }
 
 /// Decrypt private key with AES-256-GCM
-#[instrument(skip_all)]
 pub fn decrypt_private_key(
     encrypted: &EncryptedKey,
     master_key: &[u8],
+) -> Result<Vec<u8>, AcError> {
+    decrypt_private_key_with_aad(encrypted, master_key, &[])
+}
+
+/// Decrypt private key with AES-256-GCM, checking the `aad` it was
+/// encrypted with
+#[instrument(skip_all)]
+pub fn decrypt_private_key_with_aad(
+    encrypted: &EncryptedKey,
+    master_key: &[u8],
+    aad: &[u8],
 ) -> Result<Vec<u8>, AcError> {
     if master_key.len() != 32 {
         return Err(AcError::Crypto(format!(
@@ -189,7 +222,7 @@ pub fn decrypt_private_key(
 
     // Decrypt in place
     let decrypted = opening_key
-        .open_in_place(nonce, Aad::empty(), &mut in_out)
+        .open_in_place(nonce, Aad::from(aad), &mut in_out)
         .map_err(|e| AcError::Crypto(format!("Decryption operation failed: {}", e)))?;
 
     Ok(decrypted.to_vec())
@@ -201,6 +234,15 @@ pub fn sign_jwt(
     claims: &Claims,
     private_key_pkcs8: &[u8],
     key_id: &str,
+) -> Result<String, AcError> {
+    sign_claims(claims, private_key_pkcs8, key_id)
+}
+
+/// Sign any claims set with the EdDSA private key `key_id`.
+fn sign_claims<T: Serialize>(
+    claims: &T,
+    private_key_pkcs8: &[u8],
+    key_id: &str,
 ) -> Result<String, AcError> {
     // Validate the private key format
     let _key_pair = Ed25519KeyPair::from_pkcs8(private_key_pkcs8)
@@ -308,7 +350,7 @@ pub fn verify_jwt(
 /// Returns `AcError::Crypto` if:
 /// - Cost is outside valid range (10-14) - defense-in-depth validation
 /// - Bcrypt hashing fails
-#[instrument(skip_all)]
+#[instrument(name = "ac.crypto.bcrypt_hash", skip_all)]
 pub fn hash_client_secret(secret: &str, cost: u32) -> Result<String, AcError> {
     // Defense-in-depth: Validate cost even though config should have already validated.
     // This prevents insecure hashing if this function is called directly with invalid cost.
@@ -327,7 +369,7 @@ pub fn hash_client_secret(secret: &str, cost: u32) -> Result<String, AcError> {
 }
 
 /// Verify client secret against bcrypt hash
-#[instrument(skip_all)]
+#[instrument(name = "ac.crypto.bcrypt_verify", skip_all)]
 pub fn verify_client_secret(secret: &str, hash: &str) -> Result<bool, AcError> {
     let start = Instant::now();
     let result = bcrypt::verify(secret, hash)
@@ -373,22 +415,7 @@ pub fn sign_user_jwt(
     private_key_pkcs8: &[u8],
     key_id: &str,
 ) -> Result<String, AcError> {
-    use ring::signature::Ed25519KeyPair;
-
-    // Validate the private key format
-    let _key_pair = Ed25519KeyPair::from_pkcs8(private_key_pkcs8)
-        .map_err(|e| AcError::Crypto(format!("Invalid private key format: {}", e)))?;
-
-    let encoding_key = EncodingKey::from_ed_der(private_key_pkcs8);
-
-    let mut header = Header::new(Algorithm::EdDSA);
-    header.typ = Some("JWT".to_string());
-    header.kid = Some(key_id.to_string());
-
-    let token = encode(&header, claims, &encoding_key)
-        .map_err(|e| AcError::Crypto(format!("JWT signing operation failed: {}", e)))?;
-
-    Ok(token)
+    sign_claims(claims, private_key_pkcs8, key_id)
 }
 
 /// Verify a user JWT and extract claims.
@@ -398,7 +425,6 @@ pub fn sign_user_jwt(
 /// - Signature (EdDSA/Ed25519)
 /// - Expiration (`exp` claim)
 /// - Issued-at time (`iat` claim) with clock skew tolerance
-#[allow(dead_code)] // Library function - will be used by GC and MC for token validation
 #[instrument(skip_all)]
 pub fn verify_user_jwt(
     token: &str,
@@ -445,6 +471,57 @@ pub fn verify_user_jwt(
     Ok(token_data.claims)
 }
 
+/// Sign a delegated token (RFC 8693 token exchange).
+#[instrument(skip_all)]
+pub fn sign_delegated_jwt(
+    claims: &DelegatedClaims,
+    private_key_pkcs8: &[u8],
+    key_id: &str,
+) -> Result<String, AcError> {
+    sign_claims(claims, private_key_pkcs8, key_id)
+}
+
+/// Verify a delegated JWT and extract claims.
+///
+/// Same checks as [`verify_user_jwt`]. Service and user tokens fail to
+/// parse as delegated claims (no `act`), so they are rejected.
+#[instrument(skip_all)]
+pub fn verify_delegated_jwt(
+    token: &str,
+    public_key_pem: &str,
+    clock_skew: Duration,
+) -> Result<DelegatedClaims, AcError> {
+    if token.len() > MAX_JWT_SIZE_BYTES {
+        return Err(AcError::InvalidToken(
+            "The access token is invalid or expired".to_string(),
+        ));
+    }
+
+    let public_key_bytes = decode_ed25519_public_key_pem(public_key_pem).map_err(|e| {
+        tracing::debug!(target: "crypto", error = %e, "Invalid public key encoding");
+        AcError::InvalidToken("The access token is invalid or expired".to_string())
+    })?;
+
+    let decoding_key = DecodingKey::from_ed_der(&public_key_bytes);
+
+    let mut validation = Validation::new(Algorithm::EdDSA);
+    validation.validate_exp = true;
+
+    let token_data = decode::<DelegatedClaims>(token, &decoding_key, &validation).map_err(|e| {
+        tracing::debug!(target: "crypto", error = %e, "Delegated token verification failed");
+        AcError::InvalidToken("The access token is invalid or expired".to_string())
+    })?;
+
+    if let Err(_e) = validate_iat(token_data.claims.iat, clock_skew) {
+        record_token_validation("error", Some("clock_skew"));
+        return Err(AcError::InvalidToken(
+            "The access token is invalid or expired".to_string(),
+        ));
+    }
+
+    Ok(token_data.claims)
+}
+
 #[cfg(test)]
 #[allow(clippy::panic, clippy::assertions_on_constants)]
 mod tests {
@@ -460,6 +537,13 @@ mod tests {
         assert!(!private_pkcs8.is_empty());
     }
 
+    #[test]
+    fn test_public_key_pem_matches_generated_key() {
+        let (public_pem, private_pkcs8) = generate_signing_key().unwrap();
+        assert_eq!(public_key_pem(&private_pkcs8).unwrap(), public_pem);
+        assert!(public_key_pem(b"not a pkcs8 key").is_err());
+    }
+
     #[test]
     fn test_encryption_decryption() {
         let master_key = vec![0u8; 32]; // Test key
@@ -483,6 +567,7 @@ mod tests {
             iat: chrono::Utc::now().timestamp(),
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let token = sign_jwt(&claims, &private_pkcs8, "test-key-01").unwrap();
@@ -541,6 +626,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decrypt_checks_aad() {
+        let master_key = vec![0u8; 32];
+        let data = b"secret data";
+
+        let encrypted = encrypt_private_key_with_aad(data, &master_key, b"key-01").unwrap();
+        assert_eq!(
+            decrypt_private_key_with_aad(&encrypted, &master_key, b"key-01").unwrap(),
+            data
+        );
+        for aad in [&b"key-02"[..], &[][..]] {
+            let err = decrypt_private_key_with_aad(&encrypted, &master_key, aad)
+                .expect_err("Expected Crypto error");
+            assert!(
+                matches!(err, AcError::Crypto(msg) if msg.starts_with("Decryption operation failed:"))
+            );
+        }
+    }
+
     #[test]
     fn test_decrypt_with_invalid_master_key_length() {
         let data = b"secret data";
@@ -592,6 +696,7 @@ mod tests {
             iat: chrono::Utc::now().timestamp() - 7200, // 2 hours ago
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let token = sign_jwt(&claims, &private_pkcs8, "test-key-01").unwrap();
@@ -611,6 +716,7 @@ mod tests {
             iat: chrono::Utc::now().timestamp(),
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let token = sign_jwt(&claims, &private_pkcs8, "test-key-01").unwrap();
@@ -725,6 +831,7 @@ mod tests {
             iat: chrono::Utc::now().timestamp(),
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let token = sign_jwt(&claims, &private_pkcs8, "test-key-01").unwrap();
@@ -795,6 +902,7 @@ mod tests {
             iat: now + 3600, // Issued 1 hour from now (suspicious!)
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let token = sign_jwt(&claims, &private_pkcs8, "test-key-01").unwrap();
@@ -825,6 +933,7 @@ mod tests {
             iat: now + 120,  // Issued 2 minutes from now (within tolerance)
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let token = sign_jwt(&claims, &private_pkcs8, "test-key-01").unwrap();
@@ -871,6 +980,7 @@ mod tests {
             iat: chrono::Utc::now().timestamp(),
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let key_id = "auth-prod-2025-01";
@@ -915,6 +1025,7 @@ mod tests {
             iat: chrono::Utc::now().timestamp(),
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         // Use invalid PKCS8 data
@@ -940,6 +1051,7 @@ mod tests {
             iat: chrono::Utc::now().timestamp(),
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let token = sign_jwt(&claims, &private_pkcs8, "test-key-01").unwrap();
@@ -965,6 +1077,7 @@ mod tests {
             iat: chrono::Utc::now().timestamp(),
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let token = sign_jwt(&claims, &private_pkcs8, "test-key-01").unwrap();
@@ -994,6 +1107,7 @@ mod tests {
             iat: chrono::Utc::now().timestamp(),
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let mut token = sign_jwt(&claims, &private_pkcs8, "test-key-01").unwrap();
@@ -1043,6 +1157,7 @@ mod tests {
             iat: now + DEFAULT_JWT_CLOCK_SKEW.as_secs() as i64, // Exactly at boundary
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let token = sign_jwt(&claims, &private_pkcs8, "test-key-01").unwrap();
@@ -1071,6 +1186,7 @@ mod tests {
             iat: now + DEFAULT_JWT_CLOCK_SKEW.as_secs() as i64 + 1, // 1 second past boundary
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let token = sign_jwt(&claims, &private_pkcs8, "test-key-01").unwrap();
@@ -1103,6 +1219,7 @@ mod tests {
             iat: now - 1800, // Issued 30 minutes ago
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let token = sign_jwt(&claims, &private_pkcs8, "test-key-01").unwrap();
@@ -1126,6 +1243,7 @@ mod tests {
             iat: 1234567800,
             scope: "read write admin".to_string(),
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         // Serialize to JSON
@@ -1152,6 +1270,7 @@ mod tests {
             iat: 1234567800,
             scope: "user:read user:write".to_string(),
             service_type: None, // User tokens don't have service_type
+            cnf: None,
         };
 
         let json = serde_json::to_string(&claims).unwrap();
@@ -1175,6 +1294,7 @@ mod tests {
             iat: 1234567800,
             scope: "read write".to_string(),
             service_type: Some("media-handler".to_string()),
+            cnf: None,
         };
 
         let debug_str = format!("{:?}", claims);
@@ -1201,6 +1321,7 @@ mod tests {
             iat: 1234567800,
             scope: "read write".to_string(),
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         let cloned = claims.clone();
@@ -1899,4 +2020,91 @@ mod tests {
             );
         }
     }
+
+    fn delegated_claims(iat: i64, exp: i64) -> DelegatedClaims {
+        DelegatedClaims {
+            sub: "user-123".to_string(),
+            org_id: "org-456".to_string(),
+            scope: "delegated:meeting.moderate".to_string(),
+            act: common::jwt::Actor {
+                sub: "gc-client".to_string(),
+                service_type: Some("global-controller".to_string()),
+            },
+            iat,
+            exp,
+            jti: "jti-789".to_string(),
+            cnf: None,
+        }
+    }
+
+    /// Test verify_delegated_jwt round trip and signature check
+    #[test]
+    fn test_verify_delegated_jwt_validates_signature() {
+        let (public_pem, private_pkcs8) = generate_signing_key().unwrap();
+        let (wrong_public_pem, _) = generate_signing_key().unwrap();
+        let now = chrono::Utc::now().timestamp();
+
+        let token = sign_delegated_jwt(
+            &delegated_claims(now, now + 300),
+            &private_pkcs8,
+            "test-key",
+        )
+        .unwrap();
+
+        let claims = verify_delegated_jwt(&token, &public_pem, DEFAULT_JWT_CLOCK_SKEW).unwrap();
+        assert_eq!(claims.act.sub, "gc-client");
+        assert!(matches!(
+            verify_delegated_jwt(&token, &wrong_public_pem, DEFAULT_JWT_CLOCK_SKEW),
+            Err(AcError::InvalidToken(_))
+        ));
+
+        let expired = sign_delegated_jwt(
+            &delegated_claims(now - 600, now - 300),
+            &private_pkcs8,
+            "test-key",
+        )
+        .unwrap();
+        assert!(verify_delegated_jwt(&expired, &public_pem, DEFAULT_JWT_CLOCK_SKEW).is_err());
+    }
+
+    /// Service and user tokens are not delegated tokens
+    #[test]
+    fn test_verify_delegated_jwt_rejects_other_token_kinds() {
+        let (public_pem, private_pkcs8) = generate_signing_key().unwrap();
+        let now = chrono::Utc::now().timestamp();
+
+        let service = sign_jwt(
+            &Claims::new(
+                "gc-client".to_string(),
+                now + 300,
+                now,
+                "service.write.mc".to_string(),
+                Some("global-controller".to_string()),
+            ),
+            &private_pkcs8,
+            "test-key",
+        )
+        .unwrap();
+        let user = sign_user_jwt(
+            &UserClaims {
+                sub: "user-123".to_string(),
+                org_id: "org-456".to_string(),
+                email: "user@example.com".to_string(),
+                roles: vec!["user".to_string()],
+                iat: now,
+                exp: now + 300,
+                jti: "jti-789".to_string(),
+            },
+            &private_pkcs8,
+            "test-key",
+        )
+        .unwrap();
+
+        for token in [service, user] {
+            assert!(matches!(
+                verify_delegated_jwt(&token, &public_pem, DEFAULT_JWT_CLOCK_SKEW),
+                Err(AcError::InvalidToken(_))
+            ));
+        }
+    }
 }
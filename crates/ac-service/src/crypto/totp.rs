@@ -0,0 +1,239 @@
+//! Time-based one-time passwords (RFC 6238) for the optional user second
+//! factor.
+//!
+//! Codes are six digits of HMAC-SHA1 over 30-second steps, the defaults every
+//! authenticator app supports. Secrets are handed to the app as unpadded
+//! base32 in an `otpauth://` URI.
+
+use crate::errors::AcError;
+use ring::hmac;
+
+/// Secret length in bytes (160 bits, as RFC 4226 recommends).
+const SECRET_LENGTH: usize = 20;
+
+/// Seconds per time step.
+pub const STEP_SECONDS: i64 = 30;
+
+/// Digits per code.
+const DIGITS: usize = 6;
+
+/// Steps either side of the current one that are accepted, for clock drift
+/// between the server and the user's device.
+const ALLOWED_DRIFT_STEPS: i64 = 1;
+
+/// Issuer shown in authenticator apps.
+const ISSUER: &str = "Dark Tower";
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Generate a new random secret.
+pub fn generate_secret() -> Result<Vec<u8>, AcError> {
+    super::generate_random_bytes(SECRET_LENGTH)
+}
+
+/// Unpadded RFC 4648 base32, the encoding authenticator apps expect.
+pub fn encode_base32(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity((bytes.len() * 8).div_ceil(5));
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for &byte in bytes {
+        buffer = (buffer << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(base32_char(buffer >> bits));
+        }
+    }
+    if bits > 0 {
+        encoded.push(base32_char(buffer << (5 - bits)));
+    }
+    encoded
+}
+
+/// Decode unpadded RFC 4648 base32, as returned by enrollment.
+///
+/// Used by tests and tooling; returns `None` on characters outside the
+/// alphabet. Case-insensitive.
+pub fn decode_base32(encoded: &str) -> Option<Vec<u8>> {
+    let mut decoded = Vec::with_capacity(encoded.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for c in encoded.bytes() {
+        let index = BASE32_ALPHABET
+            .iter()
+            .position(|&a| a == c.to_ascii_uppercase())?;
+        buffer = (buffer << 5) | u32::try_from(index).ok()?;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push(((buffer >> bits) & 0xff) as u8);
+        }
+    }
+    Some(decoded)
+}
+
+fn base32_char(index: u32) -> char {
+    BASE32_ALPHABET
+        .get((index & 0x1f) as usize)
+        .map_or('A', |&c| char::from(c))
+}
+
+/// `otpauth://` URI for enrolling `account` in an authenticator app, usually
+/// shown as a QR code.
+pub fn provisioning_uri(account: &str, secret: &[u8]) -> String {
+    format!(
+        "otpauth://totp/{issuer}:{account}?secret={secret}&issuer={issuer}&algorithm=SHA1&digits={DIGITS}&period={STEP_SECONDS}",
+        issuer = percent_encode(ISSUER),
+        account = percent_encode(account),
+        secret = encode_base32(secret),
+    )
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                char::from(b).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// The code for time step `step` (RFC 4226 HOTP with the step as counter).
+fn code_at(secret: &[u8], step: u64) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, secret);
+    let digest = hmac::sign(&key, &step.to_be_bytes());
+    let digest = digest.as_ref();
+
+    // Dynamic truncation (RFC 4226 section 5.3); the offset is at most 15,
+    // so the four bytes are always within the 20-byte digest
+    let offset = usize::from(digest.last().copied().unwrap_or(0) & 0x0f);
+    let mut word = [0u8; 4];
+    if let Some(bytes) = digest.get(offset..offset + 4) {
+        word.copy_from_slice(bytes);
+    }
+    let value = (u32::from_be_bytes(word) & 0x7fff_ffff) % 10u32.pow(DIGITS as u32);
+
+    format!("{:0width$}", value, width = DIGITS)
+}
+
+/// The code an authenticator app shows at `unix_time`.
+///
+/// Used by tests and tooling that log in as a TOTP-enrolled user.
+pub fn code_at_time(secret: &[u8], unix_time: i64) -> String {
+    let step = u64::try_from(unix_time.div_euclid(STEP_SECONDS)).unwrap_or(0);
+    code_at(secret, step)
+}
+
+/// Compare without short-circuiting on the first differing byte.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Check `code` against the steps around `unix_time`.
+///
+/// Returns the step the code belongs to. Callers record it so the same code
+/// cannot be used twice.
+pub fn verify(secret: &[u8], code: &str, unix_time: i64) -> Option<i64> {
+    let code = code.trim();
+    if code.len() != DIGITS || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let current = unix_time.div_euclid(STEP_SECONDS);
+    let mut matched = None;
+    // Every candidate is computed so timing does not reveal which one matched
+    for step in (current - ALLOWED_DRIFT_STEPS)..=(current + ALLOWED_DRIFT_STEPS) {
+        let Ok(counter) = u64::try_from(step) else {
+            continue;
+        };
+        if constant_time_eq(code_at(secret, counter).as_bytes(), code.as_bytes())
+            && matched.is_none()
+        {
+            matched = Some(step);
+        }
+    }
+    matched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// RFC 6238 appendix B SHA-1 secret.
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+    #[test]
+    fn test_rfc6238_vectors() {
+        // Appendix B lists 8-digit codes; the 6-digit code is the last six
+        for (time, expected) in [
+            (59, "287082"),
+            (1_111_111_109, "081804"),
+            (1_234_567_890, "005924"),
+            (2_000_000_000, "279037"),
+        ] {
+            let step = u64::try_from(time / STEP_SECONDS).unwrap();
+            assert_eq!(code_at(RFC_SECRET, step), expected, "time {time}");
+        }
+    }
+
+    #[test]
+    fn test_verify_accepts_adjacent_steps_only() {
+        let now = 1_111_111_109;
+        let step = now / STEP_SECONDS;
+        let code = |s: i64| code_at(RFC_SECRET, u64::try_from(s).unwrap());
+
+        assert_eq!(verify(RFC_SECRET, &code(step), now), Some(step));
+        assert_eq!(verify(RFC_SECRET, &code(step - 1), now), Some(step - 1));
+        assert_eq!(verify(RFC_SECRET, &code(step + 1), now), Some(step + 1));
+        assert_eq!(verify(RFC_SECRET, &code(step - 2), now), None);
+        assert_eq!(
+            verify(RFC_SECRET, &format!(" {} ", code(step)), now),
+            Some(step)
+        );
+        assert_eq!(
+            verify(RFC_SECRET, &code_at_time(RFC_SECRET, now), now),
+            Some(step)
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_malformed_codes() {
+        for code in ["", "12345", "1234567", "12a456", "081 04"] {
+            assert_eq!(verify(RFC_SECRET, code, 1_111_111_109), None, "{code:?}");
+        }
+    }
+
+    #[test]
+    fn test_encode_base32() {
+        assert_eq!(encode_base32(b""), "");
+        assert_eq!(encode_base32(b"f"), "MY");
+        assert_eq!(encode_base32(b"foobar"), "MZXW6YTBOI");
+        assert_eq!(
+            encode_base32(RFC_SECRET),
+            "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ"
+        );
+    }
+
+    #[test]
+    fn test_provisioning_uri() {
+        let uri = provisioning_uri("ada@example.com", RFC_SECRET);
+        assert_eq!(
+            uri,
+            "otpauth://totp/Dark%20Tower:ada%40example.com\
+             ?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=Dark%20Tower\
+             &algorithm=SHA1&digits=6&period=30"
+        );
+    }
+
+    #[test]
+    fn test_generate_secret() {
+        let a = generate_secret().unwrap();
+        let b = generate_secret().unwrap();
+        assert_eq!(a.len(), SECRET_LENGTH);
+        assert_ne!(a, b);
+    }
+}
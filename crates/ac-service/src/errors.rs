@@ -3,6 +3,8 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
+use common::error::ErrorCode;
+use common::request_id::RequestId;
 use serde::Serialize;
 use thiserror::Error;
 
@@ -17,6 +19,10 @@ pub enum AcError {
     #[error("Invalid credentials")]
     InvalidCredentials,
 
+    /// Password accepted, but the user has TOTP enabled and sent no code.
+    #[error("TOTP code required")]
+    TotpRequired,
+
     #[error("Insufficient scope: required {required}, provided {provided:?}")]
     InsufficientScope {
         required: String,
@@ -26,6 +32,10 @@ pub enum AcError {
     #[error("Invalid token: {0}")]
     InvalidToken(String),
 
+    /// The request is well-formed but a field fails validation.
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+
     #[error("Not found: {0}")]
     NotFound(String),
 
@@ -47,12 +57,30 @@ impl AcError {
     pub fn status_code(&self) -> u16 {
         match self {
             AcError::Database(_) | AcError::Crypto(_) | AcError::Internal => 500,
-            AcError::InvalidCredentials | AcError::InvalidToken(_) => 401,
+            AcError::InvalidRequest(_) => 400,
+            AcError::InvalidCredentials | AcError::TotpRequired | AcError::InvalidToken(_) => 401,
             AcError::InsufficientScope { .. } => 403,
             AcError::NotFound(_) => 404,
             AcError::RateLimitExceeded | AcError::TooManyRequests { .. } => 429,
         }
     }
+
+    /// Returns the stable registry code included in error responses.
+    pub fn dt_code(&self) -> ErrorCode {
+        match self {
+            AcError::Database(_) => ErrorCode::AcDatabase,
+            AcError::Crypto(_) => ErrorCode::AcCrypto,
+            AcError::InvalidCredentials => ErrorCode::AcInvalidCredentials,
+            AcError::TotpRequired => ErrorCode::AcTotpRequired,
+            AcError::InsufficientScope { .. } => ErrorCode::AcInsufficientScope,
+            AcError::InvalidToken(_) => ErrorCode::AcInvalidToken,
+            AcError::InvalidRequest(_) => ErrorCode::AcInvalidRequest,
+            AcError::NotFound(_) => ErrorCode::AcNotFound,
+            AcError::RateLimitExceeded => ErrorCode::AcRateLimited,
+            AcError::TooManyRequests { .. } => ErrorCode::AcTooManyRequests,
+            AcError::Internal => ErrorCode::AcInternal,
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -63,6 +91,8 @@ struct ErrorResponse {
 #[derive(Serialize)]
 struct ErrorDetail {
     code: String,
+    /// Stable registry code (`DT-AC-NNNN`)
+    dt_code: ErrorCode,
     message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     required_scope: Option<String>,
@@ -70,6 +100,9 @@ struct ErrorDetail {
     provided_scopes: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     retry_after_seconds: Option<i64>,
+    /// Correlation ID of the failed request, for support reports
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<String>,
 }
 
 impl IntoResponse for AcError {
@@ -103,6 +136,14 @@ impl IntoResponse for AcError {
                 None,
                 None,
             ),
+            AcError::TotpRequired => (
+                StatusCode::UNAUTHORIZED,
+                "TOTP_REQUIRED",
+                "A TOTP code is required for this account".to_string(),
+                None,
+                None,
+                None,
+            ),
             AcError::InsufficientScope { required, provided } => (
                 StatusCode::FORBIDDEN,
                 "INSUFFICIENT_SCOPE",
@@ -119,6 +160,14 @@ impl IntoResponse for AcError {
                 None,
                 None,
             ),
+            AcError::InvalidRequest(reason) => (
+                StatusCode::BAD_REQUEST,
+                "INVALID_REQUEST",
+                reason.clone(),
+                None,
+                None,
+                None,
+            ),
             AcError::NotFound(resource) => (
                 StatusCode::NOT_FOUND,
                 "NOT_FOUND",
@@ -159,10 +208,12 @@ impl IntoResponse for AcError {
         let error_response = ErrorResponse {
             error: ErrorDetail {
                 code: code.to_string(),
+                dt_code: self.dt_code(),
                 message,
                 required_scope: required_scope.clone(),
                 provided_scopes: provided_scopes.clone(),
                 retry_after_seconds: retry_after,
+                request_id: RequestId::current().map(|id| id.to_string()),
             },
         };
 
@@ -310,6 +361,7 @@ mod tests {
 
         let body_json = read_body_json(response.into_body()).await;
         assert_eq!(body_json["error"]["code"], "INVALID_CREDENTIALS");
+        assert_eq!(body_json["error"]["dt_code"], "DT-AC-1001");
         assert_eq!(body_json["error"]["message"], "Invalid client credentials");
     }
 
@@ -360,6 +412,20 @@ mod tests {
         assert_eq!(body_json["error"]["message"], "token expired");
     }
 
+    #[tokio::test]
+    async fn test_into_response_invalid_request() {
+        let error = AcError::InvalidRequest("client secret too short".to_string());
+        let response = error.into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(response.headers().get("WWW-Authenticate").is_none());
+
+        let body_json = read_body_json(response.into_body()).await;
+        assert_eq!(body_json["error"]["code"], "INVALID_REQUEST");
+        assert_eq!(body_json["error"]["dt_code"], "DT-AC-1011");
+        assert_eq!(body_json["error"]["message"], "client secret too short");
+    }
+
     #[tokio::test]
     async fn test_into_response_rate_limit_exceeded() {
         let error = AcError::RateLimitExceeded;
@@ -394,4 +460,45 @@ mod tests {
         assert_eq!(body_json["error"]["code"], "INTERNAL_ERROR");
         assert_eq!(body_json["error"]["message"], "An internal error occurred");
     }
+
+    #[tokio::test]
+    async fn test_into_response_includes_request_id() {
+        let request_id = RequestId::parse("req-abc").unwrap();
+        let response = request_id
+            .scope(async { AcError::InvalidCredentials.into_response() })
+            .await;
+
+        let body_json = read_body_json(response.into_body()).await;
+        assert_eq!(body_json["error"]["request_id"], "req-abc");
+
+        // Outside a request scope the field is omitted
+        let body_json = read_body_json(AcError::Internal.into_response().into_body()).await;
+        assert!(body_json["error"].get("request_id").is_none());
+    }
+
+    #[test]
+    fn test_dt_codes_are_distinct() {
+        let errors = [
+            AcError::Database("x".to_string()),
+            AcError::Crypto("x".to_string()),
+            AcError::InvalidCredentials,
+            AcError::TotpRequired,
+            AcError::InsufficientScope {
+                required: "admin".to_string(),
+                provided: vec![],
+            },
+            AcError::InvalidToken("x".to_string()),
+            AcError::InvalidRequest("x".to_string()),
+            AcError::NotFound("x".to_string()),
+            AcError::RateLimitExceeded,
+            AcError::TooManyRequests {
+                retry_after_seconds: 1,
+                message: "x".to_string(),
+            },
+            AcError::Internal,
+        ];
+        let codes: std::collections::HashSet<_> = errors.iter().map(AcError::dt_code).collect();
+        assert_eq!(codes.len(), errors.len());
+        assert!(codes.iter().all(|c| c.as_str().starts_with("DT-AC-")));
+    }
 }
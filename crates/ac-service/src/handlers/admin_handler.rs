@@ -1,37 +1,54 @@
-use crate::crypto;
+use crate::crypto::{self, Claims};
 use crate::errors::AcError;
-use crate::models::RegisterServiceResponse;
+use crate::models::{
+    AuthEvent, AuthEventType, RegisterServiceResponse, ServiceCredential, SigningKeyExport,
+};
+use crate::mtls::normalize_fingerprint;
 use crate::observability::metrics::{
     record_credential_operation, record_error, record_key_rotation, set_active_signing_keys,
     set_key_rotation_last_success, set_signing_key_age_days,
 };
 use crate::observability::ErrorCategory;
-use crate::repositories::{service_credentials, signing_keys};
-use crate::services::{key_management_service, registration_service};
+use crate::repositories::{
+    auth_events, oidc, organizations, service_credentials, signing_keys, users,
+};
+use crate::services::{key_management_service, oidc_service, registration_service, user_service};
 use axum::{
-    extract::{Path, Request, State},
+    body::Bytes,
+    extract::{Extension, Path, Query, Request, State},
+    http::{header, HeaderMap},
     Json,
 };
 use chrono::{DateTime, Utc};
-use common::secret::ExposeSecret;
+use common::secret::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::str::FromStr;
 use std::sync::Arc;
 use tracing::instrument;
 use uuid::Uuid;
 
 use super::auth_handler::AppState;
 
+/// Service registration request
+///
+/// `client_secret` is optional; when present it must satisfy the configured
+/// [`SecretPolicy`](crate::services::secret_policy::SecretPolicy) and is
+/// used instead of a generated secret.
 #[derive(Debug, Deserialize)]
 pub struct RegisterServiceRequest {
     pub service_type: String,
     pub region: Option<String>,
+    #[serde(default)]
+    pub client_secret: Option<SecretString>,
 }
 
 /// Handle service registration
 ///
 /// POST /api/v1/admin/services/register
 ///
-/// Generates client_id and client_secret, stores in database
+/// Generates client_id and client_secret (or checks the supplied
+/// client_secret against the secret policy), stores in database
 ///
 /// ADR-0011: Handler instrumented with skip_all to prevent PII leakage.
 /// Only safe fields (service_type, status) are recorded.
@@ -66,13 +83,28 @@ pub async fn handle_register_service(
     }
 
     // Register the service using configured bcrypt cost
-    let result = registration_service::register_service(
-        &state.pool,
-        &payload.service_type,
-        payload.region,
-        state.config.bcrypt_cost,
-    )
-    .await;
+    let result = match payload.client_secret {
+        Some(client_secret) => {
+            registration_service::register_service_with_secret(
+                &state.pool,
+                &payload.service_type,
+                payload.region,
+                client_secret,
+                &state.config.secret_policy,
+                state.config.bcrypt_cost,
+            )
+            .await
+        }
+        None => {
+            registration_service::register_service(
+                &state.pool,
+                &payload.service_type,
+                payload.region,
+                state.config.bcrypt_cost,
+            )
+            .await
+        }
+    };
 
     let status = if result.is_ok() { "success" } else { "error" };
     tracing::Span::current().record("status", status);
@@ -350,7 +382,7 @@ pub async fn handle_rotate_keys(
     // Perform rotation within same transaction
     let new_key_id = key_management_service::rotate_signing_key_tx(
         &mut tx,
-        state.config.master_key.expose_secret(),
+        state.master_key.as_ref(),
         &cluster_name,
     )
     .await?;
@@ -360,6 +392,16 @@ pub async fn handle_rotate_keys(
         .await
         .map_err(|e| AcError::Database(format!("Failed to commit rotation transaction: {}", e)))?;
 
+    // Publish the new key in this instance's JWKS right away; other
+    // instances pick it up at their next periodic refresh
+    if let Err(e) = state.jwks.refresh().await {
+        tracing::warn!(
+            target: "ac.jwks",
+            error = %e,
+            "Failed to refresh JWKS cache after key rotation"
+        );
+    }
+
     // Get the updated old key to retrieve its valid_until (after transaction commit)
     let old_key_updated = signing_keys::get_by_key_id(&state.pool, &old_key.key_id)
         .await?
@@ -398,6 +440,158 @@ pub async fn handle_rotate_keys(
     }))
 }
 
+// ============================================================================
+// Signing Key Replication Endpoints
+// ============================================================================
+
+/// The shared key that protects exported signing keys in transit.
+fn key_replication_key(state: &AppState) -> Result<&[u8], AcError> {
+    state
+        .config
+        .key_replication_key
+        .as_ref()
+        .map(|key| key.expose_secret().as_slice())
+        .ok_or_else(|| AcError::NotFound("Key replication is not configured".to_string()))
+}
+
+/// Export this region's active signing keys for a secondary region.
+///
+/// POST /internal/keys/export
+///
+/// Private keys in the response are encrypted with `AC_KEY_REPLICATION_KEY`,
+/// never with this region's master key. Requires `admin.replicate-keys.ac`.
+#[instrument(name = "ac.admin.export_keys", skip_all, fields(status))]
+pub async fn handle_export_keys(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<SigningKeyExport>, AcError> {
+    let replication_key = key_replication_key(&state)?;
+
+    let keys = key_management_service::export_signing_keys(
+        &state.pool,
+        state.master_key.as_ref(),
+        replication_key,
+    )
+    .await
+    .map_err(|e| {
+        tracing::Span::current().record("status", "error");
+        record_error(
+            "export_keys",
+            ErrorCategory::from(&e).as_str(),
+            e.status_code(),
+        );
+        e
+    })?;
+
+    tracing::info!(
+        target: "audit",
+        event = "signing_keys_exported",
+        client_id = %claims.sub,
+        success = true,
+        key_ids = ?keys.iter().map(|k| k.key_id.as_str()).collect::<Vec<_>>(),
+        "Signing keys exported for replication"
+    );
+    tracing::Span::current().record("status", "success");
+
+    Ok(Json(SigningKeyExport { keys }))
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportKeysResponse {
+    /// Keys added to this region
+    pub imported: Vec<String>,
+    /// Keys this region already had
+    pub skipped: Vec<String>,
+    /// Keys this region now signs with
+    pub active: Vec<String>,
+    /// This region's own keys deactivated by `replace_active`
+    pub deactivated: Vec<String>,
+}
+
+/// Options of `/internal/keys/import`
+#[derive(Debug, Default, Deserialize)]
+pub struct ImportKeysQuery {
+    /// Deactivate this region's keys that are not in the export
+    #[serde(default)]
+    pub replace_active: bool,
+}
+
+/// Import signing keys exported by the primary region.
+///
+/// POST /internal/keys/import
+///
+/// Takes the body of `/internal/keys/export` unchanged. The exported active
+/// keys are activated here. With `?replace_active=true` they also become
+/// this region's only active keys, so its tokens verify against the
+/// primary's JWKS; the deactivated local keys are listed in the response.
+/// Importing the same export twice is a no-op. Requires
+/// `admin.replicate-keys.ac`.
+#[instrument(name = "ac.admin.import_keys", skip_all, fields(status))]
+pub async fn handle_import_keys(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<ImportKeysQuery>,
+    Json(payload): Json<SigningKeyExport>,
+) -> Result<Json<ImportKeysResponse>, AcError> {
+    let replication_key = key_replication_key(&state)?;
+
+    let result = key_management_service::import_signing_keys(
+        &state.pool,
+        state.master_key.as_ref(),
+        replication_key,
+        &payload.keys,
+        query.replace_active,
+    )
+    .await
+    .map_err(|e| {
+        tracing::warn!(
+            target: "audit",
+            event = "signing_keys_import_failed",
+            client_id = %claims.sub,
+            success = false,
+            error = %e,
+            "Signing key import failed"
+        );
+        tracing::Span::current().record("status", "error");
+        record_error(
+            "import_keys",
+            ErrorCategory::from(&e).as_str(),
+            e.status_code(),
+        );
+        e
+    })?;
+
+    // Serve the imported keys from this instance's JWKS right away
+    if let Err(e) = state.jwks.refresh().await {
+        tracing::warn!(
+            target: "ac.jwks",
+            error = %e,
+            "Failed to refresh JWKS cache after key import"
+        );
+    }
+
+    tracing::info!(
+        target: "audit",
+        event = "signing_keys_imported",
+        client_id = %claims.sub,
+        success = true,
+        imported = ?result.imported,
+        skipped = ?result.skipped,
+        active = ?result.active,
+        deactivated = ?result.deactivated,
+        replace_active = query.replace_active,
+        "Signing keys imported from another region"
+    );
+    tracing::Span::current().record("status", "success");
+
+    Ok(Json(ImportKeysResponse {
+        imported: result.imported,
+        skipped: result.skipped,
+        active: result.active,
+        deactivated: result.deactivated,
+    }))
+}
+
 // ============================================================================
 // OAuth Client Management CRUD Endpoints
 // ============================================================================
@@ -424,6 +618,8 @@ pub struct ClientDetailResponse {
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// SPKI fingerprint of the pinned mTLS client certificate, if any
+    pub client_cert_spki_sha256: Option<String>,
 }
 
 /// Create client request
@@ -483,6 +679,10 @@ impl Serialize for CreateClientResponse {
 #[derive(Debug, Deserialize)]
 pub struct UpdateClientRequest {
     pub scopes: Option<Vec<String>>,
+    /// Pin a client certificate by the hex SHA-256 of its SubjectPublicKeyInfo
+    /// (colons allowed); an empty string removes the pin.
+    #[serde(default)]
+    pub client_cert_spki_sha256: Option<String>,
 }
 
 /// Rotate secret response (ONLY time new client_secret is returned)
@@ -611,6 +811,7 @@ pub async fn handle_get_client(
                 is_active: credential.is_active,
                 created_at: credential.created_at,
                 updated_at: credential.updated_at,
+                client_cert_spki_sha256: credential.client_cert_spki_sha256,
             };
 
             tracing::Span::current().record("status", "success");
@@ -836,38 +1037,18 @@ pub async fn handle_update_client(
         }
     };
 
-    // Update scopes if provided
-    let updated_credential = if let Some(new_scopes) = payload.scopes {
-        // Validate scopes format
-        for scope in &new_scopes {
-            // Basic scope validation: non-empty, reasonable length, allowed characters
-            if scope.is_empty() {
+    // Validate the certificate pin before changing anything; "" removes it
+    let cert_pin = match payload.client_cert_spki_sha256.as_deref().map(str::trim) {
+        None => None,
+        Some("") => Some(None),
+        Some(value) => match normalize_fingerprint(value) {
+            Some(fingerprint) => Some(Some(fingerprint)),
+            None => {
                 tracing::Span::current().record("status", "error");
-                let err = AcError::Database("Scope cannot be empty".to_string());
-                record_error(
-                    "update_client",
-                    ErrorCategory::from(&err).as_str(),
-                    err.status_code(),
-                );
-
-                // Audit log failed operation
-                tracing::warn!(
-                    target: "audit",
-                    event = "client_updated",
-                    success = false,
-                    credential_id = %id,
-                    "Invalid scope: empty"
+                let err = AcError::Database(
+                    "client_cert_spki_sha256 must be a hex SHA-256 fingerprint (64 hex characters)"
+                        .to_string(),
                 );
-
-                return Err(err);
-            }
-
-            if scope.len() > 100 {
-                tracing::Span::current().record("status", "error");
-                let err = AcError::Database(format!(
-                    "Scope '{}' exceeds maximum length of 100 characters",
-                    scope
-                ));
                 record_error(
                     "update_client",
                     ErrorCategory::from(&err).as_str(),
@@ -880,19 +1061,20 @@ pub async fn handle_update_client(
                     event = "client_updated",
                     success = false,
                     credential_id = %id,
-                    "Invalid scope: too long"
+                    "Invalid client certificate fingerprint"
                 );
 
                 return Err(err);
             }
+        },
+    };
 
-            // Allow alphanumeric, hyphens, dots, colons (common in OAuth scopes)
-            if !scope
-                .chars()
-                .all(|c| c.is_alphanumeric() || c == '-' || c == '.' || c == ':')
-            {
+    // Update scopes if provided
+    let updated_credential = if let Some(new_scopes) = payload.scopes {
+        // Validate scopes format
+        for scope in &new_scopes {
+            if let Some((err, reason)) = invalid_scope(scope) {
                 tracing::Span::current().record("status", "error");
-                let err = AcError::Database(format!("Scope '{}' contains invalid characters. Only alphanumeric, hyphens, dots, and colons are allowed", scope));
                 record_error(
                     "update_client",
                     ErrorCategory::from(&err).as_str(),
@@ -905,7 +1087,8 @@ pub async fn handle_update_client(
                     event = "client_updated",
                     success = false,
                     credential_id = %id,
-                    "Invalid scope: invalid characters"
+                    "{}",
+                    reason
                 );
 
                 return Err(err);
@@ -918,6 +1101,19 @@ pub async fn handle_update_client(
         credential
     };
 
+    // Pin or unpin the mTLS client certificate if requested
+    let updated_credential = match &cert_pin {
+        Some(fingerprint) => {
+            service_credentials::set_client_cert_fingerprint(
+                &state.pool,
+                id,
+                fingerprint.as_deref(),
+            )
+            .await?
+        }
+        None => updated_credential,
+    };
+
     // Map to response type
     let response = ClientDetailResponse {
         id: updated_credential.credential_id,
@@ -928,6 +1124,7 @@ pub async fn handle_update_client(
         is_active: updated_credential.is_active,
         created_at: updated_credential.created_at,
         updated_at: updated_credential.updated_at,
+        client_cert_spki_sha256: updated_credential.client_cert_spki_sha256,
     };
 
     tracing::Span::current().record("status", "success");
@@ -938,6 +1135,7 @@ pub async fn handle_update_client(
         event = "client_updated",
         success = true,
         credential_id = %id,
+        client_cert_pin_changed = cert_pin.is_some(),
         "Client updated successfully"
     );
 
@@ -1150,129 +1348,1071 @@ pub async fn handle_rotate_client_secret(
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::config::Config;
-    use base64::{engine::general_purpose, Engine};
-    use std::collections::HashMap;
-
-    /// Create a test config with required environment variables
-    fn test_config() -> Config {
-        let master_key = general_purpose::STANDARD.encode([0u8; 32]);
-        let vars = HashMap::from([
-            (
-                "DATABASE_URL".to_string(),
-                "postgresql://localhost/test".to_string(),
-            ),
-            ("AC_MASTER_KEY".to_string(), master_key),
-        ]);
-        Config::from_vars(&vars).expect("Test config should be valid")
+/// Check one OAuth scope: non-empty, at most 100 characters, and only
+/// alphanumerics, hyphens, dots, and colons (common in OAuth scopes).
+///
+/// Returns the error and a reason for the audit log if it is invalid.
+fn invalid_scope(scope: &str) -> Option<(AcError, &'static str)> {
+    if scope.is_empty() {
+        return Some((
+            AcError::Database("Scope cannot be empty".to_string()),
+            "Invalid scope: empty",
+        ));
     }
 
-    #[test]
-    fn test_register_service_request_deserialization() {
-        let json = r#"{"service_type": "global-controller", "region": "us-west-2"}"#;
-        let req: RegisterServiceRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(req.service_type, "global-controller");
-        assert_eq!(req.region, Some("us-west-2".to_string()));
+    if scope.len() > 100 {
+        return Some((
+            AcError::Database(format!(
+                "Scope '{}' exceeds maximum length of 100 characters",
+                scope
+            )),
+            "Invalid scope: too long",
+        ));
     }
 
-    #[test]
-    fn test_register_service_request_without_region() {
-        let json = r#"{"service_type": "meeting-controller"}"#;
-        let req: RegisterServiceRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(req.service_type, "meeting-controller");
-        assert_eq!(req.region, None);
+    if !scope
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '.' || c == ':')
+    {
+        return Some((
+            AcError::Database(format!("Scope '{}' contains invalid characters. Only alphanumeric, hyphens, dots, and colons are allowed", scope)),
+            "Invalid scope: invalid characters",
+        ));
     }
 
-    #[test]
-    fn test_valid_service_types() {
-        let valid_types = ["global-controller", "meeting-controller", "media-handler"];
+    None
+}
 
-        for service_type in valid_types {
-            let json = format!(r#"{{"service_type": "{}"}}"#, service_type);
-            let req: RegisterServiceRequest = serde_json::from_str(&json).unwrap();
-            assert_eq!(req.service_type, service_type);
-        }
-    }
+// ============================================================================
+// Service Credential Management (by client_id)
+// ============================================================================
 
-    #[test]
-    fn test_invalid_service_type_format() {
-        // Note: This tests deserialization, not handler validation
-        let json = r#"{"service_type": "invalid-service"}"#;
-        let req: RegisterServiceRequest = serde_json::from_str(json).unwrap();
-        // Deserialization succeeds (it's just a string)
-        assert_eq!(req.service_type, "invalid-service");
-        // Validation happens in the handler, not during deserialization
+/// Filters for listing service credentials
+#[derive(Debug, Default, Deserialize)]
+pub struct ListServicesQuery {
+    /// Only credentials of this service type
+    pub service_type: Option<String>,
+    /// Only active (`true`) or disabled (`false`) credentials
+    pub is_active: Option<bool>,
+}
+
+/// Update service request; omitted fields are left unchanged
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateServiceRequest {
+    /// `false` disables the credential: no new or refreshed tokens are
+    /// issued. Tokens already issued stay valid until they expire.
+    pub is_active: Option<bool>,
+    /// Replaces the credential's scopes
+    pub scopes: Option<Vec<String>>,
+}
+
+/// Map a credential to its admin response (excludes client_secret_hash)
+fn client_detail(credential: ServiceCredential) -> ClientDetailResponse {
+    ClientDetailResponse {
+        id: credential.credential_id,
+        client_id: credential.client_id,
+        service_type: credential.service_type,
+        region: credential.region,
+        scopes: credential.scopes,
+        is_active: credential.is_active,
+        created_at: credential.created_at,
+        updated_at: credential.updated_at,
+        client_cert_spki_sha256: credential.client_cert_spki_sha256,
     }
+}
 
-    // ============================================================================
-    // Handler Integration Tests - Error Paths
-    // ============================================================================
+/// Look up a credential by client_id, recording a failed `operation` if it
+/// does not exist.
+async fn find_service(
+    state: &AppState,
+    client_id: &str,
+    operation: &'static str,
+    event: &'static str,
+) -> Result<ServiceCredential, AcError> {
+    let result = service_credentials::get_by_client_id(&state.pool, client_id)
+        .await
+        .and_then(|credential| {
+            credential.ok_or_else(|| {
+                AcError::NotFound(format!("Service with client_id {} not found", client_id))
+            })
+        });
 
-    /// Test handle_register_service rejects invalid service_type
-    ///
-    /// Validates that the handler properly validates service_type against
-    /// the allowed list before calling the registration service.
-    #[sqlx::test(migrations = "../../migrations")]
-    async fn test_handle_register_service_invalid_type(pool: sqlx::PgPool) {
-        let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+    if let Err(e) = &result {
+        tracing::Span::current().record("status", "error");
+        record_error(operation, ErrorCategory::from(e).as_str(), e.status_code());
 
-        let payload = RegisterServiceRequest {
-            service_type: "invalid-service-type".to_string(),
-            region: None,
-        };
+        // Audit log failed operation
+        tracing::warn!(
+            target: "audit",
+            event = event,
+            success = false,
+            client_id = %client_id,
+            "Service lookup failed"
+        );
+    }
 
-        let result = handle_register_service(State(state), Json(payload)).await;
+    result
+}
 
-        // Should return error
-        assert!(result.is_err(), "Invalid service_type should be rejected");
+/// List service credentials
+///
+/// GET /api/v1/admin/services?service_type=...&is_active=...
+///
+/// Returns registered service credentials, newest first (excludes
+/// client_secret)
+///
+/// ADR-0011: Handler instrumented with skip_all to prevent PII leakage.
+#[instrument(name = "ac.admin.list_services", skip_all, fields(status))]
+pub async fn handle_list_services(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ListServicesQuery>,
+) -> Result<Json<Vec<ClientDetailResponse>>, AcError> {
+    let credentials = match service_credentials::get_all(&state.pool).await {
+        Ok(credentials) => credentials,
+        Err(e) => {
+            tracing::Span::current().record("status", "error");
+            let category = ErrorCategory::from(&e);
+            record_error("list_services", category.as_str(), e.status_code());
+            record_credential_operation("list", "error");
 
-        let err = result.unwrap_err();
-        match err {
-            AcError::Database(msg) => {
-                assert!(
-                    msg.contains("Invalid service_type"),
-                    "Error should mention invalid service_type, got: {}",
-                    msg
-                );
-                assert!(
-                    msg.contains("invalid-service-type"),
-                    "Error should include the invalid value"
-                );
-            }
-            other => {
-                // Use expect to fail with a clear message if it's not the expected error type
-                let _ = matches!(other, AcError::Database(_));
-                assert!(
-                    matches!(other, AcError::Database(_)),
-                    "Expected Database error, got: {:?}",
-                    other
-                );
-            }
-        }
-    }
+            // Audit log failed operation
+            tracing::warn!(
+                target: "audit",
+                event = "services_listed",
+                success = false,
+                "Failed to list services"
+            );
 
-    /// Test handle_register_service succeeds for valid global-controller
-    ///
-    /// Tests the happy path for service registration with all valid inputs.
-    #[sqlx::test(migrations = "../../migrations")]
-    async fn test_handle_register_service_valid_global_controller(pool: sqlx::PgPool) {
-        let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+            return Err(e);
+        }
+    };
 
-        let payload = RegisterServiceRequest {
-            service_type: "global-controller".to_string(),
-            region: Some("us-west-2".to_string()),
-        };
+    let services: Vec<ClientDetailResponse> = credentials
+        .into_iter()
+        .filter(|c| {
+            query
+                .service_type
+                .as_deref()
+                .is_none_or(|t| c.service_type == t)
+        })
+        .filter(|c| query.is_active.is_none_or(|active| c.is_active == active))
+        .map(client_detail)
+        .collect();
 
-        let result = handle_register_service(State(state), Json(payload)).await;
+    tracing::Span::current().record("status", "success");
 
-        // Should succeed
-        assert!(
-            result.is_ok(),
+    // Audit log successful operation
+    tracing::info!(
+        target: "audit",
+        event = "services_listed",
+        success = true,
+        count = services.len(),
+        "Services listed successfully"
+    );
+
+    record_credential_operation("list", "success");
+    Ok(Json(services))
+}
+
+/// Disable, re-enable, or change the scopes of a service credential
+///
+/// PATCH /api/v1/admin/services/{client_id}
+///
+/// All scopes are validated before anything is changed.
+///
+/// ADR-0011: Handler instrumented with skip_all to prevent PII leakage.
+#[instrument(name = "ac.admin.update_service", skip_all, fields(status))]
+pub async fn handle_update_service(
+    State(state): State<Arc<AppState>>,
+    Path(client_id): Path<String>,
+    Json(payload): Json<UpdateServiceRequest>,
+) -> Result<Json<ClientDetailResponse>, AcError> {
+    let credential =
+        match find_service(&state, &client_id, "update_service", "service_updated").await {
+            Ok(credential) => credential,
+            Err(e) => {
+                record_credential_operation("update", "error");
+                return Err(e);
+            }
+        };
+
+    for scope in payload.scopes.iter().flatten() {
+        if let Some((err, reason)) = invalid_scope(scope) {
+            tracing::Span::current().record("status", "error");
+            record_error(
+                "update_service",
+                ErrorCategory::from(&err).as_str(),
+                err.status_code(),
+            );
+            record_credential_operation("update", "error");
+
+            // Audit log failed operation
+            tracing::warn!(
+                target: "audit",
+                event = "service_updated",
+                success = false,
+                client_id = %client_id,
+                "{}",
+                reason
+            );
+
+            return Err(err);
+        }
+    }
+
+    let result = async {
+        if let Some(scopes) = payload.scopes {
+            if scopes != credential.scopes {
+                registration_service::update_service_scopes(&state.pool, &client_id, scopes)
+                    .await?;
+            }
+        }
+        match payload.is_active {
+            Some(false) if credential.is_active => {
+                registration_service::deactivate_service(&state.pool, &client_id).await?
+            }
+            Some(true) if !credential.is_active => {
+                registration_service::reactivate_service(&state.pool, &client_id).await?
+            }
+            _ => {}
+        }
+        service_credentials::get_by_credential_id(&state.pool, credential.credential_id)
+            .await?
+            .ok_or_else(|| AcError::Database("Failed to retrieve updated credential".to_string()))
+    }
+    .await;
+
+    match result {
+        Ok(updated) => {
+            tracing::Span::current().record("status", "success");
+
+            // Audit log successful operation
+            tracing::info!(
+                target: "audit",
+                event = "service_updated",
+                success = true,
+                client_id = %client_id,
+                is_active = updated.is_active,
+                "Service updated successfully"
+            );
+
+            record_credential_operation("update", "success");
+            Ok(Json(client_detail(updated)))
+        }
+        Err(e) => {
+            tracing::Span::current().record("status", "error");
+            let category = ErrorCategory::from(&e);
+            record_error("update_service", category.as_str(), e.status_code());
+            record_credential_operation("update", "error");
+
+            // Audit log failed operation
+            tracing::warn!(
+                target: "audit",
+                event = "service_updated",
+                success = false,
+                client_id = %client_id,
+                "Failed to update service"
+            );
+
+            Err(e)
+        }
+    }
+}
+
+/// Rotate a service's client secret
+///
+/// POST /api/v1/admin/services/{client_id}/rotate-secret
+///
+/// Same as `POST /api/v1/admin/clients/{id}/rotate-secret`, addressed by
+/// client_id. The old secret stops working immediately.
+///
+/// ADR-0011: Handler instrumented with skip_all to prevent PII leakage.
+#[instrument(name = "ac.admin.rotate_service_secret", skip_all, fields(status))]
+pub async fn handle_rotate_service_secret(
+    State(state): State<Arc<AppState>>,
+    Path(client_id): Path<String>,
+) -> Result<Json<RotateSecretResponse>, AcError> {
+    let credential = find_service(
+        &state,
+        &client_id,
+        "rotate_client_secret",
+        "client_secret_rotated",
+    )
+    .await?;
+
+    handle_rotate_client_secret(State(state), Path(credential.credential_id)).await
+}
+
+/// Maximum users accepted in one import.
+const MAX_USER_IMPORT_RECORDS: usize = 5000;
+
+/// Maximum record errors listed in a rejection message.
+const MAX_REPORTED_RECORD_ERRORS: usize = 20;
+
+/// Roles an imported user can be granted besides "user".
+const IMPORT_ROLES: &[&str] = &["user", "org_admin", "admin"];
+
+/// Maximum email and display name length (users table columns).
+const MAX_USER_FIELD_LENGTH: usize = 255;
+
+/// A user to import, as one JSON array element or one CSV row
+///
+/// `password_hash` is the user's existing bcrypt hash from the source
+/// system, so migrated users keep their passwords. Plaintext passwords are
+/// not accepted.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserImportRecord {
+    pub email: String,
+    pub display_name: String,
+    pub password_hash: String,
+    /// Extra role besides "user" (org_admin or admin)
+    #[serde(default)]
+    pub role: Option<String>,
+}
+
+/// Custom Debug that redacts password_hash
+impl std::fmt::Debug for UserImportRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UserImportRecord")
+            .field("email", &self.email)
+            .field("display_name", &self.display_name)
+            .field("password_hash", &"[REDACTED]")
+            .field("role", &self.role)
+            .finish()
+    }
+}
+
+/// Import users request (JSON body)
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImportUsersRequest {
+    pub users: Vec<UserImportRecord>,
+}
+
+/// A created user
+#[derive(Debug, Serialize)]
+pub struct ImportedUser {
+    pub user_id: Uuid,
+    pub email: String,
+}
+
+/// Import users response
+#[derive(Debug, Serialize)]
+pub struct ImportUsersResponse {
+    pub org_id: Uuid,
+    pub imported_users: usize,
+    pub users: Vec<ImportedUser>,
+}
+
+/// Bulk import users into an organization
+///
+/// POST /api/v1/admin/orgs/{org_id}/users/import
+///
+/// Accepts `text/csv` (header row `email,display_name,password_hash,role`)
+/// or JSON (`{"users": [...]}`). Every record is validated first; users are
+/// then created in one transaction, so either all are imported or none.
+///
+/// ADR-0011: Handler instrumented with skip_all to prevent PII leakage.
+/// Only safe fields (org_id, record count, status) are recorded.
+#[instrument(
+    name = "ac.admin.import_users",
+    skip_all,
+    fields(org_id = %org_id, records, status)
+)]
+pub async fn handle_import_users(
+    State(state): State<Arc<AppState>>,
+    Path(org_id): Path<Uuid>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<ImportUsersResponse>, AcError> {
+    match import_users(&state, org_id, &headers, &body).await {
+        Ok(response) => {
+            tracing::Span::current().record("status", "success");
+
+            // Audit log successful operation
+            tracing::info!(
+                target: "audit",
+                event = "users_imported",
+                success = true,
+                org_id = %org_id,
+                imported_users = response.imported_users,
+                "Users imported successfully"
+            );
+
+            Ok(Json(response))
+        }
+        Err(e) => {
+            tracing::Span::current().record("status", "error");
+            let category = ErrorCategory::from(&e);
+            record_error("import_users", category.as_str(), e.status_code());
+
+            // Audit log failed operation
+            tracing::warn!(
+                target: "audit",
+                event = "users_imported",
+                success = false,
+                org_id = %org_id,
+                "User import failed"
+            );
+
+            Err(e)
+        }
+    }
+}
+
+async fn import_users(
+    state: &AppState,
+    org_id: Uuid,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<ImportUsersResponse, AcError> {
+    require_active_org(state, org_id).await?;
+
+    // Validation errors use InvalidToken, matching user registration.
+    let records = parse_user_import(headers, body).map_err(AcError::InvalidToken)?;
+    tracing::Span::current().record("records", records.len());
+    validate_user_import(&records).map_err(AcError::InvalidToken)?;
+
+    let new_users: Vec<users::NewUser> = records
+        .into_iter()
+        .map(|record| users::NewUser {
+            email: record.email,
+            display_name: record.display_name.trim().to_string(),
+            password_hash: record.password_hash,
+            extra_role: record.role.filter(|role| role != "user"),
+        })
+        .collect();
+
+    match users::create_users_bulk(&state.pool, org_id, &new_users).await? {
+        users::BulkCreateOutcome::Created(created) => Ok(ImportUsersResponse {
+            org_id,
+            imported_users: created.len(),
+            users: created
+                .into_iter()
+                .map(|(user_id, email)| ImportedUser { user_id, email })
+                .collect(),
+        }),
+        users::BulkCreateOutcome::EmailsTaken(taken) => {
+            let total = taken.len();
+            let listed: Vec<String> = taken.into_iter().take(MAX_REPORTED_RECORD_ERRORS).collect();
+            let mut message = format!(
+                "Accounts already exist in organization: {}",
+                listed.join(", ")
+            );
+            if total > MAX_REPORTED_RECORD_ERRORS {
+                message.push_str(&format!(
+                    " (and {} more)",
+                    total - MAX_REPORTED_RECORD_ERRORS
+                ));
+            }
+            Err(AcError::InvalidToken(message))
+        }
+    }
+}
+
+/// Parse the body as CSV or JSON depending on `Content-Type`.
+fn parse_user_import(headers: &HeaderMap, body: &[u8]) -> Result<Vec<UserImportRecord>, String> {
+    let is_csv = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.trim_start().starts_with("text/csv"));
+
+    if !is_csv {
+        return serde_json::from_slice::<ImportUsersRequest>(body)
+            .map(|request| request.users)
+            .map_err(|_| "Invalid request body".to_string());
+    }
+
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(body);
+
+    let mut records = Vec::new();
+    for (index, row) in reader.deserialize::<UserImportRecord>().enumerate() {
+        if records.len() >= MAX_USER_IMPORT_RECORDS {
+            return Err(too_many_users());
+        }
+        let record = row.map_err(|_| format!("Record {}: invalid CSV row", index + 1))?;
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+fn too_many_users() -> String {
+    format!(
+        "Import must contain at most {} users",
+        MAX_USER_IMPORT_RECORDS
+    )
+}
+
+/// Validate every record; emails must be unique within the import.
+fn validate_user_import(records: &[UserImportRecord]) -> Result<(), String> {
+    if records.is_empty() {
+        return Err("Import must contain at least one user".to_string());
+    }
+    if records.len() > MAX_USER_IMPORT_RECORDS {
+        return Err(too_many_users());
+    }
+
+    let mut emails = HashSet::new();
+    let mut errors = Vec::new();
+    for (index, record) in records.iter().enumerate() {
+        if let Err(e) = validate_user_record(record, &mut emails) {
+            errors.push(format!("Record {}: {}", index + 1, e));
+        }
+    }
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let total = errors.len();
+    errors.truncate(MAX_REPORTED_RECORD_ERRORS);
+    let mut message = errors.join("; ");
+    if total > MAX_REPORTED_RECORD_ERRORS {
+        message.push_str(&format!(
+            " (and {} more)",
+            total - MAX_REPORTED_RECORD_ERRORS
+        ));
+    }
+    Err(message)
+}
+
+fn validate_user_record<'a>(
+    record: &'a UserImportRecord,
+    emails: &mut HashSet<&'a str>,
+) -> Result<(), &'static str> {
+    if record.email.len() > MAX_USER_FIELD_LENGTH || !user_service::is_valid_email(&record.email) {
+        return Err("Invalid email format");
+    }
+
+    let display_name = record.display_name.trim();
+    if display_name.is_empty() || display_name.len() > MAX_USER_FIELD_LENGTH {
+        return Err("Display name must be 1-255 characters");
+    }
+
+    if !is_bcrypt_hash(&record.password_hash) {
+        return Err("Password hash must be a bcrypt hash");
+    }
+
+    if let Some(role) = &record.role {
+        if !IMPORT_ROLES.contains(&role.as_str()) {
+            return Err("Role must be one of: user, org_admin, admin");
+        }
+    }
+
+    if !emails.insert(record.email.as_str()) {
+        return Err("Duplicate email");
+    }
+
+    Ok(())
+}
+
+/// Check for a modular-crypt bcrypt hash (`$2a$`, `$2b$`, or `$2y$`, 60 chars).
+fn is_bcrypt_hash(hash: &str) -> bool {
+    hash.len() == 60 && ["$2a$", "$2b$", "$2y$"].iter().any(|p| hash.starts_with(p))
+}
+
+// ============================================================================
+// OIDC Federation (org SSO provider)
+// ============================================================================
+
+/// Set an org's OIDC provider request
+#[derive(Debug, Deserialize)]
+pub struct OidcProviderRequest {
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: SecretString,
+    pub redirect_uri: String,
+    #[serde(default = "default_oidc_enabled")]
+    pub enabled: bool,
+}
+
+fn default_oidc_enabled() -> bool {
+    true
+}
+
+/// An org's OIDC provider. The client secret is never returned.
+#[derive(Debug, Serialize)]
+pub struct OidcProviderResponse {
+    pub org_id: Uuid,
+    pub issuer_url: String,
+    pub client_id: String,
+    pub redirect_uri: String,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<oidc::OidcProvider> for OidcProviderResponse {
+    fn from(provider: oidc::OidcProvider) -> Self {
+        Self {
+            org_id: provider.org_id,
+            issuer_url: provider.issuer_url,
+            client_id: provider.client_id,
+            redirect_uri: provider.redirect_uri,
+            enabled: provider.is_enabled,
+            created_at: provider.created_at,
+            updated_at: provider.updated_at,
+        }
+    }
+}
+
+/// Get an org's OIDC provider
+///
+/// GET /api/v1/admin/orgs/{org_id}/oidc
+#[instrument(
+    name = "ac.admin.get_oidc_provider",
+    skip_all,
+    fields(org_id = %org_id, status)
+)]
+pub async fn handle_get_oidc_provider(
+    State(state): State<Arc<AppState>>,
+    Path(org_id): Path<Uuid>,
+) -> Result<Json<OidcProviderResponse>, AcError> {
+    let result = async {
+        require_active_org(&state, org_id).await?;
+        oidc::get_provider(&state.pool, org_id)
+            .await?
+            .map(OidcProviderResponse::from)
+            .ok_or_else(|| AcError::NotFound("Organization has no OIDC provider".to_string()))
+    }
+    .await;
+
+    match result {
+        Ok(response) => {
+            tracing::Span::current().record("status", "success");
+            Ok(Json(response))
+        }
+        Err(e) => {
+            tracing::Span::current().record("status", "error");
+            let category = ErrorCategory::from(&e);
+            record_error("get_oidc_provider", category.as_str(), e.status_code());
+            Err(e)
+        }
+    }
+}
+
+/// Set an org's OIDC provider, replacing any existing one
+///
+/// PUT /api/v1/admin/orgs/{org_id}/oidc
+///
+/// An enabled provider must serve a discovery document for `issuer_url`.
+/// `redirect_uri` must be the org's `/api/v1/auth/oidc/callback` URL as
+/// registered with the provider.
+///
+/// ADR-0011: Handler instrumented with skip_all so the client secret is
+/// never recorded.
+#[instrument(
+    name = "ac.admin.set_oidc_provider",
+    skip_all,
+    fields(org_id = %org_id, status)
+)]
+pub async fn handle_set_oidc_provider(
+    State(state): State<Arc<AppState>>,
+    Path(org_id): Path<Uuid>,
+    Json(payload): Json<OidcProviderRequest>,
+) -> Result<Json<OidcProviderResponse>, AcError> {
+    let result = async {
+        require_active_org(&state, org_id).await?;
+        let config = oidc_service::OidcProviderConfig {
+            issuer_url: payload.issuer_url,
+            client_id: payload.client_id,
+            client_secret: payload.client_secret,
+            redirect_uri: payload.redirect_uri,
+            is_enabled: payload.enabled,
+        };
+        oidc_service::configure_provider(&state.pool, state.master_key.as_ref(), org_id, &config)
+            .await
+    }
+    .await;
+
+    match result {
+        Ok(provider) => {
+            tracing::Span::current().record("status", "success");
+
+            // Audit log successful operation
+            tracing::info!(
+                target: "audit",
+                event = "oidc_provider_set",
+                success = true,
+                org_id = %org_id,
+                enabled = provider.is_enabled,
+                "OIDC provider set successfully"
+            );
+
+            Ok(Json(OidcProviderResponse::from(provider)))
+        }
+        Err(e) => {
+            tracing::Span::current().record("status", "error");
+            let category = ErrorCategory::from(&e);
+            record_error("set_oidc_provider", category.as_str(), e.status_code());
+
+            // Audit log failed operation
+            tracing::warn!(
+                target: "audit",
+                event = "oidc_provider_set",
+                success = false,
+                org_id = %org_id,
+                "Failed to set OIDC provider"
+            );
+
+            Err(e)
+        }
+    }
+}
+
+/// Remove an org's OIDC provider
+///
+/// DELETE /api/v1/admin/orgs/{org_id}/oidc
+///
+/// Users created through SSO keep their accounts but cannot sign in until a
+/// provider is set again.
+#[instrument(
+    name = "ac.admin.delete_oidc_provider",
+    skip_all,
+    fields(org_id = %org_id, status)
+)]
+pub async fn handle_delete_oidc_provider(
+    State(state): State<Arc<AppState>>,
+    Path(org_id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>, AcError> {
+    let result = match oidc::delete_provider(&state.pool, org_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(AcError::NotFound(
+            "Organization has no OIDC provider".to_string(),
+        )),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(()) => {
+            tracing::Span::current().record("status", "success");
+
+            // Audit log successful operation
+            tracing::info!(
+                target: "audit",
+                event = "oidc_provider_deleted",
+                success = true,
+                org_id = %org_id,
+                "OIDC provider deleted successfully"
+            );
+
+            Ok(Json(serde_json::json!({ "deleted": true })))
+        }
+        Err(e) => {
+            tracing::Span::current().record("status", "error");
+            let category = ErrorCategory::from(&e);
+            record_error("delete_oidc_provider", category.as_str(), e.status_code());
+            Err(e)
+        }
+    }
+}
+
+/// Return `NotFound` unless `org_id` is an active organization.
+async fn require_active_org(state: &AppState, org_id: Uuid) -> Result<(), AcError> {
+    match organizations::get_by_id(&state.pool, org_id).await? {
+        Some(org) if org.is_active => Ok(()),
+        _ => Err(AcError::NotFound(format!(
+            "Organization with ID {} not found",
+            org_id
+        ))),
+    }
+}
+
+// ============================================================================
+// Audit Events
+// ============================================================================
+
+/// Events returned when `limit` is not given.
+const DEFAULT_AUDIT_EVENTS_LIMIT: i64 = 100;
+
+/// Maximum events returned in one response.
+const MAX_AUDIT_EVENTS_LIMIT: i64 = 1000;
+
+/// Window queried when `from` is not given, ending at `to`.
+const DEFAULT_AUDIT_EVENTS_WINDOW_HOURS: i64 = 24;
+
+/// Audit events query string
+///
+/// Times are RFC 3339. Fields are parsed by the handler so that bad values
+/// get the usual error body rather than a query-string rejection.
+#[derive(Debug, Default, Deserialize)]
+pub struct AuditEventsQuery {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub event_type: Option<String>,
+    pub limit: Option<String>,
+}
+
+/// Validated audit events query
+#[derive(Debug, PartialEq)]
+struct AuditEventsFilter {
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    event_type: Option<AuthEventType>,
+    limit: i64,
+}
+
+/// Audit events response
+#[derive(Debug, Serialize)]
+pub struct AuditEventsResponse {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub events: Vec<AuthEvent>,
+}
+
+/// List audit events
+///
+/// GET /api/v1/admin/audit-events?from=&to=&event_type=&limit=
+///
+/// Returns auth events with `from <= created_at < to`, newest first. `to`
+/// defaults to now and `from` to 24 hours before `to`. Events from the
+/// batched audit writer can take up to a second to appear.
+///
+/// ADR-0011: Handler instrumented with skip_all to prevent PII leakage.
+#[instrument(name = "ac.admin.list_audit_events", skip_all, fields(status))]
+pub async fn handle_list_audit_events(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<AuditEventsQuery>,
+) -> Result<Json<AuditEventsResponse>, AcError> {
+    let result = match parse_audit_events_query(&query, Utc::now()) {
+        // Validation errors use InvalidToken, matching user registration.
+        Err(message) => Err(AcError::InvalidToken(message)),
+        Ok(filter) => auth_events::list_events(
+            &state.pool,
+            filter.event_type.map(|t| t.as_str()),
+            filter.from,
+            filter.to,
+            filter.limit,
+        )
+        .await
+        .map(|events| AuditEventsResponse {
+            from: filter.from,
+            to: filter.to,
+            events,
+        }),
+    };
+
+    match result {
+        Ok(response) => {
+            tracing::Span::current().record("status", "success");
+
+            // Audit log successful operation
+            tracing::info!(
+                target: "audit",
+                event = "audit_events_listed",
+                success = true,
+                count = response.events.len(),
+                "Audit events listed successfully"
+            );
+
+            Ok(Json(response))
+        }
+        Err(e) => {
+            tracing::Span::current().record("status", "error");
+            let category = ErrorCategory::from(&e);
+            record_error("list_audit_events", category.as_str(), e.status_code());
+
+            // Audit log failed operation
+            tracing::warn!(
+                target: "audit",
+                event = "audit_events_listed",
+                success = false,
+                "Failed to list audit events"
+            );
+
+            Err(e)
+        }
+    }
+}
+
+/// Validate the query string and apply defaults.
+fn parse_audit_events_query(
+    query: &AuditEventsQuery,
+    now: DateTime<Utc>,
+) -> Result<AuditEventsFilter, String> {
+    let parse_time = |name: &str, value: &str| {
+        DateTime::parse_from_rfc3339(value)
+            .map(|t| t.with_timezone(&Utc))
+            .map_err(|_| format!("{} must be an RFC 3339 timestamp", name))
+    };
+
+    let to = match &query.to {
+        Some(to) => parse_time("to", to)?,
+        None => now,
+    };
+    let from = match &query.from {
+        Some(from) => parse_time("from", from)?,
+        None => to - chrono::Duration::hours(DEFAULT_AUDIT_EVENTS_WINDOW_HOURS),
+    };
+    if from >= to {
+        return Err("from must be earlier than to".to_string());
+    }
+
+    let event_type = query
+        .event_type
+        .as_deref()
+        .map(AuthEventType::from_str)
+        .transpose()?;
+
+    let limit = match &query.limit {
+        Some(limit) => limit
+            .parse::<i64>()
+            .ok()
+            .filter(|limit| (1..=MAX_AUDIT_EVENTS_LIMIT).contains(limit))
+            .ok_or_else(|| format!("limit must be between 1 and {}", MAX_AUDIT_EVENTS_LIMIT))?,
+        None => DEFAULT_AUDIT_EVENTS_LIMIT,
+    };
+
+    Ok(AuditEventsFilter {
+        from,
+        to,
+        event_type,
+        limit,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use base64::{engine::general_purpose, Engine};
+    use std::collections::HashMap;
+
+    /// Create a test config with required environment variables
+    fn test_config() -> Config {
+        let master_key = general_purpose::STANDARD.encode([0u8; 32]);
+        let vars = HashMap::from([
+            (
+                "DATABASE_URL".to_string(),
+                "postgresql://localhost/test".to_string(),
+            ),
+            ("AC_MASTER_KEY".to_string(), master_key),
+        ]);
+        Config::from_vars(&vars).expect("Test config should be valid")
+    }
+
+    #[test]
+    fn test_register_service_request_deserialization() {
+        let json = r#"{"service_type": "global-controller", "region": "us-west-2"}"#;
+        let req: RegisterServiceRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.service_type, "global-controller");
+        assert_eq!(req.region, Some("us-west-2".to_string()));
+    }
+
+    #[test]
+    fn test_register_service_request_without_region() {
+        let json = r#"{"service_type": "meeting-controller"}"#;
+        let req: RegisterServiceRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.service_type, "meeting-controller");
+        assert_eq!(req.region, None);
+    }
+
+    #[test]
+    fn test_register_service_request_with_client_secret() {
+        let json = r#"{"service_type": "media-handler", "client_secret": "kQ7vZ2pX9mW4rT8y"}"#;
+        let req: RegisterServiceRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            req.client_secret.as_ref().map(|s| s.expose_secret()),
+            Some("kQ7vZ2pX9mW4rT8y")
+        );
+        // The secret must never reach logs
+        assert!(!format!("{:?}", req).contains("kQ7vZ2pX9mW4rT8y"));
+    }
+
+    #[test]
+    fn test_valid_service_types() {
+        let valid_types = ["global-controller", "meeting-controller", "media-handler"];
+
+        for service_type in valid_types {
+            let json = format!(r#"{{"service_type": "{}"}}"#, service_type);
+            let req: RegisterServiceRequest = serde_json::from_str(&json).unwrap();
+            assert_eq!(req.service_type, service_type);
+        }
+    }
+
+    #[test]
+    fn test_invalid_service_type_format() {
+        // Note: This tests deserialization, not handler validation
+        let json = r#"{"service_type": "invalid-service"}"#;
+        let req: RegisterServiceRequest = serde_json::from_str(json).unwrap();
+        // Deserialization succeeds (it's just a string)
+        assert_eq!(req.service_type, "invalid-service");
+        // Validation happens in the handler, not during deserialization
+    }
+
+    // ============================================================================
+    // Handler Integration Tests - Error Paths
+    // ============================================================================
+
+    /// Test handle_register_service rejects invalid service_type
+    ///
+    /// Validates that the handler properly validates service_type against
+    /// the allowed list before calling the registration service.
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_handle_register_service_invalid_type(pool: sqlx::PgPool) {
+        let config = test_config();
+        let state = Arc::new(AppState::new(pool, config));
+
+        let payload = RegisterServiceRequest {
+            service_type: "invalid-service-type".to_string(),
+            region: None,
+            client_secret: None,
+        };
+
+        let result = handle_register_service(State(state), Json(payload)).await;
+
+        // Should return error
+        assert!(result.is_err(), "Invalid service_type should be rejected");
+
+        let err = result.unwrap_err();
+        match err {
+            AcError::Database(msg) => {
+                assert!(
+                    msg.contains("Invalid service_type"),
+                    "Error should mention invalid service_type, got: {}",
+                    msg
+                );
+                assert!(
+                    msg.contains("invalid-service-type"),
+                    "Error should include the invalid value"
+                );
+            }
+            other => {
+                // Use expect to fail with a clear message if it's not the expected error type
+                let _ = matches!(other, AcError::Database(_));
+                assert!(
+                    matches!(other, AcError::Database(_)),
+                    "Expected Database error, got: {:?}",
+                    other
+                );
+            }
+        }
+    }
+
+    /// Test handle_register_service succeeds for valid global-controller
+    ///
+    /// Tests the happy path for service registration with all valid inputs.
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_handle_register_service_valid_global_controller(pool: sqlx::PgPool) {
+        let config = test_config();
+        let state = Arc::new(AppState::new(pool, config));
+
+        let payload = RegisterServiceRequest {
+            service_type: "global-controller".to_string(),
+            region: Some("us-west-2".to_string()),
+            client_secret: None,
+        };
+
+        let result = handle_register_service(State(state), Json(payload)).await;
+
+        // Should succeed
+        assert!(
+            result.is_ok(),
             "Valid registration should succeed: {:?}",
             result.err()
         );
@@ -1284,15 +2424,55 @@ mod tests {
         assert!(!response.scopes.is_empty());
     }
 
+    /// Test handle_register_service uses a supplied secret that meets the policy
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_handle_register_service_with_client_secret(pool: sqlx::PgPool) {
+        let state = Arc::new(AppState::new(pool, test_config()));
+
+        let payload = RegisterServiceRequest {
+            service_type: "media-handler".to_string(),
+            region: None,
+            client_secret: Some(SecretString::from("kQ7vZ2pX9mW4rT8yL1nB6hJ3fD5sG0cE")),
+        };
+
+        let response = handle_register_service(State(state), Json(payload))
+            .await
+            .expect("Strong supplied secret should be accepted")
+            .0;
+        assert_eq!(
+            response.client_secret.expose_secret(),
+            "kQ7vZ2pX9mW4rT8yL1nB6hJ3fD5sG0cE"
+        );
+    }
+
+    /// Test handle_register_service rejects a weak supplied secret with 400
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_handle_register_service_rejects_weak_client_secret(pool: sqlx::PgPool) {
+        let state = Arc::new(AppState::new(pool, test_config()));
+
+        let payload = RegisterServiceRequest {
+            service_type: "media-handler".to_string(),
+            region: None,
+            client_secret: Some(SecretString::from("changeme-changeme-changeme-12345")),
+        };
+
+        let err = handle_register_service(State(state), Json(payload))
+            .await
+            .expect_err("Weak supplied secret should be rejected");
+        assert_eq!(err.status_code(), 400);
+        assert!(matches!(err, AcError::InvalidRequest(_)));
+    }
+
     /// Test handle_register_service succeeds for meeting-controller
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_register_service_valid_meeting_controller(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let payload = RegisterServiceRequest {
             service_type: "meeting-controller".to_string(),
             region: None,
+            client_secret: None,
         };
 
         let result = handle_register_service(State(state), Json(payload)).await;
@@ -1308,11 +2488,12 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_register_service_valid_media_handler(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let payload = RegisterServiceRequest {
             service_type: "media-handler".to_string(),
             region: Some("eu-west-1".to_string()),
+            client_secret: None,
         };
 
         let result = handle_register_service(State(state), Json(payload)).await;
@@ -1331,11 +2512,12 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_register_service_case_sensitive(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let payload = RegisterServiceRequest {
             service_type: "Global-Controller".to_string(), // Wrong case
             region: None,
+            client_secret: None,
         };
 
         let result = handle_register_service(State(state), Json(payload)).await;
@@ -1348,11 +2530,12 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_register_service_empty_service_type(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let payload = RegisterServiceRequest {
             service_type: "".to_string(),
             region: None,
+            client_secret: None,
         };
 
         let result = handle_register_service(State(state), Json(payload)).await;
@@ -1364,11 +2547,12 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_register_service_whitespace_service_type(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let payload = RegisterServiceRequest {
             service_type: " global-controller ".to_string(),
             region: None,
+            client_secret: None,
         };
 
         let result = handle_register_service(State(state), Json(payload)).await;
@@ -1388,6 +2572,7 @@ mod tests {
         let req = RegisterServiceRequest {
             service_type: "global-controller".to_string(),
             region: Some("us-west-2".to_string()),
+            client_secret: None,
         };
 
         let debug_str = format!("{:?}", req);
@@ -1406,7 +2591,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_create_client_success(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let payload = CreateClientRequest {
             service_type: "global-controller".to_string(),
@@ -1430,7 +2615,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_create_client_invalid_service_type(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let payload = CreateClientRequest {
             service_type: "invalid-service".to_string(),
@@ -1451,7 +2636,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_create_client_empty_service_type(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let payload = CreateClientRequest {
             service_type: "".to_string(),
@@ -1470,7 +2655,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_list_clients_empty(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let result = handle_list_clients(State(state)).await;
 
@@ -1482,10 +2667,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_list_clients_multiple(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config,
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config));
 
         // Create multiple clients
         let payload1 = CreateClientRequest {
@@ -1524,10 +2706,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_get_client_success(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config,
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config));
 
         // Create a client
         let payload = CreateClientRequest {
@@ -1553,7 +2732,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_get_client_not_found(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         // Try to get nonexistent client
         let random_uuid = Uuid::new_v4();
@@ -1575,10 +2754,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_update_client_success(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config,
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config));
 
         // Create a client
         let payload = CreateClientRequest {
@@ -1597,6 +2773,7 @@ mod tests {
                 "scope-b".to_string(),
                 "scope-c".to_string(),
             ]),
+            client_cert_spki_sha256: None,
         };
 
         let result =
@@ -1619,11 +2796,12 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_update_client_not_found(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let random_uuid = Uuid::new_v4();
         let update_payload = UpdateClientRequest {
             scopes: Some(vec!["scope1".to_string()]),
+            client_cert_spki_sha256: None,
         };
 
         let result =
@@ -1641,10 +2819,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_update_client_invalid_scope_format(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config,
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config));
 
         // Create a client
         let payload = CreateClientRequest {
@@ -1659,6 +2834,7 @@ mod tests {
         // Try to update with invalid scope (contains special characters)
         let update_payload = UpdateClientRequest {
             scopes: Some(vec!["invalid@scope#value".to_string()]),
+            client_cert_spki_sha256: None,
         };
 
         let result =
@@ -1677,10 +2853,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_update_client_no_changes(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config,
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config));
 
         // Create a client
         let payload = CreateClientRequest {
@@ -1695,7 +2868,10 @@ mod tests {
         let original_scopes = create_response.scopes.clone();
 
         // Update with no scopes provided (no-op)
-        let update_payload = UpdateClientRequest { scopes: None };
+        let update_payload = UpdateClientRequest {
+            scopes: None,
+            client_cert_spki_sha256: None,
+        };
 
         let result =
             handle_update_client(State(state), Path(create_response.id), Json(update_payload))
@@ -1709,6 +2885,52 @@ mod tests {
         );
     }
 
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_handle_update_client_pins_certificate(pool: sqlx::PgPool) {
+        let config = test_config();
+        let state = Arc::new(AppState::new(pool, config));
+
+        let payload = CreateClientRequest {
+            service_type: "global-controller".to_string(),
+            region: None,
+        };
+        let create_response = handle_create_client(State(state.clone()), Json(payload))
+            .await
+            .unwrap()
+            .0;
+        let update = |fingerprint: &str| UpdateClientRequest {
+            scopes: None,
+            client_cert_spki_sha256: Some(fingerprint.to_string()),
+        };
+
+        // Colon-separated uppercase (openssl output) is normalized
+        let response = handle_update_client(
+            State(state.clone()),
+            Path(create_response.id),
+            Json(update(&vec!["AB"; 32].join(":"))),
+        )
+        .await
+        .unwrap()
+        .0;
+        assert_eq!(response.client_cert_spki_sha256, Some("ab".repeat(32)));
+
+        let result = handle_update_client(
+            State(state.clone()),
+            Path(create_response.id),
+            Json(update("not-a-fingerprint")),
+        )
+        .await;
+        assert!(result.is_err(), "Malformed fingerprint should be rejected");
+
+        // Empty string removes the pin
+        let response =
+            handle_update_client(State(state), Path(create_response.id), Json(update("")))
+                .await
+                .unwrap()
+                .0;
+        assert!(response.client_cert_spki_sha256.is_none());
+    }
+
     // ----------------------------------------------------------------------------
     // Delete Client Tests
     // ----------------------------------------------------------------------------
@@ -1716,10 +2938,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_delete_client_success(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config,
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config));
 
         // Create a client directly via repository (avoid creating auth_events)
         let credential = crate::repositories::service_credentials::create_service_credential(
@@ -1749,7 +2968,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_delete_client_not_found(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let random_uuid = Uuid::new_v4();
         let result = handle_delete_client(State(state), Path(random_uuid)).await;
@@ -1766,10 +2985,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_delete_client_idempotent(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config,
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config));
 
         // Create a client directly via repository (avoid creating auth_events)
         let credential = crate::repositories::service_credentials::create_service_credential(
@@ -1810,10 +3026,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_rotate_client_secret_success(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config,
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config));
 
         // Create a client
         let payload = CreateClientRequest {
@@ -1844,7 +3057,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_rotate_client_secret_not_found(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let random_uuid = Uuid::new_v4();
         let result = handle_rotate_client_secret(State(state), Path(random_uuid)).await;
@@ -1861,10 +3074,7 @@ mod tests {
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_handle_rotate_client_secret_changes_hash(pool: sqlx::PgPool) {
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config,
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config));
 
         // Create a client
         let payload = CreateClientRequest {
@@ -1904,4 +3114,327 @@ mod tests {
             "Secret hash should change after rotation"
         );
     }
+
+    const TEST_BCRYPT_HASH: &str = "$2b$12$abcdefghijklmnopqrstuuABCDEFGHIJKLMNOPQRSTUVWXYZ01234";
+
+    fn import_record(email: &str) -> UserImportRecord {
+        UserImportRecord {
+            email: email.to_string(),
+            display_name: "Imported User".to_string(),
+            password_hash: TEST_BCRYPT_HASH.to_string(),
+            role: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_user_import_csv_and_json() {
+        let mut csv_headers = HeaderMap::new();
+        csv_headers.insert(header::CONTENT_TYPE, "text/csv".parse().unwrap());
+        let csv_body = format!(
+            "email,display_name,password_hash,role\n alice@example.com , Alice ,{},org_admin\nbob@example.com,Bob,{},\n",
+            TEST_BCRYPT_HASH, TEST_BCRYPT_HASH
+        );
+        let records = parse_user_import(&csv_headers, csv_body.as_bytes()).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].email, "alice@example.com");
+        assert_eq!(records[0].role.as_deref(), Some("org_admin"));
+        assert_eq!(records[1].role, None);
+
+        let json_body = serde_json::json!({
+            "users": [{
+                "email": "carol@example.com",
+                "display_name": "Carol",
+                "password_hash": TEST_BCRYPT_HASH
+            }]
+        });
+        let records =
+            parse_user_import(&HeaderMap::new(), json_body.to_string().as_bytes()).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].email, "carol@example.com");
+    }
+
+    #[test]
+    fn test_validate_user_import_reports_each_bad_record() {
+        let mut bad_hash = import_record("b@example.com");
+        bad_hash.password_hash = "plaintext".to_string();
+        let mut bad_role = import_record("c@example.com");
+        bad_role.role = Some("superuser".to_string());
+
+        let records = vec![
+            import_record("a@example.com"),
+            bad_hash,
+            bad_role,
+            import_record("a@example.com"),
+        ];
+
+        let message = validate_user_import(&records).unwrap_err();
+        assert!(message.contains("Record 2: Password hash must be a bcrypt hash"));
+        assert!(message.contains("Record 3: Role must be one of"));
+        assert!(message.contains("Record 4: Duplicate email"));
+        assert!(!message.contains("Record 1"));
+
+        assert!(validate_user_import(&[import_record("a@example.com")]).is_ok());
+        assert!(validate_user_import(&[]).is_err());
+    }
+
+    #[test]
+    fn test_user_import_record_debug_redacts_hash() {
+        let debug = format!("{:?}", import_record("a@example.com"));
+        assert!(!debug.contains(TEST_BCRYPT_HASH));
+        assert!(debug.contains("[REDACTED]"));
+    }
+    // ----------------------------------------------------------------------------
+    // Audit Events Tests
+    // ----------------------------------------------------------------------------
+
+    #[test]
+    fn test_parse_audit_events_query_defaults() {
+        let now = Utc::now();
+        let filter = parse_audit_events_query(&AuditEventsQuery::default(), now).unwrap();
+
+        assert_eq!(filter.to, now);
+        assert_eq!(filter.from, now - chrono::Duration::hours(24));
+        assert_eq!(filter.event_type, None);
+        assert_eq!(filter.limit, DEFAULT_AUDIT_EVENTS_LIMIT);
+    }
+
+    #[test]
+    fn test_parse_audit_events_query_filters() {
+        let query = AuditEventsQuery {
+            from: Some("2026-01-01T00:00:00Z".to_string()),
+            to: Some("2026-01-02T00:00:00+02:00".to_string()),
+            event_type: Some("key_rotated".to_string()),
+            limit: Some("10".to_string()),
+        };
+        let filter = parse_audit_events_query(&query, Utc::now()).unwrap();
+
+        assert_eq!(filter.from.to_rfc3339(), "2026-01-01T00:00:00+00:00");
+        assert_eq!(filter.to.to_rfc3339(), "2026-01-01T22:00:00+00:00");
+        assert_eq!(filter.event_type, Some(AuthEventType::KeyRotated));
+        assert_eq!(filter.limit, 10);
+    }
+
+    #[test]
+    fn test_parse_audit_events_query_rejects_invalid() {
+        let now = Utc::now();
+        let invalid = [
+            AuditEventsQuery {
+                from: Some("yesterday".to_string()),
+                ..Default::default()
+            },
+            AuditEventsQuery {
+                from: Some("2026-01-02T00:00:00Z".to_string()),
+                to: Some("2026-01-01T00:00:00Z".to_string()),
+                ..Default::default()
+            },
+            AuditEventsQuery {
+                event_type: Some("scopes_updated".to_string()),
+                ..Default::default()
+            },
+            AuditEventsQuery {
+                limit: Some("0".to_string()),
+                ..Default::default()
+            },
+            AuditEventsQuery {
+                limit: Some("1001".to_string()),
+                ..Default::default()
+            },
+        ];
+
+        for query in invalid {
+            assert!(
+                parse_audit_events_query(&query, now).is_err(),
+                "{query:?} should be rejected"
+            );
+        }
+    }
+
+    // ----------------------------------------------------------------------------
+    // Service Management (by client_id) Tests
+    // ----------------------------------------------------------------------------
+
+    async fn create_service(state: &Arc<AppState>, service_type: &str) -> CreateClientResponse {
+        let payload = CreateClientRequest {
+            service_type: service_type.to_string(),
+            region: None,
+        };
+        handle_create_client(State(state.clone()), Json(payload))
+            .await
+            .unwrap()
+            .0
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_handle_list_services_filters(pool: sqlx::PgPool) {
+        let state = Arc::new(AppState::new(pool.clone(), test_config()));
+        let gc = create_service(&state, "global-controller").await;
+        let mc = create_service(&state, "meeting-controller").await;
+        crate::repositories::service_credentials::deactivate(&pool, mc.id)
+            .await
+            .unwrap();
+
+        let all = handle_list_services(State(state.clone()), Query(ListServicesQuery::default()))
+            .await
+            .unwrap()
+            .0;
+        assert_eq!(all.len(), 2);
+
+        let query = ListServicesQuery {
+            service_type: Some("meeting-controller".to_string()),
+            is_active: None,
+        };
+        let by_type = handle_list_services(State(state.clone()), Query(query))
+            .await
+            .unwrap()
+            .0;
+        assert_eq!(by_type.len(), 1);
+        assert_eq!(by_type[0].client_id, mc.client_id);
+
+        let query = ListServicesQuery {
+            service_type: None,
+            is_active: Some(true),
+        };
+        let active = handle_list_services(State(state), Query(query))
+            .await
+            .unwrap()
+            .0;
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].client_id, gc.client_id);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_handle_update_service_disable_and_scopes(pool: sqlx::PgPool) {
+        let state = Arc::new(AppState::new(pool.clone(), test_config()));
+        let created = create_service(&state, "global-controller").await;
+
+        let payload = UpdateServiceRequest {
+            is_active: Some(false),
+            scopes: Some(vec!["meeting:read".to_string()]),
+        };
+        let updated = handle_update_service(
+            State(state.clone()),
+            Path(created.client_id.clone()),
+            Json(payload),
+        )
+        .await
+        .unwrap()
+        .0;
+        assert!(!updated.is_active);
+        assert_eq!(updated.scopes, vec!["meeting:read".to_string()]);
+
+        let payload = UpdateServiceRequest {
+            is_active: Some(true),
+            scopes: None,
+        };
+        let updated = handle_update_service(State(state), Path(created.client_id), Json(payload))
+            .await
+            .unwrap()
+            .0;
+        assert!(updated.is_active);
+        assert_eq!(updated.scopes, vec!["meeting:read".to_string()]);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_handle_update_service_rejects_invalid_scope_before_disabling(pool: sqlx::PgPool) {
+        let state = Arc::new(AppState::new(pool.clone(), test_config()));
+        let created = create_service(&state, "media-handler").await;
+
+        let payload = UpdateServiceRequest {
+            is_active: Some(false),
+            scopes: Some(vec!["bad scope".to_string()]),
+        };
+        let result = handle_update_service(
+            State(state.clone()),
+            Path(created.client_id.clone()),
+            Json(payload),
+        )
+        .await;
+        assert!(
+            matches!(result, Err(AcError::Database(msg)) if msg.contains("invalid characters"))
+        );
+
+        let credential =
+            crate::repositories::service_credentials::get_by_client_id(&pool, &created.client_id)
+                .await
+                .unwrap()
+                .unwrap();
+        assert!(
+            credential.is_active,
+            "nothing changes on validation failure"
+        );
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_handle_update_service_not_found(pool: sqlx::PgPool) {
+        let state = Arc::new(AppState::new(pool, test_config()));
+
+        let payload = UpdateServiceRequest {
+            is_active: Some(false),
+            scopes: None,
+        };
+        let result =
+            handle_update_service(State(state), Path("missing".to_string()), Json(payload)).await;
+        assert!(matches!(result, Err(AcError::NotFound(_))));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_handle_rotate_service_secret(pool: sqlx::PgPool) {
+        let state = Arc::new(AppState::new(pool, test_config()));
+        let created = create_service(&state, "global-controller").await;
+
+        let response =
+            handle_rotate_service_secret(State(state.clone()), Path(created.client_id.clone()))
+                .await
+                .unwrap()
+                .0;
+        assert_eq!(response.client_id, created.client_id);
+        assert_ne!(
+            response.client_secret.expose_secret(),
+            created.client_secret.expose_secret()
+        );
+
+        let missing = handle_rotate_service_secret(State(state), Path("missing".to_string())).await;
+        assert!(matches!(missing, Err(AcError::NotFound(_))));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_handle_list_audit_events(pool: sqlx::PgPool) {
+        let state = Arc::new(AppState::new(pool.clone(), test_config()));
+        // No audit writer is installed, so events are written inline
+        let _ = handle_create_client(
+            State(state.clone()),
+            Json(CreateClientRequest {
+                service_type: "global-controller".to_string(),
+                region: None,
+            }),
+        )
+        .await
+        .unwrap();
+
+        let response = handle_list_audit_events(
+            State(state.clone()),
+            Query(AuditEventsQuery {
+                event_type: Some("service_registered".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap()
+        .0;
+        assert_eq!(response.events.len(), 1);
+        assert_eq!(response.events[0].event_type, "service_registered");
+        assert!(response.events[0].credential_id.is_some());
+
+        let response = handle_list_audit_events(
+            State(state),
+            Query(AuditEventsQuery {
+                event_type: Some("key_rotated".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap()
+        .0;
+        assert!(response.events.is_empty());
+    }
 }
@@ -1,24 +1,32 @@
 use crate::config::Config;
 #[cfg(test)]
 use crate::config::DEFAULT_BCRYPT_COST;
+use crate::crypto::master_key::{EnvMasterKey, MasterKeyProvider};
 use crate::errors::AcError;
 use crate::middleware::org_extraction::OrgContext;
-use crate::models::TokenResponse;
-use crate::observability::metrics::{record_error, record_token_issuance};
-use crate::observability::ErrorCategory;
-use crate::services::{token_service, user_service};
+use crate::models::{IntrospectionResponse, TokenResponse};
+use crate::mtls::ClientCertificate;
+use crate::observability::metrics::{record_error, record_token_issuance, record_token_validation};
+use crate::observability::{hash_for_correlation, ErrorCategory};
+use crate::repositories::service_credentials;
+use crate::services::client_rate_limiter::{unknown_client_key, ClientRateLimiter};
+use crate::services::jwks_manager::JwksManagerActorHandle;
+use crate::services::{oidc_service, token_service, totp_service, user_service};
 use axum::{
-    extract::{ConnectInfo, Extension, State},
-    http::HeaderMap,
+    extract::{ConnectInfo, Extension, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::Redirect,
     Json,
 };
 use base64::{engine::general_purpose, Engine as _};
+use chrono::Utc;
+use common::dpop::{self, DpopReplayCache, DpopRequest, DPOP_HEADER};
 use common::secret::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::instrument;
 use uuid::Uuid;
 
@@ -30,6 +38,71 @@ use uuid::Uuid;
 pub struct UserTokenRequest {
     pub email: String,
     pub password: SecretString,
+    /// Also issue a refresh token (see [`handle_user_refresh_token`]).
+    #[serde(default)]
+    pub issue_refresh_token: bool,
+}
+
+/// User login request (ADR-0020).
+///
+/// A [`UserTokenRequest`] plus the TOTP code, which users who have enabled
+/// the second factor must send.
+#[derive(Debug, Deserialize)]
+pub struct UserLoginRequest {
+    pub email: String,
+    pub password: SecretString,
+    #[serde(default)]
+    pub totp_code: Option<SecretString>,
+    /// Also issue a refresh token (see [`handle_user_refresh_token`]).
+    #[serde(default)]
+    pub issue_refresh_token: bool,
+}
+
+/// TOTP enrollment request (ADR-0020).
+///
+/// `totp_code` is required when replacing an enabled second factor.
+#[derive(Debug, Deserialize)]
+pub struct UserTotpEnrollRequest {
+    pub email: String,
+    pub password: SecretString,
+    #[serde(default)]
+    pub totp_code: Option<SecretString>,
+}
+
+/// TOTP enrollment response: the new secret for the authenticator app.
+#[derive(Debug, Clone, Serialize)]
+pub struct UserTotpEnrollResponse {
+    /// Unpadded base32 secret, for manual entry.
+    pub secret: String,
+    /// `otpauth://` URI, usually shown as a QR code.
+    pub otpauth_uri: String,
+}
+
+/// TOTP enrollment confirmation request (ADR-0020).
+#[derive(Debug, Deserialize)]
+pub struct UserTotpConfirmRequest {
+    pub email: String,
+    pub password: SecretString,
+    pub totp_code: SecretString,
+}
+
+/// OIDC callback query from the identity provider redirect.
+///
+/// The provider sends either `code` or `error` along with our `state`.
+#[derive(Debug, Deserialize)]
+pub struct OidcCallbackQuery {
+    #[serde(default)]
+    pub code: Option<SecretString>,
+    pub state: String,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// User refresh token request (OAuth 2.0 `refresh_token` grant).
+#[derive(Debug, Deserialize)]
+pub struct UserRefreshRequest {
+    pub grant_type: String,
+    pub refresh_token: SecretString,
 }
 
 /// User registration request (ADR-0020).
@@ -66,6 +139,46 @@ pub struct ServiceTokenRequest {
     pub client_secret: Option<SecretString>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<String>,
+    /// Refresh token for `grant_type=refresh_token`.
+    #[serde(default)]
+    pub refresh_token: Option<SecretString>,
+    /// Also issue a refresh token with a `client_credentials` grant.
+    #[serde(default)]
+    pub issue_refresh_token: bool,
+    /// Parameters of a token exchange grant.
+    #[serde(flatten)]
+    pub exchange: TokenExchangeParams,
+}
+
+/// Token exchange parameters (RFC 8693 Section 2.1).
+///
+/// Only used with `grant_type=urn:ietf:params:oauth:grant-type:token-exchange`.
+/// Both tokens are required; `resource` and `audience` are not supported.
+#[derive(Debug, Default, Deserialize)]
+pub struct TokenExchangeParams {
+    /// The user's access token.
+    #[serde(default)]
+    pub subject_token: Option<SecretString>,
+    #[serde(default)]
+    pub subject_token_type: Option<String>,
+    /// The calling service's access token.
+    #[serde(default)]
+    pub actor_token: Option<SecretString>,
+    #[serde(default)]
+    pub actor_token_type: Option<String>,
+    #[serde(default)]
+    pub requested_token_type: Option<String>,
+}
+
+/// Token introspection request (RFC 7662 Section 2.1).
+///
+/// `token_type_hint` is accepted but not needed: AC only issues JWTs, and
+/// the token itself says whether it is a service or user token.
+#[derive(Debug, Deserialize)]
+pub struct IntrospectionRequest {
+    pub token: SecretString,
+    #[serde(default)]
+    pub token_type_hint: Option<String>,
 }
 
 /// Application state shared across handlers
@@ -73,8 +186,58 @@ pub struct ServiceTokenRequest {
 pub struct AppState {
     pub pool: PgPool,
     pub config: Config,
+    /// Cached JWKS document served at `/.well-known/jwks.json`
+    pub jwks: JwksManagerActorHandle,
+    /// Per-client token bucket limiter for the token endpoints
+    pub client_rate_limiter: Arc<ClientRateLimiter>,
+    /// Encrypts and decrypts signing keys (`AC_MASTER_KEY_PROVIDER`)
+    pub master_key: Arc<dyn MasterKeyProvider>,
+    /// DPoP proof `jti`s already used at the service token endpoint
+    pub dpop_replay: Arc<DpopReplayCache>,
 }
 
+impl AppState {
+    /// Create the state and spawn its JWKS manager (cache starts empty).
+    ///
+    /// Uses `config.master_key` directly; services configured with a KMS
+    /// provider use [`AppState::with_master_key`].
+    #[allow(dead_code)] // Library function - used by tests and ac-test-utils
+    pub fn new(pool: PgPool, config: Config) -> Self {
+        let master_key =
+            Arc::new(EnvMasterKey::new(&config.master_key).with_version(config.master_key_version));
+        Self::with_master_key(pool, config, master_key)
+    }
+
+    /// Create the state with a master key provider from
+    /// [`crate::crypto::master_key::load`].
+    pub fn with_master_key(
+        pool: PgPool,
+        config: Config,
+        master_key: Arc<dyn MasterKeyProvider>,
+    ) -> Self {
+        let jwks = JwksManagerActorHandle::spawn(pool.clone());
+        let client_rate_limiter =
+            Arc::new(ClientRateLimiter::new(config.client_rate_limits.clone()));
+        Self {
+            pool,
+            config,
+            jwks,
+            client_rate_limiter,
+            master_key,
+            dpop_replay: Arc::new(DpopReplayCache::new()),
+        }
+    }
+}
+
+/// Limit class for user token requests.
+const USER_LIMIT_CLASS: &str = "user";
+
+/// Limit class of the bucket shared by client IDs the limiter does not know.
+const UNKNOWN_LIMIT_CLASS: &str = "unknown";
+
+/// Path of the service token endpoint, the `htu` path its DPoP proofs name.
+const SERVICE_TOKEN_PATH: &str = "/api/v1/auth/service/token";
+
 /// Handle user token request (ADR-0020).
 ///
 /// POST /api/v1/auth/user/token
@@ -95,6 +258,69 @@ pub async fn handle_user_token(
     headers: HeaderMap,
     Json(payload): Json<UserTokenRequest>,
 ) -> Result<Json<token_service::UserTokenResponse>, AcError> {
+    issue_password_token(
+        &state,
+        addr,
+        org_context,
+        &headers,
+        &payload.email,
+        &payload.password,
+        None,
+        payload.issue_refresh_token,
+    )
+    .await
+    .map(Json)
+}
+
+/// Handle user login request (ADR-0020).
+///
+/// POST /api/v1/auth/user/login
+///
+/// Requires org context from middleware (subdomain-based org identification).
+/// Like `handle_user_token`, but also takes the TOTP code required from users
+/// who have enabled a second factor.
+///
+/// ADR-0011: Handler instrumented with skip_all to prevent PII leakage.
+/// Only safe fields (grant_type, status) are recorded.
+#[instrument(
+    name = "ac.token.user_login",
+    skip_all,
+    fields(grant_type = "password", status)
+)]
+pub async fn handle_user_login(
+    State(state): State<Arc<AppState>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Extension(org_context): Extension<OrgContext>,
+    headers: HeaderMap,
+    Json(payload): Json<UserLoginRequest>,
+) -> Result<Json<token_service::UserTokenResponse>, AcError> {
+    issue_password_token(
+        &state,
+        addr,
+        org_context,
+        &headers,
+        &payload.email,
+        &payload.password,
+        payload.totp_code.as_ref(),
+        payload.issue_refresh_token,
+    )
+    .await
+    .map(Json)
+}
+
+/// Shared body of the password grant handlers: client rate limit, login,
+/// optional refresh token, and metrics.
+#[expect(clippy::too_many_arguments)]
+async fn issue_password_token(
+    state: &AppState,
+    addr: SocketAddr,
+    org_context: OrgContext,
+    headers: &HeaderMap,
+    email: &str,
+    password: &SecretString,
+    totp_code: Option<&SecretString>,
+    issue_refresh_token: bool,
+) -> Result<token_service::UserTokenResponse, AcError> {
     let start = Instant::now();
 
     // Extract IP address and User-Agent
@@ -104,13 +330,153 @@ pub async fn handle_user_token(
         .and_then(|h| h.to_str().ok())
         .map(|s| s.to_string());
 
-    let result = token_service::issue_user_token(
+    // Per-client limit keyed by org and email; failed logins are also
+    // counted by the lockout in token_service
+    let client_key = hash_for_correlation(
+        &format!("{}:{}", org_context.org_id, email),
+        state.config.hash_secret.expose_secret(),
+    );
+    if let Err(e) = state
+        .client_rate_limiter
+        .check(&client_key, USER_LIMIT_CLASS)
+    {
+        let duration = start.elapsed();
+        tracing::Span::current().record("status", "error");
+        record_token_issuance("password", "error", duration);
+        record_error(
+            "issue_user_token",
+            ErrorCategory::from(&e).as_str(),
+            e.status_code(),
+        );
+        return Err(e);
+    }
+
+    let result = token_service::issue_user_login_token(
         &state.pool,
-        state.config.master_key.expose_secret(),
+        state.master_key.as_ref(),
+        state.config.hash_secret.expose_secret(),
+        org_context.org_id,
+        email,
+        password.expose_secret(),
+        totp_code.map(|c| c.expose_secret()),
+        ip_address.as_deref(),
+        user_agent.as_deref(),
+        state.config.rate_limit_window_minutes,
+        state.config.rate_limit_max_attempts,
+        state.config.token_ttls.user_ttl(),
+    )
+    .await;
+
+    // Attach a refresh token if the user opted in
+    let result = match result {
+        Ok(mut token) if issue_refresh_token => {
+            token_service::issue_user_refresh_token(&state.pool, org_context.org_id, email)
+                .await
+                .map(|refresh_token| {
+                    token.refresh_token = Some(refresh_token);
+                    token
+                })
+        }
+        other => other,
+    };
+
+    let duration = start.elapsed();
+    let status = if result.is_ok() { "success" } else { "error" };
+    tracing::Span::current().record("status", status);
+    record_token_issuance("password", status, duration);
+
+    // ADR-0011: Record error category for failed requests
+    if let Err(e) = &result {
+        let category = ErrorCategory::from(e);
+        record_error("issue_user_token", category.as_str(), e.status_code());
+    }
+    result
+}
+
+/// Handle TOTP enrollment request (ADR-0020).
+///
+/// POST /api/v1/auth/user/totp
+///
+/// Requires org context from middleware (subdomain-based org identification).
+/// Returns a new, unconfirmed secret; `handle_user_totp_confirm` turns it on.
+///
+/// ADR-0011: Handler instrumented with skip_all so neither the password nor
+/// the secret is recorded.
+#[instrument(name = "ac.auth.totp_enroll", skip_all, fields(status))]
+pub async fn handle_user_totp_enroll(
+    State(state): State<Arc<AppState>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Extension(org_context): Extension<OrgContext>,
+    headers: HeaderMap,
+    Json(payload): Json<UserTotpEnrollRequest>,
+) -> Result<Json<UserTotpEnrollResponse>, AcError> {
+    let ip_address = Some(addr.ip().to_string());
+    let user_agent = headers
+        .get("user-agent")
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string());
+
+    let result = totp_service::begin_enrollment(
+        &state.pool,
+        state.master_key.as_ref(),
+        state.config.hash_secret.expose_secret(),
+        org_context.org_id,
+        &payload.email,
+        payload.password.expose_secret(),
+        payload.totp_code.as_ref().map(|c| c.expose_secret()),
+        ip_address.as_deref(),
+        user_agent.as_deref(),
+        state.config.rate_limit_window_minutes,
+        state.config.rate_limit_max_attempts,
+    )
+    .await;
+
+    let status = if result.is_ok() { "success" } else { "error" };
+    tracing::Span::current().record("status", status);
+
+    match result {
+        Ok(enrollment) => Ok(Json(UserTotpEnrollResponse {
+            secret: enrollment.secret.expose_secret().to_string(),
+            otpauth_uri: enrollment.otpauth_uri.expose_secret().to_string(),
+        })),
+        Err(e) => {
+            let category = ErrorCategory::from(&e);
+            record_error("totp_enroll", category.as_str(), e.status_code());
+            Err(e)
+        }
+    }
+}
+
+/// Handle TOTP enrollment confirmation (ADR-0020).
+///
+/// POST /api/v1/auth/user/totp/confirm
+///
+/// Requires org context from middleware (subdomain-based org identification).
+/// After a 204, `handle_user_login` requires a TOTP code for this user.
+///
+/// ADR-0011: Handler instrumented with skip_all to prevent PII leakage.
+#[instrument(name = "ac.auth.totp_confirm", skip_all, fields(status))]
+pub async fn handle_user_totp_confirm(
+    State(state): State<Arc<AppState>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Extension(org_context): Extension<OrgContext>,
+    headers: HeaderMap,
+    Json(payload): Json<UserTotpConfirmRequest>,
+) -> Result<StatusCode, AcError> {
+    let ip_address = Some(addr.ip().to_string());
+    let user_agent = headers
+        .get("user-agent")
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string());
+
+    let result = totp_service::confirm_enrollment(
+        &state.pool,
+        state.master_key.as_ref(),
         state.config.hash_secret.expose_secret(),
         org_context.org_id,
         &payload.email,
         payload.password.expose_secret(),
+        payload.totp_code.expose_secret(),
         ip_address.as_deref(),
         user_agent.as_deref(),
         state.config.rate_limit_window_minutes,
@@ -118,17 +484,165 @@ pub async fn handle_user_token(
     )
     .await;
 
+    let status = if result.is_ok() { "success" } else { "error" };
+    tracing::Span::current().record("status", status);
+
+    match result {
+        Ok(()) => Ok(StatusCode::NO_CONTENT),
+        Err(e) => {
+            let category = ErrorCategory::from(&e);
+            record_error("totp_confirm", category.as_str(), e.status_code());
+            Err(e)
+        }
+    }
+}
+
+/// Handle SSO login start (OIDC federation).
+///
+/// GET /api/v1/auth/oidc/authorize
+///
+/// Requires org context from middleware (subdomain-based org identification).
+/// Redirects the browser to the org's identity provider, which sends it back
+/// to `handle_oidc_callback`.
+#[instrument(name = "ac.auth.oidc_authorize", skip_all, fields(status))]
+pub async fn handle_oidc_authorize(
+    State(state): State<Arc<AppState>>,
+    Extension(org_context): Extension<OrgContext>,
+) -> Result<Redirect, AcError> {
+    let result = oidc_service::begin_login(&state.pool, org_context.org_id).await;
+
+    let status = if result.is_ok() { "success" } else { "error" };
+    tracing::Span::current().record("status", status);
+
+    match result {
+        Ok(url) => Ok(Redirect::to(&url)),
+        Err(e) => {
+            let category = ErrorCategory::from(&e);
+            record_error("oidc_authorize", category.as_str(), e.status_code());
+            Err(e)
+        }
+    }
+}
+
+/// Handle SSO login callback from the identity provider (OIDC federation).
+///
+/// GET /api/v1/auth/oidc/callback?code=...&state=...
+///
+/// Requires org context from middleware (subdomain-based org identification).
+/// Returns the same token response as `handle_user_login`.
+///
+/// ADR-0011: Handler instrumented with skip_all so the code is never recorded.
+#[instrument(
+    name = "ac.token.oidc_callback",
+    skip_all,
+    fields(grant_type = "authorization_code", status)
+)]
+pub async fn handle_oidc_callback(
+    State(state): State<Arc<AppState>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Extension(org_context): Extension<OrgContext>,
+    headers: HeaderMap,
+    Query(query): Query<OidcCallbackQuery>,
+) -> Result<Json<token_service::UserTokenResponse>, AcError> {
+    let start = Instant::now();
+
+    let ip_address = Some(addr.ip().to_string());
+    let user_agent = headers
+        .get("user-agent")
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string());
+
+    let result = match (&query.code, &query.error) {
+        (Some(code), None) => {
+            oidc_service::complete_login(
+                &state.pool,
+                state.master_key.as_ref(),
+                org_context.org_id,
+                code.expose_secret(),
+                &query.state,
+                state.config.bcrypt_cost,
+                u64::try_from(state.config.jwt_clock_skew_seconds).unwrap_or(0),
+                state.config.token_ttls.user_ttl(),
+                ip_address.as_deref(),
+                user_agent.as_deref(),
+            )
+            .await
+        }
+        // The user cancelled or the provider refused (RFC 6749 §4.1.2.1)
+        _ => Err(AcError::InvalidToken(
+            "The identity provider did not complete the login".to_string(),
+        )),
+    };
+
     let duration = start.elapsed();
     let status = if result.is_ok() { "success" } else { "error" };
     tracing::Span::current().record("status", status);
-    record_token_issuance("password", status, duration);
+    record_token_issuance("authorization_code", status, duration);
+
+    // ADR-0011: Record error category for failed requests
+    if let Err(e) = &result {
+        let category = ErrorCategory::from(e);
+        record_error("oidc_callback", category.as_str(), e.status_code());
+    }
+    result.map(Json)
+}
+
+/// Handle user refresh token request (ADR-0020).
+///
+/// POST /api/v1/auth/user/token/refresh
+///
+/// Requires org context from middleware (subdomain-based org identification).
+/// Exchanges a refresh token from `handle_user_token` for a new access token
+/// and a rotated refresh token.
+///
+/// ADR-0011: Handler instrumented with skip_all so the token is never recorded.
+#[instrument(
+    name = "ac.token.refresh_user",
+    skip_all,
+    fields(grant_type = "refresh_token", status)
+)]
+pub async fn handle_user_refresh_token(
+    State(state): State<Arc<AppState>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Extension(org_context): Extension<OrgContext>,
+    headers: HeaderMap,
+    Json(payload): Json<UserRefreshRequest>,
+) -> Result<Json<token_service::UserTokenResponse>, AcError> {
+    let start = Instant::now();
+
+    // Extract IP address and User-Agent
+    let ip_address = Some(addr.ip().to_string());
+    let user_agent = headers
+        .get("user-agent")
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string());
+
+    let result = if payload.grant_type == "refresh_token" {
+        token_service::refresh_user_token(
+            &state.pool,
+            state.master_key.as_ref(),
+            org_context.org_id,
+            payload.refresh_token.expose_secret(),
+            state.config.token_ttls.user_ttl(),
+            ip_address.as_deref(),
+            user_agent.as_deref(),
+        )
+        .await
+    } else {
+        Err(AcError::InvalidCredentials)
+    };
+
+    let duration = start.elapsed();
+    let status = if result.is_ok() { "success" } else { "error" };
+    tracing::Span::current().record("status", status);
+    record_token_issuance("refresh_token", status, duration);
 
     // ADR-0011: Record error category for failed requests
     match result {
         Ok(token) => Ok(Json(token)),
         Err(e) => {
             let category = ErrorCategory::from(&e);
-            record_error("issue_user_token", category.as_str(), e.status_code());
+            record_error("refresh_user_token", category.as_str(), e.status_code());
             Err(e)
         }
     }
@@ -167,7 +681,7 @@ pub async fn handle_register(
 
     let result = user_service::register_user(
         &state.pool,
-        state.config.master_key.expose_secret(),
+        state.master_key.as_ref(),
         state.config.hash_secret.expose_secret(),
         org_context.org_id,
         request,
@@ -178,6 +692,7 @@ pub async fn handle_register(
         state.config.registration_rate_limit_max_attempts,
         state.config.rate_limit_window_minutes,
         state.config.rate_limit_max_attempts,
+        state.config.token_ttls.user_ttl(),
     )
     .await;
 
@@ -210,24 +725,64 @@ pub async fn handle_register(
 /// Accepts credentials via:
 /// - HTTP Basic Auth (preferred)
 /// - Request body (client_id, client_secret)
+/// - TLS client certificate pinned to the credential, with client_id in the
+///   body (only when `AC_MTLS_MODE` is enabled; see [`crate::mtls`])
+///
+/// `grant_type=refresh_token` exchanges a refresh token instead; the client
+/// authenticates the same way, and the token must have been issued to it.
+/// With `issue_refresh_token`, a `client_credentials` grant also returns a
+/// refresh token. The token exchange grant (RFC 8693) exchanges a service
+/// token and a user token for a delegated token; see
+/// [`exchange_service_token`].
+///
+/// With a DPoP header (RFC 9449), the access token is bound to the proof
+/// key and issued with `token_type: "DPoP"`; an invalid proof fails the
+/// request rather than falling back to a bearer token.
 ///
 /// ADR-0011: Handler instrumented with skip_all to prevent PII leakage.
 /// Only safe fields (grant_type, status) are recorded.
-#[instrument(
-    name = "ac.token.issue_service",
-    skip_all,
-    fields(grant_type = "client_credentials", status)
-)]
+#[instrument(name = "ac.token.issue_service", skip_all, fields(grant_type, status))]
 pub async fn handle_service_token(
     State(state): State<Arc<AppState>>,
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    client_cert: Option<Extension<ClientCertificate>>,
     headers: HeaderMap,
     Json(payload): Json<ServiceTokenRequest>,
 ) -> Result<Json<TokenResponse>, AcError> {
     let start = Instant::now();
 
+    let grant_label = match payload.grant_type.as_str() {
+        "refresh_token" => "refresh_token",
+        token_service::TOKEN_EXCHANGE_GRANT_TYPE => "token_exchange",
+        _ => "client_credentials",
+    };
+    tracing::Span::current().record("grant_type", grant_label);
+
+    let dpop_jkt = match verify_dpop_proof(&state, &headers) {
+        Ok(jkt) => jkt,
+        Err(e) => {
+            let duration = start.elapsed();
+            tracing::Span::current().record("status", "error");
+            record_token_issuance(grant_label, "error", duration);
+            record_error(
+                "issue_service_token",
+                ErrorCategory::from(&e).as_str(),
+                e.status_code(),
+            );
+            return Err(e);
+        }
+    };
+
+    if payload.grant_type == token_service::TOKEN_EXCHANGE_GRANT_TYPE {
+        return exchange_service_token(&state, addr, &headers, payload, dpop_jkt.as_deref(), start)
+            .await;
+    }
+
     // Validate grant_type
-    if payload.grant_type != "client_credentials" {
+    if !matches!(
+        payload.grant_type.as_str(),
+        "client_credentials" | "refresh_token"
+    ) {
         let duration = start.elapsed();
         let err = AcError::InvalidCredentials;
         tracing::Span::current().record("status", "error");
@@ -240,13 +795,27 @@ pub async fn handle_service_token(
         return Err(err);
     }
 
-    // Extract client credentials from Basic Auth or request body
-    let (client_id, client_secret) = match extract_client_credentials(&headers, &payload) {
+    // Only trust a client certificate when mTLS is on
+    let mtls_mode = state.config.mtls.mode;
+    let client_cert = client_cert
+        .map(|Extension(cert)| cert)
+        .filter(|_| mtls_mode.is_enabled());
+
+    // Extract client credentials from Basic Auth or request body; with a
+    // client certificate the body may carry client_id alone
+    let credentials = match extract_client_credentials(&headers, &payload) {
+        Ok((id, secret)) => Ok((id, Some(secret))),
+        Err(e) => match (&client_cert, &payload.client_id, &payload.client_secret) {
+            (Some(_), Some(id), None) => Ok((id.clone(), None)),
+            _ => Err(e),
+        },
+    };
+    let (client_id, client_secret) = match credentials {
         Ok(creds) => creds,
         Err(e) => {
             let duration = start.elapsed();
             tracing::Span::current().record("status", "error");
-            record_token_issuance("client_credentials", "error", duration);
+            record_token_issuance(grant_label, "error", duration);
             record_error(
                 "issue_service_token",
                 ErrorCategory::from(&e).as_str(),
@@ -256,6 +825,31 @@ pub async fn handle_service_token(
         }
     };
 
+    // Per-client limit keyed by client_id, with the limit for its service type
+    if let Err(e) = check_service_rate_limit(&state, &client_id, addr.ip()).await {
+        let duration = start.elapsed();
+        tracing::Span::current().record("status", "error");
+        record_token_issuance(grant_label, "error", duration);
+        record_error(
+            "issue_service_token",
+            ErrorCategory::from(&e).as_str(),
+            e.status_code(),
+        );
+        return Err(e);
+    }
+
+    let auth = token_service::ServiceClientAuth {
+        client_secret: client_secret.as_deref(),
+        cert_fingerprint: client_cert.as_ref().map(|c| c.spki_sha256.as_str()),
+        mtls_mode,
+        dpop_jkt: dpop_jkt.as_deref(),
+    };
+
+    if payload.grant_type == "refresh_token" {
+        return refresh_service_token(&state, addr, &headers, payload, &client_id, auth, start)
+            .await;
+    }
+
     // Extract IP address and User-Agent
     let ip_address = Some(addr.ip().to_string());
     let user_agent = headers
@@ -271,21 +865,36 @@ pub async fn handle_service_token(
     });
 
     // Issue token
-    let result = token_service::issue_service_token(
+    let result = token_service::issue_service_token_with_auth(
         &state.pool,
-        state.config.master_key.expose_secret(),
+        state.master_key.as_ref(),
         state.config.hash_secret.expose_secret(),
         &client_id,
-        &client_secret,
+        auth,
         &payload.grant_type,
         requested_scopes,
         ip_address.as_deref(),
         user_agent.as_deref(),
         state.config.rate_limit_window_minutes,
         state.config.rate_limit_max_attempts,
+        &state.config.token_ttls,
     )
     .await;
 
+    // Attach a refresh token if the client opted in
+    let result = match result {
+        Ok(mut token) if payload.issue_refresh_token => {
+            let scopes: Vec<String> = token.scope.split_whitespace().map(String::from).collect();
+            token_service::issue_service_refresh_token(&state.pool, &client_id, &scopes)
+                .await
+                .map(|refresh_token| {
+                    token.refresh_token = Some(refresh_token);
+                    token
+                })
+        }
+        other => other,
+    };
+
     let duration = start.elapsed();
     let status = if result.is_ok() { "success" } else { "error" };
     tracing::Span::current().record("status", status);
@@ -302,6 +911,312 @@ pub async fn handle_service_token(
     }
 }
 
+/// Apply the per-client rate limit to a service token request.
+///
+/// A client the limiter has seen before is limited by its own bucket, with
+/// no database lookup. Any other client ID first takes a token from the
+/// bucket of the caller's address (the `unknown` class limit), so a flood
+/// of made-up client IDs is limited before it reaches the database without
+/// limiting callers elsewhere; an ID that turns out to be registered is
+/// remembered and charged to its own bucket.
+async fn check_service_rate_limit(
+    state: &AppState,
+    client_id: &str,
+    source: IpAddr,
+) -> Result<(), AcError> {
+    let limiter = &state.client_rate_limiter;
+    let client_key = hash_for_correlation(client_id, state.config.hash_secret.expose_secret());
+    if let Some(class) = limiter.known_class(&client_key) {
+        return limiter.check(&client_key, &class);
+    }
+
+    check_source_rate_limit(state, source)?;
+    check_registered_client_rate_limit(state, client_id).await
+}
+
+/// Take a token from the `unknown` class bucket of the caller's address.
+fn check_source_rate_limit(state: &AppState, source: IpAddr) -> Result<(), AcError> {
+    let source_key = hash_for_correlation(
+        &source.to_string(),
+        state.config.hash_secret.expose_secret(),
+    );
+    state
+        .client_rate_limiter
+        .check(&unknown_client_key(&source_key), UNKNOWN_LIMIT_CLASS)
+}
+
+/// Take a token from the bucket of `client_id` under the limit of its
+/// service type, looking the class up in the credential store if the
+/// limiter does not know it yet. IDs with no credential are not charged.
+async fn check_registered_client_rate_limit(
+    state: &AppState,
+    client_id: &str,
+) -> Result<(), AcError> {
+    let limiter = &state.client_rate_limiter;
+    let client_key = hash_for_correlation(client_id, state.config.hash_secret.expose_secret());
+    let class = match limiter.known_class(&client_key) {
+        Some(class) => class,
+        None => {
+            let Some(credential) =
+                service_credentials::get_by_client_id(&state.pool, client_id).await?
+            else {
+                return Ok(());
+            };
+            limiter.remember_class(&client_key, &credential.service_type);
+            credential.service_type
+        }
+    };
+    limiter.check(&client_key, &class)
+}
+
+/// Verify the DPoP proof sent to the service token endpoint, if any, and
+/// return the thumbprint of its key for the token's `cnf` claim.
+///
+/// The proof must name this endpoint (`POST`, `Host` header, path) and be
+/// fresh and unused (see [`common::dpop::verify_proof`]).
+fn verify_dpop_proof(state: &AppState, headers: &HeaderMap) -> Result<Option<String>, AcError> {
+    let invalid_proof = || AcError::InvalidToken("The DPoP proof is invalid".to_string());
+
+    let mut proofs = headers.get_all(DPOP_HEADER).iter();
+    let Some(proof) = proofs.next() else {
+        return Ok(None);
+    };
+    // RFC 9449 Section 4.3: exactly one proof
+    if proofs.next().is_some() {
+        return Err(invalid_proof());
+    }
+    let proof = proof.to_str().map_err(|_| invalid_proof())?;
+    let host = headers
+        .get(header::HOST)
+        .and_then(|h| h.to_str().ok())
+        .ok_or_else(invalid_proof)?;
+
+    let now = Utc::now().timestamp();
+    let request = DpopRequest {
+        method: "POST",
+        host,
+        path: SERVICE_TOKEN_PATH,
+        access_token: None,
+    };
+    let proof = dpop::verify_proof(proof, &request, now)
+        .and_then(|proof| state.dpop_replay.check(&proof, now).map(|()| proof))
+        .map_err(|e| {
+            tracing::debug!(target: "ac.token", error = ?e, "DPoP proof rejected");
+            invalid_proof()
+        })?;
+    Ok(Some(proof.jkt))
+}
+
+/// Handle the `refresh_token` grant of the service token endpoint; the
+/// client authenticates with `auth` as for `client_credentials`.
+async fn refresh_service_token(
+    state: &AppState,
+    addr: SocketAddr,
+    headers: &HeaderMap,
+    payload: ServiceTokenRequest,
+    client_id: &str,
+    auth: token_service::ServiceClientAuth<'_>,
+    start: Instant,
+) -> Result<Json<TokenResponse>, AcError> {
+    // Extract IP address and User-Agent
+    let ip_address = Some(addr.ip().to_string());
+    let user_agent = headers
+        .get("user-agent")
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string());
+
+    let result = match payload.refresh_token {
+        Some(refresh_token) => {
+            token_service::refresh_service_token(
+                &state.pool,
+                state.master_key.as_ref(),
+                state.config.hash_secret.expose_secret(),
+                client_id,
+                auth,
+                refresh_token.expose_secret(),
+                ip_address.as_deref(),
+                user_agent.as_deref(),
+                state.config.rate_limit_window_minutes,
+                state.config.rate_limit_max_attempts,
+                &state.config.token_ttls,
+            )
+            .await
+        }
+        None => Err(AcError::InvalidToken(
+            "The refresh token is invalid or expired".to_string(),
+        )),
+    };
+
+    let duration = start.elapsed();
+    let status = if result.is_ok() { "success" } else { "error" };
+    tracing::Span::current().record("status", status);
+    record_token_issuance("refresh_token", status, duration);
+
+    // ADR-0011: Record error category for failed requests
+    match result {
+        Ok(token) => Ok(Json(token)),
+        Err(e) => {
+            let category = ErrorCategory::from(&e);
+            record_error("refresh_service_token", category.as_str(), e.status_code());
+            Err(e)
+        }
+    }
+}
+
+/// Handle the token exchange grant (RFC 8693) of the service token endpoint.
+///
+/// The caller authenticates with its `actor_token` rather than client
+/// credentials. Both tokens must be access tokens (`access_token` or `jwt`
+/// type), and only an access token can be requested. With a DPoP proof the
+/// delegated token is bound to the proof key, which must be the actor
+/// token's key if that is bound. Requests count against the rate limit of
+/// the caller's address and, once the actor token verifies, of the client
+/// it names.
+async fn exchange_service_token(
+    state: &AppState,
+    addr: SocketAddr,
+    headers: &HeaderMap,
+    payload: ServiceTokenRequest,
+    dpop_jkt: Option<&str>,
+    start: Instant,
+) -> Result<Json<TokenResponse>, AcError> {
+    // Extract IP address and User-Agent
+    let ip_address = Some(addr.ip().to_string());
+    let user_agent = headers
+        .get("user-agent")
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string());
+
+    // The actor token is not verified yet, so the caller's address is
+    // charged first; the actor's own bucket only once its token verifies
+    let mut rate_limit = check_source_rate_limit(state, addr.ip());
+    if let (Ok(()), Some(actor_token)) = (&rate_limit, &payload.exchange.actor_token) {
+        rate_limit = match token_service::verified_actor(
+            &state.pool,
+            actor_token.expose_secret(),
+            Duration::from_secs(state.config.jwt_clock_skew_seconds as u64),
+        )
+        .await
+        {
+            Ok(Some(actor_id)) => check_registered_client_rate_limit(state, &actor_id).await,
+            Ok(None) => Ok(()),
+            Err(e) => Err(e),
+        };
+    }
+    if let Err(e) = rate_limit {
+        let duration = start.elapsed();
+        tracing::Span::current().record("status", "error");
+        record_token_issuance("token_exchange", "error", duration);
+        record_error(
+            "exchange_token",
+            ErrorCategory::from(&e).as_str(),
+            e.status_code(),
+        );
+        return Err(e);
+    }
+
+    let is_access_token = |token_type: Option<&str>| {
+        matches!(
+            token_type,
+            Some(token_service::ACCESS_TOKEN_TYPE | token_service::JWT_TOKEN_TYPE)
+        )
+    };
+    let params = payload.exchange;
+    let result = match (params.subject_token, params.actor_token) {
+        (Some(subject_token), Some(actor_token))
+            if is_access_token(params.subject_token_type.as_deref())
+                && is_access_token(params.actor_token_type.as_deref())
+                && params
+                    .requested_token_type
+                    .as_deref()
+                    .is_none_or(|t| t == token_service::ACCESS_TOKEN_TYPE) =>
+        {
+            let scopes = payload
+                .scope
+                .unwrap_or_default()
+                .split_whitespace()
+                .map(String::from)
+                .collect();
+            token_service::exchange_token(
+                &state.pool,
+                state.master_key.as_ref(),
+                token_service::TokenExchange {
+                    subject_token: subject_token.expose_secret(),
+                    actor_token: actor_token.expose_secret(),
+                    scopes,
+                    dpop_jkt,
+                },
+                Duration::from_secs(state.config.jwt_clock_skew_seconds as u64),
+                &state.config.token_ttls,
+                ip_address.as_deref(),
+                user_agent.as_deref(),
+            )
+            .await
+        }
+        _ => Err(AcError::InvalidRequest(
+            "Token exchange requires subject and actor access tokens".to_string(),
+        )),
+    };
+
+    let duration = start.elapsed();
+    let status = if result.is_ok() { "success" } else { "error" };
+    tracing::Span::current().record("status", status);
+    record_token_issuance("token_exchange", status, duration);
+
+    // ADR-0011: Record error category for failed requests
+    match result {
+        Ok(token) => Ok(Json(token)),
+        Err(e) => {
+            let category = ErrorCategory::from(&e);
+            record_error("exchange_token", category.as_str(), e.status_code());
+            Err(e)
+        }
+    }
+}
+
+/// Handle token introspection request (RFC 7662)
+///
+/// POST /api/v1/auth/introspect
+///
+/// Requires service authentication. Reports whether `token` is still active
+/// and, if so, its `scope`, `sub` and `exp`. Inactive tokens (expired, bad
+/// signature, retired signing key, deactivated client or user) get 200 with
+/// only `"active": false`, per RFC 7662.
+///
+/// ADR-0011: Handler instrumented with skip_all so the token is never recorded.
+#[instrument(name = "ac.token.introspect", skip_all, fields(active, status))]
+pub async fn handle_introspect(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<IntrospectionRequest>,
+) -> Result<Json<IntrospectionResponse>, AcError> {
+    let result = token_service::introspect_token(
+        &state.pool,
+        payload.token.expose_secret(),
+        Duration::from_secs(state.config.jwt_clock_skew_seconds as u64),
+    )
+    .await;
+
+    match result {
+        Ok(response) => {
+            tracing::Span::current().record("status", "success");
+            tracing::Span::current().record("active", response.active);
+            if response.active {
+                record_token_validation("success", None);
+            } else {
+                record_token_validation("error", Some(ErrorCategory::Authentication.as_str()));
+            }
+            Ok(Json(response))
+        }
+        Err(e) => {
+            let category = ErrorCategory::from(&e);
+            tracing::Span::current().record("status", "error");
+            record_token_validation("error", Some(category.as_str()));
+            record_error("introspect_token", category.as_str(), e.status_code());
+            Err(e)
+        }
+    }
+}
+
 /// Extract client credentials from Basic Auth header or request body
 fn extract_client_credentials(
     headers: &HeaderMap,
@@ -373,6 +1288,9 @@ mod tests {
             client_id: None,
             client_secret: None,
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let result = extract_client_credentials(&headers, &payload);
@@ -390,6 +1308,9 @@ mod tests {
             client_id: Some("test_client".to_string()),
             client_secret: Some(SecretString::from("test_secret")),
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let result = extract_client_credentials(&headers, &payload);
@@ -413,6 +1334,9 @@ mod tests {
             client_id: Some("body_client".to_string()),
             client_secret: Some(SecretString::from("body_secret")),
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let result = extract_client_credentials(&headers, &payload);
@@ -433,6 +1357,9 @@ mod tests {
             client_id: None,
             client_secret: None,
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let result = extract_client_credentials(&headers, &payload);
@@ -454,6 +1381,9 @@ mod tests {
             client_id: None,
             client_secret: None,
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let result = extract_client_credentials(&headers, &payload);
@@ -472,6 +1402,9 @@ mod tests {
             client_id: None,
             client_secret: None,
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let result = extract_client_credentials(&headers, &payload);
@@ -489,6 +1422,9 @@ mod tests {
             client_id: Some("body_client".to_string()),
             client_secret: Some(SecretString::from("body_secret")),
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let result = extract_client_credentials(&headers, &payload);
@@ -507,6 +1443,9 @@ mod tests {
             client_id: None,
             client_secret: None,
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let result = extract_client_credentials(&headers, &payload);
@@ -522,6 +1461,9 @@ mod tests {
             client_id: Some("test_client".to_string()),
             client_secret: None, // Missing secret
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let result = extract_client_credentials(&headers, &payload);
@@ -545,6 +1487,9 @@ mod tests {
             client_id: None,
             client_secret: None,
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let result = extract_client_credentials(&headers, &payload);
@@ -578,6 +1523,9 @@ mod tests {
             client_id: Some("fallback_client".to_string()),
             client_secret: Some(SecretString::from("fallback_secret")),
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let result = extract_client_credentials(&headers, &payload);
@@ -618,6 +1566,40 @@ mod tests {
         assert!(req.client_id.is_none());
         assert!(req.client_secret.is_none());
         assert!(req.scope.is_none());
+        assert!(req.exchange.subject_token.is_none());
+        assert!(req.exchange.actor_token.is_none());
+    }
+
+    /// Test ServiceTokenRequest deserialization of a token exchange grant
+    #[test]
+    fn test_service_token_request_exchange_deserialization() {
+        let json = r#"{
+            "grant_type": "urn:ietf:params:oauth:grant-type:token-exchange",
+            "subject_token": "user-token",
+            "subject_token_type": "urn:ietf:params:oauth:token-type:access_token",
+            "actor_token": "service-token",
+            "actor_token_type": "urn:ietf:params:oauth:token-type:jwt",
+            "scope": "delegated:meeting.moderate"
+        }"#;
+
+        let req: ServiceTokenRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.grant_type, token_service::TOKEN_EXCHANGE_GRANT_TYPE);
+        assert_eq!(
+            req.exchange
+                .subject_token
+                .as_ref()
+                .map(|s| s.expose_secret()),
+            Some("user-token")
+        );
+        assert_eq!(
+            req.exchange.actor_token_type.as_deref(),
+            Some(token_service::JWT_TOKEN_TYPE)
+        );
+        assert!(req.exchange.requested_token_type.is_none());
+        // Tokens are redacted like client secrets
+        let debug_str = format!("{:?}", req);
+        assert!(!debug_str.contains("user-token"));
+        assert!(!debug_str.contains("service-token"));
     }
 
     /// Test UserTokenRequest deserialization
@@ -633,6 +1615,22 @@ mod tests {
         assert_eq!(req.password.expose_secret(), "testpass");
     }
 
+    /// Test UserLoginRequest deserialization with and without a TOTP code
+    #[test]
+    fn test_user_login_request_deserialization() {
+        let req: UserLoginRequest =
+            serde_json::from_str(r#"{"email": "a@example.com", "password": "pw"}"#).unwrap();
+        assert!(req.totp_code.is_none());
+        assert!(!req.issue_refresh_token);
+
+        let req: UserLoginRequest = serde_json::from_str(
+            r#"{"email": "a@example.com", "password": "pw", "totp_code": "123456"}"#,
+        )
+        .unwrap();
+        assert_eq!(req.totp_code.unwrap().expose_secret(), "123456");
+        assert!(!format!("{:?}", req.password).contains("pw"));
+    }
+
     /// Test UserTokenRequest Debug implementation doesn't leak password
     ///
     /// With SecretString, Debug automatically redacts the password.
@@ -641,6 +1639,7 @@ mod tests {
         let req = UserTokenRequest {
             email: "testuser@example.com".to_string(),
             password: SecretString::from("secret123"),
+            issue_refresh_token: false,
         };
 
         let debug_str = format!("{:?}", req);
@@ -662,6 +1661,9 @@ mod tests {
             client_id: Some("test-client".to_string()),
             client_secret: Some(SecretString::from("test-secret")),
             scope: Some("read write".to_string()),
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let debug_str = format!("{:?}", req);
@@ -685,7 +1687,7 @@ mod tests {
         use std::net::SocketAddr;
 
         let config = test_config();
-        let state = Arc::new(AppState { pool, config });
+        let state = Arc::new(AppState::new(pool, config));
 
         let headers = HeaderMap::new();
         let payload = ServiceTokenRequest {
@@ -693,12 +1695,21 @@ mod tests {
             client_id: Some("test-client".to_string()),
             client_secret: Some(SecretString::from("test-secret")),
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let addr = "127.0.0.1:8080".parse::<SocketAddr>().unwrap();
 
-        let result =
-            handle_service_token(State(state), ConnectInfo(addr), headers, Json(payload)).await;
+        let result = handle_service_token(
+            State(state),
+            ConnectInfo(addr),
+            None,
+            headers,
+            Json(payload),
+        )
+        .await;
 
         // Should return InvalidCredentials error
         assert!(result.is_err(), "Invalid grant_type should be rejected");
@@ -720,10 +1731,7 @@ mod tests {
         use std::net::SocketAddr;
 
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config: config.clone(),
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config.clone()));
 
         // Initialize signing key first
         key_management_service::initialize_signing_key(
@@ -754,13 +1762,22 @@ mod tests {
                 registration.client_secret.expose_secret().to_string(),
             )),
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         // Test with IPv4 address
         let addr = "192.168.1.100:8080".parse::<SocketAddr>().unwrap();
 
-        let result =
-            handle_service_token(State(state), ConnectInfo(addr), headers, Json(payload)).await;
+        let result = handle_service_token(
+            State(state),
+            ConnectInfo(addr),
+            None,
+            headers,
+            Json(payload),
+        )
+        .await;
 
         // Should succeed (IP is logged in auth_events, not validated)
         assert!(
@@ -779,10 +1796,7 @@ mod tests {
         use std::net::SocketAddr;
 
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config: config.clone(),
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config.clone()));
 
         // Initialize signing key first
         key_management_service::initialize_signing_key(
@@ -811,12 +1825,21 @@ mod tests {
                 registration.client_secret.expose_secret().to_string(),
             )),
             scope: Some("service.write.mh service.write.gc".to_string()), // Request allowed scopes (ADR-0003)
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let addr = "127.0.0.1:8080".parse::<SocketAddr>().unwrap();
 
-        let result =
-            handle_service_token(State(state), ConnectInfo(addr), headers, Json(payload)).await;
+        let result = handle_service_token(
+            State(state),
+            ConnectInfo(addr),
+            None,
+            headers,
+            Json(payload),
+        )
+        .await;
 
         // Should succeed and parse scopes
         assert!(
@@ -839,10 +1862,7 @@ mod tests {
         use std::net::SocketAddr;
 
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config: config.clone(),
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config.clone()));
 
         // Initialize signing key first
         key_management_service::initialize_signing_key(
@@ -873,12 +1893,21 @@ mod tests {
                 registration.client_secret.expose_secret().to_string(),
             )),
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let addr = "10.0.0.5:8080".parse::<SocketAddr>().unwrap();
 
-        let result =
-            handle_service_token(State(state), ConnectInfo(addr), headers, Json(payload)).await;
+        let result = handle_service_token(
+            State(state),
+            ConnectInfo(addr),
+            None,
+            headers,
+            Json(payload),
+        )
+        .await;
 
         // Should succeed (User-Agent is logged, not validated)
         assert!(
@@ -897,10 +1926,7 @@ mod tests {
         use std::net::SocketAddr;
 
         let config = test_config();
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config: config.clone(),
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config.clone()));
 
         // Initialize signing key first
         key_management_service::initialize_signing_key(
@@ -930,12 +1956,21 @@ mod tests {
                 registration.client_secret.expose_secret().to_string(),
             )),
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         };
 
         let addr = "127.0.0.1:8080".parse::<SocketAddr>().unwrap();
 
-        let result =
-            handle_service_token(State(state), ConnectInfo(addr), headers, Json(payload)).await;
+        let result = handle_service_token(
+            State(state),
+            ConnectInfo(addr),
+            None,
+            headers,
+            Json(payload),
+        )
+        .await;
 
         // Should succeed even without User-Agent
         assert!(
@@ -12,14 +12,10 @@ use crate::observability::metrics::{record_error, record_token_issuance};
 use crate::observability::ErrorCategory;
 use crate::repositories::signing_keys;
 use axum::{extract::State, Extension, Json};
-use common::secret::ExposeSecret;
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::instrument;
 
-/// Maximum allowed TTL for meeting/guest tokens (15 minutes).
-const MAX_TOKEN_TTL_SECONDS: u32 = 900;
-
 /// Required scope for internal token endpoints.
 const REQUIRED_SCOPE: &str = "internal:meeting-token";
 
@@ -55,8 +51,8 @@ pub async fn handle_meeting_token(
         });
     }
 
-    // Validate TTL (max 15 minutes)
-    let ttl = payload.ttl_seconds.min(MAX_TOKEN_TTL_SECONDS);
+    // Cap the requested TTL (AC_MAX_TOKEN_TTL_SECONDS)
+    let ttl = state.config.token_ttls.meeting_ttl(payload.ttl_seconds);
 
     // Issue the meeting token
     let result = issue_meeting_token_internal(&state, &payload, ttl).await;
@@ -108,8 +104,8 @@ pub async fn handle_guest_token(
         });
     }
 
-    // Validate TTL (max 15 minutes)
-    let ttl = payload.ttl_seconds.min(MAX_TOKEN_TTL_SECONDS);
+    // Cap the requested TTL (AC_GUEST_TOKEN_TTL_SECONDS, AC_MAX_TOKEN_TTL_SECONDS)
+    let ttl = state.config.token_ttls.guest_ttl(payload.ttl_seconds);
 
     // Issue the guest token
     let result = issue_guest_token_internal(&state, &payload, ttl).await;
@@ -151,8 +147,7 @@ async fn issue_meeting_token_internal(
         tag: signing_key.encryption_tag,
     };
 
-    let private_key_pkcs8 =
-        crypto::decrypt_private_key(&encrypted_key, state.config.master_key.expose_secret())?;
+    let private_key_pkcs8 = state.master_key.decrypt_private_key(&encrypted_key)?;
 
     // Build meeting token claims
     let now = Utc::now().timestamp();
@@ -201,8 +196,7 @@ async fn issue_guest_token_internal(
         tag: signing_key.encryption_tag,
     };
 
-    let private_key_pkcs8 =
-        crypto::decrypt_private_key(&encrypted_key, state.config.master_key.expose_secret())?;
+    let private_key_pkcs8 = state.master_key.decrypt_private_key(&encrypted_key)?;
 
     // Build guest token claims
     let now = Utc::now().timestamp();
@@ -312,6 +306,7 @@ fn sign_guest_jwt(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::TokenTtlPolicy;
     use crate::models::{MeetingRole, ParticipantType};
     use uuid::Uuid;
 
@@ -415,8 +410,12 @@ mod tests {
     }
 
     #[test]
-    fn test_max_ttl_constant() {
-        assert_eq!(MAX_TOKEN_TTL_SECONDS, 900, "Max TTL should be 15 minutes");
+    fn test_max_ttl_default() {
+        assert_eq!(
+            TokenTtlPolicy::default().max_ttl_seconds,
+            3600,
+            "Max TTL should default to 1 hour"
+        );
     }
 
     #[test]
@@ -610,51 +609,55 @@ mod tests {
 
     // P1 Tests - TTL Capping
 
-    /// Test that TTL values are properly capped to MAX_TOKEN_TTL_SECONDS (900).
-    /// This tests the logic at line 59: `payload.ttl_seconds.min(MAX_TOKEN_TTL_SECONDS)`.
+    /// Test that requested TTLs are capped by `max_ttl_seconds` = 900
+    /// (and the default `guest_seconds` = 900).
     #[test]
     fn test_meeting_token_ttl_capping() {
-        // Test case 1: TTL above max should be capped
-        let requested_ttl: u32 = 3600; // 1 hour
-        let capped_ttl = requested_ttl.min(MAX_TOKEN_TTL_SECONDS);
-        assert_eq!(
-            capped_ttl, MAX_TOKEN_TTL_SECONDS,
-            "TTL of {} should be capped to {}",
-            requested_ttl, MAX_TOKEN_TTL_SECONDS
-        );
-
-        // Test case 2: TTL at max should remain unchanged
-        let requested_ttl_at_max: u32 = 900;
-        let capped_ttl_at_max = requested_ttl_at_max.min(MAX_TOKEN_TTL_SECONDS);
-        assert_eq!(capped_ttl_at_max, 900, "TTL at max should remain 900");
-
-        // Test case 3: TTL below max should remain unchanged
-        let requested_ttl_below: u32 = 300; // 5 minutes
-        let capped_ttl_below = requested_ttl_below.min(MAX_TOKEN_TTL_SECONDS);
-        assert_eq!(
-            capped_ttl_below, 300,
-            "TTL below max should remain unchanged"
-        );
+        let ttls = TokenTtlPolicy {
+            max_ttl_seconds: 900,
+            ..TokenTtlPolicy::default()
+        };
 
-        // Test case 4: TTL of 0 should remain 0
-        let requested_ttl_zero: u32 = 0;
-        let capped_ttl_zero = requested_ttl_zero.min(MAX_TOKEN_TTL_SECONDS);
-        assert_eq!(capped_ttl_zero, 0, "TTL of 0 should remain 0");
+        // (requested, expected)
+        let cases: [(u32, u32); 6] = [
+            (3600, 900),     // above max is capped
+            (900, 900),      // at max is unchanged
+            (300, 300),      // below max is unchanged
+            (0, 0),          // zero is unchanged
+            (901, 900),      // boundary is capped
+            (u32::MAX, 900), // very large is capped
+        ];
+        for (requested, expected) in cases {
+            assert_eq!(
+                ttls.meeting_ttl(requested),
+                expected,
+                "Meeting TTL of {} should be {}",
+                requested,
+                expected
+            );
+            assert_eq!(
+                ttls.guest_ttl(requested),
+                expected,
+                "Guest TTL of {} should be {}",
+                requested,
+                expected
+            );
+        }
+    }
 
-        // Test case 5: TTL at boundary (901) should be capped
-        let requested_ttl_boundary: u32 = 901;
-        let capped_ttl_boundary = requested_ttl_boundary.min(MAX_TOKEN_TTL_SECONDS);
-        assert_eq!(
-            capped_ttl_boundary, 900,
-            "TTL of 901 should be capped to 900"
-        );
+    /// Test that a configured guest lifetime caps guest tokens only, and
+    /// `max_ttl_seconds` caps both.
+    #[test]
+    fn test_configured_ttl_capping() {
+        let ttls = TokenTtlPolicy {
+            guest_seconds: 300,
+            max_ttl_seconds: 1800,
+            ..TokenTtlPolicy::default()
+        };
 
-        // Test case 6: Very large TTL should be capped
-        let requested_ttl_large: u32 = u32::MAX;
-        let capped_ttl_large = requested_ttl_large.min(MAX_TOKEN_TTL_SECONDS);
-        assert_eq!(
-            capped_ttl_large, 900,
-            "Very large TTL should be capped to 900"
-        );
+        assert_eq!(ttls.meeting_ttl(1200), 1200);
+        assert_eq!(ttls.meeting_ttl(3600), 1800);
+        assert_eq!(ttls.guest_ttl(1200), 300);
+        assert_eq!(ttls.guest_ttl(120), 120);
     }
 }
@@ -1,11 +1,14 @@
 use crate::errors::AcError;
-use crate::models::Jwks;
 use crate::observability::metrics::record_jwks_request;
+use crate::services::jwks_manager::{CacheStatus, RenderedJwks};
 use crate::services::key_management_service;
 use axum::{
     extract::State,
-    http::header::{HeaderMap, HeaderValue, CACHE_CONTROL},
-    Json,
+    http::{
+        header::{CACHE_CONTROL, CONTENT_TYPE, ETAG, IF_NONE_MATCH},
+        HeaderMap, HeaderValue, StatusCode,
+    },
+    response::{IntoResponse, Response},
 };
 use std::sync::Arc;
 use tracing::instrument;
@@ -17,33 +20,60 @@ use super::auth_handler::AppState;
 /// GET /.well-known/jwks.json
 ///
 /// Returns all active public keys in JWKS format (RFC 7517)
-/// with Cache-Control header set to max-age=3600 (1 hour)
+/// with Cache-Control header set to max-age=3600 (1 hour) and an ETag.
+/// A matching `If-None-Match` gets `304 Not Modified` with no body.
+///
+/// The document comes from the JWKS manager's in-memory cache; the
+/// database is only read when the cache is empty or the manager is down.
 ///
 /// ADR-0011: Handler instrumented with skip_all to prevent PII leakage.
-#[instrument(name = "ac.jwks.get", skip_all, fields(cache_status = "miss", status))]
+#[instrument(name = "ac.jwks.get", skip_all, fields(cache_status, status))]
 pub async fn handle_get_jwks(
     State(state): State<Arc<AppState>>,
-) -> Result<(HeaderMap, Json<Jwks>), AcError> {
-    // Note: Cache status is always "miss" at the handler level.
-    // Upstream caches (CDN, browser) handle caching based on Cache-Control.
-    let result = key_management_service::get_jwks(&state.pool).await;
+    headers: HeaderMap,
+) -> Result<Response, AcError> {
+    let result = lookup_jwks(&state).await;
 
     let status = if result.is_ok() { "success" } else { "error" };
     tracing::Span::current().record("status", status);
-    record_jwks_request("miss"); // Handler always fetches from DB
+    let (rendered, cache_status) = result?;
+    tracing::Span::current().record("cache_status", cache_status.as_str());
+    record_jwks_request(cache_status.as_str());
+
+    let mut response_headers = HeaderMap::new();
+    response_headers.insert(CACHE_CONTROL, HeaderValue::from_static("max-age=3600"));
+    if let Ok(etag) = HeaderValue::from_str(&rendered.etag) {
+        response_headers.insert(ETAG, etag);
+    }
+
+    let not_modified = headers
+        .get(IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| rendered.matches(value));
+    if not_modified {
+        return Ok((StatusCode::NOT_MODIFIED, response_headers).into_response());
+    }
 
-    let jwks = result?;
+    response_headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    Ok((response_headers, rendered.body.clone()).into_response())
+}
 
-    // Add Cache-Control header to allow caching for 1 hour
-    let mut headers = HeaderMap::new();
-    headers.insert(CACHE_CONTROL, HeaderValue::from_static("max-age=3600"));
+/// Get the JWKS document from the manager, or straight from the database
+/// if the manager has stopped.
+async fn lookup_jwks(state: &AppState) -> Result<(Arc<RenderedJwks>, CacheStatus), AcError> {
+    if !state.jwks.is_stopped() {
+        return state.jwks.get().await;
+    }
 
-    Ok((headers, Json(jwks)))
+    tracing::warn!(target: "ac.jwks", "JWKS manager stopped; reading keys from database");
+    let jwks = key_management_service::get_jwks(&state.pool).await?;
+    Ok((Arc::new(RenderedJwks::render(&jwks)?), CacheStatus::Bypass))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Jwks;
 
     #[test]
     fn test_jwks_serialization() {
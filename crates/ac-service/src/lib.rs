@@ -10,6 +10,8 @@
 //! - `errors` - Error types
 //! - `handlers` - HTTP request handlers
 //! - `models` - Data models
+//! - `mtls` - TLS listeners and client certificate authentication
+//! - `oidc` - OpenID Connect relying party for org SSO
 //! - `repositories` - Database access layer
 //! - `services` - Business logic layer
 
@@ -19,6 +21,8 @@ pub mod errors;
 pub mod handlers;
 pub mod middleware;
 pub mod models;
+pub mod mtls;
+pub mod oidc;
 pub mod observability;
 pub mod repositories;
 pub mod routes;
@@ -4,35 +4,53 @@ mod errors;
 mod handlers;
 mod middleware;
 mod models;
+mod mtls;
 mod observability;
 mod repositories;
 mod routes;
 mod services;
 
-use common::secret::ExposeSecret;
+use common::config::ConfigProfile;
+use common::listen::{bind_tcp_listeners, parse_bind_addresses, serve_unix};
 use config::Config;
 use handlers::auth_handler::AppState;
+use services::audit_writer::AuditWriterHandle;
 use services::key_management_service;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::signal;
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize tracing with JSON structured logging
-    // JSON format enables robust parsing in Promtail without brittle regex
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "ac_service=debug,tower_http=debug".into()),
-        )
-        .with(tracing_subscriber::fmt::layer().json())
-        .init();
+    // Trace export is part of the subscriber, so it starts before the rest
+    // of the configuration is loaded
+    let (trace_layer, otlp_guard, otlp_error) =
+        match config::otlp_endpoint_from_vars(&std::env::vars().collect()) {
+            Some(endpoint) => match common::otel::init_otlp(&endpoint, "ac-service") {
+                Ok((layer, guard)) => (Some(layer), Some(guard), None),
+                Err(e) => (None, None, Some(e)),
+            },
+            None => (None, None, None),
+        };
+
+    // Initialize tracing with JSON structured logging; the filter can be
+    // changed at runtime via /debug/log-level
+    let log_level =
+        common::log_level::init_tracing_with("ac_service=debug,tower_http=debug", trace_layer);
+    if let Some(e) = otlp_error {
+        warn!("Trace export disabled: {}", e);
+    }
 
-    info!("Starting Auth Controller");
+    let build_info = common::build_info!("ac-service");
+    info!(
+        version = build_info.version,
+        git_sha = build_info.git_sha,
+        "Starting Auth Controller"
+    );
 
     // Initialize Prometheus metrics recorder (ADR-0011)
     // Must be done before any metrics are recorded
@@ -47,6 +65,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         error!("Failed to load configuration: {}", e);
         e
     })?;
+    let profile = ConfigProfile::from_env().map_err(|e| {
+        error!("Failed to load configuration: {}", e);
+        e
+    })?;
 
     // Log whether clock skew was explicitly configured or using default
     let is_default_clock_skew =
@@ -54,6 +76,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!(
         jwt_clock_skew_seconds = config.jwt_clock_skew_seconds,
         is_default = is_default_clock_skew,
+        profile = %profile,
         "Configuration loaded successfully"
     );
 
@@ -93,21 +116,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     info!("Database connection established");
 
+    // Audit events are written in batches by a background task
+    let audit_writer = AuditWriterHandle::spawn(db_pool.clone());
+    audit_writer.clone().install();
+
     // Initialize signing key if none exists
     info!("Initializing signing keys...");
     let cluster_name = std::env::var("CLUSTER_NAME").unwrap_or_else(|_| "us".to_string());
 
-    key_management_service::initialize_signing_key(
-        &db_pool,
-        config.master_key.expose_secret(),
-        &cluster_name,
-    )
-    .await
-    .map_err(|e| {
-        error!("Failed to initialize signing key: {}", e);
+    // Unwrap the master key before touching signing keys
+    let master_key = crypto::master_key::load(&config).await.map_err(|e| {
+        error!("Failed to load master key: {}", e);
         e
     })?;
 
+    key_management_service::initialize_signing_key(&db_pool, master_key.as_ref(), &cluster_name)
+        .await
+        .map_err(|e| {
+            error!("Failed to initialize signing key: {}", e);
+            e
+        })?;
+
     info!("Signing keys initialized");
 
     // Initialize key management metric gauges from current DB state
@@ -120,33 +149,109 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let bind_address = config.bind_address.clone();
 
     // Create application state
-    let state = Arc::new(AppState {
-        pool: db_pool,
-        config,
-    });
+    let state = Arc::new(AppState::with_master_key(db_pool, config, master_key));
+
+    // Warm the JWKS cache so the first requests do not hit the database
+    if let Err(e) = state.jwks.refresh().await {
+        warn!("Failed to warm JWKS cache: {}", e);
+    }
 
     // Build application routes with HTTP request timeout
     // ADR-0012: 30s request timeout to prevent hung connections
     // ADR-0011: metrics_handle enables /metrics endpoint for Prometheus scraping
-    let app = routes::build_routes(state, metrics_handle);
+    let app = routes::build_routes(state.clone(), metrics_handle.clone())
+        .merge(routes::build_log_level_routes(state.clone(), log_level));
 
-    // Parse bind address
-    let addr: SocketAddr = bind_address.parse().map_err(|e| {
+    // Parse bind addresses
+    let addrs = parse_bind_addresses(&bind_address).map_err(|e| {
         error!("Invalid bind address: {}", e);
         e
     })?;
 
-    info!("Auth Controller listening on {}", addr);
+    let listeners = bind_tcp_listeners(&addrs)?;
+    info!("Auth Controller listening on {:?}", addrs);
 
-    // Start server with graceful shutdown support
-    // ADR-0012: 30s graceful shutdown drain period
-    let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(
-        listener,
-        app.into_make_service_with_connect_info::<SocketAddr>(),
-    )
-    .with_graceful_shutdown(shutdown_signal())
-    .await?;
+    // With mTLS enabled the listeners serve HTTPS and pass client certificates
+    // to the handlers
+    let tls_acceptor = if state.config.mtls.mode.is_enabled() {
+        let acceptor = mtls::tls_acceptor(&state.config.mtls).map_err(|e| {
+            error!("Failed to load TLS certificate: {}", e);
+            e
+        })?;
+        info!(
+            mtls_mode = ?state.config.mtls.mode,
+            "Serving HTTPS with client certificate authentication"
+        );
+        Some(acceptor)
+    } else {
+        None
+    };
+
+    // Start one server per listener; all stop accepting together on shutdown
+    let stop_accepting = CancellationToken::new();
+    let mut servers = JoinSet::new();
+    for listener in listeners {
+        if let Some(acceptor) = &tls_acceptor {
+            let server = mtls::serve_tls(
+                listener,
+                app.clone(),
+                acceptor.clone(),
+                stop_accepting.clone().cancelled_owned(),
+            );
+            servers.spawn(async move {
+                server.await;
+                Ok(())
+            });
+            continue;
+        }
+        let server = axum::serve(
+            listener,
+            app.clone()
+                .into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(stop_accepting.clone().cancelled_owned());
+        servers.spawn(async move { server.await });
+    }
+
+    if let Some(socket) = &state.config.health_socket {
+        // Health and metrics also on a Unix socket (sidecar scraping)
+        let ops_app = routes::build_ops_routes(state.clone(), metrics_handle);
+        let ops_server = serve_unix(socket, ops_app, stop_accepting.clone().cancelled_owned())
+            .map_err(|e| {
+                error!("Failed to bind health socket: {}", e);
+                e
+            })?;
+        info!(
+            "Auth Controller health socket listening on {}",
+            socket.path.display()
+        );
+        servers.spawn(async move {
+            ops_server.await;
+            Ok(())
+        });
+    }
+
+    // ADR-0012: 30s graceful shutdown drain period (inside shutdown_signal)
+    tokio::select! {
+        () = shutdown_signal(profile.drain_seconds()) => {}
+        Some(result) = servers.join_next() => result??,
+    }
+    stop_accepting.cancel();
+    while let Some(result) = servers.join_next().await {
+        result??;
+    }
+
+    // Write audit events still queued from the final requests
+    if let Err(e) = audit_writer.flush().await {
+        warn!("Failed to flush audit events: {}", e);
+    }
+
+    // Export the spans of the final requests
+    if let Some(guard) = otlp_guard {
+        if let Err(e) = tokio::task::spawn_blocking(move || guard.shutdown()).await {
+            warn!("Failed to flush trace export: {}", e);
+        }
+    }
 
     info!("Auth Controller shutdown complete");
 
@@ -156,8 +261,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// Listens for shutdown signals (SIGTERM, SIGINT)
 /// Returns when a shutdown signal is received and drain period is complete
 ///
-/// ADR-0012: Graceful shutdown with 30s drain period
-async fn shutdown_signal() {
+/// ADR-0012: Graceful shutdown with 30s drain period (`default_drain_secs`
+/// comes from the config profile; `AC_DRAIN_SECONDS` overrides it)
+async fn shutdown_signal(default_drain_secs: u64) {
     let ctrl_c = async {
         match signal::ctrl_c().await {
             Ok(()) => info!("Received SIGINT, starting graceful shutdown..."),
@@ -197,11 +303,11 @@ async fn shutdown_signal() {
     // - Existing connections are allowed to complete
     // - K8s removes us from service endpoints (readiness probe fails after SIGTERM)
     //
-    // For local development, use AC_DRAIN_SECONDS=0 to exit immediately
+    // For local development, DT_PROFILE=dev (or AC_DRAIN_SECONDS=0) exits immediately
     let drain_secs: u64 = std::env::var("AC_DRAIN_SECONDS")
         .ok()
         .and_then(|s| s.parse().ok())
-        .unwrap_or(30);
+        .unwrap_or(default_drain_secs);
 
     if drain_secs > 0 {
         warn!("Draining connections for {} seconds...", drain_secs);
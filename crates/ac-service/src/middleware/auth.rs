@@ -1,11 +1,13 @@
-use crate::crypto;
+use crate::crypto::{self, Claims};
 use crate::errors::AcError;
 use crate::repositories::signing_keys;
 use axum::{
     extract::{Request, State},
+    http::HeaderMap,
     middleware::Next,
     response::IntoResponse,
 };
+use common::debug_auth::DEBUG_SCOPE;
 use sqlx::PgPool;
 use std::sync::Arc;
 
@@ -70,9 +72,54 @@ pub async fn require_admin_scope(
     mut req: Request,
     next: Next,
 ) -> Result<impl IntoResponse, AcError> {
+    let claims = authorize_scope(&state, req.headers(), "admin:services").await?;
+
+    // Store claims in request extensions for downstream handlers
+    req.extensions_mut().insert(claims);
+
+    // Continue to next handler
+    Ok(next.run(req).await)
+}
+
+/// Authentication middleware for the `/debug/*` endpoints.
+///
+/// Same checks as [`require_admin_scope`], but requires the
+/// `admin:debug` scope so on-call tooling does not need client admin.
+pub async fn require_debug_scope(
+    State(state): State<Arc<AuthMiddlewareState>>,
+    req: Request,
+    next: Next,
+) -> Result<impl IntoResponse, AcError> {
+    authorize_scope(&state, req.headers(), DEBUG_SCOPE).await?;
+    Ok(next.run(req).await)
+}
+
+/// Scope required for the signing key replication endpoints.
+pub const KEY_REPLICATION_SCOPE: &str = "admin.replicate-keys.ac";
+
+/// Authentication middleware for `/internal/keys/*`.
+///
+/// Same checks as [`require_admin_scope`], but requires
+/// [`KEY_REPLICATION_SCOPE`]: exporting keys hands out signing material, so
+/// client admins do not get it by default.
+pub async fn require_key_replication_scope(
+    State(state): State<Arc<AuthMiddlewareState>>,
+    mut req: Request,
+    next: Next,
+) -> Result<impl IntoResponse, AcError> {
+    let claims = authorize_scope(&state, req.headers(), KEY_REPLICATION_SCOPE).await?;
+    req.extensions_mut().insert(claims);
+    Ok(next.run(req).await)
+}
+
+/// Verify the request's Bearer token and require `required_scope`.
+async fn authorize_scope(
+    state: &AuthMiddlewareState,
+    headers: &HeaderMap,
+    required_scope: &str,
+) -> Result<Claims, AcError> {
     // Extract Authorization header
-    let auth_header = req
-        .headers()
+    let auth_header = headers
         .get("authorization")
         .and_then(|h| h.to_str().ok())
         .ok_or(AcError::InvalidToken(
@@ -98,8 +145,7 @@ pub async fn require_admin_scope(
         std::time::Duration::from_secs(state.jwt_clock_skew_seconds as u64),
     )?;
 
-    // Check if token has required scope (admin:services)
-    let required_scope = "admin:services";
+    // Check if token has the required scope
     let token_scopes: Vec<&str> = claims.scope.split_whitespace().collect();
 
     if !token_scopes.contains(&required_scope) {
@@ -109,9 +155,5 @@ pub async fn require_admin_scope(
         });
     }
 
-    // Store claims in request extensions for downstream handlers
-    req.extensions_mut().insert(claims);
-
-    // Continue to next handler
-    Ok(next.run(req).await)
+    Ok(claims)
 }
@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use common::jwt::{Actor, Confirmation};
 use common::secret::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize, Serializer};
 use sqlx::FromRow;
@@ -31,7 +32,6 @@ pub struct InternalTokenResponse {
 #[derive(Debug, Clone, FromRow)]
 pub struct ServiceCredential {
     pub credential_id: Uuid,
-    #[allow(dead_code)] // Will be used in Phase 4 admin endpoints
     pub client_id: String,
     pub client_secret_hash: String,
     pub service_type: String,
@@ -43,6 +43,8 @@ pub struct ServiceCredential {
     pub created_at: DateTime<Utc>,
     #[allow(dead_code)] // Will be used in Phase 4 admin endpoints
     pub updated_at: DateTime<Utc>,
+    /// Hex SHA-256 of the pinned client certificate's SubjectPublicKeyInfo
+    pub client_cert_spki_sha256: Option<String>,
 }
 
 /// Signing key model (maps to signing_keys table)
@@ -59,38 +61,25 @@ pub struct SigningKey {
     pub master_key_version: i32,
     #[allow(dead_code)] // Will be used in Phase 4 admin endpoints
     pub algorithm: String,
-    #[allow(dead_code)] // Will be used in Phase 4 admin endpoints
     pub is_active: bool,
-    #[allow(dead_code)] // Will be used in Phase 4 admin endpoints
     pub valid_from: DateTime<Utc>,
-    #[allow(dead_code)] // Will be used in Phase 4 admin endpoints
     pub valid_until: DateTime<Utc>,
     #[allow(dead_code)] // Will be used in Phase 4 admin endpoints
     pub created_at: DateTime<Utc>,
 }
 
 /// Auth event model (maps to auth_events table)
-#[derive(Debug, Clone, FromRow)]
+#[derive(Debug, Clone, FromRow, Serialize)]
 pub struct AuthEvent {
-    #[allow(dead_code)] // Will be used in Phase 4 audit endpoints
     pub event_id: Uuid,
-    #[allow(dead_code)] // Will be used in Phase 4 audit endpoints
     pub event_type: String,
-    #[allow(dead_code)] // Will be used in Phase 4 user auth
     pub user_id: Option<Uuid>,
-    #[allow(dead_code)] // Will be used in Phase 4 audit endpoints
     pub credential_id: Option<Uuid>,
-    #[allow(dead_code)] // Will be used in Phase 4 audit endpoints
     pub success: bool,
-    #[allow(dead_code)] // Will be used in Phase 4 audit endpoints
     pub failure_reason: Option<String>,
-    #[allow(dead_code)] // Will be used in Phase 4 audit endpoints
     pub ip_address: Option<String>,
-    #[allow(dead_code)] // Will be used in Phase 4 audit endpoints
     pub user_agent: Option<String>,
-    #[allow(dead_code)] // Will be used in Phase 4 audit endpoints
     pub metadata: Option<serde_json::Value>,
-    #[allow(dead_code)] // Will be used in Phase 4 audit endpoints
     pub created_at: DateTime<Utc>,
 }
 
@@ -101,6 +90,43 @@ pub struct TokenResponse {
     pub token_type: String,
     pub expires_in: u64,
     pub scope: String,
+    /// Present only when the client opted in (`issue_refresh_token`) or
+    /// redeemed a refresh token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// Type of the issued token; present only for token exchange
+    /// (RFC 8693 Section 2.2.1).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issued_token_type: Option<String>,
+}
+
+/// Token introspection response (RFC 7662 Section 2.2).
+///
+/// Inactive tokens carry only `"active": false`; why a token is inactive is
+/// never disclosed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntrospectionResponse {
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    /// Key a DPoP-bound token is bound to (RFC 9449 Section 6.2).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cnf: Option<Confirmation>,
+    /// Service acting for the subject of a delegated token
+    /// (RFC 8693 Section 4.1).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub act: Option<Actor>,
+}
+
+impl IntrospectionResponse {
+    /// Response for a token that is not active.
+    pub fn inactive() -> Self {
+        Self::default()
+    }
 }
 
 /// Service registration response.
@@ -176,6 +202,46 @@ pub struct JsonWebKey {
     pub alg: String, // Algorithm (e.g., "EdDSA")
 }
 
+/// A signing key in transit between regions (`/internal/keys/export` and
+/// `/internal/keys/import`).
+///
+/// The private key is encrypted with the shared replication key
+/// (`AC_KEY_REPLICATION_KEY`), not either region's master key; byte fields
+/// are standard base64. Debug redacts the key material.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ReplicatedSigningKey {
+    pub key_id: String,
+    /// PEM public key, as published in the JWKS
+    pub public_key: String,
+    pub private_key_encrypted: String,
+    pub nonce: String,
+    pub tag: String,
+    pub is_active: bool,
+    pub valid_from: DateTime<Utc>,
+    pub valid_until: DateTime<Utc>,
+}
+
+impl fmt::Debug for ReplicatedSigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReplicatedSigningKey")
+            .field("key_id", &self.key_id)
+            .field("public_key", &self.public_key)
+            .field("private_key_encrypted", &"[REDACTED]")
+            .field("nonce", &"[REDACTED]")
+            .field("tag", &"[REDACTED]")
+            .field("is_active", &self.is_active)
+            .field("valid_from", &self.valid_from)
+            .field("valid_until", &self.valid_until)
+            .finish()
+    }
+}
+
+/// Signing key export, accepted as is by the import endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SigningKeyExport {
+    pub keys: Vec<ReplicatedSigningKey>,
+}
+
 /// Service type enum
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -195,6 +261,7 @@ impl ServiceType {
             ServiceType::GlobalController => vec![
                 "service.write.mc".to_string(),
                 "internal:meeting-token".to_string(),
+                "delegated:meeting.moderate".to_string(),
             ],
             ServiceType::MeetingController => vec![
                 "service.write.mh".to_string(),
@@ -234,19 +301,14 @@ impl FromStr for ServiceType {
 /// Auth event type enum
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AuthEventType {
-    #[allow(dead_code)] // Will be used in Phase 4 user auth
     UserLogin,
-    #[allow(dead_code)] // Will be used in Phase 4 user auth
     UserLoginFailed,
     ServiceTokenIssued,
     ServiceTokenFailed,
     ServiceRegistered,
     KeyGenerated,
-    #[allow(dead_code)] // Will be used in Phase 4 key rotation
     KeyRotated,
-    #[allow(dead_code)] // Will be used in Phase 4 key rotation
     KeyExpired,
-    #[allow(dead_code)] // Will be used in Phase 4 token validation
     TokenValidationFailed,
     #[allow(dead_code)] // Will be used in Phase 4 rate limiting
     RateLimitExceeded,
@@ -269,6 +331,93 @@ impl AuthEventType {
     }
 }
 
+impl FromStr for AuthEventType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "user_login" => Ok(AuthEventType::UserLogin),
+            "user_login_failed" => Ok(AuthEventType::UserLoginFailed),
+            "service_token_issued" => Ok(AuthEventType::ServiceTokenIssued),
+            "service_token_failed" => Ok(AuthEventType::ServiceTokenFailed),
+            "service_registered" => Ok(AuthEventType::ServiceRegistered),
+            "key_generated" => Ok(AuthEventType::KeyGenerated),
+            "key_rotated" => Ok(AuthEventType::KeyRotated),
+            "key_expired" => Ok(AuthEventType::KeyExpired),
+            "token_validation_failed" => Ok(AuthEventType::TokenValidationFailed),
+            "rate_limit_exceeded" => Ok(AuthEventType::RateLimitExceeded),
+            _ => Err(format!("Invalid event type: {}", s)),
+        }
+    }
+}
+
+/// An auth event waiting to be written to the auth_events table.
+#[derive(Debug, Clone)]
+pub struct NewAuthEvent {
+    pub event_type: AuthEventType,
+    pub user_id: Option<Uuid>,
+    pub credential_id: Option<Uuid>,
+    pub success: bool,
+    pub failure_reason: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+impl NewAuthEvent {
+    /// A successful event with no subject or client details.
+    pub fn success(event_type: AuthEventType) -> Self {
+        Self {
+            event_type,
+            user_id: None,
+            credential_id: None,
+            success: true,
+            failure_reason: None,
+            ip_address: None,
+            user_agent: None,
+            metadata: None,
+        }
+    }
+
+    /// A failed event with no subject or client details.
+    pub fn failure(event_type: AuthEventType, reason: &str) -> Self {
+        Self {
+            success: false,
+            failure_reason: Some(reason.to_string()),
+            ..Self::success(event_type)
+        }
+    }
+
+    pub fn user(mut self, user_id: Option<Uuid>) -> Self {
+        self.user_id = user_id;
+        self
+    }
+
+    pub fn credential(mut self, credential_id: Option<Uuid>) -> Self {
+        self.credential_id = credential_id;
+        self
+    }
+
+    /// Caller IP address and user agent.
+    pub fn client(mut self, ip_address: Option<&str>, user_agent: Option<&str>) -> Self {
+        self.ip_address = ip_address.map(str::to_string);
+        self.user_agent = user_agent.map(str::to_string);
+        self
+    }
+
+    pub fn metadata(mut self, metadata: serde_json::Value) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Whether the lockout checks in `token_service` count this event.
+    ///
+    /// They count failed events by user or credential.
+    pub fn counts_toward_lockout(&self) -> bool {
+        !self.success && (self.user_id.is_some() || self.credential_id.is_some())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -306,6 +455,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_scope_contract_gc_can_delegate_moderation() {
+        let gc_scopes = ServiceType::GlobalController.default_scopes();
+        assert!(
+            gc_scopes.contains(&"delegated:meeting.moderate".to_string()),
+            "GC must have delegated:meeting.moderate to exchange user tokens for moderation"
+        );
+        // Only GC acts on behalf of users
+        for service_type in [ServiceType::MeetingController, ServiceType::MediaHandler] {
+            assert!(!service_type
+                .default_scopes()
+                .iter()
+                .any(|scope| scope.starts_with(common::jwt::DELEGATED_SCOPE_PREFIX)));
+        }
+    }
+
     #[test]
     fn test_scope_contract_mh_can_call_mc() {
         let mh_scopes = ServiceType::MediaHandler.default_scopes();
@@ -359,6 +524,43 @@ mod tests {
         assert!(ServiceType::from_str("invalid").is_err());
     }
 
+    #[test]
+    fn test_auth_event_type_round_trip() {
+        for event_type in [
+            AuthEventType::UserLogin,
+            AuthEventType::UserLoginFailed,
+            AuthEventType::ServiceTokenIssued,
+            AuthEventType::ServiceTokenFailed,
+            AuthEventType::ServiceRegistered,
+            AuthEventType::KeyGenerated,
+            AuthEventType::KeyRotated,
+            AuthEventType::KeyExpired,
+            AuthEventType::TokenValidationFailed,
+            AuthEventType::RateLimitExceeded,
+        ] {
+            assert_eq!(AuthEventType::from_str(event_type.as_str()), Ok(event_type));
+        }
+        assert!(AuthEventType::from_str("scopes_updated").is_err());
+    }
+
+    #[test]
+    fn test_new_auth_event_counts_toward_lockout() {
+        let credential_id = Some(Uuid::new_v4());
+
+        let failed =
+            NewAuthEvent::failure(AuthEventType::ServiceTokenFailed, "Invalid client secret")
+                .credential(credential_id);
+        assert!(failed.counts_toward_lockout());
+
+        let issued =
+            NewAuthEvent::success(AuthEventType::ServiceTokenIssued).credential(credential_id);
+        assert!(!issued.counts_toward_lockout());
+
+        let anonymous =
+            NewAuthEvent::failure(AuthEventType::TokenValidationFailed, "Invalid token");
+        assert!(!anonymous.counts_toward_lockout());
+    }
+
     // ============================================================================
     // Internal Token Types Tests (ADR-0020)
     // ============================================================================
@@ -0,0 +1,406 @@
+//! TLS listeners with client certificate (mTLS) authentication.
+//!
+//! With `AC_MTLS_MODE=optional` or `required` the TCP listeners serve HTTPS
+//! and ask clients for a certificate. Certificates are not chained to a CA:
+//! a service credential pins the SHA-256 of its certificate's
+//! SubjectPublicKeyInfo (`service_credentials.client_cert_spki_sha256`), and
+//! the TLS handshake proves the client holds the matching private key. The
+//! pin survives certificate renewal as long as the key is kept.
+//!
+//! Presenting a certificate is never required at the TLS layer, so probes,
+//! JWKS fetches, and user endpoints keep working without one. Whether the
+//! service token endpoint accepts a request without a certificate is decided
+//! by [`crate::services::token_service::ServiceClientAuth`].
+
+use crate::config::MtlsConfig;
+use axum::extract::ConnectInfo;
+use axum::Router;
+use hyper::body::Incoming;
+use hyper::server::conn::http1;
+use hyper::Request;
+use hyper_util::rt::TokioIo;
+use rustls::client::danger::HandshakeSignatureValid;
+use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, WebPkiSupportedAlgorithms};
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, UnixTime};
+use rustls::server::danger::{ClientCertVerified, ClientCertVerifier};
+use rustls::{
+    CertificateError, DigitallySignedStruct, DistinguishedName, ServerConfig, SignatureScheme,
+};
+use sha2::{Digest, Sha256};
+use std::future::Future;
+use std::io;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+use tower::Service;
+use x509_parser::time::ASN1Time;
+
+/// How long a client has to complete the TLS handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The TLS client certificate of a request, added as a request extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientCertificate {
+    /// Lowercase hex SHA-256 of the certificate's SubjectPublicKeyInfo.
+    pub spki_sha256: String,
+}
+
+/// Lowercase hex SHA-256 of the SubjectPublicKeyInfo of a DER certificate.
+///
+/// Returns `None` if the certificate cannot be parsed.
+pub fn spki_fingerprint(cert_der: &[u8]) -> Option<String> {
+    let (_, cert) = x509_parser::parse_x509_certificate(cert_der).ok()?;
+    Some(hex::encode(Sha256::digest(cert.public_key().raw)))
+}
+
+/// Normalize a fingerprint entered by an operator.
+///
+/// Accepts upper or lower case hex, optionally colon-separated as printed by
+/// `openssl`. Returns `None` unless the result is 64 hex characters.
+pub fn normalize_fingerprint(value: &str) -> Option<String> {
+    let normalized: String = value
+        .trim()
+        .chars()
+        .filter(|c| *c != ':')
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+
+    (normalized.len() == 64 && normalized.chars().all(|c| c.is_ascii_hexdigit()))
+        .then_some(normalized)
+}
+
+/// Accepts any well-formed, currently valid client certificate.
+///
+/// The certificate is authenticated by its pinned fingerprint, not a CA, so
+/// there is no chain to build. Handshake signatures are still verified, which
+/// is what proves possession of the key.
+#[derive(Debug)]
+struct PinnedClientCertVerifier {
+    algorithms: WebPkiSupportedAlgorithms,
+}
+
+impl ClientCertVerifier for PinnedClientCertVerifier {
+    fn offer_client_auth(&self) -> bool {
+        true
+    }
+
+    fn client_auth_mandatory(&self) -> bool {
+        false
+    }
+
+    fn root_hint_subjects(&self) -> &[DistinguishedName] {
+        &[]
+    }
+
+    fn verify_client_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        now: UnixTime,
+    ) -> Result<ClientCertVerified, rustls::Error> {
+        let (_, cert) = x509_parser::parse_x509_certificate(end_entity.as_ref())
+            .map_err(|_| rustls::Error::InvalidCertificate(CertificateError::BadEncoding))?;
+
+        let now = i64::try_from(now.as_secs())
+            .ok()
+            .and_then(|secs| ASN1Time::from_timestamp(secs).ok())
+            .ok_or_else(|| rustls::Error::General("system time out of range".to_string()))?;
+        let validity = cert.validity();
+        if now < validity.not_before {
+            return Err(rustls::Error::InvalidCertificate(
+                CertificateError::NotValidYet,
+            ));
+        }
+        if now > validity.not_after {
+            return Err(rustls::Error::InvalidCertificate(CertificateError::Expired));
+        }
+
+        Ok(ClientCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.algorithms.supported_schemes()
+    }
+}
+
+/// Build the TLS acceptor for `config`.
+///
+/// # Errors
+///
+/// Returns an error naming the file if the certificate or key cannot be
+/// read, or if they do not form a usable server identity.
+pub fn tls_acceptor(config: &MtlsConfig) -> io::Result<TlsAcceptor> {
+    let (Some(cert_path), Some(key_path)) = (&config.cert_path, &config.key_path) else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "AC_TLS_CERT_PATH and AC_TLS_KEY_PATH are required for mTLS",
+        ));
+    };
+    let certs = load_certs(cert_path)?;
+    let key = load_key(key_path)?;
+
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let verifier = Arc::new(PinnedClientCertVerifier {
+        algorithms: provider.signature_verification_algorithms,
+    });
+    let mut server_config = ServerConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .and_then(|builder| {
+            builder
+                .with_client_cert_verifier(verifier)
+                .with_single_cert(certs, key)
+        })
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    server_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+    Ok(TlsAcceptor::from(Arc::new(server_config)))
+}
+
+fn load_certs(path: &Path) -> io::Result<Vec<CertificateDer<'static>>> {
+    let pem = std::fs::read(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+    let certs = rustls_pemfile::certs(&mut pem.as_slice()).collect::<io::Result<Vec<_>>>()?;
+    if certs.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: no certificates found", path.display()),
+        ));
+    }
+    Ok(certs)
+}
+
+fn load_key(path: &Path) -> io::Result<PrivateKeyDer<'static>> {
+    let pem = std::fs::read(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+    rustls_pemfile::private_key(&mut pem.as_slice())?.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: no private key found", path.display()),
+        )
+    })
+}
+
+/// Serve `router` over HTTPS on `listener` until `shutdown` resolves.
+///
+/// Like `axum::serve` with `into_make_service_with_connect_info`, each
+/// request carries a [`ConnectInfo<SocketAddr>`] extension; requests on a
+/// connection that presented a client certificate also carry a
+/// [`ClientCertificate`].
+pub fn serve_tls<F>(
+    listener: TcpListener,
+    router: Router,
+    acceptor: TlsAcceptor,
+    shutdown: F,
+) -> impl Future<Output = ()> + Send + 'static
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    async move {
+        tokio::pin!(shutdown);
+        loop {
+            tokio::select! {
+                () = &mut shutdown => break,
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, peer)) => {
+                        tokio::spawn(serve_connection(stream, peer, router.clone(), acceptor.clone()));
+                    }
+                    Err(e) => {
+                        // Back off so fd exhaustion does not turn into a busy loop
+                        tracing::warn!(error = %e, "TLS listener accept failed");
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn serve_connection(
+    stream: tokio::net::TcpStream,
+    peer: SocketAddr,
+    router: Router,
+    acceptor: TlsAcceptor,
+) {
+    let stream = match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(e)) => {
+            tracing::debug!(error = %e, "TLS handshake failed");
+            return;
+        }
+        Err(_) => {
+            tracing::debug!("TLS handshake timed out");
+            return;
+        }
+    };
+
+    let client_cert = stream
+        .get_ref()
+        .1
+        .peer_certificates()
+        .and_then(|certs| certs.first())
+        .and_then(|cert| spki_fingerprint(cert.as_ref()))
+        .map(|spki_sha256| ClientCertificate { spki_sha256 });
+
+    let service = hyper::service::service_fn(move |mut req: Request<Incoming>| {
+        req.extensions_mut().insert(ConnectInfo(peer));
+        if let Some(cert) = &client_cert {
+            req.extensions_mut().insert(cert.clone());
+        }
+        // Router is always ready, so poll_ready can be skipped
+        router.clone().call(req)
+    });
+
+    if let Err(e) = http1::Builder::new()
+        .serve_connection(TokioIo::new(stream), service)
+        .await
+    {
+        tracing::debug!(error = %e, "TLS connection closed with error");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::get;
+    use axum::Extension;
+
+    fn write_identity(dir: &Path, name: &str) -> (rcgen::CertifiedKey, MtlsConfig) {
+        let identity = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let cert_path = dir.join(format!("{name}.crt"));
+        let key_path = dir.join(format!("{name}.key"));
+        std::fs::write(&cert_path, identity.cert.pem()).unwrap();
+        std::fs::write(&key_path, identity.key_pair.serialize_pem()).unwrap();
+
+        let config = MtlsConfig {
+            mode: crate::config::MtlsMode::Optional,
+            cert_path: Some(cert_path),
+            key_path: Some(key_path),
+        };
+        (identity, config)
+    }
+
+    #[test]
+    fn test_spki_fingerprint_matches_public_key() {
+        let identity = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+
+        let fingerprint = spki_fingerprint(identity.cert.der()).unwrap();
+        assert_eq!(
+            fingerprint,
+            hex::encode(Sha256::digest(identity.key_pair.public_key_der()))
+        );
+        assert!(spki_fingerprint(b"not a certificate").is_none());
+    }
+
+    #[test]
+    fn test_normalize_fingerprint() {
+        let hex = "ab".repeat(32);
+        let colons = vec!["AB"; 32].join(":");
+
+        assert_eq!(normalize_fingerprint(&hex), Some(hex.clone()));
+        assert_eq!(normalize_fingerprint(&colons), Some(hex.clone()));
+        assert_eq!(normalize_fingerprint(&format!(" {hex}\n")), Some(hex));
+        assert_eq!(normalize_fingerprint("abcd"), None);
+        assert_eq!(normalize_fingerprint(&"zz".repeat(32)), None);
+    }
+
+    #[test]
+    fn test_tls_acceptor_rejects_missing_files() {
+        let config = MtlsConfig {
+            mode: crate::config::MtlsMode::Required,
+            cert_path: Some("/nonexistent/tls.crt".into()),
+            key_path: Some("/nonexistent/tls.key".into()),
+        };
+        let err = tls_acceptor(&config).unwrap_err();
+        assert!(err.to_string().contains("/nonexistent/tls.crt"));
+
+        assert!(tls_acceptor(&MtlsConfig::default()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_serve_tls_passes_client_certificate() {
+        let dir = std::env::temp_dir().join(format!("ac-mtls-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (_server, config) = write_identity(&dir, "server");
+        let (client, _) = write_identity(&dir, "client");
+
+        let router = Router::new().route(
+            "/whoami",
+            get(
+                |ConnectInfo(peer): ConnectInfo<SocketAddr>,
+                 cert: Option<Extension<ClientCertificate>>| async move {
+                    let cert = cert.map_or("none".to_string(), |Extension(c)| c.spki_sha256);
+                    format!("{} {}", peer.ip(), cert)
+                },
+            ),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let stop = tokio_util::sync::CancellationToken::new();
+        tokio::spawn(serve_tls(
+            listener,
+            router,
+            tls_acceptor(&config).unwrap(),
+            stop.clone().cancelled_owned(),
+        ));
+
+        let url = format!("https://{}/whoami", addr);
+
+        // The server certificate is self-signed; only the client side matters here
+        let anonymous = reqwest::Client::builder()
+            .danger_accept_invalid_certs(true)
+            .build()
+            .unwrap();
+        let body = anonymous
+            .get(&url)
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert_eq!(body, "127.0.0.1 none");
+
+        let pem = format!("{}{}", client.cert.pem(), client.key_pair.serialize_pem());
+        let with_cert = reqwest::Client::builder()
+            .danger_accept_invalid_certs(true)
+            .identity(reqwest::Identity::from_pem(pem.as_bytes()).unwrap())
+            .build()
+            .unwrap();
+        let body = with_cert
+            .get(&url)
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert_eq!(
+            body,
+            format!("127.0.0.1 {}", spki_fingerprint(client.cert.der()).unwrap())
+        );
+
+        stop.cancel();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
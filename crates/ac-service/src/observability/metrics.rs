@@ -22,14 +22,30 @@ use std::time::Duration;
 /// for serving metrics via HTTP.
 ///
 /// ADR-0011: Must be called before any metrics are recorded.
+/// Bucket configuration comes from [`metrics_builder`].
+///
+/// # Errors
+///
+/// Returns error if Prometheus recorder fails to install (e.g., already installed).
+pub fn init_metrics_recorder() -> Result<PrometheusHandle, String> {
+    metrics_builder()?
+        .install_recorder()
+        .map_err(|e| format!("Failed to install Prometheus recorder: {}", e))
+}
+
+/// Prometheus builder with AC's histogram buckets, not yet installed.
+///
 /// Configures histogram buckets aligned with SLO targets:
 /// - Token issuance p99 < 350ms
 /// - DB queries p99 < 50ms
 ///
+/// Tests pass this to `PrometheusCapture::with_builder` to assert on
+/// rendered output with production buckets.
+///
 /// # Errors
 ///
-/// Returns error if Prometheus recorder fails to install (e.g., already installed).
-pub fn init_metrics_recorder() -> Result<PrometheusHandle, String> {
+/// Returns error if a bucket configuration is rejected.
+pub fn metrics_builder() -> Result<PrometheusBuilder, String> {
     PrometheusBuilder::new()
         // Token issuance buckets aligned with 350ms SLO target
         .set_buckets_for_metric(
@@ -62,9 +78,7 @@ pub fn init_metrics_recorder() -> Result<PrometheusHandle, String> {
                 0.005, 0.010, 0.025, 0.050, 0.100, 0.250, 0.500, 1.000, 2.500, 5.000, 10.000,
             ],
         )
-        .map_err(|e| format!("Failed to set HTTP request buckets: {}", e))?
-        .install_recorder()
-        .map_err(|e| format!("Failed to install Prometheus recorder: {}", e))
+        .map_err(|e| format!("Failed to set HTTP request buckets: {}", e))
 }
 
 // ============================================================================
@@ -89,9 +103,6 @@ pub fn record_token_issuance(grant_type: &str, status: &str, duration: Duration)
 ///
 /// Metric: `ac_token_validations_total`
 /// Labels: `status`, `error_category`
-///
-/// NOTE: Defined per ADR-0011 for future token validation metrics.
-#[allow(dead_code)] // Will be used in Phase 4 token validation endpoints
 pub fn record_token_validation(status: &str, error_category: Option<&str>) {
     let category = error_category.unwrap_or("none");
     counter!("ac_token_validations_total", "status" => status.to_string(), "error_category" => category.to_string())
@@ -143,6 +154,26 @@ pub fn record_rate_limit_decision(action: &str) {
     counter!("ac_rate_limit_decisions_total", "action" => action.to_string()).increment(1);
 }
 
+/// Record a per-client token bucket decision
+///
+/// Metric: `ac_rate_limit_client_requests_total`
+/// Labels: `limit_class` (service type or `user`), `action` (allowed, rejected)
+pub fn record_client_rate_limit(limit_class: &str, action: &str) {
+    counter!(
+        "ac_rate_limit_client_requests_total",
+        "limit_class" => limit_class.to_string(),
+        "action" => action.to_string()
+    )
+    .increment(1);
+}
+
+/// Update the number of clients with a token bucket on this instance
+///
+/// Metric: `ac_rate_limit_tracked_clients`
+pub fn set_rate_limit_tracked_clients(count: usize) {
+    gauge!("ac_rate_limit_tracked_clients").set(count as f64);
+}
+
 // ============================================================================
 // Database Metrics
 // ============================================================================
@@ -280,11 +311,19 @@ fn normalize_path(path: &str) -> String {
         "/health" => "/health".to_string(),
         "/ready" => "/ready".to_string(),
         "/metrics" => "/metrics".to_string(),
+        "/version" => "/version".to_string(),
         "/.well-known/jwks.json" => "/.well-known/jwks.json".to_string(),
         "/api/v1/auth/service/token" => "/api/v1/auth/service/token".to_string(),
         "/api/v1/auth/user/token" => "/api/v1/auth/user/token".to_string(),
+        "/api/v1/auth/user/login" => "/api/v1/auth/user/login".to_string(),
+        "/api/v1/auth/user/totp" => "/api/v1/auth/user/totp".to_string(),
+        "/api/v1/auth/user/totp/confirm" => "/api/v1/auth/user/totp/confirm".to_string(),
+        "/api/v1/auth/oidc/authorize" => "/api/v1/auth/oidc/authorize".to_string(),
+        "/api/v1/auth/oidc/callback" => "/api/v1/auth/oidc/callback".to_string(),
         "/api/v1/admin/services/register" => "/api/v1/admin/services/register".to_string(),
+        "/api/v1/admin/services" => "/api/v1/admin/services".to_string(),
         "/api/v1/admin/clients" => "/api/v1/admin/clients".to_string(),
+        "/api/v1/admin/audit-events" => "/api/v1/admin/audit-events".to_string(),
         "/internal/rotate-keys" => "/internal/rotate-keys".to_string(),
         // For paths with dynamic segments (UUIDs), normalize them
         _ => normalize_dynamic_path(path),
@@ -299,6 +338,9 @@ fn normalize_path(path: &str) -> String {
 /// Examples:
 /// - `/api/v1/admin/clients/550e8400-e29b-41d4-a716-446655440000` → `/api/v1/admin/clients/{id}`
 /// - `/api/v1/admin/clients/550e8400-e29b-41d4-a716-446655440000/rotate-secret` → `/api/v1/admin/clients/{id}/rotate-secret`
+/// - `/api/v1/admin/orgs/550e8400-e29b-41d4-a716-446655440000/users/import` → `/api/v1/admin/orgs/{id}/users/import`
+/// - `/api/v1/admin/orgs/550e8400-e29b-41d4-a716-446655440000/oidc` → `/api/v1/admin/orgs/{id}/oidc`
+/// - `/api/v1/admin/services/a1b2c3/rotate-secret` → `/api/v1/admin/services/{client_id}/rotate-secret`
 fn normalize_dynamic_path(path: &str) -> String {
     // Check for admin client paths with UUID
     if path.starts_with("/api/v1/admin/clients/") {
@@ -324,6 +366,42 @@ fn normalize_dynamic_path(path: &str) -> String {
         }
     }
 
+    // Service paths are keyed by client_id, which is not a UUID
+    if path.starts_with("/api/v1/admin/services/") {
+        let parts: Vec<&str> = path.split('/').collect();
+        match (parts.get(5), parts.get(6), parts.len()) {
+            (Some(id), None, 6) if !id.is_empty() => {
+                return "/api/v1/admin/services/{client_id}".to_string();
+            }
+            (Some(id), Some(&"rotate-secret"), 7) if !id.is_empty() => {
+                return "/api/v1/admin/services/{client_id}/rotate-secret".to_string();
+            }
+            _ => {}
+        }
+    }
+
+    // /api/v1/admin/orgs/{uuid}/users/import → parts.len() == 8
+    // /api/v1/admin/orgs/{uuid}/oidc → parts.len() == 7
+    if path.starts_with("/api/v1/admin/orgs/") {
+        let parts: Vec<&str> = path.split('/').collect();
+        if parts.len() == 7 {
+            if let (Some(id_segment), Some("oidc")) = (parts.get(5), parts.get(6).copied()) {
+                if is_uuid(id_segment) {
+                    return "/api/v1/admin/orgs/{id}/oidc".to_string();
+                }
+            }
+        }
+        if parts.len() == 8 {
+            if let (Some(id_segment), Some("users"), Some("import")) =
+                (parts.get(5), parts.get(6).copied(), parts.get(7).copied())
+            {
+                if is_uuid(id_segment) {
+                    return "/api/v1/admin/orgs/{id}/users/import".to_string();
+                }
+            }
+        }
+    }
+
     // For unknown paths, use a generic label to bound cardinality
     "/other".to_string()
 }
@@ -384,7 +462,7 @@ mod tests {
     //   - tests/key_rotation_metrics_integration.rs (key rotation + gauges)
     //   - tests/db_metrics_integration.rs (db_query success/error cells)
     //   - tests/bcrypt_metrics_integration.rs (hash/verify with cost-12)
-    //   - tests/jwks_metrics_integration.rs (cache_status=miss)
+    //   - tests/jwks_metrics_integration.rs (cache_status=miss/hit)
     //   - tests/audit_log_failures_integration.rs (NOT VALID CHECK seam)
     //   - tests/rate_limit_metrics_integration.rs (3 gates × allowed/rejected)
     //   - tests/credential_ops_metrics_integration.rs (12-cell adjacency)
@@ -603,6 +681,19 @@ mod tests {
         snap.counter("ac_rate_limit_decisions_total")
             .with_labels(&[("action", "rejected")])
             .assert_delta(1);
+
+        record_client_rate_limit("global-controller", "allowed");
+        record_client_rate_limit("user", "rejected");
+        set_rate_limit_tracked_clients(2);
+
+        snap.counter("ac_rate_limit_client_requests_total")
+            .with_labels(&[("limit_class", "global-controller"), ("action", "allowed")])
+            .assert_delta(1);
+        snap.counter("ac_rate_limit_client_requests_total")
+            .with_labels(&[("limit_class", "user"), ("action", "rejected")])
+            .assert_delta(1);
+        snap.gauge("ac_rate_limit_tracked_clients")
+            .assert_value(2.0);
     }
 
     #[test]
@@ -752,6 +843,7 @@ mod tests {
         assert_eq!(normalize_path("/health"), "/health");
         assert_eq!(normalize_path("/ready"), "/ready");
         assert_eq!(normalize_path("/metrics"), "/metrics");
+        assert_eq!(normalize_path("/version"), "/version");
         assert_eq!(
             normalize_path("/.well-known/jwks.json"),
             "/.well-known/jwks.json"
@@ -764,10 +856,30 @@ mod tests {
             normalize_path("/api/v1/auth/user/token"),
             "/api/v1/auth/user/token"
         );
+        assert_eq!(
+            normalize_path("/api/v1/auth/user/login"),
+            "/api/v1/auth/user/login"
+        );
+        assert_eq!(
+            normalize_path("/api/v1/auth/user/totp/confirm"),
+            "/api/v1/auth/user/totp/confirm"
+        );
+        assert_eq!(
+            normalize_path("/api/v1/auth/oidc/authorize"),
+            "/api/v1/auth/oidc/authorize"
+        );
+        assert_eq!(
+            normalize_path("/api/v1/auth/oidc/callback"),
+            "/api/v1/auth/oidc/callback"
+        );
         assert_eq!(
             normalize_path("/api/v1/admin/services/register"),
             "/api/v1/admin/services/register"
         );
+        assert_eq!(
+            normalize_path("/api/v1/admin/audit-events"),
+            "/api/v1/admin/audit-events"
+        );
         assert_eq!(
             normalize_path("/internal/rotate-keys"),
             "/internal/rotate-keys"
@@ -805,6 +917,37 @@ mod tests {
             "/api/v1/admin/clients/{id}/rotate-secret"
         );
 
+        // Admin bulk user import (POST /api/v1/admin/orgs/{id}/users/import)
+        assert_eq!(
+            normalize_path("/api/v1/admin/orgs/550e8400-e29b-41d4-a716-446655440000/users/import"),
+            "/api/v1/admin/orgs/{id}/users/import"
+        );
+        assert_eq!(
+            normalize_path("/api/v1/admin/orgs/not-a-uuid/users/import"),
+            "/other"
+        );
+
+        // Admin org SSO provider (GET/PUT/DELETE /api/v1/admin/orgs/{id}/oidc)
+        assert_eq!(
+            normalize_path("/api/v1/admin/orgs/550e8400-e29b-41d4-a716-446655440000/oidc"),
+            "/api/v1/admin/orgs/{id}/oidc"
+        );
+
+        // Admin services by client_id
+        assert_eq!(
+            normalize_path("/api/v1/admin/services"),
+            "/api/v1/admin/services"
+        );
+        assert_eq!(
+            normalize_path("/api/v1/admin/services/gc-7f3a9c"),
+            "/api/v1/admin/services/{client_id}"
+        );
+        assert_eq!(
+            normalize_path("/api/v1/admin/services/gc-7f3a9c/rotate-secret"),
+            "/api/v1/admin/services/{client_id}/rotate-secret"
+        );
+        assert_eq!(normalize_path("/api/v1/admin/services/gc/other"), "/other");
+
         // Different UUIDs should normalize to same path (cardinality bounded)
         assert_eq!(
             normalize_path("/api/v1/admin/clients/123e4567-e89b-12d3-a456-426614174000"),
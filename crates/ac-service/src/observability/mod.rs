@@ -5,6 +5,8 @@
 //! # Privacy by Default
 //!
 //! All instrumentation uses `#[instrument(skip_all)]` and explicit safe field allow-listing.
+//! Span fields are exported as-is to the OTLP collector when `OTLP_ENDPOINT`
+//! is set, so the same rules apply to traces.
 //! Fields are categorized as:
 //! - **SAFE**: Can be logged in plaintext (enums, operation types)
 //! - **HASHED**: Must be HMAC-SHA256 hashed for correlation (client_id)
@@ -113,6 +115,8 @@ impl From<&crate::errors::AcError> for ErrorCategory {
         use crate::errors::AcError;
         match err {
             AcError::InvalidCredentials
+            | AcError::TotpRequired
+            | AcError::InvalidRequest(_)
             | AcError::RateLimitExceeded
             | AcError::TooManyRequests { .. } => ErrorCategory::Authentication,
             AcError::InsufficientScope { .. } => ErrorCategory::Authorization,
@@ -0,0 +1,447 @@
+//! OpenID Connect relying party for org SSO through an external IdP.
+//!
+//! AC runs the authorization code flow with PKCE against the provider an org
+//! has configured: [`authorization_url`] builds the redirect, the callback
+//! trades the code with [`exchange_code`], and [`validate_id_token`] checks
+//! the returned ID token against the provider's JWKS before
+//! [`crate::services::oidc_service`] issues a Dark Tower user token.
+//!
+//! Provider endpoints come from discovery
+//! (`{issuer}/.well-known/openid-configuration`). Issuers must use HTTPS;
+//! plain HTTP is only accepted for loopback hosts, for local IdPs and tests.
+
+use crate::crypto;
+use crate::errors::AcError;
+use base64::{engine::general_purpose, Engine as _};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use reqwest::Url;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::net::IpAddr;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Timeout for each request to the provider.
+const PROVIDER_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Random bytes in `state`, `nonce`, and the PKCE code verifier.
+const RANDOM_PARAM_BYTES: usize = 32;
+
+/// Scopes requested from the provider.
+const SCOPES: &str = "openid email profile";
+
+/// OIDC errors.
+#[derive(Debug, Error)]
+pub enum OidcError {
+    /// The provider could not be reached or answered with something unusable.
+    #[error("OIDC provider error: {0}")]
+    Provider(String),
+
+    /// The provider refused the authorization code, or the ID token failed
+    /// validation.
+    #[error("OIDC login rejected: {0}")]
+    Rejected(String),
+}
+
+/// The parts of the provider's discovery document AC uses.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
+
+/// Validated ID token claims (OIDC Core §2).
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdTokenClaims {
+    pub iss: String,
+    pub sub: String,
+    #[serde(default)]
+    pub nonce: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub email_verified: Option<bool>,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TokenEndpointResponse {
+    id_token: Option<String>,
+}
+
+/// Random `state`, `nonce`, and PKCE verifier for one authorization request.
+#[derive(Debug)]
+pub struct AuthorizationParams {
+    pub state: String,
+    pub nonce: String,
+    pub code_verifier: String,
+}
+
+impl AuthorizationParams {
+    /// Generate fresh parameters.
+    pub fn generate() -> Result<Self, AcError> {
+        Ok(Self {
+            state: random_param()?,
+            nonce: random_param()?,
+            code_verifier: random_param()?,
+        })
+    }
+}
+
+fn random_param() -> Result<String, AcError> {
+    Ok(general_purpose::URL_SAFE_NO_PAD.encode(crypto::generate_random_bytes(RANDOM_PARAM_BYTES)?))
+}
+
+/// PKCE `S256` code challenge for a verifier (RFC 7636 §4.2).
+pub fn code_challenge(code_verifier: &str) -> String {
+    general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+/// Check an issuer or redirect URL: HTTPS, or HTTP on a loopback host
+/// (`localhost`, `*.localhost`, or a loopback IP).
+///
+/// Returns the reason the URL is refused.
+pub fn check_url(url: &str) -> Result<(), String> {
+    let parsed = Url::parse(url).map_err(|e| format!("invalid URL: {}", e))?;
+    match parsed.scheme() {
+        "https" => Ok(()),
+        "http" if is_loopback(&parsed) => Ok(()),
+        _ => Err("URL must use https".to_string()),
+    }
+}
+
+fn is_loopback(url: &Url) -> bool {
+    match url.host_str() {
+        Some(host) if host == "localhost" || host.ends_with(".localhost") => true,
+        Some(host) => host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback()),
+        None => false,
+    }
+}
+
+fn http_client() -> Result<reqwest::Client, OidcError> {
+    reqwest::Client::builder()
+        .timeout(PROVIDER_REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| OidcError::Provider(format!("Failed to build HTTP client: {}", e)))
+}
+
+/// GET `url` and decode a JSON body.
+async fn get_json<T: serde::de::DeserializeOwned>(url: &str, what: &str) -> Result<T, OidcError> {
+    let response = http_client()?
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| OidcError::Provider(format!("{} request failed: {}", what, e)))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(OidcError::Provider(format!(
+            "{} request failed with {}",
+            what, status
+        )));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| OidcError::Provider(format!("Invalid {}: {}", what, e)))
+}
+
+/// Load the provider's discovery document.
+///
+/// The document must name `issuer_url` as its issuer (OIDC Discovery §4.3).
+pub async fn discover(issuer_url: &str) -> Result<ProviderMetadata, OidcError> {
+    let url = format!(
+        "{}/.well-known/openid-configuration",
+        issuer_url.trim_end_matches('/')
+    );
+    let metadata: ProviderMetadata = get_json(&url, "discovery document").await?;
+    if metadata.issuer != issuer_url {
+        return Err(OidcError::Provider(format!(
+            "Discovery document issuer {} does not match {}",
+            metadata.issuer, issuer_url
+        )));
+    }
+    Ok(metadata)
+}
+
+/// Load the provider's signing keys.
+pub async fn fetch_jwks(metadata: &ProviderMetadata) -> Result<JwkSet, OidcError> {
+    get_json(&metadata.jwks_uri, "JWKS").await
+}
+
+/// URL to send the user to for authentication.
+pub fn authorization_url(
+    metadata: &ProviderMetadata,
+    client_id: &str,
+    redirect_uri: &str,
+    params: &AuthorizationParams,
+) -> Result<String, OidcError> {
+    let challenge = code_challenge(&params.code_verifier);
+    let url = Url::parse_with_params(
+        &metadata.authorization_endpoint,
+        &[
+            ("response_type", "code"),
+            ("client_id", client_id),
+            ("redirect_uri", redirect_uri),
+            ("scope", SCOPES),
+            ("state", params.state.as_str()),
+            ("nonce", params.nonce.as_str()),
+            ("code_challenge", challenge.as_str()),
+            ("code_challenge_method", "S256"),
+        ],
+    )
+    .map_err(|e| OidcError::Provider(format!("Invalid authorization endpoint: {}", e)))?;
+    Ok(url.into())
+}
+
+/// Trade an authorization code for the ID token (OIDC Core §3.1.3).
+///
+/// Authenticates with `client_secret_post`.
+pub async fn exchange_code(
+    metadata: &ProviderMetadata,
+    client_id: &str,
+    client_secret: &str,
+    redirect_uri: &str,
+    code: &str,
+    code_verifier: &str,
+) -> Result<String, OidcError> {
+    let response = http_client()?
+        .post(&metadata.token_endpoint)
+        .form(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+            ("client_id", client_id),
+            ("client_secret", client_secret),
+            ("code_verifier", code_verifier),
+        ])
+        .send()
+        .await
+        .map_err(|e| OidcError::Provider(format!("Token request failed: {}", e)))?;
+    let status = response.status();
+    if !status.is_success() {
+        // A rejected code is the user's problem, not the provider's
+        return Err(OidcError::Rejected(format!(
+            "Token endpoint returned {}",
+            status
+        )));
+    }
+
+    let body: TokenEndpointResponse = response
+        .json()
+        .await
+        .map_err(|e| OidcError::Provider(format!("Invalid token response: {}", e)))?;
+    body.id_token
+        .ok_or_else(|| OidcError::Provider("Token response has no id_token".to_string()))
+}
+
+/// Validate an ID token (OIDC Core §3.1.3.7).
+///
+/// Checks the signature against `jwks`, `iss`, `aud`, `exp` with
+/// `leeway_seconds`, and that `nonce` matches the authorization request.
+/// Only asymmetric algorithms are accepted.
+pub fn validate_id_token(
+    id_token: &str,
+    jwks: &JwkSet,
+    issuer_url: &str,
+    client_id: &str,
+    nonce: &str,
+    leeway_seconds: u64,
+) -> Result<IdTokenClaims, OidcError> {
+    let header = decode_header(id_token)
+        .map_err(|e| OidcError::Rejected(format!("malformed header: {}", e)))?;
+    if matches!(
+        header.alg,
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+    ) {
+        return Err(OidcError::Rejected(
+            "symmetric signing algorithms are not accepted".to_string(),
+        ));
+    }
+
+    let jwk = match &header.kid {
+        Some(kid) => jwks.find(kid),
+        // Without a kid, only an unambiguous single key will do
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+    .ok_or_else(|| OidcError::Rejected("no matching signing key".to_string()))?;
+    let key = DecodingKey::from_jwk(jwk)
+        .map_err(|e| OidcError::Rejected(format!("unusable signing key: {}", e)))?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_issuer(&[issuer_url]);
+    validation.set_audience(&[client_id]);
+    validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+    validation.leeway = leeway_seconds;
+
+    let claims = decode::<IdTokenClaims>(id_token, &key, &validation)
+        .map_err(|e| OidcError::Rejected(e.to_string()))?
+        .claims;
+
+    if claims.nonce.as_deref() != Some(nonce) {
+        return Err(OidcError::Rejected("nonce mismatch".to_string()));
+    }
+
+    Ok(claims)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    const ISSUER: &str = "https://idp.example.com";
+
+    /// An Ed25519 key as a PKCS#8 document and a one-key JWKS.
+    fn signing_key(kid: &str) -> (Vec<u8>, JwkSet) {
+        let (_, pkcs8) = crypto::generate_signing_key().unwrap();
+        let public_key = Ed25519KeyPair::from_pkcs8(&pkcs8)
+            .unwrap()
+            .public_key()
+            .as_ref()
+            .to_vec();
+        let jwks = serde_json::from_value(serde_json::json!({
+            "keys": [{
+                "kty": "OKP",
+                "crv": "Ed25519",
+                "alg": "EdDSA",
+                "kid": kid,
+                "x": general_purpose::URL_SAFE_NO_PAD.encode(public_key),
+            }]
+        }))
+        .unwrap();
+        (pkcs8, jwks)
+    }
+
+    fn sign(pkcs8: &[u8], kid: &str, claims: serde_json::Value) -> String {
+        let mut header = Header::new(Algorithm::EdDSA);
+        header.kid = Some(kid.to_string());
+        encode(&header, &claims, &EncodingKey::from_ed_der(pkcs8)).unwrap()
+    }
+
+    fn claims(nonce: &str) -> serde_json::Value {
+        let now = chrono::Utc::now().timestamp();
+        serde_json::json!({
+            "iss": ISSUER,
+            "sub": "idp-user-1",
+            "aud": "dark-tower",
+            "iat": now,
+            "exp": now + 300,
+            "nonce": nonce,
+            "email": "sso@example.com",
+            "email_verified": true,
+        })
+    }
+
+    #[test]
+    fn test_code_challenge_rfc7636_vector() {
+        // RFC 7636 Appendix B
+        assert_eq!(
+            code_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_check_url() {
+        assert!(check_url("https://idp.example.com").is_ok());
+        assert!(check_url("http://127.0.0.1:8080").is_ok());
+        assert!(check_url("http://localhost:8080/realms/dt").is_ok());
+        assert!(check_url("http://[::1]:8080").is_ok());
+        assert!(check_url("http://acme.localhost:8080/api/v1/auth/oidc/callback").is_ok());
+        assert!(check_url("http://idp.example.com").is_err());
+        assert!(check_url("ftp://idp.example.com").is_err());
+        assert!(check_url("not a url").is_err());
+    }
+
+    #[test]
+    fn test_authorization_url_carries_request_params() {
+        let metadata = ProviderMetadata {
+            issuer: ISSUER.to_string(),
+            authorization_endpoint: "https://idp.example.com/authorize".to_string(),
+            token_endpoint: "https://idp.example.com/token".to_string(),
+            jwks_uri: "https://idp.example.com/jwks".to_string(),
+        };
+        let params = AuthorizationParams::generate().unwrap();
+
+        let url = authorization_url(
+            &metadata,
+            "dark-tower",
+            "https://acme.example.com/api/v1/auth/oidc/callback",
+            &params,
+        )
+        .unwrap();
+
+        let parsed = Url::parse(&url).unwrap();
+        let query: std::collections::HashMap<_, _> = parsed.query_pairs().into_owned().collect();
+        assert_eq!(parsed.path(), "/authorize");
+        assert_eq!(query["response_type"], "code");
+        assert_eq!(query["client_id"], "dark-tower");
+        assert_eq!(
+            query["redirect_uri"],
+            "https://acme.example.com/api/v1/auth/oidc/callback"
+        );
+        assert_eq!(query["state"], params.state);
+        assert_eq!(query["nonce"], params.nonce);
+        assert_eq!(
+            query["code_challenge"],
+            code_challenge(&params.code_verifier)
+        );
+        assert_eq!(query["code_challenge_method"], "S256");
+    }
+
+    #[test]
+    fn test_validate_id_token_accepts_valid_token() {
+        let (pkcs8, jwks) = signing_key("k1");
+        let token = sign(&pkcs8, "k1", claims("n-1"));
+
+        let claims = validate_id_token(&token, &jwks, ISSUER, "dark-tower", "n-1", 60).unwrap();
+        assert_eq!(claims.sub, "idp-user-1");
+        assert_eq!(claims.email.as_deref(), Some("sso@example.com"));
+        assert_eq!(claims.email_verified, Some(true));
+    }
+
+    #[test]
+    fn test_validate_id_token_rejects_wrong_nonce_audience_and_issuer() {
+        let (pkcs8, jwks) = signing_key("k1");
+        let token = sign(&pkcs8, "k1", claims("n-1"));
+
+        for (issuer, audience, nonce) in [
+            (ISSUER, "dark-tower", "n-2"),
+            (ISSUER, "other-client", "n-1"),
+            ("https://evil.example.com", "dark-tower", "n-1"),
+        ] {
+            assert!(matches!(
+                validate_id_token(&token, &jwks, issuer, audience, nonce, 60),
+                Err(OidcError::Rejected(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_validate_id_token_rejects_unknown_key_and_expired_token() {
+        let (pkcs8, jwks) = signing_key("k1");
+        let (other_pkcs8, _) = signing_key("k2");
+
+        let unknown_kid = sign(&other_pkcs8, "k2", claims("n-1"));
+        assert!(validate_id_token(&unknown_kid, &jwks, ISSUER, "dark-tower", "n-1", 60).is_err());
+
+        // Right kid, wrong key
+        let forged = sign(&other_pkcs8, "k1", claims("n-1"));
+        assert!(validate_id_token(&forged, &jwks, ISSUER, "dark-tower", "n-1", 60).is_err());
+
+        let mut expired = claims("n-1");
+        expired["exp"] = serde_json::json!(chrono::Utc::now().timestamp() - 600);
+        let expired = sign(&pkcs8, "k1", expired);
+        assert!(validate_id_token(&expired, &jwks, ISSUER, "dark-tower", "n-1", 60).is_err());
+    }
+}
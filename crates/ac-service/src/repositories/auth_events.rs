@@ -1,13 +1,15 @@
 use crate::errors::AcError;
-use crate::models::AuthEvent;
+use crate::models::{AuthEvent, NewAuthEvent};
 use crate::observability::metrics::record_db_query;
 use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use std::time::Instant;
+use tracing::instrument;
 use uuid::Uuid;
 
 /// Log an authentication event
 #[expect(clippy::too_many_arguments)] // Represents all auth_events table columns
+#[instrument(name = "ac.db.auth_events.log_event", skip_all)]
 pub async fn log_event(
     pool: &PgPool,
     event_type: &str,
@@ -50,8 +52,109 @@ pub async fn log_event(
     Ok(event)
 }
 
+/// Log a batch of authentication events in one INSERT
+///
+/// Returns the number of rows written. The batch is all-or-nothing: one
+/// event violating a constraint fails the whole statement.
+#[instrument(name = "ac.db.auth_events.log_events", skip_all)]
+pub async fn log_events(pool: &PgPool, events: &[NewAuthEvent]) -> Result<u64, AcError> {
+    let mut event_types = Vec::with_capacity(events.len());
+    let mut user_ids = Vec::with_capacity(events.len());
+    let mut credential_ids = Vec::with_capacity(events.len());
+    let mut successes = Vec::with_capacity(events.len());
+    let mut failure_reasons = Vec::with_capacity(events.len());
+    let mut ip_addresses = Vec::with_capacity(events.len());
+    let mut user_agents = Vec::with_capacity(events.len());
+    let mut metadata = Vec::with_capacity(events.len());
+    for event in events {
+        event_types.push(event.event_type.as_str());
+        user_ids.push(event.user_id);
+        credential_ids.push(event.credential_id);
+        successes.push(event.success);
+        failure_reasons.push(event.failure_reason.as_deref());
+        ip_addresses.push(event.ip_address.as_deref());
+        user_agents.push(event.user_agent.as_deref());
+        metadata.push(event.metadata.clone());
+    }
+
+    let start = Instant::now();
+    let result = sqlx::query(
+        r#"
+        INSERT INTO auth_events (
+            event_type, user_id, credential_id, success, failure_reason,
+            ip_address, user_agent, metadata
+        )
+        SELECT
+            event_type, user_id, credential_id, success, failure_reason,
+            ip_address::inet, user_agent, metadata
+        FROM UNNEST(
+            $1::text[], $2::uuid[], $3::uuid[], $4::bool[], $5::text[],
+            $6::text[], $7::text[], $8::jsonb[]
+        ) AS e(
+            event_type, user_id, credential_id, success, failure_reason,
+            ip_address, user_agent, metadata
+        )
+        "#,
+    )
+    .bind(event_types)
+    .bind(user_ids)
+    .bind(credential_ids)
+    .bind(successes)
+    .bind(failure_reasons)
+    .bind(ip_addresses)
+    .bind(user_agents)
+    .bind(metadata)
+    .execute(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("insert", "auth_events", status, start.elapsed());
+    let result =
+        result.map_err(|e| AcError::Database(format!("Failed to log auth events: {}", e)))?;
+
+    Ok(result.rows_affected())
+}
+
+/// List authentication events in `[since, until)`, newest first
+///
+/// `event_type` of `None` returns every type.
+#[instrument(name = "ac.db.auth_events.list_events", skip_all)]
+pub async fn list_events(
+    pool: &PgPool,
+    event_type: Option<&str>,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    limit: i64,
+) -> Result<Vec<AuthEvent>, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query_as::<_, AuthEvent>(
+        r#"
+        SELECT
+            event_id, event_type, user_id, credential_id, success, failure_reason,
+            host(ip_address) as ip_address, user_agent, metadata, created_at
+        FROM auth_events
+        WHERE created_at >= $1
+            AND created_at < $2
+            AND ($3::text IS NULL OR event_type = $3)
+        ORDER BY created_at DESC, event_id
+        LIMIT $4
+        "#,
+    )
+    .bind(since)
+    .bind(until)
+    .bind(event_type)
+    .bind(limit)
+    .fetch_all(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("select", "auth_events", status, start.elapsed());
+    let events = result.map_err(|e| AcError::Database(format!("Failed to list events: {}", e)))?;
+
+    Ok(events)
+}
+
 /// Get authentication events for a user
 #[allow(dead_code)] // Library function - will be used in Phase 4 audit endpoints
+#[instrument(name = "ac.db.auth_events.get_events_by_user", skip_all)]
 pub async fn get_events_by_user(
     pool: &PgPool,
     user_id: Uuid,
@@ -79,6 +182,7 @@ pub async fn get_events_by_user(
 
 /// Get authentication events for a service credential
 #[allow(dead_code)] // Library function - will be used in Phase 4 audit endpoints
+#[instrument(name = "ac.db.auth_events.get_events_by_credential", skip_all)]
 pub async fn get_events_by_credential(
     pool: &PgPool,
     credential_id: Uuid,
@@ -106,6 +210,7 @@ pub async fn get_events_by_credential(
 
 /// Get failed authentication attempts from an IP address
 #[allow(dead_code)] // Library function - will be used in Phase 4 rate limiting
+#[instrument(name = "ac.db.auth_events.get_failed_attempts_by_ip", skip_all)]
 pub async fn get_failed_attempts_by_ip(
     pool: &PgPool,
     ip_address: &str,
@@ -134,6 +239,7 @@ pub async fn get_failed_attempts_by_ip(
 
 /// Get events by type within a time range
 #[allow(dead_code)] // Library function - will be used in Phase 4 analytics/monitoring
+#[instrument(name = "ac.db.auth_events.get_events_by_type", skip_all)]
 pub async fn get_events_by_type(
     pool: &PgPool,
     event_type: &str,
@@ -166,6 +272,7 @@ pub async fn get_events_by_type(
 }
 
 /// Get count of failed authentication attempts for a credential since a given time
+#[instrument(name = "ac.db.auth_events.get_failed_attempts_count", skip_all)]
 pub async fn get_failed_attempts_count(
     pool: &PgPool,
     credential_id: &Uuid,
@@ -194,6 +301,7 @@ pub async fn get_failed_attempts_count(
 }
 
 /// Get count of failed authentication attempts for a user since a given time
+#[instrument(name = "ac.db.auth_events.get_failed_attempts_count_by_user", skip_all)]
 pub async fn get_failed_attempts_count_by_user(
     pool: &PgPool,
     user_id: &Uuid,
@@ -220,6 +328,7 @@ pub async fn get_failed_attempts_count_by_user(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::AuthEventType;
     use crate::repositories::service_credentials;
     use chrono::Duration;
 
@@ -586,6 +695,90 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_log_events_batch(pool: PgPool) -> Result<(), AcError> {
+        let credential = service_credentials::create_service_credential(
+            &pool,
+            "test-batch",
+            "hash",
+            "global-controller",
+            None,
+            &["test:scope".to_string()],
+        )
+        .await?;
+
+        let events = vec![
+            NewAuthEvent::success(AuthEventType::ServiceTokenIssued)
+                .credential(Some(credential.credential_id))
+                .client(Some("10.0.0.1"), Some("batch-agent"))
+                .metadata(serde_json::json!({"scopes": ["test:scope"]})),
+            NewAuthEvent::success(AuthEventType::KeyRotated),
+            NewAuthEvent::failure(AuthEventType::TokenValidationFailed, "Invalid token"),
+        ];
+        assert_eq!(log_events(&pool, &events).await?, 3);
+        assert_eq!(log_events(&pool, &[]).await?, 0);
+
+        let now = Utc::now();
+        let all = list_events(
+            &pool,
+            None,
+            now - Duration::hours(1),
+            now + Duration::hours(1),
+            10,
+        )
+        .await?;
+        assert_eq!(all.len(), 3);
+
+        let issued = list_events(
+            &pool,
+            Some("service_token_issued"),
+            now - Duration::hours(1),
+            now + Duration::hours(1),
+            10,
+        )
+        .await?;
+        assert_eq!(issued.len(), 1);
+        assert_eq!(issued[0].credential_id, Some(credential.credential_id));
+        assert_eq!(issued[0].ip_address, Some("10.0.0.1".to_string()));
+        assert_eq!(issued[0].user_agent, Some("batch-agent".to_string()));
+
+        // Events outside the window are excluded
+        let past = list_events(
+            &pool,
+            None,
+            now - Duration::hours(2),
+            now - Duration::hours(1),
+            10,
+        )
+        .await?;
+        assert!(past.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_log_events_batch_is_atomic(pool: PgPool) -> Result<(), AcError> {
+        // A service token event without a credential violates event_has_subject
+        let events = vec![
+            NewAuthEvent::success(AuthEventType::KeyRotated),
+            NewAuthEvent::success(AuthEventType::ServiceTokenIssued),
+        ];
+        assert!(log_events(&pool, &events).await.is_err());
+
+        let now = Utc::now();
+        let written = list_events(
+            &pool,
+            None,
+            now - Duration::hours(1),
+            now + Duration::hours(1),
+            10,
+        )
+        .await?;
+        assert!(written.is_empty());
+
+        Ok(())
+    }
+
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_get_failed_attempts_count(pool: PgPool) -> Result<(), AcError> {
         // Create credential for testing
@@ -1,5 +1,9 @@
 pub mod auth_events;
+pub mod oidc;
 pub mod organizations;
+pub mod refresh_tokens;
+pub mod service_credential_failures;
 pub mod service_credentials;
 pub mod signing_keys;
+pub mod user_totp;
 pub mod users;
@@ -0,0 +1,356 @@
+//! OIDC federation repository module for database operations.
+//!
+//! Three tables back the external IdP login: the provider each org has
+//! configured, the in-flight authorization requests consumed by the
+//! callback, and the IdP identities linked to AC users. Client secrets are
+//! stored encrypted with the master key and login states by hash only.
+
+use crate::errors::AcError;
+use crate::observability::metrics::record_db_query;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::time::Instant;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Configured upstream provider (maps to org_oidc_providers table)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct OidcProvider {
+    pub org_id: Uuid,
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret_encrypted: Vec<u8>,
+    pub client_secret_nonce: Vec<u8>,
+    pub client_secret_tag: Vec<u8>,
+    pub redirect_uri: String,
+    pub is_enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A provider to store for an org.
+#[derive(Debug, Clone)]
+pub struct NewOidcProvider<'a> {
+    pub issuer_url: &'a str,
+    pub client_id: &'a str,
+    pub client_secret_encrypted: &'a [u8],
+    pub client_secret_nonce: &'a [u8],
+    pub client_secret_tag: &'a [u8],
+    pub redirect_uri: &'a str,
+    pub is_enabled: bool,
+}
+
+/// Pending authorization request (maps to oidc_login_states table)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct OidcLoginState {
+    pub org_id: Uuid,
+    pub nonce: String,
+    pub code_verifier: String,
+}
+
+/// Get an org's provider, enabled or not.
+#[instrument(name = "ac.db.oidc.get_provider", skip_all)]
+pub async fn get_provider(pool: &PgPool, org_id: Uuid) -> Result<Option<OidcProvider>, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query_as::<_, OidcProvider>(
+        r#"
+        SELECT
+            org_id, issuer_url, client_id,
+            client_secret_encrypted, client_secret_nonce, client_secret_tag,
+            redirect_uri, is_enabled, created_at, updated_at
+        FROM org_oidc_providers
+        WHERE org_id = $1
+        "#,
+    )
+    .bind(org_id)
+    .fetch_optional(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("select", "org_oidc_providers", status, start.elapsed());
+
+    result.map_err(|e| AcError::Database(format!("Failed to fetch OIDC provider: {}", e)))
+}
+
+/// Create or replace an org's provider.
+#[instrument(name = "ac.db.oidc.upsert_provider", skip_all)]
+pub async fn upsert_provider(
+    pool: &PgPool,
+    org_id: Uuid,
+    provider: &NewOidcProvider<'_>,
+) -> Result<OidcProvider, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query_as::<_, OidcProvider>(
+        r#"
+        INSERT INTO org_oidc_providers (
+            org_id, issuer_url, client_id,
+            client_secret_encrypted, client_secret_nonce, client_secret_tag,
+            redirect_uri, is_enabled
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ON CONFLICT (org_id) DO UPDATE
+        SET issuer_url = EXCLUDED.issuer_url,
+            client_id = EXCLUDED.client_id,
+            client_secret_encrypted = EXCLUDED.client_secret_encrypted,
+            client_secret_nonce = EXCLUDED.client_secret_nonce,
+            client_secret_tag = EXCLUDED.client_secret_tag,
+            redirect_uri = EXCLUDED.redirect_uri,
+            is_enabled = EXCLUDED.is_enabled,
+            updated_at = NOW()
+        RETURNING
+            org_id, issuer_url, client_id,
+            client_secret_encrypted, client_secret_nonce, client_secret_tag,
+            redirect_uri, is_enabled, created_at, updated_at
+        "#,
+    )
+    .bind(org_id)
+    .bind(provider.issuer_url)
+    .bind(provider.client_id)
+    .bind(provider.client_secret_encrypted)
+    .bind(provider.client_secret_nonce)
+    .bind(provider.client_secret_tag)
+    .bind(provider.redirect_uri)
+    .bind(provider.is_enabled)
+    .fetch_one(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("upsert", "org_oidc_providers", status, start.elapsed());
+
+    result.map_err(|e| AcError::Database(format!("Failed to store OIDC provider: {}", e)))
+}
+
+/// Remove an org's provider.
+///
+/// Returns `false` if the org had none. Linked identities are kept, so
+/// re-adding the same issuer restores existing links.
+#[instrument(name = "ac.db.oidc.delete_provider", skip_all)]
+pub async fn delete_provider(pool: &PgPool, org_id: Uuid) -> Result<bool, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query("DELETE FROM org_oidc_providers WHERE org_id = $1")
+        .bind(org_id)
+        .execute(pool)
+        .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("delete", "org_oidc_providers", status, start.elapsed());
+    let result =
+        result.map_err(|e| AcError::Database(format!("Failed to delete OIDC provider: {}", e)))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Store a pending authorization request.
+///
+/// Expired rows are purged on the way in, so the table only holds requests
+/// still waiting for their callback.
+#[instrument(name = "ac.db.oidc.create_login_state", skip_all)]
+pub async fn create_login_state(
+    pool: &PgPool,
+    state_hash: &str,
+    org_id: Uuid,
+    nonce: &str,
+    code_verifier: &str,
+    expires_at: DateTime<Utc>,
+) -> Result<(), AcError> {
+    let start = Instant::now();
+    let result = sqlx::query(
+        r#"
+        WITH purged AS (
+            DELETE FROM oidc_login_states WHERE expires_at <= NOW()
+        )
+        INSERT INTO oidc_login_states (state_hash, org_id, nonce, code_verifier, expires_at)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+    )
+    .bind(state_hash)
+    .bind(org_id)
+    .bind(nonce)
+    .bind(code_verifier)
+    .bind(expires_at)
+    .execute(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("insert", "oidc_login_states", status, start.elapsed());
+    result.map_err(|e| AcError::Database(format!("Failed to store OIDC login state: {}", e)))?;
+
+    Ok(())
+}
+
+/// Consume a pending authorization request.
+///
+/// Returns `None` for unknown, expired, or already-used states. The delete
+/// is atomic, so each state completes at most one login.
+#[instrument(name = "ac.db.oidc.take_login_state", skip_all)]
+pub async fn take_login_state(
+    pool: &PgPool,
+    state_hash: &str,
+) -> Result<Option<OidcLoginState>, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query_as::<_, OidcLoginState>(
+        r#"
+        DELETE FROM oidc_login_states
+        WHERE state_hash = $1 AND expires_at > NOW()
+        RETURNING org_id, nonce, code_verifier
+        "#,
+    )
+    .bind(state_hash)
+    .fetch_optional(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("delete", "oidc_login_states", status, start.elapsed());
+
+    result.map_err(|e| AcError::Database(format!("Failed to consume OIDC login state: {}", e)))
+}
+
+/// Get the user an IdP identity is linked to.
+#[instrument(name = "ac.db.oidc.get_linked_user", skip_all)]
+pub async fn get_linked_user(
+    pool: &PgPool,
+    issuer_url: &str,
+    subject: &str,
+) -> Result<Option<Uuid>, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        SELECT user_id
+        FROM user_oidc_identities
+        WHERE issuer_url = $1 AND subject = $2
+        "#,
+    )
+    .bind(issuer_url)
+    .bind(subject)
+    .fetch_optional(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("select", "user_oidc_identities", status, start.elapsed());
+
+    result.map_err(|e| AcError::Database(format!("Failed to fetch OIDC identity: {}", e)))
+}
+
+/// Link an IdP identity to a user.
+#[instrument(name = "ac.db.oidc.link_identity", skip_all)]
+pub async fn link_identity(
+    pool: &PgPool,
+    issuer_url: &str,
+    subject: &str,
+    user_id: Uuid,
+) -> Result<(), AcError> {
+    let start = Instant::now();
+    let result = sqlx::query(
+        r#"
+        INSERT INTO user_oidc_identities (issuer_url, subject, user_id)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (issuer_url, subject) DO NOTHING
+        "#,
+    )
+    .bind(issuer_url)
+    .bind(subject)
+    .bind(user_id)
+    .execute(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("insert", "user_oidc_identities", status, start.elapsed());
+    result.map_err(|e| AcError::Database(format!("Failed to link OIDC identity: {}", e)))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::users;
+
+    async fn create_org(pool: &PgPool) -> Uuid {
+        let org_id: (Uuid,) = sqlx::query_as(
+            r#"
+            INSERT INTO organizations (subdomain, display_name)
+            VALUES ('oidc-repo', 'OIDC Repo')
+            RETURNING org_id
+            "#,
+        )
+        .fetch_one(pool)
+        .await
+        .expect("Should create organization");
+        org_id.0
+    }
+
+    fn provider(issuer_url: &str) -> NewOidcProvider<'_> {
+        NewOidcProvider {
+            issuer_url,
+            client_id: "dark-tower",
+            client_secret_encrypted: b"secret",
+            client_secret_nonce: &[1; 12],
+            client_secret_tag: &[2; 16],
+            redirect_uri: "https://acme.example.com/api/v1/auth/oidc/callback",
+            is_enabled: true,
+        }
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_provider_lifecycle(pool: PgPool) -> Result<(), AcError> {
+        let org_id = create_org(&pool).await;
+        assert!(get_provider(&pool, org_id).await?.is_none());
+
+        upsert_provider(&pool, org_id, &provider("https://idp.example.com")).await?;
+        let replaced = upsert_provider(&pool, org_id, &provider("https://sso.example.com")).await?;
+        assert_eq!(replaced.issuer_url, "https://sso.example.com");
+        assert_eq!(
+            get_provider(&pool, org_id).await?.unwrap().issuer_url,
+            "https://sso.example.com"
+        );
+
+        assert!(delete_provider(&pool, org_id).await?);
+        assert!(!delete_provider(&pool, org_id).await?);
+        assert!(get_provider(&pool, org_id).await?.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_login_state_is_single_use(pool: PgPool) -> Result<(), AcError> {
+        let org_id = create_org(&pool).await;
+        let expires_at = Utc::now() + chrono::Duration::minutes(10);
+        create_login_state(&pool, "live", org_id, "nonce", "verifier", expires_at).await?;
+        create_login_state(
+            &pool,
+            "expired",
+            org_id,
+            "nonce",
+            "verifier",
+            Utc::now() - chrono::Duration::seconds(1),
+        )
+        .await?;
+
+        let state = take_login_state(&pool, "live").await?.unwrap();
+        assert_eq!(state.org_id, org_id);
+        assert_eq!(state.nonce, "nonce");
+        assert_eq!(state.code_verifier, "verifier");
+        assert!(take_login_state(&pool, "live").await?.is_none(), "used");
+        assert!(take_login_state(&pool, "expired").await?.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_identity_links(pool: PgPool) -> Result<(), AcError> {
+        let org_id = create_org(&pool).await;
+        let user_id = users::create_user(&pool, org_id, "sso@example.com", "hash", "SSO User")
+            .await?
+            .user_id;
+
+        assert!(get_linked_user(&pool, "https://idp.example.com", "sub-1")
+            .await?
+            .is_none());
+        link_identity(&pool, "https://idp.example.com", "sub-1", user_id).await?;
+        // Linking again is a no-op
+        link_identity(&pool, "https://idp.example.com", "sub-1", user_id).await?;
+
+        assert_eq!(
+            get_linked_user(&pool, "https://idp.example.com", "sub-1").await?,
+            Some(user_id)
+        );
+        assert!(get_linked_user(&pool, "https://other.example.com", "sub-1")
+            .await?
+            .is_none());
+
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use crate::observability::metrics::record_db_query;
 use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use std::time::Instant;
+use tracing::instrument;
 use uuid::Uuid;
 
 /// Organization model (maps to organizations table)
@@ -28,6 +29,7 @@ pub struct Organization {
 /// Get organization by subdomain.
 ///
 /// Used by subdomain extraction middleware to look up org_id from Host header.
+#[instrument(name = "ac.db.organizations.get_by_subdomain", skip_all)]
 pub async fn get_by_subdomain(
     pool: &PgPool,
     subdomain: &str,
@@ -57,6 +59,7 @@ pub async fn get_by_subdomain(
 
 /// Get organization by org_id.
 #[allow(dead_code)] // Library function - will be used in future phases
+#[instrument(name = "ac.db.organizations.get_by_id", skip_all)]
 pub async fn get_by_id(pool: &PgPool, org_id: Uuid) -> Result<Option<Organization>, AcError> {
     let start = Instant::now();
     let result = sqlx::query_as::<_, Organization>(
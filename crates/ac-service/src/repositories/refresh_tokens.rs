@@ -0,0 +1,150 @@
+//! Refresh token repository module for database operations.
+//!
+//! Refresh tokens rotate on use: [`consume`] marks a token used and callers
+//! issue its successor in the same family. Only token hashes are stored.
+
+use crate::errors::AcError;
+use crate::observability::metrics::record_db_query;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::time::Instant;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Refresh token model (maps to refresh_tokens table)
+///
+/// Exactly one of `credential_id` (service token) and `user_id` (user
+/// token) is set.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct RefreshToken {
+    pub token_id: Uuid,
+    pub family_id: Uuid,
+    pub credential_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub scopes: Vec<String>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// Store a new refresh token.
+#[instrument(name = "ac.db.refresh_tokens.create", skip_all)]
+pub async fn create(
+    pool: &PgPool,
+    token_hash: &str,
+    family_id: Uuid,
+    credential_id: Option<Uuid>,
+    user_id: Option<Uuid>,
+    scopes: &[String],
+    expires_at: DateTime<Utc>,
+) -> Result<(), AcError> {
+    let start = Instant::now();
+    let result = sqlx::query(
+        r#"
+        INSERT INTO refresh_tokens
+            (token_hash, family_id, credential_id, user_id, scopes, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+    )
+    .bind(token_hash)
+    .bind(family_id)
+    .bind(credential_id)
+    .bind(user_id)
+    .bind(scopes)
+    .bind(expires_at)
+    .execute(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("insert", "refresh_tokens", status, start.elapsed());
+    result.map_err(|e| AcError::Database(format!("Failed to create refresh token: {}", e)))?;
+
+    Ok(())
+}
+
+/// Mark an unused, unrevoked, unexpired refresh token used.
+///
+/// `for_service` selects service tokens (`true`) or user tokens (`false`);
+/// a token of the other kind is left untouched. Returns the token if this
+/// call redeemed it. The update is atomic, so two concurrent redemptions of
+/// one token cannot both succeed.
+#[instrument(name = "ac.db.refresh_tokens.consume", skip_all)]
+pub async fn consume(
+    pool: &PgPool,
+    token_hash: &str,
+    for_service: bool,
+) -> Result<Option<RefreshToken>, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query_as::<_, RefreshToken>(
+        r#"
+        UPDATE refresh_tokens
+        SET used_at = NOW()
+        WHERE token_hash = $1
+            AND used_at IS NULL
+            AND revoked_at IS NULL
+            AND expires_at > NOW()
+            AND (credential_id IS NOT NULL) = $2
+        RETURNING
+            token_id, family_id, credential_id, user_id, scopes,
+            expires_at, used_at, revoked_at
+        "#,
+    )
+    .bind(token_hash)
+    .bind(for_service)
+    .fetch_optional(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("update", "refresh_tokens", status, start.elapsed());
+    let token =
+        result.map_err(|e| AcError::Database(format!("Failed to consume refresh token: {}", e)))?;
+
+    Ok(token)
+}
+
+/// Get a refresh token by hash, in any state.
+#[instrument(name = "ac.db.refresh_tokens.get_by_hash", skip_all)]
+pub async fn get_by_hash(pool: &PgPool, token_hash: &str) -> Result<Option<RefreshToken>, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query_as::<_, RefreshToken>(
+        r#"
+        SELECT
+            token_id, family_id, credential_id, user_id, scopes,
+            expires_at, used_at, revoked_at
+        FROM refresh_tokens
+        WHERE token_hash = $1
+        "#,
+    )
+    .bind(token_hash)
+    .fetch_optional(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("select", "refresh_tokens", status, start.elapsed());
+    let token =
+        result.map_err(|e| AcError::Database(format!("Failed to fetch refresh token: {}", e)))?;
+
+    Ok(token)
+}
+
+/// Revoke every unrevoked token in a family.
+///
+/// Returns the number of tokens revoked.
+#[instrument(name = "ac.db.refresh_tokens.revoke_family", skip_all)]
+pub async fn revoke_family(pool: &PgPool, family_id: Uuid) -> Result<u64, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query(
+        r#"
+        UPDATE refresh_tokens
+        SET revoked_at = NOW()
+        WHERE family_id = $1
+            AND revoked_at IS NULL
+        "#,
+    )
+    .bind(family_id)
+    .execute(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("update", "refresh_tokens", status, start.elapsed());
+    let result =
+        result.map_err(|e| AcError::Database(format!("Failed to revoke refresh tokens: {}", e)))?;
+
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,161 @@
+//! Consecutive authentication failures per service credential.
+//!
+//! One row per credential that has failed since its last successful
+//! authentication. `token_service` reads it to apply exponential backoff
+//! before verifying a secret, and deletes it on success.
+
+use crate::errors::AcError;
+use crate::observability::metrics::record_db_query;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::time::Instant;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Failure streak model (maps to service_credential_failures table)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CredentialFailures {
+    pub credential_id: Uuid,
+    pub consecutive_failures: i32,
+    pub last_failure_at: DateTime<Utc>,
+}
+
+/// Get a credential's current failure streak, if any.
+#[instrument(name = "ac.db.service_credential_failures.get", skip_all)]
+pub async fn get(
+    pool: &PgPool,
+    credential_id: Uuid,
+) -> Result<Option<CredentialFailures>, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query_as::<_, CredentialFailures>(
+        r#"
+        SELECT credential_id, consecutive_failures, last_failure_at
+        FROM service_credential_failures
+        WHERE credential_id = $1
+        "#,
+    )
+    .bind(credential_id)
+    .fetch_optional(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query(
+        "select",
+        "service_credential_failures",
+        status,
+        start.elapsed(),
+    );
+
+    result.map_err(|e| AcError::Database(format!("Failed to fetch credential failures: {}", e)))
+}
+
+/// Record a failed authentication and return the updated streak.
+///
+/// The increment is atomic, so concurrent failures are all counted.
+#[instrument(name = "ac.db.service_credential_failures.record_failure", skip_all)]
+pub async fn record_failure(
+    pool: &PgPool,
+    credential_id: Uuid,
+) -> Result<CredentialFailures, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query_as::<_, CredentialFailures>(
+        r#"
+        INSERT INTO service_credential_failures (credential_id, consecutive_failures)
+        VALUES ($1, 1)
+        ON CONFLICT (credential_id) DO UPDATE
+        SET consecutive_failures = service_credential_failures.consecutive_failures + 1,
+            last_failure_at = NOW()
+        RETURNING credential_id, consecutive_failures, last_failure_at
+        "#,
+    )
+    .bind(credential_id)
+    .fetch_one(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query(
+        "upsert",
+        "service_credential_failures",
+        status,
+        start.elapsed(),
+    );
+
+    result.map_err(|e| AcError::Database(format!("Failed to record credential failure: {}", e)))
+}
+
+/// End a credential's failure streak after a successful authentication.
+#[instrument(name = "ac.db.service_credential_failures.clear", skip_all)]
+pub async fn clear(pool: &PgPool, credential_id: Uuid) -> Result<(), AcError> {
+    let start = Instant::now();
+    let result = sqlx::query(
+        r#"
+        DELETE FROM service_credential_failures
+        WHERE credential_id = $1
+        "#,
+    )
+    .bind(credential_id)
+    .execute(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query(
+        "delete",
+        "service_credential_failures",
+        status,
+        start.elapsed(),
+    );
+    result.map_err(|e| AcError::Database(format!("Failed to clear credential failures: {}", e)))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::service_credentials;
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_failure_streak_lifecycle(pool: PgPool) -> Result<(), AcError> {
+        let credential = service_credentials::create_service_credential(
+            &pool,
+            "failures-client",
+            "hash",
+            "media-handler",
+            None,
+            &["media:forward".to_string()],
+        )
+        .await?;
+        let id = credential.credential_id;
+        assert!(get(&pool, id).await?.is_none());
+
+        assert_eq!(record_failure(&pool, id).await?.consecutive_failures, 1);
+        let second = record_failure(&pool, id).await?;
+        assert_eq!(second.consecutive_failures, 2);
+        assert_eq!(
+            get(&pool, id).await?.unwrap().last_failure_at,
+            second.last_failure_at
+        );
+
+        clear(&pool, id).await?;
+        assert!(get(&pool, id).await?.is_none());
+        assert_eq!(record_failure(&pool, id).await?.consecutive_failures, 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_streak_deleted_with_credential(pool: PgPool) -> Result<(), AcError> {
+        let credential = service_credentials::create_service_credential(
+            &pool,
+            "failures-cascade",
+            "hash",
+            "media-handler",
+            None,
+            &[],
+        )
+        .await?;
+        record_failure(&pool, credential.credential_id).await?;
+
+        service_credentials::delete(&pool, credential.credential_id).await?;
+        assert!(get(&pool, credential.credential_id).await?.is_none());
+
+        Ok(())
+    }
+}
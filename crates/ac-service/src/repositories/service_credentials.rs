@@ -3,9 +3,11 @@ use crate::models::ServiceCredential;
 use crate::observability::metrics::record_db_query;
 use sqlx::PgPool;
 use std::time::Instant;
+use tracing::instrument;
 use uuid::Uuid;
 
 /// Create a new service credential
+#[instrument(name = "ac.db.service_credentials.create_service_credential", skip_all)]
 pub async fn create_service_credential(
     pool: &PgPool,
     client_id: &str,
@@ -20,7 +22,7 @@ pub async fn create_service_credential(
         VALUES ($1, $2, $3, $4, $5)
         RETURNING
             credential_id, client_id, client_secret_hash, service_type, region, scopes,
-            is_active, created_at, updated_at
+            is_active, created_at, updated_at, client_cert_spki_sha256
         "#,
     )
     .bind(client_id)
@@ -36,6 +38,7 @@ pub async fn create_service_credential(
 }
 
 /// Get service credential by client_id
+#[instrument(name = "ac.db.service_credentials.get_by_client_id", skip_all)]
 pub async fn get_by_client_id(
     pool: &PgPool,
     client_id: &str,
@@ -45,7 +48,7 @@ pub async fn get_by_client_id(
         r#"
         SELECT
             credential_id, client_id, client_secret_hash, service_type, region, scopes,
-            is_active, created_at, updated_at
+            is_active, created_at, updated_at, client_cert_spki_sha256
         FROM service_credentials
         WHERE client_id = $1
         "#,
@@ -64,7 +67,7 @@ pub async fn get_by_client_id(
 }
 
 /// Update scopes for a service credential
-#[allow(dead_code)] // Library function - will be used in Phase 4 admin endpoints
+#[instrument(name = "ac.db.service_credentials.update_scopes", skip_all)]
 pub async fn update_scopes(
     pool: &PgPool,
     credential_id: Uuid,
@@ -77,7 +80,7 @@ pub async fn update_scopes(
         WHERE credential_id = $1
         RETURNING
             credential_id, client_id, client_secret_hash, service_type, region, scopes,
-            is_active, created_at, updated_at
+            is_active, created_at, updated_at, client_cert_spki_sha256
         "#,
     )
     .bind(credential_id)
@@ -90,7 +93,7 @@ pub async fn update_scopes(
 }
 
 /// Deactivate a service credential
-#[allow(dead_code)] // Library function - will be used in Phase 4 admin endpoints
+#[instrument(name = "ac.db.service_credentials.deactivate", skip_all)]
 pub async fn deactivate(pool: &PgPool, credential_id: Uuid) -> Result<ServiceCredential, AcError> {
     let credential = sqlx::query_as::<_, ServiceCredential>(
         r#"
@@ -99,7 +102,7 @@ pub async fn deactivate(pool: &PgPool, credential_id: Uuid) -> Result<ServiceCre
         WHERE credential_id = $1
         RETURNING
             credential_id, client_id, client_secret_hash, service_type, region, scopes,
-            is_active, created_at, updated_at
+            is_active, created_at, updated_at, client_cert_spki_sha256
         "#,
     )
     .bind(credential_id)
@@ -110,8 +113,33 @@ pub async fn deactivate(pool: &PgPool, credential_id: Uuid) -> Result<ServiceCre
     Ok(credential)
 }
 
+/// Reactivate a deactivated service credential
+#[instrument(name = "ac.db.service_credentials.activate", skip_all)]
+pub async fn activate(pool: &PgPool, credential_id: Uuid) -> Result<ServiceCredential, AcError> {
+    let credential = sqlx::query_as::<_, ServiceCredential>(
+        r#"
+        UPDATE service_credentials
+        SET is_active = true, updated_at = NOW()
+        WHERE credential_id = $1
+        RETURNING
+            credential_id, client_id, client_secret_hash, service_type, region, scopes,
+            is_active, created_at, updated_at, client_cert_spki_sha256
+        "#,
+    )
+    .bind(credential_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| AcError::Database(format!("Failed to activate credential: {}", e)))?;
+
+    Ok(credential)
+}
+
 /// Get all active service credentials by service type
 #[allow(dead_code)] // Library function - will be used in Phase 4 admin endpoints
+#[instrument(
+    name = "ac.db.service_credentials.get_active_by_service_type",
+    skip_all
+)]
 pub async fn get_active_by_service_type(
     pool: &PgPool,
     service_type: &str,
@@ -120,7 +148,7 @@ pub async fn get_active_by_service_type(
         r#"
         SELECT
             credential_id, client_id, client_secret_hash, service_type, region, scopes,
-            is_active, created_at, updated_at
+            is_active, created_at, updated_at, client_cert_spki_sha256
         FROM service_credentials
         WHERE service_type = $1 AND is_active = true
         ORDER BY created_at DESC
@@ -135,12 +163,13 @@ pub async fn get_active_by_service_type(
 }
 
 /// Get all service credentials (for admin listing)
+#[instrument(name = "ac.db.service_credentials.get_all", skip_all)]
 pub async fn get_all(pool: &PgPool) -> Result<Vec<ServiceCredential>, AcError> {
     let credentials = sqlx::query_as::<_, ServiceCredential>(
         r#"
         SELECT
             credential_id, client_id, client_secret_hash, service_type, region, scopes,
-            is_active, created_at, updated_at
+            is_active, created_at, updated_at, client_cert_spki_sha256
         FROM service_credentials
         ORDER BY created_at DESC
         "#,
@@ -153,6 +182,7 @@ pub async fn get_all(pool: &PgPool) -> Result<Vec<ServiceCredential>, AcError> {
 }
 
 /// Get service credential by credential_id
+#[instrument(name = "ac.db.service_credentials.get_by_credential_id", skip_all)]
 pub async fn get_by_credential_id(
     pool: &PgPool,
     credential_id: Uuid,
@@ -161,7 +191,7 @@ pub async fn get_by_credential_id(
         r#"
         SELECT
             credential_id, client_id, client_secret_hash, service_type, region, scopes,
-            is_active, created_at, updated_at
+            is_active, created_at, updated_at, client_cert_spki_sha256
         FROM service_credentials
         WHERE credential_id = $1
         "#,
@@ -175,6 +205,7 @@ pub async fn get_by_credential_id(
 }
 
 /// Update service credential metadata (name is stored in service_type for now)
+#[instrument(name = "ac.db.service_credentials.update_metadata", skip_all)]
 pub async fn update_metadata(
     pool: &PgPool,
     credential_id: Uuid,
@@ -187,7 +218,7 @@ pub async fn update_metadata(
         WHERE credential_id = $1
         RETURNING
             credential_id, client_id, client_secret_hash, service_type, region, scopes,
-            is_active, created_at, updated_at
+            is_active, created_at, updated_at, client_cert_spki_sha256
         "#,
     )
     .bind(credential_id)
@@ -199,7 +230,40 @@ pub async fn update_metadata(
     Ok(credential)
 }
 
+/// Pin (or with `None`, unpin) the client certificate for a credential.
+///
+/// `spki_sha256` must already be normalized; see
+/// [`crate::mtls::normalize_fingerprint`].
+#[instrument(
+    name = "ac.db.service_credentials.set_client_cert_fingerprint",
+    skip_all
+)]
+pub async fn set_client_cert_fingerprint(
+    pool: &PgPool,
+    credential_id: Uuid,
+    spki_sha256: Option<&str>,
+) -> Result<ServiceCredential, AcError> {
+    let credential = sqlx::query_as::<_, ServiceCredential>(
+        r#"
+        UPDATE service_credentials
+        SET client_cert_spki_sha256 = $2, updated_at = NOW()
+        WHERE credential_id = $1
+        RETURNING
+            credential_id, client_id, client_secret_hash, service_type, region, scopes,
+            is_active, created_at, updated_at, client_cert_spki_sha256
+        "#,
+    )
+    .bind(credential_id)
+    .bind(spki_sha256)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| AcError::Database(format!("Failed to pin client certificate: {}", e)))?;
+
+    Ok(credential)
+}
+
 /// Delete service credential (hard delete)
+#[instrument(name = "ac.db.service_credentials.delete", skip_all)]
 pub async fn delete(pool: &PgPool, credential_id: Uuid) -> Result<(), AcError> {
     let result = sqlx::query(
         r#"
@@ -220,6 +284,7 @@ pub async fn delete(pool: &PgPool, credential_id: Uuid) -> Result<(), AcError> {
 }
 
 /// Rotate client secret (update hash)
+#[instrument(name = "ac.db.service_credentials.rotate_secret", skip_all)]
 pub async fn rotate_secret(
     pool: &PgPool,
     credential_id: Uuid,
@@ -232,7 +297,7 @@ pub async fn rotate_secret(
         WHERE credential_id = $1
         RETURNING
             credential_id, client_id, client_secret_hash, service_type, region, scopes,
-            is_active, created_at, updated_at
+            is_active, created_at, updated_at, client_cert_spki_sha256
         "#,
     )
     .bind(credential_id)
@@ -671,4 +736,52 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_set_client_cert_fingerprint(pool: PgPool) -> Result<(), AcError> {
+        let fingerprint = "ab".repeat(32);
+        let created = create_service_credential(
+            &pool,
+            "cert-test-client",
+            "hash",
+            "global-controller",
+            None,
+            &[],
+        )
+        .await?;
+        assert!(created.client_cert_spki_sha256.is_none());
+
+        let pinned =
+            set_client_cert_fingerprint(&pool, created.credential_id, Some(&fingerprint)).await?;
+        assert_eq!(
+            pinned.client_cert_spki_sha256.as_deref(),
+            Some(&*fingerprint)
+        );
+
+        let retrieved = get_by_client_id(&pool, "cert-test-client").await?.unwrap();
+        assert_eq!(retrieved.client_cert_spki_sha256, Some(fingerprint.clone()));
+
+        // The same key cannot be pinned to a second credential
+        let other = create_service_credential(
+            &pool,
+            "cert-test-client-2",
+            "hash",
+            "global-controller",
+            None,
+            &[],
+        )
+        .await?;
+        let result =
+            set_client_cert_fingerprint(&pool, other.credential_id, Some(&fingerprint)).await;
+        assert!(matches!(result, Err(AcError::Database(_))));
+
+        // Malformed fingerprints are rejected by the database
+        let result = set_client_cert_fingerprint(&pool, other.credential_id, Some("AB:CD")).await;
+        assert!(matches!(result, Err(AcError::Database(_))));
+
+        let unpinned = set_client_cert_fingerprint(&pool, created.credential_id, None).await?;
+        assert!(unpinned.client_cert_spki_sha256.is_none());
+
+        Ok(())
+    }
 }
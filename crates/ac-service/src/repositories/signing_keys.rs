@@ -4,9 +4,11 @@ use crate::observability::metrics::record_db_query;
 use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use std::time::Instant;
+use tracing::instrument;
 
 /// Create a new signing key
 #[expect(clippy::too_many_arguments)] // Represents all signing_keys table columns
+#[instrument(name = "ac.db.signing_keys.create_signing_key", skip_all)]
 pub async fn create_signing_key(
     pool: &PgPool,
     key_id: &str,
@@ -52,6 +54,7 @@ pub async fn create_signing_key(
 }
 
 /// Get the currently active signing key
+#[instrument(name = "ac.db.signing_keys.get_active_key", skip_all)]
 pub async fn get_active_key(pool: &PgPool) -> Result<Option<SigningKey>, AcError> {
     let start = Instant::now();
     let result = sqlx::query_as::<_, SigningKey>(
@@ -80,6 +83,7 @@ pub async fn get_active_key(pool: &PgPool) -> Result<Option<SigningKey>, AcError
 
 /// Get signing key by key_id
 #[allow(dead_code)] // Library function - will be used in Phase 4 JWKS/admin endpoints
+#[instrument(name = "ac.db.signing_keys.get_by_key_id", skip_all)]
 pub async fn get_by_key_id(pool: &PgPool, key_id: &str) -> Result<Option<SigningKey>, AcError> {
     let key = sqlx::query_as::<_, SigningKey>(
         r#"
@@ -101,6 +105,7 @@ pub async fn get_by_key_id(pool: &PgPool, key_id: &str) -> Result<Option<Signing
 
 /// Mark old keys as inactive and new key as active (key rotation)
 #[allow(dead_code)] // Library function - will be used in Phase 4 key rotation
+#[instrument(name = "ac.db.signing_keys.rotate_key", skip_all)]
 pub async fn rotate_key(pool: &PgPool, new_key_id: &str) -> Result<(), AcError> {
     // Start transaction
     let mut tx = pool
@@ -142,6 +147,7 @@ pub async fn rotate_key(pool: &PgPool, new_key_id: &str) -> Result<(), AcError>
 }
 
 /// Get all active public keys (for JWKS endpoint)
+#[instrument(name = "ac.db.signing_keys.get_all_active_keys", skip_all)]
 pub async fn get_all_active_keys(pool: &PgPool) -> Result<Vec<SigningKey>, AcError> {
     let start = Instant::now();
     let result = sqlx::query_as::<_, SigningKey>(
@@ -169,6 +175,7 @@ pub async fn get_all_active_keys(pool: &PgPool) -> Result<Vec<SigningKey>, AcErr
 
 /// Mark a key as inactive
 #[allow(dead_code)] // Library function - will be used in Phase 4 key management
+#[instrument(name = "ac.db.signing_keys.deactivate_key", skip_all)]
 pub async fn deactivate_key(pool: &PgPool, key_id: &str) -> Result<(), AcError> {
     sqlx::query(
         r#"
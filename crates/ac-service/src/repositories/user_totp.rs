@@ -0,0 +1,206 @@
+//! User TOTP repository module for database operations.
+//!
+//! One row per user. A row starts unconfirmed when the user enrolls and is
+//! only required at login once [`confirm`] has run. Secrets are stored
+//! encrypted with the master key.
+
+use crate::errors::AcError;
+use crate::observability::metrics::record_db_query;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::time::Instant;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// TOTP enrollment model (maps to user_totp table)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserTotp {
+    pub user_id: Uuid,
+    pub secret_encrypted: Vec<u8>,
+    pub secret_nonce: Vec<u8>,
+    pub secret_tag: Vec<u8>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub last_used_step: Option<i64>,
+}
+
+/// Get a user's TOTP enrollment, confirmed or not.
+#[instrument(name = "ac.db.user_totp.get", skip_all)]
+pub async fn get(pool: &PgPool, user_id: Uuid) -> Result<Option<UserTotp>, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query_as::<_, UserTotp>(
+        r#"
+        SELECT
+            user_id, secret_encrypted, secret_nonce, secret_tag,
+            confirmed_at, last_used_step
+        FROM user_totp
+        WHERE user_id = $1
+        "#,
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("select", "user_totp", status, start.elapsed());
+
+    result.map_err(|e| AcError::Database(format!("Failed to fetch user TOTP: {}", e)))
+}
+
+/// Store a new, unconfirmed secret for a user.
+///
+/// Replaces any existing enrollment, confirmed or not: until the new secret
+/// is confirmed, the user logs in with a password alone.
+#[instrument(name = "ac.db.user_totp.upsert_pending", skip_all)]
+pub async fn upsert_pending(
+    pool: &PgPool,
+    user_id: Uuid,
+    secret_encrypted: &[u8],
+    secret_nonce: &[u8],
+    secret_tag: &[u8],
+) -> Result<(), AcError> {
+    let start = Instant::now();
+    let result = sqlx::query(
+        r#"
+        INSERT INTO user_totp (user_id, secret_encrypted, secret_nonce, secret_tag)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (user_id) DO UPDATE
+        SET secret_encrypted = EXCLUDED.secret_encrypted,
+            secret_nonce = EXCLUDED.secret_nonce,
+            secret_tag = EXCLUDED.secret_tag,
+            confirmed_at = NULL,
+            last_used_step = NULL,
+            created_at = NOW()
+        "#,
+    )
+    .bind(user_id)
+    .bind(secret_encrypted)
+    .bind(secret_nonce)
+    .bind(secret_tag)
+    .execute(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("upsert", "user_totp", status, start.elapsed());
+    result.map_err(|e| AcError::Database(format!("Failed to store user TOTP: {}", e)))?;
+
+    Ok(())
+}
+
+/// Confirm a pending enrollment with the time step of the code that proved it.
+///
+/// Returns `false` if the user has no pending enrollment.
+#[instrument(name = "ac.db.user_totp.confirm", skip_all)]
+pub async fn confirm(pool: &PgPool, user_id: Uuid, step: i64) -> Result<bool, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query(
+        r#"
+        UPDATE user_totp
+        SET confirmed_at = NOW(), last_used_step = $2
+        WHERE user_id = $1 AND confirmed_at IS NULL
+        "#,
+    )
+    .bind(user_id)
+    .bind(step)
+    .execute(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("update", "user_totp", status, start.elapsed());
+    let result =
+        result.map_err(|e| AcError::Database(format!("Failed to confirm user TOTP: {}", e)))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Record that a code from `step` was used.
+///
+/// Returns `false` if a code from this step or a later one was already
+/// accepted, i.e. the code is a replay. The check and update are atomic, so
+/// two concurrent logins with one code cannot both succeed.
+#[instrument(name = "ac.db.user_totp.record_used_step", skip_all)]
+pub async fn record_used_step(pool: &PgPool, user_id: Uuid, step: i64) -> Result<bool, AcError> {
+    let start = Instant::now();
+    let result = sqlx::query(
+        r#"
+        UPDATE user_totp
+        SET last_used_step = $2
+        WHERE user_id = $1
+            AND confirmed_at IS NOT NULL
+            AND (last_used_step IS NULL OR last_used_step < $2)
+        "#,
+    )
+    .bind(user_id)
+    .bind(step)
+    .execute(pool)
+    .await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("update", "user_totp", status, start.elapsed());
+    let result =
+        result.map_err(|e| AcError::Database(format!("Failed to record TOTP use: {}", e)))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::users;
+
+    async fn create_user(pool: &PgPool) -> Uuid {
+        let org_id: (Uuid,) = sqlx::query_as(
+            r#"
+            INSERT INTO organizations (subdomain, display_name)
+            VALUES ('totp-repo', 'TOTP Repo')
+            RETURNING org_id
+            "#,
+        )
+        .fetch_one(pool)
+        .await
+        .expect("Should create organization");
+
+        users::create_user(pool, org_id.0, "totp@example.com", "hash", "TOTP User")
+            .await
+            .unwrap()
+            .user_id
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_enrollment_lifecycle(pool: PgPool) -> Result<(), AcError> {
+        let user_id = create_user(&pool).await;
+        assert!(get(&pool, user_id).await?.is_none());
+
+        upsert_pending(&pool, user_id, b"secret", &[1; 12], &[2; 16]).await?;
+        let pending = get(&pool, user_id).await?.unwrap();
+        assert!(pending.confirmed_at.is_none());
+        assert_eq!(pending.secret_encrypted, b"secret");
+
+        // Codes are not accepted against a pending enrollment
+        assert!(!record_used_step(&pool, user_id, 100).await?);
+
+        assert!(confirm(&pool, user_id, 100).await?);
+        assert!(!confirm(&pool, user_id, 101).await?, "already confirmed");
+        let confirmed = get(&pool, user_id).await?.unwrap();
+        assert!(confirmed.confirmed_at.is_some());
+        assert_eq!(confirmed.last_used_step, Some(100));
+
+        // Re-enrolling replaces the confirmed secret with a pending one
+        upsert_pending(&pool, user_id, b"rotated", &[3; 12], &[4; 16]).await?;
+        let rotated = get(&pool, user_id).await?.unwrap();
+        assert!(rotated.confirmed_at.is_none());
+        assert_eq!(rotated.last_used_step, None);
+        assert_eq!(rotated.secret_encrypted, b"rotated");
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_record_used_step_rejects_replays(pool: PgPool) -> Result<(), AcError> {
+        let user_id = create_user(&pool).await;
+        upsert_pending(&pool, user_id, b"secret", &[1; 12], &[2; 16]).await?;
+        confirm(&pool, user_id, 100).await?;
+
+        assert!(!record_used_step(&pool, user_id, 100).await?, "same step");
+        assert!(!record_used_step(&pool, user_id, 99).await?, "earlier step");
+        assert!(record_used_step(&pool, user_id, 101).await?);
+        assert!(!record_used_step(&pool, user_id, 101).await?);
+
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use crate::observability::metrics::record_db_query;
 use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use std::time::Instant;
+use tracing::instrument;
 use uuid::Uuid;
 
 /// User model (maps to users table)
@@ -28,6 +29,7 @@ pub struct User {
 /// Get user by email within an organization.
 ///
 /// Users are unique per org (org_id, email is unique constraint).
+#[instrument(name = "ac.db.users.get_by_email", skip_all)]
 pub async fn get_by_email(
     pool: &PgPool,
     org_id: Uuid,
@@ -56,7 +58,7 @@ pub async fn get_by_email(
 }
 
 /// Get user by user_id.
-#[allow(dead_code)] // Library function - will be used in future phases
+#[instrument(name = "ac.db.users.get_by_id", skip_all)]
 pub async fn get_by_id(pool: &PgPool, user_id: Uuid) -> Result<Option<User>, AcError> {
     let start = Instant::now();
     let result = sqlx::query_as::<_, User>(
@@ -82,6 +84,7 @@ pub async fn get_by_id(pool: &PgPool, user_id: Uuid) -> Result<Option<User>, AcE
 /// Create a new user in an organization.
 ///
 /// Returns the created user record.
+#[instrument(name = "ac.db.users.create_user", skip_all)]
 pub async fn create_user(
     pool: &PgPool,
     org_id: Uuid,
@@ -119,7 +122,121 @@ pub async fn create_user(
     Ok(user)
 }
 
+/// A user to create in a bulk import.
+#[derive(Debug, Clone)]
+pub struct NewUser {
+    pub email: String,
+    pub display_name: String,
+    /// Bcrypt hash carried over from the source system.
+    pub password_hash: String,
+    /// Role granted in addition to the default "user" role.
+    pub extra_role: Option<String>,
+}
+
+/// Result of a bulk user import.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BulkCreateOutcome {
+    /// Every user was created; (user_id, email) in input order.
+    Created(Vec<(Uuid, String)>),
+    /// Nothing was created because these emails already exist in the org.
+    EmailsTaken(Vec<String>),
+}
+
+/// Create many users in an organization in one transaction.
+///
+/// Either every user is created (each with the "user" role plus its
+/// `extra_role`) or none are. Emails already in the org are reported instead
+/// of failing on the unique constraint.
+#[instrument(name = "ac.db.users.create_users_bulk", skip_all)]
+pub async fn create_users_bulk(
+    pool: &PgPool,
+    org_id: Uuid,
+    users: &[NewUser],
+) -> Result<BulkCreateOutcome, AcError> {
+    let start = Instant::now();
+    let result = create_users_bulk_tx(pool, org_id, users).await;
+    let status = if result.is_ok() { "success" } else { "error" };
+    record_db_query("insert", "users", status, start.elapsed());
+
+    result
+}
+
+async fn create_users_bulk_tx(
+    pool: &PgPool,
+    org_id: Uuid,
+    users: &[NewUser],
+) -> Result<BulkCreateOutcome, AcError> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| AcError::Database(format!("Failed to start transaction: {}", e)))?;
+
+    let emails: Vec<&str> = users.iter().map(|u| u.email.as_str()).collect();
+    let taken: Vec<(String,)> = sqlx::query_as(
+        r#"
+        SELECT email
+        FROM users
+        WHERE org_id = $1 AND email = ANY($2)
+        ORDER BY email
+        "#,
+    )
+    .bind(org_id)
+    .bind(&emails)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| AcError::Database(format!("Failed to check existing emails: {}", e)))?;
+
+    if !taken.is_empty() {
+        return Ok(BulkCreateOutcome::EmailsTaken(
+            taken.into_iter().map(|(email,)| email).collect(),
+        ));
+    }
+
+    let mut created = Vec::with_capacity(users.len());
+    for user in users {
+        let (user_id,): (Uuid,) = sqlx::query_as(
+            r#"
+            INSERT INTO users (org_id, email, password_hash, display_name)
+            VALUES ($1, $2, $3, $4)
+            RETURNING user_id
+            "#,
+        )
+        .bind(org_id)
+        .bind(&user.email)
+        .bind(&user.password_hash)
+        .bind(&user.display_name)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AcError::Database(format!("Failed to create user: {}", e)))?;
+
+        let roles = std::iter::once("user").chain(user.extra_role.as_deref());
+        for role in roles {
+            sqlx::query(
+                r#"
+                INSERT INTO user_roles (user_id, role)
+                VALUES ($1, $2)
+                ON CONFLICT (user_id, role) DO NOTHING
+                "#,
+            )
+            .bind(user_id)
+            .bind(role)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AcError::Database(format!("Failed to add user role: {}", e)))?;
+        }
+
+        created.push((user_id, user.email.clone()));
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| AcError::Database(format!("Failed to commit user import: {}", e)))?;
+
+    Ok(BulkCreateOutcome::Created(created))
+}
+
 /// Update the last_login_at timestamp for a user.
+#[instrument(name = "ac.db.users.update_last_login", skip_all)]
 pub async fn update_last_login(pool: &PgPool, user_id: Uuid) -> Result<(), AcError> {
     let start = Instant::now();
     let result = sqlx::query(
@@ -142,6 +259,7 @@ pub async fn update_last_login(pool: &PgPool, user_id: Uuid) -> Result<(), AcErr
 /// Get all roles for a user.
 ///
 /// Returns a list of role strings (e.g., ["user", "admin"]).
+#[instrument(name = "ac.db.users.get_user_roles", skip_all)]
 pub async fn get_user_roles(pool: &PgPool, user_id: Uuid) -> Result<Vec<String>, AcError> {
     let start = Instant::now();
     let result = sqlx::query_as(
@@ -166,6 +284,7 @@ pub async fn get_user_roles(pool: &PgPool, user_id: Uuid) -> Result<Vec<String>,
 /// Add a role to a user.
 ///
 /// Ignores duplicates (role already exists).
+#[instrument(name = "ac.db.users.add_user_role", skip_all)]
 pub async fn add_user_role(pool: &PgPool, user_id: Uuid, role: &str) -> Result<(), AcError> {
     // Validate role value
     if !["user", "admin", "org_admin"].contains(&role) {
@@ -193,6 +312,7 @@ pub async fn add_user_role(pool: &PgPool, user_id: Uuid, role: &str) -> Result<(
 
 /// Remove a role from a user.
 #[allow(dead_code)] // Library function - will be used in future phases
+#[instrument(name = "ac.db.users.remove_user_role", skip_all)]
 pub async fn remove_user_role(pool: &PgPool, user_id: Uuid, role: &str) -> Result<(), AcError> {
     let start = Instant::now();
     let result = sqlx::query(
@@ -215,6 +335,7 @@ pub async fn remove_user_role(pool: &PgPool, user_id: Uuid, role: &str) -> Resul
 /// Check if email exists in an organization.
 ///
 /// Used for registration validation.
+#[instrument(name = "ac.db.users.email_exists_in_org", skip_all)]
 pub async fn email_exists_in_org(
     pool: &PgPool,
     org_id: Uuid,
@@ -472,4 +593,66 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_create_users_bulk_all_or_nothing(pool: PgPool) -> Result<(), AcError> {
+        let org_id: (Uuid,) = sqlx::query_as(
+            r#"
+            INSERT INTO organizations (subdomain, display_name)
+            VALUES ('import-org', 'Import Org')
+            RETURNING org_id
+            "#,
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Should create organization");
+
+        let new_user = |email: &str, extra_role: Option<&str>| NewUser {
+            email: email.to_string(),
+            display_name: "Imported".to_string(),
+            password_hash: "hash".to_string(),
+            extra_role: extra_role.map(str::to_string),
+        };
+
+        let outcome = create_users_bulk(
+            &pool,
+            org_id.0,
+            &[
+                new_user("a@example.com", None),
+                new_user("b@example.com", Some("org_admin")),
+            ],
+        )
+        .await?;
+        let created = match outcome {
+            BulkCreateOutcome::Created(created) => created,
+            BulkCreateOutcome::EmailsTaken(taken) => {
+                return Err(AcError::Database(format!(
+                    "Unexpected taken emails: {taken:?}"
+                )));
+            }
+        };
+        assert_eq!(created.len(), 2);
+        assert_eq!(
+            get_user_roles(&pool, created[1].0).await?,
+            vec!["org_admin".to_string(), "user".to_string()]
+        );
+
+        // A second import reusing an email creates nothing.
+        let outcome = create_users_bulk(
+            &pool,
+            org_id.0,
+            &[
+                new_user("c@example.com", None),
+                new_user("a@example.com", None),
+            ],
+        )
+        .await?;
+        assert_eq!(
+            outcome,
+            BulkCreateOutcome::EmailsTaken(vec!["a@example.com".to_string()])
+        );
+        assert!(!email_exists_in_org(&pool, org_id.0, "c@example.com").await?);
+
+        Ok(())
+    }
 }
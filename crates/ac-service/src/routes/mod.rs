@@ -1,22 +1,72 @@
 use crate::handlers::{admin_handler, auth_handler, internal_tokens, jwks_handler};
-use crate::middleware::auth::{require_admin_scope, require_service_auth, AuthMiddlewareState};
+use crate::middleware::auth::{
+    require_admin_scope, require_debug_scope, require_key_replication_scope, require_service_auth,
+    AuthMiddlewareState,
+};
 use crate::middleware::http_metrics::http_metrics_middleware;
 use crate::middleware::org_extraction::{require_org_context, OrgExtractionState};
 use crate::repositories::signing_keys;
 use axum::{
+    body::Body,
     extract::State,
-    http::StatusCode,
+    http::{Request, StatusCode},
     middleware,
     response::IntoResponse,
-    routing::{get, post},
+    routing::{get, patch, post},
     Json, Router,
 };
+use common::build_info::version_router;
+use common::log_level::{log_level_router, LogLevelHandle};
+use common::request_id::RequestIdLayer;
 use metrics_exporter_prometheus::PrometheusHandle;
 use serde::Serialize;
 use std::sync::Arc;
 use std::time::Duration;
 use tower_http::{timeout::TimeoutLayer, trace::TraceLayer};
 
+/// Operational routes: `/health`, `/ready`, `/metrics`, and `/version`.
+///
+/// Included in [`build_routes`]; also served alone on the health socket
+/// when `AC_HEALTH_SOCKET_PATH` is set.
+pub fn build_ops_routes(
+    state: Arc<auth_handler::AppState>,
+    metrics_handle: PrometheusHandle,
+) -> Router {
+    // Metrics route with its own state (ADR-0011)
+    let metrics_routes = Router::new()
+        .route("/metrics", get(metrics_endpoint))
+        .with_state(metrics_handle);
+
+    Router::new()
+        // Health check (liveness probe) - simple response, always returns OK if process is running
+        .route("/health", get(health_check))
+        // Readiness probe - verifies DB connectivity and signing key availability
+        // ADR-0012: K8s should only route traffic when service is ready
+        .route("/ready", get(readiness_check))
+        .with_state(state)
+        .merge(metrics_routes)
+        .merge(version_router(common::build_info!("ac-service")))
+}
+
+/// Runtime log filter endpoint (`/debug/log-level`), requiring a token with
+/// the `admin:debug` scope.
+pub fn build_log_level_routes(
+    state: Arc<auth_handler::AppState>,
+    log_level: LogLevelHandle,
+) -> Router {
+    let auth_state = Arc::new(AuthMiddlewareState {
+        pool: state.pool.clone(),
+        jwt_clock_skew_seconds: state.config.jwt_clock_skew_seconds,
+    });
+
+    log_level_router(log_level)
+        .layer(middleware::from_fn_with_state(
+            auth_state,
+            require_debug_scope,
+        ))
+        .layer(RequestIdLayer)
+}
+
 pub fn build_routes(
     state: Arc<auth_handler::AppState>,
     metrics_handle: PrometheusHandle,
@@ -33,6 +83,19 @@ pub fn build_routes(
             "/api/v1/admin/services/register",
             post(admin_handler::handle_register_service),
         )
+        // Service credential management by client_id
+        .route(
+            "/api/v1/admin/services",
+            get(admin_handler::handle_list_services),
+        )
+        .route(
+            "/api/v1/admin/services/{client_id}",
+            patch(admin_handler::handle_update_service),
+        )
+        .route(
+            "/api/v1/admin/services/{client_id}/rotate-secret",
+            post(admin_handler::handle_rotate_service_secret),
+        )
         // OAuth client management CRUD endpoints
         .route(
             "/api/v1/admin/clients",
@@ -48,6 +111,23 @@ pub fn build_routes(
             "/api/v1/admin/clients/{id}/rotate-secret",
             post(admin_handler::handle_rotate_client_secret),
         )
+        // Bulk user import for org migrations
+        .route(
+            "/api/v1/admin/orgs/{id}/users/import",
+            post(admin_handler::handle_import_users),
+        )
+        // Org SSO provider (OIDC federation)
+        .route(
+            "/api/v1/admin/orgs/{id}/oidc",
+            get(admin_handler::handle_get_oidc_provider)
+                .put(admin_handler::handle_set_oidc_provider)
+                .delete(admin_handler::handle_delete_oidc_provider),
+        )
+        // Audit log query (auth_events)
+        .route(
+            "/api/v1/admin/audit-events",
+            get(admin_handler::handle_list_audit_events),
+        )
         .layer(middleware::from_fn_with_state(
             auth_state.clone(),
             require_admin_scope,
@@ -63,6 +143,22 @@ pub fn build_routes(
         )
         .with_state(state.clone());
 
+    // Signing key replication between regions (admin.replicate-keys.ac scope)
+    let key_replication_routes = Router::new()
+        .route(
+            "/internal/keys/export",
+            post(admin_handler::handle_export_keys),
+        )
+        .route(
+            "/internal/keys/import",
+            post(admin_handler::handle_import_keys),
+        )
+        .layer(middleware::from_fn_with_state(
+            auth_state.clone(),
+            require_key_replication_scope,
+        ))
+        .with_state(state.clone());
+
     // Internal token endpoints (meeting and guest tokens)
     // These require service authentication with internal:meeting-token scope
     let internal_token_routes = Router::new()
@@ -80,10 +176,21 @@ pub fn build_routes(
         ))
         .with_state(state.clone());
 
-    // Metrics route with its own state (ADR-0011)
-    let metrics_routes = Router::new()
-        .route("/metrics", get(metrics_endpoint))
-        .with_state(metrics_handle);
+    // Token introspection (RFC 7662) for downstream services
+    // Any authenticated service may introspect
+    let introspection_routes = Router::new()
+        .route(
+            "/api/v1/auth/introspect",
+            post(auth_handler::handle_introspect),
+        )
+        .layer(middleware::from_fn_with_state(
+            auth_state.clone(),
+            require_service_auth,
+        ))
+        .with_state(state.clone());
+
+    // Health, readiness, and metrics endpoints
+    let ops_routes = build_ops_routes(state.clone(), metrics_handle);
 
     // Org extraction state for user auth routes
     let org_extraction_state = Arc::new(OrgExtractionState {
@@ -97,6 +204,31 @@ pub fn build_routes(
             "/api/v1/auth/user/token",
             post(auth_handler::handle_user_token),
         )
+        .route(
+            "/api/v1/auth/user/token/refresh",
+            post(auth_handler::handle_user_refresh_token),
+        )
+        .route(
+            "/api/v1/auth/user/login",
+            post(auth_handler::handle_user_login),
+        )
+        .route(
+            "/api/v1/auth/user/totp",
+            post(auth_handler::handle_user_totp_enroll),
+        )
+        .route(
+            "/api/v1/auth/user/totp/confirm",
+            post(auth_handler::handle_user_totp_confirm),
+        )
+        // Org SSO through the org's identity provider (OIDC federation)
+        .route(
+            "/api/v1/auth/oidc/authorize",
+            get(auth_handler::handle_oidc_authorize),
+        )
+        .route(
+            "/api/v1/auth/oidc/callback",
+            get(auth_handler::handle_oidc_callback),
+        )
         .route("/api/v1/auth/register", post(auth_handler::handle_register))
         .layer(middleware::from_fn_with_state(
             org_extraction_state,
@@ -113,30 +245,44 @@ pub fn build_routes(
         )
         // JWKS endpoint (RFC 8414 well-known path, no /api/v1 prefix)
         .route("/.well-known/jwks.json", get(jwks_handler::handle_get_jwks))
-        // Health check (liveness probe) - simple response, always returns OK if process is running
-        .route("/health", get(health_check))
-        // Readiness probe - verifies DB connectivity and signing key availability
-        // ADR-0012: K8s should only route traffic when service is ready
-        .route("/ready", get(readiness_check))
         .with_state(state);
 
     // Merge routes with global layers
     // Layer order (bottom-to-top execution):
     // 1. TimeoutLayer - Timeout the request (innermost)
-    // 2. TraceLayer - Log request details
-    // 3. http_metrics_middleware - Record ALL responses (outermost)
+    // 2. TraceLayer - Log request details; the request span joins the
+    //    caller's trace (W3C traceparent) when trace export is on
+    // 3. http_metrics_middleware - Record ALL responses
+    // 4. RequestIdLayer - Assign x-request-id and scope logs to it (outermost)
     admin_routes
         .merge(key_rotation_routes)
+        .merge(key_replication_routes)
         .merge(internal_token_routes)
+        .merge(introspection_routes)
         .merge(user_auth_routes)
-        .merge(metrics_routes)
+        .merge(ops_routes)
         .merge(public_routes)
-        .layer(TraceLayer::new_for_http())
+        .layer(TraceLayer::new_for_http().make_span_with(make_request_span))
         // ADR-0012: 30s HTTP request timeout to prevent hung connections
         .layer(TimeoutLayer::new(Duration::from_secs(30)))
         // HTTP metrics layer (outermost) - captures ALL responses including
         // framework-level errors like 415, 400, 404, 405
         .layer(middleware::from_fn(http_metrics_middleware))
+        .layer(RequestIdLayer)
+}
+
+/// Span for an inbound HTTP request, made a child of the caller's span
+/// when the request carries a W3C `traceparent` header.
+///
+/// Only the path is recorded: query strings can carry OIDC codes.
+fn make_request_span(request: &Request<Body>) -> tracing::Span {
+    let span = tracing::info_span!(
+        "ac.http.request",
+        method = %request.method(),
+        path = %request.uri().path(),
+    );
+    common::otel::set_parent_from_headers(&span, request.headers());
+    span
 }
 
 /// Liveness probe - returns OK if the process is running
@@ -249,6 +395,7 @@ async fn metrics_endpoint(State(handle): State<PrometheusHandle>) -> String {
 mod tests {
     use super::*;
     use crate::config::Config;
+    use common::observability::prometheus_testing::{PrometheusCapture, RenderedMetrics};
     use sqlx::PgPool;
 
     /// Test-only version of ReadinessResponse with owned strings for deserialization
@@ -302,26 +449,36 @@ mod tests {
 
     #[tokio::test]
     async fn test_metrics_endpoint() {
-        // Initialize metrics recorder (may fail if already installed in process)
-        let handle = match crate::observability::metrics::init_metrics_recorder() {
-            Ok(h) => h,
-            Err(_) => {
-                // If recorder already installed, we can't test this in isolation.
-                // This is expected when running multiple tests in the same process.
-                // The function is also covered by E2E tests in server_harness.
-                return;
-            }
-        };
+        // A thread-local recorder with production buckets sidesteps the
+        // once-per-process global recorder
+        let builder = crate::observability::metrics::metrics_builder()
+            .expect("AC bucket configuration should be valid");
+        let capture = PrometheusCapture::with_builder(builder);
+
+        crate::observability::metrics::record_token_issuance(
+            "client_credentials",
+            "success",
+            std::time::Duration::from_millis(120),
+        );
 
         // Call the endpoint handler
-        let result = metrics_endpoint(State(handle)).await;
+        let result = metrics_endpoint(State(capture.handle())).await;
+        let rendered = RenderedMetrics::parse(&result);
 
-        // Verify metrics are in Prometheus text format
-        // Empty is valid (no metrics recorded yet)
-        // Non-empty should contain Prometheus format markers (# for comments/metadata)
+        assert_eq!(
+            rendered.counter(
+                "ac_token_issuance_total",
+                &[("grant_type", "client_credentials"), ("status", "success")]
+            ),
+            Some(1.0)
+        );
+        let duration = rendered
+            .histogram("ac_token_issuance_duration_seconds", &[])
+            .expect("token issuance histogram should be rendered");
+        assert_eq!(duration.count, 1);
         assert!(
-            result.is_empty() || result.contains('#'),
-            "Metrics should be in Prometheus text format"
+            duration.bucket_bounds().contains(&0.350),
+            "Token issuance buckets should include the 350ms SLO boundary"
         );
     }
 
@@ -338,8 +495,10 @@ mod tests {
         let config = Config {
             database_url: String::new(),
             bind_address: "127.0.0.1:0".to_string(),
+            health_socket: None,
             master_key: common::secret::SecretBox::new(Box::new(master_key.clone())),
             hash_secret: common::secret::SecretBox::new(Box::new(master_key.clone())),
+            key_replication_key: None,
             otlp_endpoint: None,
             jwt_clock_skew_seconds: crate::config::DEFAULT_JWT_CLOCK_SKEW.as_secs() as i64,
             bcrypt_cost: crate::config::DEFAULT_BCRYPT_COST,
@@ -349,11 +508,13 @@ mod tests {
                 crate::config::DEFAULT_REGISTRATION_RATE_LIMIT_WINDOW_MINUTES,
             registration_rate_limit_max_attempts:
                 crate::config::DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
+            client_rate_limits: crate::config::ClientRateLimits::default(),
+            token_ttls: crate::config::TokenTtlPolicy::default(),
+            secret_policy: crate::services::secret_policy::SecretPolicy::default(),
+            mtls: crate::config::MtlsConfig::default(),
+            master_key_source: crate::config::MasterKeySource::Env,
         };
-        let state = Arc::new(auth_handler::AppState {
-            pool: pool.clone(),
-            config,
-        });
+        let state = Arc::new(auth_handler::AppState::new(pool.clone(), config));
 
         // Call readiness check - it returns impl IntoResponse
         // We need to convert it to a response to inspect it
@@ -388,8 +549,10 @@ mod tests {
         let config = Config {
             database_url: String::new(),
             bind_address: "127.0.0.1:0".to_string(),
+            health_socket: None,
             master_key: common::secret::SecretBox::new(Box::new(vec![0u8; 32])), // Dummy key (won't be used)
             hash_secret: common::secret::SecretBox::new(Box::new(vec![0u8; 32])), // Dummy hash secret for tests
+            key_replication_key: None,
             otlp_endpoint: None,
             jwt_clock_skew_seconds: crate::config::DEFAULT_JWT_CLOCK_SKEW.as_secs() as i64,
             bcrypt_cost: crate::config::DEFAULT_BCRYPT_COST,
@@ -399,11 +562,13 @@ mod tests {
                 crate::config::DEFAULT_REGISTRATION_RATE_LIMIT_WINDOW_MINUTES,
             registration_rate_limit_max_attempts:
                 crate::config::DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
+            client_rate_limits: crate::config::ClientRateLimits::default(),
+            token_ttls: crate::config::TokenTtlPolicy::default(),
+            secret_policy: crate::services::secret_policy::SecretPolicy::default(),
+            mtls: crate::config::MtlsConfig::default(),
+            master_key_source: crate::config::MasterKeySource::Env,
         };
-        let state = Arc::new(auth_handler::AppState {
-            pool: pool.clone(),
-            config,
-        });
+        let state = Arc::new(auth_handler::AppState::new(pool.clone(), config));
 
         // Call readiness check - it returns impl IntoResponse
         let response_impl = readiness_check(State(state)).await;
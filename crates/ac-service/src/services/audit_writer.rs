@@ -0,0 +1,332 @@
+//! Batched writer for the auth_events audit log.
+//!
+//! Token issuance, registration, and key lifecycle events are queued on the
+//! [`AuditWriterHandle`] and written by a background actor in batches of up
+//! to [`AUDIT_BATCH_SIZE`], or every [`AUDIT_FLUSH_INTERVAL`], so a request
+//! does not wait on an audit INSERT.
+//!
+//! Some events are still written inline by [`record`]:
+//! - failed events with a user or credential, because the lockout checks in
+//!   `token_service` count them and the next attempt must see them;
+//! - every event when no writer is installed (tests, one-off tools) or the
+//!   writer's queue is full.
+//!
+//! A failed write is logged and counted in `ac_audit_log_failures_total`; it
+//! never fails the operation being audited.
+
+use crate::errors::AcError;
+use crate::models::NewAuthEvent;
+use crate::observability::metrics::record_audit_log_failure;
+use crate::repositories::auth_events;
+use sqlx::PgPool;
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::{interval_at, Instant, MissedTickBehavior};
+use tracing::{debug, instrument, warn};
+
+/// Maximum events per INSERT.
+pub const AUDIT_BATCH_SIZE: usize = 100;
+
+/// How long a queued event can wait before its batch is written.
+pub const AUDIT_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Mailbox size for the audit writer. Events beyond this are written inline.
+const AUDIT_CHANNEL_BUFFER: usize = 10_000;
+
+/// Writer used by [`record`], installed once at startup.
+static INSTALLED: OnceLock<AuditWriterHandle> = OnceLock::new();
+
+/// An event and the `event_type` label for `ac_audit_log_failures_total`.
+struct QueuedEvent {
+    event: NewAuthEvent,
+    failure_label: &'static str,
+}
+
+/// Messages handled by the [`AuditWriterActor`].
+enum AuditMessage {
+    Event(QueuedEvent),
+    /// Write everything queued so far, then reply.
+    Flush {
+        respond_to: oneshot::Sender<()>,
+    },
+}
+
+/// Handle to the [`AuditWriterActor`].
+///
+/// Cheap to clone; the actor writes what is queued and stops when the last
+/// handle is dropped.
+#[derive(Clone)]
+pub struct AuditWriterHandle {
+    sender: mpsc::Sender<AuditMessage>,
+}
+
+impl AuditWriterHandle {
+    /// Spawn a writer using [`AUDIT_BATCH_SIZE`] and [`AUDIT_FLUSH_INTERVAL`].
+    pub fn spawn(pool: PgPool) -> Self {
+        Self::spawn_with(pool, AUDIT_BATCH_SIZE, AUDIT_FLUSH_INTERVAL)
+    }
+
+    /// Spawn a writer with a custom batch size and flush interval.
+    pub fn spawn_with(pool: PgPool, batch_size: usize, flush_interval: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel(AUDIT_CHANNEL_BUFFER);
+        let actor = AuditWriterActor {
+            pool,
+            receiver,
+            batch_size: batch_size.max(1),
+            flush_interval,
+            pending: Vec::new(),
+        };
+        tokio::spawn(actor.run());
+
+        Self { sender }
+    }
+
+    /// Make this the writer used by [`record`].
+    ///
+    /// Returns `false` if a writer is already installed.
+    pub fn install(self) -> bool {
+        INSTALLED.set(self).is_ok()
+    }
+
+    /// Queue an event without waiting.
+    ///
+    /// Gives the event back if the queue is full or the writer has stopped.
+    pub fn try_send(
+        &self,
+        event: NewAuthEvent,
+        failure_label: &'static str,
+    ) -> Result<(), NewAuthEvent> {
+        let Err(e) = self.sender.try_send(AuditMessage::Event(QueuedEvent {
+            event,
+            failure_label,
+        })) else {
+            return Ok(());
+        };
+        match e.into_inner() {
+            AuditMessage::Event(queued) => Err(queued.event),
+            // Not sent here
+            AuditMessage::Flush { .. } => Ok(()),
+        }
+    }
+
+    /// Write every event queued before this call.
+    pub async fn flush(&self) -> Result<(), AcError> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(AuditMessage::Flush { respond_to: tx })
+            .await
+            .map_err(|_| AcError::Internal)?;
+
+        rx.await.map_err(|_| AcError::Internal)
+    }
+}
+
+/// The writer installed with [`AuditWriterHandle::install`], if any.
+pub fn installed() -> Option<&'static AuditWriterHandle> {
+    INSTALLED.get()
+}
+
+/// Record an audit event.
+pub async fn record(pool: &PgPool, event: NewAuthEvent) {
+    let failure_label = event.event_type.as_str();
+    record_as(pool, event, failure_label).await;
+}
+
+/// Record an audit event, labeling write failures with `failure_label`
+/// instead of the event type.
+///
+/// For events that reuse an event type for another action (e.g. scope
+/// updates recorded as `service_token_issued`).
+pub async fn record_as(pool: &PgPool, event: NewAuthEvent, failure_label: &'static str) {
+    let event = match installed() {
+        Some(writer) if !event.counts_toward_lockout() => {
+            match writer.try_send(event, failure_label) {
+                Ok(()) => return,
+                Err(event) => event,
+            }
+        }
+        _ => event,
+    };
+
+    if let Err(e) = auth_events::log_events(pool, std::slice::from_ref(&event)).await {
+        warn!("Failed to log auth event: {}", e);
+        record_audit_log_failure(failure_label, "db_write_failed");
+    }
+}
+
+/// Actor owning the queue of events waiting to be written.
+pub struct AuditWriterActor {
+    pool: PgPool,
+    receiver: mpsc::Receiver<AuditMessage>,
+    batch_size: usize,
+    flush_interval: Duration,
+    pending: Vec<QueuedEvent>,
+}
+
+impl AuditWriterActor {
+    /// Run the message loop until every handle is dropped, then write what
+    /// is left.
+    #[instrument(skip_all, name = "ac.audit.writer")]
+    async fn run(mut self) {
+        let mut ticker = interval_at(Instant::now() + self.flush_interval, self.flush_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                msg = self.receiver.recv() => match msg {
+                    Some(AuditMessage::Event(queued)) => {
+                        self.pending.push(queued);
+                        if self.pending.len() >= self.batch_size {
+                            self.write_pending().await;
+                        }
+                    }
+                    Some(AuditMessage::Flush { respond_to }) => {
+                        self.write_pending().await;
+                        let _ = respond_to.send(());
+                    }
+                    None => break,
+                },
+                _ = ticker.tick() => self.write_pending().await,
+            }
+        }
+
+        self.write_pending().await;
+        debug!(target: "ac.audit", "Audit writer stopped");
+    }
+
+    /// Write the pending events as one batch.
+    ///
+    /// If the batch fails, each event is retried alone so one bad event does
+    /// not lose the rest.
+    async fn write_pending(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let batch = std::mem::take(&mut self.pending);
+        let events: Vec<NewAuthEvent> = batch.iter().map(|q| q.event.clone()).collect();
+
+        let Err(e) = auth_events::log_events(&self.pool, &events).await else {
+            return;
+        };
+        warn!(
+            target: "ac.audit",
+            error = %e,
+            batch_size = batch.len(),
+            "Failed to write audit batch; retrying events individually"
+        );
+
+        for queued in &batch {
+            if let Err(e) =
+                auth_events::log_events(&self.pool, std::slice::from_ref(&queued.event)).await
+            {
+                warn!("Failed to log auth event: {}", e);
+                record_audit_log_failure(queued.failure_label, "db_write_failed");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::AuthEventType;
+    use chrono::{Duration as ChronoDuration, Utc};
+
+    async fn count_events(pool: &PgPool) -> usize {
+        let now = Utc::now();
+        auth_events::list_events(
+            pool,
+            None,
+            now - ChronoDuration::hours(1),
+            now + ChronoDuration::hours(1),
+            1000,
+        )
+        .await
+        .unwrap()
+        .len()
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_flush_writes_queued_events(pool: PgPool) {
+        let writer = AuditWriterHandle::spawn_with(pool.clone(), 100, Duration::from_secs(3600));
+
+        for _ in 0..5 {
+            writer
+                .try_send(
+                    NewAuthEvent::success(AuthEventType::KeyRotated),
+                    "key_rotated",
+                )
+                .unwrap();
+        }
+        assert_eq!(count_events(&pool).await, 0, "events wait for a full batch");
+
+        writer.flush().await.unwrap();
+        assert_eq!(count_events(&pool).await, 5);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_full_batch_is_written(pool: PgPool) {
+        let writer = AuditWriterHandle::spawn_with(pool.clone(), 3, Duration::from_secs(3600));
+
+        for _ in 0..3 {
+            writer
+                .try_send(
+                    NewAuthEvent::success(AuthEventType::KeyRotated),
+                    "key_rotated",
+                )
+                .unwrap();
+        }
+        // No flush: the third event fills the batch
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(count_events(&pool).await, 3);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_interval_flush(pool: PgPool) {
+        let writer = AuditWriterHandle::spawn_with(pool.clone(), 100, Duration::from_millis(50));
+
+        writer
+            .try_send(
+                NewAuthEvent::success(AuthEventType::KeyRotated),
+                "key_rotated",
+            )
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(count_events(&pool).await, 1);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_bad_event_does_not_drop_batch(pool: PgPool) {
+        let writer = AuditWriterHandle::spawn_with(pool.clone(), 100, Duration::from_secs(3600));
+
+        writer
+            .try_send(
+                NewAuthEvent::success(AuthEventType::KeyRotated),
+                "key_rotated",
+            )
+            .unwrap();
+        // Violates event_has_subject
+        writer
+            .try_send(
+                NewAuthEvent::success(AuthEventType::ServiceTokenIssued),
+                "service_token_issued",
+            )
+            .unwrap();
+        writer
+            .try_send(
+                NewAuthEvent::success(AuthEventType::KeyExpired),
+                "key_expired",
+            )
+            .unwrap();
+
+        writer.flush().await.unwrap();
+        assert_eq!(count_events(&pool).await, 2);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_record_without_writer_is_inline(pool: PgPool) {
+        record(&pool, NewAuthEvent::success(AuthEventType::KeyGenerated)).await;
+        assert_eq!(count_events(&pool).await, 1);
+    }
+}
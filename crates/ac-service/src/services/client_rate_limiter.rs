@@ -0,0 +1,251 @@
+//! Per-client token bucket rate limiting for the token endpoints.
+//!
+//! Each client gets a bucket holding up to `per_minute` tokens that refills
+//! continuously at `per_minute / 60` tokens per second; every token request
+//! takes one. The limit comes from [`ClientRateLimits`] by limit class (the
+//! credential's service type, or `user`).
+//!
+//! Buckets are keyed by `hash_for_correlation` of the client identity, so the
+//! limiter never holds raw client IDs or emails. The limiter also remembers
+//! the class of each service client found in the credential store, so known
+//! clients are limited without a database lookup. Requests for client IDs it
+//! does not know are limited per source address (see
+//! [`unknown_client_key`]) before the lookup, so made-up IDs from one
+//! address cannot exhaust the limit of callers elsewhere.
+//!
+//! State is per instance: with N AC replicas behind a load balancer, a
+//! client can get up to N times its configured rate.
+//!
+//! This is separate from the failed-attempt lockout in `token_service`, which
+//! counts failed logins in the database; this limiter counts every request.
+
+use crate::config::ClientRateLimits;
+use crate::errors::AcError;
+use crate::observability::metrics::{record_client_rate_limit, set_rate_limit_tracked_clients};
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Bucket count above which idle buckets are pruned.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// Time for an empty bucket to refill, whatever its capacity.
+const FULL_REFILL: Duration = Duration::from_secs(60);
+
+/// Bucket for requests naming client IDs without a remembered class, keyed
+/// by the hashed source address `source_key`. Hashed client keys start with
+/// `h:`, so it cannot collide with a client's bucket.
+pub fn unknown_client_key(source_key: &str) -> String {
+    format!("unknown:{source_key}")
+}
+
+/// Token bucket for one client.
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn full(capacity: f64, now: Instant) -> Self {
+        Self {
+            tokens: capacity,
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, capacity: f64, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * capacity / FULL_REFILL.as_secs_f64())
+            .min(capacity);
+        self.last_refill = now;
+    }
+}
+
+/// In-memory per-client rate limiter shared by the token handlers.
+#[derive(Debug)]
+pub struct ClientRateLimiter {
+    limits: ClientRateLimits,
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+    /// Limit class of service clients found in the database, by hashed key.
+    classes: Mutex<HashMap<String, String>>,
+}
+
+impl ClientRateLimiter {
+    pub fn new(limits: ClientRateLimits) -> Self {
+        Self {
+            limits,
+            buckets: Mutex::new(HashMap::new()),
+            classes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Remembered limit class of the service client with hashed `key`.
+    pub fn known_class(&self, key: &str) -> Option<String> {
+        self.classes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .cloned()
+    }
+
+    /// Remember that the service client with hashed `key` is registered in
+    /// `class`. Only registered clients are remembered, so the map is bounded
+    /// by the number of credentials; it is cleared if it grows past
+    /// [`MAX_TRACKED_CLIENTS`] regardless.
+    pub fn remember_class(&self, key: &str, class: &str) {
+        let mut classes = self.classes.lock().unwrap_or_else(PoisonError::into_inner);
+        if classes.len() >= MAX_TRACKED_CLIENTS && !classes.contains_key(key) {
+            classes.clear();
+        }
+        classes.insert(key.to_string(), class.to_string());
+    }
+
+    /// Take one token from the bucket for `key` under `class`'s limit.
+    ///
+    /// `key` must already be hashed (see `hash_for_correlation`). Returns
+    /// `TooManyRequests` with the seconds until a token is available when the
+    /// bucket is empty.
+    pub fn check(&self, key: &str, class: &str) -> Result<(), AcError> {
+        let capacity = f64::from(self.limits.per_minute(class));
+        let now = Instant::now();
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        if buckets.len() >= MAX_TRACKED_CLIENTS && !buckets.contains_key(key) {
+            Self::prune(&mut buckets, now);
+        }
+
+        let bucket = buckets
+            .entry(key.to_string())
+            .or_insert_with(|| TokenBucket::full(capacity, now));
+        bucket.refill(capacity, now);
+
+        let result = if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let wait_seconds = (1.0 - bucket.tokens) * FULL_REFILL.as_secs_f64() / capacity;
+            Err(AcError::TooManyRequests {
+                retry_after_seconds: (wait_seconds.ceil() as i64).max(1),
+                message: "Too many token requests. Please try again later.".to_string(),
+            })
+        };
+        set_rate_limit_tracked_clients(buckets.len());
+        drop(buckets);
+
+        let action = if result.is_ok() {
+            "allowed"
+        } else {
+            "rejected"
+        };
+        record_client_rate_limit(class, action);
+        result
+    }
+
+    /// Drop buckets idle for a minute; every bucket refills completely in a
+    /// minute, so they carry no state.
+    fn prune(buckets: &mut HashMap<String, TokenBucket>, now: Instant) {
+        buckets.retain(|_, bucket| now.saturating_duration_since(bucket.last_refill) < FULL_REFILL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(default_per_minute: u32, per_class: &[(&str, u32)]) -> ClientRateLimiter {
+        ClientRateLimiter::new(ClientRateLimits {
+            default_per_minute,
+            per_class: per_class
+                .iter()
+                .map(|(class, limit)| (class.to_string(), *limit))
+                .collect(),
+        })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_burst_then_reject() {
+        let limiter = limiter(3, &[]);
+
+        for _ in 0..3 {
+            assert!(limiter.check("h:client-a", "global-controller").is_ok());
+        }
+        match limiter.check("h:client-a", "global-controller") {
+            Err(AcError::TooManyRequests {
+                retry_after_seconds,
+                ..
+            }) => assert_eq!(retry_after_seconds, 20),
+            other => panic!("expected TooManyRequests, got {other:?}"),
+        }
+
+        // Other clients have their own bucket
+        assert!(limiter.check("h:client-b", "global-controller").is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_refills_over_time() {
+        let limiter = limiter(60, &[]);
+        for _ in 0..60 {
+            assert!(limiter.check("h:client-a", "user").is_ok());
+        }
+        assert!(limiter.check("h:client-a", "user").is_err());
+
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert!(limiter.check("h:client-a", "user").is_ok());
+        assert!(limiter.check("h:client-a", "user").is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_per_class_limits() {
+        let limiter = limiter(1, &[("global-controller", 5)]);
+
+        for _ in 0..5 {
+            assert!(limiter.check("h:gc", "global-controller").is_ok());
+        }
+        assert!(limiter.check("h:gc", "global-controller").is_err());
+
+        assert!(limiter.check("h:mh", "media-handler").is_ok());
+        assert!(limiter.check("h:mh", "media-handler").is_err());
+    }
+
+    #[test]
+    fn test_remembers_known_classes() {
+        let limiter = limiter(1, &[]);
+        assert_eq!(limiter.known_class("h:gc"), None);
+
+        limiter.remember_class("h:gc", "global-controller");
+        assert_eq!(
+            limiter.known_class("h:gc").as_deref(),
+            Some("global-controller")
+        );
+        assert_eq!(limiter.known_class("h:mh"), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_unknown_clients_limited_per_source() {
+        let limiter = limiter(2, &[]);
+        let flooder = unknown_client_key("h:10.0.0.1");
+        let other = unknown_client_key("h:10.0.0.2");
+        assert_ne!(flooder, other);
+
+        assert!(limiter.check(&flooder, "unknown").is_ok());
+        assert!(limiter.check(&flooder, "unknown").is_ok());
+        assert!(limiter.check(&flooder, "unknown").is_err());
+
+        // Callers at other addresses are not affected by the flood
+        assert!(limiter.check(&other, "unknown").is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_prunes_idle_buckets() {
+        let limiter = limiter(60, &[]);
+        for i in 0..MAX_TRACKED_CLIENTS {
+            assert!(limiter.check(&format!("h:{i}"), "user").is_ok());
+        }
+
+        tokio::time::advance(Duration::from_secs(61)).await;
+        assert!(limiter.check("h:new", "user").is_ok());
+        assert_eq!(limiter.buckets.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,325 @@
+//! JWKS manager actor.
+//!
+//! Keeps the rendered JWKS document in memory so `/.well-known/jwks.json`
+//! does not query the database on every request. The actor owns the cache:
+//! handlers ask it for the current document over a channel, and it reloads
+//! from the database on a fixed interval and whenever a caller asks it to
+//! refresh (e.g. after a key rotation on this instance).
+//!
+//! Rotations on other AC instances are picked up at the next interval
+//! refresh, so [`JWKS_REFRESH_INTERVAL`] bounds how long this instance can
+//! serve a key set that is missing a newly published key.
+
+use crate::errors::AcError;
+use crate::models::Jwks;
+use crate::services::key_management_service;
+use axum::body::Bytes;
+use ring::digest;
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::{interval_at, Instant, MissedTickBehavior};
+use tracing::{debug, instrument, warn};
+
+/// How often the actor reloads the key set from the database.
+pub const JWKS_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Mailbox size for the JWKS manager.
+const JWKS_CHANNEL_BUFFER: usize = 256;
+
+/// A JWKS document serialized once and served as-is.
+#[derive(Debug)]
+pub struct RenderedJwks {
+    /// JSON body.
+    pub body: Bytes,
+    /// Strong entity tag for the body, quoted per RFC 9110.
+    pub etag: String,
+}
+
+impl RenderedJwks {
+    /// Serialize `jwks` and compute its entity tag.
+    pub fn render(jwks: &Jwks) -> Result<Self, AcError> {
+        let body = serde_json::to_vec(jwks).map_err(|e| {
+            tracing::error!(target: "ac.jwks", error = %e, "Failed to serialize JWKS");
+            AcError::Internal
+        })?;
+        let hash = digest::digest(&digest::SHA256, &body);
+        let etag = format!(
+            "\"{}\"",
+            hex::encode(hash.as_ref().get(..16).unwrap_or_default())
+        );
+
+        Ok(Self {
+            body: Bytes::from(body),
+            etag,
+        })
+    }
+
+    /// Whether an `If-None-Match` header value matches this document.
+    ///
+    /// Accepts `*`, a comma-separated list, and weak tags (`W/"..."`), which
+    /// RFC 9110 compares weakly for `If-None-Match`.
+    pub fn matches(&self, if_none_match: &str) -> bool {
+        if_none_match
+            .split(',')
+            .map(str::trim)
+            .any(|tag| tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == self.etag.as_str())
+    }
+}
+
+/// Where a JWKS response came from, for `ac_jwks_requests_total`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStatus {
+    /// Served from the actor's cache.
+    Hit,
+    /// The cache was empty; the actor loaded the key set for this request.
+    Miss,
+    /// The actor was unavailable; the handler read the database directly.
+    Bypass,
+}
+
+impl CacheStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CacheStatus::Hit => "hit",
+            CacheStatus::Miss => "miss",
+            CacheStatus::Bypass => "bypass",
+        }
+    }
+}
+
+/// Messages handled by the [`JwksManagerActor`].
+enum JwksMessage {
+    /// Get the current document, loading it if the cache is empty.
+    Get {
+        respond_to: oneshot::Sender<Result<(Arc<RenderedJwks>, CacheStatus), AcError>>,
+    },
+    /// Reload the document from the database now.
+    Refresh {
+        respond_to: oneshot::Sender<Result<(), AcError>>,
+    },
+}
+
+/// Handle to the [`JwksManagerActor`].
+///
+/// Cheap to clone; the actor stops when the last handle is dropped.
+#[derive(Clone)]
+pub struct JwksManagerActorHandle {
+    sender: mpsc::Sender<JwksMessage>,
+}
+
+impl JwksManagerActorHandle {
+    /// Spawn a JWKS manager that refreshes every [`JWKS_REFRESH_INTERVAL`].
+    ///
+    /// The cache starts empty; call [`refresh`](Self::refresh) to warm it
+    /// before serving traffic.
+    pub fn spawn(pool: PgPool) -> Self {
+        Self::spawn_with_interval(pool, JWKS_REFRESH_INTERVAL)
+    }
+
+    /// Spawn a JWKS manager with a custom refresh interval.
+    pub fn spawn_with_interval(pool: PgPool, refresh_interval: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel(JWKS_CHANNEL_BUFFER);
+        let actor = JwksManagerActor {
+            pool,
+            receiver,
+            refresh_interval,
+            cached: None,
+        };
+        tokio::spawn(actor.run());
+
+        Self { sender }
+    }
+
+    /// Get the current JWKS document and whether it came from the cache.
+    pub async fn get(&self) -> Result<(Arc<RenderedJwks>, CacheStatus), AcError> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(JwksMessage::Get { respond_to: tx })
+            .await
+            .map_err(|_| AcError::Internal)?;
+
+        rx.await.map_err(|_| AcError::Internal)?
+    }
+
+    /// Reload the key set from the database.
+    ///
+    /// On failure the previously cached document (if any) stays in use.
+    pub async fn refresh(&self) -> Result<(), AcError> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(JwksMessage::Refresh { respond_to: tx })
+            .await
+            .map_err(|_| AcError::Internal)?;
+
+        rx.await.map_err(|_| AcError::Internal)?
+    }
+
+    /// Whether the actor has stopped and can no longer serve requests.
+    pub fn is_stopped(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+/// Actor owning the cached JWKS document.
+pub struct JwksManagerActor {
+    pool: PgPool,
+    receiver: mpsc::Receiver<JwksMessage>,
+    refresh_interval: Duration,
+    cached: Option<Arc<RenderedJwks>>,
+}
+
+impl JwksManagerActor {
+    /// Run the message loop until every handle is dropped.
+    #[instrument(skip_all, name = "ac.jwks.manager")]
+    async fn run(mut self) {
+        let mut ticker = interval_at(
+            Instant::now() + self.refresh_interval,
+            self.refresh_interval,
+        );
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                msg = self.receiver.recv() => match msg {
+                    Some(message) => self.handle_message(message).await,
+                    None => break,
+                },
+                _ = ticker.tick() => {
+                    if let Err(e) = self.reload().await {
+                        warn!(
+                            target: "ac.jwks",
+                            error = %e,
+                            "Periodic JWKS refresh failed; serving previous key set"
+                        );
+                    }
+                }
+            }
+        }
+
+        debug!(target: "ac.jwks", "JWKS manager stopped");
+    }
+
+    async fn handle_message(&mut self, message: JwksMessage) {
+        match message {
+            JwksMessage::Get { respond_to } => {
+                let result = match &self.cached {
+                    Some(rendered) => Ok((Arc::clone(rendered), CacheStatus::Hit)),
+                    None => self
+                        .reload()
+                        .await
+                        .map(|rendered| (rendered, CacheStatus::Miss)),
+                };
+                let _ = respond_to.send(result);
+            }
+            JwksMessage::Refresh { respond_to } => {
+                let _ = respond_to.send(self.reload().await.map(|_| ()));
+            }
+        }
+    }
+
+    /// Load and render the key set, replacing the cache on success.
+    async fn reload(&mut self) -> Result<Arc<RenderedJwks>, AcError> {
+        let jwks = key_management_service::get_jwks(&self.pool).await?;
+        let rendered = Arc::new(RenderedJwks::render(&jwks)?);
+        self.cached = Some(Arc::clone(&rendered));
+
+        Ok(rendered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ac_test_utils::crypto_fixtures::test_master_key;
+
+    fn rendered(kid: &str) -> RenderedJwks {
+        use crate::models::JsonWebKey;
+
+        RenderedJwks::render(&Jwks {
+            keys: vec![JsonWebKey {
+                kid: kid.to_string(),
+                kty: "OKP".to_string(),
+                crv: "Ed25519".to_string(),
+                x: "public-key".to_string(),
+                use_: "sig".to_string(),
+                alg: "EdDSA".to_string(),
+            }],
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_etag_tracks_content() {
+        assert_eq!(rendered("key-1").etag, rendered("key-1").etag);
+        assert_ne!(rendered("key-1").etag, rendered("key-2").etag);
+        assert!(rendered("key-1").etag.starts_with('"'));
+    }
+
+    #[test]
+    fn test_if_none_match() {
+        let doc = rendered("key-1");
+        let other = rendered("key-2").etag;
+
+        assert!(doc.matches(&doc.etag));
+        assert!(doc.matches(&format!("W/{}", doc.etag)));
+        assert!(doc.matches(&format!("{other}, {}", doc.etag)));
+        assert!(doc.matches("*"));
+        assert!(!doc.matches(&other));
+        assert!(!doc.matches(""));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_miss_then_hit(pool: PgPool) -> Result<(), AcError> {
+        key_management_service::initialize_signing_key(&pool, &test_master_key(), "test-cluster")
+            .await?;
+        let manager = JwksManagerActorHandle::spawn(pool);
+
+        let (first, status) = manager.get().await?;
+        assert_eq!(status, CacheStatus::Miss);
+        let (second, status) = manager.get().await?;
+        assert_eq!(status, CacheStatus::Hit);
+        assert!(Arc::ptr_eq(&first, &second));
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_refresh_picks_up_rotation(pool: PgPool) -> Result<(), AcError> {
+        let master_key = test_master_key();
+        key_management_service::initialize_signing_key(&pool, &master_key, "test-cluster").await?;
+        let manager = JwksManagerActorHandle::spawn(pool.clone());
+        manager.refresh().await?;
+        let (before, status) = manager.get().await?;
+        assert_eq!(status, CacheStatus::Hit, "warm-up should fill the cache");
+
+        let new_kid =
+            key_management_service::rotate_signing_key(&pool, &master_key, "test-cluster").await?;
+        let (stale, _) = manager.get().await?;
+        assert_eq!(stale.etag, before.etag, "cache is not reloaded per request");
+
+        manager.refresh().await?;
+        let (after, _) = manager.get().await?;
+        assert_ne!(after.etag, before.etag);
+        let jwks: Jwks = serde_json::from_slice(&after.body).unwrap();
+        assert!(jwks.keys.iter().any(|key| key.kid == new_kid));
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_periodic_refresh(pool: PgPool) -> Result<(), AcError> {
+        let master_key = test_master_key();
+        key_management_service::initialize_signing_key(&pool, &master_key, "test-cluster").await?;
+        let manager =
+            JwksManagerActorHandle::spawn_with_interval(pool.clone(), Duration::from_millis(50));
+        let (before, _) = manager.get().await?;
+
+        key_management_service::rotate_signing_key(&pool, &master_key, "test-cluster").await?;
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let (after, status) = manager.get().await?;
+        assert_eq!(status, CacheStatus::Hit);
+        assert_ne!(after.etag, before.etag);
+        Ok(())
+    }
+}
@@ -1,15 +1,17 @@
 use crate::crypto;
+use crate::crypto::master_key::MasterKeyProvider;
 use crate::errors::AcError;
-use crate::models::{AuthEventType, JsonWebKey, Jwks};
+use crate::models::{AuthEventType, JsonWebKey, Jwks, NewAuthEvent, ReplicatedSigningKey};
 use crate::observability::metrics::{
-    record_audit_log_failure, set_active_signing_keys, set_key_rotation_last_success,
-    set_signing_key_age_days,
+    set_active_signing_keys, set_key_rotation_last_success, set_signing_key_age_days,
 };
-use crate::repositories::{auth_events, signing_keys};
+use crate::repositories::signing_keys;
+use crate::services::audit_writer;
 use base64::{engine::general_purpose, Engine as _};
 use chrono::{Duration, Utc};
-use common::secret::ExposeSecret;
+use common::secret::{ExposeSecret, SecretBox};
 use sqlx::PgPool;
+use tracing::instrument;
 
 const KEY_VALIDITY_DAYS: i64 = 365; // 1 year
 
@@ -31,9 +33,10 @@ async fn get_next_key_sequence(pool: &PgPool, prefix: &str) -> Result<u32, AcErr
 }
 
 /// Initialize the first signing key if none exists
+#[instrument(name = "ac.keys.initialize_signing_key", skip_all)]
 pub async fn initialize_signing_key(
     pool: &PgPool,
-    master_key: &[u8],
+    master_key: &dyn MasterKeyProvider,
     cluster_name: &str,
 ) -> Result<(), AcError> {
     // Check if any active keys exist
@@ -54,7 +57,7 @@ pub async fn initialize_signing_key(
     let (public_key_pem, private_key_pkcs8) = crypto::generate_signing_key()?;
 
     // Encrypt private key with master key
-    let encrypted = crypto::encrypt_private_key(&private_key_pkcs8, master_key)?;
+    let encrypted = master_key.encrypt_private_key(&private_key_pkcs8)?;
 
     // Set validity period
     let valid_from = now;
@@ -68,33 +71,22 @@ pub async fn initialize_signing_key(
         encrypted.encrypted_data.expose_secret(),
         &encrypted.nonce,
         &encrypted.tag,
-        1, // master_key_version
+        master_key.version(),
         valid_from,
         valid_until,
     )
     .await?;
 
     // Log key generation
-    if let Err(e) = auth_events::log_event(
+    audit_writer::record(
         pool,
-        AuthEventType::KeyGenerated.as_str(),
-        None,
-        None,
-        true,
-        None,
-        None,
-        None,
-        Some(serde_json::json!({
+        NewAuthEvent::success(AuthEventType::KeyGenerated).metadata(serde_json::json!({
             "key_id": key_id,
             "valid_from": valid_from.to_rfc3339(),
             "valid_until": valid_until.to_rfc3339(),
         })),
     )
-    .await
-    {
-        tracing::warn!("Failed to log auth event: {}", e);
-        record_audit_log_failure("key_generated", "db_write_failed");
-    }
+    .await;
 
     // Update key management gauges
     set_active_signing_keys(1);
@@ -106,9 +98,10 @@ pub async fn initialize_signing_key(
 
 /// Rotate signing keys (generate new key, mark old keys as inactive)
 #[allow(dead_code)] // Library function - will be used in Phase 4 key rotation endpoints
+#[instrument(name = "ac.keys.rotate_signing_key", skip_all)]
 pub async fn rotate_signing_key(
     pool: &PgPool,
-    master_key: &[u8],
+    master_key: &dyn MasterKeyProvider,
     cluster_name: &str,
 ) -> Result<String, AcError> {
     // Generate new key_id
@@ -121,7 +114,7 @@ pub async fn rotate_signing_key(
     let (public_key_pem, private_key_pkcs8) = crypto::generate_signing_key()?;
 
     // Encrypt private key
-    let encrypted = crypto::encrypt_private_key(&private_key_pkcs8, master_key)?;
+    let encrypted = master_key.encrypt_private_key(&private_key_pkcs8)?;
 
     // Set validity period
     let valid_from = now;
@@ -135,7 +128,7 @@ pub async fn rotate_signing_key(
         encrypted.encrypted_data.expose_secret(),
         &encrypted.nonce,
         &encrypted.tag,
-        1, // master_key_version
+        master_key.version(),
         valid_from,
         valid_until,
     )
@@ -145,26 +138,15 @@ pub async fn rotate_signing_key(
     signing_keys::rotate_key(pool, &key_id).await?;
 
     // Log key rotation
-    if let Err(e) = auth_events::log_event(
+    audit_writer::record(
         pool,
-        AuthEventType::KeyRotated.as_str(),
-        None,
-        None,
-        true,
-        None,
-        None,
-        None,
-        Some(serde_json::json!({
+        NewAuthEvent::success(AuthEventType::KeyRotated).metadata(serde_json::json!({
             "new_key_id": key_id,
             "valid_from": valid_from.to_rfc3339(),
             "valid_until": valid_until.to_rfc3339(),
         })),
     )
-    .await
-    {
-        tracing::warn!("Failed to log auth event: {}", e);
-        record_audit_log_failure("key_rotated", "db_write_failed");
-    }
+    .await;
 
     // Update key management gauges
     set_active_signing_keys(1);
@@ -177,9 +159,10 @@ pub async fn rotate_signing_key(
 /// Rotate signing keys within a transaction (for atomic rate limiting + rotation)
 /// This version accepts a transaction to ensure atomicity with rate limit checks
 #[allow(dead_code)]
+#[instrument(name = "ac.keys.rotate_signing_key_tx", skip_all)]
 pub async fn rotate_signing_key_tx(
     tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
-    master_key: &[u8],
+    master_key: &dyn MasterKeyProvider,
     cluster_name: &str,
 ) -> Result<String, AcError> {
     // Generate new key_id
@@ -206,7 +189,7 @@ pub async fn rotate_signing_key_tx(
     let (public_key_pem, private_key_pkcs8) = crypto::generate_signing_key()?;
 
     // Encrypt private key
-    let encrypted = crypto::encrypt_private_key(&private_key_pkcs8, master_key)?;
+    let encrypted = master_key.encrypt_private_key(&private_key_pkcs8)?;
 
     // Set validity period
     let valid_from = now;
@@ -232,7 +215,7 @@ pub async fn rotate_signing_key_tx(
     .bind(encrypted.encrypted_data.expose_secret())
     .bind(&encrypted.nonce)
     .bind(&encrypted.tag)
-    .bind(1) // master_key_version
+    .bind(master_key.version())
     .bind(valid_from)
     .bind(valid_until)
     .fetch_one(&mut **tx)
@@ -274,6 +257,7 @@ pub async fn rotate_signing_key_tx(
 /// Get JWKS (JSON Web Key Set) for public key distribution
 ///
 /// Returns all active public keys in RFC 7517 format
+#[instrument(name = "ac.keys.get_jwks", skip_all)]
 pub async fn get_jwks(pool: &PgPool) -> Result<Jwks, AcError> {
     // Fetch all active keys
     let keys = signing_keys::get_all_active_keys(pool).await?;
@@ -342,6 +326,7 @@ pub async fn init_key_metrics(pool: &PgPool) -> Result<(), AcError> {
 /// Finds all keys where `valid_until < NOW()` and `is_active = true`,
 /// then deactivates them. Returns the list of deactivated key IDs.
 #[allow(dead_code)] // Will be used in background tasks/cron jobs in production
+#[instrument(name = "ac.keys.expire_old_keys", skip_all)]
 pub async fn expire_old_keys(pool: &PgPool) -> Result<Vec<String>, AcError> {
     // Find expired keys that are still active
     let expired_keys: Vec<(String,)> = sqlx::query_as(
@@ -364,33 +349,210 @@ pub async fn expire_old_keys(pool: &PgPool) -> Result<Vec<String>, AcError> {
         deactivated_keys.push(key_id.clone());
 
         // Log key expiration
-        if let Err(e) = auth_events::log_event(
+        audit_writer::record(
             pool,
-            AuthEventType::KeyExpired.as_str(),
-            None,
-            None,
-            true,
-            None,
-            None,
-            None,
-            Some(serde_json::json!({
+            NewAuthEvent::success(AuthEventType::KeyExpired).metadata(serde_json::json!({
                 "key_id": key_id,
                 "expired_at": chrono::Utc::now().to_rfc3339(),
             })),
         )
+        .await;
+    }
+
+    Ok(deactivated_keys)
+}
+
+/// Outcome of [`import_signing_keys`].
+#[derive(Debug, Default)]
+pub struct ImportedSigningKeys {
+    /// Keys written to this region's `signing_keys`.
+    pub imported: Vec<String>,
+    /// Keys already present here (import is idempotent).
+    pub skipped: Vec<String>,
+    /// Keys now active for signing, as in the exporting region.
+    pub active: Vec<String>,
+    /// This region's keys no longer active (only with `replace_active`).
+    pub deactivated: Vec<String>,
+}
+
+/// Export the active signing keys for another region's AC.
+///
+/// Each private key is decrypted with this region's master key and
+/// re-encrypted with `replication_key`, shared by all regions, so regions
+/// never need each other's master keys. The key ID is authenticated with
+/// the ciphertext, so an importer cannot be given a key under another ID.
+#[instrument(name = "ac.keys.export_signing_keys", skip_all)]
+pub async fn export_signing_keys(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    replication_key: &[u8],
+) -> Result<Vec<ReplicatedSigningKey>, AcError> {
+    let keys = signing_keys::get_all_active_keys(pool).await?;
+
+    keys.into_iter()
+        .map(|key| {
+            let private_key_pkcs8 = master_key.decrypt_private_key(&crypto::EncryptedKey {
+                encrypted_data: SecretBox::new(Box::new(key.private_key_encrypted)),
+                nonce: key.encryption_nonce,
+                tag: key.encryption_tag,
+            })?;
+            let encrypted = crypto::encrypt_private_key_with_aad(
+                &private_key_pkcs8,
+                replication_key,
+                key.key_id.as_bytes(),
+            )?;
+
+            Ok(ReplicatedSigningKey {
+                key_id: key.key_id,
+                public_key: key.public_key,
+                private_key_encrypted: general_purpose::STANDARD
+                    .encode(encrypted.encrypted_data.expose_secret()),
+                nonce: general_purpose::STANDARD.encode(&encrypted.nonce),
+                tag: general_purpose::STANDARD.encode(&encrypted.tag),
+                is_active: key.is_active,
+                valid_from: key.valid_from,
+                valid_until: key.valid_until,
+            })
+        })
+        .collect()
+}
+
+/// Import signing keys exported by another region's AC.
+///
+/// Every key is decrypted with `replication_key` (authenticating its key
+/// ID) and checked against its public key before anything is written; one
+/// bad key fails the whole import. Keys are stored re-encrypted with this
+/// region's master key, and the exported active keys are activated here.
+///
+/// With `replace_active`, this region's other active keys are deactivated,
+/// so tokens issued here only verify against the exporting region's JWKS;
+/// otherwise they stay active alongside the imported keys.
+///
+/// Runs under the key rotation lock, so it cannot interleave with
+/// `/internal/rotate-keys`.
+#[instrument(name = "ac.keys.import_signing_keys", skip_all)]
+pub async fn import_signing_keys(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    replication_key: &[u8],
+    keys: &[ReplicatedSigningKey],
+    replace_active: bool,
+) -> Result<ImportedSigningKeys, AcError> {
+    let decode = |field: &str, key_id: &str, value: &str| {
+        general_purpose::STANDARD.decode(value).map_err(|e| {
+            AcError::Crypto(format!(
+                "Invalid {} for imported key {}: {}",
+                field, key_id, e
+            ))
+        })
+    };
+
+    // Verify everything before touching the database
+    let mut verified = Vec::with_capacity(keys.len());
+    for key in keys {
+        let private_key_pkcs8 = crypto::decrypt_private_key_with_aad(
+            &crypto::EncryptedKey {
+                encrypted_data: SecretBox::new(Box::new(decode(
+                    "private_key_encrypted",
+                    &key.key_id,
+                    &key.private_key_encrypted,
+                )?)),
+                nonce: decode("nonce", &key.key_id, &key.nonce)?,
+                tag: decode("tag", &key.key_id, &key.tag)?,
+            },
+            replication_key,
+            key.key_id.as_bytes(),
+        )?;
+        if crypto::public_key_pem(&private_key_pkcs8)? != key.public_key {
+            return Err(AcError::Crypto(format!(
+                "Imported key {} does not match its public key",
+                key.key_id
+            )));
+        }
+        verified.push((key, master_key.encrypt_private_key(&private_key_pkcs8)?));
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| AcError::Database(format!("Failed to begin transaction: {}", e)))?;
+
+    // Same lock as /internal/rotate-keys
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext('key_rotation'))")
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AcError::Database(format!("Failed to acquire rotation lock: {}", e)))?;
+
+    let mut result = ImportedSigningKeys::default();
+    for (key, encrypted) in verified {
+        let inserted: Option<String> = sqlx::query_scalar(
+            r#"
+            INSERT INTO signing_keys (
+                key_id, public_key, private_key_encrypted, encryption_nonce, encryption_tag,
+                encryption_algorithm, master_key_version, algorithm,
+                is_active, valid_from, valid_until
+            )
+            VALUES ($1, $2, $3, $4, $5, 'AES-256-GCM', $6, 'EdDSA', false, $7, $8)
+            ON CONFLICT (key_id) DO NOTHING
+            RETURNING key_id
+            "#,
+        )
+        .bind(&key.key_id)
+        .bind(&key.public_key)
+        .bind(encrypted.encrypted_data.expose_secret())
+        .bind(&encrypted.nonce)
+        .bind(&encrypted.tag)
+        .bind(master_key.version())
+        .bind(key.valid_from)
+        .bind(key.valid_until)
+        .fetch_optional(&mut *tx)
         .await
-        {
-            tracing::warn!("Failed to log key expiration event: {}", e);
-            record_audit_log_failure("key_expired", "db_write_failed");
+        .map_err(|e| AcError::Database(format!("Failed to import signing key: {}", e)))?;
+
+        match inserted {
+            Some(key_id) => result.imported.push(key_id),
+            None => result.skipped.push(key.key_id.clone()),
+        }
+        if key.is_active {
+            result.active.push(key.key_id.clone());
         }
     }
 
-    Ok(deactivated_keys)
+    // Sign with the exporting region's active keys from now on. An export
+    // with no active key leaves this region's keys alone.
+    if !result.active.is_empty() {
+        // With replace_active, every other active key is deactivated
+        let updated: Vec<(String, bool)> = sqlx::query_as(
+            r#"
+            UPDATE signing_keys
+            SET is_active = (key_id = ANY($1))
+            WHERE key_id = ANY($1) OR ($2 AND is_active = true)
+            RETURNING key_id, is_active
+            "#,
+        )
+        .bind(&result.active)
+        .bind(replace_active)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| AcError::Database(format!("Failed to activate imported keys: {}", e)))?;
+        result.deactivated = updated
+            .into_iter()
+            .filter(|(_, is_active)| !is_active)
+            .map(|(key_id, _)| key_id)
+            .collect();
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| AcError::Database(format!("Failed to commit key import: {}", e)))?;
+
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::crypto::master_key::EnvMasterKey;
     use sqlx::PgPool;
 
     /// Test master key for tests (32 bytes for AES-256)
@@ -613,4 +775,152 @@ mod tests {
         assert!(expired.is_empty(), "Placeholder should return empty vector");
         Ok(())
     }
+
+    // ============================================================================
+    // Key Replication Tests
+    // ============================================================================
+
+    fn test_replication_key() -> Vec<u8> {
+        (0xa0..0xc0).collect()
+    }
+
+    /// Export from a "primary", then import into a "secondary" that already
+    /// generated its own key
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_import_replaces_active_key(pool: PgPool) -> Result<(), AcError> {
+        let primary_master_key = test_master_key();
+        let secondary_master_key: Vec<u8> = (0x40..0x60).collect();
+        let replication_key = test_replication_key();
+
+        initialize_signing_key(&pool, &primary_master_key, "primary").await?;
+        let exported = export_signing_keys(&pool, &primary_master_key, &replication_key).await?;
+        assert_eq!(exported.len(), 1);
+        let primary_key_id = exported[0].key_id.clone();
+
+        // Start the secondary from an empty table with its own key
+        sqlx::query("DELETE FROM signing_keys")
+            .execute(&pool)
+            .await
+            .map_err(|e| AcError::Database(e.to_string()))?;
+        initialize_signing_key(&pool, &secondary_master_key, "secondary").await?;
+        let secondary_key_id = signing_keys::get_active_key(&pool)
+            .await?
+            .expect("Secondary should have a key")
+            .key_id;
+
+        let result = import_signing_keys(
+            &pool,
+            &secondary_master_key,
+            &replication_key,
+            &exported,
+            true,
+        )
+        .await?;
+        assert_eq!(result.imported, vec![primary_key_id.clone()]);
+        assert!(result.skipped.is_empty());
+        assert_eq!(result.deactivated, vec![secondary_key_id]);
+
+        // The primary's key signs here now, stored under the secondary's master key
+        let active = signing_keys::get_all_active_keys(&pool).await?;
+        assert_eq!(active.len(), 1, "Only the imported key should be active");
+        let key = &active[0];
+        assert_eq!(key.key_id, primary_key_id);
+        let private_key_pkcs8 =
+            secondary_master_key.decrypt_private_key(&crypto::EncryptedKey {
+                encrypted_data: SecretBox::new(Box::new(key.private_key_encrypted.clone())),
+                nonce: key.encryption_nonce.clone(),
+                tag: key.encryption_tag.clone(),
+            })?;
+        assert_eq!(crypto::public_key_pem(&private_key_pkcs8)?, key.public_key);
+
+        // Importing again changes nothing
+        let result = import_signing_keys(
+            &pool,
+            &secondary_master_key,
+            &replication_key,
+            &exported,
+            true,
+        )
+        .await?;
+        assert!(result.imported.is_empty());
+        assert_eq!(result.skipped, vec![primary_key_id]);
+        assert!(result.deactivated.is_empty());
+
+        Ok(())
+    }
+
+    /// Test import rejects keys it cannot decrypt or that don't match their public key
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_import_rejects_invalid_keys(pool: PgPool) -> Result<(), AcError> {
+        let master_key = test_master_key();
+        let replication_key = test_replication_key();
+
+        initialize_signing_key(&pool, &master_key, "primary").await?;
+        let exported = export_signing_keys(&pool, &master_key, &replication_key).await?;
+        sqlx::query("DELETE FROM signing_keys")
+            .execute(&pool)
+            .await
+            .map_err(|e| AcError::Database(e.to_string()))?;
+
+        // Wrong replication key
+        let wrong_key: Vec<u8> = (0x40..0x60).collect();
+        let result = import_signing_keys(&pool, &master_key, &wrong_key, &exported, true).await;
+        assert!(matches!(result, Err(AcError::Crypto(_))));
+
+        // Public key swapped for another key's
+        let mut tampered = exported.clone();
+        tampered[0].public_key = crypto::generate_signing_key()?.0;
+        let result =
+            import_signing_keys(&pool, &master_key, &replication_key, &tampered, true).await;
+        assert!(matches!(result, Err(AcError::Crypto(_))));
+
+        // Key material relabelled with another key ID
+        let mut relabelled = exported.clone();
+        relabelled[0].key_id = "other-key-01".to_string();
+        let result =
+            import_signing_keys(&pool, &master_key, &replication_key, &relabelled, true).await;
+        assert!(matches!(result, Err(AcError::Crypto(_))));
+
+        // Nothing was written
+        assert!(signing_keys::get_all_active_keys(&pool).await?.is_empty());
+
+        Ok(())
+    }
+
+    /// Without `replace_active` the importing region's own keys stay
+    /// active, and imported keys record its master key version
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_import_keeps_local_keys_by_default(pool: PgPool) -> Result<(), AcError> {
+        let primary_master_key = test_master_key();
+        let secondary_master_key =
+            EnvMasterKey::new(&SecretBox::new(Box::new((0x40..0x60).collect()))).with_version(2);
+        let replication_key = test_replication_key();
+
+        initialize_signing_key(&pool, &primary_master_key, "primary").await?;
+        let exported = export_signing_keys(&pool, &primary_master_key, &replication_key).await?;
+        let primary_key_id = exported[0].key_id.clone();
+        sqlx::query("DELETE FROM signing_keys")
+            .execute(&pool)
+            .await
+            .map_err(|e| AcError::Database(e.to_string()))?;
+        initialize_signing_key(&pool, &secondary_master_key, "secondary").await?;
+
+        let result = import_signing_keys(
+            &pool,
+            &secondary_master_key,
+            &replication_key,
+            &exported,
+            false,
+        )
+        .await?;
+        assert_eq!(result.active, vec![primary_key_id.clone()]);
+        assert!(result.deactivated.is_empty());
+
+        let active = signing_keys::get_all_active_keys(&pool).await?;
+        assert_eq!(active.len(), 2, "Local and imported keys should be active");
+        assert!(active.iter().all(|key| key.master_key_version == 2));
+        assert!(active.iter().any(|key| key.key_id == primary_key_id));
+
+        Ok(())
+    }
 }
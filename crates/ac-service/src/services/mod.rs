@@ -1,4 +1,10 @@
+pub mod audit_writer;
+pub mod client_rate_limiter;
+pub mod jwks_manager;
 pub mod key_management_service;
+pub mod oidc_service;
 pub mod registration_service;
+pub mod secret_policy;
 pub mod token_service;
+pub mod totp_service;
 pub mod user_service;
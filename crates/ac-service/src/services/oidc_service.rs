@@ -0,0 +1,380 @@
+//! OIDC federation service: org SSO through an external identity provider.
+//!
+//! An org admin points the org at one upstream provider with
+//! [`configure_provider`]. Users then sign in with [`begin_login`], which
+//! returns the provider's authorization URL, and [`complete_login`], which
+//! handles the callback and issues the same user token as a password login.
+//!
+//! The IdP identity (`iss` + `sub`) is linked to an AC user on first login:
+//! to the org user with the same email if the provider has verified it,
+//! otherwise to a newly created user. Later logins follow the link, so a
+//! changed email at the IdP does not create a second account.
+
+use crate::crypto::master_key::MasterKeyProvider;
+use crate::crypto::{self, EncryptedKey};
+use crate::errors::AcError;
+use crate::oidc::{self, AuthorizationParams, IdTokenClaims, OidcError, ProviderMetadata};
+use crate::repositories::oidc::{self as oidc_repo, NewOidcProvider, OidcProvider};
+use crate::repositories::users;
+use crate::services::token_service::{self, UserTokenResponse};
+use chrono::Utc;
+use common::secret::{ExposeSecret, SecretBox, SecretString};
+use reqwest::Url;
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Path of the callback endpoint; configured redirect URIs must point here.
+pub const CALLBACK_PATH: &str = "/api/v1/auth/oidc/callback";
+
+/// How long a user has to finish signing in at the provider.
+const LOGIN_STATE_TTL_MINUTES: i64 = 10;
+
+/// Error for any callback whose `state` cannot be redeemed.
+///
+/// Unknown, expired, used, and other-org states are indistinguishable.
+fn invalid_login_state() -> AcError {
+    AcError::InvalidToken("The login request is invalid or expired".to_string())
+}
+
+/// Provider settings from an org admin.
+#[derive(Debug)]
+pub struct OidcProviderConfig {
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: SecretString,
+    pub redirect_uri: String,
+    pub is_enabled: bool,
+}
+
+/// Validate and store an org's provider, replacing any existing one.
+///
+/// An enabled provider must serve a discovery document for its issuer, so
+/// a typo is caught here rather than at the first user's login.
+pub async fn configure_provider(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    org_id: Uuid,
+    config: &OidcProviderConfig,
+) -> Result<OidcProvider, AcError> {
+    // Validation errors use InvalidToken, matching user registration.
+    validate_config(config).map_err(AcError::InvalidToken)?;
+
+    if config.is_enabled {
+        oidc::discover(&config.issuer_url).await.map_err(|e| {
+            tracing::warn!(target: "ac.services.oidc", error = %e, "OIDC discovery failed");
+            AcError::InvalidToken(
+                "Could not load the provider's OpenID configuration from issuer_url".to_string(),
+            )
+        })?;
+    }
+
+    let encrypted =
+        master_key.encrypt_private_key(config.client_secret.expose_secret().as_bytes())?;
+    let provider = oidc_repo::upsert_provider(
+        pool,
+        org_id,
+        &NewOidcProvider {
+            issuer_url: &config.issuer_url,
+            client_id: &config.client_id,
+            client_secret_encrypted: encrypted.encrypted_data.expose_secret(),
+            client_secret_nonce: &encrypted.nonce,
+            client_secret_tag: &encrypted.tag,
+            redirect_uri: &config.redirect_uri,
+            is_enabled: config.is_enabled,
+        },
+    )
+    .await?;
+
+    tracing::info!(
+        target: "ac.services.oidc",
+        org_id = %org_id,
+        enabled = provider.is_enabled,
+        "OIDC provider configured"
+    );
+
+    Ok(provider)
+}
+
+/// Check provider settings, returning the first problem found.
+fn validate_config(config: &OidcProviderConfig) -> Result<(), String> {
+    oidc::check_url(&config.issuer_url).map_err(|reason| format!("issuer_url: {}", reason))?;
+    oidc::check_url(&config.redirect_uri).map_err(|reason| format!("redirect_uri: {}", reason))?;
+
+    let redirect = Url::parse(&config.redirect_uri)
+        .map_err(|e| format!("redirect_uri: invalid URL: {}", e))?;
+    if redirect.path() != CALLBACK_PATH || redirect.query().is_some() {
+        return Err(format!("redirect_uri must point at {}", CALLBACK_PATH));
+    }
+
+    if config.client_id.trim().is_empty() {
+        return Err("client_id cannot be empty".to_string());
+    }
+    if config.client_secret.expose_secret().is_empty() {
+        return Err("client_secret cannot be empty".to_string());
+    }
+    Ok(())
+}
+
+/// Start an SSO login (`GET /api/v1/auth/oidc/authorize`).
+///
+/// Stores the request's `state`, `nonce`, and PKCE verifier and returns the
+/// provider URL to redirect the user to.
+pub async fn begin_login(pool: &PgPool, org_id: Uuid) -> Result<String, AcError> {
+    let provider = enabled_provider(pool, org_id).await?;
+    let metadata = discover(&provider).await?;
+
+    let params = AuthorizationParams::generate()?;
+    oidc_repo::create_login_state(
+        pool,
+        &hash_state(&params.state),
+        org_id,
+        &params.nonce,
+        &params.code_verifier,
+        Utc::now() + chrono::Duration::minutes(LOGIN_STATE_TTL_MINUTES),
+    )
+    .await?;
+
+    oidc::authorization_url(
+        &metadata,
+        &provider.client_id,
+        &provider.redirect_uri,
+        &params,
+    )
+    .map_err(provider_error)
+}
+
+/// Finish an SSO login (`GET /api/v1/auth/oidc/callback`).
+///
+/// Redeems `state`, trades `code` for an ID token, validates it, and issues
+/// a user token for the linked user.
+#[expect(clippy::too_many_arguments)]
+pub async fn complete_login(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    org_id: Uuid,
+    code: &str,
+    state: &str,
+    bcrypt_cost: u32,
+    clock_skew_seconds: u64,
+    token_ttl_seconds: u32,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+) -> Result<UserTokenResponse, AcError> {
+    let login = oidc_repo::take_login_state(pool, &hash_state(state))
+        .await?
+        .filter(|login| login.org_id == org_id)
+        .ok_or_else(invalid_login_state)?;
+
+    let provider = enabled_provider(pool, org_id).await?;
+    let client_secret = decrypt_client_secret(master_key, &provider)?;
+    let metadata = discover(&provider).await?;
+
+    let id_token = oidc::exchange_code(
+        &metadata,
+        &provider.client_id,
+        client_secret.expose_secret(),
+        &provider.redirect_uri,
+        code,
+        &login.code_verifier,
+    )
+    .await
+    .map_err(login_error)?;
+    let jwks = oidc::fetch_jwks(&metadata).await.map_err(login_error)?;
+    let claims = oidc::validate_id_token(
+        &id_token,
+        &jwks,
+        &provider.issuer_url,
+        &provider.client_id,
+        &login.nonce,
+        clock_skew_seconds,
+    )
+    .map_err(login_error)?;
+
+    let user = resolve_user(pool, org_id, &provider, &claims, bcrypt_cost).await?;
+    if !user.is_active {
+        token_service::log_user_auth_event(
+            pool,
+            &user.user_id,
+            false,
+            Some("User account is inactive"),
+            ip_address,
+            user_agent,
+        )
+        .await;
+        return Err(AcError::InvalidCredentials);
+    }
+
+    token_service::issue_token_for_user(
+        pool,
+        master_key,
+        &user,
+        token_ttl_seconds,
+        ip_address,
+        user_agent,
+    )
+    .await
+}
+
+/// Find or create the AC user for an IdP identity.
+async fn resolve_user(
+    pool: &PgPool,
+    org_id: Uuid,
+    provider: &OidcProvider,
+    claims: &IdTokenClaims,
+    bcrypt_cost: u32,
+) -> Result<users::User, AcError> {
+    if let Some(user_id) =
+        oidc_repo::get_linked_user(pool, &provider.issuer_url, &claims.sub).await?
+    {
+        // The same issuer may serve several orgs; a link never crosses them
+        return users::get_by_id(pool, user_id)
+            .await?
+            .filter(|user| user.org_id == org_id)
+            .ok_or(AcError::InvalidCredentials);
+    }
+
+    // Linking by email trusts the provider to have verified it
+    let email = match (&claims.email, claims.email_verified) {
+        (Some(email), Some(true)) if !email.is_empty() => email,
+        _ => {
+            return Err(AcError::InvalidToken(
+                "The identity provider did not supply a verified email".to_string(),
+            ))
+        }
+    };
+
+    let user = match users::get_by_email(pool, org_id, email).await? {
+        Some(user) => user,
+        None => create_federated_user(pool, org_id, email, claims, bcrypt_cost).await?,
+    };
+    oidc_repo::link_identity(pool, &provider.issuer_url, &claims.sub, user.user_id).await?;
+
+    tracing::info!(
+        target: "ac.services.oidc",
+        org_id = %org_id,
+        user_id = %user.user_id,
+        "OIDC identity linked"
+    );
+
+    Ok(user)
+}
+
+/// Create a user for a first-time SSO login.
+///
+/// The password is random and never shown, so the account can only be
+/// used through the provider.
+async fn create_federated_user(
+    pool: &PgPool,
+    org_id: Uuid,
+    email: &str,
+    claims: &IdTokenClaims,
+    bcrypt_cost: u32,
+) -> Result<users::User, AcError> {
+    let password = crypto::generate_client_secret()?;
+    let password_hash = crypto::hash_client_secret(password.expose_secret(), bcrypt_cost)?;
+    let display_name = claims
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or(email);
+
+    let user = users::create_user(pool, org_id, email, &password_hash, display_name).await?;
+    users::add_user_role(pool, user.user_id, "user").await?;
+    Ok(user)
+}
+
+async fn enabled_provider(pool: &PgPool, org_id: Uuid) -> Result<OidcProvider, AcError> {
+    oidc_repo::get_provider(pool, org_id)
+        .await?
+        .filter(|provider| provider.is_enabled)
+        .ok_or_else(|| AcError::NotFound("Single sign-on is not configured".to_string()))
+}
+
+async fn discover(provider: &OidcProvider) -> Result<ProviderMetadata, AcError> {
+    oidc::discover(&provider.issuer_url)
+        .await
+        .map_err(provider_error)
+}
+
+fn decrypt_client_secret(
+    master_key: &dyn MasterKeyProvider,
+    provider: &OidcProvider,
+) -> Result<SecretString, AcError> {
+    let encrypted = EncryptedKey {
+        encrypted_data: SecretBox::new(Box::new(provider.client_secret_encrypted.clone())),
+        nonce: provider.client_secret_nonce.clone(),
+        tag: provider.client_secret_tag.clone(),
+    };
+    let secret = String::from_utf8(master_key.decrypt_private_key(&encrypted)?)
+        .map_err(|_| AcError::Crypto("OIDC client secret is not UTF-8".to_string()))?;
+    Ok(SecretString::from(secret))
+}
+
+/// Hash a `state` value for storage and lookup (hex SHA-256).
+fn hash_state(state: &str) -> String {
+    hex::encode(Sha256::digest(state.as_bytes()))
+}
+
+/// An unreachable or misbehaving provider is our problem, not the user's.
+fn provider_error(e: OidcError) -> AcError {
+    tracing::warn!(target: "ac.services.oidc", error = %e, "OIDC provider request failed");
+    AcError::Internal
+}
+
+fn login_error(e: OidcError) -> AcError {
+    match e {
+        OidcError::Rejected(_) => {
+            tracing::debug!(target: "ac.services.oidc", error = %e, "OIDC login rejected");
+            AcError::InvalidToken("The identity provider login could not be verified".to_string())
+        }
+        OidcError::Provider(_) => provider_error(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(issuer_url: &str, redirect_uri: &str) -> OidcProviderConfig {
+        OidcProviderConfig {
+            issuer_url: issuer_url.to_string(),
+            client_id: "dark-tower".to_string(),
+            client_secret: SecretString::from("client-secret"),
+            redirect_uri: redirect_uri.to_string(),
+            is_enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_validate_config() {
+        let redirect = "https://acme.example.com/api/v1/auth/oidc/callback";
+        assert!(validate_config(&config("https://idp.example.com", redirect)).is_ok());
+
+        assert!(validate_config(&config("http://idp.example.com", redirect))
+            .unwrap_err()
+            .starts_with("issuer_url"));
+        assert!(validate_config(&config(
+            "https://idp.example.com",
+            "https://acme.example.com/elsewhere"
+        ))
+        .is_err());
+        assert!(validate_config(&config(
+            "https://idp.example.com",
+            "https://acme.example.com/api/v1/auth/oidc/callback?next=/"
+        ))
+        .is_err());
+
+        let mut no_client = config("https://idp.example.com", redirect);
+        no_client.client_id = " ".to_string();
+        assert!(validate_config(&no_client).is_err());
+    }
+
+    #[test]
+    fn test_hash_state_is_stable_hex() {
+        assert_eq!(hash_state("abc"), hash_state("abc"));
+        assert_ne!(hash_state("abc"), hash_state("abd"));
+        assert_eq!(hash_state("abc").len(), 64);
+    }
+}
@@ -2,10 +2,11 @@
 use crate::config::DEFAULT_BCRYPT_COST;
 use crate::crypto;
 use crate::errors::AcError;
-use crate::models::{AuthEventType, RegisterServiceResponse, ServiceType};
-use crate::observability::metrics::record_audit_log_failure;
-use crate::repositories::{auth_events, service_credentials};
-use common::secret::ExposeSecret;
+use crate::models::{AuthEventType, NewAuthEvent, RegisterServiceResponse, ServiceType};
+use crate::repositories::service_credentials;
+use crate::services::audit_writer;
+use crate::services::secret_policy::SecretPolicy;
+use common::secret::{ExposeSecret, SecretString};
 use sqlx::PgPool;
 use std::str::FromStr;
 use uuid::Uuid;
@@ -27,17 +28,69 @@ pub async fn register_service(
     region: Option<String>,
     bcrypt_cost: u32,
 ) -> Result<RegisterServiceResponse, AcError> {
-    // Validate and parse service_type
-    let svc_type = ServiceType::from_str(service_type).map_err(|e| {
-        AcError::Database(format!("Invalid service_type: '{}'. {}", service_type, e))
-    })?;
-
-    // Generate client_id (UUID)
-    let client_id = Uuid::new_v4().to_string();
+    let svc_type = parse_service_type(service_type)?;
 
     // Generate client_secret (32 bytes, CSPRNG, base64)
     let client_secret = crypto::generate_client_secret()?;
 
+    create_credential(
+        pool,
+        svc_type,
+        service_type,
+        region,
+        client_secret,
+        bcrypt_cost,
+    )
+    .await
+}
+
+/// Register a new service with a caller-supplied client secret
+///
+/// Same as [`register_service`], except the secret is checked against
+/// `policy` instead of being generated. A secret that fails the policy is
+/// rejected with [`AcError::InvalidRequest`] before anything is stored.
+pub async fn register_service_with_secret(
+    pool: &PgPool,
+    service_type: &str,
+    region: Option<String>,
+    client_secret: SecretString,
+    policy: &SecretPolicy,
+    bcrypt_cost: u32,
+) -> Result<RegisterServiceResponse, AcError> {
+    let svc_type = parse_service_type(service_type)?;
+
+    policy
+        .validate(client_secret.expose_secret(), &[service_type])
+        .map_err(|e| AcError::InvalidRequest(e.to_string()))?;
+
+    create_credential(
+        pool,
+        svc_type,
+        service_type,
+        region,
+        client_secret,
+        bcrypt_cost,
+    )
+    .await
+}
+
+fn parse_service_type(service_type: &str) -> Result<ServiceType, AcError> {
+    ServiceType::from_str(service_type)
+        .map_err(|e| AcError::Database(format!("Invalid service_type: '{}'. {}", service_type, e)))
+}
+
+/// Hash `client_secret`, store the credential, and log the registration
+async fn create_credential(
+    pool: &PgPool,
+    svc_type: ServiceType,
+    service_type: &str,
+    region: Option<String>,
+    client_secret: SecretString,
+    bcrypt_cost: u32,
+) -> Result<RegisterServiceResponse, AcError> {
+    // Generate client_id (UUID)
+    let client_id = Uuid::new_v4().to_string();
+
     // Hash client_secret with bcrypt using configured cost factor
     let client_secret_hash =
         crypto::hash_client_secret(client_secret.expose_secret(), bcrypt_cost)?;
@@ -57,26 +110,17 @@ pub async fn register_service(
     .await?;
 
     // Log registration event
-    if let Err(e) = auth_events::log_event(
+    audit_writer::record(
         pool,
-        AuthEventType::ServiceRegistered.as_str(),
-        None,
-        Some(credential.credential_id),
-        true,
-        None,
-        None,
-        None,
-        Some(serde_json::json!({
-            "service_type": service_type,
-            "region": region,
-            "scopes": scopes,
-        })),
+        NewAuthEvent::success(AuthEventType::ServiceRegistered)
+            .credential(Some(credential.credential_id))
+            .metadata(serde_json::json!({
+                "service_type": service_type,
+                "region": region,
+                "scopes": scopes,
+            })),
     )
-    .await
-    {
-        tracing::warn!("Failed to log auth event: {}", e);
-        record_audit_log_failure("service_registered", "db_write_failed");
-    }
+    .await;
 
     // Return credentials (this is the ONLY time the plaintext client_secret is shown)
     Ok(RegisterServiceResponse {
@@ -88,7 +132,6 @@ pub async fn register_service(
 }
 
 /// Update scopes for an existing service
-#[allow(dead_code)] // Library function - will be used in Phase 4 admin endpoints
 pub async fn update_service_scopes(
     pool: &PgPool,
     client_id: &str,
@@ -102,33 +145,24 @@ pub async fn update_service_scopes(
     // Update scopes
     service_credentials::update_scopes(pool, credential.credential_id, &new_scopes).await?;
 
-    // Log scope update
-    if let Err(e) = auth_events::log_event(
+    // Log scope update (reusing token issued type)
+    audit_writer::record_as(
         pool,
-        AuthEventType::ServiceTokenIssued.as_str(), // Reusing token issued type
-        None,
-        Some(credential.credential_id),
-        true,
-        None,
-        None,
-        None,
-        Some(serde_json::json!({
-            "action": "scopes_updated",
-            "old_scopes": credential.scopes,
-            "new_scopes": new_scopes,
-        })),
+        NewAuthEvent::success(AuthEventType::ServiceTokenIssued)
+            .credential(Some(credential.credential_id))
+            .metadata(serde_json::json!({
+                "action": "scopes_updated",
+                "old_scopes": credential.scopes,
+                "new_scopes": new_scopes,
+            })),
+        "scopes_updated",
     )
-    .await
-    {
-        tracing::warn!("Failed to log auth event: {}", e);
-        record_audit_log_failure("scopes_updated", "db_write_failed");
-    }
+    .await;
 
     Ok(())
 }
 
 /// Deactivate a service credential
-#[allow(dead_code)] // Library function - will be used in Phase 4 admin endpoints
 pub async fn deactivate_service(pool: &PgPool, client_id: &str) -> Result<(), AcError> {
     // Fetch credential
     let credential = service_credentials::get_by_client_id(pool, client_id)
@@ -138,25 +172,42 @@ pub async fn deactivate_service(pool: &PgPool, client_id: &str) -> Result<(), Ac
     // Deactivate
     service_credentials::deactivate(pool, credential.credential_id).await?;
 
-    // Log deactivation
-    if let Err(e) = auth_events::log_event(
+    // Log deactivation (reusing failed type)
+    audit_writer::record_as(
         pool,
-        AuthEventType::ServiceTokenFailed.as_str(), // Reusing failed type
-        None,
-        Some(credential.credential_id),
-        true,
-        None,
-        None,
-        None,
-        Some(serde_json::json!({
-            "action": "service_deactivated",
-        })),
+        NewAuthEvent::success(AuthEventType::ServiceTokenFailed)
+            .credential(Some(credential.credential_id))
+            .metadata(serde_json::json!({
+                "action": "service_deactivated",
+            })),
+        "service_deactivated",
     )
-    .await
-    {
-        tracing::warn!("Failed to log auth event: {}", e);
-        record_audit_log_failure("service_deactivated", "db_write_failed");
-    }
+    .await;
+
+    Ok(())
+}
+
+/// Reactivate a deactivated service credential
+pub async fn reactivate_service(pool: &PgPool, client_id: &str) -> Result<(), AcError> {
+    // Fetch credential
+    let credential = service_credentials::get_by_client_id(pool, client_id)
+        .await?
+        .ok_or_else(|| AcError::Database("Service credential not found".to_string()))?;
+
+    // Reactivate
+    service_credentials::activate(pool, credential.credential_id).await?;
+
+    // Log reactivation (reusing registered type)
+    audit_writer::record_as(
+        pool,
+        NewAuthEvent::success(AuthEventType::ServiceRegistered)
+            .credential(Some(credential.credential_id))
+            .metadata(serde_json::json!({
+                "action": "service_reactivated",
+            })),
+        "service_reactivated",
+    )
+    .await;
 
     Ok(())
 }
@@ -922,6 +973,22 @@ mod tests {
         Ok(())
     }
 
+    /// Test reactivate_service restores a deactivated credential
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_reactivate_service(pool: PgPool) -> Result<(), AcError> {
+        let response = register_service(&pool, "media-handler", None, DEFAULT_BCRYPT_COST).await?;
+        deactivate_service(&pool, &response.client_id).await?;
+
+        reactivate_service(&pool, &response.client_id).await?;
+
+        let credential = service_credentials::get_by_client_id(&pool, &response.client_id)
+            .await?
+            .expect("Credential should still exist");
+        assert!(credential.is_active);
+
+        Ok(())
+    }
+
     /// Test registration with invalid service type
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_register_invalid_service_type(pool: PgPool) -> Result<(), AcError> {
@@ -940,6 +1007,68 @@ mod tests {
         Ok(())
     }
 
+    /// Test registration with a caller-supplied secret that meets the policy
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_register_service_with_secret(pool: PgPool) -> Result<(), AcError> {
+        let secret = "kQ7vZ2pX9mW4rT8yL1nB6hJ3fD5sG0cE";
+        let response = register_service_with_secret(
+            &pool,
+            "media-handler",
+            None,
+            SecretString::from(secret),
+            &SecretPolicy::default(),
+            DEFAULT_BCRYPT_COST,
+        )
+        .await?;
+
+        assert_eq!(response.client_secret.expose_secret(), secret);
+        let credential = service_credentials::get_by_client_id(&pool, &response.client_id)
+            .await?
+            .expect("Credential should exist");
+        assert!(crypto::verify_client_secret(
+            secret,
+            &credential.client_secret_hash
+        )?);
+
+        Ok(())
+    }
+
+    /// Test that a weak caller-supplied secret is rejected before storing
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_register_service_with_weak_secret(pool: PgPool) -> Result<(), AcError> {
+        for weak in [
+            "short",
+            "meeting-controller-kQ7vZ2pX9mW4rT8yL1nB",
+            "kQ7vZ2pX9mW4rT8yL1nB6hJ3fD5sG0cE1234",
+        ] {
+            let result = register_service_with_secret(
+                &pool,
+                "meeting-controller",
+                None,
+                SecretString::from(weak),
+                &SecretPolicy::default(),
+                DEFAULT_BCRYPT_COST,
+            )
+            .await;
+
+            match result {
+                Err(AcError::InvalidRequest(msg)) => {
+                    assert!(msg.starts_with("Client secret"), "{msg}");
+                    assert!(!msg.contains(weak));
+                }
+                other => panic!("expected InvalidRequest, got {:?}", other.map(|_| ())),
+            }
+        }
+
+        let count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM service_credentials")
+            .fetch_one(&pool)
+            .await
+            .expect("service_credentials should be queryable");
+        assert_eq!(count.0, 0, "Rejected secrets must not create credentials");
+
+        Ok(())
+    }
+
     // ============================================================================
     // Bcrypt Cost Propagation Tests
     // ============================================================================
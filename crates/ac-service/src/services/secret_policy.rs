@@ -0,0 +1,353 @@
+//! Strength policy for caller-supplied client secrets.
+//!
+//! Service registration normally generates the client secret (32 random
+//! bytes, base64). Callers may supply their own instead, e.g. when a secret
+//! is provisioned by an external secret manager; those are checked against
+//! a [`SecretPolicy`] before being hashed and stored.
+//!
+//! # Checks
+//!
+//! 1. Printable ASCII only, between `min_length` characters and
+//!    [`MAX_SECRET_BYTES`] (bcrypt ignores anything past 72 bytes)
+//! 2. Estimated entropy of at least `min_entropy_bits`
+//! 3. No banned patterns: long runs of one character, ascending or
+//!    descending sequences (`abcd`, `4321`), too few distinct characters,
+//!    common words, or the service type itself
+//!
+//! Entropy is estimated as `length * log2(pool)`, where `pool` is the size
+//! of the character classes (lowercase, uppercase, digits, symbols) the
+//! secret uses. The estimate overstates secrets built from repetition,
+//! which is why the pattern checks run as well.
+
+use thiserror::Error;
+
+/// Default minimum secret length in characters.
+pub const DEFAULT_SECRET_MIN_LENGTH: usize = 32;
+/// Smallest configurable minimum length.
+pub const MIN_SECRET_MIN_LENGTH: usize = 16;
+/// Largest configurable minimum length.
+pub const MAX_SECRET_MIN_LENGTH: usize = 64;
+
+/// Default minimum estimated entropy in bits.
+pub const DEFAULT_SECRET_MIN_ENTROPY_BITS: u32 = 128;
+/// Smallest configurable minimum entropy.
+pub const MIN_SECRET_MIN_ENTROPY_BITS: u32 = 64;
+/// Largest configurable minimum entropy.
+pub const MAX_SECRET_MIN_ENTROPY_BITS: u32 = 256;
+
+/// Longest accepted secret; bcrypt only hashes the first 72 bytes.
+pub const MAX_SECRET_BYTES: usize = 72;
+
+/// Length of a same-character run or sequence that is rejected.
+const MAX_RUN: usize = 4;
+
+/// Minimum number of distinct characters.
+const MIN_DISTINCT_CHARS: usize = 10;
+
+/// Substrings rejected case-insensitively.
+const BANNED_WORDS: &[&str] = &[
+    "password",
+    "passwd",
+    "secret",
+    "changeme",
+    "letmein",
+    "qwerty",
+    "admin",
+    "default",
+    "darktower",
+    "dark-tower",
+];
+
+/// Why a client secret was rejected.
+///
+/// Messages are returned to the caller and never include the secret.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SecretPolicyError {
+    #[error("Client secret must be at least {min} characters")]
+    TooShort { min: usize },
+
+    #[error("Client secret must be at most {max} bytes")]
+    TooLong { max: usize },
+
+    #[error("Client secret must contain only printable ASCII characters")]
+    InvalidCharacters,
+
+    #[error(
+        "Client secret has an estimated {estimated} bits of entropy; at least {required} are required"
+    )]
+    InsufficientEntropy { estimated: u32, required: u32 },
+
+    #[error("Client secret must not repeat a character 4 or more times in a row")]
+    RepeatedCharacters,
+
+    #[error("Client secret must not contain sequences such as 'abcd' or '4321'")]
+    Sequence,
+
+    #[error("Client secret must contain at least 10 distinct characters")]
+    TooFewDistinct,
+
+    #[error("Client secret must not contain common words or the service type")]
+    BannedWord,
+}
+
+/// Minimum strength required of caller-supplied client secrets.
+///
+/// Configured with `AC_CLIENT_SECRET_MIN_LENGTH` (default 32, range 16-64)
+/// and `AC_CLIENT_SECRET_MIN_ENTROPY_BITS` (default 128, range 64-256).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretPolicy {
+    pub min_length: usize,
+    pub min_entropy_bits: u32,
+}
+
+impl Default for SecretPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: DEFAULT_SECRET_MIN_LENGTH,
+            min_entropy_bits: DEFAULT_SECRET_MIN_ENTROPY_BITS,
+        }
+    }
+}
+
+impl SecretPolicy {
+    /// Check `secret` against the policy.
+    ///
+    /// `context` lists values the secret must not contain, such as the
+    /// service type being registered (compared case-insensitively).
+    pub fn validate(&self, secret: &str, context: &[&str]) -> Result<(), SecretPolicyError> {
+        if !secret.bytes().all(|b| b.is_ascii_graphic()) {
+            return Err(SecretPolicyError::InvalidCharacters);
+        }
+        if secret.len() < self.min_length {
+            return Err(SecretPolicyError::TooShort {
+                min: self.min_length,
+            });
+        }
+        if secret.len() > MAX_SECRET_BYTES {
+            return Err(SecretPolicyError::TooLong {
+                max: MAX_SECRET_BYTES,
+            });
+        }
+
+        let estimated = estimate_entropy_bits(secret);
+        if estimated < self.min_entropy_bits {
+            return Err(SecretPolicyError::InsufficientEntropy {
+                estimated,
+                required: self.min_entropy_bits,
+            });
+        }
+
+        check_patterns(secret, context)
+    }
+}
+
+/// Estimated entropy of `secret` in bits, from its length and the character
+/// classes it uses.
+pub fn estimate_entropy_bits(secret: &str) -> u32 {
+    let bytes = secret.as_bytes();
+    let mut pool = 0u32;
+    if bytes.iter().any(u8::is_ascii_lowercase) {
+        pool += 26;
+    }
+    if bytes.iter().any(u8::is_ascii_uppercase) {
+        pool += 26;
+    }
+    if bytes.iter().any(u8::is_ascii_digit) {
+        pool += 10;
+    }
+    if bytes.iter().any(u8::is_ascii_punctuation) {
+        pool += 32;
+    }
+    if pool == 0 {
+        return 0;
+    }
+    (bytes.len() as f64 * f64::from(pool).log2()).floor() as u32
+}
+
+fn check_patterns(secret: &str, context: &[&str]) -> Result<(), SecretPolicyError> {
+    let bytes = secret.as_bytes();
+
+    // Runs of one character ("aaaa") and of consecutive ones ("abcd", "4321")
+    let (mut same, mut up, mut down) = (1, 1, 1);
+    for pair in bytes.windows(2) {
+        let (prev, next) = match pair {
+            [prev, next] => (i16::from(*prev), i16::from(*next)),
+            _ => continue,
+        };
+        same = if next == prev { same + 1 } else { 1 };
+        up = if next == prev + 1 { up + 1 } else { 1 };
+        down = if next == prev - 1 { down + 1 } else { 1 };
+        if same >= MAX_RUN {
+            return Err(SecretPolicyError::RepeatedCharacters);
+        }
+        if up >= MAX_RUN || down >= MAX_RUN {
+            return Err(SecretPolicyError::Sequence);
+        }
+    }
+
+    let mut distinct = [false; 128];
+    for b in bytes {
+        if let Some(seen) = distinct.get_mut(usize::from(*b)) {
+            *seen = true;
+        }
+    }
+    if distinct.iter().filter(|seen| **seen).count() < MIN_DISTINCT_CHARS {
+        return Err(SecretPolicyError::TooFewDistinct);
+    }
+
+    let lowered = secret.to_ascii_lowercase();
+    let banned = BANNED_WORDS.iter().copied().chain(context.iter().copied());
+    for word in banned {
+        let word = word.trim().to_ascii_lowercase();
+        if !word.is_empty() && lowered.contains(&word) {
+            return Err(SecretPolicyError::BannedWord);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto;
+    use common::secret::ExposeSecret;
+
+    /// A secret that passes the default policy.
+    const STRONG: &str = "kQ7vZ2pX9mW4rT8yL1nB6hJ3fD5sG0cE";
+
+    #[test]
+    fn test_generated_secrets_pass_default_policy() {
+        let policy = SecretPolicy::default();
+        for _ in 0..20 {
+            let secret = crypto::generate_client_secret().unwrap();
+            // A random secret can contain a short sequence by chance; the
+            // entropy and length checks must never fail for one
+            match policy.validate(secret.expose_secret(), &["media-handler"]) {
+                Ok(())
+                | Err(SecretPolicyError::Sequence)
+                | Err(SecretPolicyError::RepeatedCharacters) => {}
+                Err(e) => panic!("generated secret rejected: {e}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_strong_secret_accepted() {
+        assert_eq!(
+            SecretPolicy::default().validate(STRONG, &["global-controller"]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_length_limits() {
+        let policy = SecretPolicy::default();
+        assert_eq!(
+            policy.validate("kQ7vZ2pX9mW4rT8y", &[]),
+            Err(SecretPolicyError::TooShort { min: 32 })
+        );
+        assert_eq!(
+            policy.validate(&STRONG.repeat(3), &[]),
+            Err(SecretPolicyError::TooLong {
+                max: MAX_SECRET_BYTES
+            })
+        );
+    }
+
+    #[test]
+    fn test_non_printable_rejected() {
+        let policy = SecretPolicy::default();
+        for secret in [
+            "kQ7vZ2pX9mW4rT8y L1nB6hJ3fD5sG0cE",
+            "kQ7vZ2pX9mW4rT8y\nL1nB6hJ3fD5sG0cE",
+            "kQ7vZ2pX9mW4rT8yéL1nB6hJ3fD5sG0cE",
+        ] {
+            assert_eq!(
+                policy.validate(secret, &[]),
+                Err(SecretPolicyError::InvalidCharacters)
+            );
+        }
+    }
+
+    #[test]
+    fn test_low_entropy_rejected() {
+        let policy = SecretPolicy {
+            min_length: 32,
+            min_entropy_bits: 160,
+        };
+        // 32 lowercase letters: 32 * log2(26) = 150 bits
+        assert_eq!(
+            policy.validate("kqvzpxmwrtylnbhjfdsgcekqvzpxmwrt", &[]),
+            Err(SecretPolicyError::InsufficientEntropy {
+                estimated: 150,
+                required: 160
+            })
+        );
+        assert_eq!(policy.validate(STRONG, &[]), Ok(()));
+    }
+
+    #[test]
+    fn test_entropy_estimate() {
+        assert_eq!(estimate_entropy_bits(""), 0);
+        assert_eq!(estimate_entropy_bits("0123456789"), 33);
+        // All four classes: 94 symbols, ~6.55 bits each
+        assert_eq!(estimate_entropy_bits("aA0!"), 26);
+    }
+
+    #[test]
+    fn test_repeated_characters_rejected() {
+        assert_eq!(
+            SecretPolicy::default().validate("kQ7vZ2pX9mW4rT8yL1nBBBBhJ3fD5sG0cE", &[]),
+            Err(SecretPolicyError::RepeatedCharacters)
+        );
+    }
+
+    #[test]
+    fn test_sequences_rejected() {
+        let policy = SecretPolicy::default();
+        for secret in [
+            "kQ7vZ2pX9mW4rT8yabcdL1nB6hJ3fD5sG0cE",
+            "kQ7vZ2pX9mW4rT8y4321L1nB6hJ3fD5sG0cE",
+        ] {
+            assert_eq!(
+                policy.validate(secret, &[]),
+                Err(SecretPolicyError::Sequence)
+            );
+        }
+    }
+
+    #[test]
+    fn test_too_few_distinct_rejected() {
+        assert_eq!(
+            SecretPolicy::default().validate("aB3$aB3$aB3$aB3$aB3$aB3$aB3$aB3$", &[]),
+            Err(SecretPolicyError::TooFewDistinct)
+        );
+    }
+
+    #[test]
+    fn test_banned_words_rejected() {
+        let policy = SecretPolicy::default();
+        assert_eq!(
+            policy.validate("kQ7vZ2pX9mW4PassWord8yL1nB6hJ3fD5sG0cE", &[]),
+            Err(SecretPolicyError::BannedWord)
+        );
+        assert_eq!(
+            policy.validate(
+                "kQ7vZ2pX9mW4Media-Handler8yL1nB6hJ3fD5sG0cE",
+                &["media-handler"]
+            ),
+            Err(SecretPolicyError::BannedWord)
+        );
+        // Empty context entries are ignored
+        assert_eq!(policy.validate(STRONG, &["", " "]), Ok(()));
+    }
+
+    #[test]
+    fn test_errors_do_not_echo_secret() {
+        let err = SecretPolicy::default()
+            .validate("kQ7vZ2pX9mW4PassWord8yL1nB6hJ3fD5sG0cE", &[])
+            .unwrap_err();
+        assert!(!err.to_string().contains("kQ7v"));
+    }
+}
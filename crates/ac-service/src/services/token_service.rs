@@ -1,34 +1,170 @@
+use crate::config::{MtlsMode, TokenTtlPolicy, DEFAULT_USER_TOKEN_TTL_SECONDS};
 #[cfg(test)]
 use crate::config::{
     DEFAULT_BCRYPT_COST, DEFAULT_JWT_CLOCK_SKEW, DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
     DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
 };
-use crate::crypto::{self, Claims, EncryptedKey, UserClaims};
+use crate::crypto::master_key::MasterKeyProvider;
+use crate::crypto::{self, Claims, DelegatedClaims, EncryptedKey, UserClaims};
 use crate::errors::AcError;
-use crate::models::{AuthEventType, TokenResponse};
+use crate::models::{
+    AuthEventType, IntrospectionResponse, NewAuthEvent, ServiceCredential, SigningKey,
+    TokenResponse,
+};
 use crate::observability::hash_for_correlation;
-use crate::observability::metrics::{record_audit_log_failure, record_rate_limit_decision};
-use crate::repositories::{auth_events, service_credentials, signing_keys, users};
+use crate::observability::metrics::record_rate_limit_decision;
+use crate::repositories::refresh_tokens::{self, RefreshToken};
+use crate::repositories::{
+    auth_events, service_credential_failures, service_credentials, signing_keys, users,
+};
+use crate::services::audit_writer;
+use crate::services::totp_service::{self, TotpCheck};
+use base64::{engine::general_purpose, Engine as _};
 use chrono::Utc;
+use common::dpop::DPOP_TOKEN_TYPE;
+use common::jwt::{extract_kid, Actor, Confirmation, DELEGATED_SCOPE_PREFIX};
 use common::secret::SecretBox;
+use ring::digest;
 use sqlx::PgPool;
+use std::time::Duration;
+use tracing::instrument;
 use uuid::Uuid;
 
-// Token configuration
-const TOKEN_EXPIRY_SECONDS: u64 = 3600; // 1 hour
-const TOKEN_EXPIRY_SECONDS_I64: i64 = 3600; // 1 hour (for timestamp calculations)
+// Token configuration (access token lifetimes come from `TokenTtlPolicy`)
+const REFRESH_TOKEN_EXPIRY_DAYS: i64 = 30;
+const REFRESH_TOKEN_BYTES: usize = 32; // 256 bits
+
+// Per-credential backoff after consecutive failed authentications. The
+// window lockout above limits bursts; the backoff keeps growing across
+// windows until the credential authenticates successfully.
+/// Consecutive failures allowed before backoff starts.
+const BACKOFF_FREE_FAILURES: i32 = 5;
+/// Delay after the first failure past the free ones; doubles after each further one.
+const BACKOFF_BASE_SECONDS: i64 = 1;
+/// Longest backoff delay (1 hour).
+const BACKOFF_MAX_SECONDS: i64 = 3600;
 
 // Security test configuration
 #[cfg(test)]
 const MAX_TIMING_VARIANCE_PERCENT: f64 = 30.0; // Timing attack tolerance threshold
 
+/// How a service client proved its identity to the token endpoint.
+#[derive(Debug, Clone, Copy)]
+pub struct ServiceClientAuth<'a> {
+    /// The `client_secret`, if the client sent one.
+    pub client_secret: Option<&'a str>,
+    /// SPKI fingerprint of the TLS client certificate, if one was presented.
+    pub cert_fingerprint: Option<&'a str>,
+    pub mtls_mode: MtlsMode,
+    /// Thumbprint of the client's verified DPoP proof key, if it sent one;
+    /// the token is then bound to that key (see [`bind_to_dpop_key`]).
+    pub dpop_jkt: Option<&'a str>,
+}
+
+impl<'a> ServiceClientAuth<'a> {
+    /// Secret-only authentication (mTLS disabled).
+    pub fn secret(client_secret: &'a str) -> Self {
+        Self {
+            client_secret: Some(client_secret),
+            cert_fingerprint: None,
+            mtls_mode: MtlsMode::Disabled,
+            dpop_jkt: None,
+        }
+    }
+
+    /// The audit failure reason, or `None` if authentication succeeded.
+    ///
+    /// `secret_valid` and `cert_valid` are `None` when that factor was not
+    /// presented; a certificate only counts if the credential has one pinned.
+    /// Whatever is presented must be valid, and:
+    /// - `Disabled`: the secret is required (certificates are ignored)
+    /// - `Optional`: the secret or a pinned certificate is required
+    /// - `Required`: a pinned certificate is required
+    fn failure_reason(
+        &self,
+        secret_valid: Option<bool>,
+        cert_valid: Option<bool>,
+    ) -> Option<&'static str> {
+        let cert_valid = match self.mtls_mode {
+            MtlsMode::Disabled => None,
+            _ => cert_valid,
+        };
+
+        if secret_valid == Some(false) {
+            return Some("Invalid client secret");
+        }
+        if cert_valid == Some(false) {
+            return Some("Client certificate mismatch");
+        }
+        match self.mtls_mode {
+            MtlsMode::Disabled if secret_valid.is_none() => Some("Invalid client secret"),
+            MtlsMode::Optional if secret_valid.is_none() && cert_valid.is_none() => {
+                Some("Invalid client secret")
+            }
+            MtlsMode::Required if cert_valid.is_none() => Some("Client certificate required"),
+            _ => None,
+        }
+    }
+}
+
+/// The `cnf` claim and `token_type` of a service token: bound to the
+/// client's DPoP key (RFC 9449) if it sent a proof, a bearer token
+/// otherwise.
+fn bind_to_dpop_key(dpop_jkt: Option<&str>) -> (Option<Confirmation>, &'static str) {
+    match dpop_jkt {
+        Some(jkt) => (
+            Some(Confirmation {
+                jkt: jkt.to_string(),
+            }),
+            DPOP_TOKEN_TYPE,
+        ),
+        None => (None, "Bearer"),
+    }
+}
+
+/// Backoff delay in seconds after `consecutive_failures` failures in a row.
+///
+/// Zero for the first [`BACKOFF_FREE_FAILURES`], then
+/// [`BACKOFF_BASE_SECONDS`] doubling with each failure, capped at
+/// [`BACKOFF_MAX_SECONDS`].
+fn backoff_seconds(consecutive_failures: i32) -> i64 {
+    let over = consecutive_failures.saturating_sub(BACKOFF_FREE_FAILURES);
+    if over <= 0 {
+        return 0;
+    }
+    // 2^12 already exceeds the cap
+    let exponent = (over - 1).min(12) as u32;
+    (BACKOFF_BASE_SECONDS << exponent).min(BACKOFF_MAX_SECONDS)
+}
+
+/// Whole seconds until a credential with `failures` may try again, or
+/// `None` if it is not backing off at `now`.
+fn backoff_retry_after(
+    failures: &service_credential_failures::CredentialFailures,
+    now: chrono::DateTime<Utc>,
+) -> Option<i64> {
+    let delay = chrono::Duration::seconds(backoff_seconds(failures.consecutive_failures));
+    let remaining = failures.last_failure_at + delay - now;
+    if remaining <= chrono::Duration::zero() {
+        return None;
+    }
+    // Round up so a client honouring Retry-After is not refused again
+    let seconds = remaining.num_seconds();
+    Some(if remaining > chrono::Duration::seconds(seconds) {
+        seconds + 1
+    } else {
+        seconds
+    })
+}
+
 /// Issue a service token using OAuth 2.0 Client Credentials flow
 ///
-/// Verifies client credentials, generates JWT with scopes, logs event
+/// Verifies client credentials, generates JWT with scopes, logs event.
+/// Tokens get the default lifetimes ([`TokenTtlPolicy::default`]).
 #[expect(clippy::too_many_arguments)] // OAuth 2.0 token endpoint requires many params
 pub async fn issue_service_token(
     pool: &PgPool,
-    master_key: &[u8],
+    master_key: &dyn MasterKeyProvider,
     hash_secret: &[u8],
     client_id: &str,
     client_secret: &str,
@@ -38,16 +174,140 @@ pub async fn issue_service_token(
     user_agent: Option<&str>,
     rate_limit_window_minutes: i64,
     rate_limit_max_attempts: i64,
+) -> Result<TokenResponse, AcError> {
+    issue_service_token_with_auth(
+        pool,
+        master_key,
+        hash_secret,
+        client_id,
+        ServiceClientAuth::secret(client_secret),
+        grant_type,
+        requested_scopes,
+        ip_address,
+        user_agent,
+        rate_limit_window_minutes,
+        rate_limit_max_attempts,
+        &TokenTtlPolicy::default(),
+    )
+    .await
+}
+
+/// Issue a service token, authenticating the client with its secret, its
+/// TLS client certificate, or both (see [`ServiceClientAuth`]).
+///
+/// The token lifetime is `token_ttls` for the credential's service type.
+#[expect(clippy::too_many_arguments)] // OAuth 2.0 token endpoint requires many params
+#[instrument(name = "ac.token_service.issue_service_token", skip_all)]
+pub async fn issue_service_token_with_auth(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    hash_secret: &[u8],
+    client_id: &str,
+    auth: ServiceClientAuth<'_>,
+    grant_type: &str,
+    requested_scopes: Option<Vec<String>>,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+    rate_limit_window_minutes: i64,
+    rate_limit_max_attempts: i64,
+    token_ttls: &TokenTtlPolicy,
 ) -> Result<TokenResponse, AcError> {
     // Validate grant_type
     if grant_type != "client_credentials" {
         return Err(AcError::InvalidCredentials);
     }
 
+    let credential = authenticate_service_client(
+        pool,
+        hash_secret,
+        client_id,
+        &auth,
+        ip_address,
+        user_agent,
+        rate_limit_window_minutes,
+        rate_limit_max_attempts,
+    )
+    .await?;
+
+    // Determine scopes (use requested scopes if provided and valid, otherwise use default)
+    let scopes = if let Some(req_scopes) = requested_scopes {
+        // Verify requested scopes are subset of allowed scopes
+        let all_valid = req_scopes.iter().all(|s| credential.scopes.contains(s));
+
+        if !all_valid {
+            return Err(AcError::InsufficientScope {
+                required: req_scopes.join(" "),
+                provided: credential.scopes.clone(),
+            });
+        }
+        req_scopes
+    } else {
+        credential.scopes.clone()
+    };
+
+    let (key_id, private_key_pkcs8) = load_signing_key(pool, master_key).await?;
+
+    // Generate JWT claims
+    let now = Utc::now().timestamp();
+    let ttl = token_ttls.service_ttl(&credential.service_type, &scopes);
+    let (cnf, token_type) = bind_to_dpop_key(auth.dpop_jkt);
+    let claims = Claims {
+        sub: client_id.to_string(),
+        exp: now + i64::from(ttl),
+        iat: now,
+        scope: scopes.join(" "),
+        service_type: Some(credential.service_type.clone()),
+        cnf,
+    };
+
+    // Sign JWT with key_id
+    let token = crypto::sign_jwt(&claims, &private_key_pkcs8, &key_id)?;
+
+    // Log successful token issuance
+    audit_writer::record(
+        pool,
+        NewAuthEvent::success(AuthEventType::ServiceTokenIssued)
+            .credential(Some(credential.credential_id))
+            .client(ip_address, user_agent)
+            .metadata(serde_json::json!({
+                "key_id": key_id,
+                "scopes": scopes,
+                "token_type": token_type,
+            })),
+    )
+    .await;
+
+    Ok(TokenResponse {
+        access_token: token,
+        token_type: token_type.to_string(),
+        expires_in: u64::from(ttl),
+        scope: scopes.join(" "),
+        refresh_token: None,
+        issued_token_type: None,
+    })
+}
+
+/// Authenticate a service client at the token endpoint.
+///
+/// Applies the failed-attempt lockout and the consecutive-failure backoff,
+/// then checks the secret and certificate against `auth`'s mTLS mode.
+/// Failures are audited and extend the backoff; success ends it.
+#[expect(clippy::too_many_arguments)] // OAuth 2.0 token endpoint requires many params
+async fn authenticate_service_client(
+    pool: &PgPool,
+    hash_secret: &[u8],
+    client_id: &str,
+    auth: &ServiceClientAuth<'_>,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+    rate_limit_window_minutes: i64,
+    rate_limit_max_attempts: i64,
+) -> Result<ServiceCredential, AcError> {
     // Fetch credential from database
     let credential = service_credentials::get_by_client_id(pool, client_id).await?;
 
     // Check for account lockout (prevent brute force)
+    let mut failures = None;
     if let Some(ref cred) = credential {
         let rate_limit_window_ago =
             Utc::now() - chrono::Duration::minutes(rate_limit_window_minutes);
@@ -67,300 +327,1027 @@ pub async fn issue_service_token(
             record_rate_limit_decision("rejected");
             return Err(AcError::RateLimitExceeded);
         }
+
+        // Exponential backoff after consecutive failures, checked before
+        // bcrypt so guessing cannot be used to exhaust CPU
+        failures = service_credential_failures::get(pool, cred.credential_id).await?;
+        if let Some(retry_after_seconds) = failures
+            .as_ref()
+            .and_then(|f| backoff_retry_after(f, Utc::now()))
+        {
+            tracing::warn!(
+                retry_after_seconds,
+                "Credential backing off after consecutive failed attempts: client_id_hash={}",
+                hash_for_correlation(client_id, hash_secret)
+            );
+            record_rate_limit_decision("rejected");
+            return Err(AcError::TooManyRequests {
+                retry_after_seconds,
+                message: "Too many failed authentication attempts. Please try again later."
+                    .to_string(),
+            });
+        }
         record_rate_limit_decision("allowed");
     }
 
-    // Always run bcrypt to prevent timing attacks
+    // Always run bcrypt when a secret is presented to prevent timing attacks
     // Use dummy hash if credential not found (constant-time operation)
     let hash_to_verify = match &credential {
         Some(c) => c.client_secret_hash.as_str(),
         None => "$2b$12$LQv3c1yqBWVHxkd0LHAkCOYz6TtxMQJqhN8/LewY5GyYqExt7YD3a", // Dummy bcrypt hash
     };
 
-    let is_valid = crypto::verify_client_secret(client_secret, hash_to_verify)?;
+    let secret_valid = match auth.client_secret {
+        Some(secret) => Some(crypto::verify_client_secret(secret, hash_to_verify)?),
+        None => None,
+    };
 
     // Now check if credential existed and was active
     let credential = credential.ok_or(AcError::InvalidCredentials)?;
 
-    if !credential.is_active || !is_valid {
+    // A certificate only counts toward authentication if one is pinned
+    let cert_valid = match (auth.cert_fingerprint, &credential.client_cert_spki_sha256) {
+        (Some(presented), Some(pinned)) => Some(presented == pinned),
+        _ => None,
+    };
+
+    let failure = if !credential.is_active {
+        Some("Credential is inactive")
+    } else {
+        auth.failure_reason(secret_valid, cert_valid)
+    };
+
+    if let Some(reason) = failure {
         // Log failed attempt
-        if let Err(e) = auth_events::log_event(
+        audit_writer::record(
             pool,
-            AuthEventType::ServiceTokenFailed.as_str(),
-            None,
-            Some(credential.credential_id),
-            false,
-            Some(if !credential.is_active {
-                "Credential is inactive"
-            } else {
-                "Invalid client secret"
-            }),
-            ip_address,
-            user_agent,
-            None,
+            NewAuthEvent::failure(AuthEventType::ServiceTokenFailed, reason)
+                .credential(Some(credential.credential_id))
+                .client(ip_address, user_agent),
         )
-        .await
+        .await;
+
+        // Extends the backoff; a write failure must not change the response
+        if let Err(e) =
+            service_credential_failures::record_failure(pool, credential.credential_id).await
         {
-            tracing::warn!("Failed to log auth event: {}", e);
-            record_audit_log_failure("service_token_failed", "db_write_failed");
+            tracing::warn!(error = %e, "Failed to record credential failure");
         }
 
         return Err(AcError::InvalidCredentials);
     }
 
-    // Determine scopes (use requested scopes if provided and valid, otherwise use default)
-    let scopes = if let Some(req_scopes) = requested_scopes {
-        // Verify requested scopes are subset of allowed scopes
-        let all_valid = req_scopes.iter().all(|s| credential.scopes.contains(s));
+    // Authenticated: end any failure streak
+    if failures.is_some() {
+        if let Err(e) = service_credential_failures::clear(pool, credential.credential_id).await {
+            tracing::warn!(error = %e, "Failed to clear credential failures");
+        }
+    }
 
-        if !all_valid {
-            return Err(AcError::InsufficientScope {
-                required: req_scopes.join(" "),
-                provided: credential.scopes.clone(),
+    Ok(credential)
+}
+
+/// User token response (ADR-0020).
+///
+/// Contains the JWT access token and its metadata.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UserTokenResponse {
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+}
+
+/// Issue a user token using email/password authentication (ADR-0020).
+///
+/// Authenticates user within an organization, generates JWT with UserClaims.
+/// Users with a confirmed TOTP enrollment are refused with
+/// [`AcError::TotpRequired`]; they log in with [`issue_user_login_token`].
+/// The token gets the default user token lifetime
+/// ([`DEFAULT_USER_TOKEN_TTL_SECONDS`]).
+///
+/// # Security
+///
+/// - Rate limiting by IP + email (via auth_events)
+/// - Constant-time bcrypt verification (dummy hash for non-existent users)
+/// - Check is_active status
+/// - Log auth events
+#[expect(clippy::too_many_arguments)]
+pub async fn issue_user_token(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    hash_secret: &[u8],
+    org_id: Uuid,
+    email: &str,
+    password: &str,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+    rate_limit_window_minutes: i64,
+    rate_limit_max_attempts: i64,
+) -> Result<UserTokenResponse, AcError> {
+    issue_user_login_token(
+        pool,
+        master_key,
+        hash_secret,
+        org_id,
+        email,
+        password,
+        None,
+        ip_address,
+        user_agent,
+        rate_limit_window_minutes,
+        rate_limit_max_attempts,
+        DEFAULT_USER_TOKEN_TTL_SECONDS,
+    )
+    .await
+}
+
+/// Issue a user token using email/password and, for users who have enabled
+/// it, a TOTP code (`POST /api/v1/auth/user/login`).
+///
+/// Same checks as [`issue_user_token`], plus the second factor from
+/// [`authenticate_user`]. The token lives `token_ttl_seconds`.
+#[expect(clippy::too_many_arguments)]
+#[instrument(name = "ac.token_service.issue_user_login_token", skip_all)]
+pub async fn issue_user_login_token(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    hash_secret: &[u8],
+    org_id: Uuid,
+    email: &str,
+    password: &str,
+    totp_code: Option<&str>,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+    rate_limit_window_minutes: i64,
+    rate_limit_max_attempts: i64,
+    token_ttl_seconds: u32,
+) -> Result<UserTokenResponse, AcError> {
+    let user = authenticate_user(
+        pool,
+        master_key,
+        hash_secret,
+        org_id,
+        email,
+        password,
+        totp_code,
+        ip_address,
+        user_agent,
+        rate_limit_window_minutes,
+        rate_limit_max_attempts,
+    )
+    .await?;
+
+    issue_token_for_user(
+        pool,
+        master_key,
+        &user,
+        token_ttl_seconds,
+        ip_address,
+        user_agent,
+    )
+    .await
+}
+
+/// Sign a user token for an already-authenticated user and record the login.
+///
+/// Shared by password login and OIDC federation
+/// ([`crate::services::oidc_service`]).
+pub(crate) async fn issue_token_for_user(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    user: &users::User,
+    token_ttl_seconds: u32,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+) -> Result<UserTokenResponse, AcError> {
+    // Get user roles
+    let roles = users::get_user_roles(pool, user.user_id).await?;
+
+    let (key_id, private_key_pkcs8) = load_signing_key(pool, master_key).await?;
+
+    // Generate user JWT claims per ADR-0020
+    let now = Utc::now().timestamp();
+    let jti = Uuid::new_v4().to_string();
+    let claims = UserClaims {
+        sub: user.user_id.to_string(),
+        org_id: user.org_id.to_string(),
+        email: user.email.clone(),
+        roles,
+        iat: now,
+        exp: now + i64::from(token_ttl_seconds),
+        jti,
+    };
+
+    // Sign JWT with user claims
+    let token = crypto::sign_user_jwt(&claims, &private_key_pkcs8, &key_id)?;
+
+    // Log successful login
+    log_user_auth_event(pool, &user.user_id, true, None, ip_address, user_agent).await;
+
+    // Update last_login_at
+    if let Err(e) = users::update_last_login(pool, user.user_id).await {
+        tracing::warn!("Failed to update last login timestamp: {}", e);
+    }
+
+    Ok(UserTokenResponse {
+        access_token: token,
+        token_type: "Bearer".to_string(),
+        expires_in: u64::from(token_ttl_seconds),
+        refresh_token: None,
+    })
+}
+
+/// Check a user's password and, once they have confirmed a TOTP
+/// enrollment, their TOTP code (ADR-0020).
+///
+/// Shared by login and TOTP enrollment, so every failure counts toward the
+/// same lockout. A missing TOTP code returns [`AcError::TotpRequired`]
+/// without counting as a failure: the password was right.
+#[expect(clippy::too_many_arguments)]
+#[instrument(name = "ac.token_service.authenticate_user", skip_all)]
+pub async fn authenticate_user(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    hash_secret: &[u8],
+    org_id: Uuid,
+    email: &str,
+    password: &str,
+    totp_code: Option<&str>,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+    rate_limit_window_minutes: i64,
+    rate_limit_max_attempts: i64,
+) -> Result<users::User, AcError> {
+    // Check for account lockout (prevent brute force)
+    // We use email as the identifier for rate limiting in user context
+    let rate_limit_window_ago = Utc::now() - chrono::Duration::minutes(rate_limit_window_minutes);
+
+    // Get failed attempts for this email (using correlation hash)
+    // For user auth, we need to check by email correlation, not credential_id
+    // We'll use a simple approach: get user first if exists, then check
+    let user = users::get_by_email(pool, org_id, email).await?;
+
+    if let Some(ref u) = user {
+        let failed_count =
+            auth_events::get_failed_attempts_count_by_user(pool, &u.user_id, rate_limit_window_ago)
+                .await
+                .unwrap_or(0);
+
+        if failed_count >= rate_limit_max_attempts {
+            tracing::warn!(
+                "User account locked due to excessive failed attempts: email_hash={}",
+                hash_for_correlation(email, hash_secret)
+            );
+            record_rate_limit_decision("rejected");
+            return Err(AcError::TooManyRequests {
+                retry_after_seconds: rate_limit_window_minutes * 60,
+                message: "Too many failed login attempts. Please try again later.".to_string(),
             });
         }
-        req_scopes
+        record_rate_limit_decision("allowed");
+    }
+
+    // Always run bcrypt to prevent timing attacks
+    // Use dummy hash if user not found (constant-time operation)
+    let hash_to_verify = match &user {
+        Some(u) => u.password_hash.as_str(),
+        None => "$2b$12$LQv3c1yqBWVHxkd0LHAkCOYz6TtxMQJqhN8/LewY5GyYqExt7YD3a", // Dummy bcrypt hash
+    };
+
+    let is_valid = crypto::verify_client_secret(password, hash_to_verify)?;
+
+    // Now check if user existed and credentials were valid
+    let user = user.ok_or(AcError::InvalidCredentials)?;
+
+    if !user.is_active {
+        // Log failed attempt
+        log_user_auth_event(
+            pool,
+            &user.user_id,
+            false,
+            Some("User account is inactive"),
+            ip_address,
+            user_agent,
+        )
+        .await;
+        return Err(AcError::InvalidCredentials);
+    }
+
+    if !is_valid {
+        // Log failed attempt
+        log_user_auth_event(
+            pool,
+            &user.user_id,
+            false,
+            Some("Invalid password"),
+            ip_address,
+            user_agent,
+        )
+        .await;
+        return Err(AcError::InvalidCredentials);
+    }
+
+    // Second factor, once the user has confirmed a TOTP enrollment
+    match totp_service::check_code(pool, master_key, user.user_id, totp_code).await? {
+        TotpCheck::NotEnrolled | TotpCheck::Accepted => {}
+        TotpCheck::Missing => return Err(AcError::TotpRequired),
+        TotpCheck::Rejected(reason) => {
+            log_user_auth_event(
+                pool,
+                &user.user_id,
+                false,
+                Some(reason),
+                ip_address,
+                user_agent,
+            )
+            .await;
+            return Err(AcError::InvalidCredentials);
+        }
+    }
+
+    Ok(user)
+}
+
+/// Log a user authentication event.
+pub(crate) async fn log_user_auth_event(
+    pool: &PgPool,
+    user_id: &Uuid,
+    success: bool,
+    failure_reason: Option<&str>,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+) {
+    let event_type = if success {
+        AuthEventType::UserLogin
     } else {
-        credential.scopes.clone()
+        AuthEventType::UserLoginFailed
     };
+    let event = NewAuthEvent {
+        success,
+        failure_reason: failure_reason.map(str::to_string),
+        ..NewAuthEvent::success(event_type)
+    };
+
+    audit_writer::record(
+        pool,
+        event.user(Some(*user_id)).client(ip_address, user_agent),
+    )
+    .await;
+}
 
-    // Load active signing key
+/// Load the active signing key and decrypt its private key.
+///
+/// Returns the key ID and the PKCS#8 private key.
+#[instrument(name = "ac.token_service.load_signing_key", skip_all)]
+async fn load_signing_key(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+) -> Result<(String, Vec<u8>), AcError> {
     let signing_key = signing_keys::get_active_key(pool)
         .await?
-        .ok_or_else(|| AcError::Crypto("No active signing key available".to_string()))?;
+        .ok_or_else(|| AcError::Crypto("No active signing key available".to_string()))?;
+
+    let encrypted_key = EncryptedKey {
+        encrypted_data: SecretBox::new(Box::new(signing_key.private_key_encrypted)),
+        nonce: signing_key.encryption_nonce,
+        tag: signing_key.encryption_tag,
+    };
+
+    let private_key_pkcs8 = master_key.decrypt_private_key(&encrypted_key)?;
+    Ok((signing_key.key_id, private_key_pkcs8))
+}
+
+// ============================================================================
+// Refresh Tokens
+// ============================================================================
+
+/// Error returned for any refresh token that cannot be redeemed.
+///
+/// Unknown, expired, used and revoked tokens are indistinguishable to the
+/// caller.
+fn invalid_refresh_token() -> AcError {
+    AcError::InvalidToken("The refresh token is invalid or expired".to_string())
+}
+
+/// Hash a refresh token for storage and lookup (hex SHA-256).
+///
+/// Refresh tokens are 256 random bits, so an unsalted fast hash is enough;
+/// bcrypt would only slow down every refresh.
+fn hash_refresh_token(refresh_token: &str) -> String {
+    hex::encode(digest::digest(&digest::SHA256, refresh_token.as_bytes()))
+}
+
+/// Generate a refresh token in `family_id` and store its hash.
+async fn store_refresh_token(
+    pool: &PgPool,
+    family_id: Uuid,
+    credential_id: Option<Uuid>,
+    user_id: Option<Uuid>,
+    scopes: &[String],
+) -> Result<String, AcError> {
+    let bytes = crypto::generate_random_bytes(REFRESH_TOKEN_BYTES)?;
+    let refresh_token = general_purpose::URL_SAFE_NO_PAD.encode(bytes);
+    let expires_at = Utc::now() + chrono::Duration::days(REFRESH_TOKEN_EXPIRY_DAYS);
+
+    refresh_tokens::create(
+        pool,
+        &hash_refresh_token(&refresh_token),
+        family_id,
+        credential_id,
+        user_id,
+        scopes,
+        expires_at,
+    )
+    .await?;
+
+    Ok(refresh_token)
+}
+
+/// Issue a refresh token for a service client, starting a new family.
+///
+/// Called after [`issue_service_token`] succeeds for a client that opted in.
+/// `scopes` are the scopes granted to the access token.
+#[instrument(name = "ac.token_service.issue_service_refresh_token", skip_all)]
+pub async fn issue_service_refresh_token(
+    pool: &PgPool,
+    client_id: &str,
+    scopes: &[String],
+) -> Result<String, AcError> {
+    let credential = service_credentials::get_by_client_id(pool, client_id)
+        .await?
+        .filter(|credential| credential.is_active)
+        .ok_or(AcError::InvalidCredentials)?;
+
+    store_refresh_token(
+        pool,
+        Uuid::new_v4(),
+        Some(credential.credential_id),
+        None,
+        scopes,
+    )
+    .await
+}
+
+/// Issue a refresh token for a user, starting a new family.
+///
+/// Called after [`issue_user_token`] succeeds for a user that opted in.
+#[instrument(name = "ac.token_service.issue_user_refresh_token", skip_all)]
+pub async fn issue_user_refresh_token(
+    pool: &PgPool,
+    org_id: Uuid,
+    email: &str,
+) -> Result<String, AcError> {
+    let user = users::get_by_email(pool, org_id, email)
+        .await?
+        .filter(|user| user.is_active)
+        .ok_or(AcError::InvalidCredentials)?;
+
+    store_refresh_token(pool, Uuid::new_v4(), None, Some(user.user_id), &[]).await
+}
+
+/// Redeem a refresh token, marking it used.
+///
+/// A token that was already used has leaked: its whole family is revoked so
+/// neither the thief nor the legitimate holder can continue the chain, and
+/// both must re-authenticate.
+async fn redeem_refresh_token(
+    pool: &PgPool,
+    refresh_token: &str,
+    for_service: bool,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+) -> Result<RefreshToken, AcError> {
+    let token_hash = hash_refresh_token(refresh_token);
+
+    if let Some(token) = refresh_tokens::consume(pool, &token_hash, for_service).await? {
+        return Ok(token);
+    }
+
+    // Not redeemable: check for reuse of a token that was already rotated
+    let Some(token) = refresh_tokens::get_by_hash(pool, &token_hash).await? else {
+        return Err(invalid_refresh_token());
+    };
+    if token.used_at.is_none() || token.revoked_at.is_some() {
+        return Err(invalid_refresh_token());
+    }
+
+    let revoked = refresh_tokens::revoke_family(pool, token.family_id).await?;
+    tracing::warn!(
+        family_id = %token.family_id,
+        revoked,
+        "Refresh token reuse detected, revoked token family"
+    );
+
+    audit_writer::record(
+        pool,
+        NewAuthEvent::failure(AuthEventType::TokenValidationFailed, "Refresh token reuse")
+            .user(token.user_id)
+            .credential(token.credential_id)
+            .client(ip_address, user_agent)
+            .metadata(serde_json::json!({
+                "family_id": token.family_id,
+            })),
+    )
+    .await;
+
+    Err(invalid_refresh_token())
+}
+
+/// Exchange a service refresh token for a new access token
+/// (OAuth 2.0 `refresh_token` grant, RFC 6749 Section 6).
+///
+/// The client authenticates exactly as for `client_credentials` (see
+/// [`ServiceClientAuth`]), including the lockout and backoff, and the
+/// refresh token must have been issued to it (RFC 6749 Section 10.4); a
+/// leaked refresh token is useless without the client's credentials.
+///
+/// The refresh token rotates: the response carries its successor. Scopes are
+/// the originally granted scopes still allowed for the client, so a scope
+/// removed from the credential is not carried forward. With `auth.dpop_jkt`
+/// the new access token is bound to that DPoP key. The access token
+/// lifetime is `token_ttls` for the credential's service type.
+#[expect(clippy::too_many_arguments)] // OAuth 2.0 token endpoint requires many params
+#[instrument(name = "ac.token_service.refresh_service_token", skip_all)]
+pub async fn refresh_service_token(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    hash_secret: &[u8],
+    client_id: &str,
+    auth: ServiceClientAuth<'_>,
+    refresh_token: &str,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+    rate_limit_window_minutes: i64,
+    rate_limit_max_attempts: i64,
+    token_ttls: &TokenTtlPolicy,
+) -> Result<TokenResponse, AcError> {
+    let credential = authenticate_service_client(
+        pool,
+        hash_secret,
+        client_id,
+        &auth,
+        ip_address,
+        user_agent,
+        rate_limit_window_minutes,
+        rate_limit_max_attempts,
+    )
+    .await?;
+
+    // A token issued to another client is rejected without redeeming it, so
+    // presenting it cannot rotate or revoke the other client's chain
+    let issued_to = refresh_tokens::get_by_hash(pool, &hash_refresh_token(refresh_token))
+        .await?
+        .and_then(|token| token.credential_id);
+    if issued_to != Some(credential.credential_id) {
+        if issued_to.is_some() {
+            audit_writer::record(
+                pool,
+                NewAuthEvent::failure(
+                    AuthEventType::TokenValidationFailed,
+                    "Refresh token issued to another client",
+                )
+                .credential(Some(credential.credential_id))
+                .client(ip_address, user_agent),
+            )
+            .await;
+        }
+        return Err(invalid_refresh_token());
+    }
 
-    // Decrypt private key
-    let encrypted_key = EncryptedKey {
-        encrypted_data: SecretBox::new(Box::new(signing_key.private_key_encrypted)),
-        nonce: signing_key.encryption_nonce,
-        tag: signing_key.encryption_tag,
-    };
+    let stored = redeem_refresh_token(pool, refresh_token, true, ip_address, user_agent).await?;
+    if stored.credential_id != Some(credential.credential_id) {
+        return Err(invalid_refresh_token());
+    }
 
-    let private_key_pkcs8 = crypto::decrypt_private_key(&encrypted_key, master_key)?;
+    let scopes: Vec<String> = stored
+        .scopes
+        .into_iter()
+        .filter(|scope| credential.scopes.contains(scope))
+        .collect();
+
+    let (key_id, private_key_pkcs8) = load_signing_key(pool, master_key).await?;
 
-    // Generate JWT claims
     let now = Utc::now().timestamp();
+    let ttl = token_ttls.service_ttl(&credential.service_type, &scopes);
+    let (cnf, token_type) = bind_to_dpop_key(auth.dpop_jkt);
     let claims = Claims {
-        sub: client_id.to_string(),
-        exp: now + TOKEN_EXPIRY_SECONDS_I64,
+        sub: credential.client_id.clone(),
+        exp: now + i64::from(ttl),
         iat: now,
         scope: scopes.join(" "),
         service_type: Some(credential.service_type.clone()),
+        cnf,
     };
 
-    // Sign JWT with key_id
-    let token = crypto::sign_jwt(&claims, &private_key_pkcs8, &signing_key.key_id)?;
+    let token = crypto::sign_jwt(&claims, &private_key_pkcs8, &key_id)?;
 
-    // Log successful token issuance
-    if let Err(e) = auth_events::log_event(
+    let next_refresh_token = store_refresh_token(
         pool,
-        AuthEventType::ServiceTokenIssued.as_str(),
-        None,
+        stored.family_id,
         Some(credential.credential_id),
-        true,
         None,
-        ip_address,
-        user_agent,
-        Some(serde_json::json!({
-            "key_id": signing_key.key_id,
-            "scopes": scopes,
-        })),
+        &scopes,
     )
-    .await
-    {
-        tracing::warn!("Failed to log auth event: {}", e);
-        record_audit_log_failure("service_token_issued", "db_write_failed");
-    }
+    .await?;
+
+    audit_writer::record(
+        pool,
+        NewAuthEvent::success(AuthEventType::ServiceTokenIssued)
+            .credential(Some(credential.credential_id))
+            .client(ip_address, user_agent)
+            .metadata(serde_json::json!({
+                "key_id": key_id,
+                "scopes": scopes,
+                "grant_type": "refresh_token",
+                "token_type": token_type,
+            })),
+    )
+    .await;
 
     Ok(TokenResponse {
         access_token: token,
-        token_type: "Bearer".to_string(),
-        expires_in: TOKEN_EXPIRY_SECONDS,
+        token_type: token_type.to_string(),
+        expires_in: u64::from(ttl),
         scope: scopes.join(" "),
+        refresh_token: Some(next_refresh_token),
+        issued_token_type: None,
     })
 }
 
-/// User token response (ADR-0020).
-///
-/// Contains the JWT access token and its metadata.
-#[derive(Debug, Clone, serde::Serialize)]
-pub struct UserTokenResponse {
-    pub access_token: String,
-    pub token_type: String,
-    pub expires_in: u64,
-}
-
-/// Issue a user token using email/password authentication (ADR-0020).
-///
-/// Authenticates user within an organization, generates JWT with UserClaims.
-///
-/// # Security
+/// Exchange a user refresh token for a new access token (ADR-0020).
 ///
-/// - Rate limiting by IP + email (via auth_events)
-/// - Constant-time bcrypt verification (dummy hash for non-existent users)
-/// - Check is_active status
-/// - Log auth events
-#[expect(clippy::too_many_arguments)]
-pub async fn issue_user_token(
+/// The refresh token rotates like a service refresh token. The user must
+/// still be active and belong to `org_id`; roles are reloaded, so role
+/// changes take effect on the next refresh. The access token lives
+/// `token_ttl_seconds`.
+#[instrument(name = "ac.token_service.refresh_user_token", skip_all)]
+pub async fn refresh_user_token(
     pool: &PgPool,
-    master_key: &[u8],
-    hash_secret: &[u8],
+    master_key: &dyn MasterKeyProvider,
     org_id: Uuid,
-    email: &str,
-    password: &str,
+    refresh_token: &str,
+    token_ttl_seconds: u32,
     ip_address: Option<&str>,
     user_agent: Option<&str>,
-    rate_limit_window_minutes: i64,
-    rate_limit_max_attempts: i64,
 ) -> Result<UserTokenResponse, AcError> {
-    // Check for account lockout (prevent brute force)
-    // We use email as the identifier for rate limiting in user context
-    let rate_limit_window_ago = Utc::now() - chrono::Duration::minutes(rate_limit_window_minutes);
+    let stored = redeem_refresh_token(pool, refresh_token, false, ip_address, user_agent).await?;
+    let user_id = stored.user_id.ok_or_else(invalid_refresh_token)?;
 
-    // Get failed attempts for this email (using correlation hash)
-    // For user auth, we need to check by email correlation, not credential_id
-    // We'll use a simple approach: get user first if exists, then check
-    let user = users::get_by_email(pool, org_id, email).await?;
+    let user = users::get_by_id(pool, user_id)
+        .await?
+        .filter(|user| user.org_id == org_id)
+        .ok_or_else(invalid_refresh_token)?;
+    if !user.is_active {
+        return Err(AcError::InvalidCredentials);
+    }
 
-    if let Some(ref u) = user {
-        let failed_count =
-            auth_events::get_failed_attempts_count_by_user(pool, &u.user_id, rate_limit_window_ago)
-                .await
-                .unwrap_or(0);
+    let roles = users::get_user_roles(pool, user.user_id).await?;
 
-        if failed_count >= rate_limit_max_attempts {
-            tracing::warn!(
-                "User account locked due to excessive failed attempts: email_hash={}",
-                hash_for_correlation(email, hash_secret)
-            );
-            record_rate_limit_decision("rejected");
-            return Err(AcError::TooManyRequests {
-                retry_after_seconds: rate_limit_window_minutes * 60,
-                message: "Too many failed login attempts. Please try again later.".to_string(),
-            });
-        }
-        record_rate_limit_decision("allowed");
-    }
+    let (key_id, private_key_pkcs8) = load_signing_key(pool, master_key).await?;
 
-    // Always run bcrypt to prevent timing attacks
-    // Use dummy hash if user not found (constant-time operation)
-    let hash_to_verify = match &user {
-        Some(u) => u.password_hash.as_str(),
-        None => "$2b$12$LQv3c1yqBWVHxkd0LHAkCOYz6TtxMQJqhN8/LewY5GyYqExt7YD3a", // Dummy bcrypt hash
+    let now = Utc::now().timestamp();
+    let claims = UserClaims {
+        sub: user.user_id.to_string(),
+        org_id: user.org_id.to_string(),
+        email: user.email.clone(),
+        roles,
+        iat: now,
+        exp: now + i64::from(token_ttl_seconds),
+        jti: Uuid::new_v4().to_string(),
     };
 
-    let is_valid = crypto::verify_client_secret(password, hash_to_verify)?;
+    let token = crypto::sign_user_jwt(&claims, &private_key_pkcs8, &key_id)?;
 
-    // Now check if user existed and credentials were valid
-    let user = user.ok_or(AcError::InvalidCredentials)?;
+    let next_refresh_token =
+        store_refresh_token(pool, stored.family_id, None, Some(user.user_id), &[]).await?;
 
-    if !user.is_active {
-        // Log failed attempt
-        log_user_auth_event(
-            pool,
-            &user.user_id,
-            false,
-            Some("User account is inactive"),
-            ip_address,
-            user_agent,
-        )
-        .await;
-        return Err(AcError::InvalidCredentials);
-    }
+    log_user_auth_event(pool, &user.user_id, true, None, ip_address, user_agent).await;
 
-    if !is_valid {
-        // Log failed attempt
-        log_user_auth_event(
-            pool,
-            &user.user_id,
-            false,
-            Some("Invalid password"),
-            ip_address,
-            user_agent,
-        )
-        .await;
-        return Err(AcError::InvalidCredentials);
+    Ok(UserTokenResponse {
+        access_token: token,
+        token_type: "Bearer".to_string(),
+        expires_in: u64::from(token_ttl_seconds),
+        refresh_token: Some(next_refresh_token),
+    })
+}
+
+// ============================================================================
+// Token Exchange (RFC 8693)
+// ============================================================================
+
+/// `grant_type` of the token exchange grant (RFC 8693 Section 2.1).
+pub const TOKEN_EXCHANGE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:token-exchange";
+
+/// Token type identifier for access tokens (RFC 8693 Section 3).
+pub const ACCESS_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:access_token";
+
+/// Token type identifier for JWTs (RFC 8693 Section 3).
+pub const JWT_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:jwt";
+
+/// Inputs of a token exchange.
+#[derive(Debug)]
+pub struct TokenExchange<'a> {
+    /// The user's access token (`subject_token`).
+    pub subject_token: &'a str,
+    /// The calling service's access token (`actor_token`).
+    pub actor_token: &'a str,
+    /// Requested delegated scopes; at least one is required.
+    pub scopes: Vec<String>,
+    /// Thumbprint of the caller's verified DPoP proof key, if it sent one.
+    pub dpop_jkt: Option<&'a str>,
+}
+
+/// Client ID of the service named by a token exchange's actor token, if
+/// the token verifies (see [`exchange_token`]).
+///
+/// Lets the caller charge the actor's own rate limit before the exchange;
+/// the credential itself is checked by the exchange.
+#[instrument(name = "ac.token_service.verified_actor", skip_all)]
+pub async fn verified_actor(
+    pool: &PgPool,
+    actor_token: &str,
+    clock_skew: Duration,
+) -> Result<Option<String>, AcError> {
+    let keys = signing_keys::get_all_active_keys(pool).await?;
+    Ok(verify_actor(&keys, actor_token, clock_skew).map(|actor| actor.sub))
+}
+
+/// Public key of the active signing key that signed `token`.
+fn public_key_for(keys: &[SigningKey], token: &str) -> Option<String> {
+    let key_id = extract_kid(token).ok()?;
+    keys.iter()
+        .find(|key| key.key_id == key_id)
+        .map(|key| key.public_key.clone())
+}
+
+/// Claims of an exchange actor token: a service token, not a delegated
+/// token acting again.
+fn verify_actor(keys: &[SigningKey], actor_token: &str, clock_skew: Duration) -> Option<Claims> {
+    let pem = public_key_for(keys, actor_token)?;
+    if crypto::verify_delegated_jwt(actor_token, &pem, clock_skew).is_ok() {
+        return None;
     }
+    crypto::verify_jwt(actor_token, &pem, clock_skew).ok()
+}
 
-    // Get user roles
-    let roles = users::get_user_roles(pool, user.user_id).await?;
+/// Exchange a service token and a user token for a delegated token
+/// (RFC 8693 token exchange).
+///
+/// The actor token must be a service token of an active credential; the
+/// subject token a user token of an active user. A delegated token cannot
+/// act again. If the actor token is DPoP-bound, the request must carry a
+/// proof from the same key.
+///
+/// The delegated token names the user as `sub` and the service as `act`.
+/// It only carries the requested scopes, each of which must be a
+/// [`DELEGATED_SCOPE_PREFIX`] scope the credential holds, and lives for the
+/// policy's delegated lifetime ([`TokenTtlPolicy::delegated_ttl`]), never
+/// past either input token.
+#[instrument(name = "ac.token_service.exchange_token", skip_all)]
+pub async fn exchange_token(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    exchange: TokenExchange<'_>,
+    clock_skew: Duration,
+    token_ttls: &TokenTtlPolicy,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+) -> Result<TokenResponse, AcError> {
+    let keys = signing_keys::get_all_active_keys(pool).await?;
 
-    // Load active signing key
-    let signing_key = signing_keys::get_active_key(pool)
+    // Actor: a service token, not a delegated token acting again
+    let Some(actor) = verify_actor(&keys, exchange.actor_token, clock_skew) else {
+        return Err(reject_exchange(pool, "Invalid actor token", ip_address, user_agent).await);
+    };
+    let credential = service_credentials::get_by_client_id(pool, &actor.sub)
         .await?
-        .ok_or_else(|| AcError::Crypto("No active signing key available".to_string()))?;
+        .filter(|credential| credential.is_active);
+    let Some(credential) = credential else {
+        return Err(reject_exchange(pool, "Credential is inactive", ip_address, user_agent).await);
+    };
+    if let Some(cnf) = &actor.cnf {
+        if exchange.dpop_jkt != Some(cnf.jkt.as_str()) {
+            return Err(reject_exchange(
+                pool,
+                "Actor token DPoP key mismatch",
+                ip_address,
+                user_agent,
+            )
+            .await);
+        }
+    }
 
-    // Decrypt private key
-    let encrypted_key = EncryptedKey {
-        encrypted_data: SecretBox::new(Box::new(signing_key.private_key_encrypted)),
-        nonce: signing_key.encryption_nonce,
-        tag: signing_key.encryption_tag,
+    // Subject: a user token of a user who is still active
+    let subject = public_key_for(&keys, exchange.subject_token)
+        .and_then(|pem| crypto::verify_user_jwt(exchange.subject_token, &pem, clock_skew).ok());
+    let Some(subject) = subject else {
+        return Err(reject_exchange(pool, "Invalid subject token", ip_address, user_agent).await);
+    };
+    let user = match Uuid::parse_str(&subject.sub) {
+        Ok(user_id) => users::get_by_id(pool, user_id)
+            .await?
+            .filter(|user| user.is_active && user.org_id.to_string() == subject.org_id),
+        Err(_) => None,
+    };
+    let Some(user) = user else {
+        return Err(
+            reject_exchange(pool, "User account is inactive", ip_address, user_agent).await,
+        );
     };
 
-    let private_key_pkcs8 = crypto::decrypt_private_key(&encrypted_key, master_key)?;
+    // Only delegated scopes the credential holds, and only those requested
+    let delegable: Vec<String> = credential
+        .scopes
+        .iter()
+        .filter(|scope| scope.starts_with(DELEGATED_SCOPE_PREFIX))
+        .cloned()
+        .collect();
+    if exchange.scopes.is_empty() || !exchange.scopes.iter().all(|s| delegable.contains(s)) {
+        return Err(AcError::InsufficientScope {
+            required: exchange.scopes.join(" "),
+            provided: delegable,
+        });
+    }
+
+    let (key_id, private_key_pkcs8) = load_signing_key(pool, master_key).await?;
 
-    // Generate user JWT claims per ADR-0020
     let now = Utc::now().timestamp();
-    let jti = Uuid::new_v4().to_string();
-    let claims = UserClaims {
+    let exp = (now + i64::from(token_ttls.delegated_ttl()))
+        .min(subject.exp)
+        .min(actor.exp);
+    let (cnf, token_type) = bind_to_dpop_key(exchange.dpop_jkt);
+    let claims = DelegatedClaims {
         sub: user.user_id.to_string(),
         org_id: user.org_id.to_string(),
-        email: user.email.clone(),
-        roles,
+        scope: exchange.scopes.join(" "),
+        act: Actor {
+            sub: credential.client_id.clone(),
+            service_type: Some(credential.service_type.clone()),
+        },
         iat: now,
-        exp: now + TOKEN_EXPIRY_SECONDS_I64,
-        jti,
+        exp,
+        jti: Uuid::new_v4().to_string(),
+        cnf,
     };
 
-    // Sign JWT with user claims
-    let token = crypto::sign_user_jwt(&claims, &private_key_pkcs8, &signing_key.key_id)?;
-
-    // Log successful login
-    log_user_auth_event(pool, &user.user_id, true, None, ip_address, user_agent).await;
+    let token = crypto::sign_delegated_jwt(&claims, &private_key_pkcs8, &key_id)?;
 
-    // Update last_login_at
-    if let Err(e) = users::update_last_login(pool, user.user_id).await {
-        tracing::warn!("Failed to update last login timestamp: {}", e);
-    }
+    audit_writer::record(
+        pool,
+        NewAuthEvent::success(AuthEventType::ServiceTokenIssued)
+            .credential(Some(credential.credential_id))
+            .user(Some(user.user_id))
+            .client(ip_address, user_agent)
+            .metadata(serde_json::json!({
+                "key_id": key_id,
+                "scopes": exchange.scopes,
+                "grant_type": "token_exchange",
+                "token_type": token_type,
+            })),
+    )
+    .await;
 
-    Ok(UserTokenResponse {
+    Ok(TokenResponse {
         access_token: token,
-        token_type: "Bearer".to_string(),
-        expires_in: TOKEN_EXPIRY_SECONDS,
+        token_type: token_type.to_string(),
+        expires_in: u64::try_from(exp - now).unwrap_or_default(),
+        scope: claims.scope,
+        refresh_token: None,
+        issued_token_type: Some(ACCESS_TOKEN_TYPE.to_string()),
     })
 }
 
-/// Log a user authentication event.
-async fn log_user_auth_event(
+/// Audit a rejected token exchange and build its error.
+///
+/// Invalid subject and actor tokens are `invalid_request` (RFC 8693
+/// Section 2.2.2); the reason is only recorded in the audit log. Like
+/// [`inactive_token`], the event carries no subject, so a bad subject token
+/// does not count toward the calling credential's lockout.
+async fn reject_exchange(
     pool: &PgPool,
-    user_id: &Uuid,
-    success: bool,
-    failure_reason: Option<&str>,
+    reason: &str,
     ip_address: Option<&str>,
     user_agent: Option<&str>,
-) {
-    let event_type = if success {
-        AuthEventType::UserLogin.as_str()
-    } else {
-        AuthEventType::UserLoginFailed.as_str()
+) -> AcError {
+    audit_writer::record(
+        pool,
+        NewAuthEvent::failure(AuthEventType::TokenValidationFailed, reason)
+            .client(ip_address, user_agent)
+            .metadata(serde_json::json!({
+                "grant_type": "token_exchange",
+            })),
+    )
+    .await;
+    AcError::InvalidRequest("The subject or actor token is invalid or expired".to_string())
+}
+
+/// Introspect a token (RFC 7662).
+///
+/// A token is active when it was signed by a key still published in JWKS,
+/// passes the signature, `exp` and `iat` checks, and its subject is still
+/// active: the service credential for service tokens, the user for user
+/// tokens, and both the acting credential and the user for delegated tokens
+/// (reported with their `act` claim). Every other token is reported inactive
+/// without a reason; only database failures are errors.
+#[instrument(name = "ac.token_service.introspect_token", skip_all)]
+pub async fn introspect_token(
+    pool: &PgPool,
+    token: &str,
+    clock_skew: Duration,
+) -> Result<IntrospectionResponse, AcError> {
+    // extract_kid enforces the size limit before any parsing
+    let Ok(key_id) = extract_kid(token) else {
+        return Ok(inactive_token(pool, "Malformed token").await);
+    };
+
+    // Tokens signed by a retired key are no longer active
+    let keys = signing_keys::get_all_active_keys(pool).await?;
+    let Some(signing_key) = keys.iter().find(|key| key.key_id == key_id) else {
+        return Ok(inactive_token(pool, "Unknown or retired signing key").await);
     };
 
-    if let Err(e) = auth_events::log_event(
+    // Delegated tokens first: their claims are a superset of a service
+    // token's, so verify_jwt would accept them too
+    if let Ok(claims) = crypto::verify_delegated_jwt(token, &signing_key.public_key, clock_skew) {
+        let actor_active = service_credentials::get_by_client_id(pool, &claims.act.sub)
+            .await?
+            .is_some_and(|credential| credential.is_active);
+        let user_active = match Uuid::parse_str(&claims.sub) {
+            Ok(user_id) => users::get_by_id(pool, user_id)
+                .await?
+                .is_some_and(|user| user.is_active),
+            Err(_) => false,
+        };
+        if !actor_active {
+            return Ok(inactive_token(pool, "Credential is inactive").await);
+        }
+        if !user_active {
+            return Ok(inactive_token(pool, "User account is inactive").await);
+        }
+        return Ok(IntrospectionResponse {
+            active: true,
+            scope: Some(claims.scope),
+            sub: Some(claims.sub),
+            exp: Some(claims.exp),
+            cnf: claims.cnf,
+            act: Some(claims.act),
+        });
+    }
+
+    if let Ok(claims) = crypto::verify_jwt(token, &signing_key.public_key, clock_skew) {
+        let credential_active = service_credentials::get_by_client_id(pool, &claims.sub)
+            .await?
+            .is_some_and(|credential| credential.is_active);
+        if !credential_active {
+            return Ok(inactive_token(pool, "Credential is inactive").await);
+        }
+        return Ok(IntrospectionResponse {
+            active: true,
+            scope: Some(claims.scope),
+            sub: Some(claims.sub),
+            exp: Some(claims.exp),
+            cnf: claims.cnf,
+            act: None,
+        });
+    }
+
+    if let Ok(claims) = crypto::verify_user_jwt(token, &signing_key.public_key, clock_skew) {
+        let user_active = match Uuid::parse_str(&claims.sub) {
+            Ok(user_id) => users::get_by_id(pool, user_id)
+                .await?
+                .is_some_and(|user| user.is_active),
+            Err(_) => false,
+        };
+        if !user_active {
+            return Ok(inactive_token(pool, "User account is inactive").await);
+        }
+        // User tokens carry roles, not scopes
+        return Ok(IntrospectionResponse {
+            active: true,
+            scope: None,
+            sub: Some(claims.sub),
+            exp: Some(claims.exp),
+            cnf: None,
+            act: None,
+        });
+    }
+
+    Ok(inactive_token(pool, "Invalid signature or expired").await)
+}
+
+/// Audit a token that failed introspection and build the inactive response.
+///
+/// The event carries no subject: the token's claims are not trusted, and a
+/// subject would count the failure toward that subject's lockout.
+async fn inactive_token(pool: &PgPool, reason: &str) -> IntrospectionResponse {
+    audit_writer::record(
         pool,
-        event_type,
-        Some(*user_id),
-        None,
-        success,
-        failure_reason,
-        ip_address,
-        user_agent,
-        None,
+        NewAuthEvent::failure(AuthEventType::TokenValidationFailed, reason),
     )
-    .await
-    {
-        tracing::warn!("Failed to log user auth event: {}", e);
-        record_audit_log_failure(event_type, "db_write_failed");
-    }
+    .await;
+    IntrospectionResponse::inactive()
 }
 
 #[cfg(test)]
@@ -369,8 +1356,12 @@ mod tests {
     use crate::crypto;
     use crate::repositories::service_credentials;
     use crate::services::key_management_service;
+    use std::collections::HashMap;
     use std::time::Instant;
 
+    const TOKEN_EXPIRY_SECONDS: u64 = crate::config::DEFAULT_SERVICE_TOKEN_TTL_SECONDS as u64;
+    const TOKEN_EXPIRY_SECONDS_I64: i64 = TOKEN_EXPIRY_SECONDS as i64;
+
     /// P0-1 (CG-1): Test timing attack prevention - invalid client_id
     ///
     /// Verifies that authentication attempts with non-existent client_ids
@@ -698,24 +1689,140 @@ mod tests {
             .await;
         }
 
-        // Should NOT be locked (only 3 failures)
-        let result = issue_service_token(
+        // Should NOT be locked (only 3 failures)
+        let result = issue_service_token(
+            &pool,
+            &master_key,
+            &master_key,
+            "test-client-window",
+            "wrong-password",
+            "client_credentials",
+            None,
+            Some("192.168.1.2"),
+            None,
+            DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
+            DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+        )
+        .await;
+
+        // Should get InvalidCredentials, not RateLimitExceeded
+        assert!(matches!(result, Err(AcError::InvalidCredentials)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_backoff_grows_exponentially_and_caps() {
+        for free in 0..=BACKOFF_FREE_FAILURES {
+            assert_eq!(backoff_seconds(free), 0);
+        }
+        assert_eq!(backoff_seconds(BACKOFF_FREE_FAILURES + 1), 1);
+        assert_eq!(backoff_seconds(BACKOFF_FREE_FAILURES + 2), 2);
+        assert_eq!(backoff_seconds(BACKOFF_FREE_FAILURES + 5), 16);
+        assert_eq!(backoff_seconds(BACKOFF_FREE_FAILURES + 13), 3600);
+        assert_eq!(backoff_seconds(i32::MAX), BACKOFF_MAX_SECONDS);
+    }
+
+    #[test]
+    fn test_backoff_retry_after_rounds_up() {
+        let now = Utc::now();
+        let failures =
+            |consecutive_failures, ago_ms| service_credential_failures::CredentialFailures {
+                credential_id: Uuid::new_v4(),
+                consecutive_failures,
+                last_failure_at: now - chrono::Duration::milliseconds(ago_ms),
+            };
+
+        // 8 failures: 4s delay
+        assert_eq!(backoff_retry_after(&failures(8, 0), now), Some(4));
+        assert_eq!(backoff_retry_after(&failures(8, 500), now), Some(4));
+        assert_eq!(backoff_retry_after(&failures(8, 3_001), now), Some(1));
+        assert_eq!(backoff_retry_after(&failures(8, 4_000), now), None);
+        assert_eq!(
+            backoff_retry_after(&failures(BACKOFF_FREE_FAILURES, 0), now),
+            None
+        );
+    }
+
+    /// Backoff refuses even the correct secret, with Retry-After, before bcrypt
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_backoff_after_consecutive_failures(pool: PgPool) -> Result<(), AcError> {
+        let master_key = crypto::generate_random_bytes(32)?;
+        key_management_service::initialize_signing_key(&pool, &master_key, "test").await?;
+
+        let valid_secret = "valid-secret-12345";
+        let valid_hash = crypto::hash_client_secret(valid_secret, DEFAULT_BCRYPT_COST)?;
+        let credential = service_credentials::create_service_credential(
             &pool,
-            &master_key,
-            &master_key,
-            "test-client-window",
-            "wrong-password",
-            "client_credentials",
-            None,
-            Some("192.168.1.2"),
+            "test-backoff-client",
+            &valid_hash,
+            "global-controller",
             None,
-            DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
-            DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+            &["test:scope".to_string()],
         )
-        .await;
+        .await?;
 
-        // Should get InvalidCredentials, not RateLimitExceeded
-        assert!(matches!(result, Err(AcError::InvalidCredentials)));
+        // A long streak from earlier windows; the window lockout does not apply
+        sqlx::query(
+            "INSERT INTO service_credential_failures (credential_id, consecutive_failures) VALUES ($1, 10)",
+        )
+        .bind(credential.credential_id)
+        .execute(&pool)
+        .await
+        .expect("Should seed failure streak");
+
+        let issue = |secret: &'static str| {
+            let pool = pool.clone();
+            let master_key = master_key.clone();
+            async move {
+                issue_service_token(
+                    &pool,
+                    &master_key,
+                    &master_key,
+                    "test-backoff-client",
+                    secret,
+                    "client_credentials",
+                    None,
+                    None,
+                    None,
+                    DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
+                    100,
+                )
+                .await
+            }
+        };
+
+        match issue(valid_secret).await {
+            Err(AcError::TooManyRequests {
+                retry_after_seconds,
+                ..
+            }) => assert!((1..=16).contains(&retry_after_seconds)),
+            other => panic!("expected TooManyRequests, got {:?}", other.map(|_| ())),
+        }
+
+        // Once the delay has passed the correct secret works and ends the streak
+        sqlx::query(
+            "UPDATE service_credential_failures SET last_failure_at = NOW() - INTERVAL '1 hour'",
+        )
+        .execute(&pool)
+        .await
+        .expect("Should age failure streak");
+        issue(valid_secret).await?;
+        assert!(
+            service_credential_failures::get(&pool, credential.credential_id)
+                .await?
+                .is_none()
+        );
+
+        // Failures start a new streak
+        assert!(matches!(
+            issue("wrong-password").await,
+            Err(AcError::InvalidCredentials)
+        ));
+        let streak = service_credential_failures::get(&pool, credential.credential_id)
+            .await?
+            .expect("Failure should be recorded");
+        assert_eq!(streak.consecutive_failures, 1);
 
         Ok(())
     }
@@ -943,6 +2050,229 @@ mod tests {
         Ok(())
     }
 
+    /// A scope lifetime override beats the service type override, and the
+    /// shortest override among the granted scopes applies.
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_scope_ttl_override_wins(pool: PgPool) -> Result<(), AcError> {
+        let master_key = crypto::generate_random_bytes(32)?;
+        key_management_service::initialize_signing_key(&pool, &master_key, "test").await?;
+
+        let valid_secret = "valid-secret-12345";
+        let valid_hash = crypto::hash_client_secret(valid_secret, DEFAULT_BCRYPT_COST)?;
+
+        service_credentials::create_service_credential(
+            &pool,
+            "ttl-client",
+            &valid_hash,
+            "global-controller",
+            None,
+            &[
+                "meeting:read".to_string(),
+                "meeting:create".to_string(),
+                "internal:meeting-token".to_string(),
+            ],
+        )
+        .await?;
+
+        let token_ttls = TokenTtlPolicy {
+            per_service_type: HashMap::from([("global-controller".to_string(), 7200)]),
+            per_scope: HashMap::from([
+                ("meeting:create".to_string(), 600),
+                ("internal:meeting-token".to_string(), 300),
+            ]),
+            max_ttl_seconds: 7200,
+            ..TokenTtlPolicy::default()
+        };
+        let issue = |token_ttls: &TokenTtlPolicy, scopes: &[&str]| {
+            let pool = pool.clone();
+            let master_key = master_key.clone();
+            let token_ttls = token_ttls.clone();
+            let scopes: Vec<String> = scopes.iter().map(|s| s.to_string()).collect();
+            async move {
+                issue_service_token_with_auth(
+                    &pool,
+                    &master_key,
+                    &master_key,
+                    "ttl-client",
+                    ServiceClientAuth {
+                        client_secret: Some(valid_secret),
+                        cert_fingerprint: None,
+                        mtls_mode: MtlsMode::Disabled,
+                        dpop_jkt: None,
+                    },
+                    "client_credentials",
+                    Some(scopes),
+                    None,
+                    None,
+                    DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
+                    DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+                    &token_ttls,
+                )
+                .await
+            }
+        };
+
+        assert_eq!(
+            issue(&token_ttls, &["meeting:read"]).await?.expires_in,
+            7200
+        );
+        assert_eq!(
+            issue(&token_ttls, &["meeting:read", "meeting:create"])
+                .await?
+                .expires_in,
+            600
+        );
+        assert_eq!(
+            issue(&token_ttls, &["meeting:create", "internal:meeting-token"])
+                .await?
+                .expires_in,
+            300
+        );
+
+        // Overrides above max_ttl are capped
+        let capped = TokenTtlPolicy {
+            max_ttl_seconds: 450,
+            ..token_ttls.clone()
+        };
+        assert_eq!(issue(&capped, &["meeting:read"]).await?.expires_in, 450);
+        assert_eq!(issue(&capped, &["meeting:create"]).await?.expires_in, 450);
+        assert_eq!(
+            issue(&capped, &["internal:meeting-token"])
+                .await?
+                .expires_in,
+            300
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_service_client_auth_failure_reason() {
+        let auth = |secret: bool, cert: bool, mtls_mode| ServiceClientAuth {
+            client_secret: secret.then_some("secret"),
+            cert_fingerprint: cert.then_some("fingerprint"),
+            mtls_mode,
+            dpop_jkt: None,
+        };
+
+        // Disabled: only the secret counts
+        let disabled = auth(true, true, MtlsMode::Disabled);
+        assert_eq!(disabled.failure_reason(Some(true), None), None);
+        assert_eq!(disabled.failure_reason(Some(true), Some(false)), None);
+        assert_eq!(
+            disabled.failure_reason(None, Some(true)),
+            Some("Invalid client secret")
+        );
+
+        // Optional: either factor, but whatever is presented must match
+        let optional = auth(true, true, MtlsMode::Optional);
+        assert_eq!(optional.failure_reason(Some(true), None), None);
+        assert_eq!(optional.failure_reason(None, Some(true)), None);
+        assert_eq!(optional.failure_reason(Some(true), Some(true)), None);
+        assert_eq!(
+            optional.failure_reason(Some(true), Some(false)),
+            Some("Client certificate mismatch")
+        );
+        assert_eq!(
+            optional.failure_reason(Some(false), Some(true)),
+            Some("Invalid client secret")
+        );
+        assert_eq!(
+            optional.failure_reason(None, None),
+            Some("Invalid client secret")
+        );
+
+        // Required: a pinned certificate is mandatory
+        let required = auth(false, true, MtlsMode::Required);
+        assert_eq!(required.failure_reason(None, Some(true)), None);
+        assert_eq!(
+            required.failure_reason(Some(true), None),
+            Some("Client certificate required")
+        );
+        assert_eq!(
+            required.failure_reason(Some(false), Some(true)),
+            Some("Invalid client secret")
+        );
+    }
+
+    /// A pinned client certificate can replace the secret when mTLS is on.
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_service_token_with_client_certificate(pool: PgPool) -> Result<(), AcError> {
+        let master_key = crypto::generate_random_bytes(32)?;
+        key_management_service::initialize_signing_key(&pool, &master_key, "test").await?;
+
+        let valid_secret = "valid-secret-12345";
+        let valid_hash = crypto::hash_client_secret(valid_secret, DEFAULT_BCRYPT_COST)?;
+        let pinned = "ab".repeat(32);
+        let other = "cd".repeat(32);
+
+        let credential = service_credentials::create_service_credential(
+            &pool,
+            "cert-client",
+            &valid_hash,
+            "global-controller",
+            None,
+            &["scope-a".to_string()],
+        )
+        .await?;
+        service_credentials::set_client_cert_fingerprint(
+            &pool,
+            credential.credential_id,
+            Some(&pinned),
+        )
+        .await?;
+
+        let issue = |client_secret, cert_fingerprint, mtls_mode| {
+            let pool = pool.clone();
+            let master_key = master_key.clone();
+            async move {
+                issue_service_token_with_auth(
+                    &pool,
+                    &master_key,
+                    &master_key,
+                    "cert-client",
+                    ServiceClientAuth {
+                        client_secret,
+                        cert_fingerprint,
+                        mtls_mode,
+                        dpop_jkt: None,
+                    },
+                    "client_credentials",
+                    None,
+                    None,
+                    None,
+                    DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
+                    DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+                    &TokenTtlPolicy::default(),
+                )
+                .await
+            }
+        };
+
+        let token = issue(None, Some(pinned.as_str()), MtlsMode::Required).await?;
+        assert_eq!(token.scope, "scope-a");
+        issue(None, Some(pinned.as_str()), MtlsMode::Optional).await?;
+        issue(Some(valid_secret), None, MtlsMode::Optional).await?;
+
+        // Wrong certificate, no certificate in required mode, and
+        // certificate-only while mTLS is disabled are all rejected
+        for (secret, cert, mode) in [
+            (Some(valid_secret), Some(other.as_str()), MtlsMode::Optional),
+            (Some(valid_secret), None, MtlsMode::Required),
+            (None, Some(pinned.as_str()), MtlsMode::Disabled),
+        ] {
+            let result = issue(secret, cert, mode).await;
+            assert!(
+                matches!(result, Err(AcError::InvalidCredentials)),
+                "{mode:?} with secret={} cert={:?} should be rejected",
+                secret.is_some(),
+                cert
+            );
+        }
+
+        Ok(())
+    }
+
     // ============================================================================
     // P1 Security Tests - JWT Validation & Manipulation
     // ============================================================================
@@ -1054,6 +2384,7 @@ mod tests {
             iat: Utc::now().timestamp(),
             scope: "valid-scope".to_string(),
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         // Sign with the WRONG key (attacker's key)
@@ -1319,6 +2650,7 @@ mod tests {
             iat: Utc::now().timestamp() - (TOKEN_EXPIRY_SECONDS_I64 * 2), // Issued 2 hours ago
             scope: "valid-scope".to_string(),
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         // Decrypt the private key
@@ -1386,6 +2718,7 @@ mod tests {
             iat: Utc::now().timestamp() + TOKEN_EXPIRY_SECONDS_I64, // Issued 1 hour from now (way beyond 5 min skew!)
             scope: "valid-scope".to_string(),
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         // Decrypt the private key
@@ -1445,6 +2778,7 @@ mod tests {
             iat: Utc::now().timestamp() + 120, // Issued 2 minutes from now (within tolerance)
             scope: "valid-scope".to_string(),
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         // Decrypt the private key
@@ -1504,6 +2838,7 @@ mod tests {
             iat: now.timestamp() + DEFAULT_JWT_CLOCK_SKEW.as_secs() as i64, // Exactly at 5 min boundary
             scope: "valid-scope".to_string(),
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         // Decrypt the private key
@@ -1562,6 +2897,7 @@ mod tests {
             iat: now.timestamp() + DEFAULT_JWT_CLOCK_SKEW.as_secs() as i64 + 1, // 1 second beyond 5 min
             scope: "valid-scope".to_string(),
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         // Decrypt the private key
@@ -1651,6 +2987,7 @@ mod tests {
             iat: Utc::now().timestamp(),
             scope: "valid-scope".to_string(),
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         let encoding_key = EncodingKey::from_ed_der(&private_key);
@@ -1898,6 +3235,7 @@ mod tests {
             iat: Utc::now().timestamp(),
             scope: "admin:all meeting:delete".to_string(), // Escalated privileges!
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         // Create header with attacker's kid
@@ -2317,6 +3655,7 @@ mod tests {
             iat: Utc::now().timestamp(),
             scope: "valid-scope".to_string(),
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         let normal_token =
@@ -3004,4 +4343,248 @@ mod tests {
 
         Ok(())
     }
+
+    // ============================================================================
+    // Token Exchange Tests (RFC 8693)
+    // ============================================================================
+
+    /// Issue a GC service token and a user token for token exchange tests.
+    ///
+    /// Returns `(actor_token, subject_token, user_id)`.
+    async fn exchange_fixture(
+        pool: &PgPool,
+        master_key: &[u8],
+    ) -> Result<(String, String, uuid::Uuid), AcError> {
+        let secret = "exchange-secret-12345";
+        let secret_hash = crypto::hash_client_secret(secret, DEFAULT_BCRYPT_COST)?;
+        service_credentials::create_service_credential(
+            pool,
+            "exchange-gc",
+            &secret_hash,
+            "global-controller",
+            None,
+            &[
+                "meeting:create".to_string(),
+                "delegated:meeting.moderate".to_string(),
+            ],
+        )
+        .await?;
+        let actor = issue_service_token(
+            pool,
+            master_key,
+            master_key,
+            "exchange-gc",
+            secret,
+            "client_credentials",
+            None,
+            None,
+            None,
+            DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
+            DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+        )
+        .await?;
+
+        let org_id = create_test_org(pool, "exchange").await;
+        let user_id = create_test_user(pool, org_id, "host@example.com", "password-123").await;
+        let subject = issue_user_token(
+            pool,
+            master_key,
+            master_key,
+            org_id,
+            "host@example.com",
+            "password-123",
+            None,
+            None,
+            DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
+            DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+        )
+        .await?;
+
+        Ok((actor.access_token, subject.access_token, user_id))
+    }
+
+    fn moderation_exchange<'a>(actor_token: &'a str, subject_token: &'a str) -> TokenExchange<'a> {
+        TokenExchange {
+            subject_token,
+            actor_token,
+            scopes: vec!["delegated:meeting.moderate".to_string()],
+            dpop_jkt: None,
+        }
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_exchange_token_issues_delegated_token(pool: PgPool) -> Result<(), AcError> {
+        let master_key = crypto::generate_random_bytes(32)?;
+        key_management_service::initialize_signing_key(&pool, &master_key, "test").await?;
+        let (actor, subject, user_id) = exchange_fixture(&pool, &master_key).await?;
+
+        let response = exchange_token(
+            &pool,
+            &master_key,
+            moderation_exchange(&actor, &subject),
+            DEFAULT_JWT_CLOCK_SKEW,
+            &TokenTtlPolicy::default(),
+            None,
+            None,
+        )
+        .await?;
+
+        assert_eq!(response.token_type, "Bearer");
+        assert_eq!(response.scope, "delegated:meeting.moderate");
+        assert_eq!(
+            response.issued_token_type.as_deref(),
+            Some(ACCESS_TOKEN_TYPE)
+        );
+        assert!(response.refresh_token.is_none());
+        assert!(response.expires_in <= u64::from(TokenTtlPolicy::default().delegated_ttl()));
+
+        let signing_key = signing_keys::get_active_key(&pool)
+            .await?
+            .expect("No active signing key");
+        let claims = crypto::verify_delegated_jwt(
+            &response.access_token,
+            &signing_key.public_key,
+            DEFAULT_JWT_CLOCK_SKEW,
+        )?;
+        assert_eq!(claims.sub, user_id.to_string());
+        assert_eq!(claims.act.sub, "exchange-gc");
+        assert_eq!(
+            claims.act.service_type.as_deref(),
+            Some("global-controller")
+        );
+
+        // Introspection reports the actor
+        let introspection =
+            introspect_token(&pool, &response.access_token, DEFAULT_JWT_CLOCK_SKEW).await?;
+        assert!(introspection.active);
+        assert_eq!(introspection.sub, Some(user_id.to_string()));
+        assert_eq!(
+            introspection.act.map(|act| act.sub),
+            Some("exchange-gc".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_exchange_token_rejects_undelegable_scopes(pool: PgPool) -> Result<(), AcError> {
+        let master_key = crypto::generate_random_bytes(32)?;
+        key_management_service::initialize_signing_key(&pool, &master_key, "test").await?;
+        let (actor, subject, _) = exchange_fixture(&pool, &master_key).await?;
+
+        // A service scope the credential holds, one it does not, and none
+        for scopes in [
+            vec!["meeting:create".to_string()],
+            vec!["delegated:meeting.end".to_string()],
+            vec![],
+        ] {
+            let result = exchange_token(
+                &pool,
+                &master_key,
+                TokenExchange {
+                    scopes,
+                    ..moderation_exchange(&actor, &subject)
+                },
+                DEFAULT_JWT_CLOCK_SKEW,
+                &TokenTtlPolicy::default(),
+                None,
+                None,
+            )
+            .await;
+            assert!(matches!(result, Err(AcError::InsufficientScope { .. })));
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_exchange_token_rejects_swapped_and_chained_tokens(
+        pool: PgPool,
+    ) -> Result<(), AcError> {
+        let master_key = crypto::generate_random_bytes(32)?;
+        key_management_service::initialize_signing_key(&pool, &master_key, "test").await?;
+        let (actor, subject, _) = exchange_fixture(&pool, &master_key).await?;
+        let delegated = exchange_token(
+            &pool,
+            &master_key,
+            moderation_exchange(&actor, &subject),
+            DEFAULT_JWT_CLOCK_SKEW,
+            &TokenTtlPolicy::default(),
+            None,
+            None,
+        )
+        .await?
+        .access_token;
+
+        for (actor_token, subject_token) in [
+            (subject.as_str(), actor.as_str()),
+            (actor.as_str(), actor.as_str()),
+            (delegated.as_str(), subject.as_str()),
+            (actor.as_str(), delegated.as_str()),
+            ("not-a-token", subject.as_str()),
+        ] {
+            let result = exchange_token(
+                &pool,
+                &master_key,
+                moderation_exchange(actor_token, subject_token),
+                DEFAULT_JWT_CLOCK_SKEW,
+                &TokenTtlPolicy::default(),
+                None,
+                None,
+            )
+            .await;
+            assert!(matches!(result, Err(AcError::InvalidRequest(_))));
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_exchange_token_requires_active_actor_and_user(
+        pool: PgPool,
+    ) -> Result<(), AcError> {
+        let master_key = crypto::generate_random_bytes(32)?;
+        key_management_service::initialize_signing_key(&pool, &master_key, "test").await?;
+        let (actor, subject, user_id) = exchange_fixture(&pool, &master_key).await?;
+
+        sqlx::query("UPDATE users SET is_active = false WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .map_err(|e| AcError::Database(e.to_string()))?;
+        let result = exchange_token(
+            &pool,
+            &master_key,
+            moderation_exchange(&actor, &subject),
+            DEFAULT_JWT_CLOCK_SKEW,
+            &TokenTtlPolicy::default(),
+            None,
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(AcError::InvalidRequest(_))));
+
+        sqlx::query("UPDATE users SET is_active = true WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .map_err(|e| AcError::Database(e.to_string()))?;
+        let credential = service_credentials::get_by_client_id(&pool, "exchange-gc")
+            .await?
+            .expect("credential should exist");
+        service_credentials::deactivate(&pool, credential.credential_id).await?;
+        let result = exchange_token(
+            &pool,
+            &master_key,
+            moderation_exchange(&actor, &subject),
+            DEFAULT_JWT_CLOCK_SKEW,
+            &TokenTtlPolicy::default(),
+            None,
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(AcError::InvalidRequest(_))));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,405 @@
+//! TOTP service module for the optional user second factor (ADR-0020).
+//!
+//! Enrollment takes two steps so a mistyped secret cannot lock a user out:
+//! [`begin_enrollment`] stores a new secret unconfirmed and returns it for the
+//! authenticator app, and [`confirm_enrollment`] turns it on once the user
+//! sends a code the app generated. Until then, login needs the password only.
+//!
+//! Both steps re-authenticate with the password through
+//! [`token_service::authenticate_user`], so failures count toward the login
+//! lockout. Replacing a confirmed secret also needs a current code from it.
+
+use crate::crypto::master_key::MasterKeyProvider;
+use crate::crypto::{totp, EncryptedKey};
+use crate::errors::AcError;
+use crate::repositories::user_totp::{self, UserTotp};
+use crate::services::token_service;
+use chrono::Utc;
+use common::secret::{ExposeSecret, SecretBox, SecretString};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Outcome of checking a login's TOTP code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TotpCheck {
+    /// The user has no confirmed enrollment; the password is enough.
+    NotEnrolled,
+    /// The code is valid and has not been used before.
+    Accepted,
+    /// The user is enrolled but sent no code.
+    Missing,
+    /// The code is wrong or was already used. Holds the failure reason for
+    /// the auth event.
+    Rejected(&'static str),
+}
+
+/// A new secret for the user's authenticator app.
+#[derive(Debug)]
+pub struct TotpEnrollment {
+    /// Unpadded base32, for manual entry.
+    pub secret: SecretString,
+    /// `otpauth://` URI, usually shown as a QR code.
+    pub otpauth_uri: SecretString,
+}
+
+/// Check `code` against the user's confirmed enrollment.
+///
+/// An accepted code's time step is recorded, so each code works once.
+pub async fn check_code(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    user_id: Uuid,
+    code: Option<&str>,
+) -> Result<TotpCheck, AcError> {
+    let Some(enrollment) = user_totp::get(pool, user_id)
+        .await?
+        .filter(|t| t.confirmed_at.is_some())
+    else {
+        return Ok(TotpCheck::NotEnrolled);
+    };
+
+    let Some(code) = code else {
+        return Ok(TotpCheck::Missing);
+    };
+
+    let secret = decrypt_secret(master_key, enrollment)?;
+    let Some(step) = totp::verify(secret.expose_secret(), code, Utc::now().timestamp()) else {
+        return Ok(TotpCheck::Rejected("Invalid TOTP code"));
+    };
+
+    if !user_totp::record_used_step(pool, user_id, step).await? {
+        return Ok(TotpCheck::Rejected("Reused TOTP code"));
+    }
+
+    Ok(TotpCheck::Accepted)
+}
+
+/// Start a TOTP enrollment (`POST /api/v1/auth/user/totp`).
+///
+/// Stores a new unconfirmed secret, replacing any existing enrollment, and
+/// returns it. Users who already have TOTP enabled must send a current code.
+#[expect(clippy::too_many_arguments)]
+pub async fn begin_enrollment(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    hash_secret: &[u8],
+    org_id: Uuid,
+    email: &str,
+    password: &str,
+    totp_code: Option<&str>,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+    rate_limit_window_minutes: i64,
+    rate_limit_max_attempts: i64,
+) -> Result<TotpEnrollment, AcError> {
+    let user = token_service::authenticate_user(
+        pool,
+        master_key,
+        hash_secret,
+        org_id,
+        email,
+        password,
+        totp_code,
+        ip_address,
+        user_agent,
+        rate_limit_window_minutes,
+        rate_limit_max_attempts,
+    )
+    .await?;
+
+    let secret = SecretBox::new(Box::new(totp::generate_secret()?));
+    let encrypted = master_key.encrypt_private_key(secret.expose_secret())?;
+    user_totp::upsert_pending(
+        pool,
+        user.user_id,
+        encrypted.encrypted_data.expose_secret(),
+        &encrypted.nonce,
+        &encrypted.tag,
+    )
+    .await?;
+
+    tracing::info!(
+        target: "ac.services.totp",
+        user_id = %user.user_id,
+        "TOTP enrollment started"
+    );
+
+    Ok(TotpEnrollment {
+        secret: SecretString::from(totp::encode_base32(secret.expose_secret())),
+        otpauth_uri: SecretString::from(totp::provisioning_uri(
+            &user.email,
+            secret.expose_secret(),
+        )),
+    })
+}
+
+/// Confirm a pending TOTP enrollment (`POST /api/v1/auth/user/totp/confirm`).
+///
+/// From then on, login requires a code. A wrong code counts toward the
+/// lockout like a wrong password.
+#[expect(clippy::too_many_arguments)]
+pub async fn confirm_enrollment(
+    pool: &PgPool,
+    master_key: &dyn MasterKeyProvider,
+    hash_secret: &[u8],
+    org_id: Uuid,
+    email: &str,
+    password: &str,
+    totp_code: &str,
+    ip_address: Option<&str>,
+    user_agent: Option<&str>,
+    rate_limit_window_minutes: i64,
+    rate_limit_max_attempts: i64,
+) -> Result<(), AcError> {
+    let user = token_service::authenticate_user(
+        pool,
+        master_key,
+        hash_secret,
+        org_id,
+        email,
+        password,
+        None,
+        ip_address,
+        user_agent,
+        rate_limit_window_minutes,
+        rate_limit_max_attempts,
+    )
+    .await?;
+
+    let pending = user_totp::get(pool, user.user_id)
+        .await?
+        .filter(|t| t.confirmed_at.is_none())
+        .ok_or_else(|| AcError::NotFound("No pending TOTP enrollment".to_string()))?;
+
+    let secret = decrypt_secret(master_key, pending)?;
+    let Some(step) = totp::verify(secret.expose_secret(), totp_code, Utc::now().timestamp()) else {
+        token_service::log_user_auth_event(
+            pool,
+            &user.user_id,
+            false,
+            Some("Invalid TOTP confirmation code"),
+            ip_address,
+            user_agent,
+        )
+        .await;
+        return Err(AcError::InvalidCredentials);
+    };
+
+    // A concurrent enrollment may have replaced or confirmed the row
+    if !user_totp::confirm(pool, user.user_id, step).await? {
+        return Err(AcError::NotFound("No pending TOTP enrollment".to_string()));
+    }
+
+    tracing::info!(
+        target: "ac.services.totp",
+        user_id = %user.user_id,
+        "TOTP enrollment confirmed"
+    );
+
+    Ok(())
+}
+
+fn decrypt_secret(
+    master_key: &dyn MasterKeyProvider,
+    enrollment: UserTotp,
+) -> Result<SecretBox<Vec<u8>>, AcError> {
+    let encrypted = EncryptedKey {
+        encrypted_data: SecretBox::new(Box::new(enrollment.secret_encrypted)),
+        nonce: enrollment.secret_nonce,
+        tag: enrollment.secret_tag,
+    };
+    Ok(SecretBox::new(Box::new(
+        master_key.decrypt_private_key(&encrypted)?,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        DEFAULT_BCRYPT_COST, DEFAULT_RATE_LIMIT_MAX_ATTEMPTS, DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
+    };
+    use crate::crypto;
+    use crate::repositories::users;
+
+    const EMAIL: &str = "totp@example.com";
+    const PASSWORD: &str = "secure-password-123";
+    const HASH_SECRET: &[u8] = b"test-hash-secret";
+
+    async fn create_user(pool: &PgPool) -> Uuid {
+        let org_id: (Uuid,) = sqlx::query_as(
+            r#"
+            INSERT INTO organizations (subdomain, display_name)
+            VALUES ('totp-service', 'TOTP Service')
+            RETURNING org_id
+            "#,
+        )
+        .fetch_one(pool)
+        .await
+        .expect("Should create organization");
+
+        let hash = crypto::hash_client_secret(PASSWORD, DEFAULT_BCRYPT_COST).unwrap();
+        users::create_user(pool, org_id.0, EMAIL, &hash, "TOTP User")
+            .await
+            .unwrap();
+        org_id.0
+    }
+
+    async fn begin(
+        pool: &PgPool,
+        master_key: &dyn MasterKeyProvider,
+        org_id: Uuid,
+        code: Option<&str>,
+    ) -> Result<TotpEnrollment, AcError> {
+        begin_enrollment(
+            pool,
+            master_key,
+            HASH_SECRET,
+            org_id,
+            EMAIL,
+            PASSWORD,
+            code,
+            None,
+            None,
+            DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
+            DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+        )
+        .await
+    }
+
+    async fn confirm(
+        pool: &PgPool,
+        master_key: &dyn MasterKeyProvider,
+        org_id: Uuid,
+        code: &str,
+    ) -> Result<(), AcError> {
+        confirm_enrollment(
+            pool,
+            master_key,
+            HASH_SECRET,
+            org_id,
+            EMAIL,
+            PASSWORD,
+            code,
+            None,
+            None,
+            DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
+            DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+        )
+        .await
+    }
+
+    fn current_code(secret: &[u8]) -> String {
+        totp::code_at_time(secret, Utc::now().timestamp())
+    }
+
+    async fn stored_secret(
+        pool: &PgPool,
+        master_key: &dyn MasterKeyProvider,
+        user_id: Uuid,
+    ) -> Vec<u8> {
+        let enrollment = user_totp::get(pool, user_id).await.unwrap().unwrap();
+        decrypt_secret(master_key, enrollment)
+            .unwrap()
+            .expose_secret()
+            .clone()
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_enrollment_requires_confirmation(pool: PgPool) -> Result<(), AcError> {
+        let master_key = crypto::generate_random_bytes(32)?;
+        let org_id = create_user(&pool).await;
+        let user_id = users::get_by_email(&pool, org_id, EMAIL)
+            .await?
+            .unwrap()
+            .user_id;
+
+        let enrollment = begin(&pool, &master_key, org_id, None).await?;
+        assert!(enrollment
+            .otpauth_uri
+            .expose_secret()
+            .contains(enrollment.secret.expose_secret()));
+        let secret = stored_secret(&pool, &master_key, user_id).await;
+        assert_eq!(
+            totp::encode_base32(&secret),
+            enrollment.secret.expose_secret()
+        );
+
+        // Pending enrollments are not required at login
+        assert_eq!(
+            check_code(&pool, &master_key, user_id, None).await?,
+            TotpCheck::NotEnrolled
+        );
+
+        // A code from an hour ago is outside the drift window
+        let stale = totp::code_at_time(&secret, Utc::now().timestamp() - 3600);
+        assert!(matches!(
+            confirm(&pool, &master_key, org_id, &stale).await,
+            Err(AcError::InvalidCredentials)
+        ));
+
+        confirm(&pool, &master_key, org_id, &current_code(&secret)).await?;
+        assert_eq!(
+            check_code(&pool, &master_key, user_id, None).await?,
+            TotpCheck::Missing
+        );
+
+        // The confirming code cannot be replayed at login
+        assert_eq!(
+            check_code(&pool, &master_key, user_id, Some(&current_code(&secret))).await?,
+            TotpCheck::Rejected("Reused TOTP code")
+        );
+
+        // Nothing left to confirm
+        assert!(matches!(
+            confirm(&pool, &master_key, org_id, &current_code(&secret)).await,
+            Err(AcError::TotpRequired)
+        ));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_reenrollment_requires_current_code(pool: PgPool) -> Result<(), AcError> {
+        let master_key = crypto::generate_random_bytes(32)?;
+        let org_id = create_user(&pool).await;
+        let user_id = users::get_by_email(&pool, org_id, EMAIL)
+            .await?
+            .unwrap()
+            .user_id;
+
+        begin(&pool, &master_key, org_id, None).await?;
+        let secret = stored_secret(&pool, &master_key, user_id).await;
+        confirm(&pool, &master_key, org_id, &current_code(&secret)).await?;
+
+        assert!(matches!(
+            begin(&pool, &master_key, org_id, None).await,
+            Err(AcError::TotpRequired)
+        ));
+
+        // The confirming code was recorded, so use the next step's code
+        let next = totp::code_at_time(&secret, Utc::now().timestamp() + totp::STEP_SECONDS);
+        begin(&pool, &master_key, org_id, Some(&next)).await?;
+        let rotated = stored_secret(&pool, &master_key, user_id).await;
+        assert_ne!(rotated, secret);
+        assert_eq!(
+            check_code(&pool, &master_key, user_id, None).await?,
+            TotpCheck::NotEnrolled
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_confirm_without_enrollment_is_not_found(pool: PgPool) -> Result<(), AcError> {
+        let master_key = crypto::generate_random_bytes(32)?;
+        let org_id = create_user(&pool).await;
+
+        assert!(matches!(
+            confirm(&pool, &master_key, org_id, "123456").await,
+            Err(AcError::NotFound(_))
+        ));
+
+        Ok(())
+    }
+}
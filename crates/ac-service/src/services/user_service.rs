@@ -3,6 +3,7 @@
 //! Provides business logic for user self-registration per ADR-0020.
 
 use crate::crypto;
+use crate::crypto::master_key::MasterKeyProvider;
 use crate::errors::AcError;
 use crate::observability::metrics::{record_audit_log_failure, record_rate_limit_decision};
 use crate::repositories::{auth_events, users};
@@ -43,7 +44,7 @@ pub struct RegistrationResponse {
 /// 5. Hash password (bcrypt cost 12)
 /// 6. Insert user
 /// 7. Add default "user" role
-/// 8. Issue token (auto-login)
+/// 8. Issue token (auto-login), valid for `token_ttl_seconds`
 /// 9. Log registration event
 ///
 /// # Security
@@ -55,7 +56,7 @@ pub struct RegistrationResponse {
 #[expect(clippy::too_many_arguments)]
 pub async fn register_user(
     pool: &PgPool,
-    master_key: &[u8],
+    master_key: &dyn MasterKeyProvider,
     hash_secret: &[u8],
     org_id: Uuid,
     request: RegistrationRequest,
@@ -66,6 +67,7 @@ pub async fn register_user(
     registration_rate_limit_max_attempts: i64,
     rate_limit_window_minutes: i64,
     rate_limit_max_attempts: i64,
+    token_ttl_seconds: u32,
 ) -> Result<RegistrationResponse, AcError> {
     // Rate limit by IP (if IP is available)
     if let Some(ip) = ip_address {
@@ -145,17 +147,19 @@ pub async fn register_user(
     }
 
     // Issue token (auto-login)
-    let token_response = token_service::issue_user_token(
+    let token_response = token_service::issue_user_login_token(
         pool,
         master_key,
         hash_secret,
         org_id,
         &request.email,
         &request.password,
+        None,
         ip_address,
         user_agent,
         rate_limit_window_minutes,
         rate_limit_max_attempts,
+        token_ttl_seconds,
     )
     .await?;
 
@@ -172,7 +176,7 @@ pub async fn register_user(
 /// Simple email validation.
 ///
 /// Checks for basic email format: something@something.something
-fn is_valid_email(email: &str) -> bool {
+pub(crate) fn is_valid_email(email: &str) -> bool {
     // Basic validation: must have @ with something on both sides, and a dot after @
     let parts: Vec<&str> = email.split('@').collect();
     if parts.len() != 2 {
@@ -260,7 +264,7 @@ mod tests {
     use crate::config::{
         DEFAULT_BCRYPT_COST, DEFAULT_RATE_LIMIT_MAX_ATTEMPTS, DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
         DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
-        DEFAULT_REGISTRATION_RATE_LIMIT_WINDOW_MINUTES,
+        DEFAULT_REGISTRATION_RATE_LIMIT_WINDOW_MINUTES, DEFAULT_USER_TOKEN_TTL_SECONDS,
     };
     use crate::crypto;
     use crate::services::key_management_service;
@@ -353,6 +357,7 @@ mod tests {
             DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
             DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
             DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+            DEFAULT_USER_TOKEN_TTL_SECONDS,
         )
         .await?;
 
@@ -414,6 +419,7 @@ mod tests {
                 DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
                 DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
                 DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+                DEFAULT_USER_TOKEN_TTL_SECONDS,
             )
             .await;
 
@@ -487,6 +493,7 @@ mod tests {
                 DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
                 DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
                 DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+                DEFAULT_USER_TOKEN_TTL_SECONDS,
             )
             .await;
 
@@ -530,6 +537,7 @@ mod tests {
                 DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
                 DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
                 DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+                DEFAULT_USER_TOKEN_TTL_SECONDS,
             )
             .await;
 
@@ -571,6 +579,7 @@ mod tests {
             DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
             DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
             DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+            DEFAULT_USER_TOKEN_TTL_SECONDS,
         )
         .await;
         assert!(result1.is_ok(), "First registration should succeed");
@@ -595,6 +604,7 @@ mod tests {
             DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
             DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
             DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+            DEFAULT_USER_TOKEN_TTL_SECONDS,
         )
         .await;
 
@@ -636,6 +646,7 @@ mod tests {
                 DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
                 DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
                 DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+                DEFAULT_USER_TOKEN_TTL_SECONDS,
             )
             .await;
 
@@ -676,6 +687,7 @@ mod tests {
             DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
             DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
             DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+            DEFAULT_USER_TOKEN_TTL_SECONDS,
         )
         .await?;
 
@@ -715,6 +727,7 @@ mod tests {
             DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
             DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
             DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+            DEFAULT_USER_TOKEN_TTL_SECONDS,
         )
         .await?;
         assert_eq!(result1.email, email);
@@ -739,6 +752,7 @@ mod tests {
             DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
             DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
             DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+            DEFAULT_USER_TOKEN_TTL_SECONDS,
         )
         .await?;
         assert_eq!(result2.email, email);
@@ -787,6 +801,7 @@ mod tests {
                 DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
                 DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
                 DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+                DEFAULT_USER_TOKEN_TTL_SECONDS,
             )
             .await;
 
@@ -11,6 +11,10 @@
 //! production fn. The audit-log INSERT then fails, the `if let Err(_)`
 //! branch fires, the wrapper records, and the snapshot observes.
 //!
+//! Sites record through `services::audit_writer`. No writer is installed in
+//! these tests, so every event is written inline and a failure is recorded
+//! before the production fn returns.
+//!
 //! Production fn returns success (audit-log fail is a non-fatal side-effect
 //! per the existing `if let Err(e) = ...` pattern in every site). The test
 //! asserts the production reachable (event_type, "db_write_failed") combo.
@@ -40,17 +44,18 @@ use sqlx::PgPool;
 /// the constant must match production exactly so a future maintainer adding
 /// a wrapper call also updates this constant (CR catches the omission):
 ///
-///   service_registered      → registration_service.rs:78
-///   scopes_updated          → registration_service.rs:124
-///   service_deactivated     → registration_service.rs:158
-///   key_generated           → key_management_service.rs:96
-///   key_rotated             → key_management_service.rs:166
-///   key_expired             → key_management_service.rs:384
+///   service_registered      → registration_service.rs:60
+///   scopes_updated          → registration_service.rs:97
+///   service_deactivated     → registration_service.rs:125
+///   key_generated           → key_management_service.rs:78
+///   key_rotated             → key_management_service.rs:137
+///   key_expired             → key_management_service.rs:345
 ///   user_registered         → user_service.rs:144
-///   service_token_failed    → token_service.rs:105
-///   service_token_issued    → token_service.rs:172
-///   user_login              → token_service.rs:362 (success=true branch)
-///   user_login_failed       → token_service.rs:362 (success=false branch)
+///   service_token_failed    → token_service.rs:95
+///   service_token_issued    → token_service.rs:145
+///   user_login              → token_service.rs:327 (success=true branch)
+///   user_login_failed       → token_service.rs:327 (success=false branch)
+///   token_validation_failed → token_service.rs:676 (introspection)
 ///
 /// Used for `assert_delta(0)` adjacency on every sibling under the same
 /// `reason="db_write_failed"` filter (label-swap-bug catcher per ADR-0032
@@ -67,6 +72,7 @@ const ALL_EVENT_TYPES: &[&str] = &[
     "service_token_failed",
     "user_login",
     "user_login_failed",
+    "token_validation_failed",
 ];
 
 /// Force `auth_events::log_event` INSERT failures while still allowing
@@ -123,7 +129,7 @@ fn assert_only_event_type(snap: &MetricSnapshot, expected_event_type: &str) {
 
 #[sqlx::test(migrations = "../../migrations")]
 async fn audit_log_failure_emits_event_type_key_generated(pool: PgPool) {
-    // Drive `key_management_service::initialize_signing_key` (key_management_service.rs:96).
+    // Drive `key_management_service::initialize_signing_key` (key_management_service.rs:78).
     break_auth_events_table(&pool).await;
     let snap = MetricAssertion::snapshot();
     let master_key = test_master_key();
@@ -135,7 +141,7 @@ async fn audit_log_failure_emits_event_type_key_generated(pool: PgPool) {
 
 #[sqlx::test(migrations = "../../migrations")]
 async fn audit_log_failure_emits_event_type_service_registered(pool: PgPool) {
-    // Drive `registration_service::register_service` (registration_service.rs:78).
+    // Drive `registration_service::register_service` (registration_service.rs:60).
     break_auth_events_table(&pool).await;
     let snap = MetricAssertion::snapshot();
     let _ = registration_service::register_service(
@@ -183,12 +189,13 @@ async fn audit_log_failure_emits_event_type_user_registered(pool: PgPool) {
         ac_service::config::DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
         ac_service::config::DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
         ac_service::config::DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+        ac_service::config::DEFAULT_USER_TOKEN_TTL_SECONDS,
     )
     .await;
 
     // `register_user` emits BOTH `user_registered` (user_service.rs:144) AND
-    // chains to `issue_user_token` (auto-login) which emits `user_login` via
-    // the parameterized site (token_service.rs:362). Both are real production
+    // chains to `issue_user_login_token` (auto-login) which emits `user_login` via
+    // the parameterized site (token_service.rs:327). Both are real production
     // behavior on this path with auth_events broken; both must fire.
     snap.counter("ac_audit_log_failures_total")
         .with_labels(&[
@@ -215,7 +222,7 @@ async fn audit_log_failure_emits_event_type_user_registered(pool: PgPool) {
 #[sqlx::test(migrations = "../../migrations")]
 async fn audit_log_failure_emits_event_type_service_token_failed(pool: PgPool) {
     // Drive `token_service::issue_service_token` failure path
-    // (token_service.rs:105 — fires when audit-log INSERT fails inside the
+    // (token_service.rs:95 — fires when audit-log INSERT fails inside the
     // bad-credentials branch).
     use test_common::test_state::seed_service_credential;
 
@@ -253,7 +260,7 @@ async fn audit_log_failure_emits_event_type_service_token_failed(pool: PgPool) {
 #[sqlx::test(migrations = "../../migrations")]
 async fn audit_log_failure_emits_event_type_service_token_issued(pool: PgPool) {
     // Drive `token_service::issue_service_token` success path
-    // (token_service.rs:172 — fires when audit-log INSERT fails inside the
+    // (token_service.rs:145 — fires when audit-log INSERT fails inside the
     // success branch; primary token issuance still completes).
     use test_common::test_state::{seed_service_credential, TEST_CLIENT_SECRET};
 
@@ -299,7 +306,7 @@ async fn audit_log_failure_emits_event_type_service_token_issued(pool: PgPool) {
 
 #[sqlx::test(migrations = "../../migrations")]
 async fn audit_log_failure_emits_event_type_key_rotated(pool: PgPool) {
-    // Drive `key_management_service::rotate_signing_key` (key_management_service.rs:166).
+    // Drive `key_management_service::rotate_signing_key` (key_management_service.rs:137).
     // Bootstrap a key first, then break inserts (rotate doesn't pre-query
     // auth_events but a DROP would also break the signing_keys FK chain
     // in this fn — surgical CHECK is safer).
@@ -316,7 +323,7 @@ async fn audit_log_failure_emits_event_type_key_rotated(pool: PgPool) {
 
 #[sqlx::test(migrations = "../../migrations")]
 async fn audit_log_failure_emits_event_type_key_expired(pool: PgPool) {
-    // Drive `key_management_service::expire_old_keys` (key_management_service.rs:384).
+    // Drive `key_management_service::expire_old_keys` (key_management_service.rs:345).
     // expire_old_keys requires a key with valid_until < NOW() AND is_active=true.
     // Seed one directly via SQL (initialize_signing_key produces a fresh key
     // with future valid_until). Then break inserts so the audit-log INSERT
@@ -347,7 +354,7 @@ async fn audit_log_failure_emits_event_type_key_expired(pool: PgPool) {
 
 #[sqlx::test(migrations = "../../migrations")]
 async fn audit_log_failure_emits_event_type_scopes_updated(pool: PgPool) {
-    // Drive `registration_service::update_service_scopes` (registration_service.rs:124).
+    // Drive `registration_service::update_service_scopes` (registration_service.rs:97).
     // Requires an existing service credential; seed via test fixture.
     use ac_service::services::registration_service;
     use test_common::test_state::seed_service_credential;
@@ -369,7 +376,7 @@ async fn audit_log_failure_emits_event_type_scopes_updated(pool: PgPool) {
 
 #[sqlx::test(migrations = "../../migrations")]
 async fn audit_log_failure_emits_event_type_service_deactivated(pool: PgPool) {
-    // Drive `registration_service::deactivate_service` (registration_service.rs:158).
+    // Drive `registration_service::deactivate_service` (registration_service.rs:125).
     use ac_service::services::registration_service;
     use test_common::test_state::seed_service_credential;
 
@@ -386,8 +393,8 @@ async fn audit_log_failure_emits_event_type_service_deactivated(pool: PgPool) {
 #[sqlx::test(migrations = "../../migrations")]
 async fn audit_log_failure_emits_event_type_user_login(pool: PgPool) {
     // Drive `token_service::issue_user_token` success path
-    // (parameterized site at token_service.rs:362, where `event_type =
-    // AuthEventType::UserLogin.as_str()` because `success = true`).
+    // (parameterized site at token_service.rs:327, where `event_type =
+    // AuthEventType::UserLogin` because `success = true`).
     // Bootstrap user fixture, then break inserts — the audit-log INSERT
     // for "user_login" fails AFTER the JWT is signed (non-fatal per
     // token_service.rs:319).
@@ -424,6 +431,7 @@ async fn audit_log_failure_emits_event_type_user_login(pool: PgPool) {
         ac_service::config::DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
         ac_service::config::DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
         ac_service::config::DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+        ac_service::config::DEFAULT_USER_TOKEN_TTL_SECONDS,
     )
     .await
     .unwrap();
@@ -449,8 +457,8 @@ async fn audit_log_failure_emits_event_type_user_login(pool: PgPool) {
 #[sqlx::test(migrations = "../../migrations")]
 async fn audit_log_failure_emits_event_type_user_login_failed(pool: PgPool) {
     // Drive `token_service::issue_user_token` failure path
-    // (parameterized site at token_service.rs:362, where `event_type =
-    // AuthEventType::UserLoginFailed.as_str()` because `success = false`).
+    // (parameterized site at token_service.rs:327, where `event_type =
+    // AuthEventType::UserLoginFailed` because `success = false`).
     use ac_service::services::{token_service, user_service};
 
     let master_key = test_master_key();
@@ -484,6 +492,7 @@ async fn audit_log_failure_emits_event_type_user_login_failed(pool: PgPool) {
         ac_service::config::DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
         ac_service::config::DEFAULT_RATE_LIMIT_WINDOW_MINUTES,
         ac_service::config::DEFAULT_RATE_LIMIT_MAX_ATTEMPTS,
+        ac_service::config::DEFAULT_USER_TOKEN_TTL_SECONDS,
     )
     .await
     .unwrap();
@@ -505,3 +514,19 @@ async fn audit_log_failure_emits_event_type_user_login_failed(pool: PgPool) {
     .await;
     assert_only_event_type(&snap, "user_login_failed");
 }
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn audit_log_failure_emits_event_type_token_validation_failed(pool: PgPool) {
+    // Drive `token_service::introspect_token` with a malformed token
+    // (token_service.rs:676). Introspection does not pre-query auth_events.
+    use ac_service::services::token_service;
+
+    break_auth_events_table(&pool).await;
+    let snap = MetricAssertion::snapshot();
+    let response =
+        token_service::introspect_token(&pool, "not-a-jwt", std::time::Duration::from_secs(60))
+            .await
+            .unwrap();
+    assert!(!response.active);
+    assert_only_event_type(&snap, "token_validation_failed");
+}
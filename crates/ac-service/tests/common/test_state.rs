@@ -40,8 +40,10 @@ pub fn make_app_state(pool: PgPool) -> Arc<AppState> {
     let config = Config {
         database_url: String::new(),
         bind_address: "127.0.0.1:0".to_string(),
+        health_socket: None,
         master_key: SecretBox::new(Box::new(master_key.clone())),
         hash_secret: SecretBox::new(Box::new(master_key)),
+        key_replication_key: None,
         otlp_endpoint: None,
         jwt_clock_skew_seconds: ac_service::config::DEFAULT_JWT_CLOCK_SKEW.as_secs() as i64,
         bcrypt_cost: MIN_BCRYPT_COST,
@@ -51,8 +53,11 @@ pub fn make_app_state(pool: PgPool) -> Arc<AppState> {
             ac_service::config::DEFAULT_REGISTRATION_RATE_LIMIT_WINDOW_MINUTES,
         registration_rate_limit_max_attempts:
             ac_service::config::DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
+        client_rate_limits: ac_service::config::ClientRateLimits::default(),
+        token_ttls: ac_service::config::TokenTtlPolicy::default(),
+        secret_policy: ac_service::services::secret_policy::SecretPolicy::default(),
     };
-    Arc::new(AppState { pool, config })
+    Arc::new(AppState::new(pool, config))
 }
 
 /// Initialize a signing key in the test DB using the standard `test_master_key()`.
@@ -0,0 +1,227 @@
+-- Queries the last released AC binary runs against the shared database.
+--
+-- tests/migration_compat_tests.rs replays these against a database migrated
+-- to HEAD, so a migration that would break the previous release during a
+-- rolling deploy fails CI here. Format: see `common::migration_compat`.
+--
+-- When cutting a release, refresh this file from that release's
+-- repositories. Never edit a query to make a new migration pass: fix the
+-- migration (expand first, contract one release later) instead.
+
+-- @setup
+INSERT INTO organizations (org_id, subdomain, display_name)
+VALUES ('00000000-0000-0000-0000-00000000000a', 'compat', 'Compat Org');
+
+INSERT INTO users (user_id, org_id, email, password_hash, display_name)
+VALUES ('00000000-0000-0000-0000-00000000000b', '00000000-0000-0000-0000-00000000000a',
+        'alice@compat.example', 'not-a-real-hash', 'Alice');
+
+INSERT INTO service_credentials (credential_id, client_id, client_secret_hash, service_type, scopes)
+VALUES ('00000000-0000-0000-0000-00000000000d', 'gc-compat', 'not-a-real-hash',
+        'global-controller', ARRAY['meeting:create']);
+
+INSERT INTO signing_keys (
+    key_id, public_key, private_key_encrypted, encryption_nonce, encryption_tag,
+    valid_from, valid_until
+)
+VALUES ('auth-compat-2026-01', 'compat-public-key', '\x00',
+        '\x000000000000000000000000', '\x00000000000000000000000000000000',
+        NOW() - INTERVAL '1 hour', NOW() + INTERVAL '1 day');
+
+-- ============================================================================
+-- Organizations
+-- ============================================================================
+
+-- @query organizations.get_by_subdomain
+-- @param text 'compat'
+-- @returns org_id UUID, subdomain VARCHAR, display_name VARCHAR, plan_tier VARCHAR
+-- @returns max_concurrent_meetings INT4, max_participants_per_meeting INT4
+-- @returns created_at TIMESTAMPTZ, updated_at TIMESTAMPTZ, is_active BOOL
+SELECT
+    org_id, subdomain, display_name, plan_tier,
+    max_concurrent_meetings, max_participants_per_meeting,
+    created_at, updated_at, is_active
+FROM organizations
+WHERE subdomain = $1 AND is_active = true
+
+-- ============================================================================
+-- Users
+-- ============================================================================
+
+-- @query users.get_by_email
+-- @param uuid '00000000-0000-0000-0000-00000000000a'
+-- @param text 'alice@compat.example'
+-- @returns user_id UUID, org_id UUID, email VARCHAR, password_hash VARCHAR
+-- @returns display_name VARCHAR, is_active BOOL, created_at TIMESTAMPTZ
+-- @returns updated_at TIMESTAMPTZ, last_login_at TIMESTAMPTZ
+SELECT
+    user_id, org_id, email, password_hash, display_name,
+    is_active, created_at, updated_at, last_login_at
+FROM users
+WHERE org_id = $1 AND email = $2
+
+-- @query users.create
+-- @param uuid '00000000-0000-0000-0000-00000000000a'
+-- @param text 'bob@compat.example'
+-- @param text 'not-a-real-hash'
+-- @param text 'Bob'
+-- @returns user_id UUID, org_id UUID, email VARCHAR, password_hash VARCHAR
+-- @returns display_name VARCHAR, is_active BOOL, created_at TIMESTAMPTZ
+-- @returns updated_at TIMESTAMPTZ, last_login_at TIMESTAMPTZ
+INSERT INTO users (org_id, email, password_hash, display_name)
+VALUES ($1, $2, $3, $4)
+RETURNING
+    user_id, org_id, email, password_hash, display_name,
+    is_active, created_at, updated_at, last_login_at
+
+-- @query users.update_last_login
+-- @param uuid '00000000-0000-0000-0000-00000000000b'
+UPDATE users
+SET last_login_at = NOW()
+WHERE user_id = $1
+
+-- @query users.get_roles
+-- @param uuid '00000000-0000-0000-0000-00000000000b'
+-- @returns role VARCHAR
+SELECT role
+FROM user_roles
+WHERE user_id = $1
+ORDER BY role
+
+-- @query users.add_role
+-- @param uuid '00000000-0000-0000-0000-00000000000b'
+-- @param text 'user'
+INSERT INTO user_roles (user_id, role)
+VALUES ($1, $2)
+ON CONFLICT (user_id, role) DO NOTHING
+
+-- ============================================================================
+-- Service Credentials
+-- ============================================================================
+
+-- @query service_credentials.create
+-- @param text 'mc-compat'
+-- @param text 'not-a-real-hash'
+-- @param text 'meeting-controller'
+-- @param text 'us-east-1'
+-- @param text[] ARRAY['meeting:control']
+-- @returns credential_id UUID, client_id VARCHAR, client_secret_hash VARCHAR
+-- @returns service_type VARCHAR, region VARCHAR, scopes TEXT[], is_active BOOL
+-- @returns created_at TIMESTAMPTZ, updated_at TIMESTAMPTZ
+INSERT INTO service_credentials (client_id, client_secret_hash, service_type, region, scopes)
+VALUES ($1, $2, $3, $4, $5)
+RETURNING
+    credential_id, client_id, client_secret_hash, service_type, region, scopes,
+    is_active, created_at, updated_at
+
+-- @query service_credentials.get_by_client_id
+-- @param text 'gc-compat'
+-- @returns credential_id UUID, client_id VARCHAR, client_secret_hash VARCHAR
+-- @returns service_type VARCHAR, region VARCHAR, scopes TEXT[], is_active BOOL
+-- @returns created_at TIMESTAMPTZ, updated_at TIMESTAMPTZ
+SELECT
+    credential_id, client_id, client_secret_hash, service_type, region, scopes,
+    is_active, created_at, updated_at
+FROM service_credentials
+WHERE client_id = $1
+
+-- @query service_credentials.rotate_secret
+-- @param uuid '00000000-0000-0000-0000-00000000000d'
+-- @param text 'another-hash'
+-- @returns credential_id UUID, client_id VARCHAR, client_secret_hash VARCHAR
+-- @returns service_type VARCHAR, region VARCHAR, scopes TEXT[], is_active BOOL
+-- @returns created_at TIMESTAMPTZ, updated_at TIMESTAMPTZ
+UPDATE service_credentials
+SET client_secret_hash = $2, updated_at = NOW()
+WHERE credential_id = $1
+RETURNING
+    credential_id, client_id, client_secret_hash, service_type, region, scopes,
+    is_active, created_at, updated_at
+
+-- ============================================================================
+-- Signing Keys
+-- ============================================================================
+
+-- @query signing_keys.create
+-- @param text 'auth-compat-2026-02'
+-- @param text 'compat-public-key-2'
+-- @param bytea '\x00'
+-- @param bytea '\x000000000000000000000000'
+-- @param bytea '\x00000000000000000000000000000000'
+-- @param int4 1
+-- @param timestamptz '2026-01-01T00:00:00Z'
+-- @param timestamptz '2027-01-01T00:00:00Z'
+-- @returns key_id VARCHAR, public_key TEXT, private_key_encrypted BYTEA
+-- @returns encryption_nonce BYTEA, encryption_tag BYTEA, encryption_algorithm VARCHAR
+-- @returns master_key_version INT4, algorithm VARCHAR, is_active BOOL
+-- @returns valid_from TIMESTAMPTZ, valid_until TIMESTAMPTZ, created_at TIMESTAMPTZ
+INSERT INTO signing_keys (
+    key_id, public_key, private_key_encrypted, encryption_nonce, encryption_tag,
+    encryption_algorithm, master_key_version, algorithm,
+    is_active, valid_from, valid_until
+)
+VALUES ($1, $2, $3, $4, $5, 'AES-256-GCM', $6, 'EdDSA', true, $7, $8)
+RETURNING
+    key_id, public_key, private_key_encrypted, encryption_nonce, encryption_tag,
+    encryption_algorithm, master_key_version, algorithm,
+    is_active, valid_from, valid_until, created_at
+
+-- @query signing_keys.get_active
+-- @returns key_id VARCHAR, public_key TEXT, private_key_encrypted BYTEA
+-- @returns encryption_nonce BYTEA, encryption_tag BYTEA, encryption_algorithm VARCHAR
+-- @returns master_key_version INT4, algorithm VARCHAR, is_active BOOL
+-- @returns valid_from TIMESTAMPTZ, valid_until TIMESTAMPTZ, created_at TIMESTAMPTZ
+SELECT
+    key_id, public_key, private_key_encrypted, encryption_nonce, encryption_tag,
+    encryption_algorithm, master_key_version, algorithm,
+    is_active, valid_from, valid_until, created_at
+FROM signing_keys
+WHERE is_active = true
+    AND valid_from <= NOW()
+    AND valid_until > NOW()
+ORDER BY valid_from DESC
+LIMIT 1
+
+-- @query signing_keys.deactivate_all
+UPDATE signing_keys
+SET is_active = false
+WHERE is_active = true
+
+-- ============================================================================
+-- Auth Events
+-- ============================================================================
+
+-- @query auth_events.log_event
+-- @param text 'user_login'
+-- @param uuid '00000000-0000-0000-0000-00000000000b'
+-- @param uuid NULL
+-- @param bool true
+-- @param text NULL
+-- @param text '203.0.113.7'
+-- @param text 'compat-agent/1.0'
+-- @param jsonb '{"method": "password"}'
+-- @returns event_id UUID, event_type VARCHAR, user_id UUID, credential_id UUID
+-- @returns success BOOL, failure_reason VARCHAR, ip_address TEXT, user_agent TEXT
+-- @returns metadata JSONB, created_at TIMESTAMPTZ
+INSERT INTO auth_events (
+    event_type, user_id, credential_id, success, failure_reason,
+    ip_address, user_agent, metadata
+)
+VALUES ($1, $2, $3, $4, $5, $6::inet, $7, $8)
+RETURNING
+    event_id, event_type, user_id, credential_id, success, failure_reason,
+    host(ip_address) as ip_address, user_agent, metadata, created_at
+
+-- @query auth_events.get_by_credential
+-- @param uuid '00000000-0000-0000-0000-00000000000d'
+-- @param int8 10
+-- @returns event_id UUID, event_type VARCHAR, user_id UUID, credential_id UUID
+-- @returns success BOOL, failure_reason VARCHAR, ip_address TEXT, user_agent TEXT
+-- @returns metadata JSONB, created_at TIMESTAMPTZ
+SELECT
+    event_id, event_type, user_id, credential_id, success, failure_reason,
+    host(ip_address) as ip_address, user_agent, metadata, created_at
+FROM auth_events
+WHERE credential_id = $1
+ORDER BY created_at DESC
+LIMIT $2
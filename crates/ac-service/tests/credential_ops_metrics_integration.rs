@@ -193,6 +193,7 @@ async fn credential_op_update_success_emits_label(pool: PgPool) {
         Path(create_resp.id),
         Json(UpdateClientRequest {
             scopes: Some(vec!["scope-a".to_string()]),
+            client_cert_spki_sha256: None,
         }),
     )
     .await
@@ -210,6 +211,7 @@ async fn credential_op_update_error_emits_label(pool: PgPool) {
         Path(Uuid::new_v4()),
         Json(UpdateClientRequest {
             scopes: Some(vec!["valid".to_string()]),
+            client_cert_spki_sha256: None,
         }),
     )
     .await
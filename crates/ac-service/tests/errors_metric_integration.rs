@@ -85,12 +85,16 @@ async fn handle_service_token_invalid_grant_type_emits_authentication_category(p
     let _ = handle_service_token(
         State(state),
         ConnectInfo(TEST_ADDR.parse::<SocketAddr>().unwrap()),
+        None,
         HeaderMap::new(),
         Json(ServiceTokenRequest {
             grant_type: "password".to_string(), // Wrong grant_type
             client_id: Some("anything".to_string()),
             client_secret: Some("anything".to_string().into()),
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         }),
     )
     .await
@@ -140,6 +144,7 @@ async fn handle_rotate_keys_insufficient_scope_emits_authorization_category(pool
         iat: now,
         scope: "some.unrelated.scope".to_string(),
         service_type: Some("service".to_string()),
+        cnf: None,
     };
     let token = sign_service_token(&pool, &master_key, &claims).await;
 
@@ -189,6 +194,7 @@ async fn handle_rotate_keys_user_token_emits_cryptographic_category(pool: PgPool
         iat: now,
         scope: "service.rotate-keys.ac".to_string(),
         service_type: None, // user token — triggers Cryptographic branch
+        cnf: None,
     };
     let token = sign_service_token(&pool, &master_key, &claims).await;
 
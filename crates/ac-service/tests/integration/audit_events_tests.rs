@@ -0,0 +1,154 @@
+//! Integration tests for the admin audit events endpoint.
+//!
+//! Covers:
+//! - Token issuance and validation failures are recorded in auth_events
+//! - Event type and time range filters
+//! - admin:services scope is required
+//! - Invalid filters are rejected
+
+use ac_test_utils::server_harness::TestAuthServer;
+use reqwest::StatusCode;
+use serde_json::json;
+use sqlx::PgPool;
+
+/// GET the audit events endpoint with `query`, returning the status and body.
+async fn list_audit_events(
+    server: &TestAuthServer,
+    token: &str,
+    query: &[(&str, &str)],
+) -> Result<(StatusCode, serde_json::Value), anyhow::Error> {
+    let response = server
+        .client()
+        .get(format!("{}/api/v1/admin/audit-events", server.url()))
+        .bearer_auth(token)
+        .query(query)
+        .send()
+        .await?;
+    Ok((response.status(), response.json().await?))
+}
+
+fn event_types(body: &serde_json::Value) -> Vec<&str> {
+    body["events"]
+        .as_array()
+        .map(|events| {
+            events
+                .iter()
+                .filter_map(|event| event["event_type"].as_str())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_audit_events_records_issuance_and_validation_failures(
+    pool: PgPool,
+) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let admin_token = server
+        .create_service_token("audit-admin", &["admin:services"])
+        .await?;
+
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/introspect", server.url()))
+        .bearer_auth(&admin_token)
+        .json(&json!({ "token": "not-a-jwt" }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let (status, body) = list_audit_events(&server, &admin_token, &[]).await?;
+    assert_eq!(status, StatusCode::OK);
+    let types = event_types(&body);
+    assert!(types.contains(&"key_generated"), "got {types:?}");
+    assert!(types.contains(&"service_token_issued"), "got {types:?}");
+    assert!(types.contains(&"token_validation_failed"), "got {types:?}");
+
+    let (status, body) = list_audit_events(
+        &server,
+        &admin_token,
+        &[("event_type", "token_validation_failed")],
+    )
+    .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(event_types(&body), vec!["token_validation_failed"]);
+    let event = &body["events"][0];
+    assert_eq!(event["success"], json!(false));
+    assert_eq!(event["failure_reason"], json!("Malformed token"));
+    assert_eq!(event["credential_id"], json!(null));
+
+    let (status, body) = list_audit_events(
+        &server,
+        &admin_token,
+        &[("event_type", "service_token_issued"), ("limit", "1")],
+    )
+    .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(event_types(&body), vec!["service_token_issued"]);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_audit_events_time_range(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let admin_token = server
+        .create_service_token("audit-admin", &["admin:services"])
+        .await?;
+
+    let (status, body) = list_audit_events(
+        &server,
+        &admin_token,
+        &[
+            ("from", "2020-01-01T00:00:00Z"),
+            ("to", "2020-01-02T00:00:00Z"),
+        ],
+    )
+    .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(event_types(&body).is_empty());
+    assert_eq!(body["from"], json!("2020-01-01T00:00:00Z"));
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_audit_events_requires_admin_scope(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let token = server
+        .create_service_token("audit-reader", &["meeting:create"])
+        .await?;
+
+    let response = server
+        .client()
+        .get(format!("{}/api/v1/admin/audit-events", server.url()))
+        .bearer_auth(&token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = server
+        .client()
+        .get(format!("{}/api/v1/admin/audit-events", server.url()))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_audit_events_rejects_invalid_filters(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let admin_token = server
+        .create_service_token("audit-admin", &["admin:services"])
+        .await?;
+
+    for query in [
+        [("event_type", "not_an_event")],
+        [("from", "last tuesday")],
+        [("limit", "5000")],
+    ] {
+        let (status, body) = list_audit_events(&server, &admin_token, &query).await?;
+        assert_eq!(status, StatusCode::UNAUTHORIZED, "{query:?}");
+        assert_eq!(body["error"]["code"], json!("INVALID_TOKEN"));
+    }
+    Ok(())
+}
@@ -0,0 +1,271 @@
+//! Integration tests for per-client rate limiting on the token endpoints.
+//!
+//! Covers:
+//! - Service tokens are limited per client_id by service type
+//! - Unknown client IDs are limited per source address
+//! - Token exchange only charges the actor's bucket once its token verifies
+//! - The refresh grant counts against the client's bucket
+//! - User tokens are limited per org and email
+//! - Rejections are 429 with a Retry-After header
+
+use ac_service::config::ClientRateLimits;
+use ac_test_utils::server_harness::TestAuthServer;
+use base64::{engine::general_purpose, Engine as _};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Response, StatusCode};
+use serde_json::json;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr};
+
+/// One request per client per minute, except `global-controller` and `user`;
+/// unknown client IDs get two a minute per source address.
+fn limits() -> ClientRateLimits {
+    ClientRateLimits {
+        default_per_minute: 1,
+        per_class: HashMap::from([
+            ("global-controller".to_string(), 2),
+            ("user".to_string(), 2),
+            ("unknown".to_string(), 2),
+        ]),
+    }
+}
+
+async fn request_service_token(
+    server: &TestAuthServer,
+    client_id: &str,
+) -> Result<Response, anyhow::Error> {
+    request_service_token_with(&server.client(), server, client_id).await
+}
+
+async fn request_service_token_with(
+    client: &reqwest::Client,
+    server: &TestAuthServer,
+    client_id: &str,
+) -> Result<Response, anyhow::Error> {
+    Ok(client
+        .post(format!("{}/api/v1/auth/service/token", server.url()))
+        .json(&json!({
+            "grant_type": "client_credentials",
+            "client_id": client_id,
+            "client_secret": "test-secret-12345"
+        }))
+        .send()
+        .await?)
+}
+
+/// A client whose requests come from 127.0.0.2 rather than 127.0.0.1.
+fn other_address_client() -> Result<reqwest::Client, anyhow::Error> {
+    Ok(reqwest::Client::builder()
+        .local_address(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 2)))
+        .build()?)
+}
+
+fn retry_after(response: &Response) -> Result<i64, anyhow::Error> {
+    Ok(response
+        .headers()
+        .get(RETRY_AFTER)
+        .ok_or_else(|| anyhow::anyhow!("429 should carry Retry-After"))?
+        .to_str()?
+        .parse()?)
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_service_token_rate_limited_per_client(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn_with_client_rate_limits(pool, limits()).await?;
+    // Registers a global-controller credential without going through HTTP
+    server.create_service_token("gc-limited", &[]).await?;
+    server.create_service_token("gc-other", &[]).await?;
+
+    for _ in 0..2 {
+        let response = request_service_token(&server, "gc-limited").await?;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = request_service_token(&server, "gc-limited").await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry = retry_after(&response)?;
+    assert!((1..=30).contains(&retry), "Retry-After was {retry}");
+
+    // Buckets are per client
+    let response = request_service_token(&server, "gc-other").await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_unknown_clients_limited_per_address(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn_with_client_rate_limits(pool, limits()).await?;
+
+    // A fresh client ID does not get a fresh bucket
+    for client_id in ["no-such-client-1", "no-such-client-2"] {
+        let response = request_service_token(&server, client_id).await?;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+    let response = request_service_token(&server, "no-such-client-3").await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(retry_after(&response)?, 30);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_unknown_flood_does_not_limit_other_addresses(
+    pool: PgPool,
+) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn_with_client_rate_limits(pool, limits()).await?;
+    // Registered, but not yet seen by the limiter (as after a restart)
+    server.create_service_token("gc-uncached", &[]).await?;
+
+    let flooder = other_address_client()?;
+    for n in 0..2 {
+        let response =
+            request_service_token_with(&flooder, &server, &format!("random-{n}")).await?;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+    let response = request_service_token_with(&flooder, &server, "random-2").await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    let response = request_service_token(&server, "gc-uncached").await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_forged_actor_token_does_not_charge_named_client(
+    pool: PgPool,
+) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn_with_client_rate_limits(pool, limits()).await?;
+    server.create_service_token("gc-victim", &[]).await?;
+
+    // An unsigned token naming the victim as its subject
+    let encode =
+        |value: serde_json::Value| general_purpose::URL_SAFE_NO_PAD.encode(value.to_string());
+    let forged = format!(
+        "{}.{}.{}",
+        encode(json!({ "alg": "EdDSA", "typ": "JWT", "kid": "forged" })),
+        encode(json!({ "sub": "gc-victim", "exp": 4_102_444_800_i64 })),
+        general_purpose::URL_SAFE_NO_PAD.encode(b"not-a-signature"),
+    );
+    let attacker = other_address_client()?;
+    for _ in 0..2 {
+        let response = attacker
+            .post(format!("{}/api/v1/auth/service/token", server.url()))
+            .json(&json!({
+                "grant_type": "urn:ietf:params:oauth:grant-type:token-exchange",
+                "subject_token": forged,
+                "subject_token_type": "urn:ietf:params:oauth:token-type:access_token",
+                "actor_token": forged,
+                "actor_token_type": "urn:ietf:params:oauth:token-type:access_token"
+            }))
+            .send()
+            .await?;
+        assert_ne!(response.status(), StatusCode::OK);
+        assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    // The victim still has its whole global-controller limit of 2
+    for _ in 0..2 {
+        let response = request_service_token(&server, "gc-victim").await?;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_known_client_not_limited_by_unknown_flood(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn_with_client_rate_limits(pool, limits()).await?;
+    server.create_service_token("gc-known", &[]).await?;
+
+    // Seen once, the client has its own bucket
+    let response = request_service_token(&server, "gc-known").await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    for n in 0..3 {
+        request_service_token(&server, &format!("random-{n}")).await?;
+    }
+    let response = request_service_token(&server, "gc-known").await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_refresh_grant_rate_limited(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn_with_client_rate_limits(pool, limits()).await?;
+    server.create_service_token("gc-refresh", &[]).await?;
+
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/service/token", server.url()))
+        .json(&json!({
+            "grant_type": "client_credentials",
+            "client_id": "gc-refresh",
+            "client_secret": "test-secret-12345",
+            "issue_refresh_token": true
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await?;
+    let mut refresh_token = body["refresh_token"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("response should carry a refresh token"))?
+        .to_string();
+
+    let refresh = |refresh_token: String| {
+        server
+            .client()
+            .post(format!("{}/api/v1/auth/service/token", server.url()))
+            .json(&json!({
+                "grant_type": "refresh_token",
+                "client_id": "gc-refresh",
+                "client_secret": "test-secret-12345",
+                "refresh_token": refresh_token
+            }))
+            .send()
+    };
+
+    // The global-controller limit of 2 covers both grants
+    let response = refresh(refresh_token.clone()).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await?;
+    refresh_token = body["refresh_token"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("response should carry a refresh token"))?
+        .to_string();
+
+    let response = refresh(refresh_token).await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_user_token_rate_limited_per_user(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn_with_client_rate_limits(pool, limits()).await?;
+    let org_id = server.create_test_org("limited", "Limited Org").await?;
+    server
+        .create_test_user(org_id, "alice@example.com", "password123", "Alice")
+        .await?;
+
+    let login = |email: &'static str| {
+        server
+            .client()
+            .post(format!("{}/api/v1/auth/user/token", server.url()))
+            .header("Host", server.host_header("limited"))
+            .json(&json!({ "email": email, "password": "password123" }))
+            .send()
+    };
+
+    for _ in 0..2 {
+        assert_eq!(login("alice@example.com").await?.status(), StatusCode::OK);
+    }
+    let response = login("alice@example.com").await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(retry_after(&response)? >= 1);
+
+    // Another user in the same org has their own bucket
+    assert_eq!(
+        login("bob@example.com").await?.status(),
+        StatusCode::UNAUTHORIZED
+    );
+    Ok(())
+}
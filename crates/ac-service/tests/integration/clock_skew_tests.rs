@@ -65,6 +65,7 @@ async fn test_custom_clock_skew_accepts_within_tolerance(
         iat: now + 30,   // Issued 30 seconds from now (within 60s skew)
         scope: "test:scope".to_string(),
         service_type: Some("global-controller".to_string()),
+        cnf: None,
     };
 
     let token = crypto::sign_jwt(&claims, &private_key, &signing_key.key_id)?;
@@ -111,6 +112,7 @@ async fn test_custom_clock_skew_rejects_beyond_tolerance(
         iat: now + 90,   // Issued 90 seconds from now (beyond 60s skew)
         scope: "test:scope".to_string(),
         service_type: Some("global-controller".to_string()),
+        cnf: None,
     };
 
     let token = crypto::sign_jwt(&claims, &private_key, &signing_key.key_id)?;
@@ -149,6 +151,7 @@ async fn test_default_clock_skew_behavior_unchanged(pool: PgPool) -> Result<(),
         iat: now + 120, // Within default 300 second clock skew
         scope: "test:scope".to_string(),
         service_type: Some("global-controller".to_string()),
+        cnf: None,
     };
 
     let token = crypto::sign_jwt(&claims, &private_key, &signing_key.key_id)?;
@@ -189,6 +192,7 @@ async fn test_minimum_clock_skew_edge_case(pool: PgPool) -> Result<(), anyhow::E
         iat: now, // current time
         scope: "test:scope".to_string(),
         service_type: Some("global-controller".to_string()),
+        cnf: None,
     };
     let token_within = crypto::sign_jwt(&claims_within, &private_key, &signing_key.key_id)?;
     let result = crypto::verify_jwt(&token_within, &signing_key.public_key, min_clock_skew);
@@ -204,6 +208,7 @@ async fn test_minimum_clock_skew_edge_case(pool: PgPool) -> Result<(), anyhow::E
         iat: now + 60,
         scope: "test:scope".to_string(),
         service_type: Some("global-controller".to_string()),
+        cnf: None,
     };
     let token_beyond = crypto::sign_jwt(&claims_beyond, &private_key, &signing_key.key_id)?;
     let result = crypto::verify_jwt(&token_beyond, &signing_key.public_key, min_clock_skew);
@@ -237,6 +242,7 @@ async fn test_maximum_clock_skew_edge_case(pool: PgPool) -> Result<(), anyhow::E
         iat: now + 598,  // 2 seconds inside the 600 second boundary
         scope: "test:scope".to_string(),
         service_type: Some("global-controller".to_string()),
+        cnf: None,
     };
 
     let token_within_boundary =
@@ -262,6 +268,7 @@ async fn test_maximum_clock_skew_edge_case(pool: PgPool) -> Result<(), anyhow::E
         iat: now + 602, // 2 seconds beyond max boundary
         scope: "test:scope".to_string(),
         service_type: Some("global-controller".to_string()),
+        cnf: None,
     };
 
     let token_beyond = crypto::sign_jwt(&claims_beyond, &private_key, &signing_key.key_id)?;
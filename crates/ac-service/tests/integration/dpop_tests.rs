@@ -0,0 +1,220 @@
+//! Integration tests for DPoP-bound service tokens (RFC 9449).
+//!
+//! Covers:
+//! - A client credentials request with a DPoP proof gets a `DPoP` token
+//!   bound to the proof key (`cnf.jkt`)
+//! - Requests without a proof still get bearer tokens
+//! - Invalid, replayed, and misdirected proofs are rejected
+//! - The refresh grant binds the new access token to the proof key
+//! - Introspection reports the binding
+
+use ac_test_utils::server_harness::TestAuthServer;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::Utc;
+use common::dpop::DpopKey;
+use reqwest::StatusCode;
+use serde_json::json;
+use sqlx::PgPool;
+
+const CLIENT_SECRET: &str = "test-secret-12345";
+
+fn token_url(server: &TestAuthServer) -> String {
+    format!("{}/api/v1/auth/service/token", server.url())
+}
+
+/// Request a client credentials token, with `proof` in the `DPoP` header.
+async fn request_token(
+    server: &TestAuthServer,
+    client_id: &str,
+    proof: Option<&str>,
+    issue_refresh_token: bool,
+) -> Result<reqwest::Response, anyhow::Error> {
+    let mut request = server.client().post(token_url(server)).json(&json!({
+        "grant_type": "client_credentials",
+        "client_id": client_id,
+        "client_secret": CLIENT_SECRET,
+        "issue_refresh_token": issue_refresh_token
+    }));
+    if let Some(proof) = proof {
+        request = request.header("DPoP", proof);
+    }
+    Ok(request.send().await?)
+}
+
+/// The unverified claims of `token`.
+fn claims_of(token: &str) -> Result<serde_json::Value, anyhow::Error> {
+    let payload = token
+        .split('.')
+        .nth(1)
+        .ok_or_else(|| anyhow::anyhow!("token should be a JWT"))?;
+    Ok(serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload)?)?)
+}
+
+fn access_token_of(body: &serde_json::Value) -> Result<&str, anyhow::Error> {
+    body["access_token"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("response should carry an access token"))
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_dpop_proof_binds_service_token(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    server
+        .create_service_token("dpop-client", &["meeting:create"])
+        .await?;
+    let key = DpopKey::generate();
+
+    let proof = key.proof("POST", &token_url(&server), Utc::now().timestamp(), None);
+    let response = request_token(&server, "dpop-client", Some(&proof), false).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await?;
+
+    assert_eq!(body["token_type"], "DPoP");
+    let claims = claims_of(access_token_of(&body)?)?;
+    assert_eq!(claims["cnf"], json!({ "jkt": key.thumbprint() }));
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_no_proof_issues_bearer_token(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    server
+        .create_service_token("bearer-client", &["meeting:create"])
+        .await?;
+
+    let response = request_token(&server, "bearer-client", None, false).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await?;
+
+    assert_eq!(body["token_type"], "Bearer");
+    let claims = claims_of(access_token_of(&body)?)?;
+    assert!(claims.get("cnf").is_none(), "Bearer tokens carry no cnf");
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_invalid_dpop_proofs_rejected(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    server
+        .create_service_token("dpop-client", &["meeting:create"])
+        .await?;
+    let key = DpopKey::generate();
+    let now = Utc::now().timestamp();
+
+    let proofs = [
+        // Proof for another endpoint
+        key.proof(
+            "POST",
+            &format!("{}/api/v1/auth/user/token", server.url()),
+            now,
+            None,
+        ),
+        // Proof for another host
+        key.proof(
+            "POST",
+            "https://attacker.example.com/api/v1/auth/service/token",
+            now,
+            None,
+        ),
+        // Stale proof
+        key.proof("POST", &token_url(&server), now - 3600, None),
+        // Not a proof at all
+        "not-a-jwt".to_string(),
+    ];
+
+    for proof in proofs {
+        let response = request_token(&server, "dpop-client", Some(&proof), false).await?;
+        assert_eq!(
+            response.status(),
+            StatusCode::UNAUTHORIZED,
+            "An invalid proof must fail the request, not fall back to a bearer token"
+        );
+    }
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_replayed_dpop_proof_rejected(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    server
+        .create_service_token("dpop-client", &["meeting:create"])
+        .await?;
+    let key = DpopKey::generate();
+
+    let proof = key.proof("POST", &token_url(&server), Utc::now().timestamp(), None);
+    let response = request_token(&server, "dpop-client", Some(&proof), false).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = request_token(&server, "dpop-client", Some(&proof), false).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_refresh_grant_binds_to_dpop_key(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    server
+        .create_service_token("dpop-refresh", &["meeting:create"])
+        .await?;
+    let key = DpopKey::generate();
+
+    let proof = key.proof("POST", &token_url(&server), Utc::now().timestamp(), None);
+    let response = request_token(&server, "dpop-refresh", Some(&proof), true).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await?;
+    let refresh_token = body["refresh_token"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("response should carry a refresh token"))?;
+
+    let proof = key.proof("POST", &token_url(&server), Utc::now().timestamp(), None);
+    let response = server
+        .client()
+        .post(token_url(&server))
+        .header("DPoP", proof)
+        .json(&json!({
+            "grant_type": "refresh_token",
+            "client_id": "dpop-refresh",
+            "client_secret": "test-secret-12345",
+            "refresh_token": refresh_token
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await?;
+
+    assert_eq!(body["token_type"], "DPoP");
+    let claims = claims_of(access_token_of(&body)?)?;
+    assert_eq!(claims["cnf"]["jkt"], key.thumbprint());
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_introspection_reports_dpop_binding(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let caller = server
+        .create_service_token("introspect-caller", &["internal:meeting-token"])
+        .await?;
+    server
+        .create_service_token("dpop-client", &["meeting:create"])
+        .await?;
+    let key = DpopKey::generate();
+
+    let proof = key.proof("POST", &token_url(&server), Utc::now().timestamp(), None);
+    let response = request_token(&server, "dpop-client", Some(&proof), false).await?;
+    let body: serde_json::Value = response.json().await?;
+
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/introspect", server.url()))
+        .bearer_auth(&caller)
+        .json(&json!({ "token": access_token_of(&body)? }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let introspection: serde_json::Value = response.json().await?;
+
+    assert_eq!(introspection["active"], true);
+    assert_eq!(introspection["cnf"], json!({ "jkt": key.thumbprint() }));
+    Ok(())
+}
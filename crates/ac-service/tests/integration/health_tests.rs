@@ -42,6 +42,34 @@ async fn test_health_endpoint_returns_ok(pool: PgPool) -> Result<(), anyhow::Err
     Ok(())
 }
 
+/// Test /version reports the build metadata without authentication
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_version_endpoint_reports_build(pool: PgPool) -> Result<(), anyhow::Error> {
+    // Arrange
+    let server = TestAuthServer::spawn(pool).await?;
+
+    // Act
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!("{}/version", server.url()))
+        .send()
+        .await?;
+
+    // Assert
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["service"], "ac-service");
+    assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+    assert!(body["git_sha"].is_string());
+    assert!(
+        body["proto_schema"].is_null(),
+        "AC has no gRPC server and no proto schema"
+    );
+
+    Ok(())
+}
+
 // ============================================================================
 // Readiness Probe Tests
 // ============================================================================
@@ -0,0 +1,182 @@
+//! Integration tests for the token introspection endpoint (RFC 7662).
+//!
+//! Covers:
+//! - Caller authentication (require_service_auth)
+//! - Active service and user tokens
+//! - Inactive tokens: expired, malformed, deactivated client, inactive user
+
+use ac_service::repositories::service_credentials;
+use ac_test_utils::server_harness::TestAuthServer;
+use reqwest::StatusCode;
+use serde_json::json;
+use sqlx::PgPool;
+
+/// Introspect `token` as `caller_token`, returning the JSON body.
+async fn introspect(
+    server: &TestAuthServer,
+    caller_token: &str,
+    token: &str,
+) -> Result<serde_json::Value, anyhow::Error> {
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/introspect", server.url()))
+        .bearer_auth(caller_token)
+        .json(&json!({ "token": token }))
+        .send()
+        .await?;
+
+    assert_eq!(
+        response.status(),
+        StatusCode::OK,
+        "Introspection should return 200 for active and inactive tokens"
+    );
+    Ok(response.json().await?)
+}
+
+/// Assert an RFC 7662 inactive response: `active: false` and nothing else.
+fn assert_inactive(body: &serde_json::Value) {
+    assert_eq!(body, &json!({ "active": false }));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_introspect_requires_authentication(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let token = server
+        .create_service_token("introspect-target", &["meeting:create"])
+        .await?;
+
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/introspect", server.url()))
+        .json(&json!({ "token": token }))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_introspect_active_service_token(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let caller = server
+        .create_service_token("introspect-caller", &["internal:meeting-token"])
+        .await?;
+    let token = server
+        .create_service_token("introspect-target", &["meeting:create", "meeting:read"])
+        .await?;
+
+    let body = introspect(&server, &caller, &token).await?;
+
+    assert_eq!(body["active"], true);
+    assert_eq!(body["sub"], "introspect-target");
+    assert_eq!(body["scope"], "meeting:create meeting:read");
+    assert!(
+        body["exp"].as_i64().is_some(),
+        "Active token should carry exp"
+    );
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_introspect_expired_token_inactive(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let caller = server
+        .create_service_token("introspect-caller", &["internal:meeting-token"])
+        .await?;
+    let token = server
+        .create_expired_token("introspect-caller", &["meeting:create"], 3600)
+        .await?;
+
+    let body = introspect(&server, &caller, &token).await?;
+
+    assert_inactive(&body);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_introspect_malformed_token_inactive(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let caller = server
+        .create_service_token("introspect-caller", &["internal:meeting-token"])
+        .await?;
+
+    let oversized = "x".repeat(10_000);
+    for token in ["", "opaque-token", "a.b.c", oversized.as_str()] {
+        let body = introspect(&server, &caller, token).await?;
+        assert_inactive(&body);
+    }
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_introspect_deactivated_client_inactive(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let caller = server
+        .create_service_token("introspect-caller", &["internal:meeting-token"])
+        .await?;
+    let token = server
+        .create_service_token("introspect-revoked", &["meeting:create"])
+        .await?;
+
+    let credential = service_credentials::get_by_client_id(server.pool(), "introspect-revoked")
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("credential should exist"))?;
+    service_credentials::deactivate(server.pool(), credential.credential_id).await?;
+
+    let body = introspect(&server, &caller, &token).await?;
+
+    assert_inactive(&body);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_introspect_user_token(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let caller = server
+        .create_service_token("introspect-caller", &["internal:meeting-token"])
+        .await?;
+    let org_id = server
+        .create_test_org("introspect", "Introspect Corp")
+        .await?;
+    let user_id = server
+        .create_test_user(
+            org_id,
+            "introspect@example.com",
+            "password123",
+            "Introspect User",
+        )
+        .await?;
+
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/user/token", server.url()))
+        .header("Host", server.host_header("introspect"))
+        .json(&json!({
+            "email": "introspect@example.com",
+            "password": "password123"
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let login: serde_json::Value = response.json().await?;
+    let token = login["access_token"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("login should return an access token"))?
+        .to_string();
+
+    let body = introspect(&server, &caller, &token).await?;
+    assert_eq!(body["active"], true);
+    assert_eq!(body["sub"], user_id.to_string());
+    assert!(body.get("scope").is_none(), "User tokens carry no scope");
+
+    // Deactivating the user makes the token inactive
+    sqlx::query("UPDATE users SET is_active = false WHERE user_id = $1")
+        .bind(user_id)
+        .execute(server.pool())
+        .await?;
+
+    let body = introspect(&server, &caller, &token).await?;
+    assert_inactive(&body);
+    Ok(())
+}
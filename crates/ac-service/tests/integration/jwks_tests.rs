@@ -0,0 +1,117 @@
+//! Integration tests for the JWKS endpoint.
+//!
+//! Covers:
+//! - ETag and Cache-Control headers
+//! - Conditional requests (If-None-Match -> 304)
+//! - Key rotation via the admin endpoint publishes the new key immediately
+
+use ac_service::models::Jwks;
+use ac_test_utils::{rotation_time, TestAuthServer};
+use reqwest::header::{CACHE_CONTROL, ETAG, IF_NONE_MATCH};
+use reqwest::StatusCode;
+use sqlx::PgPool;
+
+/// GET the JWKS document, returning its ETag and parsed body.
+async fn get_jwks(server: &TestAuthServer) -> Result<(String, Jwks), anyhow::Error> {
+    let response = server
+        .client()
+        .get(format!("{}/.well-known/jwks.json", server.url()))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let etag = response
+        .headers()
+        .get(ETAG)
+        .ok_or_else(|| anyhow::anyhow!("JWKS response should carry an ETag"))?
+        .to_str()?
+        .to_string();
+    Ok((etag, response.json().await?))
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_jwks_headers(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+
+    let response = server
+        .client()
+        .get(format!("{}/.well-known/jwks.json", server.url()))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response
+            .headers()
+            .get(CACHE_CONTROL)
+            .map(|v| v.to_str().unwrap_or_default()),
+        Some("max-age=3600")
+    );
+    assert!(response.headers().contains_key(ETAG));
+    let jwks: Jwks = response.json().await?;
+    assert!(!jwks.keys.is_empty(), "Harness seeds a signing key");
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_jwks_if_none_match_returns_304(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let (etag, _) = get_jwks(&server).await?;
+
+    let response = server
+        .client()
+        .get(format!("{}/.well-known/jwks.json", server.url()))
+        .header(IF_NONE_MATCH, &etag)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(
+        response
+            .headers()
+            .get(ETAG)
+            .map(|v| v.to_str().unwrap_or_default()),
+        Some(etag.as_str())
+    );
+    assert!(response.bytes().await?.is_empty());
+
+    // A stale tag gets the full document
+    let response = server
+        .client()
+        .get(format!("{}/.well-known/jwks.json", server.url()))
+        .header(IF_NONE_MATCH, "\"stale\"")
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_jwks_publishes_rotated_key(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool.clone()).await?;
+    let (etag_before, _) = get_jwks(&server).await?;
+
+    rotation_time::set_eligible(&pool).await?;
+    let token = server
+        .create_service_token("jwks-rotation-client", &["service.rotate-keys.ac"])
+        .await?;
+    let response = server
+        .client()
+        .post(format!("{}/internal/rotate-keys", server.url()))
+        .bearer_auth(&token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await?;
+    let new_kid = body["new_key_id"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("rotation should return new_key_id"))?
+        .to_string();
+
+    let (etag_after, jwks) = get_jwks(&server).await?;
+    assert_ne!(etag_after, etag_before, "Rotation should change the ETag");
+    assert!(
+        jwks.keys.iter().any(|key| key.kid == new_kid),
+        "Rotated key should be published without waiting for a refresh"
+    );
+    Ok(())
+}
@@ -0,0 +1,103 @@
+//! Integration tests for signing key replication between regions.
+//!
+//! Covers:
+//! - `/internal/keys/export` and `/internal/keys/import` require the
+//!   `admin.replicate-keys.ac` scope
+//! - The export carries the keys published in JWKS, without plaintext
+//!   private key material
+//! - Importing an export into a region that already has its keys is a no-op
+
+use ac_service::middleware::auth::KEY_REPLICATION_SCOPE;
+use ac_test_utils::server_harness::TestAuthServer;
+use reqwest::StatusCode;
+use serde_json::json;
+use sqlx::PgPool;
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_key_replication_requires_scope(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let admin = server
+        .create_service_token("client-admin", &["admin:services"])
+        .await?;
+
+    for path in ["/internal/keys/export", "/internal/keys/import"] {
+        let response = server
+            .client()
+            .post(format!("{}{}", server.url(), path))
+            .json(&json!({ "keys": [] }))
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{path}");
+
+        let response = server
+            .client()
+            .post(format!("{}{}", server.url(), path))
+            .bearer_auth(&admin)
+            .json(&json!({ "keys": [] }))
+            .send()
+            .await?;
+        assert_eq!(
+            response.status(),
+            StatusCode::FORBIDDEN,
+            "admin:services must not grant {path}"
+        );
+    }
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_export_matches_jwks_and_reimport_is_noop(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let token = server
+        .create_service_token("key-replicator", &[KEY_REPLICATION_SCOPE])
+        .await?;
+
+    let jwks: serde_json::Value = server
+        .client()
+        .get(format!("{}/.well-known/jwks.json", server.url()))
+        .send()
+        .await?
+        .json()
+        .await?;
+    let kid = jwks["keys"][0]["kid"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("JWKS should publish a key"))?
+        .to_string();
+
+    let response = server
+        .client()
+        .post(format!("{}/internal/keys/export", server.url()))
+        .bearer_auth(&token)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let export: serde_json::Value = response.json().await?;
+
+    let keys = export["keys"]
+        .as_array()
+        .ok_or_else(|| anyhow::anyhow!("export should list keys"))?;
+    assert_eq!(keys.len(), 1);
+    let key = &export["keys"][0];
+    assert_eq!(key["key_id"], kid);
+    assert_eq!(key["is_active"], true);
+    assert!(
+        key["private_key_encrypted"].is_string(),
+        "private key must be exported encrypted"
+    );
+
+    let response = server
+        .client()
+        .post(format!("{}/internal/keys/import", server.url()))
+        .bearer_auth(&token)
+        .json(&export)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await?;
+
+    assert_eq!(body["imported"], json!([]));
+    assert_eq!(body["skipped"], json!([kid]));
+    assert_eq!(body["active"], json!([kid]));
+    assert_eq!(body["deactivated"], json!([]));
+    Ok(())
+}
@@ -40,7 +40,7 @@ async fn create_service_with_token(
     // Issue service token
     let token_response = token_service::issue_service_token(
         pool,
-        master_key,
+        &master_key.to_vec(),
         master_key,
         client_id,
         client_secret,
@@ -83,6 +83,7 @@ async fn create_user_token(pool: &PgPool, master_key: &[u8]) -> Result<String, a
         iat: Utc::now().timestamp(),
         scope: "service.rotate-keys.ac".to_string(),
         service_type: None, // User tokens don't have service_type
+        cnf: None,
     };
 
     // Sign token
@@ -282,6 +283,7 @@ async fn test_rotate_keys_expired_token_returns_401(pool: PgPool) -> Result<(),
         iat: Utc::now().timestamp() - 7200, // Issued 2 hours ago
         scope: "service.rotate-keys.ac".to_string(),
         service_type: Some("global-controller".to_string()),
+        cnf: None,
     };
 
     // Sign token
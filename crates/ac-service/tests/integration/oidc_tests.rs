@@ -0,0 +1,383 @@
+//! E2E tests for org SSO through an external OIDC provider.
+//!
+//! A mock IdP (discovery, token, and JWKS endpoints on a local axum server)
+//! stands in for the org's provider. Tests configure it with
+//! `PUT /api/v1/admin/orgs/{id}/oidc`, start a login at
+//! `/api/v1/auth/oidc/authorize`, and finish it at
+//! `/api/v1/auth/oidc/callback` the way the browser would.
+//!
+//! ## Test Naming
+//!
+//! Tests follow the convention: `test_<feature>_<scenario>_<expected_result>`
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use ac_service::crypto;
+use ac_test_utils::server_harness::TestAuthServer;
+use axum::extract::State;
+use axum::http::StatusCode as AxumStatus;
+use axum::routing::{get, post};
+use axum::{Form, Json, Router};
+use base64::{engine::general_purpose, Engine as _};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use reqwest::StatusCode;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+const CLIENT_ID: &str = "dark-tower";
+const CLIENT_SECRET: &str = "idp-client-secret";
+const AUTH_CODE: &str = "auth-code-1";
+
+/// What the mock IdP hands out at its token endpoint.
+#[derive(Default)]
+struct IdpState {
+    issuer: String,
+    pkcs8: Vec<u8>,
+    /// PKCE challenge from the authorization request
+    code_challenge: Option<String>,
+    /// Claims for the next ID token
+    claims: Option<serde_json::Value>,
+}
+
+struct MockIdp {
+    url: String,
+    state: Arc<Mutex<IdpState>>,
+}
+
+impl MockIdp {
+    async fn spawn() -> Result<Self, anyhow::Error> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let (_, pkcs8) = crypto::generate_signing_key().map_err(|e| anyhow::anyhow!("{e}"))?;
+        let state = Arc::new(Mutex::new(IdpState {
+            issuer: url.clone(),
+            pkcs8,
+            ..IdpState::default()
+        }));
+
+        let app = Router::new()
+            .route("/.well-known/openid-configuration", get(discovery))
+            .route("/token", post(token))
+            .route("/jwks", get(jwks))
+            .with_state(Arc::clone(&state));
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        Ok(Self { url, state })
+    }
+
+    /// Accept the authorization request behind `location` and sign the next
+    /// ID token for `sub` with the request's nonce.
+    fn authorize(&self, location: &str, sub: &str, email: &str, email_verified: bool) -> String {
+        let url = reqwest::Url::parse(location).unwrap();
+        let query: HashMap<_, _> = url.query_pairs().into_owned().collect();
+        assert!(location.starts_with(&format!("{}/authorize?", self.url)));
+        assert_eq!(query["client_id"], CLIENT_ID);
+        assert_eq!(query["code_challenge_method"], "S256");
+
+        let now = chrono::Utc::now().timestamp();
+        let mut state = self.state.lock().unwrap();
+        state.code_challenge = Some(query["code_challenge"].clone());
+        state.claims = Some(json!({
+            "iss": state.issuer,
+            "sub": sub,
+            "aud": CLIENT_ID,
+            "iat": now,
+            "exp": now + 300,
+            "nonce": query["nonce"],
+            "email": email,
+            "email_verified": email_verified,
+            "name": "SSO User",
+        }));
+        query["state"].clone()
+    }
+}
+
+async fn discovery(State(state): State<Arc<Mutex<IdpState>>>) -> Json<serde_json::Value> {
+    let issuer = state.lock().unwrap().issuer.clone();
+    Json(json!({
+        "issuer": issuer,
+        "authorization_endpoint": format!("{issuer}/authorize"),
+        "token_endpoint": format!("{issuer}/token"),
+        "jwks_uri": format!("{issuer}/jwks"),
+    }))
+}
+
+async fn token(
+    State(state): State<Arc<Mutex<IdpState>>>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Result<Json<serde_json::Value>, AxumStatus> {
+    let state = state.lock().unwrap();
+    let verifier_challenge =
+        general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(form["code_verifier"].as_bytes()));
+    if form["code"] != AUTH_CODE
+        || form["client_secret"] != CLIENT_SECRET
+        || state.code_challenge.as_deref() != Some(verifier_challenge.as_str())
+    {
+        return Err(AxumStatus::BAD_REQUEST);
+    }
+
+    let mut header = Header::new(Algorithm::EdDSA);
+    header.kid = Some("idp-key-1".to_string());
+    let id_token = encode(
+        &header,
+        state.claims.as_ref().unwrap(),
+        &EncodingKey::from_ed_der(&state.pkcs8),
+    )
+    .unwrap();
+    Ok(Json(
+        json!({ "access_token": "idp-access", "token_type": "Bearer", "id_token": id_token }),
+    ))
+}
+
+async fn jwks(State(state): State<Arc<Mutex<IdpState>>>) -> Json<serde_json::Value> {
+    let state = state.lock().unwrap();
+    let public_key = Ed25519KeyPair::from_pkcs8(&state.pkcs8)
+        .unwrap()
+        .public_key()
+        .as_ref()
+        .to_vec();
+    Json(json!({
+        "keys": [{
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "alg": "EdDSA",
+            "kid": "idp-key-1",
+            "x": general_purpose::URL_SAFE_NO_PAD.encode(public_key),
+        }]
+    }))
+}
+
+/// Set `idp` as the `sso` org's provider.
+async fn configure_sso(
+    server: &TestAuthServer,
+    org_id: Uuid,
+    idp: &MockIdp,
+) -> Result<reqwest::Response, anyhow::Error> {
+    let token = server
+        .create_service_token("admin-service", &["admin:services"])
+        .await?;
+    Ok(server
+        .client()
+        .put(format!(
+            "{}/api/v1/admin/orgs/{}/oidc",
+            server.url(),
+            org_id
+        ))
+        .bearer_auth(&token)
+        .json(&json!({
+            "issuer_url": idp.url,
+            "client_id": CLIENT_ID,
+            "client_secret": CLIENT_SECRET,
+            "redirect_uri": format!(
+                "http://{}/api/v1/auth/oidc/callback",
+                server.host_header("sso")
+            ),
+        }))
+        .send()
+        .await?)
+}
+
+/// Start a login on the `sso` org and return the IdP redirect.
+async fn start_login(server: &TestAuthServer) -> Result<reqwest::Response, anyhow::Error> {
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()?;
+    Ok(client
+        .get(format!("{}/api/v1/auth/oidc/authorize", server.url()))
+        .header("Host", server.host_header("sso"))
+        .send()
+        .await?)
+}
+
+fn location(response: &reqwest::Response) -> String {
+    response.headers()["location"].to_str().unwrap().to_string()
+}
+
+async fn callback(
+    server: &TestAuthServer,
+    code: &str,
+    state: &str,
+) -> Result<reqwest::Response, anyhow::Error> {
+    Ok(server
+        .client()
+        .get(format!("{}/api/v1/auth/oidc/callback", server.url()))
+        .query(&[("code", code), ("state", state)])
+        .header("Host", server.host_header("sso"))
+        .send()
+        .await?)
+}
+
+async fn linked_user(pool: &PgPool, sub: &str) -> Result<Option<Uuid>, anyhow::Error> {
+    Ok(
+        sqlx::query_scalar("SELECT user_id FROM user_oidc_identities WHERE subject = $1")
+            .bind(sub)
+            .fetch_optional(pool)
+            .await?,
+    )
+}
+
+/// Test that a first SSO login creates and links a user, issues a token,
+/// and that its state cannot be replayed.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_oidc_first_login_provisions_user(pool: PgPool) -> Result<(), anyhow::Error> {
+    // Arrange
+    let server = TestAuthServer::spawn(pool.clone()).await?;
+    let org_id = server.create_test_org("sso", "SSO Corp").await?;
+    let idp = MockIdp::spawn().await?;
+    assert_eq!(
+        configure_sso(&server, org_id, &idp).await?.status(),
+        StatusCode::OK
+    );
+
+    // Act
+    let response = start_login(&server).await?;
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    let state = idp.authorize(&location(&response), "idp-sub-1", "new@example.com", true);
+    let response = callback(&server, AUTH_CODE, &state).await?;
+
+    // Assert
+    assert_eq!(response.status(), StatusCode::OK, "Callback should succeed");
+    let body: serde_json::Value = response.json().await?;
+    assert!(body["access_token"].as_str().is_some_and(|t| !t.is_empty()));
+    assert_eq!(body["token_type"].as_str(), Some("Bearer"));
+
+    let (user_id, display_name): (Uuid, String) = sqlx::query_as(
+        "SELECT user_id, display_name FROM users WHERE org_id = $1 AND email = 'new@example.com'",
+    )
+    .bind(org_id)
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(display_name, "SSO User");
+    assert_eq!(linked_user(&pool, "idp-sub-1").await?, Some(user_id));
+
+    // The state is single use
+    let replay = callback(&server, AUTH_CODE, &state).await?;
+    assert_eq!(replay.status(), StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
+
+/// Test that an existing user with the verified email is linked rather than
+/// duplicated.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_oidc_login_links_existing_user(pool: PgPool) -> Result<(), anyhow::Error> {
+    // Arrange
+    let server = TestAuthServer::spawn(pool.clone()).await?;
+    let org_id = server.create_test_org("sso", "SSO Corp").await?;
+    let user_id = server
+        .create_test_user(org_id, "alice@example.com", "password123", "Alice")
+        .await?;
+    let idp = MockIdp::spawn().await?;
+    configure_sso(&server, org_id, &idp).await?;
+
+    // Act
+    let response = start_login(&server).await?;
+    let state = idp.authorize(&location(&response), "idp-alice", "alice@example.com", true);
+    let response = callback(&server, AUTH_CODE, &state).await?;
+
+    // Assert
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(linked_user(&pool, "idp-alice").await?, Some(user_id));
+
+    Ok(())
+}
+
+/// Test that unverified emails, wrong codes, and forged states are refused.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_oidc_callback_rejects_unverifiable_logins(pool: PgPool) -> Result<(), anyhow::Error> {
+    // Arrange
+    let server = TestAuthServer::spawn(pool.clone()).await?;
+    let org_id = server.create_test_org("sso", "SSO Corp").await?;
+    let idp = MockIdp::spawn().await?;
+    configure_sso(&server, org_id, &idp).await?;
+
+    // Unverified email: no user is created or linked
+    let response = start_login(&server).await?;
+    let state = idp.authorize(&location(&response), "idp-sub-2", "eve@example.com", false);
+    let response = callback(&server, AUTH_CODE, &state).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(linked_user(&pool, "idp-sub-2").await?, None);
+
+    // The provider refuses the code
+    let response = start_login(&server).await?;
+    let state = idp.authorize(&location(&response), "idp-sub-3", "bob@example.com", true);
+    let response = callback(&server, "wrong-code", &state).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // A state AC never issued
+    let response = callback(&server, AUTH_CODE, "forged-state").await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
+
+/// Test provider management: validation, reading back without the secret,
+/// and deletion turning SSO off.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_oidc_provider_admin_lifecycle(pool: PgPool) -> Result<(), anyhow::Error> {
+    // Arrange
+    let server = TestAuthServer::spawn(pool).await?;
+    let org_id = server.create_test_org("sso", "SSO Corp").await?;
+    let idp = MockIdp::spawn().await?;
+    let token = server
+        .create_service_token("admin-service", &["admin:services"])
+        .await?;
+    let admin_url = format!("{}/api/v1/admin/orgs/{}/oidc", server.url(), org_id);
+
+    // No provider yet
+    let response = start_login(&server).await?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Plain HTTP issuers are refused outside loopback
+    let response = server
+        .client()
+        .put(&admin_url)
+        .bearer_auth(&token)
+        .json(&json!({
+            "issuer_url": "http://idp.example.com",
+            "client_id": CLIENT_ID,
+            "client_secret": CLIENT_SECRET,
+            "redirect_uri": "https://sso.example.com/api/v1/auth/oidc/callback",
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    assert_eq!(
+        configure_sso(&server, org_id, &idp).await?.status(),
+        StatusCode::OK
+    );
+    let body: serde_json::Value = server
+        .client()
+        .get(&admin_url)
+        .bearer_auth(&token)
+        .send()
+        .await?
+        .json()
+        .await?;
+    assert_eq!(body["issuer_url"].as_str(), Some(idp.url.as_str()));
+    assert_eq!(body["enabled"].as_bool(), Some(true));
+    assert!(body.get("client_secret").is_none());
+
+    // Act
+    let response = server
+        .client()
+        .delete(&admin_url)
+        .bearer_auth(&token)
+        .send()
+        .await?;
+
+    // Assert
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = start_login(&server).await?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
@@ -0,0 +1,291 @@
+//! Integration tests for refresh tokens.
+//!
+//! Covers:
+//! - Opt-in issuance on the client credentials and user token endpoints
+//! - Rotation on use (`grant_type=refresh_token`)
+//! - Reuse detection revoking the whole token family
+//! - Refreshing requires the credentials of the client the token was
+//!   issued to
+//! - Deactivated clients cannot refresh
+
+use ac_service::repositories::service_credentials;
+use ac_test_utils::server_harness::TestAuthServer;
+use reqwest::StatusCode;
+use serde_json::json;
+use sqlx::PgPool;
+
+const CLIENT_SECRET: &str = "test-secret-12345";
+
+/// Get a service token with a refresh token for `client_id`.
+async fn issue_with_refresh_token(
+    server: &TestAuthServer,
+    client_id: &str,
+) -> Result<serde_json::Value, anyhow::Error> {
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/service/token", server.url()))
+        .json(&json!({
+            "grant_type": "client_credentials",
+            "client_id": client_id,
+            "client_secret": CLIENT_SECRET,
+            "issue_refresh_token": true
+        }))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    Ok(response.json().await?)
+}
+
+/// Redeem `refresh_token` on the service token endpoint as `client_id`.
+async fn refresh(
+    server: &TestAuthServer,
+    client_id: &str,
+    refresh_token: &str,
+) -> Result<reqwest::Response, anyhow::Error> {
+    Ok(server
+        .client()
+        .post(format!("{}/api/v1/auth/service/token", server.url()))
+        .json(&json!({
+            "grant_type": "refresh_token",
+            "client_id": client_id,
+            "client_secret": CLIENT_SECRET,
+            "refresh_token": refresh_token
+        }))
+        .send()
+        .await?)
+}
+
+fn refresh_token_of(body: &serde_json::Value) -> Result<String, anyhow::Error> {
+    Ok(body["refresh_token"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("response should carry a refresh token"))?
+        .to_string())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_refresh_token_only_issued_on_opt_in(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    server
+        .create_service_token("refresh-opt-in", &["meeting:create"])
+        .await?;
+
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/service/token", server.url()))
+        .json(&json!({
+            "grant_type": "client_credentials",
+            "client_id": "refresh-opt-in",
+            "client_secret": CLIENT_SECRET
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await?;
+    assert!(
+        body.get("refresh_token").is_none(),
+        "No refresh token without opt-in"
+    );
+
+    let body = issue_with_refresh_token(&server, "refresh-opt-in").await?;
+    assert!(body["access_token"].as_str().is_some());
+    refresh_token_of(&body)?;
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_refresh_token_rotates(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    server
+        .create_service_token("refresh-rotate", &["meeting:create", "meeting:read"])
+        .await?;
+
+    let first = issue_with_refresh_token(&server, "refresh-rotate").await?;
+    let first_refresh = refresh_token_of(&first)?;
+
+    let response = refresh(&server, "refresh-rotate", &first_refresh).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let second: serde_json::Value = response.json().await?;
+
+    assert_eq!(second["token_type"], "Bearer");
+    assert_eq!(second["scope"], "meeting:create meeting:read");
+    assert!(second["access_token"].as_str().is_some());
+    let second_refresh = refresh_token_of(&second)?;
+    assert_ne!(
+        first_refresh, second_refresh,
+        "Refresh token should rotate on use"
+    );
+
+    // The successor is redeemable
+    let response = refresh(&server, "refresh-rotate", &second_refresh).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_refresh_token_reuse_revokes_family(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    server
+        .create_service_token("refresh-reuse", &["meeting:create"])
+        .await?;
+
+    let first = issue_with_refresh_token(&server, "refresh-reuse").await?;
+    let first_refresh = refresh_token_of(&first)?;
+
+    let response = refresh(&server, "refresh-reuse", &first_refresh).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let second: serde_json::Value = response.json().await?;
+    let second_refresh = refresh_token_of(&second)?;
+
+    // Replaying the used token is rejected...
+    let response = refresh(&server, "refresh-reuse", &first_refresh).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // ...and revokes its unused successor too
+    let response = refresh(&server, "refresh-reuse", &second_refresh).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_refresh_rejected_for_unknown_token(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    server
+        .create_service_token("refresh-unknown", &["meeting:create"])
+        .await?;
+
+    let response = refresh(&server, "refresh-unknown", "not-a-refresh-token").await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Missing refresh_token field
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/service/token", server.url()))
+        .json(&json!({
+            "grant_type": "refresh_token",
+            "client_id": "refresh-unknown",
+            "client_secret": CLIENT_SECRET
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_refresh_requires_client_authentication(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    server
+        .create_service_token("refresh-owner", &["meeting:create"])
+        .await?;
+    server
+        .create_service_token("refresh-other", &["meeting:create"])
+        .await?;
+
+    let first = issue_with_refresh_token(&server, "refresh-owner").await?;
+    let first_refresh = refresh_token_of(&first)?;
+
+    // A leaked refresh token alone is not enough
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/service/token", server.url()))
+        .json(&json!({
+            "grant_type": "refresh_token",
+            "refresh_token": first_refresh
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Nor is it with the wrong secret
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/service/token", server.url()))
+        .json(&json!({
+            "grant_type": "refresh_token",
+            "client_id": "refresh-owner",
+            "client_secret": "wrong-secret",
+            "refresh_token": first_refresh
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Another authenticated client cannot redeem it
+    let response = refresh(&server, "refresh-other", &first_refresh).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // None of the rejections consumed it
+    let response = refresh(&server, "refresh-owner", &first_refresh).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_refresh_rejected_for_deactivated_client(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    server
+        .create_service_token("refresh-revoked", &["meeting:create"])
+        .await?;
+
+    let first = issue_with_refresh_token(&server, "refresh-revoked").await?;
+    let first_refresh = refresh_token_of(&first)?;
+
+    let credential = service_credentials::get_by_client_id(server.pool(), "refresh-revoked")
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("credential should exist"))?;
+    service_credentials::deactivate(server.pool(), credential.credential_id).await?;
+
+    let response = refresh(&server, "refresh-revoked", &first_refresh).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_user_refresh_token(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let org_id = server.create_test_org("refresh", "Refresh Corp").await?;
+    server
+        .create_test_user(org_id, "refresh@example.com", "password123", "Refresh User")
+        .await?;
+
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/user/token", server.url()))
+        .header("Host", server.host_header("refresh"))
+        .json(&json!({
+            "email": "refresh@example.com",
+            "password": "password123",
+            "issue_refresh_token": true
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let login: serde_json::Value = response.json().await?;
+    let first_refresh = refresh_token_of(&login)?;
+
+    // A user refresh token is not accepted on the service endpoint
+    server
+        .create_service_token("refresh-service", &["meeting:create"])
+        .await?;
+    let response = refresh(&server, "refresh-service", &first_refresh).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/user/token/refresh", server.url()))
+        .header("Host", server.host_header("refresh"))
+        .json(&json!({
+            "grant_type": "refresh_token",
+            "refresh_token": first_refresh
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let refreshed: serde_json::Value = response.json().await?;
+
+    assert_eq!(refreshed["token_type"], "Bearer");
+    assert!(refreshed["access_token"].as_str().is_some());
+    assert_ne!(refresh_token_of(&refreshed)?, first_refresh);
+    Ok(())
+}
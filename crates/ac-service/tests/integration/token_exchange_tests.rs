@@ -0,0 +1,142 @@
+//! Integration tests for the token exchange grant (RFC 8693).
+//!
+//! Covers:
+//! - GC exchanging its service token and a user token for a delegated token
+//! - Introspection reporting the acting service
+//! - Missing tokens, wrong token types and undelegable scopes
+
+use ac_test_utils::server_harness::TestAuthServer;
+use reqwest::StatusCode;
+use serde_json::json;
+use sqlx::PgPool;
+
+const GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:token-exchange";
+const ACCESS_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:access_token";
+
+/// Log in as a fresh test user and return their access token.
+async fn user_token(server: &TestAuthServer) -> Result<String, anyhow::Error> {
+    let org_id = server.create_test_org("exchange", "Exchange Corp").await?;
+    server
+        .create_test_user(org_id, "host@example.com", "password123", "Meeting Host")
+        .await?;
+
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/user/token", server.url()))
+        .header("Host", server.host_header("exchange"))
+        .json(&json!({
+            "email": "host@example.com",
+            "password": "password123"
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let login: serde_json::Value = response.json().await?;
+    login["access_token"]
+        .as_str()
+        .map(String::from)
+        .ok_or_else(|| anyhow::anyhow!("login should return an access token"))
+}
+
+async fn exchange(
+    server: &TestAuthServer,
+    body: serde_json::Value,
+) -> Result<reqwest::Response, anyhow::Error> {
+    Ok(server
+        .client()
+        .post(format!("{}/api/v1/auth/service/token", server.url()))
+        .json(&body)
+        .send()
+        .await?)
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_token_exchange_issues_delegated_token(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let actor = server
+        .create_service_token("exchange-gc", &["delegated:meeting.moderate"])
+        .await?;
+    let subject = user_token(&server).await?;
+
+    let response = exchange(
+        &server,
+        json!({
+            "grant_type": GRANT_TYPE,
+            "subject_token": subject,
+            "subject_token_type": ACCESS_TOKEN_TYPE,
+            "actor_token": actor,
+            "actor_token_type": ACCESS_TOKEN_TYPE,
+            "scope": "delegated:meeting.moderate"
+        }),
+    )
+    .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["issued_token_type"], ACCESS_TOKEN_TYPE);
+    assert_eq!(body["token_type"], "Bearer");
+    assert_eq!(body["scope"], "delegated:meeting.moderate");
+    assert!(body.get("refresh_token").is_none());
+    let delegated = body["access_token"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("exchange should return an access token"))?;
+
+    let caller = server
+        .create_service_token("exchange-caller", &["internal:meeting-token"])
+        .await?;
+    let response = server
+        .client()
+        .post(format!("{}/api/v1/auth/introspect", server.url()))
+        .bearer_auth(&caller)
+        .json(&json!({ "token": delegated }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let introspection: serde_json::Value = response.json().await?;
+    assert_eq!(introspection["active"], true);
+    assert_eq!(introspection["act"]["sub"], "exchange-gc");
+    assert_eq!(introspection["act"]["service_type"], "global-controller");
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_token_exchange_rejects_bad_requests(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestAuthServer::spawn(pool).await?;
+    let actor = server
+        .create_service_token("exchange-gc", &["delegated:meeting.moderate"])
+        .await?;
+    let subject = user_token(&server).await?;
+
+    let valid = json!({
+        "grant_type": GRANT_TYPE,
+        "subject_token": subject,
+        "subject_token_type": ACCESS_TOKEN_TYPE,
+        "actor_token": actor,
+        "actor_token_type": ACCESS_TOKEN_TYPE,
+        "scope": "delegated:meeting.moderate"
+    });
+    let with = |key: &str, value: serde_json::Value| {
+        let mut body = valid.clone();
+        body[key] = value;
+        body
+    };
+
+    for body in [
+        with("actor_token", serde_json::Value::Null),
+        with(
+            "subject_token_type",
+            json!("urn:ietf:params:oauth:token-type:id_token"),
+        ),
+        with(
+            "requested_token_type",
+            json!("urn:ietf:params:oauth:token-type:refresh_token"),
+        ),
+        with("subject_token", json!(actor)),
+    ] {
+        let response = exchange(&server, body).await?;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    let response = exchange(&server, with("scope", json!("delegated:meeting.end"))).await?;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    Ok(())
+}
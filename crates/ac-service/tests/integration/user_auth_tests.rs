@@ -7,12 +7,14 @@
 //!
 //! - **Registration**: User self-registration flow
 //! - **Login**: User authentication flow
+//! - **TOTP**: Second-factor enrollment and login
 //! - **Org Extraction**: Subdomain-based organization identification
 //!
 //! ## Test Naming
 //!
 //! Tests follow the convention: `test_<feature>_<scenario>_<expected_result>`
 
+use ac_service::crypto::totp;
 use ac_test_utils::server_harness::TestAuthServer;
 use reqwest::StatusCode;
 use serde_json::json;
@@ -856,6 +858,225 @@ async fn test_login_rate_limit_lockout(pool: PgPool) -> Result<(), anyhow::Error
     Ok(())
 }
 
+// ============================================================================
+// TOTP Login Tests (3 tests)
+// ============================================================================
+
+/// POST `body` to `path` on the `totp` org.
+async fn post_totp_org(
+    server: &TestAuthServer,
+    path: &str,
+    body: serde_json::Value,
+) -> Result<reqwest::Response, anyhow::Error> {
+    Ok(server
+        .client()
+        .post(format!("{}{}", server.url(), path))
+        .header("Host", server.host_header("totp"))
+        .json(&body)
+        .send()
+        .await?)
+}
+
+/// Enroll and confirm TOTP for `email`, returning the decoded secret.
+async fn enable_totp(server: &TestAuthServer, email: &str) -> Result<Vec<u8>, anyhow::Error> {
+    let response = post_totp_org(
+        server,
+        "/api/v1/auth/user/totp",
+        json!({ "email": email, "password": "password123" }),
+    )
+    .await?;
+    assert_eq!(
+        response.status(),
+        StatusCode::OK,
+        "Enrollment should succeed"
+    );
+
+    let body: serde_json::Value = response.json().await?;
+    let encoded = body["secret"].as_str().expect("Should have secret");
+    assert!(body["otpauth_uri"]
+        .as_str()
+        .is_some_and(|uri| uri.starts_with("otpauth://totp/") && uri.contains(encoded)));
+    let secret = totp::decode_base32(encoded).expect("Secret should be base32");
+
+    let response = post_totp_org(
+        server,
+        "/api/v1/auth/user/totp/confirm",
+        json!({
+            "email": email,
+            "password": "password123",
+            "totp_code": totp::code_at_time(&secret, chrono::Utc::now().timestamp()),
+        }),
+    )
+    .await?;
+    assert_eq!(
+        response.status(),
+        StatusCode::NO_CONTENT,
+        "Confirmation should succeed"
+    );
+
+    Ok(secret)
+}
+
+/// Test that an enrolled user needs a TOTP code on both login endpoints,
+/// and that login succeeds with the next one.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_login_totp_enrolled_requires_code(pool: PgPool) -> Result<(), anyhow::Error> {
+    // Arrange
+    let server = TestAuthServer::spawn(pool).await?;
+    let org_id = server.create_test_org("totp", "TOTP Corp").await?;
+    server
+        .create_test_user(org_id, "totp@example.com", "password123", "TOTP User")
+        .await?;
+
+    // Password-only login works until TOTP is confirmed
+    let response = post_totp_org(
+        &server,
+        "/api/v1/auth/user/login",
+        json!({ "email": "totp@example.com", "password": "password123" }),
+    )
+    .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let secret = enable_totp(&server, "totp@example.com").await?;
+
+    // Act / Assert: password alone is no longer enough
+    for path in ["/api/v1/auth/user/login", "/api/v1/auth/user/token"] {
+        let response = post_totp_org(
+            &server,
+            path,
+            json!({ "email": "totp@example.com", "password": "password123" }),
+        )
+        .await?;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{path}");
+        let body: serde_json::Value = response.json().await?;
+        assert_eq!(
+            body["error"]["code"].as_str(),
+            Some("TOTP_REQUIRED"),
+            "{path}"
+        );
+    }
+
+    // The confirming code was consumed; the next step's code is accepted
+    let next = totp::code_at_time(&secret, chrono::Utc::now().timestamp() + totp::STEP_SECONDS);
+    let response = post_totp_org(
+        &server,
+        "/api/v1/auth/user/login",
+        json!({
+            "email": "totp@example.com",
+            "password": "password123",
+            "totp_code": next,
+        }),
+    )
+    .await?;
+    assert_eq!(
+        response.status(),
+        StatusCode::OK,
+        "Login with TOTP should succeed"
+    );
+    let body: serde_json::Value = response.json().await?;
+    assert!(body.get("access_token").is_some());
+
+    // The same code cannot be used twice
+    let response = post_totp_org(
+        &server,
+        "/api/v1/auth/user/login",
+        json!({
+            "email": "totp@example.com",
+            "password": "password123",
+            "totp_code": next,
+        }),
+    )
+    .await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["error"]["code"].as_str(), Some("INVALID_CREDENTIALS"));
+
+    Ok(())
+}
+
+/// Test that wrong TOTP codes count toward the login lockout.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_login_totp_wrong_code_locks_out(pool: PgPool) -> Result<(), anyhow::Error> {
+    // Arrange
+    let server = TestAuthServer::spawn(pool).await?;
+    let org_id = server.create_test_org("totp", "TOTP Corp").await?;
+    server
+        .create_test_user(org_id, "lockout@example.com", "password123", "Lockout User")
+        .await?;
+    let secret = enable_totp(&server, "lockout@example.com").await?;
+
+    // A code from an hour ago is outside the drift window
+    let stale = totp::code_at_time(&secret, chrono::Utc::now().timestamp() - 3600);
+    for i in 0..5 {
+        let response = post_totp_org(
+            &server,
+            "/api/v1/auth/user/login",
+            json!({
+                "email": "lockout@example.com",
+                "password": "password123",
+                "totp_code": stale,
+            }),
+        )
+        .await?;
+        assert_eq!(
+            response.status(),
+            StatusCode::UNAUTHORIZED,
+            "Failed attempt {} should return 401",
+            i + 1
+        );
+    }
+
+    // Act: a valid code after the lockout
+    let next = totp::code_at_time(&secret, chrono::Utc::now().timestamp() + totp::STEP_SECONDS);
+    let response = post_totp_org(
+        &server,
+        "/api/v1/auth/user/login",
+        json!({
+            "email": "lockout@example.com",
+            "password": "password123",
+            "totp_code": next,
+        }),
+    )
+    .await?;
+
+    // Assert
+    assert_eq!(
+        response.status(),
+        StatusCode::TOO_MANY_REQUESTS,
+        "Valid code should be blocked after lockout"
+    );
+
+    Ok(())
+}
+
+/// Test that confirming without a pending enrollment returns 404.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_totp_confirm_without_enrollment_not_found(pool: PgPool) -> Result<(), anyhow::Error> {
+    // Arrange
+    let server = TestAuthServer::spawn(pool).await?;
+    let org_id = server.create_test_org("totp", "TOTP Corp").await?;
+    server
+        .create_test_user(org_id, "none@example.com", "password123", "No TOTP")
+        .await?;
+
+    // Act
+    let response = post_totp_org(
+        &server,
+        "/api/v1/auth/user/totp/confirm",
+        json!({
+            "email": "none@example.com",
+            "password": "password123",
+            "totp_code": "123456",
+        }),
+    )
+    .await?;
+
+    // Assert
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
 // ============================================================================
 // Org Extraction Tests (4 tests)
 // ============================================================================
@@ -0,0 +1,170 @@
+//! E2E tests for admin bulk user import.
+//!
+//! Exercises `POST /api/v1/admin/orgs/{id}/users/import` with JSON and CSV
+//! bodies. Imports are all-or-nothing: any invalid record or already-taken
+//! email rejects the whole import.
+//!
+//! ## Test Naming
+//!
+//! Tests follow the convention: `test_<feature>_<scenario>_<expected_result>`
+
+use ac_test_utils::server_harness::TestAuthServer;
+use reqwest::StatusCode;
+use serde_json::json;
+use sqlx::PgPool;
+
+/// Count users in an organization.
+async fn count_users(pool: &PgPool, org_id: uuid::Uuid) -> Result<i64, anyhow::Error> {
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM users WHERE org_id = $1")
+        .bind(org_id)
+        .fetch_one(pool)
+        .await?;
+    Ok(count)
+}
+
+/// Test that imported users keep their password and can log in.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_user_import_json_happy_path(pool: PgPool) -> Result<(), anyhow::Error> {
+    // Arrange
+    let server = TestAuthServer::spawn(pool).await?;
+    let org_id = server.create_test_org("migrate", "Migrate Corp").await?;
+    let token = server
+        .create_service_token("admin-service", &["admin:services"])
+        .await?;
+    let password_hash = bcrypt::hash("password123", 4)?;
+
+    // Act
+    let response = server
+        .client()
+        .post(format!(
+            "{}/api/v1/admin/orgs/{}/users/import",
+            server.url(),
+            org_id
+        ))
+        .bearer_auth(&token)
+        .json(&json!({
+            "users": [
+                {
+                    "email": "alice@example.com",
+                    "display_name": "Alice",
+                    "password_hash": password_hash,
+                    "role": "org_admin"
+                },
+                {
+                    "email": "bob@example.com",
+                    "display_name": "Bob",
+                    "password_hash": password_hash
+                }
+            ]
+        }))
+        .send()
+        .await?;
+
+    // Assert
+    assert_eq!(response.status(), StatusCode::OK, "Import should succeed");
+
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["imported_users"].as_u64(), Some(2));
+    assert_eq!(
+        body["users"][0]["email"].as_str(),
+        Some("alice@example.com")
+    );
+    assert_eq!(body["users"][1]["email"].as_str(), Some("bob@example.com"));
+
+    let login = server
+        .client()
+        .post(format!("{}/api/v1/auth/user/token", server.url()))
+        .header("Host", server.host_header("migrate"))
+        .json(&json!({
+            "email": "alice@example.com",
+            "password": "password123"
+        }))
+        .send()
+        .await?;
+    assert_eq!(
+        login.status(),
+        StatusCode::OK,
+        "Imported user should log in with their original password"
+    );
+
+    Ok(())
+}
+
+/// Test that a taken email rejects the whole CSV import.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_user_import_csv_taken_email_creates_nothing(
+    pool: PgPool,
+) -> Result<(), anyhow::Error> {
+    // Arrange
+    let server = TestAuthServer::spawn(pool).await?;
+    let org_id = server.create_test_org("taken", "Taken Corp").await?;
+    server
+        .create_test_user(org_id, "existing@example.com", "password123", "Existing")
+        .await?;
+    let token = server
+        .create_service_token("admin-service", &["admin:services"])
+        .await?;
+    let password_hash = bcrypt::hash("password123", 4)?;
+    let csv = format!(
+        "email,display_name,password_hash,role\n\
+         new@example.com,New,{password_hash},\n\
+         existing@example.com,Existing,{password_hash},\n"
+    );
+
+    // Act
+    let response = server
+        .client()
+        .post(format!(
+            "{}/api/v1/admin/orgs/{}/users/import",
+            server.url(),
+            org_id
+        ))
+        .bearer_auth(&token)
+        .header("Content-Type", "text/csv")
+        .body(csv)
+        .send()
+        .await?;
+
+    // Assert
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = response.json().await?;
+    assert!(body["error"]["message"]
+        .as_str()
+        .unwrap_or_default()
+        .contains("existing@example.com"));
+    assert_eq!(
+        count_users(server.pool(), org_id).await?,
+        1,
+        "No user should be created when any email is taken"
+    );
+
+    Ok(())
+}
+
+/// Test that an unknown organization returns 404.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_user_import_unknown_org_not_found(pool: PgPool) -> Result<(), anyhow::Error> {
+    // Arrange
+    let server = TestAuthServer::spawn(pool).await?;
+    let token = server
+        .create_service_token("admin-service", &["admin:services"])
+        .await?;
+
+    // Act
+    let response = server
+        .client()
+        .post(format!(
+            "{}/api/v1/admin/orgs/{}/users/import",
+            server.url(),
+            uuid::Uuid::new_v4()
+        ))
+        .bearer_auth(&token)
+        .json(&json!({ "users": [] }))
+        .send()
+        .await?;
+
+    // Assert
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
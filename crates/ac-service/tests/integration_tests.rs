@@ -20,3 +20,33 @@ mod user_auth_tests;
 
 #[path = "integration/internal_token_tests.rs"]
 mod internal_token_tests;
+
+#[path = "integration/user_import_tests.rs"]
+mod user_import_tests;
+
+#[path = "integration/introspection_tests.rs"]
+mod introspection_tests;
+
+#[path = "integration/refresh_token_tests.rs"]
+mod refresh_token_tests;
+
+#[path = "integration/token_exchange_tests.rs"]
+mod token_exchange_tests;
+
+#[path = "integration/jwks_tests.rs"]
+mod jwks_tests;
+
+#[path = "integration/client_rate_limit_tests.rs"]
+mod client_rate_limit_tests;
+
+#[path = "integration/audit_events_tests.rs"]
+mod audit_events_tests;
+
+#[path = "integration/oidc_tests.rs"]
+mod oidc_tests;
+
+#[path = "integration/dpop_tests.rs"]
+mod dpop_tests;
+
+#[path = "integration/key_replication_tests.rs"]
+mod key_replication_tests;
@@ -32,6 +32,7 @@ fn make_claims(scope: &str) -> ac_service::crypto::Claims {
         iat: now,
         scope: scope.to_string(),
         service_type: Some("service".to_string()),
+        cnf: None,
     }
 }
 
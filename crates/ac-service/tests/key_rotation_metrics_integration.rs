@@ -132,6 +132,7 @@ async fn handle_rotate_keys_user_token_emits_status_error(pool: PgPool) {
         iat: now,
         scope: "service.rotate-keys.ac".to_string(),
         service_type: None, // <- this is what triggers the user-token rejection branch
+        cnf: None,
     };
     let token = test_common::jwt_fixtures::sign_service_token(&pool, &master_key, &claims).await;
 
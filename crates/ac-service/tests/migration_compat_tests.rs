@@ -0,0 +1,32 @@
+//! Rolling-deploy check: the previous AC release against HEAD migrations.
+//!
+//! Replays `tests/compat/release_queries.sql` (the statements the last
+//! released AC binary runs) against a database migrated to HEAD. A failure
+//! names the pinned query a new migration would break mid-deploy.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use common::migration_compat::{self, PinnedQueries};
+use sqlx::PgPool;
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn previous_release_queries_run_against_head_schema(pool: PgPool) {
+    let pinned = PinnedQueries::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/compat/release_queries.sql"
+    ))
+    .unwrap();
+    assert!(!pinned.queries.is_empty());
+
+    let problems = migration_compat::check(&pool, &pinned).await.unwrap();
+
+    assert!(
+        problems.is_empty(),
+        "HEAD migrations break the previous AC release:\n{}",
+        problems
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
@@ -273,12 +273,13 @@ async fn rate_limit_decision_allowed_emits_for_registration_gate(pool: PgPool) {
         state.config.registration_rate_limit_max_attempts,
         state.config.rate_limit_window_minutes,
         state.config.rate_limit_max_attempts,
+        state.config.token_ttls.user_seconds,
     )
     .await;
 
     // Successful registration emits 2 `allowed` decisions: one from the
     // registration gate (`user_service::register_user:91`) and one from
-    // the chained user-token issuance (`token_service::issue_user_token:242`,
+    // the chained user-token issuance (`token_service::issue_user_login_token`,
     // called at user_service.rs:148 for auto-login). Both are production
     // emissions; assert the cumulative count.
     snap.counter("ac_rate_limit_decisions_total")
@@ -350,6 +351,7 @@ async fn rate_limit_decision_rejected_emits_for_registration_gate(pool: PgPool)
         state.config.registration_rate_limit_max_attempts,
         state.config.rate_limit_window_minutes,
         state.config.rate_limit_max_attempts,
+        state.config.token_ttls.user_seconds,
     )
     .await;
 
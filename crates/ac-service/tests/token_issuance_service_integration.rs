@@ -39,12 +39,16 @@ async fn handle_service_token_success_emits_grant_type_client_credentials_status
     let result = handle_service_token(
         State(state),
         ConnectInfo(TEST_ADDR.parse::<SocketAddr>().unwrap()),
+        None,
         HeaderMap::new(),
         Json(ServiceTokenRequest {
             grant_type: "client_credentials".to_string(),
             client_id: Some("tok-svc-success".to_string()),
             client_secret: Some(TEST_CLIENT_SECRET.to_string().into()),
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         }),
     )
     .await;
@@ -77,12 +81,16 @@ async fn handle_service_token_invalid_grant_type_emits_status_error(pool: PgPool
     let _ = handle_service_token(
         State(state),
         ConnectInfo(TEST_ADDR.parse::<SocketAddr>().unwrap()),
+        None,
         HeaderMap::new(),
         Json(ServiceTokenRequest {
             grant_type: "password".to_string(), // Wrong grant_type
             client_id: Some("anything".to_string()),
             client_secret: Some("anything".to_string().into()),
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         }),
     )
     .await
@@ -113,12 +121,16 @@ async fn handle_service_token_bad_credentials_emits_status_error(pool: PgPool) {
     let _ = handle_service_token(
         State(state),
         ConnectInfo(TEST_ADDR.parse::<SocketAddr>().unwrap()),
+        None,
         HeaderMap::new(),
         Json(ServiceTokenRequest {
             grant_type: "client_credentials".to_string(),
             client_id: Some("tok-svc-bad-creds".to_string()),
             client_secret: Some("wrong-secret".to_string().into()),
             scope: None,
+            refresh_token: None,
+            issue_refresh_token: false,
+            exchange: Default::default(),
         }),
     )
     .await
@@ -69,6 +69,7 @@ async fn handle_user_token_success_emits_grant_type_password_status_success(pool
         Json(UserTokenRequest {
             email: "password-tok-user@example.com".to_string(),
             password: pwd.to_string().into(),
+            issue_refresh_token: false,
         }),
     )
     .await
@@ -138,6 +139,7 @@ async fn handle_user_token_bad_credentials_emits_grant_type_password_status_erro
         Json(UserTokenRequest {
             email: "nonexistent@example.com".to_string(),
             password: "anything".to_string().into(),
+            issue_refresh_token: false,
         }),
     )
     .await
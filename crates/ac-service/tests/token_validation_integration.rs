@@ -71,6 +71,7 @@ async fn verify_jwt_with_iat_too_far_future_emits_clock_skew(pool: PgPool) {
         iat: now + 3600, // 1 hour in the future, beyond 5-min skew
         scope: "service.write".to_string(),
         service_type: Some("service".to_string()),
+        cnf: None,
     };
     let master_key = ac_test_utils::crypto_fixtures::test_master_key();
     let token = sign_service_token(&pool, &master_key, &claims).await;
@@ -164,6 +165,7 @@ async fn verify_jwt_happy_path_does_not_emit_token_validations(pool: PgPool) {
         iat: now,
         scope: "service.write".to_string(),
         service_type: Some("service".to_string()),
+        cnf: None,
     };
     let master_key = ac_test_utils::crypto_fixtures::test_master_key();
     let token = sign_service_token(&pool, &master_key, &claims).await;
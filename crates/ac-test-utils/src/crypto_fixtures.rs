@@ -113,6 +113,14 @@ pub fn test_master_key() -> Vec<u8> {
     ]
 }
 
+/// Test key replication key (`AC_KEY_REPLICATION_KEY`)
+///
+/// Deterministic 32-byte AES-256-GCM key, distinct from [`test_master_key`]
+/// so tests catch a key exported under the wrong one.
+pub fn test_replication_key() -> Vec<u8> {
+    (0xa0..0xc0).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
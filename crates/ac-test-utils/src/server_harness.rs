@@ -1,21 +1,146 @@
 //! Test server harness for E2E testing
 //!
 //! Provides TestAuthServer for spawning real AC server instances in tests.
-
-use crate::crypto_fixtures::test_master_key;
-use ac_service::config::{Config, DEFAULT_BCRYPT_COST};
+//!
+//! A spawned server can also script failure modes (stale JWKS, failing
+//! token requests, added latency) so clients' retry and circuit-breaker
+//! logic can be tested without a chaos cluster. See [`FaultScript`].
+
+use crate::crypto_fixtures::{test_master_key, test_replication_key};
+use ac_service::config::{
+    ClientRateLimits, Config, MasterKeySource, MtlsConfig, TokenTtlPolicy, DEFAULT_BCRYPT_COST,
+};
 use ac_service::crypto;
+use ac_service::errors::AcError;
 use ac_service::handlers::auth_handler::AppState;
+use ac_service::models::Jwks;
 use ac_service::repositories::{service_credentials, signing_keys};
 use ac_service::routes;
+use ac_service::services::jwks_manager::JwksManagerActorHandle;
+use ac_service::services::secret_policy::SecretPolicy;
 use ac_service::services::{key_management_service, token_service};
+use axum::extract::{Request, State};
+use axum::http::header::{HeaderValue, CACHE_CONTROL};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
 use chrono::Utc;
 use common::secret::{ExposeSecret, SecretBox};
 use sqlx::PgPool;
 use std::net::SocketAddr;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
 use tokio::task::JoinHandle;
 
+/// JWKS endpoint path
+const JWKS_PATH: &str = "/.well-known/jwks.json";
+
+/// Endpoints that issue tokens, affected by [`FaultScript::fail_token_requests`]
+const TOKEN_PATHS: &[&str] = &[
+    "/api/v1/auth/service/token",
+    "/api/v1/auth/user/token",
+    "/api/v1/auth/internal/meeting-token",
+    "/api/v1/auth/internal/guest-token",
+];
+
+/// Scripted failure modes for a [`TestAuthServer`].
+///
+/// Faults apply from the next request on and stay until cleared:
+/// - **Stale JWKS**: the JWKS endpoint serves a fixed key set instead of
+///   the current keys.
+/// - **Token failures**: the next N token requests get a 500 in AC's error
+///   format without reaching the handler.
+/// - **Latency**: every request is delayed before it is handled.
+#[derive(Debug, Default)]
+pub struct FaultScript {
+    token_failures_remaining: AtomicU32,
+    latency_ms: AtomicU64,
+    jwks_override: Mutex<Option<Jwks>>,
+}
+
+impl FaultScript {
+    /// Fail the next `count` token requests with 500 Internal Server Error.
+    ///
+    /// Replaces any failures still pending.
+    pub fn fail_token_requests(&self, count: u32) {
+        self.token_failures_remaining.store(count, Ordering::SeqCst);
+    }
+
+    /// Number of scripted token failures not yet served.
+    pub fn token_failures_remaining(&self) -> u32 {
+        self.token_failures_remaining.load(Ordering::SeqCst)
+    }
+
+    /// Delay every request by `latency` (zero disables).
+    pub fn set_latency(&self, latency: Duration) {
+        self.latency_ms
+            .store(latency.as_millis() as u64, Ordering::SeqCst);
+    }
+
+    /// Serve `jwks` from the JWKS endpoint instead of the current keys.
+    pub fn serve_jwks(&self, jwks: Jwks) {
+        *self
+            .jwks_override
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(jwks);
+    }
+
+    /// Clear every scripted fault.
+    pub fn clear(&self) {
+        self.token_failures_remaining.store(0, Ordering::SeqCst);
+        self.latency_ms.store(0, Ordering::SeqCst);
+        *self
+            .jwks_override
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Take one scripted token failure, if any are pending.
+    fn take_token_failure(&self) -> bool {
+        self.token_failures_remaining
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+    }
+
+    fn jwks_override(&self) -> Option<Jwks> {
+        self.jwks_override
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+/// Middleware applying the [`FaultScript`] in front of the real routes.
+async fn inject_faults(
+    State(faults): State<Arc<FaultScript>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let latency_ms = faults.latency_ms.load(Ordering::SeqCst);
+    if latency_ms > 0 {
+        tokio::time::sleep(Duration::from_millis(latency_ms)).await;
+    }
+
+    let path = request.uri().path();
+
+    if TOKEN_PATHS.contains(&path) && faults.take_token_failure() {
+        return AcError::Internal.into_response();
+    }
+
+    if path == JWKS_PATH {
+        if let Some(jwks) = faults.jwks_override() {
+            let mut response = Json(jwks).into_response();
+            response
+                .headers_mut()
+                .insert(CACHE_CONTROL, HeaderValue::from_static("max-age=3600"));
+            return response;
+        }
+    }
+
+    next.run(request).await
+}
+
 /// Test harness for spawning Auth Controller server in E2E tests
 ///
 /// # Example
@@ -39,6 +164,8 @@ pub struct TestAuthServer {
     addr: SocketAddr,
     pool: PgPool,
     config: Config,
+    faults: Arc<FaultScript>,
+    jwks: JwksManagerActorHandle,
     _handle: JoinHandle<()>,
 }
 
@@ -57,6 +184,17 @@ impl TestAuthServer {
     /// * `Ok(TestAuthServer)` - Running server instance
     /// * `Err(anyhow::Error)` - If server spawn fails
     pub async fn spawn(pool: PgPool) -> Result<Self, anyhow::Error> {
+        Self::spawn_with_client_rate_limits(pool, ClientRateLimits::default()).await
+    }
+
+    /// Spawn a test server with custom per-client token endpoint limits
+    ///
+    /// Same as [`spawn`](Self::spawn), for tests that need to hit the
+    /// per-client rate limiter without sending the default 60 requests.
+    pub async fn spawn_with_client_rate_limits(
+        pool: PgPool,
+        client_rate_limits: ClientRateLimits,
+    ) -> Result<Self, anyhow::Error> {
         // Use test master key from crypto_fixtures
         let master_key = test_master_key();
 
@@ -69,8 +207,11 @@ impl TestAuthServer {
         let config = Config {
             database_url: String::new(), // Not used after connection established
             bind_address: "127.0.0.1:0".to_string(),
+            health_socket: None,
             master_key: SecretBox::new(Box::new(master_key.clone())),
+            master_key_source: MasterKeySource::Env,
             hash_secret: SecretBox::new(Box::new(master_key.clone())), // Use same as master_key for tests
+            key_replication_key: Some(SecretBox::new(Box::new(test_replication_key()))),
             otlp_endpoint: None,
             jwt_clock_skew_seconds: ac_service::config::DEFAULT_JWT_CLOCK_SKEW.as_secs() as i64,
             bcrypt_cost: DEFAULT_BCRYPT_COST,
@@ -80,13 +221,15 @@ impl TestAuthServer {
                 ac_service::config::DEFAULT_REGISTRATION_RATE_LIMIT_WINDOW_MINUTES,
             registration_rate_limit_max_attempts:
                 ac_service::config::DEFAULT_REGISTRATION_RATE_LIMIT_MAX_ATTEMPTS,
+            client_rate_limits,
+            token_ttls: TokenTtlPolicy::default(),
+            secret_policy: SecretPolicy::default(),
+            mtls: MtlsConfig::default(),
         };
 
         // Create application state
-        let state = Arc::new(AppState {
-            pool: pool.clone(),
-            config: config.clone(),
-        });
+        let state = Arc::new(AppState::new(pool.clone(), config.clone()));
+        let jwks = state.jwks.clone();
 
         // Initialize metrics recorder for test server
         // Note: This may fail if already installed in the test process.
@@ -102,8 +245,12 @@ impl TestAuthServer {
             }
         };
 
-        // Build routes using ac-service's real route builder
-        let app = routes::build_routes(state, metrics_handle);
+        // Build routes using ac-service's real route builder, behind the
+        // fault injection layer
+        let faults = Arc::new(FaultScript::default());
+        let app = routes::build_routes(state, metrics_handle).layer(
+            middleware::from_fn_with_state(faults.clone(), inject_faults),
+        );
 
         // Bind to random port
         let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
@@ -127,6 +274,8 @@ impl TestAuthServer {
             addr,
             pool,
             config,
+            faults,
+            jwks,
             _handle: handle,
         })
     }
@@ -151,6 +300,36 @@ impl TestAuthServer {
         &self.config
     }
 
+    /// Get the server's scripted failure modes
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// server.faults().fail_token_requests(3);
+    /// server.faults().set_latency(Duration::from_millis(500));
+    /// ```
+    pub fn faults(&self) -> &FaultScript {
+        &self.faults
+    }
+
+    /// Freeze the JWKS endpoint at the current key set
+    ///
+    /// Keys rotated in afterwards are not published until
+    /// `faults().clear()`, so tokens they sign carry an unknown `kid`.
+    pub async fn serve_stale_jwks(&self) -> Result<(), anyhow::Error> {
+        let jwks = key_management_service::get_jwks(&self.pool).await?;
+        self.faults.serve_jwks(jwks);
+        Ok(())
+    }
+
+    /// Reload the server's cached JWKS from the database
+    ///
+    /// Keys rotated directly in the database (not via `/internal/rotate-keys`)
+    /// are otherwise published at the next periodic refresh.
+    pub async fn refresh_jwks(&self) -> Result<(), anyhow::Error> {
+        self.jwks.refresh().await?;
+        Ok(())
+    }
+
     /// Create a service token with specified scopes
     ///
     /// Registers a test service credential and issues a token.
@@ -247,6 +426,7 @@ impl TestAuthServer {
             iat: now,
             scope: scopes.join(" "),
             service_type: None, // User token, not service token
+            cnf: None,
         };
 
         // Sign and return JWT
@@ -299,6 +479,7 @@ impl TestAuthServer {
             iat: iat_time,
             scope: scopes.join(" "),
             service_type: Some("service".to_string()),
+            cnf: None,
         };
 
         // Sign and return JWT
@@ -512,4 +693,71 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_scripted_token_failures(pool: PgPool) -> Result<(), anyhow::Error> {
+        let server = TestAuthServer::spawn(pool).await?;
+        server
+            .create_service_token("fault-client", &["meeting:create"])
+            .await?;
+        server.faults().fail_token_requests(2);
+
+        let request = serde_json::json!({
+            "grant_type": "client_credentials",
+            "client_id": "fault-client",
+            "client_secret": "test-secret-12345"
+        });
+        let url = format!("{}/api/v1/auth/service/token", server.url());
+
+        for _ in 0..2 {
+            let response = server.client().post(&url).json(&request).send().await?;
+            assert_eq!(response.status(), 500);
+            let body: serde_json::Value = response.json().await?;
+            assert_eq!(body["error"]["code"], "INTERNAL_ERROR");
+        }
+        assert_eq!(server.faults().token_failures_remaining(), 0);
+
+        let response = server.client().post(&url).json(&request).send().await?;
+        assert_eq!(response.status(), 200);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_stale_jwks_hides_rotated_key(pool: PgPool) -> Result<(), anyhow::Error> {
+        let server = TestAuthServer::spawn(pool).await?;
+        let jwks_url = format!("{}/.well-known/jwks.json", server.url());
+
+        server.serve_stale_jwks().await?;
+        let new_kid = key_management_service::rotate_signing_key(
+            server.pool(),
+            server.config().master_key.expose_secret(),
+            "test-cluster",
+        )
+        .await?;
+
+        let stale: Jwks = reqwest::get(&jwks_url).await?.json().await?;
+        assert!(!stale.keys.is_empty());
+        assert!(stale.keys.iter().all(|key| key.kid != new_kid));
+
+        server.faults().clear();
+        server.refresh_jwks().await?;
+        let fresh: Jwks = reqwest::get(&jwks_url).await?.json().await?;
+        assert!(fresh.keys.iter().any(|key| key.kid == new_kid));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_scripted_latency(pool: PgPool) -> Result<(), anyhow::Error> {
+        let server = TestAuthServer::spawn(pool).await?;
+        server.faults().set_latency(Duration::from_millis(200));
+
+        let start = std::time::Instant::now();
+        let response = reqwest::get(&format!("{}/health", server.url())).await?;
+        assert_eq!(response.status(), 200);
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        Ok(())
+    }
 }
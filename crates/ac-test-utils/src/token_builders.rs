@@ -3,23 +3,42 @@
 //! Provides fluent APIs for creating test tokens and requests.
 
 use chrono::{Duration, Utc};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
 use serde_json::json;
 
-/// Builder for creating test JWT claims
+/// Default `kid` header for tokens signed by [`TestTokenBuilder::sign`].
+pub const TEST_KEY_ID: &str = "test-key-01";
+
+/// Lifetime left on a token built with [`TestTokenBuilder::near_expiry`].
+pub const NEAR_EXPIRY_SECONDS: i64 = 5;
+
+/// Builder for creating test JWT claims and signed tokens
 ///
 /// # Example
 /// ```rust,ignore
-/// let token = TestTokenBuilder::new()
+/// let claims = TestTokenBuilder::new()
 ///     .for_user("alice")
 ///     .with_scope("scope-a scope-b")
 ///     .expires_in(3600)
 ///     .build();
+///
+/// // Signed service token whose signature fails verification
+/// let (_public_pem, private_pkcs8) = test_signing_key(1)?;
+/// let token = TestTokenBuilder::new()
+///     .for_user("global-controller")
+///     .with_service_type("global-controller")
+///     .with_kid("rotated-key")
+///     .with_tampered_signature()
+///     .sign(&private_pkcs8)?;
 /// ```
 pub struct TestTokenBuilder {
     sub: String,
     scope: String,
     exp: i64,
     iat: i64,
+    service_type: Option<String>,
+    kid: Option<String>,
+    tampered: bool,
 }
 
 impl TestTokenBuilder {
@@ -31,6 +50,9 @@ impl TestTokenBuilder {
             scope: "".to_string(),
             exp: (now + Duration::seconds(3600)).timestamp(),
             iat: now.timestamp(),
+            service_type: None,
+            kid: Some(TEST_KEY_ID.to_string()),
+            tampered: false,
         }
     }
 
@@ -46,26 +68,112 @@ impl TestTokenBuilder {
         self
     }
 
+    /// Mark the token as a service token of the given type
+    /// (e.g. `"global-controller"`, `"meeting-controller"`)
+    pub fn with_service_type(mut self, service_type: &str) -> Self {
+        self.service_type = Some(service_type.to_string());
+        self
+    }
+
+    /// Set the `kid` header used by [`sign`](Self::sign)
+    ///
+    /// Any string is accepted, including unknown, empty, or hostile values.
+    pub fn with_kid(mut self, kid: &str) -> Self {
+        self.kid = Some(kid.to_string());
+        self
+    }
+
+    /// Omit the `kid` header entirely
+    pub fn without_kid(mut self) -> Self {
+        self.kid = None;
+        self
+    }
+
     /// Set expiration in seconds from now
     pub fn expires_in(mut self, seconds: i64) -> Self {
         self.exp = (Utc::now() + Duration::seconds(seconds)).timestamp();
         self
     }
 
+    /// Expire [`NEAR_EXPIRY_SECONDS`] from now
+    ///
+    /// Still valid when built; useful for boundary and refresh tests.
+    pub fn near_expiry(self) -> Self {
+        self.expires_in(NEAR_EXPIRY_SECONDS)
+    }
+
+    /// Set the expiration to `seconds` ago, with `iat` an hour before that
+    pub fn expired(mut self, seconds: i64) -> Self {
+        let exp = Utc::now() - Duration::seconds(seconds);
+        self.exp = exp.timestamp();
+        self.iat = (exp - Duration::seconds(3600)).timestamp();
+        self
+    }
+
     /// Set issued-at timestamp
     pub fn issued_at(mut self, timestamp: i64) -> Self {
         self.iat = timestamp;
         self
     }
 
+    /// Corrupt the signature produced by [`sign`](Self::sign)
+    ///
+    /// Header and claims are untouched, so the token parses but fails
+    /// signature verification.
+    pub fn with_tampered_signature(mut self) -> Self {
+        self.tampered = true;
+        self
+    }
+
     /// Build the claims as a JSON value
-    pub fn build(self) -> serde_json::Value {
-        json!({
+    pub fn build(&self) -> serde_json::Value {
+        let mut claims = json!({
             "sub": self.sub,
             "scope": self.scope,
             "exp": self.exp,
             "iat": self.iat,
-        })
+        });
+        if let Some(service_type) = &self.service_type {
+            claims["service_type"] = json!(service_type);
+        }
+        claims
+    }
+
+    /// Sign the claims with an Ed25519 private key (PKCS#8 DER, as returned by
+    /// [`test_signing_key`](crate::crypto_fixtures::test_signing_key))
+    ///
+    /// Produces an EdDSA JWT with `typ: JWT` and the configured `kid`.
+    pub fn sign(&self, private_key_pkcs8: &[u8]) -> Result<String, jsonwebtoken::errors::Error> {
+        let mut header = Header::new(Algorithm::EdDSA);
+        header.typ = Some("JWT".to_string());
+        header.kid = self.kid.clone();
+
+        let token = encode(
+            &header,
+            &self.build(),
+            &EncodingKey::from_ed_der(private_key_pkcs8),
+        )?;
+
+        if self.tampered {
+            Ok(tamper_signature(&token))
+        } else {
+            Ok(token)
+        }
+    }
+}
+
+/// Change the first character of a JWT's signature segment.
+///
+/// The first base64url character holds the top six bits of the first
+/// signature byte, so the decoded signature always differs.
+fn tamper_signature(token: &str) -> String {
+    match token.rsplit_once('.') {
+        Some((signed_part, signature)) => {
+            let replacement = if signature.starts_with('A') { 'B' } else { 'A' };
+            let rest = signature.get(1..).unwrap_or_default();
+            format!("{signed_part}.{replacement}{rest}")
+        }
+        None => token.to_string(),
     }
 }
 
@@ -78,6 +186,9 @@ impl Default for TestTokenBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::crypto_fixtures::test_signing_key;
+    use common::jwt::{decode_ed25519_public_key_pem, extract_kid, ServiceClaims as Claims};
+    use jsonwebtoken::{decode, DecodingKey, Validation};
 
     #[test]
     fn test_builder_creates_valid_claims() {
@@ -95,5 +206,71 @@ mod tests {
     fn test_builder_default() {
         let claims = TestTokenBuilder::default().build();
         assert_eq!(claims["sub"], "test-subject");
+        assert!(claims.get("service_type").is_none());
+    }
+
+    fn verify(token: &str, public_pem: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+        let key_bytes = decode_ed25519_public_key_pem(public_pem).unwrap();
+        let validation = Validation::new(Algorithm::EdDSA);
+        decode::<Claims>(token, &DecodingKey::from_ed_der(&key_bytes), &validation)
+            .map(|data| data.claims)
+    }
+
+    #[test]
+    fn test_sign_service_token_verifies() {
+        let (public_pem, private_pkcs8) = test_signing_key(1).unwrap();
+        let token = TestTokenBuilder::new()
+            .for_user("gc-client")
+            .with_scope("meeting:create")
+            .with_service_type("global-controller")
+            .sign(&private_pkcs8)
+            .unwrap();
+
+        let claims = verify(&token, &public_pem).unwrap();
+        assert_eq!(claims.sub, "gc-client");
+        assert_eq!(claims.service_type.as_deref(), Some("global-controller"));
+        assert_eq!(extract_kid(&token).unwrap(), TEST_KEY_ID);
+    }
+
+    #[test]
+    fn test_sign_custom_and_missing_kid() {
+        let (_, private_pkcs8) = test_signing_key(1).unwrap();
+
+        let token = TestTokenBuilder::new()
+            .with_kid("rotated-away")
+            .sign(&private_pkcs8)
+            .unwrap();
+        assert_eq!(extract_kid(&token).unwrap(), "rotated-away");
+
+        let token = TestTokenBuilder::new()
+            .without_kid()
+            .sign(&private_pkcs8)
+            .unwrap();
+        assert!(extract_kid(&token).is_err());
+    }
+
+    #[test]
+    fn test_tampered_signature_fails_verification() {
+        let (public_pem, private_pkcs8) = test_signing_key(1).unwrap();
+        let token = TestTokenBuilder::new()
+            .with_tampered_signature()
+            .sign(&private_pkcs8)
+            .unwrap();
+
+        assert_eq!(token.split('.').count(), 3);
+        assert!(verify(&token, &public_pem).is_err());
+    }
+
+    #[test]
+    fn test_near_expiry_and_expired() {
+        let now = Utc::now().timestamp();
+
+        let claims = TestTokenBuilder::new().near_expiry().build();
+        let exp = claims["exp"].as_i64().unwrap();
+        assert!(exp > now && exp <= now + NEAR_EXPIRY_SECONDS + 1);
+
+        let claims = TestTokenBuilder::new().expired(60).build();
+        assert!(claims["exp"].as_i64().unwrap() < now);
+        assert!(claims["iat"].as_i64().unwrap() < claims["exp"].as_i64().unwrap());
     }
 }
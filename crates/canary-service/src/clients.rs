@@ -0,0 +1,286 @@
+//! HTTP clients for AC (user registration and login) and GC (meetings).
+//!
+//! Only the fields the canary needs are deserialized. Tokens and passwords
+//! are never logged; `Debug` output redacts them.
+
+use common::secret::{ExposeSecret, SecretString};
+use reqwest::{Client, Response};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A synthetic user the canary registered at startup.
+pub struct CanaryUser {
+    /// Registration email (`canary-<uuid>@canary.dev`).
+    pub email: String,
+    /// Display name shown to the other participant.
+    pub display_name: String,
+    password: SecretString,
+}
+
+impl std::fmt::Debug for CanaryUser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CanaryUser")
+            .field("email", &self.email)
+            .field("display_name", &self.display_name)
+            .field("password", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl CanaryUser {
+    /// Create a user with a unique email and a random password.
+    #[must_use]
+    pub fn generate(display_name: &str) -> Self {
+        Self {
+            email: format!("canary-{}@canary.dev", Uuid::new_v4()),
+            display_name: display_name.to_string(),
+            password: SecretString::from(format!("canary-{}", Uuid::new_v4())),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct RegisterRequest<'a> {
+    email: &'a str,
+    password: &'a str,
+    display_name: &'a str,
+}
+
+#[derive(Serialize)]
+struct LoginRequest<'a> {
+    email: &'a str,
+    password: &'a str,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+/// Client for AC user endpoints.
+///
+/// User endpoints identify the organization from the `Host` subdomain
+/// (ADR-0020), so every request carries `<subdomain>.<host>`.
+pub struct AcClient {
+    base_url: String,
+    host_header: String,
+    http: Client,
+}
+
+impl AcClient {
+    /// Create a client for the AC at `base_url`, acting in `org_subdomain`.
+    #[must_use]
+    pub fn new(http: Client, base_url: &str, org_subdomain: &str) -> Self {
+        Self {
+            base_url: base_url.to_string(),
+            host_header: org_host_header(base_url, org_subdomain),
+            http,
+        }
+    }
+
+    /// Register `user`. Returns the auto-login access token.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the HTTP or status failure.
+    pub async fn register(&self, user: &CanaryUser) -> Result<String, String> {
+        let response = self
+            .http
+            .post(format!("{}/api/v1/auth/register", self.base_url))
+            .header("Host", &self.host_header)
+            .json(&RegisterRequest {
+                email: &user.email,
+                password: user.password.expose_secret(),
+                display_name: &user.display_name,
+            })
+            .send()
+            .await
+            .map_err(|e| format!("register request failed: {e}"))?;
+        let token: TokenResponse = json_or_status(response, "register").await?;
+        Ok(token.access_token)
+    }
+
+    /// Log `user` in with their password. Returns a fresh access token.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the HTTP or status failure.
+    pub async fn login(&self, user: &CanaryUser) -> Result<String, String> {
+        let response = self
+            .http
+            .post(format!("{}/api/v1/auth/user/token", self.base_url))
+            .header("Host", &self.host_header)
+            .json(&LoginRequest {
+                email: &user.email,
+                password: user.password.expose_secret(),
+            })
+            .send()
+            .await
+            .map_err(|e| format!("login request failed: {e}"))?;
+        let token: TokenResponse = json_or_status(response, "login").await?;
+        Ok(token.access_token)
+    }
+}
+
+/// Build a Host header with the org subdomain: `http://ac:8082` + `devtest`
+/// gives `devtest.ac:8082`.
+fn org_host_header(base_url: &str, subdomain: &str) -> String {
+    let host = base_url
+        .strip_prefix("http://")
+        .or_else(|| base_url.strip_prefix("https://"))
+        .unwrap_or(base_url)
+        .trim_end_matches('/');
+    format!("{subdomain}.{host}")
+}
+
+#[derive(Serialize)]
+struct CreateMeetingRequest<'a> {
+    display_name: &'a str,
+    waiting_room_enabled: bool,
+}
+
+/// Meeting created by the canary host.
+#[derive(Debug, Deserialize)]
+pub struct CreatedMeeting {
+    /// Meeting UUID.
+    pub meeting_id: Uuid,
+    /// Join code.
+    pub meeting_code: String,
+}
+
+#[derive(Deserialize)]
+struct McAssignment {
+    webtransport_endpoint: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct JoinMeetingResponse {
+    token: String,
+    mc_assignment: McAssignment,
+}
+
+/// What a participant needs to join the assigned MC.
+pub struct GcJoin {
+    /// Meeting JWT for MC and MH.
+    pub meeting_token: String,
+    /// Assigned MC WebTransport URL.
+    pub mc_url: String,
+}
+
+impl std::fmt::Debug for GcJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcJoin")
+            .field("meeting_token", &"[REDACTED]")
+            .field("mc_url", &self.mc_url)
+            .finish()
+    }
+}
+
+/// Client for GC meeting endpoints.
+pub struct GcClient {
+    base_url: String,
+    http: Client,
+}
+
+impl GcClient {
+    /// Create a client for the GC at `base_url`.
+    #[must_use]
+    pub fn new(http: Client, base_url: &str) -> Self {
+        Self {
+            base_url: base_url.to_string(),
+            http,
+        }
+    }
+
+    /// Create a meeting without a waiting room, so the second participant is
+    /// admitted straight away.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the HTTP or status failure.
+    pub async fn create_meeting(&self, user_token: &str) -> Result<CreatedMeeting, String> {
+        let response = self
+            .http
+            .post(format!("{}/api/v1/meetings", self.base_url))
+            .bearer_auth(user_token)
+            .json(&CreateMeetingRequest {
+                display_name: "Canary",
+                waiting_room_enabled: false,
+            })
+            .send()
+            .await
+            .map_err(|e| format!("create meeting request failed: {e}"))?;
+        json_or_status(response, "create meeting").await
+    }
+
+    /// Join a meeting by code.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the HTTP or status failure, or of a missing
+    /// MC WebTransport endpoint.
+    pub async fn join_meeting(
+        &self,
+        meeting_code: &str,
+        user_token: &str,
+    ) -> Result<GcJoin, String> {
+        let response = self
+            .http
+            .get(format!("{}/api/v1/meetings/{meeting_code}", self.base_url))
+            .bearer_auth(user_token)
+            .send()
+            .await
+            .map_err(|e| format!("join request failed: {e}"))?;
+        let join: JoinMeetingResponse = json_or_status(response, "join").await?;
+        let mc_url = join
+            .mc_assignment
+            .webtransport_endpoint
+            .ok_or_else(|| "MC assignment has no WebTransport endpoint".to_string())?;
+        Ok(GcJoin {
+            meeting_token: join.token,
+            mc_url,
+        })
+    }
+}
+
+/// Deserialize a success response, or describe the failure status. Response
+/// bodies are not included: they can echo request data.
+async fn json_or_status<T: serde::de::DeserializeOwned>(
+    response: Response,
+    operation: &str,
+) -> Result<T, String> {
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("{operation} returned {}", status.as_u16()));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| format!("{operation} response invalid: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_org_host_header() {
+        assert_eq!(
+            org_host_header("http://localhost:8082", "devtest"),
+            "devtest.localhost:8082"
+        );
+        assert_eq!(
+            org_host_header("https://ac.example.com/", "acme"),
+            "acme.ac.example.com"
+        );
+    }
+
+    #[test]
+    fn test_canary_user_debug_redacts_password() {
+        let user = CanaryUser::generate("Canary Host");
+        let debug = format!("{user:?}");
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains(user.password.expose_secret()));
+        assert!(user.email.starts_with("canary-"));
+    }
+}
@@ -0,0 +1,255 @@
+//! Canary configuration.
+//!
+//! Configuration is loaded from environment variables. The canary holds no
+//! long-lived secrets: its two synthetic users are registered at startup with
+//! random passwords that never leave the process.
+
+use std::collections::HashMap;
+use std::env;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Default health and metrics endpoint bind address.
+pub const DEFAULT_HEALTH_BIND_ADDRESS: &str = "0.0.0.0:8090";
+
+/// Default organization subdomain the synthetic users register under.
+pub const DEFAULT_ORG_SUBDOMAIN: &str = "devtest";
+
+/// Default interval between canary runs in seconds.
+pub const DEFAULT_INTERVAL_SECONDS: u64 = 60;
+
+/// Minimum interval between canary runs in seconds. Each run issues two user
+/// tokens and creates a meeting, so tighter loops only add load.
+pub const MIN_INTERVAL_SECONDS: u64 = 10;
+
+/// Default per-stage timeout in seconds.
+pub const DEFAULT_STAGE_TIMEOUT_SECONDS: u64 = 10;
+
+/// Default number of media frames each participant sends per run.
+pub const DEFAULT_MEDIA_FRAMES: u32 = 50;
+
+/// Maximum number of media frames per run (10 seconds of audio at 20ms).
+pub const MAX_MEDIA_FRAMES: u32 = 500;
+
+/// Canary configuration.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Authentication Controller base URL (e.g., `http://localhost:8082`).
+    pub ac_url: String,
+
+    /// Global Controller base URL (e.g., `http://localhost:8080`).
+    pub gc_url: String,
+
+    /// Organization subdomain for user registration and login (default: "devtest").
+    pub org_subdomain: String,
+
+    /// Health and metrics bind addresses, comma-separated (default: "0.0.0.0:8090").
+    pub health_bind_address: String,
+
+    /// Time between the start of consecutive runs (default: 60s).
+    pub interval: Duration,
+
+    /// Timeout applied to each stage of a run (default: 10s).
+    pub stage_timeout: Duration,
+
+    /// Media frames each participant sends per run (default: 50).
+    pub media_frames: u32,
+
+    /// Skip TLS certificate validation for MC and MH WebTransport.
+    /// Dev clusters only: Kind uses self-signed certs.
+    pub insecure_tls: bool,
+}
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Missing required environment variable: {0}")]
+    MissingEnvVar(String),
+
+    #[error("Invalid configuration value: {0}")]
+    InvalidValue(String),
+}
+
+impl Config {
+    /// Load configuration from environment variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::MissingEnvVar` if a required variable is missing.
+    /// Returns `ConfigError::InvalidValue` if a value is invalid.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::from_vars(&env::vars().collect())
+    }
+
+    /// Load configuration from a `HashMap` (for testing).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::MissingEnvVar` if a required variable is missing.
+    /// Returns `ConfigError::InvalidValue` if a value is invalid.
+    pub fn from_vars(vars: &HashMap<String, String>) -> Result<Self, ConfigError> {
+        let ac_url = required_url(vars, "CANARY_AC_URL")?;
+        let gc_url = required_url(vars, "CANARY_GC_URL")?;
+
+        let org_subdomain = vars
+            .get("CANARY_ORG_SUBDOMAIN")
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_ORG_SUBDOMAIN.to_string());
+
+        let health_bind_address = vars
+            .get("CANARY_HEALTH_BIND_ADDRESS")
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_HEALTH_BIND_ADDRESS.to_string());
+
+        let interval_seconds = parse_or(vars, "CANARY_INTERVAL_SECONDS", DEFAULT_INTERVAL_SECONDS)?;
+        if interval_seconds < MIN_INTERVAL_SECONDS {
+            return Err(ConfigError::InvalidValue(format!(
+                "CANARY_INTERVAL_SECONDS must be at least {MIN_INTERVAL_SECONDS}, got {interval_seconds}"
+            )));
+        }
+
+        let stage_timeout_seconds = parse_or(
+            vars,
+            "CANARY_STAGE_TIMEOUT_SECONDS",
+            DEFAULT_STAGE_TIMEOUT_SECONDS,
+        )?;
+        if stage_timeout_seconds == 0 {
+            return Err(ConfigError::InvalidValue(
+                "CANARY_STAGE_TIMEOUT_SECONDS must be greater than 0".to_string(),
+            ));
+        }
+
+        let media_frames = parse_or(vars, "CANARY_MEDIA_FRAMES", DEFAULT_MEDIA_FRAMES)?;
+        if media_frames == 0 || media_frames > MAX_MEDIA_FRAMES {
+            return Err(ConfigError::InvalidValue(format!(
+                "CANARY_MEDIA_FRAMES must be between 1 and {MAX_MEDIA_FRAMES}, got {media_frames}"
+            )));
+        }
+
+        let insecure_tls = parse_or(vars, "CANARY_INSECURE_TLS", false)?;
+
+        Ok(Config {
+            ac_url,
+            gc_url,
+            org_subdomain,
+            health_bind_address,
+            interval: Duration::from_secs(interval_seconds),
+            stage_timeout: Duration::from_secs(stage_timeout_seconds),
+            media_frames,
+            insecure_tls,
+        })
+    }
+}
+
+fn required_url(vars: &HashMap<String, String>, name: &str) -> Result<String, ConfigError> {
+    let url = vars
+        .get(name)
+        .ok_or_else(|| ConfigError::MissingEnvVar(name.to_string()))?;
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(ConfigError::InvalidValue(format!(
+            "{name} must start with http:// or https://"
+        )));
+    }
+    Ok(url.trim_end_matches('/').to_string())
+}
+
+fn parse_or<T: std::str::FromStr>(
+    vars: &HashMap<String, String>,
+    name: &str,
+    default: T,
+) -> Result<T, ConfigError> {
+    match vars.get(name) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| ConfigError::InvalidValue(format!("{name} is not valid: {value}"))),
+        None => Ok(default),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn base_vars() -> HashMap<String, String> {
+        HashMap::from([
+            (
+                "CANARY_AC_URL".to_string(),
+                "http://localhost:8082".to_string(),
+            ),
+            (
+                "CANARY_GC_URL".to_string(),
+                "http://localhost:8080/".to_string(),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_from_vars_success_with_defaults() {
+        let config = Config::from_vars(&base_vars()).expect("Config should load successfully");
+
+        assert_eq!(config.ac_url, "http://localhost:8082");
+        assert_eq!(config.gc_url, "http://localhost:8080");
+        assert_eq!(config.org_subdomain, DEFAULT_ORG_SUBDOMAIN);
+        assert_eq!(config.health_bind_address, DEFAULT_HEALTH_BIND_ADDRESS);
+        assert_eq!(
+            config.interval,
+            Duration::from_secs(DEFAULT_INTERVAL_SECONDS)
+        );
+        assert_eq!(
+            config.stage_timeout,
+            Duration::from_secs(DEFAULT_STAGE_TIMEOUT_SECONDS)
+        );
+        assert_eq!(config.media_frames, DEFAULT_MEDIA_FRAMES);
+        assert!(!config.insecure_tls);
+    }
+
+    #[test]
+    fn test_from_vars_overrides() {
+        let mut vars = base_vars();
+        vars.insert("CANARY_INTERVAL_SECONDS".to_string(), "30".to_string());
+        vars.insert("CANARY_STAGE_TIMEOUT_SECONDS".to_string(), "5".to_string());
+        vars.insert("CANARY_MEDIA_FRAMES".to_string(), "10".to_string());
+        vars.insert("CANARY_INSECURE_TLS".to_string(), "true".to_string());
+        vars.insert("CANARY_ORG_SUBDOMAIN".to_string(), "acme".to_string());
+
+        let config = Config::from_vars(&vars).unwrap();
+
+        assert_eq!(config.interval, Duration::from_secs(30));
+        assert_eq!(config.stage_timeout, Duration::from_secs(5));
+        assert_eq!(config.media_frames, 10);
+        assert!(config.insecure_tls);
+        assert_eq!(config.org_subdomain, "acme");
+    }
+
+    #[test]
+    fn test_from_vars_missing_urls() {
+        for name in ["CANARY_AC_URL", "CANARY_GC_URL"] {
+            let mut vars = base_vars();
+            vars.remove(name);
+            assert!(matches!(
+                Config::from_vars(&vars),
+                Err(ConfigError::MissingEnvVar(var)) if var == name
+            ));
+        }
+    }
+
+    #[test]
+    fn test_from_vars_rejects_invalid_values() {
+        for (name, value) in [
+            ("CANARY_AC_URL", "localhost:8082"),
+            ("CANARY_INTERVAL_SECONDS", "5"),
+            ("CANARY_INTERVAL_SECONDS", "soon"),
+            ("CANARY_STAGE_TIMEOUT_SECONDS", "0"),
+            ("CANARY_MEDIA_FRAMES", "0"),
+            ("CANARY_MEDIA_FRAMES", "501"),
+            ("CANARY_INSECURE_TLS", "yes"),
+        ] {
+            let mut vars = base_vars();
+            vars.insert(name.to_string(), value.to_string());
+            assert!(
+                matches!(Config::from_vars(&vars), Err(ConfigError::InvalidValue(_))),
+                "{name}={value} should be rejected"
+            );
+        }
+    }
+}
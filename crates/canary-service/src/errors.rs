@@ -0,0 +1,101 @@
+//! Canary error types.
+//!
+//! Every failure is attributed to the run stage it happened in; the stage is
+//! the `failed_stage` label on `canary_runs_total`.
+
+use std::fmt;
+use thiserror::Error;
+
+/// A stage of a canary run, in execution order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Synthetic users obtain fresh tokens from AC.
+    Auth,
+    /// The host creates a meeting via GC.
+    CreateMeeting,
+    /// Both participants join via GC and receive meeting tokens.
+    GcJoin,
+    /// Both participants join the assigned MC over WebTransport.
+    McJoin,
+    /// Roster notification and ping/pong between participants and MC.
+    Signaling,
+    /// Both participants connect to an MH and run the media frame loop.
+    Media,
+}
+
+impl Stage {
+    /// All stages, in execution order.
+    pub const ALL: [Stage; 6] = [
+        Stage::Auth,
+        Stage::CreateMeeting,
+        Stage::GcJoin,
+        Stage::McJoin,
+        Stage::Signaling,
+        Stage::Media,
+    ];
+
+    /// Metric label representation.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Stage::Auth => "auth",
+            Stage::CreateMeeting => "create_meeting",
+            Stage::GcJoin => "gc_join",
+            Stage::McJoin => "mc_join",
+            Stage::Signaling => "signaling",
+            Stage::Media => "media",
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Canary run errors.
+#[derive(Debug, Error)]
+pub enum CanaryError {
+    #[error("{stage} stage failed: {reason}")]
+    StageFailed { stage: Stage, reason: String },
+
+    #[error("{stage} stage timed out")]
+    Timeout { stage: Stage },
+}
+
+impl CanaryError {
+    /// Create a stage failure.
+    pub fn failed(stage: Stage, reason: impl Into<String>) -> Self {
+        CanaryError::StageFailed {
+            stage,
+            reason: reason.into(),
+        }
+    }
+
+    /// The stage the run failed in.
+    #[must_use]
+    pub fn stage(&self) -> Stage {
+        match self {
+            CanaryError::StageFailed { stage, .. } | CanaryError::Timeout { stage } => *stage,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_display_names_stage() {
+        let err = CanaryError::failed(Stage::McJoin, "connection refused");
+        assert_eq!(err.to_string(), "mc_join stage failed: connection refused");
+        assert_eq!(err.stage(), Stage::McJoin);
+
+        let err = CanaryError::Timeout {
+            stage: Stage::Signaling,
+        };
+        assert_eq!(err.to_string(), "signaling stage timed out");
+        assert_eq!(err.stage(), Stage::Signaling);
+    }
+}
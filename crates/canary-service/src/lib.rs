@@ -0,0 +1,25 @@
+//! Synthetic Canary Service Library
+//!
+//! Continuously runs a two-participant meeting through the whole stack and
+//! exports the outcome as metrics, so a broken deploy is noticed within one
+//! run interval instead of at the next env-test run or user report.
+//!
+//! # Run Flow
+//!
+//! ```text
+//! Canary → AC: register (first run) / log in both synthetic users
+//! Canary → GC: create meeting, join as host and guest
+//! Canary → MC: WebTransport join (host, then guest), roster + ping/pong
+//! Canary → MH: WebTransport connect, send media frames
+//! ```
+//!
+//! Each stage is timed and has its own timeout; a run fails at the first
+//! failing stage and records that stage as `failed_stage`.
+
+pub mod clients;
+pub mod config;
+pub mod errors;
+pub mod media;
+pub mod observability;
+pub mod runner;
+pub mod signaling;
@@ -0,0 +1,142 @@
+//! Synthetic Canary
+//!
+//! Long-lived service that runs a synthetic meeting every
+//! `CANARY_INTERVAL_SECONDS` and exports the results on `/metrics`.
+//!
+//! # Startup Flow
+//!
+//! 1. Load configuration from environment
+//! 2. Initialize Prometheus metrics recorder (ADR-0011)
+//! 3. Start health HTTP server (liveness, metrics)
+//! 4. Run the canary loop until a shutdown signal
+
+use std::time::Duration;
+
+use axum::Router;
+use canary_service::config::Config;
+use canary_service::runner::Canary;
+use common::listen::{bind_tcp_listeners, parse_bind_addresses};
+use tokio::signal;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _log_level = common::log_level::init_tracing("canary_service=debug");
+
+    info!("Starting synthetic canary");
+
+    let config = Config::from_env().map_err(|e| {
+        error!("Failed to load configuration: {}", e);
+        e
+    })?;
+
+    info!(
+        ac_url = %config.ac_url,
+        gc_url = %config.gc_url,
+        org_subdomain = %config.org_subdomain,
+        health_bind_address = %config.health_bind_address,
+        interval_seconds = config.interval.as_secs(),
+        stage_timeout_seconds = config.stage_timeout.as_secs(),
+        media_frames = config.media_frames,
+        insecure_tls = config.insecure_tls,
+        "Configuration loaded successfully"
+    );
+
+    let prometheus_handle =
+        canary_service::observability::init_metrics_recorder().map_err(|e| {
+            error!(error = %e, "Failed to install Prometheus metrics recorder");
+            e
+        })?;
+
+    let shutdown_token = CancellationToken::new();
+
+    let health_addrs = parse_bind_addresses(&config.health_bind_address).map_err(|e| {
+        error!(error = %e, addr = %config.health_bind_address, "Invalid health bind address");
+        format!("Invalid health bind address: {e}")
+    })?;
+
+    let app = Router::new()
+        .route("/health", axum::routing::get(|| async { "OK" }))
+        .route(
+            "/metrics",
+            axum::routing::get(move || {
+                let handle = prometheus_handle.clone();
+                async move { handle.render() }
+            }),
+        );
+
+    let listeners = bind_tcp_listeners(&health_addrs).map_err(|e| {
+        error!(error = %e, addrs = ?health_addrs, "Failed to bind health server");
+        format!("Failed to bind health server: {e}")
+    })?;
+
+    for listener in listeners {
+        let app = app.clone();
+        let health_shutdown_token = shutdown_token.child_token();
+        tokio::spawn(async move {
+            let addr = listener.local_addr().ok();
+            info!(addr = ?addr, "Health server starting");
+            let server = axum::serve(listener, app).with_graceful_shutdown(async move {
+                health_shutdown_token.cancelled().await;
+            });
+            if let Err(e) = server.await {
+                error!(error = %e, "Health server failed");
+            }
+        });
+    }
+    info!(addrs = ?health_addrs, "Health server started");
+
+    let canary = Canary::new(config).map_err(|e| {
+        error!(error = %e, "Failed to create canary");
+        e
+    })?;
+    let canary_handle = tokio::spawn(canary.run(shutdown_token.child_token()));
+
+    shutdown_signal().await;
+    info!("Shutdown signal received, stopping canary...");
+    shutdown_token.cancel();
+
+    if tokio::time::timeout(Duration::from_secs(5), canary_handle)
+        .await
+        .is_err()
+    {
+        error!("Canary did not stop within 5s");
+    }
+
+    info!("Synthetic canary shutdown complete");
+    Ok(())
+}
+
+/// Wait for SIGINT or SIGTERM.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        #[expect(
+            clippy::expect_used,
+            reason = "Signal handler installation is critical - panic is appropriate if it fails"
+        )]
+        signal::ctrl_c()
+            .await
+            .expect("Failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        #[expect(
+            clippy::expect_used,
+            reason = "Signal handler installation is critical - panic is appropriate if it fails"
+        )]
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("Failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {}
+        () = terminate => {}
+    }
+}
@@ -0,0 +1,133 @@
+//! MH media over WebTransport.
+//!
+//! The first framed message on the bidi stream is an `MhConnectRequest`
+//! carrying the meeting JWT and the media token from the MC join (see MH's
+//! `webtransport/connection.rs`). The
+//! canary then sends audio frames at a 20ms cadence. MH does not forward
+//! media yet, so the loop checks that MH accepts the connection and keeps it
+//! open for the whole loop; receive-side checks belong with forwarding.
+
+use crate::signaling::encode_framed;
+use bytes::{BufMut, Bytes, BytesMut};
+use media_protocol::codec::encode_frame;
+use media_protocol::frame::{FrameFlags, FramePriority, FrameType, MediaFrame};
+use proto_gen::dark_tower::signaling::v1::{mh_client_message, MhClientMessage, MhConnectRequest};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use wtransport::endpoint::endpoint_side::Client;
+use wtransport::Endpoint;
+
+/// Audio frame cadence.
+const FRAME_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Synthetic payload size (a typical 20ms Opus frame).
+const FRAME_PAYLOAD_BYTES: usize = 80;
+
+/// How long to watch for MH closing the stream after the last frame.
+const CLOSE_GRACE: Duration = Duration::from_millis(200);
+
+/// Connect to the MH at `mh_url` and send `frames` audio frames as `user_id`.
+///
+/// # Errors
+///
+/// Returns a description of the connection or write failure, or of MH
+/// closing the stream (e.g. after rejecting the meeting JWT).
+pub async fn run_frame_loop(
+    endpoint: &Endpoint<Client>,
+    mh_url: &str,
+    meeting_token: &str,
+    media_token: &str,
+    user_id: u64,
+    frames: u32,
+) -> Result<(), String> {
+    let connection = endpoint
+        .connect(mh_url)
+        .await
+        .map_err(|e| format!("connect to MH: {e}"))?;
+    let (mut send, mut recv) = connection
+        .open_bi()
+        .await
+        .map_err(|e| format!("open stream: {e}"))?
+        .await
+        .map_err(|e| format!("open stream: {e}"))?;
+
+    let connect = MhClientMessage {
+        message: Some(mh_client_message::Message::ConnectRequest(
+            MhConnectRequest {
+                join_token: meeting_token.to_string(),
+                media_token: media_token.to_string(),
+            },
+        )),
+        trace_parent: String::new(),
+        trace_state: String::new(),
+    };
+    send.write_all(&encode_framed(&connect))
+        .await
+        .map_err(|e| format!("send MhConnectRequest: {e}"))?;
+
+    let mut ticker = tokio::time::interval(FRAME_INTERVAL);
+    for sequence in 0..u64::from(frames) {
+        ticker.tick().await;
+        let frame = audio_frame(user_id, sequence)?;
+        send.write_all(&frame)
+            .await
+            .map_err(|e| format!("send media frame {sequence}: {e}"))?;
+    }
+
+    // MH never writes on this stream; any read result means it closed it.
+    let mut probe = [0u8; 1];
+    match tokio::time::timeout(CLOSE_GRACE, recv.read(&mut probe)).await {
+        Err(_) => Ok(()),
+        Ok(_) => Err("MH closed the media stream".to_string()),
+    }
+}
+
+/// Encode one length-prefixed synthetic audio frame.
+fn audio_frame(user_id: u64, sequence: u64) -> Result<Vec<u8>, String> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_micros();
+    let frame = MediaFrame {
+        version: MediaFrame::VERSION,
+        user_id,
+        stream_id: 0,
+        frame_type: FrameType::Audio,
+        timestamp: u64::try_from(timestamp).unwrap_or(u64::MAX),
+        sequence,
+        flags: FrameFlags {
+            end_of_frame: true,
+            discardable: true,
+            priority: FramePriority::Critical,
+        },
+        key_epoch: 0,
+        payload: Bytes::from_static(&[0u8; FRAME_PAYLOAD_BYTES]),
+    };
+    let encoded = encode_frame(&frame).map_err(|e| format!("encode media frame: {e}"))?;
+
+    let mut buf = BytesMut::with_capacity(4 + encoded.len());
+    buf.put_u32(encoded.len() as u32);
+    buf.put_slice(&encoded);
+    Ok(buf.to_vec())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use media_protocol::codec::decode_frame;
+
+    #[test]
+    fn test_audio_frame_round_trips() {
+        let framed = audio_frame(42, 3).unwrap();
+
+        let len = u32::from_be_bytes(framed[..4].try_into().unwrap()) as usize;
+        assert_eq!(len, MediaFrame::HEADER_SIZE + FRAME_PAYLOAD_BYTES);
+
+        let frame = decode_frame(&mut &framed[4..]).unwrap();
+        assert_eq!(frame.user_id, 42);
+        assert_eq!(frame.sequence, 3);
+        assert_eq!(frame.frame_type, FrameType::Audio);
+        assert_eq!(frame.flags.priority, FramePriority::Critical);
+        assert_eq!(frame.payload.len(), FRAME_PAYLOAD_BYTES);
+    }
+}
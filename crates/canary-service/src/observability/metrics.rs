@@ -0,0 +1,79 @@
+//! Metrics definitions for the canary service per ADR-0011
+//!
+//! All metrics follow Prometheus naming conventions:
+//! - `canary_` prefix
+//! - `_total` suffix for counters
+//! - `_seconds` suffix for duration histograms
+//!
+//! # Cardinality
+//!
+//! - `status`: 2 values (success, failure)
+//! - `stage` / `failed_stage`: 6 stages (see `Stage`), plus `none` on success
+
+use crate::errors::Stage;
+use metrics::{counter, gauge, histogram};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Initialize Prometheus metrics recorder and return the handle
+/// for serving metrics via HTTP.
+///
+/// ADR-0011: Must be called before any metrics are recorded.
+///
+/// # Errors
+///
+/// Returns error if Prometheus recorder fails to install (e.g., already installed).
+pub fn init_metrics_recorder() -> Result<PrometheusHandle, String> {
+    PrometheusBuilder::new()
+        // Stage latency buckets - single HTTP calls up to the media frame loop
+        .set_buckets_for_metric(
+            Matcher::Prefix("canary_stage_duration".to_string()),
+            &[
+                0.010, 0.025, 0.050, 0.100, 0.250, 0.500, 1.000, 2.500, 5.000, 10.000,
+            ],
+        )
+        .map_err(|e| format!("Failed to set stage duration buckets: {e}"))?
+        // Run latency buckets - whole meeting lifecycle, dominated by media
+        .set_buckets_for_metric(
+            Matcher::Prefix("canary_run_duration".to_string()),
+            &[
+                0.250, 0.500, 1.000, 2.000, 5.000, 10.000, 20.000, 30.000, 60.000,
+            ],
+        )
+        .map_err(|e| format!("Failed to set run duration buckets: {e}"))?
+        .install_recorder()
+        .map_err(|e| format!("Failed to install Prometheus recorder: {e}"))
+}
+
+/// Record a successful stage.
+///
+/// Metric: `canary_stage_duration_seconds`
+/// Labels: `stage`
+/// Buckets: [0.010, 0.025, 0.050, 0.100, 0.250, 0.500, 1.000, 2.500, 5.000, 10.000]
+pub fn record_stage(stage: Stage, duration: Duration) {
+    histogram!("canary_stage_duration_seconds", "stage" => stage.as_str())
+        .record(duration.as_secs_f64());
+}
+
+/// Record a successful run.
+///
+/// Metrics: `canary_runs_total{status="success", failed_stage="none"}`,
+/// `canary_run_duration_seconds`, `canary_last_success_timestamp_seconds`
+pub fn record_run_success(duration: Duration) {
+    counter!("canary_runs_total", "status" => "success", "failed_stage" => "none").increment(1);
+    histogram!("canary_run_duration_seconds").record(duration.as_secs_f64());
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    gauge!("canary_last_success_timestamp_seconds").set(now.as_secs_f64());
+}
+
+/// Record a failed run.
+///
+/// Metric: `canary_runs_total{status="failure", failed_stage}`
+/// Cardinality: 6 (one per stage)
+pub fn record_run_failure(stage: Stage) {
+    counter!("canary_runs_total", "status" => "failure", "failed_stage" => stage.as_str())
+        .increment(1);
+}
@@ -0,0 +1,18 @@
+//! Observability module for the canary service
+//!
+//! Implements metrics per ADR-0011 (Observability Framework). The canary's
+//! metrics are its product: alerts and env-tests assert on them instead of
+//! driving a meeting themselves.
+//!
+//! # Metrics (ADR-0011)
+//!
+//! | Metric | Type | Labels | Purpose |
+//! |--------|------|--------|---------|
+//! | `canary_runs_total` | Counter | `status`, `failed_stage` | Run outcomes |
+//! | `canary_run_duration_seconds` | Histogram | none | End-to-end latency of successful runs |
+//! | `canary_stage_duration_seconds` | Histogram | `stage` | Latency of each successful stage |
+//! | `canary_last_success_timestamp_seconds` | Gauge | none | Unix time of the last successful run |
+
+pub mod metrics;
+
+pub use metrics::{init_metrics_recorder, record_run_failure, record_run_success, record_stage};
@@ -0,0 +1,227 @@
+//! Canary run loop.
+//!
+//! Each run drives one meeting through every service a real client touches:
+//!
+//! 1. `auth` - both synthetic users get fresh tokens from AC
+//! 2. `create_meeting` - the host creates a meeting via GC
+//! 3. `gc_join` - both users join via GC and get meeting tokens
+//! 4. `mc_join` - host, then guest, join the assigned MC over WebTransport
+//! 5. `signaling` - host sees the guest's `ParticipantJoined`; guest pings MC
+//! 6. `media` - both connect to an MH and run the media frame loop
+//!
+//! A run stops at the first failing stage. Each stage has its own timeout.
+
+use crate::clients::{AcClient, CanaryUser, GcClient};
+use crate::config::Config;
+use crate::errors::{CanaryError, Stage};
+use crate::media::run_frame_loop;
+use crate::observability::metrics;
+use crate::signaling::{client_endpoint, McSession};
+use std::future::Future;
+use std::time::Instant;
+use tokio::time::MissedTickBehavior;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use wtransport::endpoint::endpoint_side::Client;
+use wtransport::Endpoint;
+
+/// Synthetic meeting runner.
+pub struct Canary {
+    config: Config,
+    ac: AcClient,
+    gc: GcClient,
+    endpoint: Endpoint<Client>,
+    host: CanaryUser,
+    guest: CanaryUser,
+    registered: bool,
+    runs: u64,
+}
+
+impl Canary {
+    /// Create a canary with two fresh synthetic users. Users are registered
+    /// with AC on the first run.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the HTTP or WebTransport client setup failure.
+    pub fn new(config: Config) -> Result<Self, String> {
+        let http = reqwest::Client::builder()
+            .timeout(config.stage_timeout)
+            .build()
+            .map_err(|e| format!("create HTTP client: {e}"))?;
+        let endpoint = client_endpoint(config.insecure_tls)?;
+
+        Ok(Self {
+            ac: AcClient::new(http.clone(), &config.ac_url, &config.org_subdomain),
+            gc: GcClient::new(http, &config.gc_url),
+            endpoint,
+            host: CanaryUser::generate("Canary Host"),
+            guest: CanaryUser::generate("Canary Guest"),
+            registered: false,
+            runs: 0,
+            config,
+        })
+    }
+
+    /// Run until `cancel` fires, starting a run every configured interval.
+    pub async fn run(mut self, cancel: CancellationToken) {
+        let mut ticker = tokio::time::interval(self.config.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                () = cancel.cancelled() => break,
+                _ = ticker.tick() => {}
+            }
+
+            let start = Instant::now();
+            let result = tokio::select! {
+                () = cancel.cancelled() => break,
+                result = self.run_once() => result,
+            };
+
+            match result {
+                Ok(()) => {
+                    let duration = start.elapsed();
+                    metrics::record_run_success(duration);
+                    info!(
+                        target: "canary.runner",
+                        duration_ms = duration.as_millis(),
+                        "Canary run succeeded"
+                    );
+                }
+                Err(e) => {
+                    metrics::record_run_failure(e.stage());
+                    warn!(
+                        target: "canary.runner",
+                        stage = e.stage().as_str(),
+                        error = %e,
+                        "Canary run failed"
+                    );
+                }
+            }
+        }
+
+        info!(target: "canary.runner", "Canary stopped");
+    }
+
+    /// Drive one meeting through every stage.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first stage failure or timeout.
+    pub async fn run_once(&mut self) -> Result<(), CanaryError> {
+        self.runs += 1;
+
+        let (host_token, guest_token) = self.stage(Stage::Auth, self.authenticate()).await?;
+        if !self.registered {
+            self.registered = true;
+            info!(target: "canary.runner", "Synthetic users registered");
+        }
+
+        let meeting = self
+            .stage(Stage::CreateMeeting, self.gc.create_meeting(&host_token))
+            .await?;
+        let meeting_id = meeting.meeting_id.to_string();
+
+        let (host_join, guest_join) = self
+            .stage(Stage::GcJoin, async {
+                tokio::try_join!(
+                    self.gc.join_meeting(&meeting.meeting_code, &host_token),
+                    self.gc.join_meeting(&meeting.meeting_code, &guest_token),
+                )
+            })
+            .await?;
+
+        // Host joins first so it is in the roster when the guest arrives.
+        let (mut host_session, mut guest_session) = self
+            .stage(Stage::McJoin, async {
+                let host = McSession::join(
+                    &self.endpoint,
+                    &host_join.mc_url,
+                    &meeting_id,
+                    &host_join.meeting_token,
+                    &self.host.display_name,
+                )
+                .await?;
+                let guest = McSession::join(
+                    &self.endpoint,
+                    &guest_join.mc_url,
+                    &meeting_id,
+                    &guest_join.meeting_token,
+                    &self.guest.display_name,
+                )
+                .await?;
+                Ok((host, guest))
+            })
+            .await?;
+
+        let sequence = self.runs;
+        self.stage(Stage::Signaling, async {
+            let guest_id = guest_session.participant_id.clone();
+            tokio::try_join!(
+                host_session.wait_for_participant(&guest_id),
+                guest_session.ping(sequence),
+            )
+        })
+        .await?;
+
+        let frames = self.config.media_frames;
+        self.stage(Stage::Media, async {
+            let host_mh = host_session
+                .media_servers
+                .first()
+                .ok_or_else(|| "join response has no media servers".to_string())?;
+            let guest_mh = guest_session
+                .media_servers
+                .first()
+                .ok_or_else(|| "join response has no media servers".to_string())?;
+            tokio::try_join!(
+                run_frame_loop(
+                    &self.endpoint,
+                    host_mh,
+                    &host_join.meeting_token,
+                    &host_session.media_token,
+                    host_session.user_id,
+                    frames,
+                ),
+                run_frame_loop(
+                    &self.endpoint,
+                    guest_mh,
+                    &guest_join.meeting_token,
+                    &guest_session.media_token,
+                    guest_session.user_id,
+                    frames,
+                ),
+            )
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Register both users on the first run; log them in afterwards.
+    async fn authenticate(&self) -> Result<(String, String), String> {
+        if self.registered {
+            tokio::try_join!(self.ac.login(&self.host), self.ac.login(&self.guest))
+        } else {
+            tokio::try_join!(self.ac.register(&self.host), self.ac.register(&self.guest))
+        }
+    }
+
+    /// Run one stage under the stage timeout, recording its duration on
+    /// success.
+    async fn stage<T>(
+        &self,
+        stage: Stage,
+        work: impl Future<Output = Result<T, String>>,
+    ) -> Result<T, CanaryError> {
+        let start = Instant::now();
+        let output = tokio::time::timeout(self.config.stage_timeout, work)
+            .await
+            .map_err(|_| CanaryError::Timeout { stage })?
+            .map_err(|reason| CanaryError::failed(stage, reason))?;
+        metrics::record_stage(stage, start.elapsed());
+        Ok(output)
+    }
+}
@@ -0,0 +1,220 @@
+//! MC signaling over WebTransport.
+//!
+//! Wire format matches MC's `connection.rs`: one bidirectional stream
+//! carrying 4-byte big-endian length-prefixed protobuf messages.
+
+use bytes::{BufMut, BytesMut};
+use prost::Message;
+use proto_gen::dark_tower::signaling::v1::{
+    client_message, server_message, ClientMessage, JoinRequest, JoinResponse, Ping, ServerMessage,
+};
+use wtransport::endpoint::endpoint_side::Client;
+use wtransport::stream::{RecvStream, SendStream};
+use wtransport::{ClientConfig, Connection, Endpoint};
+
+/// Maximum accepted server message size (64KB, matching MC).
+const MAX_MESSAGE_SIZE: usize = 64 * 1024;
+
+/// Create a WebTransport client endpoint.
+///
+/// `insecure_tls` skips certificate validation for dev clusters with
+/// self-signed certs; otherwise the platform's root certificates are used.
+///
+/// # Errors
+///
+/// Returns a description of the socket failure.
+pub fn client_endpoint(insecure_tls: bool) -> Result<Endpoint<Client>, String> {
+    let builder = ClientConfig::builder().with_bind_default();
+    let config = if insecure_tls {
+        builder.with_no_cert_validation().build()
+    } else {
+        builder.with_native_certs().build()
+    };
+    Endpoint::client(config).map_err(|e| format!("create WebTransport client: {e}"))
+}
+
+/// Encode a protobuf message as a length-prefixed frame.
+#[must_use]
+pub fn encode_framed<M: Message>(msg: &M) -> Vec<u8> {
+    let encoded = msg.encode_to_vec();
+    let mut frame = BytesMut::with_capacity(4 + encoded.len());
+    frame.put_u32(encoded.len() as u32);
+    frame.put_slice(&encoded);
+    frame.to_vec()
+}
+
+/// Read one length-prefixed `ServerMessage`.
+///
+/// # Errors
+///
+/// Returns a description of the read or decode failure.
+pub async fn read_server_message(recv: &mut RecvStream) -> Result<ServerMessage, String> {
+    let mut len_buf = [0u8; 4];
+    recv.read_exact(&mut len_buf)
+        .await
+        .map_err(|e| format!("read message length: {e}"))?;
+
+    let len = u32::from_be_bytes(len_buf) as usize;
+    if len == 0 || len > MAX_MESSAGE_SIZE {
+        return Err(format!("invalid message length {len}"));
+    }
+
+    let mut buf = vec![0u8; len];
+    recv.read_exact(&mut buf)
+        .await
+        .map_err(|e| format!("read message body: {e}"))?;
+    ServerMessage::decode(buf.as_slice()).map_err(|e| format!("decode server message: {e}"))
+}
+
+fn envelope(message: client_message::Message) -> ClientMessage {
+    ClientMessage {
+        message: Some(message),
+        trace_parent: String::new(),
+        trace_state: String::new(),
+    }
+}
+
+/// A participant's signaling session with the MC.
+pub struct McSession {
+    // Held so the session stays open while the streams are in use.
+    _connection: Connection,
+    send: SendStream,
+    recv: RecvStream,
+    /// Participant ID assigned by the MC.
+    pub participant_id: String,
+    /// 8-byte user ID for media frames.
+    pub user_id: u64,
+    /// MH WebTransport URLs from the join response.
+    pub media_servers: Vec<String>,
+    /// Media token for MH connects (empty when MC does not mint them).
+    pub media_token: String,
+}
+
+impl McSession {
+    /// Connect to the MC and join the meeting.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the connection failure or of a non-join
+    /// response (e.g. an MC `ErrorMessage`).
+    pub async fn join(
+        endpoint: &Endpoint<Client>,
+        mc_url: &str,
+        meeting_id: &str,
+        meeting_token: &str,
+        participant_name: &str,
+    ) -> Result<Self, String> {
+        let connection = endpoint
+            .connect(mc_url)
+            .await
+            .map_err(|e| format!("connect to MC: {e}"))?;
+        let (mut send, mut recv) = connection
+            .open_bi()
+            .await
+            .map_err(|e| format!("open stream: {e}"))?
+            .await
+            .map_err(|e| format!("open stream: {e}"))?;
+
+        let join = envelope(client_message::Message::JoinRequest(JoinRequest {
+            meeting_id: meeting_id.to_string(),
+            join_token: meeting_token.to_string(),
+            participant_name: participant_name.to_string(),
+            capabilities: None,
+            correlation_id: String::new(),
+            binding_token: String::new(),
+            join_id: String::new(),
+        }));
+        send.write_all(&encode_framed(&join))
+            .await
+            .map_err(|e| format!("send JoinRequest: {e}"))?;
+
+        let response = read_server_message(&mut recv).await?;
+        let JoinResponse {
+            participant_id,
+            user_id,
+            media_servers,
+            media_token,
+            ..
+        } = match response.message {
+            Some(server_message::Message::JoinResponse(join)) => join,
+            Some(server_message::Message::Error(err)) => {
+                return Err(format!("MC rejected join: {}", err.dt_code));
+            }
+            _ => return Err("unexpected reply to JoinRequest".to_string()),
+        };
+
+        Ok(Self {
+            _connection: connection,
+            send,
+            recv,
+            participant_id,
+            user_id,
+            media_servers: media_servers
+                .into_iter()
+                .map(|server| server.media_handler_url)
+                .collect(),
+            media_token,
+        })
+    }
+
+    /// Wait until the MC announces `participant_id` joining. Other server
+    /// messages (roster updates, keepalives) are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the read failure.
+    pub async fn wait_for_participant(&mut self, participant_id: &str) -> Result<(), String> {
+        loop {
+            let msg = read_server_message(&mut self.recv).await?;
+            if let Some(server_message::Message::ParticipantJoined(joined)) = msg.message {
+                if joined
+                    .participant
+                    .is_some_and(|p| p.participant_id == participant_id)
+                {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Send a `Ping` and wait for the matching `Pong`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the send or read failure.
+    pub async fn ping(&mut self, sequence: u64) -> Result<(), String> {
+        let ping = envelope(client_message::Message::Ping(Ping { sequence }));
+        self.send
+            .write_all(&encode_framed(&ping))
+            .await
+            .map_err(|e| format!("send Ping: {e}"))?;
+        loop {
+            let msg = read_server_message(&mut self.recv).await?;
+            if let Some(server_message::Message::Pong(pong)) = msg.message {
+                if pong.sequence == sequence {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_framed_length_prefix() {
+        let msg = envelope(client_message::Message::Ping(Ping { sequence: 7 }));
+        let frame = encode_framed(&msg);
+
+        let len = u32::from_be_bytes(frame[..4].try_into().unwrap()) as usize;
+        assert_eq!(len, frame.len() - 4);
+        let decoded = ClientMessage::decode(&frame[4..]).unwrap();
+        assert!(matches!(
+            decoded.message,
+            Some(client_message::Message::Ping(Ping { sequence: 7 }))
+        ));
+    }
+}
@@ -0,0 +1,63 @@
+//! Integration coverage for the canary run metrics in
+//! `canary_service::observability::metrics`:
+//!
+//! - `canary_runs_total{status, failed_stage}` via [`record_run_success`] and
+//!   [`record_run_failure`]
+//! - `canary_run_duration_seconds` and `canary_last_success_timestamp_seconds`
+//!   via [`record_run_success`]
+//! - `canary_stage_duration_seconds{stage}` via [`record_stage`]
+//!
+//! The runner calls these wrappers directly, so the tests drive them directly
+//! rather than standing up AC/GC/MC/MH.
+
+use canary_service::errors::Stage;
+use canary_service::observability::metrics::{
+    record_run_failure, record_run_success, record_stage,
+};
+use common::observability::testing::MetricAssertion;
+use std::time::Duration;
+
+#[test]
+fn record_run_success_emits_counter_histogram_and_timestamp() {
+    let snap = MetricAssertion::snapshot();
+    record_run_success(Duration::from_millis(1500));
+
+    snap.counter("canary_runs_total")
+        .with_labels(&[("status", "success"), ("failed_stage", "none")])
+        .assert_delta(1);
+    snap.histogram("canary_run_duration_seconds")
+        .assert_observation_count(1);
+    snap.gauge("canary_last_success_timestamp_seconds")
+        .assert_value_in_range(1_700_000_000.0..=f64::MAX);
+}
+
+#[test]
+fn record_run_failure_labels_failed_stage_without_success_side_effects() {
+    let snap = MetricAssertion::snapshot();
+    record_run_failure(Stage::McJoin);
+
+    snap.counter("canary_runs_total")
+        .with_labels(&[("status", "failure"), ("failed_stage", "mc_join")])
+        .assert_delta(1);
+    snap.counter("canary_runs_total")
+        .with_labels(&[("status", "success"), ("failed_stage", "none")])
+        .assert_unobserved();
+    snap.histogram("canary_run_duration_seconds")
+        .assert_unobserved();
+    snap.gauge("canary_last_success_timestamp_seconds")
+        .assert_unobserved();
+}
+
+#[test]
+fn record_stage_emits_one_series_per_stage() {
+    let snap = MetricAssertion::snapshot();
+    for stage in Stage::ALL {
+        record_stage(stage, Duration::from_millis(25));
+    }
+
+    for stage in Stage::ALL {
+        snap.histogram("canary_stage_duration_seconds")
+            .with_labels(&[("stage", stage.as_str())])
+            .assert_observation_count(1);
+    }
+}
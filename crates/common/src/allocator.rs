@@ -0,0 +1,187 @@
+//! jemalloc statistics and heap-profile dumps (`jemalloc` feature).
+//!
+//! Services built with their `jemalloc` feature install jemalloc as the
+//! global allocator and mount [`allocator_router`]:
+//!
+//! ```text
+//! GET  /debug/allocator               -> allocator stats (bytes)
+//! POST /debug/allocator/heap-profile  -> jemalloc heap profile (jeprof format)
+//! ```
+//!
+//! `resident` tracks RSS far better than `allocated`; a large gap between
+//! the two points at fragmentation or retained pages rather than a leak.
+//! Heap profiles additionally need profiling enabled at startup with
+//! `_RJEM_MALLOC_CONF=prof:true,lg_prof_sample:19`; without it the dump
+//! endpoint returns 409. Compare two dumps taken some hours apart with
+//! `jeprof --base` to see which call sites are growing.
+//!
+//! Like the other `/debug/*` routes, the router is unauthenticated and must
+//! be mounted behind [`DEBUG_SCOPE`](crate::debug_auth::DEBUG_SCOPE).
+
+use axum::{
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Serialize;
+use std::ffi::CString;
+use std::path::PathBuf;
+use tikv_jemalloc_ctl::{epoch, raw, stats};
+
+/// Path of the allocator stats endpoint.
+pub const ALLOCATOR_PATH: &str = "/debug/allocator";
+
+/// Path of the heap-profile dump endpoint.
+pub const HEAP_PROFILE_PATH: &str = "/debug/allocator/heap-profile";
+
+/// jemalloc memory statistics, in bytes.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct AllocatorStats {
+    /// Bytes allocated by the application.
+    pub allocated: usize,
+    /// Bytes in active pages (allocated plus page-level fragmentation).
+    pub active: usize,
+    /// Bytes in physically resident pages; closest to RSS.
+    pub resident: usize,
+    /// Bytes in mapped extents.
+    pub mapped: usize,
+    /// Bytes retained (unmapped from use but not returned to the OS).
+    pub retained: usize,
+    /// Bytes used by jemalloc's own metadata.
+    pub metadata: usize,
+}
+
+impl AllocatorStats {
+    /// Refresh jemalloc's cached statistics and read them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the jemalloc `mallctl` error if a statistic cannot be read.
+    pub fn read() -> Result<Self, tikv_jemalloc_ctl::Error> {
+        // Stats are cached until the epoch advances
+        epoch::advance()?;
+        Ok(Self {
+            allocated: stats::allocated::read()?,
+            active: stats::active::read()?,
+            resident: stats::resident::read()?,
+            mapped: stats::mapped::read()?,
+            retained: stats::retained::read()?,
+            metadata: stats::metadata::read()?,
+        })
+    }
+}
+
+/// Router serving [`ALLOCATOR_PATH`] and [`HEAP_PROFILE_PATH`].
+#[must_use]
+pub fn allocator_router() -> Router {
+    Router::new()
+        .route(ALLOCATOR_PATH, get(get_allocator_stats))
+        .route(HEAP_PROFILE_PATH, post(dump_heap_profile))
+}
+
+async fn get_allocator_stats() -> Response {
+    match AllocatorStats::read() {
+        Ok(stats) => Json(stats).into_response(),
+        Err(e) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &format!("Failed to read allocator stats: {e}"),
+        ),
+    }
+}
+
+async fn dump_heap_profile() -> Response {
+    let path = std::env::temp_dir().join(format!(
+        "dt-heap-{}-{}.prof",
+        std::process::id(),
+        uuid::Uuid::new_v4()
+    ));
+
+    let dumped = tokio::task::spawn_blocking({
+        let path = path.clone();
+        move || write_heap_profile(path)
+    })
+    .await;
+
+    let result = match dumped {
+        Ok(Ok(())) => tokio::fs::read(&path).await.map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to read heap profile: {e}"),
+            )
+        }),
+        Ok(Err(e)) => Err((
+            StatusCode::CONFLICT,
+            format!("Heap profiling unavailable (start with _RJEM_MALLOC_CONF=prof:true): {e}"),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Heap profile task failed: {e}"),
+        )),
+    };
+    let _ = tokio::fs::remove_file(&path).await;
+
+    match result {
+        Ok(profile) => {
+            tracing::info!(
+                target: "common.allocator",
+                bytes = profile.len(),
+                "Heap profile dumped"
+            );
+            (
+                [(header::CONTENT_TYPE, "application/octet-stream")],
+                profile,
+            )
+                .into_response()
+        }
+        Err((status, message)) => error_response(status, &message),
+    }
+}
+
+/// Ask jemalloc to write a heap profile to `path`.
+fn write_heap_profile(path: PathBuf) -> Result<(), String> {
+    let path = CString::new(path.into_os_string().into_encoded_bytes())
+        .map_err(|e| format!("invalid profile path: {e}"))?;
+    // SAFETY: `prof.dump` takes a `const char *` naming the output file.
+    // `path` is NUL-terminated and outlives the call; jemalloc copies it.
+    unsafe { raw::write(b"prof.dump\0", path.as_ptr()) }.map_err(|e| e.to_string())
+}
+
+fn error_response(status: StatusCode, message: &str) -> Response {
+    let body = serde_json::json!({ "error": message });
+    (status, Json(body)).into_response()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_stats_read() {
+        let stats = AllocatorStats::read().unwrap();
+        assert!(stats.mapped >= stats.resident.min(stats.active));
+        assert!(stats.metadata > 0);
+    }
+
+    #[tokio::test]
+    async fn test_stats_endpoint() {
+        let response = allocator_router()
+            .oneshot(
+                http::Request::get(ALLOCATOR_PATH)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(body["resident"].is_u64());
+    }
+}
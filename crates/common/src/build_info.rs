@@ -0,0 +1,179 @@
+//! Build metadata and the `/version` endpoint.
+//!
+//! Each service captures a [`BuildInfo`] with [`build_info!`](crate::build_info!)
+//! and serves it from [`version_router`], so what is running in a cluster can
+//! be read without checking image tags:
+//!
+//! ```text
+//! GET /version -> {"service": "mc-service", "version": "0.1.0",
+//!                  "git_sha": "3f9c2e1", "build_time": "2026-10-16T09:12:00Z",
+//!                  "proto_schema": "9b1e4c0d2a7f3865", "features": ["jemalloc"]}
+//! ```
+//!
+//! `git_sha` and `build_time` come from the `DT_GIT_SHA` and `DT_BUILD_TIME`
+//! environment variables at compile time (the service Dockerfiles pass them as
+//! build args) and are `unknown` in local `cargo` builds.
+//!
+//! The router is unauthenticated, like `/health`: the metadata is the same for
+//! every pod built from the same commit and reveals nothing per-deployment.
+
+use axum::{extract::State, routing::get, Json, Router};
+use serde::Serialize;
+
+/// Path of the version endpoint.
+pub const VERSION_PATH: &str = "/version";
+
+/// Placeholder for metadata not provided at build time.
+pub const UNKNOWN: &str = "unknown";
+
+/// What a service binary was built from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    /// Service name, e.g. `gc-service`.
+    pub service: &'static str,
+    /// Crate version (`CARGO_PKG_VERSION`).
+    pub version: &'static str,
+    /// Commit the binary was built from.
+    pub git_sha: &'static str,
+    /// RFC 3339 build timestamp.
+    pub build_time: &'static str,
+    /// `proto_gen::SCHEMA_VERSION`; `None` for services without gRPC.
+    pub proto_schema: Option<&'static str>,
+    /// Enabled cargo features that change runtime behavior.
+    pub features: Vec<&'static str>,
+}
+
+impl BuildInfo {
+    /// Record the protobuf schema the binary was compiled against.
+    #[must_use]
+    pub fn with_proto_schema(mut self, proto_schema: &'static str) -> Self {
+        self.proto_schema = Some(proto_schema);
+        self
+    }
+}
+
+/// `value`, or [`UNKNOWN`] when unset or empty (Docker passes unset build
+/// args through as empty strings).
+#[must_use]
+pub fn or_unknown(value: Option<&'static str>) -> &'static str {
+    match value {
+        Some(value) if !value.is_empty() => value,
+        _ => UNKNOWN,
+    }
+}
+
+/// Capture the calling crate's [`BuildInfo`].
+///
+/// Expands in the service crate, so the version, build environment, and
+/// `cfg!(feature = ...)` checks are the service's own. List the features
+/// worth reporting; only the enabled ones are included:
+///
+/// ```ignore
+/// let build_info = common::build_info!("mh-service", features = ["jemalloc", "cpu-profiling"])
+///     .with_proto_schema(proto_gen::SCHEMA_VERSION);
+/// ```
+#[macro_export]
+macro_rules! build_info {
+    ($service:expr $(, features = [$($feature:literal),* $(,)?])? $(,)?) => {
+        $crate::build_info::BuildInfo {
+            service: $service,
+            version: env!("CARGO_PKG_VERSION"),
+            git_sha: $crate::build_info::or_unknown(option_env!("DT_GIT_SHA")),
+            build_time: $crate::build_info::or_unknown(option_env!("DT_BUILD_TIME")),
+            proto_schema: None,
+            features: {
+                #[allow(unused_mut)]
+                let mut features: Vec<&'static str> = Vec::new();
+                $($(
+                    if cfg!(feature = $feature) {
+                        features.push($feature);
+                    }
+                )*)?
+                features
+            },
+        }
+    };
+}
+
+/// Router serving [`VERSION_PATH`].
+#[must_use]
+pub fn version_router(info: BuildInfo) -> Router {
+    Router::new()
+        .route(VERSION_PATH, get(get_version))
+        .with_state(info)
+}
+
+async fn get_version(State(info): State<BuildInfo>) -> Json<BuildInfo> {
+    Json(info)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_or_unknown() {
+        assert_eq!(or_unknown(None), UNKNOWN);
+        assert_eq!(or_unknown(Some("")), UNKNOWN);
+        assert_eq!(or_unknown(Some("3f9c2e1")), "3f9c2e1");
+    }
+
+    #[test]
+    fn test_macro_captures_calling_crate() {
+        let info = crate::build_info!("common", features = ["jemalloc", "fault-injection"]);
+
+        assert_eq!(info.service, "common");
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.proto_schema, None);
+        assert_eq!(
+            info.features.contains(&"jemalloc"),
+            cfg!(feature = "jemalloc")
+        );
+
+        let info = crate::build_info!("common").with_proto_schema("abc123");
+        assert!(info.features.is_empty());
+        assert_eq!(info.proto_schema, Some("abc123"));
+    }
+
+    #[tokio::test]
+    async fn test_version_endpoint() {
+        let info = BuildInfo {
+            service: "gc-service",
+            version: "0.1.0",
+            git_sha: "3f9c2e1",
+            build_time: UNKNOWN,
+            proto_schema: Some("abc123"),
+            features: vec!["nats"],
+        };
+
+        let response = version_router(info)
+            .oneshot(
+                http::Request::builder()
+                    .uri(VERSION_PATH)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::OK);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "service": "gc-service",
+                "version": "0.1.0",
+                "git_sha": "3f9c2e1",
+                "build_time": "unknown",
+                "proto_schema": "abc123",
+                "features": ["nats"],
+            })
+        );
+    }
+}
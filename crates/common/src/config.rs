@@ -1,6 +1,157 @@
 //! Common configuration types for Dark Tower components.
+//!
+//! # Profiles
+//!
+//! `DT_PROFILE` names a [`ConfigProfile`] (`dev`, `staging`, `prod`) that
+//! supplies environment-appropriate defaults. Layering, lowest to highest
+//! precedence:
+//!
+//! 1. Built-in defaults in each service's `Config::from_vars`
+//! 2. The profile's presets for that service (see [`ConfigProfile::layer`])
+//! 3. Environment variables
+//!
+//! `prod` has no presets, so leaving `DT_PROFILE` unset keeps every service
+//! exactly as configured by its environment. `dev` presets point at the
+//! local stack (`docker-compose.yml`, `scripts/generate-dev-certs.sh`), so a
+//! service boots with no other variables set.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Environment variable naming the [`ConfigProfile`].
+pub const PROFILE_ENV_VAR: &str = "DT_PROFILE";
+
+/// Environment variable overriding the profile's [`LogFormat`].
+pub const LOG_FORMAT_ENV_VAR: &str = "DT_LOG_FORMAT";
+
+/// Deployment environment whose defaults a service starts from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConfigProfile {
+    /// Local development: presets for the local stack, text logs, no drain.
+    Dev,
+    /// Shared pre-production: JSON logs, short drain.
+    Staging,
+    /// Production: no presets; everything comes from the environment.
+    #[default]
+    Prod,
+}
+
+impl ConfigProfile {
+    /// `DT_PROFILE` representation.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ConfigProfile::Dev => "dev",
+            ConfigProfile::Staging => "staging",
+            ConfigProfile::Prod => "prod",
+        }
+    }
+
+    /// Parse a `DT_PROFILE` value.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "dev" => Some(ConfigProfile::Dev),
+            "staging" => Some(ConfigProfile::Staging),
+            "prod" => Some(ConfigProfile::Prod),
+            _ => None,
+        }
+    }
+
+    /// Read the profile from `DT_PROFILE` in `vars` (default: `prod`).
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the problem if `DT_PROFILE` is set to an
+    /// unknown profile.
+    pub fn from_vars(vars: &HashMap<String, String>) -> Result<Self, String> {
+        match vars.get(PROFILE_ENV_VAR) {
+            Some(value) => Self::parse(value).ok_or_else(|| {
+                format!("{PROFILE_ENV_VAR} must be dev, staging, or prod, got '{value}'")
+            }),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Read the profile from the process environment.
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigProfile::from_vars`].
+    pub fn from_env() -> Result<Self, String> {
+        Self::from_vars(&std::env::vars().collect())
+    }
+
+    /// Default graceful-shutdown drain, before `*_DRAIN_SECONDS`.
+    #[must_use]
+    pub fn drain_seconds(self) -> u64 {
+        match self {
+            ConfigProfile::Dev => 0,
+            ConfigProfile::Staging => 5,
+            ConfigProfile::Prod => 30,
+        }
+    }
+
+    /// Default log format, before `DT_LOG_FORMAT`.
+    #[must_use]
+    pub fn log_format(self) -> LogFormat {
+        match self {
+            ConfigProfile::Dev => LogFormat::Text,
+            ConfigProfile::Staging | ConfigProfile::Prod => LogFormat::Json,
+        }
+    }
+
+    /// Layer `presets` under `vars`: each preset applies only where `vars`
+    /// does not set the variable. An empty value in `vars` still counts as
+    /// set.
+    #[must_use]
+    pub fn layer(
+        presets: &[(&str, &str)],
+        vars: &HashMap<String, String>,
+    ) -> HashMap<String, String> {
+        let mut layered = vars.clone();
+        for (name, value) in presets {
+            layered
+                .entry((*name).to_string())
+                .or_insert_with(|| (*value).to_string());
+        }
+        layered
+    }
+}
+
+impl fmt::Display for ConfigProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Log output format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// One JSON object per line, parsed by Promtail.
+    Json,
+    /// Human-readable single-line text.
+    Text,
+}
+
+impl LogFormat {
+    /// Resolve the format from `DT_LOG_FORMAT`, falling back to the
+    /// `DT_PROFILE` default.
+    ///
+    /// Never fails: unknown values fall back to JSON, because logging is
+    /// set up before configuration errors can be reported.
+    #[must_use]
+    pub fn from_vars(vars: &HashMap<String, String>) -> Self {
+        match vars.get(LOG_FORMAT_ENV_VAR).map(String::as_str) {
+            Some("text") => LogFormat::Text,
+            Some(_) => LogFormat::Json,
+            None => ConfigProfile::from_vars(vars)
+                .map(ConfigProfile::log_format)
+                .unwrap_or(LogFormat::Json),
+        }
+    }
+}
 
 /// Database configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,3 +181,69 @@ pub struct ObservabilityConfig {
     /// Enable JSON-formatted logs
     pub json_logs: bool,
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_profile_from_vars() {
+        assert_eq!(
+            ConfigProfile::from_vars(&HashMap::new()).unwrap(),
+            ConfigProfile::Prod
+        );
+        for profile in [
+            ConfigProfile::Dev,
+            ConfigProfile::Staging,
+            ConfigProfile::Prod,
+        ] {
+            let vars = vars(&[(PROFILE_ENV_VAR, profile.as_str())]);
+            assert_eq!(ConfigProfile::from_vars(&vars).unwrap(), profile);
+        }
+        assert!(ConfigProfile::from_vars(&vars(&[(PROFILE_ENV_VAR, "local")])).is_err());
+    }
+
+    #[test]
+    fn test_layer_env_overrides_presets() {
+        let presets = [
+            ("REDIS_URL", "redis://localhost:6379"),
+            ("MC_REGION", "dev"),
+        ];
+        let layered = ConfigProfile::layer(
+            &presets,
+            &vars(&[("MC_REGION", "eu-west-1"), ("MC_POOL", "")]),
+        );
+
+        assert_eq!(layered["REDIS_URL"], "redis://localhost:6379");
+        assert_eq!(layered["MC_REGION"], "eu-west-1");
+        assert_eq!(layered["MC_POOL"], "");
+    }
+
+    #[test]
+    fn test_log_format() {
+        assert_eq!(LogFormat::from_vars(&HashMap::new()), LogFormat::Json);
+        assert_eq!(
+            LogFormat::from_vars(&vars(&[(PROFILE_ENV_VAR, "dev")])),
+            LogFormat::Text
+        );
+        assert_eq!(
+            LogFormat::from_vars(&vars(&[
+                (PROFILE_ENV_VAR, "dev"),
+                (LOG_FORMAT_ENV_VAR, "json")
+            ])),
+            LogFormat::Json
+        );
+        assert_eq!(
+            LogFormat::from_vars(&vars(&[(LOG_FORMAT_ENV_VAR, "text")])),
+            LogFormat::Text
+        );
+    }
+}
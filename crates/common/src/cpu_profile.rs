@@ -0,0 +1,219 @@
+//! On-demand CPU profiling (`cpu-profiling` feature).
+//!
+//! [`cpu_profile_router`] serves a Go-style profiling endpoint backed by the
+//! `pprof` crate's sampling profiler:
+//!
+//! ```text
+//! GET /debug/pprof/profile?seconds=30&format=pprof       -> pprof protobuf
+//! GET /debug/pprof/profile?seconds=10&format=flamegraph  -> SVG flamegraph
+//! ```
+//!
+//! The profiler samples every thread at [`SAMPLE_FREQUENCY_HZ`] for the
+//! requested duration, so only one profile runs at a time; concurrent
+//! requests get 409. The protobuf output loads directly into
+//! `go tool pprof` or any pprof-compatible viewer.
+//!
+//! Services only compile this in with their `cpu-profiling` feature, and the
+//! router must be mounted behind [`DEBUG_SCOPE`](crate::debug_auth::DEBUG_SCOPE).
+
+use axum::{
+    extract::Query,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use pprof::protos::Message;
+use serde::Deserialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// Path of the CPU profile endpoint.
+pub const CPU_PROFILE_PATH: &str = "/debug/pprof/profile";
+
+/// Sampling frequency. Slightly off 100 Hz so samples do not lock step with
+/// periodic work.
+pub const SAMPLE_FREQUENCY_HZ: i32 = 99;
+
+/// Profile length when the request does not give one.
+const DEFAULT_SECONDS: u64 = 30;
+
+/// Longest profile a single request may ask for.
+const MAX_SECONDS: u64 = 120;
+
+/// Set while a profile is being collected.
+static PROFILING: AtomicBool = AtomicBool::new(false);
+
+/// Output format of a profile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProfileFormat {
+    /// pprof protobuf (`profile.proto`).
+    #[default]
+    Pprof,
+    /// SVG flamegraph.
+    Flamegraph,
+}
+
+/// Query parameters of [`CPU_PROFILE_PATH`].
+#[derive(Debug, Default, Deserialize)]
+struct ProfileQuery {
+    seconds: Option<u64>,
+    #[serde(default)]
+    format: ProfileFormat,
+}
+
+impl ProfileQuery {
+    /// Requested duration, defaulted and clamped to `1..=MAX_SECONDS`.
+    fn duration(&self) -> Duration {
+        Duration::from_secs(
+            self.seconds
+                .unwrap_or(DEFAULT_SECONDS)
+                .clamp(1, MAX_SECONDS),
+        )
+    }
+}
+
+/// Router serving [`CPU_PROFILE_PATH`].
+#[must_use]
+pub fn cpu_profile_router() -> Router {
+    Router::new().route(CPU_PROFILE_PATH, get(cpu_profile))
+}
+
+async fn cpu_profile(Query(query): Query<ProfileQuery>) -> Response {
+    let Some(_busy) = ProfilingSlot::acquire() else {
+        return error_response(
+            StatusCode::CONFLICT,
+            "A CPU profile is already being collected",
+        );
+    };
+
+    let duration = query.duration();
+    let format = query.format;
+    tracing::info!(
+        target: "common.cpu_profile",
+        seconds = duration.as_secs(),
+        format = ?format,
+        "CPU profile started"
+    );
+
+    // The profiler guard is not `Send`, so hold it on a blocking thread
+    let collected = tokio::task::spawn_blocking(move || collect(duration, format)).await;
+
+    match collected {
+        Ok(Ok(body)) => {
+            tracing::info!(
+                target: "common.cpu_profile",
+                bytes = body.len(),
+                "CPU profile finished"
+            );
+            let content_type = match format {
+                ProfileFormat::Pprof => "application/octet-stream",
+                ProfileFormat::Flamegraph => "image/svg+xml",
+            };
+            ([(header::CONTENT_TYPE, content_type)], body).into_response()
+        }
+        Ok(Err(message)) => error_response(StatusCode::INTERNAL_SERVER_ERROR, &message),
+        Err(e) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &format!("CPU profile task failed: {e}"),
+        ),
+    }
+}
+
+/// Sample for `duration` and render the report.
+fn collect(duration: Duration, format: ProfileFormat) -> Result<Vec<u8>, String> {
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(SAMPLE_FREQUENCY_HZ)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+        .map_err(|e| format!("Failed to start profiler: {e}"))?;
+
+    std::thread::sleep(duration);
+
+    let report = guard
+        .report()
+        .build()
+        .map_err(|e| format!("Failed to build profile report: {e}"))?;
+
+    let mut body = Vec::new();
+    match format {
+        ProfileFormat::Pprof => {
+            let profile = report
+                .pprof()
+                .map_err(|e| format!("Failed to encode pprof profile: {e}"))?;
+            profile
+                .encode(&mut body)
+                .map_err(|e| format!("Failed to encode pprof profile: {e}"))?;
+        }
+        ProfileFormat::Flamegraph => {
+            report
+                .flamegraph(&mut body)
+                .map_err(|e| format!("Failed to render flamegraph: {e}"))?;
+        }
+    }
+    Ok(body)
+}
+
+/// Exclusive claim on the process-wide profiler, released on drop.
+struct ProfilingSlot;
+
+impl ProfilingSlot {
+    fn acquire() -> Option<Self> {
+        PROFILING
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .ok()
+            .map(|_| Self)
+    }
+}
+
+impl Drop for ProfilingSlot {
+    fn drop(&mut self) {
+        PROFILING.store(false, Ordering::Release);
+    }
+}
+
+fn error_response(status: StatusCode, message: &str) -> Response {
+    let body = serde_json::json!({ "error": message });
+    (status, Json(body)).into_response()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn parse(query: &str) -> ProfileQuery {
+        let uri: http::Uri = format!("{CPU_PROFILE_PATH}?{query}").parse().unwrap();
+        Query::<ProfileQuery>::try_from_uri(&uri).unwrap().0
+    }
+
+    #[test]
+    fn test_query_defaults_and_clamping() {
+        let query = parse("");
+        assert_eq!(query.duration(), Duration::from_secs(DEFAULT_SECONDS));
+        assert_eq!(query.format, ProfileFormat::Pprof);
+
+        assert_eq!(parse("seconds=0").duration(), Duration::from_secs(1));
+        assert_eq!(parse("seconds=5").duration(), Duration::from_secs(5));
+        assert_eq!(
+            parse("seconds=3600").duration(),
+            Duration::from_secs(MAX_SECONDS)
+        );
+        assert_eq!(parse("format=flamegraph").format, ProfileFormat::Flamegraph);
+    }
+
+    #[test]
+    fn test_only_one_profile_at_a_time() {
+        let first = ProfilingSlot::acquire().unwrap();
+        assert!(ProfilingSlot::acquire().is_none());
+        drop(first);
+        assert!(ProfilingSlot::acquire().is_some());
+    }
+
+    #[test]
+    fn test_collect_pprof() {
+        let body = collect(Duration::from_millis(100), ProfileFormat::Pprof).unwrap();
+        assert!(pprof::protos::Profile::decode(body.as_slice()).is_ok());
+    }
+}
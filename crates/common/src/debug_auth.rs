@@ -0,0 +1,47 @@
+//! Access control for the `/debug/*` operational endpoints.
+//!
+//! Endpoints such as `/debug/log-level` and `/debug/allocator` change process
+//! behaviour or expose internals, so they require a service token carrying
+//! [`DEBUG_SCOPE`]. AC checks the scope with its own signing keys; the other
+//! services use [`require_debug_scope`], which validates against AC's JWKS.
+
+use crate::jwt::{JwtValidator, ServiceClaims};
+use axum::{
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+
+/// Service-token scope required for the `/debug/*` endpoints.
+pub const DEBUG_SCOPE: &str = "admin:debug";
+
+/// Middleware requiring a service token with [`DEBUG_SCOPE`], validated
+/// against AC's JWKS.
+///
+/// Returns 401 for a missing or invalid token and 403 when the scope is
+/// absent.
+pub async fn require_debug_scope(
+    State(validator): State<Arc<JwtValidator>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    let Some(token) = token else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+
+    match validator.validate::<ServiceClaims>(token).await {
+        Ok(claims) if claims.has_scope(DEBUG_SCOPE) => next.run(request).await,
+        Ok(_) => StatusCode::FORBIDDEN.into_response(),
+        Err(e) => {
+            tracing::debug!(target: "common.debug_auth", error = %e, "Rejected debug endpoint request");
+            StatusCode::UNAUTHORIZED.into_response()
+        }
+    }
+}
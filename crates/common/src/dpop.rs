@@ -0,0 +1,673 @@
+//! `DPoP` proof-of-possession (RFC 9449).
+//!
+//! A client that holds a key pair sends a `DPoP` proof (a JWT signed with
+//! its private key, carrying the public key in its header) with each
+//! request. AC binds the tokens it issues to that key with a `cnf.jkt`
+//! claim ([`crate::jwt::Confirmation`]); a resource server only accepts a
+//! bound token together with a fresh proof signed by the same key, so a
+//! stolen token is useless without the private key.
+//!
+//! This module verifies proofs; each service decides which requests need
+//! one and what a failure maps to.
+//!
+//! # Security
+//!
+//! - Proofs are size-checked before parsing, like access tokens
+//! - Only `EdDSA` (Ed25519) and `ES256` (P-256) proof keys are accepted
+//! - A JWK carrying private key material (`d`) is rejected
+//! - `htu` is compared by authority and path: TLS terminates at the ingress,
+//!   so the scheme a service sees is not the one the client used
+//! - Replayed `jti` values are rejected by [`DpopReplayCache`]
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use ring::digest;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Request header carrying the `DPoP` proof.
+pub const DPOP_HEADER: &str = "dpop";
+
+/// `Authorization` scheme and `token_type` of `DPoP`-bound tokens.
+pub const DPOP_TOKEN_TYPE: &str = "DPoP";
+
+/// Maximum allowed proof size in bytes.
+///
+/// A proof is a small JWT with one public key in its header; anything
+/// larger is rejected before parsing.
+pub const MAX_PROOF_SIZE_BYTES: usize = 4096;
+
+/// How far a proof's `iat` may be from the verifier's clock (either way).
+///
+/// Proofs are created per request, so this only needs to cover clock skew
+/// and request latency. It also bounds how long [`DpopReplayCache`] keeps a
+/// `jti`.
+pub const PROOF_MAX_AGE: Duration = Duration::from_secs(60);
+
+/// Maximum number of `jti` values a [`DpopReplayCache`] holds.
+///
+/// When full (after dropping expired entries), new proofs are rejected
+/// rather than letting the cache grow without bound.
+pub const MAX_REPLAY_ENTRIES: usize = 100_000;
+
+/// Proof `typ` header value (RFC 9449 Section 4.2).
+const PROOF_TYP: &str = "dpop+jwt";
+
+/// Errors that can occur during `DPoP` proof verification.
+///
+/// Like [`crate::jwt::JwtError`], every variant has the same message so a
+/// response never tells a client which check failed.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DpopError {
+    /// Proof is too large, not a JWT, or has the wrong `typ` or claims.
+    #[error("The DPoP proof is invalid")]
+    MalformedProof,
+
+    /// Proof key or algorithm is not supported, or the JWK is private.
+    #[error("The DPoP proof is invalid")]
+    UnsupportedKey,
+
+    /// Proof signature does not verify with its own key.
+    #[error("The DPoP proof is invalid")]
+    InvalidSignature,
+
+    /// `htm` or `htu` does not match the request.
+    #[error("The DPoP proof is invalid")]
+    RequestMismatch,
+
+    /// `iat` is outside [`PROOF_MAX_AGE`].
+    #[error("The DPoP proof is invalid")]
+    Stale,
+
+    /// `ath` does not match the access token presented with the proof.
+    #[error("The DPoP proof is invalid")]
+    AccessTokenMismatch,
+
+    /// Proof key is not the key the access token is bound to.
+    #[error("The DPoP proof is invalid")]
+    KeyMismatch,
+
+    /// The proof's `jti` was already used.
+    #[error("The DPoP proof is invalid")]
+    Replayed,
+}
+
+/// The request a proof must match.
+#[derive(Debug, Clone, Copy)]
+pub struct DpopRequest<'a> {
+    /// HTTP method (`htm`), e.g. `POST`.
+    pub method: &'a str,
+    /// `Host` header value (authority of `htu`).
+    pub host: &'a str,
+    /// Request path without query (path of `htu`).
+    pub path: &'a str,
+    /// Access token sent with the proof, checked against `ath`; `None` at
+    /// the token endpoint.
+    pub access_token: Option<&'a str>,
+}
+
+/// A verified proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DpopProof {
+    /// JWK thumbprint (RFC 7638) of the proof key; what `cnf.jkt` holds.
+    pub jkt: String,
+    /// Unique proof identifier, for replay detection.
+    pub jti: String,
+    /// Issued-at timestamp (Unix epoch seconds).
+    pub iat: i64,
+}
+
+/// Public key from the proof's `jwk` header.
+#[derive(Debug, Deserialize)]
+struct ProofJwk {
+    kty: String,
+    crv: String,
+    x: String,
+    #[serde(default)]
+    y: Option<String>,
+    /// Private key; present only if the client leaked it into the header.
+    #[serde(default)]
+    d: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProofHeader {
+    typ: String,
+    alg: String,
+    jwk: ProofJwk,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProofClaims {
+    jti: String,
+    htm: String,
+    htu: String,
+    iat: i64,
+    #[serde(default)]
+    ath: Option<String>,
+}
+
+impl ProofJwk {
+    /// The signature algorithm this key is used with.
+    fn algorithm(&self) -> Result<Algorithm, DpopError> {
+        match (self.kty.as_str(), self.crv.as_str(), &self.y) {
+            ("OKP", "Ed25519", None) => Ok(Algorithm::EdDSA),
+            ("EC", "P-256", Some(_)) => Ok(Algorithm::ES256),
+            _ => Err(DpopError::UnsupportedKey),
+        }
+    }
+
+    fn decoding_key(&self) -> Result<DecodingKey, DpopError> {
+        let key = match &self.y {
+            None => DecodingKey::from_ed_components(&self.x),
+            Some(y) => DecodingKey::from_ec_components(&self.x, y),
+        };
+        key.map_err(|_| DpopError::UnsupportedKey)
+    }
+
+    /// RFC 7638 thumbprint: SHA-256 of the required members in
+    /// lexicographic order, without whitespace.
+    fn thumbprint(&self) -> Result<String, DpopError> {
+        // The coordinates go into the JSON verbatim, so they must be plain
+        // base64url with nothing to escape
+        let coordinates = std::iter::once(&self.x).chain(&self.y);
+        for coordinate in coordinates {
+            URL_SAFE_NO_PAD
+                .decode(coordinate)
+                .map_err(|_| DpopError::UnsupportedKey)?;
+        }
+
+        let canonical = match &self.y {
+            None => format!(
+                r#"{{"crv":"{}","kty":"{}","x":"{}"}}"#,
+                self.crv, self.kty, self.x
+            ),
+            Some(y) => format!(
+                r#"{{"crv":"{}","kty":"{}","x":"{}","y":"{}"}}"#,
+                self.crv, self.kty, self.x, y
+            ),
+        };
+        Ok(sha256_b64url(canonical.as_bytes()))
+    }
+}
+
+/// Base64url (unpadded) SHA-256, as used by `jkt` and `ath`.
+fn sha256_b64url(input: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(digest::digest(&digest::SHA256, input))
+}
+
+/// Whether `htu` names `host` and `path`.
+///
+/// The scheme must be `http` or `https` but is otherwise ignored (see the
+/// module docs); query and fragment are ignored per RFC 9449 Section 4.3.
+fn htu_matches(htu: &str, host: &str, path: &str) -> bool {
+    let Ok(uri) = htu.parse::<http::Uri>() else {
+        return false;
+    };
+    let scheme_ok = matches!(uri.scheme_str(), Some("http" | "https"));
+    let authority_ok = uri
+        .authority()
+        .is_some_and(|authority| authority.as_str().eq_ignore_ascii_case(host));
+    scheme_ok && authority_ok && uri.path() == path
+}
+
+/// Verify a `DPoP` proof for `request` and return its key thumbprint.
+///
+/// Checks (RFC 9449 Section 4.3): size, `typ`, supported public key and
+/// matching `alg`, signature, `htm`/`htu` against `request`, `iat` within
+/// [`PROOF_MAX_AGE`] of `now`, and `ath` when an access token is presented.
+/// Replay (`jti`) and the `cnf` binding are checked separately with
+/// [`DpopReplayCache::check`] and [`verify_binding`].
+///
+/// # Errors
+///
+/// Returns a [`DpopError`] describing the first failed check.
+pub fn verify_proof(
+    proof: &str,
+    request: &DpopRequest<'_>,
+    now: i64,
+) -> Result<DpopProof, DpopError> {
+    if proof.len() > MAX_PROOF_SIZE_BYTES {
+        tracing::debug!(target: "common.dpop", size = proof.len(), "DPoP proof too large");
+        return Err(DpopError::MalformedProof);
+    }
+
+    let header_b64 = proof.split('.').next().ok_or(DpopError::MalformedProof)?;
+    let header_json = URL_SAFE_NO_PAD
+        .decode(header_b64)
+        .map_err(|_| DpopError::MalformedProof)?;
+    let header: ProofHeader =
+        serde_json::from_slice(&header_json).map_err(|_| DpopError::MalformedProof)?;
+
+    if !header.typ.eq_ignore_ascii_case(PROOF_TYP) {
+        return Err(DpopError::MalformedProof);
+    }
+    if header.jwk.d.is_some() {
+        tracing::debug!(target: "common.dpop", "DPoP proof JWK contains a private key");
+        return Err(DpopError::UnsupportedKey);
+    }
+    let algorithm = header.jwk.algorithm()?;
+    let expected_alg = match algorithm {
+        Algorithm::EdDSA => "EdDSA",
+        _ => "ES256",
+    };
+    if header.alg != expected_alg {
+        return Err(DpopError::UnsupportedKey);
+    }
+
+    let mut validation = Validation::new(algorithm);
+    validation.validate_exp = false;
+    validation.required_spec_claims.clear();
+    let claims = decode::<ProofClaims>(proof, &header.jwk.decoding_key()?, &validation)
+        .map_err(|e| {
+            tracing::debug!(target: "common.dpop", error = %e, "DPoP proof verification failed");
+            DpopError::InvalidSignature
+        })?
+        .claims;
+
+    if claims.jti.is_empty() {
+        return Err(DpopError::MalformedProof);
+    }
+    if claims.htm != request.method || !htu_matches(&claims.htu, request.host, request.path) {
+        return Err(DpopError::RequestMismatch);
+    }
+    if now.abs_diff(claims.iat) > PROOF_MAX_AGE.as_secs() {
+        return Err(DpopError::Stale);
+    }
+    match (request.access_token, &claims.ath) {
+        (None, _) => {}
+        (Some(token), Some(ath)) if *ath == sha256_b64url(token.as_bytes()) => {}
+        (Some(_), _) => return Err(DpopError::AccessTokenMismatch),
+    }
+
+    Ok(DpopProof {
+        jkt: header.jwk.thumbprint()?,
+        jti: claims.jti,
+        iat: claims.iat,
+    })
+}
+
+/// Check that `proof` was signed by the key a token is bound to.
+///
+/// # Errors
+///
+/// Returns [`DpopError::KeyMismatch`] if `bound_jkt` is not the proof key's
+/// thumbprint.
+pub fn verify_binding(proof: &DpopProof, bound_jkt: &str) -> Result<(), DpopError> {
+    if proof.jkt == bound_jkt {
+        Ok(())
+    } else {
+        Err(DpopError::KeyMismatch)
+    }
+}
+
+/// Recently seen proof `jti` values, per proof key.
+///
+/// When the cache fills, entries more than twice [`PROOF_MAX_AGE`] from
+/// `now` are dropped: a replay of one of those proofs is already stale.
+/// The cache is per process; a proof replayed against another replica
+/// within the window is not detected.
+#[derive(Debug, Default)]
+pub struct DpopReplayCache {
+    seen: Mutex<HashMap<(String, String), i64>>,
+}
+
+impl DpopReplayCache {
+    /// Create an empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `proof` as used at `now`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DpopError::Replayed`] if the same key already used the
+    /// proof's `jti`, or if the cache is full.
+    pub fn check(&self, proof: &DpopProof, now: i64) -> Result<(), DpopError> {
+        let retain_secs = PROOF_MAX_AGE.as_secs() * 2;
+        let mut seen = self
+            .seen
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        let key = (proof.jkt.clone(), proof.jti.clone());
+        if seen.contains_key(&key) {
+            return Err(DpopError::Replayed);
+        }
+        if seen.len() >= MAX_REPLAY_ENTRIES {
+            seen.retain(|_, iat| now.abs_diff(*iat) <= retain_secs);
+            if seen.len() >= MAX_REPLAY_ENTRIES {
+                tracing::warn!(target: "common.dpop", "DPoP replay cache full");
+                return Err(DpopError::Replayed);
+            }
+        }
+        seen.insert(key, proof.iat);
+        Ok(())
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+pub use testing::DpopKey;
+
+#[cfg(any(test, feature = "test-utils"))]
+mod testing {
+    use super::{sha256_b64url, Algorithm, URL_SAFE_NO_PAD};
+    use base64::Engine;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+    use serde_json::{json, Value};
+
+    /// An Ed25519 `DPoP` key that signs proofs, for tests.
+    pub struct DpopKey {
+        pkcs8: Vec<u8>,
+        x: String,
+    }
+
+    impl DpopKey {
+        /// A fresh random key.
+        ///
+        /// # Panics
+        ///
+        /// Panics if key generation fails.
+        #[must_use]
+        #[allow(clippy::expect_used)]
+        pub fn generate() -> Self {
+            let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+                .expect("Ed25519 key generation");
+            let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).expect("valid PKCS#8");
+            Self {
+                x: URL_SAFE_NO_PAD.encode(key_pair.public_key().as_ref()),
+                pkcs8: pkcs8.as_ref().to_vec(),
+            }
+        }
+
+        /// The public key as a JWK.
+        #[must_use]
+        pub fn jwk(&self) -> Value {
+            json!({ "kty": "OKP", "crv": "Ed25519", "x": self.x })
+        }
+
+        /// RFC 7638 thumbprint of the public key (the `cnf.jkt` it binds).
+        #[must_use]
+        pub fn thumbprint(&self) -> String {
+            let canonical = format!(r#"{{"crv":"Ed25519","kty":"OKP","x":"{}"}}"#, self.x);
+            sha256_b64url(canonical.as_bytes())
+        }
+
+        /// A fresh proof for `method` on `htu` issued at `iat`, with `ath`
+        /// for `access_token` if given.
+        #[must_use]
+        pub fn proof(
+            &self,
+            method: &str,
+            htu: &str,
+            iat: i64,
+            access_token: Option<&str>,
+        ) -> String {
+            let mut claims = json!({
+                "jti": uuid::Uuid::new_v4().to_string(),
+                "htm": method,
+                "htu": htu,
+                "iat": iat,
+            });
+            if let Some(token) = access_token {
+                claims["ath"] = json!(sha256_b64url(token.as_bytes()));
+            }
+            self.sign(&self.jwk(), &claims)
+        }
+
+        /// Sign `claims` as a proof whose header carries `jwk`, which need
+        /// not be this key's.
+        ///
+        /// # Panics
+        ///
+        /// Panics if signing fails.
+        #[must_use]
+        #[allow(clippy::expect_used)]
+        pub fn sign(&self, jwk: &Value, claims: &Value) -> String {
+            // jsonwebtoken's Header cannot carry arbitrary JWK JSON, so the
+            // signing input is assembled by hand
+            let header = json!({ "typ": "dpop+jwt", "alg": "EdDSA", "jwk": jwk });
+            let header_b64 = URL_SAFE_NO_PAD.encode(header.to_string());
+            let claims_b64 = URL_SAFE_NO_PAD.encode(claims.to_string());
+            let input = format!("{header_b64}.{claims_b64}");
+            let key = EncodingKey::from_ed_der(&self.pkcs8);
+            let signature = jsonwebtoken::crypto::sign(input.as_bytes(), &key, Algorithm::EdDSA)
+                .expect("Ed25519 signing");
+            format!("{input}.{signature}")
+        }
+
+        /// A plain JWT (no `typ`/`jwk` header) signed by this key.
+        ///
+        /// # Panics
+        ///
+        /// Panics if signing fails.
+        #[must_use]
+        #[allow(clippy::expect_used)]
+        pub fn sign_plain_jwt(&self, claims: &Value) -> String {
+            encode(
+                &Header::new(Algorithm::EdDSA),
+                claims,
+                &EncodingKey::from_ed_der(&self.pkcs8),
+            )
+            .expect("Ed25519 signing")
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const NOW: i64 = 1_700_000_000;
+    const HTU: &str = "https://ac.example.com/api/v1/auth/service/token";
+
+    fn request(access_token: Option<&str>) -> DpopRequest<'_> {
+        DpopRequest {
+            method: "POST",
+            host: "ac.example.com",
+            path: "/api/v1/auth/service/token",
+            access_token,
+        }
+    }
+
+    fn claims(jti: &str) -> serde_json::Value {
+        json!({ "jti": jti, "htm": "POST", "htu": HTU, "iat": NOW })
+    }
+
+    #[test]
+    fn test_verify_proof_accepts_valid_proof() {
+        let key = DpopKey::generate();
+        let proof = key.sign(&key.jwk(), &claims("jti-1"));
+        let proof = verify_proof(&proof, &request(None), NOW).unwrap();
+
+        assert_eq!(proof.jti, "jti-1");
+        assert_eq!(proof.iat, NOW);
+        assert_eq!(proof.jkt, key.thumbprint());
+    }
+
+    #[test]
+    fn test_thumbprint_uses_canonical_json() {
+        // Members in lexicographic order, no whitespace
+        let jwk = ProofJwk {
+            kty: "OKP".to_string(),
+            crv: "Ed25519".to_string(),
+            x: "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo".to_string(),
+            y: None,
+            d: None,
+        };
+        assert_eq!(
+            jwk.thumbprint().unwrap(),
+            sha256_b64url(
+                br#"{"crv":"Ed25519","kty":"OKP","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#
+            )
+        );
+
+        // A coordinate that is not base64url is rejected, not escaped
+        let jwk = ProofJwk {
+            x: r#"a","kty":"EC"#.to_string(),
+            ..jwk
+        };
+        assert_eq!(jwk.thumbprint(), Err(DpopError::UnsupportedKey));
+    }
+
+    #[test]
+    fn test_verify_proof_ignores_scheme_and_query() {
+        let key = DpopKey::generate();
+        let proof = key.proof(
+            "POST",
+            "http://AC.example.com/api/v1/auth/service/token?x=1",
+            NOW,
+            None,
+        );
+        assert!(verify_proof(&proof, &request(None), NOW).is_ok());
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_other_request() {
+        let key = DpopKey::generate();
+        let proofs = [
+            key.proof("GET", HTU, NOW, None),
+            key.proof("post", HTU, NOW, None),
+            key.proof(
+                "POST",
+                "https://ac.example.com/api/v1/auth/user/token",
+                NOW,
+                None,
+            ),
+            key.proof(
+                "POST",
+                "https://evil.example.com/api/v1/auth/service/token",
+                NOW,
+                None,
+            ),
+            key.proof(
+                "POST",
+                "ftp://ac.example.com/api/v1/auth/service/token",
+                NOW,
+                None,
+            ),
+        ];
+
+        for proof in proofs {
+            assert_eq!(
+                verify_proof(&proof, &request(None), NOW),
+                Err(DpopError::RequestMismatch)
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_stale_iat() {
+        let key = DpopKey::generate();
+        let max_age = i64::try_from(PROOF_MAX_AGE.as_secs()).unwrap();
+        for iat in [NOW - max_age - 1, NOW + max_age + 1] {
+            assert_eq!(
+                verify_proof(&key.proof("POST", HTU, iat, None), &request(None), NOW),
+                Err(DpopError::Stale)
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_proof_checks_access_token_hash() {
+        let key = DpopKey::generate();
+        let proof = key.proof("POST", HTU, NOW, Some("access-token"));
+
+        assert!(verify_proof(&proof, &request(Some("access-token")), NOW).is_ok());
+        assert_eq!(
+            verify_proof(&proof, &request(Some("other-token")), NOW),
+            Err(DpopError::AccessTokenMismatch)
+        );
+        // A proof without `ath` cannot accompany an access token
+        assert_eq!(
+            verify_proof(
+                &key.proof("POST", HTU, NOW, None),
+                &request(Some("access-token")),
+                NOW
+            ),
+            Err(DpopError::AccessTokenMismatch)
+        );
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_signature_from_other_key() {
+        let key = DpopKey::generate();
+        let other = DpopKey::generate();
+        // Header names `other`'s public key, signature is by `key`
+        let proof = key.sign(&other.jwk(), &claims("jti-1"));
+        assert_eq!(
+            verify_proof(&proof, &request(None), NOW),
+            Err(DpopError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_private_or_unsupported_keys() {
+        let key = DpopKey::generate();
+
+        let mut private = key.jwk();
+        private["d"] = json!("c2VjcmV0");
+        let rsa = json!({ "kty": "RSA", "crv": "", "x": key.jwk()["x"] });
+
+        for jwk in [private, rsa] {
+            assert_eq!(
+                verify_proof(&key.sign(&jwk, &claims("jti-1")), &request(None), NOW),
+                Err(DpopError::UnsupportedKey)
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_non_proof_jwt() {
+        let key = DpopKey::generate();
+        assert_eq!(
+            verify_proof(&key.sign_plain_jwt(&claims("jti-1")), &request(None), NOW),
+            Err(DpopError::MalformedProof)
+        );
+        assert_eq!(
+            verify_proof(&"a".repeat(MAX_PROOF_SIZE_BYTES + 1), &request(None), NOW),
+            Err(DpopError::MalformedProof)
+        );
+    }
+
+    #[test]
+    fn test_verify_binding() {
+        let key = DpopKey::generate();
+        let proof = verify_proof(&key.proof("POST", HTU, NOW, None), &request(None), NOW).unwrap();
+
+        assert!(verify_binding(&proof, &key.thumbprint()).is_ok());
+        assert_eq!(
+            verify_binding(&proof, &DpopKey::generate().thumbprint()),
+            Err(DpopError::KeyMismatch)
+        );
+    }
+
+    #[test]
+    fn test_replay_cache_rejects_reused_jti() {
+        let cache = DpopReplayCache::new();
+        let proof = DpopProof {
+            jkt: "key-1".to_string(),
+            jti: "jti-1".to_string(),
+            iat: NOW,
+        };
+
+        cache.check(&proof, NOW).unwrap();
+        assert_eq!(cache.check(&proof, NOW + 1), Err(DpopError::Replayed));
+
+        // Same jti from a different key is a different proof
+        let other_key = DpopProof {
+            jkt: "key-2".to_string(),
+            ..proof
+        };
+        cache.check(&other_key, NOW).unwrap();
+    }
+}
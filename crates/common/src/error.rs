@@ -1,5 +1,12 @@
 //! Common error types for Dark Tower components.
+//!
+//! Also holds the [`ErrorCode`] registry: stable `DT-<SVC>-<NNNN>` codes that
+//! every client-facing error payload carries, so clients and runbooks can key
+//! off a code instead of message text. Codes are never renumbered or reused;
+//! retired codes stay reserved.
 
+use serde::{Serialize, Serializer};
+use std::fmt;
 use thiserror::Error;
 
 /// Common errors that can occur across Dark Tower components
@@ -40,3 +47,266 @@ pub enum DarkTowerError {
 
 /// Result type alias using `DarkTowerError`
 pub type Result<T> = std::result::Result<T, DarkTowerError>;
+
+/// gRPC metadata key carrying an [`ErrorCode`] on error statuses.
+pub const ERROR_CODE_METADATA: &str = "dt-error-code";
+
+/// Stable error codes shared across services.
+///
+/// Serialized as the full code string (e.g. `"DT-AC-1001"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    // Auth Controller (1xxx)
+    /// `DT-AC-1001` (`invalid_credentials`)
+    AcInvalidCredentials,
+    /// `DT-AC-1002` (`invalid_token`)
+    AcInvalidToken,
+    /// `DT-AC-1003` (`insufficient_scope`)
+    AcInsufficientScope,
+    /// `DT-AC-1004` (`not_found`)
+    AcNotFound,
+    /// `DT-AC-1005` (`rate_limited`)
+    AcRateLimited,
+    /// `DT-AC-1006` (`too_many_requests`)
+    AcTooManyRequests,
+    /// `DT-AC-1007` (`database_error`)
+    AcDatabase,
+    /// `DT-AC-1008` (`crypto_error`)
+    AcCrypto,
+    /// `DT-AC-1009` (`internal_error`)
+    AcInternal,
+    /// `DT-AC-1010` (`totp_required`)
+    AcTotpRequired,
+    /// `DT-AC-1011` (`invalid_request`)
+    AcInvalidRequest,
+    // Meeting Controller (2xxx)
+    /// `DT-MC-2001` (`meeting_not_found`)
+    McMeetingNotFound,
+    /// `DT-MC-2002` (`participant_not_found`)
+    McParticipantNotFound,
+    /// `DT-MC-2003` (`fenced_out`)
+    McFencedOut,
+    /// `DT-MC-2004` (`session_binding_failed`)
+    McSessionBinding,
+    /// `DT-MC-2005` (`invalid_token`)
+    McInvalidToken,
+    /// `DT-MC-2006` (`permission_denied`)
+    McPermissionDenied,
+    /// `DT-MC-2007` (`invalid_request`)
+    McInvalidRequest,
+    /// `DT-MC-2008` (`conflict`)
+    McConflict,
+    /// `DT-MC-2009` (`meeting_at_capacity`)
+    McMeetingAtCapacity,
+    /// `DT-MC-2010` (`mc_at_capacity`)
+    McAtCapacity,
+    /// `DT-MC-2011` (`draining`)
+    McDraining,
+    /// `DT-MC-2012` (`migrating`)
+    McMigrating,
+    /// `DT-MC-2013` (`rate_limited`)
+    McRateLimited,
+    /// `DT-MC-2014` (`e2e_unsupported`)
+    McE2eUnsupported,
+    /// `DT-MC-2015` (`mh_assignment_missing`)
+    McMhAssignmentMissing,
+    /// `DT-MC-2016` (`not_registered`)
+    McNotRegistered,
+    /// `DT-MC-2017` (`storage_unavailable`)
+    McStorageUnavailable,
+    /// `DT-MC-2018` (`upstream_unavailable`)
+    McUpstreamUnavailable,
+    /// `DT-MC-2019` (`service_auth_unavailable`)
+    McServiceAuthUnavailable,
+    /// `DT-MC-2020` (`internal_error`)
+    McInternal,
+    /// `DT-MC-2021` (`message_rejected`)
+    McMessageRejected,
+    /// `DT-MC-2022` (`unsupported_protocol_version`)
+    McUnsupportedProtocolVersion,
+    /// `DT-MC-2023` (`session_replaced`)
+    McSessionReplaced,
+    // Global Controller (3xxx)
+    /// `DT-GC-3001` (`invalid_token`)
+    GcInvalidToken,
+    /// `DT-GC-3002` (`forbidden`)
+    GcForbidden,
+    /// `DT-GC-3003` (`not_found`)
+    GcNotFound,
+    /// `DT-GC-3004` (`conflict`)
+    GcConflict,
+    /// `DT-GC-3005` (`bad_request`)
+    GcBadRequest,
+    /// `DT-GC-3006` (`rate_limited`)
+    GcRateLimited,
+    /// `DT-GC-3007` (`service_unavailable`)
+    GcServiceUnavailable,
+    /// `DT-GC-3008` (`database_error`)
+    GcDatabase,
+    /// `DT-GC-3009` (`internal_error`)
+    GcInternal,
+}
+
+impl ErrorCode {
+    /// Every registered code, in registry order.
+    pub const ALL: &'static [ErrorCode] = &[
+        Self::AcInvalidCredentials,
+        Self::AcInvalidToken,
+        Self::AcInsufficientScope,
+        Self::AcNotFound,
+        Self::AcRateLimited,
+        Self::AcTooManyRequests,
+        Self::AcDatabase,
+        Self::AcCrypto,
+        Self::AcInternal,
+        Self::AcTotpRequired,
+        Self::AcInvalidRequest,
+        Self::McMeetingNotFound,
+        Self::McParticipantNotFound,
+        Self::McFencedOut,
+        Self::McSessionBinding,
+        Self::McInvalidToken,
+        Self::McPermissionDenied,
+        Self::McInvalidRequest,
+        Self::McConflict,
+        Self::McMeetingAtCapacity,
+        Self::McAtCapacity,
+        Self::McDraining,
+        Self::McMigrating,
+        Self::McRateLimited,
+        Self::McE2eUnsupported,
+        Self::McMhAssignmentMissing,
+        Self::McNotRegistered,
+        Self::McStorageUnavailable,
+        Self::McUpstreamUnavailable,
+        Self::McServiceAuthUnavailable,
+        Self::McInternal,
+        Self::McMessageRejected,
+        Self::McUnsupportedProtocolVersion,
+        Self::McSessionReplaced,
+        Self::GcInvalidToken,
+        Self::GcForbidden,
+        Self::GcNotFound,
+        Self::GcConflict,
+        Self::GcBadRequest,
+        Self::GcRateLimited,
+        Self::GcServiceUnavailable,
+        Self::GcDatabase,
+        Self::GcInternal,
+    ];
+
+    /// The code string, e.g. `DT-MC-2003`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        self.spec().0
+    }
+
+    /// Short `snake_case` name, e.g. `fenced_out`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.spec().1
+    }
+
+    const fn spec(self) -> (&'static str, &'static str) {
+        match self {
+            Self::AcInvalidCredentials => ("DT-AC-1001", "invalid_credentials"),
+            Self::AcInvalidToken => ("DT-AC-1002", "invalid_token"),
+            Self::AcInsufficientScope => ("DT-AC-1003", "insufficient_scope"),
+            Self::AcNotFound => ("DT-AC-1004", "not_found"),
+            Self::AcRateLimited => ("DT-AC-1005", "rate_limited"),
+            Self::AcTooManyRequests => ("DT-AC-1006", "too_many_requests"),
+            Self::AcDatabase => ("DT-AC-1007", "database_error"),
+            Self::AcCrypto => ("DT-AC-1008", "crypto_error"),
+            Self::AcInternal => ("DT-AC-1009", "internal_error"),
+            Self::AcTotpRequired => ("DT-AC-1010", "totp_required"),
+            Self::AcInvalidRequest => ("DT-AC-1011", "invalid_request"),
+            Self::McMeetingNotFound => ("DT-MC-2001", "meeting_not_found"),
+            Self::McParticipantNotFound => ("DT-MC-2002", "participant_not_found"),
+            Self::McFencedOut => ("DT-MC-2003", "fenced_out"),
+            Self::McSessionBinding => ("DT-MC-2004", "session_binding_failed"),
+            Self::McInvalidToken => ("DT-MC-2005", "invalid_token"),
+            Self::McPermissionDenied => ("DT-MC-2006", "permission_denied"),
+            Self::McInvalidRequest => ("DT-MC-2007", "invalid_request"),
+            Self::McConflict => ("DT-MC-2008", "conflict"),
+            Self::McMeetingAtCapacity => ("DT-MC-2009", "meeting_at_capacity"),
+            Self::McAtCapacity => ("DT-MC-2010", "mc_at_capacity"),
+            Self::McDraining => ("DT-MC-2011", "draining"),
+            Self::McMigrating => ("DT-MC-2012", "migrating"),
+            Self::McRateLimited => ("DT-MC-2013", "rate_limited"),
+            Self::McE2eUnsupported => ("DT-MC-2014", "e2e_unsupported"),
+            Self::McMhAssignmentMissing => ("DT-MC-2015", "mh_assignment_missing"),
+            Self::McNotRegistered => ("DT-MC-2016", "not_registered"),
+            Self::McStorageUnavailable => ("DT-MC-2017", "storage_unavailable"),
+            Self::McUpstreamUnavailable => ("DT-MC-2018", "upstream_unavailable"),
+            Self::McServiceAuthUnavailable => ("DT-MC-2019", "service_auth_unavailable"),
+            Self::McInternal => ("DT-MC-2020", "internal_error"),
+            Self::McMessageRejected => ("DT-MC-2021", "message_rejected"),
+            Self::McUnsupportedProtocolVersion => ("DT-MC-2022", "unsupported_protocol_version"),
+            Self::McSessionReplaced => ("DT-MC-2023", "session_replaced"),
+            Self::GcInvalidToken => ("DT-GC-3001", "invalid_token"),
+            Self::GcForbidden => ("DT-GC-3002", "forbidden"),
+            Self::GcNotFound => ("DT-GC-3003", "not_found"),
+            Self::GcConflict => ("DT-GC-3004", "conflict"),
+            Self::GcBadRequest => ("DT-GC-3005", "bad_request"),
+            Self::GcRateLimited => ("DT-GC-3006", "rate_limited"),
+            Self::GcServiceUnavailable => ("DT-GC-3007", "service_unavailable"),
+            Self::GcDatabase => ("DT-GC-3008", "database_error"),
+            Self::GcInternal => ("DT-GC-3009", "internal_error"),
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_error_codes_are_unique_and_well_formed() {
+        let mut codes = HashSet::new();
+        for code in ErrorCode::ALL {
+            assert!(codes.insert(code.as_str()), "duplicate {code}");
+
+            let mut parts = code.as_str().split('-');
+            assert_eq!(parts.next(), Some("DT"));
+            let service = parts.next().unwrap();
+            let number: u16 = parts.next().unwrap().parse().unwrap();
+            assert!(parts.next().is_none());
+
+            let range = match service {
+                "AC" => Some(1000..2000),
+                "MC" => Some(2000..3000),
+                "GC" => Some(3000..4000),
+                _ => None,
+            }
+            .expect("unknown service prefix");
+            assert!(range.contains(&number), "{code} outside {service} range");
+            assert!(!code.name().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_error_code_wire_format() {
+        assert_eq!(ErrorCode::AcInvalidCredentials.as_str(), "DT-AC-1001");
+        assert_eq!(ErrorCode::McFencedOut.as_str(), "DT-MC-2003");
+        assert_eq!(ErrorCode::McFencedOut.name(), "fenced_out");
+        assert_eq!(
+            serde_json::to_string(&ErrorCode::GcNotFound).unwrap(),
+            "\"DT-GC-3003\""
+        );
+    }
+}
@@ -0,0 +1,489 @@
+//! Runtime fault injection for resilience testing (`fault-injection` feature).
+//!
+//! Services call [`inject`] at the start of named operations (`mc_assignment`,
+//! `redis_write`, ...). With no fault armed it returns `Ok(())` immediately;
+//! once a fault is armed through [`fault_injection_router`] the operation is
+//! delayed, failed, or dropped:
+//!
+//! ```text
+//! GET    /debug/faults                                   -> armed faults
+//! PUT    /debug/faults/mc_assignment {"kind": "latency", "ms": 2000}
+//! PUT    /debug/faults/redis_write   {"kind": "error", "probability": 0.5}
+//! PUT    /debug/faults/gc_heartbeat  {"kind": "drop", "count": 3}
+//! DELETE /debug/faults/redis_write                       -> disarm one
+//! DELETE /debug/faults                                   -> disarm all
+//! ```
+//!
+//! `error` makes the operation fail the way it does when its dependency
+//! errors; `drop` makes it behave as if the request or write was silently
+//! lost. Each call site documents how it maps the two. `probability`
+//! (default 1.0) applies the fault to a fraction of calls, and `count`
+//! disarms it after that many injections.
+//!
+//! The feature must stay off in production images. The router itself is
+//! unauthenticated; services mount it behind a check for the
+//! [`DEBUG_SCOPE`](crate::debug_auth::DEBUG_SCOPE) service-token scope.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, put},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+/// Path of the fault list endpoint. Single faults live under
+/// `/debug/faults/{operation}`.
+pub const FAULTS_PATH: &str = "/debug/faults";
+
+/// Longest latency a fault may inject.
+const MAX_LATENCY_MS: u64 = 60_000;
+
+/// Armed faults by operation name.
+static FAULTS: Mutex<BTreeMap<String, Fault>> = Mutex::new(BTreeMap::new());
+
+/// What an armed fault does to its operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum FaultKind {
+    /// Delay the operation, then let it run.
+    Latency {
+        /// Delay in milliseconds.
+        ms: u64,
+    },
+    /// Fail the operation.
+    Error,
+    /// Lose the operation without an error from the dependency.
+    Drop,
+}
+
+/// A fault armed on one operation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fault {
+    /// What to inject.
+    #[serde(flatten)]
+    pub kind: FaultKind,
+    /// Fraction of calls the fault applies to, in `0.0..=1.0`.
+    #[serde(default = "default_probability")]
+    pub probability: f64,
+    /// Injections left before the fault disarms itself. `None` keeps it
+    /// armed until deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+}
+
+fn default_probability() -> f64 {
+    1.0
+}
+
+/// A fault [`inject`] applied to the operation instead of letting it run.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum InjectedFault {
+    /// The operation must fail.
+    #[error("injected error on {0}")]
+    Error(String),
+    /// The operation must be treated as lost.
+    #[error("injected drop on {0}")]
+    Drop(String),
+}
+
+/// Errors from arming a fault.
+#[derive(Debug, thiserror::Error)]
+pub enum FaultInjectionError {
+    /// The service has no injection point with this name.
+    #[error("Unknown operation: {0}")]
+    UnknownOperation(String),
+    /// The fault's parameters are out of range.
+    #[error("Invalid fault: {0}")]
+    InvalidFault(String),
+}
+
+/// Apply the fault armed on `operation`, if any.
+///
+/// Latency faults sleep and return `Ok(())`.
+///
+/// # Errors
+///
+/// Returns [`InjectedFault`] when an `error` or `drop` fault fires; the
+/// caller turns it into the failure its operation normally reports.
+pub async fn inject(operation: &str) -> Result<(), InjectedFault> {
+    let Some(kind) = take(operation) else {
+        return Ok(());
+    };
+    tracing::warn!(
+        target: "common.fault_injection",
+        operation = %operation,
+        fault = ?kind,
+        "Injecting fault"
+    );
+    match kind {
+        FaultKind::Latency { ms } => {
+            tokio::time::sleep(Duration::from_millis(ms)).await;
+            Ok(())
+        }
+        FaultKind::Error => Err(InjectedFault::Error(operation.to_string())),
+        FaultKind::Drop => Err(InjectedFault::Drop(operation.to_string())),
+    }
+}
+
+/// Decide whether the fault on `operation` fires for this call, counting
+/// it down if so.
+fn take(operation: &str) -> Option<FaultKind> {
+    let mut faults = FAULTS.lock().unwrap_or_else(PoisonError::into_inner);
+    let fault = faults.get_mut(operation)?;
+    if fault.probability < 1.0 && rand::random::<f64>() >= fault.probability {
+        return None;
+    }
+    let kind = fault.kind;
+    if let Some(count) = fault.count.as_mut() {
+        *count = count.saturating_sub(1);
+        if *count == 0 {
+            faults.remove(operation);
+        }
+    }
+    Some(kind)
+}
+
+/// Arm `fault` on `operation`, replacing any fault already armed there.
+///
+/// # Errors
+///
+/// Returns [`FaultInjectionError::InvalidFault`] if the probability is
+/// outside `0.0..=1.0`, the latency is above one minute, or the count is 0.
+pub fn arm(operation: &str, fault: Fault) -> Result<(), FaultInjectionError> {
+    if !(0.0..=1.0).contains(&fault.probability) {
+        return Err(FaultInjectionError::InvalidFault(format!(
+            "probability {} is outside 0.0..=1.0",
+            fault.probability
+        )));
+    }
+    if let FaultKind::Latency { ms } = fault.kind {
+        if ms > MAX_LATENCY_MS {
+            return Err(FaultInjectionError::InvalidFault(format!(
+                "latency {ms}ms exceeds {MAX_LATENCY_MS}ms"
+            )));
+        }
+    }
+    if fault.count == Some(0) {
+        return Err(FaultInjectionError::InvalidFault(
+            "count must be at least 1".to_string(),
+        ));
+    }
+    FAULTS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(operation.to_string(), fault);
+    Ok(())
+}
+
+/// Disarm the fault on `operation`. Returns whether one was armed.
+pub fn disarm(operation: &str) -> bool {
+    FAULTS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(operation)
+        .is_some()
+}
+
+/// Disarm every fault.
+pub fn disarm_all() {
+    FAULTS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clear();
+}
+
+/// Currently armed faults by operation.
+#[must_use]
+pub fn armed() -> BTreeMap<String, Fault> {
+    FAULTS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// Response body of the fault endpoints.
+#[derive(Debug, Serialize)]
+struct FaultsResponse {
+    operations: &'static [&'static str],
+    faults: BTreeMap<String, Fault>,
+}
+
+impl FaultsResponse {
+    fn current(operations: &'static [&'static str]) -> Self {
+        // Faults armed by another router (or a test) on operations this
+        // service does not know are not reported
+        let mut faults = armed();
+        faults.retain(|operation, _| operations.contains(&operation.as_str()));
+        Self { operations, faults }
+    }
+}
+
+/// Router serving [`FAULTS_PATH`] for the service's injection points.
+/// `operations` lists the names the service passes to [`inject`]; arming
+/// any other name is rejected with 404. Unauthenticated: callers must layer
+/// an auth check requiring [`DEBUG_SCOPE`](crate::debug_auth::DEBUG_SCOPE)
+/// on top.
+#[must_use]
+pub fn fault_injection_router(operations: &'static [&'static str]) -> Router {
+    Router::new()
+        .route(FAULTS_PATH, get(list_faults).delete(clear_faults))
+        .route(
+            &format!("{FAULTS_PATH}/:operation"),
+            put(set_fault).delete(clear_fault),
+        )
+        .with_state(operations)
+}
+
+async fn list_faults(State(operations): State<&'static [&'static str]>) -> Json<FaultsResponse> {
+    Json(FaultsResponse::current(operations))
+}
+
+async fn set_fault(
+    State(operations): State<&'static [&'static str]>,
+    Path(operation): Path<String>,
+    Json(fault): Json<Fault>,
+) -> Response {
+    if !operations.contains(&operation.as_str()) {
+        return error_response(&FaultInjectionError::UnknownOperation(operation));
+    }
+    let description = format!("{fault:?}");
+    match arm(&operation, fault) {
+        Ok(()) => {
+            tracing::warn!(
+                target: "common.fault_injection",
+                operation = %operation,
+                fault = %description,
+                "Fault armed"
+            );
+            Json(FaultsResponse::current(operations)).into_response()
+        }
+        Err(e) => error_response(&e),
+    }
+}
+
+async fn clear_fault(
+    State(operations): State<&'static [&'static str]>,
+    Path(operation): Path<String>,
+) -> Response {
+    if !operations.contains(&operation.as_str()) {
+        return error_response(&FaultInjectionError::UnknownOperation(operation));
+    }
+    if disarm(&operation) {
+        tracing::warn!(
+            target: "common.fault_injection",
+            operation = %operation,
+            "Fault disarmed"
+        );
+    }
+    Json(FaultsResponse::current(operations)).into_response()
+}
+
+async fn clear_faults(State(operations): State<&'static [&'static str]>) -> Json<FaultsResponse> {
+    for operation in operations {
+        disarm(operation);
+    }
+    tracing::warn!(target: "common.fault_injection", "All faults disarmed");
+    Json(FaultsResponse::current(operations))
+}
+
+fn error_response(error: &FaultInjectionError) -> Response {
+    let status = match error {
+        FaultInjectionError::UnknownOperation(_) => StatusCode::NOT_FOUND,
+        FaultInjectionError::InvalidFault(_) => StatusCode::BAD_REQUEST,
+    };
+    let body = serde_json::json!({ "error": error.to_string() });
+    (status, Json(body)).into_response()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::header;
+    use tower::ServiceExt;
+
+    // The registry is process-wide, so every test uses its own operation
+    // names.
+
+    fn fault(kind: FaultKind) -> Fault {
+        Fault {
+            kind,
+            probability: 1.0,
+            count: None,
+        }
+    }
+
+    async fn send(router: Router, request: http::Request<Body>) -> (StatusCode, serde_json::Value) {
+        let response = router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    fn put_fault(operation: &str, body: &'static str) -> http::Request<Body> {
+        http::Request::put(format!("{FAULTS_PATH}/{operation}"))
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_unarmed_operation_runs() {
+        assert_eq!(inject("test_unarmed").await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_error_and_drop() {
+        arm("test_error", fault(FaultKind::Error)).unwrap();
+        arm("test_drop", fault(FaultKind::Drop)).unwrap();
+
+        assert_eq!(
+            inject("test_error").await,
+            Err(InjectedFault::Error("test_error".to_string()))
+        );
+        assert_eq!(
+            inject("test_drop").await,
+            Err(InjectedFault::Drop("test_drop".to_string()))
+        );
+
+        assert!(disarm("test_error"));
+        assert!(disarm("test_drop"));
+        assert_eq!(inject("test_error").await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_latency_delays_then_runs() {
+        arm("test_latency", fault(FaultKind::Latency { ms: 50 })).unwrap();
+
+        let start = tokio::time::Instant::now();
+        assert_eq!(inject("test_latency").await, Ok(()));
+        assert!(start.elapsed() >= Duration::from_millis(50));
+
+        disarm("test_latency");
+    }
+
+    #[tokio::test]
+    async fn test_count_disarms() {
+        let mut twice = fault(FaultKind::Error);
+        twice.count = Some(2);
+        arm("test_count", twice).unwrap();
+
+        assert!(inject("test_count").await.is_err());
+        assert!(inject("test_count").await.is_err());
+        assert_eq!(inject("test_count").await, Ok(()));
+        assert!(!armed().contains_key("test_count"));
+    }
+
+    #[tokio::test]
+    async fn test_zero_probability_never_fires() {
+        let mut never = fault(FaultKind::Error);
+        never.probability = 0.0;
+        arm("test_never", never).unwrap();
+
+        for _ in 0..100 {
+            assert_eq!(inject("test_never").await, Ok(()));
+        }
+        disarm("test_never");
+    }
+
+    #[test]
+    fn test_invalid_faults_rejected() {
+        let fault_with = |f: fn(&mut Fault)| {
+            let mut fault = fault(FaultKind::Error);
+            f(&mut fault);
+            arm("test_invalid", fault)
+        };
+
+        assert!(fault_with(|f| f.probability = 1.5).is_err());
+        assert!(fault_with(|f| f.count = Some(0)).is_err());
+        assert!(fault_with(|f| f.kind = FaultKind::Latency { ms: 120_000 }).is_err());
+        assert!(!armed().contains_key("test_invalid"));
+    }
+
+    #[test]
+    fn test_fault_json() {
+        let fault: Fault = serde_json::from_str(r#"{"kind":"latency","ms":250}"#).unwrap();
+        assert_eq!(fault.kind, FaultKind::Latency { ms: 250 });
+        assert!((fault.probability - 1.0).abs() < f64::EPSILON);
+        assert_eq!(fault.count, None);
+
+        let fault: Fault =
+            serde_json::from_str(r#"{"kind":"drop","probability":0.25,"count":3}"#).unwrap();
+        assert_eq!(fault.kind, FaultKind::Drop);
+        assert_eq!(fault.count, Some(3));
+
+        assert!(serde_json::from_str::<Fault>(r#"{"kind":"explode"}"#).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_router_put_get_delete() {
+        const OPERATIONS: &[&str] = &["test_router_a", "test_router_b"];
+        let router = fault_injection_router(OPERATIONS);
+
+        let (status, body) = send(
+            router.clone(),
+            put_fault("test_router_a", r#"{"kind":"error"}"#),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["faults"]["test_router_a"]["kind"], "error");
+
+        let (status, _) = send(
+            router.clone(),
+            put_fault("test_router_b", r#"{"kind":"latency","ms":10}"#),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let get = http::Request::get(FAULTS_PATH).body(Body::empty()).unwrap();
+        let (status, body) = send(router.clone(), get).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["operations"], serde_json::json!(OPERATIONS));
+        assert_eq!(body["faults"]["test_router_b"]["ms"], 10);
+
+        let delete_one = http::Request::delete(format!("{FAULTS_PATH}/test_router_a"))
+            .body(Body::empty())
+            .unwrap();
+        let (status, body) = send(router.clone(), delete_one).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["faults"].get("test_router_a").is_none());
+        assert!(body["faults"].get("test_router_b").is_some());
+
+        let delete_all = http::Request::delete(FAULTS_PATH)
+            .body(Body::empty())
+            .unwrap();
+        let (status, body) = send(router, delete_all).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["faults"], serde_json::json!({}));
+    }
+
+    #[tokio::test]
+    async fn test_router_rejects_unknown_operation_and_bad_fault() {
+        const OPERATIONS: &[&str] = &["test_router_known"];
+        let router = fault_injection_router(OPERATIONS);
+
+        let (status, body) = send(
+            router.clone(),
+            put_fault("test_router_unknown", r#"{"kind":"error"}"#),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["error"], "Unknown operation: test_router_unknown");
+
+        let (status, _) = send(
+            router,
+            put_fault("test_router_known", r#"{"kind":"error","probability":2.0}"#),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(!armed().contains_key("test_router_known"));
+    }
+}
@@ -7,6 +7,7 @@
 //! - iat validation logic
 //! - Service token claims structure
 //! - User token claims structure (ADR-0020)
+//! - Delegated token claims structure (RFC 8693 token exchange)
 //!
 //! # Security
 //!
@@ -34,6 +35,7 @@
 //!
 //! **ADRs**: ADR-0003 (Service Auth), ADR-0007 (Token Lifetime)
 
+use crate::time::{system_clock, SharedClock};
 use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
 use serde::de::DeserializeOwned;
@@ -152,6 +154,7 @@ pub trait HasIat {
 /// - `iat`: Issued-at timestamp (Unix epoch seconds)
 /// - `scope`: Space-separated permissions
 /// - `service_type`: Optional service type identifier
+/// - `cnf`: Key the token is bound to, for DPoP-bound tokens (RFC 9449)
 ///
 /// # Security
 ///
@@ -174,6 +177,11 @@ pub struct ServiceClaims {
     /// Optional service type for service-to-service tokens.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub service_type: Option<String>,
+
+    /// Confirmation of the key a DPoP-bound token is bound to; `None` for
+    /// bearer tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cnf: Option<Confirmation>,
 }
 
 impl fmt::Debug for ServiceClaims {
@@ -184,10 +192,22 @@ impl fmt::Debug for ServiceClaims {
             .field("iat", &self.iat)
             .field("scope", &self.scope)
             .field("service_type", &self.service_type)
+            .field("cnf", &self.cnf)
             .finish()
     }
 }
 
+/// Confirmation claim (`cnf`, RFC 7800) of a sender-constrained token.
+///
+/// Only the DPoP key thumbprint (`jkt`, RFC 9449 Section 6.1) is used: the
+/// token is only accepted together with a DPoP proof signed by that key
+/// (see [`crate::dpop`]).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Confirmation {
+    /// Base64url SHA-256 JWK thumbprint (RFC 7638) of the proof key.
+    pub jkt: String,
+}
+
 impl ServiceClaims {
     /// Creates a new `ServiceClaims` instance.
     ///
@@ -212,6 +232,7 @@ impl ServiceClaims {
             iat,
             scope,
             service_type,
+            cnf: None,
         }
     }
 
@@ -289,6 +310,86 @@ impl fmt::Debug for UserClaims {
     }
 }
 
+/// Scope prefix of every scope a delegated token can carry.
+///
+/// Service gRPC layers require `service.*` scopes, so a delegated token is
+/// never accepted where a service token is expected.
+pub const DELEGATED_SCOPE_PREFIX: &str = "delegated:";
+
+/// Delegated token claims (RFC 8693 token exchange).
+///
+/// Issued by AC when a service exchanges its own token plus a user token,
+/// so it can call another service on that user's behalf. `sub` is the user;
+/// `act` is the service acting for them.
+///
+/// # Security
+///
+/// The `sub`, `act.sub`, and `jti` fields are redacted in Debug output.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DelegatedClaims {
+    /// Subject (user UUID) - redacted in Debug output.
+    pub sub: String,
+    /// Organization ID of the user.
+    pub org_id: String,
+    /// Space-separated delegated scopes, all prefixed with
+    /// [`DELEGATED_SCOPE_PREFIX`].
+    pub scope: String,
+    /// The service acting on the user's behalf.
+    pub act: Actor,
+    /// Issued-at timestamp (Unix epoch seconds).
+    pub iat: i64,
+    /// Expiration timestamp (Unix epoch seconds).
+    pub exp: i64,
+    /// Unique token identifier - redacted in Debug output.
+    pub jti: String,
+    /// Confirmation of the key a DPoP-bound token is bound to; `None` for
+    /// bearer tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cnf: Option<Confirmation>,
+}
+
+impl fmt::Debug for DelegatedClaims {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DelegatedClaims")
+            .field("sub", &"[REDACTED]")
+            .field("org_id", &self.org_id)
+            .field("scope", &self.scope)
+            .field("act", &self.act)
+            .field("iat", &self.iat)
+            .field("exp", &self.exp)
+            .field("jti", &"[REDACTED]")
+            .field("cnf", &self.cnf)
+            .finish()
+    }
+}
+
+impl DelegatedClaims {
+    /// Check if the token has a specific scope.
+    #[must_use]
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scope.split_whitespace().any(|s| s == scope)
+    }
+}
+
+/// Actor claim (`act`, RFC 8693 Section 4.1) of a delegated token.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Actor {
+    /// Client ID of the acting service - redacted in Debug output.
+    pub sub: String,
+    /// Service type of the acting service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_type: Option<String>,
+}
+
+impl fmt::Debug for Actor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Actor")
+            .field("sub", &"[REDACTED]")
+            .field("service_type", &self.service_type)
+            .finish()
+    }
+}
+
 // =============================================================================
 // Meeting Token Enums
 // =============================================================================
@@ -508,6 +609,12 @@ impl HasIat for UserClaims {
     }
 }
 
+impl HasIat for DelegatedClaims {
+    fn iat(&self) -> i64 {
+        self.iat
+    }
+}
+
 impl HasIat for MeetingTokenClaims {
     fn iat(&self) -> i64 {
         self.iat
@@ -911,12 +1018,13 @@ impl JwksClient {
             .send()
             .await
             .map_err(|e| {
-                tracing::error!(target: "common.jwt.jwks", error = %e, "Failed to fetch JWKS");
+                // Fires on every validation while AC is down; throttle it
+                crate::warn_throttled!(target: "common.jwt.jwks", error = %e, "Failed to fetch JWKS");
                 JwtError::ServiceUnavailable("Authentication service unavailable".to_string())
             })?;
 
         if !response.status().is_success() {
-            tracing::error!(
+            crate::warn_throttled!(
                 target: "common.jwt.jwks",
                 status = %response.status(),
                 "JWKS endpoint returned error"
@@ -1046,6 +1154,9 @@ pub struct JwtValidator {
 
     /// Clock skew tolerance in seconds for iat validation.
     clock_skew_seconds: i64,
+
+    /// Clock the iat check compares against.
+    clock: SharedClock,
 }
 
 impl JwtValidator {
@@ -1067,9 +1178,21 @@ impl JwtValidator {
         Self {
             jwks_client,
             clock_skew_seconds: clamped,
+            clock: system_clock(),
         }
     }
 
+    /// Check `iat` against `clock` instead of the system clock.
+    ///
+    /// `exp` is still checked by `jsonwebtoken` against the host clock, so
+    /// tests that move the clock should sign tokens with an `exp` that
+    /// covers both.
+    #[must_use]
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Validate a JWT and return the claims.
     ///
     /// # Security Checks
@@ -1107,9 +1230,10 @@ impl JwtValidator {
         let claims = verify_token::<T>(token, &jwk)?;
 
         // 4. Validate iat claim with clock skew tolerance
-        validate_iat(
+        validate_iat_at(
             claims.iat(),
             Duration::from_secs(self.clock_skew_seconds as u64),
+            self.clock.timestamp(),
         )?;
 
         tracing::debug!(target: "common.jwt", "Token validated successfully");
@@ -1336,6 +1460,7 @@ mod tests {
             iat: 1_234_567_800,
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let debug_str = format!("{claims:?}");
@@ -1358,6 +1483,7 @@ mod tests {
             iat: 1_234_567_800,
             scope: "read write admin".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         assert!(claims.has_scope("read"));
@@ -1375,6 +1501,7 @@ mod tests {
             iat: 1_234_567_800,
             scope: "read write admin".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let scopes = claims.scopes();
@@ -1389,6 +1516,7 @@ mod tests {
             iat: 1_234_567_800,
             scope: String::new(),
             service_type: None,
+            cnf: None,
         };
 
         assert!(!claims.has_scope("read"));
@@ -1403,6 +1531,7 @@ mod tests {
             iat: 1_234_567_800,
             scope: "read write".to_string(),
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         let json = serde_json::to_string(&claims).unwrap();
@@ -1423,6 +1552,7 @@ mod tests {
             iat: 1_234_567_800,
             scope: "read".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let json = serde_json::to_string(&claims).unwrap();
@@ -1523,6 +1653,65 @@ mod tests {
         assert_eq!(cloned.jti, claims.jti);
     }
 
+    // -------------------------------------------------------------------------
+    // DelegatedClaims Tests
+    // -------------------------------------------------------------------------
+
+    fn delegated_claims() -> DelegatedClaims {
+        DelegatedClaims {
+            sub: "user-secret-id".to_string(),
+            org_id: "org-456".to_string(),
+            scope: "delegated:meeting.moderate".to_string(),
+            act: Actor {
+                sub: "gc-secret-client".to_string(),
+                service_type: Some("global-controller".to_string()),
+            },
+            iat: 1_234_567_890,
+            exp: 1_234_568_190,
+            jti: "secret-jti-token".to_string(),
+            cnf: None,
+        }
+    }
+
+    #[test]
+    fn test_delegated_claims_debug_redacts_subjects() {
+        let debug_str = format!("{:?}", delegated_claims());
+        assert!(!debug_str.contains("user-secret-id"));
+        assert!(!debug_str.contains("gc-secret-client"));
+        assert!(!debug_str.contains("secret-jti-token"));
+        assert!(debug_str.contains("global-controller"));
+        assert!(debug_str.contains("delegated:meeting.moderate"));
+    }
+
+    #[test]
+    fn test_delegated_claims_serialization() {
+        let claims = delegated_claims();
+        let json = serde_json::to_value(&claims).unwrap();
+        assert_eq!(
+            json.pointer("/act/sub").and_then(|v| v.as_str()),
+            Some("gc-secret-client")
+        );
+        assert!(json.get("cnf").is_none(), "cnf should be omitted when None");
+
+        let parsed: DelegatedClaims = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.act, claims.act);
+        assert!(parsed.has_scope("delegated:meeting.moderate"));
+        assert!(!parsed.has_scope("service.write.mc"));
+    }
+
+    #[test]
+    fn test_service_claims_are_not_delegated_claims() {
+        let claims = ServiceClaims::new(
+            "service".to_string(),
+            1_234_567_890,
+            1_234_567_800,
+            "service.write.mc".to_string(),
+            None,
+        );
+        let json = serde_json::to_string(&claims).unwrap();
+        assert!(serde_json::from_str::<DelegatedClaims>(&json).is_err());
+    }
+
     // -------------------------------------------------------------------------
     // Key Decoding Tests
     // -------------------------------------------------------------------------
@@ -2971,6 +3160,55 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_jwt_validator_checks_iat_against_injected_clock() {
+        use crate::time::MockClock;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let (kid, pkcs8_bytes, public_key_bytes) = generate_ed25519_keypair();
+        let x_b64 = URL_SAFE_NO_PAD.encode(&public_key_bytes);
+
+        let mock_server = MockServer::start().await;
+
+        let jwks_response = serde_json::json!({
+            "keys": [{"kty": "OKP", "kid": kid, "crv": "Ed25519", "x": x_b64, "alg": "EdDSA", "use": "sig"}]
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/.well-known/jwks.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&jwks_response))
+            .mount(&mock_server)
+            .await;
+
+        let jwks_client = Arc::new(
+            JwksClient::new(format!("{}/.well-known/jwks.json", mock_server.uri())).unwrap(),
+        );
+        let now = chrono::Utc::now().timestamp();
+        let clock = MockClock::at_timestamp(now);
+        let validator = JwtValidator::new(jwks_client, 1).with_clock(clock.shared());
+
+        // Issued an hour from now: too early until the clock gets there
+        let claims = ServiceClaims::new(
+            "test".to_string(),
+            now + 7200,
+            now + 3600,
+            "read".to_string(),
+            None,
+        );
+        let token = sign_jwt(&claims, &kid, &pkcs8_bytes);
+
+        let result: Result<ServiceClaims, JwtError> = validator.validate(&token).await;
+        assert!(matches!(result, Err(JwtError::IatTooFarInFuture)));
+
+        clock.advance(Duration::from_secs(3600));
+        let result: Result<ServiceClaims, JwtError> = validator.validate(&token).await;
+        assert!(
+            result.is_ok(),
+            "iat is current once the clock advances: {result:?}"
+        );
+    }
+
     // Note: Missing-iat validation is enforced at compile time via the HasIat trait bound
     // on JwtValidator::validate<T: DeserializeOwned + HasIat>. Types without HasIat
     // cannot be passed to validate().
@@ -22,12 +22,67 @@ pub mod secret;
 /// Module for JWT utilities (validation, claims, constants)
 pub mod jwt;
 
+/// `DPoP` proof verification for sender-constrained tokens (RFC 9449)
+pub mod dpop;
+
 /// Module for OAuth 2.0 token management with automatic refresh
 pub mod token_manager;
 
+/// Bind address lists and dual-stack TCP listeners
+pub mod listen;
+
+/// Build metadata (`build_info!`) and the `/version` endpoint
+pub mod build_info;
+
+/// jemalloc stats and heap-profile endpoints (`jemalloc` feature)
+#[cfg(feature = "jemalloc")]
+pub mod allocator;
+
+/// On-demand CPU profiling endpoint (`cpu-profiling` feature)
+#[cfg(feature = "cpu-profiling")]
+pub mod cpu_profile;
+
+/// Scope check for the `/debug/*` operational endpoints
+pub mod debug_auth;
+
+/// Latency/error/drop injection on named operations and the `/debug/faults`
+/// endpoint (`fault-injection` feature)
+#[cfg(feature = "fault-injection")]
+pub mod fault_injection;
+
+/// Reloadable log filter and the `/debug/log-level` endpoint
+pub mod log_level;
+
+/// Rate-limited `warn_throttled!` logging for repetitive failures
+pub mod log_throttle;
+
+/// OTLP trace export and W3C trace context propagation (`otlp` feature)
+#[cfg(feature = "otlp")]
+pub mod otel;
+
+/// UDP socket tuning (DSCP, buffer sizes) for QUIC media endpoints
+pub mod media_socket;
+
+/// Peer address tracking for QUIC connection migration
+pub mod quic_path;
+
 /// Shared types for internal meeting/guest token requests (GC <-> AC)
 pub mod meeting_token;
 
+/// MC-minted tokens binding MH media connections to a signaling session
+pub mod media_token;
+
+/// Rolling-deploy checks of pinned release queries against HEAD migrations
+/// (`migration-compat` feature)
+#[cfg(any(test, feature = "migration-compat"))]
+pub mod migration_compat;
+
+/// Injectable wall clock (`Clock`) so tests can control time
+pub mod time;
+
+/// Per-request correlation IDs (`x-request-id`) and the layer that assigns them
+pub mod request_id;
+
 /// Observability utilities shared across services.
 ///
 /// The `observability::testing` submodule is only compiled when `cfg(test)`
@@ -0,0 +1,495 @@
+//! Bind address lists and dual-stack listeners.
+//!
+//! Every `*_BIND_ADDRESS` setting accepts a comma-separated list of socket
+//! addresses, e.g. `0.0.0.0:8080`, `[::]:8080`, or
+//! `10.0.0.5:8080,[fd00::5]:8080`.
+//!
+//! An IPv6 wildcard (`[::]`) listens dual-stack, accepting IPv4 clients as
+//! v4-mapped addresses, unless the same list also binds an IPv4 address on
+//! that port; then the IPv6 socket is IPv6-only so both binds succeed.
+//!
+//! Health and metrics servers can instead listen on a Unix domain socket
+//! ([`UnixSocketConfig`], [`serve_unix`]) so a sidecar can scrape them
+//! without a TCP port being exposed on the node.
+
+use axum::Router;
+use futures::stream::{self, Stream};
+use socket2::{Domain, Protocol, Socket, Type};
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use tokio::net::{TcpListener, TcpStream};
+
+/// Listen backlog for TCP listeners (matches the std/tokio default).
+const LISTEN_BACKLOG: i32 = 1024;
+
+/// Default file mode for Unix sockets: owner and group may connect.
+pub const DEFAULT_UNIX_SOCKET_MODE: u32 = 0o660;
+
+/// Parse a comma-separated list of bind addresses.
+///
+/// Duplicates are dropped; order is preserved.
+///
+/// # Errors
+///
+/// Returns a message naming the entry if the list is empty or an entry is
+/// not a valid socket address.
+pub fn parse_bind_addresses(value: &str) -> Result<Vec<SocketAddr>, String> {
+    let mut addrs: Vec<SocketAddr> = Vec::new();
+    for entry in value.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let addr: SocketAddr = entry
+            .parse()
+            .map_err(|e| format!("invalid bind address '{entry}': {e}"))?;
+        if !addrs.contains(&addr) {
+            addrs.push(addr);
+        }
+    }
+    if addrs.is_empty() {
+        return Err("no bind address configured".to_string());
+    }
+    Ok(addrs)
+}
+
+/// Whether `addr` must be bound IPv6-only because `all` also binds an IPv4
+/// address on the same (non-ephemeral) port.
+#[must_use]
+pub fn ipv6_only(addr: &SocketAddr, all: &[SocketAddr]) -> bool {
+    addr.is_ipv6()
+        && addr.port() != 0
+        && all
+            .iter()
+            .any(|other| other.is_ipv4() && other.port() == addr.port())
+}
+
+/// Bind a TCP listener on every address.
+///
+/// Must be called from within a Tokio runtime.
+///
+/// # Errors
+///
+/// Returns the OS error (annotated with the address) for the first address
+/// that fails to bind.
+pub fn bind_tcp_listeners(addrs: &[SocketAddr]) -> io::Result<Vec<TcpListener>> {
+    addrs
+        .iter()
+        .map(|addr| {
+            bind_tcp(addr, ipv6_only(addr, addrs))
+                .map_err(|e| io::Error::new(e.kind(), format!("failed to bind {addr}: {e}")))
+        })
+        .collect()
+}
+
+fn bind_tcp(addr: &SocketAddr, ipv6_only: bool) -> io::Result<TcpListener> {
+    let socket = Socket::new(
+        Domain::for_address(*addr),
+        Type::STREAM,
+        Some(Protocol::TCP),
+    )?;
+    if addr.is_ipv6() {
+        socket.set_only_v6(ipv6_only)?;
+    }
+    #[cfg(unix)]
+    socket.set_reuse_address(true)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&(*addr).into())?;
+    socket.listen(LISTEN_BACKLOG)?;
+    TcpListener::from_std(socket.into())
+}
+
+/// Merge listeners into one stream of accepted connections, for servers
+/// that take an incoming stream (e.g. tonic's `serve_with_incoming`).
+pub fn tcp_incoming(
+    listeners: Vec<TcpListener>,
+) -> impl Stream<Item = io::Result<TcpStream>> + Send + 'static {
+    stream::select_all(listeners.into_iter().map(|listener| {
+        Box::pin(stream::unfold(listener, |listener| async move {
+            let accepted = listener.accept().await.map(|(stream, _)| stream);
+            Some((accepted, listener))
+        }))
+    }))
+}
+
+/// Unix domain socket to serve on instead of a TCP port.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnixSocketConfig {
+    /// Socket file path.
+    pub path: PathBuf,
+    /// File mode applied to the socket after binding.
+    pub mode: u32,
+}
+
+impl UnixSocketConfig {
+    /// Load from `{prefix}_SOCKET_PATH` and `{prefix}_SOCKET_MODE` (octal,
+    /// default `660`). Returns `None` when no path is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the variable if the mode is not an octal
+    /// permission value, or is set without a path.
+    pub fn from_vars(vars: &HashMap<String, String>, prefix: &str) -> Result<Option<Self>, String> {
+        let path_var = format!("{prefix}_SOCKET_PATH");
+        let mode_var = format!("{prefix}_SOCKET_MODE");
+        let path = vars
+            .get(&path_var)
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty());
+        let mode = match vars.get(&mode_var) {
+            Some(value) => {
+                let digits = value.trim().trim_start_matches("0o");
+                match u32::from_str_radix(digits, 8) {
+                    Ok(mode) if mode <= 0o777 => mode,
+                    _ => {
+                        return Err(format!(
+                            "{mode_var}: expected octal permissions like '660', got '{value}'"
+                        ))
+                    }
+                }
+            }
+            None => DEFAULT_UNIX_SOCKET_MODE,
+        };
+
+        match path {
+            Some(path) => Ok(Some(Self {
+                path: PathBuf::from(path),
+                mode,
+            })),
+            None if vars.contains_key(&mode_var) => {
+                Err(format!("{mode_var}: set without {path_var}"))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// Bind `config.path` and return a future serving `router` over HTTP/1.1
+/// until `shutdown` resolves, after which the socket file is removed.
+///
+/// A socket file left behind by a previous process is replaced; a live
+/// socket or any other kind of file at the path is an error. The mode is
+/// applied right after binding, so the parent directory should not be
+/// writable by untrusted users.
+///
+/// # Errors
+///
+/// Returns the OS error if the socket cannot be bound or its permissions
+/// set, and `Unsupported` on non-Unix platforms.
+#[cfg(unix)]
+pub fn serve_unix<F>(
+    config: &UnixSocketConfig,
+    router: Router,
+    shutdown: F,
+) -> io::Result<impl Future<Output = ()> + Send + 'static>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    use hyper::server::conn::http1;
+    use hyper_util::rt::TokioIo;
+    use hyper_util::service::TowerToHyperService;
+
+    let listener = bind_unix(config).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("failed to bind {}: {e}", config.path.display()),
+        )
+    })?;
+    let path = config.path.clone();
+
+    Ok(async move {
+        tokio::pin!(shutdown);
+        loop {
+            tokio::select! {
+                () = &mut shutdown => break,
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, _)) => {
+                        let service = TowerToHyperService::new(router.clone());
+                        tokio::spawn(async move {
+                            if let Err(e) = http1::Builder::new()
+                                .serve_connection(TokioIo::new(stream), service)
+                                .await
+                            {
+                                tracing::debug!(error = %e, "Unix socket connection closed with error");
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        // Back off so fd exhaustion does not turn into a busy loop
+                        tracing::warn!(error = %e, "Unix socket accept failed");
+                        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                    }
+                }
+            }
+        }
+        drop(listener);
+        if let Err(e) = std::fs::remove_file(&path) {
+            tracing::warn!(error = %e, path = %path.display(), "Failed to remove Unix socket");
+        }
+    })
+}
+
+/// Unix domain sockets are not available on this platform.
+///
+/// # Errors
+///
+/// Always returns `Unsupported`.
+#[cfg(not(unix))]
+pub fn serve_unix<F>(
+    _config: &UnixSocketConfig,
+    _router: Router,
+    _shutdown: F,
+) -> io::Result<std::future::Ready<()>>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "Unix domain sockets are not supported on this platform",
+    ))
+}
+
+#[cfg(unix)]
+fn bind_unix(config: &UnixSocketConfig) -> io::Result<tokio::net::UnixListener> {
+    use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+
+    match std::fs::symlink_metadata(&config.path) {
+        Ok(meta) if meta.file_type().is_socket() => {
+            if std::os::unix::net::UnixStream::connect(&config.path).is_ok() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AddrInUse,
+                    "another process is listening on the socket",
+                ));
+            }
+            std::fs::remove_file(&config.path)?;
+        }
+        Ok(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "path exists and is not a socket",
+            ))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+
+    let listener = tokio::net::UnixListener::bind(&config.path)?;
+    std::fs::set_permissions(&config.path, std::fs::Permissions::from_mode(config.mode))?;
+    Ok(listener)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    fn ipv6_available() -> bool {
+        std::net::TcpListener::bind((Ipv6Addr::LOCALHOST, 0)).is_ok()
+    }
+
+    #[test]
+    fn test_parse_bind_addresses() {
+        assert_eq!(
+            parse_bind_addresses("0.0.0.0:8080").unwrap(),
+            vec!["0.0.0.0:8080".parse::<SocketAddr>().unwrap()]
+        );
+        assert_eq!(
+            parse_bind_addresses(" 0.0.0.0:8080, [::]:8080 ,0.0.0.0:8080").unwrap(),
+            vec![
+                "0.0.0.0:8080".parse::<SocketAddr>().unwrap(),
+                "[::]:8080".parse().unwrap(),
+            ]
+        );
+        assert!(parse_bind_addresses("").is_err());
+        assert!(parse_bind_addresses("localhost:8080").is_err());
+        assert!(parse_bind_addresses(":::8080").is_err());
+    }
+
+    #[test]
+    fn test_ipv6_only_only_when_port_shared_with_ipv4() {
+        let all = parse_bind_addresses("0.0.0.0:8080,[::]:8080,[::]:9090").unwrap();
+        assert!(ipv6_only(&"[::]:8080".parse().unwrap(), &all));
+        assert!(!ipv6_only(&"[::]:9090".parse().unwrap(), &all));
+        assert!(!ipv6_only(&"0.0.0.0:8080".parse().unwrap(), &all));
+
+        let ephemeral = parse_bind_addresses("127.0.0.1:0,[::1]:0").unwrap();
+        assert!(!ipv6_only(&"[::1]:0".parse().unwrap(), &ephemeral));
+    }
+
+    #[tokio::test]
+    async fn test_dual_stack_listener_accepts_both_families() {
+        if !ipv6_available() {
+            eprintln!("skipping: IPv6 unavailable");
+            return;
+        }
+        let listeners = bind_tcp_listeners(&["[::]:0".parse().unwrap()]).unwrap();
+        let port = listeners.first().unwrap().local_addr().unwrap().port();
+        let mut incoming = Box::pin(tcp_incoming(listeners));
+
+        for ip in [
+            std::net::IpAddr::from(Ipv4Addr::LOCALHOST),
+            Ipv6Addr::LOCALHOST.into(),
+        ] {
+            let _client = TcpStream::connect((ip, port)).await.unwrap();
+            let accepted = incoming.next().await.unwrap().unwrap();
+            let peer = accepted.peer_addr().unwrap().ip();
+            assert_eq!(peer.to_canonical(), ip, "peer {peer}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_separate_ipv4_and_ipv6_listeners_share_port() {
+        if !ipv6_available() {
+            eprintln!("skipping: IPv6 unavailable");
+            return;
+        }
+        // Reserve a port, then bind both families on it explicitly
+        let port = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let addrs = parse_bind_addresses(&format!("0.0.0.0:{port},[::]:{port}")).unwrap();
+        let listeners = bind_tcp_listeners(&addrs).unwrap();
+        assert_eq!(listeners.len(), 2);
+        let mut incoming = Box::pin(tcp_incoming(listeners));
+
+        let _v4 = TcpStream::connect((Ipv4Addr::LOCALHOST, port))
+            .await
+            .unwrap();
+        let _v6 = TcpStream::connect((Ipv6Addr::LOCALHOST, port))
+            .await
+            .unwrap();
+        let mut peers = [
+            incoming.next().await.unwrap().unwrap().peer_addr().unwrap(),
+            incoming.next().await.unwrap().unwrap().peer_addr().unwrap(),
+        ];
+        peers.sort_by_key(SocketAddr::is_ipv6);
+        assert!(peers.first().unwrap().is_ipv4());
+        assert!(peers.last().unwrap().is_ipv6());
+    }
+
+    #[test]
+    fn test_unix_socket_config_from_vars() {
+        assert_eq!(
+            UnixSocketConfig::from_vars(&HashMap::new(), "MC_HEALTH").unwrap(),
+            None
+        );
+
+        let vars = HashMap::from([(
+            "MC_HEALTH_SOCKET_PATH".to_string(),
+            "/run/mc/health.sock".to_string(),
+        )]);
+        assert_eq!(
+            UnixSocketConfig::from_vars(&vars, "MC_HEALTH").unwrap(),
+            Some(UnixSocketConfig {
+                path: PathBuf::from("/run/mc/health.sock"),
+                mode: DEFAULT_UNIX_SOCKET_MODE,
+            })
+        );
+
+        for mode in ["0600", "600", "0o600"] {
+            let mut vars = vars.clone();
+            vars.insert("MC_HEALTH_SOCKET_MODE".to_string(), mode.to_string());
+            let config = UnixSocketConfig::from_vars(&vars, "MC_HEALTH").unwrap();
+            assert_eq!(config.map(|c| c.mode), Some(0o600), "{mode}");
+        }
+
+        for (var, value) in [
+            ("MC_HEALTH_SOCKET_MODE", "rw-rw----"),
+            ("MC_HEALTH_SOCKET_MODE", "1777"),
+        ] {
+            let mut vars = vars.clone();
+            vars.insert(var.to_string(), value.to_string());
+            assert!(UnixSocketConfig::from_vars(&vars, "MC_HEALTH").is_err());
+        }
+
+        let mode_only = HashMap::from([("MC_HEALTH_SOCKET_MODE".to_string(), "600".to_string())]);
+        assert!(UnixSocketConfig::from_vars(&mode_only, "MC_HEALTH").is_err());
+    }
+
+    #[cfg(unix)]
+    fn temp_socket_path() -> PathBuf {
+        std::env::temp_dir().join(format!("dt-listen-{}.sock", uuid::Uuid::new_v4()))
+    }
+
+    #[cfg(unix)]
+    async fn http_get_unix(path: &std::path::Path, uri: &str) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let mut stream = tokio::net::UnixStream::connect(path).await.unwrap();
+        stream
+            .write_all(
+                format!("GET {uri} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+                    .as_bytes(),
+            )
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_serve_unix_applies_mode_and_removes_socket_on_shutdown() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let config = UnixSocketConfig {
+            path: temp_socket_path(),
+            mode: 0o600,
+        };
+        let router = Router::new().route("/health", axum::routing::get(|| async { "OK" }));
+        let (stop_tx, stop_rx) = tokio::sync::oneshot::channel::<()>();
+        let server = serve_unix(&config, router, async move {
+            let _ = stop_rx.await;
+        })
+        .unwrap();
+        let server = tokio::spawn(server);
+
+        let mode = std::fs::metadata(&config.path)
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        let response = http_get_unix(&config.path, "/health").await;
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(response.ends_with("OK"), "{response}");
+
+        stop_tx.send(()).unwrap();
+        server.await.unwrap();
+        assert!(!config.path.exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_serve_unix_replaces_stale_socket_only() {
+        let config = UnixSocketConfig {
+            path: temp_socket_path(),
+            mode: DEFAULT_UNIX_SOCKET_MODE,
+        };
+
+        // A socket file nobody listens on is stale and gets replaced
+        drop(std::os::unix::net::UnixListener::bind(&config.path).unwrap());
+        let server = serve_unix(&config, Router::new(), std::future::pending()).unwrap();
+        let server = tokio::spawn(server);
+
+        // A live socket is not taken over
+        let err = serve_unix(&config, Router::new(), std::future::pending())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::AddrInUse);
+        server.abort();
+        std::fs::remove_file(&config.path).unwrap();
+
+        // Nor is a regular file
+        std::fs::write(&config.path, b"not a socket").unwrap();
+        let err = serve_unix(&config, Router::new(), std::future::pending())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        std::fs::remove_file(&config.path).unwrap();
+    }
+}
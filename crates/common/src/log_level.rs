@@ -0,0 +1,313 @@
+//! Runtime-tunable tracing filter.
+//!
+//! [`init_tracing`] installs the log subscriber every service uses, with
+//! its `EnvFilter` behind a `tracing_subscriber::reload` layer. The returned
+//! [`LogLevelHandle`] backs the `/debug/log-level` endpoint from
+//! [`log_level_router`], which lets on-call raise a single module to `debug`
+//! during an incident and revert afterwards without restarting the pod:
+//!
+//! ```text
+//! GET    /debug/log-level                          -> current and startup filter
+//! PUT    /debug/log-level {"filter": "mc_service::actors=debug,info"}
+//! DELETE /debug/log-level                          -> back to the startup filter
+//! ```
+//!
+//! The router itself is unauthenticated. Services mount it behind a check for
+//! the [`DEBUG_SCOPE`](crate::debug_auth::DEBUG_SCOPE) service-token scope.
+
+use crate::config::LogFormat;
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing_subscriber::{
+    layer::{Layered, SubscriberExt},
+    reload,
+    util::SubscriberInitExt,
+    EnvFilter, Layer, Registry,
+};
+
+/// Path of the log-level endpoint.
+pub const LOG_LEVEL_PATH: &str = "/debug/log-level";
+
+/// The filtered registry that extra layers passed to [`init_tracing_with`]
+/// are stacked on; they only see spans and events the filter enables.
+pub type FilteredRegistry = Layered<reload::Layer<EnvFilter, Registry>, Registry>;
+
+/// An extra layer for [`init_tracing_with`], such as the OTLP trace exporter.
+pub type ExtraLayer = Box<dyn Layer<FilteredRegistry> + Send + Sync>;
+
+/// Errors from changing the log filter.
+#[derive(Debug, thiserror::Error)]
+pub enum LogLevelError {
+    /// The directives are not a valid `EnvFilter`.
+    #[error("Invalid filter: {0}")]
+    InvalidFilter(String),
+
+    /// The subscriber the handle belongs to is gone.
+    #[error("Failed to reload filter: {0}")]
+    Reload(String),
+}
+
+/// Handle to the reloadable filter installed by [`init_tracing`].
+#[derive(Clone, Debug)]
+pub struct LogLevelHandle {
+    reload: reload::Handle<EnvFilter, Registry>,
+    startup: Arc<str>,
+}
+
+impl LogLevelHandle {
+    fn new(reload: reload::Handle<EnvFilter, Registry>, startup: &str) -> Self {
+        Self {
+            reload,
+            startup: startup.into(),
+        }
+    }
+
+    /// Directives of the filter currently in effect.
+    #[must_use]
+    pub fn current(&self) -> String {
+        self.reload
+            .with_current(ToString::to_string)
+            .unwrap_or_default()
+    }
+
+    /// Directives the process started with (`RUST_LOG` or the service default).
+    #[must_use]
+    pub fn startup(&self) -> &str {
+        &self.startup
+    }
+
+    /// Replace the filter with `directives`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LogLevelError::InvalidFilter`] if `directives` does not parse,
+    /// leaving the current filter in place.
+    pub fn set(&self, directives: &str) -> Result<(), LogLevelError> {
+        let filter = EnvFilter::try_new(directives)
+            .map_err(|e| LogLevelError::InvalidFilter(e.to_string()))?;
+        self.reload
+            .reload(filter)
+            .map_err(|e| LogLevelError::Reload(e.to_string()))
+    }
+
+    /// Restore the startup filter.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LogLevelError::Reload`] if the subscriber is gone.
+    pub fn reset(&self) -> Result<(), LogLevelError> {
+        self.set(&self.startup)
+    }
+}
+
+/// Install the global log subscriber and return its filter handle.
+///
+/// The filter comes from `RUST_LOG`, falling back to `default_filter`.
+/// Output is JSON, which Promtail parses without brittle regex, unless
+/// `DT_LOG_FORMAT` or the `dev` profile selects text (see [`LogFormat`]).
+#[must_use]
+pub fn init_tracing(default_filter: &str) -> LogLevelHandle {
+    init_tracing_with(default_filter, None)
+}
+
+/// [`init_tracing`] with an `extra` layer, e.g. the OTLP trace exporter from
+/// `otel::init_otlp` (`otlp` feature) when trace export is on.
+#[must_use]
+pub fn init_tracing_with(default_filter: &str, extra: Option<ExtraLayer>) -> LogLevelHandle {
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_filter));
+    let startup = filter.to_string();
+    let (filter_layer, reload) = reload::Layer::new(filter);
+
+    let format = LogFormat::from_vars(&std::env::vars().collect());
+    let json_layer = (format == LogFormat::Json).then(|| tracing_subscriber::fmt::layer().json());
+    let text_layer = (format == LogFormat::Text).then(tracing_subscriber::fmt::layer);
+
+    tracing_subscriber::registry()
+        .with(filter_layer)
+        .with(extra)
+        .with(json_layer)
+        .with(text_layer)
+        .init();
+
+    LogLevelHandle::new(reload, &startup)
+}
+
+/// Body of `PUT /debug/log-level`.
+#[derive(Debug, Deserialize)]
+struct SetLogLevelRequest {
+    filter: String,
+}
+
+/// Response of every `/debug/log-level` method.
+#[derive(Debug, Serialize)]
+struct LogLevelResponse {
+    filter: String,
+    startup: String,
+}
+
+impl From<&LogLevelHandle> for LogLevelResponse {
+    fn from(handle: &LogLevelHandle) -> Self {
+        Self {
+            filter: handle.current(),
+            startup: handle.startup().to_string(),
+        }
+    }
+}
+
+/// Router serving [`LOG_LEVEL_PATH`]. Unauthenticated: callers must layer an
+/// auth check requiring [`DEBUG_SCOPE`](crate::debug_auth::DEBUG_SCOPE) on top.
+#[must_use]
+pub fn log_level_router(handle: LogLevelHandle) -> Router {
+    Router::new()
+        .route(
+            LOG_LEVEL_PATH,
+            get(get_log_level)
+                .put(set_log_level)
+                .delete(reset_log_level),
+        )
+        .with_state(handle)
+}
+
+async fn get_log_level(State(handle): State<LogLevelHandle>) -> Json<LogLevelResponse> {
+    Json(LogLevelResponse::from(&handle))
+}
+
+async fn set_log_level(
+    State(handle): State<LogLevelHandle>,
+    Json(request): Json<SetLogLevelRequest>,
+) -> Response {
+    let previous = handle.current();
+    match handle.set(&request.filter) {
+        Ok(()) => {
+            tracing::warn!(
+                target: "common.log_level",
+                previous = %previous,
+                filter = %handle.current(),
+                "Log filter changed at runtime"
+            );
+            Json(LogLevelResponse::from(&handle)).into_response()
+        }
+        Err(e) => error_response(&e),
+    }
+}
+
+async fn reset_log_level(State(handle): State<LogLevelHandle>) -> Response {
+    match handle.reset() {
+        Ok(()) => {
+            tracing::warn!(
+                target: "common.log_level",
+                filter = %handle.current(),
+                "Log filter reset to startup value"
+            );
+            Json(LogLevelResponse::from(&handle)).into_response()
+        }
+        Err(e) => error_response(&e),
+    }
+}
+
+fn error_response(error: &LogLevelError) -> Response {
+    let status = match error {
+        LogLevelError::InvalidFilter(_) => StatusCode::BAD_REQUEST,
+        LogLevelError::Reload(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    let body = serde_json::json!({ "error": error.to_string() });
+    (status, Json(body)).into_response()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::header;
+    use tower::ServiceExt;
+
+    /// A handle bound to a local (non-global) subscriber. The subscriber must
+    /// outlive the handle, so it is returned alongside.
+    fn local_handle(directives: &str) -> (LogLevelHandle, impl tracing::Subscriber) {
+        let (layer, reload) = reload::Layer::new(EnvFilter::new(directives));
+        let subscriber = tracing_subscriber::registry().with(layer);
+        (LogLevelHandle::new(reload, directives), subscriber)
+    }
+
+    async fn send(router: Router, request: http::Request<Body>) -> (StatusCode, serde_json::Value) {
+        let response = router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[test]
+    fn test_set_and_reset() {
+        let (handle, _subscriber) = local_handle("info");
+        assert_eq!(handle.current(), "info");
+
+        handle.set("mc_service::actors=debug,info").unwrap();
+        assert!(handle.current().contains("mc_service::actors=debug"));
+        assert_eq!(handle.startup(), "info");
+
+        handle.reset().unwrap();
+        assert_eq!(handle.current(), "info");
+    }
+
+    #[test]
+    fn test_invalid_filter_keeps_current() {
+        let (handle, _subscriber) = local_handle("info");
+
+        let result = handle.set("mc_service=notalevel");
+        assert!(matches!(result, Err(LogLevelError::InvalidFilter(_))));
+        assert_eq!(handle.current(), "info");
+    }
+
+    #[tokio::test]
+    async fn test_router_put_get_delete() {
+        let (handle, _subscriber) = local_handle("warn");
+        let router = log_level_router(handle);
+
+        let put = http::Request::put(LOG_LEVEL_PATH)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"filter":"gc_service=debug"}"#))
+            .unwrap();
+        let (status, body) = send(router.clone(), put).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["filter"], "gc_service=debug");
+        assert_eq!(body["startup"], "warn");
+
+        let get = http::Request::get(LOG_LEVEL_PATH)
+            .body(Body::empty())
+            .unwrap();
+        let (_, body) = send(router.clone(), get).await;
+        assert_eq!(body["filter"], "gc_service=debug");
+
+        let delete = http::Request::delete(LOG_LEVEL_PATH)
+            .body(Body::empty())
+            .unwrap();
+        let (status, body) = send(router, delete).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["filter"], "warn");
+    }
+
+    #[tokio::test]
+    async fn test_router_rejects_invalid_filter() {
+        let (handle, _subscriber) = local_handle("info");
+        let router = log_level_router(handle);
+
+        let put = http::Request::put(LOG_LEVEL_PATH)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"filter":"ac_service=loud"}"#))
+            .unwrap();
+        let (status, body) = send(router, put).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].as_str().unwrap().contains("Invalid filter"));
+    }
+}
@@ -0,0 +1,138 @@
+//! Rate-limited logging for failures that repeat on every retry.
+//!
+//! During a dependency outage a retry loop logs the same warning every few
+//! seconds for as long as the outage lasts. [`warn_throttled!`] logs the first
+//! occurrence immediately, then at most once per [`THROTTLE_INTERVAL`] per
+//! call site. Each emitted line carries a `suppressed` field counting the
+//! occurrences dropped since the previous line, so the volume is still visible.
+//!
+//! ```ignore
+//! common::warn_throttled!(target: "mc.gc", error = %e, "Heartbeat failed");
+//! ```
+//!
+//! [`warn_throttled!`]: crate::warn_throttled
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+#[doc(hidden)]
+pub use tracing as __tracing;
+
+/// Minimum time between two lines from the same call site.
+pub const THROTTLE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Marks a throttle that has never emitted.
+const NEVER: u64 = u64::MAX;
+
+/// Per-call-site state behind [`warn_throttled!`](crate::warn_throttled).
+#[derive(Debug)]
+pub struct LogThrottle {
+    /// Milliseconds since the process epoch of the last emitted line.
+    last_emit_ms: AtomicU64,
+    /// Occurrences dropped since the last emitted line.
+    suppressed: AtomicU64,
+}
+
+impl LogThrottle {
+    /// A throttle that lets the next occurrence through.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            last_emit_ms: AtomicU64::new(NEVER),
+            suppressed: AtomicU64::new(0),
+        }
+    }
+
+    /// Record an occurrence. Returns `Some(suppressed)` if a line should be
+    /// emitted now, or `None` if it falls inside the current interval.
+    pub fn check(&self, interval: Duration) -> Option<u64> {
+        static EPOCH: OnceLock<Instant> = OnceLock::new();
+        self.check_at(EPOCH.get_or_init(Instant::now).elapsed(), interval)
+    }
+
+    fn check_at(&self, now: Duration, interval: Duration) -> Option<u64> {
+        let now_ms = u64::try_from(now.as_millis()).unwrap_or(NEVER - 1);
+        let interval_ms = u64::try_from(interval.as_millis()).unwrap_or(NEVER);
+        let last = self.last_emit_ms.load(Ordering::Relaxed);
+        let due = last == NEVER || now_ms.saturating_sub(last) >= interval_ms;
+
+        // Only one concurrent caller wins the slot; the rest count as suppressed
+        if due
+            && self
+                .last_emit_ms
+                .compare_exchange(last, now_ms, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+        {
+            Some(self.suppressed.swap(0, Ordering::Relaxed))
+        } else {
+            self.suppressed.fetch_add(1, Ordering::Relaxed);
+            None
+        }
+    }
+}
+
+impl Default for LogThrottle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `tracing::warn!` limited to one line per [`THROTTLE_INTERVAL`] per call
+/// site, with a `suppressed` count of the lines dropped in between.
+///
+/// Accepts the same arguments as `tracing::warn!`, including `target:`.
+#[macro_export]
+macro_rules! warn_throttled {
+    (target: $target:expr, $($arg:tt)+) => {{
+        static THROTTLE: $crate::log_throttle::LogThrottle =
+            $crate::log_throttle::LogThrottle::new();
+        if let Some(suppressed) = THROTTLE.check($crate::log_throttle::THROTTLE_INTERVAL) {
+            $crate::log_throttle::__tracing::warn!(target: $target, suppressed, $($arg)+);
+        }
+    }};
+    ($($arg:tt)+) => {{
+        static THROTTLE: $crate::log_throttle::LogThrottle =
+            $crate::log_throttle::LogThrottle::new();
+        if let Some(suppressed) = THROTTLE.check($crate::log_throttle::THROTTLE_INTERVAL) {
+            $crate::log_throttle::__tracing::warn!(suppressed, $($arg)+);
+        }
+    }};
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_first_occurrence_is_emitted() {
+        let throttle = LogThrottle::new();
+        assert_eq!(throttle.check_at(Duration::ZERO, MINUTE), Some(0));
+    }
+
+    #[test]
+    fn test_repeats_are_counted_until_interval_elapses() {
+        let throttle = LogThrottle::new();
+        let at = Duration::from_secs;
+
+        assert_eq!(throttle.check_at(at(100), MINUTE), Some(0));
+        assert_eq!(throttle.check_at(at(110), MINUTE), None);
+        assert_eq!(throttle.check_at(at(120), MINUTE), None);
+        assert_eq!(throttle.check_at(at(159), MINUTE), None);
+        // Summary line reports the three dropped occurrences
+        assert_eq!(throttle.check_at(at(160), MINUTE), Some(3));
+        assert_eq!(throttle.check_at(at(170), MINUTE), None);
+        assert_eq!(throttle.check_at(at(500), MINUTE), Some(1));
+    }
+
+    #[test]
+    fn test_macro_compiles_with_and_without_target() {
+        for attempt in 0..3 {
+            crate::warn_throttled!(target: "common.test", attempt, "Throttled with target");
+            crate::warn_throttled!(attempt = attempt, "Throttled without target");
+        }
+    }
+}
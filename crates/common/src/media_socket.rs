@@ -0,0 +1,311 @@
+//! UDP socket tuning for QUIC media endpoints (MC and MH).
+//!
+//! Enterprise networks prioritize real-time traffic by DSCP code point, and
+//! busy media servers need larger socket buffers than the OS default. Both
+//! services bind their WebTransport socket through [`bind_media_socket`] and
+//! report the [`AppliedSocketOptions`] read back from the kernel, which may
+//! differ from what was requested (Linux doubles buffer sizes and caps them
+//! at `net.core.{w,r}mem_max`).
+//!
+//! # DSCP and QUIC
+//!
+//! One QUIC endpoint carries audio, video, and signaling on the same socket,
+//! so the mark applies to the whole endpoint.
+//!
+//! `quinn-udp` writes ECN bits per packet with an `IP_TOS` / `IPV6_TCLASS`
+//! control message, and on Linux that control message replaces the socket's
+//! TOS byte. The gauge therefore confirms the socket option, not the wire
+//! mark. Where packets leave unmarked, add a host egress rule on the media
+//! port (e.g. an nftables `ip dscp set ef` rule).
+//!
+//! # Configuration
+//!
+//! Each service reads `{PREFIX}_MEDIA_DSCP`, `{PREFIX}_MEDIA_SEND_BUFFER_BYTES`,
+//! `{PREFIX}_MEDIA_RECV_BUFFER_BYTES`, and `{PREFIX}_MEDIA_GSO_ENABLED`.
+
+use serde::{Deserialize, Serialize};
+use socket2::{Domain, Protocol, Socket, Type};
+use std::collections::HashMap;
+use std::io;
+use std::net::{SocketAddr, UdpSocket};
+
+/// Expedited Forwarding (RFC 3246), the usual code point for voice.
+pub const DSCP_EF: u8 = 46;
+
+/// Largest valid DSCP value (6 bits).
+pub const MAX_DSCP: u8 = 63;
+
+/// Requested socket options for a media endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MediaSocketConfig {
+    /// DSCP code point to mark outgoing packets with (`None` = unmarked).
+    pub dscp: Option<u8>,
+    /// `SO_SNDBUF` size in bytes (`None` = OS default).
+    pub send_buffer_bytes: Option<usize>,
+    /// `SO_RCVBUF` size in bytes (`None` = OS default).
+    pub recv_buffer_bytes: Option<usize>,
+    /// Use UDP generic segmentation offload where the platform supports it.
+    pub gso_enabled: bool,
+}
+
+impl Default for MediaSocketConfig {
+    fn default() -> Self {
+        Self {
+            dscp: None,
+            send_buffer_bytes: None,
+            recv_buffer_bytes: None,
+            gso_enabled: true,
+        }
+    }
+}
+
+impl MediaSocketConfig {
+    /// Load from `{prefix}_MEDIA_*` variables; unset variables keep defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the variable if a value cannot be parsed.
+    pub fn from_vars(vars: &HashMap<String, String>, prefix: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+
+        let dscp_var = format!("{prefix}_MEDIA_DSCP");
+        if let Some(value) = vars.get(&dscp_var) {
+            config.dscp = parse_dscp(value).map_err(|e| format!("{dscp_var}: {e}"))?;
+        }
+
+        for (suffix, field) in [
+            ("SEND_BUFFER_BYTES", &mut config.send_buffer_bytes),
+            ("RECV_BUFFER_BYTES", &mut config.recv_buffer_bytes),
+        ] {
+            let var = format!("{prefix}_MEDIA_{suffix}");
+            if let Some(value) = vars.get(&var) {
+                let bytes: usize = value
+                    .parse()
+                    .map_err(|e| format!("{var}: invalid byte count '{value}': {e}"))?;
+                if bytes == 0 {
+                    return Err(format!("{var}: must be greater than 0"));
+                }
+                *field = Some(bytes);
+            }
+        }
+
+        let gso_var = format!("{prefix}_MEDIA_GSO_ENABLED");
+        if let Some(value) = vars.get(&gso_var) {
+            config.gso_enabled = value
+                .parse()
+                .map_err(|_| format!("{gso_var}: expected 'true' or 'false', got '{value}'"))?;
+        }
+
+        Ok(config)
+    }
+}
+
+/// Parse a DSCP setting: `off`, a PHB name (`ef`, `af11`..`af43`,
+/// `cs0`..`cs7`), or a number from 0 to 63.
+///
+/// # Errors
+///
+/// Returns a message if the value is not a known name or is out of range.
+pub fn parse_dscp(value: &str) -> Result<Option<u8>, String> {
+    let value = value.trim().to_ascii_lowercase();
+    if value.is_empty() || value == "off" || value == "none" {
+        return Ok(None);
+    }
+    if value == "ef" {
+        return Ok(Some(DSCP_EF));
+    }
+    if let Some(class) = value.strip_prefix("cs") {
+        if let Ok(class @ 0..=7) = class.parse::<u8>() {
+            return Ok(Some(class << 3));
+        }
+    }
+    if let Some(af) = value.strip_prefix("af") {
+        let mut digits = af.chars().map(|c| c.to_digit(10));
+        if let (Some(Some(class @ 1..=4)), Some(Some(drop @ 1..=3)), None) =
+            (digits.next(), digits.next(), digits.next())
+        {
+            // AFxy = 8x + 2y
+            return Ok(u8::try_from(class * 8 + drop * 2).ok());
+        }
+    }
+    match value.parse::<u8>() {
+        Ok(dscp) if dscp <= MAX_DSCP => Ok(Some(dscp)),
+        _ => Err(format!("invalid DSCP value '{value}'")),
+    }
+}
+
+/// Socket options as reported by the kernel after binding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppliedSocketOptions {
+    /// DSCP code point in the socket's TOS / traffic class.
+    pub dscp: u8,
+    /// Effective `SO_SNDBUF` size in bytes.
+    pub send_buffer_bytes: usize,
+    /// Effective `SO_RCVBUF` size in bytes.
+    pub recv_buffer_bytes: usize,
+}
+
+/// Bind a non-blocking UDP socket with the configured options applied.
+///
+/// IPv6 sockets are dual-stack unless `ipv6_only` is set (see
+/// [`crate::listen::ipv6_only`]). The returned socket is ready to hand to the
+/// QUIC endpoint.
+///
+/// # Errors
+///
+/// Returns the OS error if the socket cannot be created, bound, or an option
+/// is rejected.
+pub fn bind_media_socket(
+    addr: SocketAddr,
+    ipv6_only: bool,
+    config: &MediaSocketConfig,
+) -> io::Result<(UdpSocket, AppliedSocketOptions)> {
+    let socket = Socket::new(Domain::for_address(addr), Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_nonblocking(true)?;
+    if addr.is_ipv6() {
+        socket.set_only_v6(ipv6_only)?;
+    }
+
+    if let Some(bytes) = config.send_buffer_bytes {
+        socket.set_send_buffer_size(bytes)?;
+    }
+    if let Some(bytes) = config.recv_buffer_bytes {
+        socket.set_recv_buffer_size(bytes)?;
+    }
+    if let Some(dscp) = config.dscp {
+        // DSCP is the upper six bits; the lower two belong to ECN.
+        let tos = u32::from(dscp.min(MAX_DSCP)) << 2;
+        if addr.is_ipv4() {
+            socket.set_tos_v4(tos)?;
+        } else {
+            socket.set_tclass_v6(tos)?;
+        }
+    }
+
+    socket.bind(&addr.into())?;
+
+    let tos = if addr.is_ipv4() {
+        socket.tos_v4()?
+    } else {
+        socket.tclass_v6()?
+    };
+    let applied = AppliedSocketOptions {
+        dscp: u8::try_from(tos >> 2).unwrap_or(0) & MAX_DSCP,
+        send_buffer_bytes: socket.send_buffer_size()?,
+        recv_buffer_bytes: socket.recv_buffer_size()?,
+    };
+
+    Ok((socket.into(), applied))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dscp() {
+        assert_eq!(parse_dscp("off"), Ok(None));
+        assert_eq!(parse_dscp(""), Ok(None));
+        assert_eq!(parse_dscp("EF"), Ok(Some(46)));
+        assert_eq!(parse_dscp("af41"), Ok(Some(34)));
+        assert_eq!(parse_dscp("af11"), Ok(Some(10)));
+        assert_eq!(parse_dscp("cs5"), Ok(Some(40)));
+        assert_eq!(parse_dscp("26"), Ok(Some(26)));
+        assert!(parse_dscp("64").is_err());
+        assert!(parse_dscp("af51").is_err());
+        assert!(parse_dscp("cs8").is_err());
+        assert!(parse_dscp("voice").is_err());
+    }
+
+    #[test]
+    fn test_from_vars() {
+        let config = MediaSocketConfig::from_vars(&HashMap::new(), "MH").unwrap();
+        assert_eq!(config, MediaSocketConfig::default());
+
+        let vars = HashMap::from([
+            ("MH_MEDIA_DSCP".to_string(), "ef".to_string()),
+            (
+                "MH_MEDIA_SEND_BUFFER_BYTES".to_string(),
+                "4194304".to_string(),
+            ),
+            (
+                "MH_MEDIA_RECV_BUFFER_BYTES".to_string(),
+                "8388608".to_string(),
+            ),
+            ("MH_MEDIA_GSO_ENABLED".to_string(), "false".to_string()),
+        ]);
+        let config = MediaSocketConfig::from_vars(&vars, "MH").unwrap();
+        assert_eq!(config.dscp, Some(DSCP_EF));
+        assert_eq!(config.send_buffer_bytes, Some(4_194_304));
+        assert_eq!(config.recv_buffer_bytes, Some(8_388_608));
+        assert!(!config.gso_enabled);
+
+        // Prefix is respected
+        assert_eq!(
+            MediaSocketConfig::from_vars(&vars, "MC").unwrap(),
+            MediaSocketConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_from_vars_rejects_invalid_values() {
+        for (var, value) in [
+            ("MC_MEDIA_DSCP", "99"),
+            ("MC_MEDIA_SEND_BUFFER_BYTES", "0"),
+            ("MC_MEDIA_RECV_BUFFER_BYTES", "lots"),
+            ("MC_MEDIA_GSO_ENABLED", "yes"),
+        ] {
+            let vars = HashMap::from([(var.to_string(), value.to_string())]);
+            let err = MediaSocketConfig::from_vars(&vars, "MC").unwrap_err();
+            assert!(err.starts_with(var), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_bind_media_socket_applies_options() {
+        let config = MediaSocketConfig {
+            dscp: Some(DSCP_EF),
+            send_buffer_bytes: Some(256 * 1024),
+            recv_buffer_bytes: Some(256 * 1024),
+            gso_enabled: true,
+        };
+        let (socket, applied) =
+            bind_media_socket("127.0.0.1:0".parse().unwrap(), false, &config).unwrap();
+
+        assert_ne!(socket.local_addr().unwrap().port(), 0);
+        assert_eq!(applied.dscp, DSCP_EF);
+        // The kernel may round or cap buffer sizes; it must not ignore them
+        assert!(applied.send_buffer_bytes > 0);
+        assert!(applied.recv_buffer_bytes > 0);
+    }
+
+    #[test]
+    fn test_dual_stack_media_socket_receives_both_families() {
+        if std::net::UdpSocket::bind("[::1]:0").is_err() {
+            eprintln!("skipping: IPv6 unavailable");
+            return;
+        }
+        let (socket, _) = bind_media_socket(
+            "[::]:0".parse().unwrap(),
+            false,
+            &MediaSocketConfig::default(),
+        )
+        .unwrap();
+        socket.set_nonblocking(false).unwrap();
+        let port = socket.local_addr().unwrap().port();
+
+        for client_addr in ["127.0.0.1:0", "[::1]:0"] {
+            let client = UdpSocket::bind(client_addr).unwrap();
+            let target = if client_addr.starts_with('[') {
+                format!("[::1]:{port}")
+            } else {
+                format!("127.0.0.1:{port}")
+            };
+            client.send_to(b"ping", target).unwrap();
+            let mut buf = [0u8; 4];
+            let (len, _) = socket.recv_from(&mut buf).unwrap();
+            assert_eq!(buf.get(..len), Some(&b"ping"[..]));
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! Media tokens binding MH connections to an MC signaling session.
+//!
+//! The meeting JWT alone lets anyone holding it open media connections for
+//! the whole token lifetime, whether or not they ever joined through MC.
+//! After a successful join, MC mints a short-lived media token for that
+//! signaling session and returns it in the `JoinResponse`; the client
+//! presents it, alongside the meeting JWT, in its `MhConnectRequest`. MH
+//! only accepts media from connections whose token verifies and names the
+//! same meeting and subject as the JWT.
+//!
+//! # Format
+//!
+//! ```text
+//! v1.<base64url(claims JSON)>.<base64url(HMAC-SHA256(key, "v1.<claims>"))>
+//! ```
+//!
+//! MC and MH share a secret (`MC_MEDIA_TOKEN_SECRET` / `MH_MEDIA_TOKEN_SECRET`);
+//! the HMAC key is derived from it with HKDF-SHA256 (`info = "media-token"`)
+//! so the secret is never used directly.
+//!
+//! # Security
+//!
+//! - Tokens are size-checked before decoding
+//! - The MAC is checked (constant time) before the claims are parsed
+//! - [`MEDIA_TOKEN_TTL`] bounds how long a leaked token is useful; a client
+//!   that reconnects to MC gets a fresh one with its new `JoinResponse`
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use ring::{hkdf, hmac};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+use thiserror::Error;
+
+/// How long a media token is valid after MC mints it.
+pub const MEDIA_TOKEN_TTL: Duration = Duration::from_secs(120);
+
+/// Minimum shared secret length in bytes.
+pub const MIN_SECRET_LENGTH: usize = 32;
+
+/// Maximum accepted token size in bytes.
+pub const MAX_MEDIA_TOKEN_SIZE: usize = 1024;
+
+/// Version prefix of the token format.
+const VERSION: &str = "v1";
+
+/// HKDF `info` for the token MAC key.
+const KEY_INFO: &[u8] = b"media-token";
+
+/// Errors from creating a [`MediaTokenKey`] or verifying a token.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MediaTokenError {
+    /// The shared secret is not base64 or is shorter than [`MIN_SECRET_LENGTH`].
+    #[error("Media token secret must be base64 and at least 32 bytes")]
+    InvalidSecret,
+
+    /// The client sent no media token.
+    #[error("The media token is invalid")]
+    Missing,
+
+    /// Too large, wrong version, or not three base64 parts with JSON claims.
+    #[error("The media token is invalid")]
+    Malformed,
+
+    /// The MAC does not verify (wrong secret or tampered claims).
+    #[error("The media token is invalid")]
+    InvalidSignature,
+
+    /// `exp` has passed.
+    #[error("The media token is invalid")]
+    Expired,
+}
+
+impl MediaTokenError {
+    /// Bounded label for the `failure_reason` of JWT validation metrics.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::InvalidSecret => "invalid_secret",
+            Self::Missing => "missing",
+            Self::Malformed => "malformed",
+            Self::InvalidSignature => "signature_invalid",
+            Self::Expired => "expired",
+        }
+    }
+}
+
+/// Claims carried by a media token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MediaTokenClaims {
+    /// Meeting the signaling session joined.
+    pub meeting_id: String,
+    /// Subject (`sub`) of the meeting JWT the session joined with.
+    pub sub: String,
+    /// MC's participant ID for the session.
+    pub participant_id: String,
+    /// Expiry, Unix seconds.
+    pub exp: i64,
+}
+
+/// Signs and verifies media tokens with the MC/MH shared secret.
+pub struct MediaTokenKey {
+    key: hmac::Key,
+}
+
+impl fmt::Debug for MediaTokenKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MediaTokenKey")
+            .field("key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl MediaTokenKey {
+    /// Derive the token key from the shared secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MediaTokenError::InvalidSecret`] if `secret` is shorter than
+    /// [`MIN_SECRET_LENGTH`].
+    pub fn from_secret(secret: &[u8]) -> Result<Self, MediaTokenError> {
+        if secret.len() < MIN_SECRET_LENGTH {
+            return Err(MediaTokenError::InvalidSecret);
+        }
+        let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, &[]).extract(secret);
+        let okm = prk
+            .expand(&[KEY_INFO], hmac::HMAC_SHA256)
+            .map_err(|_| MediaTokenError::InvalidSecret)?;
+        Ok(Self {
+            key: hmac::Key::from(okm),
+        })
+    }
+
+    /// Derive the token key from a base64-encoded shared secret, as read
+    /// from the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MediaTokenError::InvalidSecret`] if `secret` is not valid
+    /// standard base64 or decodes to fewer than [`MIN_SECRET_LENGTH`] bytes.
+    pub fn from_base64(secret: &str) -> Result<Self, MediaTokenError> {
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(secret)
+            .map_err(|_| MediaTokenError::InvalidSecret)?;
+        Self::from_secret(&bytes)
+    }
+
+    /// Mint a token carrying `claims`.
+    #[must_use]
+    pub fn sign(&self, claims: &MediaTokenClaims) -> String {
+        // Serializing a struct of strings and an integer cannot fail
+        let payload = serde_json::to_vec(claims).unwrap_or_default();
+        let signed = format!("{VERSION}.{}", URL_SAFE_NO_PAD.encode(payload));
+        let tag = hmac::sign(&self.key, signed.as_bytes());
+        format!("{signed}.{}", URL_SAFE_NO_PAD.encode(tag.as_ref()))
+    }
+
+    /// Verify `token` and return its claims.
+    ///
+    /// `now` is the current time in Unix seconds. Callers still have to
+    /// check the claims against the meeting JWT presented with the token.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`MediaTokenError`] describing why the token was rejected.
+    pub fn verify(&self, token: &str, now: i64) -> Result<MediaTokenClaims, MediaTokenError> {
+        if token.is_empty() {
+            return Err(MediaTokenError::Missing);
+        }
+        if token.len() > MAX_MEDIA_TOKEN_SIZE {
+            return Err(MediaTokenError::Malformed);
+        }
+
+        let (signed, tag) = token.rsplit_once('.').ok_or(MediaTokenError::Malformed)?;
+        let (version, payload) = signed.split_once('.').ok_or(MediaTokenError::Malformed)?;
+        if version != VERSION {
+            return Err(MediaTokenError::Malformed);
+        }
+        let tag = URL_SAFE_NO_PAD
+            .decode(tag)
+            .map_err(|_| MediaTokenError::Malformed)?;
+        hmac::verify(&self.key, signed.as_bytes(), &tag)
+            .map_err(|_| MediaTokenError::InvalidSignature)?;
+
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| MediaTokenError::Malformed)?;
+        let claims: MediaTokenClaims =
+            serde_json::from_slice(&payload).map_err(|_| MediaTokenError::Malformed)?;
+        if claims.exp <= now {
+            return Err(MediaTokenError::Expired);
+        }
+        Ok(claims)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn key(byte: u8) -> MediaTokenKey {
+        MediaTokenKey::from_secret(&[byte; 32]).unwrap()
+    }
+
+    fn claims() -> MediaTokenClaims {
+        MediaTokenClaims {
+            meeting_id: "meeting-1".to_string(),
+            sub: "user-1".to_string(),
+            participant_id: "participant-1".to_string(),
+            exp: NOW + 120,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let key = key(7);
+        let token = key.sign(&claims());
+        assert!(token.starts_with("v1."));
+        assert_eq!(key.verify(&token, NOW).unwrap(), claims());
+    }
+
+    #[test]
+    fn test_other_secret_rejected() {
+        let token = key(7).sign(&claims());
+        assert_eq!(
+            key(8).verify(&token, NOW),
+            Err(MediaTokenError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_tampered_claims_rejected() {
+        let key = key(7);
+        let token = key.sign(&claims());
+        let forged = key.sign(&MediaTokenClaims {
+            meeting_id: "meeting-2".to_string(),
+            ..claims()
+        });
+        // Forged claims with the original MAC
+        let (_, tag) = token.rsplit_once('.').unwrap();
+        let (forged_signed, _) = forged.rsplit_once('.').unwrap();
+        assert_eq!(
+            key.verify(&format!("{forged_signed}.{tag}"), NOW),
+            Err(MediaTokenError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_expired_rejected() {
+        let key = key(7);
+        let token = key.sign(&claims());
+        assert_eq!(key.verify(&token, NOW + 120), Err(MediaTokenError::Expired));
+    }
+
+    #[test]
+    fn test_malformed_rejected() {
+        let key = key(7);
+        assert_eq!(key.verify("", NOW), Err(MediaTokenError::Missing));
+        for token in [
+            "not-a-token",
+            "v1.only-two",
+            "v2.e30.AAAA",
+            "v1.e30.!!!",
+            &"a".repeat(MAX_MEDIA_TOKEN_SIZE + 1),
+        ] {
+            assert_eq!(
+                key.verify(token, NOW),
+                Err(MediaTokenError::Malformed),
+                "{token}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_short_secret_rejected() {
+        assert!(MediaTokenKey::from_secret(&[0; 31]).is_err());
+        assert!(MediaTokenKey::from_base64("not base64!").is_err());
+        assert!(MediaTokenKey::from_base64(
+            &base64::engine::general_purpose::STANDARD.encode([1; 32])
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,435 @@
+//! Rolling-deploy compatibility checks for database migrations.
+//!
+//! During a rolling deploy the previous AC/GC release keeps serving traffic
+//! against a database that has already been migrated to HEAD. Replaying the
+//! old binaries in CI is impractical, so each service pins the SQL its last
+//! release runs in a query file, and [`check`] replays those statements
+//! against a freshly migrated database.
+//!
+//! # Query file format
+//!
+//! Plain SQL with `-- @` directives:
+//!
+//! ```text
+//! -- @setup
+//! INSERT INTO organizations (org_id, subdomain, display_name)
+//! VALUES ('00000000-0000-0000-0000-00000000000a', 'compat', 'Compat Org');
+//!
+//! -- @query organizations.get_by_subdomain
+//! -- @param text 'compat'
+//! -- @returns org_id UUID, subdomain VARCHAR
+//! SELECT org_id, subdomain FROM organizations WHERE subdomain = $1
+//! ```
+//!
+//! - `@setup` (at most once) seeds fixture rows before any query runs.
+//! - `@query <name>` starts a statement; everything up to the next `@query`
+//!   is its SQL.
+//! - `@param <type> <literal>` declares `$1`, `$2`, ... in order, with the
+//!   type the old binary binds and a SQL literal to execute with.
+//! - `@returns <column> <TYPE>, ...` pins the result columns the old binary
+//!   decodes (may repeat; sqlx type names, case-insensitive). Omit it for
+//!   statements without a result set.
+//!
+//! Every query is prepared, which catches dropped or renamed columns and
+//! changed result types, and then executed inside a savepoint, which catches
+//! new `NOT NULL` columns without defaults and tightened constraints. All
+//! writes are rolled back.
+
+use std::fmt;
+use std::path::Path;
+
+use sqlx::postgres::types::Oid;
+use sqlx::postgres::PgTypeInfo;
+use sqlx::{Column, Executor, PgPool, Statement, TypeInfo};
+
+/// A statement the previous release runs, with its pinned result shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PinnedQuery {
+    /// Stable name, e.g. `users.get_by_email`.
+    pub name: String,
+    /// SQL text exactly as the old binary sends it.
+    pub sql: String,
+    /// `(type, literal)` per positional parameter.
+    pub params: Vec<(String, String)>,
+    /// `(column, type)` per result column; empty for statements without rows.
+    pub returns: Vec<(String, String)>,
+}
+
+/// A parsed query file: fixtures plus the pinned statements.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PinnedQueries {
+    /// SQL run once before the queries (may contain several statements).
+    pub setup: String,
+    /// Pinned statements in file order.
+    pub queries: Vec<PinnedQuery>,
+}
+
+/// Malformed query file.
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("failed to read query file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("line {line}: {message}")]
+    Syntax { line: usize, message: String },
+}
+
+/// A pinned query that no longer works against the migrated schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Incompatibility {
+    /// Query name, or `@setup` when the fixtures themselves failed.
+    pub query: String,
+    /// What broke.
+    pub problem: String,
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.query, self.problem)
+    }
+}
+
+enum Section {
+    None,
+    Setup,
+    Query(PinnedQuery),
+}
+
+impl PinnedQueries {
+    /// Read and parse a query file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError`] if the file cannot be read or is malformed.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ParseError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parse query file contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Syntax`] for unknown directives, directives
+    /// outside a query, duplicate names or setup sections, and queries
+    /// without SQL.
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        let mut pinned = Self::default();
+        let mut section = Section::None;
+
+        for (index, line) in source.lines().enumerate() {
+            let line_no = index + 1;
+            let syntax = |message: String| ParseError::Syntax {
+                line: line_no,
+                message,
+            };
+
+            let Some(directive) = line.trim_start().strip_prefix("-- @") else {
+                match &mut section {
+                    Section::Setup => push_line(&mut pinned.setup, line),
+                    Section::Query(query) => push_line(&mut query.sql, line),
+                    Section::None => {}
+                }
+                continue;
+            };
+
+            let (keyword, rest) = directive
+                .split_once(char::is_whitespace)
+                .map_or((directive.trim(), ""), |(k, r)| (k, r.trim()));
+
+            match keyword {
+                "setup" => {
+                    if !pinned.setup.is_empty() {
+                        return Err(syntax("duplicate @setup section".to_string()));
+                    }
+                    pinned.finish(std::mem::replace(&mut section, Section::Setup), line_no)?;
+                }
+                "query" => {
+                    if rest.is_empty() {
+                        return Err(syntax("@query needs a name".to_string()));
+                    }
+                    if pinned.queries.iter().any(|q| q.name == rest) {
+                        return Err(syntax(format!("duplicate query name `{rest}`")));
+                    }
+                    let next = Section::Query(PinnedQuery {
+                        name: rest.to_string(),
+                        sql: String::new(),
+                        params: Vec::new(),
+                        returns: Vec::new(),
+                    });
+                    pinned.finish(std::mem::replace(&mut section, next), line_no)?;
+                }
+                "param" | "returns" => {
+                    let Section::Query(query) = &mut section else {
+                        return Err(syntax(format!("@{keyword} outside a @query")));
+                    };
+                    if keyword == "param" {
+                        let (ty, literal) =
+                            rest.split_once(char::is_whitespace).ok_or_else(|| {
+                                syntax("@param needs a type and a literal".to_string())
+                            })?;
+                        query
+                            .params
+                            .push((ty.to_string(), literal.trim().to_string()));
+                    } else {
+                        for column in rest.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+                            let (name, ty) = column
+                                .split_once(char::is_whitespace)
+                                .ok_or_else(|| syntax(format!("column `{column}` needs a type")))?;
+                            query
+                                .returns
+                                .push((name.to_string(), ty.trim().to_string()));
+                        }
+                    }
+                }
+                _ => return Err(syntax(format!("unknown directive @{keyword}"))),
+            }
+        }
+
+        let end = source.lines().count();
+        pinned.finish(section, end)?;
+        pinned.setup = pinned.setup.trim().to_string();
+        Ok(pinned)
+    }
+
+    fn finish(&mut self, section: Section, line: usize) -> Result<(), ParseError> {
+        if let Section::Query(mut query) = section {
+            query.sql = query.sql.trim().to_string();
+            if query.sql.is_empty() {
+                return Err(ParseError::Syntax {
+                    line,
+                    message: format!("query `{}` has no SQL", query.name),
+                });
+            }
+            self.queries.push(query);
+        }
+        Ok(())
+    }
+}
+
+fn push_line(buffer: &mut String, line: &str) {
+    buffer.push_str(line);
+    buffer.push('\n');
+}
+
+/// Replay pinned queries against `pool`, which must already be migrated to
+/// HEAD. Returns every incompatibility found; an empty list means the
+/// previous release can run against this schema.
+///
+/// # Errors
+///
+/// Returns a database error only when the harness itself cannot run (no
+/// connection, savepoint failure). Query failures are reported as
+/// [`Incompatibility`] values instead.
+pub async fn check(
+    pool: &PgPool,
+    pinned: &PinnedQueries,
+) -> Result<Vec<Incompatibility>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let mut problems = Vec::new();
+
+    if !pinned.setup.is_empty() {
+        if let Err(e) = sqlx::raw_sql(&pinned.setup).execute(&mut *tx).await {
+            problems.push(Incompatibility {
+                query: "@setup".to_string(),
+                problem: e.to_string(),
+            });
+            return Ok(problems);
+        }
+    }
+
+    for (index, query) in pinned.queries.iter().enumerate() {
+        sqlx::raw_sql("SAVEPOINT migration_compat")
+            .execute(&mut *tx)
+            .await?;
+
+        if let Err(problem) = check_query(&mut tx, index, query).await {
+            problems.push(Incompatibility {
+                query: query.name.clone(),
+                problem,
+            });
+        }
+
+        sqlx::raw_sql("ROLLBACK TO SAVEPOINT migration_compat")
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.rollback().await?;
+    Ok(problems)
+}
+
+async fn check_query(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    index: usize,
+    query: &PinnedQuery,
+) -> Result<(), String> {
+    let mut param_types = Vec::with_capacity(query.params.len());
+    for (ty, _) in &query.params {
+        let oid: Option<Oid> = sqlx::query_scalar("SELECT to_regtype($1)::oid")
+            .bind(ty)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(|e| format!("resolving parameter type `{ty}`: {e}"))?;
+        let oid = oid.ok_or_else(|| format!("unknown parameter type `{ty}`"))?;
+        param_types.push(PgTypeInfo::with_oid(oid));
+    }
+
+    let statement = (&mut **tx)
+        .prepare_with(&query.sql, &param_types)
+        .await
+        .map_err(|e| format!("prepare failed: {e}"))?;
+
+    let actual: Vec<(String, String)> = statement
+        .columns()
+        .iter()
+        .map(|c| (c.name().to_string(), c.type_info().name().to_string()))
+        .collect();
+    let matches = actual.len() == query.returns.len()
+        && actual
+            .iter()
+            .zip(&query.returns)
+            .all(|((name, ty), (want_name, want_ty))| {
+                name == want_name && ty.eq_ignore_ascii_case(want_ty)
+            });
+    if !matches {
+        return Err(format!(
+            "result columns changed: pinned [{}], schema now returns [{}]",
+            render_columns(&query.returns),
+            render_columns(&actual)
+        ));
+    }
+
+    // Prepared under a per-query name so a failed EXECUTE can't collide
+    // with the next statement; names are session-scoped, not rolled back.
+    let name = format!("migration_compat_{index}");
+    let types: Vec<&str> = query.params.iter().map(|(ty, _)| ty.as_str()).collect();
+    let literals: Vec<&str> = query.params.iter().map(|(_, lit)| lit.as_str()).collect();
+    let (declare, execute) = if types.is_empty() {
+        (String::new(), String::new())
+    } else {
+        (
+            format!(" ({})", types.join(", ")),
+            format!("({})", literals.join(", ")),
+        )
+    };
+
+    sqlx::raw_sql(&format!("PREPARE {name}{declare} AS {}", query.sql))
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| format!("prepare failed: {e}"))?;
+    let result = sqlx::raw_sql(&format!("EXECUTE {name}{execute}"))
+        .execute(&mut **tx)
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("execute failed: {e}"));
+
+    // A failed EXECUTE leaves the transaction aborted; DEALLOCATE has to
+    // wait until the caller rolls back to the savepoint.
+    if result.is_ok() {
+        sqlx::raw_sql(&format!("DEALLOCATE {name}"))
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| format!("deallocate failed: {e}"))?;
+    }
+    result
+}
+
+fn render_columns(columns: &[(String, String)]) -> String {
+    columns
+        .iter()
+        .map(|(name, ty)| format!("{name} {ty}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    const FILE: &str = r"
+-- Pinned by the v0.1 release.
+-- @setup
+INSERT INTO organizations (subdomain, display_name) VALUES ('compat', 'Compat');
+
+-- @query organizations.get_by_subdomain
+-- @param text 'compat'
+-- @returns org_id UUID,
+-- @returns subdomain VARCHAR
+SELECT org_id, subdomain
+FROM organizations
+-- trailing comments stay part of the SQL
+WHERE subdomain = $1
+
+-- @query organizations.touch
+UPDATE organizations SET updated_at = NOW()
+";
+
+    #[test]
+    fn test_parse_setup_params_and_returns() {
+        let pinned = PinnedQueries::parse(FILE).unwrap();
+
+        assert_eq!(
+            pinned.setup,
+            "INSERT INTO organizations (subdomain, display_name) VALUES ('compat', 'Compat');"
+        );
+        assert_eq!(pinned.queries.len(), 2);
+
+        let select = &pinned.queries[0];
+        assert_eq!(select.name, "organizations.get_by_subdomain");
+        assert_eq!(
+            select.params,
+            vec![("text".to_string(), "'compat'".to_string())]
+        );
+        assert_eq!(
+            select.returns,
+            vec![
+                ("org_id".to_string(), "UUID".to_string()),
+                ("subdomain".to_string(), "VARCHAR".to_string()),
+            ]
+        );
+        assert!(select.sql.starts_with("SELECT org_id, subdomain"));
+        assert!(select.sql.ends_with("WHERE subdomain = $1"));
+
+        let update = &pinned.queries[1];
+        assert!(update.params.is_empty());
+        assert!(update.returns.is_empty());
+    }
+
+    #[test]
+    fn test_param_literal_keeps_spaces() {
+        let pinned =
+            PinnedQueries::parse("-- @query q\n-- @param text 'two words'\nSELECT $1").unwrap();
+        assert_eq!(pinned.queries[0].params[0].1, "'two words'");
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_files() {
+        let cases = [
+            ("-- @param text 'x'\nSELECT 1", "outside a @query"),
+            ("-- @query q\n-- @bogus\nSELECT 1", "unknown directive"),
+            ("-- @query q\n-- @query q\nSELECT 1", "duplicate query name"),
+            ("-- @query q\n\n-- @query r\nSELECT 1", "has no SQL"),
+            ("-- @query q\n-- @returns id\nSELECT 1", "needs a type"),
+            ("-- @query\nSELECT 1", "needs a name"),
+            (
+                "-- @setup\nSELECT 1;\n-- @setup\nSELECT 2;",
+                "duplicate @setup",
+            ),
+        ];
+
+        for (source, expected) in cases {
+            let err = PinnedQueries::parse(source).unwrap_err().to_string();
+            assert!(err.contains(expected), "{source:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_incompatibility_display() {
+        let problem = Incompatibility {
+            query: "users.create".to_string(),
+            problem: "execute failed".to_string(),
+        };
+        assert_eq!(problem.to_string(), "users.create: execute failed");
+    }
+}
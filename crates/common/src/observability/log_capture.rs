@@ -0,0 +1,387 @@
+// Test-utility module behind the `test-utils` feature. Failed assertions
+// panic by design (every public assertion is a panic, so `# Panics` docs
+// would only restate the function), and a poisoned capture buffer is a
+// fail-fast for the test.
+#![allow(clippy::panic, clippy::expect_used, clippy::missing_panics_doc)]
+
+//! `LogCapture`: capture `tracing` events in tests and assert on them.
+//!
+//! Component tests use this to check that a code path logged what operators
+//! rely on (a `WARN` when a write is fenced out) and, just as important,
+//! that it did not log what it must not (tokens, passwords, secrets).
+//!
+//! # Usage
+//!
+//! ```ignore
+//! use common::observability::log_capture::LogCapture;
+//! use common::{assert_logged, assert_not_logged};
+//!
+//! let _capture = LogCapture::start();
+//! run_code_under_test().await;
+//!
+//! assert_logged!(level: WARN, contains: "fenced");
+//! assert_not_logged!(contains: client_secret);
+//! ```
+//!
+//! The macros check the capture started on the current thread. Methods on
+//! [`LogCapture`] do the same checks for tests that prefer an explicit
+//! handle.
+//!
+//! # What is captured
+//!
+//! Every event at every level, as its level, target and a rendered line:
+//! the `message` followed by each other field as `name=value`. Span fields
+//! are not included; secrets logged as span fields are checked by asserting
+//! on the events inside the span.
+//!
+//! # Threads
+//!
+//! [`LogCapture::start`] installs the capturing subscriber as the
+//! *thread-local* default, with the same reach as `MetricAssertion`
+//! (see [`super::testing`]): events from the test thread and from futures
+//! `.await`-ed on a current-thread runtime (the `#[tokio::test]` default)
+//! are captured; events from `std::thread::spawn`, `tokio::spawn` on a
+//! multi-thread runtime, or `spawn_blocking` are not. `LogCapture` is
+//! `!Send`, so it cannot leave the test thread.
+//!
+//! Starting a second capture on a thread while one is live routes events to
+//! the newer capture until it drops, then restores the older one.
+
+use std::cell::RefCell;
+use std::fmt::{self, Write as _};
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::subscriber::DefaultGuard;
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+pub use tracing::Level;
+
+type EventBuffer = Arc<Mutex<Vec<CapturedEvent>>>;
+
+thread_local! {
+    /// Buffers of the live captures on this thread, innermost last.
+    static ACTIVE: RefCell<Vec<EventBuffer>> = const { RefCell::new(Vec::new()) };
+}
+
+/// One captured `tracing` event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedEvent {
+    pub level: Level,
+    pub target: String,
+    /// The `message` field followed by the other fields as `name=value`.
+    pub line: String,
+}
+
+impl fmt::Display for CapturedEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.level, self.target, self.line)
+    }
+}
+
+/// Captures `tracing` events on the current thread while alive.
+#[must_use = "events are only captured while the LogCapture is alive"]
+pub struct LogCapture {
+    events: EventBuffer,
+    // Restores the previous default subscriber on drop
+    _guard: DefaultGuard,
+}
+
+impl LogCapture {
+    /// Start capturing events on the current thread.
+    pub fn start() -> Self {
+        let events = EventBuffer::default();
+        let subscriber = tracing_subscriber::registry().with(CaptureLayer {
+            events: Arc::clone(&events),
+        });
+        let guard = tracing::subscriber::set_default(subscriber);
+        ACTIVE.with(|active| active.borrow_mut().push(Arc::clone(&events)));
+        Self {
+            events,
+            _guard: guard,
+        }
+    }
+
+    /// All events captured so far, oldest first.
+    #[must_use]
+    pub fn events(&self) -> Vec<CapturedEvent> {
+        self.events.lock().expect("log capture poisoned").clone()
+    }
+
+    /// Panic unless an event at `level` (any level if `None`) contains
+    /// `needle`.
+    #[track_caller]
+    pub fn assert_logged(&self, level: Option<Level>, needle: &str) {
+        assert_logged_in(&self.events(), level, needle);
+    }
+
+    /// Panic if any event at `level` (any level if `None`) contains
+    /// `needle`.
+    #[track_caller]
+    pub fn assert_not_logged(&self, level: Option<Level>, needle: &str) {
+        assert_not_logged_in(&self.events(), level, needle);
+    }
+}
+
+impl Drop for LogCapture {
+    fn drop(&mut self) {
+        ACTIVE.with(|active| {
+            active
+                .borrow_mut()
+                .retain(|events| !Arc::ptr_eq(events, &self.events));
+        });
+    }
+}
+
+/// Panic unless the current thread's capture saw a matching event.
+///
+/// Backs [`assert_logged!`](crate::assert_logged).
+#[track_caller]
+pub fn assert_logged(level: Option<Level>, needle: &str) {
+    assert_logged_in(&active_events(), level, needle);
+}
+
+/// Panic if the current thread's capture saw a matching event.
+///
+/// Backs [`assert_not_logged!`](crate::assert_not_logged).
+#[track_caller]
+pub fn assert_not_logged(level: Option<Level>, needle: &str) {
+    assert_not_logged_in(&active_events(), level, needle);
+}
+
+#[track_caller]
+fn active_events() -> Vec<CapturedEvent> {
+    let events = ACTIVE.with(|active| active.borrow().last().cloned());
+    let Some(events) = events else {
+        panic!("no LogCapture is active on this thread; call LogCapture::start() first");
+    };
+    let captured = events.lock().expect("log capture poisoned");
+    captured.clone()
+}
+
+fn matches(event: &CapturedEvent, level: Option<Level>, needle: &str) -> bool {
+    level.is_none_or(|level| event.level == level) && event.line.contains(needle)
+}
+
+fn describe(level: Option<Level>) -> String {
+    level.map_or_else(|| "any level".to_string(), |level| level.to_string())
+}
+
+#[track_caller]
+fn assert_logged_in(events: &[CapturedEvent], level: Option<Level>, needle: &str) {
+    if events.iter().any(|event| matches(event, level, needle)) {
+        return;
+    }
+    let captured: Vec<String> = events.iter().map(ToString::to_string).collect();
+    panic!(
+        "expected an event at {} containing {:?}; captured:\n{}",
+        describe(level),
+        needle,
+        captured.join("\n")
+    );
+}
+
+#[track_caller]
+fn assert_not_logged_in(events: &[CapturedEvent], level: Option<Level>, needle: &str) {
+    // Do not echo `needle` or the matching line: the point of the check is
+    // often that `needle` is a secret.
+    let count = events
+        .iter()
+        .filter(|event| matches(event, level, needle))
+        .count();
+    assert!(
+        count == 0,
+        "expected no event at {} containing the given text; {} matched",
+        describe(level),
+        count
+    );
+}
+
+/// Assert that the current thread's [`LogCapture`] saw a matching event.
+///
+/// ```ignore
+/// assert_logged!(level: WARN, contains: "fenced");
+/// assert_logged!(contains: "meeting_id=m-1");
+/// ```
+///
+/// [`LogCapture`]: crate::observability::log_capture::LogCapture
+#[macro_export]
+macro_rules! assert_logged {
+    (level: $level:ident, contains: $needle:expr $(,)?) => {
+        $crate::observability::log_capture::assert_logged(
+            ::std::option::Option::Some($crate::observability::log_capture::Level::$level),
+            ::std::convert::AsRef::<str>::as_ref(&$needle),
+        )
+    };
+    (contains: $needle:expr $(,)?) => {
+        $crate::observability::log_capture::assert_logged(
+            ::std::option::Option::None,
+            ::std::convert::AsRef::<str>::as_ref(&$needle),
+        )
+    };
+}
+
+/// Assert that the current thread's [`LogCapture`] saw no matching event.
+///
+/// The panic message never includes the searched text, so this is safe to
+/// use with real secrets.
+///
+/// ```ignore
+/// assert_not_logged!(contains: client_secret);
+/// assert_not_logged!(level: ERROR, contains: "panicked");
+/// ```
+///
+/// [`LogCapture`]: crate::observability::log_capture::LogCapture
+#[macro_export]
+macro_rules! assert_not_logged {
+    (level: $level:ident, contains: $needle:expr $(,)?) => {
+        $crate::observability::log_capture::assert_not_logged(
+            ::std::option::Option::Some($crate::observability::log_capture::Level::$level),
+            ::std::convert::AsRef::<str>::as_ref(&$needle),
+        )
+    };
+    (contains: $needle:expr $(,)?) => {
+        $crate::observability::log_capture::assert_not_logged(
+            ::std::option::Option::None,
+            ::std::convert::AsRef::<str>::as_ref(&$needle),
+        )
+    };
+}
+
+/// Layer that renders each event into the capture buffer.
+struct CaptureLayer {
+    events: EventBuffer,
+}
+
+impl<S: Subscriber> Layer<S> for CaptureLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = LineVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        self.events
+            .lock()
+            .expect("log capture poisoned")
+            .push(CapturedEvent {
+                level: *metadata.level(),
+                target: metadata.target().to_string(),
+                line: visitor.finish(),
+            });
+    }
+}
+
+#[derive(Default)]
+struct LineVisitor {
+    message: String,
+    fields: String,
+}
+
+impl LineVisitor {
+    fn finish(self) -> String {
+        if self.fields.is_empty() {
+            self.message
+        } else if self.message.is_empty() {
+            self.fields
+        } else {
+            format!("{} {}", self.message, self.fields)
+        }
+    }
+}
+
+impl Visit for LineVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            self.record_debug(field, &format_args!("{value}"));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            if !self.fields.is_empty() {
+                self.fields.push(' ');
+            }
+            let _ = write!(self.fields, "{}={value:?}", field.name());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_level_message_and_fields() {
+        let capture = LogCapture::start();
+        tracing::warn!(meeting_id = "m-1", generation = 7, "write fenced out");
+
+        let events = capture.events();
+        assert_eq!(
+            events,
+            vec![CapturedEvent {
+                level: Level::WARN,
+                target: module_path!().to_string(),
+                line: "write fenced out meeting_id=m-1 generation=7".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn macros_check_active_capture() {
+        let _capture = LogCapture::start();
+        tracing::warn!("write fenced out");
+        tracing::info!(client_id = "svc-a", "token issued");
+
+        assert_logged!(level: WARN, contains: "fenced");
+        assert_logged!(contains: "client_id=svc-a");
+        assert_not_logged!(level: ERROR, contains: "fenced");
+        assert_not_logged!(contains: "super-secret");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected an event at WARN")]
+    fn assert_logged_fails_on_level_mismatch() {
+        let capture = LogCapture::start();
+        tracing::info!("write fenced out");
+
+        capture.assert_logged(Some(Level::WARN), "fenced");
+    }
+
+    #[test]
+    fn assert_not_logged_does_not_echo_needle() {
+        let capture = LogCapture::start();
+        tracing::debug!(password = "hunter2", "login attempt");
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            capture.assert_not_logged(None, "hunter2");
+        }));
+        let payload = result.expect_err("secret was logged");
+        let message = payload
+            .downcast_ref::<String>()
+            .expect("panic message should be a String");
+        assert!(!message.contains("hunter2"));
+    }
+
+    #[test]
+    #[should_panic(expected = "no LogCapture is active")]
+    fn macros_require_active_capture() {
+        assert_logged!(contains: "anything");
+    }
+
+    #[test]
+    fn nested_capture_restores_outer() {
+        let outer = LogCapture::start();
+        {
+            let inner = LogCapture::start();
+            tracing::info!("inner event");
+            inner.assert_logged(None, "inner event");
+        }
+        tracing::info!("outer event");
+
+        outer.assert_logged(None, "outer event");
+        outer.assert_not_logged(None, "inner event");
+        assert_logged!(contains: "outer event");
+    }
+}
@@ -1,13 +1,23 @@
 //! Observability utilities shared across Dark Tower services.
 //!
 //! This module is a home for cross-service observability primitives that
-//! must not live inside a single service crate. At present it hosts only
-//! the test-side `MetricAssertion` helper (see [`testing`]).
+//! must not live inside a single service crate: the Tokio runtime sampler
+//! (see [`runtime`]) and the test-side helpers: `MetricAssertion` (see
+//! [`testing`]), `PrometheusCapture` (see [`prometheus_testing`]) and
+//! `LogCapture` (see [`log_capture`]).
 //!
-//! The `testing` submodule is only compiled when `cfg(test)` is active or
+//! The test-side submodules are only compiled when `cfg(test)` is active or
 //! the `test-utils` feature is enabled, so production builds of consumer
-//! services do not pull in `metrics-util` or the `metrics` facade through
-//! this crate.
+//! services do not pull in `metrics-util`, the `metrics` facade or the
+//! Prometheus exporter through this crate.
+
+pub mod runtime;
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod log_capture;
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod prometheus_testing;
 
 #[cfg(any(test, feature = "test-utils"))]
 pub mod testing;
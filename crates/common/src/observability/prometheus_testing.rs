@@ -0,0 +1,424 @@
+// Test-utility module behind the `test-utils` feature. Counts are rendered
+// as floats by the exporter and converted back to integers here; they are
+// always small non-negative whole numbers in tests.
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::doc_markdown
+)]
+
+//! `PrometheusCapture`: assert on rendered Prometheus output in unit tests.
+//!
+//! `MetricAssertion` (see [`super::testing`]) checks what code *emits*.
+//! This helper checks what `/metrics` *serves*: it binds a fresh
+//! `metrics-exporter-prometheus` recorder to the current thread, so the
+//! metric names, label sets and histogram buckets a scraper sees can be
+//! asserted without the process-global recorder. Only one global recorder
+//! can ever be installed per process, which is why route tests that call a
+//! service's `init_metrics_recorder()` have to bail out when another test
+//! installed one first.
+//!
+//! # Usage
+//!
+//! ```ignore
+//! use common::observability::prometheus_testing::PrometheusCapture;
+//!
+//! // A service's own builder keeps its production bucket layout.
+//! let capture = PrometheusCapture::with_builder(metrics_builder()?);
+//! run_code_under_test().await;
+//!
+//! assert_eq!(capture.counter("gc_rate_limited_total", &[("route", "join")]), Some(1.0));
+//! let latency = capture
+//!     .histogram("gc_http_request_duration_seconds", &[])
+//!     .expect("histogram rendered");
+//! assert_eq!(latency.count, 1);
+//! ```
+//!
+//! Output fetched some other way (a spawned server's `/metrics` body) can
+//! be queried the same way through [`RenderedMetrics::parse`].
+//!
+//! The exporter renders a histogram as a `summary` unless its builder sets
+//! buckets for it, so [`PrometheusCapture::histogram`] needs a builder with
+//! buckets; [`PrometheusCapture::install`] alone will not do.
+//!
+//! # Label matching
+//!
+//! Queries take a label *subset*: every series carrying all the given
+//! pairs matches, and matching series are summed (PromQL `sum`). Pass the
+//! full label set to pin one series; pass `&[]` for the total. A query that
+//! matches no series returns `None`, which is distinct from a series at 0.
+//!
+//! # Threads and recorder lifetime
+//!
+//! Same model as `MetricAssertion`: the binding is thread-local, so
+//! emissions from other OS threads are not captured, `PrometheusCapture`
+//! is `!Send`, and the recorder is leaked (`Box::leak`) so the guard can be
+//! `'static`. Do not hold two captures on one thread at once.
+
+use metrics::LocalRecorderGuard;
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle, PrometheusRecorder};
+use std::collections::{BTreeMap, HashMap};
+
+/// A fresh Prometheus recorder bound to the current thread.
+#[must_use = "metrics are only captured while the PrometheusCapture is alive"]
+pub struct PrometheusCapture {
+    handle: PrometheusHandle,
+    _guard: LocalRecorderGuard<'static>,
+}
+
+impl PrometheusCapture {
+    /// Capture with the exporter's default configuration.
+    pub fn install() -> Self {
+        Self::with_builder(PrometheusBuilder::new())
+    }
+
+    /// Capture with a service's builder, keeping its bucket configuration.
+    pub fn with_builder(builder: PrometheusBuilder) -> Self {
+        let recorder: &'static PrometheusRecorder = Box::leak(Box::new(builder.build_recorder()));
+        let handle = recorder.handle();
+        let guard = metrics::set_default_local_recorder(recorder);
+        Self {
+            handle,
+            _guard: guard,
+        }
+    }
+
+    /// Handle for code that renders metrics itself (a `/metrics` handler).
+    #[must_use]
+    pub fn handle(&self) -> PrometheusHandle {
+        self.handle.clone()
+    }
+
+    /// Current output in Prometheus text exposition format.
+    #[must_use]
+    pub fn render(&self) -> String {
+        self.handle.render()
+    }
+
+    /// Current output, parsed.
+    #[must_use]
+    pub fn metrics(&self) -> RenderedMetrics {
+        RenderedMetrics::parse(&self.render())
+    }
+
+    /// Sum of the matching counter series. See [`RenderedMetrics::counter`].
+    #[must_use]
+    pub fn counter(&self, name: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        self.metrics().counter(name, labels)
+    }
+
+    /// Sum of the matching gauge series. See [`RenderedMetrics::gauge`].
+    #[must_use]
+    pub fn gauge(&self, name: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        self.metrics().gauge(name, labels)
+    }
+
+    /// The matching histogram series, merged. See
+    /// [`RenderedMetrics::histogram`].
+    #[must_use]
+    pub fn histogram(&self, name: &str, labels: &[(&str, &str)]) -> Option<RenderedHistogram> {
+        self.metrics().histogram(name, labels)
+    }
+}
+
+/// One sample line of exposition output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub name: String,
+    pub labels: BTreeMap<String, String>,
+    pub value: f64,
+}
+
+impl Sample {
+    fn matches(&self, name: &str, labels: &[(&str, &str)]) -> bool {
+        self.name == name
+            && labels
+                .iter()
+                .all(|(key, value)| self.labels.get(*key).is_some_and(|v| v == value))
+    }
+}
+
+/// A histogram merged across its matching series.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedHistogram {
+    pub count: u64,
+    pub sum: f64,
+    /// Cumulative `(le, count)` pairs in ascending `le`, ending at `+Inf`.
+    pub buckets: Vec<(f64, u64)>,
+}
+
+impl RenderedHistogram {
+    /// Upper bounds of the finite buckets, ascending.
+    #[must_use]
+    pub fn bucket_bounds(&self) -> Vec<f64> {
+        self.buckets
+            .iter()
+            .map(|(le, _)| *le)
+            .filter(|le| le.is_finite())
+            .collect()
+    }
+}
+
+/// Parsed Prometheus text exposition output.
+#[derive(Debug, Clone, Default)]
+pub struct RenderedMetrics {
+    types: HashMap<String, String>,
+    samples: Vec<Sample>,
+}
+
+impl RenderedMetrics {
+    /// Parse text exposition output. Lines that are not `# TYPE` comments
+    /// or well-formed samples are skipped.
+    #[must_use]
+    pub fn parse(text: &str) -> Self {
+        let mut metrics = Self::default();
+        for line in text.lines().map(str::trim) {
+            if let Some(rest) = line.strip_prefix("# TYPE ") {
+                if let Some((name, kind)) = rest.split_once(' ') {
+                    metrics
+                        .types
+                        .insert(name.to_string(), kind.trim().to_string());
+                }
+            } else if !line.is_empty() && !line.starts_with('#') {
+                if let Some(sample) = parse_sample(line) {
+                    metrics.samples.push(sample);
+                }
+            }
+        }
+        metrics
+    }
+
+    /// All samples, in output order.
+    #[must_use]
+    pub fn samples(&self) -> &[Sample] {
+        &self.samples
+    }
+
+    /// The `# TYPE` of a metric family (`counter`, `gauge`, `histogram`...).
+    #[must_use]
+    pub fn metric_type(&self, name: &str) -> Option<&str> {
+        self.types.get(name).map(String::as_str)
+    }
+
+    /// Sum of the counter series of `name` carrying all of `labels`.
+    ///
+    /// `None` if `name` is not a counter or no series matches.
+    #[must_use]
+    pub fn counter(&self, name: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        self.typed_sum(name, "counter", labels)
+    }
+
+    /// Sum of the gauge series of `name` carrying all of `labels`.
+    ///
+    /// `None` if `name` is not a gauge or no series matches.
+    #[must_use]
+    pub fn gauge(&self, name: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        self.typed_sum(name, "gauge", labels)
+    }
+
+    /// The histogram series of `name` carrying all of `labels`, merged.
+    ///
+    /// `None` if `name` is not a histogram or no series matches.
+    #[must_use]
+    pub fn histogram(&self, name: &str, labels: &[(&str, &str)]) -> Option<RenderedHistogram> {
+        if self.metric_type(name) != Some("histogram") {
+            return None;
+        }
+        let count = self.sum_of(&format!("{name}_count"), labels)?;
+        let sum = self.sum_of(&format!("{name}_sum"), labels).unwrap_or(0.0);
+
+        let bucket_name = format!("{name}_bucket");
+        let mut buckets: Vec<(f64, u64)> = Vec::new();
+        for sample in self
+            .samples
+            .iter()
+            .filter(|s| s.matches(&bucket_name, labels))
+        {
+            let Some(le) = sample
+                .labels
+                .get("le")
+                .and_then(|le| le.parse::<f64>().ok())
+            else {
+                continue;
+            };
+            match buckets
+                .iter_mut()
+                .find(|(bound, _)| bound.total_cmp(&le).is_eq())
+            {
+                Some((_, total)) => *total += sample.value as u64,
+                None => buckets.push((le, sample.value as u64)),
+            }
+        }
+        buckets.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Some(RenderedHistogram {
+            count: count as u64,
+            sum,
+            buckets,
+        })
+    }
+
+    fn typed_sum(&self, name: &str, kind: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        if self.metric_type(name) != Some(kind) {
+            return None;
+        }
+        self.sum_of(name, labels)
+    }
+
+    fn sum_of(&self, name: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        let mut matched = self.samples.iter().filter(|s| s.matches(name, labels));
+        let first = matched.next()?.value;
+        Some(matched.fold(first, |total, sample| total + sample.value))
+    }
+}
+
+/// Parse `name{k="v",...} value [timestamp]` or `name value [timestamp]`.
+fn parse_sample(line: &str) -> Option<Sample> {
+    let name_end = line.find(['{', ' '])?;
+    let name = line.get(..name_end)?.to_string();
+    let mut rest = line.get(name_end..)?;
+
+    let mut labels = BTreeMap::new();
+    if let Some(after_brace) = rest.strip_prefix('{') {
+        let (parsed, after_labels) = parse_labels(after_brace)?;
+        labels = parsed;
+        rest = after_labels;
+    }
+
+    let value = rest.split_whitespace().next()?.parse::<f64>().ok()?;
+    Some(Sample {
+        name,
+        labels,
+        value,
+    })
+}
+
+/// Parse `k="v",...}` (after the opening brace), returning the labels and
+/// the text after the closing brace.
+fn parse_labels(text: &str) -> Option<(BTreeMap<String, String>, &str)> {
+    let mut labels = BTreeMap::new();
+    let mut rest = text;
+    loop {
+        rest = rest.trim_start_matches([',', ' ']);
+        if let Some(after) = rest.strip_prefix('}') {
+            return Some((labels, after));
+        }
+
+        let (key, after_key) = rest.split_once("=\"")?;
+        let mut value = String::new();
+        let mut chars = after_key.char_indices();
+        let end = loop {
+            let (index, c) = chars.next()?;
+            match c {
+                '"' => break index,
+                '\\' => match chars.next()?.1 {
+                    'n' => value.push('\n'),
+                    other => value.push(other),
+                },
+                other => value.push(other),
+            }
+        };
+        labels.insert(key.trim().to_string(), value);
+        rest = after_key.get(end + 1..)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPOSITION: &str = r#"# HELP gc_rate_limited_total Requests rejected by the rate limiter
+# TYPE gc_rate_limited_total counter
+gc_rate_limited_total{route="join",reason="burst"} 2
+gc_rate_limited_total{route="create",reason="burst"} 1
+# TYPE gc_active_meetings gauge
+gc_active_meetings 4
+# TYPE gc_request_duration_seconds histogram
+gc_request_duration_seconds_bucket{route="join",le="0.1"} 1
+gc_request_duration_seconds_bucket{route="join",le="0.5"} 2
+gc_request_duration_seconds_bucket{route="join",le="+Inf"} 3
+gc_request_duration_seconds_sum{route="join"} 1.25
+gc_request_duration_seconds_count{route="join"} 3
+# TYPE odd_labels_total counter
+odd_labels_total{path="a \"quoted\" \\ path",note="x,y}"} 1
+"#;
+
+    #[test]
+    fn parses_counters_with_label_subsets() {
+        let metrics = RenderedMetrics::parse(EXPOSITION);
+
+        assert_eq!(
+            metrics.counter(
+                "gc_rate_limited_total",
+                &[("route", "join"), ("reason", "burst")]
+            ),
+            Some(2.0)
+        );
+        assert_eq!(
+            metrics.counter("gc_rate_limited_total", &[("reason", "burst")]),
+            Some(3.0)
+        );
+        assert_eq!(
+            metrics.counter("gc_rate_limited_total", &[("route", "leave")]),
+            None
+        );
+        assert_eq!(metrics.gauge("gc_rate_limited_total", &[]), None);
+    }
+
+    #[test]
+    fn parses_gauges_and_histograms() {
+        let metrics = RenderedMetrics::parse(EXPOSITION);
+
+        assert_eq!(metrics.gauge("gc_active_meetings", &[]), Some(4.0));
+        assert_eq!(
+            metrics.histogram("gc_request_duration_seconds", &[("route", "join")]),
+            Some(RenderedHistogram {
+                count: 3,
+                sum: 1.25,
+                buckets: vec![(0.1, 1), (0.5, 2), (f64::INFINITY, 3)],
+            })
+        );
+    }
+
+    #[test]
+    fn parses_escaped_label_values() {
+        let metrics = RenderedMetrics::parse(EXPOSITION);
+
+        assert_eq!(
+            metrics.counter(
+                "odd_labels_total",
+                &[("path", "a \"quoted\" \\ path"), ("note", "x,y}")]
+            ),
+            Some(1.0)
+        );
+    }
+
+    #[test]
+    fn capture_renders_local_emissions() {
+        let capture = PrometheusCapture::install();
+        metrics::counter!("test_requests_total", "status" => "ok").increment(2);
+        metrics::gauge!("test_queue_depth").set(5.0);
+        metrics::histogram!("test_latency_seconds").record(0.2);
+
+        assert_eq!(
+            capture.counter("test_requests_total", &[("status", "ok")]),
+            Some(2.0)
+        );
+        assert_eq!(capture.gauge("test_queue_depth", &[]), Some(5.0));
+    }
+
+    #[test]
+    fn capture_uses_builder_buckets() {
+        let builder = PrometheusBuilder::new()
+            .set_buckets(&[0.1, 0.35, 1.0])
+            .expect("valid buckets");
+        let capture = PrometheusCapture::with_builder(builder);
+        metrics::histogram!("test_latency_seconds").record(0.2);
+
+        let histogram = capture
+            .histogram("test_latency_seconds", &[])
+            .expect("histogram should be rendered");
+        assert_eq!(histogram.count, 1);
+        assert_eq!(histogram.bucket_bounds(), vec![0.1, 0.35, 1.0]);
+    }
+}
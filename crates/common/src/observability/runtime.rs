@@ -0,0 +1,174 @@
+//! Tokio runtime health sampling.
+//!
+//! [`RuntimeSampler`] periodically reads the stable `tokio::runtime::RuntimeMetrics`
+//! counters and turns them into a [`RuntimeSample`] that services export as
+//! gauges. It also measures scheduling delay directly: each sample spawns a
+//! no-op task and times how long it waits before its first poll. That delay
+//! rises as soon as workers are saturated (long polls, blocking calls on a
+//! worker, task floods), which is usually well before request latency does.
+//!
+//! Per-task poll-time histograms need `--cfg tokio_unstable`, which the
+//! services are not built with, so the spawn probe stands in for them.
+
+use std::time::{Duration, Instant};
+use tokio::runtime::Handle;
+
+/// How often services sample the runtime.
+pub const RUNTIME_SAMPLE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// One reading of runtime health.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeSample {
+    /// Worker threads in the runtime (1 for `current_thread`).
+    pub workers: usize,
+    /// Tasks spawned and not yet finished.
+    pub alive_tasks: usize,
+    /// Tasks waiting in the global (injection) queue.
+    pub global_queue_depth: usize,
+    /// Fraction of time workers were busy since the previous sample, averaged
+    /// over all workers (0.0 idle to 1.0 saturated).
+    pub busy_ratio: f64,
+    /// Time a freshly spawned task waited before it was first polled.
+    pub schedule_delay: Duration,
+}
+
+/// Computes [`RuntimeSample`]s from the deltas between successive reads.
+#[derive(Debug)]
+pub struct RuntimeSampler {
+    handle: Handle,
+    last_busy: Duration,
+    last_at: Instant,
+}
+
+impl RuntimeSampler {
+    /// Sampler for the runtime the caller is running on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a Tokio runtime.
+    #[must_use]
+    pub fn new() -> Self {
+        let handle = Handle::current();
+        let last_busy = total_busy(&handle);
+        Self {
+            handle,
+            last_busy,
+            last_at: Instant::now(),
+        }
+    }
+
+    /// Take a sample. Busy ratio covers the time since the previous call
+    /// (or since construction).
+    pub async fn sample(&mut self) -> RuntimeSample {
+        let schedule_delay = self.probe_schedule_delay().await;
+
+        let metrics = self.handle.metrics();
+        let workers = metrics.num_workers();
+        let busy = total_busy(&self.handle);
+        let now = Instant::now();
+
+        #[expect(clippy::cast_precision_loss, reason = "worker counts are tiny")]
+        let capacity = now.duration_since(self.last_at).as_secs_f64() * workers.max(1) as f64;
+        let busy_delta = busy.saturating_sub(self.last_busy).as_secs_f64();
+        let busy_ratio = if capacity > 0.0 {
+            (busy_delta / capacity).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        self.last_busy = busy;
+        self.last_at = now;
+
+        RuntimeSample {
+            workers,
+            alive_tasks: metrics.num_alive_tasks(),
+            global_queue_depth: metrics.global_queue_depth(),
+            busy_ratio,
+            schedule_delay,
+        }
+    }
+
+    /// Sample every `interval` and hand each sample to `record`. Never
+    /// returns; run it under a `select!` with the shutdown signal.
+    pub async fn run<F>(mut self, interval: Duration, mut record: F)
+    where
+        F: FnMut(&RuntimeSample),
+    {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            let sample = self.sample().await;
+            record(&sample);
+        }
+    }
+
+    async fn probe_schedule_delay(&self) -> Duration {
+        let spawned_at = Instant::now();
+        self.handle
+            .spawn(async move { spawned_at.elapsed() })
+            .await
+            .unwrap_or_default()
+    }
+}
+
+impl Default for RuntimeSampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Busy time summed over all workers since the runtime started.
+fn total_busy(handle: &Handle) -> Duration {
+    let metrics = handle.metrics();
+    (0..metrics.num_workers())
+        .map(|worker| metrics.worker_total_busy_duration(worker))
+        .sum()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_sample_current_thread() {
+        let mut sampler = RuntimeSampler::new();
+        let sample = sampler.sample().await;
+
+        assert_eq!(sample.workers, 1);
+        assert!((0.0..=1.0).contains(&sample.busy_ratio));
+        // Nothing else is queued, so the probe is polled almost immediately
+        assert!(sample.schedule_delay < Duration::from_secs(1));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_sample_counts_workers_and_tasks() {
+        let mut sampler = RuntimeSampler::new();
+        let (release_tx, release_rx) = tokio::sync::oneshot::channel::<()>();
+        let parked = tokio::spawn(async move {
+            let _ = release_rx.await;
+        });
+
+        let sample = sampler.sample().await;
+        assert_eq!(sample.workers, 2);
+        assert!(sample.alive_tasks >= 1, "{sample:?}");
+
+        release_tx.send(()).unwrap();
+        parked.await.unwrap();
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_run_records_each_tick() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let task = tokio::spawn(
+            RuntimeSampler::new().run(Duration::from_millis(10), move |s| {
+                let _ = tx.send(s.workers);
+            }),
+        );
+
+        assert_eq!(rx.recv().await, Some(1));
+        assert_eq!(rx.recv().await, Some(1));
+        task.abort();
+    }
+}
@@ -0,0 +1,175 @@
+//! OpenTelemetry trace export (`otlp` feature).
+//!
+//! [`init_otlp`] builds a `tracing` layer that exports spans over OTLP/gRPC
+//! to a collector, for [`init_tracing_with`](crate::log_level::init_tracing_with).
+//! Spans are batched and exported in the background; [`OtlpGuard::shutdown`]
+//! flushes what is left when the service stops.
+//!
+//! # Propagation
+//!
+//! [`init_otlp`] also installs the W3C Trace Context propagator. HTTP
+//! servers call [`set_parent_from_headers`] on their request span so a
+//! caller's `traceparent` header makes the request part of the caller's
+//! trace. Without export the global propagator is a no-op and so is the
+//! call.
+
+use crate::log_level::ExtraLayer;
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::{runtime, trace, Resource};
+use std::time::Duration;
+use thiserror::Error;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// How long a single export to the collector may take.
+pub const EXPORT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Errors from starting trace export.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum OtlpError {
+    /// The endpoint is not an `http://` or `https://` URL.
+    #[error("OTLP endpoint must be an http:// or https:// URL")]
+    InvalidEndpoint,
+
+    /// The exporter could not be built.
+    #[error("Failed to start OTLP trace export: {0}")]
+    Install(String),
+}
+
+/// Keeps trace export running; call [`OtlpGuard::shutdown`] on exit.
+#[derive(Debug)]
+#[must_use = "dropping the guard without shutdown loses the last batch of spans"]
+pub struct OtlpGuard {
+    _private: (),
+}
+
+impl OtlpGuard {
+    /// Flush pending spans and stop exporting.
+    ///
+    /// Blocks until the exporter is done, at most about [`EXPORT_TIMEOUT`].
+    pub fn shutdown(self) {
+        opentelemetry::global::shutdown_tracer_provider();
+    }
+}
+
+/// Start exporting spans to the OTLP/gRPC collector at `endpoint`, tagged
+/// with `service.name = service_name`, and install the W3C Trace Context
+/// propagator.
+///
+/// Must be called from within a Tokio runtime, which runs the exporter.
+///
+/// # Errors
+///
+/// Returns [`OtlpError::InvalidEndpoint`] for a non-HTTP endpoint and
+/// [`OtlpError::Install`] if the exporter cannot be built.
+pub fn init_otlp(
+    endpoint: &str,
+    service_name: &'static str,
+) -> Result<(ExtraLayer, OtlpGuard), OtlpError> {
+    if !(endpoint.starts_with("http://") || endpoint.starts_with("https://")) {
+        return Err(OtlpError::InvalidEndpoint);
+    }
+
+    let provider = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(endpoint)
+                .with_timeout(EXPORT_TIMEOUT),
+        )
+        .with_trace_config(
+            trace::Config::default()
+                .with_resource(Resource::new([KeyValue::new("service.name", service_name)])),
+        )
+        .install_batch(runtime::Tokio)
+        .map_err(|e| OtlpError::Install(e.to_string()))?;
+
+    let tracer = provider.tracer(service_name);
+    opentelemetry::global::set_tracer_provider(provider);
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let layer: ExtraLayer = Box::new(tracing_opentelemetry::layer().with_tracer(tracer));
+    Ok((layer, OtlpGuard { _private: () }))
+}
+
+/// Make `span` a child of the remote span named by the W3C `traceparent`
+/// (and `tracestate`) headers of an inbound request.
+///
+/// Requests without a valid `traceparent` keep `span` as a new root.
+pub fn set_parent_from_headers(span: &tracing::Span, headers: &http::HeaderMap) {
+    let parent = opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(headers))
+    });
+    span.set_parent(parent);
+}
+
+/// Reads propagation fields from HTTP headers.
+struct HeaderExtractor<'a>(&'a http::HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(http::HeaderName::as_str).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+    use opentelemetry::trace::TraceContextExt;
+
+    const TRACEPARENT: &str = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01";
+
+    #[test]
+    fn test_traceparent_extracted() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert("traceparent", HeaderValue::from_static(TRACEPARENT));
+
+        let context = TraceContextPropagator::new().extract(&HeaderExtractor(&headers));
+        let span = context.span();
+        let parent = span.span_context();
+        assert!(parent.is_valid());
+        assert!(parent.is_remote());
+        assert_eq!(
+            parent.trace_id().to_string(),
+            "0af7651916cd43dd8448eb211c80319c"
+        );
+        assert_eq!(parent.span_id().to_string(), "b7ad6b7169203331");
+    }
+
+    #[test]
+    fn test_missing_or_invalid_traceparent_is_no_parent() {
+        let mut headers = http::HeaderMap::new();
+        let propagator = TraceContextPropagator::new();
+        assert!(!propagator
+            .extract(&HeaderExtractor(&headers))
+            .span()
+            .span_context()
+            .is_valid());
+
+        headers.insert("traceparent", HeaderValue::from_static("00-not-a-trace-01"));
+        assert!(!propagator
+            .extract(&HeaderExtractor(&headers))
+            .span()
+            .span_context()
+            .is_valid());
+    }
+
+    #[test]
+    fn test_non_http_endpoint_rejected() {
+        for endpoint in ["", "collector:4317", "grpc://collector:4317"] {
+            assert_eq!(
+                init_otlp(endpoint, "test-service").err(),
+                Some(OtlpError::InvalidEndpoint)
+            );
+        }
+    }
+}
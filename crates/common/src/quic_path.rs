@@ -0,0 +1,148 @@
+//! Peer address bookkeeping for QUIC connection migration.
+//!
+//! A client that changes networks (Wi-Fi to LTE, NAT rebinding) keeps its
+//! QUIC connection: the transport validates the new path (`PATH_CHALLENGE`/
+//! `PATH_RESPONSE`, RFC 9000 §9) and switches the connection's remote
+//! address once it is validated. Nothing above the transport is torn down,
+//! so services only need to notice the new address and update whatever
+//! they keep about the peer.
+//!
+//! The transport does not announce migrations, so services poll the
+//! connection's remote address every [`PATH_CHECK_INTERVAL`] and feed it to
+//! [`PeerPath::observe`].
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+/// How often connections check their remote address for a migration.
+pub const PATH_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How a connection's validated peer address changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Migration {
+    /// Same IP, new port: typically a NAT rebinding.
+    Rebinding,
+    /// New IP: the client moved to another network.
+    NewAddress,
+}
+
+impl Migration {
+    /// Metric label for this kind of migration.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Rebinding => "rebinding",
+            Self::NewAddress => "address",
+        }
+    }
+}
+
+/// The current validated peer address of a connection.
+#[derive(Debug, Clone)]
+pub struct PeerPath {
+    address: SocketAddr,
+    migrations: u32,
+}
+
+impl PeerPath {
+    /// Start tracking a connection whose peer is at `address`.
+    #[must_use]
+    pub fn new(address: SocketAddr) -> Self {
+        Self {
+            address,
+            migrations: 0,
+        }
+    }
+
+    /// Current peer address.
+    #[must_use]
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Migrations observed so far.
+    #[must_use]
+    pub fn migrations(&self) -> u32 {
+        self.migrations
+    }
+
+    /// Record the connection's current remote address.
+    ///
+    /// Returns the kind of migration if it differs from the last address
+    /// seen. IPv4-mapped IPv6 addresses compare equal to their IPv4 form, so
+    /// a dual-stack socket reporting either is not a migration.
+    pub fn observe(&mut self, address: SocketAddr) -> Option<Migration> {
+        let (old_ip, new_ip) = (
+            self.address.ip().to_canonical(),
+            address.ip().to_canonical(),
+        );
+        if old_ip == new_ip && self.address.port() == address.port() {
+            return None;
+        }
+        self.address = address;
+        self.migrations = self.migrations.saturating_add(1);
+        Some(if old_ip == new_ip {
+            Migration::Rebinding
+        } else {
+            Migration::NewAddress
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn addr(s: &str) -> SocketAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_same_address_is_not_a_migration() {
+        let mut path = PeerPath::new(addr("192.0.2.1:4433"));
+        assert_eq!(path.observe(addr("192.0.2.1:4433")), None);
+        assert_eq!(path.migrations(), 0);
+    }
+
+    #[test]
+    fn test_new_port_is_rebinding() {
+        let mut path = PeerPath::new(addr("192.0.2.1:4433"));
+        assert_eq!(
+            path.observe(addr("192.0.2.1:5000")),
+            Some(Migration::Rebinding)
+        );
+        assert_eq!(path.address(), addr("192.0.2.1:5000"));
+        assert_eq!(path.migrations(), 1);
+    }
+
+    #[test]
+    fn test_new_ip_is_new_address() {
+        let mut path = PeerPath::new(addr("192.0.2.1:4433"));
+        assert_eq!(
+            path.observe(addr("198.51.100.7:4433")),
+            Some(Migration::NewAddress)
+        );
+        assert_eq!(
+            path.observe(addr("[2001:db8::1]:4433")),
+            Some(Migration::NewAddress)
+        );
+        assert_eq!(path.migrations(), 2);
+    }
+
+    #[test]
+    fn test_ipv4_mapped_address_is_same_path() {
+        let mut path = PeerPath::new(addr("192.0.2.1:4433"));
+        assert_eq!(path.observe(addr("[::ffff:192.0.2.1]:4433")), None);
+        assert_eq!(
+            path.observe(addr("[::ffff:192.0.2.1]:5000")),
+            Some(Migration::Rebinding)
+        );
+    }
+
+    #[test]
+    fn test_migration_labels() {
+        assert_eq!(Migration::Rebinding.as_str(), "rebinding");
+        assert_eq!(Migration::NewAddress.as_str(), "address");
+    }
+}
@@ -0,0 +1,220 @@
+//! Per-request correlation IDs (`x-request-id`).
+//!
+//! [`RequestIdLayer`] wraps the HTTP and gRPC servers of every service. For
+//! each request it keeps the caller's `x-request-id` (or generates one),
+//! records it on a `request` tracing span, and echoes it on the response.
+//! While the request is handled, [`RequestId::current`] returns the ID so
+//! outbound clients can forward it as an `x-request-id` header or gRPC
+//! metadata entry, and error bodies can include it for support tickets.
+
+use http::{HeaderValue, Request, Response};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+use tracing::Instrument;
+
+/// Header (and gRPC metadata key) carrying the request ID.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Longest caller-supplied ID that is accepted.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: RequestId;
+}
+
+/// Correlation ID for one request as it crosses services.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(String);
+
+impl RequestId {
+    /// Generate a fresh ID (UUID v4).
+    #[must_use]
+    pub fn generate() -> Self {
+        Self(uuid::Uuid::new_v4().to_string())
+    }
+
+    /// Accept a caller-supplied ID of 1 to 128 characters from
+    /// `[A-Za-z0-9._:-]`. Anything else is rejected so untrusted input
+    /// cannot inject content into logs or headers.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let valid = !value.is_empty()
+            && value.len() <= MAX_REQUEST_ID_LEN
+            && value
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b':'));
+        valid.then(|| Self(value.to_string()))
+    }
+
+    /// The ID as a string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// ID of the request the current task is handling, if any.
+    #[must_use]
+    pub fn current() -> Option<Self> {
+        CURRENT_REQUEST_ID.try_with(Clone::clone).ok()
+    }
+
+    /// Run `future` with this ID as the current request ID.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT_REQUEST_ID.scope(self, future).await
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Tower layer that assigns a [`RequestId`] to every request.
+///
+/// Works for both axum routers and tonic servers. Add it outermost so that
+/// rejections from inner layers (auth, timeouts) carry the ID too.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequestIdLayer;
+
+impl<S> Layer<S> for RequestIdLayer {
+    type Service = RequestIdService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestIdService { inner }
+    }
+}
+
+/// Service produced by [`RequestIdLayer`].
+#[derive(Debug, Clone)]
+pub struct RequestIdService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for RequestIdService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let request_id = request
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(RequestId::parse)
+            .unwrap_or_else(RequestId::generate);
+        // Always valid: `parse` and `generate` only produce header-safe ASCII
+        let header = HeaderValue::from_str(request_id.as_str()).ok();
+
+        if let Some(header) = &header {
+            request
+                .headers_mut()
+                .insert(REQUEST_ID_HEADER, header.clone());
+        }
+        request.extensions_mut().insert(request_id.clone());
+
+        let span = tracing::info_span!("request", request_id = %request_id);
+        let future = span.in_scope(|| {
+            CURRENT_REQUEST_ID.sync_scope(request_id.clone(), || self.inner.call(request))
+        });
+
+        Box::pin(async move {
+            let mut response = CURRENT_REQUEST_ID
+                .scope(request_id, future.instrument(span))
+                .await?;
+            if let Some(header) = header {
+                response.headers_mut().insert(REQUEST_ID_HEADER, header);
+            }
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use tower::ServiceExt;
+
+    /// Echoes the current request ID (as seen by the handler) in the body.
+    fn echo_service() -> RequestIdService<
+        impl Service<
+                Request<()>,
+                Response = Response<String>,
+                Error = Infallible,
+                Future = impl Future<Output = Result<Response<String>, Infallible>> + Send,
+            > + Clone,
+    > {
+        RequestIdLayer.layer(tower::service_fn(|request: Request<()>| async move {
+            let current = RequestId::current().map(|id| id.to_string());
+            let extension = request.extensions().get::<RequestId>().cloned();
+            assert_eq!(current, extension.map(|id| id.to_string()));
+            Ok::<_, Infallible>(Response::new(current.unwrap_or_default()))
+        }))
+    }
+
+    fn request_with(id: Option<&str>) -> Request<()> {
+        let mut builder = Request::builder().uri("/");
+        if let Some(id) = id {
+            builder = builder.header(REQUEST_ID_HEADER, id);
+        }
+        builder.body(()).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(RequestId::parse("7f9c0a1e-4b7d-4a4b-9a55-0e4b3e2f1c11").is_some());
+        assert!(RequestId::parse("support.ticket_42:retry-1").is_some());
+        assert!(RequestId::parse("").is_none());
+        assert!(RequestId::parse("has space").is_none());
+        assert!(RequestId::parse("line\nbreak").is_none());
+        assert!(RequestId::parse(&"a".repeat(MAX_REQUEST_ID_LEN)).is_some());
+        assert!(RequestId::parse(&"a".repeat(MAX_REQUEST_ID_LEN + 1)).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_layer_keeps_valid_incoming_id() {
+        let response = echo_service()
+            .oneshot(request_with(Some("req-123")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "req-123");
+        assert_eq!(response.body(), "req-123");
+    }
+
+    #[tokio::test]
+    async fn test_layer_generates_id_when_missing_or_invalid() {
+        for incoming in [None, Some("bad id")] {
+            let response = echo_service()
+                .oneshot(request_with(incoming))
+                .await
+                .unwrap();
+
+            let echoed = response.headers()[REQUEST_ID_HEADER].to_str().unwrap();
+            assert!(uuid::Uuid::parse_str(echoed).is_ok(), "{echoed}");
+            assert_eq!(response.body(), echoed);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_current_is_scoped() {
+        assert_eq!(RequestId::current(), None);
+        let id = RequestId::parse("scoped").unwrap();
+        let seen = id.clone().scope(async { RequestId::current() }).await;
+        assert_eq!(seen, Some(id));
+        assert_eq!(RequestId::current(), None);
+    }
+}
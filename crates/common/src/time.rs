@@ -0,0 +1,151 @@
+//! Injectable wall clock.
+//!
+//! Code that compares wall-clock timestamps (JWT `iat`/`exp`, token refresh
+//! deadlines, attendance times) reads the time through a [`Clock`] instead
+//! of calling `Utc::now()`, so tests can pin and advance it with
+//! [`MockClock`] rather than sleeping. Production code uses [`SystemClock`],
+//! usually through the [`system_clock`] default.
+//!
+//! Elapsed-time checks (grace periods, TTLs, rate limits) do not need a
+//! `Clock`: they use `tokio::time::Instant`, which tests drive with
+//! `tokio::time::pause` and `advance`. Time evaluated inside Postgres
+//! (`NOW()` in staleness and cleanup queries) stays there, so every GC
+//! replica agrees on it.
+
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::sync::Arc;
+
+/// Source of the current wall-clock time.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// The current time.
+    fn now(&self) -> DateTime<Utc>;
+
+    /// The current time as Unix seconds.
+    fn timestamp(&self) -> i64 {
+        self.now().timestamp()
+    }
+
+    /// The current time as Unix milliseconds.
+    fn timestamp_millis(&self) -> i64 {
+        self.now().timestamp_millis()
+    }
+}
+
+/// A shared, type-erased [`Clock`].
+pub type SharedClock = Arc<dyn Clock>;
+
+/// The host's clock (`Utc::now()`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A [`SharedClock`] backed by [`SystemClock`].
+#[must_use]
+pub fn system_clock() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+pub use mock::MockClock;
+
+#[cfg(any(test, feature = "test-utils"))]
+mod mock {
+    use super::{Clock, SharedClock};
+    use chrono::{DateTime, Utc};
+    use std::sync::{Arc, Mutex, PoisonError};
+    use std::time::Duration;
+
+    /// A clock that only moves when told to. Clones share the same time,
+    /// so a test keeps one and hands [`MockClock::shared`] to the code under
+    /// test.
+    #[derive(Debug, Clone)]
+    pub struct MockClock {
+        now: Arc<Mutex<DateTime<Utc>>>,
+    }
+
+    impl MockClock {
+        /// A clock stopped at `now`.
+        #[must_use]
+        pub fn new(now: DateTime<Utc>) -> Self {
+            Self {
+                now: Arc::new(Mutex::new(now)),
+            }
+        }
+
+        /// A clock stopped at the Unix timestamp `secs`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `secs` is outside chrono's representable range.
+        #[must_use]
+        #[allow(clippy::expect_used)]
+        pub fn at_timestamp(secs: i64) -> Self {
+            Self::new(DateTime::from_timestamp(secs, 0).expect("timestamp in range"))
+        }
+
+        /// This clock as a [`SharedClock`].
+        #[must_use]
+        pub fn shared(&self) -> SharedClock {
+            Arc::new(self.clone())
+        }
+
+        /// Move the clock forward by `by`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `by` does not fit a `chrono::Duration`.
+        #[allow(clippy::expect_used)]
+        pub fn advance(&self, by: Duration) {
+            let by = chrono::Duration::from_std(by).expect("duration in range");
+            *self.now.lock().unwrap_or_else(PoisonError::into_inner) += by;
+        }
+
+        /// Set the clock to `now`, forwards or backwards.
+        pub fn set(&self, now: DateTime<Utc>) {
+            *self.now.lock().unwrap_or_else(PoisonError::into_inner) = now;
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_system_clock_tracks_utc_now() {
+        let before = Utc::now();
+        let now = SystemClock.now();
+        assert!(now >= before);
+        assert!(now <= Utc::now());
+    }
+
+    #[test]
+    fn test_mock_clock_advances_only_when_told() {
+        let clock = MockClock::at_timestamp(1_700_000_000);
+        let shared = clock.shared();
+        assert_eq!(shared.timestamp(), 1_700_000_000);
+
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(shared.timestamp(), 1_700_000_000);
+
+        clock.advance(Duration::from_millis(1500));
+        assert_eq!(shared.timestamp_millis(), 1_700_000_001_500);
+
+        clock.set(DateTime::from_timestamp(1_600_000_000, 0).unwrap());
+        assert_eq!(shared.timestamp(), 1_600_000_000);
+    }
+}
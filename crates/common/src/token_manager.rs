@@ -47,6 +47,7 @@
 //! **ADRs**: ADR-0003 (Service Auth)
 
 use crate::secret::{ExposeSecret, SecretString};
+use crate::time::{system_clock, SharedClock};
 use serde::Deserialize;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -190,6 +191,9 @@ pub struct TokenManagerConfig {
     /// Enables services to record metrics without the `common` crate
     /// depending on any metrics library.
     pub on_refresh: Option<TokenRefreshCallback>,
+
+    /// Clock used to track token expiry and schedule refreshes.
+    pub clock: SharedClock,
 }
 
 impl std::fmt::Debug for TokenManagerConfig {
@@ -204,6 +208,7 @@ impl std::fmt::Debug for TokenManagerConfig {
                 "on_refresh",
                 &self.on_refresh.as_ref().map(|_| "<callback>"),
             )
+            .field("clock", &self.clock)
             .finish()
     }
 }
@@ -230,6 +235,7 @@ impl TokenManagerConfig {
             refresh_threshold: DEFAULT_REFRESH_THRESHOLD,
             http_timeout: DEFAULT_HTTP_TIMEOUT,
             on_refresh: None,
+            clock: system_clock(),
         }
     }
 
@@ -332,6 +338,14 @@ impl TokenManagerConfig {
         self.on_refresh = Some(callback);
         self
     }
+
+    /// Set the clock token expiry is measured against (defaults to the
+    /// system clock).
+    #[must_use]
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
 }
 
 // =============================================================================
@@ -525,7 +539,7 @@ async fn token_refresh_loop(
         // between this host and the AC server
         let needs_refresh = match expires_at {
             Some(exp) => {
-                let now = chrono::Utc::now().timestamp();
+                let now = config.clock.timestamp();
                 #[allow(clippy::cast_possible_wrap)]
                 let threshold_secs = config.refresh_threshold.as_secs() as i64;
                 // Add clock drift margin for safety
@@ -613,7 +627,7 @@ async fn token_refresh_loop(
         // We subtract clock drift margin to ensure we wake up early enough
         let sleep_duration = match expires_at {
             Some(exp) => {
-                let now = chrono::Utc::now().timestamp();
+                let now = config.clock.timestamp();
                 #[allow(clippy::cast_possible_wrap)]
                 let threshold_secs = config.refresh_threshold.as_secs() as i64;
                 // Account for clock drift margin when calculating wake time
@@ -676,7 +690,7 @@ async fn acquire_token(
         })?;
 
         // Calculate expiration time
-        let now = chrono::Utc::now().timestamp();
+        let now = config.clock.timestamp();
         #[allow(clippy::cast_possible_wrap)]
         let expires_at = now + token_response.expires_in as i64;
 
@@ -864,6 +878,31 @@ mod tests {
         handle.abort();
     }
 
+    #[tokio::test]
+    async fn test_acquire_token_expiry_uses_configured_clock() {
+        use crate::time::MockClock;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/auth/service/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "clocked-token",
+                "token_type": "Bearer",
+                "expires_in": 3600
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let clock = MockClock::at_timestamp(1_000_000);
+        let config = test_config(&mock_server.uri()).with_clock(clock.shared());
+
+        let (_, expires_at) = acquire_token(&config, &reqwest::Client::new())
+            .await
+            .unwrap();
+        assert_eq!(expires_at, 1_003_600);
+    }
+
     #[tokio::test]
     async fn test_token_receiver_always_valid_after_spawn() {
         let mock_server = MockServer::start().await;
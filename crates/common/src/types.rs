@@ -3,6 +3,10 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+pub mod ids;
+
+pub use ids::{CorrelationId, McId, MeetingId, ParticipantId};
+
 /// Unique identifier for an organization (tenant)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct OrganizationId(pub Uuid);
@@ -21,42 +25,6 @@ impl Default for OrganizationId {
     }
 }
 
-/// Unique identifier for a meeting
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct MeetingId(pub Uuid);
-
-impl MeetingId {
-    /// Create a new random meeting ID
-    #[must_use]
-    pub fn new() -> Self {
-        Self(Uuid::new_v4())
-    }
-}
-
-impl Default for MeetingId {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-/// Unique identifier for a participant (session-level, UUID for DB)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct ParticipantId(pub Uuid);
-
-impl ParticipantId {
-    /// Create a new random participant ID
-    #[must_use]
-    pub fn new() -> Self {
-        Self(Uuid::new_v4())
-    }
-}
-
-impl Default for ParticipantId {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 /// User identifier for media frames (8 bytes, assigned on join)
 ///
 /// This is a random 64-bit number assigned by the Meeting Controller
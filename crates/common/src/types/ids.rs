@@ -0,0 +1,248 @@
+//! Typed identifiers generated as UUIDv7 (ADR-0023).
+//!
+//! A UUIDv7 starts with a millisecond Unix timestamp, so IDs sort by
+//! creation time: B-tree inserts stay local and IDs in logs read in order.
+//! Each ID converts to and from:
+//!
+//! - [`Uuid`] (`From`, `as_uuid`)
+//! - its hyphenated string, as carried in protobuf `string` fields and
+//!   Redis keys (`Display`, `FromStr`, `From<Id> for String`)
+//! - a bare UUID string in JSON (serde)
+//! - Postgres `UUID` columns (`sqlx` feature)
+//!
+//! Service instance IDs ([`McId`]) are not UUIDs: they are chosen by the
+//! deployment (`mc-us-east-1-0`) and only wrap the string, so they can't be
+//! mixed up with meeting or participant IDs.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+macro_rules! uuid_v7_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+        )]
+        #[serde(transparent)]
+        #[cfg_attr(feature = "sqlx", derive(sqlx::Type), sqlx(transparent))]
+        pub struct $name(pub Uuid);
+
+        impl $name {
+            /// Generate a new UUIDv7 ID.
+            #[must_use]
+            pub fn new() -> Self {
+                Self(Uuid::now_v7())
+            }
+
+            /// Get the underlying UUID.
+            #[must_use]
+            pub const fn as_uuid(self) -> Uuid {
+                self.0
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl From<Uuid> for $name {
+            fn from(uuid: Uuid) -> Self {
+                Self(uuid)
+            }
+        }
+
+        impl From<$name> for Uuid {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.to_string()
+            }
+        }
+
+        impl PartialEq<Uuid> for $name {
+            fn eq(&self, other: &Uuid) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = uuid::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Uuid::parse_str(s).map(Self)
+            }
+        }
+    };
+}
+
+uuid_v7_id! {
+    /// Unique identifier for a meeting
+    MeetingId
+}
+
+uuid_v7_id! {
+    /// Unique identifier for a participant session
+    ParticipantId
+}
+
+uuid_v7_id! {
+    /// Server-generated correlation ID binding a participant session across
+    /// reconnects (ADR-0023)
+    CorrelationId
+}
+
+macro_rules! string_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+        )]
+        #[serde(transparent)]
+        #[cfg_attr(feature = "sqlx", derive(sqlx::Type), sqlx(transparent))]
+        pub struct $name(String);
+
+        impl $name {
+            /// Wrap an existing ID.
+            #[must_use]
+            pub fn new(id: impl Into<String>) -> Self {
+                Self(id.into())
+            }
+
+            /// The ID as a string slice.
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Unwrap into the underlying string.
+            #[must_use]
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self(id.to_string())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl std::borrow::Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    };
+}
+
+string_id! {
+    /// Identifier of a Meeting Controller instance
+    McId
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_ids_are_uuid_v7() {
+        assert_eq!(MeetingId::new().as_uuid().get_version_num(), 7);
+        assert_eq!(ParticipantId::new().as_uuid().get_version_num(), 7);
+        assert_eq!(CorrelationId::new().as_uuid().get_version_num(), 7);
+    }
+
+    #[test]
+    fn test_ids_sort_by_creation_time() {
+        let first = CorrelationId::new();
+        std::thread::sleep(Duration::from_millis(2));
+        let second = CorrelationId::new();
+        assert!(first < second);
+        assert!(first.to_string() < second.to_string());
+    }
+
+    #[test]
+    fn test_string_roundtrip() {
+        let id = ParticipantId::new();
+        let wire: String = id.into();
+        assert_eq!(wire.len(), 36);
+        assert_eq!(wire.parse::<ParticipantId>().unwrap(), id);
+        assert!("part-123".parse::<ParticipantId>().is_err());
+    }
+
+    #[test]
+    fn test_json_is_bare_uuid() {
+        let id = MeetingId::new();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{}\"", id.as_uuid()));
+        assert_eq!(serde_json::from_str::<MeetingId>(&json).unwrap(), id);
+    }
+
+    #[test]
+    fn test_uuid_conversions() {
+        let uuid = Uuid::now_v7();
+        let id = MeetingId::from(uuid);
+        assert_eq!(id, uuid);
+        assert_eq!(Uuid::from(id), uuid);
+    }
+
+    #[test]
+    fn test_string_id_wraps_without_parsing() {
+        let id = McId::new("mc-us-east-1-0");
+        assert_eq!(id, "mc-us-east-1-0");
+        assert_eq!(id.to_string(), "mc-us-east-1-0");
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"mc-us-east-1-0\"");
+        assert_eq!(String::from(id), "mc-us-east-1-0");
+    }
+}
@@ -744,6 +744,11 @@ fn write_port_map_shell(path: &Path, alloc: &PortAllocation) -> Result<(), Helpe
     writeln!(file, "# Generated by devloop-helper")?;
     writeln!(file, "AC_HTTP_PORT={}", alloc.port(PortOffsets::AC_HTTP))?;
     writeln!(file, "GC_HTTP_PORT={}", alloc.port(PortOffsets::GC_HTTP))?;
+    writeln!(
+        file,
+        "MC_HEALTH_PORT={}",
+        alloc.port(PortOffsets::MC_0_HEALTH)
+    )?;
     writeln!(
         file,
         "MH_HEALTH_PORT={}",
@@ -2083,6 +2088,7 @@ current-context: kind-devloop-test
         let expected = [
             ("AC_HTTP_PORT", alloc.port(PortOffsets::AC_HTTP)),
             ("GC_HTTP_PORT", alloc.port(PortOffsets::GC_HTTP)),
+            ("MC_HEALTH_PORT", alloc.port(PortOffsets::MC_0_HEALTH)),
             ("MH_HEALTH_PORT", alloc.port(PortOffsets::MH_0_HEALTH)),
             ("POSTGRES_PORT", alloc.port(PortOffsets::POSTGRES)),
             ("PROMETHEUS_PORT", alloc.port(PortOffsets::PROMETHEUS)),
@@ -24,6 +24,7 @@ pub const CANONICAL_SERVICES: &[(&str, &str)] = &[
     ("gc", "gc-service"),
     ("mc", "mc-service"),
     ("mh", "mh-service"),
+    ("canary", "canary-service"),
 ];
 
 /// Service-metric reference in PromQL expressions: matches the prefix list
@@ -31,7 +32,7 @@ pub const CANONICAL_SERVICES: &[(&str, &str)] = &[
 /// full metric name (`ac_token_validations_total`, `mh_active_connections`,
 /// etc.). Consumers walk `captures_iter` and read group 1.
 ///
-/// Pattern is `\b((?:ac|gc|mc|mh)_[a-z][a-z0-9_]*)`. The alternation is
+/// Pattern is `\b((?:ac|gc|mc|mh|canary)_[a-z][a-z0-9_]*)`. The alternation is
 /// constructed dynamically from `CANONICAL_SERVICES` so adding a service
 /// updates the regex automatically — no separate mirror to maintain.
 #[expect(
@@ -55,7 +56,7 @@ mod tests {
         // CANONICAL_SERVICES but the regex Lazy somehow forks (it can't
         // structurally, but tests cheap), this fires.
         let captured: Vec<&str> = SERVICE_METRIC_PREFIX_RE
-            .captures_iter("ac_x_total gc_y_total mc_z_total mh_w_total")
+            .captures_iter("ac_x_total gc_y_total mc_z_total mh_w_total canary_v_total")
             .filter_map(|c| c.get(1).map(|m| m.as_str()))
             .collect();
         assert_eq!(captured.len(), CANONICAL_SERVICES.len());
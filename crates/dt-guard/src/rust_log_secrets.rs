@@ -126,8 +126,19 @@ static DEBUG_FMT_RE: Lazy<Regex> = Lazy::new(|| {
         .expect("static pattern compiles")
 });
 
+/// `target: "..."` in a log macro names a module (`common.jwt.jwks`), not a
+/// value, so its contents are not scanned for secret vocabulary.
+#[expect(
+    clippy::disallowed_methods,
+    clippy::expect_used,
+    reason = "module-local canonical-home static-regex initializer; pattern compiles at load-time or binary fails — ADR-0034 §6 + ADR-0002 §expect-over-allow"
+)]
+static LOG_TARGET_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"target:\s*"[^"]*""#).expect("static pattern compiles"));
+
 fn secret_hit(line: &str) -> Option<&'static str> {
-    let m = SECRET_WORD_RE.find(line)?;
+    let line = LOG_TARGET_RE.replace_all(line, "target: \"\"");
+    let m = SECRET_WORD_RE.find(&line)?;
     let s = m.as_str();
     PII_TOKENS_CATEGORY_A.iter().copied().find(|t| *t == s)
 }
@@ -351,6 +362,18 @@ mod tests {
         assert!(secret_hit("regular code").is_none());
     }
 
+    #[test]
+    fn secret_hit_ignores_log_target_names() {
+        assert!(secret_hit(
+            r#"tracing::debug!(target: "common.jwt.jwks", kid = %kid, "JWKS cache hit");"#
+        )
+        .is_none());
+        assert_eq!(
+            secret_hit(r#"tracing::debug!(target: "common.jwt", jwt = %jwt, "x");"#),
+            Some("jwt")
+        );
+    }
+
     #[test]
     fn wave2_category_a_additions_recognized() {
         for tok in &["pwd", "cred", "bearer", "auth_code"] {
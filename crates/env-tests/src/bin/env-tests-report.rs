@@ -0,0 +1,48 @@
+//! Write `junit.xml` and `report.html` from the records env-tests left in a
+//! report directory (see `env_tests::report`).
+//!
+//! Usage: `env-tests-report [DIR]`. `DIR` defaults to `$ENV_TEST_REPORT_DIR`.
+
+use env_tests::report::{html_summary, junit_xml, load_records, Outcome, REPORT_DIR_ENV};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let dir = match std::env::args()
+        .nth(1)
+        .or_else(|| std::env::var(REPORT_DIR_ENV).ok())
+    {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => {
+            eprintln!("usage: env-tests-report DIR (or set {})", REPORT_DIR_ENV);
+            return ExitCode::from(2);
+        }
+    };
+
+    let records = match load_records(&dir) {
+        Ok(records) => records,
+        Err(e) => {
+            eprintln!("Failed to read test records from {}: {}", dir.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for (file, contents) in [
+        ("junit.xml", junit_xml(&records)),
+        ("report.html", html_summary(&records)),
+    ] {
+        let path = dir.join(file);
+        if let Err(e) = std::fs::write(&path, contents) {
+            eprintln!("Failed to write {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+        println!("Wrote {}", path.display());
+    }
+
+    let failed = records
+        .iter()
+        .filter(|r| r.outcome == Outcome::Failed)
+        .count();
+    println!("{} tests, {} failed", records.len(), failed);
+    ExitCode::SUCCESS
+}
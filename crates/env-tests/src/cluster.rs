@@ -3,6 +3,7 @@
 //! This module provides the `ClusterConnection` type for validating that the local
 //! kind cluster and port-forwards are available before running tests.
 
+use serde::Deserialize;
 use std::net::{TcpStream, ToSocketAddrs};
 use std::time::Duration;
 use thiserror::Error;
@@ -29,6 +30,8 @@ pub struct ClusterPorts {
     pub ac_url: String,
     pub gc_url: String,
     pub mc_webtransport_url: String,
+    pub mc_health_url: String,
+    pub mh_health_url: String,
     pub prometheus_url: String,
     pub grafana_url: String,
     pub loki_url: Option<String>,
@@ -40,6 +43,8 @@ impl Default for ClusterPorts {
             ac_url: "http://localhost:8082".to_string(),
             gc_url: "http://localhost:8080".to_string(),
             mc_webtransport_url: "https://localhost:4433".to_string(),
+            mc_health_url: "http://localhost:8081".to_string(),
+            mh_health_url: "http://localhost:8083".to_string(),
             prometheus_url: "http://localhost:9090".to_string(),
             grafana_url: "http://localhost:3000".to_string(),
             loki_url: Some("http://localhost:3100".to_string()),
@@ -53,16 +58,21 @@ impl ClusterPorts {
     /// Reads the following env vars as full URLs:
     /// - `ENV_TEST_AC_URL` (default: `http://localhost:8082`)
     /// - `ENV_TEST_GC_URL` (default: `http://localhost:8080`)
+    /// - `ENV_TEST_MC_HEALTH_URL` (default: `http://localhost:8081`)
+    /// - `ENV_TEST_MH_HEALTH_URL` (default: `http://localhost:8083`)
     /// - `ENV_TEST_PROMETHEUS_URL` (default: `http://localhost:9090`)
     /// - `ENV_TEST_GRAFANA_URL` (default: `http://localhost:3000`)
     /// - `ENV_TEST_LOKI_URL` (default: `http://localhost:3100`)
     ///
-    /// MC/MH endpoints come from GC join response, not configuration.
+    /// MC/MH media endpoints come from GC join response, not configuration;
+    /// their health ports only serve `/version`.
     pub fn from_env() -> Result<Self, ClusterError> {
         let defaults = Self::default();
 
         let ac_url = read_env_url("ENV_TEST_AC_URL", &defaults.ac_url)?;
         let gc_url = read_env_url("ENV_TEST_GC_URL", &defaults.gc_url)?;
+        let mc_health_url = read_env_url("ENV_TEST_MC_HEALTH_URL", &defaults.mc_health_url)?;
+        let mh_health_url = read_env_url("ENV_TEST_MH_HEALTH_URL", &defaults.mh_health_url)?;
         let prometheus_url = read_env_url("ENV_TEST_PROMETHEUS_URL", &defaults.prometheus_url)?;
         let grafana_url = read_env_url("ENV_TEST_GRAFANA_URL", &defaults.grafana_url)?;
         let loki_url = match std::env::var("ENV_TEST_LOKI_URL") {
@@ -85,6 +95,8 @@ impl ClusterPorts {
             ac_url,
             gc_url,
             mc_webtransport_url: defaults.mc_webtransport_url,
+            mc_health_url,
+            mh_health_url,
             prometheus_url,
             grafana_url,
             loki_url,
@@ -175,6 +187,18 @@ pub(crate) fn parse_host_port(url: &str) -> Result<(String, u16), ClusterError>
     }
 }
 
+/// Build metadata from a service's `/version` endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServiceVersion {
+    pub service: String,
+    pub version: String,
+    pub git_sha: String,
+    pub build_time: String,
+    /// Protobuf schema fingerprint; `None` for AC, which has no gRPC server.
+    pub proto_schema: Option<String>,
+    pub features: Vec<String>,
+}
+
 /// Connection to the local kind cluster.
 ///
 /// Provides health check utilities and base URLs for service access.
@@ -182,6 +206,8 @@ pub struct ClusterConnection {
     pub ac_base_url: String,
     pub gc_base_url: String,
     pub mc_webtransport_url: String,
+    pub mc_health_url: String,
+    pub mh_health_url: String,
     pub prometheus_base_url: String,
     pub grafana_base_url: String,
     pub loki_base_url: Option<String>,
@@ -237,6 +263,8 @@ impl ClusterConnection {
             ac_base_url: ports.ac_url,
             gc_base_url: ports.gc_url,
             mc_webtransport_url: ports.mc_webtransport_url,
+            mc_health_url: ports.mc_health_url,
+            mh_health_url: ports.mh_health_url,
             prometheus_base_url: ports.prometheus_url,
             grafana_base_url: ports.grafana_url,
             loki_base_url,
@@ -333,6 +361,24 @@ impl ClusterConnection {
         &self.mc_webtransport_url
     }
 
+    /// Fetch `/version` from a service's HTTP base URL.
+    ///
+    /// MC and MH are not TCP-probed at initialization; an unreachable health
+    /// port-forward surfaces here as an HTTP error.
+    pub async fn fetch_version(&self, base_url: &str) -> Result<ServiceVersion, ClusterError> {
+        let version_url = format!("{}/version", base_url);
+
+        let response = self.http_client.get(&version_url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(ClusterError::HealthCheckFailed {
+                message: format!("{} returned status {}", version_url, response.status()),
+            });
+        }
+
+        Ok(response.json().await?)
+    }
+
     /// Check if the AC service ready endpoint is responding.
     pub async fn check_ac_ready(&self) -> Result<(), ClusterError> {
         let ready_url = format!("{}/ready", self.ac_base_url);
@@ -419,6 +465,8 @@ mod tests {
         // Clear any env vars that might be set
         std::env::remove_var("ENV_TEST_AC_URL");
         std::env::remove_var("ENV_TEST_GC_URL");
+        std::env::remove_var("ENV_TEST_MC_HEALTH_URL");
+        std::env::remove_var("ENV_TEST_MH_HEALTH_URL");
         std::env::remove_var("ENV_TEST_PROMETHEUS_URL");
         std::env::remove_var("ENV_TEST_GRAFANA_URL");
         std::env::remove_var("ENV_TEST_LOKI_URL");
@@ -428,6 +476,8 @@ mod tests {
         assert_eq!(ports.ac_url, defaults.ac_url);
         assert_eq!(ports.gc_url, defaults.gc_url);
         assert_eq!(ports.mc_webtransport_url, defaults.mc_webtransport_url);
+        assert_eq!(ports.mc_health_url, defaults.mc_health_url);
+        assert_eq!(ports.mh_health_url, defaults.mh_health_url);
         assert_eq!(ports.prometheus_url, defaults.prometheus_url);
         assert_eq!(ports.grafana_url, defaults.grafana_url);
         assert_eq!(ports.loki_url, defaults.loki_url);
@@ -447,12 +497,17 @@ mod tests {
             "http://host.containers.internal:24301",
         );
         std::env::set_var("ENV_TEST_LOKI_URL", "http://host.containers.internal:24302");
+        std::env::set_var(
+            "ENV_TEST_MC_HEALTH_URL",
+            "http://host.containers.internal:24210",
+        );
 
         let ports = ClusterPorts::from_env().expect("from_env should succeed with env vars");
         assert_eq!(ports.ac_url, "http://host.containers.internal:24200");
         assert_eq!(ports.gc_url, "http://host.containers.internal:24201");
         // MC should remain at default — not configurable via env
         assert_eq!(ports.mc_webtransport_url, "https://localhost:4433");
+        assert_eq!(ports.mc_health_url, "http://host.containers.internal:24210");
         assert_eq!(
             ports.prometheus_url,
             "http://host.containers.internal:24300"
@@ -469,6 +524,7 @@ mod tests {
         std::env::remove_var("ENV_TEST_PROMETHEUS_URL");
         std::env::remove_var("ENV_TEST_GRAFANA_URL");
         std::env::remove_var("ENV_TEST_LOKI_URL");
+        std::env::remove_var("ENV_TEST_MC_HEALTH_URL");
     }
 
     #[test]
@@ -32,9 +32,23 @@
 //!
 //! # Pre-deploy validation - full suite (8-10min)
 //! cargo test -p env-tests --features all
+//!
+//! # Flows in parallel (resources are namespaced per test, see `namespace`)
+//! cargo test -p env-tests --features smoke,flows -- --test-threads=4
+//!
+//! # JUnit XML + HTML summary for a pre-deploy run (see `report`)
+//! ENV_TEST_REPORT_DIR=target/env-tests-report cargo test -p env-tests --features all
+//! cargo run -p env-tests --bin env-tests-report -- target/env-tests-report
+//!
+//! # Only tests exercising AC and GC, e.g. when MC/MH are unchanged (see `targeting`)
+//! ENV_TESTS_SERVICES=ac,gc cargo test -p env-tests --features all
 //! ```
 
 pub mod canary;
 pub mod cluster;
 pub mod eventual;
 pub mod fixtures;
+pub mod namespace;
+pub mod report;
+pub mod scenario;
+pub mod targeting;
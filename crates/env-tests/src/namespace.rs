@@ -0,0 +1,250 @@
+//! Per-test namespacing of cluster resources.
+//!
+//! Every env-test talks to the same Kind cluster and database. Tests that
+//! create meetings or users tag them with a [`TestNamespace`] prefix, so
+//! concurrent tests (`--test-threads` > 1, or two `cargo test` runs against
+//! one cluster) never share or look up each other's data:
+//!
+//! ```ignore
+//! use env_tests::namespace::TestNamespace;
+//!
+//! let ns = TestNamespace::new("join_flow");
+//!
+//! // test-join-flow-1a2b3c4d-<uuid>@envtest.dev
+//! let user = auth_client.register_user(&ns.user("Host")).await?;
+//!
+//! // "Standup [test-join-flow-1a2b3c4d]"
+//! let meeting = gc_client
+//!     .create_meeting(&user.access_token, &ns.meeting("Standup"))
+//!     .await?;
+//! ```
+//!
+//! When the namespace is dropped (including while unwinding from a failed
+//! assertion) it deletes every meeting and user carrying its prefix from
+//! Postgres. Cleanup is best-effort: failures are printed as warnings so a
+//! cleanup problem never hides the test's own result.
+//!
+//! # Prerequisites
+//!
+//! - kubectl must be in PATH and configured for the Kind cluster
+//! - RBAC: pods/exec on `postgres-0` in the `dark-tower` namespace
+
+use crate::fixtures::auth_client::{UserRegistrationRequest, TEST_USER_PASSWORD};
+use crate::fixtures::gc_client::CreateMeetingRequest;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Kubernetes namespace of the Postgres pod.
+const NAMESPACE: &str = "dark-tower";
+
+/// Postgres pod seeded by `infra/kind/scripts/setup.sh`.
+const POSTGRES_POD: &str = "postgres-0";
+
+/// Longest test-name part kept in a prefix.
+const MAX_SLUG_LEN: usize = 32;
+
+/// Errors that can occur while cleaning up a namespace.
+#[derive(Debug, Error)]
+pub enum NamespaceError {
+    #[error("Failed to execute kubectl: {0}")]
+    KubectlExec(String),
+
+    #[error("Failed to delete namespaced resources: {0}")]
+    CleanupFailed(String),
+}
+
+/// A unique prefix for the resources one test creates, and the guard that
+/// deletes them.
+///
+/// Prefixes look like `test-{test name}-{8 hex chars}` and only contain
+/// `[a-z0-9-]`, so they are valid in emails and safe to put in SQL.
+pub struct TestNamespace {
+    prefix: String,
+    /// Set once anything has been tagged; untouched namespaces skip cleanup.
+    used: AtomicBool,
+    cleaned_up: AtomicBool,
+}
+
+impl TestNamespace {
+    /// Create a namespace for the test `name` (usually the test function
+    /// name without its `test_` prefix).
+    pub fn new(name: &str) -> Self {
+        let uuid = Uuid::new_v4().simple().to_string();
+        let short_uuid = uuid.get(..8).unwrap_or(&uuid);
+
+        Self {
+            prefix: format!("test-{}-{}", slug(name), short_uuid),
+            used: AtomicBool::new(false),
+            cleaned_up: AtomicBool::new(false),
+        }
+    }
+
+    /// The prefix shared by every resource in this namespace.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// A unique email in this namespace.
+    pub fn email(&self) -> String {
+        self.used.store(true, Ordering::SeqCst);
+        format!("{}-{}@envtest.dev", self.prefix, Uuid::new_v4().simple())
+    }
+
+    /// A user registration request with an email in this namespace.
+    pub fn user(&self, display_name: impl Into<String>) -> UserRegistrationRequest {
+        UserRegistrationRequest {
+            email: self.email(),
+            password: TEST_USER_PASSWORD.to_string(),
+            display_name: display_name.into(),
+        }
+    }
+
+    /// `display_name` tagged with this namespace, e.g. `Standup [test-x-1a2b3c4d]`.
+    pub fn meeting_name(&self, display_name: &str) -> String {
+        self.used.store(true, Ordering::SeqCst);
+        format!("{} [{}]", display_name, self.prefix)
+    }
+
+    /// A create-meeting request whose display name is tagged with this
+    /// namespace.
+    pub fn meeting(&self, display_name: &str) -> CreateMeetingRequest {
+        CreateMeetingRequest::new(self.meeting_name(display_name))
+    }
+
+    /// Delete every meeting and user in this namespace.
+    ///
+    /// Idempotent; also runs on drop.
+    pub fn cleanup(&self) -> Result<(), NamespaceError> {
+        if !self.used.load(Ordering::SeqCst) || self.cleaned_up.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let output = Command::new("kubectl")
+            .args([
+                "exec",
+                &format!("--namespace={}", NAMESPACE),
+                POSTGRES_POD,
+                "--",
+                "psql",
+                "-U",
+                "darktower",
+                "-d",
+                "dark_tower",
+                "-v",
+                "ON_ERROR_STOP=1",
+                "-c",
+                &self.cleanup_sql(),
+            ])
+            .output()
+            .map_err(|e| NamespaceError::KubectlExec(e.to_string()))?;
+
+        if !output.status.success() {
+            return Err(NamespaceError::CleanupFailed(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// SQL deleting this namespace's rows; psql runs a multi-statement `-c`
+    /// in one transaction.
+    ///
+    /// Participants, audit logs, and auth events of namespaced users are
+    /// deleted first because their `user_id` foreign keys do not cascade.
+    fn cleanup_sql(&self) -> String {
+        let users = format!(
+            "SELECT user_id FROM users WHERE starts_with(email, '{}-')",
+            self.prefix
+        );
+        format!(
+            "DELETE FROM meetings WHERE strpos(display_name, '[{prefix}]') > 0 \
+                OR created_by_user_id IN ({users}); \
+             DELETE FROM participants WHERE user_id IN ({users}); \
+             DELETE FROM audit_logs WHERE user_id IN ({users}); \
+             DELETE FROM auth_events WHERE user_id IN ({users}); \
+             DELETE FROM users WHERE user_id IN ({users});",
+            prefix = self.prefix,
+            users = users,
+        )
+    }
+}
+
+impl Drop for TestNamespace {
+    fn drop(&mut self) {
+        // Synchronous so it also runs while a failed test unwinds
+        if let Err(e) = self.cleanup() {
+            eprintln!("Warning: cleanup of {} failed: {}", self.prefix, e);
+        }
+    }
+}
+
+/// Lowercase `name`, replacing anything outside `[a-z0-9]` with `-`.
+fn slug(name: &str) -> String {
+    let slug: String = name
+        .trim_start_matches("test_")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .take(MAX_SLUG_LEN)
+        .collect();
+    let slug = slug.trim_matches('-');
+
+    if slug.is_empty() {
+        "anon".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A namespace whose drop does not shell out to kubectl.
+    fn offline(name: &str) -> TestNamespace {
+        let ns = TestNamespace::new(name);
+        ns.cleaned_up.store(true, Ordering::SeqCst);
+        ns
+    }
+
+    #[test]
+    fn test_prefix_is_sanitized_and_unique() {
+        let a = offline("test_Join Flow's 'quote'");
+        let b = offline("test_Join Flow's 'quote'");
+
+        assert!(a.prefix().starts_with("test-join-flow-s--quote-"));
+        assert!(a
+            .prefix()
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'));
+        assert_ne!(a.prefix(), b.prefix());
+        assert_eq!(slug("!!!"), "anon");
+    }
+
+    #[test]
+    fn test_resources_carry_prefix() {
+        let ns = offline("tagging");
+
+        assert!(ns.email().starts_with(&format!("{}-", ns.prefix())));
+        assert_ne!(ns.email(), ns.email());
+        assert_eq!(
+            ns.meeting("Standup").display_name,
+            format!("Standup [{}]", ns.prefix())
+        );
+        assert!(ns.cleanup_sql().contains(&format!("'[{}]'", ns.prefix())));
+    }
+
+    #[test]
+    fn test_unused_namespace_skips_cleanup() {
+        let ns = TestNamespace::new("unused");
+        // Would fail without kubectl if it tried to run
+        assert!(ns.cleanup().is_ok());
+    }
+}
@@ -0,0 +1,650 @@
+//! Test reports for pre-deploy runs.
+//!
+//! When `ENV_TEST_REPORT_DIR` is set, each test's [`TestRecorder`] appends a
+//! [`TestRecord`] to `results.jsonl` in that directory: timing, category,
+//! outcome, pods that restarted or were recreated while the test ran, and
+//! on failure the panic message plus recent warning/error lines from the
+//! Dark Tower services. The `env-tests-report` binary turns the records into
+//! `junit.xml` and `report.html`:
+//!
+//! ```bash
+//! rm -rf target/env-tests-report
+//! ENV_TEST_REPORT_DIR=target/env-tests-report cargo test -p env-tests --features all
+//! cargo run -p env-tests --bin env-tests-report -- target/env-tests-report
+//! ```
+//!
+//! Every test starts with:
+//!
+//! ```ignore
+//! let _report = TestRecorder::start(file!(), "test_ac_health_endpoint");
+//! ```
+//!
+//! Without `ENV_TEST_REPORT_DIR` the recorder does nothing, so a plain
+//! `cargo test` makes no extra kubectl calls.
+
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::fs::OpenOptions;
+use std::io::{self, BufRead, BufReader, Write as _};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Mutex, Once};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Directory the records are written to; reporting is off when unset.
+pub const REPORT_DIR_ENV: &str = "ENV_TEST_REPORT_DIR";
+
+/// File in the report directory holding one JSON record per test.
+pub const RESULTS_FILE: &str = "results.jsonl";
+
+/// Namespace the Kind cluster deploys Dark Tower into.
+const NAMESPACE: &str = "dark-tower";
+
+/// Services whose logs are excerpted for failed tests.
+const LOG_SELECTOR: &str = "app in (ac-service,gc-service,mc-service,mh-service)";
+
+/// Most log lines kept per failed test.
+const MAX_LOG_LINES: usize = 40;
+
+/// Serializes appends from concurrently finishing tests in one binary.
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+static PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    /// Message of the panic unwinding this thread, for the failing test's record.
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Outcome of one test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Passed,
+    Failed,
+}
+
+/// A pod that restarted or was recreated while a test ran.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PodChange {
+    pub pod: String,
+    /// e.g. `restarted 2x` or `recreated`.
+    pub change: String,
+}
+
+/// What one test did, as written to `results.jsonl`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestRecord {
+    /// Test file stem, e.g. `24_join_flow`.
+    pub suite: String,
+    pub name: String,
+    /// Feature category: `smoke`, `flows`, `observability`, or `resilience`.
+    pub category: String,
+    pub outcome: Outcome,
+    pub duration_ms: u64,
+    /// Unix time the test started.
+    pub started_at: u64,
+    #[serde(default)]
+    pub pod_changes: Vec<PodChange>,
+    #[serde(default)]
+    pub failure_message: Option<String>,
+    #[serde(default)]
+    pub log_excerpt: Vec<String>,
+}
+
+/// Guard recording one test; writes its [`TestRecord`] on drop.
+///
+/// Drop sees a panic as a failure, so the record is written whether the
+/// test passes or an assertion fails.
+pub struct TestRecorder {
+    state: Option<RecorderState>,
+}
+
+struct RecorderState {
+    dir: PathBuf,
+    suite: String,
+    name: String,
+    started: Instant,
+    started_at: u64,
+    /// Pod name to (uid, restart count) when the test started.
+    pods: HashMap<String, (String, u32)>,
+}
+
+impl TestRecorder {
+    /// Start recording the test `name` in the test file `file` (pass `file!()`).
+    pub fn start(file: &str, name: &str) -> Self {
+        let dir = match std::env::var(REPORT_DIR_ENV) {
+            Ok(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => return Self { state: None },
+        };
+        install_panic_hook();
+        PANIC_MESSAGE.with(|m| m.borrow_mut().take());
+
+        Self {
+            state: Some(RecorderState {
+                dir,
+                suite: suite_name(file),
+                name: name.to_string(),
+                started: Instant::now(),
+                started_at: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or_default(),
+                pods: pod_snapshot().unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+impl Drop for TestRecorder {
+    fn drop(&mut self) {
+        let Some(state) = self.state.take() else {
+            return;
+        };
+        let elapsed = state.started.elapsed();
+        let failed = std::thread::panicking();
+
+        let pod_changes = match pod_snapshot() {
+            Ok(now) => pod_changes(&state.pods, &now),
+            Err(e) => {
+                eprintln!("Warning: could not list pods for the test report: {}", e);
+                Vec::new()
+            }
+        };
+        let record = TestRecord {
+            category: category(&state.suite).to_string(),
+            suite: state.suite,
+            name: state.name,
+            outcome: if failed {
+                Outcome::Failed
+            } else {
+                Outcome::Passed
+            },
+            duration_ms: elapsed.as_millis() as u64,
+            started_at: state.started_at,
+            pod_changes,
+            failure_message: if failed {
+                PANIC_MESSAGE.with(|m| m.borrow_mut().take())
+            } else {
+                None
+            },
+            log_excerpt: if failed {
+                log_excerpt(elapsed)
+            } else {
+                Vec::new()
+            },
+        };
+
+        if let Err(e) = append_record(&state.dir, &record) {
+            eprintln!("Warning: could not write test report record: {}", e);
+        }
+    }
+}
+
+/// Keep the default hook's output, and remember the message for the record.
+fn install_panic_hook() {
+    PANIC_HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let message = info
+                .payload()
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| info.payload().downcast_ref::<&str>().map(|s| s.to_string()))
+                .unwrap_or_else(|| "test panicked".to_string());
+            let message = match info.location() {
+                Some(location) => format!("{} ({})", message, location),
+                None => message,
+            };
+            PANIC_MESSAGE.with(|m| *m.borrow_mut() = Some(message));
+            previous(info);
+        }));
+    });
+}
+
+fn append_record(dir: &Path, record: &TestRecord) -> io::Result<()> {
+    let mut line = serde_json::to_string(record)?;
+    line.push('\n');
+
+    let _guard = WRITE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    std::fs::create_dir_all(dir)?;
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(RESULTS_FILE))?
+        .write_all(line.as_bytes())
+}
+
+/// Read every record in `dir`, skipping lines that do not parse.
+pub fn load_records(dir: &Path) -> io::Result<Vec<TestRecord>> {
+    let file = std::fs::File::open(dir.join(RESULTS_FILE))?;
+    let mut records = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(&line) {
+            Ok(record) => records.push(record),
+            Err(e) => eprintln!("Warning: skipping {}:{}: {}", RESULTS_FILE, index + 1, e),
+        }
+    }
+    Ok(records)
+}
+
+/// `tests/24_join_flow.rs` -> `24_join_flow`.
+fn suite_name(file: &str) -> String {
+    Path::new(file)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| file.to_string())
+}
+
+/// Feature category of a suite, from its numeric prefix (see `lib.rs`).
+pub fn category(suite: &str) -> &'static str {
+    match suite.chars().next() {
+        Some('0') | Some('1') => "smoke",
+        Some('2') => "flows",
+        Some('3') => "observability",
+        Some('4') => "resilience",
+        _ => "other",
+    }
+}
+
+/// Pod name to (uid, total container restarts) in the Dark Tower namespace.
+fn pod_snapshot() -> Result<HashMap<String, (String, u32)>, String> {
+    let output = Command::new("kubectl")
+        .args([
+            "get",
+            "pods",
+            "--namespace",
+            NAMESPACE,
+            "-o",
+            "jsonpath={range .items[*]}{.metadata.name} {.metadata.uid}\
+             {range .status.containerStatuses[*]} {.restartCount}{end}{\"\\n\"}{end}",
+        ])
+        .output()
+        .map_err(|e| format!("Failed to execute kubectl: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    Ok(parse_pod_snapshot(&String::from_utf8_lossy(&output.stdout)))
+}
+
+fn parse_pod_snapshot(output: &str) -> HashMap<String, (String, u32)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let name = fields.next()?;
+            let uid = fields.next()?;
+            let restarts = fields.filter_map(|n| n.parse::<u32>().ok()).sum();
+            Some((name.to_string(), (uid.to_string(), restarts)))
+        })
+        .collect()
+}
+
+fn pod_changes(
+    before: &HashMap<String, (String, u32)>,
+    after: &HashMap<String, (String, u32)>,
+) -> Vec<PodChange> {
+    let mut changes: Vec<PodChange> = after
+        .iter()
+        .filter_map(|(pod, (uid, restarts))| {
+            let change = match before.get(pod) {
+                Some((old_uid, _)) if old_uid != uid => "recreated".to_string(),
+                Some((_, old)) if restarts > old => format!("restarted {}x", restarts - old),
+                Some(_) => return None,
+                None => "created".to_string(),
+            };
+            Some(PodChange {
+                pod: pod.clone(),
+                change,
+            })
+        })
+        .collect();
+    changes.sort_by(|a, b| a.pod.cmp(&b.pod));
+    changes
+}
+
+/// Warning and error lines the services logged while the test ran.
+fn log_excerpt(since: Duration) -> Vec<String> {
+    let since = format!("--since={}s", since.as_secs().max(1) + 5);
+    let output = Command::new("kubectl")
+        .args([
+            "logs",
+            "--namespace",
+            NAMESPACE,
+            "-l",
+            LOG_SELECTOR,
+            &since,
+            "--prefix",
+            "--tail=500",
+            "--max-log-requests=20",
+        ])
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => {
+            let logs = String::from_utf8_lossy(&output.stdout);
+            let lines: Vec<&str> = logs
+                .lines()
+                .filter(|l| l.contains("ERROR") || l.contains("WARN"))
+                .collect();
+            let skip = lines.len().saturating_sub(MAX_LOG_LINES);
+            lines.into_iter().skip(skip).map(str::to_string).collect()
+        }
+        Ok(output) => vec![format!(
+            "(kubectl logs failed: {})",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )],
+        Err(e) => vec![format!("(kubectl logs failed: {})", e)],
+    }
+}
+
+/// Records grouped by suite, in suite order.
+fn by_suite(records: &[TestRecord]) -> BTreeMap<&str, Vec<&TestRecord>> {
+    let mut suites: BTreeMap<&str, Vec<&TestRecord>> = BTreeMap::new();
+    for record in records {
+        suites.entry(&record.suite).or_default().push(record);
+    }
+    suites
+}
+
+fn seconds(ms: u64) -> String {
+    format!("{:.3}", ms as f64 / 1000.0)
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            // Not allowed in XML 1.0
+            c if c.is_control() && !matches!(c, '\n' | '\t' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Pod changes and log lines for a record, as plain text.
+fn diagnostics(record: &TestRecord) -> String {
+    let mut text = String::new();
+    for change in &record.pod_changes {
+        let _ = writeln!(text, "pod {}: {}", change.pod, change.change);
+    }
+    if !record.log_excerpt.is_empty() {
+        text.push_str("service warnings/errors:\n");
+        for line in &record.log_excerpt {
+            let _ = writeln!(text, "{}", line);
+        }
+    }
+    text
+}
+
+/// Render records as JUnit XML, one `<testsuite>` per test file.
+pub fn junit_xml(records: &[TestRecord]) -> String {
+    let failures = records
+        .iter()
+        .filter(|r| r.outcome == Outcome::Failed)
+        .count();
+    let total_ms: u64 = records.iter().map(|r| r.duration_ms).sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"env-tests\" tests=\"{}\" failures=\"{}\" time=\"{}\">",
+        records.len(),
+        failures,
+        seconds(total_ms)
+    );
+    for (suite, tests) in by_suite(records) {
+        let suite_failures = tests
+            .iter()
+            .filter(|r| r.outcome == Outcome::Failed)
+            .count();
+        let suite_ms: u64 = tests.iter().map(|r| r.duration_ms).sum();
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{}\">",
+            escape(suite),
+            tests.len(),
+            suite_failures,
+            seconds(suite_ms)
+        );
+        for record in tests {
+            let _ = writeln!(
+                xml,
+                "    <testcase classname=\"env_tests.{}.{}\" name=\"{}\" time=\"{}\">",
+                escape(&record.category),
+                escape(suite),
+                escape(&record.name),
+                seconds(record.duration_ms)
+            );
+            if record.outcome == Outcome::Failed {
+                let message = record.failure_message.as_deref().unwrap_or("test failed");
+                let _ = writeln!(
+                    xml,
+                    "      <failure message=\"{}\">{}</failure>",
+                    escape(message),
+                    escape(message)
+                );
+            }
+            let diagnostics = diagnostics(record);
+            if !diagnostics.is_empty() {
+                let _ = writeln!(
+                    xml,
+                    "      <system-out>{}</system-out>",
+                    escape(&diagnostics)
+                );
+            }
+            xml.push_str("    </testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// Render records as a standalone HTML summary: totals per category, then
+/// every test with failure details expanded.
+pub fn html_summary(records: &[TestRecord]) -> String {
+    let mut categories: BTreeMap<&str, (usize, usize, u64)> = BTreeMap::new();
+    for record in records {
+        let entry = categories.entry(&record.category).or_default();
+        entry.0 += 1;
+        if record.outcome == Outcome::Failed {
+            entry.1 += 1;
+        }
+        entry.2 += record.duration_ms;
+    }
+    let failures: usize = categories.values().map(|c| c.1).sum();
+
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>env-tests report</title>\n<style>\n\
+         body { font-family: sans-serif; margin: 2em; }\n\
+         table { border-collapse: collapse; margin-bottom: 2em; }\n\
+         th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: left; vertical-align: top; }\n\
+         .passed { color: #1a7f37; } .failed { color: #cf222e; font-weight: bold; }\n\
+         pre { white-space: pre-wrap; margin: 0; }\n\
+         </style>\n</head>\n<body>\n",
+    );
+    let _ = writeln!(
+        html,
+        "<h1>env-tests: {} passed, {} failed</h1>",
+        records.len() - failures,
+        failures
+    );
+
+    html.push_str(
+        "<table>\n<tr><th>Category</th><th>Tests</th><th>Failed</th><th>Time (s)</th></tr>\n",
+    );
+    for (category, (tests, failed, ms)) in &categories {
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            escape(category),
+            tests,
+            failed,
+            seconds(*ms)
+        );
+    }
+    html.push_str("</table>\n");
+
+    html.push_str(
+        "<table>\n<tr><th>Suite</th><th>Test</th><th>Result</th><th>Time (s)</th><th>Pods</th><th>Details</th></tr>\n",
+    );
+    for (suite, tests) in by_suite(records) {
+        for record in tests {
+            let (class, label) = match record.outcome {
+                Outcome::Passed => ("passed", "passed"),
+                Outcome::Failed => ("failed", "FAILED"),
+            };
+            let pods: Vec<String> = record
+                .pod_changes
+                .iter()
+                .map(|c| format!("{} {}", escape(&c.pod), escape(&c.change)))
+                .collect();
+            let details = if record.outcome == Outcome::Failed {
+                let mut details = escape(record.failure_message.as_deref().unwrap_or(""));
+                if !record.log_excerpt.is_empty() {
+                    let _ = write!(
+                        details,
+                        "<details><summary>{} log lines</summary><pre>{}</pre></details>",
+                        record.log_excerpt.len(),
+                        escape(&record.log_excerpt.join("\n"))
+                    );
+                }
+                details
+            } else {
+                String::new()
+            };
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td class=\"{}\">{}</td><td>{}</td><td>{}</td><td><pre>{}</pre></td></tr>",
+                escape(suite),
+                escape(&record.name),
+                class,
+                label,
+                seconds(record.duration_ms),
+                pods.join("<br>"),
+                details
+            );
+        }
+    }
+    html.push_str("</table>\n</body>\n</html>\n");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(suite: &str, name: &str, outcome: Outcome) -> TestRecord {
+        TestRecord {
+            suite: suite.to_string(),
+            name: name.to_string(),
+            category: category(suite).to_string(),
+            outcome,
+            duration_ms: 1500,
+            started_at: 0,
+            pod_changes: Vec::new(),
+            failure_message: None,
+            log_excerpt: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_suite_and_category() {
+        assert_eq!(
+            suite_name("crates/env-tests/tests/24_join_flow.rs"),
+            "24_join_flow"
+        );
+        assert_eq!(category("00_cluster_health"), "smoke");
+        assert_eq!(category("10_auth_smoke"), "smoke");
+        assert_eq!(category("26_mh_quic"), "flows");
+        assert_eq!(category("30_observability"), "observability");
+        assert_eq!(category("41_standby_failover"), "resilience");
+    }
+
+    #[test]
+    fn test_pod_changes() {
+        let before = parse_pod_snapshot("mc-0 uid-a 0\nac-0 uid-b 1 0\ngc-0 uid-c 0\n");
+        let after = parse_pod_snapshot("mc-0 uid-x 0\nac-0 uid-b 2 1\ngc-0 uid-c 0\nmh-0 uid-d\n");
+
+        assert_eq!(
+            pod_changes(&before, &after),
+            vec![
+                PodChange {
+                    pod: "ac-0".to_string(),
+                    change: "restarted 2x".to_string()
+                },
+                PodChange {
+                    pod: "mc-0".to_string(),
+                    change: "recreated".to_string()
+                },
+                PodChange {
+                    pod: "mh-0".to_string(),
+                    change: "created".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_junit_xml() {
+        let mut failed = record("24_join_flow", "test_join", Outcome::Failed);
+        failed.failure_message = Some("expected <200> & got \"404\"".to_string());
+        failed.pod_changes = vec![PodChange {
+            pod: "mc-0".to_string(),
+            change: "recreated".to_string(),
+        }];
+        let records = vec![
+            record(
+                "00_cluster_health",
+                "test_ac_health_endpoint",
+                Outcome::Passed,
+            ),
+            failed,
+        ];
+
+        let xml = junit_xml(&records);
+        assert!(xml
+            .contains("<testsuites name=\"env-tests\" tests=\"2\" failures=\"1\" time=\"3.000\">"));
+        assert!(xml.contains("<testsuite name=\"24_join_flow\" tests=\"1\" failures=\"1\""));
+        assert!(xml.contains("classname=\"env_tests.flows.24_join_flow\" name=\"test_join\""));
+        assert!(xml.contains("message=\"expected &lt;200&gt; &amp; got &quot;404&quot;\""));
+        assert!(xml.contains("<system-out>pod mc-0: recreated\n</system-out>"));
+    }
+
+    #[test]
+    fn test_html_summary() {
+        let mut failed = record("41_standby_failover", "test_failover", Outcome::Failed);
+        failed.log_excerpt = vec!["[pod/mc-0] ERROR <lost lease>".to_string()];
+        let records = vec![
+            record("10_auth_smoke", "test_token", Outcome::Passed),
+            failed,
+        ];
+
+        let html = html_summary(&records);
+        assert!(html.contains("<h1>env-tests: 1 passed, 1 failed</h1>"));
+        assert!(html.contains("<tr><td>resilience</td><td>1</td><td>1</td><td>1.500</td></tr>"));
+        assert!(html.contains("ERROR &lt;lost lease&gt;"));
+    }
+
+    #[test]
+    fn test_recorder_disabled_without_env() {
+        std::env::remove_var(REPORT_DIR_ENV);
+        let recorder = TestRecorder::start(file!(), "test_recorder_disabled_without_env");
+        assert!(recorder.state.is_none());
+    }
+}
@@ -0,0 +1,439 @@
+//! Scenario builder for meeting flow tests.
+//!
+//! Composes the cluster, fixture, and eventual-consistency helpers into a
+//! chain of steps, so a flow test reads as the flow it checks:
+//!
+//! ```no_run
+//! use env_tests::scenario::Scenario;
+//!
+//! #[tokio::test]
+//! async fn test_guests_follow_failover() {
+//!     Scenario::new()
+//!         .create_meeting()
+//!         .join_as_guest(2)
+//!         .kill_pod("mc-0")
+//!         .expect_reconnect()
+//!         .run()
+//!         .await
+//!         .expect("scenario should pass");
+//! }
+//! ```
+//!
+//! Steps run in order when [`Scenario::run`] is awaited and stop at the first
+//! failure. "Joining" here is the GC side of a join: each participant gets a
+//! meeting token and an MC assignment. Tests that need a WebTransport
+//! session connect to `participant.mc_assignment` themselves.
+//!
+//! Pods killed by the scenario are waited on (and deployments passed to
+//! [`Scenario::restore_deployment`] restarted) after the last step, whether
+//! or not the steps passed, so later tests see a settled cluster. Restore
+//! failures are printed as warnings rather than failing the scenario.
+//!
+//! The host and meeting live in a [`TestNamespace`] and are deleted when
+//! `run` returns, so scenarios can run concurrently.
+
+use crate::cluster::{ClusterConnection, ClusterError};
+use crate::eventual::{assert_eventually, ConsistencyCategory};
+use crate::fixtures::gc_client::{GcClient, GuestTokenRequest, JoinMeetingResponse, McAssignment};
+use crate::fixtures::AuthClient;
+use crate::namespace::TestNamespace;
+use std::process::Command;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Namespace the Kind cluster deploys Dark Tower into.
+const NAMESPACE: &str = "dark-tower";
+
+/// CAPTCHA token accepted by GC in the Kind cluster.
+const TEST_CAPTCHA_TOKEN: &str = "test-captcha-token";
+
+/// Scenario errors.
+#[derive(Debug, Error)]
+pub enum ScenarioError {
+    #[error("Cluster unavailable: {0}")]
+    Cluster(#[from] ClusterError),
+
+    #[error("Step {step} ({name}) failed: {message}")]
+    StepFailed {
+        step: usize,
+        name: &'static str,
+        message: String,
+    },
+}
+
+/// One step of a [`Scenario`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Step {
+    CreateMeeting,
+    JoinAsHost,
+    JoinAsGuest(usize),
+    KillPod(String),
+    ExpectReconnect,
+    ExpectMc(String),
+}
+
+impl Step {
+    fn name(&self) -> &'static str {
+        match self {
+            Step::CreateMeeting => "create_meeting",
+            Step::JoinAsHost => "join_as_host",
+            Step::JoinAsGuest(_) => "join_as_guest",
+            Step::KillPod(_) => "kill_pod",
+            Step::ExpectReconnect => "expect_reconnect",
+            Step::ExpectMc(_) => "expect_mc",
+        }
+    }
+}
+
+/// Builder for a meeting flow test. See the [module docs](self).
+#[derive(Debug, Default)]
+#[must_use = "a scenario does nothing until `.run().await`"]
+pub struct Scenario {
+    steps: Vec<Step>,
+    restore: Vec<String>,
+}
+
+impl Scenario {
+    /// Start an empty scenario.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a host user with AC and create a meeting that allows guests.
+    pub fn create_meeting(mut self) -> Self {
+        self.steps.push(Step::CreateMeeting);
+        self
+    }
+
+    /// Join the meeting as its host.
+    pub fn join_as_host(mut self) -> Self {
+        self.steps.push(Step::JoinAsHost);
+        self
+    }
+
+    /// Join the meeting as `count` guests.
+    pub fn join_as_guest(mut self, count: usize) -> Self {
+        self.steps.push(Step::JoinAsGuest(count));
+        self
+    }
+
+    /// Force-delete the pods of an instance (e.g. `mc-0`).
+    pub fn kill_pod(mut self, instance: impl Into<String>) -> Self {
+        self.steps.push(Step::KillPod(instance.into()));
+        self
+    }
+
+    /// Expect every participant to rejoin on an MC other than the killed
+    /// pods within [`ConsistencyCategory::ReplicaSync`].
+    pub fn expect_reconnect(mut self) -> Self {
+        self.steps.push(Step::ExpectReconnect);
+        self
+    }
+
+    /// Expect every participant to be assigned to `mc_id`.
+    pub fn expect_mc(mut self, mc_id: impl Into<String>) -> Self {
+        self.steps.push(Step::ExpectMc(mc_id.into()));
+        self
+    }
+
+    /// Restart a deployment once the scenario finishes (e.g. to re-pair a
+    /// promoted standby with its primary).
+    pub fn restore_deployment(mut self, deployment: impl Into<String>) -> Self {
+        self.restore.push(deployment.into());
+        self
+    }
+
+    /// Connect to the cluster and run the steps in order.
+    pub async fn run(self) -> Result<ScenarioOutcome, ScenarioError> {
+        let cluster = ClusterConnection::new().await?;
+        let mut runner = Runner {
+            auth_client: AuthClient::new(&cluster.ac_base_url),
+            gc_client: GcClient::new(&cluster.gc_base_url),
+            namespace: TestNamespace::new("scenario"),
+            host_token: None,
+            outcome: ScenarioOutcome::default(),
+            killed: Vec::new(),
+        };
+
+        let mut result = Ok(());
+        for (index, step) in self.steps.iter().enumerate() {
+            result = runner
+                .run_step(step)
+                .await
+                .map_err(|message| ScenarioError::StepFailed {
+                    step: index + 1,
+                    name: step.name(),
+                    message,
+                });
+            if result.is_err() {
+                break;
+            }
+        }
+
+        runner.restore(&self.restore);
+        result.map(|()| runner.outcome)
+    }
+}
+
+/// A participant that joined through GC.
+#[derive(Debug, Clone)]
+pub struct Participant {
+    pub display_name: String,
+    pub is_guest: bool,
+    pub mc_assignment: McAssignment,
+}
+
+/// What a successful scenario did.
+#[derive(Debug, Default)]
+pub struct ScenarioOutcome {
+    /// Code of the meeting from `create_meeting`.
+    pub meeting_code: Option<String>,
+    /// Participants with their latest MC assignment.
+    pub participants: Vec<Participant>,
+    /// Time from the last `kill_pod` to every participant reconnecting.
+    pub reconnect_time: Option<Duration>,
+}
+
+/// Mutable state while a scenario runs.
+struct Runner {
+    auth_client: AuthClient,
+    gc_client: GcClient,
+    /// Tags the host and meeting; cleans them up when the run ends.
+    namespace: TestNamespace,
+    host_token: Option<String>,
+    outcome: ScenarioOutcome,
+    /// Killed instances and when they were killed.
+    killed: Vec<(String, Instant)>,
+}
+
+impl Runner {
+    async fn run_step(&mut self, step: &Step) -> Result<(), String> {
+        match step {
+            Step::CreateMeeting => self.create_meeting().await,
+            Step::JoinAsHost => {
+                let joined = self.join(None).await?;
+                self.push_participant("Scenario Host", false, joined);
+                Ok(())
+            }
+            Step::JoinAsGuest(count) => {
+                for _ in 0..*count {
+                    let name = format!("Scenario Guest {}", self.guest_count() + 1);
+                    let joined = self.join(Some(&name)).await?;
+                    self.push_participant(&name, true, joined);
+                }
+                Ok(())
+            }
+            Step::KillPod(instance) => {
+                kubectl(&[
+                    "delete",
+                    "pod",
+                    "-l",
+                    &format!("instance={}", instance),
+                    "--grace-period=0",
+                    "--force",
+                ])?;
+                self.killed.push((instance.clone(), Instant::now()));
+                Ok(())
+            }
+            Step::ExpectReconnect => self.expect_reconnect().await,
+            Step::ExpectMc(mc_id) => {
+                let elsewhere: Vec<String> = self
+                    .outcome
+                    .participants
+                    .iter()
+                    .filter(|p| &p.mc_assignment.mc_id != mc_id)
+                    .map(|p| format!("{} on {}", p.display_name, p.mc_assignment.mc_id))
+                    .collect();
+                if elsewhere.is_empty() {
+                    Ok(())
+                } else {
+                    Err(format!("expected everyone on {}: {:?}", mc_id, elsewhere))
+                }
+            }
+        }
+    }
+
+    async fn create_meeting(&mut self) -> Result<(), String> {
+        let host = self
+            .auth_client
+            .register_user(&self.namespace.user("Scenario Host"))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut request = self.namespace.meeting("Scenario Meeting");
+        request.allow_guests = Some(true);
+        let created = self
+            .gc_client
+            .create_meeting(&host.access_token, &request)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.host_token = Some(host.access_token);
+        self.outcome.meeting_code = Some(created.meeting_code);
+        Ok(())
+    }
+
+    /// Join as the host (`guest_name` is `None`) or as a named guest.
+    async fn join(&self, guest_name: Option<&str>) -> Result<JoinMeetingResponse, String> {
+        let meeting_code = self
+            .outcome
+            .meeting_code
+            .as_deref()
+            .ok_or("no meeting yet; add create_meeting() first")?;
+
+        let result = match guest_name {
+            Some(name) => {
+                let request = GuestTokenRequest {
+                    display_name: name.to_string(),
+                    captcha_token: TEST_CAPTCHA_TOKEN.to_string(),
+                };
+                self.gc_client.get_guest_token(meeting_code, &request).await
+            }
+            None => {
+                let token = self
+                    .host_token
+                    .as_deref()
+                    .ok_or("no host token; add create_meeting() first")?;
+                self.gc_client.join_meeting(meeting_code, token).await
+            }
+        };
+        result.map_err(|e| e.to_string())
+    }
+
+    /// Rejoin every participant, returning their new MC IDs in order.
+    async fn rejoin_all(&self) -> Result<Vec<McAssignment>, String> {
+        let mut assignments = Vec::with_capacity(self.outcome.participants.len());
+        for participant in &self.outcome.participants {
+            let guest_name = participant
+                .is_guest
+                .then_some(participant.display_name.as_str());
+            assignments.push(self.join(guest_name).await?.mc_assignment);
+        }
+        Ok(assignments)
+    }
+
+    async fn expect_reconnect(&mut self) -> Result<(), String> {
+        let (_, killed_at) = self
+            .killed
+            .last()
+            .cloned()
+            .ok_or("nothing to reconnect from; add kill_pod() first")?;
+        if self.outcome.participants.is_empty() {
+            return Err("no participants; add a join step first".to_string());
+        }
+
+        let moved_off_killed = |assignments: &[McAssignment]| {
+            assignments.iter().all(|assignment| {
+                !self
+                    .killed
+                    .iter()
+                    .any(|(instance, _)| *instance == assignment.mc_id)
+            })
+        };
+
+        let this = &*self;
+        let moved_off_killed = &moved_off_killed;
+        assert_eventually(ConsistencyCategory::ReplicaSync, || async move {
+            this.rejoin_all()
+                .await
+                .is_ok_and(|assignments| moved_off_killed(&assignments))
+        })
+        .await
+        .map_err(|e| format!("participants did not move off the killed pods: {}", e))?;
+        let reconnect_time = killed_at.elapsed();
+
+        let assignments = self.rejoin_all().await?;
+        for (participant, assignment) in self.outcome.participants.iter_mut().zip(assignments) {
+            participant.mc_assignment = assignment;
+        }
+        self.outcome.reconnect_time = Some(reconnect_time);
+        Ok(())
+    }
+
+    fn guest_count(&self) -> usize {
+        self.outcome
+            .participants
+            .iter()
+            .filter(|p| p.is_guest)
+            .count()
+    }
+
+    fn push_participant(
+        &mut self,
+        display_name: &str,
+        is_guest: bool,
+        joined: JoinMeetingResponse,
+    ) {
+        self.outcome.participants.push(Participant {
+            display_name: display_name.to_string(),
+            is_guest,
+            mc_assignment: joined.mc_assignment,
+        });
+    }
+
+    /// Wait for killed pods to come back, then restart `deployments`.
+    ///
+    /// Best effort: failures are printed, not returned, so they never mask
+    /// the scenario's own result.
+    fn restore(&self, deployments: &[String]) {
+        for (instance, _) in &self.killed {
+            let deployment = format!("deployment/{}", instance);
+            if let Err(e) = kubectl(&["rollout", "status", &deployment, "--timeout=120s"]) {
+                eprintln!("Warning: {} did not come back: {}", instance, e);
+            }
+        }
+        for deployment in deployments {
+            let deployment = format!("deployment/{}", deployment);
+            if let Err(e) = kubectl(&["rollout", "restart", &deployment])
+                .and_then(|()| kubectl(&["rollout", "status", &deployment, "--timeout=120s"]))
+            {
+                eprintln!("Warning: {} was not restored: {}", deployment, e);
+            }
+        }
+    }
+}
+
+/// Run kubectl against the Dark Tower namespace, returning its stderr on
+/// failure.
+fn kubectl(args: &[&str]) -> Result<(), String> {
+    let output = Command::new("kubectl")
+        .args(args)
+        .args(["--namespace", NAMESPACE])
+        .output()
+        .map_err(|e| format!("Failed to execute kubectl: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "kubectl {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_records_steps_in_order() {
+        let scenario = Scenario::new()
+            .create_meeting()
+            .join_as_guest(2)
+            .kill_pod("mc-0")
+            .expect_reconnect()
+            .restore_deployment("mc-1");
+
+        assert_eq!(
+            scenario.steps,
+            vec![
+                Step::CreateMeeting,
+                Step::JoinAsGuest(2),
+                Step::KillPod("mc-0".to_string()),
+                Step::ExpectReconnect,
+            ]
+        );
+        assert_eq!(scenario.restore, vec!["mc-1".to_string()]);
+    }
+}
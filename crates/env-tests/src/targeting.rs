@@ -0,0 +1,196 @@
+//! Selecting tests by the services they exercise.
+//!
+//! Each test declares the Dark Tower services it needs with
+//! [`requires_services!`](crate::requires_services). Setting
+//! `ENV_TESTS_SERVICES` to a comma-separated list runs only the tests whose
+//! services are all in the list; the rest return early and pass:
+//!
+//! ```bash
+//! # AC-only change: skip everything that needs GC, MC, or MH
+//! ENV_TESTS_SERVICES=ac cargo test -p env-tests --features all
+//!
+//! # AC + GC: also runs cross-service flows, still skips MC/MH join tests
+//! ENV_TESTS_SERVICES=ac,gc cargo test -p env-tests --features all
+//! ```
+//!
+//! Unset (or empty) runs everything. Tests that need none of the services
+//! (Prometheus and Grafana reachability) always run. Skipped tests print a
+//! line to stderr and are not recorded in the [`report`](crate::report).
+//!
+//! ```ignore
+//! #[tokio::test]
+//! async fn test_gc_join_rejects_unauthenticated() {
+//!     requires_services!(Ac, Gc);
+//!     let _report = TestRecorder::start(file!(), "test_gc_join_rejects_unauthenticated");
+//!     // ...
+//! }
+//! ```
+
+use std::collections::BTreeSet;
+use std::sync::OnceLock;
+
+/// Comma-separated services to test; unset runs every test.
+pub const SERVICES_ENV: &str = "ENV_TESTS_SERVICES";
+
+/// A Dark Tower service a test can depend on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Service {
+    /// Auth Controller
+    Ac,
+    /// Global Controller
+    Gc,
+    /// Meeting Controller
+    Mc,
+    /// Media Handler
+    Mh,
+}
+
+impl Service {
+    pub const ALL: [Service; 4] = [Service::Ac, Service::Gc, Service::Mc, Service::Mh];
+
+    /// Name used in `ENV_TESTS_SERVICES`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Service::Ac => "ac",
+            Service::Gc => "gc",
+            Service::Mc => "mc",
+            Service::Mh => "mh",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|s| s.as_str().eq_ignore_ascii_case(name))
+    }
+}
+
+/// Parse an `ENV_TESTS_SERVICES` value; `None` selects every service.
+///
+/// # Panics
+///
+/// On an unknown service name, so a typo fails the run instead of silently
+/// skipping everything.
+fn parse_selection(value: &str) -> Option<BTreeSet<Service>> {
+    let names: Vec<&str> = value
+        .split(',')
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .collect();
+    if names.is_empty() {
+        return None;
+    }
+
+    Some(
+        names
+            .into_iter()
+            .map(|name| {
+                Service::parse(name).unwrap_or_else(|| {
+                    panic!(
+                        "{}: unknown service '{}' (expected ac, gc, mc, mh)",
+                        SERVICES_ENV, name
+                    )
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Services selected by `ENV_TESTS_SERVICES`, read once per test binary.
+fn selection() -> Option<&'static BTreeSet<Service>> {
+    static SELECTION: OnceLock<Option<BTreeSet<Service>>> = OnceLock::new();
+    SELECTION
+        .get_or_init(|| {
+            std::env::var(SERVICES_ENV)
+                .ok()
+                .and_then(|v| parse_selection(&v))
+        })
+        .as_ref()
+}
+
+/// Services in `required` that are not selected.
+fn missing_from(selection: Option<&BTreeSet<Service>>, required: &[Service]) -> Vec<Service> {
+    match selection {
+        None => Vec::new(),
+        Some(selected) => required
+            .iter()
+            .copied()
+            .filter(|s| !selected.contains(s))
+            .collect(),
+    }
+}
+
+/// Whether a test needing `required` should be skipped; prints why if so.
+///
+/// Use [`requires_services!`](crate::requires_services) rather than calling
+/// this directly.
+pub fn should_skip(test: &str, required: &[Service]) -> bool {
+    let missing = missing_from(selection(), required);
+    if missing.is_empty() {
+        return false;
+    }
+
+    let missing: Vec<&str> = missing.iter().map(|s| s.as_str()).collect();
+    eprintln!(
+        "skipping {}: needs {} (not in {})",
+        test,
+        missing.join(","),
+        SERVICES_ENV
+    );
+    true
+}
+
+/// Declare the services a test exercises, returning early when
+/// `ENV_TESTS_SERVICES` excludes any of them.
+///
+/// Takes [`Service`] variant names and goes first in the test body, before
+/// the [`TestRecorder`](crate::report::TestRecorder):
+///
+/// ```ignore
+/// requires_services!(Ac, Gc, Mc);
+/// ```
+#[macro_export]
+macro_rules! requires_services {
+    ($($service:ident),+ $(,)?) => {
+        if $crate::targeting::should_skip(
+            file!(),
+            &[$($crate::targeting::Service::$service),+],
+        ) {
+            return;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_selection() {
+        assert_eq!(parse_selection(""), None);
+        assert_eq!(parse_selection(" , "), None);
+        assert_eq!(
+            parse_selection("AC, gc"),
+            Some(BTreeSet::from([Service::Ac, Service::Gc]))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown service 'redis'")]
+    fn test_parse_selection_rejects_unknown() {
+        parse_selection("ac,redis");
+    }
+
+    #[test]
+    fn test_missing_from_selection() {
+        let ac_gc = BTreeSet::from([Service::Ac, Service::Gc]);
+
+        assert!(missing_from(None, &Service::ALL).is_empty());
+        assert!(missing_from(Some(&ac_gc), &[Service::Ac]).is_empty());
+        assert!(missing_from(Some(&ac_gc), &[]).is_empty());
+        assert_eq!(
+            missing_from(Some(&ac_gc), &[Service::Ac, Service::Mc, Service::Mh]),
+            vec![Service::Mc, Service::Mh]
+        );
+    }
+}
@@ -6,6 +6,8 @@
 #![cfg(feature = "smoke")]
 
 use env_tests::cluster::ClusterConnection;
+use env_tests::report::TestRecorder;
+use env_tests::requires_services;
 use std::process::Command;
 
 /// Helper to create a cluster connection for tests.
@@ -17,6 +19,8 @@ async fn cluster() -> ClusterConnection {
 
 #[tokio::test]
 async fn test_ac_health_endpoint() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_ac_health_endpoint");
     let cluster = cluster().await;
 
     cluster
@@ -27,6 +31,8 @@ async fn test_ac_health_endpoint() {
 
 #[tokio::test]
 async fn test_ac_ready_endpoint() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_ac_ready_endpoint");
     let cluster = cluster().await;
 
     cluster
@@ -37,6 +43,7 @@ async fn test_ac_ready_endpoint() {
 
 #[tokio::test]
 async fn test_prometheus_reachable() {
+    let _report = TestRecorder::start(file!(), "test_prometheus_reachable");
     let cluster = cluster().await;
 
     cluster
@@ -47,6 +54,7 @@ async fn test_prometheus_reachable() {
 
 #[tokio::test]
 async fn test_grafana_reachable() {
+    let _report = TestRecorder::start(file!(), "test_grafana_reachable");
     let cluster = cluster().await;
 
     cluster
@@ -55,8 +63,67 @@ async fn test_grafana_reachable() {
         .expect("Grafana should be reachable on localhost:3000");
 }
 
+#[tokio::test]
+async fn test_deployed_versions_match() {
+    requires_services!(Ac, Gc, Mc, Mh);
+    let _report = TestRecorder::start(file!(), "test_deployed_versions_match");
+    let cluster = cluster().await;
+
+    let mut versions = Vec::new();
+    for url in [
+        &cluster.ac_base_url,
+        &cluster.gc_base_url,
+        &cluster.mc_health_url,
+        &cluster.mh_health_url,
+    ] {
+        let version = cluster
+            .fetch_version(url)
+            .await
+            .unwrap_or_else(|e| panic!("{}/version should respond: {}", url, e));
+        println!(
+            "{}: {} ({}, built {})",
+            version.service, version.version, version.git_sha, version.build_time
+        );
+        versions.push(version);
+    }
+
+    // Mixed commits mean a stale image survived a rebuild
+    let expected_sha = std::env::var("ENV_TESTS_EXPECTED_GIT_SHA")
+        .ok()
+        .filter(|sha| !sha.is_empty())
+        .unwrap_or_else(|| versions[0].git_sha.clone());
+    for version in &versions {
+        assert_eq!(
+            version.git_sha, expected_sha,
+            "{} was built from a different commit",
+            version.service
+        );
+    }
+
+    // AC has no gRPC server; the others must agree on the wire schema
+    let schemas: Vec<_> = versions
+        .iter()
+        .filter(|v| v.service != "ac-service")
+        .map(|v| (v.service.as_str(), v.proto_schema.as_deref()))
+        .collect();
+    for (service, schema) in &schemas {
+        assert!(
+            schema.is_some(),
+            "{} should report its proto schema",
+            service
+        );
+        assert_eq!(
+            *schema, schemas[0].1,
+            "{} was compiled against a different proto schema than {}",
+            service, schemas[0].0
+        );
+    }
+}
+
 #[tokio::test]
 async fn test_secrets_not_in_env_vars() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_secrets_not_in_env_vars");
     // Use kubectl to check pod environment variables don't contain secrets
     let output = Command::new("kubectl")
         .args([
@@ -104,6 +171,8 @@ async fn test_secrets_not_in_env_vars() {
 
 #[tokio::test]
 async fn test_secrets_not_in_logs() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_secrets_not_in_logs");
     // Use kubectl to sample recent logs and check for leaked credentials
     let output = Command::new("kubectl")
         .args([
@@ -7,6 +7,8 @@
 use env_tests::cluster::ClusterConnection;
 use env_tests::fixtures::auth_client::TokenRequest;
 use env_tests::fixtures::AuthClient;
+use env_tests::report::TestRecorder;
+use env_tests::requires_services;
 
 /// Helper to create a cluster connection for tests.
 async fn cluster() -> ClusterConnection {
@@ -17,6 +19,8 @@ async fn cluster() -> ClusterConnection {
 
 #[tokio::test]
 async fn test_token_issuance_with_valid_credentials() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_token_issuance_with_valid_credentials");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -36,6 +40,8 @@ async fn test_token_issuance_with_valid_credentials() {
 
 #[tokio::test]
 async fn test_token_issuance_rejected_invalid_credentials() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_token_issuance_rejected_invalid_credentials");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -59,6 +65,8 @@ async fn test_token_issuance_rejected_invalid_credentials() {
 
 #[tokio::test]
 async fn test_jwks_endpoint_returns_keys() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_jwks_endpoint_returns_keys");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -75,6 +83,8 @@ async fn test_jwks_endpoint_returns_keys() {
 
 #[tokio::test]
 async fn test_jwks_keys_are_valid_format() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_jwks_keys_are_valid_format");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -120,6 +130,8 @@ async fn test_jwks_keys_are_valid_format() {
 /// Alternative approach (if metrics unavailable): Send rapid requests until 429.
 #[tokio::test]
 async fn test_rate_limiting_enabled() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_rate_limiting_enabled");
     let cluster = cluster().await;
 
     // Try to query Prometheus for rate limit metrics
@@ -9,6 +9,8 @@ use base64::Engine;
 use env_tests::cluster::ClusterConnection;
 use env_tests::fixtures::auth_client::TokenRequest;
 use env_tests::fixtures::AuthClient;
+use env_tests::report::TestRecorder;
+use env_tests::requires_services;
 use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
 use serde::{Deserialize, Serialize};
 
@@ -31,6 +33,8 @@ async fn cluster() -> ClusterConnection {
 
 #[tokio::test]
 async fn test_token_validates_against_jwks() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_token_validates_against_jwks");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -88,6 +92,8 @@ async fn test_token_validates_against_jwks() {
 
 #[tokio::test]
 async fn test_token_has_expected_claims() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_token_has_expected_claims");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -114,6 +120,8 @@ async fn test_token_has_expected_claims() {
 
 #[tokio::test]
 async fn test_cross_replica_token_consistency() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_cross_replica_token_consistency");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -173,6 +181,8 @@ async fn test_cross_replica_token_consistency() {
 
 #[tokio::test]
 async fn test_expired_token_rejected() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_expired_token_rejected");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -231,6 +241,8 @@ async fn test_expired_token_rejected() {
 
 #[tokio::test]
 async fn test_invalid_signature_rejected() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_invalid_signature_rejected");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -21,6 +21,8 @@ use env_tests::cluster::ClusterConnection;
 use env_tests::fixtures::auth_client::{TokenRequest, UserRegistrationRequest};
 use env_tests::fixtures::gc_client::{GcClient, GuestTokenRequest, UpdateMeetingSettingsRequest};
 use env_tests::fixtures::AuthClient;
+use env_tests::report::TestRecorder;
+use env_tests::requires_services;
 
 /// Helper to create a cluster connection and verify GC is available.
 ///
@@ -49,6 +51,8 @@ async fn cluster() -> ClusterConnection {
 /// 3. GC can reach AC's JWKS endpoint for token validation
 #[tokio::test]
 async fn test_ac_gc_services_healthy() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_ac_gc_services_healthy");
     let cluster = cluster().await;
 
     // Verify AC is healthy
@@ -85,6 +89,8 @@ async fn test_ac_gc_services_healthy() {
 /// 4. GC returns user claims from token
 #[tokio::test]
 async fn test_gc_validates_ac_token_via_me_endpoint() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_gc_validates_ac_token_via_me_endpoint");
     let cluster = cluster().await;
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
@@ -121,6 +127,8 @@ async fn test_gc_validates_ac_token_via_me_endpoint() {
 /// Protected endpoints should return 401 when no Authorization header is provided.
 #[tokio::test]
 async fn test_gc_rejects_unauthenticated_requests() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_gc_rejects_unauthenticated_requests");
     let cluster = cluster().await;
 
     let gc_client = GcClient::new(&cluster.gc_base_url);
@@ -147,6 +155,8 @@ async fn test_gc_rejects_unauthenticated_requests() {
 /// Tokens with invalid signatures should be rejected.
 #[tokio::test]
 async fn test_gc_rejects_invalid_token() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_gc_rejects_invalid_token");
     let cluster = cluster().await;
 
     let gc_client = GcClient::new(&cluster.gc_base_url);
@@ -185,6 +195,8 @@ async fn test_gc_rejects_invalid_token() {
 /// when the meeting is not found (database not seeded).
 #[tokio::test]
 async fn test_meeting_join_requires_authentication() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_meeting_join_requires_authentication");
     let cluster = cluster().await;
 
     let gc_client = GcClient::new(&cluster.gc_base_url);
@@ -209,6 +221,8 @@ async fn test_meeting_join_requires_authentication() {
 /// Test: Authenticated user gets appropriate error for non-existent meeting.
 #[tokio::test]
 async fn test_meeting_join_returns_404_for_unknown_meeting() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_meeting_join_returns_404_for_unknown_meeting");
     let cluster = cluster().await;
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
@@ -256,6 +270,8 @@ async fn test_meeting_join_returns_404_for_unknown_meeting() {
 /// but validate the captcha and meeting permissions.
 #[tokio::test]
 async fn test_guest_token_endpoint_is_public() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_guest_token_endpoint_is_public");
     let cluster = cluster().await;
 
     let gc_client = GcClient::new(&cluster.gc_base_url);
@@ -304,6 +320,8 @@ async fn test_guest_token_endpoint_is_public() {
 /// Test: Guest token request validates required fields.
 #[tokio::test]
 async fn test_guest_token_validates_display_name() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_guest_token_validates_display_name");
     let cluster = cluster().await;
 
     let gc_client = GcClient::new(&cluster.gc_base_url);
@@ -345,6 +363,8 @@ async fn test_guest_token_validates_display_name() {
 /// Test: Meeting settings update requires authentication.
 #[tokio::test]
 async fn test_meeting_settings_requires_authentication() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_meeting_settings_requires_authentication");
     let cluster = cluster().await;
 
     let gc_client = GcClient::new(&cluster.gc_base_url);
@@ -373,6 +393,11 @@ async fn test_meeting_settings_requires_authentication() {
 /// Test: Meeting settings update returns 404 for non-existent meeting.
 #[tokio::test]
 async fn test_meeting_settings_returns_404_for_unknown_meeting() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(
+        file!(),
+        "test_meeting_settings_returns_404_for_unknown_meeting",
+    );
     let cluster = cluster().await;
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
@@ -422,6 +447,8 @@ async fn test_meeting_settings_returns_404_for_unknown_meeting() {
 /// This validates that GC properly caches JWKS and validates tokens consistently.
 #[tokio::test]
 async fn test_token_validation_consistency() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_token_validation_consistency");
     let cluster = cluster().await;
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
@@ -453,6 +480,8 @@ async fn test_token_validation_consistency() {
 /// Test: Different tokens from AC are all validated correctly by GC.
 #[tokio::test]
 async fn test_multiple_tokens_validated() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_multiple_tokens_validated");
     let cluster = cluster().await;
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
@@ -36,6 +36,8 @@
 
 use env_tests::cluster::ClusterConnection;
 use env_tests::fixtures::gc_client::{GcClient, GcClientError, GuestTokenRequest};
+use env_tests::report::TestRecorder;
+use env_tests::requires_services;
 
 /// Helper to create a cluster connection and verify GC is available.
 ///
@@ -64,6 +66,8 @@ async fn cluster() -> ClusterConnection {
 /// 3. Does not return 401 (which would mean auth is incorrectly required)
 #[tokio::test]
 async fn test_guest_token_returns_404_for_unknown_meeting() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_guest_token_returns_404_for_unknown_meeting");
     let cluster = cluster().await;
 
     let gc_client = GcClient::new(&cluster.gc_base_url);
@@ -114,6 +118,8 @@ async fn test_guest_token_returns_404_for_unknown_meeting() {
 /// - Failure is due to business logic (404, 403, 400, 503), not auth (401)
 #[tokio::test]
 async fn test_guest_endpoint_does_not_require_auth() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_guest_endpoint_does_not_require_auth");
     let cluster = cluster().await;
 
     let gc_client = GcClient::new(&cluster.gc_base_url);
@@ -171,6 +177,8 @@ async fn test_guest_endpoint_does_not_require_auth() {
 /// validates the error body does not contain internal details.
 #[tokio::test]
 async fn test_error_responses_sanitized() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_error_responses_sanitized");
     let cluster = cluster().await;
 
     let gc_client = GcClient::new(&cluster.gc_base_url);
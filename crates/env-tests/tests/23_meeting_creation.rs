@@ -24,9 +24,12 @@
 #![cfg(feature = "flows")]
 
 use env_tests::cluster::ClusterConnection;
-use env_tests::fixtures::auth_client::{TokenRequest, UserRegistrationRequest};
-use env_tests::fixtures::gc_client::{CreateMeetingRequest, GcClient};
+use env_tests::fixtures::auth_client::TokenRequest;
+use env_tests::fixtures::gc_client::GcClient;
 use env_tests::fixtures::AuthClient;
+use env_tests::namespace::TestNamespace;
+use env_tests::report::TestRecorder;
+use env_tests::requires_services;
 use std::collections::HashSet;
 
 /// Helper to create a cluster connection and verify both AC and GC are available.
@@ -47,9 +50,13 @@ async fn cluster() -> ClusterConnection {
 
 /// Register a test user via AC and return the user JWT access token.
 ///
-/// Creates a unique user with a UUID-based email to prevent collisions.
-async fn register_test_user(auth_client: &AuthClient, display_name: &str) -> String {
-    let request = UserRegistrationRequest::unique(display_name);
+/// The user's email is in `ns`, so it is deleted when the test ends.
+async fn register_test_user(
+    auth_client: &AuthClient,
+    ns: &TestNamespace,
+    display_name: &str,
+) -> String {
+    let request = ns.user(display_name);
 
     let response = auth_client
         .register_user(&request)
@@ -75,16 +82,19 @@ async fn register_test_user(auth_client: &AuthClient, display_name: &str) -> Str
 ///    enable_e2e_encryption=true, recording_enabled=false
 #[tokio::test]
 async fn test_authenticated_user_can_create_meeting() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_authenticated_user_can_create_meeting");
     let cluster = cluster().await;
+    let ns = TestNamespace::new("authenticated_user_can_create_meeting");
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
     let gc_client = GcClient::new(&cluster.gc_base_url);
 
     // Step 1: Register user and get JWT
-    let user_token = register_test_user(&auth_client, "Create Meeting Test User").await;
+    let user_token = register_test_user(&auth_client, &ns, "Create Meeting Test User").await;
 
     // Step 2: Create meeting with minimal request (secure defaults)
-    let create_request = CreateMeetingRequest::new("Env Test Meeting");
+    let create_request = ns.meeting("Env Test Meeting");
 
     let response = gc_client
         .create_meeting(&user_token, &create_request)
@@ -111,7 +121,7 @@ async fn test_authenticated_user_can_create_meeting() {
         response.meeting_code
     );
 
-    assert_eq!(response.display_name, "Env Test Meeting");
+    assert_eq!(response.display_name, create_request.display_name);
     assert_eq!(response.status, "scheduled");
     assert_eq!(response.max_participants, 100, "Default max_participants");
 
@@ -160,15 +170,18 @@ async fn test_authenticated_user_can_create_meeting() {
 /// crucially should NOT return 404 — proving the meeting was persisted.
 #[tokio::test]
 async fn test_create_meeting_round_trip_findable() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_create_meeting_round_trip_findable");
     let cluster = cluster().await;
+    let ns = TestNamespace::new("create_meeting_round_trip_findable");
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
     let gc_client = GcClient::new(&cluster.gc_base_url);
 
     // Step 1: Register user and create meeting
-    let user_token = register_test_user(&auth_client, "Round-Trip Test User").await;
+    let user_token = register_test_user(&auth_client, &ns, "Round-Trip Test User").await;
 
-    let create_request = CreateMeetingRequest::new("Round-Trip Test Meeting");
+    let create_request = ns.meeting("Round-Trip Test Meeting");
     let created = gc_client
         .create_meeting(&user_token, &create_request)
         .await
@@ -217,6 +230,8 @@ async fn test_create_meeting_round_trip_findable() {
 /// POST /api/v1/meetings without Authorization header should return 401.
 #[tokio::test]
 async fn test_create_meeting_unauthenticated_rejected() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_create_meeting_unauthenticated_rejected");
     let cluster = cluster().await;
 
     let gc_client = GcClient::new(&cluster.gc_base_url);
@@ -246,6 +261,8 @@ async fn test_create_meeting_unauthenticated_rejected() {
 /// should be rejected with 401.
 #[tokio::test]
 async fn test_create_meeting_rejects_service_token() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_create_meeting_rejects_service_token");
     let cluster = cluster().await;
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
@@ -284,13 +301,16 @@ async fn test_create_meeting_rejects_service_token() {
 /// 2. Missing required field (display_name) returns 400
 #[tokio::test]
 async fn test_create_meeting_invalid_body_rejected() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_create_meeting_invalid_body_rejected");
     let cluster = cluster().await;
+    let ns = TestNamespace::new("create_meeting_invalid_body_rejected");
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
     let gc_client = GcClient::new(&cluster.gc_base_url);
 
     // Register user for authentication
-    let user_token = register_test_user(&auth_client, "Invalid Body Test User").await;
+    let user_token = register_test_user(&auth_client, &ns, "Invalid Body Test User").await;
 
     // Test 1: Malformed JSON
     let result = gc_client
@@ -339,19 +359,22 @@ async fn test_create_meeting_invalid_body_rejected() {
 /// are distinct (72 bits entropy should make collisions practically impossible).
 #[tokio::test]
 async fn test_create_meeting_unique_codes() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_create_meeting_unique_codes");
     let cluster = cluster().await;
+    let ns = TestNamespace::new("create_meeting_unique_codes");
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
     let gc_client = GcClient::new(&cluster.gc_base_url);
 
     // Register a single user (to conserve rate limit quota)
-    let user_token = register_test_user(&auth_client, "Unique Codes Test User").await;
+    let user_token = register_test_user(&auth_client, &ns, "Unique Codes Test User").await;
 
     let mut codes = HashSet::new();
     let meeting_count = 3;
 
     for i in 0..meeting_count {
-        let request = CreateMeetingRequest::new(format!("Unique Code Test {}", i));
+        let request = ns.meeting(&format!("Unique Code Test {}", i));
 
         let response = gc_client
             .create_meeting(&user_token, &request)
@@ -21,8 +21,11 @@
 use bytes::{BufMut, BytesMut};
 use env_tests::cluster::ClusterConnection;
 use env_tests::fixtures::auth_client::{TokenRequest, UserRegistrationRequest};
-use env_tests::fixtures::gc_client::{CreateMeetingRequest, GcClient, GcClientError};
+use env_tests::fixtures::gc_client::{GcClient, GcClientError};
 use env_tests::fixtures::AuthClient;
+use env_tests::namespace::TestNamespace;
+use env_tests::report::TestRecorder;
+use env_tests::requires_services;
 use prost::Message;
 use proto_gen::dark_tower::signaling::v1::{
     client_message, server_message, ClientMessage, JoinRequest, ServerMessage,
@@ -39,6 +42,9 @@ static CLUSTER: OnceCell<ClusterConnection> = OnceCell::const_new();
 
 /// Shared test user token (reduces registration count within rate limit budget).
 /// Tests that only need a valid user JWT reuse this instead of registering their own.
+///
+/// Statics are never dropped, so this user is not in a [`TestNamespace`];
+/// the meetings tests create with it are.
 static SHARED_USER: OnceCell<(String, String)> = OnceCell::const_new();
 
 /// Helper to get a cluster connection, verifying AC + GC are available.
@@ -73,14 +79,20 @@ async fn shared_user(cluster: &ClusterConnection) -> &'static (String, String) {
     SHARED_USER
         .get_or_init(|| async {
             let auth_client = AuthClient::new(&cluster.ac_base_url);
-            register_test_user(&auth_client, "Join Flow Shared User").await
+            register_test_user(
+                &auth_client,
+                UserRegistrationRequest::unique("Join Flow Shared User"),
+            )
+            .await
         })
         .await
 }
 
 /// Register a test user via AC and return the user JWT access token and display name.
-async fn register_test_user(auth_client: &AuthClient, display_name: &str) -> (String, String) {
-    let request = UserRegistrationRequest::unique(display_name);
+async fn register_test_user(
+    auth_client: &AuthClient,
+    request: UserRegistrationRequest,
+) -> (String, String) {
     let display = request.display_name.clone();
     let response = auth_client
         .register_user(&request)
@@ -166,6 +178,7 @@ async fn send_join_and_read_response(
             capabilities: None,
             correlation_id: String::new(),
             binding_token: String::new(),
+            join_id: String::new(),
         })),
         trace_parent: String::new(),
         trace_state: String::new(),
@@ -198,12 +211,18 @@ async fn send_join_and_read_response(
 ///    and mc_assignment with mc_id and grpc_endpoint
 #[tokio::test]
 async fn test_gc_join_returns_meeting_token_and_mc_assignment() {
+    requires_services!(Ac, Gc, Mc);
+    let _report = TestRecorder::start(
+        file!(),
+        "test_gc_join_returns_meeting_token_and_mc_assignment",
+    );
+    let ns = TestNamespace::new("gc_join_returns_meeting_token_and_mc_assignment");
     let cluster = cluster().await;
     let (user_token, _) = shared_user(cluster).await;
     let gc_client = GcClient::new(&cluster.gc_base_url);
 
     // Step 1: Create meeting
-    let create_request = CreateMeetingRequest::new("Join Flow Test Meeting");
+    let create_request = ns.meeting("Join Flow Test Meeting");
     let created = gc_client
         .create_meeting(user_token, &create_request)
         .await
@@ -255,6 +274,8 @@ async fn test_gc_join_returns_meeting_token_and_mc_assignment() {
 /// GET /api/v1/meetings/{code} without Authorization header should return 401.
 #[tokio::test]
 async fn test_gc_join_rejects_unauthenticated() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_gc_join_rejects_unauthenticated");
     let cluster = cluster().await;
 
     let gc_client = GcClient::new(&cluster.gc_base_url);
@@ -280,6 +301,8 @@ async fn test_gc_join_rejects_unauthenticated() {
 /// Authenticated user tries to join a meeting code that does not exist.
 #[tokio::test]
 async fn test_gc_join_returns_404_for_unknown_meeting() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_gc_join_returns_404_for_unknown_meeting");
     let cluster = cluster().await;
     let (user_token, _) = shared_user(cluster).await;
     let gc_client = GcClient::new(&cluster.gc_base_url);
@@ -310,6 +333,9 @@ async fn test_gc_join_returns_404_for_unknown_meeting() {
 /// tokens without a valid user UUID `sub` claim.
 #[tokio::test]
 async fn test_gc_join_rejects_service_token() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_gc_join_rejects_service_token");
+    let ns = TestNamespace::new("gc_join_rejects_service_token");
     let cluster = cluster().await;
     let (user_token, _) = shared_user(cluster).await;
 
@@ -325,7 +351,7 @@ async fn test_gc_join_rejects_service_token() {
         .expect("Should issue service token");
 
     // Create a meeting with the shared user token so the code exists
-    let create_request = CreateMeetingRequest::new("Service Token Test Meeting");
+    let create_request = ns.meeting("Service Token Test Meeting");
     let created = gc_client
         .create_meeting(user_token, &create_request)
         .await
@@ -351,12 +377,15 @@ async fn test_gc_join_rejects_service_token() {
 /// The guest-token endpoint is public (no auth required).
 #[tokio::test]
 async fn test_gc_guest_join_succeeds_when_allowed() {
+    requires_services!(Ac, Gc, Mc);
+    let _report = TestRecorder::start(file!(), "test_gc_guest_join_succeeds_when_allowed");
+    let ns = TestNamespace::new("gc_guest_join_succeeds_when_allowed");
     let cluster = cluster().await;
     let (user_token, _) = shared_user(cluster).await;
     let gc_client = GcClient::new(&cluster.gc_base_url);
 
     // Create meeting with allow_guests enabled
-    let mut create_request = CreateMeetingRequest::new("Guest Allowed Meeting");
+    let mut create_request = ns.meeting("Guest Allowed Meeting");
     create_request.allow_guests = Some(true);
     create_request.waiting_room_enabled = Some(false);
 
@@ -393,12 +422,15 @@ async fn test_gc_guest_join_succeeds_when_allowed() {
 /// attempts to get a guest token. Should be rejected.
 #[tokio::test]
 async fn test_gc_guest_join_rejected_when_disabled() {
+    requires_services!(Ac, Gc);
+    let _report = TestRecorder::start(file!(), "test_gc_guest_join_rejected_when_disabled");
+    let ns = TestNamespace::new("gc_guest_join_rejected_when_disabled");
     let cluster = cluster().await;
     let (user_token, _) = shared_user(cluster).await;
     let gc_client = GcClient::new(&cluster.gc_base_url);
 
     // Create meeting with default settings (allow_guests=false)
-    let create_request = CreateMeetingRequest::new("Guest Denied Meeting");
+    let create_request = ns.meeting("Guest Denied Meeting");
 
     let created = gc_client
         .create_meeting(user_token, &create_request)
@@ -446,6 +478,9 @@ async fn test_gc_guest_join_rejected_when_disabled() {
 /// 4. JoinResponse contains participant_id and correlation_id (ADR-0023)
 #[tokio::test]
 async fn test_mc_webtransport_connect_and_join() {
+    requires_services!(Ac, Gc, Mc);
+    let _report = TestRecorder::start(file!(), "test_mc_webtransport_connect_and_join");
+    let ns = TestNamespace::new("mc_webtransport_connect_and_join");
     let cluster = cluster().await;
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
@@ -453,9 +488,9 @@ async fn test_mc_webtransport_connect_and_join() {
 
     // Step 1: Register user, create meeting, join via GC
     let (user_token, display_name) =
-        register_test_user(&auth_client, "WebTransport Join User").await;
+        register_test_user(&auth_client, ns.user("WebTransport Join User")).await;
 
-    let create_request = CreateMeetingRequest::new("WebTransport Test Meeting");
+    let create_request = ns.meeting("WebTransport Test Meeting");
     let created = gc_client
         .create_meeting(&user_token, &create_request)
         .await
@@ -521,15 +556,19 @@ async fn test_mc_webtransport_connect_and_join() {
 /// 2. A bogus token (forged JWT, correct meeting ID)
 #[tokio::test]
 async fn test_mc_rejects_invalid_meeting_token() {
+    requires_services!(Ac, Gc, Mc);
+    let _report = TestRecorder::start(file!(), "test_mc_rejects_invalid_meeting_token");
+    let ns = TestNamespace::new("mc_rejects_invalid_meeting_token");
     let cluster = cluster().await;
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
     let gc_client = GcClient::new(&cluster.gc_base_url);
 
     // Step 1: Register user, create meeting, join via GC to get a real token
-    let (user_token, _display_name) = register_test_user(&auth_client, "Rejection Test User").await;
+    let (user_token, _display_name) =
+        register_test_user(&auth_client, ns.user("Rejection Test User")).await;
 
-    let create_request = CreateMeetingRequest::new("Rejection Test Meeting");
+    let create_request = ns.meeting("Rejection Test Meeting");
     let created = gc_client
         .create_meeting(&user_token, &create_request)
         .await
@@ -629,17 +668,25 @@ async fn test_mc_rejects_invalid_meeting_token() {
 /// 3. The notification contains the new participant's information
 #[tokio::test]
 async fn test_second_participant_receives_join_notification() {
+    requires_services!(Ac, Gc, Mc);
+    let _report = TestRecorder::start(
+        file!(),
+        "test_second_participant_receives_join_notification",
+    );
+    let ns = TestNamespace::new("second_participant_receives_join_notification");
     let cluster = cluster().await;
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
     let gc_client = GcClient::new(&cluster.gc_base_url);
 
     // Step 1: Register two users
-    let (user1_token, user1_name) = register_test_user(&auth_client, "Bridge Test User 1").await;
-    let (user2_token, user2_name) = register_test_user(&auth_client, "Bridge Test User 2").await;
+    let (user1_token, user1_name) =
+        register_test_user(&auth_client, ns.user("Bridge Test User 1")).await;
+    let (user2_token, user2_name) =
+        register_test_user(&auth_client, ns.user("Bridge Test User 2")).await;
 
     // Step 2: User 1 creates a meeting
-    let create_request = CreateMeetingRequest::new("Bridge Notification Test");
+    let create_request = ns.meeting("Bridge Notification Test");
     let created = gc_client
         .create_meeting(&user1_token, &create_request)
         .await
@@ -680,6 +727,7 @@ async fn test_second_participant_receives_join_notification() {
             capabilities: None,
             correlation_id: String::new(),
             binding_token: String::new(),
+            join_id: String::new(),
         })),
         trace_parent: String::new(),
         trace_state: String::new(),
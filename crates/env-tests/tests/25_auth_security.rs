@@ -10,6 +10,8 @@ use chrono::Utc;
 use env_tests::cluster::ClusterConnection;
 use env_tests::fixtures::auth_client::TokenRequest;
 use env_tests::fixtures::AuthClient;
+use env_tests::report::TestRecorder;
+use env_tests::requires_services;
 use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +34,8 @@ async fn cluster() -> ClusterConnection {
 
 #[tokio::test]
 async fn test_tampered_token_rejected() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_tampered_token_rejected");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -102,6 +106,8 @@ async fn test_tampered_token_rejected() {
 
 #[tokio::test]
 async fn test_wrong_algorithm_rejected() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_wrong_algorithm_rejected");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -167,6 +173,8 @@ async fn test_wrong_algorithm_rejected() {
 
 #[tokio::test]
 async fn test_missing_required_claims_rejected() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_missing_required_claims_rejected");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -224,6 +232,8 @@ async fn test_missing_required_claims_rejected() {
 /// Validates CWE-321 (cryptographic key exposure).
 #[tokio::test]
 async fn test_jwks_no_private_key_leakage() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_jwks_no_private_key_leakage");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -288,6 +298,8 @@ async fn test_jwks_no_private_key_leakage() {
 /// Test that the 'iat' (issued at) claim is set to a current timestamp.
 #[tokio::test]
 async fn test_iat_claim_is_current() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_iat_claim_is_current");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -348,6 +360,8 @@ async fn test_iat_claim_is_current() {
 /// Test that token lifetime is approximately 1 hour (3600 seconds) per ADR-0007.
 #[tokio::test]
 async fn test_token_lifetime_is_reasonable() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_token_lifetime_is_reasonable");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -404,6 +418,8 @@ async fn test_token_lifetime_is_reasonable() {
 /// The service should fail signature validation when kid is tampered.
 #[tokio::test]
 async fn test_kid_injection_rejected() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_kid_injection_rejected");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -485,6 +501,8 @@ async fn test_kid_injection_rejected() {
 /// An attacker should not be able to embed their own key in the token header.
 #[tokio::test]
 async fn test_jwk_header_injection_rejected() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_jwk_header_injection_rejected");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
@@ -561,6 +579,8 @@ async fn test_jwk_header_injection_rejected() {
 /// The service should NEVER fetch keys from URLs specified in token headers.
 #[tokio::test]
 async fn test_jku_header_injection_rejected() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_jku_header_injection_rejected");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
 
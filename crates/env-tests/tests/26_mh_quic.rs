@@ -54,6 +54,9 @@ use env_tests::cluster::ClusterConnection;
 use env_tests::fixtures::auth_client::UserRegistrationRequest;
 use env_tests::fixtures::gc_client::{CreateMeetingRequest, GcClient, JoinMeetingResponse};
 use env_tests::fixtures::{AuthClient, PrometheusClient};
+use env_tests::namespace::TestNamespace;
+use env_tests::report::TestRecorder;
+use env_tests::requires_services;
 use prost::Message;
 use std::time::Duration;
 use tokio::sync::OnceCell;
@@ -66,6 +69,9 @@ use tokio::sync::OnceCell;
 static CLUSTER: OnceCell<ClusterConnection> = OnceCell::const_new();
 
 /// Shared test user (cuts AC registrations under the 5/hour rate limit).
+///
+/// Statics are never dropped, so this user is not in a [`TestNamespace`];
+/// the meetings tests create with it are.
 static SHARED_USER: OnceCell<(String, String)> = OnceCell::const_new();
 
 async fn cluster() -> &'static ClusterConnection {
@@ -91,14 +97,20 @@ async fn shared_user(cluster: &ClusterConnection) -> &'static (String, String) {
     SHARED_USER
         .get_or_init(|| async {
             let auth_client = AuthClient::new(&cluster.ac_base_url);
-            register_test_user(&auth_client, "MH QUIC Shared User").await
+            register_test_user(
+                &auth_client,
+                UserRegistrationRequest::unique("MH QUIC Shared User"),
+            )
+            .await
         })
         .await
 }
 
 /// Register a test user via AC and return `(access_token, display_name)`.
-async fn register_test_user(auth_client: &AuthClient, display_name: &str) -> (String, String) {
-    let request = UserRegistrationRequest::unique(display_name);
+async fn register_test_user(
+    auth_client: &AuthClient,
+    request: UserRegistrationRequest,
+) -> (String, String) {
     let display = request.display_name.clone();
     let response = auth_client
         .register_user(&request)
@@ -149,15 +161,16 @@ async fn connect_wt(url: &str) -> wtransport::Connection {
         .unwrap_or_else(|e| panic!("connect to WebTransport at {url} failed: {e}"))
 }
 
-/// Encode `jwt` as the typed `MhClientMessage{ConnectRequest{join_token}}`
-/// envelope and frame it (4-byte BE length + encoded payload).
+/// Encode `jwt` and `media_token` as the typed
+/// `MhClientMessage{ConnectRequest{join_token, media_token}}` envelope and
+/// frame it (4-byte BE length + encoded payload).
 ///
 /// MH's wire format on the first message of the bidi stream is a typed
 /// protobuf envelope, mirroring MC's `ClientMessage{JoinRequest{...}}`.
 /// For negative tests that need to send malformed bytes (e.g., the oversized
 /// payload tests below), the bytes are wrapped in the same envelope and the
 /// validator/decoder observes the failure mode that's intended.
-fn encode_jwt_frame(jwt: &str) -> Vec<u8> {
+fn encode_jwt_frame(jwt: &str, media_token: &str) -> Vec<u8> {
     use proto_gen::dark_tower::signaling::v1::{
         mh_client_message, MhClientMessage, MhConnectRequest,
     };
@@ -166,6 +179,7 @@ fn encode_jwt_frame(jwt: &str) -> Vec<u8> {
         message: Some(mh_client_message::Message::ConnectRequest(
             MhConnectRequest {
                 join_token: jwt.to_string(),
+                media_token: media_token.to_string(),
             },
         )),
         trace_parent: String::new(),
@@ -197,6 +211,7 @@ fn encode_jwt_frame(jwt: &str) -> Vec<u8> {
 async fn send_jwt_on_bi_stream(
     conn: &wtransport::Connection,
     jwt: &str,
+    media_token: &str,
 ) -> (
     wtransport::stream::SendStream,
     wtransport::stream::RecvStream,
@@ -207,7 +222,7 @@ async fn send_jwt_on_bi_stream(
         .expect("open bi stream")
         .await
         .expect("bi stream ready");
-    let frame = encode_jwt_frame(jwt);
+    let frame = encode_jwt_frame(jwt, media_token);
     send.write_all(&frame).await.expect("write JWT frame");
     (send, recv)
 }
@@ -243,7 +258,8 @@ fn jwt_preview(jwt: &[u8]) -> String {
 /// (session held open for media frames).
 async fn assert_mh_rejects(mh_url: &str, jwt: &str) {
     let conn = connect_wt(mh_url).await;
-    let (_send, _recv) = send_jwt_on_bi_stream(&conn, jwt).await;
+    // The JWT is rejected before MH looks at the media token
+    let (_send, _recv) = send_jwt_on_bi_stream(&conn, jwt, "").await;
 
     let close_outcome = tokio::time::timeout(Duration::from_secs(5), conn.closed()).await;
 
@@ -358,10 +374,18 @@ async fn mh_notification_counter(prom: &PrometheusClient, event_type: &str) -> f
 /// MH-assignment data missing for the meeting.
 #[tokio::test]
 async fn test_mh_url_present_in_join_response() {
+    requires_services!(Ac, Gc, Mc, Mh);
+    let _report = TestRecorder::start(file!(), "test_mh_url_present_in_join_response");
+    let ns = TestNamespace::new("mh_url_present_in_join_response");
     let cluster = cluster().await;
     let (user_token, display_name) = shared_user(cluster).await.clone();
 
-    let gc_join = gc_create_and_join(cluster, &user_token, "MH URL Present Test").await;
+    let gc_join = gc_create_and_join(
+        cluster,
+        &user_token,
+        &ns.meeting_name("MH URL Present Test"),
+    )
+    .await;
 
     let mc_url = gc_join
         .mc_assignment
@@ -435,6 +459,7 @@ async fn mc_join(
             capabilities: None,
             correlation_id: String::new(),
             binding_token: String::new(),
+            join_id: String::new(),
         })),
         trace_parent: String::new(),
         trace_state: String::new(),
@@ -478,14 +503,15 @@ async fn mc_join(
 }
 
 /// Drive the full GC→MC join so MC fires `RegisterMeeting` to all assigned MHs,
-/// then return the meeting JWT and the first MH WebTransport URL. Used by the
-/// MH-side scenarios that need a "registered meeting" precondition.
+/// then return the meeting JWT, the MC-minted media token, and the first MH
+/// WebTransport URL. Used by the MH-side scenarios that need a "registered
+/// meeting" precondition.
 async fn join_with_registered_mh(
     cluster: &ClusterConnection,
     user_token: &str,
     display_name: &str,
     meeting_name: &str,
-) -> (String, String) {
+) -> (String, String, String) {
     let gc_join = gc_create_and_join(cluster, user_token, meeting_name).await;
     let mc_url = gc_join
         .mc_assignment
@@ -508,7 +534,7 @@ async fn join_with_registered_mh(
         .filter(|u| !u.is_empty())
         .expect("MC JoinResponse must include at least one non-empty MH URL");
 
-    (gc_join.token, mh_url)
+    (gc_join.token, join_response.media_token, mh_url)
 }
 
 /// Test: MH accepts a connection authenticated by a valid meeting JWT for a
@@ -527,20 +553,24 @@ async fn join_with_registered_mh(
 /// be in MH's provisional pool and time out before this test finishes).
 #[tokio::test]
 async fn test_mh_accepts_valid_meeting_jwt() {
+    requires_services!(Ac, Gc, Mc, Mh);
+    let _report = TestRecorder::start(file!(), "test_mh_accepts_valid_meeting_jwt");
+    let ns = TestNamespace::new("mh_accepts_valid_meeting_jwt");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
-    let (user_token, display_name) = register_test_user(&auth_client, "MH Valid JWT User").await;
+    let (user_token, display_name) =
+        register_test_user(&auth_client, ns.user("MH Valid JWT User")).await;
 
-    let (jwt, mh_url) = join_with_registered_mh(
+    let (jwt, media_token, mh_url) = join_with_registered_mh(
         cluster,
         &user_token,
         &display_name,
-        "MH Valid JWT Test Meeting",
+        &ns.meeting_name("MH Valid JWT Test Meeting"),
     )
     .await;
 
     let conn = connect_wt(&mh_url).await;
-    let (_send, _recv) = send_jwt_on_bi_stream(&conn, &jwt).await;
+    let (_send, _recv) = send_jwt_on_bi_stream(&conn, &jwt, &media_token).await;
 
     // Held-open invariant: `conn.closed()` resolves with the close reason
     // only after the WebTransport session terminates. If the session is
@@ -567,15 +597,19 @@ async fn test_mh_accepts_valid_meeting_jwt() {
 /// is not enforcing signature verification — security-critical.
 #[tokio::test]
 async fn test_mh_rejects_forged_jwt() {
+    requires_services!(Ac, Gc, Mc, Mh);
+    let _report = TestRecorder::start(file!(), "test_mh_rejects_forged_jwt");
+    let ns = TestNamespace::new("mh_rejects_forged_jwt");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
-    let (user_token, display_name) = register_test_user(&auth_client, "MH Forged JWT User").await;
+    let (user_token, display_name) =
+        register_test_user(&auth_client, ns.user("MH Forged JWT User")).await;
 
-    let (_jwt, mh_url) = join_with_registered_mh(
+    let (_jwt, _media_token, mh_url) = join_with_registered_mh(
         cluster,
         &user_token,
         &display_name,
-        "MH Forged JWT Test Meeting",
+        &ns.meeting_name("MH Forged JWT Test Meeting"),
     )
     .await;
 
@@ -599,16 +633,19 @@ async fn test_mh_rejects_forged_jwt() {
 /// be allocating per-byte memory before enforcing the size cap — DoS risk.
 #[tokio::test]
 async fn test_mh_rejects_oversized_jwt() {
+    requires_services!(Ac, Gc, Mc, Mh);
+    let _report = TestRecorder::start(file!(), "test_mh_rejects_oversized_jwt");
+    let ns = TestNamespace::new("mh_rejects_oversized_jwt");
     let cluster = cluster().await;
     let auth_client = AuthClient::new(&cluster.ac_base_url);
     let (user_token, display_name) =
-        register_test_user(&auth_client, "MH Oversized JWT User").await;
+        register_test_user(&auth_client, ns.user("MH Oversized JWT User")).await;
 
-    let (_jwt, mh_url) = join_with_registered_mh(
+    let (_jwt, _media_token, mh_url) = join_with_registered_mh(
         cluster,
         &user_token,
         &display_name,
-        "MH Oversized JWT Test Meeting",
+        &ns.meeting_name("MH Oversized JWT Test Meeting"),
     )
     .await;
 
@@ -637,12 +674,18 @@ async fn test_mh_rejects_oversized_jwt() {
 #[tokio::test]
 #[serial_test::serial(mh_notifications)]
 async fn test_mh_connect_increments_mc_notification_metric_connected() {
+    requires_services!(Ac, Gc, Mc, Mh);
+    let _report = TestRecorder::start(
+        file!(),
+        "test_mh_connect_increments_mc_notification_metric_connected",
+    );
+    let ns = TestNamespace::new("mh_connect_increments_mc_notification_metric_connected");
     let cluster = cluster().await;
     let prom = PrometheusClient::new(&cluster.prometheus_base_url);
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
     let (user_token, display_name) =
-        register_test_user(&auth_client, "MH MC-Metric Connect User").await;
+        register_test_user(&auth_client, ns.user("MH MC-Metric Connect User")).await;
 
     // Cross-test stabilization (mirrors test 5's pattern): wait for any in-flight
     // connect signal from a sibling test under the same `#[serial(mh_notifications)]`
@@ -651,16 +694,16 @@ async fn test_mh_connect_increments_mc_notification_metric_connected() {
     wait_for_notification_counter_stable(&prom, "connected").await;
     let baseline = mh_notification_counter(&prom, "connected").await;
 
-    let (jwt, mh_url) = join_with_registered_mh(
+    let (jwt, media_token, mh_url) = join_with_registered_mh(
         cluster,
         &user_token,
         &display_name,
-        "MH Connect Metric Test Meeting",
+        &ns.meeting_name("MH Connect Metric Test Meeting"),
     )
     .await;
 
     let conn = connect_wt(&mh_url).await;
-    let (_send, _recv) = send_jwt_on_bi_stream(&conn, &jwt).await;
+    let (_send, _recv) = send_jwt_on_bi_stream(&conn, &jwt, &media_token).await;
 
     // The connect notification fires from MH best-effort fire-and-forget after
     // JWT validation. The chain is MH spawn-task → gRPC to MC → MC counter →
@@ -681,12 +724,18 @@ async fn test_mh_connect_increments_mc_notification_metric_connected() {
 #[tokio::test]
 #[serial_test::serial(mh_notifications)]
 async fn test_mh_disconnect_increments_mc_notification_metric_disconnected() {
+    requires_services!(Ac, Gc, Mc, Mh);
+    let _report = TestRecorder::start(
+        file!(),
+        "test_mh_disconnect_increments_mc_notification_metric_disconnected",
+    );
+    let ns = TestNamespace::new("mh_disconnect_increments_mc_notification_metric_disconnected");
     let cluster = cluster().await;
     let prom = PrometheusClient::new(&cluster.prometheus_base_url);
 
     let auth_client = AuthClient::new(&cluster.ac_base_url);
     let (user_token, display_name) =
-        register_test_user(&auth_client, "MH MC-Metric Disconnect User").await;
+        register_test_user(&auth_client, ns.user("MH MC-Metric Disconnect User")).await;
 
     // Cross-test stabilization: the predecessor test under the same
     // `#[serial(mh_notifications)]` group may have produced a disconnect
@@ -696,16 +745,16 @@ async fn test_mh_disconnect_increments_mc_notification_metric_disconnected() {
     wait_for_notification_counter_stable(&prom, "disconnected").await;
     let baseline = mh_notification_counter(&prom, "disconnected").await;
 
-    let (jwt, mh_url) = join_with_registered_mh(
+    let (jwt, media_token, mh_url) = join_with_registered_mh(
         cluster,
         &user_token,
         &display_name,
-        "MH Disconnect Metric Test Meeting",
+        &ns.meeting_name("MH Disconnect Metric Test Meeting"),
     )
     .await;
 
     let conn = connect_wt(&mh_url).await;
-    let (mut send, _recv) = send_jwt_on_bi_stream(&conn, &jwt).await;
+    let (mut send, _recv) = send_jwt_on_bi_stream(&conn, &jwt, &media_token).await;
 
     // Clean close: finish the send stream (produces Ok(None) on the server's
     // recv) and drop the connection. Matches the `ClientClosed` branch in
@@ -746,5 +795,10 @@ async fn test_mh_disconnect_increments_mc_notification_metric_disconnected() {
 #[tokio::test]
 #[ignore = "covered at component tier — see crates/mh-service/tests/webtransport_integration.rs::provisional_connection_kicked_after_register_meeting_timeout"]
 async fn test_mh_disconnects_unregistered_meeting_after_timeout() {
+    requires_services!(Ac, Gc, Mc, Mh);
+    let _report = TestRecorder::start(
+        file!(),
+        "test_mh_disconnects_unregistered_meeting_after_timeout",
+    );
     // Intentionally unimplemented. See doc-comment above.
 }
@@ -8,6 +8,8 @@
 use env_tests::cluster::ClusterConnection;
 use env_tests::eventual::{assert_eventually, ConsistencyCategory};
 use env_tests::fixtures::PrometheusClient;
+use env_tests::report::TestRecorder;
+use env_tests::requires_services;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Helper to create a cluster connection for tests.
@@ -29,6 +31,8 @@ const EXPECTED_SERVICES: &[(&str, &str)] = &[
 
 #[tokio::test]
 async fn test_all_services_scraped_by_prometheus() {
+    requires_services!(Ac, Gc, Mc, Mh);
+    let _report = TestRecorder::start(file!(), "test_all_services_scraped_by_prometheus");
     let cluster = cluster().await;
     let prometheus_client = PrometheusClient::new(&cluster.prometheus_base_url);
 
@@ -110,6 +114,8 @@ async fn test_all_services_scraped_by_prometheus() {
 
 #[tokio::test]
 async fn test_all_services_have_logs_in_loki() {
+    requires_services!(Ac, Gc, Mc, Mh);
+    let _report = TestRecorder::start(file!(), "test_all_services_have_logs_in_loki");
     let cluster = cluster().await;
 
     // Loki must be available for this test.
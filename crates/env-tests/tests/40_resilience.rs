@@ -5,6 +5,8 @@
 #![cfg(feature = "resilience")]
 
 use env_tests::canary::{CanaryConfig, CanaryPod};
+use env_tests::report::TestRecorder;
+use env_tests::requires_services;
 use serial_test::serial;
 
 // ============================================================================
@@ -24,6 +26,8 @@ use serial_test::serial;
 #[tokio::test]
 #[serial]
 async fn test_same_namespace_connectivity() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_same_namespace_connectivity");
     // Deploy a canary pod with labels that match the NetworkPolicy ingress rules
     // The AC NetworkPolicy allows traffic from pods with app=gc-service
     let config = CanaryConfig {
@@ -68,6 +72,8 @@ async fn test_same_namespace_connectivity() {
 #[tokio::test]
 #[serial]
 async fn test_network_policy_blocks_cross_namespace() {
+    requires_services!(Ac);
+    let _report = TestRecorder::start(file!(), "test_network_policy_blocks_cross_namespace");
     // Use a unique test namespace to ensure isolation
     let test_namespace = "canary-test-isolated";
 
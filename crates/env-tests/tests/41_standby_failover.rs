@@ -0,0 +1,54 @@
+//! P2 Resilience Tests: Warm Standby MC Failover
+//!
+//! In the Kind cluster `mc-1` runs as the warm standby of `mc-0`
+//! (`MC_STANDBY_FOR=mc-0`), so every meeting is placed on `mc-0`. Killing
+//! the `mc-0` pod breaks its heartbeat stream; GC finds its endpoint gone
+//! and promotes `mc-1`, which takes the meetings over.
+//!
+//! # Prerequisites
+//!
+//! - Kind cluster with AC, GC, and both MC instances deployed
+//! - Port-forwards active: AC (8082), GC (8080)
+//! - kubectl configured for the cluster, allowed to delete pods and
+//!   restart deployments in `dark-tower`
+
+#![cfg(feature = "resilience")]
+
+use env_tests::report::TestRecorder;
+use env_tests::requires_services;
+use env_tests::scenario::Scenario;
+use serial_test::serial;
+
+/// Test that killing the primary MC moves its meeting to the warm standby
+/// within `ConsistencyCategory::ReplicaSync` (10s).
+#[tokio::test]
+#[serial]
+async fn test_primary_mc_failure_promotes_standby() {
+    requires_services!(Gc, Mc);
+    let _report = TestRecorder::start(file!(), "test_primary_mc_failure_promotes_standby");
+    let outcome = Scenario::new()
+        .create_meeting()
+        .join_as_host()
+        .join_as_guest(1)
+        // The standby takes no meetings, so the primary should get this one
+        .expect_mc("mc-0")
+        .kill_pod("mc-0")
+        .expect_reconnect()
+        .expect_mc("mc-1")
+        // Re-pair mc-1 as the standby of the restarted mc-0
+        .restore_deployment("mc-1")
+        .run()
+        .await
+        .unwrap_or_else(|e| {
+            panic!(
+                "{}. Check GC logs for 'Promoted warm standby' and that mc-1 \
+                 registered with MC_STANDBY_FOR=mc-0.",
+                e
+            )
+        });
+
+    eprintln!(
+        "Meeting failed over to mc-1 after {:?}",
+        outcome.reconnect_time
+    );
+}
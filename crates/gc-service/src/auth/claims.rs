@@ -3,7 +3,7 @@
 //! Contains the claims extracted from validated JWTs. The `sub` field is
 //! redacted in Debug output to prevent exposure in logs.
 
-use common::jwt::HasIat;
+use common::jwt::{Confirmation, HasIat};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -28,6 +28,11 @@ pub struct Claims {
     /// Optional service type for service-to-service tokens.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub service_type: Option<String>,
+
+    /// Key the token is bound to if it is DPoP-bound (RFC 9449); such a
+    /// token is only accepted with a DPoP proof signed by that key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cnf: Option<Confirmation>,
 }
 
 /// Custom Debug implementation that redacts the `sub` field.
@@ -42,6 +47,7 @@ impl fmt::Debug for Claims {
             .field("iat", &self.iat)
             .field("scope", &self.scope)
             .field("service_type", &self.service_type)
+            .field("cnf", &self.cnf)
             .finish()
     }
 }
@@ -80,6 +86,7 @@ mod tests {
             iat: 1234567800,
             scope: "read write".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let debug_str = format!("{:?}", claims);
@@ -102,6 +109,7 @@ mod tests {
             iat: 1234567800,
             scope: "read write admin".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         assert!(claims.has_scope("read"));
@@ -119,6 +127,7 @@ mod tests {
             iat: 1234567800,
             scope: "read write admin".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let scopes = claims.scopes();
@@ -133,6 +142,7 @@ mod tests {
             iat: 1234567800,
             scope: "".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         assert!(!claims.has_scope("read"));
@@ -147,6 +157,7 @@ mod tests {
             iat: 1234567800,
             scope: "read write".to_string(),
             service_type: Some("global-controller".to_string()),
+            cnf: None,
         };
 
         let json = serde_json::to_string(&claims).unwrap();
@@ -167,6 +178,7 @@ mod tests {
             iat: 1234567800,
             scope: "read".to_string(),
             service_type: None,
+            cnf: None,
         };
 
         let json = serde_json::to_string(&claims).unwrap();
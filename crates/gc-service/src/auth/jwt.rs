@@ -2,10 +2,12 @@
 //!
 //! Thin wrapper around `common::jwt::JwtValidator` that provides GC-specific
 //! `validate()` and `validate_user()` methods with automatic `JwtError` -> `GcError`
-//! error mapping.
+//! error mapping, plus `verify_dpop()` for DPoP-bound service tokens (RFC 9449).
 
 use crate::auth::claims::Claims;
 use crate::errors::GcError;
+use chrono::Utc;
+use common::dpop::{self, DpopReplayCache, DpopRequest};
 use common::jwt::{JwksClient, UserClaims};
 use std::sync::Arc;
 use tracing::instrument;
@@ -13,12 +15,23 @@ use tracing::instrument;
 /// Re-export the common JwtValidator for direct generic usage.
 pub use common::jwt::JwtValidator as CommonJwtValidator;
 
+/// `Authorization` scheme a service token was presented with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenScheme {
+    /// `Authorization: Bearer <token>`
+    Bearer,
+    /// `Authorization: DPoP <token>`, with a proof in the `DPoP` header
+    Dpop,
+}
+
 /// JWT validator using JWKS from Auth Controller.
 ///
 /// Wraps `common::jwt::JwtValidator` and provides GC-specific typed methods
 /// that return `GcError`.
 pub struct JwtValidator {
     inner: CommonJwtValidator,
+    /// DPoP proof `jti`s already presented to this GC instance
+    dpop_replay: DpopReplayCache,
 }
 
 impl JwtValidator {
@@ -31,6 +44,7 @@ impl JwtValidator {
     pub fn new(jwks_client: Arc<JwksClient>, clock_skew_seconds: i64) -> Self {
         Self {
             inner: CommonJwtValidator::new(jwks_client, clock_skew_seconds),
+            dpop_replay: DpopReplayCache::new(),
         }
     }
 
@@ -75,13 +89,64 @@ impl JwtValidator {
     pub async fn validate_user(&self, token: &str) -> Result<UserClaims, GcError> {
         Ok(self.inner.validate::<UserClaims>(token).await?)
     }
+
+    /// Check that a validated service token was presented the way its
+    /// binding requires (RFC 9449 Section 7).
+    ///
+    /// - Bearer tokens (no `cnf`) must use the `Bearer` scheme; any proof is
+    ///   ignored
+    /// - DPoP-bound tokens must use the `DPoP` scheme with a proof that
+    ///   names this request (`request.access_token` is the token itself),
+    ///   is signed by the `cnf.jkt` key, and was not used before
+    ///
+    /// # Errors
+    ///
+    /// Returns `GcError::InvalidToken` if the scheme does not match the
+    /// binding or the proof is missing or invalid.
+    #[instrument(skip_all)]
+    pub fn verify_dpop(
+        &self,
+        claims: &Claims,
+        scheme: TokenScheme,
+        proof: Option<&str>,
+        request: &DpopRequest<'_>,
+    ) -> Result<(), GcError> {
+        let invalid_proof = || GcError::InvalidToken("The DPoP proof is invalid".to_string());
+
+        let cnf = match (&claims.cnf, scheme) {
+            (None, TokenScheme::Bearer) => return Ok(()),
+            (Some(cnf), TokenScheme::Dpop) => cnf,
+            (None, TokenScheme::Dpop) | (Some(_), TokenScheme::Bearer) => {
+                // A stolen bound token replayed as a bearer token lands here
+                tracing::debug!(
+                    target: "gc.auth.dpop",
+                    bound = claims.cnf.is_some(),
+                    "Token presented with the wrong authorization scheme"
+                );
+                return Err(invalid_proof());
+            }
+        };
+
+        let proof = proof.ok_or_else(invalid_proof)?;
+        let now = Utc::now().timestamp();
+        dpop::verify_proof(proof, request, now)
+            .and_then(|proof| {
+                dpop::verify_binding(&proof, &cnf.jkt)?;
+                self.dpop_replay.check(&proof, now)
+            })
+            .map_err(|e| {
+                tracing::debug!(target: "gc.auth.dpop", error = ?e, "DPoP proof rejected");
+                invalid_proof()
+            })
+    }
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
-    use common::jwt::JwtError;
+    use common::dpop::DpopKey;
+    use common::jwt::{Confirmation, JwtError};
 
     #[test]
     fn test_jwt_error_to_gc_error_invalid_token() {
@@ -168,4 +233,122 @@ mod tests {
         );
         let _validator = JwtValidator::new(jwks_client, 300);
     }
+
+    // =========================================================================
+    // DPoP binding (RFC 9449)
+    // =========================================================================
+
+    const ACCESS_TOKEN: &str = "header.claims.signature";
+    const HTU: &str = "https://gc.example.com/api/v1/meetings";
+
+    fn validator() -> JwtValidator {
+        let jwks_client = Arc::new(
+            JwksClient::new("http://localhost:8082/.well-known/jwks.json".to_string())
+                .expect("Failed to create JWKS client"),
+        );
+        JwtValidator::new(jwks_client, 300)
+    }
+
+    fn claims(cnf: Option<&DpopKey>) -> Claims {
+        Claims {
+            sub: "client".to_string(),
+            exp: 1234567890,
+            iat: 1234567800,
+            scope: "meeting:create".to_string(),
+            service_type: None,
+            cnf: cnf.map(|key| Confirmation {
+                jkt: key.thumbprint(),
+            }),
+        }
+    }
+
+    fn request() -> DpopRequest<'static> {
+        DpopRequest {
+            method: "POST",
+            host: "gc.example.com",
+            path: "/api/v1/meetings",
+            access_token: Some(ACCESS_TOKEN),
+        }
+    }
+
+    fn proof(key: &DpopKey) -> String {
+        key.proof("POST", HTU, Utc::now().timestamp(), Some(ACCESS_TOKEN))
+    }
+
+    #[test]
+    fn test_verify_dpop_accepts_bearer_token_as_bearer() {
+        let validator = validator();
+        validator
+            .verify_dpop(&claims(None), TokenScheme::Bearer, None, &request())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_verify_dpop_accepts_bound_token_with_proof() {
+        let validator = validator();
+        let key = DpopKey::generate();
+        let proof = proof(&key);
+
+        validator
+            .verify_dpop(
+                &claims(Some(&key)),
+                TokenScheme::Dpop,
+                Some(&proof),
+                &request(),
+            )
+            .unwrap();
+
+        // Each proof is single-use
+        let result = validator.verify_dpop(
+            &claims(Some(&key)),
+            TokenScheme::Dpop,
+            Some(&proof),
+            &request(),
+        );
+        assert!(matches!(result, Err(GcError::InvalidToken(_))));
+    }
+
+    #[test]
+    fn test_verify_dpop_rejects_bound_token_as_bearer() {
+        // A stolen bound token replayed without the key
+        let validator = validator();
+        let key = DpopKey::generate();
+        let result =
+            validator.verify_dpop(&claims(Some(&key)), TokenScheme::Bearer, None, &request());
+        assert!(matches!(result, Err(GcError::InvalidToken(_))));
+    }
+
+    #[test]
+    fn test_verify_dpop_rejects_proof_from_other_key() {
+        let validator = validator();
+        let key = DpopKey::generate();
+        let attacker = DpopKey::generate();
+        let result = validator.verify_dpop(
+            &claims(Some(&key)),
+            TokenScheme::Dpop,
+            Some(&proof(&attacker)),
+            &request(),
+        );
+        assert!(matches!(result, Err(GcError::InvalidToken(_))));
+    }
+
+    #[test]
+    fn test_verify_dpop_rejects_scheme_binding_mismatch() {
+        let validator = validator();
+        let key = DpopKey::generate();
+
+        // Bound token without a proof
+        let result =
+            validator.verify_dpop(&claims(Some(&key)), TokenScheme::Dpop, None, &request());
+        assert!(matches!(result, Err(GcError::InvalidToken(_))));
+
+        // Bearer token under the DPoP scheme
+        let result = validator.verify_dpop(
+            &claims(None),
+            TokenScheme::Dpop,
+            Some(&proof(&key)),
+            &request(),
+        );
+        assert!(matches!(result, Err(GcError::InvalidToken(_))));
+    }
 }
@@ -5,7 +5,7 @@
 //! # Components
 //!
 //! - `jwks` - JWKS client re-exported from common
-//! - `jwt` - JWT validation wrapper for GC-specific error mapping
+//! - `jwt` - JWT validation wrapper for GC-specific error mapping and DPoP checks
 //! - `claims` - JWT claims structure for validated tokens
 
 pub mod claims;
@@ -14,4 +14,4 @@ pub mod jwt;
 
 pub use claims::Claims;
 pub use jwks::JwksClient;
-pub use jwt::JwtValidator;
+pub use jwt::{JwtValidator, TokenScheme};
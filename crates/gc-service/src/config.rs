@@ -3,7 +3,10 @@
 //! Configuration is loaded from environment variables. All sensitive
 //! fields are redacted in Debug output.
 
+use crate::repositories::StorageBackend;
+use crate::services::fleet_versions::ReleaseVersion;
 use common::jwt::{DEFAULT_CLOCK_SKEW, MAX_CLOCK_SKEW};
+use common::listen::UnixSocketConfig;
 use common::secret::SecretString;
 use std::collections::HashMap;
 use std::env;
@@ -19,6 +22,15 @@ pub const DEFAULT_GRPC_BIND_ADDRESS: &str = "0.0.0.0:50051";
 /// Default MC staleness threshold in seconds.
 pub const DEFAULT_MC_STALENESS_THRESHOLD_SECONDS: u64 = 30;
 
+/// Default recording retention in days.
+pub const DEFAULT_RECORDING_RETENTION_DAYS: u32 = 90;
+
+/// Maximum recording retention in days (10 years).
+pub const MAX_RECORDING_RETENTION_DAYS: u32 = 3650;
+
+/// Default minimum client signaling protocol version advertised by preflight.
+pub const DEFAULT_MIN_CLIENT_PROTOCOL_VERSION: u32 = 1;
+
 /// Default GC instance ID prefix.
 pub const DEFAULT_GC_ID_PREFIX: &str = "gc";
 
@@ -31,9 +43,13 @@ pub struct Config {
     /// PostgreSQL connection URL.
     pub database_url: String,
 
-    /// Server bind address (default: "0.0.0.0:8080").
+    /// Server bind addresses, comma-separated (default: "0.0.0.0:8080").
     pub bind_address: String,
 
+    /// Unix socket that also serves `/health`, `/ready`, and `/metrics`
+    /// (`GC_HEALTH_SOCKET_PATH`, `GC_HEALTH_SOCKET_MODE`).
+    pub health_socket: Option<UnixSocketConfig>,
+
     /// Deployment region identifier (e.g., "us-east-1").
     pub region: String,
 
@@ -49,7 +65,7 @@ pub struct Config {
     /// Rate limit in requests per minute per client.
     pub rate_limit_rpm: u32,
 
-    /// gRPC server bind address (default: "0.0.0.0:50051").
+    /// gRPC server bind addresses, comma-separated (default: "0.0.0.0:50051").
     pub grpc_bind_address: String,
 
     /// MC staleness threshold in seconds (default: 30).
@@ -65,6 +81,25 @@ pub struct Config {
 
     /// OAuth client secret for GC to authenticate with AC (SecretString prevents logging).
     pub gc_client_secret: SecretString,
+
+    /// Days a meeting recording is kept before the retention task removes it (default: 90).
+    pub recording_retention_days: u32,
+
+    /// Oldest signaling protocol version clients must speak, reported by
+    /// meeting preflight (default: 1).
+    pub min_client_protocol_version: u32,
+
+    /// Oldest MC/MH release allowed to register, from `GC_MIN_FLEET_VERSION`
+    /// (default: none, every version is accepted).
+    pub min_fleet_version: Option<ReleaseVersion>,
+
+    /// TURN relay URLs handed to clients by meeting preflight, comma-separated
+    /// in `GC_TURN_SERVERS` (default: none, relay unavailable).
+    pub turn_servers: Vec<String>,
+
+    /// Database engine behind `DATABASE_URL`, from `GC_STORAGE_BACKEND`
+    /// (default: postgres).
+    pub storage_backend: StorageBackend,
 }
 
 /// Custom Debug implementation that redacts sensitive fields.
@@ -73,6 +108,7 @@ impl fmt::Debug for Config {
         f.debug_struct("Config")
             .field("database_url", &"[REDACTED]")
             .field("bind_address", &self.bind_address)
+            .field("health_socket", &self.health_socket)
             .field("region", &self.region)
             .field("ac_jwks_url", &self.ac_jwks_url)
             .field("ac_internal_url", &self.ac_internal_url)
@@ -86,6 +122,14 @@ impl fmt::Debug for Config {
             .field("gc_id", &self.gc_id)
             .field("gc_client_id", &self.gc_client_id)
             .field("gc_client_secret", &"[REDACTED]")
+            .field("recording_retention_days", &self.recording_retention_days)
+            .field(
+                "min_client_protocol_version",
+                &self.min_client_protocol_version,
+            )
+            .field("min_fleet_version", &self.min_fleet_version)
+            .field("turn_servers", &self.turn_servers)
+            .field("storage_backend", &self.storage_backend)
             .finish()
     }
 }
@@ -103,6 +147,21 @@ pub enum ConfigError {
 
     #[error("Invalid MC staleness threshold configuration: {0}")]
     InvalidMcStalenessThreshold(String),
+
+    #[error("Invalid recording retention configuration: {0}")]
+    InvalidRecordingRetention(String),
+
+    #[error("Invalid client protocol version configuration: {0}")]
+    InvalidClientProtocolVersion(String),
+
+    #[error("Invalid minimum fleet version configuration: {0}")]
+    InvalidMinFleetVersion(String),
+
+    #[error("Invalid health socket configuration: {0}")]
+    InvalidHealthSocket(String),
+
+    #[error("Invalid storage backend configuration: {0}")]
+    InvalidStorageBackend(String),
 }
 
 impl Config {
@@ -235,9 +294,91 @@ impl Config {
             .ok_or_else(|| ConfigError::MissingEnvVar("GC_CLIENT_SECRET".to_string()))?
             .clone();
 
+        // Parse recording retention with validation
+        let recording_retention_days =
+            if let Some(value_str) = vars.get("GC_RECORDING_RETENTION_DAYS") {
+                let value: u32 = value_str.parse().map_err(|e| {
+                    ConfigError::InvalidRecordingRetention(format!(
+                    "GC_RECORDING_RETENTION_DAYS must be a valid positive integer, got '{}': {}",
+                    value_str, e
+                ))
+                })?;
+
+                if value == 0 || value > MAX_RECORDING_RETENTION_DAYS {
+                    return Err(ConfigError::InvalidRecordingRetention(format!(
+                        "GC_RECORDING_RETENTION_DAYS must be between 1 and {}",
+                        MAX_RECORDING_RETENTION_DAYS
+                    )));
+                }
+
+                value
+            } else {
+                DEFAULT_RECORDING_RETENTION_DAYS
+            };
+
+        // Parse minimum client protocol version with validation
+        let min_client_protocol_version = if let Some(value_str) =
+            vars.get("GC_MIN_CLIENT_PROTOCOL_VERSION")
+        {
+            let value: u32 = value_str.parse().map_err(|e| {
+                ConfigError::InvalidClientProtocolVersion(format!(
+                    "GC_MIN_CLIENT_PROTOCOL_VERSION must be a valid positive integer, got '{}': {}",
+                    value_str, e
+                ))
+            })?;
+
+            if value == 0 {
+                return Err(ConfigError::InvalidClientProtocolVersion(
+                    "GC_MIN_CLIENT_PROTOCOL_VERSION must be greater than 0".to_string(),
+                ));
+            }
+
+            value
+        } else {
+            DEFAULT_MIN_CLIENT_PROTOCOL_VERSION
+        };
+
+        let min_fleet_version = match vars.get("GC_MIN_FLEET_VERSION") {
+            Some(value) if !value.trim().is_empty() => {
+                Some(ReleaseVersion::parse(value).ok_or_else(|| {
+                    ConfigError::InvalidMinFleetVersion(format!(
+                        "GC_MIN_FLEET_VERSION must be a major.minor.patch version, got '{}'",
+                        value
+                    ))
+                })?)
+            }
+            _ => None,
+        };
+
+        let turn_servers = vars
+            .get("GC_TURN_SERVERS")
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let health_socket = UnixSocketConfig::from_vars(vars, "GC_HEALTH")
+            .map_err(ConfigError::InvalidHealthSocket)?;
+
+        let storage_backend = match vars.get("GC_STORAGE_BACKEND") {
+            Some(value) => StorageBackend::parse(value).ok_or_else(|| {
+                ConfigError::InvalidStorageBackend(format!(
+                    "GC_STORAGE_BACKEND must be postgres, cockroachdb, or yugabytedb, got '{}'",
+                    value
+                ))
+            })?,
+            None => StorageBackend::default(),
+        };
+
         Ok(Config {
             database_url,
             bind_address,
+            health_socket,
             region,
             ac_jwks_url,
             ac_internal_url,
@@ -248,6 +389,11 @@ impl Config {
             gc_id,
             gc_client_id,
             gc_client_secret: SecretString::from(gc_client_secret),
+            recording_retention_days,
+            min_client_protocol_version,
+            min_fleet_version,
+            turn_servers,
+            storage_backend,
         })
     }
 }
@@ -293,6 +439,10 @@ mod tests {
             config.mc_staleness_threshold_seconds,
             DEFAULT_MC_STALENESS_THRESHOLD_SECONDS
         );
+        assert_eq!(
+            config.recording_retention_days,
+            DEFAULT_RECORDING_RETENTION_DAYS
+        );
         // GC ID should be auto-generated
         assert!(config.gc_id.starts_with("gc-"));
         // OAuth client credentials should be loaded
@@ -491,6 +641,124 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_recording_retention_bounds() {
+        for (value, ok) in [
+            ("30", true),
+            ("3650", true),
+            ("0", false),
+            ("3651", false),
+            ("forever", false),
+        ] {
+            let mut vars = base_vars();
+            vars.insert("GC_RECORDING_RETENTION_DAYS".to_string(), value.to_string());
+
+            let result = Config::from_vars(&vars);
+            if ok {
+                assert_eq!(
+                    result.unwrap().recording_retention_days,
+                    value.parse::<u32>().unwrap()
+                );
+            } else {
+                assert!(
+                    matches!(result, Err(ConfigError::InvalidRecordingRetention(_))),
+                    "{value} should be rejected"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_min_client_protocol_version() {
+        let config = Config::from_vars(&base_vars()).expect("Config should load successfully");
+        assert_eq!(
+            config.min_client_protocol_version,
+            DEFAULT_MIN_CLIENT_PROTOCOL_VERSION
+        );
+
+        for (value, ok) in [("2", true), ("0", false), ("two", false)] {
+            let mut vars = base_vars();
+            vars.insert(
+                "GC_MIN_CLIENT_PROTOCOL_VERSION".to_string(),
+                value.to_string(),
+            );
+
+            let result = Config::from_vars(&vars);
+            if ok {
+                assert_eq!(result.unwrap().min_client_protocol_version, 2);
+            } else {
+                assert!(
+                    matches!(result, Err(ConfigError::InvalidClientProtocolVersion(_))),
+                    "{value} should be rejected"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_min_fleet_version() {
+        let config = Config::from_vars(&base_vars()).expect("Config should load successfully");
+        assert_eq!(config.min_fleet_version, None);
+
+        for (value, expected) in [
+            ("0.2.0", Some("0.2.0")),
+            ("", None),
+            ("0.2", None),
+            ("latest", None),
+        ] {
+            let mut vars = base_vars();
+            vars.insert("GC_MIN_FLEET_VERSION".to_string(), value.to_string());
+
+            let result = Config::from_vars(&vars);
+            match expected {
+                Some(version) => assert_eq!(
+                    result.unwrap().min_fleet_version.map(|v| v.to_string()),
+                    Some(version.to_string())
+                ),
+                None if value.is_empty() => assert_eq!(result.unwrap().min_fleet_version, None),
+                None => assert!(
+                    matches!(result, Err(ConfigError::InvalidMinFleetVersion(_))),
+                    "{value} should be rejected"
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test_turn_servers() {
+        let config = Config::from_vars(&base_vars()).expect("Config should load successfully");
+        assert!(config.turn_servers.is_empty());
+
+        let mut vars = base_vars();
+        vars.insert(
+            "GC_TURN_SERVERS".to_string(),
+            "turn:relay-a.example.com:3478, turns:relay-b.example.com:5349,".to_string(),
+        );
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(
+            config.turn_servers,
+            vec![
+                "turn:relay-a.example.com:3478".to_string(),
+                "turns:relay-b.example.com:5349".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_storage_backend() {
+        let config = Config::from_vars(&base_vars()).expect("Config should load successfully");
+        assert_eq!(config.storage_backend, StorageBackend::Postgres);
+
+        let mut vars = base_vars();
+        vars.insert("GC_STORAGE_BACKEND".to_string(), "cockroachdb".to_string());
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(config.storage_backend, StorageBackend::CockroachDb);
+
+        vars.insert("GC_STORAGE_BACKEND".to_string(), "sqlite".to_string());
+        let result = Config::from_vars(&vars);
+        assert!(matches!(result, Err(ConfigError::InvalidStorageBackend(_))));
+    }
+
     #[test]
     fn test_debug_redacts_database_url() {
         let vars = base_vars();
@@ -517,4 +785,23 @@ mod tests {
         let redacted_count = debug_output.matches("[REDACTED]").count();
         assert_eq!(redacted_count, 2);
     }
+
+    #[test]
+    fn test_health_socket_config() {
+        let config = Config::from_vars(&base_vars()).expect("Config should load successfully");
+        assert!(config.health_socket.is_none());
+
+        let mut vars = base_vars();
+        vars.insert(
+            "GC_HEALTH_SOCKET_PATH".to_string(),
+            "/run/gc/health.sock".to_string(),
+        );
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        let socket = config.health_socket.expect("health socket should be set");
+        assert_eq!(socket.mode, common::listen::DEFAULT_UNIX_SOCKET_MODE);
+
+        vars.insert("GC_HEALTH_SOCKET_MODE".to_string(), "rw".to_string());
+        let result = Config::from_vars(&vars);
+        assert!(matches!(result, Err(ConfigError::InvalidHealthSocket(_))));
+    }
 }
@@ -2,15 +2,20 @@
 //!
 //! All errors map to appropriate HTTP status codes via the `IntoResponse` impl.
 //! Error messages returned to clients are intentionally generic to avoid
-//! leaking internal details. Actual errors are logged server-side.
+//! leaking internal details. Actual errors are logged server-side. Each body
+//! carries the request's `x-request-id` so a user report can be matched to
+//! server logs.
 
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
     Json,
 };
+use common::error::{ErrorCode, ERROR_CODE_METADATA};
+use common::request_id::RequestId;
 use serde::Serialize;
 use thiserror::Error;
+use tonic::Status;
 
 /// Global Controller error type.
 ///
@@ -69,6 +74,46 @@ impl GcError {
         }
     }
 
+    /// Returns the stable registry code included in error responses.
+    pub fn dt_code(&self) -> ErrorCode {
+        match self {
+            GcError::Database(_) => ErrorCode::GcDatabase,
+            GcError::InvalidToken(_) => ErrorCode::GcInvalidToken,
+            GcError::NotFound(_) => ErrorCode::GcNotFound,
+            GcError::Conflict(_) => ErrorCode::GcConflict,
+            GcError::RateLimitExceeded => ErrorCode::GcRateLimited,
+            GcError::Forbidden(_) => ErrorCode::GcForbidden,
+            GcError::BadRequest(_) => ErrorCode::GcBadRequest,
+            GcError::ServiceUnavailable(_) => ErrorCode::GcServiceUnavailable,
+            GcError::Internal(_) => ErrorCode::GcInternal,
+        }
+    }
+
+    /// Convert to a gRPC status with a client-safe message.
+    ///
+    /// The registry code travels as `dt-error-code` metadata. Callers log
+    /// internal details before converting.
+    pub fn to_grpc_status(&self) -> Status {
+        let mut status = match self {
+            GcError::Database(_) | GcError::Internal(_) => {
+                Status::internal("An internal error occurred")
+            }
+            GcError::InvalidToken(reason) => Status::unauthenticated(reason.clone()),
+            GcError::NotFound(resource) => Status::not_found(resource.clone()),
+            GcError::Conflict(reason) => Status::already_exists(reason.clone()),
+            GcError::RateLimitExceeded => Status::resource_exhausted("Too many requests"),
+            GcError::Forbidden(reason) => Status::permission_denied(reason.clone()),
+            GcError::BadRequest(reason) => Status::invalid_argument(reason.clone()),
+            GcError::ServiceUnavailable(_) => {
+                Status::unavailable("Service temporarily unavailable")
+            }
+        };
+        if let Ok(value) = self.dt_code().as_str().parse() {
+            status.metadata_mut().insert(ERROR_CODE_METADATA, value);
+        }
+        status
+    }
+
     /// Returns a bounded label string for the error variant (for metrics).
     ///
     /// Uses enum variant names, not error message content.
@@ -95,7 +140,10 @@ struct ErrorResponse {
 #[derive(Serialize)]
 struct ErrorDetail {
     code: String,
+    dt_code: ErrorCode,
     message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<String>,
 }
 
 impl IntoResponse for GcError {
@@ -145,7 +193,9 @@ impl IntoResponse for GcError {
         let error_response = ErrorResponse {
             error: ErrorDetail {
                 code: code.to_string(),
+                dt_code: self.dt_code(),
                 message,
+                request_id: RequestId::current().map(|id| id.to_string()),
             },
         };
 
@@ -336,6 +386,7 @@ mod tests {
 
         let body_json = read_body_json(response.into_body()).await;
         assert_eq!(body_json["error"]["code"], "NOT_FOUND");
+        assert_eq!(body_json["error"]["dt_code"], "DT-GC-3003");
         assert_eq!(body_json["error"]["message"], "Meeting not found");
     }
 
@@ -413,4 +464,36 @@ mod tests {
         assert_eq!(body_json["error"]["code"], "INTERNAL_ERROR");
         assert_eq!(body_json["error"]["message"], "An internal error occurred");
     }
+
+    #[tokio::test]
+    async fn test_into_response_includes_request_id() {
+        let request_id = RequestId::parse("req-abc").unwrap();
+        let response = request_id
+            .scope(async { GcError::NotFound("Meeting not found".to_string()).into_response() })
+            .await;
+
+        let body_json = read_body_json(response.into_body()).await;
+        assert_eq!(body_json["error"]["request_id"], "req-abc");
+
+        // Outside a request scope the field is omitted
+        let response = GcError::NotFound("Meeting not found".to_string()).into_response();
+        let body_json = read_body_json(response.into_body()).await;
+        assert!(body_json["error"].get("request_id").is_none());
+    }
+
+    #[test]
+    fn test_to_grpc_status_carries_dt_code() {
+        let status = GcError::NotFound("Meeting not found".to_string()).to_grpc_status();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        assert_eq!(status.message(), "Meeting not found");
+        assert_eq!(
+            status.metadata().get(ERROR_CODE_METADATA).unwrap(),
+            "DT-GC-3003"
+        );
+
+        // Internal details are not exposed
+        let status = GcError::Database("connection reset by 10.0.0.5".to_string()).to_grpc_status();
+        assert_eq!(status.code(), tonic::Code::Internal);
+        assert!(!status.message().contains("10.0.0.5"));
+    }
 }
@@ -0,0 +1,278 @@
+//! In-process event bus for Global Controller.
+//!
+//! Decouples HTTP/gRPC handlers from side effects. Meeting mutations produce
+//! a typed [`GcEvent`]; consumers subscribe independently and never block the
+//! request path.
+//!
+//! # Consumers
+//!
+//! - `tasks::event_metering` - usage metering (`gc_meeting_events_total`);
+//!   always running
+//! - `tasks::event_forwarder` - forwards to an external message queue via the
+//!   `publisher` submodule; only when `GC_EVENT_PUBLISHER` is set
+//!
+//! # Delivery Semantics
+//!
+//! The bus is a thin wrapper over `tokio::sync::broadcast`:
+//! - Publishing never blocks and never fails the caller. With no subscribers
+//!   the event is dropped (logged at debug).
+//! - Each subscriber has its own bounded queue. A slow subscriber that falls
+//!   more than `capacity` events behind observes `RecvError::Lagged` and skips
+//!   ahead; other subscribers are unaffected.
+//!
+//! No [`GcEvent`] is published directly by handlers. Every event is written
+//! to the `event_outbox` table in the same transaction as the mutation it
+//! describes (the meeting insert or update, or the MC assignment); the outbox
+//! relay task (`tasks::outbox_relay`) publishes them here, so a GC crash
+//! between commit and notify cannot lose them. The relay keeps an event
+//! pending until a publish reaches at least one subscriber; the always-on
+//! metering consumer makes that the steady state. Delivery from the outbox is
+//! at-least-once; subscribers must tolerate duplicates.
+
+pub mod publisher;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// Default per-subscriber queue depth.
+pub const DEFAULT_EVENT_BUS_CAPACITY: usize = 1024;
+
+/// Meeting lifecycle events published by GC.
+///
+/// Payloads carry identifiers only. Secrets (join token secret) and
+/// user-supplied free text (display names) are intentionally excluded so
+/// events are safe to forward to external sinks.
+///
+/// The serialized form (internally tagged by `type`) is the `event_outbox`
+/// payload format; renaming a variant or field is a breaking change for rows
+/// already queued.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GcEvent {
+    /// A meeting was created.
+    MeetingCreated {
+        meeting_id: Uuid,
+        org_id: Uuid,
+        created_by_user_id: Uuid,
+    },
+
+    /// A meeting was assigned to a (new) Meeting Controller.
+    ///
+    /// Not emitted when a join reuses an existing healthy assignment.
+    AssignmentChanged {
+        meeting_id: Uuid,
+        mc_id: String,
+        region: String,
+    },
+
+    /// Host-editable meeting settings were updated.
+    MeetingSettingsUpdated { meeting_id: Uuid, updated_by: Uuid },
+}
+
+impl GcEvent {
+    /// Bounded event name for logging and downstream routing.
+    pub fn name(&self) -> &'static str {
+        match self {
+            GcEvent::MeetingCreated { .. } => "meeting_created",
+            GcEvent::AssignmentChanged { .. } => "assignment_changed",
+            GcEvent::MeetingSettingsUpdated { .. } => "meeting_settings_updated",
+        }
+    }
+
+    /// Meeting the event refers to.
+    pub fn meeting_id(&self) -> Uuid {
+        match self {
+            GcEvent::MeetingCreated { meeting_id, .. }
+            | GcEvent::AssignmentChanged { meeting_id, .. }
+            | GcEvent::MeetingSettingsUpdated { meeting_id, .. } => *meeting_id,
+        }
+    }
+}
+
+/// Typed broadcast bus for [`GcEvent`]s.
+///
+/// Cheap to clone; all clones publish to the same set of subscribers.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<GcEvent>,
+}
+
+impl EventBus {
+    /// Create a bus with the given per-subscriber queue depth.
+    ///
+    /// A capacity of 0 is bumped to 1 (tokio rejects zero-capacity channels).
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self { sender }
+    }
+
+    /// Publish an event to all current subscribers.
+    ///
+    /// Returns the number of subscribers the event was delivered to.
+    /// Never fails: an event with no subscribers is simply dropped.
+    pub fn publish(&self, event: GcEvent) -> usize {
+        let name = event.name();
+        let meeting_id = event.meeting_id();
+        match self.sender.send(event) {
+            Ok(receivers) => {
+                tracing::debug!(
+                    target: "gc.events",
+                    event = name,
+                    meeting_id = %meeting_id,
+                    receivers = receivers,
+                    "Published event"
+                );
+                receivers
+            }
+            Err(_) => {
+                tracing::debug!(
+                    target: "gc.events",
+                    event = name,
+                    meeting_id = %meeting_id,
+                    "No subscribers for event, dropping"
+                );
+                0
+            }
+        }
+    }
+
+    /// Subscribe to events published after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<GcEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Number of active subscribers.
+    #[allow(dead_code)]
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_BUS_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+
+    fn created(meeting_id: Uuid) -> GcEvent {
+        GcEvent::MeetingCreated {
+            meeting_id,
+            org_id: Uuid::new_v4(),
+            created_by_user_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_publish_without_subscribers_is_noop() {
+        let bus = EventBus::default();
+        assert_eq!(bus.publish(created(Uuid::new_v4())), 0);
+    }
+
+    #[tokio::test]
+    async fn test_all_subscribers_receive_event() {
+        let bus = EventBus::default();
+        let mut rx1 = bus.subscribe();
+        let mut rx2 = bus.subscribe();
+        assert_eq!(bus.subscriber_count(), 2);
+
+        let event = created(Uuid::new_v4());
+        assert_eq!(bus.publish(event.clone()), 2);
+
+        assert_eq!(rx1.recv().await.unwrap(), event);
+        assert_eq!(rx2.recv().await.unwrap(), event);
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_subscribers() {
+        let bus = EventBus::default();
+        let publisher = bus.clone();
+        let mut rx = bus.subscribe();
+
+        let event = GcEvent::AssignmentChanged {
+            meeting_id: Uuid::new_v4(),
+            mc_id: "mc-1".to_string(),
+            region: "us-east-1".to_string(),
+        };
+        publisher.publish(event.clone());
+
+        assert_eq!(rx.recv().await.unwrap(), event);
+    }
+
+    #[test]
+    fn test_subscriber_only_sees_events_after_subscribe() {
+        let bus = EventBus::default();
+        let _keepalive = bus.subscribe();
+        bus.publish(created(Uuid::new_v4()));
+
+        let mut rx = bus.subscribe();
+        assert!(matches!(rx.try_recv(), Err(TryRecvError::Empty)));
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_lags_without_blocking_publisher() {
+        let bus = EventBus::new(2);
+        let mut rx = bus.subscribe();
+
+        for _ in 0..5 {
+            bus.publish(created(Uuid::new_v4()));
+        }
+
+        assert!(matches!(rx.recv().await, Err(RecvError::Lagged(3))));
+        // After lagging the receiver resumes with the retained events
+        assert!(rx.recv().await.is_ok());
+    }
+
+    #[test]
+    fn test_zero_capacity_is_clamped() {
+        let bus = EventBus::new(0);
+        let _rx = bus.subscribe();
+        assert_eq!(bus.publish(created(Uuid::new_v4())), 1);
+    }
+
+    #[test]
+    fn test_event_names_and_meeting_id() {
+        let meeting_id = Uuid::new_v4();
+        let events = [
+            created(meeting_id),
+            GcEvent::AssignmentChanged {
+                meeting_id,
+                mc_id: "mc-1".to_string(),
+                region: "us-east-1".to_string(),
+            },
+            GcEvent::MeetingSettingsUpdated {
+                meeting_id,
+                updated_by: Uuid::new_v4(),
+            },
+        ];
+        let names: Vec<&str> = events.iter().map(GcEvent::name).collect();
+        assert_eq!(
+            names,
+            [
+                "meeting_created",
+                "assignment_changed",
+                "meeting_settings_updated"
+            ]
+        );
+        assert!(events.iter().all(|e| e.meeting_id() == meeting_id));
+    }
+
+    #[test]
+    fn test_serde_tag_matches_event_name() {
+        let event = GcEvent::MeetingSettingsUpdated {
+            meeting_id: Uuid::new_v4(),
+            updated_by: Uuid::new_v4(),
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], event.name());
+
+        let decoded: GcEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, event);
+    }
+}
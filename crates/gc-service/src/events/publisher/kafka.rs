@@ -0,0 +1,59 @@
+//! Kafka event publisher (feature `kafka`).
+//!
+//! All events go to a single topic keyed by meeting ID, so events for the same
+//! meeting land on the same partition and keep their relative order.
+
+use super::{encode_event, EventPublisher, PublishError};
+use crate::events::GcEvent;
+use async_trait::async_trait;
+use rdkafka::config::ClientConfig;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use std::time::Duration;
+
+/// Producer-side delivery timeout.
+const MESSAGE_TIMEOUT_MS: &str = "5000";
+
+/// How long `publish` waits for space in the producer queue.
+const QUEUE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Kafka publisher backed by an `rdkafka` future producer.
+pub struct KafkaPublisher {
+    producer: FutureProducer,
+    topic: String,
+}
+
+impl KafkaPublisher {
+    /// Create a producer for the given bootstrap servers.
+    pub fn new(bootstrap_servers: &str, topic: &str) -> Result<Self, PublishError> {
+        let producer: FutureProducer = ClientConfig::new()
+            .set("bootstrap.servers", bootstrap_servers)
+            .set("message.timeout.ms", MESSAGE_TIMEOUT_MS)
+            .set("enable.idempotence", "true")
+            .create()
+            .map_err(|e| PublishError::Connect(e.to_string()))?;
+
+        Ok(Self {
+            producer,
+            topic: topic.to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl EventPublisher for KafkaPublisher {
+    fn backend(&self) -> &'static str {
+        "kafka"
+    }
+
+    async fn publish(&self, event: &GcEvent) -> Result<(), PublishError> {
+        let payload = encode_event(event)?;
+        let key = event.meeting_id().to_string();
+        let record = FutureRecord::to(&self.topic).key(&key).payload(&payload);
+
+        self.producer
+            .send(record, QUEUE_TIMEOUT)
+            .await
+            .map(|_| ())
+            .map_err(|(e, _)| PublishError::Publish(e.to_string()))
+    }
+}
@@ -0,0 +1,263 @@
+//! External message queue publishing for meeting lifecycle events.
+//!
+//! Forwards [`GcEvent`]s from the in-process [`EventBus`](super::EventBus) to
+//! an external broker so downstream consumers (analytics, billing) can read a
+//! stream instead of polling the GC API.
+//!
+//! # Backends
+//!
+//! Broker clients are compiled in only when their cargo feature is enabled:
+//!
+//! | Backend | Feature | Routing |
+//! |---------|---------|---------|
+//! | NATS    | `nats`  | subject `{topic}.{event_name}` |
+//! | Kafka   | `kafka` | topic `{topic}`, key = meeting ID (per-meeting ordering) |
+//!
+//! # Configuration
+//!
+//! - `GC_EVENT_PUBLISHER` - `none` (default), `nats`, or `kafka`
+//! - `GC_EVENT_PUBLISHER_URL` - NATS server URL or Kafka bootstrap servers
+//!   (required unless `none`)
+//! - `GC_EVENT_PUBLISHER_TOPIC` - subject prefix / topic
+//!   (default: `dark_tower.meetings`)
+//!
+//! Selecting a backend whose feature was not compiled in is a startup error
+//! rather than a silent no-op.
+//!
+//! # Wire Format
+//!
+//! Payloads are the JSON serialization of `GcEvent` (internally tagged by
+//! `type`), identical to the `event_outbox` payload.
+
+#[cfg(feature = "kafka")]
+pub mod kafka;
+#[cfg(feature = "nats")]
+pub mod nats;
+
+use super::GcEvent;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Default subject prefix / topic.
+pub const DEFAULT_EVENT_TOPIC: &str = "dark_tower.meetings";
+
+/// Errors from event publisher configuration and delivery.
+#[allow(dead_code)] // Connect/Publish are only constructed by feature-gated backends
+#[derive(Debug, Error)]
+pub enum PublishError {
+    #[error("Invalid event publisher configuration: {0}")]
+    Config(String),
+
+    #[error("Failed to connect to event broker: {0}")]
+    Connect(String),
+
+    #[error("Failed to serialize event: {0}")]
+    Serialize(String),
+
+    #[error("Failed to publish event: {0}")]
+    Publish(String),
+}
+
+/// Sink for meeting lifecycle events.
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    /// Bounded backend name for logging.
+    fn backend(&self) -> &'static str;
+
+    /// Publish a single event.
+    async fn publish(&self, event: &GcEvent) -> Result<(), PublishError>;
+}
+
+/// Supported publisher backends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublisherBackend {
+    Nats,
+    Kafka,
+}
+
+/// Event publisher configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublisherConfig {
+    /// Selected backend.
+    pub backend: PublisherBackend,
+    /// NATS server URL or Kafka bootstrap servers.
+    pub url: String,
+    /// Subject prefix (NATS) or topic (Kafka).
+    pub topic: String,
+}
+
+impl PublisherConfig {
+    /// Load configuration from environment variables.
+    ///
+    /// Returns `Ok(None)` when publishing is disabled.
+    pub fn from_env() -> Result<Option<Self>, PublishError> {
+        Self::from_vars(&std::env::vars().collect())
+    }
+
+    /// Load configuration from a HashMap (for testing).
+    pub fn from_vars(vars: &HashMap<String, String>) -> Result<Option<Self>, PublishError> {
+        let backend = match vars.get("GC_EVENT_PUBLISHER").map(|s| s.trim()) {
+            None | Some("") | Some("none") => return Ok(None),
+            Some("nats") => PublisherBackend::Nats,
+            Some("kafka") => PublisherBackend::Kafka,
+            Some(other) => {
+                return Err(PublishError::Config(format!(
+                    "GC_EVENT_PUBLISHER must be one of none, nats, kafka, got '{}'",
+                    other
+                )))
+            }
+        };
+
+        let url = vars
+            .get("GC_EVENT_PUBLISHER_URL")
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| {
+                PublishError::Config(
+                    "GC_EVENT_PUBLISHER_URL is required when GC_EVENT_PUBLISHER is set".to_string(),
+                )
+            })?
+            .clone();
+
+        let topic = vars
+            .get("GC_EVENT_PUBLISHER_TOPIC")
+            .filter(|s| !s.is_empty())
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_EVENT_TOPIC.to_string());
+
+        Ok(Some(Self {
+            backend,
+            url,
+            topic,
+        }))
+    }
+}
+
+/// Connect the configured publisher backend.
+///
+/// # Errors
+///
+/// - `PublishError::Config` - backend feature not compiled in
+/// - `PublishError::Connect` - broker unreachable
+pub async fn connect(config: &PublisherConfig) -> Result<Arc<dyn EventPublisher>, PublishError> {
+    match config.backend {
+        #[cfg(feature = "nats")]
+        PublisherBackend::Nats => Ok(Arc::new(
+            nats::NatsPublisher::connect(&config.url, &config.topic).await?,
+        )),
+        #[cfg(feature = "kafka")]
+        PublisherBackend::Kafka => Ok(Arc::new(kafka::KafkaPublisher::new(
+            &config.url,
+            &config.topic,
+        )?)),
+        #[allow(unreachable_patterns)]
+        backend => Err(PublishError::Config(format!(
+            "{:?} publisher requested but gc-service was built without the '{}' feature",
+            backend,
+            feature_name(backend)
+        ))),
+    }
+}
+
+fn feature_name(backend: PublisherBackend) -> &'static str {
+    match backend {
+        PublisherBackend::Nats => "nats",
+        PublisherBackend::Kafka => "kafka",
+    }
+}
+
+/// Serialize an event to its wire format.
+#[allow(dead_code)] // Used by feature-gated backends
+pub fn encode_event(event: &GcEvent) -> Result<Vec<u8>, PublishError> {
+    serde_json::to_vec(event).map_err(|e| PublishError::Serialize(e.to_string()))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        assert_eq!(PublisherConfig::from_vars(&vars(&[])).unwrap(), None);
+        assert_eq!(
+            PublisherConfig::from_vars(&vars(&[("GC_EVENT_PUBLISHER", "none")])).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_nats_config_with_default_topic() {
+        let config = PublisherConfig::from_vars(&vars(&[
+            ("GC_EVENT_PUBLISHER", "nats"),
+            ("GC_EVENT_PUBLISHER_URL", "nats://localhost:4222"),
+        ]))
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(config.backend, PublisherBackend::Nats);
+        assert_eq!(config.url, "nats://localhost:4222");
+        assert_eq!(config.topic, DEFAULT_EVENT_TOPIC);
+    }
+
+    #[test]
+    fn test_kafka_config_with_custom_topic() {
+        let config = PublisherConfig::from_vars(&vars(&[
+            ("GC_EVENT_PUBLISHER", "kafka"),
+            ("GC_EVENT_PUBLISHER_URL", "broker-1:9092,broker-2:9092"),
+            ("GC_EVENT_PUBLISHER_TOPIC", "meetings-v1"),
+        ]))
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(config.backend, PublisherBackend::Kafka);
+        assert_eq!(config.topic, "meetings-v1");
+    }
+
+    #[test]
+    fn test_unknown_backend_rejected() {
+        let result = PublisherConfig::from_vars(&vars(&[("GC_EVENT_PUBLISHER", "rabbitmq")]));
+        assert!(matches!(result, Err(PublishError::Config(msg)) if msg.contains("rabbitmq")));
+    }
+
+    #[test]
+    fn test_missing_url_rejected() {
+        let result = PublisherConfig::from_vars(&vars(&[("GC_EVENT_PUBLISHER", "nats")]));
+        assert!(
+            matches!(result, Err(PublishError::Config(msg)) if msg.contains("GC_EVENT_PUBLISHER_URL"))
+        );
+    }
+
+    #[cfg(not(feature = "nats"))]
+    #[tokio::test]
+    async fn test_connect_without_feature_is_config_error() {
+        let config = PublisherConfig {
+            backend: PublisherBackend::Nats,
+            url: "nats://localhost:4222".to_string(),
+            topic: DEFAULT_EVENT_TOPIC.to_string(),
+        };
+        let result = connect(&config).await;
+        assert!(matches!(result, Err(PublishError::Config(msg)) if msg.contains("'nats' feature")));
+    }
+
+    #[test]
+    fn test_encode_event_is_tagged_json() {
+        let event = GcEvent::MeetingCreated {
+            meeting_id: Uuid::new_v4(),
+            org_id: Uuid::new_v4(),
+            created_by_user_id: Uuid::new_v4(),
+        };
+        let bytes = encode_event(&event).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["type"], "meeting_created");
+    }
+}
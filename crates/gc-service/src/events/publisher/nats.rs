@@ -0,0 +1,47 @@
+//! NATS event publisher (feature `nats`).
+//!
+//! Publishes each event to `{prefix}.{event_name}` so consumers can subscribe
+//! to a single event type or the whole stream with `{prefix}.>`.
+
+use super::{encode_event, EventPublisher, PublishError};
+use crate::events::GcEvent;
+use async_trait::async_trait;
+
+/// NATS core publisher.
+pub struct NatsPublisher {
+    client: async_nats::Client,
+    subject_prefix: String,
+}
+
+impl NatsPublisher {
+    /// Connect to a NATS server.
+    pub async fn connect(url: &str, subject_prefix: &str) -> Result<Self, PublishError> {
+        let client = async_nats::connect(url)
+            .await
+            .map_err(|e| PublishError::Connect(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            subject_prefix: subject_prefix.to_string(),
+        })
+    }
+
+    fn subject_for(&self, event: &GcEvent) -> String {
+        format!("{}.{}", self.subject_prefix, event.name())
+    }
+}
+
+#[async_trait]
+impl EventPublisher for NatsPublisher {
+    fn backend(&self) -> &'static str {
+        "nats"
+    }
+
+    async fn publish(&self, event: &GcEvent) -> Result<(), PublishError> {
+        let payload = encode_event(event)?;
+        self.client
+            .publish(self.subject_for(event), payload.into())
+            .await
+            .map_err(|e| PublishError::Publish(e.to_string()))
+    }
+}
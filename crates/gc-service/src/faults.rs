@@ -0,0 +1,12 @@
+//! Fault injection points (`fault-injection` feature).
+//!
+//! Names accepted by `PUT /debug/faults/{operation}` on GC. See
+//! `common::fault_injection` for the endpoint and fault kinds.
+
+/// `AssignMeetingWithMh` RPC to the chosen MC. `error` fails the call as
+/// an unreachable MC does (`ServiceUnavailable`); `drop` fails it the same
+/// way after the 10s RPC timeout, as an unanswered request would.
+pub const MC_ASSIGNMENT: &str = "mc_assignment";
+
+/// Every injection point GC has.
+pub const OPERATIONS: &[&str] = &[MC_ASSIGNMENT];
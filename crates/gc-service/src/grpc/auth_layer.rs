@@ -22,12 +22,13 @@
 //!
 //! 1. Structural fast-path: format, non-empty, size limit (8KB)
 //! 2. Cryptographic: `EdDSA` signature via JWKS
-//! 3. Claims: exp, iat with clock skew tolerance
+//! 3. Claims: exp, iat with clock skew tolerance; DPoP-bound tokens rejected
 //! 4. Authorization: `service.write.gc` scope check
 //! 5. Routing: `service_type` must match target gRPC service (fail closed)
 
 use crate::auth::claims::Claims;
 use crate::auth::JwtValidator;
+use crate::errors::GcError;
 use crate::observability::metrics;
 use axum::http;
 use common::jwt::{JwtError, MAX_JWT_SIZE_BYTES};
@@ -43,6 +44,9 @@ use tower::{Layer, Service};
 /// All callers (MC, MH) must present a token with this scope to call GC gRPC.
 const REQUIRED_SCOPE: &str = "service.write.gc";
 
+/// gRPC path prefix of `BuildInfoService`, open to every caller type.
+const BUILD_INFO_GRPC_PREFIX: &str = "/dark_tower.internal.v1.BuildInfoService/";
+
 /// Map a `JwtError` to a bounded `failure_reason` label for the
 /// `gc_jwt_validations_total` metric.
 fn classify_jwt_error(err: &JwtError) -> &'static str {
@@ -148,27 +152,35 @@ where
             // Extract authorization header
             let Some(auth_header) = request.headers().get("authorization") else {
                 tracing::debug!(target: "gc.grpc.auth", "Missing authorization metadata");
-                let response = tonic::Status::unauthenticated("Invalid token").into_http();
+                let response = GcError::InvalidToken("Invalid token".to_string())
+                    .to_grpc_status()
+                    .into_http();
                 return Ok(response);
             };
 
             let Ok(auth_str) = auth_header.to_str() else {
                 tracing::debug!(target: "gc.grpc.auth", "Authorization header not valid ASCII");
-                let response = tonic::Status::unauthenticated("Invalid token").into_http();
+                let response = GcError::InvalidToken("Invalid token".to_string())
+                    .to_grpc_status()
+                    .into_http();
                 return Ok(response);
             };
 
             // Extract Bearer token
             let Some(token) = auth_str.strip_prefix("Bearer ") else {
                 tracing::debug!(target: "gc.grpc.auth", "Invalid authorization format");
-                let response = tonic::Status::unauthenticated("Invalid token").into_http();
+                let response = GcError::InvalidToken("Invalid token".to_string())
+                    .to_grpc_status()
+                    .into_http();
                 return Ok(response);
             };
 
             // Structural fast-path checks
             if token.is_empty() {
                 tracing::debug!(target: "gc.grpc.auth", "Empty token");
-                let response = tonic::Status::unauthenticated("Invalid token").into_http();
+                let response = GcError::InvalidToken("Invalid token".to_string())
+                    .to_grpc_status()
+                    .into_http();
                 return Ok(response);
             }
 
@@ -178,7 +190,9 @@ where
                     token_size = token.len(),
                     "Token exceeds size limit"
                 );
-                let response = tonic::Status::unauthenticated("Invalid token").into_http();
+                let response = GcError::InvalidToken("Invalid token".to_string())
+                    .to_grpc_status()
+                    .into_http();
                 return Ok(response);
             }
 
@@ -187,6 +201,19 @@ where
             // GcError. We need the raw JwtError to classify the failure reason for
             // metrics, so call the inner validator directly. See auth/jwt.rs.
             let claims: Claims = match jwt_validator.validate_raw(token).await {
+                // DPoP proofs are only defined for HTTP requests, so a
+                // DPoP-bound token can never be presented here
+                Ok(claims) if claims.cnf.is_some() => {
+                    tracing::warn!(
+                        target: "gc.grpc.auth",
+                        "DPoP-bound service token presented as a bearer token"
+                    );
+                    metrics::record_jwt_validation("failure", "service", "dpop_bound");
+                    let response = GcError::InvalidToken("Invalid token".to_string())
+                        .to_grpc_status()
+                        .into_http();
+                    return Ok(response);
+                }
                 Ok(claims) => {
                     metrics::record_jwt_validation("success", "service", "none");
                     claims
@@ -200,7 +227,9 @@ where
                         "Service token validation failed"
                     );
                     metrics::record_jwt_validation("failure", "service", reason);
-                    let response = tonic::Status::unauthenticated("Invalid token").into_http();
+                    let response = GcError::InvalidToken("Invalid token".to_string())
+                        .to_grpc_status()
+                        .into_http();
                     return Ok(response);
                 }
             };
@@ -214,13 +243,24 @@ where
                     "Service token missing required scope"
                 );
                 metrics::record_jwt_validation("failure", "service", "scope_mismatch");
-                let response = tonic::Status::unauthenticated("Invalid token").into_http();
+                let response = GcError::InvalidToken("Invalid token".to_string())
+                    .to_grpc_status()
+                    .into_http();
                 return Ok(response);
             }
 
             // Layer 2: service_type routing (ADR-0003)
             // Match the gRPC service path to the expected caller service_type.
             let grpc_path = request.uri().path();
+
+            // BuildInfoService only returns build metadata: any authenticated
+            // service may call it, so it skips caller-type routing.
+            if grpc_path.starts_with(BUILD_INFO_GRPC_PREFIX) {
+                let mut request = request;
+                request.extensions_mut().insert(claims);
+                return inner.call(request).await;
+            }
+
             let (grpc_service_label, expected_type) = if grpc_path
                 .starts_with("/dark_tower.internal.v1.GlobalControllerService/")
             {
@@ -235,7 +275,9 @@ where
                     path = %grpc_path,
                     "Unknown gRPC service path, rejecting"
                 );
-                let response = tonic::Status::permission_denied("Access denied").into_http();
+                let response = GcError::Forbidden("Access denied".to_string())
+                    .to_grpc_status()
+                    .into_http();
                 return Ok(response);
             };
 
@@ -253,7 +295,9 @@ where
                     expected_type,
                     actual_type,
                 );
-                let response = tonic::Status::permission_denied("Access denied").into_http();
+                let response = GcError::Forbidden("Access denied".to_string())
+                    .to_grpc_status()
+                    .into_http();
                 return Ok(response);
             }
 
@@ -298,6 +342,8 @@ mod tests {
         scope: String,
         #[serde(skip_serializing_if = "Option::is_none")]
         service_type: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cnf: Option<serde_json::Value>,
     }
 
     struct TestKeypair {
@@ -407,6 +453,7 @@ mod tests {
             iat: now,
             scope: scope.to_string(),
             service_type: service_type.map(String::from),
+            cnf: None,
         }
     }
 
@@ -440,6 +487,11 @@ mod tests {
             tonic::Code::Unauthenticated,
             "{context}: expected UNAUTHENTICATED"
         );
+        assert_eq!(
+            response.headers().get(common::error::ERROR_CODE_METADATA),
+            Some(&http::HeaderValue::from_static("DT-GC-3001")),
+            "{context}: expected registry code"
+        );
     }
 
     fn assert_permission_denied(response: &http::Response<BoxBody>, context: &str) {
@@ -453,6 +505,11 @@ mod tests {
             tonic::Code::PermissionDenied,
             "{context}: expected PERMISSION_DENIED"
         );
+        assert_eq!(
+            response.headers().get(common::error::ERROR_CODE_METADATA),
+            Some(&http::HeaderValue::from_static("DT-GC-3002")),
+            "{context}: expected registry code"
+        );
     }
 
     #[test]
@@ -568,6 +625,7 @@ mod tests {
             iat: now - 7200,
             scope: "service.write.gc".to_string(),
             service_type: Some("meeting-controller".to_string()),
+            cnf: None,
         };
         let token = keypair.sign_token(&claims);
 
@@ -581,6 +639,27 @@ mod tests {
         assert_unauthenticated(&response, "expired token");
     }
 
+    #[tokio::test]
+    async fn test_auth_layer_rejects_dpop_bound_token() {
+        let (_mock_server, keypair, layer) = setup_auth_layer().await;
+        let mut svc = layer.layer(NoopService);
+
+        let claims = TestClaims {
+            cnf: Some(serde_json::json!({ "jkt": "bound-key-thumbprint" })),
+            ..make_claims("service.write.gc", Some("meeting-controller"))
+        };
+        let token = keypair.sign_token(&claims);
+
+        let request = http::Request::builder()
+            .uri(MC_GRPC_PATH)
+            .header("authorization", format!("Bearer {token}"))
+            .body(BoxBody::default())
+            .unwrap();
+
+        let response = svc.ready().await.unwrap().call(request).await.unwrap();
+        assert_unauthenticated(&response, "DPoP-bound token");
+    }
+
     #[tokio::test]
     async fn test_auth_layer_rejects_wrong_scope() {
         // Sending a non-GC scope (service.write.mc) to GC to prove scope-level
@@ -762,6 +841,28 @@ mod tests {
         assert_permission_denied(&response, "no service_type (fail closed)");
     }
 
+    #[tokio::test]
+    async fn test_layer2_allows_any_caller_type_for_build_info() {
+        let (_mock_server, keypair, layer) = setup_auth_layer().await;
+        let mut svc = layer.layer(NoopService);
+
+        let claims = make_claims("service.write.gc", Some("global-controller"));
+        let token = keypair.sign_token(&claims);
+
+        let request = http::Request::builder()
+            .uri("/dark_tower.internal.v1.BuildInfoService/GetVersion")
+            .header("authorization", format!("Bearer {token}"))
+            .body(BoxBody::default())
+            .unwrap();
+
+        let response = svc.ready().await.unwrap().call(request).await.unwrap();
+        let status = tonic::Status::from_header_map(response.headers());
+        assert!(
+            status.is_none(),
+            "BuildInfoService should accept any caller type, got: {status:?}"
+        );
+    }
+
     #[tokio::test]
     async fn test_layer2_rejects_unknown_grpc_path() {
         let (_mock_server, keypair, layer) = setup_auth_layer().await;
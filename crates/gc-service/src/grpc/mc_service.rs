@@ -2,25 +2,59 @@
 //!
 //! Implements the `GlobalControllerService` trait for MC registration and heartbeat.
 //!
+//! # Heartbeat Stream
+//!
+//! MCs that support it send fast heartbeats over one long-lived
+//! `StreamHeartbeat` call instead of a unary `FastHeartbeat` every 10
+//! seconds. Each heartbeat is acknowledged on the response stream with a
+//! [`HeartbeatDirective`]: `DRAIN` once an operator has set the controller's
+//! `health_status` to `draining`, `REREGISTER` if the controller has no
+//! registration (GC lost it, or it was deleted). The unary RPC stays for MCs
+//! that predate the stream.
+//!
+//! Directives operators issue in `mc_directives` (heartbeat intervals, soft
+//! limits, drain, ending a meeting) ride along in the next ack, each sent
+//! once and logged with its ID.
+//!
+//! # Warm Standby
+//!
+//! An MC that registers with `standby_for` gets its primary's active
+//! meeting IDs in every ack, so it can keep their journals warm. If the
+//! primary's stream then breaks with an error (rather than closing
+//! cleanly), GC promotes the standby; see [`StandbyService`].
+//!
 //! # Security
 //!
 //! - All requests require JWT authentication (enforced by auth interceptor)
 //! - Input validation performed on all requests
 //! - Generic error messages returned to prevent information leakage
 
+use crate::auth::Claims;
 use crate::observability::metrics;
-use crate::repositories::{HealthStatus, MeetingControllersRepository};
+use crate::repositories::{
+    AttendanceRecord, AttendanceRepository, HealthStatus, McDirective, McDirectiveAction,
+    McDirectivesRepository, MeetingAssignmentsRepository, MeetingControllersRepository,
+    MeetingReportsRepository, DEFAULT_MC_POOL,
+};
 use crate::routes::AppState;
-use crate::services::McAssignmentService;
+use crate::services::{fleet_versions, McAssignmentService, StandbyService};
+use chrono::{DateTime, Utc};
+use proto_gen::dark_tower::internal::v1::controller_directive::Action;
 use proto_gen::dark_tower::internal::v1::global_controller_service_server::GlobalControllerService;
 use proto_gen::dark_tower::internal::v1::{
-    ComprehensiveHeartbeatRequest, ComprehensiveHeartbeatResponse, FastHeartbeatRequest,
-    FastHeartbeatResponse, NotifyMeetingEndedRequest, NotifyMeetingEndedResponse,
-    RegisterMcRequest, RegisterMcResponse,
+    ComprehensiveHeartbeatRequest, ComprehensiveHeartbeatResponse, ControllerDirective,
+    DrainDirective, EndMeetingDirective, FastHeartbeatRequest, FastHeartbeatResponse,
+    HeartbeatDirective, HeartbeatIntervalsDirective, MeetingTelemetry, NotifyMeetingEndedRequest,
+    NotifyMeetingEndedResponse, RegisterMcRequest, RegisterMcResponse, SoftLimitsDirective,
+    StreamHeartbeatRequest, StreamHeartbeatResponse,
 };
 use std::sync::Arc;
-use tonic::{Request, Response, Status};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::sync::CancellationToken;
+use tonic::{Request, Response, Status, Streaming};
 use tracing::instrument;
+use uuid::Uuid;
 
 /// Default fast heartbeat interval in milliseconds (10 seconds).
 const DEFAULT_FAST_HEARTBEAT_INTERVAL_MS: u64 = 10_000;
@@ -28,6 +62,9 @@ const DEFAULT_FAST_HEARTBEAT_INTERVAL_MS: u64 = 10_000;
 /// Default comprehensive heartbeat interval in milliseconds (30 seconds).
 const DEFAULT_COMPREHENSIVE_HEARTBEAT_INTERVAL_MS: u64 = 30_000;
 
+/// Acks buffered per heartbeat stream before the stream stops reading.
+const HEARTBEAT_STREAM_BUFFER: usize = 4;
+
 /// Maximum allowed controller ID length.
 const MAX_CONTROLLER_ID_LENGTH: usize = 255;
 
@@ -37,20 +74,53 @@ const MAX_MEETING_ID_LENGTH: usize = 255;
 /// Maximum allowed region length.
 const MAX_REGION_LENGTH: usize = 50;
 
+/// Maximum allowed capacity pool name length (matches the column width).
+const MAX_POOL_LENGTH: usize = 64;
+
 /// Maximum allowed endpoint length.
 const MAX_ENDPOINT_LENGTH: usize = 255;
 
+/// Maximum QoE score (MOS-style 0.0-5.0 scale).
+const MAX_QOE_SCORE: f32 = 5.0;
+
+/// Maximum attendance records accepted in one flush.
+const MAX_ATTENDANCE_RECORDS: usize = 10_000;
+
+/// Maximum length of attendance string fields (matches column widths).
+const MAX_ATTENDANCE_FIELD_LENGTH: usize = 255;
+
+/// Accepted attendance leave reasons.
+const ATTENDANCE_LEAVE_REASONS: &[&str] = &[
+    "voluntary",
+    "timeout",
+    "removed",
+    "meeting_ended",
+    "replaced",
+];
+
 /// Meeting Controller gRPC service.
 ///
 /// Handles registration and heartbeat requests from Meeting Controllers.
 pub struct McService {
     state: Arc<AppState>,
+    shutdown: CancellationToken,
 }
 
 impl McService {
     /// Create a new MC service with the given application state.
     pub fn new(state: Arc<AppState>) -> Self {
-        Self { state }
+        Self {
+            state,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    /// Close open heartbeat streams when `shutdown` is cancelled, so they
+    /// do not hold up graceful shutdown. MCs reconnect to another GC.
+    #[must_use]
+    pub fn with_shutdown(mut self, shutdown: CancellationToken) -> Self {
+        self.shutdown = shutdown;
+        self
     }
 
     /// Refresh the registered controllers gauge metric.
@@ -78,6 +148,124 @@ impl McService {
         }
     }
 
+    /// Apply one heartbeat from a heartbeat stream and build its ack.
+    async fn handle_stream_heartbeat(
+        &self,
+        req: StreamHeartbeatRequest,
+    ) -> Result<StreamHeartbeatResponse, Status> {
+        Self::validate_controller_id(&req.controller_id)?;
+
+        let capacity = req
+            .capacity
+            .ok_or_else(|| Status::invalid_argument("capacity is required"))?;
+        let current_meetings = i32::try_from(capacity.current_meetings).unwrap_or(i32::MAX);
+        let current_participants = i32::try_from(capacity.current_participants).unwrap_or(i32::MAX);
+
+        let stored = MeetingControllersRepository::update_stream_heartbeat(
+            &self.state.pool,
+            &req.controller_id,
+            current_meetings,
+            current_participants,
+            HealthStatus::from_proto(req.health),
+        )
+        .await
+        .map_err(|e| {
+            tracing::error!(target: "gc.grpc.stream_heartbeat", error = %e, "Failed to update heartbeat");
+            Status::internal("Heartbeat update failed")
+        })?;
+
+        let mut directives = Vec::new();
+        let mut standby_meeting_ids = Vec::new();
+        if stored.is_some() {
+            // Refresh the registered controllers metric (status may have changed)
+            self.refresh_controller_metrics().await;
+            directives = self.take_directives(&req.controller_id).await;
+            standby_meeting_ids = self.standby_meeting_ids(&req.controller_id).await;
+        }
+
+        Ok(StreamHeartbeatResponse {
+            sequence: req.sequence,
+            timestamp: chrono::Utc::now().timestamp() as u64,
+            directive: heartbeat_directive(stored).into(),
+            directives,
+            standby_meeting_ids,
+        })
+    }
+
+    /// Active meetings of the primary a standby is paired with (empty for
+    /// an MC that is not a standby, or on a database failure).
+    async fn standby_meeting_ids(&self, controller_id: &str) -> Vec<String> {
+        MeetingAssignmentsRepository::get_standby_meeting_ids(&self.state.pool, controller_id)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(
+                    target: "gc.grpc.stream_heartbeat",
+                    error = %e,
+                    controller_id = %controller_id,
+                    "Failed to load standby meetings"
+                );
+                Vec::new()
+            })
+    }
+
+    /// Promote the warm standby of a controller whose heartbeat stream broke.
+    async fn fail_over(&self, primary_id: &str) {
+        let result = StandbyService::promote_if_unreachable(
+            &self.state.pool,
+            self.state.mc_client.as_ref(),
+            primary_id,
+            &self.state.config.gc_id,
+        )
+        .await;
+
+        match result {
+            Ok(Some(_)) => self.refresh_controller_metrics().await,
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(
+                    target: "gc.grpc.stream_heartbeat",
+                    error = %e,
+                    primary_id = %primary_id,
+                    "Standby promotion failed"
+                );
+            }
+        }
+    }
+
+    /// Take a controller's pending directives for a heartbeat ack.
+    ///
+    /// A database failure leaves them pending for the next heartbeat.
+    async fn take_directives(&self, controller_id: &str) -> Vec<ControllerDirective> {
+        let pending =
+            match McDirectivesRepository::take_pending(&self.state.pool, controller_id).await {
+                Ok(pending) => pending,
+                Err(e) => {
+                    tracing::warn!(
+                        target: "gc.grpc.stream_heartbeat",
+                        error = %e,
+                        controller_id = %controller_id,
+                        "Failed to load MC directives"
+                    );
+                    return Vec::new();
+                }
+            };
+
+        pending
+            .into_iter()
+            .map(|directive| {
+                tracing::info!(
+                    target: "gc.grpc.stream_heartbeat",
+                    controller_id = %controller_id,
+                    directive_id = %directive.directive_id,
+                    kind = directive.action.kind(),
+                    created_by = %directive.created_by,
+                    "Sending directive to MC"
+                );
+                directive_to_proto(directive)
+            })
+            .collect()
+    }
+
     /// Validate a controller ID.
     #[expect(
         clippy::result_large_err,
@@ -141,6 +329,25 @@ impl McService {
         Ok(())
     }
 
+    /// Validate a capacity pool name.
+    #[expect(
+        clippy::result_large_err,
+        reason = "Status is the standard gRPC error type"
+    )]
+    fn validate_pool(mc_pool: &str) -> Result<(), Status> {
+        if mc_pool.len() > MAX_POOL_LENGTH {
+            return Err(Status::invalid_argument("pool is too long"));
+        }
+        // Same character set as controller_id
+        if !mc_pool
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(Status::invalid_argument("pool contains invalid characters"));
+        }
+        Ok(())
+    }
+
     /// Validate an endpoint URL.
     #[expect(
         clippy::result_large_err,
@@ -187,6 +394,83 @@ impl McService {
         }
         Ok(())
     }
+
+    /// Validate end-of-meeting telemetry and convert counts to database types.
+    ///
+    /// Returns `(peak_participants, join_failures, avg_qoe_score)`.
+    #[expect(
+        clippy::result_large_err,
+        reason = "Status is the standard gRPC error type"
+    )]
+    fn validate_telemetry(telemetry: &MeetingTelemetry) -> Result<(i32, i32, Option<f32>), Status> {
+        let peak_participants = i32::try_from(telemetry.peak_participants)
+            .map_err(|_| Status::invalid_argument("peak_participants is out of range"))?;
+        let join_failures = i32::try_from(telemetry.join_failures)
+            .map_err(|_| Status::invalid_argument("join_failures is out of range"))?;
+
+        if let Some(score) = telemetry.avg_qoe_score {
+            if !(0.0..=MAX_QOE_SCORE).contains(&score) {
+                return Err(Status::invalid_argument(
+                    "avg_qoe_score must be between 0.0 and 5.0",
+                ));
+            }
+        }
+
+        Ok((peak_participants, join_failures, telemetry.avg_qoe_score))
+    }
+
+    /// Validate and convert attendance records from an MC flush.
+    #[expect(
+        clippy::result_large_err,
+        reason = "Status is the standard gRPC error type"
+    )]
+    fn validate_attendance(
+        records: &[proto_gen::dark_tower::internal::v1::AttendanceRecord],
+    ) -> Result<Vec<AttendanceRecord>, Status> {
+        if records.len() > MAX_ATTENDANCE_RECORDS {
+            return Err(Status::invalid_argument("too many attendance records"));
+        }
+
+        records
+            .iter()
+            .map(|r| {
+                for (value, field) in [
+                    (&r.participant_id, "participant_id"),
+                    (&r.user_id, "user_id"),
+                    (&r.display_name, "display_name"),
+                ] {
+                    if value.is_empty() || value.len() > MAX_ATTENDANCE_FIELD_LENGTH {
+                        return Err(Status::invalid_argument(format!(
+                            "attendance {field} must be 1-{MAX_ATTENDANCE_FIELD_LENGTH} bytes"
+                        )));
+                    }
+                }
+
+                if !ATTENDANCE_LEAVE_REASONS.contains(&r.leave_reason.as_str()) {
+                    return Err(Status::invalid_argument("invalid attendance leave_reason"));
+                }
+
+                let joined_at = DateTime::<Utc>::from_timestamp_millis(r.joined_at_ms)
+                    .ok_or_else(|| Status::invalid_argument("invalid attendance joined_at_ms"))?;
+                let left_at = DateTime::<Utc>::from_timestamp_millis(r.left_at_ms)
+                    .ok_or_else(|| Status::invalid_argument("invalid attendance left_at_ms"))?;
+                if left_at < joined_at {
+                    return Err(Status::invalid_argument(
+                        "attendance left_at_ms must not precede joined_at_ms",
+                    ));
+                }
+
+                Ok(AttendanceRecord {
+                    participant_id: r.participant_id.clone(),
+                    user_id: r.user_id.clone(),
+                    display_name: r.display_name.clone(),
+                    joined_at,
+                    left_at,
+                    leave_reason: r.leave_reason.clone(),
+                })
+            })
+            .collect()
+    }
 }
 
 #[tonic::async_trait]
@@ -214,6 +498,45 @@ impl GlobalControllerService for McService {
 
         Self::validate_capacity(req.max_meetings, req.max_participants)?;
 
+        let standby_for = if req.standby_for.is_empty() {
+            None
+        } else {
+            Self::validate_controller_id(&req.standby_for)?;
+            if req.standby_for == req.id {
+                return Err(Status::invalid_argument(
+                    "standby_for must name another controller",
+                ));
+            }
+            Some(req.standby_for.as_str())
+        };
+
+        let mc_pool = if req.pool.is_empty() {
+            DEFAULT_MC_POOL
+        } else {
+            Self::validate_pool(&req.pool)?;
+            req.pool.as_str()
+        };
+
+        let build =
+            fleet_versions::reported_build(req.build.as_ref()).map_err(Status::invalid_argument)?;
+        if let Err(message) =
+            fleet_versions::check_min_version(self.state.config.min_fleet_version, build)
+        {
+            tracing::warn!(
+                target: "gc.grpc.register_mc",
+                controller_id = %req.id,
+                reason = %message,
+                "MC registration rejected by minimum fleet version"
+            );
+            metrics::record_registration_version_rejection("meeting");
+            return Ok(Response::new(RegisterMcResponse {
+                accepted: false,
+                message,
+                fast_heartbeat_interval_ms: 0,
+                comprehensive_heartbeat_interval_ms: 0,
+            }));
+        }
+
         // Convert capacity to i32 for database (validated as positive above)
         let max_meetings = i32::try_from(req.max_meetings).map_err(|e| {
             Status::invalid_argument(format!("max_meetings value too large: {}", e))
@@ -244,15 +567,41 @@ impl GlobalControllerService for McService {
             Status::internal("Registration failed")
         })?;
 
+        // Re-registration without standby_for clears a stale pairing
+        MeetingControllersRepository::set_standby_for(&self.state.pool, &req.id, standby_for)
+            .await
+            .map_err(|e| {
+                tracing::error!(target: "gc.grpc.register_mc", error = %e, "Failed to record standby pairing");
+                Status::internal("Registration failed")
+            })?;
+
+        MeetingControllersRepository::set_pool(&self.state.pool, &req.id, mc_pool)
+            .await
+            .map_err(|e| {
+                tracing::error!(target: "gc.grpc.register_mc", error = %e, "Failed to record MC pool");
+                Status::internal("Registration failed")
+            })?;
+
+        MeetingControllersRepository::set_build(&self.state.pool, &req.id, build)
+            .await
+            .map_err(|e| {
+                tracing::error!(target: "gc.grpc.register_mc", error = %e, "Failed to record MC build");
+                Status::internal("Registration failed")
+            })?;
+
         tracing::info!(
             target: "gc.grpc.register_mc",
             controller_id = %req.id,
             region = %req.region,
+            mc_pool = %mc_pool,
+            standby_for = standby_for.unwrap_or(""),
+            version = build.map_or(fleet_versions::UNKNOWN_VERSION, |b| b.version),
             "MC registered successfully"
         );
 
-        // Refresh the registered controllers metric
+        // Refresh the registered controllers and fleet version metrics
         self.refresh_controller_metrics().await;
+        fleet_versions::refresh_fleet_version_metrics(&self.state.pool).await;
 
         Ok(Response::new(RegisterMcResponse {
             accepted: true,
@@ -317,6 +666,59 @@ impl GlobalControllerService for McService {
         }))
     }
 
+    type StreamHeartbeatStream = ReceiverStream<Result<StreamHeartbeatResponse, Status>>;
+
+    /// Handle a fast heartbeat stream from a Meeting Controller.
+    ///
+    /// Acknowledges each heartbeat in order. An invalid heartbeat or a
+    /// database failure ends the stream with that error; the MC reopens it
+    /// (or falls back to unary heartbeats) on its next tick. A stream the
+    /// MC side breaks (rather than closes) triggers standby failover for
+    /// the controller that was heartbeating on it.
+    #[instrument(skip_all, name = "gc.grpc.stream_heartbeat")]
+    async fn stream_heartbeat(
+        &self,
+        request: Request<Streaming<StreamHeartbeatRequest>>,
+    ) -> Result<Response<Self::StreamHeartbeatStream>, Status> {
+        let mut inbound = request.into_inner();
+        let (tx, rx) = mpsc::channel(HEARTBEAT_STREAM_BUFFER);
+        let service = McService::new(Arc::clone(&self.state));
+        let shutdown = self.shutdown.clone();
+
+        tokio::spawn(async move {
+            let mut controller_id: Option<String> = None;
+            loop {
+                let message = tokio::select! {
+                    () = shutdown.cancelled() => break,
+                    message = inbound.message() => message,
+                };
+                let req = match message {
+                    Ok(Some(req)) => req,
+                    Ok(None) => break,
+                    Err(status) => {
+                        tracing::debug!(
+                            target: "gc.grpc.stream_heartbeat",
+                            code = ?status.code(),
+                            "Heartbeat stream closed by MC"
+                        );
+                        if let Some(primary_id) = controller_id {
+                            service.fail_over(&primary_id).await;
+                        }
+                        break;
+                    }
+                };
+                controller_id = Some(req.controller_id.clone());
+                let ack = service.handle_stream_heartbeat(req).await;
+                let failed = ack.is_err();
+                if tx.send(ack).await.is_err() || failed {
+                    break;
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
     /// Handle comprehensive heartbeat from a Meeting Controller.
     ///
     /// Updates capacity, health status, and metrics.
@@ -386,8 +788,9 @@ impl GlobalControllerService for McService {
 
     /// Handle meeting ended notification from a Meeting Controller.
     ///
-    /// Called when a meeting ends (last participant leaves). Marks the
-    /// assignment as ended (soft delete) for audit trail.
+    /// Called when a meeting ends (last participant leaves). Records any
+    /// reported telemetry, then marks the assignment as ended (soft delete)
+    /// for audit trail.
     #[instrument(skip_all, name = "gc.grpc.notify_meeting_ended")]
     async fn notify_meeting_ended(
         &self,
@@ -401,6 +804,32 @@ impl GlobalControllerService for McService {
         // Validate region
         Self::validate_region(&req.region)?;
 
+        // Record telemetry for the report rollup before the assignment ends.
+        // Failure here is logged but does not block ending the assignment.
+        if let Some(telemetry) = req.telemetry.as_ref() {
+            let (peak_participants, join_failures, avg_qoe_score) =
+                Self::validate_telemetry(telemetry)?;
+
+            if let Err(e) = MeetingAssignmentsRepository::record_telemetry(
+                &self.state.pool,
+                &req.meeting_id,
+                &req.region,
+                peak_participants,
+                join_failures,
+                avg_qoe_score,
+            )
+            .await
+            {
+                tracing::warn!(
+                    target: "gc.grpc.notify_meeting_ended",
+                    error = %e,
+                    meeting_id = %req.meeting_id,
+                    region = %req.region,
+                    "Failed to record meeting telemetry"
+                );
+            }
+        }
+
         // End the assignment
         let count = McAssignmentService::end_assignment(
             &self.state.pool,
@@ -434,6 +863,179 @@ impl GlobalControllerService for McService {
             acknowledged: true,
         }))
     }
+
+    /// Handle an attendance flush from a Meeting Controller.
+    ///
+    /// Called when a meeting ends on the MC. Stores each participant session
+    /// for host export; retried flushes are deduplicated by the repository.
+    /// Live streaming time is recorded on the region's assignment and patched
+    /// into the meeting report if it was already computed.
+    ///
+    /// The caller is identified by the subject of its validated service
+    /// token (each MC authenticates as a credential named after its
+    /// controller ID), not by the request body: a `controller_id` that does
+    /// not match the caller, or an MC that does not hold the meeting's
+    /// assignment in the region, gets `PermissionDenied`.
+    #[instrument(skip_all, name = "gc.grpc.report_attendance")]
+    async fn report_attendance(
+        &self,
+        request: Request<ReportAttendanceRequest>,
+    ) -> Result<Response<ReportAttendanceResponse>, Status> {
+        let caller = request
+            .extensions()
+            .get::<Claims>()
+            .map(|claims| claims.sub.clone())
+            .ok_or_else(|| Status::unauthenticated("Missing caller identity"))?;
+        let req = request.into_inner();
+
+        Self::validate_controller_id(&req.controller_id)?;
+        if req.controller_id != caller {
+            tracing::warn!(
+                target: "gc.grpc.report_attendance",
+                meeting_id = %req.meeting_id,
+                controller_id = %req.controller_id,
+                "Attendance controller_id does not match the authenticated caller"
+            );
+            return Err(Status::permission_denied(
+                "controller_id does not match the caller",
+            ));
+        }
+        Self::validate_meeting_id(&req.meeting_id)?;
+        Self::validate_region(&req.region)?;
+
+        let meeting_id = Uuid::parse_str(&req.meeting_id)
+            .map_err(|_| Status::invalid_argument("meeting_id must be a UUID"))?;
+        let records = Self::validate_attendance(&req.records)?;
+        let live_stream_seconds = i64::try_from(req.live_stream_seconds)
+            .map_err(|_| Status::invalid_argument("live_stream_seconds is out of range"))?;
+
+        let assigned_mc_id = MeetingAssignmentsRepository::get_assigned_mc_id(
+            &self.state.pool,
+            &req.meeting_id,
+            &req.region,
+        )
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                target: "gc.grpc.report_attendance",
+                error = %e,
+                meeting_id = %req.meeting_id,
+                region = %req.region,
+                "Failed to look up meeting assignment"
+            );
+            Status::internal("Failed to look up meeting assignment")
+        })?;
+        if assigned_mc_id.as_deref() != Some(req.controller_id.as_str()) {
+            tracing::warn!(
+                target: "gc.grpc.report_attendance",
+                meeting_id = %req.meeting_id,
+                region = %req.region,
+                controller_id = %req.controller_id,
+                "Attendance reported by an MC not assigned to the meeting"
+            );
+            return Err(Status::permission_denied(
+                "Meeting is not assigned to this controller",
+            ));
+        }
+
+        if live_stream_seconds > 0 {
+            let recorded = async {
+                MeetingAssignmentsRepository::record_live_stream_seconds(
+                    &self.state.pool,
+                    &req.meeting_id,
+                    &req.region,
+                    live_stream_seconds,
+                )
+                .await?;
+                MeetingReportsRepository::refresh_live_stream_seconds(&self.state.pool, meeting_id)
+                    .await
+            }
+            .await;
+            if let Err(e) = recorded {
+                tracing::error!(
+                    target: "gc.grpc.report_attendance",
+                    error = %e,
+                    meeting_id = %req.meeting_id,
+                    region = %req.region,
+                    "Failed to store live stream time"
+                );
+                return Err(Status::internal("Failed to store live stream time"));
+            }
+        }
+
+        let stored =
+            AttendanceRepository::insert_batch(&self.state.pool, meeting_id, &req.region, &records)
+                .await
+                .map_err(|e| {
+                    tracing::error!(
+                        target: "gc.grpc.report_attendance",
+                        error = %e,
+                        meeting_id = %req.meeting_id,
+                        region = %req.region,
+                        "Failed to store attendance records"
+                    );
+                    Status::internal("Failed to store attendance records")
+                })?;
+
+        tracing::info!(
+            target: "gc.grpc.report_attendance",
+            meeting_id = %req.meeting_id,
+            region = %req.region,
+            received = records.len(),
+            stored = stored,
+            live_stream_seconds = live_stream_seconds,
+            "Attendance records stored"
+        );
+
+        Ok(Response::new(ReportAttendanceResponse {
+            acknowledged: true,
+            records_stored: u32::try_from(stored).unwrap_or(u32::MAX),
+        }))
+    }
+}
+
+/// Convert a stored directive for the heartbeat stream.
+fn directive_to_proto(directive: McDirective) -> ControllerDirective {
+    // Negative values cannot pass the table's checks; treat them as unset
+    let ms = |v: Option<i64>| v.and_then(|v| u64::try_from(v).ok()).unwrap_or(0);
+    let limit = |v: Option<i32>| v.and_then(|v| u32::try_from(v).ok()).unwrap_or(0);
+    let action = match directive.action {
+        McDirectiveAction::HeartbeatIntervals {
+            fast_ms,
+            comprehensive_ms,
+        } => Action::HeartbeatIntervals(HeartbeatIntervalsDirective {
+            fast_heartbeat_interval_ms: ms(fast_ms),
+            comprehensive_heartbeat_interval_ms: ms(comprehensive_ms),
+        }),
+        McDirectiveAction::SoftLimits {
+            max_meetings,
+            max_participants,
+        } => Action::SoftLimits(SoftLimitsDirective {
+            max_meetings: limit(max_meetings),
+            max_participants: limit(max_participants),
+        }),
+        McDirectiveAction::Drain { draining } => Action::Drain(DrainDirective { draining }),
+        McDirectiveAction::EndMeeting { meeting_id, reason } => {
+            Action::EndMeeting(EndMeetingDirective {
+                meeting_id,
+                reason: reason.unwrap_or_default(),
+            })
+        }
+    };
+    ControllerDirective {
+        directive_id: directive.directive_id.to_string(),
+        action: Some(action),
+    }
+}
+
+/// Directive for a heartbeat ack, given the controller's stored health
+/// status after the heartbeat (`None` if it is not registered).
+fn heartbeat_directive(stored: Option<HealthStatus>) -> HeartbeatDirective {
+    match stored {
+        None => HeartbeatDirective::Reregister,
+        Some(HealthStatus::Draining) => HeartbeatDirective::Drain,
+        Some(_) => HeartbeatDirective::Unspecified,
+    }
 }
 
 #[cfg(test)]
@@ -565,6 +1167,20 @@ mod tests {
         assert!(err.message().contains("too long"));
     }
 
+    #[test]
+    fn test_validate_pool() {
+        assert!(McService::validate_pool("enterprise-acme_1").is_ok());
+        assert!(McService::validate_pool(&"p".repeat(64)).is_ok());
+
+        let err = McService::validate_pool(&"p".repeat(65)).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert!(err.message().contains("too long"));
+
+        let err = McService::validate_pool("acme pool").unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert!(err.message().contains("invalid characters"));
+    }
+
     #[test]
     fn test_validate_endpoint_at_255_chars() {
         // Exactly at the limit (255 chars) - need valid URL scheme
@@ -667,4 +1283,155 @@ mod tests {
             "Meeting ID with 1 char should pass"
         );
     }
+
+    // === Telemetry Validation Tests ===
+
+    #[test]
+    fn test_validate_telemetry_valid() {
+        let telemetry = MeetingTelemetry {
+            peak_participants: 12,
+            join_failures: 1,
+            avg_qoe_score: Some(4.2),
+        };
+        let (peak, failures, qoe) = McService::validate_telemetry(&telemetry).unwrap();
+        assert_eq!(peak, 12);
+        assert_eq!(failures, 1);
+        assert_eq!(qoe, Some(4.2));
+    }
+
+    #[test]
+    fn test_validate_telemetry_without_qoe_samples() {
+        let telemetry = MeetingTelemetry {
+            peak_participants: 0,
+            join_failures: 0,
+            avg_qoe_score: None,
+        };
+        assert!(McService::validate_telemetry(&telemetry).is_ok());
+    }
+
+    #[test]
+    fn test_validate_telemetry_count_out_of_range() {
+        let telemetry = MeetingTelemetry {
+            peak_participants: u32::MAX,
+            join_failures: 0,
+            avg_qoe_score: None,
+        };
+        let err = McService::validate_telemetry(&telemetry).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert!(err.message().contains("peak_participants"));
+    }
+
+    #[test]
+    fn test_validate_telemetry_qoe_out_of_range() {
+        for score in [-0.1, 5.1, f32::NAN, f32::INFINITY] {
+            let telemetry = MeetingTelemetry {
+                peak_participants: 1,
+                join_failures: 0,
+                avg_qoe_score: Some(score),
+            };
+            let err = McService::validate_telemetry(&telemetry).unwrap_err();
+            assert_eq!(err.code(), tonic::Code::InvalidArgument);
+            assert!(err.message().contains("avg_qoe_score"));
+        }
+    }
+
+    // === Attendance Validation Tests ===
+
+    fn attendance_record(
+        joined_at_ms: i64,
+        left_at_ms: i64,
+        leave_reason: &str,
+    ) -> proto_gen::dark_tower::internal::v1::AttendanceRecord {
+        proto_gen::dark_tower::internal::v1::AttendanceRecord {
+            participant_id: "part-1".to_string(),
+            user_id: "user-1".to_string(),
+            display_name: "Participant 1".to_string(),
+            joined_at_ms,
+            left_at_ms,
+            leave_reason: leave_reason.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_attendance_valid() {
+        let records = vec![attendance_record(
+            1_700_000_000_000,
+            1_700_000_060_000,
+            "voluntary",
+        )];
+        let converted = McService::validate_attendance(&records).unwrap();
+        assert_eq!(converted.len(), 1);
+        assert_eq!(converted[0].duration_seconds(), 60);
+    }
+
+    #[test]
+    fn test_validate_attendance_rejects_unknown_reason() {
+        let records = vec![attendance_record(0, 1000, "kicked")];
+        let err = McService::validate_attendance(&records).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert!(err.message().contains("leave_reason"));
+    }
+
+    #[test]
+    fn test_validate_attendance_rejects_inverted_interval() {
+        let records = vec![attendance_record(2000, 1000, "timeout")];
+        let err = McService::validate_attendance(&records).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[test]
+    fn test_validate_attendance_rejects_empty_fields() {
+        let mut record = attendance_record(0, 1000, "meeting_ended");
+        record.display_name = String::new();
+        let err = McService::validate_attendance(&[record]).unwrap_err();
+        assert!(err.message().contains("display_name"));
+    }
+
+    #[test]
+    fn test_validate_attendance_rejects_oversized_batch() {
+        let records = vec![attendance_record(0, 1000, "voluntary"); MAX_ATTENDANCE_RECORDS + 1];
+        let err = McService::validate_attendance(&records).unwrap_err();
+        assert!(err.message().contains("too many"));
+    }
+
+    #[test]
+    fn test_directive_to_proto() {
+        let directive = McDirective {
+            directive_id: Uuid::nil(),
+            action: McDirectiveAction::SoftLimits {
+                max_meetings: Some(20),
+                max_participants: None,
+            },
+            created_by: "ops".to_string(),
+            created_at: Utc::now(),
+        };
+
+        let proto = directive_to_proto(directive);
+
+        assert_eq!(proto.directive_id, Uuid::nil().to_string());
+        assert_eq!(
+            proto.action,
+            Some(Action::SoftLimits(SoftLimitsDirective {
+                max_meetings: 20,
+                max_participants: 0,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_heartbeat_directive() {
+        assert_eq!(heartbeat_directive(None), HeartbeatDirective::Reregister);
+        assert_eq!(
+            heartbeat_directive(Some(HealthStatus::Draining)),
+            HeartbeatDirective::Drain
+        );
+        assert_eq!(
+            heartbeat_directive(Some(HealthStatus::Healthy)),
+            HeartbeatDirective::Unspecified
+        );
+        assert_eq!(
+            heartbeat_directive(Some(HealthStatus::Degraded)),
+            HeartbeatDirective::Unspecified
+        );
+    }
 }
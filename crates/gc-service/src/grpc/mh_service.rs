@@ -1,7 +1,8 @@
 //! gRPC service for Media Handler registration and load reports.
 //!
 //! Implements the MediaHandlerRegistryService from internal.proto.
-//! MHs call these RPCs to register with GC and send periodic load reports.
+//! MHs call these RPCs to register with GC, send periodic load reports, and
+//! report finished recordings.
 //!
 //! # Security
 //!
@@ -9,16 +10,25 @@
 //! - Handler IDs are validated for format
 //! - Sensitive fields are not logged
 
-use crate::repositories::{HealthStatus, MediaHandlersRepository};
-use chrono::Utc;
+use crate::errors::GcError;
+use crate::handlers::meetings::find_meeting_by_id;
+use crate::models::is_valid_content_type;
+use crate::repositories::{
+    HealthStatus, MediaHandlersRepository, MeetingRecording, MeetingRecordingsRepository,
+};
+use crate::observability::metrics;
+use crate::services::fleet_versions::{self, ReleaseVersion};
+use chrono::{DateTime, Utc};
 use proto_gen::dark_tower::internal::v1::{
     media_handler_registry_service_server::MediaHandlerRegistryService, RegisterMhRequest,
-    RegisterMhResponse, SendLoadReportRequest, SendLoadReportResponse,
+    RegisterMhResponse, ReportRecordingRequest, ReportRecordingResponse, SendLoadReportRequest,
+    SendLoadReportResponse,
 };
 use sqlx::PgPool;
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
 use tracing::instrument;
+use uuid::Uuid;
 
 /// Default load report interval in milliseconds (10 seconds).
 const DEFAULT_LOAD_REPORT_INTERVAL_MS: u64 = 10_000;
@@ -32,15 +42,29 @@ const MAX_REGION_LENGTH: usize = 50;
 /// Maximum allowed endpoint length.
 const MAX_ENDPOINT_LENGTH: usize = 255;
 
+/// Maximum allowed recording object key length.
+const MAX_OBJECT_KEY_LENGTH: usize = 512;
+
 /// gRPC service for MH registration and load reports.
 pub struct MhService {
     pool: Arc<PgPool>,
+    min_fleet_version: Option<ReleaseVersion>,
 }
 
 impl MhService {
     /// Create a new MH service.
     pub fn new(pool: Arc<PgPool>) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            min_fleet_version: None,
+        }
+    }
+
+    /// Reject registrations from MHs older than `min_fleet_version`.
+    #[must_use]
+    pub fn with_min_fleet_version(mut self, min_fleet_version: Option<ReleaseVersion>) -> Self {
+        self.min_fleet_version = min_fleet_version;
+        self
     }
 
     /// Validate a handler ID.
@@ -150,6 +174,23 @@ impl MediaHandlerRegistryService for MhService {
             ));
         }
 
+        let build =
+            fleet_versions::reported_build(req.build.as_ref()).map_err(Status::invalid_argument)?;
+        if let Err(message) = fleet_versions::check_min_version(self.min_fleet_version, build) {
+            tracing::warn!(
+                target: "gc.grpc.mh_service",
+                handler_id = %req.handler_id,
+                reason = %message,
+                "MH registration rejected by minimum fleet version"
+            );
+            metrics::record_registration_version_rejection("media");
+            return Ok(Response::new(RegisterMhResponse {
+                accepted: false,
+                message,
+                load_report_interval_ms: 0,
+            }));
+        }
+
         // Register the handler
         MediaHandlersRepository::register_mh(
             &self.pool,
@@ -165,13 +206,23 @@ impl MediaHandlerRegistryService for MhService {
             Status::internal("Registration failed")
         })?;
 
+        MediaHandlersRepository::set_build(&self.pool, &req.handler_id, build)
+            .await
+            .map_err(|e| {
+                tracing::error!(target: "gc.grpc.mh_service", error = %e, "Failed to record MH build");
+                Status::internal("Registration failed")
+            })?;
+
         tracing::info!(
             target: "gc.grpc.mh_service",
             handler_id = %req.handler_id,
             region = %req.region,
+            version = build.map_or(fleet_versions::UNKNOWN_VERSION, |b| b.version),
             "MH registered successfully"
         );
 
+        fleet_versions::refresh_fleet_version_metrics(&self.pool).await;
+
         Ok(Response::new(RegisterMhResponse {
             accepted: true,
             message: "Registered successfully".to_string(),
@@ -253,6 +304,94 @@ impl MediaHandlerRegistryService for MhService {
             timestamp,
         }))
     }
+
+    /// Handle a finished recording reported by an MH recorder.
+    ///
+    /// The object must already be uploaded under `recordings/{meeting_id}/`.
+    /// Repeated reports of the same `recording_id` are accepted and ignored.
+    #[instrument(skip_all, fields(handler_id = %request.get_ref().handler_id, meeting_id = %request.get_ref().meeting_id))]
+    async fn report_recording(
+        &self,
+        request: Request<ReportRecordingRequest>,
+    ) -> Result<Response<ReportRecordingResponse>, Status> {
+        let req = request.into_inner();
+
+        Self::validate_handler_id(&req.handler_id)?;
+
+        let meeting_id = Uuid::parse_str(&req.meeting_id)
+            .map_err(|_| Status::invalid_argument("meeting_id must be a UUID"))?;
+        let recording_id = Uuid::parse_str(&req.recording_id)
+            .map_err(|_| Status::invalid_argument("recording_id must be a UUID"))?;
+
+        let key_prefix = format!("recordings/{meeting_id}/");
+        if req.object_key.len() > MAX_OBJECT_KEY_LENGTH
+            || req.object_key.len() <= key_prefix.len()
+            || !req.object_key.starts_with(&key_prefix)
+        {
+            return Err(Status::invalid_argument(
+                "object_key must be under recordings/{meeting_id}/",
+            ));
+        }
+        if !is_valid_content_type(&req.content_type) {
+            return Err(Status::invalid_argument("content_type is invalid"));
+        }
+
+        let size_bytes = i64::try_from(req.size_bytes)
+            .ok()
+            .filter(|size| *size > 0)
+            .ok_or_else(|| Status::invalid_argument("size_bytes is invalid"))?;
+        let duration_seconds = i64::try_from(req.duration_seconds)
+            .map_err(|_| Status::invalid_argument("duration_seconds is invalid"))?;
+        let (Some(started_at), Some(completed_at)) = (
+            DateTime::from_timestamp(req.started_at, 0),
+            DateTime::from_timestamp(req.completed_at, 0),
+        ) else {
+            return Err(Status::invalid_argument("timestamps are invalid"));
+        };
+        if req.started_at <= 0 || completed_at < started_at {
+            return Err(Status::invalid_argument("timestamps are invalid"));
+        }
+
+        find_meeting_by_id(&self.pool, meeting_id)
+            .await
+            .map_err(|e| {
+                if !matches!(e, GcError::NotFound(_)) {
+                    tracing::error!(target: "gc.grpc.mh_service", error = %e, "Failed to look up meeting");
+                }
+                e.to_grpc_status()
+            })?;
+
+        let recording = MeetingRecording {
+            recording_id,
+            meeting_id,
+            media_handler_id: req.handler_id.clone(),
+            object_key: req.object_key,
+            content_type: req.content_type,
+            size_bytes,
+            duration_seconds,
+            started_at,
+            completed_at,
+        };
+
+        let inserted = MeetingRecordingsRepository::insert(&self.pool, &recording)
+            .await
+            .map_err(|e| {
+                tracing::error!(target: "gc.grpc.mh_service", error = %e, "Failed to store recording");
+                e.to_grpc_status()
+            })?;
+
+        tracing::info!(
+            target: "gc.grpc.mh_service",
+            handler_id = %req.handler_id,
+            meeting_id = %meeting_id,
+            recording_id = %recording_id,
+            size_bytes = size_bytes,
+            duplicate = !inserted,
+            "Recording reported"
+        );
+
+        Ok(Response::new(ReportRecordingResponse { accepted: true }))
+    }
 }
 
 #[cfg(test)]
@@ -384,6 +523,7 @@ mod tests {
 #[cfg(test)]
 mod integration_tests {
     use super::*;
+    use proto_gen::dark_tower::internal::v1::BuildVersion;
     use sqlx::PgPool;
 
     #[sqlx::test(migrations = "../../migrations")]
@@ -396,6 +536,7 @@ mod integration_tests {
             webtransport_endpoint: "https://mh:443".to_string(),
             grpc_endpoint: "grpc://mh:50051".to_string(),
             max_streams: 1000,
+            build: None,
         });
 
         let response = service.register_mh(request).await.unwrap();
@@ -428,6 +569,7 @@ mod integration_tests {
             webtransport_endpoint: "https://mh:443".to_string(),
             grpc_endpoint: "grpc://mh:50051".to_string(),
             max_streams: 1000,
+            build: None,
         });
         let result = service.register_mh(request).await;
         assert!(result.is_err());
@@ -440,6 +582,7 @@ mod integration_tests {
             webtransport_endpoint: "https://mh:443".to_string(),
             grpc_endpoint: "grpc://mh:50051".to_string(),
             max_streams: 1000,
+            build: None,
         });
         let result = service.register_mh(request).await;
         assert!(result.is_err());
@@ -451,11 +594,61 @@ mod integration_tests {
             webtransport_endpoint: "https://mh:443".to_string(),
             grpc_endpoint: "grpc://mh:50051".to_string(),
             max_streams: 0,
+            build: None,
         });
         let result = service.register_mh(request).await;
         assert!(result.is_err());
     }
 
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_register_mh_records_build_and_enforces_minimum(pool: PgPool) {
+        let service = MhService::new(Arc::new(pool.clone()))
+            .with_min_fleet_version(ReleaseVersion::parse("0.2.0"));
+        let request = |handler_id: &str, version: Option<&str>| {
+            Request::new(RegisterMhRequest {
+                handler_id: handler_id.to_string(),
+                region: "us-east-1".to_string(),
+                webtransport_endpoint: "https://mh:443".to_string(),
+                grpc_endpoint: "grpc://mh:50051".to_string(),
+                max_streams: 1000,
+                build: version.map(|version| BuildVersion {
+                    version: version.to_string(),
+                    git_sha: "3f9c2e1".to_string(),
+                    proto_schema: "9b1e4c0d2a7f3865".to_string(),
+                }),
+            })
+        };
+
+        let response = service
+            .register_mh(request("mh-current", Some("0.2.1")))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(response.accepted);
+        let handler = MediaHandlersRepository::get_handler(&pool, "mh-current")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(handler.version.as_deref(), Some("0.2.1"));
+        assert_eq!(handler.git_sha.as_deref(), Some("3f9c2e1"));
+        assert_eq!(handler.proto_schema.as_deref(), Some("9b1e4c0d2a7f3865"));
+
+        // Too old, or too old to report a version: rejected before anything is stored
+        for (handler_id, version) in [("mh-old", Some("0.1.0")), ("mh-unversioned", None)] {
+            let response = service
+                .register_mh(request(handler_id, version))
+                .await
+                .unwrap()
+                .into_inner();
+            assert!(!response.accepted, "{handler_id}");
+            assert!(response.message.contains("0.2.0"));
+            assert!(MediaHandlersRepository::get_handler(&pool, handler_id)
+                .await
+                .unwrap()
+                .is_none());
+        }
+    }
+
     #[sqlx::test(migrations = "../../migrations")]
     async fn test_send_load_report_success(pool: PgPool) {
         let service = MhService::new(Arc::new(pool.clone()));
@@ -533,4 +726,96 @@ mod integration_tests {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
     }
+
+    fn recording_request(meeting_id: Uuid, recording_id: Uuid) -> ReportRecordingRequest {
+        ReportRecordingRequest {
+            handler_id: "rec-mh".to_string(),
+            meeting_id: meeting_id.to_string(),
+            recording_id: recording_id.to_string(),
+            object_key: format!("recordings/{meeting_id}/{recording_id}.webm"),
+            content_type: "video/webm".to_string(),
+            size_bytes: 4096,
+            duration_seconds: 60,
+            started_at: 1_700_000_000,
+            completed_at: 1_700_000_060,
+        }
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_report_recording_stores_once(pool: PgPool) {
+        let service = MhService::new(Arc::new(pool.clone()));
+
+        let org_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO organizations (subdomain, display_name) VALUES ('rec-org', 'Rec Org') RETURNING org_id",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let host_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO users (org_id, email, password_hash, display_name) VALUES ($1, 'host@rec.test', 'hash', 'Host') RETURNING user_id",
+        )
+        .bind(org_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let meeting_id: Uuid = sqlx::query_scalar(
+            r#"
+            INSERT INTO meetings (org_id, created_by_user_id, display_name, meeting_code, join_token_secret)
+            VALUES ($1, $2, 'Recorded', 'REC001', 'secret')
+            RETURNING meeting_id
+            "#,
+        )
+        .bind(org_id)
+        .bind(host_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let recording_id = Uuid::new_v4();
+        for _ in 0..2 {
+            let response = service
+                .report_recording(Request::new(recording_request(meeting_id, recording_id)))
+                .await
+                .unwrap();
+            assert!(response.into_inner().accepted);
+        }
+
+        let recordings = MeetingRecordingsRepository::list_for_meeting(&pool, meeting_id)
+            .await
+            .unwrap();
+        assert_eq!(recordings.len(), 1);
+        assert_eq!(recordings[0].recording_id, recording_id);
+        assert_eq!(recordings[0].media_handler_id, "rec-mh");
+        assert_eq!(recordings[0].duration_seconds, 60);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_report_recording_validation(pool: PgPool) {
+        let service = MhService::new(Arc::new(pool));
+        let meeting_id = Uuid::new_v4();
+
+        // Unknown meeting
+        let result = service
+            .report_recording(Request::new(recording_request(meeting_id, Uuid::new_v4())))
+            .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::NotFound);
+
+        // Object key outside the meeting's prefix
+        let mut request = recording_request(meeting_id, Uuid::new_v4());
+        request.object_key = format!("recordings/{}/x.webm", Uuid::new_v4());
+        let result = service.report_recording(Request::new(request)).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        // Completed before started
+        let mut request = recording_request(meeting_id, Uuid::new_v4());
+        request.completed_at = request.started_at - 1;
+        let result = service.report_recording(Request::new(request)).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+        // Empty file
+        let mut request = recording_request(meeting_id, Uuid::new_v4());
+        request.size_bytes = 0;
+        let result = service.report_recording(Request::new(request)).await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+    }
 }
@@ -1,11 +1,14 @@
 //! gRPC services for Global Controller.
 //!
-//! Provides gRPC endpoints for Meeting Controller and Media Handler registration.
+//! Provides gRPC endpoints for Meeting Controller and Media Handler registration,
+//! plus `BuildInfoService.GetVersion` for any caller type.
 //! All gRPC requests require JWT authentication via the auth layer.
 
 pub mod auth_layer;
+pub mod build_info_service;
 pub mod mc_service;
 pub mod mh_service;
 
+pub use build_info_service::GcBuildInfoService;
 pub use mc_service::McService;
 pub use mh_service::MhService;
@@ -0,0 +1,409 @@
+//! Fleet admin handlers for Global Controller.
+//!
+//! Implements:
+//!
+//! - `GET /api/v1/admin/controllers` - List registered MCs
+//! - `POST /api/v1/admin/controllers/{id}/cordon` - Cordon an MC
+//! - `POST /api/v1/admin/controllers/{id}/uncordon` - Uncordon an MC
+//! - `GET /api/v1/admin/handlers` - List registered MHs
+//! - `POST /api/v1/admin/handlers/{id}/cordon` - Cordon an MH
+//! - `POST /api/v1/admin/handlers/{id}/uncordon` - Uncordon an MH
+//! - `GET /api/v1/admin/versions` - MC/MH version distribution
+//!
+//! Cordoning takes a controller or handler out of selection for new
+//! meetings ahead of maintenance. Meetings it already serves are left to
+//! finish; once its load drains to zero it can be taken down.
+//!
+//! The version distribution shows how far a rolling deploy has got, and
+//! which instances were built against a different protobuf schema than GC.
+//!
+//! # Security
+//!
+//! - Service authenticated, and the token must carry [`FLEET_ADMIN_SCOPE`]
+//! - Cordon state changes are logged
+
+use crate::auth::Claims;
+use crate::errors::GcError;
+use crate::models::{
+    AdminControllerResponse, AdminHandlerResponse, FleetVersionCount, FleetVersionsResponse,
+    ListControllersResponse, ListHandlersResponse,
+};
+use crate::repositories::{
+    ControllerRegistryStore, HandlerRegistryStore, MediaHandler, MediaHandlersRepository,
+    MeetingController, MeetingControllersRepository, VersionCount,
+};
+use crate::routes::AppState;
+use crate::services::fleet_versions;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+/// Scope required for the fleet admin endpoints.
+pub const FLEET_ADMIN_SCOPE: &str = "admin:fleet";
+
+/// Require the caller's token to carry [`FLEET_ADMIN_SCOPE`].
+fn require_fleet_admin(claims: &Claims) -> Result<(), GcError> {
+    if claims.has_scope(FLEET_ADMIN_SCOPE) {
+        return Ok(());
+    }
+
+    warn!(
+        target: "gc.handlers.admin",
+        "Fleet admin request without required scope"
+    );
+    Err(GcError::Forbidden(format!(
+        "Requires the {} scope",
+        FLEET_ADMIN_SCOPE
+    )))
+}
+
+fn controller_response(mc: MeetingController) -> AdminControllerResponse {
+    AdminControllerResponse {
+        controller_id: mc.controller_id,
+        region: mc.region,
+        pool: mc.pool,
+        health_status: mc.health_status.as_db_str().to_string(),
+        current_meetings: mc.current_meetings,
+        max_meetings: mc.max_meetings,
+        standby_for: mc.standby_for,
+        cordoned_at: mc.cordoned_at,
+        version: mc.version,
+        git_sha: mc.git_sha,
+        proto_schema: mc.proto_schema,
+        last_heartbeat_at: mc.last_heartbeat_at,
+    }
+}
+
+fn handler_response(mh: MediaHandler) -> AdminHandlerResponse {
+    AdminHandlerResponse {
+        handler_id: mh.handler_id,
+        region: mh.region,
+        health_status: mh.health_status.as_db_str().to_string(),
+        current_streams: mh.current_streams,
+        max_streams: mh.max_streams,
+        cordoned_at: mh.cordoned_at,
+        version: mh.version,
+        git_sha: mh.git_sha,
+        proto_schema: mh.proto_schema,
+        last_heartbeat_at: mh.last_heartbeat_at,
+    }
+}
+
+fn version_counts(counts: Vec<VersionCount>, gc_proto_schema: &str) -> Vec<FleetVersionCount> {
+    fleet_versions::label_counts(counts)
+        .into_iter()
+        .map(|(version, proto_schema, count)| FleetVersionCount {
+            matches_gc_schema: proto_schema == gc_proto_schema,
+            version,
+            proto_schema,
+            count,
+        })
+        .collect()
+}
+
+/// Handler for GET /api/v1/admin/controllers
+///
+/// # Response
+///
+/// - 200 OK: All registered MCs, including unhealthy and cordoned ones
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: Token lacks the fleet admin scope
+#[instrument(
+    skip_all,
+    name = "gc.admin.list_controllers",
+    fields(
+        method = "GET",
+        endpoint = "/api/v1/admin/controllers",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn list_controllers(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<ListControllersResponse>, GcError> {
+    require_fleet_admin(&claims)?;
+
+    let controllers = state.store.list_controllers().await?;
+
+    Ok(Json(ListControllersResponse {
+        controllers: controllers.into_iter().map(controller_response).collect(),
+    }))
+}
+
+/// Handler for POST /api/v1/admin/controllers/{id}/cordon
+///
+/// # Response
+///
+/// - 204 No Content: MC cordoned (idempotent)
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: Token lacks the fleet admin scope
+/// - 404 Not Found: MC not registered
+#[instrument(
+    skip_all,
+    name = "gc.admin.cordon_controller",
+    fields(
+        method = "POST",
+        endpoint = "/api/v1/admin/controllers/{id}/cordon",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn cordon_controller(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(controller_id): Path<String>,
+) -> Result<StatusCode, GcError> {
+    set_controller_cordoned(&state, &claims, &controller_id, true).await
+}
+
+/// Handler for POST /api/v1/admin/controllers/{id}/uncordon
+///
+/// # Response
+///
+/// - 204 No Content: MC uncordoned (idempotent)
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: Token lacks the fleet admin scope
+/// - 404 Not Found: MC not registered
+#[instrument(
+    skip_all,
+    name = "gc.admin.uncordon_controller",
+    fields(
+        method = "POST",
+        endpoint = "/api/v1/admin/controllers/{id}/uncordon",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn uncordon_controller(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(controller_id): Path<String>,
+) -> Result<StatusCode, GcError> {
+    set_controller_cordoned(&state, &claims, &controller_id, false).await
+}
+
+async fn set_controller_cordoned(
+    state: &AppState,
+    claims: &Claims,
+    controller_id: &str,
+    cordoned: bool,
+) -> Result<StatusCode, GcError> {
+    require_fleet_admin(claims)?;
+
+    if !state
+        .store
+        .set_controller_cordoned(controller_id, cordoned)
+        .await?
+    {
+        return Err(GcError::NotFound(format!(
+            "Meeting controller {} not found",
+            controller_id
+        )));
+    }
+
+    info!(
+        target: "gc.handlers.admin",
+        controller_id = %controller_id,
+        cordoned = cordoned,
+        "Meeting controller cordon state changed"
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Handler for GET /api/v1/admin/handlers
+///
+/// # Response
+///
+/// - 200 OK: All registered MHs, including unhealthy and cordoned ones
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: Token lacks the fleet admin scope
+#[instrument(
+    skip_all,
+    name = "gc.admin.list_handlers",
+    fields(
+        method = "GET",
+        endpoint = "/api/v1/admin/handlers",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn list_handlers(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<ListHandlersResponse>, GcError> {
+    require_fleet_admin(&claims)?;
+
+    let handlers = state.store.list_handlers().await?;
+
+    Ok(Json(ListHandlersResponse {
+        handlers: handlers.into_iter().map(handler_response).collect(),
+    }))
+}
+
+/// Handler for POST /api/v1/admin/handlers/{id}/cordon
+///
+/// # Response
+///
+/// - 204 No Content: MH cordoned (idempotent)
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: Token lacks the fleet admin scope
+/// - 404 Not Found: MH not registered
+#[instrument(
+    skip_all,
+    name = "gc.admin.cordon_handler",
+    fields(
+        method = "POST",
+        endpoint = "/api/v1/admin/handlers/{id}/cordon",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn cordon_handler(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(handler_id): Path<String>,
+) -> Result<StatusCode, GcError> {
+    set_handler_cordoned(&state, &claims, &handler_id, true).await
+}
+
+/// Handler for POST /api/v1/admin/handlers/{id}/uncordon
+///
+/// # Response
+///
+/// - 204 No Content: MH uncordoned (idempotent)
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: Token lacks the fleet admin scope
+/// - 404 Not Found: MH not registered
+#[instrument(
+    skip_all,
+    name = "gc.admin.uncordon_handler",
+    fields(
+        method = "POST",
+        endpoint = "/api/v1/admin/handlers/{id}/uncordon",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn uncordon_handler(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(handler_id): Path<String>,
+) -> Result<StatusCode, GcError> {
+    set_handler_cordoned(&state, &claims, &handler_id, false).await
+}
+
+async fn set_handler_cordoned(
+    state: &AppState,
+    claims: &Claims,
+    handler_id: &str,
+    cordoned: bool,
+) -> Result<StatusCode, GcError> {
+    require_fleet_admin(claims)?;
+
+    if !state
+        .store
+        .set_handler_cordoned(handler_id, cordoned)
+        .await?
+    {
+        return Err(GcError::NotFound(format!(
+            "Media handler {} not found",
+            handler_id
+        )));
+    }
+
+    info!(
+        target: "gc.handlers.admin",
+        handler_id = %handler_id,
+        cordoned = cordoned,
+        "Media handler cordon state changed"
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Handler for GET /api/v1/admin/versions
+///
+/// Counts exclude unhealthy instances, so a finished rolling deploy shows a
+/// single version per controller type.
+///
+/// # Response
+///
+/// - 200 OK: MC and MH counts by reported version and protobuf schema
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: Token lacks the fleet admin scope
+#[instrument(
+    skip_all,
+    name = "gc.admin.list_versions",
+    fields(
+        method = "GET",
+        endpoint = "/api/v1/admin/versions",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn list_versions(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<FleetVersionsResponse>, GcError> {
+    require_fleet_admin(&claims)?;
+
+    let controllers = MeetingControllersRepository::get_version_counts(&state.pool).await?;
+    let handlers = MediaHandlersRepository::get_version_counts(&state.pool).await?;
+
+    Ok(Json(FleetVersionsResponse {
+        gc_version: env!("CARGO_PKG_VERSION").to_string(),
+        gc_proto_schema: proto_gen::SCHEMA_VERSION.to_string(),
+        min_fleet_version: state.config.min_fleet_version.map(|v| v.to_string()),
+        controllers: version_counts(controllers, proto_gen::SCHEMA_VERSION),
+        handlers: version_counts(handlers, proto_gen::SCHEMA_VERSION),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claims_with_scope(scope: &str) -> Claims {
+        Claims {
+            sub: "ops-client".to_string(),
+            exp: 0,
+            iat: 0,
+            scope: scope.to_string(),
+            service_type: None,
+            cnf: None,
+        }
+    }
+
+    #[test]
+    fn test_require_fleet_admin_accepts_scope() {
+        assert!(require_fleet_admin(&claims_with_scope("read admin:fleet")).is_ok());
+    }
+
+    #[test]
+    fn test_require_fleet_admin_rejects_other_scopes() {
+        let result = require_fleet_admin(&claims_with_scope("read write admin:debug"));
+        assert!(matches!(result, Err(GcError::Forbidden(_))));
+    }
+
+    #[test]
+    fn test_version_counts_flags_schema_mismatch() {
+        let counts = vec![
+            VersionCount {
+                version: Some("0.2.0".to_string()),
+                proto_schema: Some("abc".to_string()),
+                count: 2,
+            },
+            VersionCount {
+                version: None,
+                proto_schema: None,
+                count: 1,
+            },
+        ];
+
+        let versions = version_counts(counts, "abc");
+        assert_eq!(versions.len(), 2);
+        assert!(versions[0].matches_gc_schema);
+        assert_eq!(versions[0].count, 2);
+        assert_eq!(versions[1].version, fleet_versions::UNKNOWN_VERSION);
+        assert!(!versions[1].matches_gc_schema);
+    }
+}
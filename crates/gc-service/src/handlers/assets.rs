@@ -0,0 +1,152 @@
+//! Meeting asset upload handler for Global Controller.
+//!
+//! Implements:
+//!
+//! - `POST /api/v1/meetings/{id}/assets` - Issue a pre-signed upload URL
+//!   (user authenticated, meeting members)
+//!
+//! Clients upload the file directly to the object store and then reference
+//! the returned `asset_id` in a data channel message.
+//!
+//! # Security
+//!
+//! - Only users who may join the meeting can upload (host, same-org members,
+//!   or external users when the meeting allows them)
+//! - Object keys are generated server-side; the client file name is metadata only
+//! - Upload URLs are short-lived and signed for the declared content type
+
+use crate::errors::GcError;
+use crate::handlers::meetings::{find_meeting_by_id, parse_user_id};
+use crate::models::{CreateAssetRequest, CreateAssetResponse};
+use crate::repositories::{MeetingAsset, MeetingAssetsRepository};
+use crate::routes::AppState;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::Utc;
+use common::jwt::UserClaims;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+/// Upload URL lifetime (15 minutes).
+const UPLOAD_URL_TTL: Duration = Duration::from_secs(900);
+
+/// Handler for POST /api/v1/meetings/{id}/assets
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "file_name": "diagram.png",
+///   "content_type": "image/png",
+///   "size_bytes": 48213
+/// }
+/// ```
+///
+/// # Response
+///
+/// - 201 Created: Upload URL issued
+/// - 400 Bad Request: Invalid request body
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: User may not join this meeting
+/// - 404 Not Found: Meeting not found or has ended
+/// - 503 Service Unavailable: No object store configured
+#[instrument(
+    skip_all,
+    name = "gc.meeting.create_asset",
+    fields(
+        method = "POST",
+        endpoint = "/api/v1/meetings/{id}/assets",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn create_meeting_asset(
+    State(state): State<Arc<AppState>>,
+    Extension(user_claims): Extension<UserClaims>,
+    Path(meeting_id): Path<Uuid>,
+    body: axum::body::Bytes,
+) -> Result<(StatusCode, Json<CreateAssetResponse>), GcError> {
+    // Deserialize request body manually to return 400 (not Axum's default 422)
+    let request: CreateAssetRequest = serde_json::from_slice(&body).map_err(|e| {
+        tracing::debug!(target: "gc.handlers.assets", error = %e, "Invalid request body");
+        GcError::BadRequest("Invalid request body".to_string())
+    })?;
+    request
+        .validate()
+        .map_err(|msg| GcError::BadRequest(msg.to_string()))?;
+
+    let meeting = find_meeting_by_id(&state.pool, meeting_id).await?;
+    if meeting.status != "active" && meeting.status != "scheduled" {
+        return Err(GcError::NotFound(
+            "Meeting not found or has ended".to_string(),
+        ));
+    }
+
+    let user_id = parse_user_id(&user_claims.sub)?;
+    let user_org_id = Uuid::parse_str(&user_claims.org_id).map_err(|e| {
+        tracing::debug!(target: "gc.handlers.assets", error = %e, "Failed to parse org_id from user token");
+        GcError::InvalidToken("Invalid organization identifier in token".to_string())
+    })?;
+
+    let is_host = meeting.created_by_user_id == user_id;
+    let is_same_org = user_org_id == meeting.org_id;
+    if !is_host && !is_same_org && !meeting.allow_external_participants {
+        warn!(
+            target: "gc.handlers.assets",
+            meeting_id = %meeting_id,
+            user_id = %user_id,
+            "External user denied asset upload"
+        );
+        return Err(GcError::Forbidden(
+            "External participants are not allowed in this meeting".to_string(),
+        ));
+    }
+
+    let object_store = state.object_store.as_ref().ok_or_else(|| {
+        GcError::ServiceUnavailable("File uploads are not configured".to_string())
+    })?;
+
+    let asset_id = Uuid::new_v4();
+    let asset = MeetingAsset {
+        asset_id,
+        meeting_id,
+        uploaded_by_user_id: user_id,
+        file_name: request.file_name.trim().to_string(),
+        content_type: request.content_type,
+        size_bytes: request.size_bytes,
+        object_key: format!("meetings/{meeting_id}/{asset_id}"),
+    };
+    MeetingAssetsRepository::insert(&state.pool, &asset).await?;
+
+    let upload = object_store.presign_upload(
+        &asset.object_key,
+        &asset.content_type,
+        UPLOAD_URL_TTL,
+        Utc::now(),
+    );
+
+    info!(
+        target: "gc.handlers.assets",
+        meeting_id = %meeting_id,
+        asset_id = %asset_id,
+        user_id = %user_id,
+        size_bytes = asset.size_bytes,
+        backend = object_store.backend(),
+        "Asset upload URL issued"
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreateAssetResponse {
+            asset_id,
+            upload_method: upload.method.to_string(),
+            upload_url: upload.url,
+            upload_headers: upload.headers.into_iter().collect(),
+            expires_at: upload.expires_at,
+        }),
+    ))
+}
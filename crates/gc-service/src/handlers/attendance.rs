@@ -0,0 +1,178 @@
+//! Attendance export handler for Global Controller.
+//!
+//! Implements:
+//!
+//! - `GET /api/v1/meetings/{id}/attendance.csv` - Attendance CSV export
+//!   (user authenticated, host only)
+//!
+//! Attendance is flushed by MCs via the `ReportAttendance` RPC when a meeting
+//! ends, so the export is empty (header only) for meetings still in progress.
+//!
+//! # Security
+//!
+//! - Only the meeting host can export attendance
+//! - Cells starting with spreadsheet formula characters are prefixed with `'`
+//!   to prevent CSV injection via display names
+
+use crate::errors::GcError;
+use crate::handlers::meetings::{find_meeting_by_id, parse_user_id};
+use crate::repositories::{AttendanceRecord, AttendanceRepository};
+use crate::routes::AppState;
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+    Extension,
+};
+use chrono::SecondsFormat;
+use common::jwt::UserClaims;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+/// CSV header row.
+const CSV_HEADER: &str =
+    "participant_id,user_id,display_name,joined_at,left_at,duration_seconds,leave_reason\r\n";
+
+/// Handler for GET /api/v1/meetings/{id}/attendance.csv
+///
+/// # Response
+///
+/// - 200 OK: `text/csv` attendance export
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: User is not the host
+/// - 404 Not Found: Meeting not found
+#[instrument(
+    skip_all,
+    name = "gc.meeting.export_attendance",
+    fields(
+        method = "GET",
+        endpoint = "/api/v1/meetings/{id}/attendance.csv",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn export_meeting_attendance(
+    State(state): State<Arc<AppState>>,
+    Extension(user_claims): Extension<UserClaims>,
+    Path(meeting_id): Path<Uuid>,
+) -> Result<impl IntoResponse, GcError> {
+    let meeting = find_meeting_by_id(&state.pool, meeting_id).await?;
+    let user_id = parse_user_id(&user_claims.sub)?;
+
+    if meeting.created_by_user_id != user_id {
+        warn!(
+            target: "gc.handlers.attendance",
+            meeting_id = %meeting_id,
+            user_id = %user_id,
+            "Non-host user attempted to export attendance"
+        );
+        return Err(GcError::Forbidden(
+            "Only the meeting host can export attendance".to_string(),
+        ));
+    }
+
+    let records = AttendanceRepository::list_for_meeting(&state.pool, meeting_id).await?;
+
+    info!(
+        target: "gc.handlers.attendance",
+        meeting_id = %meeting_id,
+        record_count = records.len(),
+        "Attendance exported"
+    );
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"attendance-{meeting_id}.csv\""),
+            ),
+        ],
+        render_csv(&records),
+    ))
+}
+
+/// Render attendance records as RFC 4180 CSV.
+fn render_csv(records: &[AttendanceRecord]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    for record in records {
+        let row = [
+            csv_cell(&record.participant_id),
+            csv_cell(&record.user_id),
+            csv_cell(&record.display_name),
+            record.joined_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            record.left_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            record.duration_seconds().to_string(),
+            csv_cell(&record.leave_reason),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// Escape a single CSV cell.
+///
+/// Quotes cells containing delimiters, quotes, or line breaks, and neutralizes
+/// leading formula characters (`=`, `+`, `-`, `@`, tab, CR).
+fn csv_cell(value: &str) -> String {
+    let neutralized = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+
+    if neutralized.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", neutralized.replace('"', "\"\""))
+    } else {
+        neutralized
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn record(display_name: &str) -> AttendanceRecord {
+        let joined_at = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+        AttendanceRecord {
+            participant_id: "part-1".to_string(),
+            user_id: "user-1".to_string(),
+            display_name: display_name.to_string(),
+            joined_at,
+            left_at: joined_at + chrono::Duration::minutes(45),
+            leave_reason: "voluntary".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_csv_header_only_when_empty() {
+        assert_eq!(render_csv(&[]), CSV_HEADER);
+    }
+
+    #[test]
+    fn test_render_csv_row() {
+        let csv = render_csv(&[record("Ada Lovelace")]);
+        assert_eq!(
+            csv.lines().nth(1),
+            Some("part-1,user-1,Ada Lovelace,2026-03-01T09:00:00Z,2026-03-01T09:45:00Z,2700,voluntary")
+        );
+    }
+
+    #[test]
+    fn test_csv_cell_quotes_delimiters() {
+        assert_eq!(csv_cell("Smith, Jane"), "\"Smith, Jane\"");
+        assert_eq!(csv_cell("The \"Boss\""), "\"The \"\"Boss\"\"\"");
+        assert_eq!(csv_cell("line\nbreak"), "\"line\nbreak\"");
+    }
+
+    #[test]
+    fn test_csv_cell_neutralizes_formulas() {
+        assert_eq!(csv_cell("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+        assert_eq!(csv_cell("+1234"), "'+1234");
+        assert_eq!(csv_cell("@handle"), "'@handle");
+        assert_eq!(csv_cell("plain"), "plain");
+    }
+}
@@ -0,0 +1,88 @@
+//! Client error report handler for Global Controller.
+//!
+//! Implements:
+//!
+//! - `POST /api/v1/client-errors` - Report a client-side failure
+//!   (user authenticated)
+//!
+//! Clients report failures the servers cannot see (join failed before reaching
+//! an MC, transport fallback, unsupported codec). Reports feed
+//! `gc_client_errors_total` for dashboards.
+//!
+//! # Cardinality
+//!
+//! - `kind` and `platform` are closed enums; unknown values are rejected
+//! - Clients sample high-volume failures and send their `sample_rate`; each
+//!   report is counted `1 / sample_rate` times
+//! - Error codes and meeting IDs are logged only, never used as labels
+
+use crate::errors::GcError;
+use crate::handlers::meetings::parse_user_id;
+use crate::models::ClientErrorReport;
+use crate::observability::metrics;
+use axum::{http::StatusCode, Extension};
+use common::jwt::UserClaims;
+use tracing::{info, instrument};
+
+/// Handler for POST /api/v1/client-errors
+///
+/// # Request Body
+///
+/// ```json
+/// {
+///   "kind": "transport_fallback",
+///   "platform": "web",
+///   "error_code": "DT-MC-2022",
+///   "meeting_id": "550e8400-e29b-41d4-a716-446655440000",
+///   "sample_rate": 0.1
+/// }
+/// ```
+///
+/// # Response
+///
+/// - 202 Accepted: Report recorded
+/// - 400 Bad Request: Invalid request body
+/// - 401 Unauthorized: Invalid or missing token
+#[instrument(
+    skip_all,
+    name = "gc.client_errors.report",
+    fields(
+        method = "POST",
+        endpoint = "/api/v1/client-errors",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn report_client_error(
+    Extension(user_claims): Extension<UserClaims>,
+    body: axum::body::Bytes,
+) -> Result<StatusCode, GcError> {
+    // Deserialize request body manually to return 400 (not Axum's default 422)
+    let report: ClientErrorReport = serde_json::from_slice(&body).map_err(|e| {
+        tracing::debug!(target: "gc.handlers.client_errors", error = %e, "Invalid request body");
+        GcError::BadRequest("Invalid request body".to_string())
+    })?;
+    report
+        .validate()
+        .map_err(|msg| GcError::BadRequest(msg.to_string()))?;
+
+    let user_id = parse_user_id(&user_claims.sub)?;
+
+    metrics::record_client_error(
+        report.kind.as_str(),
+        report.platform.as_str(),
+        report.weight(),
+    );
+
+    info!(
+        target: "gc.handlers.client_errors",
+        kind = report.kind.as_str(),
+        platform = report.platform.as_str(),
+        error_code = report.error_code.as_deref().unwrap_or("none"),
+        meeting_id = ?report.meeting_id,
+        user_id = %user_id,
+        sample_rate = report.sample_rate,
+        "Client error reported"
+    );
+
+    Ok(StatusCode::ACCEPTED)
+}
@@ -0,0 +1,366 @@
+//! Bulk meeting import handlers for Global Controller.
+//!
+//! Implements:
+//!
+//! - `POST /api/v1/meeting-imports` - Submit a CSV or JSON import (org admin)
+//! - `GET /api/v1/meeting-imports/{id}` - Import job status (org admin)
+//!
+//! Every record is validated before a job is created, so a malformed import
+//! is rejected with its per-record errors and creates nothing. Accepted
+//! imports are stored as a job and created all-or-nothing by the import
+//! worker (`tasks::meeting_imports`).
+//!
+//! # Security
+//!
+//! - Only `admin` and `org_admin` roles can import or read import jobs
+//! - Jobs are scoped to the caller's org; other orgs' jobs return 404
+//! - Imported meetings are owned by the submitting admin and subject to the
+//!   org's participant and concurrent meeting limits
+
+use crate::errors::GcError;
+use crate::handlers::meetings::parse_user_id;
+use crate::models::{
+    ImportedMeeting, MeetingImportRecord, MeetingImportRequest, MeetingImportResponse,
+    MeetingImportStatus, MAX_MEETING_IMPORT_RECORDS,
+};
+use crate::observability::metrics;
+use crate::repositories::{MeetingImportJob, MeetingImportsRepository};
+use crate::routes::AppState;
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::{header, HeaderMap, StatusCode},
+    Extension, Json,
+};
+use common::jwt::UserClaims;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+/// Roles allowed to import meetings.
+const MEETING_IMPORT_ROLES: &[&str] = &["admin", "org_admin"];
+
+/// Maximum record errors listed in a rejection message.
+const MAX_REPORTED_RECORD_ERRORS: usize = 20;
+
+/// Handler for POST /api/v1/meeting-imports
+///
+/// Accepts `text/csv` (header row naming `MeetingImportRecord` fields) or
+/// JSON (`{"meetings": [...]}`).
+///
+/// # Response
+///
+/// - 202 Accepted: Import job created (`status: running`)
+/// - 400 Bad Request: Unparseable body or invalid records
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: Caller is not an org admin
+#[instrument(
+    skip_all,
+    name = "gc.meeting_import.create",
+    fields(
+        method = "POST",
+        endpoint = "/api/v1/meeting-imports",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn import_meetings(
+    State(state): State<Arc<AppState>>,
+    Extension(user_claims): Extension<UserClaims>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<(StatusCode, Json<MeetingImportResponse>), GcError> {
+    require_import_role(&user_claims)?;
+    let (org_id, user_id) = parse_caller(&user_claims)?;
+
+    let records = parse_records(&headers, &body)
+        .and_then(|records| validate_records(&records).map(|()| records))
+        .map_err(|e| {
+            metrics::record_meeting_import("rejected", 0);
+            GcError::BadRequest(e)
+        })?;
+
+    let job = MeetingImportsRepository::create_job(&state.pool, org_id, user_id, &records).await?;
+
+    info!(
+        target: "gc.handlers.meeting_imports",
+        job_id = %job.job_id,
+        org_id = %org_id,
+        total_records = job.total_records,
+        "Meeting import accepted"
+    );
+
+    Ok((StatusCode::ACCEPTED, Json(job_response(job, Vec::new()))))
+}
+
+/// Handler for GET /api/v1/meeting-imports/{id}
+///
+/// # Response
+///
+/// - 200 OK: Job status; lists created meetings once `succeeded`
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: Caller is not an org admin
+/// - 404 Not Found: No such job in the caller's org
+#[instrument(
+    skip_all,
+    name = "gc.meeting_import.get",
+    fields(
+        method = "GET",
+        endpoint = "/api/v1/meeting-imports/{id}",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn get_meeting_import(
+    State(state): State<Arc<AppState>>,
+    Extension(user_claims): Extension<UserClaims>,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<MeetingImportResponse>, GcError> {
+    require_import_role(&user_claims)?;
+    let (org_id, _) = parse_caller(&user_claims)?;
+
+    let job = MeetingImportsRepository::get_job(&state.pool, org_id, job_id)
+        .await?
+        .ok_or_else(|| GcError::NotFound("Import not found".to_string()))?;
+
+    let meetings = if job.status == MeetingImportStatus::Succeeded {
+        MeetingImportsRepository::list_imported_meetings(&state.pool, job_id).await?
+    } else {
+        Vec::new()
+    };
+
+    Ok(Json(job_response(job, meetings)))
+}
+
+fn require_import_role(user_claims: &UserClaims) -> Result<(), GcError> {
+    let has_required_role = user_claims
+        .roles
+        .iter()
+        .any(|r| MEETING_IMPORT_ROLES.contains(&r.as_str()));
+
+    if !has_required_role {
+        warn!(
+            target: "gc.handlers.meeting_imports",
+            user_id = %user_claims.sub,
+            roles = ?user_claims.roles,
+            "User lacks required role for meeting import"
+        );
+        return Err(GcError::Forbidden(
+            "Insufficient permissions to import meetings".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Parse (org_id, user_id) from the caller's token.
+fn parse_caller(user_claims: &UserClaims) -> Result<(Uuid, Uuid), GcError> {
+    let user_id = parse_user_id(&user_claims.sub)?;
+    let org_id = Uuid::parse_str(&user_claims.org_id).map_err(|e| {
+        tracing::debug!(target: "gc.handlers.meeting_imports", error = %e, "Failed to parse org_id from token");
+        GcError::InvalidToken("Invalid organization identifier in token".to_string())
+    })?;
+    Ok((org_id, user_id))
+}
+
+/// Parse the body as CSV or JSON depending on `Content-Type`.
+fn parse_records(headers: &HeaderMap, body: &[u8]) -> Result<Vec<MeetingImportRecord>, String> {
+    let is_csv = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.trim_start().starts_with("text/csv"));
+
+    if is_csv {
+        parse_csv(body)
+    } else {
+        serde_json::from_slice::<MeetingImportRequest>(body)
+            .map(|request| request.meetings)
+            .map_err(|e| {
+                tracing::debug!(target: "gc.handlers.meeting_imports", error = %e, "Invalid request body");
+                "Invalid request body".to_string()
+            })
+    }
+}
+
+/// Parse CSV rows. Empty cells are treated as absent fields.
+fn parse_csv(body: &[u8]) -> Result<Vec<MeetingImportRecord>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(body);
+
+    let mut records = Vec::new();
+    for (index, row) in reader.deserialize::<MeetingImportRecord>().enumerate() {
+        if records.len() >= MAX_MEETING_IMPORT_RECORDS {
+            return Err(too_many_records());
+        }
+        let record = row.map_err(|e| {
+            tracing::debug!(target: "gc.handlers.meeting_imports", error = %e, "Invalid CSV row");
+            format!("Record {}: invalid CSV row", index + 1)
+        })?;
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+fn too_many_records() -> String {
+    format!("Import must contain at most {MAX_MEETING_IMPORT_RECORDS} meetings")
+}
+
+/// Validate every record; external IDs must be unique within the import.
+///
+/// Returns all record errors (up to `MAX_REPORTED_RECORD_ERRORS`) in one
+/// message, with records numbered from 1.
+fn validate_records(records: &[MeetingImportRecord]) -> Result<(), String> {
+    if records.is_empty() {
+        return Err("Import must contain at least one meeting".to_string());
+    }
+    if records.len() > MAX_MEETING_IMPORT_RECORDS {
+        return Err(too_many_records());
+    }
+
+    let mut external_ids = HashSet::new();
+    let mut errors = Vec::new();
+    for (index, record) in records.iter().enumerate() {
+        let result = record.validate().and_then(|()| match &record.external_id {
+            Some(id) if !external_ids.insert(id.as_str()) => Err("Duplicate external ID"),
+            _ => Ok(()),
+        });
+        if let Err(e) = result {
+            errors.push(format!("Record {}: {}", index + 1, e));
+        }
+    }
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let total = errors.len();
+    errors.truncate(MAX_REPORTED_RECORD_ERRORS);
+    let mut message = errors.join("; ");
+    if total > MAX_REPORTED_RECORD_ERRORS {
+        message.push_str(&format!(
+            " (and {} more)",
+            total - MAX_REPORTED_RECORD_ERRORS
+        ));
+    }
+    Err(message)
+}
+
+fn job_response(job: MeetingImportJob, meetings: Vec<ImportedMeeting>) -> MeetingImportResponse {
+    MeetingImportResponse {
+        job_id: job.job_id,
+        status: job.status,
+        total_records: job.total_records,
+        imported_records: job.imported_records,
+        error: job.error,
+        created_at: job.created_at,
+        completed_at: job.completed_at,
+        meetings,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::models::MeetingPriority;
+    use axum::http::HeaderValue;
+
+    fn json_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        headers
+    }
+
+    fn csv_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("text/csv; charset=utf-8"),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_parse_csv_with_optional_columns() {
+        let body = "external_id,display_name,max_participants,priority,allow_guests\n\
+                    zoom-1,Weekly Sync,50,webinar,\n\
+                    zoom-2, All Hands ,,,true\n";
+
+        let records = parse_records(&csv_headers(), body.as_bytes()).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].external_id.as_deref(), Some("zoom-1"));
+        assert_eq!(records[0].max_participants, Some(50));
+        assert_eq!(records[0].priority, Some(MeetingPriority::Webinar));
+        assert_eq!(records[0].allow_guests, None);
+        assert_eq!(records[1].display_name, "All Hands");
+        assert_eq!(records[1].max_participants, None);
+        assert_eq!(records[1].allow_guests, Some(true));
+    }
+
+    #[test]
+    fn test_parse_csv_rejects_unknown_column_and_bad_value() {
+        let unknown = "display_name,host_email\nSync,a@example.com\n";
+        assert_eq!(
+            parse_records(&csv_headers(), unknown.as_bytes()).unwrap_err(),
+            "Record 1: invalid CSV row"
+        );
+
+        let bad = "display_name,max_participants\nSync,10\nStandup,lots\n";
+        assert_eq!(
+            parse_records(&csv_headers(), bad.as_bytes()).unwrap_err(),
+            "Record 2: invalid CSV row"
+        );
+    }
+
+    #[test]
+    fn test_parse_json_body() {
+        let body = r#"{"meetings":[{"display_name":"Sync","external_id":"a"}]}"#;
+        let records = parse_records(&json_headers(), body.as_bytes()).unwrap();
+        assert_eq!(records.len(), 1);
+
+        assert_eq!(
+            parse_records(&json_headers(), b"not json").unwrap_err(),
+            "Invalid request body"
+        );
+    }
+
+    #[test]
+    fn test_validate_records_lists_errors_by_record() {
+        let body = r#"{"meetings":[
+            {"display_name":"Sync","external_id":"a"},
+            {"display_name":"  "},
+            {"display_name":"Standup","external_id":"a"},
+            {"display_name":"Tiny","max_participants":1}
+        ]}"#;
+        let records = parse_records(&json_headers(), body.as_bytes()).unwrap();
+
+        assert_eq!(
+            validate_records(&records).unwrap_err(),
+            "Record 2: Display name is required; \
+             Record 3: Duplicate external ID; \
+             Record 4: Maximum participants must be at least 2"
+        );
+    }
+
+    #[test]
+    fn test_validate_records_bounds() {
+        assert_eq!(
+            validate_records(&[]).unwrap_err(),
+            "Import must contain at least one meeting"
+        );
+
+        let body = format!(
+            r#"{{"meetings":[{}]}}"#,
+            vec![r#"{"display_name":""}"#; MAX_REPORTED_RECORD_ERRORS + 3].join(",")
+        );
+        let records = parse_records(&json_headers(), body.as_bytes()).unwrap();
+        assert!(validate_records(&records)
+            .unwrap_err()
+            .ends_with("(and 3 more)"));
+    }
+}
@@ -4,8 +4,10 @@
 //!
 //! - `POST /api/v1/meetings` - Create meeting (user authenticated)
 //! - `GET /api/v1/meetings/{code}` - Join meeting (user authenticated)
+//! - `GET /api/v1/meetings/{code}/preflight` - Pre-join readiness check (user authenticated)
 //! - `POST /api/v1/meetings/{code}/guest-token` - Get guest token (public)
 //! - `PATCH /api/v1/meetings/{id}/settings` - Update meeting settings (user authenticated)
+//! - `GET /api/v1/meetings/{id}/report` - Post-meeting report (user authenticated, host only)
 //!
 //! # Security
 //!
@@ -15,19 +17,24 @@
 //! - Error messages are generic to prevent information leakage
 
 use crate::errors::GcError;
+use crate::events::GcEvent;
 use crate::models::{
     CreateMeetingRequest, CreateMeetingResponse, GuestJoinRequest, JoinMeetingResponse,
-    McAssignmentInfo, MeetingResponse, MeetingRow, UpdateMeetingSettingsRequest,
-    DEFAULT_MAX_PARTICIPANTS, MIN_PARTICIPANTS,
+    McAssignmentInfo, MeetingReportResponse, MeetingResponse, MeetingRow, PreflightMediaHandler,
+    PreflightResponse, RelayInfo, UpdateMeetingSettingsRequest, DEFAULT_MAX_PARTICIPANTS,
+    MIN_PARTICIPANTS,
 };
 use crate::observability::metrics;
-use crate::repositories::{map_row_to_meeting, McAssignment, MeetingsRepository};
+use crate::repositories::{
+    map_row_to_meeting, EventOutboxRepository, HandlerRegistryStore, McAssignment,
+    MeetingReportsRepository, MeetingsRepository,
+};
 use crate::routes::AppState;
 use crate::services::ac_client::{
     AcClient, GuestTokenRequest, MeetingRole, MeetingTokenRequest, ParticipantType, TokenResponse,
 };
 use crate::services::mc_assignment::AssignmentWithMh;
-use crate::services::McAssignmentService;
+use crate::services::{ExperimentService, ExperimentSubject, McAssignmentService};
 use axum::{
     extract::{Path, State},
     http::StatusCode,
@@ -36,6 +43,7 @@ use axum::{
 use common::jwt::UserClaims;
 use ring::rand::{SecureRandom, SystemRandom};
 use sqlx::PgPool;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::{info, instrument, warn};
@@ -65,6 +73,10 @@ const MAX_CODE_COLLISION_RETRIES: usize = 3;
 /// Length of join token secret in bytes (256 bits).
 const JOIN_TOKEN_SECRET_BYTES: usize = 32;
 
+/// Maximum media handlers listed in a preflight response (matches the
+/// number of MH peers selected at join).
+const PREFLIGHT_MAX_MEDIA_HANDLERS: usize = 2;
+
 impl From<McAssignment> for McAssignmentInfo {
     fn from(mc: McAssignment) -> Self {
         Self {
@@ -76,11 +88,14 @@ impl From<McAssignment> for McAssignmentInfo {
 }
 
 impl JoinMeetingResponse {
-    /// Construct a join response from a token, meeting, and MC assignment.
+    /// Construct a join response from a token, meeting, MC assignment, join
+    /// correlation ID, and the joiner's experiment variants.
     pub fn new(
         token_response: TokenResponse,
         meeting: MeetingRow,
         assignment_with_mh: AssignmentWithMh,
+        join_id: Uuid,
+        experiments: BTreeMap<String, String>,
     ) -> Self {
         Self {
             token: token_response.token,
@@ -88,6 +103,8 @@ impl JoinMeetingResponse {
             meeting_id: meeting.meeting_id,
             meeting_name: meeting.display_name,
             mc_assignment: assignment_with_mh.mc_assignment.into(),
+            join_id,
+            experiments,
         }
     }
 }
@@ -224,6 +241,7 @@ pub async fn create_meeting(
             allow_external_participants,
             waiting_room_enabled,
             request.scheduled_start_time,
+            request.priority.unwrap_or_default(),
         )
         .await
         {
@@ -289,6 +307,7 @@ pub async fn create_meeting(
     }
 
     // 9. Record success metrics (R-10)
+    //    MeetingCreated was enqueued to the event outbox with the INSERT
     let duration = start.elapsed();
     metrics::record_meeting_creation("success", None, duration);
 
@@ -415,6 +434,9 @@ pub async fn join_meeting(
         let duration = start.elapsed();
         metrics::record_meeting_join("user", "error", Some("mc_assignment"), duration);
     })?;
+    // Join correlation ID: its UUIDv7 timestamp marks the assignment, and the
+    // MC measures join latency from it
+    let join_id = Uuid::now_v7();
 
     // Create AC client and request meeting token
     let ac_client = create_ac_client(&state).inspect_err(|_| {
@@ -443,6 +465,14 @@ pub async fn join_meeting(
             metrics::record_meeting_join("user", "error", Some("ac_request"), duration);
         })?;
 
+    let experiments = ExperimentService::assign_for_join(
+        &state.pool,
+        meeting.meeting_id,
+        meeting.org_id,
+        ExperimentSubject::User(user_id),
+    )
+    .await;
+
     // Record success metrics
     let duration = start.elapsed();
     metrics::record_meeting_join("user", "success", None, duration);
@@ -459,6 +489,7 @@ pub async fn join_meeting(
         user_id = %user_id,
         mc_id = %assignment_with_mh.mc_assignment.mc_id,
         mh_ids = ?mh_ids,
+        join_id = %join_id,
         participant_type = ?participant_type,
         "User joined meeting"
     );
@@ -467,9 +498,111 @@ pub async fn join_meeting(
         token_response,
         meeting,
         assignment_with_mh,
+        join_id,
+        experiments,
     )))
 }
 
+// ============================================================================
+// Handler: GET /v1/meetings/{code}/preflight
+// ============================================================================
+
+/// Handler for GET /v1/meetings/{code}/preflight
+///
+/// Pre-join readiness check. Applies the same access rules as join and
+/// reports what the client needs before the user clicks join: MC and MH
+/// endpoints, the minimum protocol version, TURN relay availability, and
+/// meeting features. Read only: the meeting is not assigned to an MC and no
+/// token is issued.
+///
+/// # Response
+///
+/// - 200 OK: Readiness report (check `ready`)
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: User not allowed to join
+/// - 404 Not Found: Meeting not found
+#[instrument(
+    skip_all,
+    name = "gc.meeting.preflight",
+    fields(
+        method = "GET",
+        endpoint = "/api/v1/meetings/{code}/preflight",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn preflight_meeting(
+    State(state): State<Arc<AppState>>,
+    Extension(user_claims): Extension<UserClaims>,
+    Path(code): Path<String>,
+) -> Result<Json<PreflightResponse>, GcError> {
+    let meeting = find_meeting_by_code(&state.pool, &code).await?;
+
+    if meeting.status != "active" && meeting.status != "scheduled" {
+        return Err(GcError::NotFound(
+            "Meeting not found or has ended".to_string(),
+        ));
+    }
+
+    let user_org_id = Uuid::parse_str(&user_claims.org_id).map_err(|e| {
+        tracing::debug!(target: "gc.handlers.meetings", error = %e, "Failed to parse org_id from user token");
+        GcError::InvalidToken("Invalid organization identifier in token".to_string())
+    })?;
+
+    if user_org_id != meeting.org_id && !meeting.allow_external_participants {
+        return Err(GcError::Forbidden(
+            "External participants are not allowed in this meeting".to_string(),
+        ));
+    }
+
+    let probe =
+        McAssignmentService::probe_capacity(&state.pool, meeting.meeting_id, &state.config.region)
+            .await?;
+
+    let media_handlers: Vec<PreflightMediaHandler> = state
+        .store
+        .get_candidate_mhs(&state.config.region)
+        .await?
+        .into_iter()
+        .take(PREFLIGHT_MAX_MEDIA_HANDLERS)
+        .map(|mh| PreflightMediaHandler {
+            mh_id: mh.handler_id,
+            webtransport_endpoint: mh.webtransport_endpoint,
+        })
+        .collect();
+
+    let ready = probe.capacity_available && !media_handlers.is_empty();
+    if !ready {
+        warn!(
+            target: "gc.handlers.meetings",
+            meeting_id = %meeting.meeting_id,
+            mc_capacity_available = probe.capacity_available,
+            mh_count = media_handlers.len(),
+            "Preflight found meeting not ready to join"
+        );
+    }
+
+    let turn_servers = state.config.turn_servers.clone();
+    let features = BTreeMap::from([
+        ("e2e_encryption".to_string(), meeting.enable_e2e_encryption),
+        ("recording".to_string(), meeting.recording_enabled),
+        ("waiting_room".to_string(), meeting.waiting_room_enabled),
+    ]);
+
+    Ok(Json(PreflightResponse {
+        meeting_id: meeting.meeting_id,
+        ready,
+        mc_assignment: probe.assignment.map(McAssignmentInfo::from),
+        mc_capacity_available: probe.capacity_available,
+        media_handlers,
+        min_protocol_version: state.config.min_client_protocol_version,
+        relay: RelayInfo {
+            available: !turn_servers.is_empty(),
+            turn_servers,
+        },
+        features,
+    }))
+}
+
 // ============================================================================
 // Handler: POST /v1/meetings/{code}/guest-token
 // ============================================================================
@@ -601,6 +734,9 @@ pub async fn get_guest_token(
         let duration = start.elapsed();
         metrics::record_meeting_join("guest", "error", Some("mc_assignment"), duration);
     })?;
+    // Join correlation ID: its UUIDv7 timestamp marks the assignment, and the
+    // MC measures join latency from it
+    let join_id = Uuid::now_v7();
 
     // Create AC client and request guest token.
     //
@@ -632,6 +768,14 @@ pub async fn get_guest_token(
             metrics::record_meeting_join("guest", "error", Some("ac_request"), duration);
         })?;
 
+    let experiments = ExperimentService::assign_for_join(
+        &state.pool,
+        meeting.meeting_id,
+        meeting.org_id,
+        ExperimentSubject::Guest(guest_id),
+    )
+    .await;
+
     // Record success metrics
     let duration = start.elapsed();
     metrics::record_meeting_join("guest", "success", None, duration);
@@ -648,6 +792,7 @@ pub async fn get_guest_token(
         guest_id = %guest_id,
         mc_id = %assignment_with_mh.mc_assignment.mc_id,
         mh_ids = ?mh_ids,
+        join_id = %join_id,
         waiting_room = meeting.waiting_room_enabled,
         "Guest joined meeting"
     );
@@ -656,6 +801,8 @@ pub async fn get_guest_token(
         token_response,
         meeting,
         assignment_with_mh,
+        join_id,
+        experiments,
     )))
 }
 
@@ -673,7 +820,9 @@ pub async fn get_guest_token(
 /// {
 ///   "allow_guests": true,
 ///   "allow_external_participants": false,
-///   "waiting_room_enabled": true
+///   "waiting_room_enabled": true,
+///   "duplicate_join_policy": "takeover",
+///   "recording_layout": "speaker"
 /// }
 /// ```
 ///
@@ -725,8 +874,9 @@ pub async fn update_meeting_settings(
         ));
     }
 
-    // Update meeting settings
-    let updated_meeting = update_meeting_settings_in_db(&state.pool, meeting_id, &request).await?;
+    // Update meeting settings (enqueues MeetingSettingsUpdated in the same transaction)
+    let updated_meeting =
+        update_meeting_settings_in_db(&state.pool, meeting_id, user_id, &request).await?;
 
     info!(
         target: "gc.handlers.meetings",
@@ -738,6 +888,69 @@ pub async fn update_meeting_settings(
     Ok(Json(MeetingResponse::from(updated_meeting)))
 }
 
+/// Handler for GET /api/v1/meetings/{id}/report
+///
+/// Returns the post-meeting analytics report. Reports are computed by a
+/// background rollup task shortly after every MC assignment for the meeting
+/// has ended.
+///
+/// # Authorization
+///
+/// Only the meeting host (creator) can view the report.
+///
+/// # Response
+///
+/// - 200 OK: Report returned
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: User is not the host
+/// - 404 Not Found: Meeting not found or report not yet available
+#[instrument(
+    skip_all,
+    name = "gc.meeting.get_report",
+    fields(
+        method = "GET",
+        endpoint = "/api/v1/meetings/{id}/report",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn get_meeting_report(
+    State(state): State<Arc<AppState>>,
+    Extension(user_claims): Extension<UserClaims>,
+    Path(meeting_id): Path<Uuid>,
+) -> Result<Json<MeetingReportResponse>, GcError> {
+    let meeting = find_meeting_by_id(&state.pool, meeting_id).await?;
+    let user_id = parse_user_id(&user_claims.sub)?;
+
+    if meeting.created_by_user_id != user_id {
+        warn!(
+            target: "gc.handlers.meetings",
+            meeting_id = %meeting_id,
+            user_id = %user_id,
+            "Non-host user attempted to view meeting report"
+        );
+        return Err(GcError::Forbidden(
+            "Only the meeting host can view the report".to_string(),
+        ));
+    }
+
+    let report = MeetingReportsRepository::get_report(&state.pool, meeting_id)
+        .await?
+        .ok_or_else(|| GcError::NotFound("Meeting report not available".to_string()))?;
+
+    Ok(Json(MeetingReportResponse {
+        meeting_id: report.meeting_id,
+        started_at: report.started_at,
+        ended_at: report.ended_at,
+        duration_seconds: report.duration_seconds,
+        peak_participants: report.peak_participants,
+        total_participants: report.total_participants,
+        join_failures: report.join_failures,
+        avg_qoe_score: report.avg_qoe_score,
+        live_stream_seconds: report.live_stream_seconds,
+        computed_at: report.computed_at,
+    }))
+}
+
 // ============================================================================
 // Database Helpers
 // ============================================================================
@@ -765,7 +978,10 @@ const MEETING_SELECT_QUERY: &str = r#"
         updated_at,
         allow_guests,
         allow_external_participants,
-        waiting_room_enabled
+        waiting_room_enabled,
+        duplicate_join_policy,
+        recording_layout,
+        priority
     FROM meetings
 "#;
 
@@ -783,7 +999,10 @@ async fn find_meeting_by_code(pool: &PgPool, code: &str) -> Result<MeetingRow, G
 }
 
 /// Find a meeting by its ID.
-async fn find_meeting_by_id(pool: &PgPool, meeting_id: Uuid) -> Result<MeetingRow, GcError> {
+pub(crate) async fn find_meeting_by_id(
+    pool: &PgPool,
+    meeting_id: Uuid,
+) -> Result<MeetingRow, GcError> {
     let query = format!("{} WHERE meeting_id = $1", MEETING_SELECT_QUERY);
 
     let row = sqlx::query(&query)
@@ -796,11 +1015,17 @@ async fn find_meeting_by_id(pool: &PgPool, meeting_id: Uuid) -> Result<MeetingRo
 }
 
 /// Update meeting settings in the database.
+///
+/// The `MeetingSettingsUpdated` outbox event is written in the same
+/// transaction as the UPDATE.
 async fn update_meeting_settings_in_db(
     pool: &PgPool,
     meeting_id: Uuid,
+    updated_by: Uuid,
     request: &UpdateMeetingSettingsRequest,
 ) -> Result<MeetingRow, GcError> {
+    let mut tx = pool.begin().await?;
+
     let row = sqlx::query(
         r#"
         UPDATE meetings
@@ -808,6 +1033,8 @@ async fn update_meeting_settings_in_db(
             allow_guests = COALESCE($2, allow_guests),
             allow_external_participants = COALESCE($3, allow_external_participants),
             waiting_room_enabled = COALESCE($4, waiting_room_enabled),
+            duplicate_join_policy = COALESCE($5, duplicate_join_policy),
+            recording_layout = COALESCE($6, recording_layout),
             updated_at = NOW()
         WHERE meeting_id = $1
         RETURNING
@@ -831,17 +1058,33 @@ async fn update_meeting_settings_in_db(
             updated_at,
             allow_guests,
             allow_external_participants,
-            waiting_room_enabled
+            waiting_room_enabled,
+            duplicate_join_policy,
+            recording_layout,
+            priority
         "#,
     )
     .bind(meeting_id)
     .bind(request.allow_guests)
     .bind(request.allow_external_participants)
     .bind(request.waiting_room_enabled)
-    .fetch_optional(pool)
+    .bind(request.duplicate_join_policy.map(|policy| policy.as_str()))
+    .bind(request.recording_layout.map(|layout| layout.as_str()))
+    .fetch_optional(&mut *tx)
     .await?
     .ok_or_else(|| GcError::NotFound("Meeting not found".to_string()))?;
 
+    EventOutboxRepository::enqueue(
+        &mut tx,
+        &GcEvent::MeetingSettingsUpdated {
+            meeting_id,
+            updated_by,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
     Ok(map_row_to_meeting(row))
 }
 
@@ -852,7 +1095,7 @@ async fn update_meeting_settings_in_db(
 /// Parse user ID from JWT subject.
 ///
 /// Supports both plain UUID and "user:{uuid}" formats.
-fn parse_user_id(sub: &str) -> Result<Uuid, GcError> {
+pub(crate) fn parse_user_id(sub: &str) -> Result<Uuid, GcError> {
     let uuid_str = sub.strip_prefix("user:").unwrap_or(sub);
     Uuid::parse_str(uuid_str).map_err(|e| {
         tracing::debug!(target: "gc.handlers.meetings", error = %e, "Failed to parse user ID from token");
@@ -890,7 +1133,7 @@ fn create_ac_client(state: &AppState) -> Result<AcClient, GcError> {
 /// Produces 12 base62 characters (72 bits entropy) using CSPRNG.
 /// Always returns exactly `MEETING_CODE_LENGTH` characters, left-padded
 /// with '0' if the random value produces fewer digits.
-fn generate_meeting_code() -> Result<String, GcError> {
+pub(crate) fn generate_meeting_code() -> Result<String, GcError> {
     let rng = SystemRandom::new();
     let mut bytes = [0u8; MEETING_CODE_RANDOM_BYTES];
 
@@ -926,7 +1169,7 @@ fn generate_meeting_code() -> Result<String, GcError> {
 /// Generate a cryptographically secure join token secret.
 ///
 /// Produces 32 random bytes (256 bits) hex-encoded to 64 characters.
-fn generate_join_token_secret() -> Result<String, GcError> {
+pub(crate) fn generate_join_token_secret() -> Result<String, GcError> {
     let rng = SystemRandom::new();
     let mut bytes = [0u8; JOIN_TOKEN_SECRET_BYTES];
 
@@ -1,11 +1,33 @@
 //! HTTP request handlers for Global Controller.
 
+pub mod admin;
+pub mod assets;
+pub mod attendance;
+pub mod client_errors;
 pub mod health;
 pub mod me;
+pub mod meeting_imports;
 pub mod meetings;
 pub mod metrics;
+pub mod org_config;
+pub mod recordings;
 
+pub use admin::{
+    cordon_controller, cordon_handler, list_controllers, list_handlers, list_versions,
+    uncordon_controller, uncordon_handler,
+};
+pub use assets::create_meeting_asset;
+pub use attendance::export_meeting_attendance;
+pub use client_errors::report_client_error;
 pub use health::{health_check, readiness_check};
 pub use me::get_me;
-pub use meetings::{create_meeting, get_guest_token, join_meeting, update_meeting_settings};
+pub use meeting_imports::{get_meeting_import, import_meetings};
+pub use meetings::{
+    create_meeting, get_guest_token, get_meeting_report, join_meeting, preflight_meeting,
+    update_meeting_settings,
+};
 pub use metrics::metrics_handler;
+pub use org_config::{get_org_config, reconcile_org_config};
+pub use recordings::{
+    delete_meeting_recording, get_recording_download_url, list_meeting_recordings,
+};
@@ -0,0 +1,231 @@
+//! Declarative org config handlers for Global Controller.
+//!
+//! Implements:
+//!
+//! - `GET /api/v1/admin/orgs/{subdomain}/config` - Current org config
+//! - `PUT /api/v1/admin/orgs/{subdomain}/config` - Reconcile org config
+//!
+//! The PUT body is the complete desired state of the organization (see
+//! [`OrgConfig`]). GC creates the organization if the subdomain is new,
+//! otherwise overwrites every field that differs, and returns the changes.
+//! Reconciling the same document again is a no-op, so a GitOps controller
+//! (Terraform, Crossplane, a CI job) can apply it on every sync. With
+//! `?dry_run=true` the diff is computed and nothing is written.
+//!
+//! Organizations are never deleted here; set `active: false` instead.
+//!
+//! # Security
+//!
+//! - Service authenticated, and the token must carry [`TENANT_ADMIN_SCOPE`]
+//! - Applied changes are logged
+
+use crate::auth::Claims;
+use crate::errors::GcError;
+use crate::models::{OrgConfig, OrgConfigAction, OrgConfigReconcileResponse};
+use crate::observability::metrics;
+use crate::repositories::OrganizationsRepository;
+use crate::routes::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+/// Scope required for the org config endpoints.
+pub const TENANT_ADMIN_SCOPE: &str = "admin:tenants";
+
+/// Query parameters of the reconcile endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct ReconcileParams {
+    /// Compute the diff without writing it.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Require the caller's token to carry [`TENANT_ADMIN_SCOPE`].
+fn require_tenant_admin(claims: &Claims) -> Result<(), GcError> {
+    if claims.has_scope(TENANT_ADMIN_SCOPE) {
+        return Ok(());
+    }
+
+    warn!(
+        target: "gc.handlers.org_config",
+        "Org config request without required scope"
+    );
+    Err(GcError::Forbidden(format!(
+        "Requires the {} scope",
+        TENANT_ADMIN_SCOPE
+    )))
+}
+
+/// Check a subdomain against the `organizations.subdomain` format: 1-63
+/// lowercase letters, digits, and hyphens, not starting or ending with a
+/// hyphen.
+fn is_valid_subdomain(subdomain: &str) -> bool {
+    !subdomain.is_empty()
+        && subdomain.len() <= 63
+        && !subdomain.starts_with('-')
+        && !subdomain.ends_with('-')
+        && subdomain
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+/// Handler for GET /api/v1/admin/orgs/{subdomain}/config
+///
+/// # Response
+///
+/// - 200 OK: Current config, in the document format the PUT accepts
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: Token lacks the tenant admin scope
+/// - 404 Not Found: No organization with that subdomain
+#[instrument(
+    skip_all,
+    name = "gc.org_config.get",
+    fields(
+        method = "GET",
+        endpoint = "/api/v1/admin/orgs/{subdomain}/config",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn get_org_config(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(subdomain): Path<String>,
+) -> Result<Json<OrgConfig>, GcError> {
+    require_tenant_admin(&claims)?;
+
+    let mut conn = state.pool.acquire().await?;
+    let (_, config) = OrganizationsRepository::get_config(&mut conn, &subdomain, false)
+        .await?
+        .ok_or_else(|| GcError::NotFound(format!("Organization {} not found", subdomain)))?;
+
+    Ok(Json(config))
+}
+
+/// Handler for PUT /api/v1/admin/orgs/{subdomain}/config
+///
+/// # Response
+///
+/// - 200 OK: Reconciled (or diffed, on a dry run); lists the changes
+/// - 400 Bad Request: Invalid subdomain or document
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: Token lacks the tenant admin scope
+/// - 409 Conflict: The organization was created concurrently; retry
+#[instrument(
+    skip_all,
+    name = "gc.org_config.reconcile",
+    fields(
+        method = "PUT",
+        endpoint = "/api/v1/admin/orgs/{subdomain}/config",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn reconcile_org_config(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(subdomain): Path<String>,
+    Query(params): Query<ReconcileParams>,
+    Json(config): Json<OrgConfig>,
+) -> Result<Json<OrgConfigReconcileResponse>, GcError> {
+    require_tenant_admin(&claims)?;
+
+    if !is_valid_subdomain(&subdomain) {
+        return Err(GcError::BadRequest("Invalid subdomain".to_string()));
+    }
+    config
+        .validate()
+        .map_err(|e| GcError::BadRequest(e.to_string()))?;
+
+    let mut tx = state.pool.begin().await?;
+
+    let current = OrganizationsRepository::get_config(&mut tx, &subdomain, true).await?;
+    let changes = config.diff(current.as_ref().map(|(_, current)| current));
+    let action = match (&current, changes.is_empty()) {
+        (None, _) => OrgConfigAction::Created,
+        (Some(_), false) => OrgConfigAction::Updated,
+        (Some(_), true) => OrgConfigAction::Unchanged,
+    };
+
+    let org_id = if params.dry_run {
+        // Dropping the transaction releases the row lock; nothing written.
+        current.map(|(org_id, _)| org_id)
+    } else {
+        let org_id = match current {
+            None => OrganizationsRepository::create(&mut tx, &subdomain, &config)
+                .await?
+                .ok_or_else(|| {
+                    GcError::Conflict(format!(
+                        "Organization {} was created concurrently",
+                        subdomain
+                    ))
+                })?,
+            Some((org_id, _)) => {
+                if action == OrgConfigAction::Updated {
+                    OrganizationsRepository::update_config(&mut tx, org_id, &config).await?;
+                }
+                org_id
+            }
+        };
+        tx.commit().await?;
+
+        if action != OrgConfigAction::Unchanged {
+            let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+            info!(
+                target: "gc.handlers.org_config",
+                org_id = %org_id,
+                subdomain = %subdomain,
+                action = action.as_str(),
+                fields = ?fields,
+                "Org config reconciled"
+            );
+        }
+        Some(org_id)
+    };
+
+    metrics::record_org_config_reconcile(action.as_str(), params.dry_run);
+
+    Ok(Json(OrgConfigReconcileResponse {
+        org_id,
+        subdomain,
+        action,
+        dry_run: params.dry_run,
+        changes,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claims_with_scope(scope: &str) -> Claims {
+        Claims {
+            sub: "gitops-client".to_string(),
+            exp: 0,
+            iat: 0,
+            scope: scope.to_string(),
+            service_type: None,
+            cnf: None,
+        }
+    }
+
+    #[test]
+    fn test_require_tenant_admin() {
+        assert!(require_tenant_admin(&claims_with_scope("read admin:tenants")).is_ok());
+        let result = require_tenant_admin(&claims_with_scope("admin:fleet"));
+        assert!(matches!(result, Err(GcError::Forbidden(_))));
+    }
+
+    #[test]
+    fn test_is_valid_subdomain() {
+        for valid in ["acme", "a", "acme-corp", "team42"] {
+            assert!(is_valid_subdomain(valid), "{valid} should be valid");
+        }
+        let too_long = "a".repeat(64);
+        for invalid in ["", "-acme", "acme-", "Acme", "acme.corp", too_long.as_str()] {
+            assert!(!is_valid_subdomain(invalid), "{invalid} should be invalid");
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! Meeting recording handlers for Global Controller.
+//!
+//! Implements:
+//!
+//! - `GET /api/v1/meetings/{id}/recordings` - List recordings (host only)
+//! - `GET /api/v1/meetings/{id}/recordings/{recording_id}/download` - Issue a
+//!   pre-signed download URL (host only)
+//! - `DELETE /api/v1/meetings/{id}/recordings/{recording_id}` - Delete a
+//!   recording (host only)
+//!
+//! Recordings are reported by the MH recorder via the `ReportRecording` RPC
+//! once the upload to the object store completes.
+//!
+//! # Security
+//!
+//! - Only the meeting host can list, download, or delete recordings
+//! - Download URLs are short-lived; object keys are never returned
+//! - Deletion is audit logged and hides the recording immediately; the
+//!   object itself is removed by the retention task
+
+use crate::errors::GcError;
+use crate::handlers::meetings::{find_meeting_by_id, parse_user_id};
+use crate::models::{
+    ListRecordingsResponse, MeetingRow, RecordingDownloadResponse, RecordingResponse,
+};
+use crate::repositories::{MeetingRecording, MeetingRecordingsRepository};
+use crate::routes::AppState;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::Utc;
+use common::jwt::UserClaims;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+/// Download URL lifetime (15 minutes).
+const DOWNLOAD_URL_TTL: Duration = Duration::from_secs(900);
+
+/// Load the meeting and require the caller to be its host.
+async fn find_meeting_as_host(
+    state: &AppState,
+    user_claims: &UserClaims,
+    meeting_id: Uuid,
+) -> Result<(MeetingRow, Uuid), GcError> {
+    let meeting = find_meeting_by_id(&state.pool, meeting_id).await?;
+    let user_id = parse_user_id(&user_claims.sub)?;
+
+    if meeting.created_by_user_id != user_id {
+        warn!(
+            target: "gc.handlers.recordings",
+            meeting_id = %meeting_id,
+            user_id = %user_id,
+            "Non-host user attempted to access meeting recordings"
+        );
+        return Err(GcError::Forbidden(
+            "Only the meeting host can manage recordings".to_string(),
+        ));
+    }
+
+    Ok((meeting, user_id))
+}
+
+fn to_response(recording: MeetingRecording, retention_days: u32) -> RecordingResponse {
+    RecordingResponse {
+        recording_id: recording.recording_id,
+        content_type: recording.content_type,
+        size_bytes: recording.size_bytes,
+        duration_seconds: recording.duration_seconds,
+        started_at: recording.started_at,
+        completed_at: recording.completed_at,
+        expires_at: recording.completed_at + chrono::Duration::days(i64::from(retention_days)),
+    }
+}
+
+/// Handler for GET /api/v1/meetings/{id}/recordings
+///
+/// # Response
+///
+/// - 200 OK: Recordings in start order (empty if none)
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: User is not the host
+/// - 404 Not Found: Meeting not found
+#[instrument(
+    skip_all,
+    name = "gc.meeting.list_recordings",
+    fields(
+        method = "GET",
+        endpoint = "/api/v1/meetings/{id}/recordings",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn list_meeting_recordings(
+    State(state): State<Arc<AppState>>,
+    Extension(user_claims): Extension<UserClaims>,
+    Path(meeting_id): Path<Uuid>,
+) -> Result<Json<ListRecordingsResponse>, GcError> {
+    find_meeting_as_host(&state, &user_claims, meeting_id).await?;
+
+    let recordings = MeetingRecordingsRepository::list_for_meeting(&state.pool, meeting_id).await?;
+    let retention_days = state.config.recording_retention_days;
+
+    Ok(Json(ListRecordingsResponse {
+        recordings: recordings
+            .into_iter()
+            .map(|r| to_response(r, retention_days))
+            .collect(),
+    }))
+}
+
+/// Handler for GET /api/v1/meetings/{id}/recordings/{recording_id}/download
+///
+/// # Response
+///
+/// - 200 OK: Download URL issued
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: User is not the host
+/// - 404 Not Found: Meeting or recording not found
+/// - 503 Service Unavailable: No object store configured
+#[instrument(
+    skip_all,
+    name = "gc.meeting.download_recording",
+    fields(
+        method = "GET",
+        endpoint = "/api/v1/meetings/{id}/recordings/{recording_id}/download",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn get_recording_download_url(
+    State(state): State<Arc<AppState>>,
+    Extension(user_claims): Extension<UserClaims>,
+    Path((meeting_id, recording_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<RecordingDownloadResponse>, GcError> {
+    let (_, user_id) = find_meeting_as_host(&state, &user_claims, meeting_id).await?;
+
+    let recording = MeetingRecordingsRepository::get(&state.pool, meeting_id, recording_id)
+        .await?
+        .ok_or_else(|| GcError::NotFound("Recording not found".to_string()))?;
+
+    let object_store = state.object_store.as_ref().ok_or_else(|| {
+        GcError::ServiceUnavailable("Recording downloads are not configured".to_string())
+    })?;
+
+    let download =
+        object_store.presign_download(&recording.object_key, DOWNLOAD_URL_TTL, Utc::now());
+
+    info!(
+        target: "gc.handlers.recordings",
+        meeting_id = %meeting_id,
+        recording_id = %recording_id,
+        user_id = %user_id,
+        backend = object_store.backend(),
+        "Recording download URL issued"
+    );
+
+    Ok(Json(RecordingDownloadResponse {
+        recording_id,
+        download_url: download.url,
+        expires_at: download.expires_at,
+    }))
+}
+
+/// Handler for DELETE /api/v1/meetings/{id}/recordings/{recording_id}
+///
+/// # Response
+///
+/// - 204 No Content: Recording deleted
+/// - 401 Unauthorized: Invalid or missing token
+/// - 403 Forbidden: User is not the host
+/// - 404 Not Found: Meeting or recording not found (or already deleted)
+#[instrument(
+    skip_all,
+    name = "gc.meeting.delete_recording",
+    fields(
+        method = "DELETE",
+        endpoint = "/api/v1/meetings/{id}/recordings/{recording_id}",
+        status = tracing::field::Empty,
+    )
+)]
+pub async fn delete_meeting_recording(
+    State(state): State<Arc<AppState>>,
+    Extension(user_claims): Extension<UserClaims>,
+    Path((meeting_id, recording_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, GcError> {
+    let (meeting, user_id) = find_meeting_as_host(&state, &user_claims, meeting_id).await?;
+
+    let deleted =
+        MeetingRecordingsRepository::mark_deleted(&state.pool, meeting_id, recording_id, user_id)
+            .await?;
+    if !deleted {
+        return Err(GcError::NotFound("Recording not found".to_string()));
+    }
+
+    if let Err(e) = MeetingRecordingsRepository::log_audit_event(
+        &state.pool,
+        meeting.org_id,
+        Some(user_id),
+        meeting_id,
+        recording_id,
+        "recording_deleted",
+    )
+    .await
+    {
+        warn!(
+            target: "gc.handlers.recordings",
+            meeting_id = %meeting_id,
+            recording_id = %recording_id,
+            error = %e,
+            "Failed to log audit event for recording deletion"
+        );
+    }
+
+    info!(
+        target: "gc.handlers.recordings",
+        meeting_id = %meeting_id,
+        recording_id = %recording_id,
+        user_id = %user_id,
+        "Recording deleted"
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -21,6 +21,8 @@
 //! - `auth` - JWT validation via AC JWKS endpoint
 //! - `config` - Service configuration from environment
 //! - `errors` - Error types with HTTP status code mapping
+//! - `events` - In-process event bus for meeting lifecycle side effects
+//! - `faults` - Fault injection points (`fault-injection` feature)
 //! - `handlers` - HTTP request handlers
 //! - `middleware` - HTTP middleware (authentication, metrics)
 //! - `models` - Data models
@@ -31,6 +33,9 @@
 pub mod auth;
 pub mod config;
 pub mod errors;
+pub mod events;
+#[cfg(feature = "fault-injection")]
+pub mod faults;
 pub mod grpc;
 pub mod handlers;
 pub mod middleware;
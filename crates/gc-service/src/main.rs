@@ -12,10 +12,14 @@
 //! # Background Tasks
 //!
 //! - Health checker: Monitors MC heartbeats and marks stale controllers unhealthy
+//! - Outbox relay: Publishes committed meeting events from the outbox to the event bus
 
 mod auth;
 mod config;
 mod errors;
+mod events;
+#[cfg(feature = "fault-injection")]
+mod faults;
 mod grpc;
 mod handlers;
 mod middleware;
@@ -27,41 +31,52 @@ mod services;
 mod tasks;
 
 use auth::{JwksClient, JwtValidator};
+use common::build_info::version_router;
+use common::config::ConfigProfile;
+use common::debug_auth::require_debug_scope;
+use common::listen::{bind_tcp_listeners, parse_bind_addresses, serve_unix, tcp_incoming};
+use common::log_level::log_level_router;
+use common::request_id::RequestIdLayer;
 use common::token_manager::{spawn_token_manager, TokenManagerConfig};
 use config::Config;
 use grpc::auth_layer::GrpcAuthLayer;
-use grpc::{McService, MhService};
+use grpc::{GcBuildInfoService, McService, MhService};
+use proto_gen::dark_tower::internal::v1::build_info_service_server::BuildInfoServiceServer;
 use proto_gen::dark_tower::internal::v1::global_controller_service_server::GlobalControllerServiceServer;
 use proto_gen::dark_tower::internal::v1::media_handler_registry_service_server::MediaHandlerRegistryServiceServer;
-use repositories::MeetingControllersRepository;
+use repositories::{GcStore, MeetingControllersRepository, PgStore};
 use routes::AppState;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use tasks::{
-    start_assignment_cleanup, start_health_checker, start_mh_health_checker,
-    AssignmentCleanupConfig,
+    start_assignment_cleanup, start_event_forwarder, start_event_metering, start_health_checker,
+    start_meeting_import_worker, start_meeting_report_rollup, start_mh_health_checker,
+    start_outbox_relay, start_recording_retention, AssignmentCleanupConfig, MeetingImportConfig,
+    MeetingReportConfig, OutboxRelayConfig, RecordingRetentionConfig,
 };
 use tokio::signal;
-use tokio::task::JoinHandle;
+use tokio::task::{JoinError, JoinHandle, JoinSet};
 use tokio_util::sync::CancellationToken;
 use tonic::transport::Server as TonicServer;
 use tracing::{error, info, warn};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize tracing with JSON structured logging
-    // JSON format enables robust parsing in Promtail without brittle regex
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "gc_service=debug,tower_http=debug".into()),
-        )
-        .with(tracing_subscriber::fmt::layer().json())
-        .init();
+    // Initialize tracing with JSON structured logging; the filter can be
+    // changed at runtime via /debug/log-level
+    let log_level = common::log_level::init_tracing("gc_service=debug,tower_http=debug");
 
-    info!("Starting Global Controller");
+    let build_info = common::build_info!(
+        "gc-service",
+        features = ["nats", "kafka", "fault-injection"]
+    )
+    .with_proto_schema(proto_gen::SCHEMA_VERSION);
+    info!(
+        version = build_info.version,
+        git_sha = build_info.git_sha,
+        "Starting Global Controller"
+    );
 
     // Initialize Prometheus metrics recorder (ADR-0011)
     // Must be done before any metrics are recorded
@@ -76,6 +91,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         error!("Failed to load configuration: {}", e);
         e
     })?;
+    let profile = ConfigProfile::from_env().map_err(|e| {
+        error!("Failed to load configuration: {}", e);
+        e
+    })?;
 
     info!(
         region = %config.region,
@@ -83,6 +102,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         grpc_bind_address = %config.grpc_bind_address,
         jwt_clock_skew_seconds = config.jwt_clock_skew_seconds,
         mc_staleness_threshold_seconds = config.mc_staleness_threshold_seconds,
+        storage_backend = %config.storage_backend,
+        profile = %profile,
         "Configuration loaded successfully"
     );
 
@@ -107,6 +128,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize registered controllers metric from database (ADR-0011)
     // Query current controller counts before accepting traffic
     init_registered_controllers_metric(&db_pool).await;
+    services::fleet_versions::refresh_fleet_version_metrics(&db_pool).await;
 
     // Parse bind addresses before moving config
     let http_bind_address = config.bind_address.clone();
@@ -138,12 +160,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mc_client: Arc<dyn services::McClientTrait> =
         Arc::new(services::McClient::new(token_rx.clone()));
 
+    // Build asset upload presigner if configured
+    let object_store = services::object_store::ObjectStoreConfig::from_env()
+        .map_err(|e| {
+            error!("Invalid object store configuration: {}", e);
+            e
+        })?
+        .map(|object_store_config| {
+            info!(
+                bucket = %object_store_config.bucket,
+                region = %object_store_config.region,
+                "Asset uploads and recording downloads enabled"
+            );
+            services::object_store::build(&object_store_config)
+        });
+
+    // CockroachDB and YugabyteDB speak the Postgres protocol, so every
+    // backend runs on the same pool
+    let store: Arc<dyn GcStore> = Arc::new(PgStore::new(db_pool.clone(), config.storage_backend));
+
     // Create application state
     let state = Arc::new(AppState {
         pool: db_pool.clone(),
         config,
         mc_client,
         token_receiver: token_rx,
+        event_bus: events::EventBus::default(),
+        object_store,
+        store,
     });
 
     // Create JWT validator for gRPC auth
@@ -154,24 +198,45 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         })?,
     );
     let jwt_validator = Arc::new(JwtValidator::new(
+        Arc::clone(&jwks_client),
+        state.config.jwt_clock_skew_seconds,
+    ));
+
+    // Debug endpoints (log filter, fault injection), gated on the admin:debug scope
+    let debug_validator = Arc::new(common::jwt::JwtValidator::new(
         jwks_client,
         state.config.jwt_clock_skew_seconds,
     ));
+    let debug_routes = log_level_router(log_level);
+    #[cfg(feature = "fault-injection")]
+    let debug_routes = debug_routes.merge(common::fault_injection::fault_injection_router(
+        faults::OPERATIONS,
+    ));
+    let debug_routes = debug_routes
+        .layer(axum::middleware::from_fn_with_state(
+            debug_validator,
+            require_debug_scope,
+        ))
+        .layer(RequestIdLayer);
 
     // Build HTTP application routes with metrics endpoint (ADR-0011)
-    let http_app = routes::build_routes(state.clone(), metrics_handle).map_err(|e| {
-        error!("Failed to build routes: {}", e);
-        e
-    })?;
-
-    // Create gRPC services with auth layer
-    let mc_service = McService::new(state.clone());
-    let mh_service = MhService::new(Arc::new(db_pool.clone()));
-    let grpc_auth_layer = GrpcAuthLayer::new(jwt_validator);
+    let http_app = routes::build_routes(state.clone(), metrics_handle.clone())
+        .map_err(|e| {
+            error!("Failed to build routes: {}", e);
+            e
+        })?
+        .merge(debug_routes)
+        .merge(version_router(build_info.clone()));
 
     // Create cancellation token for graceful shutdown
     let cancel_token = CancellationToken::new();
 
+    // Create gRPC services with auth layer
+    let mc_service = McService::new(state.clone()).with_shutdown(cancel_token.clone());
+    let mh_service = MhService::new(Arc::new(db_pool.clone()))
+        .with_min_fleet_version(state.config.min_fleet_version);
+    let grpc_auth_layer = GrpcAuthLayer::new(jwt_validator);
+
     // Start health checker background task
     let health_checker_pool = db_pool.clone();
     let health_checker_token = cancel_token.clone();
@@ -198,6 +263,50 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         start_assignment_cleanup(cleanup_pool, cleanup_config, cleanup_token).await;
     });
 
+    // Start meeting report rollup background task
+    let report_pool = db_pool.clone();
+    let report_token = cancel_token.clone();
+    let report_config = MeetingReportConfig::from_env();
+    info!(
+        report_interval_seconds = report_config.check_interval_seconds,
+        report_batch_size = report_config.batch_size,
+        "Meeting report configuration loaded"
+    );
+    let report_handle = tokio::spawn(async move {
+        start_meeting_report_rollup(report_pool, report_config, report_token).await;
+    });
+
+    // Start meeting import worker background task
+    let import_pool = db_pool.clone();
+    let import_token = cancel_token.clone();
+    let import_config = MeetingImportConfig::from_env();
+    let import_handle = tokio::spawn(async move {
+        start_meeting_import_worker(import_pool, import_config, import_token).await;
+    });
+
+    // Start recording retention background task (needs the object store to delete objects)
+    let recording_retention_handle = match state.object_store.clone() {
+        Some(retention_store) => {
+            let retention_pool = db_pool.clone();
+            let retention_token = cancel_token.clone();
+            let retention_config =
+                RecordingRetentionConfig::from_env(state.config.recording_retention_days);
+            Some(tokio::spawn(async move {
+                start_recording_retention(
+                    retention_pool,
+                    retention_store,
+                    retention_config,
+                    retention_token,
+                )
+                .await;
+            }))
+        }
+        None => {
+            warn!("No object store configured, recording retention disabled");
+            None
+        }
+    };
+
     // Start MH health checker background task
     let mh_health_checker_pool = db_pool.clone();
     let mh_health_checker_token = cancel_token.clone();
@@ -211,48 +320,139 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .await;
     });
 
-    // Parse HTTP bind address
-    let http_addr: SocketAddr = http_bind_address.parse().map_err(|e| {
+    // Start the in-process usage metering consumer. It always runs, so the
+    // outbox relay has a subscriber to deliver to without an external
+    // publisher. Subscribe before the outbox relay starts so no events are
+    // missed.
+    let metering_events = state.event_bus.subscribe();
+    let metering_token = cancel_token.clone();
+    let event_metering_handle = tokio::spawn(async move {
+        start_event_metering(metering_events, metering_token).await;
+    });
+
+    // Start external event forwarder (NATS/Kafka) if configured.
+    // Subscribe before the outbox relay starts so no events are missed.
+    let publisher_config = events::publisher::PublisherConfig::from_env().map_err(|e| {
+        error!("Invalid event publisher configuration: {}", e);
+        e
+    })?;
+    let event_forwarder_handle = match publisher_config {
+        Some(publisher_config) => {
+            let publisher = events::publisher::connect(&publisher_config)
+                .await
+                .map_err(|e| {
+                    error!("Failed to start event publisher: {}", e);
+                    e
+                })?;
+            info!(
+                backend = publisher.backend(),
+                topic = %publisher_config.topic,
+                "Event publisher connected"
+            );
+            let forwarder_events = state.event_bus.subscribe();
+            let forwarder_token = cancel_token.clone();
+            Some(tokio::spawn(async move {
+                start_event_forwarder(forwarder_events, publisher, forwarder_token).await;
+            }))
+        }
+        None => None,
+    };
+
+    // Start event outbox relay background task
+    let outbox_pool = db_pool.clone();
+    let outbox_event_bus = state.event_bus.clone();
+    let outbox_token = cancel_token.clone();
+    let outbox_config = OutboxRelayConfig::from_env();
+    let outbox_relay_handle = tokio::spawn(async move {
+        start_outbox_relay(outbox_pool, outbox_event_bus, outbox_config, outbox_token).await;
+    });
+
+    // Parse HTTP bind addresses
+    let http_addrs = parse_bind_addresses(&http_bind_address).map_err(|e| {
         error!("Invalid HTTP bind address: {}", e);
         e
     })?;
 
-    // Parse gRPC bind address
-    let grpc_addr: SocketAddr = grpc_bind_address.parse().map_err(|e| {
+    // Parse gRPC bind addresses
+    let grpc_addrs = parse_bind_addresses(&grpc_bind_address).map_err(|e| {
         error!("Invalid gRPC bind address: {}", e);
         e
     })?;
 
-    info!("Global Controller HTTP server listening on {}", http_addr);
-    info!("Global Controller gRPC server listening on {}", grpc_addr);
+    let http_listeners = bind_tcp_listeners(&http_addrs)?;
+    let grpc_listeners = bind_tcp_listeners(&grpc_addrs)?;
 
-    // Start HTTP server
-    let http_listener = tokio::net::TcpListener::bind(http_addr).await?;
-    let http_server = axum::serve(
-        http_listener,
-        http_app.into_make_service_with_connect_info::<SocketAddr>(),
+    info!(
+        "Global Controller HTTP server listening on {:?}",
+        http_addrs
+    );
+    info!(
+        "Global Controller gRPC server listening on {:?}",
+        grpc_addrs
     );
 
+    // Start one HTTP server per listener
+    let mut servers = JoinSet::new();
+    if let Some(socket) = &state.config.health_socket {
+        // Health and metrics also on a Unix socket (sidecar scraping)
+        let ops_app = routes::build_ops_routes(state.clone(), metrics_handle);
+        let ops_server = serve_unix(socket, ops_app, cancel_token.clone().cancelled_owned())
+            .map_err(|e| {
+                error!("Failed to bind health socket: {}", e);
+                e
+            })?;
+        info!(
+            "Global Controller health socket listening on {}",
+            socket.path.display()
+        );
+        servers.spawn(async move {
+            ops_server.await;
+            Ok(())
+        });
+    }
+    for listener in http_listeners {
+        let http_server = axum::serve(
+            listener,
+            http_app
+                .clone()
+                .into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(cancel_token.clone().cancelled_owned());
+        servers.spawn(async move {
+            http_server
+                .await
+                .map_err(|e| format!("HTTP server error: {e}"))
+        });
+    }
+
     // Start gRPC server with auth layer and both MC and MH services
     let grpc_server = TonicServer::builder()
+        // First layer is outermost: auth rejections carry the request ID too
+        .layer(RequestIdLayer)
         .layer(grpc_auth_layer)
         .add_service(GlobalControllerServiceServer::new(mc_service))
         .add_service(MediaHandlerRegistryServiceServer::new(mh_service))
-        .serve(grpc_addr);
+        .add_service(BuildInfoServiceServer::new(GcBuildInfoService::new(
+            build_info,
+        )))
+        .serve_with_incoming_shutdown(
+            tcp_incoming(grpc_listeners),
+            cancel_token.clone().cancelled_owned(),
+        );
+    servers.spawn(async move {
+        grpc_server
+            .await
+            .map_err(|e| format!("gRPC server error: {e}"))
+    });
 
-    // Run both servers concurrently with graceful shutdown
-    let cancel_for_shutdown = cancel_token.clone();
+    // Run until a shutdown signal or the first server exits, then drain the rest
     tokio::select! {
-        result = http_server.with_graceful_shutdown(shutdown_signal(cancel_for_shutdown.clone())) => {
-            if let Err(e) = result {
-                error!("HTTP server error: {}", e);
-            }
-        }
-        result = grpc_server => {
-            if let Err(e) = result {
-                error!("gRPC server error: {}", e);
-            }
-        }
+        () = shutdown_signal(cancel_token.clone(), profile.drain_seconds()) => {}
+        Some(result) = servers.join_next() => log_server_exit(result),
+    }
+    cancel_token.cancel();
+    while let Some(result) = servers.join_next().await {
+        log_server_exit(result);
     }
 
     // Cancel background tasks
@@ -269,15 +469,46 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     if let Err(e) = cleanup_handle.await {
         error!("Assignment cleanup task error: {}", e);
     }
+    if let Err(e) = report_handle.await {
+        error!("Meeting report rollup task error: {}", e);
+    }
+    if let Err(e) = import_handle.await {
+        error!("Meeting import worker error: {}", e);
+    }
+    if let Some(handle) = recording_retention_handle {
+        if let Err(e) = handle.await {
+            error!("Recording retention task error: {}", e);
+        }
+    }
     if let Err(e) = mh_health_checker_handle.await {
         error!("MH health checker task error: {}", e);
     }
+    if let Err(e) = outbox_relay_handle.await {
+        error!("Outbox relay task error: {}", e);
+    }
+    if let Err(e) = event_metering_handle.await {
+        error!("Event metering task error: {}", e);
+    }
+    if let Some(handle) = event_forwarder_handle {
+        if let Err(e) = handle.await {
+            error!("Event forwarder task error: {}", e);
+        }
+    }
 
     info!("Global Controller shutdown complete");
 
     Ok(())
 }
 
+/// Log a server task's exit, if it was abnormal.
+fn log_server_exit(result: Result<Result<(), String>, JoinError>) {
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => error!("{}", e),
+        Err(e) => error!("Server task error: {}", e),
+    }
+}
+
 /// Initialize the registered controllers metric from database.
 ///
 /// Queries current controller counts by status and sets the gauge values.
@@ -325,7 +556,8 @@ async fn init_registered_controllers_metric(pool: &sqlx::PgPool) {
 /// Listens for shutdown signals (SIGTERM, SIGINT).
 /// Returns when a shutdown signal is received and drain period is complete.
 /// Also triggers the cancellation token for coordinated shutdown.
-async fn shutdown_signal(cancel_token: CancellationToken) {
+/// `GC_DRAIN_SECONDS` overrides the config profile's `default_drain_secs`.
+async fn shutdown_signal(cancel_token: CancellationToken, default_drain_secs: u64) {
     let ctrl_c = async {
         match signal::ctrl_c().await {
             Ok(()) => info!("Received SIGINT, starting graceful shutdown..."),
@@ -362,7 +594,7 @@ async fn shutdown_signal(cancel_token: CancellationToken) {
     let drain_secs: u64 = std::env::var("GC_DRAIN_SECONDS")
         .ok()
         .and_then(|s| s.parse().ok())
-        .unwrap_or(30);
+        .unwrap_or(default_drain_secs);
 
     if drain_secs > 0 {
         warn!("Draining connections for {} seconds...", drain_secs);
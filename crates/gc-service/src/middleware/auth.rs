@@ -6,14 +6,18 @@
 //!
 //! Both extract Bearer token from Authorization header, validate JWT using
 //! the JWKS client, and inject the appropriate claims into request extensions.
+//! Service tokens may also be DPoP-bound (RFC 9449), in which case they are
+//! sent as `Authorization: DPoP <token>` with a proof in the `DPoP` header.
 
-use crate::auth::{Claims, JwtValidator};
+use crate::auth::{Claims, JwtValidator, TokenScheme};
 use crate::errors::GcError;
 use axum::{
-    extract::{Request, State},
+    extract::{OriginalUri, Request, State},
+    http::header,
     middleware::Next,
     response::IntoResponse,
 };
+use common::dpop::{DpopRequest, DPOP_HEADER};
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -24,23 +28,61 @@ pub struct AuthState {
     pub jwt_validator: Arc<JwtValidator>,
 }
 
-/// Extract Bearer token from the Authorization header.
-///
-/// Shared helper used by both `require_auth` and `require_user_auth`.
-fn extract_bearer_token(req: &Request) -> Result<&str, GcError> {
-    let auth_header = req
-        .headers()
+/// Extract the Authorization header value.
+fn authorization_header(req: &Request) -> Result<&str, GcError> {
+    req.headers()
         .get("authorization")
         .and_then(|h| h.to_str().ok())
         .ok_or_else(|| {
             tracing::debug!(target: "gc.middleware.auth", "Missing Authorization header");
             GcError::InvalidToken("Missing Authorization header".to_string())
-        })?;
+        })
+}
+
+fn invalid_header_format() -> GcError {
+    tracing::debug!(target: "gc.middleware.auth", "Invalid Authorization header format");
+    GcError::InvalidToken("Invalid Authorization header format".to_string())
+}
+
+/// Extract Bearer token from the Authorization header.
+///
+/// Used by `require_user_auth`; user tokens are never DPoP-bound.
+fn extract_bearer_token(req: &Request) -> Result<&str, GcError> {
+    authorization_header(req)?
+        .strip_prefix("Bearer ")
+        .ok_or_else(invalid_header_format)
+}
+
+/// Extract a service token and its scheme (`Bearer` or `DPoP`) from the
+/// Authorization header.
+fn extract_service_token(req: &Request) -> Result<(TokenScheme, &str), GcError> {
+    let auth_header = authorization_header(req)?;
+    if let Some(token) = auth_header.strip_prefix("Bearer ") {
+        return Ok((TokenScheme::Bearer, token));
+    }
+    auth_header
+        .strip_prefix("DPoP ")
+        .map(|token| (TokenScheme::Dpop, token))
+        .ok_or_else(invalid_header_format)
+}
 
-    auth_header.strip_prefix("Bearer ").ok_or_else(|| {
-        tracing::debug!(target: "gc.middleware.auth", "Invalid Authorization header format");
-        GcError::InvalidToken("Invalid Authorization header format".to_string())
-    })
+/// The single `DPoP` proof header, if present.
+///
+/// More than one proof is invalid (RFC 9449 Section 4.3).
+fn extract_dpop_proof(req: &Request) -> Result<Option<&str>, GcError> {
+    let mut proofs = req.headers().get_all(DPOP_HEADER).iter();
+    let proof = proofs.next();
+    if proofs.next().is_some() {
+        return Err(GcError::InvalidToken(
+            "The DPoP proof is invalid".to_string(),
+        ));
+    }
+    proof
+        .map(|p| {
+            p.to_str()
+                .map_err(|_| GcError::InvalidToken("The DPoP proof is invalid".to_string()))
+        })
+        .transpose()
 }
 
 /// Authentication middleware for service tokens.
@@ -48,9 +90,14 @@ fn extract_bearer_token(req: &Request) -> Result<&str, GcError> {
 /// Validates JWT and deserializes into `Claims` (with `scope`, `service_type`).
 /// Used for service-to-service authenticated endpoints.
 ///
+/// A DPoP-bound token (`cnf` claim) is only accepted with the `DPoP` scheme
+/// and a proof for this request signed by the bound key, so a stolen token
+/// cannot be replayed from another host (see `JwtValidator::verify_dpop`).
+///
 /// # Response
 ///
 /// - Returns 401 Unauthorized if token is missing or invalid
+/// - Returns 401 Unauthorized if a bound token lacks a valid proof
 /// - Continues to next handler with `Claims` in extensions if token is valid
 #[instrument(skip_all, name = "gc.middleware.auth")]
 pub async fn require_auth(
@@ -58,11 +105,32 @@ pub async fn require_auth(
     mut req: Request,
     next: Next,
 ) -> Result<impl IntoResponse, GcError> {
-    let token = extract_bearer_token(&req)?;
+    let (scheme, token) = extract_service_token(&req)?;
 
     // Validate JWT as service token
     let claims = state.jwt_validator.validate(token).await?;
 
+    // Proofs name the URI the client called, before any router nesting
+    let uri = req
+        .extensions()
+        .get::<OriginalUri>()
+        .map_or(req.uri(), |original| &original.0);
+    let host = req
+        .headers()
+        .get(header::HOST)
+        .and_then(|h| h.to_str().ok())
+        .or_else(|| uri.authority().map(|authority| authority.as_str()))
+        .unwrap_or_default();
+    let dpop_request = DpopRequest {
+        method: req.method().as_str(),
+        host,
+        path: uri.path(),
+        access_token: Some(token),
+    };
+    state
+        .jwt_validator
+        .verify_dpop(&claims, scheme, extract_dpop_proof(&req)?, &dpop_request)?;
+
     // Store claims in request extensions for downstream handlers
     req.extensions_mut().insert(claims);
 
@@ -127,4 +195,44 @@ mod tests {
         fn assert_clone<T: Clone>() {}
         assert_clone::<AuthState>();
     }
+
+    fn request_with(headers: &[(&str, &str)]) -> Request {
+        let mut builder = Request::builder().uri("/api/v1/meetings");
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        builder.body(axum::body::Body::empty()).unwrap()
+    }
+
+    #[test]
+    fn test_extract_service_token_schemes() {
+        let req = request_with(&[("authorization", "Bearer abc")]);
+        assert_eq!(
+            extract_service_token(&req).unwrap(),
+            (TokenScheme::Bearer, "abc")
+        );
+
+        let req = request_with(&[("authorization", "DPoP abc")]);
+        assert_eq!(
+            extract_service_token(&req).unwrap(),
+            (TokenScheme::Dpop, "abc")
+        );
+
+        let req = request_with(&[("authorization", "Basic abc")]);
+        assert!(extract_service_token(&req).is_err());
+
+        // User tokens are bearer-only
+        let req = request_with(&[("authorization", "DPoP abc")]);
+        assert!(extract_bearer_token(&req).is_err());
+    }
+
+    #[test]
+    fn test_extract_dpop_proof_allows_at_most_one() {
+        assert_eq!(extract_dpop_proof(&request_with(&[])).unwrap(), None);
+        assert_eq!(
+            extract_dpop_proof(&request_with(&[("dpop", "proof")])).unwrap(),
+            Some("proof")
+        );
+        assert!(extract_dpop_proof(&request_with(&[("dpop", "a"), ("dpop", "b")])).is_err());
+    }
 }
@@ -3,7 +3,9 @@
 //! Contains data types used across the Global Controller service.
 
 use chrono::{DateTime, Utc};
+use common::types::{MeetingId, ParticipantId};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use uuid::Uuid;
 
 /// Meeting status enumeration.
@@ -39,6 +41,105 @@ impl MeetingStatus {
     }
 }
 
+/// What happens when a user already in a meeting joins it again (from
+/// another device or tab). Enforced by the MC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateJoinPolicy {
+    /// Keep both connections as separate participants.
+    Allow,
+    /// Close the earlier connection; the new join wins.
+    Takeover,
+    /// Refuse the new join.
+    Reject,
+}
+
+impl DuplicateJoinPolicy {
+    /// Database representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DuplicateJoinPolicy::Allow => "allow",
+            DuplicateJoinPolicy::Takeover => "takeover",
+            DuplicateJoinPolicy::Reject => "reject",
+        }
+    }
+
+    /// Parse the database representation. Unknown values fall back to the
+    /// column default, `takeover`.
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "allow" => DuplicateJoinPolicy::Allow,
+            "reject" => DuplicateJoinPolicy::Reject,
+            _ => DuplicateJoinPolicy::Takeover,
+        }
+    }
+}
+
+/// How the MH compositor arranges participants in a meeting's recording.
+/// Sent to the MC with each assignment and on to the MHs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingLayout {
+    /// Every participant in equal tiles.
+    Grid,
+    /// The active speaker large, others in a filmstrip.
+    Speaker,
+}
+
+impl RecordingLayout {
+    /// Database representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingLayout::Grid => "grid",
+            RecordingLayout::Speaker => "speaker",
+        }
+    }
+
+    /// Parse the database representation. Unknown values fall back to the
+    /// column default, `grid`.
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "speaker" => RecordingLayout::Speaker,
+            _ => RecordingLayout::Grid,
+        }
+    }
+}
+
+/// Meeting priority class, set at creation. Under capacity pressure lower
+/// priorities are kept off busy MCs first (see `McAssignmentService`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeetingPriority {
+    /// Large audience; may use any MC capacity.
+    Webinar,
+    /// Regular meeting.
+    #[default]
+    Standard,
+    /// Test or internal meeting; shed first.
+    Test,
+}
+
+impl MeetingPriority {
+    /// Database and metric label representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MeetingPriority::Webinar => "webinar",
+            MeetingPriority::Standard => "standard",
+            MeetingPriority::Test => "test",
+        }
+    }
+
+    /// Parse the database representation. Unknown values fall back to the
+    /// column default, `standard`.
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "webinar" => MeetingPriority::Webinar,
+            "test" => MeetingPriority::Test,
+            _ => MeetingPriority::Standard,
+        }
+    }
+}
+
 /// Health check response.
 ///
 /// Returned by the `/health` endpoint (liveness probe).
@@ -90,11 +191,11 @@ pub struct ReadinessResponse {
 #[derive(Debug, Clone, sqlx::FromRow)]
 #[allow(dead_code)] // Used by integration tests and future join handler
 pub struct Participant {
-    /// Unique participant record identifier.
-    pub participant_id: Uuid,
+    /// Unique participant record identifier (UUIDv7).
+    pub participant_id: ParticipantId,
 
     /// Meeting the participant belongs to.
-    pub meeting_id: Uuid,
+    pub meeting_id: MeetingId,
 
     /// User identifier (None for anonymous guests).
     pub user_id: Option<Uuid>,
@@ -194,6 +295,15 @@ pub struct MeetingRow {
 
     /// Whether waiting room is enabled.
     pub waiting_room_enabled: bool,
+
+    /// Duplicate join policy (`allow`, `takeover`, or `reject`).
+    pub duplicate_join_policy: String,
+
+    /// Recording layout (`grid` or `speaker`).
+    pub recording_layout: String,
+
+    /// Priority class (`webinar`, `standard`, or `test`).
+    pub priority: String,
 }
 
 /// Response for joining a meeting.
@@ -216,6 +326,15 @@ pub struct JoinMeetingResponse {
 
     /// Assigned meeting controller information.
     pub mc_assignment: McAssignmentInfo,
+
+    /// Join correlation ID (UUIDv7 stamped when the meeting was assigned).
+    /// Clients pass it in the MC `JoinRequest` so the join can be timed end
+    /// to end.
+    pub join_id: Uuid,
+
+    /// A/B experiment variant by experiment name (omitted when none apply).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub experiments: BTreeMap<String, String>,
 }
 
 /// Meeting controller assignment information.
@@ -236,6 +355,62 @@ pub struct McAssignmentInfo {
     pub grpc_endpoint: String,
 }
 
+/// Response for the pre-join readiness check.
+///
+/// Returned by `GET /v1/meetings/{code}/preflight`. Nothing is assigned or
+/// issued; the client uses it to catch problems before the user joins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PreflightResponse {
+    /// Meeting ID.
+    pub meeting_id: Uuid,
+
+    /// True when a join is expected to succeed: an MC has capacity for the
+    /// meeting and at least one MH is available.
+    pub ready: bool,
+
+    /// Assigned meeting controller, when the meeting already has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mc_assignment: Option<McAssignmentInfo>,
+
+    /// Whether an MC has capacity for the meeting (always true once assigned).
+    pub mc_capacity_available: bool,
+
+    /// Media handlers the client is likely to be sent to, least loaded first.
+    pub media_handlers: Vec<PreflightMediaHandler>,
+
+    /// Oldest signaling protocol version the client must speak.
+    pub min_protocol_version: u32,
+
+    /// TURN relay availability.
+    pub relay: RelayInfo,
+
+    /// Meeting features the client should prepare for, by name.
+    pub features: BTreeMap<String, bool>,
+}
+
+/// Media handler endpoint in a preflight response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PreflightMediaHandler {
+    /// Handler ID.
+    pub mh_id: String,
+
+    /// WebTransport endpoint for client connections.
+    pub webtransport_endpoint: String,
+}
+
+/// TURN relay availability in a preflight response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RelayInfo {
+    /// Whether any TURN relay is configured.
+    pub available: bool,
+
+    /// TURN relay URLs.
+    pub turn_servers: Vec<String>,
+}
+
 /// Request for guest token.
 ///
 /// Sent by anonymous users to join a meeting.
@@ -291,6 +466,14 @@ pub struct UpdateMeetingSettingsRequest {
     /// Whether waiting room is enabled.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub waiting_room_enabled: Option<bool>,
+
+    /// What happens when a user already in the meeting joins again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duplicate_join_policy: Option<DuplicateJoinPolicy>,
+
+    /// How participants are arranged in the meeting's recording.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_layout: Option<RecordingLayout>,
 }
 
 impl UpdateMeetingSettingsRequest {
@@ -299,6 +482,8 @@ impl UpdateMeetingSettingsRequest {
         self.allow_guests.is_some()
             || self.allow_external_participants.is_some()
             || self.waiting_room_enabled.is_some()
+            || self.duplicate_join_policy.is_some()
+            || self.recording_layout.is_some()
     }
 }
 
@@ -328,6 +513,12 @@ pub struct MeetingResponse {
     /// Whether waiting room is enabled.
     pub waiting_room_enabled: bool,
 
+    /// Duplicate join policy (`allow`, `takeover`, or `reject`).
+    pub duplicate_join_policy: String,
+
+    /// Recording layout (`grid` or `speaker`).
+    pub recording_layout: String,
+
     /// Last update timestamp.
     pub updated_at: DateTime<Utc>,
 }
@@ -339,152 +530,1078 @@ pub struct MeetingResponse {
 /// Maximum display name length for meetings (in bytes).
 pub const MAX_MEETING_DISPLAY_NAME_LENGTH: usize = 255;
 
-/// Minimum display name length for meetings (in bytes, after trimming).
-pub const MIN_MEETING_DISPLAY_NAME_LENGTH: usize = 1;
+/// Minimum display name length for meetings (in bytes, after trimming).
+pub const MIN_MEETING_DISPLAY_NAME_LENGTH: usize = 1;
+
+/// Minimum number of participants for a meeting.
+pub const MIN_PARTICIPANTS: i32 = 2;
+
+/// Default maximum participants if not specified in request.
+pub const DEFAULT_MAX_PARTICIPANTS: i32 = 100;
+
+/// Request to create a new meeting.
+///
+/// Sent by authenticated users to create a meeting in their organization.
+/// All settings fields are optional; secure defaults are applied server-side.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateMeetingRequest {
+    /// Meeting display name (required, 1-255 bytes after trimming).
+    pub display_name: String,
+
+    /// Maximum number of participants (optional, default 100, min 2).
+    pub max_participants: Option<i32>,
+
+    /// Scheduled start time (optional, NULL = ad-hoc meeting).
+    pub scheduled_start_time: Option<DateTime<Utc>>,
+
+    /// Whether end-to-end encryption is enabled (default: true).
+    pub enable_e2e_encryption: Option<bool>,
+
+    /// Whether authentication is required to join (default: true).
+    pub require_auth: Option<bool>,
+
+    /// Whether recording is enabled (default: false).
+    pub recording_enabled: Option<bool>,
+
+    /// Whether anonymous guests can join (default: false).
+    pub allow_guests: Option<bool>,
+
+    /// Whether external org users can join (default: false).
+    pub allow_external_participants: Option<bool>,
+
+    /// Whether waiting room is enabled (default: true).
+    pub waiting_room_enabled: Option<bool>,
+
+    /// Priority class (default: standard).
+    pub priority: Option<MeetingPriority>,
+}
+
+impl CreateMeetingRequest {
+    /// Validate the request fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if validation fails.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let display_name = self.display_name.trim();
+
+        if display_name.len() < MIN_MEETING_DISPLAY_NAME_LENGTH {
+            return Err("Display name is required");
+        }
+
+        if display_name.len() > MAX_MEETING_DISPLAY_NAME_LENGTH {
+            return Err("Display name must be at most 255 characters");
+        }
+
+        if let Some(max_participants) = self.max_participants {
+            if max_participants < MIN_PARTICIPANTS {
+                return Err("Maximum participants must be at least 2");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Response after creating a meeting.
+///
+/// Returned by `POST /api/v1/meetings` with status 201 Created.
+/// Excludes `join_token_secret` and other internal fields.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateMeetingResponse {
+    /// Unique meeting identifier.
+    pub meeting_id: Uuid,
+
+    /// Short meeting code for joining.
+    pub meeting_code: String,
+
+    /// Meeting display name.
+    pub display_name: String,
+
+    /// Current meeting status.
+    pub status: String,
+
+    /// Maximum number of participants.
+    pub max_participants: i32,
+
+    /// Whether end-to-end encryption is enabled.
+    pub enable_e2e_encryption: bool,
+
+    /// Whether authentication is required to join.
+    pub require_auth: bool,
+
+    /// Whether recording is enabled.
+    pub recording_enabled: bool,
+
+    /// Whether anonymous guests can join.
+    pub allow_guests: bool,
+
+    /// Whether external org users can join.
+    pub allow_external_participants: bool,
+
+    /// Whether waiting room is enabled.
+    pub waiting_room_enabled: bool,
+
+    /// Priority class (`webinar`, `standard`, or `test`).
+    pub priority: String,
+
+    /// Creation timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<MeetingRow> for CreateMeetingResponse {
+    fn from(row: MeetingRow) -> Self {
+        Self {
+            meeting_id: row.meeting_id,
+            meeting_code: row.meeting_code,
+            display_name: row.display_name,
+            status: row.status,
+            max_participants: row.max_participants,
+            enable_e2e_encryption: row.enable_e2e_encryption,
+            require_auth: row.require_auth,
+            recording_enabled: row.recording_enabled,
+            allow_guests: row.allow_guests,
+            allow_external_participants: row.allow_external_participants,
+            waiting_room_enabled: row.waiting_room_enabled,
+            priority: row.priority,
+            created_at: row.created_at,
+        }
+    }
+}
+
+impl From<MeetingRow> for MeetingResponse {
+    fn from(row: MeetingRow) -> Self {
+        Self {
+            meeting_id: row.meeting_id,
+            display_name: row.display_name,
+            meeting_code: row.meeting_code,
+            status: row.status,
+            allow_guests: row.allow_guests,
+            allow_external_participants: row.allow_external_participants,
+            waiting_room_enabled: row.waiting_room_enabled,
+            duplicate_join_policy: row.duplicate_join_policy,
+            recording_layout: row.recording_layout,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+// ============================================================================
+// Meeting Report API Models
+// ============================================================================
+
+/// Post-meeting analytics report.
+///
+/// Returned by `GET /v1/meetings/{id}/report`.
+#[derive(Debug, Clone, Serialize)]
+pub struct MeetingReportResponse {
+    /// Meeting ID.
+    pub meeting_id: Uuid,
+
+    /// When the meeting started (earliest MC assignment).
+    pub started_at: DateTime<Utc>,
+
+    /// When the meeting ended (latest MC assignment end).
+    pub ended_at: DateTime<Utc>,
+
+    /// Meeting duration in seconds.
+    pub duration_seconds: i64,
+
+    /// Peak concurrent participants.
+    pub peak_participants: i32,
+
+    /// Participant sessions over the meeting's lifetime.
+    pub total_participants: i32,
+
+    /// Failed join attempts.
+    pub join_failures: i32,
+
+    /// Average quality of experience score (0.0-5.0), omitted if unavailable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_qoe_score: Option<f64>,
+
+    /// Seconds the meeting was live streamed.
+    pub live_stream_seconds: i64,
+
+    /// When the report was computed.
+    pub computed_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Meeting Asset API Models
+// ============================================================================
+
+/// Maximum asset size in bytes (100 MiB).
+pub const MAX_ASSET_SIZE_BYTES: i64 = 100 * 1024 * 1024;
+
+/// Maximum asset file name length in bytes.
+pub const MAX_ASSET_FILE_NAME_LENGTH: usize = 255;
+
+/// Maximum content type length in bytes.
+pub const MAX_ASSET_CONTENT_TYPE_LENGTH: usize = 127;
+
+/// Request for a pre-signed asset upload URL.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateAssetRequest {
+    /// File name shown to other participants (1-255 bytes after trimming).
+    pub file_name: String,
+
+    /// MIME type, e.g. `image/png`. The upload must use exactly this type.
+    pub content_type: String,
+
+    /// Declared file size in bytes (1 to 100 MiB).
+    pub size_bytes: i64,
+}
+
+impl CreateAssetRequest {
+    /// Validate the request fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if validation fails.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let file_name = self.file_name.trim();
+
+        if file_name.is_empty() {
+            return Err("File name is required");
+        }
+
+        if file_name.len() > MAX_ASSET_FILE_NAME_LENGTH {
+            return Err("File name must be at most 255 characters");
+        }
+
+        if file_name.chars().any(char::is_control) {
+            return Err("File name must not contain control characters");
+        }
+
+        if !is_valid_content_type(&self.content_type) {
+            return Err("Content type must be a valid MIME type");
+        }
+
+        if self.size_bytes <= 0 || self.size_bytes > MAX_ASSET_SIZE_BYTES {
+            return Err("File size must be between 1 byte and 100 MiB");
+        }
+
+        Ok(())
+    }
+}
+
+/// Check a `type/subtype` MIME type without parameters.
+pub(crate) fn is_valid_content_type(content_type: &str) -> bool {
+    let is_token = |part: &str| {
+        !part.is_empty()
+            && part
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"!#$&^_.+-".contains(&b))
+    };
+
+    content_type.len() <= MAX_ASSET_CONTENT_TYPE_LENGTH
+        && content_type
+            .split_once('/')
+            .is_some_and(|(kind, subtype)| is_token(kind) && is_token(subtype))
+}
+
+/// Pre-signed upload details.
+///
+/// Returned by `POST /api/v1/meetings/{id}/assets` with status 201 Created.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateAssetResponse {
+    /// Asset ID to reference in data channel messages.
+    pub asset_id: Uuid,
+
+    /// HTTP method for the upload (always `PUT`).
+    pub upload_method: String,
+
+    /// Pre-signed upload URL.
+    pub upload_url: String,
+
+    /// Headers the upload request must include unchanged.
+    pub upload_headers: std::collections::BTreeMap<String, String>,
+
+    /// When the upload URL expires.
+    pub expires_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Client Error Report API Models
+// ============================================================================
+
+/// Lowest accepted client sampling rate (1 in 1000 failures reported).
+pub const MIN_CLIENT_ERROR_SAMPLE_RATE: f64 = 0.001;
+
+/// Maximum client error code length in bytes.
+pub const MAX_CLIENT_ERROR_CODE_LENGTH: usize = 32;
+
+/// Kind of client-side failure being reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientErrorKind {
+    /// The client could not join the meeting.
+    JoinFailed,
+    /// The client fell back to a slower transport (e.g. WebSocket relay).
+    TransportFallback,
+    /// No codec supported by both the client and the meeting.
+    CodecUnsupported,
+}
+
+impl ClientErrorKind {
+    /// Metric label representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClientErrorKind::JoinFailed => "join_failed",
+            ClientErrorKind::TransportFallback => "transport_fallback",
+            ClientErrorKind::CodecUnsupported => "codec_unsupported",
+        }
+    }
+}
+
+/// Client platform that sent a report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientPlatform {
+    /// Browser client.
+    Web,
+    /// iOS app.
+    Ios,
+    /// Android app.
+    Android,
+    /// Desktop app.
+    Desktop,
+}
+
+impl ClientPlatform {
+    /// Metric label representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClientPlatform::Web => "web",
+            ClientPlatform::Ios => "ios",
+            ClientPlatform::Android => "android",
+            ClientPlatform::Desktop => "desktop",
+        }
+    }
+}
+
+/// Structured client failure report.
+///
+/// Kind and platform are closed enums so metric labels stay bounded; unknown
+/// values are rejected with 400.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientErrorReport {
+    /// What failed.
+    pub kind: ClientErrorKind,
+
+    /// Platform the client runs on.
+    pub platform: ClientPlatform,
+
+    /// Error code the client saw (e.g. `DT-MC-2022`). Logged only, never a
+    /// metric label.
+    #[serde(default)]
+    pub error_code: Option<String>,
+
+    /// Meeting the failure happened in, if any.
+    #[serde(default)]
+    pub meeting_id: Option<Uuid>,
+
+    /// Fraction of failures of this kind the client reports (0.001 to 1).
+    #[serde(default = "default_client_error_sample_rate")]
+    pub sample_rate: f64,
+}
+
+fn default_client_error_sample_rate() -> f64 {
+    1.0
+}
+
+impl ClientErrorReport {
+    /// Validate the request fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if validation fails.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if !(MIN_CLIENT_ERROR_SAMPLE_RATE..=1.0).contains(&self.sample_rate) {
+            return Err("Sample rate must be between 0.001 and 1");
+        }
+
+        if let Some(code) = &self.error_code {
+            if code.is_empty()
+                || code.len() > MAX_CLIENT_ERROR_CODE_LENGTH
+                || !code
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+            {
+                return Err("Error code must be 1-32 characters of [A-Za-z0-9_-]");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Number of failures this report stands for, undoing client sampling.
+    pub fn weight(&self) -> u64 {
+        // sample_rate is validated to [0.001, 1], so this is 1..=1000.
+        (1.0 / self.sample_rate).round() as u64
+    }
+}
+
+// ============================================================================
+// Meeting Import API Models
+// ============================================================================
+
+/// Maximum meetings accepted in one import.
+pub const MAX_MEETING_IMPORT_RECORDS: usize = 5000;
+
+/// Maximum length of a record's external ID in bytes.
+pub const MAX_IMPORT_EXTERNAL_ID_LENGTH: usize = 255;
+
+/// A meeting to import, as one JSON array element or one CSV row.
+///
+/// Fields and defaults match `CreateMeetingRequest`. `external_id` is the
+/// meeting's ID on the platform being migrated from; it is echoed back with
+/// the new meeting code so the caller can map old meetings to new ones.
+/// Validated records are stored on the import job until the import worker
+/// creates the meetings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MeetingImportRecord {
+    /// ID on the source platform (optional, unique within the import).
+    #[serde(default)]
+    pub external_id: Option<String>,
+
+    /// Meeting display name (required, 1-255 bytes after trimming).
+    pub display_name: String,
+
+    /// Maximum number of participants (optional, default 100, min 2).
+    #[serde(default)]
+    pub max_participants: Option<i32>,
+
+    /// Scheduled start time (optional, RFC 3339).
+    #[serde(default)]
+    pub scheduled_start_time: Option<DateTime<Utc>>,
+
+    /// Whether end-to-end encryption is enabled (default: true).
+    #[serde(default)]
+    pub enable_e2e_encryption: Option<bool>,
+
+    /// Whether authentication is required to join (default: true).
+    #[serde(default)]
+    pub require_auth: Option<bool>,
+
+    /// Whether recording is enabled (default: false).
+    #[serde(default)]
+    pub recording_enabled: Option<bool>,
+
+    /// Whether anonymous guests can join (default: false).
+    #[serde(default)]
+    pub allow_guests: Option<bool>,
+
+    /// Whether external org users can join (default: false).
+    #[serde(default)]
+    pub allow_external_participants: Option<bool>,
+
+    /// Whether waiting room is enabled (default: true).
+    #[serde(default)]
+    pub waiting_room_enabled: Option<bool>,
+
+    /// Priority class (default: standard).
+    #[serde(default)]
+    pub priority: Option<MeetingPriority>,
+}
+
+impl MeetingImportRecord {
+    /// Validate the record fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if validation fails.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let display_name = self.display_name.trim();
+
+        if display_name.len() < MIN_MEETING_DISPLAY_NAME_LENGTH {
+            return Err("Display name is required");
+        }
+
+        if display_name.len() > MAX_MEETING_DISPLAY_NAME_LENGTH {
+            return Err("Display name must be at most 255 characters");
+        }
+
+        if let Some(max_participants) = self.max_participants {
+            if max_participants < MIN_PARTICIPANTS {
+                return Err("Maximum participants must be at least 2");
+            }
+        }
+
+        if let Some(external_id) = &self.external_id {
+            if external_id.is_empty() || external_id.len() > MAX_IMPORT_EXTERNAL_ID_LENGTH {
+                return Err("External ID must be 1-255 characters");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// JSON body of `POST /api/v1/meeting-imports`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MeetingImportRequest {
+    /// Meetings to create.
+    pub meetings: Vec<MeetingImportRecord>,
+}
+
+/// State of a meeting import job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeetingImportStatus {
+    /// Accepted; meetings are being created.
+    Running,
+    /// Every meeting was created.
+    Succeeded,
+    /// Nothing was created; see the job's `error`.
+    Failed,
+}
+
+impl MeetingImportStatus {
+    /// Database and metric label representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MeetingImportStatus::Running => "running",
+            MeetingImportStatus::Succeeded => "succeeded",
+            MeetingImportStatus::Failed => "failed",
+        }
+    }
+
+    /// Parse the database representation. Unknown values are treated as
+    /// `failed`.
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "running" => MeetingImportStatus::Running,
+            "succeeded" => MeetingImportStatus::Succeeded,
+            _ => MeetingImportStatus::Failed,
+        }
+    }
+}
+
+/// A meeting created by an import.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedMeeting {
+    /// ID on the source platform, if the record had one.
+    pub external_id: Option<String>,
+
+    /// New meeting ID.
+    pub meeting_id: Uuid,
+
+    /// New meeting code.
+    pub meeting_code: String,
+}
+
+/// Meeting import job status.
+///
+/// Returned by `POST /api/v1/meeting-imports` (202 Accepted) and
+/// `GET /api/v1/meeting-imports/{id}`.
+#[derive(Debug, Clone, Serialize)]
+pub struct MeetingImportResponse {
+    /// Import job ID.
+    pub job_id: Uuid,
+
+    /// Job state.
+    pub status: MeetingImportStatus,
+
+    /// Meetings in the import.
+    pub total_records: i32,
+
+    /// Meetings created (all or none).
+    pub imported_records: i32,
+
+    /// Why the import failed, when `status` is `failed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// When the import was accepted.
+    pub created_at: DateTime<Utc>,
+
+    /// When the import finished.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+
+    /// Created meetings, once `status` is `succeeded`.
+    pub meetings: Vec<ImportedMeeting>,
+}
+
+// ============================================================================
+// Org Config API Models
+// ============================================================================
+
+/// Maximum length of an organization display name in bytes.
+pub const MAX_ORG_DISPLAY_NAME_LENGTH: usize = 255;
+
+/// Maximum length of an MC pool name in bytes.
+pub const MAX_MC_POOL_LENGTH: usize = 64;
+
+/// Allowed recording consent timeouts in seconds.
+pub const RECORDING_CONSENT_TIMEOUT_RANGE: std::ops::RangeInclusive<i32> = 10..=600;
+
+/// Organization plan tier.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanTier {
+    /// Free plan.
+    #[default]
+    Free,
+    /// Paid plan.
+    Pro,
+    /// Enterprise plan.
+    Enterprise,
+}
+
+impl PlanTier {
+    /// Database representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlanTier::Free => "free",
+            PlanTier::Pro => "pro",
+            PlanTier::Enterprise => "enterprise",
+        }
+    }
+
+    /// Parse the database representation. Unknown values fall back to the
+    /// column default, `free`.
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "pro" => PlanTier::Pro,
+            "enterprise" => PlanTier::Enterprise,
+            _ => PlanTier::Free,
+        }
+    }
+}
+
+/// How participants are told about recording. Enforced by the MC.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingConsentMode {
+    /// A notice is sufficient.
+    Notify,
+    /// Participants must accept within the timeout or are removed.
+    #[default]
+    RequireAck,
+}
+
+impl RecordingConsentMode {
+    /// Database representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingConsentMode::Notify => "notify",
+            RecordingConsentMode::RequireAck => "require_ack",
+        }
+    }
+
+    /// Parse the database representation. Unknown values fall back to the
+    /// column default, `require_ack`.
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "notify" => RecordingConsentMode::Notify,
+            _ => RecordingConsentMode::RequireAck,
+        }
+    }
+}
+
+/// Organization policies in an [`OrgConfig`].
+///
+/// Omitted fields take the column defaults, so a document always describes
+/// the complete policy set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OrgPolicies {
+    /// Scheduled plus active meetings allowed at once (default: 10).
+    pub max_concurrent_meetings: i32,
+
+    /// Participant cap for every meeting (default: 100).
+    pub max_participants_per_meeting: i32,
+
+    /// Recording consent mode (default: require_ack).
+    pub recording_consent_mode: RecordingConsentMode,
+
+    /// Time to accept recording before removal (default: 60, 10-600).
+    pub recording_consent_timeout_seconds: i32,
+
+    /// Dedicated MC pool (default: none, the shared pool).
+    pub mc_pool: Option<String>,
+}
+
+impl Default for OrgPolicies {
+    fn default() -> Self {
+        Self {
+            max_concurrent_meetings: 10,
+            max_participants_per_meeting: DEFAULT_MAX_PARTICIPANTS,
+            recording_consent_mode: RecordingConsentMode::default(),
+            recording_consent_timeout_seconds: 60,
+            mc_pool: None,
+        }
+    }
+}
+
+fn default_org_active() -> bool {
+    true
+}
+
+/// Declarative organization config.
+///
+/// Body of `PUT /api/v1/admin/orgs/{subdomain}/config` and response of the
+/// matching GET. The document is the desired state: reconciling it creates
+/// the organization if needed and sets every field to the given value (or
+/// its default when omitted).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OrgConfig {
+    /// Organization display name (required, 1-255 bytes after trimming).
+    pub display_name: String,
+
+    /// Plan tier (default: free).
+    #[serde(default)]
+    pub plan_tier: PlanTier,
+
+    /// Whether the organization can create and join meetings (default: true).
+    #[serde(default = "default_org_active")]
+    pub active: bool,
+
+    /// Organization policies.
+    #[serde(default)]
+    pub policies: OrgPolicies,
+}
+
+impl OrgConfig {
+    /// Validate the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if validation fails.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let display_name = self.display_name.trim();
+        if display_name.is_empty() || display_name.len() > MAX_ORG_DISPLAY_NAME_LENGTH {
+            return Err("Display name must be 1-255 characters");
+        }
+
+        let policies = &self.policies;
+        if policies.max_concurrent_meetings < 1 {
+            return Err("max_concurrent_meetings must be at least 1");
+        }
+        if policies.max_participants_per_meeting < MIN_PARTICIPANTS {
+            return Err("max_participants_per_meeting must be at least 2");
+        }
+        if !RECORDING_CONSENT_TIMEOUT_RANGE.contains(&policies.recording_consent_timeout_seconds) {
+            return Err("recording_consent_timeout_seconds must be between 10 and 600");
+        }
+        if let Some(pool) = &policies.mc_pool {
+            if pool.is_empty() || pool.len() > MAX_MC_POOL_LENGTH {
+                return Err("mc_pool must be 1-64 characters");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fields in document order, keyed by their JSON path.
+    fn fields(&self) -> [(&'static str, serde_json::Value); 8] {
+        let policies = &self.policies;
+        [
+            ("display_name", self.display_name.trim().into()),
+            ("plan_tier", self.plan_tier.as_str().into()),
+            ("active", self.active.into()),
+            (
+                "policies.max_concurrent_meetings",
+                policies.max_concurrent_meetings.into(),
+            ),
+            (
+                "policies.max_participants_per_meeting",
+                policies.max_participants_per_meeting.into(),
+            ),
+            (
+                "policies.recording_consent_mode",
+                policies.recording_consent_mode.as_str().into(),
+            ),
+            (
+                "policies.recording_consent_timeout_seconds",
+                policies.recording_consent_timeout_seconds.into(),
+            ),
+            ("policies.mc_pool", policies.mc_pool.clone().into()),
+        ]
+    }
+
+    /// Changes needed to go from `current` to this document.
+    ///
+    /// With no current config (organization not created yet) every field is
+    /// reported, changing from `null`.
+    pub fn diff(&self, current: Option<&OrgConfig>) -> Vec<OrgConfigChange> {
+        let current = current.map(OrgConfig::fields);
+
+        self.fields()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, (field, to))| {
+                let from = current
+                    .as_ref()
+                    .and_then(|fields| fields.get(index))
+                    .map(|(_, value)| value.clone())
+                    .unwrap_or(serde_json::Value::Null);
+                (from != to || current.is_none()).then(|| OrgConfigChange {
+                    field: field.to_string(),
+                    from,
+                    to,
+                })
+            })
+            .collect()
+    }
+}
+
+/// One field changed by a reconcile.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OrgConfigChange {
+    /// JSON path of the field, e.g. `policies.mc_pool`.
+    pub field: String,
+
+    /// Value before the reconcile (`null` when the org is created).
+    pub from: serde_json::Value,
+
+    /// Value after the reconcile.
+    pub to: serde_json::Value,
+}
+
+/// What a reconcile did (or would do, on a dry run).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrgConfigAction {
+    /// The organization was created.
+    Created,
+    /// Existing fields were changed.
+    Updated,
+    /// Already matched the document.
+    Unchanged,
+}
+
+impl OrgConfigAction {
+    /// Metric label representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrgConfigAction::Created => "created",
+            OrgConfigAction::Updated => "updated",
+            OrgConfigAction::Unchanged => "unchanged",
+        }
+    }
+}
+
+/// Result of reconciling an [`OrgConfig`].
+#[derive(Debug, Clone, Serialize)]
+pub struct OrgConfigReconcileResponse {
+    /// Organization ID (absent on a dry run that would create the org).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<Uuid>,
+
+    /// Organization subdomain.
+    pub subdomain: String,
+
+    /// What was done.
+    pub action: OrgConfigAction,
+
+    /// Whether this was a dry run (nothing written).
+    pub dry_run: bool,
+
+    /// Changed fields, in document order.
+    pub changes: Vec<OrgConfigChange>,
+}
+
+// ============================================================================
+// Meeting Recording API Models
+// ============================================================================
+
+/// A meeting recording.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingResponse {
+    /// Recording ID.
+    pub recording_id: Uuid,
+
+    /// MIME type of the recording file.
+    pub content_type: String,
+
+    /// File size in bytes.
+    pub size_bytes: i64,
+
+    /// Recorded duration in seconds.
+    pub duration_seconds: i64,
+
+    /// When recording started.
+    pub started_at: DateTime<Utc>,
 
-/// Minimum number of participants for a meeting.
-pub const MIN_PARTICIPANTS: i32 = 2;
+    /// When recording finished.
+    pub completed_at: DateTime<Utc>,
 
-/// Default maximum participants if not specified in request.
-pub const DEFAULT_MAX_PARTICIPANTS: i32 = 100;
+    /// When the recording will be removed by the retention policy.
+    pub expires_at: DateTime<Utc>,
+}
 
-/// Request to create a new meeting.
+/// Recordings of a meeting.
 ///
-/// Sent by authenticated users to create a meeting in their organization.
-/// All settings fields are optional; secure defaults are applied server-side.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct CreateMeetingRequest {
-    /// Meeting display name (required, 1-255 bytes after trimming).
-    pub display_name: String,
+/// Returned by `GET /api/v1/meetings/{id}/recordings`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListRecordingsResponse {
+    /// Recordings in start order.
+    pub recordings: Vec<RecordingResponse>,
+}
 
-    /// Maximum number of participants (optional, default 100, min 2).
-    pub max_participants: Option<i32>,
+/// Pre-signed download details.
+///
+/// Returned by `GET /api/v1/meetings/{id}/recordings/{recording_id}/download`.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingDownloadResponse {
+    /// Recording ID.
+    pub recording_id: Uuid,
 
-    /// Scheduled start time (optional, NULL = ad-hoc meeting).
-    pub scheduled_start_time: Option<DateTime<Utc>>,
+    /// Pre-signed download URL (`GET`).
+    pub download_url: String,
 
-    /// Whether end-to-end encryption is enabled (default: true).
-    pub enable_e2e_encryption: Option<bool>,
+    /// When the download URL expires.
+    pub expires_at: DateTime<Utc>,
+}
 
-    /// Whether authentication is required to join (default: true).
-    pub require_auth: Option<bool>,
+/// A meeting controller as seen by operators.
+#[derive(Debug, Clone, Serialize)]
+pub struct AdminControllerResponse {
+    /// Controller ID.
+    pub controller_id: String,
 
-    /// Whether recording is enabled (default: false).
-    pub recording_enabled: Option<bool>,
+    /// Region the controller serves.
+    pub region: String,
 
-    /// Whether anonymous guests can join (default: false).
-    pub allow_guests: Option<bool>,
+    /// Capacity pool.
+    pub pool: String,
 
-    /// Whether external org users can join (default: false).
-    pub allow_external_participants: Option<bool>,
+    /// Health status reported by the last heartbeat.
+    pub health_status: String,
 
-    /// Whether waiting room is enabled (default: true).
-    pub waiting_room_enabled: Option<bool>,
-}
+    /// Meetings currently hosted.
+    pub current_meetings: i32,
 
-impl CreateMeetingRequest {
-    /// Validate the request fields.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error message if validation fails.
-    pub fn validate(&self) -> Result<(), &'static str> {
-        let display_name = self.display_name.trim();
+    /// Meeting capacity.
+    pub max_meetings: i32,
 
-        if display_name.len() < MIN_MEETING_DISPLAY_NAME_LENGTH {
-            return Err("Display name is required");
-        }
+    /// Primary this controller is a warm standby for, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub standby_for: Option<String>,
 
-        if display_name.len() > MAX_MEETING_DISPLAY_NAME_LENGTH {
-            return Err("Display name must be at most 255 characters");
-        }
+    /// When the controller was cordoned (absent = takes new meetings).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cordoned_at: Option<DateTime<Utc>>,
 
-        if let Some(max_participants) = self.max_participants {
-            if max_participants < MIN_PARTICIPANTS {
-                return Err("Maximum participants must be at least 2");
-            }
-        }
+    /// Version reported at registration (absent = not reported).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
 
-        Ok(())
-    }
+    /// Commit the controller was built from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_sha: Option<String>,
+
+    /// Protobuf schema the controller was compiled against.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proto_schema: Option<String>,
+
+    /// Last heartbeat time.
+    pub last_heartbeat_at: DateTime<Utc>,
 }
 
-/// Response after creating a meeting.
+/// Registered meeting controllers.
 ///
-/// Returned by `POST /api/v1/meetings` with status 201 Created.
-/// Excludes `join_token_secret` and other internal fields.
+/// Returned by `GET /api/v1/admin/controllers`.
 #[derive(Debug, Clone, Serialize)]
-pub struct CreateMeetingResponse {
-    /// Unique meeting identifier.
-    pub meeting_id: Uuid,
+pub struct ListControllersResponse {
+    /// Controllers by region then ID.
+    pub controllers: Vec<AdminControllerResponse>,
+}
 
-    /// Short meeting code for joining.
-    pub meeting_code: String,
+/// A media handler as seen by operators.
+#[derive(Debug, Clone, Serialize)]
+pub struct AdminHandlerResponse {
+    /// Handler ID.
+    pub handler_id: String,
 
-    /// Meeting display name.
-    pub display_name: String,
+    /// Region the handler serves.
+    pub region: String,
 
-    /// Current meeting status.
-    pub status: String,
+    /// Health status reported by the last load report.
+    pub health_status: String,
 
-    /// Maximum number of participants.
-    pub max_participants: i32,
+    /// Streams currently handled.
+    pub current_streams: i32,
 
-    /// Whether end-to-end encryption is enabled.
-    pub enable_e2e_encryption: bool,
+    /// Stream capacity.
+    pub max_streams: i32,
 
-    /// Whether authentication is required to join.
-    pub require_auth: bool,
+    /// When the handler was cordoned (absent = takes new meetings).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cordoned_at: Option<DateTime<Utc>>,
 
-    /// Whether recording is enabled.
-    pub recording_enabled: bool,
+    /// Version reported at registration (absent = not reported).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
 
-    /// Whether anonymous guests can join.
-    pub allow_guests: bool,
+    /// Commit the handler was built from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_sha: Option<String>,
 
-    /// Whether external org users can join.
-    pub allow_external_participants: bool,
+    /// Protobuf schema the handler was compiled against.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proto_schema: Option<String>,
 
-    /// Whether waiting room is enabled.
-    pub waiting_room_enabled: bool,
+    /// Last heartbeat time.
+    pub last_heartbeat_at: DateTime<Utc>,
+}
 
-    /// Creation timestamp.
-    pub created_at: DateTime<Utc>,
+/// Registered media handlers.
+///
+/// Returned by `GET /api/v1/admin/handlers`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListHandlersResponse {
+    /// Handlers by region then ID.
+    pub handlers: Vec<AdminHandlerResponse>,
 }
 
-impl From<MeetingRow> for CreateMeetingResponse {
-    fn from(row: MeetingRow) -> Self {
-        Self {
-            meeting_id: row.meeting_id,
-            meeting_code: row.meeting_code,
-            display_name: row.display_name,
-            status: row.status,
-            max_participants: row.max_participants,
-            enable_e2e_encryption: row.enable_e2e_encryption,
-            require_auth: row.require_auth,
-            recording_enabled: row.recording_enabled,
-            allow_guests: row.allow_guests,
-            allow_external_participants: row.allow_external_participants,
-            waiting_room_enabled: row.waiting_room_enabled,
-            created_at: row.created_at,
-        }
-    }
+/// Registered instances running one build.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FleetVersionCount {
+    /// Reported version (`unknown` = not reported).
+    pub version: String,
+
+    /// Reported protobuf schema (`unknown` = not reported).
+    pub proto_schema: String,
+
+    /// Healthy, pending, degraded, or draining instances on this build.
+    pub count: u64,
+
+    /// Whether the instances were compiled against the same protobuf schema
+    /// as this GC.
+    pub matches_gc_schema: bool,
 }
 
-impl From<MeetingRow> for MeetingResponse {
-    fn from(row: MeetingRow) -> Self {
-        Self {
-            meeting_id: row.meeting_id,
-            display_name: row.display_name,
-            meeting_code: row.meeting_code,
-            status: row.status,
-            allow_guests: row.allow_guests,
-            allow_external_participants: row.allow_external_participants,
-            waiting_room_enabled: row.waiting_room_enabled,
-            updated_at: row.updated_at,
-        }
-    }
+/// MC and MH version distribution.
+///
+/// Returned by `GET /api/v1/admin/versions`.
+#[derive(Debug, Clone, Serialize)]
+pub struct FleetVersionsResponse {
+    /// This GC's version.
+    pub gc_version: String,
+
+    /// Protobuf schema this GC was compiled against.
+    pub gc_proto_schema: String,
+
+    /// Oldest version allowed to register (absent = no minimum).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_fleet_version: Option<String>,
+
+    /// Meeting controllers by version.
+    pub controllers: Vec<FleetVersionCount>,
+
+    /// Media handlers by version.
+    pub handlers: Vec<FleetVersionCount>,
 }
 
 #[cfg(test)]
@@ -571,11 +1688,14 @@ mod tests {
                 webtransport_endpoint: Some("https://mc.example.com:443".to_string()),
                 grpc_endpoint: "https://mc.example.com:50051".to_string(),
             },
+            join_id: Uuid::nil(),
+            experiments: BTreeMap::new(),
         };
 
         let json = serde_json::to_string(&response).expect("serialization should succeed");
 
         assert!(json.contains("\"token\":\"eyJ"));
+        assert!(!json.contains("experiments"));
         assert!(json.contains("\"expires_in\":900"));
         assert!(json.contains("\"meeting_name\":\"Test Meeting\""));
         assert!(json.contains("\"mc_id\":\"mc-001\""));
@@ -611,6 +1731,33 @@ mod tests {
         assert!(json.contains("\"grpc_endpoint\":\"https://mc:50051\""));
     }
 
+    #[test]
+    fn test_preflight_response_serialization_unassigned() {
+        let response = PreflightResponse {
+            meeting_id: Uuid::nil(),
+            ready: true,
+            mc_assignment: None,
+            mc_capacity_available: true,
+            media_handlers: vec![PreflightMediaHandler {
+                mh_id: "mh-001".to_string(),
+                webtransport_endpoint: "https://mh.example.com:443".to_string(),
+            }],
+            min_protocol_version: 1,
+            relay: RelayInfo {
+                available: false,
+                turn_servers: vec![],
+            },
+            features: BTreeMap::from([("e2e_encryption".to_string(), true)]),
+        };
+
+        let json = serde_json::to_string(&response).expect("serialization should succeed");
+        assert!(!json.contains("mc_assignment"));
+        assert!(json.contains("\"mc_capacity_available\":true"));
+        assert!(json.contains("\"mh_id\":\"mh-001\""));
+        assert!(json.contains("\"relay\":{\"available\":false,\"turn_servers\":[]}"));
+        assert!(json.contains("\"features\":{\"e2e_encryption\":true}"));
+    }
+
     #[test]
     fn test_guest_join_request_deserialization() {
         let json = r#"{"display_name":"John Doe","captcha_token":"abc123"}"#;
@@ -701,6 +1848,48 @@ mod tests {
         assert_eq!(request.allow_guests, Some(true));
         assert_eq!(request.allow_external_participants, None);
         assert_eq!(request.waiting_room_enabled, Some(false));
+        assert_eq!(request.duplicate_join_policy, None);
+        assert_eq!(request.recording_layout, None);
+    }
+
+    #[test]
+    fn test_update_meeting_settings_duplicate_join_policy() {
+        let json = r#"{"duplicate_join_policy":"reject"}"#;
+        let request: UpdateMeetingSettingsRequest =
+            serde_json::from_str(json).expect("deserialization should succeed");
+        assert_eq!(
+            request.duplicate_join_policy,
+            Some(DuplicateJoinPolicy::Reject)
+        );
+
+        let json = r#"{"duplicate_join_policy":"kick_everyone"}"#;
+        let result: Result<UpdateMeetingSettingsRequest, _> = serde_json::from_str(json);
+        assert!(result.is_err(), "Should reject unknown policies");
+
+        for policy in [
+            DuplicateJoinPolicy::Allow,
+            DuplicateJoinPolicy::Takeover,
+            DuplicateJoinPolicy::Reject,
+        ] {
+            assert_eq!(DuplicateJoinPolicy::from_db(policy.as_str()), policy);
+        }
+    }
+
+    #[test]
+    fn test_update_meeting_settings_recording_layout() {
+        let json = r#"{"recording_layout":"speaker"}"#;
+        let request: UpdateMeetingSettingsRequest =
+            serde_json::from_str(json).expect("deserialization should succeed");
+        assert_eq!(request.recording_layout, Some(RecordingLayout::Speaker));
+        assert!(request.has_changes());
+
+        let json = r#"{"recording_layout":"mosaic"}"#;
+        let result: Result<UpdateMeetingSettingsRequest, _> = serde_json::from_str(json);
+        assert!(result.is_err(), "Should reject unknown layouts");
+
+        for layout in [RecordingLayout::Grid, RecordingLayout::Speaker] {
+            assert_eq!(RecordingLayout::from_db(layout.as_str()), layout);
+        }
     }
 
     #[test]
@@ -717,13 +1906,26 @@ mod tests {
             allow_guests: Some(true),
             allow_external_participants: None,
             waiting_room_enabled: None,
+            duplicate_join_policy: None,
+            recording_layout: None,
         };
         assert!(request_with_changes.has_changes());
 
+        let policy_only = UpdateMeetingSettingsRequest {
+            allow_guests: None,
+            allow_external_participants: None,
+            waiting_room_enabled: None,
+            duplicate_join_policy: Some(DuplicateJoinPolicy::Reject),
+            recording_layout: None,
+        };
+        assert!(policy_only.has_changes());
+
         let request_no_changes = UpdateMeetingSettingsRequest {
             allow_guests: None,
             allow_external_participants: None,
             waiting_room_enabled: None,
+            duplicate_join_policy: None,
+            recording_layout: None,
         };
         assert!(!request_no_changes.has_changes());
     }
@@ -755,6 +1957,26 @@ mod tests {
         assert_eq!(request.scheduled_start_time, None);
     }
 
+    #[test]
+    fn test_create_meeting_request_priority() {
+        let json = r#"{"display_name":"All Hands","priority":"webinar"}"#;
+        let request: CreateMeetingRequest =
+            serde_json::from_str(json).expect("deserialization should succeed");
+        assert_eq!(request.priority, Some(MeetingPriority::Webinar));
+
+        let json = r#"{"display_name":"All Hands","priority":"urgent"}"#;
+        let result: Result<CreateMeetingRequest, _> = serde_json::from_str(json);
+        assert!(result.is_err(), "Should reject unknown priorities");
+
+        for priority in [
+            MeetingPriority::Webinar,
+            MeetingPriority::Standard,
+            MeetingPriority::Test,
+        ] {
+            assert_eq!(MeetingPriority::from_db(priority.as_str()), priority);
+        }
+    }
+
     #[test]
     fn test_create_meeting_request_rejects_unknown_fields() {
         let json = r#"{"display_name":"Test","extra_field":"value"}"#;
@@ -774,6 +1996,7 @@ mod tests {
             allow_guests: None,
             allow_external_participants: None,
             waiting_room_enabled: None,
+            priority: None,
         };
         assert!(request.validate().is_ok());
     }
@@ -790,6 +2013,7 @@ mod tests {
             allow_guests: None,
             allow_external_participants: None,
             waiting_room_enabled: None,
+            priority: None,
         };
         let result = request.validate();
         assert!(result.is_err());
@@ -808,6 +2032,7 @@ mod tests {
             allow_guests: None,
             allow_external_participants: None,
             waiting_room_enabled: None,
+            priority: None,
         };
         let result = request.validate();
         assert!(result.is_err(), "Should reject whitespace-only name");
@@ -825,6 +2050,7 @@ mod tests {
             allow_guests: None,
             allow_external_participants: None,
             waiting_room_enabled: None,
+            priority: None,
         };
         let result = request.validate();
         assert!(result.is_err());
@@ -846,6 +2072,7 @@ mod tests {
             allow_guests: None,
             allow_external_participants: None,
             waiting_room_enabled: None,
+            priority: None,
         };
         let result = request.validate();
         assert!(result.is_err());
@@ -867,6 +2094,7 @@ mod tests {
             allow_guests: None,
             allow_external_participants: None,
             waiting_room_enabled: None,
+            priority: None,
         };
         assert!(request.validate().is_ok(), "max_participants=2 should pass");
     }
@@ -889,6 +2117,7 @@ mod tests {
             allow_guests: false,
             allow_external_participants: false,
             waiting_room_enabled: true,
+            priority: "standard".to_string(),
             created_at: Utc::now(),
         };
 
@@ -929,6 +2158,9 @@ mod tests {
             allow_guests: false,
             allow_external_participants: false,
             waiting_room_enabled: true,
+            duplicate_join_policy: "takeover".to_string(),
+            recording_layout: "grid".to_string(),
+            priority: "webinar".to_string(),
         };
 
         let response = CreateMeetingResponse::from(row.clone());
@@ -938,10 +2170,261 @@ mod tests {
         assert_eq!(response.display_name, "From Row");
         assert_eq!(response.max_participants, 50);
         assert_eq!(response.status, "scheduled");
+        assert_eq!(response.priority, "webinar");
 
         // Serialize and verify no join_token_secret
         let json = serde_json::to_string(&response).unwrap();
         assert!(!json.contains("join_token_secret"));
         assert!(!json.contains("should_not_appear_in_response"));
     }
+
+    #[test]
+    fn test_create_asset_request_validation() {
+        let valid = CreateAssetRequest {
+            file_name: "diagram.png".to_string(),
+            content_type: "image/png".to_string(),
+            size_bytes: 2048,
+        };
+        assert!(valid.validate().is_ok());
+
+        let invalid = [
+            CreateAssetRequest {
+                file_name: "  ".to_string(),
+                ..valid.clone()
+            },
+            CreateAssetRequest {
+                file_name: "a".repeat(MAX_ASSET_FILE_NAME_LENGTH + 1),
+                ..valid.clone()
+            },
+            CreateAssetRequest {
+                file_name: "bad\nname".to_string(),
+                ..valid.clone()
+            },
+            CreateAssetRequest {
+                content_type: "image".to_string(),
+                ..valid.clone()
+            },
+            CreateAssetRequest {
+                content_type: "text/html; charset=utf-8".to_string(),
+                ..valid.clone()
+            },
+            CreateAssetRequest {
+                size_bytes: 0,
+                ..valid.clone()
+            },
+            CreateAssetRequest {
+                size_bytes: MAX_ASSET_SIZE_BYTES + 1,
+                ..valid.clone()
+            },
+        ];
+        for request in invalid {
+            assert!(
+                request.validate().is_err(),
+                "{request:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_create_asset_request_rejects_unknown_fields() {
+        let result: Result<CreateAssetRequest, _> = serde_json::from_str(
+            r#"{"file_name":"a.txt","content_type":"text/plain","size_bytes":1,"object_key":"x"}"#,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_client_error_report_validation() {
+        let valid: ClientErrorReport = serde_json::from_str(
+            r#"{"kind":"transport_fallback","platform":"web","error_code":"DT-MC-2022"}"#,
+        )
+        .unwrap();
+        assert_eq!(valid.kind, ClientErrorKind::TransportFallback);
+        assert_eq!(valid.platform, ClientPlatform::Web);
+        assert_eq!(valid.sample_rate, 1.0);
+        assert!(valid.validate().is_ok());
+        assert_eq!(valid.weight(), 1);
+
+        let sampled = ClientErrorReport {
+            sample_rate: 0.1,
+            ..valid.clone()
+        };
+        assert!(sampled.validate().is_ok());
+        assert_eq!(sampled.weight(), 10);
+
+        let invalid = [
+            ClientErrorReport {
+                sample_rate: 0.0,
+                ..valid.clone()
+            },
+            ClientErrorReport {
+                sample_rate: 1.5,
+                ..valid.clone()
+            },
+            ClientErrorReport {
+                sample_rate: f64::NAN,
+                ..valid.clone()
+            },
+            ClientErrorReport {
+                error_code: Some(String::new()),
+                ..valid.clone()
+            },
+            ClientErrorReport {
+                error_code: Some("a".repeat(MAX_CLIENT_ERROR_CODE_LENGTH + 1)),
+                ..valid.clone()
+            },
+            ClientErrorReport {
+                error_code: Some("bad code".to_string()),
+                ..valid.clone()
+            },
+        ];
+        for report in invalid {
+            assert!(report.validate().is_err(), "{report:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_client_error_report_rejects_unknown_labels() {
+        for body in [
+            r#"{"kind":"crashed","platform":"web"}"#,
+            r#"{"kind":"join_failed","platform":"smart_fridge"}"#,
+            r#"{"kind":"join_failed","platform":"web","user_agent":"x"}"#,
+        ] {
+            let result: Result<ClientErrorReport, _> = serde_json::from_str(body);
+            assert!(result.is_err(), "{body} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_meeting_import_record_validation() {
+        let valid: MeetingImportRecord = serde_json::from_str(
+            r#"{"external_id":"legacy-42","display_name":"Weekly Sync","priority":"webinar"}"#,
+        )
+        .unwrap();
+        assert_eq!(valid.priority, Some(MeetingPriority::Webinar));
+        assert!(valid.validate().is_ok());
+
+        let invalid = [
+            MeetingImportRecord {
+                display_name: "   ".to_string(),
+                ..valid.clone()
+            },
+            MeetingImportRecord {
+                max_participants: Some(1),
+                ..valid.clone()
+            },
+            MeetingImportRecord {
+                external_id: Some(String::new()),
+                ..valid.clone()
+            },
+            MeetingImportRecord {
+                external_id: Some("x".repeat(MAX_IMPORT_EXTERNAL_ID_LENGTH + 1)),
+                ..valid.clone()
+            },
+        ];
+        for record in invalid {
+            assert!(record.validate().is_err(), "{record:?} should be rejected");
+        }
+
+        let unknown: Result<MeetingImportRecord, _> =
+            serde_json::from_str(r#"{"display_name":"x","host_email":"a@b.c"}"#);
+        assert!(unknown.is_err());
+    }
+
+    #[test]
+    fn test_meeting_import_status_db_round_trip() {
+        for status in [
+            MeetingImportStatus::Running,
+            MeetingImportStatus::Succeeded,
+            MeetingImportStatus::Failed,
+        ] {
+            assert_eq!(MeetingImportStatus::from_db(status.as_str()), status);
+        }
+        assert_eq!(
+            MeetingImportStatus::from_db("bogus"),
+            MeetingImportStatus::Failed
+        );
+    }
+
+    fn org_config() -> OrgConfig {
+        serde_json::from_str(r#"{"display_name":"Acme Corp"}"#).unwrap()
+    }
+
+    #[test]
+    fn test_org_config_defaults_match_columns() {
+        let config = org_config();
+        assert_eq!(config.plan_tier, PlanTier::Free);
+        assert!(config.active);
+        assert_eq!(config.policies, OrgPolicies::default());
+        assert!(config.validate().is_ok());
+
+        let unknown: Result<OrgConfig, _> =
+            serde_json::from_str(r#"{"display_name":"x","webhooks":[]}"#);
+        assert!(unknown.is_err());
+        let unknown_policy: Result<OrgConfig, _> =
+            serde_json::from_str(r#"{"display_name":"x","policies":{"retention_days":7}}"#);
+        assert!(unknown_policy.is_err());
+    }
+
+    #[test]
+    fn test_org_config_validate_rejects_out_of_range() {
+        let valid = org_config();
+        let mutations: [fn(&mut OrgConfig); 5] = [
+            |c| c.display_name = " ".to_string(),
+            |c| c.policies.max_concurrent_meetings = 0,
+            |c| c.policies.max_participants_per_meeting = 1,
+            |c| c.policies.recording_consent_timeout_seconds = 601,
+            |c| c.policies.mc_pool = Some(String::new()),
+        ];
+
+        for mutate in mutations {
+            let mut config = valid.clone();
+            mutate(&mut config);
+            assert!(config.validate().is_err(), "{config:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_org_config_diff() {
+        let current = org_config();
+
+        let created = current.diff(None);
+        assert_eq!(created.len(), 8);
+        assert!(created.iter().all(|c| c.from.is_null()));
+
+        assert!(current.diff(Some(&current)).is_empty());
+
+        let mut desired = current.clone();
+        desired.policies.mc_pool = Some("acme-dedicated".to_string());
+        desired.policies.max_concurrent_meetings = 50;
+        let changes = desired.diff(Some(&current));
+        assert_eq!(
+            changes,
+            vec![
+                OrgConfigChange {
+                    field: "policies.max_concurrent_meetings".to_string(),
+                    from: 10.into(),
+                    to: 50.into(),
+                },
+                OrgConfigChange {
+                    field: "policies.mc_pool".to_string(),
+                    from: serde_json::Value::Null,
+                    to: "acme-dedicated".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_org_enums_db_round_trip() {
+        for tier in [PlanTier::Free, PlanTier::Pro, PlanTier::Enterprise] {
+            assert_eq!(PlanTier::from_db(tier.as_str()), tier);
+        }
+        for mode in [
+            RecordingConsentMode::Notify,
+            RecordingConsentMode::RequireAck,
+        ] {
+            assert_eq!(RecordingConsentMode::from_db(mode.as_str()), mode);
+        }
+    }
 }
@@ -30,6 +30,8 @@
 
 use metrics::{counter, gauge, histogram};
 use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use std::collections::BTreeSet;
+use std::sync::{Mutex, PoisonError};
 use std::time::Duration;
 
 /// Initialize Prometheus metrics recorder and return the handle
@@ -169,6 +171,8 @@ fn normalize_endpoint(path: &str) -> String {
         "/metrics" => "/metrics".to_string(),
         "/api/v1/me" => "/api/v1/me".to_string(),
         "/api/v1/meetings" => "/api/v1/meetings".to_string(),
+        "/api/v1/client-errors" => "/api/v1/client-errors".to_string(),
+        "/api/v1/meeting-imports" => "/api/v1/meeting-imports".to_string(),
         _ => normalize_dynamic_endpoint(path),
     }
 }
@@ -195,6 +199,15 @@ fn normalize_dynamic_endpoint(path: &str) -> String {
             }
         }
 
+        // /api/v1/meetings/{code}/preflight → parts.len() == 6
+        if parts.len() == 6 {
+            if let Some(action) = parts.get(5) {
+                if *action == "preflight" {
+                    return "/api/v1/meetings/{code}/preflight".to_string();
+                }
+            }
+        }
+
         // /api/v1/meetings/{id}/settings → parts.len() == 6
         if parts.len() == 6 {
             if let Some(action) = parts.get(5) {
@@ -205,6 +218,19 @@ fn normalize_dynamic_endpoint(path: &str) -> String {
         }
     }
 
+    // Meeting import status: /api/v1/meeting-imports/{id} → parts.len() == 5
+    if path.starts_with("/api/v1/meeting-imports/") && path.split('/').count() == 5 {
+        return "/api/v1/meeting-imports/{id}".to_string();
+    }
+
+    // Org config: /api/v1/admin/orgs/{subdomain}/config → parts.len() == 7
+    if path.starts_with("/api/v1/admin/orgs/") {
+        let parts: Vec<&str> = path.split('/').collect();
+        if parts.len() == 7 && parts.get(6) == Some(&"config") {
+            return "/api/v1/admin/orgs/{subdomain}/config".to_string();
+        }
+    }
+
     // Unknown paths normalized to "/other" to bound cardinality
     "/other".to_string()
 }
@@ -234,6 +260,108 @@ pub fn record_mc_assignment(status: &str, rejection_reason: Option<&str>, durati
     .increment(1);
 }
 
+/// Record where a new meeting assignment was placed, by meeting priority
+///
+/// Metric: `gc_mc_assignments_by_priority_total`
+/// Labels: `priority`, `outcome`
+///
+/// Priorities: webinar, standard, test
+/// Outcomes: assigned (MC with headroom), spillover (spill-over pool),
+///           rejected (no MC accepted the meeting)
+pub fn record_priority_assignment(priority: &str, outcome: &str) {
+    counter!("gc_mc_assignments_by_priority_total",
+        "priority" => priority.to_string(),
+        "outcome" => outcome.to_string()
+    )
+    .increment(1);
+}
+
+// ============================================================================
+// Client Error Metrics
+// ============================================================================
+
+/// Record a client-side failure report
+///
+/// Metric: `gc_client_errors_total`
+/// Labels: `kind`, `platform`
+///
+/// Kinds: join_failed, transport_fallback, codec_unsupported
+/// Platforms: web, ios, android, desktop
+///
+/// `weight` is the number of failures the report stands for (the inverse of
+/// the client's sample rate), so rates stay comparable across sampling.
+pub fn record_client_error(kind: &str, platform: &str, weight: u64) {
+    counter!("gc_client_errors_total",
+        "kind" => kind.to_string(),
+        "platform" => platform.to_string()
+    )
+    .increment(weight);
+}
+
+// ============================================================================
+// Meeting Import Metrics
+// ============================================================================
+
+/// Record a finished or rejected meeting import
+///
+/// Metrics: `gc_meeting_imports_total`, `gc_meeting_import_records_total`
+/// Labels: `status`
+///
+/// Statuses: succeeded, failed (accepted but nothing created),
+///           rejected (validation failed at submission, no job created)
+///
+/// `gc_meeting_import_records_total` counts the meetings created by
+/// successful imports.
+pub fn record_meeting_import(status: &str, imported_records: u64) {
+    counter!("gc_meeting_imports_total",
+        "status" => status.to_string()
+    )
+    .increment(1);
+
+    if imported_records > 0 {
+        counter!("gc_meeting_import_records_total").increment(imported_records);
+    }
+}
+
+// ============================================================================
+// Event Bus Metrics
+// ============================================================================
+
+/// Record a meeting lifecycle event seen by the usage metering consumer
+///
+/// Metric: `gc_meeting_events_total`
+/// Labels: `event`
+///
+/// Events: meeting_created, assignment_changed, meeting_settings_updated
+/// (see `GcEvent::name`)
+pub fn record_meeting_event(event: &'static str) {
+    counter!("gc_meeting_events_total",
+        "event" => event
+    )
+    .increment(1);
+}
+
+// ============================================================================
+// Org Config Metrics
+// ============================================================================
+
+/// Record a declarative org config reconcile
+///
+/// Metric: `gc_org_config_reconciles_total`
+/// Labels: `action`, `mode`
+///
+/// Actions: created, updated, unchanged
+/// Modes: apply, dry_run
+pub fn record_org_config_reconcile(action: &str, dry_run: bool) {
+    let mode = if dry_run { "dry_run" } else { "apply" };
+
+    counter!("gc_org_config_reconciles_total",
+        "action" => action.to_string(),
+        "mode" => mode
+    )
+    .increment(1);
+}
+
 // ============================================================================
 // Database Metrics
 // ============================================================================
@@ -363,9 +491,10 @@ pub fn record_error(operation: &str, error_type: &str, status_code: u16) {
 /// Token type values: "service" (gRPC auth only sees service tokens;
 ///   user/guest tokens flow through HTTP middleware and are not recorded here)
 /// Failure reason values: "none" (success), "signature_invalid", "expired",
-///   "missing_token", "scope_mismatch", "malformed"
+///   "missing_token", "scope_mismatch", "malformed", "dpop_bound" (a
+///   DPoP-bound token, which gRPC cannot carry a proof for)
 ///
-/// Cardinality: bounded (2 x 1 x 6 = 12 max, plus headroom if token_type
+/// Cardinality: bounded (2 x 1 x 7 = 14 max, plus headroom if token_type
 /// expands in the future).
 ///
 /// Recorded in `grpc/auth_layer.rs` for every validation attempt that
@@ -600,6 +729,79 @@ pub fn update_registered_controller_gauges(controller_type: &str, counts: &[(Str
     }
 }
 
+// ============================================================================
+// Fleet Version Metrics
+// ============================================================================
+
+/// Label sets last set on `gc_fleet_versions`, so versions that leave the
+/// fleet are zeroed instead of reporting their last count forever.
+static FLEET_VERSION_LABELS: Mutex<BTreeSet<(String, String, String)>> =
+    Mutex::new(BTreeSet::new());
+
+/// Update the fleet version distribution for a controller type.
+///
+/// Metric: `gc_fleet_versions`
+/// Labels: `controller_type`, `version`, `proto_schema`
+///
+/// Cardinality: bounded by the builds in flight at once, normally one or two
+/// per type during a rolling deploy. Instances that registered without
+/// reporting a build are counted under `unknown`.
+///
+/// # Arguments
+///
+/// * `controller_type` - Type of controller: "meeting" or "media"
+/// * `counts` - (version, proto_schema, count) for every build currently registered
+pub fn update_fleet_version_gauges(controller_type: &str, counts: &[(String, String, u64)]) {
+    let mut labels = FLEET_VERSION_LABELS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    let current: BTreeSet<(String, String, String)> = counts
+        .iter()
+        .map(|(version, proto_schema, _)| {
+            (
+                controller_type.to_string(),
+                version.clone(),
+                proto_schema.clone(),
+            )
+        })
+        .collect();
+
+    let gone = labels
+        .iter()
+        .filter(|label| label.0 == controller_type && !current.contains(*label));
+    for (ty, version, proto_schema) in gone {
+        gauge!("gc_fleet_versions",
+            "controller_type" => ty.clone(),
+            "version" => version.clone(),
+            "proto_schema" => proto_schema.clone()
+        )
+        .set(0.0);
+    }
+    labels.retain(|(ty, _, _)| ty != controller_type);
+
+    for (version, proto_schema, count) in counts {
+        gauge!("gc_fleet_versions",
+            "controller_type" => controller_type.to_string(),
+            "version" => version.clone(),
+            "proto_schema" => proto_schema.clone()
+        )
+        .set(*count as f64);
+    }
+    labels.extend(current);
+}
+
+/// Record a registration refused for being older than `GC_MIN_FLEET_VERSION`
+///
+/// Metric: `gc_registration_version_rejections_total`
+/// Labels: `controller_type` ("meeting" or "media")
+pub fn record_registration_version_rejection(controller_type: &str) {
+    counter!("gc_registration_version_rejections_total",
+        "controller_type" => controller_type.to_string()
+    )
+    .increment(1);
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -637,6 +839,10 @@ mod tests {
         assert_eq!(normalize_endpoint("/metrics"), "/metrics");
         assert_eq!(normalize_endpoint("/api/v1/me"), "/api/v1/me");
         assert_eq!(normalize_endpoint("/api/v1/meetings"), "/api/v1/meetings");
+        assert_eq!(
+            normalize_endpoint("/api/v1/client-errors"),
+            "/api/v1/client-errors"
+        );
     }
 
     #[test]
@@ -649,12 +855,44 @@ mod tests {
             normalize_endpoint("/api/v1/meetings/abc123/guest-token"),
             "/api/v1/meetings/{code}/guest-token"
         );
+        assert_eq!(
+            normalize_endpoint("/api/v1/meetings/abc123/preflight"),
+            "/api/v1/meetings/{code}/preflight"
+        );
         assert_eq!(
             normalize_endpoint("/api/v1/meetings/550e8400-e29b-41d4-a716-446655440000/settings"),
             "/api/v1/meetings/{id}/settings"
         );
     }
 
+    #[test]
+    fn normalize_endpoint_meeting_import_paths() {
+        assert_eq!(
+            normalize_endpoint("/api/v1/meeting-imports"),
+            "/api/v1/meeting-imports"
+        );
+        assert_eq!(
+            normalize_endpoint("/api/v1/meeting-imports/550e8400-e29b-41d4-a716-446655440000"),
+            "/api/v1/meeting-imports/{id}"
+        );
+        assert_eq!(
+            normalize_endpoint("/api/v1/meeting-imports/abc/extra"),
+            "/other"
+        );
+    }
+
+    #[test]
+    fn normalize_endpoint_org_config_path() {
+        assert_eq!(
+            normalize_endpoint("/api/v1/admin/orgs/acme/config"),
+            "/api/v1/admin/orgs/{subdomain}/config"
+        );
+        assert_eq!(
+            normalize_endpoint("/api/v1/admin/orgs/acme/other"),
+            "/other"
+        );
+    }
+
     #[test]
     fn normalize_endpoint_unknown_paths() {
         assert_eq!(normalize_endpoint("/unknown"), "/other");
@@ -751,6 +989,22 @@ mod tests {
             .assert_delta(1);
     }
 
+    #[test]
+    fn metrics_module_emits_meeting_events() {
+        let snap = MetricAssertion::snapshot();
+
+        record_meeting_event("meeting_created");
+        record_meeting_event("meeting_created");
+        record_meeting_event("assignment_changed");
+
+        snap.counter("gc_meeting_events_total")
+            .with_labels(&[("event", "meeting_created")])
+            .assert_delta(2);
+        snap.counter("gc_meeting_events_total")
+            .with_labels(&[("event", "assignment_changed")])
+            .assert_delta(1);
+    }
+
     #[test]
     fn metrics_module_emits_db_query_cluster() {
         let snap = MetricAssertion::snapshot();
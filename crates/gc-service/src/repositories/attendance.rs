@@ -0,0 +1,174 @@
+//! Meeting attendance repository for database operations.
+//!
+//! Stores per-participant join/leave sessions flushed by MCs at meeting end
+//! and reads them back for host CSV export.
+//!
+//! # Idempotency
+//!
+//! MCs may retry a flush after a timeout. Sessions are unique on
+//! `(meeting_id, participant_id, joined_at)`, so retried records are ignored.
+
+use crate::errors::GcError;
+use crate::observability::metrics;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::time::Instant;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// One participant session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttendanceRecord {
+    /// Participant ID assigned for the meeting.
+    pub participant_id: String,
+    /// Join token subject (guest ID for guests).
+    pub user_id: String,
+    /// Display name at join time.
+    pub display_name: String,
+    /// When the session started.
+    pub joined_at: DateTime<Utc>,
+    /// When the session ended.
+    pub left_at: DateTime<Utc>,
+    /// Why the session ended (voluntary, timeout, removed, meeting_ended).
+    pub leave_reason: String,
+}
+
+impl AttendanceRecord {
+    /// Session length in whole seconds.
+    pub fn duration_seconds(&self) -> i64 {
+        (self.left_at - self.joined_at).num_seconds().max(0)
+    }
+}
+
+/// Repository for meeting attendance operations.
+pub struct AttendanceRepository;
+
+impl AttendanceRepository {
+    /// Insert a batch of attendance records for a meeting.
+    ///
+    /// # Returns
+    ///
+    /// Number of new records stored (duplicates are skipped).
+    #[instrument(skip_all, name = "gc.repo.insert_attendance", fields(meeting_id = %meeting_id, count = records.len()))]
+    pub async fn insert_batch(
+        pool: &PgPool,
+        meeting_id: Uuid,
+        region: &str,
+        records: &[AttendanceRecord],
+    ) -> Result<u64, GcError> {
+        if records.is_empty() {
+            return Ok(0);
+        }
+
+        let start = Instant::now();
+
+        let participant_ids: Vec<&str> =
+            records.iter().map(|r| r.participant_id.as_str()).collect();
+        let user_ids: Vec<&str> = records.iter().map(|r| r.user_id.as_str()).collect();
+        let display_names: Vec<&str> = records.iter().map(|r| r.display_name.as_str()).collect();
+        let joined_at: Vec<DateTime<Utc>> = records.iter().map(|r| r.joined_at).collect();
+        let left_at: Vec<DateTime<Utc>> = records.iter().map(|r| r.left_at).collect();
+        let leave_reasons: Vec<&str> = records.iter().map(|r| r.leave_reason.as_str()).collect();
+
+        let query_result = sqlx::query(
+            r#"
+            INSERT INTO meeting_attendance (
+                meeting_id, participant_id, user_id, display_name,
+                joined_at, left_at, leave_reason, region
+            )
+            SELECT $1, r.participant_id, r.user_id, r.display_name,
+                   r.joined_at, r.left_at, r.leave_reason, $8
+            FROM UNNEST($2::text[], $3::text[], $4::text[], $5::timestamptz[], $6::timestamptz[], $7::text[])
+                AS r(participant_id, user_id, display_name, joined_at, left_at, leave_reason)
+            ON CONFLICT (meeting_id, participant_id, joined_at) DO NOTHING
+            "#,
+        )
+        .bind(meeting_id)
+        .bind(&participant_ids)
+        .bind(&user_ids)
+        .bind(&display_names)
+        .bind(&joined_at)
+        .bind(&left_at)
+        .bind(&leave_reasons)
+        .bind(region)
+        .execute(pool)
+        .await;
+
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("insert_attendance", status, start.elapsed());
+
+        Ok(result?.rows_affected())
+    }
+
+    /// List a meeting's attendance records in join order.
+    #[instrument(skip_all, name = "gc.repo.list_attendance", fields(meeting_id = %meeting_id))]
+    pub async fn list_for_meeting(
+        pool: &PgPool,
+        meeting_id: Uuid,
+    ) -> Result<Vec<AttendanceRecord>, GcError> {
+        let start = Instant::now();
+
+        #[allow(clippy::type_complexity)]
+        let query_result: Result<
+            Vec<(String, String, String, DateTime<Utc>, DateTime<Utc>, String)>,
+            sqlx::Error,
+        > = sqlx::query_as(
+            r#"
+            SELECT participant_id, user_id, display_name, joined_at, left_at, leave_reason
+            FROM meeting_attendance
+            WHERE meeting_id = $1
+            ORDER BY joined_at, participant_id
+            "#,
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("list_attendance", status, start.elapsed());
+
+        Ok(query_result?
+            .into_iter()
+            .map(
+                |(participant_id, user_id, display_name, joined_at, left_at, leave_reason)| {
+                    AttendanceRecord {
+                        participant_id,
+                        user_id,
+                        display_name,
+                        joined_at,
+                        left_at,
+                        leave_reason,
+                    }
+                },
+            )
+            .collect())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_duration_seconds() {
+        let joined_at = Utc::now();
+        let record = AttendanceRecord {
+            participant_id: "p-1".to_string(),
+            user_id: "u-1".to_string(),
+            display_name: "Participant 1".to_string(),
+            joined_at,
+            left_at: joined_at + Duration::seconds(95),
+            leave_reason: "voluntary".to_string(),
+        };
+        assert_eq!(record.duration_seconds(), 95);
+    }
+}
@@ -0,0 +1,237 @@
+//! Event outbox repository for database operations.
+//!
+//! Implements the transactional outbox pattern: meeting mutations enqueue a
+//! [`GcEvent`] on the same connection/transaction as the mutation itself, and
+//! the outbox relay task later claims, publishes, and marks the rows.
+//!
+//! # Concurrency
+//!
+//! Multiple GC instances may run the relay concurrently. Rows are claimed with
+//! `FOR UPDATE SKIP LOCKED` inside the relay's transaction so each pending row
+//! is published by at most one relay at a time.
+
+use crate::errors::GcError;
+use crate::events::GcEvent;
+use crate::observability::metrics;
+use sqlx::{PgConnection, PgPool};
+use std::time::Instant;
+use tracing::instrument;
+
+/// Default batch size for published-row cleanup.
+const DEFAULT_CLEANUP_BATCH_SIZE: i64 = 1000;
+
+/// A pending outbox row claimed by the relay.
+#[derive(Debug, Clone)]
+pub struct OutboxEntry {
+    /// Monotonic outbox row ID.
+    pub outbox_id: i64,
+    /// Bounded event name (mirrors `GcEvent::name`).
+    pub event_type: String,
+    /// Serialized `GcEvent`.
+    pub payload: serde_json::Value,
+}
+
+impl OutboxEntry {
+    /// Decode the payload back into a `GcEvent`.
+    pub fn decode(&self) -> Result<GcEvent, serde_json::Error> {
+        serde_json::from_value(self.payload.clone())
+    }
+}
+
+/// Repository for event outbox operations.
+pub struct EventOutboxRepository;
+
+impl EventOutboxRepository {
+    /// Enqueue an event on the caller's connection.
+    ///
+    /// Pass the mutation's transaction (`&mut *tx`) so the event commits or
+    /// rolls back atomically with the change it describes.
+    #[instrument(skip_all, name = "gc.repo.enqueue_outbox_event", fields(event = event.name()))]
+    pub async fn enqueue(conn: &mut PgConnection, event: &GcEvent) -> Result<(), GcError> {
+        let start = Instant::now();
+
+        let payload = serde_json::to_value(event)
+            .map_err(|e| GcError::Internal(format!("Failed to serialize outbox event: {}", e)))?;
+
+        let query_result = sqlx::query(
+            r#"
+            INSERT INTO event_outbox (event_type, meeting_id, payload)
+            VALUES ($1, $2, $3)
+            "#,
+        )
+        .bind(event.name())
+        .bind(event.meeting_id())
+        .bind(payload)
+        .execute(conn)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("enqueue_outbox_event", status, start.elapsed());
+
+        query_result?;
+        Ok(())
+    }
+
+    /// Claim up to `limit` pending rows, oldest first.
+    ///
+    /// Must be called inside a transaction; the row locks are held until the
+    /// caller commits (after [`Self::mark_published`]) or rolls back.
+    #[instrument(skip_all, name = "gc.repo.claim_outbox_batch", fields(limit = limit))]
+    pub async fn claim_pending(
+        conn: &mut PgConnection,
+        limit: i64,
+    ) -> Result<Vec<OutboxEntry>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Vec<(i64, String, serde_json::Value)>, sqlx::Error> =
+            sqlx::query_as(
+                r#"
+                SELECT outbox_id, event_type, payload
+                FROM event_outbox
+                WHERE published_at IS NULL
+                ORDER BY outbox_id
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+                "#,
+            )
+            .bind(limit)
+            .fetch_all(conn)
+            .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("claim_outbox_batch", status, start.elapsed());
+
+        Ok(query_result?
+            .into_iter()
+            .map(|(outbox_id, event_type, payload)| OutboxEntry {
+                outbox_id,
+                event_type,
+                payload,
+            })
+            .collect())
+    }
+
+    /// Mark claimed rows as published.
+    #[instrument(skip_all, name = "gc.repo.mark_outbox_published", fields(count = outbox_ids.len()))]
+    pub async fn mark_published(
+        conn: &mut PgConnection,
+        outbox_ids: &[i64],
+    ) -> Result<u64, GcError> {
+        if outbox_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE event_outbox
+            SET published_at = NOW()
+            WHERE outbox_id = ANY($1)
+            "#,
+        )
+        .bind(outbox_ids)
+        .execute(conn)
+        .await;
+
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("mark_outbox_published", status, start.elapsed());
+
+        Ok(result?.rows_affected())
+    }
+
+    /// Delete published rows older than `retention_hours`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - Database connection pool
+    /// * `retention_hours` - Hours to keep published rows for debugging
+    /// * `batch_size` - Optional batch size limit (defaults to 1000)
+    #[instrument(skip_all, name = "gc.repo.cleanup_outbox", fields(retention_hours = retention_hours))]
+    pub async fn cleanup_published(
+        pool: &PgPool,
+        retention_hours: i32,
+        batch_size: Option<i64>,
+    ) -> Result<u64, GcError> {
+        let start = Instant::now();
+        let limit = batch_size.unwrap_or(DEFAULT_CLEANUP_BATCH_SIZE);
+
+        // LIMIT via subquery prevents long-running transactions in pathological cases.
+        let query_result = sqlx::query(
+            r#"
+            DELETE FROM event_outbox
+            WHERE outbox_id IN (
+                SELECT outbox_id FROM event_outbox
+                WHERE published_at < NOW() - $1 * INTERVAL '1 hour'
+                LIMIT $2
+            )
+            "#,
+        )
+        .bind(retention_hours)
+        .bind(limit)
+        .execute(pool)
+        .await;
+
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("cleanup_outbox", status, start.elapsed());
+
+        Ok(result?.rows_affected())
+    }
+
+    /// Count rows still awaiting publication.
+    #[allow(dead_code)] // Used by integration tests and future readiness checks
+    #[instrument(skip_all, name = "gc.repo.count_pending_outbox")]
+    pub async fn count_pending(pool: &PgPool) -> Result<i64, GcError> {
+        let (count,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM event_outbox WHERE published_at IS NULL")
+                .fetch_one(pool)
+                .await?;
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_outbox_entry_decode_round_trip() {
+        let event = GcEvent::MeetingCreated {
+            meeting_id: Uuid::new_v4(),
+            org_id: Uuid::new_v4(),
+            created_by_user_id: Uuid::new_v4(),
+        };
+        let entry = OutboxEntry {
+            outbox_id: 1,
+            event_type: event.name().to_string(),
+            payload: serde_json::to_value(&event).unwrap(),
+        };
+        assert_eq!(entry.decode().unwrap(), event);
+    }
+
+    #[test]
+    fn test_outbox_entry_decode_rejects_unknown_type() {
+        let entry = OutboxEntry {
+            outbox_id: 1,
+            event_type: "meeting_exploded".to_string(),
+            payload: serde_json::json!({"type": "meeting_exploded"}),
+        };
+        assert!(entry.decode().is_err());
+    }
+}
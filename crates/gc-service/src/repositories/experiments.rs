@@ -0,0 +1,141 @@
+//! Experiments repository for database operations.
+//!
+//! Reads the enabled A/B experiments and records which variant each joiner
+//! was bucketed into. Bucketing itself lives in `services::experiments`.
+
+use crate::errors::GcError;
+use crate::observability::metrics;
+use sqlx::PgPool;
+use std::collections::BTreeMap;
+use std::time::Instant;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// What an experiment buckets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExperimentUnit {
+    /// The meeting's organization: everyone in a meeting gets one variant.
+    Org,
+    /// Each joiner (user ID, or guest ID for guests).
+    User,
+}
+
+impl ExperimentUnit {
+    fn from_db(unit: &str) -> Option<Self> {
+        match unit {
+            "org" => Some(Self::Org),
+            "user" => Some(Self::User),
+            _ => None,
+        }
+    }
+}
+
+/// An enabled experiment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Experiment {
+    /// Experiment name, the key in join responses.
+    pub name: String,
+    /// Salt mixed into the bucketing hash.
+    pub salt: String,
+    /// What is bucketed.
+    pub unit: ExperimentUnit,
+    /// Variant names, equally weighted.
+    pub variants: Vec<String>,
+}
+
+/// Repository for experiment operations.
+pub struct ExperimentsRepository;
+
+impl ExperimentsRepository {
+    /// List enabled experiments, by name. Rows with an unknown unit are
+    /// skipped.
+    #[instrument(skip_all, name = "gc.repo.list_experiments")]
+    pub async fn list_enabled(pool: &PgPool) -> Result<Vec<Experiment>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Vec<(String, String, String, Vec<String>)>, sqlx::Error> =
+            sqlx::query_as(
+                r#"
+                SELECT name, salt, unit, variants
+                FROM experiments
+                WHERE enabled
+                ORDER BY name
+                "#,
+            )
+            .fetch_all(pool)
+            .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("list_experiments", status, start.elapsed());
+
+        Ok(query_result?
+            .into_iter()
+            .filter_map(|(name, salt, unit, variants)| {
+                Some(Experiment {
+                    name,
+                    salt,
+                    unit: ExperimentUnit::from_db(&unit)?,
+                    variants,
+                })
+            })
+            .collect())
+    }
+
+    /// Record a joiner's variants for a meeting. A rejoin keeps the
+    /// variants recorded first.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject_id` - User ID, or guest ID for guests
+    /// * `subject_type` - `user` or `guest`
+    /// * `variants` - Variant by experiment name
+    #[instrument(skip_all, name = "gc.repo.record_experiment_assignments", fields(meeting_id = %meeting_id))]
+    pub async fn record_assignments(
+        pool: &PgPool,
+        meeting_id: Uuid,
+        subject_id: &str,
+        subject_type: &str,
+        variants: &BTreeMap<String, String>,
+    ) -> Result<(), GcError> {
+        if variants.is_empty() {
+            return Ok(());
+        }
+
+        let start = Instant::now();
+
+        let experiments: Vec<&str> = variants.keys().map(String::as_str).collect();
+        let variant_names: Vec<&str> = variants.values().map(String::as_str).collect();
+
+        let query_result = sqlx::query(
+            r#"
+            INSERT INTO experiment_assignments (
+                experiment, meeting_id, subject_id, subject_type, variant
+            )
+            SELECT a.experiment, $2, $3, $4, a.variant
+            FROM UNNEST($1::text[], $5::text[]) AS a(experiment, variant)
+            ON CONFLICT (experiment, meeting_id, subject_id) DO NOTHING
+            "#,
+        )
+        .bind(&experiments)
+        .bind(meeting_id)
+        .bind(subject_id)
+        .bind(subject_type)
+        .bind(&variant_names)
+        .execute(pool)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("record_experiment_assignments", status, start.elapsed());
+
+        query_result?;
+        Ok(())
+    }
+}
@@ -0,0 +1,300 @@
+//! MC directives repository for database operations.
+//!
+//! Operators insert rows into `mc_directives` to change a running MC
+//! (heartbeat intervals, soft limits, drain, ending a meeting). The heartbeat
+//! stream handler takes an MC's pending directives on each heartbeat and
+//! sends them in the ack.
+//!
+//! # Delivery
+//!
+//! Taking a directive stamps `delivered_at` in the same statement, so each
+//! directive is sent at most once even with several GCs serving the same MC
+//! over time. The rows are kept as the audit trail.
+
+use crate::errors::GcError;
+use crate::observability::metrics;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::time::Instant;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// What a directive asks the MC to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum McDirectiveAction {
+    /// Change heartbeat intervals (`None` = unchanged).
+    HeartbeatIntervals {
+        fast_ms: Option<i64>,
+        comprehensive_ms: Option<i64>,
+    },
+    /// Limits below the MC's configured ones (`None` = configured limit).
+    SoftLimits {
+        max_meetings: Option<i32>,
+        max_participants: Option<i32>,
+    },
+    /// Stop (`true`) or resume (`false`) accepting new meetings.
+    Drain { draining: bool },
+    /// End a meeting on the MC.
+    EndMeeting {
+        meeting_id: String,
+        reason: Option<String>,
+    },
+}
+
+impl McDirectiveAction {
+    /// Value of the `kind` column.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::HeartbeatIntervals { .. } => "heartbeat_intervals",
+            Self::SoftLimits { .. } => "soft_limits",
+            Self::Drain { .. } => "drain",
+            Self::EndMeeting { .. } => "end_meeting",
+        }
+    }
+}
+
+/// A directive for one MC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McDirective {
+    /// Directive ID.
+    pub directive_id: Uuid,
+    /// What to do.
+    pub action: McDirectiveAction,
+    /// Operator (or tool) that issued it.
+    pub created_by: String,
+    /// When it was issued.
+    pub created_at: DateTime<Utc>,
+}
+
+type DirectiveRow = (
+    Uuid,
+    String,
+    Option<i64>,
+    Option<i64>,
+    Option<i32>,
+    Option<i32>,
+    Option<bool>,
+    Option<String>,
+    Option<String>,
+    String,
+    DateTime<Utc>,
+);
+
+/// Map a row to a directive, or `None` if its columns do not form one.
+fn map_directive_row(row: DirectiveRow) -> Option<McDirective> {
+    let (
+        directive_id,
+        kind,
+        fast_ms,
+        comprehensive_ms,
+        max_meetings,
+        max_participants,
+        draining,
+        meeting_id,
+        reason,
+        created_by,
+        created_at,
+    ) = row;
+    let action = match kind.as_str() {
+        "heartbeat_intervals" => McDirectiveAction::HeartbeatIntervals {
+            fast_ms,
+            comprehensive_ms,
+        },
+        "soft_limits" => McDirectiveAction::SoftLimits {
+            max_meetings,
+            max_participants,
+        },
+        "drain" => McDirectiveAction::Drain {
+            draining: draining?,
+        },
+        "end_meeting" => McDirectiveAction::EndMeeting {
+            meeting_id: meeting_id?,
+            reason,
+        },
+        _ => return None,
+    };
+    Some(McDirective {
+        directive_id,
+        action,
+        created_by,
+        created_at,
+    })
+}
+
+/// Repository for MC directive operations.
+pub struct McDirectivesRepository;
+
+impl McDirectivesRepository {
+    /// Issue a directive for an MC.
+    ///
+    /// # Returns
+    ///
+    /// The new directive's ID.
+    #[instrument(skip_all, name = "gc.repo.insert_mc_directive", fields(controller_id = %controller_id, kind = action.kind()))]
+    pub async fn insert(
+        pool: &PgPool,
+        controller_id: &str,
+        action: &McDirectiveAction,
+        created_by: &str,
+    ) -> Result<Uuid, GcError> {
+        let start = Instant::now();
+
+        let (
+            fast_ms,
+            comprehensive_ms,
+            max_meetings,
+            max_participants,
+            draining,
+            meeting_id,
+            reason,
+        ) = match action {
+            McDirectiveAction::HeartbeatIntervals {
+                fast_ms,
+                comprehensive_ms,
+            } => (*fast_ms, *comprehensive_ms, None, None, None, None, None),
+            McDirectiveAction::SoftLimits {
+                max_meetings,
+                max_participants,
+            } => (
+                None,
+                None,
+                *max_meetings,
+                *max_participants,
+                None,
+                None,
+                None,
+            ),
+            McDirectiveAction::Drain { draining } => {
+                (None, None, None, None, Some(*draining), None, None)
+            }
+            McDirectiveAction::EndMeeting { meeting_id, reason } => (
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(meeting_id.as_str()),
+                reason.as_deref(),
+            ),
+        };
+
+        let query_result: Result<Uuid, sqlx::Error> = sqlx::query_scalar(
+            r#"
+            INSERT INTO mc_directives (
+                controller_id, kind, fast_heartbeat_interval_ms,
+                comprehensive_heartbeat_interval_ms, max_meetings, max_participants,
+                draining, meeting_id, reason, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING directive_id
+            "#,
+        )
+        .bind(controller_id)
+        .bind(action.kind())
+        .bind(fast_ms)
+        .bind(comprehensive_ms)
+        .bind(max_meetings)
+        .bind(max_participants)
+        .bind(draining)
+        .bind(meeting_id)
+        .bind(reason)
+        .bind(created_by)
+        .fetch_one(pool)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("insert_mc_directive", status, start.elapsed());
+
+        Ok(query_result?)
+    }
+
+    /// Take an MC's pending directives, oldest first, marking them delivered.
+    #[instrument(skip_all, name = "gc.repo.take_mc_directives", fields(controller_id = %controller_id))]
+    pub async fn take_pending(
+        pool: &PgPool,
+        controller_id: &str,
+    ) -> Result<Vec<McDirective>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Vec<DirectiveRow>, sqlx::Error> = sqlx::query_as(
+            r#"
+            UPDATE mc_directives
+            SET delivered_at = NOW()
+            WHERE directive_id IN (
+                SELECT directive_id
+                FROM mc_directives
+                WHERE controller_id = $1 AND delivered_at IS NULL
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING directive_id, kind, fast_heartbeat_interval_ms,
+                      comprehensive_heartbeat_interval_ms, max_meetings, max_participants,
+                      draining, meeting_id, reason, created_by, created_at
+            "#,
+        )
+        .bind(controller_id)
+        .fetch_all(pool)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("take_mc_directives", status, start.elapsed());
+
+        // RETURNING has no order
+        let mut directives: Vec<McDirective> = query_result?
+            .into_iter()
+            .filter_map(map_directive_row)
+            .collect();
+        directives
+            .sort_by(|a, b| (a.created_at, a.directive_id).cmp(&(b.created_at, b.directive_id)));
+        Ok(directives)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn row(kind: &str, draining: Option<bool>, meeting_id: Option<&str>) -> DirectiveRow {
+        (
+            Uuid::nil(),
+            kind.to_string(),
+            None,
+            None,
+            None,
+            None,
+            draining,
+            meeting_id.map(str::to_string),
+            None,
+            "ops".to_string(),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_map_directive_row() {
+        let drain = map_directive_row(row("drain", Some(true), None)).unwrap();
+        assert_eq!(drain.action, McDirectiveAction::Drain { draining: true });
+        assert_eq!(drain.action.kind(), "drain");
+
+        let end = map_directive_row(row("end_meeting", None, Some("meeting-1"))).unwrap();
+        assert_eq!(
+            end.action,
+            McDirectiveAction::EndMeeting {
+                meeting_id: "meeting-1".to_string(),
+                reason: None,
+            }
+        );
+
+        assert!(map_directive_row(row("drain", None, None)).is_none());
+        assert!(map_directive_row(row("reboot", None, None)).is_none());
+    }
+}
@@ -15,7 +15,7 @@
 
 use crate::errors::GcError;
 use crate::observability::metrics;
-use crate::repositories::HealthStatus;
+use crate::repositories::{HealthStatus, ReportedBuild, VersionCount};
 use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use std::time::Instant;
@@ -42,6 +42,10 @@ pub struct MediaHandler {
     pub memory_usage_percent: Option<f32>,
     pub bandwidth_usage_percent: Option<f32>,
     pub last_heartbeat_at: DateTime<Utc>,
+    pub cordoned_at: Option<DateTime<Utc>>,
+    pub version: Option<String>,
+    pub git_sha: Option<String>,
+    pub proto_schema: Option<String>,
     pub registered_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -241,12 +245,12 @@ impl MediaHandlersRepository {
                 health_status = 'unhealthy',
                 updated_at = NOW()
             WHERE
-                last_heartbeat_at < NOW() - ($1 || ' seconds')::INTERVAL
+                last_heartbeat_at < NOW() - $1 * INTERVAL '1 second'
                 AND health_status != 'unhealthy'
                 AND health_status != 'draining'
             "#,
         )
-        .bind(staleness_threshold_seconds.to_string())
+        .bind(staleness_threshold_seconds)
         .execute(pool)
         .await;
 
@@ -273,7 +277,8 @@ impl MediaHandlersRepository {
 
     /// Get candidate MHs for load balancing in a region.
     ///
-    /// Returns up to 5 healthy MHs with capacity, ordered by load ratio (least loaded first).
+    /// Returns up to 5 healthy, uncordoned MHs with capacity, ordered by load ratio
+    /// (least loaded first).
     ///
     /// # Arguments
     ///
@@ -300,13 +305,14 @@ impl MediaHandlersRepository {
             WHERE health_status = 'healthy'
               AND region = $1
               AND current_streams < max_streams
-              AND last_heartbeat_at > NOW() - ($2 || ' seconds')::INTERVAL
+              AND cordoned_at IS NULL
+              AND last_heartbeat_at > NOW() - $2 * INTERVAL '1 second'
             ORDER BY load_ratio ASC, last_heartbeat_at DESC
             LIMIT $3
             "#,
         )
         .bind(region)
-        .bind(DEFAULT_HEARTBEAT_STALENESS_SECONDS.to_string())
+        .bind(DEFAULT_HEARTBEAT_STALENESS_SECONDS)
         .bind(LOAD_BALANCING_CANDIDATE_COUNT)
         .fetch_all(pool)
         .await;
@@ -361,6 +367,10 @@ impl MediaHandlersRepository {
                 memory_usage_percent,
                 bandwidth_usage_percent,
                 last_heartbeat_at,
+                cordoned_at,
+                version,
+                git_sha,
+                proto_schema,
                 registered_at,
                 updated_at
             FROM media_handlers
@@ -378,21 +388,162 @@ impl MediaHandlersRepository {
         };
         metrics::record_db_query("get_handler", status, start.elapsed());
 
-        Ok(row?.map(|r| MediaHandler {
-            handler_id: r.handler_id,
-            region: r.region,
-            webtransport_endpoint: r.webtransport_endpoint,
-            grpc_endpoint: r.grpc_endpoint,
-            max_streams: r.max_streams,
-            current_streams: r.current_streams,
-            health_status: HealthStatus::from_db_str(&r.health_status),
-            cpu_usage_percent: r.cpu_usage_percent,
-            memory_usage_percent: r.memory_usage_percent,
-            bandwidth_usage_percent: r.bandwidth_usage_percent,
-            last_heartbeat_at: r.last_heartbeat_at,
-            registered_at: r.registered_at,
-            updated_at: r.updated_at,
-        }))
+        Ok(row?.map(MediaHandler::from))
+    }
+
+    /// List all registered media handlers, by region then ID.
+    ///
+    /// Backs the admin list endpoint, so unhealthy and cordoned handlers are
+    /// included.
+    #[instrument(skip_all)]
+    pub async fn list_handlers(pool: &PgPool) -> Result<Vec<MediaHandler>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Vec<MediaHandlerRow>, sqlx::Error> = sqlx::query_as(
+            r#"
+            SELECT
+                handler_id,
+                region,
+                webtransport_endpoint,
+                grpc_endpoint,
+                max_streams,
+                current_streams,
+                health_status,
+                cpu_usage_percent,
+                memory_usage_percent,
+                bandwidth_usage_percent,
+                last_heartbeat_at,
+                cordoned_at,
+                version,
+                git_sha,
+                proto_schema,
+                registered_at,
+                updated_at
+            FROM media_handlers
+            ORDER BY region, handler_id
+            "#,
+        )
+        .fetch_all(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, rows) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("list_handlers", status, start.elapsed());
+
+        Ok(rows?.into_iter().map(MediaHandler::from).collect())
+    }
+
+    /// Record the build a handler registered with.
+    ///
+    /// Called after registration; `None` (an MH that predates version
+    /// reporting) clears a build recorded by an earlier registration.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a row was updated, `false` if handler not found.
+    #[instrument(skip_all, fields(handler_id = %handler_id))]
+    pub async fn set_build(
+        pool: &PgPool,
+        handler_id: &str,
+        build: Option<ReportedBuild<'_>>,
+    ) -> Result<bool, GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE media_handlers
+            SET version = $2, git_sha = $3, proto_schema = $4, updated_at = NOW()
+            WHERE handler_id = $1
+            "#,
+        )
+        .bind(handler_id)
+        .bind(build.map(|b| b.version))
+        .bind(build.map(|b| b.git_sha))
+        .bind(build.map(|b| b.proto_schema))
+        .execute(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("set_mh_build", status, start.elapsed());
+
+        Ok(result?.rows_affected() > 0)
+    }
+
+    /// Count handlers by reported version and proto schema.
+    ///
+    /// Unhealthy handlers are left out, as for
+    /// `MeetingControllersRepository::get_version_counts`.
+    #[instrument(skip_all)]
+    pub async fn get_version_counts(pool: &PgPool) -> Result<Vec<VersionCount>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Vec<VersionCount>, sqlx::Error> = sqlx::query_as(
+            r#"
+            SELECT version, proto_schema, COUNT(*) as count
+            FROM media_handlers
+            WHERE health_status != 'unhealthy'
+            GROUP BY version, proto_schema
+            ORDER BY version, proto_schema
+            "#,
+        )
+        .fetch_all(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, rows) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("get_mh_version_counts", status, start.elapsed());
+
+        Ok(rows?)
+    }
+
+    /// Cordon or uncordon a media handler.
+    ///
+    /// A cordoned handler is left out of MH selection; meetings already
+    /// using it are unaffected. Cordoning an already cordoned handler keeps
+    /// its original `cordoned_at`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a row was updated, `false` if handler not found.
+    #[instrument(skip_all, fields(handler_id = %handler_id, cordoned = cordoned))]
+    pub async fn set_cordoned(
+        pool: &PgPool,
+        handler_id: &str,
+        cordoned: bool,
+    ) -> Result<bool, GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE media_handlers
+            SET cordoned_at = CASE WHEN $2 THEN COALESCE(cordoned_at, NOW()) END,
+                updated_at = NOW()
+            WHERE handler_id = $1
+            "#,
+        )
+        .bind(handler_id)
+        .bind(cordoned)
+        .execute(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("set_mh_cordoned", status, start.elapsed());
+
+        Ok(result?.rows_affected() > 0)
     }
 }
 
@@ -422,10 +573,38 @@ struct MediaHandlerRow {
     memory_usage_percent: Option<f32>,
     bandwidth_usage_percent: Option<f32>,
     last_heartbeat_at: DateTime<Utc>,
+    cordoned_at: Option<DateTime<Utc>>,
+    version: Option<String>,
+    git_sha: Option<String>,
+    proto_schema: Option<String>,
     registered_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
 
+impl From<MediaHandlerRow> for MediaHandler {
+    fn from(r: MediaHandlerRow) -> Self {
+        Self {
+            handler_id: r.handler_id,
+            region: r.region,
+            webtransport_endpoint: r.webtransport_endpoint,
+            grpc_endpoint: r.grpc_endpoint,
+            max_streams: r.max_streams,
+            current_streams: r.current_streams,
+            health_status: HealthStatus::from_db_str(&r.health_status),
+            cpu_usage_percent: r.cpu_usage_percent,
+            memory_usage_percent: r.memory_usage_percent,
+            bandwidth_usage_percent: r.bandwidth_usage_percent,
+            last_heartbeat_at: r.last_heartbeat_at,
+            cordoned_at: r.cordoned_at,
+            version: r.version,
+            git_sha: r.git_sha,
+            proto_schema: r.proto_schema,
+            registered_at: r.registered_at,
+            updated_at: r.updated_at,
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -461,6 +640,10 @@ mod tests {
             memory_usage_percent: Some(50.0),
             bandwidth_usage_percent: Some(30.0),
             last_heartbeat_at: now,
+            cordoned_at: None,
+            version: None,
+            git_sha: None,
+            proto_schema: None,
             registered_at: now,
             updated_at: now,
         };
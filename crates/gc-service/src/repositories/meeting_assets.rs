@@ -0,0 +1,72 @@
+//! Meeting assets repository for database operations.
+//!
+//! Records metadata for files uploaded to the object store through
+//! pre-signed URLs. A row is written when the upload URL is issued, so it
+//! describes an upload the client was authorized to perform, not one GC has
+//! observed completing.
+
+use crate::errors::GcError;
+use crate::observability::metrics;
+use sqlx::PgPool;
+use std::time::Instant;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Metadata for an uploaded meeting asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeetingAsset {
+    /// Asset ID.
+    pub asset_id: Uuid,
+    /// Meeting the asset belongs to.
+    pub meeting_id: Uuid,
+    /// User who requested the upload.
+    pub uploaded_by_user_id: Uuid,
+    /// Client-supplied file name (display only, never used in the object key).
+    pub file_name: String,
+    /// Content type the upload URL is signed for.
+    pub content_type: String,
+    /// Declared size in bytes.
+    pub size_bytes: i64,
+    /// Object store key.
+    pub object_key: String,
+}
+
+/// Repository for meeting asset operations.
+pub struct MeetingAssetsRepository;
+
+impl MeetingAssetsRepository {
+    /// Insert an asset metadata row.
+    #[instrument(skip_all, name = "gc.repo.insert_meeting_asset", fields(meeting_id = %asset.meeting_id, asset_id = %asset.asset_id))]
+    pub async fn insert(pool: &PgPool, asset: &MeetingAsset) -> Result<(), GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            INSERT INTO meeting_assets (
+                asset_id, meeting_id, uploaded_by_user_id, file_name,
+                content_type, size_bytes, object_key
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(asset.asset_id)
+        .bind(asset.meeting_id)
+        .bind(asset.uploaded_by_user_id)
+        .bind(&asset.file_name)
+        .bind(&asset.content_type)
+        .bind(asset.size_bytes)
+        .bind(&asset.object_key)
+        .execute(pool)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("insert_meeting_asset", status, start.elapsed());
+
+        query_result?;
+        Ok(())
+    }
+}
@@ -10,12 +10,15 @@
 //! - Uses atomic operations to prevent race conditions
 
 use crate::errors::GcError;
+use crate::events::GcEvent;
 use crate::observability::metrics;
+use crate::repositories::{EventOutboxRepository, DEFAULT_MC_POOL};
 use chrono::{DateTime, Utc};
 use ring::rand::{SecureRandom, SystemRandom};
 use sqlx::PgPool;
 use std::time::Instant;
 use tracing::instrument;
+use uuid::Uuid;
 
 /// Default heartbeat staleness threshold in seconds.
 /// Controllers without heartbeat within this time are considered unhealthy.
@@ -105,12 +108,12 @@ impl MeetingAssignmentsRepository {
               AND ma.region = $2
               AND ma.ended_at IS NULL
               AND mc.health_status = 'healthy'
-              AND mc.last_heartbeat_at > NOW() - ($3 || ' seconds')::INTERVAL
+              AND mc.last_heartbeat_at > NOW() - $3 * INTERVAL '1 second'
             "#,
         )
         .bind(meeting_id)
         .bind(region)
-        .bind(DEFAULT_HEARTBEAT_STALENESS_SECONDS.to_string())
+        .bind(DEFAULT_HEARTBEAT_STALENESS_SECONDS)
         .fetch_optional(pool)
         .await;
 
@@ -128,18 +131,32 @@ impl MeetingAssignmentsRepository {
         }))
     }
 
-    /// Get candidate MCs for load balancing in a region.
+    /// Get candidate MCs for load balancing in a region's default pool.
+    ///
+    /// See [`get_candidate_mcs_in_pool`](Self::get_candidate_mcs_in_pool).
+    pub async fn get_candidate_mcs(
+        pool: &PgPool,
+        region: &str,
+    ) -> Result<Vec<McCandidate>, GcError> {
+        Self::get_candidate_mcs_in_pool(pool, region, DEFAULT_MC_POOL).await
+    }
+
+    /// Get candidate MCs for load balancing in a region and capacity pool.
     ///
     /// Returns up to 5 healthy MCs with capacity, ordered by load ratio (least loaded first).
+    /// Warm standbys are never candidates: they hold capacity for their primary.
+    /// Neither are cordoned MCs.
     ///
     /// # Arguments
     ///
     /// * `pool` - Database connection pool
     /// * `region` - Deployment region
-    #[instrument(skip_all, fields(region = %region))]
-    pub async fn get_candidate_mcs(
+    /// * `mc_pool` - Capacity pool the MCs registered into
+    #[instrument(skip_all, fields(region = %region, mc_pool = %mc_pool))]
+    pub async fn get_candidate_mcs_in_pool(
         pool: &PgPool,
         region: &str,
+        mc_pool: &str,
     ) -> Result<Vec<McCandidate>, GcError> {
         let start = Instant::now();
 
@@ -156,15 +173,19 @@ impl MeetingAssignmentsRepository {
             FROM meeting_controllers
             WHERE health_status = 'healthy'
               AND region = $1
+              AND pool = $4
               AND current_meetings < max_meetings
-              AND last_heartbeat_at > NOW() - ($2 || ' seconds')::INTERVAL
+              AND standby_for IS NULL
+              AND cordoned_at IS NULL
+              AND last_heartbeat_at > NOW() - $2 * INTERVAL '1 second'
             ORDER BY load_ratio ASC, last_heartbeat_at DESC
             LIMIT $3
             "#,
         )
         .bind(region)
-        .bind(DEFAULT_HEARTBEAT_STALENESS_SECONDS.to_string())
+        .bind(DEFAULT_HEARTBEAT_STALENESS_SECONDS)
         .bind(LOAD_BALANCING_CANDIDATE_COUNT)
+        .bind(mc_pool)
         .fetch_all(pool)
         .await;
 
@@ -193,6 +214,10 @@ impl MeetingAssignmentsRepository {
     /// 2. Inserts new assignment only if no healthy assignment exists
     /// 3. Returns the assignment (ours or the winner's if race lost)
     ///
+    /// When our assignment is written, an `AssignmentChanged` event is queued
+    /// in the `event_outbox` in the same transaction. Non-UUID meeting IDs
+    /// (not backed by a `meetings` row) get no event.
+    ///
     /// # Arguments
     ///
     /// * `pool` - Database connection pool
@@ -209,6 +234,7 @@ impl MeetingAssignmentsRepository {
         gc_id: &str,
     ) -> Result<McAssignment, GcError> {
         let start = Instant::now();
+        let mut tx = pool.begin().await?;
 
         // Use INSERT ... ON CONFLICT DO UPDATE to atomically handle:
         // - New assignments (no existing row)
@@ -230,7 +256,7 @@ impl MeetingAssignmentsRepository {
                 SELECT 1 FROM meeting_controllers mc
                 WHERE mc.controller_id = meeting_assignments.meeting_controller_id
                   AND (mc.health_status != 'healthy'
-                       OR mc.last_heartbeat_at < NOW() - ($5 || ' seconds')::INTERVAL)
+                       OR mc.last_heartbeat_at < NOW() - $5 * INTERVAL '1 second')
             )
             RETURNING meeting_controller_id
             "#,
@@ -239,8 +265,8 @@ impl MeetingAssignmentsRepository {
         .bind(region)
         .bind(&selected_mc.controller_id)
         .bind(gc_id)
-        .bind(DEFAULT_HEARTBEAT_STALENESS_SECONDS.to_string())
-        .fetch_optional(pool)
+        .bind(DEFAULT_HEARTBEAT_STALENESS_SECONDS)
+        .fetch_optional(&mut *tx)
         .await;
 
         // Record DB query metrics (ADR-0011)
@@ -252,8 +278,22 @@ impl MeetingAssignmentsRepository {
 
         match result? {
             Some(_) => {
-                // We won the race - return our assignment
+                // We won the race - queue the change with the assignment and
+                // return it
                 // Note: Logging happens at service layer to avoid duplication
+                if let Ok(meeting_uuid) = Uuid::parse_str(meeting_id) {
+                    EventOutboxRepository::enqueue(
+                        &mut tx,
+                        &GcEvent::AssignmentChanged {
+                            meeting_id: meeting_uuid,
+                            mc_id: selected_mc.controller_id.clone(),
+                            region: region.to_string(),
+                        },
+                    )
+                    .await?;
+                }
+                tx.commit().await?;
+
                 Ok(McAssignment {
                     mc_id: selected_mc.controller_id.clone(),
                     grpc_endpoint: selected_mc.grpc_endpoint.clone(),
@@ -265,6 +305,7 @@ impl MeetingAssignmentsRepository {
                 // 1. Another GC won the race and assigned a healthy MC
                 // 2. There's already a healthy assignment (we should have caught this earlier)
                 // Re-query to get the current assignment
+                tx.rollback().await?;
                 tracing::debug!(
                     target: "gc.repository.assignments",
                     meeting_id = %meeting_id,
@@ -402,6 +443,264 @@ impl MeetingAssignmentsRepository {
         Ok(count)
     }
 
+    /// Get the IDs of the meetings actively assigned to an MC.
+    #[instrument(skip_all, fields(mc_id = %mc_id))]
+    pub async fn get_active_meeting_ids(
+        pool: &PgPool,
+        mc_id: &str,
+    ) -> Result<Vec<String>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Vec<String>, sqlx::Error> = sqlx::query_scalar(
+            r#"
+            SELECT meeting_id
+            FROM meeting_assignments
+            WHERE meeting_controller_id = $1
+              AND ended_at IS NULL
+            ORDER BY assigned_at
+            "#,
+        )
+        .bind(mc_id)
+        .fetch_all(pool)
+        .await;
+
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("get_active_meeting_ids", status, start.elapsed());
+
+        Ok(result?)
+    }
+
+    /// Get the meetings a warm standby should pre-load: those actively
+    /// assigned to its primary. Empty for an MC that is not a standby.
+    #[instrument(skip_all, fields(standby_id = %standby_id))]
+    pub async fn get_standby_meeting_ids(
+        pool: &PgPool,
+        standby_id: &str,
+    ) -> Result<Vec<String>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Vec<String>, sqlx::Error> = sqlx::query_scalar(
+            r#"
+            SELECT ma.meeting_id
+            FROM meeting_controllers mc
+            JOIN meeting_assignments ma ON ma.meeting_controller_id = mc.standby_for
+            WHERE mc.controller_id = $1
+              AND ma.ended_at IS NULL
+            ORDER BY ma.assigned_at
+            "#,
+        )
+        .bind(standby_id)
+        .fetch_all(pool)
+        .await;
+
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("get_standby_meeting_ids", status, start.elapsed());
+
+        Ok(result?)
+    }
+
+    /// Move active assignments from a failed primary to its promoted standby.
+    ///
+    /// Only the listed meetings are moved (the ones the standby took over);
+    /// any others stay with the primary and are reassigned on the next join
+    /// like after any MC failure.
+    ///
+    /// # Returns
+    ///
+    /// Number of assignments moved.
+    #[instrument(skip_all, fields(from_mc_id = %from_mc_id, to_mc_id = %to_mc_id))]
+    pub async fn move_assignments(
+        pool: &PgPool,
+        from_mc_id: &str,
+        to_mc_id: &str,
+        meeting_ids: &[String],
+        gc_id: &str,
+    ) -> Result<u64, GcError> {
+        if meeting_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE meeting_assignments
+            SET meeting_controller_id = $2,
+                assigned_at = NOW(),
+                assigned_by_gc_id = $4
+            WHERE meeting_controller_id = $1
+              AND meeting_id = ANY($3)
+              AND ended_at IS NULL
+            "#,
+        )
+        .bind(from_mc_id)
+        .bind(to_mc_id)
+        .bind(meeting_ids)
+        .bind(gc_id)
+        .execute(pool)
+        .await;
+
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("move_assignments", status, start.elapsed());
+
+        let count = result?.rows_affected();
+
+        tracing::info!(
+            target: "gc.repository.assignments",
+            from_mc_id = %from_mc_id,
+            to_mc_id = %to_mc_id,
+            count = count,
+            "Moved meeting assignments to standby"
+        );
+
+        Ok(count)
+    }
+
+    /// Record end-of-meeting telemetry on the active assignment.
+    ///
+    /// Must be called before [`Self::end_assignment`]; only active
+    /// assignments are updated. Values feed the meeting report rollup.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - Database connection pool
+    /// * `meeting_id` - Meeting identifier
+    /// * `region` - Deployment region
+    /// * `peak_participants` - Max concurrent participants on the MC
+    /// * `join_failures` - Failed join attempts on the MC
+    /// * `avg_qoe_score` - Average QoE score, if the MC collected samples
+    #[instrument(skip_all, fields(meeting_id = %meeting_id, region = %region))]
+    pub async fn record_telemetry(
+        pool: &PgPool,
+        meeting_id: &str,
+        region: &str,
+        peak_participants: i32,
+        join_failures: i32,
+        avg_qoe_score: Option<f32>,
+    ) -> Result<u64, GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE meeting_assignments
+            SET peak_participants = $3,
+                join_failures = $4,
+                avg_qoe_score = $5
+            WHERE meeting_id = $1
+              AND region = $2
+              AND ended_at IS NULL
+            "#,
+        )
+        .bind(meeting_id)
+        .bind(region)
+        .bind(peak_participants)
+        .bind(join_failures)
+        .bind(avg_qoe_score)
+        .execute(pool)
+        .await;
+
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("record_assignment_telemetry", status, start.elapsed());
+
+        Ok(result?.rows_affected())
+    }
+
+    /// Get the MC a meeting is (or was last) assigned to in a region.
+    ///
+    /// Includes ended assignments: an MC's attendance flush may arrive
+    /// after its assignment has ended.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - Database connection pool
+    /// * `meeting_id` - Meeting identifier
+    /// * `region` - Deployment region
+    #[instrument(skip_all, fields(meeting_id = %meeting_id, region = %region))]
+    pub async fn get_assigned_mc_id(
+        pool: &PgPool,
+        meeting_id: &str,
+        region: &str,
+    ) -> Result<Option<String>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Option<(String,)>, sqlx::Error> = sqlx::query_as(
+            r#"
+            SELECT meeting_controller_id
+            FROM meeting_assignments
+            WHERE meeting_id = $1
+              AND region = $2
+            "#,
+        )
+        .bind(meeting_id)
+        .bind(region)
+        .fetch_optional(pool)
+        .await;
+
+        let (status, row) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("get_assigned_mc_id", status, start.elapsed());
+
+        Ok(row?.map(|(mc_id,)| mc_id))
+    }
+
+    /// Record the time a meeting was live streamed from a region.
+    ///
+    /// Reported with the MC's attendance flush, which may arrive after the
+    /// assignment has ended, so ended assignments are updated too. The value
+    /// is overwritten, making retried flushes idempotent.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - Database connection pool
+    /// * `meeting_id` - Meeting identifier
+    /// * `region` - Deployment region
+    /// * `live_stream_seconds` - Total seconds live streamed on the MC
+    #[instrument(skip_all, fields(meeting_id = %meeting_id, region = %region))]
+    pub async fn record_live_stream_seconds(
+        pool: &PgPool,
+        meeting_id: &str,
+        region: &str,
+        live_stream_seconds: i64,
+    ) -> Result<u64, GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE meeting_assignments
+            SET live_stream_seconds = $3
+            WHERE meeting_id = $1
+              AND region = $2
+            "#,
+        )
+        .bind(meeting_id)
+        .bind(region)
+        .bind(live_stream_seconds)
+        .execute(pool)
+        .await;
+
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("record_live_stream_seconds", status, start.elapsed());
+
+        Ok(result?.rows_affected())
+    }
+
     /// End stale assignments (meetings with no activity).
     ///
     /// Soft-deletes assignments where the meeting has had no activity
@@ -442,7 +741,7 @@ impl MeetingAssignmentsRepository {
                 SELECT inner_ma.meeting_id
                 FROM meeting_assignments inner_ma
                 WHERE inner_ma.ended_at IS NULL
-                  AND inner_ma.assigned_at < NOW() - ($1 || ' hours')::INTERVAL
+                  AND inner_ma.assigned_at < NOW() - $1 * INTERVAL '1 hour'
                   AND EXISTS (
                       SELECT 1 FROM meeting_controllers mc
                       WHERE mc.controller_id = inner_ma.meeting_controller_id
@@ -452,7 +751,7 @@ impl MeetingAssignmentsRepository {
             )
             "#,
         )
-        .bind(inactivity_hours.to_string())
+        .bind(inactivity_hours)
         .bind(limit)
         .execute(pool)
         .await;
@@ -504,12 +803,12 @@ impl MeetingAssignmentsRepository {
             DELETE FROM meeting_assignments
             WHERE meeting_id IN (
                 SELECT meeting_id FROM meeting_assignments
-                WHERE ended_at < NOW() - ($1 || ' days')::INTERVAL
+                WHERE ended_at < NOW() - $1 * INTERVAL '1 day'
                 LIMIT $2
             )
             "#,
         )
-        .bind(retention_days.to_string())
+        .bind(retention_days)
         .bind(limit)
         .execute(pool)
         .await;
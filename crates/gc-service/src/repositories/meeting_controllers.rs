@@ -16,6 +16,18 @@ use sqlx::PgPool;
 use std::time::Instant;
 use tracing::instrument;
 
+/// Pool an MC registers into unless it names one; meetings of organizations
+/// without a dedicated pool are placed here.
+pub const DEFAULT_MC_POOL: &str = "default";
+
+/// Capacity pool for meetings shed under capacity pressure: those finding
+/// no MC with headroom for their priority in their own pool.
+pub const SPILLOVER_MC_POOL: &str = "spillover";
+
+/// Heartbeat staleness beyond which a standby is not promoted (matches the
+/// threshold for assigning meetings).
+const STANDBY_HEARTBEAT_STALENESS_SECONDS: i64 = 30;
+
 /// Health status values matching the database enum.
 /// Maps to proto HealthStatus enum.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -67,7 +79,6 @@ impl HealthStatus {
 
 /// Meeting controller record from database.
 #[derive(Debug, Clone)]
-#[allow(dead_code)] // Will be used for MC lookup
 pub struct MeetingController {
     pub controller_id: String,
     pub region: String,
@@ -80,10 +91,46 @@ pub struct MeetingController {
     pub current_participants: i32,
     pub health_status: HealthStatus,
     pub last_heartbeat_at: DateTime<Utc>,
+    pub pool: String,
+    pub standby_for: Option<String>,
+    pub cordoned_at: Option<DateTime<Utc>>,
+    pub version: Option<String>,
+    pub git_sha: Option<String>,
+    pub proto_schema: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// A warm standby MC ready to take over for its primary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McStandby {
+    /// Standby controller ID.
+    pub controller_id: String,
+    /// gRPC endpoint for GC->MC calls.
+    pub grpc_endpoint: String,
+}
+
+/// The build an MC or MH reported when it registered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReportedBuild<'a> {
+    /// Crate version, e.g. `0.2.0`.
+    pub version: &'a str,
+    /// Commit the binary was built from.
+    pub git_sha: &'a str,
+    /// Protobuf schema fingerprint the binary was compiled against.
+    pub proto_schema: &'a str,
+}
+
+/// How many registered instances run one version.
+///
+/// `None` fields are instances that registered without reporting a build.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct VersionCount {
+    pub version: Option<String>,
+    pub proto_schema: Option<String>,
+    pub count: i64,
+}
+
 /// Repository for meeting controller operations.
 pub struct MeetingControllersRepository;
 
@@ -244,6 +291,70 @@ impl MeetingControllersRepository {
         Ok(updated)
     }
 
+    /// Update heartbeat timestamp and capacity for a controller on the
+    /// streaming heartbeat channel.
+    ///
+    /// Same as [`Self::update_heartbeat`], except that a controller an
+    /// operator has set to `draining` stays draining whatever it reports, so
+    /// GC can tell it to drain in the acknowledgement.
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored health status after the update, or `None` if the
+    /// controller is not registered.
+    #[instrument(skip_all, fields(controller_id = %controller_id))]
+    pub async fn update_stream_heartbeat(
+        pool: &PgPool,
+        controller_id: &str,
+        current_meetings: i32,
+        current_participants: i32,
+        health_status: HealthStatus,
+    ) -> Result<Option<HealthStatus>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Option<String>, sqlx::Error> = sqlx::query_scalar(
+            r#"
+            UPDATE meeting_controllers
+            SET
+                current_meetings = $2,
+                current_participants = $3,
+                health_status = CASE
+                    WHEN health_status = 'draining' THEN 'draining'
+                    ELSE $4
+                END,
+                last_heartbeat_at = NOW(),
+                updated_at = NOW()
+            WHERE controller_id = $1
+            RETURNING health_status
+            "#,
+        )
+        .bind(controller_id)
+        .bind(current_meetings)
+        .bind(current_participants)
+        .bind(health_status.as_db_str())
+        .fetch_optional(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("update_stream_heartbeat", status, start.elapsed());
+
+        let stored = result?.map(|s| HealthStatus::from_db_str(&s));
+
+        if stored.is_none() {
+            tracing::warn!(
+                target: "gc.repository.mc",
+                controller_id = %controller_id,
+                "Stream heartbeat update failed: controller not found"
+            );
+        }
+
+        Ok(stored)
+    }
+
     /// Mark stale controllers as unhealthy.
     ///
     /// Controllers that haven't sent a heartbeat within the staleness threshold
@@ -271,12 +382,12 @@ impl MeetingControllersRepository {
                 health_status = 'unhealthy',
                 updated_at = NOW()
             WHERE
-                last_heartbeat_at < NOW() - ($1 || ' seconds')::INTERVAL
+                last_heartbeat_at < NOW() - $1 * INTERVAL '1 second'
                 AND health_status != 'unhealthy'
                 AND health_status != 'draining'
             "#,
         )
-        .bind(staleness_threshold_seconds.to_string())
+        .bind(staleness_threshold_seconds)
         .execute(pool)
         .await;
 
@@ -301,6 +412,223 @@ impl MeetingControllersRepository {
         Ok(count)
     }
 
+    /// Set (or clear) the primary a controller is the warm standby for.
+    ///
+    /// Called after registration with what the MC registered as, and on
+    /// promotion to clear it.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a row was updated, `false` if controller not found.
+    #[instrument(skip_all, fields(controller_id = %controller_id))]
+    pub async fn set_standby_for(
+        pool: &PgPool,
+        controller_id: &str,
+        primary_id: Option<&str>,
+    ) -> Result<bool, GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE meeting_controllers
+            SET standby_for = $2, updated_at = NOW()
+            WHERE controller_id = $1
+            "#,
+        )
+        .bind(controller_id)
+        .bind(primary_id)
+        .execute(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("set_mc_standby_for", status, start.elapsed());
+
+        Ok(result?.rows_affected() > 0)
+    }
+
+    /// Set the capacity pool a controller takes meetings for.
+    ///
+    /// Called after registration with the pool the MC registered into.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a row was updated, `false` if controller not found.
+    #[instrument(skip_all, fields(controller_id = %controller_id, mc_pool = %mc_pool))]
+    pub async fn set_pool(
+        pool: &PgPool,
+        controller_id: &str,
+        mc_pool: &str,
+    ) -> Result<bool, GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE meeting_controllers
+            SET pool = $2, updated_at = NOW()
+            WHERE controller_id = $1
+            "#,
+        )
+        .bind(controller_id)
+        .bind(mc_pool)
+        .execute(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("set_mc_pool", status, start.elapsed());
+
+        Ok(result?.rows_affected() > 0)
+    }
+
+    /// Record the build a controller registered with.
+    ///
+    /// Called after registration; `None` (an MC that predates version
+    /// reporting) clears a build recorded by an earlier registration.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a row was updated, `false` if controller not found.
+    #[instrument(skip_all, fields(controller_id = %controller_id))]
+    pub async fn set_build(
+        pool: &PgPool,
+        controller_id: &str,
+        build: Option<ReportedBuild<'_>>,
+    ) -> Result<bool, GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE meeting_controllers
+            SET version = $2, git_sha = $3, proto_schema = $4, updated_at = NOW()
+            WHERE controller_id = $1
+            "#,
+        )
+        .bind(controller_id)
+        .bind(build.map(|b| b.version))
+        .bind(build.map(|b| b.git_sha))
+        .bind(build.map(|b| b.proto_schema))
+        .execute(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("set_mc_build", status, start.elapsed());
+
+        Ok(result?.rows_affected() > 0)
+    }
+
+    /// Count controllers by reported version and proto schema.
+    ///
+    /// Unhealthy controllers are left out: they are usually pods that are
+    /// gone, and would otherwise keep an old version in the distribution
+    /// after a rollout completes.
+    #[instrument(skip_all)]
+    pub async fn get_version_counts(pool: &PgPool) -> Result<Vec<VersionCount>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Vec<VersionCount>, sqlx::Error> = sqlx::query_as(
+            r#"
+            SELECT version, proto_schema, COUNT(*) as count
+            FROM meeting_controllers
+            WHERE health_status != 'unhealthy'
+            GROUP BY version, proto_schema
+            ORDER BY version, proto_schema
+            "#,
+        )
+        .fetch_all(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, rows) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("get_mc_version_counts", status, start.elapsed());
+
+        Ok(rows?)
+    }
+
+    /// Get the healthy warm standby for a primary, if it has one.
+    ///
+    /// With more than one standby registered for the primary, the one that
+    /// heartbeated most recently is returned.
+    #[instrument(skip_all, fields(primary_id = %primary_id))]
+    pub async fn get_standby(
+        pool: &PgPool,
+        primary_id: &str,
+    ) -> Result<Option<McStandby>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Option<(String, String)>, sqlx::Error> = sqlx::query_as(
+            r#"
+            SELECT controller_id, grpc_endpoint
+            FROM meeting_controllers
+            WHERE standby_for = $1
+              AND health_status = 'healthy'
+              AND last_heartbeat_at > NOW() - $2 * INTERVAL '1 second'
+            ORDER BY last_heartbeat_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(primary_id)
+        .bind(STANDBY_HEARTBEAT_STALENESS_SECONDS)
+        .fetch_optional(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, row) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("get_mc_standby", status, start.elapsed());
+
+        Ok(row?.map(|(controller_id, grpc_endpoint)| McStandby {
+            controller_id,
+            grpc_endpoint,
+        }))
+    }
+
+    /// Mark one controller unhealthy, so no new meetings are assigned to it
+    /// until it heartbeats again.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a row was updated, `false` if controller not found.
+    #[instrument(skip_all, fields(controller_id = %controller_id))]
+    pub async fn mark_unhealthy(pool: &PgPool, controller_id: &str) -> Result<bool, GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE meeting_controllers
+            SET health_status = 'unhealthy', updated_at = NOW()
+            WHERE controller_id = $1
+            "#,
+        )
+        .bind(controller_id)
+        .execute(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("mark_controller_unhealthy", status, start.elapsed());
+
+        Ok(result?.rows_affected() > 0)
+    }
+
     /// Get a meeting controller by ID.
     ///
     /// # Arguments
@@ -311,7 +639,6 @@ impl MeetingControllersRepository {
     /// # Returns
     ///
     /// Returns `Some(MeetingController)` if found, `None` otherwise.
-    #[allow(dead_code)] // Will be used in future phases
     #[instrument(skip_all, fields(controller_id = %controller_id))]
     pub async fn get_controller(
         pool: &PgPool,
@@ -334,6 +661,12 @@ impl MeetingControllersRepository {
                 current_participants,
                 health_status,
                 last_heartbeat_at,
+                pool,
+                standby_for,
+                cordoned_at,
+                version,
+                git_sha,
+                proto_schema,
                 created_at,
                 updated_at
             FROM meeting_controllers
@@ -351,21 +684,94 @@ impl MeetingControllersRepository {
         };
         metrics::record_db_query("get_controller", status, start.elapsed());
 
-        Ok(row?.map(|r| MeetingController {
-            controller_id: r.controller_id,
-            region: r.region,
-            endpoint: r.endpoint,
-            grpc_endpoint: r.grpc_endpoint,
-            webtransport_endpoint: r.webtransport_endpoint,
-            max_meetings: r.max_meetings,
-            current_meetings: r.current_meetings,
-            max_participants: r.max_participants,
-            current_participants: r.current_participants,
-            health_status: HealthStatus::from_db_str(&r.health_status),
-            last_heartbeat_at: r.last_heartbeat_at,
-            created_at: r.created_at,
-            updated_at: r.updated_at,
-        }))
+        Ok(row?.map(MeetingController::from))
+    }
+
+    /// List all registered meeting controllers, by region then ID.
+    ///
+    /// Backs the admin list endpoint, so unhealthy and cordoned controllers
+    /// are included.
+    #[instrument(skip_all)]
+    pub async fn list_controllers(pool: &PgPool) -> Result<Vec<MeetingController>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Vec<MeetingControllerRow>, sqlx::Error> = sqlx::query_as(
+            r#"
+            SELECT
+                controller_id,
+                region,
+                endpoint,
+                grpc_endpoint,
+                webtransport_endpoint,
+                max_meetings,
+                current_meetings,
+                max_participants,
+                current_participants,
+                health_status,
+                last_heartbeat_at,
+                pool,
+                standby_for,
+                cordoned_at,
+                version,
+                git_sha,
+                proto_schema,
+                created_at,
+                updated_at
+            FROM meeting_controllers
+            ORDER BY region, controller_id
+            "#,
+        )
+        .fetch_all(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, rows) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("list_controllers", status, start.elapsed());
+
+        Ok(rows?.into_iter().map(MeetingController::from).collect())
+    }
+
+    /// Cordon or uncordon a controller.
+    ///
+    /// A cordoned controller is left out of candidate selection; meetings
+    /// already assigned to it are unaffected. Cordoning an already cordoned
+    /// controller keeps its original `cordoned_at`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a row was updated, `false` if controller not found.
+    #[instrument(skip_all, fields(controller_id = %controller_id, cordoned = cordoned))]
+    pub async fn set_cordoned(
+        pool: &PgPool,
+        controller_id: &str,
+        cordoned: bool,
+    ) -> Result<bool, GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE meeting_controllers
+            SET cordoned_at = CASE WHEN $2 THEN COALESCE(cordoned_at, NOW()) END,
+                updated_at = NOW()
+            WHERE controller_id = $1
+            "#,
+        )
+        .bind(controller_id)
+        .bind(cordoned)
+        .execute(pool)
+        .await;
+
+        // Record DB query metrics (ADR-0011)
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("set_mc_cordoned", status, start.elapsed());
+
+        Ok(result?.rows_affected() > 0)
     }
 
     /// Get counts of meeting controllers grouped by health status.
@@ -420,7 +826,6 @@ struct ControllerCountRow {
 
 /// Database row representation for meeting controllers.
 #[derive(sqlx::FromRow)]
-#[allow(dead_code)] // Used by get_controller
 struct MeetingControllerRow {
     controller_id: String,
     region: String,
@@ -433,10 +838,42 @@ struct MeetingControllerRow {
     current_participants: i32,
     health_status: String,
     last_heartbeat_at: DateTime<Utc>,
+    pool: String,
+    standby_for: Option<String>,
+    cordoned_at: Option<DateTime<Utc>>,
+    version: Option<String>,
+    git_sha: Option<String>,
+    proto_schema: Option<String>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
 
+impl From<MeetingControllerRow> for MeetingController {
+    fn from(r: MeetingControllerRow) -> Self {
+        Self {
+            controller_id: r.controller_id,
+            region: r.region,
+            endpoint: r.endpoint,
+            grpc_endpoint: r.grpc_endpoint,
+            webtransport_endpoint: r.webtransport_endpoint,
+            max_meetings: r.max_meetings,
+            current_meetings: r.current_meetings,
+            max_participants: r.max_participants,
+            current_participants: r.current_participants,
+            health_status: HealthStatus::from_db_str(&r.health_status),
+            last_heartbeat_at: r.last_heartbeat_at,
+            pool: r.pool,
+            standby_for: r.standby_for,
+            cordoned_at: r.cordoned_at,
+            version: r.version,
+            git_sha: r.git_sha,
+            proto_schema: r.proto_schema,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -0,0 +1,494 @@
+//! Meeting imports repository for database operations.
+//!
+//! An import job row, holding the validated records, is written when an
+//! import is accepted. The import worker claims a running job
+//! (`FOR UPDATE SKIP LOCKED`) and creates its meetings in the same
+//! transaction that marks the job finished, so a job is either `succeeded`
+//! with every meeting created or has created nothing.
+//!
+//! # Org Limits
+//!
+//! Imported meetings are `scheduled` and count toward the org's
+//! `max_concurrent_meetings`. The org row is locked for the transaction so
+//! concurrent imports for the same org cannot both pass the limit check.
+
+use crate::errors::GcError;
+use crate::events::GcEvent;
+use crate::models::{
+    ImportedMeeting, MeetingImportRecord, MeetingImportStatus, DEFAULT_MAX_PARTICIPANTS,
+};
+use crate::observability::metrics;
+use crate::repositories::EventOutboxRepository;
+use chrono::{DateTime, Utc};
+use common::types::MeetingId;
+use sqlx::{PgConnection, PgPool};
+use std::time::Instant;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// A meeting import job.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeetingImportJob {
+    /// Job ID.
+    pub job_id: Uuid,
+    /// Organization the meetings are imported into.
+    pub org_id: Uuid,
+    /// Org admin who submitted the import; owns the imported meetings.
+    pub created_by_user_id: Uuid,
+    /// Job state.
+    pub status: MeetingImportStatus,
+    /// Meetings in the import.
+    pub total_records: i32,
+    /// Meetings created.
+    pub imported_records: i32,
+    /// Why the import failed.
+    pub error: Option<String>,
+    /// When the import was accepted.
+    pub created_at: DateTime<Utc>,
+    /// When the import finished.
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// A validated record with its generated meeting code and join secret.
+#[derive(Debug, Clone)]
+pub struct PreparedImportMeeting {
+    /// The record as submitted.
+    pub record: MeetingImportRecord,
+    /// Generated meeting code.
+    pub meeting_code: String,
+    /// Generated join token secret (hex-encoded).
+    pub join_token_secret: String,
+}
+
+/// A running job claimed by the import worker, with its stored records.
+#[derive(Debug, Clone)]
+pub struct PendingMeetingImport {
+    /// The claimed job.
+    pub job: MeetingImportJob,
+    /// Records as stored at submission (JSON array).
+    pub records: serde_json::Value,
+}
+
+impl PendingMeetingImport {
+    /// Decode the stored records.
+    pub fn decode(&self) -> Result<Vec<MeetingImportRecord>, serde_json::Error> {
+        serde_json::from_value(self.records.clone())
+    }
+}
+
+/// Result of creating a job's meetings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MeetingImportOutcome {
+    /// Every meeting was created.
+    Imported(usize),
+    /// The org is missing or inactive.
+    OrgInactive,
+    /// The import would take the org over its concurrent meeting limit.
+    LimitExceeded {
+        /// Org's `max_concurrent_meetings`.
+        limit: i32,
+        /// Scheduled and active meetings the org already has.
+        existing: i64,
+    },
+    /// A generated meeting code is already in use in the org.
+    CodeCollision,
+}
+
+type JobRow = (
+    Uuid,
+    Uuid,
+    Uuid,
+    String,
+    i32,
+    i32,
+    Option<String>,
+    DateTime<Utc>,
+    Option<DateTime<Utc>>,
+);
+
+type PendingRow = (
+    Uuid,
+    Uuid,
+    Uuid,
+    String,
+    i32,
+    i32,
+    Option<String>,
+    DateTime<Utc>,
+    Option<DateTime<Utc>>,
+    Option<serde_json::Value>,
+);
+
+fn map_job_row(row: JobRow) -> MeetingImportJob {
+    let (
+        job_id,
+        org_id,
+        created_by_user_id,
+        status,
+        total_records,
+        imported_records,
+        error,
+        created_at,
+        completed_at,
+    ) = row;
+    MeetingImportJob {
+        job_id,
+        org_id,
+        created_by_user_id,
+        status: MeetingImportStatus::from_db(&status),
+        total_records,
+        imported_records,
+        error,
+        created_at,
+        completed_at,
+    }
+}
+
+/// Repository for meeting import operations.
+pub struct MeetingImportsRepository;
+
+impl MeetingImportsRepository {
+    /// Record an accepted import as a `running` job holding its records.
+    #[instrument(skip_all, name = "gc.repo.create_meeting_import_job", fields(org_id = %org_id, records = records.len()))]
+    pub async fn create_job(
+        pool: &PgPool,
+        org_id: Uuid,
+        created_by_user_id: Uuid,
+        records: &[MeetingImportRecord],
+    ) -> Result<MeetingImportJob, GcError> {
+        let payload = serde_json::to_value(records)
+            .map_err(|e| GcError::Internal(format!("Failed to serialize import records: {}", e)))?;
+        let total_records = i32::try_from(records.len())
+            .map_err(|_| GcError::BadRequest("Too many records".to_string()))?;
+
+        let start = Instant::now();
+
+        let result: Result<JobRow, sqlx::Error> = sqlx::query_as(
+            r#"
+            INSERT INTO meeting_import_jobs (
+                job_id, org_id, created_by_user_id, total_records, records
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING
+                job_id, org_id, created_by_user_id, status, total_records,
+                imported_records, error, created_at, completed_at
+            "#,
+        )
+        .bind(Uuid::now_v7())
+        .bind(org_id)
+        .bind(created_by_user_id)
+        .bind(total_records)
+        .bind(payload)
+        .fetch_one(pool)
+        .await;
+
+        let status = if result.is_ok() { "success" } else { "error" };
+        metrics::record_db_query("create_meeting_import_job", status, start.elapsed());
+
+        Ok(map_job_row(result?))
+    }
+
+    /// Fetch a job within an organization.
+    ///
+    /// Returns `None` if the job does not exist or belongs to another org.
+    #[instrument(skip_all, name = "gc.repo.get_meeting_import_job", fields(job_id = %job_id))]
+    pub async fn get_job(
+        pool: &PgPool,
+        org_id: Uuid,
+        job_id: Uuid,
+    ) -> Result<Option<MeetingImportJob>, GcError> {
+        let start = Instant::now();
+
+        let result: Result<Option<JobRow>, sqlx::Error> = sqlx::query_as(
+            r#"
+            SELECT
+                job_id, org_id, created_by_user_id, status, total_records,
+                imported_records, error, created_at, completed_at
+            FROM meeting_import_jobs
+            WHERE job_id = $1 AND org_id = $2
+            "#,
+        )
+        .bind(job_id)
+        .bind(org_id)
+        .fetch_optional(pool)
+        .await;
+
+        let status = if result.is_ok() { "success" } else { "error" };
+        metrics::record_db_query("get_meeting_import_job", status, start.elapsed());
+
+        Ok(result?.map(map_job_row))
+    }
+
+    /// List the meetings a job created, in record order.
+    #[instrument(skip_all, name = "gc.repo.list_imported_meetings", fields(job_id = %job_id))]
+    pub async fn list_imported_meetings(
+        pool: &PgPool,
+        job_id: Uuid,
+    ) -> Result<Vec<ImportedMeeting>, GcError> {
+        let start = Instant::now();
+
+        // Meeting IDs are UUIDv7 generated in record order, so they sort
+        // the way the records were submitted.
+        let result: Result<Vec<(Option<String>, Uuid, String)>, sqlx::Error> = sqlx::query_as(
+            r#"
+            SELECT import_external_id, meeting_id, meeting_code
+            FROM meetings
+            WHERE import_job_id = $1
+            ORDER BY meeting_id
+            "#,
+        )
+        .bind(job_id)
+        .fetch_all(pool)
+        .await;
+
+        let status = if result.is_ok() { "success" } else { "error" };
+        metrics::record_db_query("list_imported_meetings", status, start.elapsed());
+
+        Ok(result?
+            .into_iter()
+            .map(|(external_id, meeting_id, meeting_code)| ImportedMeeting {
+                external_id,
+                meeting_id,
+                meeting_code,
+            })
+            .collect())
+    }
+
+    /// Claim the oldest running job.
+    ///
+    /// Must be called inside a transaction; the row lock is held until the
+    /// caller finishes the job and commits, or rolls back to leave it for a
+    /// later attempt.
+    #[instrument(skip_all, name = "gc.repo.claim_meeting_import_job")]
+    pub async fn claim_next(
+        conn: &mut PgConnection,
+    ) -> Result<Option<PendingMeetingImport>, GcError> {
+        let start = Instant::now();
+
+        let result: Result<Option<PendingRow>, sqlx::Error> = sqlx::query_as(
+            r#"
+            SELECT
+                job_id, org_id, created_by_user_id, status, total_records,
+                imported_records, error, created_at, completed_at, records
+            FROM meeting_import_jobs
+            WHERE status = 'running'
+            ORDER BY created_at
+            LIMIT 1
+            FOR UPDATE SKIP LOCKED
+            "#,
+        )
+        .fetch_optional(conn)
+        .await;
+
+        let status = if result.is_ok() { "success" } else { "error" };
+        metrics::record_db_query("claim_meeting_import_job", status, start.elapsed());
+
+        Ok(result?.map(
+            |(
+                job_id,
+                org_id,
+                created_by_user_id,
+                status,
+                total_records,
+                imported_records,
+                error,
+                created_at,
+                completed_at,
+                records,
+            )| PendingMeetingImport {
+                job: map_job_row((
+                    job_id,
+                    org_id,
+                    created_by_user_id,
+                    status,
+                    total_records,
+                    imported_records,
+                    error,
+                    created_at,
+                    completed_at,
+                )),
+                records: records.unwrap_or(serde_json::Value::Null),
+            },
+        ))
+    }
+
+    /// Create every meeting of a claimed job.
+    ///
+    /// Applies the same defaults and participant cap as single meeting
+    /// creation. A `MeetingCreated` event is written to the event outbox
+    /// for each meeting. Must be called in the claiming transaction; on any
+    /// outcome other than `Imported` the caller must not commit the
+    /// meetings (`OrgInactive` and `LimitExceeded` are detected before any
+    /// insert).
+    #[instrument(skip_all, name = "gc.repo.import_meetings", fields(job_id = %job.job_id, records = meetings.len()))]
+    pub async fn import_meetings(
+        conn: &mut PgConnection,
+        job: &MeetingImportJob,
+        meetings: &[PreparedImportMeeting],
+    ) -> Result<MeetingImportOutcome, GcError> {
+        let start = Instant::now();
+        let result = Self::insert_meetings(conn, job, meetings).await;
+
+        let status = if result.is_ok() { "success" } else { "error" };
+        metrics::record_db_query("import_meetings", status, start.elapsed());
+
+        result
+    }
+
+    async fn insert_meetings(
+        conn: &mut PgConnection,
+        job: &MeetingImportJob,
+        meetings: &[PreparedImportMeeting],
+    ) -> Result<MeetingImportOutcome, GcError> {
+        let limits: Option<(i32, i32)> = sqlx::query_as(
+            r#"
+            SELECT max_concurrent_meetings, max_participants_per_meeting
+            FROM organizations
+            WHERE org_id = $1 AND is_active = true
+            FOR UPDATE
+            "#,
+        )
+        .bind(job.org_id)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        let Some((max_concurrent_meetings, max_participants_per_meeting)) = limits else {
+            return Ok(MeetingImportOutcome::OrgInactive);
+        };
+
+        let (existing,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*)
+            FROM meetings
+            WHERE org_id = $1 AND status IN ('scheduled', 'active')
+            "#,
+        )
+        .bind(job.org_id)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        let requested = i64::try_from(meetings.len()).unwrap_or(i64::MAX);
+        if existing.saturating_add(requested) > i64::from(max_concurrent_meetings) {
+            return Ok(MeetingImportOutcome::LimitExceeded {
+                limit: max_concurrent_meetings,
+                existing,
+            });
+        }
+
+        for meeting in meetings {
+            let record = &meeting.record;
+            let meeting_id = MeetingId::new();
+
+            let inserted = sqlx::query(
+                r#"
+                INSERT INTO meetings (
+                    meeting_id, org_id, created_by_user_id, display_name, meeting_code,
+                    join_token_secret, max_participants, enable_e2e_encryption,
+                    require_auth, recording_enabled, allow_guests,
+                    allow_external_participants, waiting_room_enabled,
+                    scheduled_start_time, status, priority,
+                    import_job_id, import_external_id
+                )
+                VALUES (
+                    $1, $2, $3, $4, $5, $6, LEAST($7, $8), $9, $10, $11, $12, $13, $14,
+                    $15, 'scheduled', $16, $17, $18
+                )
+                ON CONFLICT (org_id, meeting_code) DO NOTHING
+                "#,
+            )
+            .bind(meeting_id) // $1
+            .bind(job.org_id) // $2
+            .bind(job.created_by_user_id) // $3
+            .bind(record.display_name.trim()) // $4
+            .bind(&meeting.meeting_code) // $5
+            .bind(&meeting.join_token_secret) // $6
+            .bind(record.max_participants.unwrap_or(DEFAULT_MAX_PARTICIPANTS)) // $7
+            .bind(max_participants_per_meeting) // $8
+            .bind(record.enable_e2e_encryption.unwrap_or(true)) // $9
+            .bind(record.require_auth.unwrap_or(true)) // $10
+            .bind(record.recording_enabled.unwrap_or(false)) // $11
+            .bind(record.allow_guests.unwrap_or(false)) // $12
+            .bind(record.allow_external_participants.unwrap_or(false)) // $13
+            .bind(record.waiting_room_enabled.unwrap_or(true)) // $14
+            .bind(record.scheduled_start_time) // $15
+            .bind(record.priority.unwrap_or_default().as_str()) // $16
+            .bind(job.job_id) // $17
+            .bind(record.external_id.as_deref()) // $18
+            .execute(&mut *conn)
+            .await?;
+
+            if inserted.rows_affected() == 0 {
+                return Ok(MeetingImportOutcome::CodeCollision);
+            }
+
+            EventOutboxRepository::enqueue(
+                &mut *conn,
+                &GcEvent::MeetingCreated {
+                    meeting_id: meeting_id.as_uuid(),
+                    org_id: job.org_id,
+                    created_by_user_id: job.created_by_user_id,
+                },
+            )
+            .await?;
+        }
+
+        Ok(MeetingImportOutcome::Imported(meetings.len()))
+    }
+
+    /// Mark a claimed job succeeded and drop its stored records.
+    #[instrument(skip_all, name = "gc.repo.complete_meeting_import_job", fields(job_id = %job_id))]
+    pub async fn complete_job(
+        conn: &mut PgConnection,
+        job_id: Uuid,
+        imported_records: i32,
+    ) -> Result<(), GcError> {
+        let start = Instant::now();
+
+        let result = sqlx::query(
+            r#"
+            UPDATE meeting_import_jobs
+            SET status = 'succeeded', imported_records = $2, records = NULL,
+                completed_at = NOW()
+            WHERE job_id = $1 AND status = 'running'
+            "#,
+        )
+        .bind(job_id)
+        .bind(imported_records)
+        .execute(conn)
+        .await;
+
+        let status = if result.is_ok() { "success" } else { "error" };
+        metrics::record_db_query("complete_meeting_import_job", status, start.elapsed());
+
+        result?;
+        Ok(())
+    }
+
+    /// Mark a claimed job failed and drop its stored records.
+    #[instrument(skip_all, name = "gc.repo.fail_meeting_import_job", fields(job_id = %job_id))]
+    pub async fn fail_job(
+        conn: &mut PgConnection,
+        job_id: Uuid,
+        error: &str,
+    ) -> Result<(), GcError> {
+        let start = Instant::now();
+
+        let result = sqlx::query(
+            r#"
+            UPDATE meeting_import_jobs
+            SET status = 'failed', error = $2, records = NULL, completed_at = NOW()
+            WHERE job_id = $1 AND status = 'running'
+            "#,
+        )
+        .bind(job_id)
+        .bind(error)
+        .execute(conn)
+        .await;
+
+        let status = if result.is_ok() { "success" } else { "error" };
+        metrics::record_db_query("fail_meeting_import_job", status, start.elapsed());
+
+        result?;
+        Ok(())
+    }
+}
@@ -0,0 +1,352 @@
+//! Meeting recordings repository for database operations.
+//!
+//! Rows are written when an MH recorder reports a finished upload and read
+//! back for the host recordings API. Deletion is two-phase: the API marks a
+//! row deleted (hiding it immediately), and the retention task removes the
+//! object before dropping the row.
+//!
+//! # Idempotency
+//!
+//! The recorder chooses the `recording_id`, so a report retried after a
+//! timeout does not create a second row.
+
+use crate::errors::GcError;
+use crate::observability::metrics;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::time::Instant;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Metadata for a recording stored in the object store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeetingRecording {
+    /// Recording ID (chosen by the recorder).
+    pub recording_id: Uuid,
+    /// Meeting the recording belongs to.
+    pub meeting_id: Uuid,
+    /// MH that produced the recording.
+    pub media_handler_id: String,
+    /// Object store key.
+    pub object_key: String,
+    /// MIME type of the recording file.
+    pub content_type: String,
+    /// File size in bytes.
+    pub size_bytes: i64,
+    /// Recorded media duration in seconds.
+    pub duration_seconds: i64,
+    /// When recording started.
+    pub started_at: DateTime<Utc>,
+    /// When recording finished.
+    pub completed_at: DateTime<Utc>,
+}
+
+/// A recording due for removal from the object store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PurgeableRecording {
+    /// Recording ID.
+    pub recording_id: Uuid,
+    /// Meeting the recording belongs to.
+    pub meeting_id: Uuid,
+    /// Owning organization (for audit logging).
+    pub org_id: Uuid,
+    /// Object store key.
+    pub object_key: String,
+    /// Whether a host deleted it (`false` = expired by retention).
+    pub deleted_by_host: bool,
+}
+
+type RecordingRow = (
+    Uuid,
+    Uuid,
+    String,
+    String,
+    String,
+    i64,
+    i64,
+    DateTime<Utc>,
+    DateTime<Utc>,
+);
+
+fn map_recording_row(row: RecordingRow) -> MeetingRecording {
+    let (
+        recording_id,
+        meeting_id,
+        media_handler_id,
+        object_key,
+        content_type,
+        size_bytes,
+        duration_seconds,
+        started_at,
+        completed_at,
+    ) = row;
+    MeetingRecording {
+        recording_id,
+        meeting_id,
+        media_handler_id,
+        object_key,
+        content_type,
+        size_bytes,
+        duration_seconds,
+        started_at,
+        completed_at,
+    }
+}
+
+/// Repository for meeting recording operations.
+pub struct MeetingRecordingsRepository;
+
+impl MeetingRecordingsRepository {
+    /// Insert a reported recording.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a new row was stored, `false` for a repeated report.
+    #[instrument(skip_all, name = "gc.repo.insert_meeting_recording", fields(meeting_id = %recording.meeting_id, recording_id = %recording.recording_id))]
+    pub async fn insert(pool: &PgPool, recording: &MeetingRecording) -> Result<bool, GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            INSERT INTO meeting_recordings (
+                recording_id, meeting_id, media_handler_id, object_key, content_type,
+                size_bytes, duration_seconds, started_at, completed_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (recording_id) DO NOTHING
+            "#,
+        )
+        .bind(recording.recording_id)
+        .bind(recording.meeting_id)
+        .bind(&recording.media_handler_id)
+        .bind(&recording.object_key)
+        .bind(&recording.content_type)
+        .bind(recording.size_bytes)
+        .bind(recording.duration_seconds)
+        .bind(recording.started_at)
+        .bind(recording.completed_at)
+        .execute(pool)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("insert_meeting_recording", status, start.elapsed());
+
+        Ok(query_result?.rows_affected() > 0)
+    }
+
+    /// List a meeting's recordings (excluding deleted ones) in start order.
+    #[instrument(skip_all, name = "gc.repo.list_meeting_recordings", fields(meeting_id = %meeting_id))]
+    pub async fn list_for_meeting(
+        pool: &PgPool,
+        meeting_id: Uuid,
+    ) -> Result<Vec<MeetingRecording>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Vec<RecordingRow>, sqlx::Error> = sqlx::query_as(
+            r#"
+            SELECT recording_id, meeting_id, media_handler_id, object_key, content_type,
+                   size_bytes, duration_seconds, started_at, completed_at
+            FROM meeting_recordings
+            WHERE meeting_id = $1 AND deleted_at IS NULL
+            ORDER BY started_at, recording_id
+            "#,
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("list_meeting_recordings", status, start.elapsed());
+
+        Ok(query_result?.into_iter().map(map_recording_row).collect())
+    }
+
+    /// Get one visible recording of a meeting.
+    #[instrument(skip_all, name = "gc.repo.get_meeting_recording", fields(meeting_id = %meeting_id, recording_id = %recording_id))]
+    pub async fn get(
+        pool: &PgPool,
+        meeting_id: Uuid,
+        recording_id: Uuid,
+    ) -> Result<Option<MeetingRecording>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Option<RecordingRow>, sqlx::Error> = sqlx::query_as(
+            r#"
+            SELECT recording_id, meeting_id, media_handler_id, object_key, content_type,
+                   size_bytes, duration_seconds, started_at, completed_at
+            FROM meeting_recordings
+            WHERE meeting_id = $1 AND recording_id = $2 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(meeting_id)
+        .bind(recording_id)
+        .fetch_optional(pool)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("get_meeting_recording", status, start.elapsed());
+
+        Ok(query_result?.map(map_recording_row))
+    }
+
+    /// Mark a recording deleted so it is hidden and queued for removal.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a visible recording was marked, `false` if none matched.
+    #[instrument(skip_all, name = "gc.repo.mark_recording_deleted", fields(meeting_id = %meeting_id, recording_id = %recording_id))]
+    pub async fn mark_deleted(
+        pool: &PgPool,
+        meeting_id: Uuid,
+        recording_id: Uuid,
+        deleted_by_user_id: Uuid,
+    ) -> Result<bool, GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE meeting_recordings
+            SET deleted_at = NOW(), deleted_by_user_id = $3
+            WHERE meeting_id = $1 AND recording_id = $2 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(meeting_id)
+        .bind(recording_id)
+        .bind(deleted_by_user_id)
+        .execute(pool)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("mark_recording_deleted", status, start.elapsed());
+
+        Ok(query_result?.rows_affected() > 0)
+    }
+
+    /// List recordings whose objects should be removed: deleted by a host,
+    /// or completed more than `retention_days` ago.
+    #[instrument(skip_all, name = "gc.repo.list_purgeable_recordings")]
+    pub async fn list_purgeable(
+        pool: &PgPool,
+        retention_days: i32,
+        limit: i64,
+    ) -> Result<Vec<PurgeableRecording>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Vec<(Uuid, Uuid, Uuid, String, bool)>, sqlx::Error> =
+            sqlx::query_as(
+                r#"
+                SELECT r.recording_id, r.meeting_id, m.org_id, r.object_key,
+                       r.deleted_at IS NOT NULL AS deleted_by_host
+                FROM meeting_recordings r
+                JOIN meetings m ON m.meeting_id = r.meeting_id
+                WHERE r.deleted_at IS NOT NULL
+                   OR r.completed_at < NOW() - $1 * INTERVAL '1 day'
+                ORDER BY r.completed_at
+                LIMIT $2
+                "#,
+            )
+            .bind(retention_days)
+            .bind(limit)
+            .fetch_all(pool)
+            .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("list_purgeable_recordings", status, start.elapsed());
+
+        Ok(query_result?
+            .into_iter()
+            .map(
+                |(recording_id, meeting_id, org_id, object_key, deleted_by_host)| {
+                    PurgeableRecording {
+                        recording_id,
+                        meeting_id,
+                        org_id,
+                        object_key,
+                        deleted_by_host,
+                    }
+                },
+            )
+            .collect())
+    }
+
+    /// Remove a recording row once its object is gone.
+    #[instrument(skip_all, name = "gc.repo.remove_meeting_recording", fields(recording_id = %recording_id))]
+    pub async fn remove(pool: &PgPool, recording_id: Uuid) -> Result<(), GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query("DELETE FROM meeting_recordings WHERE recording_id = $1")
+            .bind(recording_id)
+            .execute(pool)
+            .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("remove_meeting_recording", status, start.elapsed());
+
+        query_result?;
+        Ok(())
+    }
+
+    /// Log a recording audit event against the owning meeting.
+    ///
+    /// Fire-and-forget like `MeetingsRepository::log_audit_event`; the
+    /// recording ID is kept in `details`.
+    #[instrument(skip_all, name = "gc.repo.log_recording_audit_event", fields(action = %action))]
+    pub async fn log_audit_event(
+        pool: &PgPool,
+        org_id: Uuid,
+        user_id: Option<Uuid>,
+        meeting_id: Uuid,
+        recording_id: Uuid,
+        action: &str,
+    ) -> Result<(), GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            INSERT INTO audit_logs (org_id, user_id, action, resource_type, resource_id, details)
+            VALUES ($1, $2, $3, 'meeting', $4, $5)
+            "#,
+        )
+        .bind(org_id)
+        .bind(user_id)
+        .bind(action)
+        .bind(meeting_id)
+        .bind(serde_json::json!({"action": action, "recording_id": recording_id}))
+        .execute(pool)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("log_audit_event", status, start.elapsed());
+
+        query_result?;
+        Ok(())
+    }
+}
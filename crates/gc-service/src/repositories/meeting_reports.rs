@@ -0,0 +1,238 @@
+//! Meeting reports repository for database operations.
+//!
+//! Computes and reads post-meeting analytics rollups. A meeting is eligible for
+//! a report once it has at least one assignment and every regional assignment
+//! has ended. Inputs are the assignment rows (timing plus MC-reported
+//! telemetry) and the participants table.
+//!
+//! # Recompute
+//!
+//! If a meeting is reassigned after its report was computed (e.g. a host
+//! restarts the meeting), the report is recomputed once the new assignments end.
+//! Live streaming time arrives with the attendance flush, possibly after the
+//! report was computed, and is patched into an existing report.
+
+use crate::errors::GcError;
+use crate::observability::metrics;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::time::Instant;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Default batch size for report computation.
+const DEFAULT_REPORT_BATCH_SIZE: i64 = 100;
+
+/// Post-meeting analytics rollup.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeetingReport {
+    /// Meeting ID.
+    pub meeting_id: Uuid,
+    /// Earliest regional assignment time.
+    pub started_at: DateTime<Utc>,
+    /// Latest regional assignment end time.
+    pub ended_at: DateTime<Utc>,
+    /// Meeting duration in seconds.
+    pub duration_seconds: i64,
+    /// Sum of per-region peak concurrent participants.
+    pub peak_participants: i32,
+    /// Participant sessions recorded for the meeting.
+    pub total_participants: i32,
+    /// Failed join attempts reported by MCs.
+    pub join_failures: i32,
+    /// Mean of per-region QoE scores (None = no samples).
+    pub avg_qoe_score: Option<f64>,
+    /// Sum of per-region live streaming time in seconds.
+    pub live_stream_seconds: i64,
+    /// When the rollup was computed.
+    pub computed_at: DateTime<Utc>,
+}
+
+/// Repository for meeting report operations.
+pub struct MeetingReportsRepository;
+
+impl MeetingReportsRepository {
+    /// Compute reports for ended meetings that have none (or a stale one).
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - Database connection pool
+    /// * `batch_size` - Optional batch size limit (defaults to 100)
+    ///
+    /// # Returns
+    ///
+    /// Number of reports written.
+    #[instrument(skip_all, name = "gc.repo.compute_meeting_reports")]
+    pub async fn compute_pending(pool: &PgPool, batch_size: Option<i64>) -> Result<u64, GcError> {
+        let start = Instant::now();
+        let limit = batch_size.unwrap_or(DEFAULT_REPORT_BATCH_SIZE);
+
+        // meeting_assignments.meeting_id is TEXT; rows that are not meeting UUIDs
+        // simply don't join.
+        let query_result = sqlx::query(
+            r#"
+            INSERT INTO meeting_reports (
+                meeting_id, started_at, ended_at, duration_seconds,
+                peak_participants, total_participants, join_failures,
+                avg_qoe_score, live_stream_seconds, computed_at
+            )
+            SELECT
+                m.meeting_id,
+                a.started_at,
+                a.ended_at,
+                GREATEST(EXTRACT(EPOCH FROM (a.ended_at - a.started_at)), 0)::BIGINT,
+                a.peak_participants,
+                (SELECT COUNT(*) FROM participants p WHERE p.meeting_id = m.meeting_id),
+                a.join_failures,
+                a.avg_qoe_score,
+                a.live_stream_seconds,
+                NOW()
+            FROM (
+                SELECT
+                    meeting_id,
+                    MIN(assigned_at) AS started_at,
+                    MAX(ended_at) AS ended_at,
+                    COALESCE(SUM(peak_participants), 0) AS peak_participants,
+                    COALESCE(SUM(join_failures), 0) AS join_failures,
+                    AVG(avg_qoe_score) AS avg_qoe_score,
+                    COALESCE(SUM(live_stream_seconds), 0)::BIGINT AS live_stream_seconds
+                FROM meeting_assignments
+                GROUP BY meeting_id
+                HAVING COUNT(*) FILTER (WHERE ended_at IS NULL) = 0
+            ) a
+            JOIN meetings m ON m.meeting_id::text = a.meeting_id
+            LEFT JOIN meeting_reports r ON r.meeting_id = m.meeting_id
+            WHERE r.meeting_id IS NULL OR r.ended_at < a.ended_at
+            ORDER BY a.ended_at
+            LIMIT $1
+            ON CONFLICT (meeting_id) DO UPDATE SET
+                started_at = EXCLUDED.started_at,
+                ended_at = EXCLUDED.ended_at,
+                duration_seconds = EXCLUDED.duration_seconds,
+                peak_participants = EXCLUDED.peak_participants,
+                total_participants = EXCLUDED.total_participants,
+                join_failures = EXCLUDED.join_failures,
+                avg_qoe_score = EXCLUDED.avg_qoe_score,
+                live_stream_seconds = EXCLUDED.live_stream_seconds,
+                computed_at = EXCLUDED.computed_at
+            "#,
+        )
+        .bind(limit)
+        .execute(pool)
+        .await;
+
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("compute_meeting_reports", status, start.elapsed());
+
+        Ok(result?.rows_affected())
+    }
+
+    /// Get the report for a meeting, if one has been computed.
+    #[instrument(skip_all, name = "gc.repo.get_meeting_report", fields(meeting_id = %meeting_id))]
+    pub async fn get_report(
+        pool: &PgPool,
+        meeting_id: Uuid,
+    ) -> Result<Option<MeetingReport>, GcError> {
+        let start = Instant::now();
+
+        #[allow(clippy::type_complexity)]
+        let query_result: Result<
+            Option<(
+                Uuid,
+                DateTime<Utc>,
+                DateTime<Utc>,
+                i64,
+                i32,
+                i32,
+                i32,
+                Option<f64>,
+                i64,
+                DateTime<Utc>,
+            )>,
+            sqlx::Error,
+        > = sqlx::query_as(
+            r#"
+            SELECT
+                meeting_id, started_at, ended_at, duration_seconds,
+                peak_participants, total_participants, join_failures,
+                avg_qoe_score, live_stream_seconds, computed_at
+            FROM meeting_reports
+            WHERE meeting_id = $1
+            "#,
+        )
+        .bind(meeting_id)
+        .fetch_optional(pool)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("get_meeting_report", status, start.elapsed());
+
+        Ok(query_result?.map(
+            |(
+                meeting_id,
+                started_at,
+                ended_at,
+                duration_seconds,
+                peak_participants,
+                total_participants,
+                join_failures,
+                avg_qoe_score,
+                live_stream_seconds,
+                computed_at,
+            )| MeetingReport {
+                meeting_id,
+                started_at,
+                ended_at,
+                duration_seconds,
+                peak_participants,
+                total_participants,
+                join_failures,
+                avg_qoe_score,
+                live_stream_seconds,
+                computed_at,
+            },
+        ))
+    }
+
+    /// Refresh an existing report's live streaming total from its assignments.
+    ///
+    /// A no-op if the report has not been computed yet (the rollup will
+    /// include the value).
+    #[instrument(skip_all, name = "gc.repo.refresh_report_live_stream", fields(meeting_id = %meeting_id))]
+    pub async fn refresh_live_stream_seconds(
+        pool: &PgPool,
+        meeting_id: Uuid,
+    ) -> Result<u64, GcError> {
+        let start = Instant::now();
+
+        let query_result = sqlx::query(
+            r#"
+            UPDATE meeting_reports
+            SET live_stream_seconds = (
+                SELECT COALESCE(SUM(live_stream_seconds), 0)::BIGINT
+                FROM meeting_assignments
+                WHERE meeting_id = $1::text
+            )
+            WHERE meeting_id = $1
+            "#,
+        )
+        .bind(meeting_id)
+        .execute(pool)
+        .await;
+
+        let (status, result) = match query_result {
+            Ok(r) => ("success", Ok(r)),
+            Err(e) => ("error", Err(e)),
+        };
+        metrics::record_db_query("refresh_report_live_stream", status, start.elapsed());
+
+        Ok(result?.rows_affected())
+    }
+}
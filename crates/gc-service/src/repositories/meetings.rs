@@ -8,16 +8,44 @@
 //! - Atomic CTE prevents TOCTOU race on concurrent meeting limit
 //! - All queries use parameterized statements (SQL injection safe)
 //! - Audit log failures are logged but don't block meeting creation
+//! - `MeetingCreated` is enqueued to the event outbox in the same transaction
+//!   as the INSERT, so the event exists if and only if the meeting does
 
 use crate::errors::GcError;
-use crate::models::MeetingRow;
+use crate::events::GcEvent;
+use crate::models::{DuplicateJoinPolicy, MeetingPriority, MeetingRow, RecordingLayout};
 use crate::observability::metrics;
+use crate::repositories::EventOutboxRepository;
 use chrono::{DateTime, Utc};
+use common::types::MeetingId;
 use sqlx::{PgPool, Row};
 use std::time::Instant;
 use tracing::instrument;
 use uuid::Uuid;
 
+/// Organization recording consent policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordingConsentPolicy {
+    /// Whether participants must accept recording to stay (`require_ack`),
+    /// rather than only being notified (`notify`).
+    pub require_ack: bool,
+    /// Time a participant has to accept before removal.
+    pub consent_timeout_seconds: u32,
+}
+
+/// Meeting settings the MC enforces, sent with each assignment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McMeetingSettings {
+    /// Whether media is end-to-end encrypted (no server-side processing).
+    pub e2e_enabled: bool,
+    /// Owning organization's recording consent policy.
+    pub recording_policy: RecordingConsentPolicy,
+    /// What the MC does when a user already in the meeting joins again.
+    pub duplicate_join_policy: DuplicateJoinPolicy,
+    /// Recording layout the MC passes on to the MHs.
+    pub recording_layout: RecordingLayout,
+}
+
 /// Meetings repository for database operations.
 pub struct MeetingsRepository;
 
@@ -30,6 +58,12 @@ impl MeetingsRepository {
     /// 3. Inserts the meeting only if under the limit
     /// 4. Caps max_participants at the org's max_participants_per_meeting
     ///
+    /// The meeting ID is generated here as a UUIDv7 rather than by the
+    /// column default, so meeting IDs sort by creation time.
+    ///
+    /// On success a `MeetingCreated` event is written to the event outbox in
+    /// the same transaction.
+    ///
     /// Returns `Some(MeetingRow)` on success, `None` if org limit exceeded.
     ///
     /// # Arguments
@@ -48,6 +82,7 @@ impl MeetingsRepository {
     /// * `allow_external_participants` - External participants setting
     /// * `waiting_room_enabled` - Waiting room setting
     /// * `scheduled_start_time` - Optional scheduled start time
+    /// * `priority` - Priority class
     #[instrument(skip_all, name = "gc.repo.create_meeting")]
     #[expect(
         clippy::too_many_arguments,
@@ -68,9 +103,12 @@ impl MeetingsRepository {
         allow_external_participants: bool,
         waiting_room_enabled: bool,
         scheduled_start_time: Option<DateTime<Utc>>,
+        priority: MeetingPriority,
     ) -> Result<Option<MeetingRow>, GcError> {
         let start = Instant::now();
 
+        let mut tx = pool.begin().await?;
+
         let row = sqlx::query(
             r#"
             WITH org_limits AS (
@@ -84,17 +122,17 @@ impl MeetingsRepository {
                 WHERE org_id = $1 AND status IN ('scheduled', 'active')
             )
             INSERT INTO meetings (
-                org_id, created_by_user_id, display_name, meeting_code,
+                meeting_id, org_id, created_by_user_id, display_name, meeting_code,
                 join_token_secret, max_participants, enable_e2e_encryption,
                 require_auth, recording_enabled, allow_guests,
                 allow_external_participants, waiting_room_enabled,
-                scheduled_start_time, status
+                scheduled_start_time, status, priority
             )
             SELECT
-                $1, $2, $3, $4, $5,
+                $14, $1, $2, $3, $4, $5,
                 LEAST($6, org_limits.max_participants_per_meeting),
                 $7, $8, $9, $10, $11, $12, $13,
-                'scheduled'
+                'scheduled', $15
             FROM org_limits, current_count
             WHERE current_count.cnt < org_limits.max_concurrent_meetings
             RETURNING
@@ -104,7 +142,8 @@ impl MeetingsRepository {
                 meeting_controller_id, meeting_controller_region,
                 status, scheduled_start_time, actual_start_time,
                 actual_end_time, created_at, updated_at,
-                allow_guests, allow_external_participants, waiting_room_enabled
+                allow_guests, allow_external_participants, waiting_room_enabled,
+                duplicate_join_policy, recording_layout, priority
             "#,
         )
         .bind(org_id) // $1
@@ -120,7 +159,9 @@ impl MeetingsRepository {
         .bind(allow_external_participants) // $11
         .bind(waiting_room_enabled) // $12
         .bind(scheduled_start_time) // $13
-        .fetch_optional(pool)
+        .bind(MeetingId::new()) // $14
+        .bind(priority.as_str()) // $15
+        .fetch_optional(&mut *tx)
         .await
         .map_err(|e| {
             let duration = start.elapsed();
@@ -131,10 +172,25 @@ impl MeetingsRepository {
         let duration = start.elapsed();
         metrics::record_db_query("create_meeting", "success", duration);
 
-        match row {
-            Some(row) => Ok(Some(map_row_to_meeting(row))),
-            None => Ok(None),
-        }
+        // Org limit exceeded: nothing inserted, transaction rolls back on drop
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let meeting = map_row_to_meeting(row);
+
+        EventOutboxRepository::enqueue(
+            &mut tx,
+            &GcEvent::MeetingCreated {
+                meeting_id: meeting.meeting_id,
+                org_id: meeting.org_id,
+                created_by_user_id: meeting.created_by_user_id,
+            },
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(meeting))
     }
 
     /// Log a meeting audit event.
@@ -233,6 +289,111 @@ impl MeetingsRepository {
 
         Ok(row)
     }
+
+    /// Load the settings the MC enforces for a meeting: its E2E flag,
+    /// duplicate join policy, recording layout, and the owning
+    /// organization's recording consent policy.
+    ///
+    /// Returns `None` if the meeting does not exist.
+    #[instrument(skip_all, name = "gc.repo.get_mc_meeting_settings", fields(meeting_id = %meeting_id))]
+    pub async fn get_mc_meeting_settings(
+        pool: &PgPool,
+        meeting_id: Uuid,
+    ) -> Result<Option<McMeetingSettings>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Option<(bool, String, i32, String, String)>, sqlx::Error> =
+            sqlx::query_as(
+                r#"
+            SELECT m.enable_e2e_encryption, o.recording_consent_mode,
+                   o.recording_consent_timeout_seconds, m.duplicate_join_policy,
+                   m.recording_layout
+            FROM meetings m
+            JOIN organizations o ON o.org_id = m.org_id
+            WHERE m.meeting_id = $1
+            "#,
+            )
+            .bind(meeting_id)
+            .fetch_optional(pool)
+            .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("get_mc_meeting_settings", status, start.elapsed());
+
+        Ok(query_result?.map(
+            |(e2e_enabled, mode, timeout_seconds, duplicate_join_policy, recording_layout)| {
+                McMeetingSettings {
+                    e2e_enabled,
+                    recording_policy: RecordingConsentPolicy {
+                        require_ack: mode != "notify",
+                        consent_timeout_seconds: u32::try_from(timeout_seconds).unwrap_or(0),
+                    },
+                    duplicate_join_policy: DuplicateJoinPolicy::from_db(&duplicate_join_policy),
+                    recording_layout: RecordingLayout::from_db(&recording_layout),
+                }
+            },
+        ))
+    }
+
+    /// Load the dedicated MC pool of the organization owning a meeting.
+    ///
+    /// Returns `None` if the meeting does not exist or its organization
+    /// uses the default pool.
+    #[instrument(skip_all, name = "gc.repo.get_mc_pool", fields(meeting_id = %meeting_id))]
+    pub async fn get_mc_pool(pool: &PgPool, meeting_id: Uuid) -> Result<Option<String>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Option<Option<String>>, sqlx::Error> = sqlx::query_scalar(
+            r#"
+            SELECT o.mc_pool
+            FROM meetings m
+            JOIN organizations o ON o.org_id = m.org_id
+            WHERE m.meeting_id = $1
+            "#,
+        )
+        .bind(meeting_id)
+        .fetch_optional(pool)
+        .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("get_mc_pool", status, start.elapsed());
+
+        Ok(query_result?.flatten())
+    }
+
+    /// Load a meeting's priority class.
+    ///
+    /// Returns `None` if the meeting does not exist.
+    #[instrument(skip_all, name = "gc.repo.get_priority", fields(meeting_id = %meeting_id))]
+    pub async fn get_priority(
+        pool: &PgPool,
+        meeting_id: Uuid,
+    ) -> Result<Option<MeetingPriority>, GcError> {
+        let start = Instant::now();
+
+        let query_result: Result<Option<String>, sqlx::Error> =
+            sqlx::query_scalar("SELECT priority FROM meetings WHERE meeting_id = $1")
+                .bind(meeting_id)
+                .fetch_optional(pool)
+                .await;
+
+        let status = if query_result.is_ok() {
+            "success"
+        } else {
+            "error"
+        };
+        metrics::record_db_query("get_meeting_priority", status, start.elapsed());
+
+        Ok(query_result?.map(|priority| MeetingPriority::from_db(&priority)))
+    }
 }
 
 /// Map a database row to a MeetingRow struct.
@@ -262,5 +423,8 @@ pub fn map_row_to_meeting(row: sqlx::postgres::PgRow) -> MeetingRow {
         allow_guests: row.get("allow_guests"),
         allow_external_participants: row.get("allow_external_participants"),
         waiting_room_enabled: row.get("waiting_room_enabled"),
+        duplicate_join_policy: row.get("duplicate_join_policy"),
+        recording_layout: row.get("recording_layout"),
+        priority: row.get("priority"),
     }
 }
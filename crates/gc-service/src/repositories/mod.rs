@@ -3,21 +3,49 @@
 //! Provides database access patterns following the Handler -> Service -> Repository
 //! architecture. All database queries use sqlx compile-time checking.
 
+pub mod attendance;
+pub mod event_outbox;
+pub mod experiments;
+pub mod mc_directives;
 pub mod media_handlers;
+pub mod meeting_assets;
 pub mod meeting_assignments;
 pub mod meeting_controllers;
+pub mod meeting_imports;
+pub mod meeting_recordings;
+pub mod meeting_reports;
 pub mod meetings;
+pub mod organizations;
 pub mod participants;
+pub mod store;
 
+pub use attendance::{AttendanceRecord, AttendanceRepository};
+pub use event_outbox::EventOutboxRepository;
+pub use experiments::{Experiment, ExperimentUnit, ExperimentsRepository};
+pub use mc_directives::{McDirective, McDirectiveAction, McDirectivesRepository};
 // Media handler types will be used in handlers in future phase
 #[allow(unused_imports)]
 pub use media_handlers::{MediaHandler, MediaHandlersRepository, MhCandidate};
+pub use meeting_assets::{MeetingAsset, MeetingAssetsRepository};
 pub use meeting_assignments::{weighted_random_select, McAssignment, MeetingAssignmentsRepository};
 // McCandidate and MeetingAssignment are used in tests
 #[allow(unused_imports)]
 pub use meeting_assignments::{McCandidate, MeetingAssignment};
-pub use meeting_controllers::{HealthStatus, MeetingControllersRepository};
-pub use meetings::{map_row_to_meeting, MeetingsRepository};
+pub use meeting_controllers::{
+    HealthStatus, McStandby, MeetingController, MeetingControllersRepository, ReportedBuild,
+    VersionCount, DEFAULT_MC_POOL, SPILLOVER_MC_POOL,
+};
+pub use meeting_imports::{
+    MeetingImportJob, MeetingImportOutcome, MeetingImportsRepository, PendingMeetingImport,
+    PreparedImportMeeting,
+};
+pub use meeting_recordings::{MeetingRecording, MeetingRecordingsRepository, PurgeableRecording};
+pub use meeting_reports::MeetingReportsRepository;
+pub use meetings::{
+    map_row_to_meeting, McMeetingSettings, MeetingsRepository, RecordingConsentPolicy,
+};
+pub use organizations::OrganizationsRepository;
 // ParticipantsRepository will be used in meeting join handler
 #[allow(unused_imports)]
 pub use participants::ParticipantsRepository;
+pub use store::{ControllerRegistryStore, GcStore, HandlerRegistryStore, PgStore, StorageBackend};
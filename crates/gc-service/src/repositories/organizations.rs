@@ -0,0 +1,187 @@
+//! Organizations repository for declarative org config.
+//!
+//! Reads and writes the organization fields covered by [`OrgConfig`]. A
+//! reconcile locks the org row (`FOR UPDATE`) for its transaction so
+//! concurrent reconciles of the same org apply one after the other and each
+//! reports the diff against the state it actually replaced.
+
+use crate::errors::GcError;
+use crate::models::{OrgConfig, OrgPolicies, PlanTier, RecordingConsentMode};
+use crate::observability::metrics;
+use sqlx::PgConnection;
+use std::time::Instant;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Repository for organization config.
+pub struct OrganizationsRepository;
+
+/// Row type for org config queries.
+type OrgConfigRow = (
+    Uuid,
+    String,
+    String,
+    bool,
+    i32,
+    i32,
+    String,
+    i32,
+    Option<String>,
+);
+
+fn map_config_row(row: OrgConfigRow) -> (Uuid, OrgConfig) {
+    let (
+        org_id,
+        display_name,
+        plan_tier,
+        is_active,
+        max_concurrent_meetings,
+        max_participants_per_meeting,
+        recording_consent_mode,
+        recording_consent_timeout_seconds,
+        mc_pool,
+    ) = row;
+
+    (
+        org_id,
+        OrgConfig {
+            display_name,
+            plan_tier: PlanTier::from_db(&plan_tier),
+            active: is_active,
+            policies: OrgPolicies {
+                max_concurrent_meetings,
+                max_participants_per_meeting,
+                recording_consent_mode: RecordingConsentMode::from_db(&recording_consent_mode),
+                recording_consent_timeout_seconds,
+                mc_pool,
+            },
+        },
+    )
+}
+
+impl OrganizationsRepository {
+    /// Fetch an organization's config by subdomain.
+    ///
+    /// With `for_update` the row stays locked until the caller's
+    /// transaction ends.
+    #[instrument(skip_all, name = "gc.repo.get_org_config", fields(subdomain = %subdomain))]
+    pub async fn get_config(
+        conn: &mut PgConnection,
+        subdomain: &str,
+        for_update: bool,
+    ) -> Result<Option<(Uuid, OrgConfig)>, GcError> {
+        let start = Instant::now();
+
+        let query = if for_update {
+            r#"
+            SELECT
+                org_id, display_name, plan_tier, is_active, max_concurrent_meetings,
+                max_participants_per_meeting, recording_consent_mode,
+                recording_consent_timeout_seconds, mc_pool
+            FROM organizations
+            WHERE subdomain = $1
+            FOR UPDATE
+            "#
+        } else {
+            r#"
+            SELECT
+                org_id, display_name, plan_tier, is_active, max_concurrent_meetings,
+                max_participants_per_meeting, recording_consent_mode,
+                recording_consent_timeout_seconds, mc_pool
+            FROM organizations
+            WHERE subdomain = $1
+            "#
+        };
+
+        let result: Result<Option<OrgConfigRow>, sqlx::Error> = sqlx::query_as(query)
+            .bind(subdomain)
+            .fetch_optional(conn)
+            .await;
+
+        let status = if result.is_ok() { "success" } else { "error" };
+        metrics::record_db_query("get_org_config", status, start.elapsed());
+
+        Ok(result?.map(map_config_row))
+    }
+
+    /// Create an organization with the given config.
+    ///
+    /// Returns `None` if the subdomain was taken concurrently.
+    #[instrument(skip_all, name = "gc.repo.create_org", fields(subdomain = %subdomain))]
+    pub async fn create(
+        conn: &mut PgConnection,
+        subdomain: &str,
+        config: &OrgConfig,
+    ) -> Result<Option<Uuid>, GcError> {
+        let start = Instant::now();
+        let policies = &config.policies;
+
+        let result: Result<Option<Uuid>, sqlx::Error> = sqlx::query_scalar(
+            r#"
+            INSERT INTO organizations (
+                subdomain, display_name, plan_tier, is_active, max_concurrent_meetings,
+                max_participants_per_meeting, recording_consent_mode,
+                recording_consent_timeout_seconds, mc_pool
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (subdomain) DO NOTHING
+            RETURNING org_id
+            "#,
+        )
+        .bind(subdomain)
+        .bind(config.display_name.trim())
+        .bind(config.plan_tier.as_str())
+        .bind(config.active)
+        .bind(policies.max_concurrent_meetings)
+        .bind(policies.max_participants_per_meeting)
+        .bind(policies.recording_consent_mode.as_str())
+        .bind(policies.recording_consent_timeout_seconds)
+        .bind(policies.mc_pool.as_deref())
+        .fetch_optional(conn)
+        .await;
+
+        let status = if result.is_ok() { "success" } else { "error" };
+        metrics::record_db_query("create_org", status, start.elapsed());
+
+        Ok(result?)
+    }
+
+    /// Overwrite an organization's config.
+    #[instrument(skip_all, name = "gc.repo.update_org_config", fields(org_id = %org_id))]
+    pub async fn update_config(
+        conn: &mut PgConnection,
+        org_id: Uuid,
+        config: &OrgConfig,
+    ) -> Result<(), GcError> {
+        let start = Instant::now();
+        let policies = &config.policies;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE organizations
+            SET display_name = $2, plan_tier = $3, is_active = $4,
+                max_concurrent_meetings = $5, max_participants_per_meeting = $6,
+                recording_consent_mode = $7, recording_consent_timeout_seconds = $8,
+                mc_pool = $9
+            WHERE org_id = $1
+            "#,
+        )
+        .bind(org_id)
+        .bind(config.display_name.trim())
+        .bind(config.plan_tier.as_str())
+        .bind(config.active)
+        .bind(policies.max_concurrent_meetings)
+        .bind(policies.max_participants_per_meeting)
+        .bind(policies.recording_consent_mode.as_str())
+        .bind(policies.recording_consent_timeout_seconds)
+        .bind(policies.mc_pool.as_deref())
+        .execute(conn)
+        .await;
+
+        let status = if result.is_ok() { "success" } else { "error" };
+        metrics::record_db_query("update_org_config", status, start.elapsed());
+
+        result?;
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@
 use crate::errors::GcError;
 use crate::models::Participant;
 use crate::observability::metrics;
+use common::types::{MeetingId, ParticipantId};
 use sqlx::PgPool;
 use std::time::Instant;
 use tracing::instrument;
@@ -41,11 +42,11 @@ impl ParticipantsRepository {
     /// # Arguments
     ///
     /// * `pool` - Database connection pool
-    /// * `meeting_id` - Meeting UUID
+    /// * `meeting_id` - Meeting ID
     #[instrument(skip_all, name = "gc.repo.count_active_participants", fields(meeting_id = %meeting_id))]
     pub async fn count_active_participants(
         pool: &PgPool,
-        meeting_id: Uuid,
+        meeting_id: MeetingId,
     ) -> Result<i64, GcError> {
         let start = Instant::now();
 
@@ -86,7 +87,7 @@ impl ParticipantsRepository {
     /// # Arguments
     ///
     /// * `pool` - Database connection pool
-    /// * `meeting_id` - Meeting UUID
+    /// * `meeting_id` - Meeting ID
     /// * `user_id` - User UUID (None for anonymous guests)
     /// * `display_name` - Participant's display name
     /// * `participant_type` - "member" (same-org) or "external" (cross-org/guest)
@@ -99,7 +100,7 @@ impl ParticipantsRepository {
     #[instrument(skip_all, name = "gc.repo.add_participant", fields(meeting_id = %meeting_id))]
     pub async fn add_participant(
         pool: &PgPool,
-        meeting_id: Uuid,
+        meeting_id: MeetingId,
         user_id: Option<Uuid>,
         display_name: &str,
         participant_type: &str,
@@ -109,12 +110,15 @@ impl ParticipantsRepository {
 
         let result: Result<Participant, sqlx::Error> = sqlx::query_as(
             r#"
-            INSERT INTO participants (meeting_id, user_id, display_name, participant_type, role)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO participants (
+                participant_id, meeting_id, user_id, display_name, participant_type, role
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
             RETURNING participant_id, meeting_id, user_id, display_name,
                       participant_type, role, joined_at, left_at
             "#,
         )
+        .bind(ParticipantId::new())
         .bind(meeting_id)
         .bind(user_id)
         .bind(display_name)
@@ -141,12 +145,12 @@ impl ParticipantsRepository {
     /// # Arguments
     ///
     /// * `pool` - Database connection pool
-    /// * `meeting_id` - Meeting UUID
+    /// * `meeting_id` - Meeting ID
     /// * `user_id` - User UUID (must not be None — guest removal uses participant_id)
     #[instrument(skip_all, name = "gc.repo.remove_participant", fields(meeting_id = %meeting_id))]
     pub async fn remove_participant(
         pool: &PgPool,
-        meeting_id: Uuid,
+        meeting_id: MeetingId,
         user_id: Uuid,
     ) -> Result<bool, GcError> {
         let start = Instant::now();
@@ -0,0 +1,360 @@
+//! Storage backend abstraction for GC repositories.
+//!
+//! The repositories in this module are the Postgres implementation of GC
+//! storage. The traits here are the seam other backends plug in at: code
+//! that holds a [`GcStore`] (see `AppState::store`) works against any
+//! implementation, while code that still calls the repositories directly is
+//! Postgres-only until its repository moves behind a trait.
+//!
+//! Behind the traits so far:
+//!
+//! - [`ControllerRegistryStore`] - MC registry (`MeetingControllersRepository`)
+//! - [`HandlerRegistryStore`] - MH registry (`MediaHandlersRepository`)
+//!
+//! # Backends
+//!
+//! | Backend | `GC_STORAGE_BACKEND` | Notes |
+//! |---------|----------------------|-------|
+//! | PostgreSQL | `postgres` (default) | Reference backend; everything is tested here |
+//! | CockroachDB | `cockroachdb` | Postgres wire protocol; runs on [`PgStore`] |
+//! | YugabyteDB | `yugabytedb` | Postgres wire protocol (YSQL); runs on [`PgStore`] |
+//!
+//! The wire-compatible backends share the Postgres driver and queries, so
+//! repository SQL sticks to what all three accept: interval arithmetic is
+//! written `$1 * INTERVAL '1 second'` rather than built from strings,
+//! upserts use `ON CONFLICT`, and work queues use `FOR UPDATE SKIP LOCKED`.
+//! The `storage-compat` CI job runs the registry and org repository tests
+//! against each backend.
+
+use crate::errors::GcError;
+use crate::repositories::{
+    HealthStatus, MediaHandler, MediaHandlersRepository, MeetingController,
+    MeetingControllersRepository, MhCandidate,
+};
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::fmt;
+
+/// Database engine GC is deployed against.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StorageBackend {
+    /// PostgreSQL.
+    #[default]
+    Postgres,
+    /// CockroachDB.
+    CockroachDb,
+    /// YugabyteDB (YSQL).
+    YugabyteDb,
+}
+
+impl StorageBackend {
+    /// Configuration and logging representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageBackend::Postgres => "postgres",
+            StorageBackend::CockroachDb => "cockroachdb",
+            StorageBackend::YugabyteDb => "yugabytedb",
+        }
+    }
+
+    /// Parse the `GC_STORAGE_BACKEND` value.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "postgres" => Some(StorageBackend::Postgres),
+            "cockroachdb" => Some(StorageBackend::CockroachDb),
+            "yugabytedb" => Some(StorageBackend::YugabyteDb),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for StorageBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// MC registry storage.
+///
+/// Semantics match the `MeetingControllersRepository` methods of the same
+/// names.
+#[async_trait]
+pub trait ControllerRegistryStore: Send + Sync {
+    /// Register or re-register an MC.
+    async fn register_mc(
+        &self,
+        id: &str,
+        region: &str,
+        grpc_endpoint: &str,
+        webtransport_endpoint: Option<&str>,
+        max_meetings: i32,
+        max_participants: i32,
+    ) -> Result<(), GcError>;
+
+    /// Record an MC heartbeat; `false` if the MC is not registered.
+    async fn update_heartbeat(
+        &self,
+        controller_id: &str,
+        current_meetings: i32,
+        current_participants: i32,
+        health_status: HealthStatus,
+    ) -> Result<bool, GcError>;
+
+    /// Mark MCs without a recent heartbeat unhealthy; returns how many.
+    async fn mark_stale_controllers_unhealthy(
+        &self,
+        staleness_threshold_seconds: i64,
+    ) -> Result<u64, GcError>;
+
+    /// Fetch one MC.
+    async fn get_controller(
+        &self,
+        controller_id: &str,
+    ) -> Result<Option<MeetingController>, GcError>;
+
+    /// List every registered MC.
+    async fn list_controllers(&self) -> Result<Vec<MeetingController>, GcError>;
+
+    /// Cordon or uncordon an MC; `false` if the MC is not registered.
+    async fn set_controller_cordoned(
+        &self,
+        controller_id: &str,
+        cordoned: bool,
+    ) -> Result<bool, GcError>;
+}
+
+/// MH registry storage.
+///
+/// Semantics match the `MediaHandlersRepository` methods of the same names.
+#[async_trait]
+pub trait HandlerRegistryStore: Send + Sync {
+    /// Register or re-register an MH.
+    async fn register_mh(
+        &self,
+        handler_id: &str,
+        region: &str,
+        webtransport_endpoint: &str,
+        grpc_endpoint: &str,
+        max_streams: i32,
+    ) -> Result<(), GcError>;
+
+    /// Record an MH load report; `false` if the MH is not registered.
+    async fn update_load_report(
+        &self,
+        handler_id: &str,
+        current_streams: i32,
+        health_status: HealthStatus,
+        cpu_usage: Option<f32>,
+        memory_usage: Option<f32>,
+        bandwidth_usage: Option<f32>,
+    ) -> Result<bool, GcError>;
+
+    /// Mark MHs without a recent load report unhealthy; returns how many.
+    async fn mark_stale_handlers_unhealthy(
+        &self,
+        staleness_threshold_seconds: i64,
+    ) -> Result<u64, GcError>;
+
+    /// Healthy, uncordoned MHs in a region with spare capacity.
+    async fn get_candidate_mhs(&self, region: &str) -> Result<Vec<MhCandidate>, GcError>;
+
+    /// Fetch one MH.
+    async fn get_handler(&self, handler_id: &str) -> Result<Option<MediaHandler>, GcError>;
+
+    /// List every registered MH.
+    async fn list_handlers(&self) -> Result<Vec<MediaHandler>, GcError>;
+
+    /// Cordon or uncordon an MH; `false` if the MH is not registered.
+    async fn set_handler_cordoned(&self, handler_id: &str, cordoned: bool)
+        -> Result<bool, GcError>;
+}
+
+/// All storage GC reaches through the trait layer.
+pub trait GcStore: ControllerRegistryStore + HandlerRegistryStore {
+    /// Backend the store runs against.
+    fn backend(&self) -> StorageBackend;
+}
+
+/// [`GcStore`] over a Postgres-protocol pool (Postgres, CockroachDB,
+/// YugabyteDB).
+#[derive(Debug, Clone)]
+pub struct PgStore {
+    pool: PgPool,
+    backend: StorageBackend,
+}
+
+impl PgStore {
+    /// Create a store over `pool`.
+    pub fn new(pool: PgPool, backend: StorageBackend) -> Self {
+        Self { pool, backend }
+    }
+}
+
+impl GcStore for PgStore {
+    fn backend(&self) -> StorageBackend {
+        self.backend
+    }
+}
+
+#[async_trait]
+impl ControllerRegistryStore for PgStore {
+    async fn register_mc(
+        &self,
+        id: &str,
+        region: &str,
+        grpc_endpoint: &str,
+        webtransport_endpoint: Option<&str>,
+        max_meetings: i32,
+        max_participants: i32,
+    ) -> Result<(), GcError> {
+        MeetingControllersRepository::register_mc(
+            &self.pool,
+            id,
+            region,
+            grpc_endpoint,
+            webtransport_endpoint,
+            max_meetings,
+            max_participants,
+        )
+        .await
+    }
+
+    async fn update_heartbeat(
+        &self,
+        controller_id: &str,
+        current_meetings: i32,
+        current_participants: i32,
+        health_status: HealthStatus,
+    ) -> Result<bool, GcError> {
+        MeetingControllersRepository::update_heartbeat(
+            &self.pool,
+            controller_id,
+            current_meetings,
+            current_participants,
+            health_status,
+        )
+        .await
+    }
+
+    async fn mark_stale_controllers_unhealthy(
+        &self,
+        staleness_threshold_seconds: i64,
+    ) -> Result<u64, GcError> {
+        MeetingControllersRepository::mark_stale_controllers_unhealthy(
+            &self.pool,
+            staleness_threshold_seconds,
+        )
+        .await
+    }
+
+    async fn get_controller(
+        &self,
+        controller_id: &str,
+    ) -> Result<Option<MeetingController>, GcError> {
+        MeetingControllersRepository::get_controller(&self.pool, controller_id).await
+    }
+
+    async fn list_controllers(&self) -> Result<Vec<MeetingController>, GcError> {
+        MeetingControllersRepository::list_controllers(&self.pool).await
+    }
+
+    async fn set_controller_cordoned(
+        &self,
+        controller_id: &str,
+        cordoned: bool,
+    ) -> Result<bool, GcError> {
+        MeetingControllersRepository::set_cordoned(&self.pool, controller_id, cordoned).await
+    }
+}
+
+#[async_trait]
+impl HandlerRegistryStore for PgStore {
+    async fn register_mh(
+        &self,
+        handler_id: &str,
+        region: &str,
+        webtransport_endpoint: &str,
+        grpc_endpoint: &str,
+        max_streams: i32,
+    ) -> Result<(), GcError> {
+        MediaHandlersRepository::register_mh(
+            &self.pool,
+            handler_id,
+            region,
+            webtransport_endpoint,
+            grpc_endpoint,
+            max_streams,
+        )
+        .await
+    }
+
+    async fn update_load_report(
+        &self,
+        handler_id: &str,
+        current_streams: i32,
+        health_status: HealthStatus,
+        cpu_usage: Option<f32>,
+        memory_usage: Option<f32>,
+        bandwidth_usage: Option<f32>,
+    ) -> Result<bool, GcError> {
+        MediaHandlersRepository::update_load_report(
+            &self.pool,
+            handler_id,
+            current_streams,
+            health_status,
+            cpu_usage,
+            memory_usage,
+            bandwidth_usage,
+        )
+        .await
+    }
+
+    async fn mark_stale_handlers_unhealthy(
+        &self,
+        staleness_threshold_seconds: i64,
+    ) -> Result<u64, GcError> {
+        MediaHandlersRepository::mark_stale_handlers_unhealthy(
+            &self.pool,
+            staleness_threshold_seconds,
+        )
+        .await
+    }
+
+    async fn get_candidate_mhs(&self, region: &str) -> Result<Vec<MhCandidate>, GcError> {
+        MediaHandlersRepository::get_candidate_mhs(&self.pool, region).await
+    }
+
+    async fn get_handler(&self, handler_id: &str) -> Result<Option<MediaHandler>, GcError> {
+        MediaHandlersRepository::get_handler(&self.pool, handler_id).await
+    }
+
+    async fn list_handlers(&self) -> Result<Vec<MediaHandler>, GcError> {
+        MediaHandlersRepository::list_handlers(&self.pool).await
+    }
+
+    async fn set_handler_cordoned(
+        &self,
+        handler_id: &str,
+        cordoned: bool,
+    ) -> Result<bool, GcError> {
+        MediaHandlersRepository::set_cordoned(&self.pool, handler_id, cordoned).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_storage_backend_parse_round_trip() {
+        for backend in [
+            StorageBackend::Postgres,
+            StorageBackend::CockroachDb,
+            StorageBackend::YugabyteDb,
+        ] {
+            assert_eq!(StorageBackend::parse(backend.as_str()), Some(backend));
+        }
+        assert_eq!(StorageBackend::parse("mysql"), None);
+        assert_eq!(StorageBackend::parse("Postgres"), None);
+    }
+}
@@ -4,14 +4,18 @@
 
 use crate::auth::{JwksClient, JwtValidator};
 use crate::config::Config;
+use crate::events::EventBus;
 use crate::handlers;
 use crate::middleware::{http_metrics_middleware, require_auth, require_user_auth, AuthState};
+use crate::repositories::GcStore;
 use crate::services::mc_client::McClientTrait;
+use crate::services::ObjectStore;
 use axum::{
     middleware,
-    routing::{get, patch, post},
+    routing::{delete, get, patch, post},
     Router,
 };
+use common::request_id::RequestIdLayer;
 use common::token_manager::TokenReceiver;
 use metrics_exporter_prometheus::PrometheusHandle;
 use sqlx::PgPool;
@@ -33,6 +37,32 @@ pub struct AppState {
 
     /// Token receiver for dynamically refreshed OAuth tokens from TokenManager.
     pub token_receiver: TokenReceiver,
+
+    /// In-process event bus for meeting lifecycle side effects.
+    pub event_bus: EventBus,
+
+    /// Pre-signed URL issuer for asset uploads (None = uploads disabled).
+    pub object_store: Option<Arc<dyn ObjectStore>>,
+
+    /// Storage for the repositories behind the [`GcStore`] traits.
+    pub store: Arc<dyn GcStore>,
+}
+
+/// Build the operational routes: `/health`, `/ready`, and `/metrics`.
+///
+/// Part of [`build_routes`], and served on their own over the health
+/// socket when `GC_HEALTH_SOCKET_PATH` is set.
+pub fn build_ops_routes(state: Arc<AppState>, metrics_handle: PrometheusHandle) -> Router {
+    // Metrics route with its own state (ADR-0011)
+    let metrics_routes = Router::new()
+        .route("/metrics", get(handlers::metrics_handler))
+        .with_state(metrics_handle);
+
+    Router::new()
+        .route("/health", get(handlers::health_check))
+        .route("/ready", get(handlers::readiness_check))
+        .with_state(state)
+        .merge(metrics_routes)
 }
 
 /// Build the application routes.
@@ -44,8 +74,21 @@ pub struct AppState {
 /// - `/api/v1/me` - Current user endpoint - requires service authentication
 /// - `/api/v1/meetings` - Create meeting (user authenticated)
 /// - `/api/v1/meetings/{code}` - Join meeting (user authenticated)
+/// - `/api/v1/meetings/{code}/preflight` - Pre-join readiness check (user authenticated)
 /// - `/api/v1/meetings/{code}/guest-token` - Get guest token (public)
 /// - `/api/v1/meetings/{id}/settings` - Update meeting settings (user authenticated, host only)
+/// - `/api/v1/meetings/{id}/report` - Post-meeting report (user authenticated, host only)
+/// - `/api/v1/meetings/{id}/attendance.csv` - Attendance export (user authenticated, host only)
+/// - `/api/v1/meetings/{id}/assets` - Asset upload URL (user authenticated, meeting members)
+/// - `/api/v1/meetings/{id}/recordings[/{recording_id}[/download]]` - Recording
+///   list, download URL, and deletion (user authenticated, host only)
+/// - `/api/v1/client-errors` - Client failure reports (user authenticated)
+/// - `/api/v1/admin/controllers[/{id}/cordon|uncordon]` - MC list and cordoning
+///   (service authenticated, fleet admin scope)
+/// - `/api/v1/admin/handlers[/{id}/cordon|uncordon]` - MH list and cordoning
+///   (service authenticated, fleet admin scope)
+/// - `/api/v1/admin/versions` - MC/MH version distribution (service
+///   authenticated, fleet admin scope)
 /// - TraceLayer for request logging
 /// - HTTP metrics middleware (ADR-0011)
 /// - 30 second request timeout
@@ -63,9 +106,6 @@ pub fn build_routes(
 
     // Public routes (no authentication required)
     let public_routes = Router::new()
-        // Health check endpoints (unversioned operational endpoints)
-        .route("/health", get(handlers::health_check))
-        .route("/ready", get(handlers::readiness_check))
         // Guest token endpoint (public, rate limited)
         .route(
             "/api/v1/meetings/:code/guest-token",
@@ -73,10 +113,8 @@ pub fn build_routes(
         )
         .with_state(state.clone());
 
-    // Metrics route with its own state (ADR-0011)
-    let metrics_routes = Router::new()
-        .route("/metrics", get(handlers::metrics_handler))
-        .with_state(metrics_handle);
+    // Health and metrics endpoints (unversioned operational endpoints)
+    let ops_routes = build_ops_routes(state.clone(), metrics_handle);
 
     // User-authenticated routes (require user JWT with UserClaims)
     let user_auth_routes = Router::new()
@@ -84,11 +122,52 @@ pub fn build_routes(
         .route("/api/v1/meetings", post(handlers::create_meeting))
         // Meeting join endpoint
         .route("/api/v1/meetings/:code", get(handlers::join_meeting))
+        // Pre-join readiness check endpoint
+        .route(
+            "/api/v1/meetings/:code/preflight",
+            get(handlers::preflight_meeting),
+        )
         // Meeting settings endpoint
         .route(
             "/api/v1/meetings/:id/settings",
             patch(handlers::update_meeting_settings),
         )
+        // Post-meeting report endpoint (host only)
+        .route(
+            "/api/v1/meetings/:id/report",
+            get(handlers::get_meeting_report),
+        )
+        // Attendance CSV export endpoint (host only)
+        .route(
+            "/api/v1/meetings/:id/attendance.csv",
+            get(handlers::export_meeting_attendance),
+        )
+        // Asset upload URL endpoint
+        .route(
+            "/api/v1/meetings/:id/assets",
+            post(handlers::create_meeting_asset),
+        )
+        // Recording endpoints (host only)
+        .route(
+            "/api/v1/meetings/:id/recordings",
+            get(handlers::list_meeting_recordings),
+        )
+        .route(
+            "/api/v1/meetings/:id/recordings/:recording_id",
+            delete(handlers::delete_meeting_recording),
+        )
+        .route(
+            "/api/v1/meetings/:id/recordings/:recording_id/download",
+            get(handlers::get_recording_download_url),
+        )
+        // Client failure report endpoint
+        .route("/api/v1/client-errors", post(handlers::report_client_error))
+        // Bulk meeting import endpoints (org admin only)
+        .route("/api/v1/meeting-imports", post(handlers::import_meetings))
+        .route(
+            "/api/v1/meeting-imports/:id",
+            get(handlers::get_meeting_import),
+        )
         .route_layer(middleware::from_fn_with_state(
             auth_state.clone(),
             require_user_auth,
@@ -99,6 +178,31 @@ pub fn build_routes(
     let protected_routes = Router::new()
         // Current user endpoint
         .route("/api/v1/me", get(handlers::get_me))
+        // Fleet admin endpoints (fleet admin scope checked by the handlers)
+        .route("/api/v1/admin/controllers", get(handlers::list_controllers))
+        .route(
+            "/api/v1/admin/controllers/:id/cordon",
+            post(handlers::cordon_controller),
+        )
+        .route(
+            "/api/v1/admin/controllers/:id/uncordon",
+            post(handlers::uncordon_controller),
+        )
+        .route("/api/v1/admin/handlers", get(handlers::list_handlers))
+        .route(
+            "/api/v1/admin/handlers/:id/cordon",
+            post(handlers::cordon_handler),
+        )
+        .route(
+            "/api/v1/admin/handlers/:id/uncordon",
+            post(handlers::uncordon_handler),
+        )
+        .route("/api/v1/admin/versions", get(handlers::list_versions))
+        // Declarative org config endpoints (tenant admin scope checked by the handlers)
+        .route(
+            "/api/v1/admin/orgs/:subdomain/config",
+            get(handlers::get_org_config).put(handlers::reconcile_org_config),
+        )
         .route_layer(middleware::from_fn_with_state(
             auth_state.clone(),
             require_auth,
@@ -109,16 +213,18 @@ pub fn build_routes(
     // Layer order (bottom-to-top execution):
     // 1. TimeoutLayer - Timeout the request (innermost)
     // 2. TraceLayer - Log request details
-    // 3. http_metrics_middleware - Record ALL responses (outermost)
+    // 3. http_metrics_middleware - Record ALL responses
+    // 4. RequestIdLayer - Assign x-request-id and scope logs to it (outermost)
     Ok(public_routes
-        .merge(metrics_routes)
+        .merge(ops_routes)
         .merge(user_auth_routes)
         .merge(protected_routes)
         .layer(TraceLayer::new_for_http())
         .layer(TimeoutLayer::new(Duration::from_secs(30)))
         // HTTP metrics layer (outermost) - captures ALL responses including
         // framework-level errors like 415, 400, 404, 405
-        .layer(middleware::from_fn(http_metrics_middleware)))
+        .layer(middleware::from_fn(http_metrics_middleware))
+        .layer(RequestIdLayer))
 }
 
 #[cfg(test)]
@@ -12,9 +12,10 @@
 
 use crate::errors::GcError;
 use crate::observability::metrics;
+use common::request_id::{RequestId, REQUEST_ID_HEADER};
 use common::secret::ExposeSecret;
 use common::token_manager::TokenReceiver;
-use reqwest::Client;
+use reqwest::{Client, RequestBuilder};
 use std::time::{Duration, Instant};
 use tracing::{error, instrument, warn};
 
@@ -88,7 +89,7 @@ impl AcClient {
         let start = Instant::now();
         let url = format!("{}/api/v1/auth/internal/meeting-token", self.base_url);
 
-        let response = self
+        let builder = self
             .client
             .post(&url)
             .header(
@@ -96,15 +97,13 @@ impl AcClient {
                 format!("Bearer {}", self.token_receiver.token().expose_secret()),
             )
             .header("Content-Type", "application/json")
-            .json(request)
-            .send()
-            .await
-            .map_err(|e| {
-                metrics::record_ac_request("meeting_token", "error", start.elapsed());
-                metrics::record_error("ac_meeting_token", "service_unavailable", 503);
-                warn!(target: "gc.services.ac_client", error = %e, "AC request failed");
-                GcError::ServiceUnavailable("Auth Controller is unavailable".to_string())
-            })?;
+            .json(request);
+        let response = with_request_id(builder).send().await.map_err(|e| {
+            metrics::record_ac_request("meeting_token", "error", start.elapsed());
+            metrics::record_error("ac_meeting_token", "service_unavailable", 503);
+            warn!(target: "gc.services.ac_client", error = %e, "AC request failed");
+            GcError::ServiceUnavailable("Auth Controller is unavailable".to_string())
+        })?;
 
         let result = self.handle_response(response).await;
         match &result {
@@ -136,7 +135,7 @@ impl AcClient {
         let start = Instant::now();
         let url = format!("{}/api/v1/auth/internal/guest-token", self.base_url);
 
-        let response = self
+        let builder = self
             .client
             .post(&url)
             .header(
@@ -144,15 +143,13 @@ impl AcClient {
                 format!("Bearer {}", self.token_receiver.token().expose_secret()),
             )
             .header("Content-Type", "application/json")
-            .json(request)
-            .send()
-            .await
-            .map_err(|e| {
-                metrics::record_ac_request("guest_token", "error", start.elapsed());
-                metrics::record_error("ac_guest_token", "service_unavailable", 503);
-                warn!(target: "gc.services.ac_client", error = %e, "AC request failed");
-                GcError::ServiceUnavailable("Auth Controller is unavailable".to_string())
-            })?;
+            .json(request);
+        let response = with_request_id(builder).send().await.map_err(|e| {
+            metrics::record_ac_request("guest_token", "error", start.elapsed());
+            metrics::record_error("ac_guest_token", "service_unavailable", 503);
+            warn!(target: "gc.services.ac_client", error = %e, "AC request failed");
+            GcError::ServiceUnavailable("Auth Controller is unavailable".to_string())
+        })?;
 
         let result = self.handle_response(response).await;
         match &result {
@@ -202,6 +199,14 @@ impl AcClient {
     }
 }
 
+/// Forward the current request ID (if any) so AC logs can be correlated.
+fn with_request_id(builder: RequestBuilder) -> RequestBuilder {
+    match RequestId::current() {
+        Some(request_id) => builder.header(REQUEST_ID_HEADER, request_id.as_str()),
+        None => builder,
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -382,6 +387,41 @@ mod tests {
         assert_eq!(token_response.expires_in, 900);
     }
 
+    #[tokio::test]
+    async fn test_request_meeting_token_forwards_request_id() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/auth/internal/meeting-token"))
+            .and(header(REQUEST_ID_HEADER, "req-from-client"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "token": "eyJ.test.token",
+                "expires_in": 900
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let token_receiver = test_token_receiver("test-service-token");
+        let client = AcClient::new(mock_server.uri(), token_receiver).unwrap();
+
+        let request = MeetingTokenRequest {
+            subject_user_id: Uuid::from_u128(1),
+            meeting_id: Uuid::from_u128(2),
+            meeting_org_id: Uuid::from_u128(3),
+            home_org_id: Uuid::nil(),
+            participant_type: ParticipantType::Member,
+            role: MeetingRole::Participant,
+            capabilities: vec![],
+            ttl_seconds: 900,
+        };
+
+        let result = RequestId::parse("req-from-client")
+            .unwrap()
+            .scope(client.request_meeting_token(&request))
+            .await;
+        assert!(result.is_ok(), "request ID header should reach AC");
+    }
+
     #[tokio::test]
     async fn test_request_meeting_token_network_error() {
         // Point to a non-existent server
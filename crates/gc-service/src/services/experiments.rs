@@ -0,0 +1,173 @@
+//! A/B experiment bucketing for meeting joins.
+//!
+//! Every enabled experiment puts a joiner into one of its variants by
+//! hashing the experiment's salt with the bucketed ID (the meeting's org,
+//! or the joiner). The same inputs always give the same variant, so a user
+//! keeps their variant across meetings and GC instances without any
+//! assignment lookup. The variants are returned in the join response and
+//! recorded in `experiment_assignments` as the analysis key.
+//!
+//! Experiments never block a join: if they cannot be read or recorded, the
+//! join proceeds without them.
+
+use crate::repositories::{Experiment, ExperimentUnit, ExperimentsRepository};
+use ring::digest;
+use sqlx::PgPool;
+use std::collections::BTreeMap;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Who is joining, for bucketing and the assignment record.
+#[derive(Debug, Clone, Copy)]
+pub enum ExperimentSubject {
+    /// Authenticated user.
+    User(Uuid),
+    /// Anonymous guest (a fresh ID per join).
+    Guest(Uuid),
+}
+
+impl ExperimentSubject {
+    fn id(self) -> Uuid {
+        match self {
+            Self::User(id) | Self::Guest(id) => id,
+        }
+    }
+
+    fn subject_type(self) -> &'static str {
+        match self {
+            Self::User(_) => "user",
+            Self::Guest(_) => "guest",
+        }
+    }
+}
+
+/// The variant of `experiment` for a subject, or `None` if it has no
+/// variants.
+#[must_use]
+pub fn bucket<'a>(experiment: &'a Experiment, org_id: Uuid, subject_id: Uuid) -> Option<&'a str> {
+    let key = match experiment.unit {
+        ExperimentUnit::Org => org_id,
+        ExperimentUnit::User => subject_id,
+    };
+    let hash = digest::digest(
+        &digest::SHA256,
+        format!("{}:{}", experiment.salt, key).as_bytes(),
+    );
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(hash.as_ref().get(..8)?);
+    let count = u64::try_from(experiment.variants.len()).ok()?;
+    let index = u64::from_be_bytes(prefix).checked_rem(count)?;
+    experiment
+        .variants
+        .get(usize::try_from(index).ok()?)
+        .map(String::as_str)
+}
+
+/// Service for experiment assignment.
+pub struct ExperimentService;
+
+impl ExperimentService {
+    /// Bucket a joiner into every enabled experiment and record the result.
+    ///
+    /// # Returns
+    ///
+    /// Variant by experiment name; empty if experiments are unavailable.
+    pub async fn assign_for_join(
+        pool: &PgPool,
+        meeting_id: Uuid,
+        org_id: Uuid,
+        subject: ExperimentSubject,
+    ) -> BTreeMap<String, String> {
+        let experiments = match ExperimentsRepository::list_enabled(pool).await {
+            Ok(experiments) => experiments,
+            Err(e) => {
+                warn!(
+                    target: "gc.services.experiments",
+                    error = %e,
+                    "Failed to load experiments, joining without them"
+                );
+                return BTreeMap::new();
+            }
+        };
+
+        let variants: BTreeMap<String, String> = experiments
+            .iter()
+            .filter_map(|experiment| {
+                bucket(experiment, org_id, subject.id())
+                    .map(|variant| (experiment.name.clone(), variant.to_string()))
+            })
+            .collect();
+
+        if let Err(e) = ExperimentsRepository::record_assignments(
+            pool,
+            meeting_id,
+            &subject.id().to_string(),
+            subject.subject_type(),
+            &variants,
+        )
+        .await
+        {
+            // Unrecorded joins are missing from analysis, not wrong in it
+            warn!(
+                target: "gc.services.experiments",
+                error = %e,
+                meeting_id = %meeting_id,
+                "Failed to record experiment assignments"
+            );
+        }
+
+        variants
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn experiment(unit: ExperimentUnit, variants: &[&str]) -> Experiment {
+        Experiment {
+            name: "simulcast".to_string(),
+            salt: "simulcast-2026-10".to_string(),
+            unit,
+            variants: variants.iter().map(|v| (*v).to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_bucket_is_deterministic() {
+        let experiment = experiment(ExperimentUnit::User, &["on", "off"]);
+        let org = Uuid::from_u128(1);
+        let user = Uuid::from_u128(42);
+
+        let first = bucket(&experiment, org, user).unwrap();
+        assert_eq!(bucket(&experiment, org, user), Some(first));
+        assert!(["on", "off"].contains(&first));
+    }
+
+    #[test]
+    fn test_bucket_by_org_ignores_user() {
+        let experiment = experiment(ExperimentUnit::Org, &["a", "b", "c", "d"]);
+        let org = Uuid::from_u128(7);
+
+        let variant = bucket(&experiment, org, Uuid::from_u128(1));
+        for user in 2..50 {
+            assert_eq!(bucket(&experiment, org, Uuid::from_u128(user)), variant);
+        }
+    }
+
+    #[test]
+    fn test_bucket_spreads_users_over_variants() {
+        let experiment = experiment(ExperimentUnit::User, &["on", "off"]);
+        let on = (0..1000)
+            .filter(|user| bucket(&experiment, Uuid::nil(), Uuid::from_u128(*user)) == Some("on"))
+            .count();
+        assert!((400..600).contains(&on), "on = {on}");
+    }
+
+    #[test]
+    fn test_bucket_without_variants() {
+        let experiment = experiment(ExperimentUnit::User, &[]);
+        assert_eq!(bucket(&experiment, Uuid::nil(), Uuid::nil()), None);
+    }
+}
@@ -0,0 +1,270 @@
+//! Fleet version tracking.
+//!
+//! MCs and MHs report the build they run (`BuildVersion`) when they
+//! register. GC stores it with the registration and uses it to:
+//!
+//! - Show the version distribution during a rolling deploy, through
+//!   `GET /api/v1/admin/versions` and the `gc_fleet_versions` gauge
+//! - Refuse registrations older than `GC_MIN_FLEET_VERSION`, once a release
+//!   depends on behavior older binaries lack
+//!
+//! Binaries that predate version reporting register without a build. They
+//! are counted as `unknown`, and refused whenever a minimum is configured.
+
+use crate::observability::metrics;
+use crate::repositories::{
+    MediaHandlersRepository, MeetingControllersRepository, ReportedBuild, VersionCount,
+};
+use proto_gen::dark_tower::internal::v1::BuildVersion;
+use sqlx::PgPool;
+use std::fmt;
+
+/// Label and response value for instances that did not report a build.
+pub const UNKNOWN_VERSION: &str = "unknown";
+
+/// Maximum length of each reported build field (matches the column widths).
+const MAX_BUILD_FIELD_LENGTH: usize = 64;
+
+/// A `major.minor.patch` release version.
+///
+/// Pre-release and build metadata suffixes (`-rc.1`, `+3f9c2e1`) are
+/// ignored, so `0.3.0-rc.1` satisfies a minimum of `0.3.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReleaseVersion {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+impl ReleaseVersion {
+    /// Parse `major.minor.patch`, returning `None` for anything else.
+    pub fn parse(value: &str) -> Option<Self> {
+        let core = value.split(['-', '+']).next().unwrap_or_default().trim();
+        let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+
+        let version = Self {
+            major: parts.next()??,
+            minor: parts.next()??,
+            patch: parts.next()??,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(version)
+    }
+}
+
+impl fmt::Display for ReleaseVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Validate the build in a registration request.
+///
+/// Returns `None` for a request without one (or with an empty version).
+///
+/// # Errors
+///
+/// Returns a message for `Status::invalid_argument` if a field is too long
+/// to store.
+pub fn reported_build(build: Option<&BuildVersion>) -> Result<Option<ReportedBuild<'_>>, String> {
+    let Some(build) = build.filter(|b| !b.version.is_empty()) else {
+        return Ok(None);
+    };
+
+    for (field, value) in [
+        ("build.version", &build.version),
+        ("build.git_sha", &build.git_sha),
+        ("build.proto_schema", &build.proto_schema),
+    ] {
+        if value.len() > MAX_BUILD_FIELD_LENGTH {
+            return Err(format!(
+                "{} exceeds maximum length of {} characters",
+                field, MAX_BUILD_FIELD_LENGTH
+            ));
+        }
+    }
+
+    Ok(Some(ReportedBuild {
+        version: &build.version,
+        git_sha: &build.git_sha,
+        proto_schema: &build.proto_schema,
+    }))
+}
+
+/// Check a registration against the configured minimum version.
+///
+/// # Errors
+///
+/// Returns the message to send back in the rejected registration response
+/// when the build is older than `min_version`, does not report a version, or
+/// reports one that does not parse.
+pub fn check_min_version(
+    min_version: Option<ReleaseVersion>,
+    build: Option<ReportedBuild<'_>>,
+) -> Result<(), String> {
+    let Some(min_version) = min_version else {
+        return Ok(());
+    };
+
+    let Some(build) = build else {
+        return Err(format!(
+            "Registration does not report a version; minimum is {}",
+            min_version
+        ));
+    };
+
+    match ReleaseVersion::parse(build.version) {
+        Some(version) if version >= min_version => Ok(()),
+        Some(version) => Err(format!(
+            "Version {} is older than the minimum {}",
+            version, min_version
+        )),
+        None => Err(format!(
+            "Version '{}' is not a release version; minimum is {}",
+            build.version, min_version
+        )),
+    }
+}
+
+/// `(version, proto_schema, count)` for the metrics and admin API, with
+/// unreported builds as [`UNKNOWN_VERSION`].
+pub fn label_counts(counts: Vec<VersionCount>) -> Vec<(String, String, u64)> {
+    counts
+        .into_iter()
+        .map(|c| {
+            (
+                c.version.unwrap_or_else(|| UNKNOWN_VERSION.to_string()),
+                c.proto_schema
+                    .unwrap_or_else(|| UNKNOWN_VERSION.to_string()),
+                u64::try_from(c.count).unwrap_or(0),
+            )
+        })
+        .collect()
+}
+
+/// Refresh the `gc_fleet_versions` gauge for MCs and MHs.
+///
+/// Called after registrations and after the health checkers mark instances
+/// unhealthy, which takes them out of the distribution.
+pub async fn refresh_fleet_version_metrics(pool: &PgPool) {
+    match MeetingControllersRepository::get_version_counts(pool).await {
+        Ok(counts) => metrics::update_fleet_version_gauges("meeting", &label_counts(counts)),
+        Err(e) => tracing::warn!(
+            target: "gc.services.fleet_versions",
+            error = %e,
+            "Failed to refresh MC version metrics"
+        ),
+    }
+
+    match MediaHandlersRepository::get_version_counts(pool).await {
+        Ok(counts) => metrics::update_fleet_version_gauges("media", &label_counts(counts)),
+        Err(e) => tracing::warn!(
+            target: "gc.services.fleet_versions",
+            error = %e,
+            "Failed to refresh MH version metrics"
+        ),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn build(version: &str) -> BuildVersion {
+        BuildVersion {
+            version: version.to_string(),
+            git_sha: "3f9c2e1".to_string(),
+            proto_schema: "9b1e4c0d2a7f3865".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_release_version_parse_and_order() {
+        let v = |s| ReleaseVersion::parse(s).unwrap();
+
+        assert_eq!(v("0.2.0").to_string(), "0.2.0");
+        assert_eq!(v("1.4.2-rc.1+3f9c2e1"), v("1.4.2"));
+        assert!(v("0.10.0") > v("0.9.9"));
+        assert!(v("1.0.0") > v("0.99.99"));
+
+        for invalid in [
+            "",
+            "1",
+            "1.2",
+            "1.2.3.4",
+            "v1.2.3",
+            "1.x.0",
+            UNKNOWN_VERSION,
+        ] {
+            assert_eq!(ReleaseVersion::parse(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_reported_build() {
+        assert_eq!(reported_build(None), Ok(None));
+        assert_eq!(reported_build(Some(&build(""))), Ok(None));
+
+        let reported = build("0.2.0");
+        let parsed = reported_build(Some(&reported)).unwrap().unwrap();
+        assert_eq!(parsed.version, "0.2.0");
+        assert_eq!(parsed.git_sha, "3f9c2e1");
+
+        let mut too_long = build("0.2.0");
+        too_long.git_sha = "a".repeat(MAX_BUILD_FIELD_LENGTH + 1);
+        let err = reported_build(Some(&too_long)).unwrap_err();
+        assert!(err.contains("build.git_sha"));
+    }
+
+    #[test]
+    fn test_check_min_version() {
+        let min = ReleaseVersion::parse("0.2.0");
+        let at = build("0.2.0");
+        let newer = build("0.3.0-rc.1");
+        let older = build("0.1.9");
+        let garbage = build("latest");
+        let check =
+            |min, b: &BuildVersion| check_min_version(min, reported_build(Some(b)).unwrap());
+
+        assert!(check(None, &older).is_ok());
+        assert!(check_min_version(None, None).is_ok());
+
+        assert!(check(min, &at).is_ok());
+        assert!(check(min, &newer).is_ok());
+        assert_eq!(
+            check(min, &older).unwrap_err(),
+            "Version 0.1.9 is older than the minimum 0.2.0"
+        );
+        assert!(check(min, &garbage).unwrap_err().contains("'latest'"));
+        assert!(check_min_version(min, None)
+            .unwrap_err()
+            .contains("does not report a version"));
+    }
+
+    #[test]
+    fn test_label_counts_marks_unreported_builds_unknown() {
+        let counts = vec![
+            VersionCount {
+                version: Some("0.2.0".to_string()),
+                proto_schema: Some("abc".to_string()),
+                count: 3,
+            },
+            VersionCount {
+                version: None,
+                proto_schema: None,
+                count: 1,
+            },
+        ];
+
+        assert_eq!(
+            label_counts(counts),
+            vec![
+                ("0.2.0".to_string(), "abc".to_string(), 3),
+                (UNKNOWN_VERSION.to_string(), UNKNOWN_VERSION.to_string(), 1),
+            ]
+        );
+    }
+}
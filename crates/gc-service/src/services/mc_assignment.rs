@@ -7,7 +7,10 @@
 //!
 //! This service orchestrates the assignment flow:
 //! 1. Check for existing healthy assignment
-//! 2. If no healthy assignment, select candidate MC via load balancing
+//! 2. If no healthy assignment, select candidate MC via load balancing,
+//!    from the owning organization's dedicated MC pool if it has one
+//!    and among MCs with headroom for the meeting's priority, spilling
+//!    over to the spill-over pool when there are none
 //! 3. Select MHs for the meeting via weighted load balancing
 //! 4. Call MC via gRPC to notify of assignment (ADR-0010 Section 4a)
 //! 5. On acceptance, atomic DB write
@@ -20,18 +23,42 @@
 //! - Error messages are generic to prevent information leakage
 
 use crate::errors::GcError;
+use crate::models::MeetingPriority;
 use crate::observability::metrics;
-use crate::repositories::{weighted_random_select, McAssignment, MeetingAssignmentsRepository};
+use crate::repositories::{
+    weighted_random_select, McAssignment, McCandidate, MeetingAssignmentsRepository,
+    MeetingsRepository, DEFAULT_MC_POOL, SPILLOVER_MC_POOL,
+};
 use crate::services::mc_client::{McAssignmentResult, McClientTrait, McRejectionReason};
 use crate::services::mh_selection::{MhSelection, MhSelectionService};
 use sqlx::PgPool;
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::instrument;
+use uuid::Uuid;
 
 /// Maximum number of retry attempts for MC rejection per ADR-0010.
 const MAX_MC_ASSIGNMENT_RETRIES: usize = 3;
 
+/// Load ratio at or above which an MC takes no new `standard` meetings,
+/// keeping the rest of its capacity for webinars.
+const STANDARD_MAX_LOAD_RATIO: f64 = 0.9;
+
+/// Load ratio at or above which an MC takes no new `test` meetings.
+const TEST_MAX_LOAD_RATIO: f64 = 0.7;
+
+/// Load ratio below which an MC may take a new meeting of `priority`.
+///
+/// Lower priorities stop short of full, so under capacity pressure they are
+/// shed (to the spill-over pool, then rejected) before higher ones.
+fn max_load_ratio(priority: MeetingPriority) -> f64 {
+    match priority {
+        MeetingPriority::Webinar => 1.0,
+        MeetingPriority::Standard => STANDARD_MAX_LOAD_RATIO,
+        MeetingPriority::Test => TEST_MAX_LOAD_RATIO,
+    }
+}
+
 /// Service for MC assignment operations.
 pub struct McAssignmentService;
 
@@ -44,6 +71,16 @@ pub struct AssignmentWithMh {
     pub mh_selection: MhSelection,
 }
 
+/// Result of a read-only MC capacity probe for a meeting.
+#[derive(Debug, Clone)]
+pub struct McCapacityProbe {
+    /// Existing healthy assignment, if the meeting already has one.
+    pub assignment: Option<McAssignment>,
+    /// True when the meeting is assigned or an MC could take it now,
+    /// counting the spill-over pool.
+    pub capacity_available: bool,
+}
+
 impl McAssignmentService {
     /// End a meeting assignment.
     ///
@@ -89,8 +126,6 @@ impl McAssignmentService {
     /// * `pool` - Database connection pool
     /// * `meeting_id` - Meeting to look up
     /// * `region` - Region to look up in
-    // Allow: Used in tests; will be used for future status endpoints
-    #[allow(dead_code)]
     #[instrument(skip_all, fields(meeting_id = %meeting_id, region = %region))]
     pub async fn get_assignment(
         pool: &PgPool,
@@ -100,6 +135,48 @@ impl McAssignmentService {
         MeetingAssignmentsRepository::get_healthy_assignment(pool, meeting_id, region).await
     }
 
+    /// Probe MC capacity for a meeting without assigning it.
+    ///
+    /// Runs the same pool and priority candidate selection as
+    /// [`Self::assign_meeting_with_mh`] but makes no MC calls and no writes,
+    /// so clients can check readiness before joining.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - Database connection pool
+    /// * `meeting_id` - Meeting to probe for
+    /// * `region` - Region to probe in
+    #[instrument(skip_all, fields(meeting_id = %meeting_id, region = %region))]
+    pub async fn probe_capacity(
+        pool: &PgPool,
+        meeting_id: Uuid,
+        region: &str,
+    ) -> Result<McCapacityProbe, GcError> {
+        let meeting_id_str = meeting_id.to_string();
+
+        if let Some(assignment) = Self::get_assignment(pool, &meeting_id_str, region).await? {
+            return Ok(McCapacityProbe {
+                assignment: Some(assignment),
+                capacity_available: true,
+            });
+        }
+
+        let mc_pool = MeetingsRepository::get_mc_pool(pool, meeting_id).await?;
+        let mc_pool = mc_pool.as_deref().unwrap_or(DEFAULT_MC_POOL);
+        let priority = MeetingsRepository::get_priority(pool, meeting_id)
+            .await?
+            .unwrap_or_default();
+
+        let (candidates, _) =
+            Self::get_untried_candidates(pool, &meeting_id_str, region, mc_pool, priority, &[])
+                .await?;
+
+        Ok(McCapacityProbe {
+            assignment: None,
+            capacity_available: !candidates.is_empty(),
+        })
+    }
+
     /// Assign a meeting with MH selection and MC notification (ADR-0010 Section 4a).
     ///
     /// This is the new assignment flow that:
@@ -174,23 +251,38 @@ impl McAssignmentService {
             "Selected MHs for meeting"
         );
 
+        // E2E flag and org recording consent policy travel with the assignment;
+        // meetings not in the database (e.g. non-UUID IDs) get the MC defaults,
+        // the default MC pool, and standard priority.
+        let (settings, mc_pool, priority) = match Uuid::parse_str(meeting_id) {
+            Ok(id) => (
+                MeetingsRepository::get_mc_meeting_settings(pool, id).await?,
+                MeetingsRepository::get_mc_pool(pool, id).await?,
+                MeetingsRepository::get_priority(pool, id).await?,
+            ),
+            Err(_) => (None, None, None),
+        };
+        let mc_pool = mc_pool.as_deref().unwrap_or(DEFAULT_MC_POOL);
+        let priority = priority.unwrap_or_default();
+
         // Step 3: Get candidate MCs and try assignment with retry
         let mut tried_mcs: Vec<String> = Vec::new();
         let mut last_rejection_reason: Option<McRejectionReason> = None;
 
         for attempt in 1..=MAX_MC_ASSIGNMENT_RETRIES {
             // Get candidate MCs, excluding ones we've already tried
-            let mut candidates =
-                MeetingAssignmentsRepository::get_candidate_mcs(pool, region).await?;
-
-            // Filter out already-tried MCs
-            candidates.retain(|c| !tried_mcs.contains(&c.controller_id));
+            let (candidates, spilled_over) = Self::get_untried_candidates(
+                pool, meeting_id, region, mc_pool, priority, &tried_mcs,
+            )
+            .await?;
 
             if candidates.is_empty() {
                 tracing::warn!(
                     target: "gc.service.assignment",
                     meeting_id = %meeting_id,
                     region = %region,
+                    mc_pool = %mc_pool,
+                    priority = priority.as_str(),
                     attempt = attempt,
                     tried_mcs = ?tried_mcs,
                     "No more MCs available for assignment"
@@ -217,7 +309,13 @@ impl McAssignmentService {
 
             // Step 4: Call MC to notify of assignment BEFORE writing to DB
             let result = mc_client
-                .assign_meeting(&mc_endpoint, meeting_id, &mh_selection.handlers, gc_id)
+                .assign_meeting(
+                    &mc_endpoint,
+                    meeting_id,
+                    &mh_selection.handlers,
+                    gc_id,
+                    settings.as_ref(),
+                )
                 .await;
 
             match result {
@@ -244,11 +342,19 @@ impl McAssignmentService {
                         meeting_id = %meeting_id,
                         mc_id = %assignment.mc_id,
                         region = %region,
+                        priority = priority.as_str(),
+                        spilled_over = spilled_over,
                         "Meeting assigned to MC with MH"
                     );
 
                     // Record success metrics (ADR-0011)
                     metrics::record_mc_assignment("success", None, start.elapsed());
+                    let outcome = if spilled_over {
+                        "spillover"
+                    } else {
+                        "assigned"
+                    };
+                    metrics::record_priority_assignment(priority.as_str(), outcome);
 
                     return Ok(AssignmentWithMh {
                         mc_assignment: assignment,
@@ -292,6 +398,7 @@ impl McAssignmentService {
             None => ("error", Some("no_mcs_available")),
         };
         metrics::record_mc_assignment(status, rejection_reason, start.elapsed());
+        metrics::record_priority_assignment(priority.as_str(), "rejected");
 
         let reason_str = match last_rejection_reason {
             Some(McRejectionReason::AtCapacity) => "All meeting controllers are at capacity",
@@ -304,6 +411,7 @@ impl McAssignmentService {
             target: "gc.service.assignment",
             meeting_id = %meeting_id,
             region = %region,
+            priority = priority.as_str(),
             tried_mcs = ?tried_mcs,
             "Failed to assign meeting after {} attempts",
             MAX_MC_ASSIGNMENT_RETRIES
@@ -311,6 +419,70 @@ impl McAssignmentService {
 
         Err(GcError::ServiceUnavailable(reason_str.to_string()))
     }
+
+    /// Get candidate MCs in `mc_pool` that have not been tried yet and have
+    /// headroom for `priority`.
+    ///
+    /// A dedicated pool with no such candidates falls back to the default
+    /// pool, so an organization's meetings still start when its dedicated
+    /// MCs are full or down. When neither has room the meeting spills over
+    /// to the spill-over pool, regardless of load; the returned flag is set
+    /// in that case.
+    async fn get_untried_candidates(
+        pool: &PgPool,
+        meeting_id: &str,
+        region: &str,
+        mc_pool: &str,
+        priority: MeetingPriority,
+        tried_mcs: &[String],
+    ) -> Result<(Vec<McCandidate>, bool), GcError> {
+        let max_load_ratio = max_load_ratio(priority);
+        let usable = |c: &McCandidate| {
+            !tried_mcs.contains(&c.controller_id) && c.load_ratio < max_load_ratio
+        };
+
+        let mut candidates =
+            MeetingAssignmentsRepository::get_candidate_mcs_in_pool(pool, region, mc_pool).await?;
+        candidates.retain(usable);
+
+        if candidates.is_empty() && mc_pool != DEFAULT_MC_POOL {
+            tracing::warn!(
+                target: "gc.service.assignment",
+                meeting_id = %meeting_id,
+                region = %region,
+                mc_pool = %mc_pool,
+                "No MCs available in dedicated pool, falling back to default pool"
+            );
+            candidates = MeetingAssignmentsRepository::get_candidate_mcs_in_pool(
+                pool,
+                region,
+                DEFAULT_MC_POOL,
+            )
+            .await?;
+            candidates.retain(usable);
+        }
+
+        if !candidates.is_empty() {
+            return Ok((candidates, false));
+        }
+
+        tracing::warn!(
+            target: "gc.service.assignment",
+            meeting_id = %meeting_id,
+            region = %region,
+            priority = priority.as_str(),
+            "No MCs with headroom for meeting priority, spilling over"
+        );
+        let mut candidates = MeetingAssignmentsRepository::get_candidate_mcs_in_pool(
+            pool,
+            region,
+            SPILLOVER_MC_POOL,
+        )
+        .await?;
+        candidates.retain(|c| !tried_mcs.contains(&c.controller_id));
+
+        Ok((candidates, true))
+    }
 }
 
 #[cfg(test)]
@@ -11,13 +11,17 @@
 //! - Error messages are generic to prevent information leakage
 
 use crate::errors::GcError;
+use crate::models::{DuplicateJoinPolicy, RecordingLayout};
 use crate::observability::metrics;
+use crate::repositories::McMeetingSettings;
 use crate::services::mh_selection::MhAssignmentInfo;
+use common::request_id::{RequestId, REQUEST_ID_HEADER};
 use common::secret::ExposeSecret;
 use common::token_manager::TokenReceiver;
 use proto_gen::dark_tower::internal::v1::meeting_controller_service_client::MeetingControllerServiceClient;
 use proto_gen::dark_tower::internal::v1::{
-    AssignMeetingWithMhRequest, AssignMeetingWithMhResponse, MhAssignment,
+    self as internal, AssignMeetingWithMhRequest, AssignMeetingWithMhResponse, MhAssignment,
+    PromoteStandbyRequest, PromotedMeeting,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -131,6 +135,7 @@ impl McClient {
     /// * `meeting_id` - Meeting being assigned
     /// * `mh_assignments` - List of MH assignments
     /// * `gc_id` - ID of this GC instance
+    /// * `settings` - E2E flag and org recording consent policy (`None` = MC defaults)
     ///
     /// # Returns
     ///
@@ -148,6 +153,7 @@ impl McClient {
         meeting_id: &str,
         mh_assignments: &[MhAssignmentInfo],
         gc_id: &str,
+        settings: Option<&McMeetingSettings>,
     ) -> Result<McAssignmentResult, GcError> {
         // Start timing before get_channel so connection failures are included in metrics
         let rpc_start = Instant::now();
@@ -176,19 +182,26 @@ impl McClient {
             meeting_id: meeting_id.to_string(),
             mh_assignments: proto_assignments,
             requesting_gc_id: gc_id.to_string(),
+            recording_consent_policy: recording_policy_to_proto(settings),
+            e2e_enabled: settings.is_some_and(|settings| settings.e2e_enabled),
+            duplicate_join_policy: duplicate_join_policy_to_proto(settings).into(),
+            recording_layout: recording_layout_to_proto(settings).into(),
         };
 
-        // Add authorization header (token accessed via ExposeSecret from TokenReceiver)
-        let mut grpc_request = Request::new(request);
-        grpc_request.metadata_mut().insert(
-            "authorization",
-            format!("Bearer {}", self.token_receiver.token().expose_secret())
-                .parse()
-                .map_err(|e| {
-                    error!(target: "gc.services.mc_client", error = %e, "Invalid service token format");
-                    GcError::Internal(format!("Invalid service token format: {}", e))
-                })?,
-        );
+        let grpc_request = self.authorized_request(request)?;
+
+        #[cfg(feature = "fault-injection")]
+        if let Err(fault) = common::fault_injection::inject(crate::faults::MC_ASSIGNMENT).await {
+            // A dropped request goes unanswered until the RPC deadline
+            if matches!(fault, common::fault_injection::InjectedFault::Drop(_)) {
+                tokio::time::sleep(Duration::from_secs(MC_RPC_TIMEOUT_SECS)).await;
+            }
+            metrics::record_grpc_mc_call("assign_meeting_with_mh", "error", rpc_start.elapsed());
+            warn!(target: "gc.services.mc_client", error = %fault, mc_endpoint = %mc_endpoint, "MC RPC failed");
+            return Err(GcError::ServiceUnavailable(
+                "Meeting controller unavailable".to_string(),
+            ));
+        }
 
         // Make the RPC call
         let mut client = MeetingControllerServiceClient::new(channel);
@@ -226,6 +239,150 @@ impl McClient {
             Ok(McAssignmentResult::Rejected(reason))
         }
     }
+
+    /// Promote a warm standby MC, handing it its failed primary's meetings.
+    ///
+    /// # Arguments
+    ///
+    /// * `mc_endpoint` - gRPC endpoint of the standby
+    /// * `primary_id` - The failed primary
+    /// * `meetings` - The primary's active meetings
+    /// * `gc_id` - ID of this GC instance
+    ///
+    /// # Returns
+    ///
+    /// The meetings the standby took over.
+    ///
+    /// # Errors
+    ///
+    /// - `GcError::ServiceUnavailable` - Standby unreachable or refused the promotion
+    #[instrument(skip_all, fields(mc_endpoint = %mc_endpoint, primary_id = %primary_id, gc_id = %gc_id))]
+    pub async fn promote_standby(
+        &self,
+        mc_endpoint: &str,
+        primary_id: &str,
+        meetings: &[StandbyMeeting],
+        gc_id: &str,
+    ) -> Result<Vec<String>, GcError> {
+        let rpc_start = Instant::now();
+
+        let channel = match self.get_channel(mc_endpoint).await {
+            Ok(ch) => ch,
+            Err(e) => {
+                metrics::record_grpc_mc_call("promote_standby", "error", rpc_start.elapsed());
+                metrics::record_error("mc_grpc", "connection_failed", 503);
+                return Err(e);
+            }
+        };
+
+        let request = PromoteStandbyRequest {
+            primary_id: primary_id.to_string(),
+            requesting_gc_id: gc_id.to_string(),
+            meetings: meetings
+                .iter()
+                .map(|meeting| {
+                    let settings = meeting.settings.as_ref();
+                    PromotedMeeting {
+                        meeting_id: meeting.meeting_id.clone(),
+                        recording_consent_policy: recording_policy_to_proto(settings),
+                        e2e_enabled: settings.is_some_and(|settings| settings.e2e_enabled),
+                        duplicate_join_policy: duplicate_join_policy_to_proto(settings).into(),
+                        recording_layout: recording_layout_to_proto(settings).into(),
+                    }
+                })
+                .collect(),
+        };
+        let grpc_request = self.authorized_request(request)?;
+
+        let mut client = MeetingControllerServiceClient::new(channel);
+        let response = client.promote_standby(grpc_request).await;
+        let rpc_duration = rpc_start.elapsed();
+
+        let response = response.map_err(|e| {
+            metrics::record_grpc_mc_call("promote_standby", "error", rpc_duration);
+            metrics::record_error("mc_grpc", "service_unavailable", 503);
+            warn!(target: "gc.services.mc_client", error = %e, mc_endpoint = %mc_endpoint, "Standby promotion RPC failed");
+            GcError::ServiceUnavailable("Meeting controller unavailable".to_string())
+        })?;
+        metrics::record_grpc_mc_call("promote_standby", "success", rpc_duration);
+
+        Ok(response.into_inner().promoted_meeting_ids)
+    }
+
+    /// Wrap a message in a request carrying the service token and the
+    /// caller's request ID.
+    fn authorized_request<T>(&self, message: T) -> Result<Request<T>, GcError> {
+        // Token accessed via ExposeSecret from TokenReceiver
+        let mut grpc_request = Request::new(message);
+        grpc_request.metadata_mut().insert(
+            "authorization",
+            format!("Bearer {}", self.token_receiver.token().expose_secret())
+                .parse()
+                .map_err(|e| {
+                    error!(target: "gc.services.mc_client", error = %e, "Invalid service token format");
+                    GcError::Internal(format!("Invalid service token format: {}", e))
+                })?,
+        );
+        if let Some(request_id) = RequestId::current() {
+            if let Ok(value) = request_id.as_str().parse() {
+                grpc_request.metadata_mut().insert(REQUEST_ID_HEADER, value);
+            }
+        }
+        Ok(grpc_request)
+    }
+}
+
+/// Org recording consent policy for the MC (`None` = MC default).
+fn recording_policy_to_proto(
+    settings: Option<&McMeetingSettings>,
+) -> Option<internal::RecordingConsentPolicy> {
+    settings.map(|settings| {
+        let policy = &settings.recording_policy;
+        let mode = if policy.require_ack {
+            internal::RecordingConsentMode::RequireAck
+        } else {
+            internal::RecordingConsentMode::Notify
+        };
+        internal::RecordingConsentPolicy {
+            mode: mode.into(),
+            consent_timeout_seconds: policy.consent_timeout_seconds,
+        }
+    })
+}
+
+/// Duplicate join policy for the MC (`Unspecified` = MC default).
+fn duplicate_join_policy_to_proto(
+    settings: Option<&McMeetingSettings>,
+) -> internal::DuplicateJoinPolicy {
+    settings.map_or(
+        internal::DuplicateJoinPolicy::Unspecified,
+        |settings| match settings.duplicate_join_policy {
+            DuplicateJoinPolicy::Allow => internal::DuplicateJoinPolicy::Allow,
+            DuplicateJoinPolicy::Takeover => internal::DuplicateJoinPolicy::Takeover,
+            DuplicateJoinPolicy::Reject => internal::DuplicateJoinPolicy::Reject,
+        },
+    )
+}
+
+/// Recording layout for the MC to pass to the MHs (`Unspecified` = MH
+/// default).
+fn recording_layout_to_proto(settings: Option<&McMeetingSettings>) -> internal::RecordingLayout {
+    settings.map_or(
+        internal::RecordingLayout::Unspecified,
+        |settings| match settings.recording_layout {
+            RecordingLayout::Grid => internal::RecordingLayout::Grid,
+            RecordingLayout::Speaker => internal::RecordingLayout::Speaker,
+        },
+    )
+}
+
+/// A meeting handed to a promoted standby.
+#[derive(Debug, Clone)]
+pub struct StandbyMeeting {
+    /// Meeting ID.
+    pub meeting_id: String,
+    /// E2E flag and org policies (`None` = MC defaults).
+    pub settings: Option<McMeetingSettings>,
 }
 
 /// Trait for MC client operations (enables mocking).
@@ -239,7 +396,17 @@ pub trait McClientTrait: Send + Sync {
         meeting_id: &str,
         mh_assignments: &[MhAssignmentInfo],
         gc_id: &str,
+        settings: Option<&McMeetingSettings>,
     ) -> Result<McAssignmentResult, GcError>;
+
+    /// Promote a warm standby MC; returns the meetings it took over.
+    async fn promote_standby(
+        &self,
+        mc_endpoint: &str,
+        primary_id: &str,
+        meetings: &[StandbyMeeting],
+        gc_id: &str,
+    ) -> Result<Vec<String>, GcError>;
 }
 
 #[async_trait::async_trait]
@@ -250,8 +417,20 @@ impl McClientTrait for McClient {
         meeting_id: &str,
         mh_assignments: &[MhAssignmentInfo],
         gc_id: &str,
+        settings: Option<&McMeetingSettings>,
     ) -> Result<McAssignmentResult, GcError> {
-        self.assign_meeting(mc_endpoint, meeting_id, mh_assignments, gc_id)
+        self.assign_meeting(mc_endpoint, meeting_id, mh_assignments, gc_id, settings)
+            .await
+    }
+
+    async fn promote_standby(
+        &self,
+        mc_endpoint: &str,
+        primary_id: &str,
+        meetings: &[StandbyMeeting],
+        gc_id: &str,
+    ) -> Result<Vec<String>, GcError> {
+        self.promote_standby(mc_endpoint, primary_id, meetings, gc_id)
             .await
     }
 }
@@ -265,6 +444,7 @@ pub mod mock {
 
     use super::*;
     use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
 
     /// Mock MC client for unit testing.
     pub struct MockMcClient {
@@ -274,6 +454,10 @@ pub mod mock {
         call_count: AtomicUsize,
         /// Whether to return errors.
         return_error: bool,
+        /// Meeting settings from the most recent call.
+        last_settings: Mutex<Option<McMeetingSettings>>,
+        /// Meetings offered in the most recent standby promotion.
+        last_promoted: Mutex<Vec<String>>,
     }
 
     impl MockMcClient {
@@ -283,6 +467,8 @@ pub mod mock {
                 responses: vec![McAssignmentResult::Accepted],
                 call_count: AtomicUsize::new(0),
                 return_error: false,
+                last_settings: Mutex::new(None),
+                last_promoted: Mutex::new(Vec::new()),
             }
         }
 
@@ -292,6 +478,8 @@ pub mod mock {
                 responses: vec![McAssignmentResult::Rejected(reason)],
                 call_count: AtomicUsize::new(0),
                 return_error: false,
+                last_settings: Mutex::new(None),
+                last_promoted: Mutex::new(Vec::new()),
             }
         }
 
@@ -301,6 +489,8 @@ pub mod mock {
                 responses,
                 call_count: AtomicUsize::new(0),
                 return_error: false,
+                last_settings: Mutex::new(None),
+                last_promoted: Mutex::new(Vec::new()),
             }
         }
 
@@ -310,6 +500,8 @@ pub mod mock {
                 responses: vec![],
                 call_count: AtomicUsize::new(0),
                 return_error: true,
+                last_settings: Mutex::new(None),
+                last_promoted: Mutex::new(Vec::new()),
             }
         }
 
@@ -317,6 +509,22 @@ pub mod mock {
         pub fn call_count(&self) -> usize {
             self.call_count.load(Ordering::SeqCst)
         }
+
+        /// Get the meetings offered in the most recent standby promotion.
+        pub fn last_promoted(&self) -> Vec<String> {
+            self.last_promoted
+                .lock()
+                .map(|promoted| promoted.clone())
+                .unwrap_or_default()
+        }
+
+        /// Get the meeting settings passed on the most recent call.
+        pub fn last_settings(&self) -> Option<McMeetingSettings> {
+            self.last_settings
+                .lock()
+                .ok()
+                .and_then(|settings| settings.clone())
+        }
     }
 
     #[async_trait::async_trait]
@@ -327,8 +535,12 @@ pub mod mock {
             _meeting_id: &str,
             _mh_assignments: &[MhAssignmentInfo],
             _gc_id: &str,
+            settings: Option<&McMeetingSettings>,
         ) -> Result<McAssignmentResult, GcError> {
             let count = self.call_count.fetch_add(1, Ordering::SeqCst);
+            if let Ok(mut last) = self.last_settings.lock() {
+                *last = settings.cloned();
+            }
 
             if self.return_error {
                 return Err(GcError::ServiceUnavailable(
@@ -347,6 +559,28 @@ pub mod mock {
                 McAssignmentResult::Rejected(reason) => Ok(McAssignmentResult::Rejected(*reason)),
             }
         }
+
+        /// Takes over every meeting offered (errors with `failing()`).
+        async fn promote_standby(
+            &self,
+            _mc_endpoint: &str,
+            _primary_id: &str,
+            meetings: &[StandbyMeeting],
+            _gc_id: &str,
+        ) -> Result<Vec<String>, GcError> {
+            self.call_count.fetch_add(1, Ordering::SeqCst);
+            if let Ok(mut last) = self.last_promoted.lock() {
+                *last = meetings.iter().map(|m| m.meeting_id.clone()).collect();
+            }
+
+            if self.return_error {
+                return Err(GcError::ServiceUnavailable(
+                    "Mock MC client error".to_string(),
+                ));
+            }
+
+            Ok(meetings.iter().map(|m| m.meeting_id.clone()).collect())
+        }
     }
 
     #[cfg(test)]
@@ -357,7 +591,7 @@ pub mod mock {
         async fn test_mock_accepting() {
             let mock = MockMcClient::accepting();
             let result = mock
-                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1")
+                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1", None)
                 .await
                 .unwrap();
 
@@ -369,7 +603,7 @@ pub mod mock {
         async fn test_mock_rejecting() {
             let mock = MockMcClient::rejecting(McRejectionReason::AtCapacity);
             let result = mock
-                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1")
+                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1", None)
                 .await
                 .unwrap();
 
@@ -383,12 +617,42 @@ pub mod mock {
         async fn test_mock_failing() {
             let mock = MockMcClient::failing();
             let result = mock
-                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1")
+                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1", None)
                 .await;
 
             assert!(result.is_err());
         }
 
+        #[tokio::test]
+        async fn test_mock_promote_standby() {
+            let mock = MockMcClient::accepting();
+            let meetings = vec![
+                StandbyMeeting {
+                    meeting_id: "meeting-1".to_string(),
+                    settings: None,
+                },
+                StandbyMeeting {
+                    meeting_id: "meeting-2".to_string(),
+                    settings: None,
+                },
+            ];
+
+            let promoted = mock
+                .promote_standby("http://mc:50051", "mc-primary", &meetings, "gc-1")
+                .await
+                .unwrap();
+
+            assert_eq!(promoted, vec!["meeting-1", "meeting-2"]);
+            assert_eq!(mock.last_promoted(), vec!["meeting-1", "meeting-2"]);
+            assert_eq!(mock.call_count(), 1);
+
+            let failing = MockMcClient::failing();
+            assert!(failing
+                .promote_standby("http://mc:50051", "mc-primary", &meetings, "gc-1")
+                .await
+                .is_err());
+        }
+
         #[tokio::test]
         async fn test_mock_cycling_responses() {
             let mock = MockMcClient::with_responses(vec![
@@ -399,7 +663,7 @@ pub mod mock {
 
             // First call: AtCapacity
             let r1 = mock
-                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1")
+                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1", None)
                 .await
                 .unwrap();
             assert!(matches!(
@@ -409,7 +673,7 @@ pub mod mock {
 
             // Second call: Draining
             let r2 = mock
-                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1")
+                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1", None)
                 .await
                 .unwrap();
             assert!(matches!(
@@ -419,14 +683,14 @@ pub mod mock {
 
             // Third call: Accepted
             let r3 = mock
-                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1")
+                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1", None)
                 .await
                 .unwrap();
             assert!(matches!(r3, McAssignmentResult::Accepted));
 
             // Fourth call: cycles back to AtCapacity
             let r4 = mock
-                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1")
+                .assign_meeting("http://mc:50051", "meeting-1", &[], "gc-1", None)
                 .await
                 .unwrap();
             assert!(matches!(
@@ -6,21 +6,32 @@
 //! # Components
 //!
 //! - `ac_client` - HTTP client for Auth Controller internal endpoints
+//! - `experiments` - A/B experiment bucketing on meeting join
+//! - `fleet_versions` - MC/MH version tracking and the minimum version check
 //! - `mc_assignment` - Meeting Controller assignment with load balancing
 //! - `mc_client` - gRPC client for GC→MC communication
 //! - `mh_selection` - Media Handler selection for meetings
+//! - `object_store` - Pre-signed URL issuer for meeting assets and recordings
+//! - `standby` - Warm standby MC promotion when a primary fails
 
 pub mod ac_client;
+pub mod experiments;
+pub mod fleet_versions;
 pub mod mc_assignment;
 pub mod mc_client;
 pub mod mh_selection;
+pub mod object_store;
+pub mod standby;
 
+pub use experiments::{ExperimentService, ExperimentSubject};
 pub use mc_assignment::McAssignmentService;
 // MC client types exposed for external use
-pub use mc_client::{McClient, McClientTrait};
+pub use mc_client::{McClient, McClientTrait, StandbyMeeting};
 // Mock MC client for testing (exposed for integration tests)
 #[allow(unused_imports)]
 pub use mc_client::mock::MockMcClient;
 // MH selection types exposed for external/test use
 #[allow(unused_imports)]
 pub use mh_selection::{MhAssignmentInfo, MhSelection, MhSelectionService};
+pub use object_store::ObjectStore;
+pub use standby::{StandbyPromotion, StandbyService};
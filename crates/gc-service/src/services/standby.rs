@@ -0,0 +1,170 @@
+//! Warm standby promotion for failed meeting controllers.
+//!
+//! An MC that registers with `standby_for` takes no meetings of its own; it
+//! pre-loads the journals of its primary's meetings from the meeting IDs GC
+//! sends on each heartbeat ack. When the primary's heartbeat stream breaks,
+//! GC checks whether the primary's gRPC endpoint still answers. If it does
+//! not, the standby is promoted: it takes the meetings over (bumping each
+//! meeting's fencing generation so a half-dead primary cannot write), their
+//! assignments are moved to it, and it becomes an ordinary MC.
+//!
+//! A primary that is only partitioned from this GC, but still reachable,
+//! is left alone; the regular staleness check handles it.
+
+use crate::errors::GcError;
+use crate::repositories::{
+    MeetingAssignmentsRepository, MeetingControllersRepository, MeetingsRepository,
+};
+use crate::services::mc_client::{McClientTrait, StandbyMeeting};
+use sqlx::PgPool;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tracing::{info, instrument, warn};
+use uuid::Uuid;
+
+/// How long the primary's gRPC endpoint gets to accept a connection before
+/// it is considered down.
+const REACHABILITY_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Outcome of a standby promotion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StandbyPromotion {
+    /// The promoted standby.
+    pub standby_id: String,
+    /// Meetings the standby took over and that now point at it.
+    pub moved: Vec<String>,
+    /// Meetings the standby could not take over; they stay assigned to the
+    /// primary and are re-placed by the next join.
+    pub failed: Vec<String>,
+}
+
+/// Service for warm standby failover.
+pub struct StandbyService;
+
+impl StandbyService {
+    /// Promote `primary_id`'s standby if the primary is unreachable.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the primary has no healthy standby, is still reachable, or
+    /// had no active meetings.
+    #[instrument(skip_all, fields(primary_id = %primary_id))]
+    pub async fn promote_if_unreachable(
+        pool: &PgPool,
+        mc_client: &dyn McClientTrait,
+        primary_id: &str,
+        gc_id: &str,
+    ) -> Result<Option<StandbyPromotion>, GcError> {
+        let Some(standby) = MeetingControllersRepository::get_standby(pool, primary_id).await?
+        else {
+            return Ok(None);
+        };
+
+        if let Some(primary) =
+            MeetingControllersRepository::get_controller(pool, primary_id).await?
+        {
+            if endpoint_reachable(&primary.grpc_endpoint).await {
+                info!(
+                    target: "gc.services.standby",
+                    primary_id = %primary_id,
+                    "Primary heartbeat stream broke but endpoint is reachable, not promoting standby"
+                );
+                return Ok(None);
+            }
+        }
+
+        MeetingControllersRepository::mark_unhealthy(pool, primary_id).await?;
+
+        let meeting_ids =
+            MeetingAssignmentsRepository::get_active_meeting_ids(pool, primary_id).await?;
+        if meeting_ids.is_empty() {
+            return Ok(None);
+        }
+
+        let mut meetings = Vec::with_capacity(meeting_ids.len());
+        for meeting_id in meeting_ids {
+            let settings = match Uuid::parse_str(&meeting_id) {
+                Ok(id) => MeetingsRepository::get_mc_meeting_settings(pool, id).await?,
+                Err(_) => None,
+            };
+            meetings.push(StandbyMeeting {
+                meeting_id,
+                settings,
+            });
+        }
+
+        let moved = mc_client
+            .promote_standby(&standby.grpc_endpoint, primary_id, &meetings, gc_id)
+            .await?;
+        MeetingAssignmentsRepository::move_assignments(
+            pool,
+            primary_id,
+            &standby.controller_id,
+            &moved,
+            gc_id,
+        )
+        .await?;
+        MeetingControllersRepository::set_standby_for(pool, &standby.controller_id, None).await?;
+
+        let failed: Vec<String> = meetings
+            .into_iter()
+            .map(|meeting| meeting.meeting_id)
+            .filter(|id| !moved.contains(id))
+            .collect();
+        if !failed.is_empty() {
+            warn!(
+                target: "gc.services.standby",
+                primary_id = %primary_id,
+                standby_id = %standby.controller_id,
+                failed = failed.len(),
+                "Standby did not take over every meeting"
+            );
+        }
+        info!(
+            target: "gc.services.standby",
+            primary_id = %primary_id,
+            standby_id = %standby.controller_id,
+            moved = moved.len(),
+            "Promoted warm standby"
+        );
+
+        Ok(Some(StandbyPromotion {
+            standby_id: standby.controller_id,
+            moved,
+            failed,
+        }))
+    }
+}
+
+/// Whether a TCP connection to a gRPC endpoint (`http://host:port`) opens
+/// within [`REACHABILITY_TIMEOUT`].
+async fn endpoint_reachable(grpc_endpoint: &str) -> bool {
+    let authority = grpc_endpoint
+        .split_once("://")
+        .map_or(grpc_endpoint, |(_, rest)| rest);
+    let authority = authority.split('/').next().unwrap_or(authority);
+
+    matches!(
+        tokio::time::timeout(REACHABILITY_TIMEOUT, TcpStream::connect(authority)).await,
+        Ok(Ok(_))
+    )
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_endpoint_reachable() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        assert!(endpoint_reachable(&format!("http://{addr}")).await);
+        assert!(endpoint_reachable(&format!("http://{addr}/")).await);
+
+        drop(listener);
+        assert!(!endpoint_reachable(&format!("http://{addr}")).await);
+    }
+}
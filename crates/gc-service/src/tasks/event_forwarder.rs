@@ -0,0 +1,204 @@
+//! Event forwarder background task.
+//!
+//! Subscribes to the in-process event bus and forwards every event to the
+//! configured external publisher (NATS/Kafka). Publish failures are logged
+//! and the event is skipped; the forwarder never blocks the bus or handlers.
+//!
+//! # Graceful Shutdown
+//!
+//! The task supports graceful shutdown via a cancellation token. When the token
+//! is cancelled, the task finishes the in-flight publish and exits.
+
+use crate::events::publisher::EventPublisher;
+use crate::events::GcEvent;
+use std::sync::Arc;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, instrument, warn};
+
+/// Start the event forwarder background task.
+///
+/// # Arguments
+///
+/// * `events` - Bus subscription (subscribe before starting publishers so no
+///   events are missed at startup)
+/// * `publisher` - External publisher to forward to
+/// * `cancel_token` - Token for graceful shutdown
+#[instrument(skip_all, name = "gc.task.event_forwarder")]
+pub async fn start_event_forwarder(
+    mut events: broadcast::Receiver<GcEvent>,
+    publisher: Arc<dyn EventPublisher>,
+    cancel_token: CancellationToken,
+) {
+    info!(
+        target: "gc.task.event_forwarder",
+        backend = publisher.backend(),
+        "Starting event forwarder task"
+    );
+
+    loop {
+        tokio::select! {
+            received = events.recv() => match received {
+                Ok(event) => forward(publisher.as_ref(), &event).await,
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(
+                        target: "gc.task.event_forwarder",
+                        backend = publisher.backend(),
+                        skipped = skipped,
+                        "Event forwarder lagged behind event bus, events skipped"
+                    );
+                }
+                Err(RecvError::Closed) => {
+                    info!(
+                        target: "gc.task.event_forwarder",
+                        "Event bus closed, exiting"
+                    );
+                    break;
+                }
+            },
+            _ = cancel_token.cancelled() => {
+                info!(
+                    target: "gc.task.event_forwarder",
+                    "Event forwarder task received shutdown signal, exiting"
+                );
+                break;
+            }
+        }
+    }
+
+    info!(target: "gc.task.event_forwarder", "Event forwarder task stopped");
+}
+
+async fn forward(publisher: &dyn EventPublisher, event: &GcEvent) {
+    if let Err(e) = publisher.publish(event).await {
+        warn!(
+            target: "gc.task.event_forwarder",
+            backend = publisher.backend(),
+            event = event.name(),
+            meeting_id = %event.meeting_id(),
+            error = %e,
+            "Failed to forward event to external publisher"
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::events::publisher::PublishError;
+    use crate::events::EventBus;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    /// Publisher that records events and can be told to fail.
+    #[derive(Default)]
+    struct RecordingPublisher {
+        published: Mutex<Vec<GcEvent>>,
+        fail: AtomicBool,
+    }
+
+    #[async_trait]
+    impl EventPublisher for RecordingPublisher {
+        fn backend(&self) -> &'static str {
+            "recording"
+        }
+
+        async fn publish(&self, event: &GcEvent) -> Result<(), PublishError> {
+            if self.fail.load(Ordering::SeqCst) {
+                return Err(PublishError::Publish("broker down".to_string()));
+            }
+            self.published.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    fn event() -> GcEvent {
+        GcEvent::MeetingSettingsUpdated {
+            meeting_id: Uuid::new_v4(),
+            updated_by: Uuid::new_v4(),
+        }
+    }
+
+    async fn wait_for_count(publisher: &RecordingPublisher, count: usize) {
+        for _ in 0..100 {
+            if publisher.published.lock().unwrap().len() >= count {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("timed out waiting for {} forwarded events", count);
+    }
+
+    #[tokio::test]
+    async fn test_forwards_bus_events_in_order() {
+        let bus = EventBus::default();
+        let publisher = Arc::new(RecordingPublisher::default());
+        let cancel_token = CancellationToken::new();
+
+        let handle = tokio::spawn(start_event_forwarder(
+            bus.subscribe(),
+            publisher.clone(),
+            cancel_token.clone(),
+        ));
+
+        let events = vec![event(), event()];
+        for e in &events {
+            bus.publish(e.clone());
+        }
+
+        wait_for_count(&publisher, 2).await;
+        assert_eq!(*publisher.published.lock().unwrap(), events);
+
+        cancel_token.cancel();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_publish_failure_does_not_stop_forwarder() {
+        let bus = EventBus::default();
+        let publisher = Arc::new(RecordingPublisher::default());
+        publisher.fail.store(true, Ordering::SeqCst);
+        let cancel_token = CancellationToken::new();
+
+        let handle = tokio::spawn(start_event_forwarder(
+            bus.subscribe(),
+            publisher.clone(),
+            cancel_token.clone(),
+        ));
+
+        bus.publish(event());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        publisher.fail.store(false, Ordering::SeqCst);
+
+        let delivered = event();
+        bus.publish(delivered.clone());
+
+        wait_for_count(&publisher, 1).await;
+        assert_eq!(*publisher.published.lock().unwrap(), vec![delivered]);
+
+        cancel_token.cancel();
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_exits_when_bus_dropped() {
+        let bus = EventBus::default();
+        let rx = bus.subscribe();
+        drop(bus);
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(5),
+            start_event_forwarder(
+                rx,
+                Arc::new(RecordingPublisher::default()),
+                CancellationToken::new(),
+            ),
+        )
+        .await;
+        assert!(result.is_ok(), "Forwarder should exit when bus is closed");
+    }
+}
@@ -0,0 +1,110 @@
+//! Event metering background task.
+//!
+//! In-process usage metering consumer of the event bus: counts every meeting
+//! lifecycle event in `gc_meeting_events_total`, labelled by event name. It is
+//! always running, so the outbox relay has a subscriber to deliver to even
+//! when no external publisher is configured.
+//!
+//! # Graceful Shutdown
+//!
+//! The task supports graceful shutdown via a cancellation token. When the token
+//! is cancelled, the task exits after the event being recorded.
+
+use crate::events::GcEvent;
+use crate::observability::metrics;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, instrument, warn};
+
+/// Start the event metering background task.
+///
+/// # Arguments
+///
+/// * `events` - Bus subscription (subscribe before starting the outbox relay
+///   so no events are missed at startup)
+/// * `cancel_token` - Token for graceful shutdown
+#[instrument(skip_all, name = "gc.task.event_metering")]
+pub async fn start_event_metering(
+    mut events: broadcast::Receiver<GcEvent>,
+    cancel_token: CancellationToken,
+) {
+    info!(target: "gc.task.event_metering", "Starting event metering task");
+
+    loop {
+        tokio::select! {
+            received = events.recv() => match received {
+                Ok(event) => metrics::record_meeting_event(event.name()),
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(
+                        target: "gc.task.event_metering",
+                        skipped = skipped,
+                        "Event metering lagged behind event bus, events not counted"
+                    );
+                }
+                Err(RecvError::Closed) => {
+                    info!(
+                        target: "gc.task.event_metering",
+                        "Event bus closed, exiting"
+                    );
+                    break;
+                }
+            },
+            _ = cancel_token.cancelled() => {
+                info!(
+                    target: "gc.task.event_metering",
+                    "Event metering task received shutdown signal, exiting"
+                );
+                break;
+            }
+        }
+    }
+
+    info!(target: "gc.task.event_metering", "Event metering task stopped");
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::events::EventBus;
+    use common::observability::testing::MetricAssertion;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_counts_bus_events_by_name() {
+        let snap = MetricAssertion::snapshot();
+        let bus = EventBus::default();
+        let cancel_token = CancellationToken::new();
+
+        let handle = tokio::spawn(start_event_metering(bus.subscribe(), cancel_token.clone()));
+
+        let meeting_id = Uuid::new_v4();
+        assert_eq!(
+            bus.publish(GcEvent::MeetingSettingsUpdated {
+                meeting_id,
+                updated_by: Uuid::new_v4(),
+            }),
+            1
+        );
+        bus.publish(GcEvent::AssignmentChanged {
+            meeting_id,
+            mc_id: "mc-1".to_string(),
+            region: "us-east-1".to_string(),
+        });
+
+        // Closing the bus lets the task drain both events and exit
+        drop(bus);
+        tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("Metering task should exit when bus is closed")
+            .unwrap();
+
+        snap.counter("gc_meeting_events_total")
+            .with_labels(&[("event", "meeting_settings_updated")])
+            .assert_delta(1);
+        snap.counter("gc_meeting_events_total")
+            .with_labels(&[("event", "assignment_changed")])
+            .assert_delta(1);
+    }
+}
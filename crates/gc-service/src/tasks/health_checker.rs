@@ -14,6 +14,7 @@
 
 use crate::observability::metrics;
 use crate::repositories::MeetingControllersRepository;
+use crate::services::fleet_versions::refresh_fleet_version_metrics;
 use crate::tasks::generic_health_checker::{
     start_generic_health_checker, DEFAULT_CHECK_INTERVAL_SECONDS,
 };
@@ -92,6 +93,7 @@ pub async fn start_health_checker(
                     .await;
             if result.is_ok() {
                 refresh_controller_metrics(&pool).await;
+                refresh_fleet_version_metrics(&pool).await;
             }
             result
         },
@@ -0,0 +1,374 @@
+//! Meeting import worker background task.
+//!
+//! Creates the meetings of accepted bulk imports:
+//! 1. Claims the oldest running import job (`FOR UPDATE SKIP LOCKED`)
+//! 2. Generates a meeting code and join secret for every record
+//! 3. Creates all meetings and marks the job succeeded in one transaction
+//!
+//! An import the org cannot take (org deactivated, concurrent meeting limit
+//! exceeded) is marked failed with nothing created. A meeting code collision
+//! rolls the whole import back; it is retried with fresh codes on the next
+//! poll. A crash mid-import leaves the job running, so another GC instance
+//! or the next poll picks it up again.
+//!
+//! # Graceful Shutdown
+//!
+//! The task supports graceful shutdown via a cancellation token. When the token
+//! is cancelled, the task completes its current iteration and exits cleanly.
+
+use crate::errors::GcError;
+use crate::handlers::meetings::{generate_join_token_secret, generate_meeting_code};
+use crate::observability::metrics;
+use crate::repositories::{MeetingImportOutcome, MeetingImportsRepository, PreparedImportMeeting};
+use sqlx::PgPool;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, instrument, warn};
+
+/// Default poll interval in seconds.
+const DEFAULT_POLL_INTERVAL_SECONDS: u64 = 5;
+
+/// Configuration for the meeting import worker.
+#[derive(Debug, Clone)]
+pub struct MeetingImportConfig {
+    /// Poll interval in seconds.
+    pub poll_interval_seconds: u64,
+}
+
+impl Default for MeetingImportConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_seconds: DEFAULT_POLL_INTERVAL_SECONDS,
+        }
+    }
+}
+
+impl MeetingImportConfig {
+    /// Create config from environment variables.
+    ///
+    /// Environment variables:
+    /// - `GC_IMPORT_POLL_INTERVAL_SECONDS` - Poll interval (default: 5)
+    pub fn from_env() -> Self {
+        let poll_interval_seconds = std::env::var("GC_IMPORT_POLL_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_POLL_INTERVAL_SECONDS);
+
+        Self {
+            poll_interval_seconds,
+        }
+    }
+}
+
+/// Start the meeting import worker background task.
+///
+/// # Arguments
+///
+/// * `pool` - Database connection pool
+/// * `config` - Worker configuration
+/// * `cancel_token` - Token for graceful shutdown
+///
+/// # Returns
+///
+/// Returns when the cancellation token is triggered.
+#[instrument(skip_all, name = "gc.task.meeting_imports")]
+pub async fn start_meeting_import_worker(
+    pool: PgPool,
+    config: MeetingImportConfig,
+    cancel_token: CancellationToken,
+) {
+    info!(
+        target: "gc.task.meeting_imports",
+        poll_interval_seconds = config.poll_interval_seconds,
+        "Starting meeting import worker"
+    );
+
+    let mut interval = tokio::time::interval(Duration::from_secs(config.poll_interval_seconds));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {
+                // Keep going while jobs are finished so queued imports do
+                // not each wait one poll interval.
+                loop {
+                    match process_next_import(&pool).await {
+                        Ok(true) => continue,
+                        Ok(false) => break,
+                        Err(e) => {
+                            tracing::error!(
+                                target: "gc.task.meeting_imports",
+                                error = %e,
+                                "Failed to process meeting import"
+                            );
+                            break;
+                        }
+                    }
+                }
+            }
+            _ = cancel_token.cancelled() => {
+                info!(
+                    target: "gc.task.meeting_imports",
+                    "Meeting import worker received shutdown signal, exiting"
+                );
+                break;
+            }
+        }
+    }
+
+    info!(target: "gc.task.meeting_imports", "Meeting import worker stopped");
+}
+
+/// Claim and run one import job.
+///
+/// Returns `true` if a job was finished (succeeded or failed), `false` if
+/// there was no job to claim or the job was left running for a retry.
+pub(crate) async fn process_next_import(pool: &PgPool) -> Result<bool, GcError> {
+    let mut tx = pool.begin().await?;
+
+    let Some(pending) = MeetingImportsRepository::claim_next(&mut tx).await? else {
+        return Ok(false);
+    };
+    let job = pending.job.clone();
+
+    let records = match pending.decode() {
+        Ok(records) => records,
+        Err(e) => {
+            warn!(
+                target: "gc.task.meeting_imports",
+                job_id = %job.job_id,
+                error = %e,
+                "Stored import records are undecodable"
+            );
+            MeetingImportsRepository::fail_job(&mut tx, job.job_id, "Import records unreadable")
+                .await?;
+            tx.commit().await?;
+            metrics::record_meeting_import("failed", 0);
+            return Ok(true);
+        }
+    };
+
+    let mut meetings = Vec::with_capacity(records.len());
+    for record in records {
+        meetings.push(PreparedImportMeeting {
+            record,
+            meeting_code: generate_meeting_code()?,
+            join_token_secret: generate_join_token_secret()?,
+        });
+    }
+
+    let error = match MeetingImportsRepository::import_meetings(&mut tx, &job, &meetings).await? {
+        MeetingImportOutcome::Imported(count) => {
+            let imported = i32::try_from(count).unwrap_or(i32::MAX);
+            MeetingImportsRepository::complete_job(&mut tx, job.job_id, imported).await?;
+            tx.commit().await?;
+
+            metrics::record_meeting_import("succeeded", count as u64);
+            info!(
+                target: "gc.task.meeting_imports",
+                job_id = %job.job_id,
+                org_id = %job.org_id,
+                imported_records = count,
+                "Meeting import succeeded"
+            );
+            return Ok(true);
+        }
+        MeetingImportOutcome::CodeCollision => {
+            // Dropping the transaction rolls back the meetings created so
+            // far; the job stays running and gets fresh codes next time.
+            warn!(
+                target: "gc.task.meeting_imports",
+                job_id = %job.job_id,
+                "Meeting code collision during import, will retry"
+            );
+            return Ok(false);
+        }
+        MeetingImportOutcome::OrgInactive => "Organization is not active".to_string(),
+        MeetingImportOutcome::LimitExceeded { limit, existing } => format!(
+            "Import of {} meetings exceeds the organization's limit of {} \
+             concurrent meetings ({} already scheduled or active)",
+            meetings.len(),
+            limit,
+            existing
+        ),
+    };
+
+    // Nothing was inserted before these outcomes were detected.
+    MeetingImportsRepository::fail_job(&mut tx, job.job_id, &error).await?;
+    tx.commit().await?;
+
+    metrics::record_meeting_import("failed", 0);
+    info!(
+        target: "gc.task.meeting_imports",
+        job_id = %job.job_id,
+        org_id = %job.org_id,
+        error = %error,
+        "Meeting import failed"
+    );
+
+    Ok(true)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // Mutex to ensure env var tests don't run in parallel
+    static ENV_MUTEX: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_default_config() {
+        let config = MeetingImportConfig::default();
+        assert_eq!(config.poll_interval_seconds, DEFAULT_POLL_INTERVAL_SECONDS);
+    }
+
+    #[test]
+    fn test_from_env_with_valid_and_zero_values() {
+        let _guard = ENV_MUTEX.lock().unwrap();
+
+        std::env::set_var("GC_IMPORT_POLL_INTERVAL_SECONDS", "2");
+        let config = MeetingImportConfig::from_env();
+        assert_eq!(config.poll_interval_seconds, 2);
+
+        std::env::set_var("GC_IMPORT_POLL_INTERVAL_SECONDS", "0");
+        let config = MeetingImportConfig::from_env();
+        std::env::remove_var("GC_IMPORT_POLL_INTERVAL_SECONDS");
+        assert_eq!(config.poll_interval_seconds, DEFAULT_POLL_INTERVAL_SECONDS);
+    }
+}
+
+/// Integration tests for the meeting import worker requiring database.
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod integration_tests {
+    use super::*;
+    use crate::models::{MeetingImportRecord, MeetingImportStatus};
+    use uuid::Uuid;
+
+    /// Create an org and an admin user; returns (org_id, user_id).
+    async fn create_org(pool: &PgPool, max_concurrent_meetings: i32) -> (Uuid, Uuid) {
+        let (org_id,): (Uuid,) = sqlx::query_as(
+            r#"
+            INSERT INTO organizations (subdomain, display_name, max_concurrent_meetings)
+            VALUES ($1, 'Import Org', $2)
+            RETURNING org_id
+            "#,
+        )
+        .bind(format!("import-{}", Uuid::new_v4().simple()))
+        .bind(max_concurrent_meetings)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+        let (user_id,): (Uuid,) = sqlx::query_as(
+            r#"
+            INSERT INTO users (org_id, email, password_hash, display_name)
+            VALUES ($1, 'admin@example.com', 'hash', 'Admin')
+            RETURNING user_id
+            "#,
+        )
+        .bind(org_id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+        (org_id, user_id)
+    }
+
+    fn record(external_id: &str) -> MeetingImportRecord {
+        MeetingImportRecord {
+            external_id: Some(external_id.to_string()),
+            display_name: format!("Imported {external_id}"),
+            max_participants: None,
+            scheduled_start_time: None,
+            enable_e2e_encryption: None,
+            require_auth: None,
+            recording_enabled: None,
+            allow_guests: None,
+            allow_external_participants: None,
+            waiting_room_enabled: None,
+            priority: None,
+        }
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_import_creates_all_meetings(pool: PgPool) {
+        let (org_id, user_id) = create_org(&pool, 10).await;
+        let job = MeetingImportsRepository::create_job(
+            &pool,
+            org_id,
+            user_id,
+            &[record("a"), record("b"), record("c")],
+        )
+        .await
+        .unwrap();
+
+        assert!(process_next_import(&pool).await.unwrap());
+        assert!(!process_next_import(&pool).await.unwrap());
+
+        let job = MeetingImportsRepository::get_job(&pool, org_id, job.job_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(job.status, MeetingImportStatus::Succeeded);
+        assert_eq!(job.imported_records, 3);
+        assert!(job.completed_at.is_some());
+
+        let meetings = MeetingImportsRepository::list_imported_meetings(&pool, job.job_id)
+            .await
+            .unwrap();
+        let external_ids: Vec<_> = meetings
+            .iter()
+            .map(|m| m.external_id.as_deref().unwrap())
+            .collect();
+        assert_eq!(external_ids, vec!["a", "b", "c"]);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_import_over_limit_fails_without_creating(pool: PgPool) {
+        let (org_id, user_id) = create_org(&pool, 2).await;
+        let job = MeetingImportsRepository::create_job(
+            &pool,
+            org_id,
+            user_id,
+            &[record("a"), record("b"), record("c")],
+        )
+        .await
+        .unwrap();
+
+        assert!(process_next_import(&pool).await.unwrap());
+
+        let job = MeetingImportsRepository::get_job(&pool, org_id, job.job_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(job.status, MeetingImportStatus::Failed);
+        assert_eq!(job.imported_records, 0);
+        assert!(job.error.unwrap().contains("limit of 2"));
+        assert!(
+            MeetingImportsRepository::list_imported_meetings(&pool, job.job_id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_worker_stops_on_cancel(pool: PgPool) {
+        let cancel_token = CancellationToken::new();
+        let handle = tokio::spawn(start_meeting_import_worker(
+            pool,
+            MeetingImportConfig::default(),
+            cancel_token.clone(),
+        ));
+
+        cancel_token.cancel();
+        let result = tokio::time::timeout(Duration::from_secs(5), handle).await;
+        assert!(
+            result.is_ok(),
+            "Import worker should stop after cancellation"
+        );
+    }
+}
@@ -0,0 +1,430 @@
+//! Meeting report rollup background task.
+//!
+//! Periodically computes post-meeting analytics (duration, peak participants,
+//! join failures, average QoE) for meetings whose assignments have all ended,
+//! and stores them in `meeting_reports`.
+//!
+//! The check interval must stay well below the assignment retention period
+//! (`GC_RETENTION_DAYS`), since ended assignments are the rollup input.
+//!
+//! # Graceful Shutdown
+//!
+//! The task supports graceful shutdown via a cancellation token. When the token
+//! is cancelled, the task completes its current iteration and exits cleanly.
+
+use crate::repositories::MeetingReportsRepository;
+use sqlx::PgPool;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, instrument};
+
+/// Default rollup check interval in seconds.
+const DEFAULT_CHECK_INTERVAL_SECONDS: u64 = 60;
+
+/// Default maximum reports computed per iteration.
+const DEFAULT_BATCH_SIZE: i64 = 100;
+
+/// Configuration for meeting report rollup task.
+#[derive(Debug, Clone)]
+pub struct MeetingReportConfig {
+    /// Rollup check interval in seconds.
+    pub check_interval_seconds: u64,
+    /// Maximum reports computed per iteration.
+    pub batch_size: i64,
+}
+
+impl Default for MeetingReportConfig {
+    fn default() -> Self {
+        Self {
+            check_interval_seconds: DEFAULT_CHECK_INTERVAL_SECONDS,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+}
+
+impl MeetingReportConfig {
+    /// Create config from environment variables.
+    ///
+    /// Environment variables:
+    /// - `GC_REPORT_INTERVAL_SECONDS` - Rollup check interval (default: 60)
+    /// - `GC_REPORT_BATCH_SIZE` - Reports per iteration (default: 100)
+    pub fn from_env() -> Self {
+        let check_interval_seconds = std::env::var("GC_REPORT_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_CHECK_INTERVAL_SECONDS);
+
+        let batch_size = std::env::var("GC_REPORT_BATCH_SIZE")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_BATCH_SIZE);
+
+        Self {
+            check_interval_seconds,
+            batch_size,
+        }
+    }
+}
+
+/// Start the meeting report rollup background task.
+///
+/// # Arguments
+///
+/// * `pool` - Database connection pool
+/// * `config` - Rollup configuration
+/// * `cancel_token` - Token for graceful shutdown
+///
+/// # Returns
+///
+/// Returns when the cancellation token is triggered.
+#[instrument(skip_all, name = "gc.task.meeting_reports")]
+pub async fn start_meeting_report_rollup(
+    pool: PgPool,
+    config: MeetingReportConfig,
+    cancel_token: CancellationToken,
+) {
+    info!(
+        target: "gc.task.meeting_reports",
+        check_interval_seconds = config.check_interval_seconds,
+        batch_size = config.batch_size,
+        "Starting meeting report rollup task"
+    );
+
+    let mut interval = tokio::time::interval(Duration::from_secs(config.check_interval_seconds));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {
+                run_rollup(&pool, &config).await;
+            }
+            _ = cancel_token.cancelled() => {
+                info!(
+                    target: "gc.task.meeting_reports",
+                    "Meeting report rollup task received shutdown signal, exiting"
+                );
+                break;
+            }
+        }
+    }
+
+    info!(
+        target: "gc.task.meeting_reports",
+        "Meeting report rollup task stopped"
+    );
+}
+
+/// Run a single rollup iteration.
+///
+/// This is separated from the main loop to allow direct testing.
+pub(crate) async fn run_rollup(pool: &PgPool, config: &MeetingReportConfig) {
+    match MeetingReportsRepository::compute_pending(pool, Some(config.batch_size)).await {
+        Ok(count) => {
+            if count > 0 {
+                info!(
+                    target: "gc.task.meeting_reports",
+                    report_count = count,
+                    "Computed meeting reports"
+                );
+            }
+        }
+        Err(e) => {
+            tracing::error!(
+                target: "gc.task.meeting_reports",
+                error = %e,
+                "Failed to compute meeting reports"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // Mutex to ensure env var tests don't run in parallel
+    static ENV_MUTEX: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_default_config() {
+        let config = MeetingReportConfig::default();
+        assert_eq!(config.check_interval_seconds, 60);
+        assert_eq!(config.batch_size, 100);
+    }
+
+    #[test]
+    fn test_from_env_with_valid_values() {
+        let _guard = ENV_MUTEX.lock().unwrap();
+
+        std::env::set_var("GC_REPORT_INTERVAL_SECONDS", "300");
+        std::env::set_var("GC_REPORT_BATCH_SIZE", "25");
+
+        let config = MeetingReportConfig::from_env();
+
+        std::env::remove_var("GC_REPORT_INTERVAL_SECONDS");
+        std::env::remove_var("GC_REPORT_BATCH_SIZE");
+
+        assert_eq!(config.check_interval_seconds, 300);
+        assert_eq!(config.batch_size, 25);
+    }
+
+    #[test]
+    fn test_from_env_with_invalid_values_uses_defaults() {
+        let _guard = ENV_MUTEX.lock().unwrap();
+
+        std::env::set_var("GC_REPORT_INTERVAL_SECONDS", "soon");
+        std::env::set_var("GC_REPORT_BATCH_SIZE", "");
+
+        let config = MeetingReportConfig::from_env();
+
+        std::env::remove_var("GC_REPORT_INTERVAL_SECONDS");
+        std::env::remove_var("GC_REPORT_BATCH_SIZE");
+
+        assert_eq!(
+            config.check_interval_seconds,
+            DEFAULT_CHECK_INTERVAL_SECONDS
+        );
+        assert_eq!(config.batch_size, DEFAULT_BATCH_SIZE);
+    }
+}
+
+/// Integration tests for meeting report rollup requiring database.
+#[cfg(test)]
+mod integration_tests {
+    use super::*;
+    use crate::repositories::{MeetingAssignmentsRepository, MeetingControllersRepository};
+    use uuid::Uuid;
+
+    /// Create an org, host, and meeting; returns the meeting ID.
+    async fn create_meeting(pool: &PgPool, code: &str) -> Uuid {
+        let (org_id,): (Uuid,) = sqlx::query_as(
+            "INSERT INTO organizations (subdomain, display_name) VALUES ($1, 'Report Org') RETURNING org_id",
+        )
+        .bind(format!("report-{}", code.to_lowercase()))
+        .fetch_one(pool)
+        .await
+        .expect("Failed to create org");
+
+        let (user_id,): (Uuid,) = sqlx::query_as(
+            r#"
+            INSERT INTO users (org_id, email, password_hash, display_name)
+            VALUES ($1, $2, 'hash', 'Host')
+            RETURNING user_id
+            "#,
+        )
+        .bind(org_id)
+        .bind(format!("host-{}@test.com", code.to_lowercase()))
+        .fetch_one(pool)
+        .await
+        .expect("Failed to create user");
+
+        let (meeting_id,): (Uuid,) = sqlx::query_as(
+            r#"
+            INSERT INTO meetings (org_id, created_by_user_id, display_name, meeting_code, join_token_secret)
+            VALUES ($1, $2, 'Report Meeting', $3, 'secret')
+            RETURNING meeting_id
+            "#,
+        )
+        .bind(org_id)
+        .bind(user_id)
+        .bind(code)
+        .fetch_one(pool)
+        .await
+        .expect("Failed to create meeting");
+
+        meeting_id
+    }
+
+    async fn assign(pool: &PgPool, meeting_id: Uuid, region: &str, minutes_ago: i32) {
+        let mc_id = format!("report-mc-{}", region);
+        MeetingControllersRepository::register_mc(
+            pool,
+            &mc_id,
+            region,
+            "https://report-mc:50051",
+            None,
+            100,
+            1000,
+        )
+        .await
+        .expect("Failed to register controller");
+
+        sqlx::query(
+            r#"
+            INSERT INTO meeting_assignments (meeting_id, meeting_controller_id, region, assigned_by_gc_id, assigned_at)
+            VALUES ($1, $2, $3, 'gc-test', NOW() - $4 * INTERVAL '1 minute')
+            "#,
+        )
+        .bind(meeting_id.to_string())
+        .bind(&mc_id)
+        .bind(region)
+        .bind(minutes_ago)
+        .execute(pool)
+        .await
+        .expect("Failed to create assignment");
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_rollup_computes_report_after_all_regions_end(pool: PgPool) {
+        let meeting_id = create_meeting(&pool, "RPT001").await;
+        let meeting_key = meeting_id.to_string();
+        assign(&pool, meeting_id, "us-east-1", 30).await;
+        assign(&pool, meeting_id, "eu-west-1", 20).await;
+
+        MeetingAssignmentsRepository::record_telemetry(
+            &pool,
+            &meeting_key,
+            "us-east-1",
+            8,
+            1,
+            Some(4.0),
+        )
+        .await
+        .unwrap();
+        MeetingAssignmentsRepository::end_assignment(&pool, &meeting_key, Some("us-east-1"))
+            .await
+            .unwrap();
+
+        // One region still active: no report yet
+        run_rollup(&pool, &MeetingReportConfig::default()).await;
+        assert!(MeetingReportsRepository::get_report(&pool, meeting_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        MeetingAssignmentsRepository::record_telemetry(
+            &pool,
+            &meeting_key,
+            "eu-west-1",
+            4,
+            2,
+            Some(3.0),
+        )
+        .await
+        .unwrap();
+        MeetingAssignmentsRepository::end_assignment(&pool, &meeting_key, Some("eu-west-1"))
+            .await
+            .unwrap();
+
+        run_rollup(&pool, &MeetingReportConfig::default()).await;
+
+        let report = MeetingReportsRepository::get_report(&pool, meeting_id)
+            .await
+            .unwrap()
+            .expect("Report should be computed once all regions end");
+        assert_eq!(report.peak_participants, 12);
+        assert_eq!(report.join_failures, 3);
+        assert_eq!(report.avg_qoe_score, Some(3.5));
+        assert!(report.duration_seconds >= 30 * 60 - 5);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_rollup_without_telemetry_uses_zero_counts(pool: PgPool) {
+        let meeting_id = create_meeting(&pool, "RPT002").await;
+        assign(&pool, meeting_id, "us-east-1", 5).await;
+        MeetingAssignmentsRepository::end_assignment(&pool, &meeting_id.to_string(), None)
+            .await
+            .unwrap();
+
+        run_rollup(&pool, &MeetingReportConfig::default()).await;
+
+        let report = MeetingReportsRepository::get_report(&pool, meeting_id)
+            .await
+            .unwrap()
+            .expect("Report should exist");
+        assert_eq!(report.peak_participants, 0);
+        assert_eq!(report.join_failures, 0);
+        assert_eq!(report.avg_qoe_score, None);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_rollup_is_idempotent(pool: PgPool) {
+        let meeting_id = create_meeting(&pool, "RPT003").await;
+        assign(&pool, meeting_id, "us-east-1", 5).await;
+        MeetingAssignmentsRepository::end_assignment(&pool, &meeting_id.to_string(), None)
+            .await
+            .unwrap();
+
+        let first = MeetingReportsRepository::compute_pending(&pool, None)
+            .await
+            .unwrap();
+        let second = MeetingReportsRepository::compute_pending(&pool, None)
+            .await
+            .unwrap();
+
+        assert_eq!(first, 1);
+        assert_eq!(second, 0, "Unchanged meetings should not be recomputed");
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_live_stream_seconds_summed_and_refreshed(pool: PgPool) {
+        let meeting_id = create_meeting(&pool, "RPT004").await;
+        let meeting_key = meeting_id.to_string();
+        assign(&pool, meeting_id, "us-east-1", 10).await;
+        assign(&pool, meeting_id, "eu-west-1", 10).await;
+
+        MeetingAssignmentsRepository::record_live_stream_seconds(
+            &pool,
+            &meeting_key,
+            "us-east-1",
+            120,
+        )
+        .await
+        .unwrap();
+        MeetingAssignmentsRepository::end_assignment(&pool, &meeting_key, None)
+            .await
+            .unwrap();
+        run_rollup(&pool, &MeetingReportConfig::default()).await;
+
+        let report = MeetingReportsRepository::get_report(&pool, meeting_id)
+            .await
+            .unwrap()
+            .expect("Report should exist");
+        assert_eq!(report.live_stream_seconds, 120);
+
+        // The other region's attendance flush arrives after the rollup
+        MeetingAssignmentsRepository::record_live_stream_seconds(
+            &pool,
+            &meeting_key,
+            "eu-west-1",
+            30,
+        )
+        .await
+        .unwrap();
+        MeetingReportsRepository::refresh_live_stream_seconds(&pool, meeting_id)
+            .await
+            .unwrap();
+
+        let report = MeetingReportsRepository::get_report(&pool, meeting_id)
+            .await
+            .unwrap()
+            .expect("Report should exist");
+        assert_eq!(report.live_stream_seconds, 150);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_meeting_report_rollup_starts_and_stops(pool: PgPool) {
+        let cancel_token = CancellationToken::new();
+        let config = MeetingReportConfig {
+            check_interval_seconds: 1,
+            batch_size: 10,
+        };
+
+        let handle = tokio::spawn(start_meeting_report_rollup(
+            pool,
+            config,
+            cancel_token.clone(),
+        ));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        cancel_token.cancel();
+
+        let result = tokio::time::timeout(Duration::from_secs(2), handle).await;
+        assert!(
+            result.is_ok(),
+            "Meeting report rollup should stop within 2 seconds after cancellation"
+        );
+        result.unwrap().expect("Task should not panic");
+    }
+}
@@ -13,6 +13,7 @@
 //! is cancelled, the task completes its current iteration and exits cleanly.
 
 use crate::repositories::MediaHandlersRepository;
+use crate::services::fleet_versions::refresh_fleet_version_metrics;
 use crate::tasks::generic_health_checker::{
     start_generic_health_checker, DEFAULT_CHECK_INTERVAL_SECONDS,
 };
@@ -52,7 +53,12 @@ pub async fn start_mh_health_checker(
         cancel_token,
         "handlers",
         |pool, threshold| async move {
-            MediaHandlersRepository::mark_stale_handlers_unhealthy(&pool, threshold).await
+            let result =
+                MediaHandlersRepository::mark_stale_handlers_unhealthy(&pool, threshold).await;
+            if result.is_ok() {
+                refresh_fleet_version_metrics(&pool).await;
+            }
+            result
         },
     )
     .instrument(tracing::info_span!("gc.task.mh_health_checker"))
@@ -8,12 +8,30 @@
 //! - `mh_health_checker` - Monitors MH heartbeats and marks stale handlers unhealthy
 //! - `generic_health_checker` - Shared health checker loop used by MC and MH checkers
 //! - `assignment_cleanup` - Cleans up stale and old meeting assignments
+//! - `meeting_reports` - Rolls up ended meetings into post-meeting reports
+//! - `meeting_imports` - Creates the meetings of accepted bulk imports
+//! - `recording_retention` - Removes deleted and expired meeting recordings
+//! - `outbox_relay` - Publishes transactional outbox events to the event bus
+//! - `event_metering` - Counts event bus events for usage metering
+//! - `event_forwarder` - Forwards event bus events to an external message queue
 
 pub mod assignment_cleanup;
+pub mod event_forwarder;
+pub mod event_metering;
 pub mod generic_health_checker;
 pub mod health_checker;
+pub mod meeting_imports;
+pub mod meeting_reports;
 pub mod mh_health_checker;
+pub mod outbox_relay;
+pub mod recording_retention;
 
 pub use assignment_cleanup::{start_assignment_cleanup, AssignmentCleanupConfig};
+pub use event_forwarder::start_event_forwarder;
+pub use event_metering::start_event_metering;
 pub use health_checker::start_health_checker;
+pub use meeting_imports::{start_meeting_import_worker, MeetingImportConfig};
+pub use meeting_reports::{start_meeting_report_rollup, MeetingReportConfig};
 pub use mh_health_checker::start_mh_health_checker;
+pub use outbox_relay::{start_outbox_relay, OutboxRelayConfig};
+pub use recording_retention::{start_recording_retention, RecordingRetentionConfig};
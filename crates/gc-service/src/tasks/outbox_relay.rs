@@ -0,0 +1,452 @@
+//! Event outbox relay background task.
+//!
+//! Periodically drains the `event_outbox` table into the in-process event bus:
+//! 1. Claims a batch of pending rows (`FOR UPDATE SKIP LOCKED`)
+//! 2. Publishes each decoded event to the `EventBus`
+//! 3. Marks the delivered rows published and commits
+//!
+//! A crash between publish and commit leaves the rows pending, so they are
+//! re-published on the next iteration (at-least-once delivery).
+//!
+//! The bus drops events nobody is subscribed to. When a publish reaches no
+//! subscriber, that row and the rest of the batch stay pending and are
+//! retried on the next poll, so events are not lost while consumers are
+//! starting up and are still relayed in order. GC always runs the usage
+//! metering consumer (`tasks::event_metering`), with or without an external
+//! publisher, so rows do not pile up once the consumers are running.
+//!
+//! Published rows are hard-deleted after a retention period.
+//!
+//! # Graceful Shutdown
+//!
+//! The task supports graceful shutdown via a cancellation token. When the token
+//! is cancelled, the task completes its current iteration and exits cleanly.
+
+use crate::errors::GcError;
+use crate::events::EventBus;
+use crate::repositories::EventOutboxRepository;
+use sqlx::PgPool;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, instrument, warn};
+
+/// Default poll interval in milliseconds.
+const DEFAULT_POLL_INTERVAL_MS: u64 = 500;
+
+/// Default maximum rows claimed per iteration.
+const DEFAULT_BATCH_SIZE: i64 = 100;
+
+/// Default retention period in hours for published rows.
+const DEFAULT_RETENTION_HOURS: i32 = 24;
+
+/// Interval between published-row cleanup passes (1 hour).
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Configuration for the outbox relay task.
+#[derive(Debug, Clone)]
+pub struct OutboxRelayConfig {
+    /// Poll interval in milliseconds.
+    pub poll_interval_ms: u64,
+    /// Maximum rows claimed per iteration.
+    pub batch_size: i64,
+    /// Hours to retain published rows before hard-deleting.
+    pub retention_hours: i32,
+}
+
+impl Default for OutboxRelayConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_ms: DEFAULT_POLL_INTERVAL_MS,
+            batch_size: DEFAULT_BATCH_SIZE,
+            retention_hours: DEFAULT_RETENTION_HOURS,
+        }
+    }
+}
+
+impl OutboxRelayConfig {
+    /// Create config from environment variables.
+    ///
+    /// Environment variables:
+    /// - `GC_OUTBOX_POLL_INTERVAL_MS` - Poll interval (default: 500)
+    /// - `GC_OUTBOX_BATCH_SIZE` - Rows per iteration (default: 100)
+    /// - `GC_OUTBOX_RETENTION_HOURS` - Published-row retention (default: 24)
+    pub fn from_env() -> Self {
+        let poll_interval_ms = std::env::var("GC_OUTBOX_POLL_INTERVAL_MS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_POLL_INTERVAL_MS);
+
+        let batch_size = std::env::var("GC_OUTBOX_BATCH_SIZE")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_BATCH_SIZE);
+
+        let retention_hours = std::env::var("GC_OUTBOX_RETENTION_HOURS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_RETENTION_HOURS);
+
+        Self {
+            poll_interval_ms,
+            batch_size,
+            retention_hours,
+        }
+    }
+}
+
+/// Start the outbox relay background task.
+///
+/// # Arguments
+///
+/// * `pool` - Database connection pool
+/// * `event_bus` - Bus to publish relayed events to
+/// * `config` - Relay configuration
+/// * `cancel_token` - Token for graceful shutdown
+///
+/// # Returns
+///
+/// Returns when the cancellation token is triggered.
+#[instrument(skip_all, name = "gc.task.outbox_relay")]
+pub async fn start_outbox_relay(
+    pool: PgPool,
+    event_bus: EventBus,
+    config: OutboxRelayConfig,
+    cancel_token: CancellationToken,
+) {
+    info!(
+        target: "gc.task.outbox_relay",
+        poll_interval_ms = config.poll_interval_ms,
+        batch_size = config.batch_size,
+        retention_hours = config.retention_hours,
+        "Starting outbox relay task"
+    );
+
+    let mut poll = tokio::time::interval(Duration::from_millis(config.poll_interval_ms));
+    let mut cleanup = tokio::time::interval(CLEANUP_INTERVAL);
+
+    loop {
+        tokio::select! {
+            _ = poll.tick() => {
+                // Keep draining while full batches come back so a backlog
+                // clears without waiting one poll interval per batch.
+                loop {
+                    match relay_batch(&pool, &event_bus, config.batch_size).await {
+                        Ok(n) if n as i64 >= config.batch_size => continue,
+                        Ok(_) => break,
+                        Err(e) => {
+                            tracing::error!(
+                                target: "gc.task.outbox_relay",
+                                error = %e,
+                                "Failed to relay outbox batch"
+                            );
+                            break;
+                        }
+                    }
+                }
+            }
+            _ = cleanup.tick() => {
+                run_cleanup(&pool, config.retention_hours).await;
+            }
+            _ = cancel_token.cancelled() => {
+                info!(
+                    target: "gc.task.outbox_relay",
+                    "Outbox relay task received shutdown signal, exiting"
+                );
+                break;
+            }
+        }
+    }
+
+    info!(target: "gc.task.outbox_relay", "Outbox relay task stopped");
+}
+
+/// Relay a single batch of pending outbox rows.
+///
+/// Returns the number of rows marked published. Rows whose payload cannot be
+/// decoded are logged and marked published so they do not block the queue.
+/// Relaying stops at the first event that reached no subscriber, leaving it
+/// and the rows after it pending.
+pub(crate) async fn relay_batch(
+    pool: &PgPool,
+    event_bus: &EventBus,
+    batch_size: i64,
+) -> Result<usize, GcError> {
+    let mut tx = pool.begin().await?;
+
+    let entries = EventOutboxRepository::claim_pending(&mut tx, batch_size).await?;
+    if entries.is_empty() {
+        return Ok(0);
+    }
+
+    let mut ids = Vec::with_capacity(entries.len());
+    for entry in &entries {
+        match entry.decode() {
+            Ok(event) => {
+                if event_bus.publish(event) == 0 {
+                    debug!(
+                        target: "gc.task.outbox_relay",
+                        outbox_id = entry.outbox_id,
+                        event_type = %entry.event_type,
+                        "No subscribers for outbox event, leaving it pending"
+                    );
+                    break;
+                }
+            }
+            Err(e) => {
+                warn!(
+                    target: "gc.task.outbox_relay",
+                    outbox_id = entry.outbox_id,
+                    event_type = %entry.event_type,
+                    error = %e,
+                    "Dropping undecodable outbox event"
+                );
+            }
+        }
+        ids.push(entry.outbox_id);
+    }
+
+    EventOutboxRepository::mark_published(&mut tx, &ids).await?;
+    tx.commit().await?;
+
+    Ok(ids.len())
+}
+
+/// Hard-delete published rows past retention.
+async fn run_cleanup(pool: &PgPool, retention_hours: i32) {
+    match EventOutboxRepository::cleanup_published(pool, retention_hours, None).await {
+        Ok(count) => {
+            if count > 0 {
+                info!(
+                    target: "gc.task.outbox_relay",
+                    deleted_count = count,
+                    retention_hours = retention_hours,
+                    "Deleted published outbox rows"
+                );
+            }
+        }
+        Err(e) => {
+            tracing::error!(
+                target: "gc.task.outbox_relay",
+                error = %e,
+                "Failed to clean up published outbox rows"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // Mutex to ensure env var tests don't run in parallel
+    static ENV_MUTEX: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_default_config() {
+        let config = OutboxRelayConfig::default();
+        assert_eq!(config.poll_interval_ms, DEFAULT_POLL_INTERVAL_MS);
+        assert_eq!(config.batch_size, DEFAULT_BATCH_SIZE);
+        assert_eq!(config.retention_hours, DEFAULT_RETENTION_HOURS);
+    }
+
+    #[test]
+    fn test_from_env_with_valid_values() {
+        let _guard = ENV_MUTEX.lock().unwrap();
+
+        std::env::set_var("GC_OUTBOX_POLL_INTERVAL_MS", "250");
+        std::env::set_var("GC_OUTBOX_BATCH_SIZE", "10");
+        std::env::set_var("GC_OUTBOX_RETENTION_HOURS", "48");
+
+        let config = OutboxRelayConfig::from_env();
+
+        std::env::remove_var("GC_OUTBOX_POLL_INTERVAL_MS");
+        std::env::remove_var("GC_OUTBOX_BATCH_SIZE");
+        std::env::remove_var("GC_OUTBOX_RETENTION_HOURS");
+
+        assert_eq!(config.poll_interval_ms, 250);
+        assert_eq!(config.batch_size, 10);
+        assert_eq!(config.retention_hours, 48);
+    }
+
+    #[test]
+    fn test_from_env_rejects_zero_interval_and_batch() {
+        let _guard = ENV_MUTEX.lock().unwrap();
+
+        std::env::set_var("GC_OUTBOX_POLL_INTERVAL_MS", "0");
+        std::env::set_var("GC_OUTBOX_BATCH_SIZE", "0");
+
+        let config = OutboxRelayConfig::from_env();
+
+        std::env::remove_var("GC_OUTBOX_POLL_INTERVAL_MS");
+        std::env::remove_var("GC_OUTBOX_BATCH_SIZE");
+
+        assert_eq!(config.poll_interval_ms, DEFAULT_POLL_INTERVAL_MS);
+        assert_eq!(config.batch_size, DEFAULT_BATCH_SIZE);
+    }
+}
+
+/// Integration tests for the outbox relay requiring database.
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod integration_tests {
+    use super::*;
+    use crate::events::GcEvent;
+    use crate::tasks::start_event_metering;
+    use uuid::Uuid;
+
+    async fn enqueue(pool: &PgPool, event: &GcEvent) {
+        let mut conn = pool.acquire().await.unwrap();
+        EventOutboxRepository::enqueue(&mut conn, event)
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_relay_publishes_and_marks_rows(pool: PgPool) {
+        let bus = EventBus::default();
+        let mut rx = bus.subscribe();
+
+        let event = GcEvent::MeetingSettingsUpdated {
+            meeting_id: Uuid::new_v4(),
+            updated_by: Uuid::new_v4(),
+        };
+        enqueue(&pool, &event).await;
+        assert_eq!(
+            EventOutboxRepository::count_pending(&pool).await.unwrap(),
+            1
+        );
+
+        let relayed = relay_batch(&pool, &bus, 10).await.unwrap();
+        assert_eq!(relayed, 1);
+        assert_eq!(rx.recv().await.unwrap(), event);
+        assert_eq!(
+            EventOutboxRepository::count_pending(&pool).await.unwrap(),
+            0
+        );
+
+        // Nothing left to relay
+        assert_eq!(relay_batch(&pool, &bus, 10).await.unwrap(), 0);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_relay_preserves_insertion_order(pool: PgPool) {
+        let bus = EventBus::default();
+        let mut rx = bus.subscribe();
+
+        let ids: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for id in &ids {
+            enqueue(
+                &pool,
+                &GcEvent::MeetingSettingsUpdated {
+                    meeting_id: *id,
+                    updated_by: Uuid::new_v4(),
+                },
+            )
+            .await;
+        }
+
+        assert_eq!(relay_batch(&pool, &bus, 2).await.unwrap(), 2);
+        assert_eq!(relay_batch(&pool, &bus, 2).await.unwrap(), 1);
+
+        for id in ids {
+            assert_eq!(rx.recv().await.unwrap().meeting_id(), id);
+        }
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_relay_skips_undecodable_rows(pool: PgPool) {
+        sqlx::query(
+            "INSERT INTO event_outbox (event_type, meeting_id, payload) VALUES ('bogus', $1, '{}')",
+        )
+        .bind(Uuid::new_v4())
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let bus = EventBus::default();
+        assert_eq!(relay_batch(&pool, &bus, 10).await.unwrap(), 1);
+        assert_eq!(
+            EventOutboxRepository::count_pending(&pool).await.unwrap(),
+            0
+        );
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_relay_leaves_rows_pending_without_subscribers(pool: PgPool) {
+        let bus = EventBus::default();
+        let event = GcEvent::MeetingSettingsUpdated {
+            meeting_id: Uuid::new_v4(),
+            updated_by: Uuid::new_v4(),
+        };
+        enqueue(&pool, &event).await;
+
+        // Nobody listening: the row must survive for a later consumer
+        assert_eq!(relay_batch(&pool, &bus, 10).await.unwrap(), 0);
+        assert_eq!(
+            EventOutboxRepository::count_pending(&pool).await.unwrap(),
+            1
+        );
+
+        let mut rx = bus.subscribe();
+        assert_eq!(relay_batch(&pool, &bus, 10).await.unwrap(), 1);
+        assert_eq!(rx.recv().await.unwrap(), event);
+        assert_eq!(
+            EventOutboxRepository::count_pending(&pool).await.unwrap(),
+            0
+        );
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_relay_publishes_without_external_publisher(pool: PgPool) {
+        // Default config: GC_EVENT_PUBLISHER unset, so the metering consumer
+        // is the only subscriber
+        let bus = EventBus::default();
+        let cancel_token = CancellationToken::new();
+        let metering = tokio::spawn(start_event_metering(bus.subscribe(), cancel_token.clone()));
+
+        for _ in 0..3 {
+            enqueue(
+                &pool,
+                &GcEvent::MeetingSettingsUpdated {
+                    meeting_id: Uuid::new_v4(),
+                    updated_by: Uuid::new_v4(),
+                },
+            )
+            .await;
+        }
+
+        assert_eq!(relay_batch(&pool, &bus, 10).await.unwrap(), 3);
+        assert_eq!(
+            EventOutboxRepository::count_pending(&pool).await.unwrap(),
+            0
+        );
+
+        // Published rows are eligible for retention cleanup
+        let deleted = EventOutboxRepository::cleanup_published(&pool, 0, None)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 3);
+
+        cancel_token.cancel();
+        metering.await.unwrap();
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_relay_task_stops_on_cancel(pool: PgPool) {
+        let cancel_token = CancellationToken::new();
+        let handle = tokio::spawn(start_outbox_relay(
+            pool,
+            EventBus::default(),
+            OutboxRelayConfig::default(),
+            cancel_token.clone(),
+        ));
+
+        cancel_token.cancel();
+        let result = tokio::time::timeout(Duration::from_secs(5), handle).await;
+        assert!(result.is_ok(), "Relay task should stop after cancellation");
+    }
+}
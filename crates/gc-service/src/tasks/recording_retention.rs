@@ -0,0 +1,426 @@
+//! Recording retention background task.
+//!
+//! Removes recording objects from the object store and then their metadata
+//! rows, for recordings that either:
+//!
+//! - were deleted by the host (`deleted_at` set), or
+//! - completed more than `GC_RECORDING_RETENTION_DAYS` ago (audit logged as
+//!   `recording_expired`)
+//!
+//! Objects are removed with a pre-signed `DELETE`, so the task needs no
+//! object store SDK. A failed delete leaves the row in place and is retried
+//! on the next iteration.
+//!
+//! # Graceful Shutdown
+//!
+//! The task supports graceful shutdown via a cancellation token. When the token
+//! is cancelled, the task completes its current iteration and exits cleanly.
+
+use crate::repositories::{MeetingRecordingsRepository, PurgeableRecording};
+use crate::services::ObjectStore;
+use chrono::Utc;
+use reqwest::{Method, StatusCode};
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, instrument, warn};
+
+/// Default retention check interval in seconds (1 hour).
+const DEFAULT_CHECK_INTERVAL_SECONDS: u64 = 3600;
+
+/// Default maximum recordings removed per iteration.
+const DEFAULT_BATCH_SIZE: i64 = 100;
+
+/// Lifetime of the pre-signed delete URL used by the task itself.
+const DELETE_URL_TTL: Duration = Duration::from_secs(60);
+
+/// Timeout for a single object delete request.
+const DELETE_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Configuration for the recording retention task.
+#[derive(Debug, Clone)]
+pub struct RecordingRetentionConfig {
+    /// Days a recording is kept after it completes.
+    pub retention_days: u32,
+    /// Check interval in seconds.
+    pub check_interval_seconds: u64,
+    /// Maximum recordings removed per iteration.
+    pub batch_size: i64,
+}
+
+impl RecordingRetentionConfig {
+    /// Create config from environment variables.
+    ///
+    /// `retention_days` comes from the validated service config
+    /// (`GC_RECORDING_RETENTION_DAYS`). Environment variables:
+    /// - `GC_RECORDING_RETENTION_INTERVAL_SECONDS` - Check interval (default: 3600)
+    /// - `GC_RECORDING_RETENTION_BATCH_SIZE` - Recordings per iteration (default: 100)
+    pub fn from_env(retention_days: u32) -> Self {
+        let check_interval_seconds = std::env::var("GC_RECORDING_RETENTION_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_CHECK_INTERVAL_SECONDS);
+
+        let batch_size = std::env::var("GC_RECORDING_RETENTION_BATCH_SIZE")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_BATCH_SIZE);
+
+        Self {
+            retention_days,
+            check_interval_seconds,
+            batch_size,
+        }
+    }
+}
+
+/// Start the recording retention background task.
+///
+/// # Arguments
+///
+/// * `pool` - Database connection pool
+/// * `object_store` - Presigner for the recording bucket
+/// * `config` - Retention configuration
+/// * `cancel_token` - Token for graceful shutdown
+///
+/// # Returns
+///
+/// Returns when the cancellation token is triggered.
+#[instrument(skip_all, name = "gc.task.recording_retention")]
+pub async fn start_recording_retention(
+    pool: PgPool,
+    object_store: Arc<dyn ObjectStore>,
+    config: RecordingRetentionConfig,
+    cancel_token: CancellationToken,
+) {
+    info!(
+        target: "gc.task.recording_retention",
+        retention_days = config.retention_days,
+        check_interval_seconds = config.check_interval_seconds,
+        batch_size = config.batch_size,
+        "Starting recording retention task"
+    );
+
+    let http = match reqwest::Client::builder()
+        .timeout(DELETE_REQUEST_TIMEOUT)
+        .build()
+    {
+        Ok(http) => http,
+        Err(e) => {
+            tracing::error!(
+                target: "gc.task.recording_retention",
+                error = %e,
+                "Failed to build HTTP client, recording retention disabled"
+            );
+            return;
+        }
+    };
+
+    let mut interval = tokio::time::interval(Duration::from_secs(config.check_interval_seconds));
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {
+                run_retention(&pool, object_store.as_ref(), &http, &config).await;
+            }
+            _ = cancel_token.cancelled() => {
+                info!(
+                    target: "gc.task.recording_retention",
+                    "Recording retention task received shutdown signal, exiting"
+                );
+                break;
+            }
+        }
+    }
+
+    info!(
+        target: "gc.task.recording_retention",
+        "Recording retention task stopped"
+    );
+}
+
+/// Run a single retention iteration. Returns the number of recordings removed.
+///
+/// This is separated from the main loop to allow direct testing.
+pub(crate) async fn run_retention(
+    pool: &PgPool,
+    object_store: &dyn ObjectStore,
+    http: &reqwest::Client,
+    config: &RecordingRetentionConfig,
+) -> usize {
+    let retention_days = i32::try_from(config.retention_days).unwrap_or(i32::MAX);
+    let recordings =
+        match MeetingRecordingsRepository::list_purgeable(pool, retention_days, config.batch_size)
+            .await
+        {
+            Ok(recordings) => recordings,
+            Err(e) => {
+                tracing::error!(
+                    target: "gc.task.recording_retention",
+                    error = %e,
+                    "Failed to list recordings for retention"
+                );
+                return 0;
+            }
+        };
+
+    let mut removed = 0;
+    for recording in &recordings {
+        if let Err(reason) = delete_object(object_store, http, recording).await {
+            warn!(
+                target: "gc.task.recording_retention",
+                recording_id = %recording.recording_id,
+                reason = %reason,
+                "Failed to delete recording object, will retry"
+            );
+            continue;
+        }
+
+        if let Err(e) = MeetingRecordingsRepository::remove(pool, recording.recording_id).await {
+            warn!(
+                target: "gc.task.recording_retention",
+                recording_id = %recording.recording_id,
+                error = %e,
+                "Failed to remove recording row, will retry"
+            );
+            continue;
+        }
+        removed += 1;
+
+        // Host deletions were audit logged when requested
+        if !recording.deleted_by_host {
+            if let Err(e) = MeetingRecordingsRepository::log_audit_event(
+                pool,
+                recording.org_id,
+                None,
+                recording.meeting_id,
+                recording.recording_id,
+                "recording_expired",
+            )
+            .await
+            {
+                warn!(
+                    target: "gc.task.recording_retention",
+                    recording_id = %recording.recording_id,
+                    error = %e,
+                    "Failed to log audit event for recording expiry"
+                );
+            }
+        }
+    }
+
+    if removed > 0 {
+        info!(
+            target: "gc.task.recording_retention",
+            removed_count = removed,
+            "Removed recordings"
+        );
+    }
+    removed
+}
+
+/// Delete a recording object. A missing object counts as deleted.
+async fn delete_object(
+    object_store: &dyn ObjectStore,
+    http: &reqwest::Client,
+    recording: &PurgeableRecording,
+) -> Result<(), String> {
+    let request = object_store.presign_delete(&recording.object_key, DELETE_URL_TTL, Utc::now());
+    let method = Method::from_bytes(request.method.as_bytes()).map_err(|e| e.to_string())?;
+
+    let response = http
+        .request(method, &request.url)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    if status.is_success() || status == StatusCode::NOT_FOUND {
+        Ok(())
+    } else {
+        Err(format!("object store returned {status}"))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // Mutex to ensure env var tests don't run in parallel
+    static ENV_MUTEX: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_from_env_defaults() {
+        let _guard = ENV_MUTEX.lock().unwrap();
+
+        std::env::remove_var("GC_RECORDING_RETENTION_INTERVAL_SECONDS");
+        std::env::set_var("GC_RECORDING_RETENTION_BATCH_SIZE", "lots");
+
+        let config = RecordingRetentionConfig::from_env(30);
+
+        std::env::remove_var("GC_RECORDING_RETENTION_BATCH_SIZE");
+
+        assert_eq!(config.retention_days, 30);
+        assert_eq!(
+            config.check_interval_seconds,
+            DEFAULT_CHECK_INTERVAL_SECONDS
+        );
+        assert_eq!(config.batch_size, DEFAULT_BATCH_SIZE);
+    }
+}
+
+/// Integration tests for recording retention requiring database.
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod integration_tests {
+    use super::*;
+    use crate::repositories::MeetingRecording;
+    use crate::services::object_store::{self, ObjectStoreConfig};
+    use std::collections::HashMap;
+    use uuid::Uuid;
+    use wiremock::matchers::{method, path_regex};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn test_store(endpoint: &str) -> Arc<dyn ObjectStore> {
+        let vars: HashMap<String, String> = [
+            ("GC_OBJECT_STORE", "s3"),
+            ("GC_OBJECT_STORE_ENDPOINT", endpoint),
+            ("GC_OBJECT_STORE_BUCKET", "test-recordings"),
+            ("GC_OBJECT_STORE_PATH_STYLE", "true"),
+            ("GC_OBJECT_STORE_ACCESS_KEY_ID", "test-access-key"),
+            ("GC_OBJECT_STORE_SECRET_ACCESS_KEY", "test-secret-key"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        object_store::build(&ObjectStoreConfig::from_vars(&vars).unwrap().unwrap())
+    }
+
+    /// Create an org, host, and meeting; returns (org_id, host_id, meeting_id).
+    async fn create_meeting(pool: &PgPool) -> (Uuid, Uuid, Uuid) {
+        let org_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO organizations (subdomain, display_name) VALUES ('rec-org', 'Rec Org') RETURNING org_id",
+        )
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        let host_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO users (org_id, email, password_hash, display_name) VALUES ($1, 'host@rec.test', 'hash', 'Host') RETURNING user_id",
+        )
+        .bind(org_id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        let meeting_id: Uuid = sqlx::query_scalar(
+            r#"
+            INSERT INTO meetings (org_id, created_by_user_id, display_name, meeting_code, join_token_secret)
+            VALUES ($1, $2, 'Recorded', 'REC001', 'secret')
+            RETURNING meeting_id
+            "#,
+        )
+        .bind(org_id)
+        .bind(host_id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        (org_id, host_id, meeting_id)
+    }
+
+    async fn insert_recording(pool: &PgPool, meeting_id: Uuid, age_days: i64) -> Uuid {
+        let recording_id = Uuid::new_v4();
+        let completed_at = Utc::now() - chrono::Duration::days(age_days);
+        MeetingRecordingsRepository::insert(
+            pool,
+            &MeetingRecording {
+                recording_id,
+                meeting_id,
+                media_handler_id: "mh-1".to_string(),
+                object_key: format!("recordings/{meeting_id}/{recording_id}.mp4"),
+                content_type: "video/mp4".to_string(),
+                size_bytes: 1024,
+                duration_seconds: 60,
+                started_at: completed_at - chrono::Duration::seconds(60),
+                completed_at,
+            },
+        )
+        .await
+        .unwrap();
+        recording_id
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_retention_removes_expired_and_deleted(pool: PgPool) {
+        let object_store_server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path_regex("^/test-recordings/recordings/.+"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(2)
+            .mount(&object_store_server)
+            .await;
+
+        let (_, host_id, meeting_id) = create_meeting(&pool).await;
+        let expired = insert_recording(&pool, meeting_id, 40).await;
+        let deleted = insert_recording(&pool, meeting_id, 1).await;
+        let kept = insert_recording(&pool, meeting_id, 1).await;
+        assert!(
+            MeetingRecordingsRepository::mark_deleted(&pool, meeting_id, deleted, host_id)
+                .await
+                .unwrap()
+        );
+
+        let config = RecordingRetentionConfig {
+            retention_days: 30,
+            check_interval_seconds: 60,
+            batch_size: 100,
+        };
+        let store = test_store(&object_store_server.uri());
+        let removed = run_retention(&pool, store.as_ref(), &reqwest::Client::new(), &config).await;
+        assert_eq!(removed, 2);
+
+        let remaining: Vec<Uuid> =
+            sqlx::query_scalar("SELECT recording_id FROM meeting_recordings")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(remaining, vec![kept]);
+
+        // Only the expiry is audit logged here; host deletions are logged by the API
+        let details: Vec<serde_json::Value> =
+            sqlx::query_scalar("SELECT details FROM audit_logs WHERE action = 'recording_expired'")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(details.len(), 1);
+        assert_eq!(details[0]["recording_id"], expired.to_string());
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_retention_keeps_row_when_delete_fails(pool: PgPool) {
+        let object_store_server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&object_store_server)
+            .await;
+
+        let (_, _, meeting_id) = create_meeting(&pool).await;
+        insert_recording(&pool, meeting_id, 400).await;
+
+        let config = RecordingRetentionConfig {
+            retention_days: 90,
+            check_interval_seconds: 60,
+            batch_size: 100,
+        };
+        let store = test_store(&object_store_server.uri());
+        let removed = run_retention(&pool, store.as_ref(), &reqwest::Client::new(), &config).await;
+        assert_eq!(removed, 0);
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM meeting_recordings")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 1, "row stays for the next attempt");
+    }
+}
@@ -0,0 +1,409 @@
+//! Golden-file contract tests for the Global Controller public API.
+//!
+//! Every response model a client can receive is rendered to canonical JSON
+//! (sorted keys, pretty-printed) and compared against the committed file in
+//! `tests/golden/`. A failure here means the wire format changed: if the
+//! change is intentional, regenerate the files with
+//!
+//! ```text
+//! UPDATE_GOLDEN=1 cargo test -p gc-service --test api_contract_tests
+//! ```
+//!
+//! and review the diff as an API change (see `docs/API_CONTRACTS.md`).
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use axum::http::header;
+use axum::response::IntoResponse;
+use chrono::{DateTime, TimeZone, Utc};
+use gc_service::errors::GcError;
+use gc_service::handlers::me::MeResponse;
+use gc_service::models::{
+    CreateAssetResponse, CreateMeetingResponse, JoinMeetingResponse, ListRecordingsResponse,
+    McAssignmentInfo, MeetingReportResponse, MeetingResponse, PreflightMediaHandler,
+    PreflightResponse, ReadinessResponse, RecordingDownloadResponse, RecordingResponse, RelayInfo,
+};
+use http_body_util::BodyExt;
+use serde::Serialize;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const MEETING_ID: Uuid = Uuid::from_u128(0x0192_8c3e_7a1b_7c00_8000_0000_0000_0001);
+const ASSET_ID: Uuid = Uuid::from_u128(0x0192_8c3e_7a1b_7c00_8000_0000_0000_0002);
+const RECORDING_ID: Uuid = Uuid::from_u128(0x0192_8c3e_7a1b_7c00_8000_0000_0000_0003);
+const JOIN_ID: Uuid = Uuid::from_u128(0x0192_8c3e_7a1b_7c00_8000_0000_0000_0004);
+
+fn at(hour: u32, min: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 15, hour, min, 0).unwrap()
+}
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{name}.json"))
+}
+
+/// Compare `value` against `tests/golden/{name}.json`, or rewrite the file
+/// when `UPDATE_GOLDEN` is set.
+fn assert_golden(name: &str, value: &impl Serialize) {
+    // `serde_json::Value` objects are BTreeMaps, so keys come out sorted
+    let actual = serde_json::to_value(value).unwrap();
+    let rendered = format!("{}\n", serde_json::to_string_pretty(&actual).unwrap());
+    let path = golden_path(name);
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, rendered).unwrap();
+        return;
+    }
+
+    let committed = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!(
+            "missing golden file {}: {e}; run with UPDATE_GOLDEN=1 to create it",
+            path.display()
+        )
+    });
+    let expected: Value = serde_json::from_str(&committed).unwrap();
+
+    assert_eq!(
+        actual,
+        expected,
+        "response `{name}` no longer matches {}. If this is an intentional API \
+         change, rerun with UPDATE_GOLDEN=1 and commit the diff.\nrendered:\n{rendered}",
+        path.display()
+    );
+}
+
+fn mc_assignment(webtransport_endpoint: Option<&str>) -> McAssignmentInfo {
+    McAssignmentInfo {
+        mc_id: "mc-us-east-1-0".to_string(),
+        webtransport_endpoint: webtransport_endpoint.map(str::to_string),
+        grpc_endpoint: "https://mc-0.us-east-1.dark-tower.example:50052".to_string(),
+    }
+}
+
+fn join_response(webtransport_endpoint: Option<&str>) -> JoinMeetingResponse {
+    JoinMeetingResponse {
+        token: "eyJhbGciOiJFZERTQSJ9.meeting.signature".to_string(),
+        expires_in: 900,
+        meeting_id: MEETING_ID,
+        meeting_name: "Weekly Sync".to_string(),
+        mc_assignment: mc_assignment(webtransport_endpoint),
+        join_id: JOIN_ID,
+        experiments: BTreeMap::new(),
+    }
+}
+
+fn recording() -> RecordingResponse {
+    RecordingResponse {
+        recording_id: RECORDING_ID,
+        content_type: "video/webm".to_string(),
+        size_bytes: 52_428_800,
+        duration_seconds: 1800,
+        started_at: at(10, 0),
+        completed_at: at(10, 30),
+        expires_at: at(22, 30),
+    }
+}
+
+// ============================================================================
+// Meeting Responses
+// ============================================================================
+
+#[test]
+fn join_meeting_response() {
+    assert_golden(
+        "join_meeting_response",
+        &join_response(Some("https://mc-0.us-east-1.dark-tower.example:4433")),
+    );
+}
+
+#[test]
+fn join_meeting_response_with_experiments() {
+    let mut response = join_response(Some("https://mc-0.us-east-1.dark-tower.example:4433"));
+    response.experiments = BTreeMap::from([
+        ("noise_suppression".to_string(), "rnnoise".to_string()),
+        ("simulcast".to_string(), "on".to_string()),
+    ]);
+    assert_golden("join_meeting_response_with_experiments", &response);
+}
+
+#[test]
+fn join_meeting_response_without_webtransport() {
+    assert_golden("join_meeting_response_grpc_only", &join_response(None));
+}
+
+#[test]
+fn preflight_response() {
+    let response = PreflightResponse {
+        meeting_id: MEETING_ID,
+        ready: true,
+        mc_assignment: Some(mc_assignment(Some(
+            "https://mc-0.us-east-1.dark-tower.example:4433",
+        ))),
+        mc_capacity_available: true,
+        media_handlers: vec![PreflightMediaHandler {
+            mh_id: "mh-us-east-1-0".to_string(),
+            webtransport_endpoint: "https://mh-0.us-east-1.dark-tower.example:4433".to_string(),
+        }],
+        min_protocol_version: 2,
+        relay: RelayInfo {
+            available: true,
+            turn_servers: vec!["turn:relay.us-east-1.dark-tower.example:3478".to_string()],
+        },
+        features: BTreeMap::from([
+            ("e2e_encryption".to_string(), true),
+            ("recording".to_string(), false),
+            ("waiting_room".to_string(), false),
+        ]),
+    };
+    assert_golden("preflight_response", &response);
+}
+
+#[test]
+fn preflight_response_not_ready() {
+    let response = PreflightResponse {
+        meeting_id: MEETING_ID,
+        ready: false,
+        mc_assignment: None,
+        mc_capacity_available: false,
+        media_handlers: vec![],
+        min_protocol_version: 1,
+        relay: RelayInfo {
+            available: false,
+            turn_servers: vec![],
+        },
+        features: BTreeMap::from([
+            ("e2e_encryption".to_string(), false),
+            ("recording".to_string(), false),
+            ("waiting_room".to_string(), true),
+        ]),
+    };
+    assert_golden("preflight_response_not_ready", &response);
+}
+
+#[test]
+fn create_meeting_response() {
+    assert_golden(
+        "create_meeting_response",
+        &CreateMeetingResponse {
+            meeting_id: MEETING_ID,
+            meeting_code: "abc-defg-hij".to_string(),
+            display_name: "Weekly Sync".to_string(),
+            status: "scheduled".to_string(),
+            max_participants: 100,
+            enable_e2e_encryption: true,
+            require_auth: true,
+            recording_enabled: false,
+            allow_guests: false,
+            allow_external_participants: false,
+            waiting_room_enabled: true,
+            priority: "standard".to_string(),
+            created_at: at(9, 0),
+        },
+    );
+}
+
+#[test]
+fn meeting_response() {
+    assert_golden(
+        "meeting_response",
+        &MeetingResponse {
+            meeting_id: MEETING_ID,
+            display_name: "Weekly Sync".to_string(),
+            meeting_code: "abc-defg-hij".to_string(),
+            status: "active".to_string(),
+            allow_guests: true,
+            allow_external_participants: false,
+            waiting_room_enabled: true,
+            duplicate_join_policy: "takeover".to_string(),
+            recording_layout: "grid".to_string(),
+            updated_at: at(9, 45),
+        },
+    );
+}
+
+#[test]
+fn meeting_report_response() {
+    let report = MeetingReportResponse {
+        meeting_id: MEETING_ID,
+        started_at: at(10, 0),
+        ended_at: at(10, 30),
+        duration_seconds: 1800,
+        peak_participants: 8,
+        total_participants: 11,
+        join_failures: 1,
+        avg_qoe_score: Some(4.25),
+        live_stream_seconds: 1200,
+        computed_at: at(10, 31),
+    };
+    assert_golden("meeting_report_response", &report);
+
+    assert_golden(
+        "meeting_report_response_without_qoe",
+        &MeetingReportResponse {
+            avg_qoe_score: None,
+            ..report
+        },
+    );
+}
+
+// ============================================================================
+// Asset and Recording Responses
+// ============================================================================
+
+#[test]
+fn create_asset_response() {
+    assert_golden(
+        "create_asset_response",
+        &CreateAssetResponse {
+            asset_id: ASSET_ID,
+            upload_method: "PUT".to_string(),
+            upload_url: "https://assets.dark-tower.example/meetings/upload?sig=abc".to_string(),
+            upload_headers: BTreeMap::from([
+                ("content-length".to_string(), "2048".to_string()),
+                ("content-type".to_string(), "application/pdf".to_string()),
+            ]),
+            expires_at: at(10, 15),
+        },
+    );
+}
+
+#[test]
+fn list_recordings_response() {
+    assert_golden(
+        "list_recordings_response",
+        &ListRecordingsResponse {
+            recordings: vec![recording()],
+        },
+    );
+    assert_golden(
+        "list_recordings_response_empty",
+        &ListRecordingsResponse { recordings: vec![] },
+    );
+}
+
+#[test]
+fn recording_download_response() {
+    assert_golden(
+        "recording_download_response",
+        &RecordingDownloadResponse {
+            recording_id: RECORDING_ID,
+            download_url: "https://recordings.dark-tower.example/download?sig=abc".to_string(),
+            expires_at: at(10, 45),
+        },
+    );
+}
+
+// ============================================================================
+// Service Responses
+// ============================================================================
+
+#[test]
+fn me_response() {
+    assert_golden(
+        "me_response",
+        &MeResponse {
+            sub: "client_abc123".to_string(),
+            scopes: vec!["meeting:create".to_string(), "meeting:read".to_string()],
+            service_type: Some("global-controller".to_string()),
+            exp: 1_768_474_800,
+            iat: 1_768_471_200,
+        },
+    );
+}
+
+#[test]
+fn readiness_response() {
+    assert_golden(
+        "readiness_response_ready",
+        &ReadinessResponse {
+            status: "ready",
+            database: Some("healthy"),
+            ac_jwks: Some("available"),
+            error: None,
+        },
+    );
+    assert_golden(
+        "readiness_response_not_ready",
+        &ReadinessResponse {
+            status: "not_ready",
+            database: Some("unhealthy"),
+            ac_jwks: None,
+            error: Some("Service dependencies unavailable".to_string()),
+        },
+    );
+}
+
+// ============================================================================
+// Error Responses
+// ============================================================================
+
+/// Status, contract-relevant headers and body of an error response.
+async fn render_error(error: GcError) -> Value {
+    let response = error.into_response();
+    let status = response.status().as_u16();
+
+    let headers: BTreeMap<&str, &str> = [header::CONTENT_TYPE, header::WWW_AUTHENTICATE]
+        .iter()
+        .filter_map(|name| {
+            let value = response.headers().get(name)?.to_str().ok()?;
+            Some((name.as_str(), value))
+        })
+        .collect();
+    let headers = serde_json::to_value(headers).unwrap();
+
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+
+    json!({ "status": status, "headers": headers, "body": body })
+}
+
+#[tokio::test]
+async fn error_responses() {
+    // Internal details must never reach the body, so the generic variants
+    // are rendered with a message that would show up if they leaked
+    let cases = [
+        (
+            "error_database",
+            GcError::Database("relation \"meetings\" does not exist".to_string()),
+        ),
+        (
+            "error_invalid_token",
+            GcError::InvalidToken("The access token is invalid or expired".to_string()),
+        ),
+        (
+            "error_not_found",
+            GcError::NotFound("Meeting not found".to_string()),
+        ),
+        (
+            "error_conflict",
+            GcError::Conflict("Meeting code already in use".to_string()),
+        ),
+        ("error_rate_limit_exceeded", GcError::RateLimitExceeded),
+        (
+            "error_forbidden",
+            GcError::Forbidden("Only the meeting host can update settings".to_string()),
+        ),
+        (
+            "error_bad_request",
+            GcError::BadRequest("Display name is required".to_string()),
+        ),
+        (
+            "error_service_unavailable",
+            GcError::ServiceUnavailable("no healthy meeting controllers in us-east-1".to_string()),
+        ),
+        (
+            "error_internal",
+            GcError::Internal("token signing key missing".to_string()),
+        ),
+    ];
+
+    for (name, error) in cases {
+        assert_golden(name, &render_error(error).await);
+    }
+}
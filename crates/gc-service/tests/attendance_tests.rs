@@ -0,0 +1,324 @@
+//! Meeting attendance repository integration tests.
+//!
+//! Tests the AttendanceRepository functionality including:
+//! - Batch insert of participant sessions
+//! - Idempotent retry (duplicate sessions skipped)
+//! - Listing in join order
+//! - Leave reason constraint enforcement
+//! - `ReportAttendance` accepted only from the meeting's assigned MC, as
+//!   identified by its service token
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use chrono::{Duration, TimeZone, Utc};
+use common::secret::SecretString;
+use common::token_manager::TokenReceiver;
+use gc_service::auth::Claims;
+use gc_service::config::Config;
+use gc_service::events::EventBus;
+use gc_service::grpc::McService;
+use gc_service::repositories::{
+    AttendanceRecord, AttendanceRepository, HealthStatus, McCandidate,
+    MeetingAssignmentsRepository, MeetingControllersRepository, PgStore,
+};
+use gc_service::routes::AppState;
+use gc_service::services::MockMcClient;
+use gc_test_utils::TestMeetingRow;
+use proto_gen::dark_tower::internal::v1::global_controller_service_server::GlobalControllerService;
+use proto_gen::dark_tower::internal::v1::ReportAttendanceRequest;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tonic::{Code, Request};
+use uuid::Uuid;
+
+/// Insert an ended fixture meeting. Returns meeting_id.
+async fn create_test_meeting(pool: &PgPool) -> Uuid {
+    TestMeetingRow::new()
+        .status("ended")
+        .insert(pool)
+        .await
+        .expect("Failed to insert test meeting")
+        .meeting_id
+}
+
+fn session(
+    participant_id: &str,
+    joined_offset_minutes: i64,
+    leave_reason: &str,
+) -> AttendanceRecord {
+    let base = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+    let joined_at = base + Duration::minutes(joined_offset_minutes);
+    AttendanceRecord {
+        participant_id: participant_id.to_string(),
+        user_id: format!("user-{participant_id}"),
+        display_name: format!("Name {participant_id}"),
+        joined_at,
+        left_at: joined_at + Duration::minutes(10),
+        leave_reason: leave_reason.to_string(),
+    }
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_insert_and_list_attendance(pool: PgPool) {
+    let meeting_id = create_test_meeting(&pool).await;
+    let records = vec![
+        session("part-b", 5, "timeout"),
+        session("part-a", 0, "voluntary"),
+        session("part-a", 20, "meeting_ended"),
+    ];
+
+    let stored = AttendanceRepository::insert_batch(&pool, meeting_id, "us-east-1", &records)
+        .await
+        .unwrap();
+    assert_eq!(stored, 3);
+
+    let listed = AttendanceRepository::list_for_meeting(&pool, meeting_id)
+        .await
+        .unwrap();
+    let order: Vec<(&str, &str)> = listed
+        .iter()
+        .map(|r| (r.participant_id.as_str(), r.leave_reason.as_str()))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            ("part-a", "voluntary"),
+            ("part-b", "timeout"),
+            ("part-a", "meeting_ended"),
+        ],
+        "Sessions should be listed in join order"
+    );
+    assert_eq!(listed[0], records[1]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_insert_attendance_retry_is_idempotent(pool: PgPool) {
+    let meeting_id = create_test_meeting(&pool).await;
+    let records = vec![session("part-a", 0, "voluntary")];
+
+    let first = AttendanceRepository::insert_batch(&pool, meeting_id, "us-east-1", &records)
+        .await
+        .unwrap();
+    let second = AttendanceRepository::insert_batch(&pool, meeting_id, "us-east-1", &records)
+        .await
+        .unwrap();
+
+    assert_eq!(first, 1);
+    assert_eq!(second, 0, "Retried sessions should be skipped");
+    assert_eq!(
+        AttendanceRepository::list_for_meeting(&pool, meeting_id)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_insert_attendance_empty_batch(pool: PgPool) {
+    let meeting_id = create_test_meeting(&pool).await;
+
+    let stored = AttendanceRepository::insert_batch(&pool, meeting_id, "us-east-1", &[])
+        .await
+        .unwrap();
+
+    assert_eq!(stored, 0);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_insert_attendance_rejects_unknown_leave_reason(pool: PgPool) {
+    let meeting_id = create_test_meeting(&pool).await;
+    let records = vec![session("part-a", 0, "kicked")];
+
+    let result = AttendanceRepository::insert_batch(&pool, meeting_id, "us-east-1", &records).await;
+
+    assert!(
+        result.is_err(),
+        "Leave reason CHECK constraint should reject"
+    );
+}
+
+// ============================================================================
+// ReportAttendance RPC
+// ============================================================================
+
+fn mc_service(pool: PgPool) -> McService {
+    let vars = HashMap::from([
+        (
+            "DATABASE_URL".to_string(),
+            "postgresql://test/test".to_string(),
+        ),
+        ("BIND_ADDRESS".to_string(), "127.0.0.1:0".to_string()),
+        ("GC_REGION".to_string(), "us-east-1".to_string()),
+        (
+            "AC_JWKS_URL".to_string(),
+            "http://localhost:8082/.well-known/jwks.json".to_string(),
+        ),
+        (
+            "AC_INTERNAL_URL".to_string(),
+            "http://localhost:8082".to_string(),
+        ),
+        ("GC_CLIENT_ID".to_string(), "test-gc-client".to_string()),
+        ("GC_CLIENT_SECRET".to_string(), "test-gc-secret".to_string()),
+    ]);
+    let config = Config::from_vars(&vars).expect("test config should be valid");
+
+    let (_tx, rx) = watch::channel(SecretString::from("test-token"));
+    let store = Arc::new(PgStore::new(pool.clone(), config.storage_backend));
+
+    McService::new(Arc::new(AppState {
+        pool,
+        config,
+        mc_client: Arc::new(MockMcClient::accepting()),
+        token_receiver: TokenReceiver::from_watch_receiver(rx),
+        event_bus: EventBus::default(),
+        object_store: None,
+        store,
+    }))
+}
+
+/// Register a healthy MC in us-east-1 and assign `meeting_id` to it.
+async fn assign_meeting(pool: &PgPool, meeting_id: Uuid, mc_id: &str) {
+    let endpoint = format!("http://{mc_id}.example.com:50051");
+    MeetingControllersRepository::register_mc(pool, mc_id, "us-east-1", &endpoint, None, 100, 1000)
+        .await
+        .unwrap();
+    MeetingControllersRepository::update_heartbeat(pool, mc_id, 0, 0, HealthStatus::Healthy)
+        .await
+        .unwrap();
+    let candidate = McCandidate {
+        controller_id: mc_id.to_string(),
+        grpc_endpoint: endpoint,
+        webtransport_endpoint: None,
+        load_ratio: 0.0,
+    };
+    MeetingAssignmentsRepository::atomic_assign(
+        pool,
+        &meeting_id.to_string(),
+        "us-east-1",
+        &candidate,
+        "gc-test-001",
+    )
+    .await
+    .unwrap();
+}
+
+/// Validated claims of the MC service token with subject `caller`.
+fn mc_claims(caller: &str) -> Claims {
+    let now = Utc::now().timestamp();
+    Claims {
+        sub: caller.to_string(),
+        exp: now + 3600,
+        iat: now,
+        scope: "service.write.gc".to_string(),
+        service_type: Some("meeting-controller".to_string()),
+        cnf: None,
+    }
+}
+
+/// Attendance report sent by `controller_id` about itself.
+fn report(meeting_id: Uuid, controller_id: &str) -> Request<ReportAttendanceRequest> {
+    report_as(controller_id, meeting_id, controller_id)
+}
+
+/// Attendance report naming `controller_id`, sent by the MC `caller`.
+fn report_as(
+    caller: &str,
+    meeting_id: Uuid,
+    controller_id: &str,
+) -> Request<ReportAttendanceRequest> {
+    let record = session("part-a", 0, "voluntary");
+    let mut request = Request::new(ReportAttendanceRequest {
+        meeting_id: meeting_id.to_string(),
+        region: "us-east-1".to_string(),
+        records: vec![proto_gen::dark_tower::internal::v1::AttendanceRecord {
+            participant_id: record.participant_id,
+            user_id: record.user_id,
+            display_name: record.display_name,
+            joined_at_ms: record.joined_at.timestamp_millis(),
+            left_at_ms: record.left_at.timestamp_millis(),
+            leave_reason: record.leave_reason,
+        }],
+        live_stream_seconds: 0,
+        controller_id: controller_id.to_string(),
+    });
+    request.extensions_mut().insert(mc_claims(caller));
+    request
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_report_attendance_from_assigned_mc(pool: PgPool) {
+    let meeting_id = create_test_meeting(&pool).await;
+    assign_meeting(&pool, meeting_id, "mc-1").await;
+    let service = mc_service(pool.clone());
+
+    let response = service
+        .report_attendance(report(meeting_id, "mc-1"))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(response.acknowledged);
+    assert_eq!(response.records_stored, 1);
+
+    // The flush may follow the end of the assignment
+    MeetingAssignmentsRepository::end_assignment(&pool, &meeting_id.to_string(), None)
+        .await
+        .unwrap();
+    let retried = service
+        .report_attendance(report(meeting_id, "mc-1"))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(retried.records_stored, 0, "Retry should be deduplicated");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_report_attendance_rejects_unassigned_mc(pool: PgPool) {
+    let meeting_id = create_test_meeting(&pool).await;
+    let unassigned_meeting_id = create_test_meeting(&pool).await;
+    assign_meeting(&pool, meeting_id, "mc-1").await;
+    let service = mc_service(pool.clone());
+
+    for (meeting_id, controller_id) in [(meeting_id, "mc-2"), (unassigned_meeting_id, "mc-1")] {
+        let status = service
+            .report_attendance(report(meeting_id, controller_id))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::PermissionDenied);
+
+        let listed = AttendanceRepository::list_for_meeting(&pool, meeting_id)
+            .await
+            .unwrap();
+        assert!(listed.is_empty(), "Rejected reports must not be stored");
+    }
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_report_attendance_rejects_other_mcs_meeting(pool: PgPool) {
+    let meeting_id = create_test_meeting(&pool).await;
+    assign_meeting(&pool, meeting_id, "mc-1").await;
+    let service = mc_service(pool.clone());
+
+    // mc-2 claims to be mc-1, the meeting's assigned MC
+    let status = service
+        .report_attendance(report_as("mc-2", meeting_id, "mc-1"))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::PermissionDenied);
+
+    // A request that did not pass the auth layer has no caller
+    let mut unauthenticated = report(meeting_id, "mc-1");
+    unauthenticated.extensions_mut().remove::<Claims>();
+    let status = service
+        .report_attendance(unauthenticated)
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::Unauthenticated);
+
+    let listed = AttendanceRepository::list_for_meeting(&pool, meeting_id)
+        .await
+        .unwrap();
+    assert!(listed.is_empty(), "Rejected reports must not be stored");
+}
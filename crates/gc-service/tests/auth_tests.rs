@@ -15,7 +15,9 @@ use chrono::Utc;
 use common::secret::SecretString;
 use common::token_manager::TokenReceiver;
 use gc_service::config::Config;
+use gc_service::events::EventBus;
 use gc_service::observability::metrics::init_metrics_recorder;
+use gc_service::repositories::PgStore;
 use gc_service::routes::{self, AppState};
 use gc_service::services::MockMcClient;
 use std::sync::OnceLock;
@@ -99,6 +101,9 @@ impl TestAuthServer {
             config: config.clone(),
             mc_client: mock_mc_client,
             token_receiver,
+            event_bus: EventBus::default(),
+            object_store: None,
+            store: Arc::new(PgStore::new(pool.clone(), config.storage_backend)),
         });
 
         // Build routes with metrics handle
@@ -0,0 +1,50 @@
+//! Integration cover for `gc_client_errors_total`.
+//!
+//! `MetricAssertion`'s per-thread recorder isolation applies. No tokio
+//! runtime pinning needed — `record_client_error` is synchronous.
+//!
+//! The production recording site is
+//! `crates/gc-service/src/handlers/client_errors.rs`; the HTTP endpoint is
+//! covered in `crates/gc-service/tests/meeting_tests.rs`.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use ::common::observability::testing::MetricAssertion;
+use gc_service::observability::metrics::record_client_error;
+
+const ALL_KINDS: &[&str] = &["join_failed", "transport_fallback", "codec_unsupported"];
+
+#[test]
+fn client_error_emits_kind_and_platform() {
+    for kind in ALL_KINDS {
+        let snap = MetricAssertion::snapshot();
+
+        record_client_error(kind, "web", 1);
+
+        snap.counter("gc_client_errors_total")
+            .with_labels(&[("kind", *kind), ("platform", "web")])
+            .assert_delta(1);
+        // Label-swap catcher: other kinds silent.
+        for sibling in ALL_KINDS.iter().filter(|k| *k != kind) {
+            snap.counter("gc_client_errors_total")
+                .with_labels(&[("kind", *sibling), ("platform", "web")])
+                .assert_delta(0);
+        }
+    }
+}
+
+#[test]
+fn client_error_counts_sample_weight() {
+    let snap = MetricAssertion::snapshot();
+
+    record_client_error("transport_fallback", "ios", 100);
+
+    snap.counter("gc_client_errors_total")
+        .with_labels(&[("kind", "transport_fallback"), ("platform", "ios")])
+        .assert_delta(100);
+    for sibling in ["web", "android", "desktop"] {
+        snap.counter("gc_client_errors_total")
+            .with_labels(&[("kind", "transport_fallback"), ("platform", sibling)])
+            .assert_delta(0);
+    }
+}
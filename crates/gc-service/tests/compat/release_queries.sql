@@ -0,0 +1,326 @@
+-- Queries the last released GC binary runs against the shared database.
+--
+-- tests/migration_compat_tests.rs replays these against a database migrated
+-- to HEAD, so a migration that would break the previous release during a
+-- rolling deploy fails CI here. Format: see `common::migration_compat`.
+--
+-- When cutting a release, refresh this file from that release's
+-- repositories. Never edit a query to make a new migration pass: fix the
+-- migration (expand first, contract one release later) instead.
+
+-- @setup
+INSERT INTO organizations (org_id, subdomain, display_name)
+VALUES ('00000000-0000-0000-0000-00000000000a', 'compat', 'Compat Org');
+
+INSERT INTO users (user_id, org_id, email, password_hash, display_name)
+VALUES ('00000000-0000-0000-0000-00000000000b', '00000000-0000-0000-0000-00000000000a',
+        'host@compat.example', 'not-a-real-hash', 'Compat Host');
+
+INSERT INTO meetings (meeting_id, org_id, created_by_user_id, display_name, meeting_code, join_token_secret)
+VALUES ('00000000-0000-0000-0000-00000000000c', '00000000-0000-0000-0000-00000000000a',
+        '00000000-0000-0000-0000-00000000000b', 'Compat Meeting', 'compat-code', 'compat-secret');
+
+INSERT INTO meeting_controllers (controller_id, region, endpoint, grpc_endpoint, max_meetings, max_participants)
+VALUES ('mc-compat', 'us-east-1', 'http://mc-compat:50052', 'http://mc-compat:50052', 100, 1000);
+
+INSERT INTO meeting_assignments (meeting_id, meeting_controller_id, region, assigned_by_gc_id)
+VALUES ('00000000-0000-0000-0000-00000000000c', 'mc-compat', 'us-east-1', 'gc-compat');
+
+-- ============================================================================
+-- Meetings
+-- ============================================================================
+
+-- @query meetings.find_by_code
+-- @param text 'compat-code'
+-- @returns meeting_id UUID, org_id UUID, created_by_user_id UUID, display_name VARCHAR
+-- @returns meeting_code VARCHAR, join_token_secret VARCHAR, max_participants INT4
+-- @returns enable_e2e_encryption BOOL, require_auth BOOL, recording_enabled BOOL
+-- @returns meeting_controller_id VARCHAR, meeting_controller_region VARCHAR, status VARCHAR
+-- @returns scheduled_start_time TIMESTAMPTZ, actual_start_time TIMESTAMPTZ
+-- @returns actual_end_time TIMESTAMPTZ, created_at TIMESTAMPTZ, updated_at TIMESTAMPTZ
+-- @returns allow_guests BOOL, allow_external_participants BOOL, waiting_room_enabled BOOL
+-- @returns duplicate_join_policy VARCHAR
+SELECT
+    meeting_id, org_id, created_by_user_id, display_name, meeting_code,
+    join_token_secret, max_participants, enable_e2e_encryption, require_auth,
+    recording_enabled, meeting_controller_id, meeting_controller_region, status,
+    scheduled_start_time, actual_start_time, actual_end_time, created_at,
+    updated_at, allow_guests, allow_external_participants, waiting_room_enabled,
+    duplicate_join_policy
+FROM meetings
+WHERE meeting_code = $1
+
+-- @query meetings.create
+-- @param uuid '00000000-0000-0000-0000-00000000000a'
+-- @param uuid '00000000-0000-0000-0000-00000000000b'
+-- @param text 'New Meeting'
+-- @param text 'compat-new'
+-- @param text 'new-secret'
+-- @param int4 50
+-- @param bool true
+-- @param bool true
+-- @param bool false
+-- @param bool false
+-- @param bool false
+-- @param bool true
+-- @param timestamptz NULL
+-- @returns meeting_id UUID, org_id UUID, created_by_user_id UUID, display_name VARCHAR
+-- @returns meeting_code VARCHAR, join_token_secret VARCHAR, max_participants INT4
+-- @returns enable_e2e_encryption BOOL, require_auth BOOL, recording_enabled BOOL
+-- @returns meeting_controller_id VARCHAR, meeting_controller_region VARCHAR, status VARCHAR
+-- @returns scheduled_start_time TIMESTAMPTZ, actual_start_time TIMESTAMPTZ
+-- @returns actual_end_time TIMESTAMPTZ, created_at TIMESTAMPTZ, updated_at TIMESTAMPTZ
+-- @returns allow_guests BOOL, allow_external_participants BOOL, waiting_room_enabled BOOL
+-- @returns duplicate_join_policy VARCHAR
+WITH org_limits AS (
+    SELECT max_concurrent_meetings, max_participants_per_meeting
+    FROM organizations
+    WHERE org_id = $1 AND is_active = true
+),
+current_count AS (
+    SELECT COUNT(*) as cnt
+    FROM meetings
+    WHERE org_id = $1 AND status IN ('scheduled', 'active')
+)
+INSERT INTO meetings (
+    org_id, created_by_user_id, display_name, meeting_code,
+    join_token_secret, max_participants, enable_e2e_encryption,
+    require_auth, recording_enabled, allow_guests,
+    allow_external_participants, waiting_room_enabled,
+    scheduled_start_time, status
+)
+SELECT
+    $1, $2, $3, $4, $5,
+    LEAST($6, org_limits.max_participants_per_meeting),
+    $7, $8, $9, $10, $11, $12, $13,
+    'scheduled'
+FROM org_limits, current_count
+WHERE current_count.cnt < org_limits.max_concurrent_meetings
+RETURNING
+    meeting_id, org_id, created_by_user_id, display_name,
+    meeting_code, join_token_secret, max_participants,
+    enable_e2e_encryption, require_auth, recording_enabled,
+    meeting_controller_id, meeting_controller_region,
+    status, scheduled_start_time, actual_start_time,
+    actual_end_time, created_at, updated_at,
+    allow_guests, allow_external_participants, waiting_room_enabled,
+    duplicate_join_policy
+
+-- @query meetings.update_settings
+-- @param uuid '00000000-0000-0000-0000-00000000000c'
+-- @param bool true
+-- @param bool NULL
+-- @param bool false
+-- @param text 'reject'
+-- @returns meeting_id UUID, org_id UUID, created_by_user_id UUID, display_name VARCHAR
+-- @returns meeting_code VARCHAR, join_token_secret VARCHAR, max_participants INT4
+-- @returns enable_e2e_encryption BOOL, require_auth BOOL, recording_enabled BOOL
+-- @returns meeting_controller_id VARCHAR, meeting_controller_region VARCHAR, status VARCHAR
+-- @returns scheduled_start_time TIMESTAMPTZ, actual_start_time TIMESTAMPTZ
+-- @returns actual_end_time TIMESTAMPTZ, created_at TIMESTAMPTZ, updated_at TIMESTAMPTZ
+-- @returns allow_guests BOOL, allow_external_participants BOOL, waiting_room_enabled BOOL
+-- @returns duplicate_join_policy VARCHAR
+UPDATE meetings
+SET
+    allow_guests = COALESCE($2, allow_guests),
+    allow_external_participants = COALESCE($3, allow_external_participants),
+    waiting_room_enabled = COALESCE($4, waiting_room_enabled),
+    duplicate_join_policy = COALESCE($5, duplicate_join_policy),
+    updated_at = NOW()
+WHERE meeting_id = $1
+RETURNING
+    meeting_id, org_id, created_by_user_id, display_name, meeting_code,
+    join_token_secret, max_participants, enable_e2e_encryption, require_auth,
+    recording_enabled, meeting_controller_id, meeting_controller_region, status,
+    scheduled_start_time, actual_start_time, actual_end_time, created_at,
+    updated_at, allow_guests, allow_external_participants, waiting_room_enabled,
+    duplicate_join_policy
+
+-- @query meetings.activate
+-- @param uuid '00000000-0000-0000-0000-00000000000c'
+-- @returns meeting_id UUID, org_id UUID
+UPDATE meetings
+SET status = 'active', actual_start_time = NOW()
+WHERE meeting_id = $1 AND status = 'scheduled'
+RETURNING meeting_id, org_id
+
+-- @query meetings.get_mc_meeting_settings
+-- @param uuid '00000000-0000-0000-0000-00000000000c'
+-- @returns enable_e2e_encryption BOOL, recording_consent_mode VARCHAR
+-- @returns recording_consent_timeout_seconds INT4, duplicate_join_policy VARCHAR
+SELECT m.enable_e2e_encryption, o.recording_consent_mode,
+       o.recording_consent_timeout_seconds, m.duplicate_join_policy
+FROM meetings m
+JOIN organizations o ON o.org_id = m.org_id
+WHERE m.meeting_id = $1
+
+-- ============================================================================
+-- Participants
+-- ============================================================================
+
+-- @query participants.count_active
+-- @param uuid '00000000-0000-0000-0000-00000000000c'
+-- @returns count INT8
+SELECT COUNT(*) as count
+FROM participants
+WHERE meeting_id = $1
+  AND left_at IS NULL
+
+-- @query participants.add
+-- @param uuid '00000000-0000-0000-0000-00000000000c'
+-- @param uuid '00000000-0000-0000-0000-00000000000b'
+-- @param text 'Compat Host'
+-- @param text 'member'
+-- @param text 'host'
+-- @returns participant_id UUID, meeting_id UUID, user_id UUID, display_name VARCHAR
+-- @returns participant_type VARCHAR, role VARCHAR, joined_at TIMESTAMPTZ, left_at TIMESTAMPTZ
+INSERT INTO participants (meeting_id, user_id, display_name, participant_type, role)
+VALUES ($1, $2, $3, $4, $5)
+RETURNING participant_id, meeting_id, user_id, display_name,
+          participant_type, role, joined_at, left_at
+
+-- @query participants.remove
+-- @param uuid '00000000-0000-0000-0000-00000000000c'
+-- @param uuid '00000000-0000-0000-0000-00000000000b'
+UPDATE participants
+SET left_at = NOW()
+WHERE meeting_id = $1
+  AND user_id = $2
+  AND left_at IS NULL
+
+-- ============================================================================
+-- Meeting Controllers
+-- ============================================================================
+
+-- @query meeting_controllers.register
+-- @param text 'mc-compat-2'
+-- @param text 'us-east-1'
+-- @param text 'http://mc-compat-2:50052'
+-- @param text 'http://mc-compat-2:50052'
+-- @param text 'https://mc-compat-2:4433'
+-- @param int4 100
+-- @param int4 1000
+INSERT INTO meeting_controllers (
+    controller_id, region, endpoint, grpc_endpoint, webtransport_endpoint,
+    max_meetings, max_participants, health_status, last_heartbeat_at
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, 'pending', NOW())
+ON CONFLICT (controller_id) DO UPDATE SET
+    region = EXCLUDED.region,
+    endpoint = EXCLUDED.endpoint,
+    grpc_endpoint = EXCLUDED.grpc_endpoint,
+    webtransport_endpoint = EXCLUDED.webtransport_endpoint,
+    max_meetings = EXCLUDED.max_meetings,
+    max_participants = EXCLUDED.max_participants,
+    health_status = 'pending',
+    last_heartbeat_at = NOW(),
+    updated_at = NOW()
+
+-- @query meeting_controllers.heartbeat
+-- @param text 'mc-compat'
+-- @param int4 1
+-- @param int4 4
+-- @param text 'healthy'
+UPDATE meeting_controllers
+SET
+    current_meetings = $2,
+    current_participants = $3,
+    health_status = $4,
+    last_heartbeat_at = NOW(),
+    updated_at = NOW()
+WHERE controller_id = $1
+
+-- @query meeting_controllers.mark_stale_unhealthy
+-- @param text '30'
+UPDATE meeting_controllers
+SET
+    health_status = 'unhealthy',
+    updated_at = NOW()
+WHERE
+    last_heartbeat_at < NOW() - ($1 || ' seconds')::INTERVAL
+    AND health_status != 'unhealthy'
+    AND health_status != 'draining'
+
+-- @query meeting_controllers.get
+-- @param text 'mc-compat'
+-- @returns controller_id VARCHAR, region VARCHAR, endpoint VARCHAR, grpc_endpoint VARCHAR
+-- @returns webtransport_endpoint VARCHAR, max_meetings INT4, current_meetings INT4
+-- @returns max_participants INT4, current_participants INT4, health_status VARCHAR
+-- @returns last_heartbeat_at TIMESTAMPTZ, created_at TIMESTAMPTZ, updated_at TIMESTAMPTZ
+SELECT
+    controller_id, region, endpoint, grpc_endpoint, webtransport_endpoint,
+    max_meetings, current_meetings, max_participants, current_participants,
+    health_status, last_heartbeat_at, created_at, updated_at
+FROM meeting_controllers
+WHERE controller_id = $1
+
+-- @query meeting_controllers.count_by_status
+-- @returns health_status VARCHAR, count INT8
+SELECT health_status, COUNT(*) as count
+FROM meeting_controllers
+GROUP BY health_status
+
+-- ============================================================================
+-- Meeting Assignments
+-- ============================================================================
+
+-- @query meeting_assignments.get_healthy_assignment
+-- @param text '00000000-0000-0000-0000-00000000000c'
+-- @param text 'us-east-1'
+-- @param text '30'
+-- @returns meeting_controller_id VARCHAR, grpc_endpoint VARCHAR, webtransport_endpoint VARCHAR
+SELECT
+    ma.meeting_controller_id,
+    mc.grpc_endpoint,
+    mc.webtransport_endpoint
+FROM meeting_assignments ma
+JOIN meeting_controllers mc ON ma.meeting_controller_id = mc.controller_id
+WHERE ma.meeting_id = $1
+  AND ma.region = $2
+  AND ma.ended_at IS NULL
+  AND mc.health_status = 'healthy'
+  AND mc.last_heartbeat_at > NOW() - ($3 || ' seconds')::INTERVAL
+
+-- @query meeting_assignments.get_candidate_mcs
+-- @param text 'us-east-1'
+-- @param text '30'
+-- @param int8 5
+-- @returns controller_id VARCHAR, grpc_endpoint VARCHAR, webtransport_endpoint VARCHAR
+-- @returns load_ratio FLOAT8
+SELECT
+    controller_id,
+    grpc_endpoint,
+    webtransport_endpoint,
+    CASE
+        WHEN max_meetings = 0 THEN 1.0
+        ELSE (current_meetings::float / max_meetings)
+    END AS load_ratio
+FROM meeting_controllers
+WHERE health_status = 'healthy'
+  AND region = $1
+  AND current_meetings < max_meetings
+  AND last_heartbeat_at > NOW() - ($2 || ' seconds')::INTERVAL
+ORDER BY load_ratio ASC, last_heartbeat_at DESC
+LIMIT $3
+
+-- @query meeting_assignments.atomic_assign
+-- @param text '00000000-0000-0000-0000-00000000000c'
+-- @param text 'us-east-1'
+-- @param text 'mc-compat'
+-- @param text 'gc-compat'
+-- @param text '30'
+-- @returns meeting_controller_id VARCHAR
+INSERT INTO meeting_assignments (meeting_id, meeting_controller_id, region, assigned_by_gc_id)
+VALUES ($1, $3, $2, $4)
+ON CONFLICT (meeting_id, region) DO UPDATE
+SET meeting_controller_id = EXCLUDED.meeting_controller_id,
+    assigned_by_gc_id = EXCLUDED.assigned_by_gc_id,
+    assigned_at = NOW()
+WHERE EXISTS (
+    -- Only update if current assignment's MC is unhealthy or stale
+    SELECT 1 FROM meeting_controllers mc
+    WHERE mc.controller_id = meeting_assignments.meeting_controller_id
+      AND (mc.health_status != 'healthy'
+           OR mc.last_heartbeat_at < NOW() - ($5 || ' seconds')::INTERVAL)
+)
+RETURNING meeting_controller_id
@@ -0,0 +1,165 @@
+//! MC/MH cordoning integration tests.
+//!
+//! Tests cordoning including:
+//! - A cordoned MC or MH is never a candidate for new meetings
+//! - Meetings already on a cordoned MC stay there
+//! - Uncordoning makes it a candidate again
+//! - Re-registration leaves the cordon in place
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use gc_service::repositories::{
+    HealthStatus, McCandidate, MediaHandlersRepository, MeetingAssignmentsRepository,
+    MeetingControllersRepository,
+};
+use sqlx::PgPool;
+
+/// Register a healthy MC in us-east-1.
+async fn register_healthy_mc(pool: &PgPool, id: &str) -> Result<(), anyhow::Error> {
+    MeetingControllersRepository::register_mc(
+        pool,
+        id,
+        "us-east-1",
+        &format!("http://{}.example.com:50051", id),
+        Some(&format!("https://{}.example.com:443", id)),
+        100,
+        1000,
+    )
+    .await?;
+
+    MeetingControllersRepository::update_heartbeat(pool, id, 0, 0, HealthStatus::Healthy).await?;
+
+    Ok(())
+}
+
+/// Register a healthy MH in us-east-1.
+async fn register_healthy_mh(pool: &PgPool, id: &str) -> Result<(), anyhow::Error> {
+    MediaHandlersRepository::register_mh(
+        pool,
+        id,
+        "us-east-1",
+        &format!("https://{}.example.com:443", id),
+        &format!("grpc://{}.example.com:50051", id),
+        1000,
+    )
+    .await?;
+
+    MediaHandlersRepository::update_load_report(
+        pool,
+        id,
+        0,
+        HealthStatus::Healthy,
+        None,
+        None,
+        None,
+    )
+    .await?;
+
+    Ok(())
+}
+
+async fn candidate_mc_ids(pool: &PgPool) -> Result<Vec<String>, anyhow::Error> {
+    Ok(
+        MeetingAssignmentsRepository::get_candidate_mcs(pool, "us-east-1")
+            .await?
+            .into_iter()
+            .map(|c| c.controller_id)
+            .collect(),
+    )
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_cordoned_mc_is_not_a_candidate(pool: PgPool) -> Result<(), anyhow::Error> {
+    register_healthy_mc(&pool, "mc-0").await?;
+    register_healthy_mc(&pool, "mc-1").await?;
+
+    assert!(MeetingControllersRepository::set_cordoned(&pool, "mc-0", true).await?);
+
+    assert_eq!(candidate_mc_ids(&pool).await?, vec!["mc-1".to_string()]);
+
+    let controller = MeetingControllersRepository::get_controller(&pool, "mc-0")
+        .await?
+        .expect("mc-0 should be registered");
+    assert!(controller.cordoned_at.is_some());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_cordon_leaves_existing_meetings(pool: PgPool) -> Result<(), anyhow::Error> {
+    register_healthy_mc(&pool, "mc-0").await?;
+    let candidate = McCandidate {
+        controller_id: "mc-0".to_string(),
+        grpc_endpoint: "http://mc-0.example.com:50051".to_string(),
+        webtransport_endpoint: None,
+        load_ratio: 0.0,
+    };
+    MeetingAssignmentsRepository::atomic_assign(
+        &pool,
+        "meeting-1",
+        "us-east-1",
+        &candidate,
+        "gc-test-001",
+    )
+    .await?;
+
+    MeetingControllersRepository::set_cordoned(&pool, "mc-0", true).await?;
+
+    let assignment =
+        MeetingAssignmentsRepository::get_healthy_assignment(&pool, "meeting-1", "us-east-1")
+            .await?
+            .expect("assignment should survive the cordon");
+    assert_eq!(assignment.mc_id, "mc-0");
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_uncordon_restores_candidate(pool: PgPool) -> Result<(), anyhow::Error> {
+    register_healthy_mc(&pool, "mc-0").await?;
+
+    MeetingControllersRepository::set_cordoned(&pool, "mc-0", true).await?;
+    assert!(candidate_mc_ids(&pool).await?.is_empty());
+
+    assert!(MeetingControllersRepository::set_cordoned(&pool, "mc-0", false).await?);
+    assert_eq!(candidate_mc_ids(&pool).await?, vec!["mc-0".to_string()]);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_cordon_survives_reregistration(pool: PgPool) -> Result<(), anyhow::Error> {
+    register_healthy_mc(&pool, "mc-0").await?;
+    MeetingControllersRepository::set_cordoned(&pool, "mc-0", true).await?;
+
+    register_healthy_mc(&pool, "mc-0").await?;
+
+    assert!(candidate_mc_ids(&pool).await?.is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_cordon_unknown_mc_returns_false(pool: PgPool) -> Result<(), anyhow::Error> {
+    assert!(!MeetingControllersRepository::set_cordoned(&pool, "mc-missing", true).await?);
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_cordoned_mh_is_not_a_candidate(pool: PgPool) -> Result<(), anyhow::Error> {
+    register_healthy_mh(&pool, "mh-0").await?;
+    register_healthy_mh(&pool, "mh-1").await?;
+
+    assert!(MediaHandlersRepository::set_cordoned(&pool, "mh-0", true).await?);
+
+    let candidates = MediaHandlersRepository::get_candidate_mhs(&pool, "us-east-1").await?;
+    let ids: Vec<_> = candidates.into_iter().map(|c| c.handler_id).collect();
+    assert_eq!(ids, vec!["mh-1".to_string()]);
+
+    let handlers = MediaHandlersRepository::list_handlers(&pool).await?;
+    assert_eq!(handlers.len(), 2);
+    assert!(handlers[0].cordoned_at.is_some());
+    assert!(handlers[1].cordoned_at.is_none());
+
+    Ok(())
+}
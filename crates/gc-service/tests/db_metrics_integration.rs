@@ -50,6 +50,7 @@
 use std::time::Duration;
 
 use ::common::observability::testing::MetricAssertion;
+use gc_service::models::MeetingPriority;
 use gc_service::observability::metrics::record_db_query;
 use gc_service::repositories::{
     HealthStatus, MediaHandlersRepository, MeetingsRepository, ParticipantsRepository,
@@ -138,6 +139,7 @@ async fn db_query_create_meeting_success_emits_operation_create_meeting(pool: Pg
         false,
         true,
         None,
+        MeetingPriority::Standard,
     )
     .await
     .unwrap();
@@ -228,7 +230,7 @@ async fn db_query_count_active_participants_success_wrapper_cat_c_orphan(pool: P
     let meeting_id = insert_test_meeting(&pool, org_id, user_id, "COUNTPARTAA").await;
 
     let snap = MetricAssertion::snapshot();
-    let _ = ParticipantsRepository::count_active_participants(&pool, meeting_id)
+    let _ = ParticipantsRepository::count_active_participants(&pool, meeting_id.into())
         .await
         .unwrap();
 
@@ -263,7 +265,7 @@ async fn db_query_add_participant_success_wrapper_cat_c_orphan(pool: PgPool) {
     let snap = MetricAssertion::snapshot();
     let _ = ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user_id),
         "Test Participant",
         "member",
@@ -295,7 +297,7 @@ async fn db_query_remove_participant_success_wrapper_cat_c_orphan(pool: PgPool)
     let meeting_id = insert_test_meeting(&pool, org_id, user_id, "REMPARTOK01").await;
     ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user_id),
         "Test",
         "member",
@@ -305,7 +307,7 @@ async fn db_query_remove_participant_success_wrapper_cat_c_orphan(pool: PgPool)
     .unwrap();
 
     let snap = MetricAssertion::snapshot();
-    let _ = ParticipantsRepository::remove_participant(&pool, meeting_id, user_id)
+    let _ = ParticipantsRepository::remove_participant(&pool, meeting_id.into(), user_id)
         .await
         .unwrap();
 
@@ -423,6 +425,7 @@ async fn db_query_create_meeting_unique_collision_emits_status_error(pool: PgPoo
         false,
         true,
         None,
+        MeetingPriority::Standard,
     )
     .await
     .unwrap();
@@ -444,6 +447,7 @@ async fn db_query_create_meeting_unique_collision_emits_status_error(pool: PgPoo
         false,
         true,
         None,
+        MeetingPriority::Standard,
     )
     .await;
     assert!(result.is_err(), "Expected unique-constraint collision");
@@ -0,0 +1,186 @@
+//! Experiment bucketing integration tests.
+//!
+//! Tests the ExperimentsRepository and ExperimentService including:
+//! - Only enabled experiments are bucketed
+//! - Assignments are recorded per joiner, and a rejoin keeps the first
+//! - Org-unit experiments give a whole meeting one variant
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use gc_service::repositories::ExperimentsRepository;
+use gc_service::services::{ExperimentService, ExperimentSubject};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Insert fixture org, user, and meeting rows. Returns (org_id, meeting_id).
+async fn create_test_meeting(pool: &PgPool) -> (Uuid, Uuid) {
+    let org_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let meeting_id = Uuid::new_v4();
+
+    sqlx::query(
+        r#"
+        INSERT INTO organizations (org_id, subdomain, display_name, plan_tier)
+        VALUES ($1, $2, 'Test Org', 'free')
+        "#,
+    )
+    .bind(org_id)
+    .bind(format!("test-{}", &org_id.to_string()[..8]))
+    .execute(pool)
+    .await
+    .expect("Failed to insert test org");
+
+    sqlx::query(
+        r#"
+        INSERT INTO users (user_id, org_id, email, password_hash, display_name)
+        VALUES ($1, $2, $3, 'hashed', 'Test User')
+        "#,
+    )
+    .bind(user_id)
+    .bind(org_id)
+    .bind(format!("test-{}@example.com", &user_id.to_string()[..8]))
+    .execute(pool)
+    .await
+    .expect("Failed to insert test user");
+
+    sqlx::query(
+        r#"
+        INSERT INTO meetings (meeting_id, org_id, created_by_user_id, display_name,
+                              meeting_code, join_token_secret, status)
+        VALUES ($1, $2, $3, 'Test Meeting', $4, 'secret123', 'active')
+        "#,
+    )
+    .bind(meeting_id)
+    .bind(org_id)
+    .bind(user_id)
+    .bind(format!("CODE{}", &meeting_id.to_string()[..8]))
+    .execute(pool)
+    .await
+    .expect("Failed to insert test meeting");
+
+    (org_id, meeting_id)
+}
+
+async fn create_experiment(
+    pool: &PgPool,
+    name: &str,
+    unit: &str,
+    variants: &[&str],
+    enabled: bool,
+) {
+    sqlx::query(
+        r#"
+        INSERT INTO experiments (name, salt, unit, variants, enabled)
+        VALUES ($1, $1, $2, $3, $4)
+        "#,
+    )
+    .bind(name)
+    .bind(unit)
+    .bind(variants)
+    .bind(enabled)
+    .execute(pool)
+    .await
+    .expect("Failed to insert experiment");
+}
+
+async fn recorded(pool: &PgPool, meeting_id: Uuid) -> Vec<(String, String, String, String)> {
+    sqlx::query_as(
+        r#"
+        SELECT experiment, subject_id, subject_type, variant
+        FROM experiment_assignments
+        WHERE meeting_id = $1
+        ORDER BY experiment, subject_id
+        "#,
+    )
+    .bind(meeting_id)
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_list_enabled_skips_disabled(pool: PgPool) {
+    create_experiment(&pool, "simulcast", "user", &["on", "off"], true).await;
+    create_experiment(&pool, "retired", "user", &["a", "b"], false).await;
+
+    let experiments = ExperimentsRepository::list_enabled(&pool).await.unwrap();
+
+    let names: Vec<&str> = experiments.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, ["simulcast"]);
+    assert_eq!(experiments[0].variants, ["on", "off"]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_assign_for_join_records_variants(pool: PgPool) {
+    create_experiment(&pool, "simulcast", "user", &["on", "off"], true).await;
+    let (org_id, meeting_id) = create_test_meeting(&pool).await;
+    let guest_id = Uuid::new_v4();
+
+    let variants = ExperimentService::assign_for_join(
+        &pool,
+        meeting_id,
+        org_id,
+        ExperimentSubject::Guest(guest_id),
+    )
+    .await;
+
+    let variant = variants.get("simulcast").unwrap().clone();
+    assert_eq!(
+        recorded(&pool, meeting_id).await,
+        vec![(
+            "simulcast".to_string(),
+            guest_id.to_string(),
+            "guest".to_string(),
+            variant.clone(),
+        )]
+    );
+
+    // A rejoin gets the same variant and does not add a row
+    let again = ExperimentService::assign_for_join(
+        &pool,
+        meeting_id,
+        org_id,
+        ExperimentSubject::Guest(guest_id),
+    )
+    .await;
+    assert_eq!(again.get("simulcast"), Some(&variant));
+    assert_eq!(recorded(&pool, meeting_id).await.len(), 1);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_org_experiment_gives_meeting_one_variant(pool: PgPool) {
+    create_experiment(&pool, "codec", "org", &["vp9", "av1", "h264"], true).await;
+    let (org_id, meeting_id) = create_test_meeting(&pool).await;
+
+    let mut seen = Vec::new();
+    for _ in 0..5 {
+        let variants = ExperimentService::assign_for_join(
+            &pool,
+            meeting_id,
+            org_id,
+            ExperimentSubject::User(Uuid::new_v4()),
+        )
+        .await;
+        seen.push(variants.get("codec").unwrap().clone());
+    }
+
+    seen.dedup();
+    assert_eq!(seen.len(), 1);
+    assert_eq!(recorded(&pool, meeting_id).await.len(), 5);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_assign_for_join_without_experiments(pool: PgPool) {
+    let (org_id, meeting_id) = create_test_meeting(&pool).await;
+
+    let variants = ExperimentService::assign_for_join(
+        &pool,
+        meeting_id,
+        org_id,
+        ExperimentSubject::User(Uuid::new_v4()),
+    )
+    .await;
+
+    assert!(variants.is_empty());
+    assert!(recorded(&pool, meeting_id).await.is_empty());
+}
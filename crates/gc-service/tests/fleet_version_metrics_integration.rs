@@ -0,0 +1,112 @@
+//! Integration cover for `gc_fleet_versions` and
+//! `gc_registration_version_rejections_total`.
+//!
+//! `MetricAssertion`'s per-thread recorder isolation applies. No tokio
+//! runtime pinning needed — both wrappers are synchronous.
+//!
+//! `update_fleet_version_gauges` remembers the label sets it last set in a
+//! process-wide static, so each test uses its own `controller_type` to stay
+//! independent of the others running in parallel.
+//!
+//! Production recording sites are
+//! `crates/gc-service/src/services/fleet_versions.rs` (gauge) and
+//! `crates/gc-service/src/grpc/{mc_service,mh_service}.rs` (counter).
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use ::common::observability::testing::MetricAssertion;
+use gc_service::observability::metrics::{
+    record_registration_version_rejection, update_fleet_version_gauges,
+};
+
+fn count(version: &str, proto_schema: &str, n: u64) -> (String, String, u64) {
+    (version.to_string(), proto_schema.to_string(), n)
+}
+
+#[test]
+fn fleet_versions_sets_count_per_build() {
+    let snap = MetricAssertion::snapshot();
+
+    update_fleet_version_gauges(
+        "meeting",
+        &[count("0.2.0", "abc", 4), count("unknown", "unknown", 1)],
+    );
+
+    snap.gauge("gc_fleet_versions")
+        .with_labels(&[
+            ("controller_type", "meeting"),
+            ("version", "0.2.0"),
+            ("proto_schema", "abc"),
+        ])
+        .assert_value(4.0);
+    snap.gauge("gc_fleet_versions")
+        .with_labels(&[
+            ("controller_type", "meeting"),
+            ("version", "unknown"),
+            ("proto_schema", "unknown"),
+        ])
+        .assert_value(1.0);
+}
+
+#[test]
+fn fleet_versions_zeroes_builds_that_leave_the_fleet() {
+    // A rolling deploy from 0.1.0 to 0.2.0: the old build must drop to
+    // zero rather than keep reporting its last count.
+    let snap = MetricAssertion::snapshot();
+
+    update_fleet_version_gauges(
+        "media",
+        &[count("0.1.0", "abc", 3), count("0.2.0", "def", 1)],
+    );
+    update_fleet_version_gauges("media", &[count("0.2.0", "def", 4)]);
+
+    snap.gauge("gc_fleet_versions")
+        .with_labels(&[
+            ("controller_type", "media"),
+            ("version", "0.1.0"),
+            ("proto_schema", "abc"),
+        ])
+        .assert_value(0.0);
+    snap.gauge("gc_fleet_versions")
+        .with_labels(&[
+            ("controller_type", "media"),
+            ("version", "0.2.0"),
+            ("proto_schema", "def"),
+        ])
+        .assert_value(4.0);
+}
+
+#[test]
+fn fleet_versions_leaves_other_controller_type_alone() {
+    let snap = MetricAssertion::snapshot();
+
+    update_fleet_version_gauges("type-a", &[count("0.2.0", "abc", 2)]);
+    update_fleet_version_gauges("type-b", &[]);
+
+    snap.gauge("gc_fleet_versions")
+        .with_labels(&[
+            ("controller_type", "type-a"),
+            ("version", "0.2.0"),
+            ("proto_schema", "abc"),
+        ])
+        .assert_value(2.0);
+    snap.gauge("gc_fleet_versions")
+        .with_labels(&[("controller_type", "type-b")])
+        .assert_unobserved();
+}
+
+#[test]
+fn registration_version_rejections_counts_per_controller_type() {
+    for (controller_type, other) in [("meeting", "media"), ("media", "meeting")] {
+        let snap = MetricAssertion::snapshot();
+
+        record_registration_version_rejection(controller_type);
+
+        snap.counter("gc_registration_version_rejections_total")
+            .with_labels(&[("controller_type", controller_type)])
+            .assert_delta(1);
+        snap.counter("gc_registration_version_rejections_total")
+            .with_labels(&[("controller_type", other)])
+            .assert_delta(0);
+    }
+}
@@ -73,3 +73,55 @@ async fn test_unknown_route_returns_404(pool: PgPool) -> Result<(), anyhow::Erro
 
     Ok(())
 }
+
+/// Send `GET {uri}` over a Unix socket and return the raw response.
+#[cfg(unix)]
+async fn get_over_unix_socket(path: &std::path::Path, uri: &str) -> Result<String, anyhow::Error> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut stream = tokio::net::UnixStream::connect(path).await?;
+    stream
+        .write_all(
+            format!("GET {uri} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+                .as_bytes(),
+        )
+        .await?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await?;
+    Ok(response)
+}
+
+/// Test that health and metrics are served on the configured Unix socket
+/// with the configured permissions, and API routes are not.
+#[cfg(unix)]
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_health_socket_serves_ops_routes(pool: PgPool) -> Result<(), anyhow::Error> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = std::env::temp_dir().join(format!("gc-health-{}.sock", uuid::Uuid::new_v4()));
+    let path_str = path.to_string_lossy().to_string();
+    let _server = TestGcServer::spawn_with_vars(
+        pool,
+        &[
+            ("GC_HEALTH_SOCKET_PATH", &path_str),
+            ("GC_HEALTH_SOCKET_MODE", "600"),
+        ],
+    )
+    .await?;
+
+    let mode = std::fs::metadata(&path)?.permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    let health = get_over_unix_socket(&path, "/health").await?;
+    assert!(health.starts_with("HTTP/1.1 200"), "{health}");
+    assert!(health.ends_with("OK"), "{health}");
+
+    let metrics = get_over_unix_socket(&path, "/metrics").await?;
+    assert!(metrics.starts_with("HTTP/1.1 200"), "{metrics}");
+
+    let api = get_over_unix_socket(&path, "/api/v1/me").await?;
+    assert!(api.starts_with("HTTP/1.1 404"), "{api}");
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
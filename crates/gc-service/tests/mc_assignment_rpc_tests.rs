@@ -4,14 +4,18 @@
 
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 
+use gc_service::errors::GcError;
+use gc_service::models::{DuplicateJoinPolicy, RecordingLayout};
 use gc_service::repositories::{
-    HealthStatus, MediaHandlersRepository, MeetingControllersRepository,
+    HealthStatus, McMeetingSettings, MediaHandlersRepository, MeetingControllersRepository,
+    RecordingConsentPolicy, SPILLOVER_MC_POOL,
 };
 use gc_service::services::mc_client::mock::MockMcClient;
 use gc_service::services::mc_client::{McAssignmentResult, McRejectionReason};
 use gc_service::services::McAssignmentService;
 use sqlx::PgPool;
 use std::sync::Arc;
+use uuid::Uuid;
 
 /// Helper to set up test MCs.
 async fn setup_mcs(pool: &PgPool, count: usize, region: &str) {
@@ -471,3 +475,358 @@ async fn test_concurrent_assignment_same_meeting(pool: PgPool) {
         total_calls
     );
 }
+
+/// Test the meeting's E2E flag and org recording consent policy are sent with
+/// the assignment.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_assign_meeting_with_mh_sends_meeting_settings(pool: PgPool) {
+    setup_mcs(&pool, 1, "us-east-1").await;
+    setup_mhs(&pool, 1, "us-east-1").await;
+
+    let org_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let meeting_id = Uuid::new_v4();
+
+    sqlx::query(
+        r#"
+        INSERT INTO organizations (org_id, subdomain, display_name, plan_tier,
+                                   recording_consent_mode, recording_consent_timeout_seconds)
+        VALUES ($1, 'consent-test', 'Consent Org', 'pro', 'notify', 30)
+        "#,
+    )
+    .bind(org_id)
+    .execute(&pool)
+    .await
+    .expect("Failed to insert test org");
+
+    sqlx::query(
+        r#"
+        INSERT INTO users (user_id, org_id, email, password_hash, display_name)
+        VALUES ($1, $2, 'consent@example.com', 'hashed', 'Test User')
+        "#,
+    )
+    .bind(user_id)
+    .bind(org_id)
+    .execute(&pool)
+    .await
+    .expect("Failed to insert test user");
+
+    sqlx::query(
+        r#"
+        INSERT INTO meetings (meeting_id, org_id, created_by_user_id, display_name,
+                              meeting_code, join_token_secret, enable_e2e_encryption,
+                              duplicate_join_policy, recording_layout)
+        VALUES ($1, $2, $3, 'Consent Meeting', 'CONSENT00001', 'secret123', true, 'reject',
+                'speaker')
+        "#,
+    )
+    .bind(meeting_id)
+    .bind(org_id)
+    .bind(user_id)
+    .execute(&pool)
+    .await
+    .expect("Failed to insert test meeting");
+
+    let mock_client = Arc::new(MockMcClient::accepting());
+    McAssignmentService::assign_meeting_with_mh(
+        &pool,
+        mock_client.clone(),
+        &meeting_id.to_string(),
+        "us-east-1",
+        "gc-test",
+    )
+    .await
+    .expect("Assignment should succeed");
+
+    assert_eq!(
+        mock_client.last_settings(),
+        Some(McMeetingSettings {
+            e2e_enabled: true,
+            recording_policy: RecordingConsentPolicy {
+                require_ack: false,
+                consent_timeout_seconds: 30,
+            },
+            duplicate_join_policy: DuplicateJoinPolicy::Reject,
+            recording_layout: RecordingLayout::Speaker,
+        })
+    );
+
+    // Unknown meetings fall back to the MC default
+    McAssignmentService::assign_meeting_with_mh(
+        &pool,
+        mock_client.clone(),
+        "meeting-unknown",
+        "us-east-1",
+        "gc-test",
+    )
+    .await
+    .expect("Assignment should succeed");
+    assert_eq!(mock_client.last_settings(), None);
+}
+
+/// Insert an organization with `mc_pool` and one of its meetings.
+async fn insert_pooled_meeting(pool: &PgPool, mc_pool: &str) -> Uuid {
+    let org_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let meeting_id = Uuid::new_v4();
+
+    sqlx::query(
+        r#"
+        INSERT INTO organizations (org_id, subdomain, display_name, plan_tier, mc_pool)
+        VALUES ($1, 'pool-test', 'Pool Org', 'enterprise', $2)
+        "#,
+    )
+    .bind(org_id)
+    .bind(mc_pool)
+    .execute(pool)
+    .await
+    .expect("Failed to insert test org");
+
+    sqlx::query(
+        r#"
+        INSERT INTO users (user_id, org_id, email, password_hash, display_name)
+        VALUES ($1, $2, 'pool@example.com', 'hashed', 'Test User')
+        "#,
+    )
+    .bind(user_id)
+    .bind(org_id)
+    .execute(pool)
+    .await
+    .expect("Failed to insert test user");
+
+    sqlx::query(
+        r#"
+        INSERT INTO meetings (meeting_id, org_id, created_by_user_id, display_name,
+                              meeting_code, join_token_secret)
+        VALUES ($1, $2, $3, 'Pool Meeting', 'POOL00000001', 'secret123')
+        "#,
+    )
+    .bind(meeting_id)
+    .bind(org_id)
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .expect("Failed to insert test meeting");
+
+    meeting_id
+}
+
+/// Test meetings of an org with a dedicated pool land on that pool's MCs,
+/// and other meetings never do.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_assign_meeting_with_mh_uses_dedicated_pool(pool: PgPool) {
+    setup_mcs(&pool, 3, "us-east-1").await;
+    setup_mhs(&pool, 1, "us-east-1").await;
+    MeetingControllersRepository::set_pool(&pool, "mc-us-east-1-3", "acme")
+        .await
+        .expect("Setting pool should succeed");
+    let meeting_id = insert_pooled_meeting(&pool, "acme").await;
+
+    let mock_client = Arc::new(MockMcClient::accepting());
+    let result = McAssignmentService::assign_meeting_with_mh(
+        &pool,
+        mock_client.clone(),
+        &meeting_id.to_string(),
+        "us-east-1",
+        "gc-test",
+    )
+    .await
+    .expect("Assignment should succeed");
+    assert_eq!(result.mc_assignment.mc_id, "mc-us-east-1-3");
+
+    for i in 0..5 {
+        let result = McAssignmentService::assign_meeting_with_mh(
+            &pool,
+            mock_client.clone(),
+            &format!("meeting-shared-{}", i),
+            "us-east-1",
+            "gc-test",
+        )
+        .await
+        .expect("Assignment should succeed");
+        assert_ne!(
+            result.mc_assignment.mc_id, "mc-us-east-1-3",
+            "Default-pool meetings must not use dedicated MCs"
+        );
+    }
+}
+
+/// Test a dedicated pool without available MCs falls back to the default pool.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_assign_meeting_with_mh_falls_back_to_default_pool(pool: PgPool) {
+    setup_mcs(&pool, 2, "us-east-1").await;
+    setup_mhs(&pool, 1, "us-east-1").await;
+    MeetingControllersRepository::set_pool(&pool, "mc-us-east-1-2", "acme")
+        .await
+        .expect("Setting pool should succeed");
+    let meeting_id = insert_pooled_meeting(&pool, "acme").await;
+
+    // The only dedicated MC rejects, so the retry moves to the default pool
+    let mock_client = Arc::new(MockMcClient::with_responses(vec![
+        McAssignmentResult::Rejected(McRejectionReason::AtCapacity),
+        McAssignmentResult::Accepted,
+    ]));
+    let result = McAssignmentService::assign_meeting_with_mh(
+        &pool,
+        mock_client.clone(),
+        &meeting_id.to_string(),
+        "us-east-1",
+        "gc-test",
+    )
+    .await
+    .expect("Assignment should fall back to the default pool");
+
+    assert_eq!(result.mc_assignment.mc_id, "mc-us-east-1-1");
+    assert_eq!(mock_client.call_count(), 2);
+}
+
+/// Insert a meeting of the given priority (with its org and host).
+async fn insert_meeting_with_priority(pool: &PgPool, priority: &str) -> Uuid {
+    let org_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let meeting_id = Uuid::new_v4();
+
+    sqlx::query(
+        r#"
+        INSERT INTO organizations (org_id, subdomain, display_name, plan_tier)
+        VALUES ($1, $2, 'Priority Org', 'pro')
+        "#,
+    )
+    .bind(org_id)
+    .bind(format!("priority-{}", priority))
+    .execute(pool)
+    .await
+    .expect("Failed to insert test org");
+
+    sqlx::query(
+        r#"
+        INSERT INTO users (user_id, org_id, email, password_hash, display_name)
+        VALUES ($1, $2, 'priority@example.com', 'hashed', 'Test User')
+        "#,
+    )
+    .bind(user_id)
+    .bind(org_id)
+    .execute(pool)
+    .await
+    .expect("Failed to insert test user");
+
+    sqlx::query(
+        r#"
+        INSERT INTO meetings (meeting_id, org_id, created_by_user_id, display_name,
+                              meeting_code, join_token_secret, priority)
+        VALUES ($1, $2, $3, 'Priority Meeting', $4, 'secret123', $5)
+        "#,
+    )
+    .bind(meeting_id)
+    .bind(org_id)
+    .bind(user_id)
+    .bind(format!("PRIO{:0>8}", priority.to_uppercase()))
+    .bind(priority)
+    .execute(pool)
+    .await
+    .expect("Failed to insert test meeting");
+
+    meeting_id
+}
+
+/// Register a healthy MC hosting `current_meetings` of 100.
+async fn setup_loaded_mc(pool: &PgPool, id: &str, current_meetings: i32) {
+    MeetingControllersRepository::register_mc(
+        pool,
+        id,
+        "us-east-1",
+        &format!("grpc://{}:50051", id),
+        Some(&format!("https://{}:443", id)),
+        100,
+        1000,
+    )
+    .await
+    .expect("MC registration should succeed");
+
+    MeetingControllersRepository::update_heartbeat(
+        pool,
+        id,
+        current_meetings,
+        50,
+        HealthStatus::Healthy,
+    )
+    .await
+    .expect("MC heartbeat should succeed");
+}
+
+/// Test a busy MC keeps taking standard meetings while test meetings spill
+/// over to the spill-over pool.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_assign_meeting_with_mh_spills_over_low_priority(pool: PgPool) {
+    setup_loaded_mc(&pool, "mc-busy", 80).await;
+    setup_loaded_mc(&pool, "mc-spill", 0).await;
+    MeetingControllersRepository::set_pool(&pool, "mc-spill", SPILLOVER_MC_POOL)
+        .await
+        .expect("Setting pool should succeed");
+    setup_mhs(&pool, 1, "us-east-1").await;
+    let test_meeting = insert_meeting_with_priority(&pool, "test").await;
+    let standard_meeting = insert_meeting_with_priority(&pool, "standard").await;
+
+    let mock_client = Arc::new(MockMcClient::accepting());
+    let result = McAssignmentService::assign_meeting_with_mh(
+        &pool,
+        mock_client.clone(),
+        &test_meeting.to_string(),
+        "us-east-1",
+        "gc-test",
+    )
+    .await
+    .expect("Test meeting should spill over");
+    assert_eq!(result.mc_assignment.mc_id, "mc-spill");
+
+    let result = McAssignmentService::assign_meeting_with_mh(
+        &pool,
+        mock_client.clone(),
+        &standard_meeting.to_string(),
+        "us-east-1",
+        "gc-test",
+    )
+    .await
+    .expect("Standard meeting should use the busy MC");
+    assert_eq!(result.mc_assignment.mc_id, "mc-busy");
+}
+
+/// Test low-priority meetings are rejected, and webinars still placed, when
+/// MCs are busy and there is no spill-over capacity.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_assign_meeting_with_mh_sheds_low_priority_first(pool: PgPool) {
+    setup_loaded_mc(&pool, "mc-busy", 95).await;
+    setup_mhs(&pool, 1, "us-east-1").await;
+    let test_meeting = insert_meeting_with_priority(&pool, "test").await;
+    let standard_meeting = insert_meeting_with_priority(&pool, "standard").await;
+    let webinar_meeting = insert_meeting_with_priority(&pool, "webinar").await;
+
+    let mock_client = Arc::new(MockMcClient::accepting());
+    for meeting_id in [test_meeting, standard_meeting] {
+        let result = McAssignmentService::assign_meeting_with_mh(
+            &pool,
+            mock_client.clone(),
+            &meeting_id.to_string(),
+            "us-east-1",
+            "gc-test",
+        )
+        .await;
+        assert!(
+            matches!(result, Err(GcError::ServiceUnavailable(_))),
+            "Low-priority meeting should be rejected, got {:?}",
+            result
+        );
+    }
+    assert_eq!(mock_client.call_count(), 0);
+
+    let result = McAssignmentService::assign_meeting_with_mh(
+        &pool,
+        mock_client.clone(),
+        &webinar_meeting.to_string(),
+        "us-east-1",
+        "gc-test",
+    )
+    .await
+    .expect("Webinar should use the remaining capacity");
+    assert_eq!(result.mc_assignment.mc_id, "mc-busy");
+}
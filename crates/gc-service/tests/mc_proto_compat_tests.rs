@@ -0,0 +1,323 @@
+//! Wire-compatibility tests between the current GC and MC heartbeat messages
+//! produced by earlier `proto-gen` releases.
+//!
+//! During a rolling deploy the GC is upgraded while MCs built against the
+//! previous proto keep registering and heartbeating. The fixtures under
+//! `tests/fixtures/mc_proto/<version>/` are the exact bytes those MCs put on
+//! the wire; they are vendored, never regenerated from the current proto.
+//!
+//! A proto change that renumbers a field, changes a wire type, or tightens
+//! what GC accepts fails here. When a new release is cut, add a directory for
+//! it instead of editing an existing one.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use common::secret::SecretString;
+use common::token_manager::TokenReceiver;
+use gc_service::config::Config;
+use gc_service::events::EventBus;
+use gc_service::grpc::McService;
+use gc_service::repositories::meeting_controllers::MeetingController;
+use gc_service::repositories::{HealthStatus, MeetingControllersRepository, PgStore};
+use gc_service::routes::AppState;
+use gc_service::services::MockMcClient;
+use proto_gen::dark_tower::internal::v1::global_controller_service_server::GlobalControllerService;
+use proto_gen::dark_tower::internal::v1::{
+    ComprehensiveHeartbeatRequest, FastHeartbeatRequest, RegisterMcRequest,
+};
+use proto_gen::Message;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tonic::{Code, Request};
+
+/// Releases whose MC messages the current GC must still accept.
+const PINNED_VERSIONS: &[&str] = &["v0.1.0"];
+
+/// Controller ID used by every vendored fixture.
+const FIXTURE_CONTROLLER_ID: &str = "mc-compat-1";
+
+/// Load `tests/fixtures/mc_proto/{version}/{name}.hex` as raw bytes.
+///
+/// Lines starting with `#` are comments; the rest is whitespace-separated hex.
+fn fixture(version: &str, name: &str) -> Vec<u8> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/mc_proto")
+        .join(version)
+        .join(format!("{name}.hex"));
+    let text = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("missing MC proto fixture {}: {e}", path.display()));
+
+    let digits: String = text
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .flat_map(|line| line.split_whitespace())
+        .collect();
+    hex::decode(digits).expect("fixture must contain valid hex")
+}
+
+fn decode<M: Message + Default>(version: &str, name: &str) -> M {
+    M::decode(fixture(version, name).as_slice()).unwrap_or_else(|e| {
+        panic!("{version}/{name} no longer decodes with the current proto: {e}")
+    })
+}
+
+fn mc_service(pool: PgPool) -> McService {
+    mc_service_with_vars(pool, &[])
+}
+
+/// `mc_service` with extra GC environment variables.
+fn mc_service_with_vars(pool: PgPool, extra: &[(&str, &str)]) -> McService {
+    let mut vars = HashMap::from([
+        (
+            "DATABASE_URL".to_string(),
+            "postgresql://test/test".to_string(),
+        ),
+        ("BIND_ADDRESS".to_string(), "127.0.0.1:0".to_string()),
+        ("GC_REGION".to_string(), "us-east-1".to_string()),
+        (
+            "AC_JWKS_URL".to_string(),
+            "http://localhost:8082/.well-known/jwks.json".to_string(),
+        ),
+        (
+            "AC_INTERNAL_URL".to_string(),
+            "http://localhost:8082".to_string(),
+        ),
+        ("GC_CLIENT_ID".to_string(), "test-gc-client".to_string()),
+        ("GC_CLIENT_SECRET".to_string(), "test-gc-secret".to_string()),
+    ]);
+    vars.extend(
+        extra
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+    );
+    let config = Config::from_vars(&vars).expect("test config should be valid");
+
+    let (_tx, rx) = watch::channel(SecretString::from("test-token"));
+    let store = Arc::new(PgStore::new(pool.clone(), config.storage_backend));
+
+    McService::new(Arc::new(AppState {
+        pool,
+        config,
+        mc_client: Arc::new(MockMcClient::accepting()),
+        token_receiver: TokenReceiver::from_watch_receiver(rx),
+        event_bus: EventBus::default(),
+        object_store: None,
+        store,
+    }))
+}
+
+// ============================================================================
+// Decoding
+// ============================================================================
+
+#[test]
+fn test_pinned_messages_roundtrip_byte_for_byte() {
+    // Re-encoding with the current types must reproduce the old bytes: any
+    // field that changed number or wire type would be dropped or moved.
+    for version in PINNED_VERSIONS {
+        let bytes = fixture(version, "register_mc");
+        let register = RegisterMcRequest::decode(bytes.as_slice()).unwrap();
+        assert_eq!(register.encode_to_vec(), bytes, "{version}/register_mc");
+
+        let bytes = fixture(version, "fast_heartbeat");
+        let fast = FastHeartbeatRequest::decode(bytes.as_slice()).unwrap();
+        assert_eq!(fast.encode_to_vec(), bytes, "{version}/fast_heartbeat");
+
+        let bytes = fixture(version, "comprehensive_heartbeat");
+        let comprehensive = ComprehensiveHeartbeatRequest::decode(bytes.as_slice()).unwrap();
+        assert_eq!(
+            comprehensive.encode_to_vec(),
+            bytes,
+            "{version}/comprehensive_heartbeat"
+        );
+    }
+}
+
+#[test]
+fn test_pinned_messages_decode_to_expected_values() {
+    for version in PINNED_VERSIONS {
+        let register: RegisterMcRequest = decode(version, "register_mc");
+        assert_eq!(register.id, FIXTURE_CONTROLLER_ID);
+        assert_eq!(register.region, "us-east-1");
+        assert_eq!(register.grpc_endpoint, "https://mc-1.example:50052");
+        assert_eq!(register.webtransport_endpoint, "https://mc-1.example:4433");
+        assert_eq!(register.max_meetings, 100);
+        assert_eq!(register.max_participants, 1000);
+
+        let fast: FastHeartbeatRequest = decode(version, "fast_heartbeat");
+        let capacity = fast.capacity.expect("fixture carries capacity");
+        assert_eq!(capacity.current_meetings, 3);
+        assert_eq!(capacity.current_participants, 42);
+
+        let comprehensive: ComprehensiveHeartbeatRequest =
+            decode(version, "comprehensive_heartbeat");
+        assert!((comprehensive.cpu_usage_percent - 12.5).abs() < f32::EPSILON);
+        assert!((comprehensive.memory_usage_percent - 50.0).abs() < f32::EPSILON);
+    }
+}
+
+#[test]
+fn test_unknown_fields_are_ignored_on_decode() {
+    // The fixture is `fast_heartbeat` plus fields a newer MC might send,
+    // including one nested inside `capacity`
+    for version in PINNED_VERSIONS {
+        let with_unknown: FastHeartbeatRequest = decode(version, "fast_heartbeat_unknown_fields");
+        let plain: FastHeartbeatRequest = decode(version, "fast_heartbeat");
+        assert_eq!(with_unknown, plain, "{version}");
+    }
+}
+
+// ============================================================================
+// GC Acceptance
+// ============================================================================
+
+async fn register_fixture_controller(service: &McService, version: &str) {
+    let response = service
+        .register_mc(Request::new(decode(version, "register_mc")))
+        .await
+        .expect("GC must accept a pinned registration")
+        .into_inner();
+    assert!(response.accepted);
+}
+
+async fn stored_controller(pool: &PgPool) -> MeetingController {
+    MeetingControllersRepository::get_controller(pool, FIXTURE_CONTROLLER_ID)
+        .await
+        .unwrap()
+        .expect("controller should be registered")
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_gc_accepts_pinned_registration_and_heartbeats(pool: PgPool) {
+    let service = mc_service(pool.clone());
+
+    for version in PINNED_VERSIONS {
+        register_fixture_controller(&service, version).await;
+        let controller = stored_controller(&pool).await;
+        assert_eq!(controller.grpc_endpoint, "https://mc-1.example:50052");
+        assert_eq!(
+            controller.webtransport_endpoint.as_deref(),
+            Some("https://mc-1.example:4433")
+        );
+        assert_eq!(controller.max_meetings, 100);
+        assert_eq!(controller.max_participants, 1000);
+        assert_eq!(controller.health_status, HealthStatus::Pending);
+        // Pinned releases predate version reporting
+        assert_eq!(controller.version, None);
+        assert_eq!(controller.proto_schema, None);
+
+        let fast = service
+            .fast_heartbeat(Request::new(decode(version, "fast_heartbeat")))
+            .await
+            .expect("GC must accept a pinned fast heartbeat")
+            .into_inner();
+        assert!(fast.acknowledged);
+
+        let controller = stored_controller(&pool).await;
+        assert_eq!(controller.current_meetings, 3);
+        assert_eq!(controller.current_participants, 42);
+        assert_eq!(controller.health_status, HealthStatus::Healthy);
+
+        let comprehensive = service
+            .comprehensive_heartbeat(Request::new(decode(version, "comprehensive_heartbeat")))
+            .await
+            .expect("GC must accept a pinned comprehensive heartbeat")
+            .into_inner();
+        assert!(comprehensive.acknowledged);
+    }
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_gc_accepts_heartbeat_with_unknown_fields(pool: PgPool) {
+    let service = mc_service(pool.clone());
+
+    for version in PINNED_VERSIONS {
+        register_fixture_controller(&service, version).await;
+
+        service
+            .fast_heartbeat(Request::new(decode(
+                version,
+                "fast_heartbeat_unknown_fields",
+            )))
+            .await
+            .expect("unknown fields must not cause a rejection");
+
+        let controller = stored_controller(&pool).await;
+        assert_eq!(controller.current_meetings, 3);
+        assert_eq!(controller.current_participants, 42);
+        assert_eq!(controller.health_status, HealthStatus::Healthy);
+    }
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_gc_treats_unknown_health_value_as_unhealthy(pool: PgPool) {
+    let service = mc_service(pool.clone());
+
+    for version in PINNED_VERSIONS {
+        register_fixture_controller(&service, version).await;
+
+        // An enum value GC does not know must never be read as healthy,
+        // or the controller would receive new meetings
+        service
+            .fast_heartbeat(Request::new(decode(
+                version,
+                "fast_heartbeat_unknown_health",
+            )))
+            .await
+            .expect("unknown health values must not cause a rejection");
+
+        let controller = stored_controller(&pool).await;
+        assert_eq!(controller.health_status, HealthStatus::Unhealthy);
+    }
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_gc_rejects_heartbeat_without_capacity(pool: PgPool) {
+    let service = mc_service(pool.clone());
+
+    for version in PINNED_VERSIONS {
+        register_fixture_controller(&service, version).await;
+
+        let status = service
+            .fast_heartbeat(Request::new(decode(
+                version,
+                "fast_heartbeat_missing_capacity",
+            )))
+            .await
+            .expect_err("capacity is required on every heartbeat");
+        assert_eq!(status.code(), Code::InvalidArgument);
+
+        // The rejected heartbeat must not have touched the stored row
+        let controller = stored_controller(&pool).await;
+        assert_eq!(controller.health_status, HealthStatus::Pending);
+    }
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_gc_min_fleet_version_rejects_pinned_registration(pool: PgPool) {
+    // Once a minimum is set, releases that do not report a version are
+    // refused with accepted=false rather than a gRPC error, so they log the
+    // reason instead of retrying
+    let service = mc_service_with_vars(pool.clone(), &[("GC_MIN_FLEET_VERSION", "0.2.0")]);
+
+    for version in PINNED_VERSIONS {
+        let response = service
+            .register_mc(Request::new(decode(version, "register_mc")))
+            .await
+            .expect("rejection is reported in the response")
+            .into_inner();
+        assert!(!response.accepted, "{version}");
+        assert!(response.message.contains("0.2.0"), "{version}");
+
+        let stored = MeetingControllersRepository::get_controller(&pool, FIXTURE_CONTROLLER_ID)
+            .await
+            .unwrap();
+        assert!(
+            stored.is_none(),
+            "{version}: rejected MC must not be stored"
+        );
+    }
+}
@@ -4,7 +4,9 @@
 //! `#[sqlx::test]` for isolated test databases.
 
 use chrono::Utc;
-use gc_service::repositories::{HealthStatus, MeetingControllersRepository};
+use gc_service::repositories::{
+    HealthStatus, McDirectiveAction, McDirectivesRepository, MeetingControllersRepository,
+};
 use sqlx::PgPool;
 
 /// Test that register_mc creates a new record.
@@ -166,6 +168,111 @@ async fn test_update_heartbeat_returns_false_for_missing(
     Ok(())
 }
 
+/// Test that update_stream_heartbeat keeps an operator drain in place.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_update_stream_heartbeat_keeps_draining(pool: PgPool) -> Result<(), anyhow::Error> {
+    MeetingControllersRepository::register_mc(
+        &pool,
+        "mc-stream-001",
+        "us-east-1",
+        "https://mc1.example.com:50051",
+        None,
+        100,
+        1000,
+    )
+    .await?;
+
+    let stored = MeetingControllersRepository::update_stream_heartbeat(
+        &pool,
+        "mc-stream-001",
+        5,
+        20,
+        HealthStatus::Healthy,
+    )
+    .await?;
+    assert_eq!(stored, Some(HealthStatus::Healthy));
+
+    // Operator drains the controller
+    sqlx::query(
+        "UPDATE meeting_controllers SET health_status = 'draining' WHERE controller_id = $1",
+    )
+    .bind("mc-stream-001")
+    .execute(&pool)
+    .await?;
+
+    let stored = MeetingControllersRepository::update_stream_heartbeat(
+        &pool,
+        "mc-stream-001",
+        6,
+        25,
+        HealthStatus::Healthy,
+    )
+    .await?;
+    assert_eq!(stored, Some(HealthStatus::Draining));
+
+    let mc = MeetingControllersRepository::get_controller(&pool, "mc-stream-001")
+        .await?
+        .expect("Record should exist");
+    assert_eq!(mc.current_meetings, 6);
+    assert_eq!(mc.current_participants, 25);
+
+    let missing = MeetingControllersRepository::update_stream_heartbeat(
+        &pool,
+        "mc-nonexistent-001",
+        0,
+        0,
+        HealthStatus::Healthy,
+    )
+    .await?;
+    assert_eq!(missing, None);
+
+    Ok(())
+}
+
+/// Test that pending directives are taken once, oldest first.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_mc_directives_taken_once_in_order(pool: PgPool) -> Result<(), anyhow::Error> {
+    MeetingControllersRepository::register_mc(
+        &pool,
+        "mc-directive-001",
+        "us-east-1",
+        "https://mc1.example.com:50051",
+        None,
+        100,
+        1000,
+    )
+    .await?;
+
+    let drain = McDirectiveAction::Drain { draining: true };
+    let end = McDirectiveAction::EndMeeting {
+        meeting_id: "meeting-1".to_string(),
+        reason: Some("abuse report".to_string()),
+    };
+    let first = McDirectivesRepository::insert(&pool, "mc-directive-001", &drain, "ops").await?;
+    let second = McDirectivesRepository::insert(&pool, "mc-directive-001", &end, "ops").await?;
+
+    let taken = McDirectivesRepository::take_pending(&pool, "mc-directive-001").await?;
+    let ids: Vec<_> = taken.iter().map(|d| d.directive_id).collect();
+    assert_eq!(ids, vec![first, second]);
+    assert_eq!(taken[0].action, drain);
+    assert_eq!(taken[1].action, end);
+    assert_eq!(taken[1].created_by, "ops");
+
+    let again = McDirectivesRepository::take_pending(&pool, "mc-directive-001").await?;
+    assert!(again.is_empty(), "Directives should be delivered once");
+
+    // The table rejects a directive missing its fields
+    let invalid = sqlx::query(
+        "INSERT INTO mc_directives (controller_id, kind, created_by) VALUES ($1, 'drain', 'ops')",
+    )
+    .bind("mc-directive-001")
+    .execute(&pool)
+    .await;
+    assert!(invalid.is_err());
+
+    Ok(())
+}
+
 /// Test that mark_stale_controllers_unhealthy marks stale controllers.
 #[sqlx::test(migrations = "../../migrations")]
 async fn test_mark_stale_controllers_unhealthy_marks_stale(
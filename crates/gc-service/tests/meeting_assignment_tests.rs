@@ -2,6 +2,7 @@
 //!
 //! Tests the MC assignment functionality including:
 //! - Load balancing MC selection
+//! - Capacity pool filtering
 //! - Atomic assignment with race condition handling
 //! - Existing assignment reuse
 //! - Assignment cleanup
@@ -12,6 +13,7 @@ use gc_service::repositories::{
 use gc_service::services::{McAssignmentService, MockMcClient};
 use sqlx::PgPool;
 use std::sync::Arc;
+use uuid::Uuid;
 
 /// Helper to register a healthy MC for testing.
 async fn register_healthy_mc(
@@ -201,6 +203,32 @@ async fn test_get_candidate_mcs_excludes_full(pool: PgPool) -> Result<(), anyhow
     Ok(())
 }
 
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_get_candidate_mcs_filters_by_pool(pool: PgPool) -> Result<(), anyhow::Error> {
+    register_healthy_mc(&pool, "mc-shared", "us-east-1", 10, 100).await?;
+    register_healthy_mc(&pool, "mc-acme", "us-east-1", 10, 100).await?;
+    assert!(MeetingControllersRepository::set_pool(&pool, "mc-acme", "acme").await?);
+    assert!(!MeetingControllersRepository::set_pool(&pool, "mc-missing", "acme").await?);
+
+    // Dedicated MCs are not part of the default pool
+    let default_candidates =
+        MeetingAssignmentsRepository::get_candidate_mcs(&pool, "us-east-1").await?;
+    let acme_candidates =
+        MeetingAssignmentsRepository::get_candidate_mcs_in_pool(&pool, "us-east-1", "acme").await?;
+    let other_candidates =
+        MeetingAssignmentsRepository::get_candidate_mcs_in_pool(&pool, "us-east-1", "globex")
+            .await?;
+
+    let ids = |candidates: &[McCandidate]| -> Vec<String> {
+        candidates.iter().map(|c| c.controller_id.clone()).collect()
+    };
+    assert_eq!(ids(&default_candidates), ["mc-shared"]);
+    assert_eq!(ids(&acme_candidates), ["mc-acme"]);
+    assert!(other_candidates.is_empty());
+
+    Ok(())
+}
+
 #[sqlx::test(migrations = "../../migrations")]
 async fn test_get_candidate_mcs_filters_by_region(pool: PgPool) -> Result<(), anyhow::Error> {
     register_healthy_mc(&pool, "mc-us-1", "us-east-1", 10, 100).await?;
@@ -301,6 +329,50 @@ async fn test_atomic_assign_reuses_existing(pool: PgPool) -> Result<(), anyhow::
     Ok(())
 }
 
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_atomic_assign_queues_assignment_changed(pool: PgPool) -> Result<(), anyhow::Error> {
+    register_healthy_mc(&pool, "mc-1", "us-east-1", 10, 100).await?;
+    register_healthy_mc(&pool, "mc-2", "us-east-1", 5, 100).await?;
+    let meeting_id = Uuid::new_v4();
+
+    for mc_id in ["mc-1", "mc-2"] {
+        let candidate = McCandidate {
+            controller_id: mc_id.to_string(),
+            grpc_endpoint: format!("https://{}.example.com:50051", mc_id),
+            webtransport_endpoint: None,
+            load_ratio: 0.1,
+        };
+        MeetingAssignmentsRepository::atomic_assign(
+            &pool,
+            &meeting_id.to_string(),
+            "us-east-1",
+            &candidate,
+            "gc-test-001",
+        )
+        .await?;
+    }
+
+    // Only the assignment that was written queues an event; the second
+    // attempt lost to the healthy mc-1 assignment
+    let payloads: Vec<serde_json::Value> = sqlx::query_scalar(
+        "SELECT payload FROM event_outbox WHERE event_type = 'assignment_changed' AND meeting_id = $1",
+    )
+    .bind(meeting_id)
+    .fetch_all(&pool)
+    .await?;
+    assert_eq!(
+        payloads,
+        vec![serde_json::json!({
+            "type": "assignment_changed",
+            "meeting_id": meeting_id,
+            "mc_id": "mc-1",
+            "region": "us-east-1",
+        })]
+    );
+
+    Ok(())
+}
+
 #[sqlx::test(migrations = "../../migrations")]
 async fn test_end_assignment_by_region(pool: PgPool) -> Result<(), anyhow::Error> {
     register_healthy_mc(&pool, "mc-1", "us-east-1", 10, 100).await?;
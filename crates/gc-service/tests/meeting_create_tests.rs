@@ -26,7 +26,9 @@ use chrono::Utc;
 use common::secret::SecretString;
 use common::token_manager::TokenReceiver;
 use gc_service::config::Config;
+use gc_service::events::EventBus;
 use gc_service::observability::metrics::init_metrics_recorder;
+use gc_service::repositories::PgStore;
 use gc_service::routes::{self, AppState};
 use gc_service::services::MockMcClient;
 use std::sync::OnceLock;
@@ -110,11 +112,15 @@ impl TestCreateMeetingServer {
         let token_receiver = TokenReceiver::from_watch_receiver(rx);
 
         let mock_mc_client = Arc::new(MockMcClient::accepting());
+        let store = Arc::new(PgStore::new(pool.clone(), config.storage_backend));
         let state = Arc::new(AppState {
             pool: pool.clone(),
             config,
             mc_client: mock_mc_client,
             token_receiver,
+            event_bus: EventBus::default(),
+            object_store: None,
+            store,
         });
 
         let metrics_handle = get_test_metrics_handle();
@@ -286,6 +292,8 @@ async fn test_create_meeting_happy_path(pool: PgPool) -> Result<()> {
 
     let body: serde_json::Value = resp.json().await?;
     assert!(body["meeting_id"].is_string(), "Should have meeting_id");
+    let meeting_id: Uuid = body["meeting_id"].as_str().unwrap().parse().unwrap();
+    assert_eq!(meeting_id.get_version_num(), 7, "Meeting IDs are UUIDv7");
     assert!(body["meeting_code"].is_string(), "Should have meeting_code");
     assert_eq!(body["display_name"], "Team Standup");
     assert_eq!(body["status"], "scheduled");
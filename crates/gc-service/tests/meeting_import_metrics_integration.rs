@@ -0,0 +1,52 @@
+//! Integration cover for `gc_meeting_imports_total` and
+//! `gc_meeting_import_records_total`.
+//!
+//! `MetricAssertion`'s per-thread recorder isolation applies. No tokio
+//! runtime pinning needed — `record_meeting_import` is synchronous.
+//!
+//! Production recording sites are
+//! `crates/gc-service/src/handlers/meeting_imports.rs` (`rejected`) and
+//! `crates/gc-service/src/tasks/meeting_imports.rs` (`succeeded`, `failed`);
+//! the worker's database paths are covered by that module's integration
+//! tests.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use ::common::observability::testing::MetricAssertion;
+use gc_service::observability::metrics::record_meeting_import;
+
+const ALL_STATUSES: &[&str] = &["succeeded", "failed", "rejected"];
+
+#[test]
+fn meeting_import_emits_status() {
+    for status in ALL_STATUSES {
+        let snap = MetricAssertion::snapshot();
+
+        record_meeting_import(status, 0);
+
+        snap.counter("gc_meeting_imports_total")
+            .with_labels(&[("status", *status)])
+            .assert_delta(1);
+        // Label-swap catcher: other statuses silent.
+        for sibling in ALL_STATUSES.iter().filter(|s| *s != status) {
+            snap.counter("gc_meeting_imports_total")
+                .with_labels(&[("status", *sibling)])
+                .assert_delta(0);
+        }
+        snap.counter("gc_meeting_import_records_total")
+            .assert_unobserved();
+    }
+}
+
+#[test]
+fn meeting_import_counts_created_meetings() {
+    let snap = MetricAssertion::snapshot();
+
+    record_meeting_import("succeeded", 1200);
+
+    snap.counter("gc_meeting_imports_total")
+        .with_labels(&[("status", "succeeded")])
+        .assert_delta(1);
+    snap.counter("gc_meeting_import_records_total")
+        .assert_delta(1200);
+}
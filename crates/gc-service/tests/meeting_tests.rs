@@ -3,8 +3,13 @@
 //! Tests the meeting join, guest token, and settings update endpoints:
 //!
 //! - `GET /api/v1/meetings/{code}` - Join meeting (authenticated)
+//! - `GET /api/v1/meetings/{code}/preflight` - Pre-join readiness check (authenticated)
 //! - `POST /api/v1/meetings/{code}/guest-token` - Get guest token (public)
 //! - `PATCH /api/v1/meetings/{id}/settings` - Update meeting settings (host only)
+//! - `POST /api/v1/meetings/{id}/assets` - Asset upload URL (meeting members)
+//! - `POST /api/v1/client-errors` - Client failure report (authenticated)
+//! - `POST /api/v1/meeting-imports` - Bulk meeting import (org admin)
+//! - `GET /api/v1/meeting-imports/{id}` - Meeting import status (org admin)
 //!
 //! # Test Setup
 //!
@@ -27,9 +32,12 @@ use common::secret::SecretString;
 use common::token_manager::TokenReceiver;
 use futures::future::join_all;
 use gc_service::config::Config;
+use gc_service::events::EventBus;
 use gc_service::observability::metrics::init_metrics_recorder;
+use gc_service::repositories::PgStore;
 use gc_service::routes::{self, AppState};
-use gc_service::services::MockMcClient;
+use gc_service::services::object_store::{self, ObjectStoreConfig};
+use gc_service::services::{MockMcClient, ObjectStore};
 use std::sync::OnceLock;
 
 /// Global metrics handle for test servers
@@ -65,6 +73,34 @@ use wiremock::{Mock, MockServer, ResponseTemplate};
 // `create_tampered_token`) live as methods on `TestMeetingServer` below —
 // they operate on `TestUserClaims` shapes specific to this file.
 
+/// S3 presigner with static test credentials (presigning needs no network).
+fn test_object_store() -> Arc<dyn ObjectStore> {
+    let vars = HashMap::from([
+        ("GC_OBJECT_STORE".to_string(), "s3".to_string()),
+        (
+            "GC_OBJECT_STORE_ENDPOINT".to_string(),
+            "http://objects.test:9000".to_string(),
+        ),
+        (
+            "GC_OBJECT_STORE_BUCKET".to_string(),
+            "test-assets".to_string(),
+        ),
+        ("GC_OBJECT_STORE_PATH_STYLE".to_string(), "true".to_string()),
+        (
+            "GC_OBJECT_STORE_ACCESS_KEY_ID".to_string(),
+            "test-access-key".to_string(),
+        ),
+        (
+            "GC_OBJECT_STORE_SECRET_ACCESS_KEY".to_string(),
+            "test-secret-key".to_string(),
+        ),
+    ]);
+    let config = ObjectStoreConfig::from_vars(&vars)
+        .expect("valid object store config")
+        .expect("object store enabled");
+    object_store::build(&config)
+}
+
 /// Test server with mocked JWKS and AC internal endpoints.
 struct TestMeetingServer {
     addr: SocketAddr,
@@ -144,6 +180,9 @@ impl TestMeetingServer {
             config: config.clone(),
             mc_client: mock_mc_client,
             token_receiver,
+            event_bus: EventBus::default(),
+            object_store: Some(test_object_store()),
+            store: Arc::new(PgStore::new(pool.clone(), config.storage_backend)),
         });
 
         // Build routes with metrics handle
@@ -232,6 +271,9 @@ impl TestMeetingServer {
             config: config.clone(),
             mc_client: mock_mc_client,
             token_receiver,
+            event_bus: EventBus::default(),
+            object_store: None,
+            store: Arc::new(PgStore::new(pool.clone(), config.storage_backend)),
         });
 
         let metrics_handle = get_test_metrics_handle();
@@ -281,6 +323,21 @@ impl TestMeetingServer {
         self.keypair.sign_user_token(&claims)
     }
 
+    /// Create a valid user token with the given roles.
+    fn create_token_with_roles(&self, user_id: Uuid, org_id: Uuid, roles: &[&str]) -> String {
+        let now = Utc::now().timestamp();
+        let claims = TestUserClaims {
+            sub: user_id.to_string(),
+            org_id: org_id.to_string(),
+            email: format!("{}@test.com", user_id),
+            roles: roles.iter().map(|r| r.to_string()).collect(),
+            iat: now,
+            exp: now + 3600,
+            jti: Uuid::new_v4().to_string(),
+        };
+        self.keypair.sign_user_token(&claims)
+    }
+
     /// Create an expired user token.
     fn create_expired_token(&self) -> String {
         let now = Utc::now().timestamp();
@@ -559,7 +616,7 @@ async fn test_join_meeting_authenticated_success(pool: PgPool) -> Result<()> {
     // Create test fixtures
     let org_id = create_test_org(&server.pool, "test-org", "Test Organization").await;
     let user_id = create_test_user(&server.pool, org_id, "user@test.com", "Test User").await;
-    let _meeting_id = create_test_meeting(
+    let meeting_id = create_test_meeting(
         &server.pool,
         org_id,
         user_id,
@@ -597,6 +654,15 @@ async fn test_join_meeting_authenticated_success(pool: PgPool) -> Result<()> {
         "Should return mc_assignment with grpc_endpoint"
     );
 
+    // The new assignment is announced through the event outbox
+    let (queued,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM event_outbox WHERE event_type = 'assignment_changed' AND meeting_id = $1",
+    )
+    .bind(meeting_id)
+    .fetch_one(&server.pool)
+    .await?;
+    assert_eq!(queued, 1, "Should enqueue one AssignmentChanged event");
+
     Ok(())
 }
 
@@ -946,6 +1012,149 @@ async fn test_join_meeting_invalid_auth(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+// ============================================================================
+// Preflight Tests - GET /api/v1/meetings/{code}/preflight
+// ============================================================================
+
+/// Test that preflight reports readiness without assigning the meeting.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_preflight_ready_without_assigning(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    register_healthy_mc_for_region(&server.pool, "test-region").await;
+    register_healthy_mhs_for_region(&server.pool, "test-region").await;
+
+    let org_id = create_test_org(&server.pool, "preflight-org", "Preflight Org").await;
+    let user_id = create_test_user(&server.pool, org_id, "user@test.com", "Test User").await;
+    let _meeting_id = create_test_meeting(
+        &server.pool,
+        org_id,
+        user_id,
+        "PREFLT1",
+        "scheduled",
+        false,
+        false,
+        true,
+    )
+    .await;
+
+    let token = server.create_token_for_user(user_id, org_id);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/meetings/PREFLT1/preflight",
+            server.url()
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 200, "Should return 200 OK");
+
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["ready"], true);
+    assert_eq!(body["mc_capacity_available"], true);
+    assert!(
+        body.get("mc_assignment").is_none(),
+        "Unassigned meeting should have no mc_assignment"
+    );
+    assert_eq!(body["media_handlers"].as_array().map(Vec::len), Some(2));
+    assert_eq!(body["min_protocol_version"], 1);
+    assert_eq!(body["relay"]["available"], false);
+    assert!(body["features"]["e2e_encryption"].is_boolean());
+
+    let assignments: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM meeting_assignments")
+        .fetch_one(&server.pool)
+        .await?;
+    assert_eq!(assignments, 0, "Preflight must not assign the meeting");
+
+    Ok(())
+}
+
+/// Test that preflight reports not ready when no MC has capacity.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_preflight_not_ready_without_mc(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    // MHs only, no MC
+    register_healthy_mhs_for_region(&server.pool, "test-region").await;
+
+    let org_id = create_test_org(&server.pool, "preflight-nomc", "Preflight No MC").await;
+    let user_id = create_test_user(&server.pool, org_id, "user@test.com", "Test User").await;
+    let _meeting_id = create_test_meeting(
+        &server.pool,
+        org_id,
+        user_id,
+        "PREFLT2",
+        "scheduled",
+        false,
+        false,
+        true,
+    )
+    .await;
+
+    let token = server.create_token_for_user(user_id, org_id);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/meetings/PREFLT2/preflight",
+            server.url()
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 200, "Should return 200 OK");
+
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["ready"], false);
+    assert_eq!(body["mc_capacity_available"], false);
+
+    Ok(())
+}
+
+/// Test that preflight applies the join access rules.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_preflight_cross_org_denied(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org1_id = create_test_org(&server.pool, "preflight-one", "Organization One").await;
+    let org2_id = create_test_org(&server.pool, "preflight-two", "Organization Two").await;
+    let host_id = create_test_user(&server.pool, org1_id, "host@org1.com", "Host User").await;
+    let external_user_id =
+        create_test_user(&server.pool, org2_id, "external@org2.com", "External User").await;
+
+    let _meeting_id = create_test_meeting(
+        &server.pool,
+        org1_id,
+        host_id,
+        "PREFLT3",
+        "scheduled",
+        false,
+        false, // External not allowed
+        true,
+    )
+    .await;
+
+    let token = server.create_token_for_user(external_user_id, org2_id);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/meetings/PREFLT3/preflight",
+            server.url()
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 403, "External user should be denied");
+
+    Ok(())
+}
+
 // ============================================================================
 // Guest Token Flow Tests - POST /api/v1/meetings/{code}/guest-token
 // ============================================================================
@@ -1417,6 +1626,118 @@ async fn test_update_settings_waiting_room(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+/// Test that host can change the duplicate join policy (default takeover).
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_update_settings_duplicate_join_policy(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "upd-org-dup", "Update Org Dup").await;
+    let host_id = create_test_user(&server.pool, org_id, "host@test.com", "Host").await;
+    let meeting_id = create_test_meeting(
+        &server.pool,
+        org_id,
+        host_id,
+        "UPDDUP",
+        "scheduled",
+        false,
+        false,
+        true,
+    )
+    .await;
+
+    let token = server.create_token_for_user(host_id, org_id);
+    let url = format!("{}/api/v1/meetings/{}/settings", server.url(), meeting_id);
+
+    let response = client
+        .patch(&url)
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "duplicate_join_policy": "reject"
+        }))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["duplicate_join_policy"], "reject");
+
+    // Unknown policies are rejected
+    let response = client
+        .patch(&url)
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "duplicate_join_policy": "kick_everyone"
+        }))
+        .send()
+        .await?;
+    assert!(response.status().is_client_error());
+
+    Ok(())
+}
+
+/// Test that host can change the recording layout (default grid).
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_update_settings_recording_layout(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "upd-org-layout", "Update Org Layout").await;
+    let host_id = create_test_user(&server.pool, org_id, "host@test.com", "Host").await;
+    let meeting_id = create_test_meeting(
+        &server.pool,
+        org_id,
+        host_id,
+        "UPDLAYOUT",
+        "scheduled",
+        false,
+        false,
+        true,
+    )
+    .await;
+
+    let token = server.create_token_for_user(host_id, org_id);
+    let url = format!("{}/api/v1/meetings/{}/settings", server.url(), meeting_id);
+
+    let response = client
+        .patch(&url)
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "waiting_room_enabled": false
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["recording_layout"], "grid");
+
+    let response = client
+        .patch(&url)
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "recording_layout": "speaker"
+        }))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["recording_layout"], "speaker");
+
+    // Unknown layouts are rejected
+    let response = client
+        .patch(&url)
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "recording_layout": "mosaic"
+        }))
+        .send()
+        .await?;
+    assert!(response.status().is_client_error());
+
+    Ok(())
+}
+
 /// Test that non-host user gets 403.
 #[sqlx::test(migrations = "../../migrations")]
 async fn test_update_settings_non_host_forbidden(pool: PgPool) -> Result<()> {
@@ -1688,54 +2009,328 @@ async fn test_update_settings_requires_auth(pool: PgPool) -> Result<()> {
 }
 
 // ============================================================================
-// Security Reviewer Findings - JWT Manipulation Tests (MAJOR)
+// Meeting Report Tests - GET /api/v1/meetings/{id}/report
 // ============================================================================
 
-/// Test that token with wrong algorithm (HS256 instead of EdDSA) returns 401.
-///
-/// This tests defense against algorithm confusion attacks where an attacker
-/// tries to use the public key as an HMAC secret.
+/// Insert a computed report row for a meeting.
+async fn insert_test_report(pool: &PgPool, meeting_id: Uuid) {
+    sqlx::query(
+        r#"
+        INSERT INTO meeting_reports (
+            meeting_id, started_at, ended_at, duration_seconds,
+            peak_participants, total_participants, join_failures, avg_qoe_score
+        )
+        VALUES ($1, NOW() - INTERVAL '1 hour', NOW(), 3600, 7, 9, 1, 4.25)
+        "#,
+    )
+    .bind(meeting_id)
+    .execute(pool)
+    .await
+    .expect("Failed to insert test report");
+}
+
+/// Test that host can read a computed meeting report.
 #[sqlx::test(migrations = "../../migrations")]
-async fn test_jwt_wrong_algorithm_returns_401(pool: PgPool) -> Result<()> {
+async fn test_get_report_host_success(pool: PgPool) -> Result<()> {
     let server = TestMeetingServer::spawn(pool.clone()).await?;
     let client = reqwest::Client::new();
 
-    let org_id = create_test_org(&server.pool, "jwt-alg-org", "JWT Algorithm Org").await;
-    let user_id = create_test_user(&server.pool, org_id, "user@test.com", "Test User").await;
-    let _meeting_id = create_test_meeting(
+    let org_id = create_test_org(&server.pool, "rpt-org1", "Report Org 1").await;
+    let host_id = create_test_user(&server.pool, org_id, "host@test.com", "Host").await;
+    let meeting_id = create_test_meeting(
         &server.pool,
         org_id,
-        user_id,
-        "JWTALG",
-        "scheduled",
+        host_id,
+        "RPT001",
+        "ended",
         false,
         false,
         true,
     )
     .await;
+    insert_test_report(&server.pool, meeting_id).await;
 
-    // Create token with HS256 algorithm (should be rejected)
-    let token = server.create_hs256_token_for_user(user_id, org_id);
+    let token = server.create_token_for_user(host_id, org_id);
 
     let response = client
-        .get(format!("{}/api/v1/meetings/JWTALG", server.url()))
+        .get(format!(
+            "{}/api/v1/meetings/{}/report",
+            server.url(),
+            meeting_id
+        ))
         .header("Authorization", format!("Bearer {}", token))
         .send()
         .await?;
 
-    assert_eq!(
-        response.status(),
-        401,
-        "Token with wrong algorithm (HS256) should return 401"
-    );
+    assert_eq!(response.status(), 200, "Host should read report");
+
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["meeting_id"], meeting_id.to_string());
+    assert_eq!(body["duration_seconds"], 3600);
+    assert_eq!(body["peak_participants"], 7);
+    assert_eq!(body["total_participants"], 9);
+    assert_eq!(body["join_failures"], 1);
+    assert_eq!(body["avg_qoe_score"], 4.25);
 
     Ok(())
 }
 
-/// Test that token signed with unknown/different key returns 401.
-///
-/// This tests defense against key substitution attacks where an attacker
-/// creates a valid-looking token but signs it with their own key.
+/// Test that a report that has not been computed yet returns 404.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_get_report_not_available(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "rpt-org2", "Report Org 2").await;
+    let host_id = create_test_user(&server.pool, org_id, "host@test.com", "Host").await;
+    let meeting_id = create_test_meeting(
+        &server.pool,
+        org_id,
+        host_id,
+        "RPT002",
+        "active",
+        false,
+        false,
+        true,
+    )
+    .await;
+
+    let token = server.create_token_for_user(host_id, org_id);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/meetings/{}/report",
+            server.url(),
+            meeting_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await?;
+
+    assert_eq!(
+        response.status(),
+        404,
+        "Meeting without a report should return 404"
+    );
+
+    Ok(())
+}
+
+/// Test that non-host user gets 403 for the report.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_get_report_non_host_forbidden(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "rpt-org3", "Report Org 3").await;
+    let host_id = create_test_user(&server.pool, org_id, "host@test.com", "Host").await;
+    let other_user_id =
+        create_test_user(&server.pool, org_id, "other@test.com", "Other User").await;
+    let meeting_id = create_test_meeting(
+        &server.pool,
+        org_id,
+        host_id,
+        "RPT003",
+        "ended",
+        false,
+        false,
+        true,
+    )
+    .await;
+    insert_test_report(&server.pool, meeting_id).await;
+
+    let token = server.create_token_for_user(other_user_id, org_id);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/meetings/{}/report",
+            server.url(),
+            meeting_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 403, "Non-host should get 403 Forbidden");
+
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["error"]["code"], "FORBIDDEN");
+
+    Ok(())
+}
+
+// ============================================================================
+// Attendance Export Tests - GET /api/v1/meetings/{id}/attendance.csv
+// ============================================================================
+
+/// Insert an attendance session for a meeting.
+async fn insert_test_attendance(pool: &PgPool, meeting_id: Uuid, participant_id: &str, name: &str) {
+    sqlx::query(
+        r#"
+        INSERT INTO meeting_attendance (
+            meeting_id, participant_id, user_id, display_name,
+            joined_at, left_at, leave_reason, region
+        )
+        VALUES ($1, $2, $2, $3, NOW() - INTERVAL '30 minutes', NOW(), 'voluntary', 'us-east-1')
+        "#,
+    )
+    .bind(meeting_id)
+    .bind(participant_id)
+    .bind(name)
+    .execute(pool)
+    .await
+    .expect("Failed to insert test attendance");
+}
+
+/// Test that host can export attendance as CSV.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_export_attendance_host_success(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "att-org1", "Attendance Org 1").await;
+    let host_id = create_test_user(&server.pool, org_id, "host@test.com", "Host").await;
+    let meeting_id = create_test_meeting(
+        &server.pool,
+        org_id,
+        host_id,
+        "ATT001",
+        "ended",
+        false,
+        false,
+        true,
+    )
+    .await;
+    insert_test_attendance(&server.pool, meeting_id, "part-1", "Alice").await;
+    insert_test_attendance(&server.pool, meeting_id, "part-2", "=cmd|calc").await;
+
+    let token = server.create_token_for_user(host_id, org_id);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/meetings/{}/attendance.csv",
+            server.url(),
+            meeting_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 200, "Host should export attendance");
+    let content_type = response
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    assert!(content_type.starts_with("text/csv"));
+
+    let body = response.text().await?;
+    let lines: Vec<&str> = body.lines().collect();
+    assert_eq!(lines.len(), 3, "Header plus one row per session");
+    assert!(lines[0].starts_with("participant_id,user_id,display_name"));
+    assert!(body.contains(",Alice,"));
+    assert!(
+        body.contains(",'=cmd|calc,"),
+        "Formula-leading names should be neutralized"
+    );
+    assert!(body.contains(",1800,voluntary"));
+
+    Ok(())
+}
+
+/// Test that non-host user gets 403 for attendance export.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_export_attendance_non_host_forbidden(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "att-org2", "Attendance Org 2").await;
+    let host_id = create_test_user(&server.pool, org_id, "host@test.com", "Host").await;
+    let other_user_id =
+        create_test_user(&server.pool, org_id, "other@test.com", "Other User").await;
+    let meeting_id = create_test_meeting(
+        &server.pool,
+        org_id,
+        host_id,
+        "ATT002",
+        "ended",
+        false,
+        false,
+        true,
+    )
+    .await;
+    insert_test_attendance(&server.pool, meeting_id, "part-1", "Alice").await;
+
+    let token = server.create_token_for_user(other_user_id, org_id);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/meetings/{}/attendance.csv",
+            server.url(),
+            meeting_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 403, "Non-host should get 403 Forbidden");
+
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["error"]["code"], "FORBIDDEN");
+
+    Ok(())
+}
+
+// ============================================================================
+// Security Reviewer Findings - JWT Manipulation Tests (MAJOR)
+// ============================================================================
+
+/// Test that token with wrong algorithm (HS256 instead of EdDSA) returns 401.
+///
+/// This tests defense against algorithm confusion attacks where an attacker
+/// tries to use the public key as an HMAC secret.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_jwt_wrong_algorithm_returns_401(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "jwt-alg-org", "JWT Algorithm Org").await;
+    let user_id = create_test_user(&server.pool, org_id, "user@test.com", "Test User").await;
+    let _meeting_id = create_test_meeting(
+        &server.pool,
+        org_id,
+        user_id,
+        "JWTALG",
+        "scheduled",
+        false,
+        false,
+        true,
+    )
+    .await;
+
+    // Create token with HS256 algorithm (should be rejected)
+    let token = server.create_hs256_token_for_user(user_id, org_id);
+
+    let response = client
+        .get(format!("{}/api/v1/meetings/JWTALG", server.url()))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await?;
+
+    assert_eq!(
+        response.status(),
+        401,
+        "Token with wrong algorithm (HS256) should return 401"
+    );
+
+    Ok(())
+}
+
+/// Test that token signed with unknown/different key returns 401.
+///
+/// This tests defense against key substitution attacks where an attacker
+/// creates a valid-looking token but signs it with their own key.
 #[sqlx::test(migrations = "../../migrations")]
 async fn test_jwt_wrong_key_returns_401(pool: PgPool) -> Result<()> {
     let server = TestMeetingServer::spawn(pool.clone()).await?;
@@ -2295,3 +2890,364 @@ async fn test_same_org_join_sends_home_org_id_equal_to_user_org_id(pool: PgPool)
 
     Ok(())
 }
+
+// ============================================================================
+// Asset Upload Tests - POST /api/v1/meetings/{id}/assets
+// ============================================================================
+
+fn asset_request_body() -> serde_json::Value {
+    serde_json::json!({
+        "file_name": "diagram.png",
+        "content_type": "image/png",
+        "size_bytes": 2048
+    })
+}
+
+/// Test that a same-org member gets a pre-signed upload URL and a metadata row.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_create_asset_member_success(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "asset-org1", "Asset Org 1").await;
+    let host_id = create_test_user(&server.pool, org_id, "host@test.com", "Host").await;
+    let member_id = create_test_user(&server.pool, org_id, "member@test.com", "Member").await;
+    let meeting_id = create_test_meeting(
+        &server.pool,
+        org_id,
+        host_id,
+        "AST001",
+        "active",
+        false,
+        false,
+        true,
+    )
+    .await;
+
+    let token = server.create_token_for_user(member_id, org_id);
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/meetings/{}/assets",
+            server.url(),
+            meeting_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&asset_request_body())
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 201, "Member should get an upload URL");
+
+    let body: serde_json::Value = response.json().await?;
+    let asset_id: Uuid = body["asset_id"].as_str().unwrap().parse()?;
+    assert_eq!(body["upload_method"], "PUT");
+    assert_eq!(body["upload_headers"]["Content-Type"], "image/png");
+    let upload_url = body["upload_url"].as_str().unwrap();
+    assert!(upload_url.starts_with(&format!(
+        "http://objects.test:9000/test-assets/meetings/{}/{}?",
+        meeting_id, asset_id
+    )));
+    assert!(upload_url.contains("X-Amz-Signature="));
+
+    let (uploaded_by, file_name, size_bytes): (Uuid, String, i64) = sqlx::query_as(
+        "SELECT uploaded_by_user_id, file_name, size_bytes FROM meeting_assets WHERE asset_id = $1",
+    )
+    .bind(asset_id)
+    .fetch_one(&server.pool)
+    .await?;
+    assert_eq!(uploaded_by, member_id);
+    assert_eq!(file_name, "diagram.png");
+    assert_eq!(size_bytes, 2048);
+
+    Ok(())
+}
+
+/// Test that external users cannot upload when the meeting disallows them.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_create_asset_external_forbidden(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "asset-org2", "Asset Org 2").await;
+    let other_org_id = create_test_org(&server.pool, "asset-org3", "Asset Org 3").await;
+    let host_id = create_test_user(&server.pool, org_id, "host@test.com", "Host").await;
+    let external_id =
+        create_test_user(&server.pool, other_org_id, "ext@test.com", "External").await;
+    let meeting_id = create_test_meeting(
+        &server.pool,
+        org_id,
+        host_id,
+        "AST002",
+        "active",
+        false,
+        false,
+        true,
+    )
+    .await;
+
+    let token = server.create_token_for_user(external_id, other_org_id);
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/meetings/{}/assets",
+            server.url(),
+            meeting_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&asset_request_body())
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 403, "External user should get 403");
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM meeting_assets")
+        .fetch_one(&server.pool)
+        .await?;
+    assert_eq!(count, 0, "No metadata row should be written");
+
+    Ok(())
+}
+
+/// Test that an oversized upload is rejected with 400.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_create_asset_too_large(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "asset-org4", "Asset Org 4").await;
+    let host_id = create_test_user(&server.pool, org_id, "host@test.com", "Host").await;
+    let meeting_id = create_test_meeting(
+        &server.pool,
+        org_id,
+        host_id,
+        "AST003",
+        "active",
+        false,
+        false,
+        true,
+    )
+    .await;
+
+    let token = server.create_token_for_user(host_id, org_id);
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/meetings/{}/assets",
+            server.url(),
+            meeting_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "file_name": "huge.bin",
+            "content_type": "application/octet-stream",
+            "size_bytes": 1_i64 << 40
+        }))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 400, "Oversized asset should get 400");
+
+    Ok(())
+}
+
+// ============================================================================
+// Client Error Report Tests - POST /api/v1/client-errors
+// ============================================================================
+
+/// Test that a valid sampled report is accepted with 202.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_report_client_error_accepted(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "clierr-org1", "Client Error Org").await;
+    let user_id = create_test_user(&server.pool, org_id, "user@test.com", "User").await;
+    let token = server.create_token_for_user(user_id, org_id);
+
+    let response = client
+        .post(format!("{}/api/v1/client-errors", server.url()))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "kind": "transport_fallback",
+            "platform": "web",
+            "error_code": "DT-MC-2022",
+            "meeting_id": Uuid::new_v4(),
+            "sample_rate": 0.1
+        }))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 202, "Valid report should get 202");
+
+    Ok(())
+}
+
+/// Test that labels outside the bounded sets are rejected with 400.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_report_client_error_unknown_kind_rejected(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "clierr-org2", "Client Error Org 2").await;
+    let user_id = create_test_user(&server.pool, org_id, "user@test.com", "User").await;
+    let token = server.create_token_for_user(user_id, org_id);
+
+    for body in [
+        serde_json::json!({ "kind": "segfault", "platform": "web" }),
+        serde_json::json!({ "kind": "join_failed", "platform": "web", "sample_rate": 0.0 }),
+    ] {
+        let response = client
+            .post(format!("{}/api/v1/client-errors", server.url()))
+            .header("Authorization", format!("Bearer {}", token))
+            .json(&body)
+            .send()
+            .await?;
+
+        assert_eq!(response.status(), 400, "{body} should get 400");
+    }
+
+    Ok(())
+}
+
+/// Test that reports require authentication.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_report_client_error_requires_auth(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/api/v1/client-errors", server.url()))
+        .json(&serde_json::json!({ "kind": "join_failed", "platform": "web" }))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 401, "Missing token should get 401");
+
+    Ok(())
+}
+
+// ============================================================================
+// Meeting Import Tests - /api/v1/meeting-imports
+// ============================================================================
+
+/// Test that a CSV import is accepted and its job is readable by the org.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_import_meetings_csv_accepted(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "import-org1", "Import Org").await;
+    let user_id = create_test_user(&server.pool, org_id, "admin@test.com", "Admin").await;
+    let token = server.create_token_with_roles(user_id, org_id, &["org_admin"]);
+
+    let response = client
+        .post(format!("{}/api/v1/meeting-imports", server.url()))
+        .header("Authorization", format!("Bearer {}", token))
+        .header("Content-Type", "text/csv")
+        .body("external_id,display_name,max_participants\nz-1,Weekly Sync,50\nz-2,All Hands,\n")
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 202, "Valid import should get 202");
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["status"], "running");
+    assert_eq!(body["total_records"], 2);
+    let job_id = body["job_id"].as_str().unwrap().to_string();
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/meeting-imports/{}",
+            server.url(),
+            job_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["job_id"], job_id.as_str());
+    assert_eq!(body["imported_records"], 0);
+
+    // Another org's admin cannot see the job.
+    let other_org = create_test_org(&server.pool, "import-org2", "Other Org").await;
+    let other_user = create_test_user(&server.pool, other_org, "admin@test.com", "Admin").await;
+    let other_token = server.create_token_with_roles(other_user, other_org, &["admin"]);
+
+    let response = client
+        .get(format!(
+            "{}/api/v1/meeting-imports/{}",
+            server.url(),
+            job_id
+        ))
+        .header("Authorization", format!("Bearer {}", other_token))
+        .send()
+        .await?;
+
+    assert_eq!(
+        response.status(),
+        404,
+        "Cross-org job lookup should get 404"
+    );
+
+    Ok(())
+}
+
+/// Test that an import with invalid records is rejected without a job.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_import_meetings_invalid_records_rejected(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "import-org3", "Import Org").await;
+    let user_id = create_test_user(&server.pool, org_id, "admin@test.com", "Admin").await;
+    let token = server.create_token_with_roles(user_id, org_id, &["admin"]);
+
+    let response = client
+        .post(format!("{}/api/v1/meeting-imports", server.url()))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({
+            "meetings": [
+                { "display_name": "Sync", "external_id": "a" },
+                { "display_name": "Standup", "external_id": "a" }
+            ]
+        }))
+        .send()
+        .await?;
+
+    assert_eq!(
+        response.status(),
+        400,
+        "Duplicate external IDs should get 400"
+    );
+
+    let (jobs,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM meeting_import_jobs")
+        .fetch_one(&server.pool)
+        .await?;
+    assert_eq!(jobs, 0, "Rejected import should not create a job");
+
+    Ok(())
+}
+
+/// Test that regular users cannot import meetings.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_import_meetings_requires_admin_role(pool: PgPool) -> Result<()> {
+    let server = TestMeetingServer::spawn(pool.clone()).await?;
+    let client = reqwest::Client::new();
+
+    let org_id = create_test_org(&server.pool, "import-org4", "Import Org").await;
+    let user_id = create_test_user(&server.pool, org_id, "user@test.com", "User").await;
+    let token = server.create_token_for_user(user_id, org_id);
+
+    let response = client
+        .post(format!("{}/api/v1/meeting-imports", server.url()))
+        .header("Authorization", format!("Bearer {}", token))
+        .json(&serde_json::json!({ "meetings": [{ "display_name": "Sync" }] }))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 403, "Non-admin import should get 403");
+
+    Ok(())
+}
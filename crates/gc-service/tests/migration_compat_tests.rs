@@ -0,0 +1,105 @@
+//! Rolling-deploy check: the previous GC release against HEAD migrations.
+//!
+//! Replays `tests/compat/release_queries.sql` (the statements the last
+//! released GC binary runs) against a database migrated to HEAD, and checks
+//! that the harness actually catches the migrations it exists to stop.
+
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::indexing_slicing
+)]
+
+use common::migration_compat::{self, Incompatibility, PinnedQueries};
+use sqlx::PgPool;
+
+fn release_queries() -> PinnedQueries {
+    PinnedQueries::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/compat/release_queries.sql"
+    ))
+    .unwrap()
+}
+
+fn render(problems: &[Incompatibility]) -> String {
+    problems
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn previous_release_queries_run_against_head_schema(pool: PgPool) {
+    let pinned = release_queries();
+    assert!(!pinned.queries.is_empty());
+
+    let problems = migration_compat::check(&pool, &pinned).await.unwrap();
+
+    assert!(
+        problems.is_empty(),
+        "HEAD migrations break the previous GC release:\n{}",
+        render(&problems)
+    );
+}
+
+/// A `NOT NULL` column without a default breaks the old binary's INSERTs.
+#[sqlx::test(migrations = "../../migrations")]
+async fn detects_required_column_added_without_default(pool: PgPool) {
+    sqlx::raw_sql("ALTER TABLE participants ADD COLUMN device_id TEXT NOT NULL")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let problems = migration_compat::check(&pool, &release_queries())
+        .await
+        .unwrap();
+
+    let names: Vec<&str> = problems.iter().map(|p| p.query.as_str()).collect();
+    assert_eq!(names, ["participants.add"], "{}", render(&problems));
+    assert!(problems[0].problem.contains("execute failed"));
+}
+
+/// Renaming a column the old binary selects breaks its statements at
+/// prepare time.
+#[sqlx::test(migrations = "../../migrations")]
+async fn detects_renamed_column(pool: PgPool) {
+    sqlx::raw_sql("ALTER TABLE meeting_controllers RENAME COLUMN grpc_endpoint TO grpc_url")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let problems = migration_compat::check(&pool, &release_queries())
+        .await
+        .unwrap();
+
+    let names: Vec<&str> = problems.iter().map(|p| p.query.as_str()).collect();
+    for query in [
+        "meeting_controllers.register",
+        "meeting_controllers.get",
+        "meeting_assignments.get_healthy_assignment",
+        "meeting_assignments.get_candidate_mcs",
+    ] {
+        assert!(names.contains(&query), "{query} not flagged: {names:?}");
+    }
+}
+
+/// Widening a column changes the type the old binary decodes.
+#[sqlx::test(migrations = "../../migrations")]
+async fn detects_changed_result_type(pool: PgPool) {
+    sqlx::raw_sql("ALTER TABLE meeting_controllers ALTER COLUMN max_meetings TYPE BIGINT")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let problems = migration_compat::check(&pool, &release_queries())
+        .await
+        .unwrap();
+
+    let changed = problems
+        .iter()
+        .find(|p| p.query == "meeting_controllers.get")
+        .unwrap_or_else(|| panic!("type change not flagged:\n{}", render(&problems)));
+    assert!(changed.problem.contains("max_meetings INT8"), "{changed}");
+}
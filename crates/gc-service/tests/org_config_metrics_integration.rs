@@ -0,0 +1,39 @@
+//! Integration cover for `gc_org_config_reconciles_total`.
+//!
+//! `MetricAssertion`'s per-thread recorder isolation applies. No tokio
+//! runtime pinning needed — `record_org_config_reconcile` is synchronous.
+//!
+//! Production recording site is
+//! `crates/gc-service/src/handlers/org_config.rs`.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use ::common::observability::testing::MetricAssertion;
+use gc_service::observability::metrics::record_org_config_reconcile;
+
+const ALL_ACTIONS: &[&str] = &["created", "updated", "unchanged"];
+
+#[test]
+fn org_config_reconcile_emits_action_and_mode() {
+    for action in ALL_ACTIONS {
+        for (dry_run, mode, other_mode) in [(false, "apply", "dry_run"), (true, "dry_run", "apply")]
+        {
+            let snap = MetricAssertion::snapshot();
+
+            record_org_config_reconcile(action, dry_run);
+
+            snap.counter("gc_org_config_reconciles_total")
+                .with_labels(&[("action", *action), ("mode", mode)])
+                .assert_delta(1);
+            // Label-swap catchers: other mode and other actions silent.
+            snap.counter("gc_org_config_reconciles_total")
+                .with_labels(&[("action", *action), ("mode", other_mode)])
+                .assert_delta(0);
+            for sibling in ALL_ACTIONS.iter().filter(|a| *a != action) {
+                snap.counter("gc_org_config_reconciles_total")
+                    .with_labels(&[("action", *sibling), ("mode", mode)])
+                    .assert_delta(0);
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Declarative org config integration tests.
+//!
+//! Tests the organizations repository behind the org config reconciler:
+//! - A created org reads back exactly as the document that created it
+//! - Reconciling an updated document changes only the differing fields
+//! - A reconciled document diffs as unchanged against the stored config
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use gc_service::models::{OrgConfig, PlanTier, RecordingConsentMode};
+use gc_service::repositories::OrganizationsRepository;
+use sqlx::PgPool;
+
+fn acme_config() -> OrgConfig {
+    serde_json::from_value(serde_json::json!({
+        "display_name": "Acme Corp",
+        "plan_tier": "enterprise",
+        "policies": {
+            "max_concurrent_meetings": 50,
+            "recording_consent_mode": "notify",
+            "mc_pool": "acme-dedicated"
+        }
+    }))
+    .unwrap()
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_created_org_reads_back_as_document(pool: PgPool) -> Result<(), anyhow::Error> {
+    let mut conn = pool.acquire().await?;
+    let config = acme_config();
+
+    let org_id = OrganizationsRepository::create(&mut conn, "acme", &config)
+        .await?
+        .expect("subdomain should be free");
+
+    let (stored_id, stored) = OrganizationsRepository::get_config(&mut conn, "acme", false)
+        .await?
+        .expect("org should exist");
+    assert_eq!(stored_id, org_id);
+    assert_eq!(stored, config);
+    assert_eq!(stored.policies.max_participants_per_meeting, 100);
+    assert!(config.diff(Some(&stored)).is_empty());
+
+    // A second create of the same subdomain reports the conflict.
+    assert!(OrganizationsRepository::create(&mut conn, "acme", &config)
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_update_config_overwrites_fields(pool: PgPool) -> Result<(), anyhow::Error> {
+    let mut conn = pool.acquire().await?;
+    let org_id = OrganizationsRepository::create(&mut conn, "acme", &acme_config())
+        .await?
+        .expect("subdomain should be free");
+
+    let mut desired = acme_config();
+    desired.plan_tier = PlanTier::Pro;
+    desired.active = false;
+    desired.policies.recording_consent_mode = RecordingConsentMode::RequireAck;
+    desired.policies.mc_pool = None;
+
+    let (_, current) = OrganizationsRepository::get_config(&mut conn, "acme", true)
+        .await?
+        .expect("org should exist");
+    let fields: Vec<String> = desired
+        .diff(Some(&current))
+        .into_iter()
+        .map(|c| c.field)
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            "plan_tier",
+            "active",
+            "policies.recording_consent_mode",
+            "policies.mc_pool"
+        ]
+    );
+
+    OrganizationsRepository::update_config(&mut conn, org_id, &desired).await?;
+
+    let (_, stored) = OrganizationsRepository::get_config(&mut conn, "acme", false)
+        .await?
+        .expect("org should exist");
+    assert_eq!(stored, desired);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_get_config_unknown_subdomain(pool: PgPool) -> Result<(), anyhow::Error> {
+    let mut conn = pool.acquire().await?;
+
+    assert!(
+        OrganizationsRepository::get_config(&mut conn, "nope", false)
+            .await?
+            .is_none()
+    );
+
+    Ok(())
+}
@@ -106,7 +106,7 @@ async fn test_add_participant(pool: PgPool) -> Result<(), anyhow::Error> {
 
     let participant = ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user_id),
         "Test User",
         "member",
@@ -115,6 +115,7 @@ async fn test_add_participant(pool: PgPool) -> Result<(), anyhow::Error> {
     .await?;
 
     assert_eq!(participant.meeting_id, meeting_id);
+    assert_eq!(participant.participant_id.as_uuid().get_version_num(), 7);
     assert_eq!(participant.user_id, Some(user_id));
     assert_eq!(participant.display_name, "Test User");
     assert_eq!(participant.participant_type, "member");
@@ -132,34 +133,34 @@ async fn test_count_active_participants(pool: PgPool) -> Result<(), anyhow::Erro
     let (org_id, user_id, meeting_id) = create_test_fixtures(&pool).await;
 
     // Initially zero
-    let count = ParticipantsRepository::count_active_participants(&pool, meeting_id).await?;
+    let count = ParticipantsRepository::count_active_participants(&pool, meeting_id.into()).await?;
     assert_eq!(count, 0);
 
     // Add first participant (the meeting creator)
     ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user_id),
         "Test User",
         "member",
         "host",
     )
     .await?;
-    let count = ParticipantsRepository::count_active_participants(&pool, meeting_id).await?;
+    let count = ParticipantsRepository::count_active_participants(&pool, meeting_id.into()).await?;
     assert_eq!(count, 1);
 
     // Add second participant (different user)
     let user2 = create_extra_user(&pool, org_id).await;
     ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user2),
         "Extra User",
         "external",
         "participant",
     )
     .await?;
-    let count = ParticipantsRepository::count_active_participants(&pool, meeting_id).await?;
+    let count = ParticipantsRepository::count_active_participants(&pool, meeting_id.into()).await?;
     assert_eq!(count, 2);
 
     Ok(())
@@ -171,7 +172,7 @@ async fn test_remove_participant(pool: PgPool) -> Result<(), anyhow::Error> {
 
     ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user_id),
         "Test User",
         "member",
@@ -179,16 +180,17 @@ async fn test_remove_participant(pool: PgPool) -> Result<(), anyhow::Error> {
     )
     .await?;
     assert_eq!(
-        ParticipantsRepository::count_active_participants(&pool, meeting_id).await?,
+        ParticipantsRepository::count_active_participants(&pool, meeting_id.into()).await?,
         1
     );
 
     // Remove the participant
-    let removed = ParticipantsRepository::remove_participant(&pool, meeting_id, user_id).await?;
+    let removed =
+        ParticipantsRepository::remove_participant(&pool, meeting_id.into(), user_id).await?;
     assert!(removed, "Should return true when participant was removed");
 
     // Count should drop to zero
-    let count = ParticipantsRepository::count_active_participants(&pool, meeting_id).await?;
+    let count = ParticipantsRepository::count_active_participants(&pool, meeting_id.into()).await?;
     assert_eq!(count, 0);
 
     Ok(())
@@ -199,7 +201,8 @@ async fn test_remove_nonexistent_participant(pool: PgPool) -> Result<(), anyhow:
     let (_org_id, _user_id, meeting_id) = create_test_fixtures(&pool).await;
 
     let removed =
-        ParticipantsRepository::remove_participant(&pool, meeting_id, Uuid::new_v4()).await?;
+        ParticipantsRepository::remove_participant(&pool, meeting_id.into(), Uuid::new_v4())
+            .await?;
     assert!(
         !removed,
         "Should return false when no active participant found"
@@ -215,7 +218,7 @@ async fn test_duplicate_active_participant_rejected(pool: PgPool) -> Result<(),
     // First add succeeds
     ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user_id),
         "Test User",
         "member",
@@ -226,7 +229,7 @@ async fn test_duplicate_active_participant_rejected(pool: PgPool) -> Result<(),
     // Second add with same user should fail (unique constraint)
     let result = ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user_id),
         "Test User",
         "member",
@@ -248,7 +251,7 @@ async fn test_rejoin_after_leaving(pool: PgPool) -> Result<(), anyhow::Error> {
     // Join
     ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user_id),
         "Test User",
         "member",
@@ -257,16 +260,16 @@ async fn test_rejoin_after_leaving(pool: PgPool) -> Result<(), anyhow::Error> {
     .await?;
 
     // Leave
-    ParticipantsRepository::remove_participant(&pool, meeting_id, user_id).await?;
+    ParticipantsRepository::remove_participant(&pool, meeting_id.into(), user_id).await?;
     assert_eq!(
-        ParticipantsRepository::count_active_participants(&pool, meeting_id).await?,
+        ParticipantsRepository::count_active_participants(&pool, meeting_id.into()).await?,
         0
     );
 
     // Rejoin should succeed (previous row has left_at set, so unique constraint allows new row)
     let participant = ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user_id),
         "Test User",
         "member",
@@ -278,7 +281,7 @@ async fn test_rejoin_after_leaving(pool: PgPool) -> Result<(), anyhow::Error> {
         "Rejoined participant should be active"
     );
     assert_eq!(
-        ParticipantsRepository::count_active_participants(&pool, meeting_id).await?,
+        ParticipantsRepository::count_active_participants(&pool, meeting_id.into()).await?,
         1
     );
 
@@ -294,7 +297,7 @@ async fn test_capacity_check_against_max_participants(pool: PgPool) -> Result<()
     // Add participants up to capacity
     ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user_id),
         "Host User",
         "member",
@@ -304,7 +307,7 @@ async fn test_capacity_check_against_max_participants(pool: PgPool) -> Result<()
     let user2 = create_extra_user(&pool, org_id).await;
     ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user2),
         "User Two",
         "member",
@@ -314,7 +317,7 @@ async fn test_capacity_check_against_max_participants(pool: PgPool) -> Result<()
     let user3 = create_extra_user(&pool, org_id).await;
     ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user3),
         "User Three",
         "external",
@@ -323,7 +326,7 @@ async fn test_capacity_check_against_max_participants(pool: PgPool) -> Result<()
     .await?;
 
     // Verify count matches max_participants
-    let count = ParticipantsRepository::count_active_participants(&pool, meeting_id).await?;
+    let count = ParticipantsRepository::count_active_participants(&pool, meeting_id.into()).await?;
     assert_eq!(count, i64::from(max_participants));
 
     // Verify the capacity check pattern: count >= max_participants means full
@@ -347,7 +350,7 @@ async fn test_invalid_participant_type_rejected(pool: PgPool) -> Result<(), anyh
 
     let result = ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user_id),
         "Test User",
         "admin", // invalid participant_type
@@ -368,7 +371,7 @@ async fn test_invalid_role_rejected(pool: PgPool) -> Result<(), anyhow::Error> {
 
     let result = ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         Some(user_id),
         "Test User",
         "member",
@@ -390,7 +393,7 @@ async fn test_add_guest_participant(pool: PgPool) -> Result<(), anyhow::Error> {
 
     let participant = ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         None, // guest — no user_id
         "Anonymous Guest",
         "guest",
@@ -417,15 +420,29 @@ async fn test_multiple_guests_allowed(pool: PgPool) -> Result<(), anyhow::Error>
 
     // Add multiple guests with user_id = None — the partial unique index
     // treats NULLs as distinct, so this should succeed
-    ParticipantsRepository::add_participant(&pool, meeting_id, None, "Guest One", "guest", "guest")
-        .await?;
+    ParticipantsRepository::add_participant(
+        &pool,
+        meeting_id.into(),
+        None,
+        "Guest One",
+        "guest",
+        "guest",
+    )
+    .await?;
 
-    ParticipantsRepository::add_participant(&pool, meeting_id, None, "Guest Two", "guest", "guest")
-        .await?;
+    ParticipantsRepository::add_participant(
+        &pool,
+        meeting_id.into(),
+        None,
+        "Guest Two",
+        "guest",
+        "guest",
+    )
+    .await?;
 
     ParticipantsRepository::add_participant(
         &pool,
-        meeting_id,
+        meeting_id.into(),
         None,
         "Guest Three",
         "guest",
@@ -433,7 +450,7 @@ async fn test_multiple_guests_allowed(pool: PgPool) -> Result<(), anyhow::Error>
     )
     .await?;
 
-    let count = ParticipantsRepository::count_active_participants(&pool, meeting_id).await?;
+    let count = ParticipantsRepository::count_active_participants(&pool, meeting_id.into()).await?;
     assert_eq!(count, 3, "All three guest participants should be counted");
 
     Ok(())
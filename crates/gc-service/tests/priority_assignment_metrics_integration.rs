@@ -0,0 +1,50 @@
+//! Integration cover for `gc_mc_assignments_by_priority_total`.
+//!
+//! `MetricAssertion`'s per-thread recorder isolation applies. No tokio
+//! runtime pinning needed — `record_priority_assignment` is synchronous.
+//!
+//! Production recording sites are in
+//! `crates/gc-service/src/services/mc_assignment.rs`; real placement by
+//! priority is covered in `crates/gc-service/tests/mc_assignment_rpc_tests.rs`.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use ::common::observability::testing::MetricAssertion;
+use gc_service::observability::metrics::record_priority_assignment;
+
+const ALL_OUTCOMES: &[&str] = &["assigned", "spillover", "rejected"];
+
+#[test]
+fn priority_assignment_emits_priority_and_outcome() {
+    for outcome in ALL_OUTCOMES {
+        let snap = MetricAssertion::snapshot();
+
+        record_priority_assignment("test", outcome);
+
+        snap.counter("gc_mc_assignments_by_priority_total")
+            .with_labels(&[("priority", "test"), ("outcome", *outcome)])
+            .assert_delta(1);
+        // Label-swap catcher: other outcomes silent.
+        for sibling in ALL_OUTCOMES.iter().filter(|o| *o != outcome) {
+            snap.counter("gc_mc_assignments_by_priority_total")
+                .with_labels(&[("priority", "test"), ("outcome", *sibling)])
+                .assert_delta(0);
+        }
+    }
+}
+
+#[test]
+fn priority_assignment_keeps_priorities_apart() {
+    let snap = MetricAssertion::snapshot();
+
+    record_priority_assignment("webinar", "assigned");
+
+    snap.counter("gc_mc_assignments_by_priority_total")
+        .with_labels(&[("priority", "webinar"), ("outcome", "assigned")])
+        .assert_delta(1);
+    for sibling in ["standard", "test"] {
+        snap.counter("gc_mc_assignments_by_priority_total")
+            .with_labels(&[("priority", sibling), ("outcome", "assigned")])
+            .assert_delta(0);
+    }
+}
@@ -0,0 +1,57 @@
+//! `x-request-id` correlation tests.
+//!
+//! Every response echoes the request ID, and error bodies include it so a
+//! user report can be traced through GC, AC and MC logs.
+
+use gc_test_utils::TestGcServer;
+use sqlx::PgPool;
+
+/// A valid caller-supplied ID is kept and echoed.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_request_id_is_echoed(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestGcServer::spawn(pool).await?;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("{}/health", server.url()))
+        .header("x-request-id", "support-1234")
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response
+            .headers()
+            .get("x-request-id")
+            .and_then(|v| v.to_str().ok()),
+        Some("support-1234")
+    );
+
+    Ok(())
+}
+
+/// Without a caller ID one is generated, and error bodies carry it.
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_error_body_includes_generated_request_id(pool: PgPool) -> Result<(), anyhow::Error> {
+    let server = TestGcServer::spawn(pool).await?;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("{}/api/v1/me", server.url()))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 401);
+    let header_id = response
+        .headers()
+        .get("x-request-id")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+        .ok_or_else(|| anyhow::anyhow!("missing x-request-id header"))?;
+    assert!(uuid::Uuid::parse_str(&header_id).is_ok());
+
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["error"]["request_id"], header_id.as_str());
+
+    Ok(())
+}
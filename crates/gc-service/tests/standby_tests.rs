@@ -0,0 +1,168 @@
+//! Warm standby MC integration tests.
+//!
+//! Tests standby pairing and promotion including:
+//! - A standby is found for its primary and never load balanced onto
+//! - A standby sees its primary's active meetings
+//! - An unreachable primary's meetings move to its promoted standby
+//! - A reachable primary is left alone
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use gc_service::repositories::{
+    HealthStatus, McCandidate, MeetingAssignmentsRepository, MeetingControllersRepository,
+};
+use gc_service::services::{MockMcClient, StandbyService};
+use sqlx::PgPool;
+use tokio::net::TcpListener;
+
+/// Register a healthy MC reachable (or not) at `grpc_endpoint`.
+async fn register_healthy_mc(
+    pool: &PgPool,
+    id: &str,
+    grpc_endpoint: &str,
+) -> Result<(), anyhow::Error> {
+    MeetingControllersRepository::register_mc(
+        pool,
+        id,
+        "us-east-1",
+        grpc_endpoint,
+        Some(&format!("https://{}.example.com:443", id)),
+        100,
+        1000,
+    )
+    .await?;
+
+    MeetingControllersRepository::update_heartbeat(pool, id, 0, 0, HealthStatus::Healthy).await?;
+
+    Ok(())
+}
+
+/// Assign `meeting_id` to `mc_id`.
+async fn assign(pool: &PgPool, meeting_id: &str, mc_id: &str) -> Result<(), anyhow::Error> {
+    let candidate = McCandidate {
+        controller_id: mc_id.to_string(),
+        grpc_endpoint: format!("http://{}.example.com:50051", mc_id),
+        webtransport_endpoint: None,
+        load_ratio: 0.0,
+    };
+    MeetingAssignmentsRepository::atomic_assign(
+        pool,
+        meeting_id,
+        "us-east-1",
+        &candidate,
+        "gc-test-001",
+    )
+    .await?;
+    Ok(())
+}
+
+async fn assigned_mc(pool: &PgPool, meeting_id: &str) -> Result<String, anyhow::Error> {
+    Ok(sqlx::query_scalar(
+        "SELECT meeting_controller_id FROM meeting_assignments WHERE meeting_id = $1",
+    )
+    .bind(meeting_id)
+    .fetch_one(pool)
+    .await?)
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_standby_is_found_and_not_a_candidate(pool: PgPool) -> Result<(), anyhow::Error> {
+    register_healthy_mc(&pool, "mc-0", "http://mc-0.example.com:50051").await?;
+    register_healthy_mc(&pool, "mc-1", "http://mc-1.example.com:50051").await?;
+    MeetingControllersRepository::set_standby_for(&pool, "mc-1", Some("mc-0")).await?;
+
+    let standby = MeetingControllersRepository::get_standby(&pool, "mc-0")
+        .await?
+        .expect("mc-1 should be mc-0's standby");
+    assert_eq!(standby.controller_id, "mc-1");
+    assert!(MeetingControllersRepository::get_standby(&pool, "mc-1")
+        .await?
+        .is_none());
+
+    let candidates = MeetingAssignmentsRepository::get_candidate_mcs(&pool, "us-east-1").await?;
+    let ids: Vec<&str> = candidates
+        .iter()
+        .map(|c| c.controller_id.as_str())
+        .collect();
+    assert_eq!(ids, ["mc-0"]);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_standby_sees_primary_meetings(pool: PgPool) -> Result<(), anyhow::Error> {
+    register_healthy_mc(&pool, "mc-0", "http://mc-0.example.com:50051").await?;
+    register_healthy_mc(&pool, "mc-1", "http://mc-1.example.com:50051").await?;
+    MeetingControllersRepository::set_standby_for(&pool, "mc-1", Some("mc-0")).await?;
+    assign(&pool, "meeting-a", "mc-0").await?;
+    assign(&pool, "meeting-b", "mc-0").await?;
+
+    let mut meetings = MeetingAssignmentsRepository::get_standby_meeting_ids(&pool, "mc-1").await?;
+    meetings.sort();
+    assert_eq!(meetings, ["meeting-a", "meeting-b"]);
+    assert!(
+        MeetingAssignmentsRepository::get_standby_meeting_ids(&pool, "mc-0")
+            .await?
+            .is_empty()
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_unreachable_primary_fails_over(pool: PgPool) -> Result<(), anyhow::Error> {
+    // Nothing listens on port 1
+    register_healthy_mc(&pool, "mc-0", "http://127.0.0.1:1").await?;
+    register_healthy_mc(&pool, "mc-1", "http://mc-1.example.com:50051").await?;
+    MeetingControllersRepository::set_standby_for(&pool, "mc-1", Some("mc-0")).await?;
+    assign(&pool, "meeting-a", "mc-0").await?;
+    let mc_client = MockMcClient::accepting();
+
+    let promotion =
+        StandbyService::promote_if_unreachable(&pool, &mc_client, "mc-0", "gc-test-002")
+            .await?
+            .expect("standby should be promoted");
+
+    assert_eq!(promotion.standby_id, "mc-1");
+    assert_eq!(promotion.moved, ["meeting-a"]);
+    assert!(promotion.failed.is_empty());
+    assert_eq!(mc_client.last_promoted(), ["meeting-a"]);
+    assert_eq!(assigned_mc(&pool, "meeting-a").await?, "mc-1");
+
+    // The primary takes no new meetings; the standby now does
+    let primary = MeetingControllersRepository::get_controller(&pool, "mc-0")
+        .await?
+        .expect("mc-0 registered");
+    assert_eq!(primary.health_status, HealthStatus::Unhealthy);
+    assert!(MeetingControllersRepository::get_standby(&pool, "mc-0")
+        .await?
+        .is_none());
+    let candidates = MeetingAssignmentsRepository::get_candidate_mcs(&pool, "us-east-1").await?;
+    let ids: Vec<&str> = candidates
+        .iter()
+        .map(|c| c.controller_id.as_str())
+        .collect();
+    assert_eq!(ids, ["mc-1"]);
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_reachable_primary_is_not_failed_over(pool: PgPool) -> Result<(), anyhow::Error> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let primary_endpoint = format!("http://{}", listener.local_addr()?);
+    register_healthy_mc(&pool, "mc-0", &primary_endpoint).await?;
+    register_healthy_mc(&pool, "mc-1", "http://mc-1.example.com:50051").await?;
+    MeetingControllersRepository::set_standby_for(&pool, "mc-1", Some("mc-0")).await?;
+    assign(&pool, "meeting-a", "mc-0").await?;
+    let mc_client = MockMcClient::accepting();
+
+    let promotion =
+        StandbyService::promote_if_unreachable(&pool, &mc_client, "mc-0", "gc-test-002").await?;
+
+    assert!(promotion.is_none());
+    assert_eq!(mc_client.call_count(), 0);
+    assert_eq!(assigned_mc(&pool, "meeting-a").await?, "mc-0");
+
+    Ok(())
+}
@@ -0,0 +1,497 @@
+//! Builder-style database fixtures for GC tests
+//!
+//! Each builder inserts one row with sensible defaults, so a test only sets
+//! the columns it cares about:
+//!
+//! - [`TestMeetingRow`] inserts a meeting, creating its org and creator user
+//!   unless given existing ones
+//! - [`TestMcRegistration`] inserts a healthy, fresh meeting controller
+//! - [`TestAssignment`] inserts an active meeting-to-MC assignment
+//!
+//! # Example
+//! ```rust,ignore
+//! #[sqlx::test(migrations = "../../migrations")]
+//! async fn test_example(pool: PgPool) -> Result<(), anyhow::Error> {
+//!     let meeting = TestMeetingRow::new().status("active").insert(&pool).await?;
+//!     let mc_id = TestMcRegistration::new("mc-1").insert(&pool).await?;
+//!     TestAssignment::new(meeting.meeting_id.to_string(), &mc_id)
+//!         .insert(&pool)
+//!         .await?;
+//!     Ok(())
+//! }
+//! ```
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Region used by fixtures that do not set one.
+pub const DEFAULT_TEST_REGION: &str = "us-east-1";
+
+/// Ids of a meeting inserted by [`TestMeetingRow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsertedMeeting {
+    pub meeting_id: Uuid,
+    pub org_id: Uuid,
+    pub created_by_user_id: Uuid,
+}
+
+/// Builder for a `meetings` row.
+///
+/// Defaults: a fresh org and creator user, a unique meeting code, status
+/// `scheduled`, 100 max participants, and `standard` priority.
+#[derive(Debug, Clone)]
+pub struct TestMeetingRow {
+    meeting_id: Uuid,
+    org_id: Option<Uuid>,
+    created_by_user_id: Option<Uuid>,
+    display_name: String,
+    meeting_code: Option<String>,
+    status: String,
+    max_participants: i32,
+    priority: String,
+    allow_guests: bool,
+    enable_e2e_encryption: bool,
+}
+
+impl Default for TestMeetingRow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestMeetingRow {
+    pub fn new() -> Self {
+        Self {
+            meeting_id: Uuid::new_v4(),
+            org_id: None,
+            created_by_user_id: None,
+            display_name: "Test Meeting".to_string(),
+            meeting_code: None,
+            status: "scheduled".to_string(),
+            max_participants: 100,
+            priority: "standard".to_string(),
+            allow_guests: false,
+            enable_e2e_encryption: true,
+        }
+    }
+
+    pub fn meeting_id(mut self, meeting_id: Uuid) -> Self {
+        self.meeting_id = meeting_id;
+        self
+    }
+
+    /// Use an existing org instead of creating one.
+    pub fn org_id(mut self, org_id: Uuid) -> Self {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    /// Use an existing user as creator. Requires [`org_id`](Self::org_id)
+    /// to be set to the user's org.
+    pub fn created_by(mut self, user_id: Uuid) -> Self {
+        self.created_by_user_id = Some(user_id);
+        self
+    }
+
+    pub fn display_name(mut self, display_name: &str) -> Self {
+        self.display_name = display_name.to_string();
+        self
+    }
+
+    pub fn meeting_code(mut self, meeting_code: &str) -> Self {
+        self.meeting_code = Some(meeting_code.to_string());
+        self
+    }
+
+    /// One of `scheduled`, `active`, `ended`, `cancelled`.
+    pub fn status(mut self, status: &str) -> Self {
+        self.status = status.to_string();
+        self
+    }
+
+    pub fn max_participants(mut self, max_participants: i32) -> Self {
+        self.max_participants = max_participants;
+        self
+    }
+
+    /// One of `webinar`, `standard`, `test`.
+    pub fn priority(mut self, priority: &str) -> Self {
+        self.priority = priority.to_string();
+        self
+    }
+
+    pub fn allow_guests(mut self, allow_guests: bool) -> Self {
+        self.allow_guests = allow_guests;
+        self
+    }
+
+    pub fn enable_e2e_encryption(mut self, enabled: bool) -> Self {
+        self.enable_e2e_encryption = enabled;
+        self
+    }
+
+    /// Insert the meeting, and its org and creator if not given.
+    pub async fn insert(self, pool: &PgPool) -> Result<InsertedMeeting, anyhow::Error> {
+        let org_id = match self.org_id {
+            Some(org_id) => org_id,
+            None => insert_test_org(pool).await?,
+        };
+        let created_by_user_id = match self.created_by_user_id {
+            Some(user_id) => user_id,
+            None => insert_test_user(pool, org_id).await?,
+        };
+        let meeting_code = self
+            .meeting_code
+            .unwrap_or_else(|| format!("CODE{}", short_id(self.meeting_id)));
+
+        sqlx::query(
+            r#"
+            INSERT INTO meetings (
+                meeting_id, org_id, created_by_user_id, display_name, meeting_code,
+                join_token_secret, status, max_participants, priority, allow_guests,
+                enable_e2e_encryption
+            )
+            VALUES ($1, $2, $3, $4, $5, 'test-secret', $6, $7, $8, $9, $10)
+            "#,
+        )
+        .bind(self.meeting_id)
+        .bind(org_id)
+        .bind(created_by_user_id)
+        .bind(&self.display_name)
+        .bind(&meeting_code)
+        .bind(&self.status)
+        .bind(self.max_participants)
+        .bind(&self.priority)
+        .bind(self.allow_guests)
+        .bind(self.enable_e2e_encryption)
+        .execute(pool)
+        .await?;
+
+        Ok(InsertedMeeting {
+            meeting_id: self.meeting_id,
+            org_id,
+            created_by_user_id,
+        })
+    }
+}
+
+/// Builder for a `meeting_controllers` row.
+///
+/// Defaults: healthy in [`DEFAULT_TEST_REGION`] and the `default` pool,
+/// capacity for 100 meetings and 1000 participants with none in use, a
+/// heartbeat of now, not cordoned, and not a standby.
+#[derive(Debug, Clone)]
+pub struct TestMcRegistration {
+    controller_id: String,
+    region: String,
+    pool: String,
+    health_status: String,
+    max_meetings: i32,
+    current_meetings: i32,
+    max_participants: i32,
+    current_participants: i32,
+    heartbeat_age: Duration,
+    standby_for: Option<String>,
+    cordoned: bool,
+}
+
+impl TestMcRegistration {
+    pub fn new(controller_id: &str) -> Self {
+        Self {
+            controller_id: controller_id.to_string(),
+            region: DEFAULT_TEST_REGION.to_string(),
+            pool: "default".to_string(),
+            health_status: "healthy".to_string(),
+            max_meetings: 100,
+            current_meetings: 0,
+            max_participants: 1000,
+            current_participants: 0,
+            heartbeat_age: Duration::zero(),
+            standby_for: None,
+            cordoned: false,
+        }
+    }
+
+    pub fn region(mut self, region: &str) -> Self {
+        self.region = region.to_string();
+        self
+    }
+
+    pub fn pool(mut self, pool: &str) -> Self {
+        self.pool = pool.to_string();
+        self
+    }
+
+    /// One of `pending`, `healthy`, `degraded`, `unhealthy`, `draining`.
+    pub fn health_status(mut self, health_status: &str) -> Self {
+        self.health_status = health_status.to_string();
+        self
+    }
+
+    pub fn capacity(mut self, max_meetings: i32, max_participants: i32) -> Self {
+        self.max_meetings = max_meetings;
+        self.max_participants = max_participants;
+        self
+    }
+
+    pub fn load(mut self, current_meetings: i32, current_participants: i32) -> Self {
+        self.current_meetings = current_meetings;
+        self.current_participants = current_participants;
+        self
+    }
+
+    /// Backdate the last heartbeat, e.g. to make the MC stale.
+    pub fn heartbeat_age(mut self, age: Duration) -> Self {
+        self.heartbeat_age = age;
+        self
+    }
+
+    pub fn standby_for(mut self, primary_id: &str) -> Self {
+        self.standby_for = Some(primary_id.to_string());
+        self
+    }
+
+    pub fn cordoned(mut self) -> Self {
+        self.cordoned = true;
+        self
+    }
+
+    /// Insert the controller. Returns its controller ID.
+    pub async fn insert(self, pool: &PgPool) -> Result<String, anyhow::Error> {
+        let grpc_endpoint = format!("https://{}.example.com:50051", self.controller_id);
+        let webtransport_endpoint = format!("https://{}.example.com:443", self.controller_id);
+        let last_heartbeat_at = Utc::now() - self.heartbeat_age;
+        let cordoned_at = self.cordoned.then(Utc::now);
+
+        sqlx::query(
+            r#"
+            INSERT INTO meeting_controllers (
+                controller_id, region, endpoint, grpc_endpoint, webtransport_endpoint,
+                max_meetings, max_participants, current_meetings, current_participants,
+                health_status, last_heartbeat_at, pool, standby_for, cordoned_at
+            )
+            VALUES ($1, $2, $3, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            "#,
+        )
+        .bind(&self.controller_id)
+        .bind(&self.region)
+        .bind(&grpc_endpoint)
+        .bind(&webtransport_endpoint)
+        .bind(self.max_meetings)
+        .bind(self.max_participants)
+        .bind(self.current_meetings)
+        .bind(self.current_participants)
+        .bind(&self.health_status)
+        .bind(last_heartbeat_at)
+        .bind(&self.pool)
+        .bind(&self.standby_for)
+        .bind(cordoned_at)
+        .execute(pool)
+        .await?;
+
+        Ok(self.controller_id)
+    }
+}
+
+/// Builder for a `meeting_assignments` row.
+///
+/// Defaults: active (not ended) in [`DEFAULT_TEST_REGION`], assigned now by
+/// `gc-test`. The controller must already exist.
+#[derive(Debug, Clone)]
+pub struct TestAssignment {
+    meeting_id: String,
+    controller_id: String,
+    region: String,
+    assigned_by_gc_id: String,
+    assigned_at: Option<DateTime<Utc>>,
+    ended_at: Option<DateTime<Utc>>,
+}
+
+impl TestAssignment {
+    pub fn new(meeting_id: impl Into<String>, controller_id: &str) -> Self {
+        Self {
+            meeting_id: meeting_id.into(),
+            controller_id: controller_id.to_string(),
+            region: DEFAULT_TEST_REGION.to_string(),
+            assigned_by_gc_id: "gc-test".to_string(),
+            assigned_at: None,
+            ended_at: None,
+        }
+    }
+
+    pub fn region(mut self, region: &str) -> Self {
+        self.region = region.to_string();
+        self
+    }
+
+    pub fn assigned_by(mut self, gc_id: &str) -> Self {
+        self.assigned_by_gc_id = gc_id.to_string();
+        self
+    }
+
+    pub fn assigned_at(mut self, assigned_at: DateTime<Utc>) -> Self {
+        self.assigned_at = Some(assigned_at);
+        self
+    }
+
+    pub fn ended_at(mut self, ended_at: DateTime<Utc>) -> Self {
+        self.ended_at = Some(ended_at);
+        self
+    }
+
+    /// Insert the assignment.
+    pub async fn insert(self, pool: &PgPool) -> Result<(), anyhow::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO meeting_assignments (
+                meeting_id, meeting_controller_id, region, assigned_by_gc_id,
+                assigned_at, ended_at
+            )
+            VALUES ($1, $2, $3, $4, COALESCE($5, NOW()), $6)
+            "#,
+        )
+        .bind(&self.meeting_id)
+        .bind(&self.controller_id)
+        .bind(&self.region)
+        .bind(&self.assigned_by_gc_id)
+        .bind(self.assigned_at)
+        .bind(self.ended_at)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// Insert an org with a unique subdomain. Returns its org_id.
+pub async fn insert_test_org(pool: &PgPool) -> Result<Uuid, anyhow::Error> {
+    let org_id = Uuid::new_v4();
+    sqlx::query(
+        r#"
+        INSERT INTO organizations (org_id, subdomain, display_name, plan_tier)
+        VALUES ($1, $2, 'Test Org', 'free')
+        "#,
+    )
+    .bind(org_id)
+    .bind(format!("test-{}", short_id(org_id)))
+    .execute(pool)
+    .await?;
+
+    Ok(org_id)
+}
+
+/// Insert a user with a unique email into `org_id`. Returns its user_id.
+pub async fn insert_test_user(pool: &PgPool, org_id: Uuid) -> Result<Uuid, anyhow::Error> {
+    let user_id = Uuid::new_v4();
+    sqlx::query(
+        r#"
+        INSERT INTO users (user_id, org_id, email, password_hash, display_name)
+        VALUES ($1, $2, $3, 'hashed', 'Test User')
+        "#,
+    )
+    .bind(user_id)
+    .bind(org_id)
+    .bind(format!("test-{}@example.com", short_id(user_id)))
+    .execute(pool)
+    .await?;
+
+    Ok(user_id)
+}
+
+/// First 8 hex digits of a UUID, for unique human-readable values.
+fn short_id(id: Uuid) -> String {
+    id.simple().to_string().chars().take(8).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_service::repositories::{MeetingAssignmentsRepository, MeetingControllersRepository};
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_meeting_row_defaults_create_parents(pool: PgPool) -> Result<(), anyhow::Error> {
+        let first = TestMeetingRow::new().insert(&pool).await?;
+        let second = TestMeetingRow::new().status("active").insert(&pool).await?;
+
+        assert_ne!(first.org_id, second.org_id);
+        let status: String =
+            sqlx::query_scalar("SELECT status FROM meetings WHERE meeting_id = $1")
+                .bind(second.meeting_id)
+                .fetch_one(&pool)
+                .await?;
+        assert_eq!(status, "active");
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_meeting_row_reuses_org_and_user(pool: PgPool) -> Result<(), anyhow::Error> {
+        let org_id = insert_test_org(&pool).await?;
+        let user_id = insert_test_user(&pool, org_id).await?;
+
+        let meeting = TestMeetingRow::new()
+            .org_id(org_id)
+            .created_by(user_id)
+            .meeting_code("SHARED000001")
+            .insert(&pool)
+            .await?;
+
+        assert_eq!(meeting.org_id, org_id);
+        assert_eq!(meeting.created_by_user_id, user_id);
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_mc_registration_and_assignment(pool: PgPool) -> Result<(), anyhow::Error> {
+        let mc_id = TestMcRegistration::new("mc-fixture")
+            .pool("dedicated")
+            .load(3, 30)
+            .insert(&pool)
+            .await?;
+        let controller = MeetingControllersRepository::get_controller(&pool, &mc_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("controller should exist"))?;
+        assert_eq!(controller.pool, "dedicated");
+        assert_eq!(controller.current_meetings, 3);
+        assert!(controller.cordoned_at.is_none());
+
+        TestAssignment::new("meeting-1", &mc_id)
+            .insert(&pool)
+            .await?;
+        let assignment = MeetingAssignmentsRepository::get_healthy_assignment(
+            &pool,
+            "meeting-1",
+            DEFAULT_TEST_REGION,
+        )
+        .await?;
+        assert_eq!(assignment.map(|a| a.mc_id), Some(mc_id));
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_stale_mc_and_ended_assignment(pool: PgPool) -> Result<(), anyhow::Error> {
+        let mc_id = TestMcRegistration::new("mc-stale")
+            .heartbeat_age(Duration::hours(1))
+            .insert(&pool)
+            .await?;
+        TestAssignment::new("meeting-stale", &mc_id)
+            .insert(&pool)
+            .await?;
+        let mc_live = TestMcRegistration::new("mc-live").insert(&pool).await?;
+        TestAssignment::new("meeting-ended", &mc_live)
+            .ended_at(Utc::now())
+            .insert(&pool)
+            .await?;
+
+        for meeting_id in ["meeting-stale", "meeting-ended"] {
+            let assignment = MeetingAssignmentsRepository::get_healthy_assignment(
+                &pool,
+                meeting_id,
+                DEFAULT_TEST_REGION,
+            )
+            .await?;
+            assert!(assignment.is_none(), "{meeting_id} should not be healthy");
+        }
+        Ok(())
+    }
+}
@@ -4,6 +4,8 @@
 //!
 //! This crate provides:
 //! - Server test harness (`TestGcServer` for E2E tests)
+//! - Database fixture builders (`TestMeetingRow`, `TestMcRegistration`,
+//!   `TestAssignment`)
 //!
 //! ## Usage
 //!
@@ -25,7 +27,9 @@
 //! }
 //! ```
 
+pub mod fixtures;
 pub mod server_harness;
 
 // Re-export commonly used items
+pub use fixtures::*;
 pub use server_harness::*;
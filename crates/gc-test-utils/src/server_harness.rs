@@ -5,7 +5,9 @@
 use common::secret::SecretString;
 use common::token_manager::TokenReceiver;
 use gc_service::config::Config;
+use gc_service::events::EventBus;
 use gc_service::observability::metrics::init_metrics_recorder;
+use gc_service::repositories::PgStore;
 use gc_service::routes::{self, AppState};
 use gc_service::services::MockMcClient;
 use sqlx::PgPool;
@@ -65,6 +67,7 @@ pub struct TestGcServer {
     pool: PgPool,
     config: Config,
     _handle: JoinHandle<()>,
+    socket_handle: Option<JoinHandle<()>>,
 }
 
 impl TestGcServer {
@@ -81,8 +84,19 @@ impl TestGcServer {
     /// * `Ok(TestGcServer)` - Running server instance
     /// * `Err(anyhow::Error)` - If server spawn fails
     pub async fn spawn(pool: PgPool) -> Result<Self, anyhow::Error> {
+        Self::spawn_with_vars(pool, &[]).await
+    }
+
+    /// Spawn a test server with extra configuration variables.
+    ///
+    /// When `GC_HEALTH_SOCKET_PATH` is among them, the operational routes
+    /// are also served on that Unix socket, as in `main.rs`.
+    pub async fn spawn_with_vars(
+        pool: PgPool,
+        extra_vars: &[(&str, &str)],
+    ) -> Result<Self, anyhow::Error> {
         // Build configuration for test environment
-        let vars = HashMap::from([
+        let mut vars = HashMap::from([
             (
                 "DATABASE_URL".to_string(),
                 "postgresql://test/test".to_string(),
@@ -96,6 +110,11 @@ impl TestGcServer {
             ("GC_CLIENT_ID".to_string(), "test-gc-client".to_string()),
             ("GC_CLIENT_SECRET".to_string(), "test-gc-secret".to_string()),
         ]);
+        vars.extend(
+            extra_vars
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string())),
+        );
 
         let config = Config::from_vars(&vars)
             .map_err(|e| anyhow::anyhow!("Failed to create config: {}", e))?;
@@ -111,11 +130,25 @@ impl TestGcServer {
             config: config.clone(),
             mc_client: mock_mc_client,
             token_receiver,
+            event_bus: EventBus::default(),
+            object_store: None,
+            store: Arc::new(PgStore::new(pool.clone(), config.storage_backend)),
         });
 
         // Get or initialize the metrics handle (shared across all test servers)
         let metrics_handle = get_or_init_metrics_handle();
 
+        // Serve the operational routes on the health socket, if configured
+        let socket_handle = match &config.health_socket {
+            Some(socket) => {
+                let ops_app = routes::build_ops_routes(state.clone(), metrics_handle.clone());
+                let server = common::listen::serve_unix(socket, ops_app, std::future::pending())
+                    .map_err(|e| anyhow::anyhow!("Failed to bind health socket: {}", e))?;
+                Some(tokio::spawn(server))
+            }
+            None => None,
+        };
+
         // Build routes using global-controller's real route builder
         let app = routes::build_routes(state, metrics_handle)
             .map_err(|e| anyhow::anyhow!("Failed to build routes: {}", e))?;
@@ -143,6 +176,7 @@ impl TestGcServer {
             pool,
             config,
             _handle: handle,
+            socket_handle,
         })
     }
 
@@ -172,6 +206,9 @@ impl Drop for TestGcServer {
         // Explicitly abort the HTTP server task to ensure immediate cleanup
         // when the test completes. This stops the server gracefully.
         self._handle.abort();
+        if let Some(handle) = &self.socket_handle {
+            handle.abort();
+        }
     }
 }
 
@@ -15,12 +15,22 @@
 //! 2. Cancels the root `CancellationToken` (propagates to all children)
 //! 3. Waits for meetings to drain or migrate
 //! 4. Reports completion to GC
+//!
+//! # Operator Directives
+//!
+//! GC can push [`OperatorDirective`]s on the heartbeat stream: drain or
+//! resume, soft limits on meetings and participants, and ending a meeting.
+//! Each one applied is audit-logged under the `mc.audit.directive` target.
 
 use crate::errors::McError;
 use crate::mh_connection_registry::MhConnectionRegistry;
+use crate::redis::JournalRecord;
 
-use super::meeting::{MeetingActor, MeetingActorHandle};
-use super::messages::{ControllerMessage, ControllerStatus, JoinResult, MeetingInfo};
+use super::keyframes::{KeyframeRequest, KeyframeRequester};
+use super::meeting::{MeetingActor, MeetingActorHandle, MeetingServices, MeetingSettings};
+use super::messages::{
+    ControllerMessage, ControllerStatus, JoinResult, MeetingInfo, OperatorDirective, SessionResume,
+};
 use super::metrics::{ActorMetrics, ActorType, ControllerMetrics, MailboxMonitor};
 
 use common::secret::{ExposeSecret, SecretBox};
@@ -30,7 +40,7 @@ use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
-use tracing::{debug, error, info, instrument, warn};
+use tracing::{debug, debug_span, error, info, instrument, warn, Instrument};
 
 /// Default channel buffer size for the controller mailbox.
 const CONTROLLER_CHANNEL_BUFFER: usize = 1000;
@@ -66,6 +76,47 @@ impl MeetingControllerActorHandle {
         controller_metrics: Arc<ControllerMetrics>,
         master_secret: SecretBox<Vec<u8>>,
         mh_connection_registry: Arc<MhConnectionRegistry>,
+    ) -> Self {
+        Self::spawn(
+            mc_id,
+            metrics,
+            controller_metrics,
+            master_secret,
+            mh_connection_registry,
+            MeetingServices::default(),
+        )
+    }
+
+    /// Create a controller whose meetings use the given external services
+    /// (attendance sink, interaction store).
+    ///
+    /// See [`MeetingControllerActorHandle::new`] for the remaining arguments.
+    #[must_use]
+    pub fn with_services(
+        mc_id: String,
+        metrics: Arc<ActorMetrics>,
+        controller_metrics: Arc<ControllerMetrics>,
+        master_secret: SecretBox<Vec<u8>>,
+        mh_connection_registry: Arc<MhConnectionRegistry>,
+        services: MeetingServices,
+    ) -> Self {
+        Self::spawn(
+            mc_id,
+            metrics,
+            controller_metrics,
+            master_secret,
+            mh_connection_registry,
+            services,
+        )
+    }
+
+    fn spawn(
+        mc_id: String,
+        metrics: Arc<ActorMetrics>,
+        controller_metrics: Arc<ControllerMetrics>,
+        master_secret: SecretBox<Vec<u8>>,
+        mh_connection_registry: Arc<MhConnectionRegistry>,
+        services: MeetingServices,
     ) -> Self {
         let (sender, receiver) = mpsc::channel(CONTROLLER_CHANNEL_BUFFER);
         let cancel_token = CancellationToken::new();
@@ -78,6 +129,7 @@ impl MeetingControllerActorHandle {
             Arc::clone(&controller_metrics),
             master_secret,
             mh_connection_registry,
+            services,
         );
 
         tokio::spawn(actor.run());
@@ -88,14 +140,51 @@ impl MeetingControllerActorHandle {
         }
     }
 
-    /// Create a new meeting.
+    /// Create a new meeting with default settings.
     ///
     /// Returns `Ok(())` if the meeting was created, or an error if creation failed.
     pub async fn create_meeting(&self, meeting_id: String) -> Result<(), McError> {
+        self.create_meeting_with_settings(meeting_id, MeetingSettings::default())
+            .await
+    }
+
+    /// Create a new meeting that enforces the given GC-provided settings.
+    ///
+    /// Returns `Ok(())` if the meeting was created, or an error if creation failed.
+    pub async fn create_meeting_with_settings(
+        &self,
+        meeting_id: String,
+        settings: MeetingSettings,
+    ) -> Result<(), McError> {
+        self.send_create_meeting(meeting_id, settings, None).await
+    }
+
+    /// Create a meeting taken over from a failed primary, replaying its
+    /// journal from the entries this standby pre-loaded.
+    ///
+    /// Returns `Ok(())` if the meeting was created, or an error if creation failed.
+    pub async fn create_promoted_meeting(
+        &self,
+        meeting_id: String,
+        settings: MeetingSettings,
+        preloaded_journal: Vec<JournalRecord>,
+    ) -> Result<(), McError> {
+        self.send_create_meeting(meeting_id, settings, Some(preloaded_journal))
+            .await
+    }
+
+    async fn send_create_meeting(
+        &self,
+        meeting_id: String,
+        settings: MeetingSettings,
+        preloaded_journal: Option<Vec<JournalRecord>>,
+    ) -> Result<(), McError> {
         let (tx, rx) = tokio::sync::oneshot::channel();
         self.sender
             .send(ControllerMessage::CreateMeeting {
                 meeting_id,
+                settings,
+                preloaded_journal,
                 respond_to: tx,
             })
             .await
@@ -124,6 +213,12 @@ impl MeetingControllerActorHandle {
     ///
     /// The controller looks up the meeting and forwards the join request.
     /// Returns a oneshot receiver that the caller can await for the result.
+    /// With `resume`, the meeting first tries to resume that session (see
+    /// [`JoinResult::resumed`]).
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "Mirrors the JoinConnection message fields"
+    )]
     pub async fn join_connection(
         &self,
         meeting_id: String,
@@ -132,6 +227,7 @@ impl MeetingControllerActorHandle {
         participant_id: String,
         is_host: bool,
         stream_tx: tokio::sync::mpsc::Sender<bytes::Bytes>,
+        resume: Option<SessionResume>,
     ) -> Result<tokio::sync::oneshot::Receiver<Result<JoinResult, McError>>, McError> {
         let (tx, rx) = tokio::sync::oneshot::channel();
         self.sender
@@ -142,6 +238,7 @@ impl MeetingControllerActorHandle {
                 participant_id,
                 is_host,
                 stream_tx,
+                resume,
                 respond_to: tx,
             })
             .await
@@ -176,6 +273,49 @@ impl MeetingControllerActorHandle {
             .map_err(|e| McError::Internal(format!("response receive failed: {e}")))
     }
 
+    /// Route an MH keyframe request to its meeting.
+    ///
+    /// Returns once the meeting is found; the request is forwarded to the
+    /// publisher (or dropped by its cooldown) asynchronously.
+    pub async fn request_keyframe(
+        &self,
+        meeting_id: String,
+        request: KeyframeRequest,
+    ) -> Result<(), McError> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(ControllerMessage::RequestKeyframe {
+                meeting_id,
+                request,
+                respond_to: tx,
+            })
+            .await
+            .map_err(|e| McError::Internal(format!("channel send failed: {e}")))?;
+
+        rx.await
+            .map_err(|e| McError::Internal(format!("response receive failed: {e}")))?
+    }
+
+    /// Apply an operator directive pushed by GC.
+    pub async fn apply_directive(
+        &self,
+        directive_id: String,
+        directive: OperatorDirective,
+    ) -> Result<(), McError> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(ControllerMessage::ApplyDirective {
+                directive_id,
+                directive,
+                respond_to: tx,
+            })
+            .await
+            .map_err(|e| McError::Internal(format!("channel send failed: {e}")))?;
+
+        rx.await
+            .map_err(|e| McError::Internal(format!("response receive failed: {e}")))?
+    }
+
     /// Initiate graceful shutdown.
     pub async fn shutdown(&self, deadline: Duration) -> Result<(), McError> {
         let (tx, rx) = tokio::sync::oneshot::channel();
@@ -233,6 +373,10 @@ pub struct MeetingControllerActor {
     meetings: HashMap<String, ManagedMeeting>,
     /// Whether the controller is accepting new meetings.
     accepting_new: bool,
+    /// Operator soft limit on meetings (`None` = no soft limit).
+    max_meetings: Option<u32>,
+    /// Operator soft limit on participants across all meetings.
+    max_participants: Option<u32>,
     /// Shared actor metrics.
     metrics: Arc<ActorMetrics>,
     /// Controller metrics for GC heartbeat reporting (participant count).
@@ -245,6 +389,8 @@ pub struct MeetingControllerActor {
     /// Registry tracking participant-to-MH connection state.
     /// Cleaned up when meetings are removed.
     mh_connection_registry: Arc<MhConnectionRegistry>,
+    /// External services handed to each meeting actor.
+    services: MeetingServices,
 }
 
 impl MeetingControllerActor {
@@ -261,6 +407,11 @@ impl MeetingControllerActor {
     ///   Wrapped in SecretBox to ensure secure memory handling.
     /// * `mh_connection_registry` - Registry tracking participant-to-MH connections.
     ///   Cleaned up when meetings are removed.
+    /// * `services` - External services handed to each meeting actor.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "Actor state wiring; all fields are set once at construction"
+    )]
     fn new(
         mc_id: String,
         receiver: mpsc::Receiver<ControllerMessage>,
@@ -269,6 +420,7 @@ impl MeetingControllerActor {
         controller_metrics: Arc<ControllerMetrics>,
         master_secret: SecretBox<Vec<u8>>,
         mh_connection_registry: Arc<MhConnectionRegistry>,
+        services: MeetingServices,
     ) -> Self {
         let mailbox = MailboxMonitor::new(ActorType::Controller, &mc_id);
 
@@ -278,11 +430,14 @@ impl MeetingControllerActor {
             cancel_token,
             meetings: HashMap::new(),
             accepting_new: true,
+            max_meetings: None,
+            max_participants: None,
             metrics,
             controller_metrics,
             mailbox,
             master_secret,
             mh_connection_registry,
+            services,
         }
     }
 
@@ -316,7 +471,9 @@ impl MeetingControllerActor {
                     match msg {
                         Some(message) => {
                             self.mailbox.record_enqueue();
-                            self.handle_message(message).await;
+                            self.handle_message(message)
+                                .instrument(debug_span!("mc.actor.controller.message"))
+                                .await;
                             self.mailbox.record_dequeue();
                             self.metrics.record_message_processed();
                         }
@@ -348,9 +505,13 @@ impl MeetingControllerActor {
         match message {
             ControllerMessage::CreateMeeting {
                 meeting_id,
+                settings,
+                preloaded_journal,
                 respond_to,
             } => {
-                let result = self.create_meeting(meeting_id).await;
+                let result = self
+                    .create_meeting(meeting_id, settings, preloaded_journal)
+                    .await;
                 let _ = respond_to.send(result);
             }
 
@@ -369,19 +530,25 @@ impl MeetingControllerActor {
                 participant_id,
                 is_host,
                 stream_tx,
+                resume,
                 respond_to,
             } => {
+                if resume.is_none() && self.at_participant_limit() {
+                    let _ = respond_to.send(Err(McError::McCapacityExceeded));
+                    return;
+                }
                 match self.get_meeting_handle(&meeting_id) {
                     Ok(meeting_handle) => {
                         // Spawn the join as a background task so we don't block the controller
                         tokio::spawn(async move {
                             let result = meeting_handle
-                                .connection_join(
+                                .connection_join_or_resume(
                                     connection_id,
                                     user_id,
                                     participant_id,
                                     is_host,
                                     Some(stream_tx),
+                                    resume,
                                 )
                                 .await;
                             let _ = respond_to.send(result);
@@ -406,6 +573,34 @@ impl MeetingControllerActor {
                 let _ = respond_to.send(status);
             }
 
+            ControllerMessage::RequestKeyframe {
+                meeting_id,
+                request,
+                respond_to,
+            } => match self.get_meeting_handle(&meeting_id) {
+                Ok(meeting_handle) => {
+                    let _ = respond_to.send(Ok(()));
+                    // Don't block the controller on a busy meeting mailbox
+                    tokio::spawn(async move {
+                        let _ = meeting_handle
+                            .request_keyframe(KeyframeRequester::MediaHandler, request)
+                            .await;
+                    });
+                }
+                Err(e) => {
+                    let _ = respond_to.send(Err(e));
+                }
+            },
+
+            ControllerMessage::ApplyDirective {
+                directive_id,
+                directive,
+                respond_to,
+            } => {
+                let result = self.apply_directive(&directive_id, directive);
+                let _ = respond_to.send(result);
+            }
+
             ControllerMessage::Shutdown {
                 deadline,
                 respond_to,
@@ -417,12 +612,24 @@ impl MeetingControllerActor {
     }
 
     /// Create a new meeting actor.
-    async fn create_meeting(&mut self, meeting_id: String) -> Result<(), McError> {
+    async fn create_meeting(
+        &mut self,
+        meeting_id: String,
+        settings: MeetingSettings,
+        preloaded_journal: Option<Vec<JournalRecord>>,
+    ) -> Result<(), McError> {
         // Check if we're accepting new meetings
         if !self.accepting_new {
             return Err(McError::Draining);
         }
 
+        if self
+            .max_meetings
+            .is_some_and(|max| self.meetings.len() >= max as usize)
+        {
+            return Err(McError::McCapacityExceeded);
+        }
+
         // Check if meeting already exists (MINOR-001: don't include meeting_id in error)
         if self.meetings.contains_key(&meeting_id) {
             return Err(McError::Conflict("Meeting already exists".to_string()));
@@ -441,12 +648,18 @@ impl MeetingControllerActor {
         // Create the meeting actor (with master_secret for session binding tokens)
         // Create a new SecretBox from the exposed secret bytes for each meeting
         let meeting_secret = SecretBox::new(Box::new(self.master_secret.expose_secret().clone()));
-        let (handle, task_handle) = MeetingActor::spawn(
+        let services = MeetingServices {
+            settings,
+            preloaded_journal,
+            ..self.services.clone()
+        };
+        let (handle, task_handle) = MeetingActor::spawn_with_services(
             meeting_id.clone(),
             meeting_token,
             Arc::clone(&self.metrics),
             Arc::clone(&self.controller_metrics),
             meeting_secret,
+            services,
         );
 
         let created_at = chrono::Utc::now().timestamp();
@@ -461,6 +674,7 @@ impl MeetingControllerActor {
         );
 
         self.metrics.meeting_created();
+        self.controller_metrics.increment_meetings();
 
         info!(
             target: "mc.actor.controller",
@@ -473,6 +687,79 @@ impl MeetingControllerActor {
         Ok(())
     }
 
+    /// Whether the participant soft limit is reached. Resumed sessions are
+    /// not counted against it: they already hold a seat.
+    fn at_participant_limit(&self) -> bool {
+        self.max_participants
+            .is_some_and(|max| self.controller_metrics.participants() >= max)
+    }
+
+    /// Apply an operator directive and audit-log the outcome.
+    fn apply_directive(
+        &mut self,
+        directive_id: &str,
+        directive: OperatorDirective,
+    ) -> Result<(), McError> {
+        let kind = directive.kind();
+        let result = match &directive {
+            OperatorDirective::Drain { draining } => {
+                // Shutdown drains for good; an undrain must not reopen
+                if !draining && self.cancel_token.is_cancelled() {
+                    Err(McError::Draining)
+                } else {
+                    self.accepting_new = !draining;
+                    Ok(())
+                }
+            }
+            OperatorDirective::SoftLimits {
+                max_meetings,
+                max_participants,
+            } => {
+                self.max_meetings = *max_meetings;
+                self.max_participants = *max_participants;
+                Ok(())
+            }
+            OperatorDirective::EndMeeting { meeting_id, reason } => {
+                self.get_meeting_handle(meeting_id).map(|meeting_handle| {
+                    // The health check removes the meeting once its actor exits
+                    let reason = reason.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = meeting_handle.end_meeting(reason).await {
+                            warn!(
+                                target: "mc.actor.controller",
+                                error = %e,
+                                "Failed to end meeting for directive"
+                            );
+                        }
+                    });
+                })
+            }
+        };
+
+        match &result {
+            Ok(()) => info!(
+                target: "mc.audit.directive",
+                mc_id = %self.mc_id,
+                directive_id = %directive_id,
+                kind,
+                directive = ?directive,
+                outcome = "applied",
+                "Operator directive applied"
+            ),
+            Err(e) => warn!(
+                target: "mc.audit.directive",
+                mc_id = %self.mc_id,
+                directive_id = %directive_id,
+                kind,
+                directive = ?directive,
+                outcome = "rejected",
+                error = %e,
+                "Operator directive rejected"
+            ),
+        }
+        result
+    }
+
     /// Get a cloned handle to a meeting actor for connection handling.
     fn get_meeting_handle(&self, meeting_id: &str) -> Result<MeetingActorHandle, McError> {
         match self.meetings.get(meeting_id) {
@@ -568,6 +855,7 @@ impl MeetingControllerActor {
                 });
 
                 self.metrics.meeting_removed();
+                self.controller_metrics.decrement_meetings();
 
                 // Clean up MH connection registry entries for this meeting
                 self.mh_connection_registry.remove_meeting(meeting_id).await;
@@ -723,6 +1011,7 @@ impl MeetingControllerActor {
                 }
 
                 self.metrics.meeting_removed();
+                self.controller_metrics.decrement_meetings();
             }
         }
     }
@@ -840,6 +1129,29 @@ mod tests {
         handle.cancel();
     }
 
+    #[tokio::test]
+    async fn test_controller_reports_meeting_count_for_heartbeat() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let handle = MeetingControllerActorHandle::new(
+            "mc-test-004b".to_string(),
+            metrics,
+            Arc::clone(&controller_metrics),
+            test_secret(),
+            test_registry(),
+        );
+
+        handle.create_meeting("m1".to_string()).await.unwrap();
+        handle.create_meeting("m2".to_string()).await.unwrap();
+        assert_eq!(controller_metrics.meetings(), 2);
+
+        handle.remove_meeting("m1".to_string()).await.unwrap();
+        assert_eq!(controller_metrics.meetings(), 1);
+
+        // Cleanup
+        handle.cancel();
+    }
+
     #[tokio::test]
     async fn test_controller_handle_status() {
         let metrics = ActorMetrics::new();
@@ -899,6 +1211,129 @@ mod tests {
         // This is expected behavior - the actor cancels after shutdown
     }
 
+    #[tokio::test]
+    async fn test_controller_drain_directive() {
+        let handle = MeetingControllerActorHandle::new(
+            "mc-test-008".to_string(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
+            test_secret(),
+            test_registry(),
+        );
+
+        handle
+            .apply_directive(
+                "d-1".to_string(),
+                OperatorDirective::Drain { draining: true },
+            )
+            .await
+            .unwrap();
+        assert!(handle.get_status().await.unwrap().is_draining);
+        let result = handle.create_meeting("m1".to_string()).await;
+        assert!(matches!(result, Err(McError::Draining)));
+
+        handle
+            .apply_directive(
+                "d-2".to_string(),
+                OperatorDirective::Drain { draining: false },
+            )
+            .await
+            .unwrap();
+        assert!(handle.create_meeting("m1".to_string()).await.is_ok());
+
+        // Cleanup
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_controller_soft_limits_directive() {
+        let controller_metrics = ControllerMetrics::new();
+        let handle = MeetingControllerActorHandle::new(
+            "mc-test-009".to_string(),
+            ActorMetrics::new(),
+            Arc::clone(&controller_metrics),
+            test_secret(),
+            test_registry(),
+        );
+        handle.create_meeting("m1".to_string()).await.unwrap();
+        controller_metrics.set_participants(2);
+
+        handle
+            .apply_directive(
+                "d-1".to_string(),
+                OperatorDirective::SoftLimits {
+                    max_meetings: Some(1),
+                    max_participants: Some(2),
+                },
+            )
+            .await
+            .unwrap();
+
+        // Existing meetings stay; new ones and fresh joins are refused
+        assert!(handle.get_meeting("m1".to_string()).await.is_ok());
+        let result = handle.create_meeting("m2".to_string()).await;
+        assert!(matches!(result, Err(McError::McCapacityExceeded)));
+        let (stream_tx, _stream_rx) = mpsc::channel(1);
+        let join = handle
+            .join_connection(
+                "m1".to_string(),
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                stream_tx,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(matches!(
+            join.await.unwrap(),
+            Err(McError::McCapacityExceeded)
+        ));
+
+        // Lifting the limits restores the configured ones
+        handle
+            .apply_directive(
+                "d-2".to_string(),
+                OperatorDirective::SoftLimits {
+                    max_meetings: None,
+                    max_participants: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert!(handle.create_meeting("m2".to_string()).await.is_ok());
+
+        // Cleanup
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_controller_end_meeting_directive() {
+        let handle = MeetingControllerActorHandle::new(
+            "mc-test-010".to_string(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
+            test_secret(),
+            test_registry(),
+        );
+        handle.create_meeting("m1".to_string()).await.unwrap();
+
+        let end = |meeting_id: &str| OperatorDirective::EndMeeting {
+            meeting_id: meeting_id.to_string(),
+            reason: "operator".to_string(),
+        };
+        let result = handle.apply_directive("d-1".to_string(), end("nope")).await;
+        assert!(matches!(result, Err(McError::MeetingNotFound(_))));
+        handle
+            .apply_directive("d-2".to_string(), end("m1"))
+            .await
+            .unwrap();
+
+        // Cleanup
+        handle.cancel();
+    }
+
     #[tokio::test]
     async fn test_controller_cancellation_token() {
         let metrics = ActorMetrics::new();
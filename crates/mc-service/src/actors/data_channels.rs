@@ -0,0 +1,333 @@
+//! Opaque application data channels routed by the `MeetingActor`.
+//!
+//! A data channel is a named, meeting-scoped byte stream. Participants
+//! subscribe to channel IDs and receive every payload other participants send
+//! on them. The MC never inspects payloads, so feature teams can prototype
+//! (whiteboard strokes, file offers) without adding proto messages.
+//!
+//! # Ordering
+//!
+//! Each channel has a sequence number assigned by the actor. Because the actor
+//! processes one message at a time, all subscribers observe a channel's
+//! messages in the same order. Delivery is best-effort: a subscriber whose
+//! outbound queue is full misses messages and can detect the gap from the
+//! sequence numbers.
+//!
+//! # Limits
+//!
+//! Channel IDs, payload sizes, channel counts and subscriptions are bounded
+//! (see the constants below); send rates are bounded per participant by the
+//! actor. Channel state is not persisted.
+
+use crate::errors::McError;
+
+use std::collections::{BTreeSet, HashMap};
+use std::time::Duration;
+
+/// Maximum channel ID length (bytes).
+pub const MAX_CHANNEL_ID_LEN: usize = 64;
+
+/// Maximum payload size per message (bytes).
+pub const MAX_DATA_CHANNEL_PAYLOAD: usize = 16 * 1024;
+
+/// Maximum channels with at least one subscriber per meeting.
+pub const MAX_CHANNELS_PER_MEETING: usize = 64;
+
+/// Maximum channel subscriptions per participant.
+pub const MAX_SUBSCRIPTIONS_PER_PARTICIPANT: usize = 16;
+
+/// Data channel requests allowed per participant per window.
+pub const DATA_CHANNEL_RATE_MAX_ACTIONS: u32 = 50;
+
+/// Data channel rate limit window.
+pub const DATA_CHANNEL_RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// A data channel request from a participant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataChannelRequest {
+    /// Start receiving messages on a channel.
+    Subscribe { channel_id: String },
+    /// Stop receiving messages on a channel.
+    Unsubscribe { channel_id: String },
+    /// Send a payload to a channel's subscribers.
+    Send {
+        channel_id: String,
+        payload: Vec<u8>,
+    },
+}
+
+/// A payload ready for delivery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataChannelDelivery {
+    /// Channel sequence number (starts at 1; 0 when the channel has no
+    /// subscribers and the payload is dropped).
+    pub sequence: u64,
+    /// Participants to deliver to (subscribers other than the sender).
+    pub recipients: Vec<String>,
+}
+
+/// Subscriptions and sequence counters for one meeting.
+#[derive(Debug, Default)]
+pub struct DataChannels {
+    /// Subscribed participant IDs by channel ID.
+    subscribers: HashMap<String, BTreeSet<String>>,
+    /// Last assigned sequence number by channel ID (reset when a channel
+    /// loses its last subscriber).
+    sequences: HashMap<String, u64>,
+}
+
+impl DataChannels {
+    /// Create empty channel state.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribe a participant to a channel (idempotent).
+    ///
+    /// # Errors
+    ///
+    /// - `McError::InvalidRequest` for a malformed channel ID
+    /// - `McError::Conflict` when the participant or meeting is at its channel limit
+    pub fn subscribe(&mut self, participant_id: &str, channel_id: &str) -> Result<(), McError> {
+        validate_channel_id(channel_id)?;
+
+        if self
+            .subscribers
+            .get(channel_id)
+            .is_some_and(|s| s.contains(participant_id))
+        {
+            return Ok(());
+        }
+        if self.subscription_count(participant_id) >= MAX_SUBSCRIPTIONS_PER_PARTICIPANT {
+            return Err(McError::Conflict(
+                "Data channel subscription limit reached".to_string(),
+            ));
+        }
+        if !self.subscribers.contains_key(channel_id)
+            && self.subscribers.len() >= MAX_CHANNELS_PER_MEETING
+        {
+            return Err(McError::Conflict("Data channel limit reached".to_string()));
+        }
+
+        self.subscribers
+            .entry(channel_id.to_string())
+            .or_default()
+            .insert(participant_id.to_string());
+        Ok(())
+    }
+
+    /// Unsubscribe a participant from a channel (idempotent).
+    pub fn unsubscribe(&mut self, participant_id: &str, channel_id: &str) {
+        if let Some(subscribers) = self.subscribers.get_mut(channel_id) {
+            subscribers.remove(participant_id);
+            if subscribers.is_empty() {
+                self.subscribers.remove(channel_id);
+                self.sequences.remove(channel_id);
+            }
+        }
+    }
+
+    /// Drop all of a participant's subscriptions (participant left).
+    pub fn remove_participant(&mut self, participant_id: &str) {
+        let sequences = &mut self.sequences;
+        self.subscribers.retain(|channel_id, subscribers| {
+            subscribers.remove(participant_id);
+            if subscribers.is_empty() {
+                sequences.remove(channel_id);
+                return false;
+            }
+            true
+        });
+    }
+
+    /// Assign the next sequence number for a payload and resolve recipients.
+    ///
+    /// The sender does not need to be subscribed and never receives its own
+    /// payload. Payloads for channels without subscribers are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::InvalidRequest` for a malformed channel ID or an
+    /// empty or oversized payload.
+    pub fn publish(
+        &mut self,
+        sender_id: &str,
+        channel_id: &str,
+        payload_len: usize,
+    ) -> Result<DataChannelDelivery, McError> {
+        validate_channel_id(channel_id)?;
+        if payload_len == 0 {
+            return Err(McError::InvalidRequest(
+                "Data channel payload must not be empty".to_string(),
+            ));
+        }
+        if payload_len > MAX_DATA_CHANNEL_PAYLOAD {
+            return Err(McError::InvalidRequest(format!(
+                "Data channel payload exceeds {MAX_DATA_CHANNEL_PAYLOAD} bytes"
+            )));
+        }
+
+        let Some(subscribers) = self.subscribers.get(channel_id) else {
+            return Ok(DataChannelDelivery {
+                sequence: 0,
+                recipients: Vec::new(),
+            });
+        };
+        let recipients: Vec<String> = subscribers
+            .iter()
+            .filter(|id| id.as_str() != sender_id)
+            .cloned()
+            .collect();
+
+        let sequence = self.sequences.entry(channel_id.to_string()).or_insert(0);
+        *sequence += 1;
+
+        Ok(DataChannelDelivery {
+            sequence: *sequence,
+            recipients,
+        })
+    }
+
+    fn subscription_count(&self, participant_id: &str) -> usize {
+        self.subscribers
+            .values()
+            .filter(|s| s.contains(participant_id))
+            .count()
+    }
+}
+
+/// Check a channel ID is 1-64 bytes of `[A-Za-z0-9._-]`.
+fn validate_channel_id(channel_id: &str) -> Result<(), McError> {
+    let valid = !channel_id.is_empty()
+        && channel_id.len() <= MAX_CHANNEL_ID_LEN
+        && channel_id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-'));
+    if valid {
+        Ok(())
+    } else {
+        Err(McError::InvalidRequest(
+            "Invalid data channel ID".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_routes_to_other_subscribers() {
+        let mut channels = DataChannels::new();
+        channels.subscribe("part-1", "whiteboard").unwrap();
+        channels.subscribe("part-2", "whiteboard").unwrap();
+        channels.subscribe("part-3", "files").unwrap();
+
+        let delivery = channels.publish("part-1", "whiteboard", 10).unwrap();
+        assert_eq!(delivery.recipients, vec!["part-2".to_string()]);
+        assert_eq!(delivery.sequence, 1);
+
+        let delivery = channels.publish("part-3", "whiteboard", 10).unwrap();
+        assert_eq!(
+            delivery.recipients,
+            vec!["part-1".to_string(), "part-2".to_string()]
+        );
+        assert_eq!(delivery.sequence, 2, "Sequence is per channel");
+
+        let delivery = channels.publish("part-1", "files", 10).unwrap();
+        assert_eq!(delivery.sequence, 1);
+    }
+
+    #[test]
+    fn test_publish_without_subscribers_does_not_grow_state() {
+        let mut channels = DataChannels::new();
+        for i in 0..1000 {
+            let delivery = channels.publish("part-1", &format!("ch-{i}"), 1).unwrap();
+            assert!(delivery.recipients.is_empty());
+            assert_eq!(delivery.sequence, 0);
+        }
+        assert!(channels.sequences.is_empty());
+    }
+
+    #[test]
+    fn test_publish_validates_payload() {
+        let mut channels = DataChannels::new();
+        assert!(matches!(
+            channels.publish("part-1", "whiteboard", 0),
+            Err(McError::InvalidRequest(_))
+        ));
+        assert!(matches!(
+            channels.publish("part-1", "whiteboard", MAX_DATA_CHANNEL_PAYLOAD + 1),
+            Err(McError::InvalidRequest(_))
+        ));
+        assert!(channels
+            .publish("part-1", "whiteboard", MAX_DATA_CHANNEL_PAYLOAD)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_channel_id_validation() {
+        let mut channels = DataChannels::new();
+        let too_long = "x".repeat(MAX_CHANNEL_ID_LEN + 1);
+        for bad in ["", "has space", "slash/es", too_long.as_str()] {
+            assert!(
+                matches!(
+                    channels.subscribe("part-1", bad),
+                    Err(McError::InvalidRequest(_))
+                ),
+                "{bad:?} should be rejected"
+            );
+        }
+        channels
+            .subscribe("part-1", "app.whiteboard_v2-beta")
+            .unwrap();
+    }
+
+    #[test]
+    fn test_subscription_limits() {
+        let mut channels = DataChannels::new();
+        for i in 0..MAX_SUBSCRIPTIONS_PER_PARTICIPANT {
+            channels.subscribe("part-1", &format!("ch-{i}")).unwrap();
+        }
+        // Re-subscribing is idempotent, even at the limit
+        channels.subscribe("part-1", "ch-0").unwrap();
+        assert!(matches!(
+            channels.subscribe("part-1", "one-more"),
+            Err(McError::Conflict(_))
+        ));
+
+        let mut channels = DataChannels::new();
+        for i in 0..MAX_CHANNELS_PER_MEETING {
+            channels
+                .subscribe(&format!("part-{i}"), &format!("ch-{i}"))
+                .unwrap();
+        }
+        assert!(matches!(
+            channels.subscribe("part-x", "one-more"),
+            Err(McError::Conflict(_))
+        ));
+        // Existing channels can still be joined
+        channels.subscribe("part-x", "ch-0").unwrap();
+    }
+
+    #[test]
+    fn test_unsubscribe_and_remove_participant() {
+        let mut channels = DataChannels::new();
+        channels.subscribe("part-1", "a").unwrap();
+        channels.subscribe("part-1", "b").unwrap();
+        channels.subscribe("part-2", "b").unwrap();
+
+        channels.unsubscribe("part-1", "a");
+        assert!(!channels.subscribers.contains_key("a"));
+
+        channels.remove_participant("part-2");
+        let delivery = channels.publish("part-3", "b", 1).unwrap();
+        assert_eq!(delivery.recipients, vec!["part-1".to_string()]);
+
+        channels.remove_participant("part-1");
+        assert!(channels.subscribers.is_empty());
+        assert!(channels.sequences.is_empty());
+    }
+}
@@ -0,0 +1,758 @@
+//! Polls and Q&A state for a meeting.
+//!
+//! `InteractionState` is owned by the `MeetingActor` and serialized to Redis
+//! after every change so a replacement MC can restore it. It is plain data:
+//! validation and state transitions live here, while host checks, rate
+//! limiting, persistence and broadcast are done by the actor.
+//!
+//! # Visibility
+//!
+//! - New polls and question changes are broadcast to everyone
+//! - Individual votes are never broadcast; tallies are only sent when the
+//!   host publishes results, which also closes the poll
+//! - Votes and upvotes are keyed by user ID, so rejoining does not grant
+//!   a second vote
+//!
+//! # Limits
+//!
+//! All client-controlled text and collection sizes are bounded (see the
+//! constants below). Per-participant request rates are bounded by the
+//! actor's [`RateLimiter`](super::rate_limit::RateLimiter), which is
+//! intentionally not persisted.
+
+use crate::errors::McError;
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+
+/// Maximum poll question length (bytes).
+pub const MAX_POLL_QUESTION_LEN: usize = 500;
+
+/// Minimum number of options in a poll.
+pub const MIN_POLL_OPTIONS: usize = 2;
+
+/// Maximum number of options in a poll.
+pub const MAX_POLL_OPTIONS: usize = 10;
+
+/// Maximum poll option length (bytes).
+pub const MAX_POLL_OPTION_LEN: usize = 200;
+
+/// Maximum polls per meeting.
+pub const MAX_POLLS: usize = 50;
+
+/// Maximum Q&A question length (bytes).
+pub const MAX_QUESTION_LEN: usize = 1000;
+
+/// Maximum Q&A questions per meeting.
+pub const MAX_QUESTIONS: usize = 500;
+
+/// Interaction requests allowed per participant per window.
+pub const RATE_LIMIT_MAX_ACTIONS: u32 = 10;
+
+/// Rate limit window.
+pub const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(10);
+
+/// A poll or Q&A request from a participant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InteractionRequest {
+    /// Open a new poll (host only).
+    CreatePoll {
+        question: String,
+        options: Vec<String>,
+    },
+    /// Vote on an open poll.
+    Vote { poll_id: String, option_index: u32 },
+    /// Close a poll and publish its results (host only).
+    PublishResults { poll_id: String },
+    /// Add a question to the Q&A queue.
+    AskQuestion { text: String },
+    /// Upvote a question.
+    UpvoteQuestion { question_id: String },
+    /// Mark a question answered (host only).
+    MarkAnswered { question_id: String },
+}
+
+impl InteractionRequest {
+    /// Whether the request requires host privileges.
+    #[must_use]
+    pub fn requires_host(&self) -> bool {
+        matches!(
+            self,
+            Self::CreatePoll { .. } | Self::PublishResults { .. } | Self::MarkAnswered { .. }
+        )
+    }
+}
+
+/// A change to broadcast to every participant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InteractionEvent {
+    /// A poll was created (also replayed to late joiners).
+    PollCreated {
+        poll_id: String,
+        question: String,
+        options: Vec<String>,
+        closed: bool,
+    },
+    /// A poll's results were published.
+    PollResults {
+        poll_id: String,
+        vote_counts: Vec<u32>,
+        total_votes: u32,
+    },
+    /// A question was asked, upvoted, or answered.
+    QuestionUpdated {
+        question_id: String,
+        text: String,
+        asked_by: String,
+        upvotes: u32,
+        answered: bool,
+    },
+}
+
+/// A poll and its votes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Poll {
+    poll_id: String,
+    question: String,
+    options: Vec<String>,
+    closed: bool,
+    /// Option index by user ID.
+    votes: BTreeMap<String, u32>,
+}
+
+impl Poll {
+    fn created_event(&self) -> InteractionEvent {
+        InteractionEvent::PollCreated {
+            poll_id: self.poll_id.clone(),
+            question: self.question.clone(),
+            options: self.options.clone(),
+            closed: self.closed,
+        }
+    }
+
+    fn results_event(&self) -> InteractionEvent {
+        let mut vote_counts = vec![0u32; self.options.len()];
+        for index in self.votes.values() {
+            if let Some(count) = vote_counts.get_mut(*index as usize) {
+                *count += 1;
+            }
+        }
+        InteractionEvent::PollResults {
+            poll_id: self.poll_id.clone(),
+            total_votes: vote_counts.iter().sum(),
+            vote_counts,
+        }
+    }
+}
+
+/// A Q&A question and its upvoters.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Question {
+    question_id: String,
+    text: String,
+    /// Participant ID of the asker.
+    asked_by: String,
+    answered: bool,
+    /// User IDs that upvoted.
+    upvoters: BTreeSet<String>,
+}
+
+impl Question {
+    fn updated_event(&self) -> InteractionEvent {
+        InteractionEvent::QuestionUpdated {
+            question_id: self.question_id.clone(),
+            text: self.text.clone(),
+            asked_by: self.asked_by.clone(),
+            upvotes: u32::try_from(self.upvoters.len()).unwrap_or(u32::MAX),
+            answered: self.answered,
+        }
+    }
+}
+
+/// Polls and Q&A state for one meeting.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InteractionState {
+    polls: Vec<Poll>,
+    questions: Vec<Question>,
+    next_id: u64,
+}
+
+impl InteractionState {
+    /// Create empty state.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether there are no polls or questions.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.polls.is_empty() && self.questions.is_empty()
+    }
+
+    /// Restore state from its JSON snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Internal` if the snapshot is malformed.
+    pub fn from_json(json: &str) -> Result<Self, McError> {
+        serde_json::from_str(json)
+            .map_err(|e| McError::Internal(format!("invalid interaction snapshot: {e}")))
+    }
+
+    /// Serialize state to a JSON snapshot.
+    ///
+    /// Votes and upvoters are kept sorted, so equal states serialize to the
+    /// same bytes (see [`InteractionState::checksum`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Internal` if serialization fails.
+    pub fn to_json(&self) -> Result<String, McError> {
+        serde_json::to_string(self)
+            .map_err(|e| McError::Internal(format!("serialization failed: {e}")))
+    }
+
+    /// SHA-256 (hex) of the JSON snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Internal` if serialization fails.
+    pub fn checksum(&self) -> Result<String, McError> {
+        self.to_json()
+            .map(|json| super::state_check::checksum(json.as_bytes()))
+    }
+
+    /// Apply a request from a participant.
+    ///
+    /// Host privileges must already have been checked by the caller.
+    ///
+    /// # Returns
+    ///
+    /// The event to broadcast, or `None` when nothing visible changed
+    /// (votes, repeated upvotes, re-marking an answered question).
+    ///
+    /// # Errors
+    ///
+    /// - `McError::InvalidRequest` for malformed input, unknown IDs, or a closed poll
+    /// - `McError::Conflict` when the meeting's poll or question limit is reached
+    pub fn apply(
+        &mut self,
+        participant_id: &str,
+        user_id: &str,
+        request: InteractionRequest,
+    ) -> Result<Option<InteractionEvent>, McError> {
+        match request {
+            InteractionRequest::CreatePoll { question, options } => {
+                self.create_poll(question, options).map(Some)
+            }
+            InteractionRequest::Vote {
+                poll_id,
+                option_index,
+            } => self.vote(&poll_id, user_id, option_index).map(|()| None),
+            InteractionRequest::PublishResults { poll_id } => {
+                self.publish_results(&poll_id).map(Some)
+            }
+            InteractionRequest::AskQuestion { text } => {
+                self.ask_question(participant_id, text).map(Some)
+            }
+            InteractionRequest::UpvoteQuestion { question_id } => {
+                self.upvote(&question_id, user_id)
+            }
+            InteractionRequest::MarkAnswered { question_id } => self.mark_answered(&question_id),
+        }
+    }
+
+    /// Events that bring a late joiner up to date.
+    #[must_use]
+    pub fn snapshot_events(&self) -> Vec<InteractionEvent> {
+        let mut events = Vec::with_capacity(self.polls.len() * 2 + self.questions.len());
+        for poll in &self.polls {
+            events.push(poll.created_event());
+            if poll.closed {
+                events.push(poll.results_event());
+            }
+        }
+        events.extend(self.questions.iter().map(Question::updated_event));
+        events
+    }
+
+    fn create_poll(
+        &mut self,
+        question: String,
+        options: Vec<String>,
+    ) -> Result<InteractionEvent, McError> {
+        let question = required_text(question, MAX_POLL_QUESTION_LEN, "Poll question")?;
+        if !(MIN_POLL_OPTIONS..=MAX_POLL_OPTIONS).contains(&options.len()) {
+            return Err(McError::InvalidRequest(format!(
+                "Poll must have {MIN_POLL_OPTIONS}-{MAX_POLL_OPTIONS} options"
+            )));
+        }
+        let options = options
+            .into_iter()
+            .map(|option| required_text(option, MAX_POLL_OPTION_LEN, "Poll option"))
+            .collect::<Result<Vec<_>, _>>()?;
+        if self.polls.len() >= MAX_POLLS {
+            return Err(McError::Conflict("Poll limit reached".to_string()));
+        }
+
+        let poll = Poll {
+            poll_id: self.next_id("poll"),
+            question,
+            options,
+            closed: false,
+            votes: BTreeMap::new(),
+        };
+        let event = poll.created_event();
+        self.polls.push(poll);
+        Ok(event)
+    }
+
+    fn vote(&mut self, poll_id: &str, user_id: &str, option_index: u32) -> Result<(), McError> {
+        let poll = self.poll_mut(poll_id)?;
+        if poll.closed {
+            return Err(McError::InvalidRequest("Poll is closed".to_string()));
+        }
+        if option_index as usize >= poll.options.len() {
+            return Err(McError::InvalidRequest("Invalid poll option".to_string()));
+        }
+        // Re-voting replaces the previous vote
+        poll.votes.insert(user_id.to_string(), option_index);
+        Ok(())
+    }
+
+    fn publish_results(&mut self, poll_id: &str) -> Result<InteractionEvent, McError> {
+        let poll = self.poll_mut(poll_id)?;
+        poll.closed = true;
+        Ok(poll.results_event())
+    }
+
+    fn ask_question(
+        &mut self,
+        participant_id: &str,
+        text: String,
+    ) -> Result<InteractionEvent, McError> {
+        let text = required_text(text, MAX_QUESTION_LEN, "Question")?;
+        if self.questions.len() >= MAX_QUESTIONS {
+            return Err(McError::Conflict("Question limit reached".to_string()));
+        }
+
+        let question = Question {
+            question_id: self.next_id("q"),
+            text,
+            asked_by: participant_id.to_string(),
+            answered: false,
+            upvoters: BTreeSet::new(),
+        };
+        let event = question.updated_event();
+        self.questions.push(question);
+        Ok(event)
+    }
+
+    fn upvote(
+        &mut self,
+        question_id: &str,
+        user_id: &str,
+    ) -> Result<Option<InteractionEvent>, McError> {
+        let question = self.question_mut(question_id)?;
+        if question.upvoters.insert(user_id.to_string()) {
+            Ok(Some(question.updated_event()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn mark_answered(&mut self, question_id: &str) -> Result<Option<InteractionEvent>, McError> {
+        let question = self.question_mut(question_id)?;
+        if question.answered {
+            return Ok(None);
+        }
+        question.answered = true;
+        Ok(Some(question.updated_event()))
+    }
+
+    fn poll_mut(&mut self, poll_id: &str) -> Result<&mut Poll, McError> {
+        self.polls
+            .iter_mut()
+            .find(|p| p.poll_id == poll_id)
+            .ok_or_else(|| McError::InvalidRequest("Unknown poll".to_string()))
+    }
+
+    fn question_mut(&mut self, question_id: &str) -> Result<&mut Question, McError> {
+        self.questions
+            .iter_mut()
+            .find(|q| q.question_id == question_id)
+            .ok_or_else(|| McError::InvalidRequest("Unknown question".to_string()))
+    }
+
+    fn next_id(&mut self, prefix: &str) -> String {
+        self.next_id += 1;
+        format!("{prefix}-{}", self.next_id)
+    }
+}
+
+/// Trim `text` and check it is non-empty and at most `max_len` bytes.
+fn required_text(text: String, max_len: usize, what: &str) -> Result<String, McError> {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return Err(McError::InvalidRequest(format!("{what} must not be empty")));
+    }
+    if trimmed.len() > max_len {
+        return Err(McError::InvalidRequest(format!(
+            "{what} exceeds {max_len} bytes"
+        )));
+    }
+    Ok(trimmed.to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    fn create_poll(state: &mut InteractionState, options: &[&str]) -> String {
+        let event = state
+            .apply(
+                "host-part",
+                "host-user",
+                InteractionRequest::CreatePoll {
+                    question: "Lunch?".to_string(),
+                    options: options.iter().map(ToString::to_string).collect(),
+                },
+            )
+            .unwrap();
+        match event {
+            Some(InteractionEvent::PollCreated { poll_id, .. }) => poll_id,
+            other => panic!("Expected PollCreated, got {other:?}"),
+        }
+    }
+
+    fn vote(state: &mut InteractionState, user: &str, poll_id: &str, option_index: u32) {
+        let event = state
+            .apply(
+                "part",
+                user,
+                InteractionRequest::Vote {
+                    poll_id: poll_id.to_string(),
+                    option_index,
+                },
+            )
+            .unwrap();
+        assert_eq!(event, None, "Votes must not be broadcast");
+    }
+
+    fn ask(state: &mut InteractionState, text: &str) -> String {
+        match state
+            .apply(
+                "part-1",
+                "user-1",
+                InteractionRequest::AskQuestion {
+                    text: text.to_string(),
+                },
+            )
+            .unwrap()
+        {
+            Some(InteractionEvent::QuestionUpdated { question_id, .. }) => question_id,
+            other => panic!("Expected QuestionUpdated, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_requires_host() {
+        assert!(InteractionRequest::CreatePoll {
+            question: String::new(),
+            options: Vec::new()
+        }
+        .requires_host());
+        assert!(InteractionRequest::PublishResults {
+            poll_id: String::new()
+        }
+        .requires_host());
+        assert!(InteractionRequest::MarkAnswered {
+            question_id: String::new()
+        }
+        .requires_host());
+        assert!(!InteractionRequest::Vote {
+            poll_id: String::new(),
+            option_index: 0
+        }
+        .requires_host());
+        assert!(!InteractionRequest::AskQuestion {
+            text: String::new()
+        }
+        .requires_host());
+        assert!(!InteractionRequest::UpvoteQuestion {
+            question_id: String::new()
+        }
+        .requires_host());
+    }
+
+    #[test]
+    fn test_poll_vote_and_publish() {
+        let mut state = InteractionState::new();
+        let poll_id = create_poll(&mut state, &["Pizza", "Tacos", "Salad"]);
+
+        vote(&mut state, "user-1", &poll_id, 0);
+        vote(&mut state, "user-2", &poll_id, 1);
+        vote(&mut state, "user-3", &poll_id, 1);
+        // Re-vote replaces user-1's earlier vote
+        vote(&mut state, "user-1", &poll_id, 1);
+
+        let event = state
+            .apply(
+                "host-part",
+                "host-user",
+                InteractionRequest::PublishResults {
+                    poll_id: poll_id.clone(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            event,
+            Some(InteractionEvent::PollResults {
+                poll_id: poll_id.clone(),
+                vote_counts: vec![0, 3, 0],
+                total_votes: 3,
+            })
+        );
+
+        // Poll is closed after publishing
+        let result = state.apply(
+            "part",
+            "user-4",
+            InteractionRequest::Vote {
+                poll_id,
+                option_index: 0,
+            },
+        );
+        assert!(matches!(result, Err(McError::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_create_poll_validation() {
+        let mut state = InteractionState::new();
+        let mut create = |question: &str, options: Vec<String>| {
+            state.apply(
+                "host-part",
+                "host-user",
+                InteractionRequest::CreatePoll {
+                    question: question.to_string(),
+                    options,
+                },
+            )
+        };
+
+        assert!(matches!(
+            create("   ", vec!["a".into(), "b".into()]),
+            Err(McError::InvalidRequest(_))
+        ));
+        assert!(matches!(
+            create("Q", vec!["only".into()]),
+            Err(McError::InvalidRequest(_))
+        ));
+        assert!(matches!(
+            create("Q", vec!["x".into(); MAX_POLL_OPTIONS + 1]),
+            Err(McError::InvalidRequest(_))
+        ));
+        assert!(matches!(
+            create("Q", vec!["a".into(), "x".repeat(MAX_POLL_OPTION_LEN + 1)]),
+            Err(McError::InvalidRequest(_))
+        ));
+        assert!(matches!(
+            create(
+                &"q".repeat(MAX_POLL_QUESTION_LEN + 1),
+                vec!["a".into(), "b".into()]
+            ),
+            Err(McError::InvalidRequest(_))
+        ));
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn test_poll_limit() {
+        let mut state = InteractionState::new();
+        for _ in 0..MAX_POLLS {
+            create_poll(&mut state, &["a", "b"]);
+        }
+        let result = state.apply(
+            "host-part",
+            "host-user",
+            InteractionRequest::CreatePoll {
+                question: "One more".to_string(),
+                options: vec!["a".to_string(), "b".to_string()],
+            },
+        );
+        assert!(matches!(result, Err(McError::Conflict(_))));
+    }
+
+    #[test]
+    fn test_vote_invalid_option_and_unknown_poll() {
+        let mut state = InteractionState::new();
+        let poll_id = create_poll(&mut state, &["a", "b"]);
+
+        let result = state.apply(
+            "part",
+            "user-1",
+            InteractionRequest::Vote {
+                poll_id,
+                option_index: 2,
+            },
+        );
+        assert!(matches!(result, Err(McError::InvalidRequest(_))));
+
+        let result = state.apply(
+            "part",
+            "user-1",
+            InteractionRequest::Vote {
+                poll_id: "poll-999".to_string(),
+                option_index: 0,
+            },
+        );
+        assert!(matches!(result, Err(McError::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_question_upvote_once_per_user() {
+        let mut state = InteractionState::new();
+        let question_id = ask(&mut state, "  When is the launch?  ");
+
+        let upvote = |state: &mut InteractionState, user: &str| {
+            state
+                .apply(
+                    "part",
+                    user,
+                    InteractionRequest::UpvoteQuestion {
+                        question_id: question_id.clone(),
+                    },
+                )
+                .unwrap()
+        };
+
+        assert!(matches!(
+            upvote(&mut state, "user-2"),
+            Some(InteractionEvent::QuestionUpdated { upvotes: 1, .. })
+        ));
+        assert_eq!(upvote(&mut state, "user-2"), None);
+        assert_eq!(
+            upvote(&mut state, "user-3"),
+            Some(InteractionEvent::QuestionUpdated {
+                question_id: question_id.clone(),
+                text: "When is the launch?".to_string(),
+                asked_by: "part-1".to_string(),
+                upvotes: 2,
+                answered: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_mark_answered_is_idempotent() {
+        let mut state = InteractionState::new();
+        let question_id = ask(&mut state, "Recording available?");
+
+        let mark = |state: &mut InteractionState| {
+            state
+                .apply(
+                    "host-part",
+                    "host-user",
+                    InteractionRequest::MarkAnswered {
+                        question_id: question_id.clone(),
+                    },
+                )
+                .unwrap()
+        };
+
+        assert!(matches!(
+            mark(&mut state),
+            Some(InteractionEvent::QuestionUpdated { answered: true, .. })
+        ));
+        assert_eq!(mark(&mut state), None);
+    }
+
+    #[test]
+    fn test_question_validation() {
+        let mut state = InteractionState::new();
+        for text in [String::new(), "x".repeat(MAX_QUESTION_LEN + 1)] {
+            let result = state.apply("part", "user", InteractionRequest::AskQuestion { text });
+            assert!(matches!(result, Err(McError::InvalidRequest(_))));
+        }
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_events_and_json_round_trip() {
+        let mut state = InteractionState::new();
+        let open_poll = create_poll(&mut state, &["a", "b"]);
+        let closed_poll = create_poll(&mut state, &["c", "d"]);
+        vote(&mut state, "user-1", &closed_poll, 1);
+        state
+            .apply(
+                "host-part",
+                "host-user",
+                InteractionRequest::PublishResults {
+                    poll_id: closed_poll.clone(),
+                },
+            )
+            .unwrap();
+        let question_id = ask(&mut state, "Question?");
+
+        let restored = InteractionState::from_json(&state.to_json().unwrap()).unwrap();
+        assert_eq!(restored, state);
+
+        let events = restored.snapshot_events();
+        assert_eq!(events.len(), 4);
+        assert!(matches!(
+            &events[0],
+            InteractionEvent::PollCreated { poll_id, closed: false, .. } if *poll_id == open_poll
+        ));
+        assert!(matches!(
+            &events[1],
+            InteractionEvent::PollCreated { poll_id, closed: true, .. } if *poll_id == closed_poll
+        ));
+        assert!(matches!(
+            &events[2],
+            InteractionEvent::PollResults { total_votes: 1, .. }
+        ));
+        assert!(matches!(
+            &events[3],
+            InteractionEvent::QuestionUpdated { question_id: id, .. } if *id == question_id
+        ));
+    }
+
+    #[test]
+    fn test_checksum_ignores_vote_order() {
+        let mut first = InteractionState::new();
+        let mut second = InteractionState::new();
+        let poll_a = create_poll(&mut first, &["a", "b"]);
+        let poll_b = create_poll(&mut second, &["a", "b"]);
+        assert_eq!(poll_a, poll_b);
+        for (user, option) in [("user-1", 0), ("user-2", 1), ("user-3", 0)] {
+            vote(&mut first, user, &poll_a, option);
+        }
+        for (user, option) in [("user-3", 0), ("user-1", 0), ("user-2", 1)] {
+            vote(&mut second, user, &poll_b, option);
+        }
+
+        assert_eq!(first.checksum().unwrap(), second.checksum().unwrap());
+        vote(&mut second, "user-4", &poll_b, 1);
+        assert_ne!(first.checksum().unwrap(), second.checksum().unwrap());
+    }
+
+    #[test]
+    fn test_ids_not_reused_after_restore() {
+        let mut state = InteractionState::new();
+        let first = ask(&mut state, "First?");
+        let mut restored = InteractionState::from_json(&state.to_json().unwrap()).unwrap();
+        let second = ask(&mut restored, "Second?");
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_from_json_rejects_malformed() {
+        assert!(matches!(
+            InteractionState::from_json("{not json"),
+            Err(McError::Internal(_))
+        ));
+    }
+}
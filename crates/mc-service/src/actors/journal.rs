@@ -0,0 +1,254 @@
+//! Meeting event journal entries and their replay.
+//!
+//! The `MeetingActor` appends a [`JournalEvent`] for every join (with the
+//! participant's role) and every leave to the meeting's Redis stream
+//! ([`crate::redis::journal`]). A replacement MC replays the journal on
+//! start with [`replay`] to rebuild the attendance log: sessions that ended
+//! on the old MC are reported as they ended, and sessions it left open are
+//! handed back so the actor can close them.
+//!
+//! Entries that do not parse (written by a newer MC, say) are skipped and
+//! counted rather than failing the replay.
+
+use super::messages::{AttendanceEntry, LeaveReason};
+use crate::errors::McError;
+use crate::redis::JournalRecord;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A membership change recorded in the journal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JournalEvent {
+    /// A participant joined.
+    Joined {
+        /// Participant ID.
+        participant_id: String,
+        /// User ID (from JWT).
+        user_id: String,
+        /// Display name at join time.
+        display_name: String,
+        /// Whether the participant joined with host privileges.
+        is_host: bool,
+        /// Join time (Unix milliseconds).
+        at_ms: i64,
+    },
+    /// A participant left.
+    Left {
+        /// Participant ID.
+        participant_id: String,
+        /// Leave reason ([`LeaveReason::as_str`]).
+        reason: String,
+        /// Leave time (Unix milliseconds).
+        at_ms: i64,
+    },
+}
+
+impl JournalEvent {
+    /// Parse an event from its JSON form.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Internal` if `json` is not a journal event.
+    pub fn from_json(json: &str) -> Result<Self, McError> {
+        serde_json::from_str(json)
+            .map_err(|e| McError::Internal(format!("invalid journal event: {e}")))
+    }
+
+    /// Serialize the event to JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Internal` if serialization fails.
+    pub fn to_json(&self) -> Result<String, McError> {
+        serde_json::to_string(self)
+            .map_err(|e| McError::Internal(format!("serialization failed: {e}")))
+    }
+}
+
+/// A session the journal shows as joined but never left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenSession {
+    /// Participant ID.
+    pub participant_id: String,
+    /// User ID (from JWT).
+    pub user_id: String,
+    /// Display name at join time.
+    pub display_name: String,
+    /// Join time (Unix milliseconds).
+    pub joined_at_ms: i64,
+}
+
+impl OpenSession {
+    /// Close the session as an attendance entry.
+    #[must_use]
+    pub fn close(self, reason: LeaveReason, left_at_ms: i64) -> AttendanceEntry {
+        AttendanceEntry {
+            participant_id: self.participant_id,
+            user_id: self.user_id,
+            display_name: self.display_name,
+            joined_at_ms: self.joined_at_ms,
+            left_at_ms,
+            reason,
+        }
+    }
+}
+
+/// What a journal replay rebuilt.
+#[derive(Debug, Default)]
+pub struct JournalReplay {
+    /// Sessions that ended, in leave order.
+    pub ended: Vec<AttendanceEntry>,
+    /// Sessions still open at the end of the journal, in join order.
+    pub open: Vec<OpenSession>,
+    /// Entries that could not be used.
+    pub skipped: usize,
+}
+
+/// Replay journal records (oldest first) into attendance.
+///
+/// A leave whose join was trimmed from the stream cannot be attributed to
+/// a session and counts as skipped, as does a second join for a session
+/// that is already open.
+#[must_use]
+pub fn replay(records: &[JournalRecord]) -> JournalReplay {
+    let mut result = JournalReplay::default();
+    // Join order, with ended sessions taken out
+    let mut sessions: Vec<Option<OpenSession>> = Vec::new();
+    let mut by_participant: HashMap<String, usize> = HashMap::new();
+
+    for record in records {
+        match JournalEvent::from_json(&record.event) {
+            Ok(JournalEvent::Joined {
+                participant_id,
+                user_id,
+                display_name,
+                is_host: _,
+                at_ms,
+            }) => {
+                // Re-journaled by the divergence check for a session the
+                // stream still has open: keep the original join time
+                if by_participant.contains_key(&participant_id) {
+                    result.skipped += 1;
+                    continue;
+                }
+                by_participant.insert(participant_id.clone(), sessions.len());
+                sessions.push(Some(OpenSession {
+                    participant_id,
+                    user_id,
+                    display_name,
+                    joined_at_ms: at_ms,
+                }));
+            }
+            Ok(JournalEvent::Left {
+                participant_id,
+                reason,
+                at_ms,
+            }) => {
+                let session = by_participant
+                    .remove(&participant_id)
+                    .and_then(|index| sessions.get_mut(index))
+                    .and_then(Option::take);
+                match (session, LeaveReason::from_wire(&reason)) {
+                    (Some(session), Some(reason)) => {
+                        result.ended.push(session.close(reason, at_ms));
+                    }
+                    _ => result.skipped += 1,
+                }
+            }
+            Err(_) => result.skipped += 1,
+        }
+    }
+
+    result.open = sessions.into_iter().flatten().collect();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(event: &JournalEvent) -> JournalRecord {
+        JournalRecord {
+            id: "0-0".to_string(),
+            generation: 1,
+            event: event.to_json().unwrap(),
+        }
+    }
+
+    fn joined(participant_id: &str, at_ms: i64) -> JournalEvent {
+        JournalEvent::Joined {
+            participant_id: participant_id.to_string(),
+            user_id: format!("user-{participant_id}"),
+            display_name: "Participant".to_string(),
+            is_host: false,
+            at_ms,
+        }
+    }
+
+    fn left(participant_id: &str, reason: LeaveReason, at_ms: i64) -> JournalEvent {
+        JournalEvent::Left {
+            participant_id: participant_id.to_string(),
+            reason: reason.as_str().to_string(),
+            at_ms,
+        }
+    }
+
+    #[test]
+    fn test_event_json_is_tagged() {
+        let json = joined("part-1", 1000).to_json().unwrap();
+        assert!(json.contains(r#""type":"joined""#));
+        assert_eq!(
+            JournalEvent::from_json(&json).unwrap(),
+            joined("part-1", 1000)
+        );
+    }
+
+    #[test]
+    fn test_replay_splits_ended_and_open_sessions() {
+        let records = [
+            record(&joined("part-1", 1000)),
+            record(&joined("part-2", 2000)),
+            record(&left("part-1", LeaveReason::Voluntary, 3000)),
+            record(&joined("part-3", 4000)),
+        ];
+
+        let rebuilt = replay(&records);
+
+        assert_eq!(rebuilt.ended.len(), 1);
+        assert_eq!(rebuilt.ended[0].participant_id, "part-1");
+        assert_eq!(rebuilt.ended[0].user_id, "user-part-1");
+        assert_eq!(rebuilt.ended[0].joined_at_ms, 1000);
+        assert_eq!(rebuilt.ended[0].left_at_ms, 3000);
+        assert_eq!(rebuilt.ended[0].reason, LeaveReason::Voluntary);
+        let open: Vec<&str> = rebuilt
+            .open
+            .iter()
+            .map(|s| s.participant_id.as_str())
+            .collect();
+        assert_eq!(open, vec!["part-2", "part-3"]);
+        assert_eq!(rebuilt.skipped, 0);
+    }
+
+    #[test]
+    fn test_replay_skips_orphaned_and_unparseable_entries() {
+        let records = [
+            // Join trimmed from the stream
+            record(&left("part-0", LeaveReason::Timeout, 500)),
+            JournalRecord {
+                id: "0-1".to_string(),
+                generation: 1,
+                event: r#"{"type":"renamed"}"#.to_string(),
+            },
+            record(&joined("part-1", 1000)),
+            record(&joined("part-1", 5000)),
+        ];
+
+        let rebuilt = replay(&records);
+
+        assert!(rebuilt.ended.is_empty());
+        assert_eq!(rebuilt.open.len(), 1);
+        assert_eq!(rebuilt.open[0].joined_at_ms, 1000);
+        assert_eq!(rebuilt.skipped, 3);
+    }
+}
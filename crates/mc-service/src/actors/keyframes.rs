@@ -0,0 +1,201 @@
+//! Keyframe requests (PLI/FIR equivalent) routed by the `MeetingActor`.
+//!
+//! A subscriber that cannot decode a video stream (it joined after the last
+//! keyframe, or lost packets) asks for a fresh keyframe with
+//! `RequestKeyframe`; an MH that sees loss on a stream asks through the
+//! `RequestKeyframe` RPC. The actor forwards the request to the publisher's
+//! signaling connection as `KeyframeRequested`.
+//!
+//! # Deduplication
+//!
+//! When many subscribers join at once, or loss hits everyone behind the
+//! same MH, the publisher would otherwise get a storm of requests and send a
+//! keyframe for each. The actor forwards at most one request per publisher
+//! stream per [`KEYFRAME_REQUEST_COOLDOWN`]; the others are dropped, since
+//! the keyframe already on its way serves every subscriber. Cooldown state
+//! is in-memory only and cleared when the publisher leaves.
+
+use proto_gen::dark_tower::signaling::v1::StreamType;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Minimum interval between keyframe requests forwarded for one stream.
+pub const KEYFRAME_REQUEST_COOLDOWN: Duration = Duration::from_secs(1);
+
+/// A publisher video stream a keyframe can be requested for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VideoStream {
+    /// Camera video.
+    Camera,
+    /// Screen share video.
+    Screen,
+}
+
+impl VideoStream {
+    /// Video stream for a signaling `StreamType` (`None` for audio).
+    #[must_use]
+    pub fn from_stream_type(stream_type: StreamType) -> Option<Self> {
+        match stream_type {
+            StreamType::VideoCamera => Some(Self::Camera),
+            StreamType::VideoScreen => Some(Self::Screen),
+            StreamType::Audio => None,
+        }
+    }
+
+    /// Signaling `StreamType` of this stream.
+    #[must_use]
+    pub fn stream_type(self) -> StreamType {
+        match self {
+            Self::Camera => StreamType::VideoCamera,
+            Self::Screen => StreamType::VideoScreen,
+        }
+    }
+}
+
+/// A request for a keyframe on one publisher stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyframeRequest {
+    /// Participant publishing the stream.
+    pub publisher_id: String,
+    /// Which of the publisher's video streams.
+    pub stream: VideoStream,
+}
+
+/// Who asked for a keyframe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyframeRequester {
+    /// A subscribing participant, over signaling.
+    Participant(String),
+    /// The meeting's MH, over gRPC.
+    MediaHandler,
+}
+
+impl KeyframeRequester {
+    /// Bounded label for the `source` of the keyframe request metric.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Participant(_) => "client",
+            Self::MediaHandler => "media_handler",
+        }
+    }
+}
+
+/// Per-stream cooldown for forwarded keyframe requests in one meeting.
+#[derive(Debug)]
+pub struct KeyframeCooldowns {
+    cooldown: Duration,
+    /// Time of the last forwarded request by publisher ID and stream.
+    last_forwarded: HashMap<(String, VideoStream), Instant>,
+}
+
+impl KeyframeCooldowns {
+    /// Create cooldown state forwarding at most one request per stream per
+    /// `cooldown`.
+    #[must_use]
+    pub fn new(cooldown: Duration) -> Self {
+        Self {
+            cooldown,
+            last_forwarded: HashMap::new(),
+        }
+    }
+
+    /// Record a request for `stream` of `publisher_id` at `now`, returning
+    /// whether it should be forwarded.
+    pub fn admit(&mut self, publisher_id: &str, stream: VideoStream, now: Instant) -> bool {
+        let key = (publisher_id.to_string(), stream);
+        if let Some(last) = self.last_forwarded.get(&key) {
+            if now.duration_since(*last) < self.cooldown {
+                return false;
+            }
+        }
+        self.last_forwarded.insert(key, now);
+        true
+    }
+
+    /// Forget a publisher's streams (participant left).
+    pub fn remove_participant(&mut self, publisher_id: &str) {
+        self.last_forwarded.retain(|(id, _), _| id != publisher_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cooldown_per_stream() {
+        let mut cooldowns = KeyframeCooldowns::new(Duration::from_secs(1));
+        let start = Instant::now();
+
+        assert!(cooldowns.admit("part-1", VideoStream::Camera, start));
+        assert!(!cooldowns.admit(
+            "part-1",
+            VideoStream::Camera,
+            start + Duration::from_millis(999)
+        ));
+        // Other streams and publishers have their own cooldown
+        assert!(cooldowns.admit("part-1", VideoStream::Screen, start));
+        assert!(cooldowns.admit("part-2", VideoStream::Camera, start));
+        // Cooldown elapsed
+        assert!(cooldowns.admit(
+            "part-1",
+            VideoStream::Camera,
+            start + Duration::from_secs(1)
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_suppressed_requests_do_not_extend_cooldown() {
+        let mut cooldowns = KeyframeCooldowns::new(Duration::from_secs(1));
+        let start = Instant::now();
+
+        assert!(cooldowns.admit("part-1", VideoStream::Camera, start));
+        for ms in [200, 400, 600, 800] {
+            assert!(!cooldowns.admit(
+                "part-1",
+                VideoStream::Camera,
+                start + Duration::from_millis(ms)
+            ));
+        }
+        assert!(cooldowns.admit(
+            "part-1",
+            VideoStream::Camera,
+            start + Duration::from_secs(1)
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_remove_participant() {
+        let mut cooldowns = KeyframeCooldowns::new(Duration::from_secs(1));
+        let start = Instant::now();
+        cooldowns.admit("part-1", VideoStream::Camera, start);
+        cooldowns.admit("part-1", VideoStream::Screen, start);
+        cooldowns.admit("part-2", VideoStream::Camera, start);
+
+        cooldowns.remove_participant("part-1");
+        assert_eq!(cooldowns.last_forwarded.len(), 1);
+        assert!(cooldowns.admit("part-1", VideoStream::Camera, start));
+    }
+
+    #[test]
+    fn test_stream_type_mapping() {
+        assert_eq!(VideoStream::from_stream_type(StreamType::Audio), None);
+        for stream in [VideoStream::Camera, VideoStream::Screen] {
+            assert_eq!(
+                VideoStream::from_stream_type(stream.stream_type()),
+                Some(stream)
+            );
+        }
+    }
+
+    #[test]
+    fn test_requester_labels() {
+        assert_eq!(
+            KeyframeRequester::Participant("part-1".to_string()).as_str(),
+            "client"
+        );
+        assert_eq!(KeyframeRequester::MediaHandler.as_str(), "media_handler");
+    }
+}
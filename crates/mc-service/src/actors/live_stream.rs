@@ -0,0 +1,348 @@
+//! Live streaming (egress) state owned by the `MeetingActor`.
+//!
+//! A host can broadcast the meeting webinar-style: the meeting's MH composes
+//! selected video tiles and the mixed audio into an RTMP push or HLS output.
+//! The MC drives the MH over gRPC (`StartEgress` / `StopEgress`), relays
+//! `LiveStreamStatus` to participants, and accumulates the time spent live
+//! for the meeting report.
+//!
+//! # Lifecycle
+//!
+//! ```text
+//! Idle ──start──> Starting ──MH ok──> Live ──stop──> Idle
+//!                    └──MH error──> Idle
+//! ```
+//!
+//! One stream runs per meeting. Time is measured by the MC from the moment
+//! the MH confirms the start until the host stops it or the meeting ends.
+
+use crate::errors::McError;
+
+use std::fmt;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Maximum video tiles in a composed stream (matches MH's 3x3 grid).
+pub const MAX_LIVE_STREAM_TILES: usize = 9;
+
+/// Maximum RTMP URL length (bytes).
+pub const MAX_RTMP_URL_LEN: usize = 2048;
+
+/// Broadcast output requested by the host.
+#[derive(Clone, PartialEq, Eq)]
+pub enum LiveStreamOutput {
+    /// RTMP push; the URL embeds the destination's stream key.
+    Rtmp { url: String },
+    /// HLS segments served by the MH (0 = MH default segment duration).
+    Hls { segment_duration_seconds: u32 },
+}
+
+/// Redacts the RTMP URL, which carries the stream key.
+impl fmt::Debug for LiveStreamOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LiveStreamOutput::Rtmp { .. } => {
+                f.debug_struct("Rtmp").field("url", &"[REDACTED]").finish()
+            }
+            LiveStreamOutput::Hls {
+                segment_duration_seconds,
+            } => f
+                .debug_struct("Hls")
+                .field("segment_duration_seconds", segment_duration_seconds)
+                .finish(),
+        }
+    }
+}
+
+/// A live streaming request from a participant (host only).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LiveStreamRequest {
+    /// Start broadcasting the given tiles to `output`.
+    Start {
+        tile_participant_ids: Vec<String>,
+        output: LiveStreamOutput,
+    },
+    /// Stop the active broadcast.
+    Stop,
+}
+
+/// Status relayed to participants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LiveStreamStatus {
+    /// `StartEgress` is in flight.
+    Starting,
+    /// The MH is producing output (`playback_url` is empty for RTMP).
+    Live { playback_url: String },
+    /// The host stopped the stream.
+    Stopped,
+    /// The MH could not start the stream.
+    Failed { error_message: String },
+}
+
+/// An egress running on an MH, needed to stop it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveEgress {
+    /// gRPC endpoint of the MH running the egress.
+    pub mh_grpc_endpoint: String,
+    /// MH-assigned egress ID.
+    pub egress_id: String,
+}
+
+#[derive(Debug)]
+enum Phase {
+    Idle,
+    Starting,
+    Live {
+        egress: ActiveEgress,
+        playback_url: String,
+        live_since: Instant,
+    },
+}
+
+/// Live stream lifecycle and accumulated live time for one meeting.
+#[derive(Debug)]
+pub struct LiveStream {
+    phase: Phase,
+    /// Time spent live by streams that have already stopped.
+    live_total: Duration,
+}
+
+impl Default for LiveStream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LiveStream {
+    /// Create idle state.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            phase: Phase::Idle,
+            live_total: Duration::ZERO,
+        }
+    }
+
+    /// Move to `Starting` before calling `StartEgress`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Conflict` if a stream is already starting or live.
+    pub fn begin_start(&mut self) -> Result<(), McError> {
+        if !matches!(self.phase, Phase::Idle) {
+            return Err(McError::Conflict(
+                "A live stream is already active".to_string(),
+            ));
+        }
+        self.phase = Phase::Starting;
+        Ok(())
+    }
+
+    /// Record a successful `StartEgress`.
+    ///
+    /// Returns `false` if no start was pending (the stream was abandoned and
+    /// the caller should stop the egress).
+    pub fn started(&mut self, egress: ActiveEgress, playback_url: String, now: Instant) -> bool {
+        if !matches!(self.phase, Phase::Starting) {
+            return false;
+        }
+        self.phase = Phase::Live {
+            egress,
+            playback_url,
+            live_since: now,
+        };
+        true
+    }
+
+    /// Record a failed `StartEgress`.
+    pub fn start_failed(&mut self) {
+        if matches!(self.phase, Phase::Starting) {
+            self.phase = Phase::Idle;
+        }
+    }
+
+    /// Stop the live stream and return the egress to stop on the MH.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Conflict` while a start is pending and
+    /// `McError::InvalidRequest` if nothing is live.
+    pub fn stop(&mut self, now: Instant) -> Result<ActiveEgress, McError> {
+        match self.phase {
+            Phase::Starting => Err(McError::Conflict(
+                "The live stream is still starting".to_string(),
+            )),
+            Phase::Idle => Err(McError::InvalidRequest(
+                "No live stream is active".to_string(),
+            )),
+            Phase::Live { .. } => self
+                .finish(now)
+                .ok_or_else(|| McError::Internal("live stream state".to_string())),
+        }
+    }
+
+    /// End any stream when the meeting ends. Returns the egress to stop, if
+    /// one was live; a pending start is abandoned.
+    pub fn finish(&mut self, now: Instant) -> Option<ActiveEgress> {
+        match std::mem::replace(&mut self.phase, Phase::Idle) {
+            Phase::Live {
+                egress, live_since, ..
+            } => {
+                self.live_total += now.saturating_duration_since(live_since);
+                Some(egress)
+            }
+            Phase::Idle | Phase::Starting => None,
+        }
+    }
+
+    /// Status for a participant who joins mid-stream (`None` when idle).
+    #[must_use]
+    pub fn current_status(&self) -> Option<LiveStreamStatus> {
+        match &self.phase {
+            Phase::Idle => None,
+            Phase::Starting => Some(LiveStreamStatus::Starting),
+            Phase::Live { playback_url, .. } => Some(LiveStreamStatus::Live {
+                playback_url: playback_url.clone(),
+            }),
+        }
+    }
+
+    /// Whole seconds spent live, including a stream that is still running.
+    #[must_use]
+    pub fn live_seconds(&self, now: Instant) -> u64 {
+        let running = match &self.phase {
+            Phase::Live { live_since, .. } => now.saturating_duration_since(*live_since),
+            Phase::Idle | Phase::Starting => Duration::ZERO,
+        };
+        (self.live_total + running).as_secs()
+    }
+}
+
+/// Validate a start request against the meeting's current participants.
+///
+/// # Errors
+///
+/// Returns `McError::InvalidRequest` for too many, duplicate or unknown
+/// tiles, or a malformed output.
+pub fn validate_start(
+    tile_participant_ids: &[String],
+    output: &LiveStreamOutput,
+    is_participant: impl Fn(&str) -> bool,
+) -> Result<(), McError> {
+    if tile_participant_ids.len() > MAX_LIVE_STREAM_TILES {
+        return Err(McError::InvalidRequest(format!(
+            "At most {MAX_LIVE_STREAM_TILES} video tiles can be streamed"
+        )));
+    }
+    for (i, id) in tile_participant_ids.iter().enumerate() {
+        if !is_participant(id) {
+            return Err(McError::InvalidRequest(
+                "Tile participant is not in the meeting".to_string(),
+            ));
+        }
+        if tile_participant_ids
+            .iter()
+            .skip(i + 1)
+            .any(|other| other == id)
+        {
+            return Err(McError::InvalidRequest(
+                "Duplicate tile participant".to_string(),
+            ));
+        }
+    }
+
+    if let LiveStreamOutput::Rtmp { url } = output {
+        let valid_scheme = url.starts_with("rtmp://") || url.starts_with("rtmps://");
+        if !valid_scheme || url.len() > MAX_RTMP_URL_LEN {
+            return Err(McError::InvalidRequest(
+                "Live stream URL must be an rtmp:// or rtmps:// URL".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn egress() -> ActiveEgress {
+        ActiveEgress {
+            mh_grpc_endpoint: "http://mh-1:50053".to_string(),
+            egress_id: "egress-1".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_lifecycle_accumulates_live_time() {
+        let mut stream = LiveStream::new();
+        let t0 = Instant::now();
+
+        stream.begin_start().unwrap();
+        assert!(matches!(stream.begin_start(), Err(McError::Conflict(_))));
+        assert!(matches!(stream.stop(t0), Err(McError::Conflict(_))));
+
+        assert!(stream.started(egress(), String::new(), t0));
+        assert_eq!(stream.live_seconds(t0 + Duration::from_secs(30)), 30);
+
+        let stopped = stream.stop(t0 + Duration::from_secs(90)).unwrap();
+        assert_eq!(stopped, egress());
+        assert_eq!(stream.current_status(), None);
+
+        // A second stream adds to the total
+        let t1 = t0 + Duration::from_secs(200);
+        stream.begin_start().unwrap();
+        assert!(stream.started(egress(), String::new(), t1));
+        assert!(stream.finish(t1 + Duration::from_secs(10)).is_some());
+        assert_eq!(stream.live_seconds(t1 + Duration::from_secs(500)), 100);
+    }
+
+    #[test]
+    fn test_start_failed_and_abandoned_start() {
+        let mut stream = LiveStream::new();
+        let now = Instant::now();
+
+        stream.begin_start().unwrap();
+        stream.start_failed();
+        assert!(matches!(stream.stop(now), Err(McError::InvalidRequest(_))));
+
+        // Meeting ended while StartEgress was in flight
+        stream.begin_start().unwrap();
+        assert!(stream.finish(now).is_none());
+        assert!(!stream.started(egress(), String::new(), now));
+        assert_eq!(stream.live_seconds(now), 0);
+    }
+
+    #[test]
+    fn test_validate_start() {
+        let present = |id: &str| id.starts_with("part-");
+        let hls = LiveStreamOutput::Hls {
+            segment_duration_seconds: 0,
+        };
+
+        assert!(validate_start(&["part-1".to_string()], &hls, present).is_ok());
+        assert!(validate_start(&["ghost".to_string()], &hls, present).is_err());
+        assert!(
+            validate_start(&["part-1".to_string(), "part-1".to_string()], &hls, present).is_err()
+        );
+        let too_many: Vec<String> = (0..=MAX_LIVE_STREAM_TILES)
+            .map(|i| format!("part-{i}"))
+            .collect();
+        assert!(validate_start(&too_many, &hls, present).is_err());
+
+        let rtmp = |url: &str| LiveStreamOutput::Rtmp {
+            url: url.to_string(),
+        };
+        assert!(validate_start(&[], &rtmp("rtmps://live.example.com/app/key"), present).is_ok());
+        assert!(validate_start(&[], &rtmp("https://live.example.com/app"), present).is_err());
+    }
+
+    #[test]
+    fn test_rtmp_url_redacted_in_debug() {
+        let output = LiveStreamOutput::Rtmp {
+            url: "rtmp://live.example.com/app/secret-key".to_string(),
+        };
+        assert!(!format!("{output:?}").contains("secret-key"));
+    }
+}
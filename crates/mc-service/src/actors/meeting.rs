@@ -10,35 +10,235 @@
 //!
 //! When a connection drops:
 //! 1. Participant marked as "disconnected" (still visible to others)
-//! 2. 30-second grace period for reconnection
+//! 2. Grace period for reconnection (`MC_DISCONNECT_GRACE_PERIOD_SECONDS`,
+//!    default 30s)
 //! 3. If not reconnected: participant removed, slots released
+//!
+//! A client resumes by sending the correlation ID and binding token from its
+//! last `JoinResponse` in a new `JoinRequest`. It gets the same participant
+//! ID, roster entry, mute state, and subscriptions, plus a fresh binding.
+//! A binding that does not verify, or arrives after the grace period, is not
+//! an error: the client simply joins as a new participant.
+//!
+//! # Attendance
+//!
+//! Each participant session (join to leave) is logged with its leave reason.
+//! Reconnects within the grace period continue the same session. When the
+//! meeting ends, open sessions are closed as `meeting_ended` and the log is
+//! sent to the attendance sink (if configured) for reporting to GC. A meeting
+//! that ended (rather than stopping with the MC) also carries its peak
+//! participant count and refused joins for GC's post-meeting report.
+//!
+//! # Polls and Q&A
+//!
+//! The actor owns the meeting's [`InteractionState`], restores it from the
+//! interaction store on start and writes it back after every change. Host-only
+//! requests are checked against the participant's `is_host` flag, and each
+//! participant's request rate is bounded. Late joiners receive a snapshot.
+//!
+//! # Event Journal
+//!
+//! With a journal store configured, every join (with the participant's
+//! role) and leave is appended to the meeting's Redis stream at the current
+//! fencing generation. On start the actor replays it ([`journal::replay`])
+//! so a replacement MC reports the sessions that ended on the old one.
+//!
+//! # State Divergence Checks
+//!
+//! With a check interval configured, the actor periodically compares
+//! checksums of its polls and Q&A state and its roster with the copies in
+//! Redis (the interaction snapshot and the journal's open sessions). A
+//! mismatch is counted in `mc_state_divergence_total` and Redis is rewritten
+//! from memory: the snapshot is stored again, and the journal gets a `left`
+//! for each ghost participant and a `joined` for each missing one.
+//!
+//! # Data Channels
+//!
+//! Opaque payloads are routed to channel subscribers via [`DataChannels`]
+//! with a per-channel sequence number. Subscriptions are dropped when a
+//! participant leaves and are not persisted.
+//!
+//! # Keyframe Requests
+//!
+//! Subscribers (over signaling) and the MH (over gRPC) ask for keyframes on
+//! a publisher's video stream. The actor forwards them to the publisher as
+//! `KeyframeRequested`, at most once per stream per cooldown
+//! ([`KeyframeCooldowns`]), so a burst of late joiners costs one keyframe.
+//!
+//! # Live Streaming
+//!
+//! Hosts start and stop a broadcast of the meeting ([`LiveStream`]). The
+//! `StartEgress`/`StopEgress` RPCs to the meeting's MH run in spawned tasks so
+//! a slow MH never stalls the mailbox; the start outcome comes back as
+//! `MeetingMessage::LiveStreamStarted`. Time spent live is included in the
+//! attendance report, and a running stream is stopped when the meeting ends.
+//!
+//! # Recording Consent
+//!
+//! Hosts start and stop recording; each participant gets a recording notice
+//! with their own consent deadline ([`RecordingState`]). Under the org's
+//! `RequireAck` policy, participants who decline are removed at once and
+//! those who have not answered are removed on the periodic grace check.
+//!
+//! # End-to-End Encrypted Meetings
+//!
+//! The MH cannot decode E2E media, so recording and live streaming are
+//! rejected with `E2E_UNSUPPORTED` when the GC assignment marks the meeting
+//! E2E. Video effects are then the client's job (`client_side_effects`).
 
 use crate::errors::McError;
+use crate::grpc::MhEgressClient;
+use crate::ids::MeetingId;
+use crate::observability::metrics as prom;
+use crate::redis::{InteractionStore, JournalRecord, JournalStore, MhAssignmentStore};
+use crate::webtransport::handler::{
+    encode_data_channel_message, encode_error_message, encode_interaction_event,
+    encode_keyframe_requested, encode_live_stream_status, encode_recording_notice,
+};
+
+use super::data_channels::{
+    DataChannelRequest, DataChannels, DATA_CHANNEL_RATE_MAX_ACTIONS, DATA_CHANNEL_RATE_WINDOW,
+};
+
+use super::journal::{self, JournalEvent};
+use super::keyframes::{
+    KeyframeCooldowns, KeyframeRequest, KeyframeRequester, KEYFRAME_REQUEST_COOLDOWN,
+};
 
+use super::interactions::{
+    InteractionEvent, InteractionRequest, InteractionState, RATE_LIMIT_MAX_ACTIONS,
+    RATE_LIMIT_WINDOW,
+};
+use super::live_stream::{
+    validate_start, ActiveEgress, LiveStream, LiveStreamOutput, LiveStreamRequest, LiveStreamStatus,
+};
 use super::messages::{
-    JoinResult, LeaveReason, MeetingMessage, MeetingState, ParticipantInfo, ParticipantStateUpdate,
-    ParticipantStatus, ReconnectResult, SignalingPayload,
+    AttendanceEntry, JoinResult, LeaveReason, MeetingAttendance, MeetingEndSummary, MeetingMessage,
+    MeetingState, ParticipantInfo, ParticipantStateUpdate, ParticipantStatus, ReconnectResult,
+    SessionResume, SignalingPayload,
 };
 use super::metrics::{ActorMetrics, ActorType, ControllerMetrics, MailboxMonitor};
 use super::participant::{ParticipantActor, ParticipantActorHandle};
+use super::rate_limit::RateLimiter;
+use super::recording::{
+    ConsentOutcome, RecordingConsentPolicy, RecordingLayout, RecordingNotice, RecordingRequest,
+    RecordingState,
+};
 use super::session::{SessionBindingManager, StoredBinding};
+use super::state_check::{self, CheckedState};
+use super::usage::{MeetingUsage, MeetingUsageRegistry};
 
 use common::secret::SecretBox;
-use std::collections::HashMap;
+use common::time::{system_clock, SharedClock};
+use prost::Message;
+use proto_gen::dark_tower::internal::v1 as internal;
+use proto_gen::dark_tower::signaling::v1::{CloseReason, ServerMessage};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
-use tokio::time::Instant;
+use tokio::time::{Instant, Interval};
 use tokio_util::sync::CancellationToken;
-use tracing::{debug, error, info, instrument, warn};
+use tracing::{debug, debug_span, error, info, instrument, warn, Instrument};
 
 /// Default channel buffer size for the meeting mailbox.
 const MEETING_CHANNEL_BUFFER: usize = 500;
 
-/// Grace period for participant reconnection (ADR-0023: 30 seconds).
+/// Default grace period for participant reconnection (ADR-0023: 30 seconds).
 const DISCONNECT_GRACE_PERIOD: Duration = Duration::from_secs(30);
 
+/// Maximum attendance entries kept per meeting (matches GC's per-report limit).
+const MAX_ATTENDANCE_ENTRIES: usize = 10_000;
+
+/// Timeout for interaction store reads and writes, so a slow Redis cannot
+/// stall the meeting mailbox.
+const INTERACTION_STORE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Timeout for event journal reads and appends.
+const JOURNAL_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Wait for the next state check; never completes when checks are off.
+async fn next_state_check(timer: &mut Option<Interval>) {
+    match timer {
+        Some(timer) => {
+            timer.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Optional external dependencies and GC-provided policy for a `MeetingActor`.
+#[derive(Clone, Default)]
+pub struct MeetingServices {
+    /// Where the attendance log is sent when the meeting ends.
+    pub attendance_tx: Option<mpsc::Sender<MeetingAttendance>>,
+    /// Persistence for polls and Q&A state.
+    pub interaction_store: Option<Arc<dyn InteractionStore>>,
+    /// Event journal of joins and leaves (off if `None`).
+    pub journal: Option<Arc<dyn JournalStore>>,
+    /// MC->MH egress control for live streaming.
+    pub egress_client: Option<Arc<dyn MhEgressClient>>,
+    /// Lookup of the meeting's MH assignment (egress target).
+    pub mh_assignments: Option<Arc<dyn MhAssignmentStore>>,
+    /// Where the meeting registers its resource counters.
+    pub usage_registry: Option<Arc<MeetingUsageRegistry>>,
+    /// How long a disconnected participant is held for reconnection
+    /// (`MC_DISCONNECT_GRACE_PERIOD_SECONDS`; default 30s).
+    pub disconnect_grace_period: Option<Duration>,
+    /// How often state is checked against Redis
+    /// (`MC_STATE_CHECK_INTERVAL_SECONDS`; off if `None`).
+    pub state_check_interval: Option<Duration>,
+    /// Wall clock for meeting and attendance timestamps (default: system
+    /// clock).
+    pub clock: Option<SharedClock>,
+    /// Per-meeting settings (set by the controller from the GC assignment).
+    pub settings: MeetingSettings,
+    /// Journal entries a warm standby already read; only newer entries are
+    /// loaded from the store on start.
+    pub preloaded_journal: Option<Vec<JournalRecord>>,
+}
+
+/// Meeting settings from the GC assignment.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MeetingSettings {
+    /// Organization recording consent policy.
+    pub recording_policy: RecordingConsentPolicy,
+    /// Media is end-to-end encrypted, so features that need the MH to
+    /// decode video (recording, live streaming) are rejected.
+    pub e2e_enabled: bool,
+    /// What happens when a user already in the meeting joins again.
+    pub duplicate_join_policy: DuplicateJoinPolicy,
+    /// Recording layout passed to the MHs when the meeting is registered.
+    pub recording_layout: RecordingLayout,
+}
+
+/// What happens when a user already in the meeting joins again from
+/// another device or tab.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DuplicateJoinPolicy {
+    /// Both connections stay, as separate participants.
+    #[default]
+    Allow,
+    /// The earlier connection gets `SESSION_REPLACED` and is closed.
+    Takeover,
+    /// The new join is refused while the earlier one is connected.
+    Reject,
+}
+
+impl DuplicateJoinPolicy {
+    /// Build the policy from GC's assignment request. GCs that predate the
+    /// policy send `UNSPECIFIED`, which keeps the old behavior (`Allow`).
+    #[must_use]
+    pub fn from_proto(policy: i32) -> Self {
+        match internal::DuplicateJoinPolicy::try_from(policy) {
+            Ok(internal::DuplicateJoinPolicy::Takeover) => Self::Takeover,
+            Ok(internal::DuplicateJoinPolicy::Reject) => Self::Reject,
+            _ => Self::Allow,
+        }
+    }
+}
+
 /// Handle to a `MeetingActor`.
 #[derive(Clone, Debug)]
 pub struct MeetingActorHandle {
@@ -69,6 +269,30 @@ impl MeetingActorHandle {
         participant_id: String,
         is_host: bool,
         stream_tx: Option<tokio::sync::mpsc::Sender<bytes::Bytes>>,
+    ) -> Result<JoinResult, McError> {
+        self.connection_join_or_resume(
+            connection_id,
+            user_id,
+            participant_id,
+            is_host,
+            stream_tx,
+            None,
+        )
+        .await
+    }
+
+    /// Like [`Self::connection_join`], but first tries to resume the session
+    /// in `resume`. `participant_id` is only used if that fails and the
+    /// connection joins as a new participant; check
+    /// [`JoinResult::resumed`] for which happened.
+    pub async fn connection_join_or_resume(
+        &self,
+        connection_id: String,
+        user_id: String,
+        participant_id: String,
+        is_host: bool,
+        stream_tx: Option<tokio::sync::mpsc::Sender<bytes::Bytes>>,
+        resume: Option<SessionResume>,
     ) -> Result<JoinResult, McError> {
         let (tx, rx) = tokio::sync::oneshot::channel();
         self.sender
@@ -78,6 +302,7 @@ impl MeetingActorHandle {
                 participant_id,
                 is_host,
                 stream_tx,
+                resume,
                 respond_to: tx,
             })
             .await
@@ -106,15 +331,19 @@ impl MeetingActorHandle {
     pub async fn connection_reconnect(
         &self,
         connection_id: String,
+        user_id: String,
         correlation_id: String,
         binding_token: String,
+        stream_tx: Option<tokio::sync::mpsc::Sender<bytes::Bytes>>,
     ) -> Result<ReconnectResult, McError> {
         let (tx, rx) = tokio::sync::oneshot::channel();
         self.sender
             .send(MeetingMessage::ConnectionReconnect {
                 connection_id,
+                user_id,
                 correlation_id,
                 binding_token,
+                stream_tx,
                 respond_to: tx,
             })
             .await
@@ -154,6 +383,89 @@ impl MeetingActorHandle {
             .map_err(|e| McError::Internal(format!("channel send failed: {e}")))
     }
 
+    /// Forward a poll or Q&A request from a participant.
+    pub async fn interaction(
+        &self,
+        participant_id: String,
+        request: InteractionRequest,
+    ) -> Result<(), McError> {
+        self.sender
+            .send(MeetingMessage::Interaction {
+                participant_id,
+                request,
+            })
+            .await
+            .map_err(|e| McError::Internal(format!("channel send failed: {e}")))
+    }
+
+    /// Forward an application data channel request from a participant.
+    pub async fn data_channel(
+        &self,
+        participant_id: String,
+        request: DataChannelRequest,
+    ) -> Result<(), McError> {
+        self.sender
+            .send(MeetingMessage::DataChannel {
+                participant_id,
+                request,
+            })
+            .await
+            .map_err(|e| McError::Internal(format!("channel send failed: {e}")))
+    }
+
+    /// Forward a keyframe request from a participant or the MH.
+    pub async fn request_keyframe(
+        &self,
+        requester: KeyframeRequester,
+        request: KeyframeRequest,
+    ) -> Result<(), McError> {
+        self.sender
+            .send(MeetingMessage::RequestKeyframe { requester, request })
+            .await
+            .map_err(|e| McError::Internal(format!("channel send failed: {e}")))
+    }
+
+    /// Forward a live stream request from a participant.
+    pub async fn live_stream(
+        &self,
+        participant_id: String,
+        request: LiveStreamRequest,
+    ) -> Result<(), McError> {
+        self.sender
+            .send(MeetingMessage::LiveStream {
+                participant_id,
+                request,
+            })
+            .await
+            .map_err(|e| McError::Internal(format!("channel send failed: {e}")))
+    }
+
+    /// Forward a recording request or consent answer from a participant.
+    pub async fn recording(
+        &self,
+        participant_id: String,
+        request: RecordingRequest,
+    ) -> Result<(), McError> {
+        self.sender
+            .send(MeetingMessage::Recording {
+                participant_id,
+                request,
+            })
+            .await
+            .map_err(|e| McError::Internal(format!("channel send failed: {e}")))
+    }
+
+    /// Deliver the outcome of a `StartEgress` call to the actor.
+    async fn live_stream_started(
+        &self,
+        result: Result<(ActiveEgress, String), McError>,
+    ) -> Result<(), McError> {
+        self.sender
+            .send(MeetingMessage::LiveStreamStarted { result })
+            .await
+            .map_err(|e| McError::Internal(format!("channel send failed: {e}")))
+    }
+
     /// Get current meeting state.
     pub async fn get_state(&self) -> Result<MeetingState, McError> {
         let (tx, rx) = tokio::sync::oneshot::channel();
@@ -267,6 +579,8 @@ struct Participant {
     video_host_muted: bool,
     /// Whether this participant has host privileges.
     is_host: bool,
+    /// Join time (Unix milliseconds) for attendance.
+    joined_at_ms: i64,
 }
 
 impl Participant {
@@ -282,6 +596,27 @@ impl Participant {
             status: self.status,
         }
     }
+
+    fn to_attendance(&self, reason: LeaveReason, left_at_ms: i64) -> AttendanceEntry {
+        AttendanceEntry {
+            participant_id: self.participant_id.clone(),
+            user_id: self.user_id.clone(),
+            display_name: self.display_name.clone(),
+            joined_at_ms: self.joined_at_ms,
+            left_at_ms,
+            reason,
+        }
+    }
+
+    fn to_joined_event(&self) -> JournalEvent {
+        JournalEvent::Joined {
+            participant_id: self.participant_id.clone(),
+            user_id: self.user_id.clone(),
+            display_name: self.display_name.clone(),
+            is_host: self.is_host,
+            at_ms: self.joined_at_ms,
+        }
+    }
 }
 
 /// Managed connection state.
@@ -298,7 +633,7 @@ struct ManagedConnection {
 /// The `MeetingActor` implementation.
 pub struct MeetingActor {
     /// Meeting ID.
-    meeting_id: String,
+    meeting_id: MeetingId,
     /// Message receiver.
     receiver: mpsc::Receiver<MeetingMessage>,
     /// Cancellation token (child of controller's token).
@@ -319,6 +654,12 @@ pub struct MeetingActor {
     created_at: i64,
     /// Whether the meeting is shutting down.
     is_shutting_down: bool,
+    /// Whether the meeting ended (not just stopped with the MC).
+    ended: bool,
+    /// Most participants in the meeting at once.
+    peak_participants: usize,
+    /// Join attempts refused.
+    join_failures: u32,
     /// Shared actor metrics.
     metrics: Arc<ActorMetrics>,
     /// Controller metrics for GC heartbeat reporting (participant count).
@@ -327,6 +668,48 @@ pub struct MeetingActor {
     mailbox: MailboxMonitor,
     /// Handle to self, for passing to child ParticipantActors.
     self_handle: MeetingActorHandle,
+    /// Completed participant sessions.
+    attendance: Vec<AttendanceEntry>,
+    /// Where the attendance log is sent when the meeting ends.
+    attendance_tx: Option<mpsc::Sender<MeetingAttendance>>,
+    /// Polls and Q&A state.
+    interactions: InteractionState,
+    /// Per-participant bound on interaction requests.
+    interaction_limiter: RateLimiter,
+    /// Persistence for `interactions`.
+    interaction_store: Option<Arc<dyn InteractionStore>>,
+    /// Event journal of joins and leaves.
+    journal: Option<Arc<dyn JournalStore>>,
+    /// Journal head pre-loaded by a warm standby, consumed on start.
+    preloaded_journal: Option<Vec<JournalRecord>>,
+    /// Application data channel subscriptions.
+    data_channels: DataChannels,
+    /// Per-participant bound on data channel requests.
+    data_channel_limiter: RateLimiter,
+    /// Per-stream cooldown for forwarded keyframe requests.
+    keyframe_cooldowns: KeyframeCooldowns,
+    /// Live stream lifecycle and accumulated live time.
+    live_stream: LiveStream,
+    /// MC->MH egress control (live streaming is unavailable if `None`).
+    egress_client: Option<Arc<dyn MhEgressClient>>,
+    /// MH assignment lookup for egress.
+    mh_assignments: Option<Arc<dyn MhAssignmentStore>>,
+    /// Recording state and outstanding consent.
+    recording: RecordingState,
+    /// Whether media is end-to-end encrypted.
+    e2e_enabled: bool,
+    /// Resource counters (messages, bytes, Redis operations).
+    usage: Arc<MeetingUsage>,
+    /// How long a disconnected participant is held for reconnection.
+    disconnect_grace_period: Duration,
+    /// Period of the state divergence check (off if `None`).
+    state_check_interval: Option<Duration>,
+    /// What happens when a user already in the meeting joins again.
+    duplicate_join_policy: DuplicateJoinPolicy,
+    /// Recording layout for MH registration.
+    recording_layout: RecordingLayout,
+    /// Wall clock for meeting and attendance timestamps.
+    clock: SharedClock,
 }
 
 impl MeetingActor {
@@ -348,6 +731,28 @@ impl MeetingActor {
         metrics: Arc<ActorMetrics>,
         controller_metrics: Arc<ControllerMetrics>,
         master_secret: SecretBox<Vec<u8>>,
+    ) -> (MeetingActorHandle, JoinHandle<()>) {
+        Self::spawn_with_services(
+            meeting_id,
+            cancel_token,
+            metrics,
+            controller_metrics,
+            master_secret,
+            MeetingServices::default(),
+        )
+    }
+
+    /// Spawn a new meeting actor wired to external services (attendance
+    /// sink, interaction store, MH egress).
+    ///
+    /// See [`MeetingActor::spawn`] for the remaining arguments.
+    pub fn spawn_with_services(
+        meeting_id: String,
+        cancel_token: CancellationToken,
+        metrics: Arc<ActorMetrics>,
+        controller_metrics: Arc<ControllerMetrics>,
+        master_secret: SecretBox<Vec<u8>>,
+        services: MeetingServices,
     ) -> (MeetingActorHandle, JoinHandle<()>) {
         let (sender, receiver) = mpsc::channel(MEETING_CHANNEL_BUFFER);
 
@@ -358,8 +763,15 @@ impl MeetingActor {
             meeting_id: meeting_id.clone(),
         };
 
+        let usage = services.usage_registry.as_ref().map_or_else(
+            || Arc::new(MeetingUsage::default()),
+            |registry| registry.register(&meeting_id),
+        );
+
+        let clock = services.clock.unwrap_or_else(system_clock);
+
         let actor = Self {
-            meeting_id: meeting_id.clone(),
+            meeting_id: MeetingId::from(&meeting_id),
             receiver,
             cancel_token,
             participants: HashMap::new(),
@@ -368,12 +780,41 @@ impl MeetingActor {
             binding_manager: SessionBindingManager::new(master_secret),
             stored_bindings: HashMap::new(),
             fencing_generation: 1,
-            created_at: chrono::Utc::now().timestamp(),
+            created_at: clock.timestamp(),
             is_shutting_down: false,
+            ended: false,
+            peak_participants: 0,
+            join_failures: 0,
             metrics,
             controller_metrics,
             mailbox: MailboxMonitor::new(ActorType::Meeting, &meeting_id),
             self_handle: handle.clone(),
+            attendance: Vec::new(),
+            attendance_tx: services.attendance_tx,
+            interactions: InteractionState::new(),
+            interaction_limiter: RateLimiter::new(RATE_LIMIT_MAX_ACTIONS, RATE_LIMIT_WINDOW),
+            interaction_store: services.interaction_store,
+            journal: services.journal,
+            preloaded_journal: services.preloaded_journal,
+            data_channels: DataChannels::new(),
+            data_channel_limiter: RateLimiter::new(
+                DATA_CHANNEL_RATE_MAX_ACTIONS,
+                DATA_CHANNEL_RATE_WINDOW,
+            ),
+            keyframe_cooldowns: KeyframeCooldowns::new(KEYFRAME_REQUEST_COOLDOWN),
+            live_stream: LiveStream::new(),
+            egress_client: services.egress_client,
+            mh_assignments: services.mh_assignments,
+            recording: RecordingState::new(services.settings.recording_policy),
+            e2e_enabled: services.settings.e2e_enabled,
+            duplicate_join_policy: services.settings.duplicate_join_policy,
+            recording_layout: services.settings.recording_layout,
+            usage,
+            disconnect_grace_period: services
+                .disconnect_grace_period
+                .unwrap_or(DISCONNECT_GRACE_PERIOD),
+            state_check_interval: services.state_check_interval,
+            clock,
         };
 
         let task_handle = tokio::spawn(actor.run());
@@ -390,9 +831,18 @@ impl MeetingActor {
             "MeetingActor started"
         );
 
+        self.restore_interactions().await;
+        self.restore_journal().await;
+
         // Create interval for checking disconnect grace periods
         let mut grace_check = tokio::time::interval(Duration::from_secs(5));
 
+        // First check one period in, after restore has written anything it
+        // needed to
+        let mut state_check = self
+            .state_check_interval
+            .map(|period| tokio::time::interval_at(Instant::now() + period, period));
+
         loop {
             // Check for terminated connection actors
             self.check_connection_health().await;
@@ -412,6 +862,12 @@ impl MeetingActor {
                 // Check disconnect grace periods
                 _ = grace_check.tick() => {
                     self.check_disconnect_timeouts().await;
+                    self.check_recording_consent_timeouts().await;
+                }
+
+                // Compare state with Redis
+                () = next_state_check(&mut state_check) => {
+                    self.check_state_divergence().await;
                 }
 
                 // Handle messages
@@ -419,8 +875,11 @@ impl MeetingActor {
                     match msg {
                         Some(message) => {
                             self.mailbox.record_enqueue();
-                            self.handle_message(message).await;
+                            self.handle_message(message)
+                                .instrument(debug_span!("mc.actor.meeting.message"))
+                                .await;
                             self.mailbox.record_dequeue();
+                            self.usage.record_message();
                             self.metrics.record_message_processed();
                         }
                         None => {
@@ -454,11 +913,26 @@ impl MeetingActor {
                 participant_id,
                 is_host,
                 stream_tx,
+                resume,
                 respond_to,
             } => {
-                let result = self
-                    .handle_join(connection_id, user_id, participant_id, is_host, stream_tx)
-                    .await;
+                let resumed = match resume {
+                    Some(resume) => {
+                        self.try_resume(&connection_id, &user_id, resume, stream_tx.clone())
+                            .await
+                    }
+                    None => None,
+                };
+                let result = match resumed {
+                    Some(result) => result,
+                    None => {
+                        self.handle_join(connection_id, user_id, participant_id, is_host, stream_tx)
+                            .await
+                    }
+                };
+                if result.is_err() {
+                    self.join_failures = self.join_failures.saturating_add(1);
+                }
                 let _ = respond_to.send(result);
             }
 
@@ -472,12 +946,20 @@ impl MeetingActor {
 
             MeetingMessage::ConnectionReconnect {
                 connection_id,
+                user_id,
                 correlation_id,
                 binding_token,
+                stream_tx,
                 respond_to,
             } => {
                 let result = self
-                    .handle_reconnect(connection_id, correlation_id, binding_token)
+                    .handle_reconnect(
+                        connection_id,
+                        &user_id,
+                        correlation_id,
+                        binding_token,
+                        stream_tx,
+                    )
                     .await;
                 let _ = respond_to.send(result);
             }
@@ -497,6 +979,42 @@ impl MeetingActor {
                 self.handle_signaling(&participant_id, message).await;
             }
 
+            MeetingMessage::Interaction {
+                participant_id,
+                request,
+            } => {
+                self.handle_interaction(&participant_id, request).await;
+            }
+
+            MeetingMessage::DataChannel {
+                participant_id,
+                request,
+            } => {
+                self.handle_data_channel(&participant_id, request).await;
+            }
+
+            MeetingMessage::RequestKeyframe { requester, request } => {
+                self.handle_keyframe_request(&requester, &request).await;
+            }
+
+            MeetingMessage::LiveStream {
+                participant_id,
+                request,
+            } => {
+                self.handle_live_stream(&participant_id, request).await;
+            }
+
+            MeetingMessage::Recording {
+                participant_id,
+                request,
+            } => {
+                self.handle_recording(&participant_id, request).await;
+            }
+
+            MeetingMessage::LiveStreamStarted { result } => {
+                self.handle_live_stream_started(result).await;
+            }
+
             MeetingMessage::GetState { respond_to } => {
                 let state = self.get_state();
                 let _ = respond_to.send(state);
@@ -556,6 +1074,8 @@ impl MeetingActor {
             ));
         }
 
+        self.resolve_duplicate_join(&user_id).await?;
+
         debug!(
             target: "mc.actor.meeting",
             "Participant joining"
@@ -583,7 +1103,7 @@ impl MeetingActor {
         let (conn_handle, conn_task) = ParticipantActor::spawn_inner(
             connection_id.clone(),
             participant_id.clone(),
-            self.meeting_id.clone(),
+            self.meeting_id.to_string(),
             connection_token,
             Arc::clone(&self.metrics),
             stream_tx,
@@ -616,17 +1136,21 @@ impl MeetingActor {
             audio_host_muted: false,
             video_host_muted: false,
             is_host,
+            joined_at_ms: self.clock.timestamp_millis(),
         };
 
         let participant_info = participant.to_info();
+        let joined = participant.to_joined_event();
 
         self.participants
             .insert(participant_id.clone(), participant);
+        self.peak_participants = self.peak_participants.max(self.participants.len());
         self.correlation_to_participant
             .insert(correlation_id.clone(), participant_id.clone());
 
         self.metrics.connection_created();
         self.controller_metrics.increment_participants();
+        self.append_journal(joined).await;
 
         // Get list of other participants
         let participants: Vec<ParticipantInfo> = self
@@ -643,6 +1167,14 @@ impl MeetingActor {
         )
         .await;
 
+        self.recording.participant_joined(
+            &participant_id,
+            Instant::now(),
+            self.clock.timestamp_millis(),
+        );
+        self.send_catch_up(&participant_id, &conn_handle_for_result)
+            .await;
+
         info!(
             target: "mc.actor.meeting",
             total_participants = self.participants.len(),
@@ -656,9 +1188,127 @@ impl MeetingActor {
             participants,
             fencing_generation: self.fencing_generation,
             participant_handle: conn_handle_for_result,
+            meeting_handle: self.self_handle.clone(),
+            e2e_enabled: self.e2e_enabled,
+            recording_layout: self.recording_layout,
+            resumed: false,
         })
     }
 
+    /// Apply the duplicate join policy before `user_id` joins again.
+    ///
+    /// Under `Takeover` every earlier session of the user is replaced. Under
+    /// `Reject` a connected earlier session refuses the join; sessions only
+    /// waiting out their disconnect grace period are replaced, since the user
+    /// has evidently moved to a new device.
+    async fn resolve_duplicate_join(&mut self, user_id: &str) -> Result<(), McError> {
+        let existing: Vec<(String, bool)> = self
+            .participants
+            .values()
+            .filter(|p| p.user_id == user_id)
+            .map(|p| {
+                (
+                    p.participant_id.clone(),
+                    p.status == ParticipantStatus::Connected,
+                )
+            })
+            .collect();
+        if existing.is_empty() {
+            return Ok(());
+        }
+
+        match self.duplicate_join_policy {
+            DuplicateJoinPolicy::Allow => {
+                prom::record_duplicate_join("allowed");
+                return Ok(());
+            }
+            DuplicateJoinPolicy::Reject if existing.iter().any(|(_, connected)| *connected) => {
+                prom::record_duplicate_join("rejected");
+                return Err(McError::Conflict(
+                    "Already joined from another device".to_string(),
+                ));
+            }
+            DuplicateJoinPolicy::Reject | DuplicateJoinPolicy::Takeover => {}
+        }
+
+        info!(
+            target: "mc.actor.meeting",
+            replaced = existing.len(),
+            "Duplicate join replacing earlier session"
+        );
+        prom::record_duplicate_join("replaced");
+        for (participant_id, _) in existing {
+            self.replace_participant(&participant_id).await;
+        }
+        Ok(())
+    }
+
+    /// Remove a participant superseded by a newer join of the same user,
+    /// telling a live connection why with `SESSION_REPLACED` first.
+    async fn replace_participant(&mut self, participant_id: &str) {
+        if let Some(participant) = self.participants.get(participant_id) {
+            Self::send_error(participant, &McError::SessionReplaced).await;
+        }
+        self.remove_participant(participant_id, LeaveReason::Replaced)
+            .await;
+    }
+
+    /// Bring a new connection up to date on polls and Q&A, live streaming,
+    /// and recording (queued behind JoinResponse).
+    async fn send_catch_up(&self, participant_id: &str, conn: &ParticipantActorHandle) {
+        for event in self.interactions.snapshot_events() {
+            self.deliver(conn, raw_server_message(&encode_interaction_event(&event)))
+                .await;
+        }
+        if let Some(status) = self.live_stream.current_status() {
+            self.deliver(
+                conn,
+                raw_server_message(&encode_live_stream_status(&status)),
+            )
+            .await;
+        }
+        if let Some(notice) = self.recording.notice_for(participant_id) {
+            self.deliver(conn, raw_server_message(&encode_recording_notice(&notice)))
+                .await;
+        }
+    }
+
+    /// Resume the session in `resume` for a connection sent through the join
+    /// path. Returns `None` when the binding does not resume anything (unknown,
+    /// forged, another user's, or past the grace period) so the caller joins
+    /// the connection as a new participant instead, per ADR-0023, unless
+    /// [`SessionResume::join_if_rejected`] is off.
+    async fn try_resume(
+        &mut self,
+        connection_id: &str,
+        user_id: &str,
+        resume: SessionResume,
+        stream_tx: Option<tokio::sync::mpsc::Sender<bytes::Bytes>>,
+    ) -> Option<Result<JoinResult, McError>> {
+        let join_if_rejected = resume.join_if_rejected;
+        match self
+            .handle_reconnect(
+                connection_id.to_string(),
+                user_id,
+                resume.correlation_id,
+                resume.binding_token,
+                stream_tx,
+            )
+            .await
+        {
+            Ok(result) => Some(Ok(result.into())),
+            Err(McError::SessionBinding(error)) if join_if_rejected => {
+                debug!(
+                    target: "mc.actor.meeting",
+                    error = ?error,
+                    "Session resume rejected, joining as new participant"
+                );
+                None
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+
     /// Handle connection disconnect.
     async fn handle_disconnect(&mut self, connection_id: &str, participant_id: &str) {
         debug!(
@@ -676,8 +1326,14 @@ impl MeetingActor {
             self.metrics.connection_closed();
         }
 
-        // Mark participant as disconnected (start grace period)
-        if let Some(participant) = self.participants.get_mut(participant_id) {
+        // Mark participant as disconnected (start grace period). A late
+        // disconnect from a connection that a reconnect already replaced
+        // must not take the participant down with it.
+        if let Some(participant) = self.participants.get_mut(participant_id).filter(|p| {
+            p.connection
+                .as_ref()
+                .is_none_or(|c| c.connection_id() == connection_id)
+        }) {
             participant.status = ParticipantStatus::Disconnected;
             participant.disconnected_at = Some(Instant::now());
             participant.connection = None;
@@ -705,16 +1361,27 @@ impl MeetingActor {
     /// Validates binding token per ADR-0023 Section 1:
     /// 1. Correlation ID exists
     /// 2. Binding token HMAC verification (constant-time)
-    /// 3. Token not expired (30s TTL)
+    /// 3. JWT user matches the original binding
+    /// 4. Participant still held: connected, or disconnected for less than
+    ///    the grace period
     ///
-    /// On success, rotates correlation ID and binding token.
+    /// On success, rotates correlation ID and binding token, and sends the
+    /// new connection the poll/Q&A, live stream, and recording state it
+    /// missed. Roster entry, mute state, and data channel subscriptions were
+    /// kept while disconnected, so nothing else needs restoring.
     #[instrument(skip_all, fields(meeting_id = %self.meeting_id))]
     async fn handle_reconnect(
         &mut self,
         connection_id: String,
+        user_id: &str,
         correlation_id: String,
         binding_token: String,
+        stream_tx: Option<tokio::sync::mpsc::Sender<bytes::Bytes>>,
     ) -> Result<ReconnectResult, McError> {
+        if self.is_shutting_down {
+            return Err(McError::Draining);
+        }
+
         // Find stored binding by correlation ID
         let stored_binding =
             self.stored_bindings
@@ -723,15 +1390,6 @@ impl MeetingActor {
                     crate::errors::SessionBindingError::SessionNotFound,
                 ))?;
 
-        // Check if binding has expired (ADR-0023: 30s TTL)
-        if stored_binding.is_expired() {
-            // Remove expired binding
-            self.stored_bindings.remove(&correlation_id);
-            return Err(McError::SessionBinding(
-                crate::errors::SessionBindingError::TokenExpired,
-            ));
-        }
-
         // Validate binding token via HMAC-SHA256 (MAJOR-003 fix)
         let is_valid = self.binding_manager.validate_token(
             &self.meeting_id,
@@ -751,6 +1409,17 @@ impl MeetingActor {
             ));
         }
 
+        // A binding token alone must not move a session to another user
+        if stored_binding.user_id != user_id {
+            warn!(
+                target: "mc.actor.meeting",
+                "Reconnect JWT user does not match session binding"
+            );
+            return Err(McError::SessionBinding(
+                crate::errors::SessionBindingError::InvalidToken,
+            ));
+        }
+
         // Find participant by correlation ID
         let participant_id = self
             .correlation_to_participant
@@ -767,21 +1436,36 @@ impl MeetingActor {
                     crate::errors::SessionBindingError::SessionNotFound,
                 ))?;
 
+        // The reconnect window runs from the disconnect, not from when the
+        // binding was issued: a participant an hour into the meeting must
+        // still be able to resume. The periodic grace check may not have
+        // removed an expired participant yet.
+        if participant
+            .disconnected_at
+            .is_some_and(|at| at.elapsed() >= self.disconnect_grace_period)
+        {
+            return Err(McError::SessionBinding(
+                crate::errors::SessionBindingError::TokenExpired,
+            ));
+        }
+
         debug!(
             target: "mc.actor.meeting",
             "Participant reconnecting"
         );
 
-        // Create new participant actor with meeting handle for disconnect notification
+        // Create new participant actor with stream + meeting handle for disconnect notification
         let connection_token = self.cancel_token.child_token();
-        let (conn_handle, conn_task) = ParticipantActor::spawn_with_meeting(
+        let (conn_handle, conn_task) = ParticipantActor::spawn_inner(
             connection_id.clone(),
             participant_id.clone(),
-            self.meeting_id.clone(),
+            self.meeting_id.to_string(),
             connection_token,
             Arc::clone(&self.metrics),
-            self.self_handle.clone(),
+            stream_tx,
+            Some(self.self_handle.clone()),
         );
+        let conn_handle_for_result = conn_handle.clone();
 
         // Store new connection
         self.connections.insert(
@@ -796,7 +1480,15 @@ impl MeetingActor {
         // Update participant state
         participant.status = ParticipantStatus::Connected;
         participant.disconnected_at = None;
-        participant.connection = Some(conn_handle);
+        // The previous connection may still be registered if the client
+        // reconnected before its keepalive timed out. Retire it now so it
+        // is not counted as active twice.
+        if let Some(stale) = participant.connection.replace(conn_handle) {
+            let _ = stale.close(CloseReason::Takeover).await;
+            if self.connections.remove(stale.connection_id()).is_some() {
+                self.metrics.connection_closed();
+            }
+        }
 
         // Remove old binding and correlation mapping
         self.stored_bindings.remove(&correlation_id);
@@ -850,20 +1542,46 @@ impl MeetingActor {
             .map(Participant::to_info)
             .collect();
 
+        self.send_catch_up(&participant_id, &conn_handle_for_result)
+            .await;
+
         Ok(ReconnectResult {
             participant_id,
             new_correlation_id,
             new_binding_token,
             participants,
-        })
+            fencing_generation: self.fencing_generation,
+            participant_handle: conn_handle_for_result,
+            meeting_handle: self.self_handle.clone(),
+            e2e_enabled: self.e2e_enabled,
+            recording_layout: self.recording_layout,
+        })
     }
 
     /// Handle participant leaving.
     #[instrument(skip_all, fields(meeting_id = %self.meeting_id))]
     async fn handle_leave(&mut self, participant_id: &str) -> Result<(), McError> {
+        if self
+            .remove_participant(participant_id, LeaveReason::Voluntary)
+            .await
+        {
+            Ok(())
+        } else {
+            // MINOR-002 fix: Don't include participant ID in error message
+            Err(McError::ParticipantNotFound(
+                "Participant not found".to_string(),
+            ))
+        }
+    }
+
+    /// Remove a participant, close their connection, and tell the others.
+    ///
+    /// Returns `false` if the participant was not in the meeting.
+    async fn remove_participant(&mut self, participant_id: &str, reason: LeaveReason) -> bool {
         if let Some(participant) = self.participants.remove(participant_id) {
             debug!(
                 target: "mc.actor.meeting",
+                reason = reason.as_str(),
                 "Participant leaving"
             );
 
@@ -872,11 +1590,30 @@ impl MeetingActor {
                 .remove(&participant.correlation_id);
             self.stored_bindings.remove(&participant.correlation_id);
 
-            // Close connection if still active
+            // Close connection if still active. Closing through the mailbox
+            // lands the reason after anything already queued for the client.
             if let Some(conn_handle) = &participant.connection {
-                conn_handle.cancel();
+                match close_reason(reason) {
+                    Some(close) => {
+                        let _ = conn_handle.close(close).await;
+                    }
+                    None => conn_handle.cancel(),
+                }
             }
 
+            self.record_attendance(&participant, reason);
+            self.append_journal(JournalEvent::Left {
+                participant_id: participant_id.to_string(),
+                reason: reason.as_str().to_string(),
+                at_ms: self.clock.timestamp_millis(),
+            })
+            .await;
+            self.interaction_limiter.remove(participant_id);
+            self.data_channels.remove_participant(participant_id);
+            self.data_channel_limiter.remove(participant_id);
+            self.keyframe_cooldowns.remove_participant(participant_id);
+            self.recording.remove_participant(participant_id);
+
             // Decrement participant count for GC heartbeat reporting
             self.controller_metrics.decrement_participants();
 
@@ -885,7 +1622,7 @@ impl MeetingActor {
                 participant_id,
                 ParticipantStateUpdate::Left {
                     participant_id: participant_id.to_string(),
-                    reason: LeaveReason::Voluntary,
+                    reason,
                 },
             )
             .await;
@@ -896,12 +1633,9 @@ impl MeetingActor {
                 "Participant left"
             );
 
-            Ok(())
+            true
         } else {
-            // MINOR-002 fix: Don't include participant ID in error message
-            Err(McError::ParticipantNotFound(
-                "Participant not found".to_string(),
-            ))
+            false
         }
     }
 
@@ -950,7 +1684,7 @@ impl MeetingActor {
     /// Get current meeting state.
     fn get_state(&self) -> MeetingState {
         MeetingState {
-            meeting_id: self.meeting_id.clone(),
+            meeting_id: self.meeting_id.to_string(),
             participants: self
                 .participants
                 .values()
@@ -1067,6 +1801,7 @@ impl MeetingActor {
         );
 
         self.is_shutting_down = true;
+        self.ended = true;
 
         // Notify all participants
         for participant_id in self.participants.keys().cloned().collect::<Vec<_>>() {
@@ -1080,9 +1815,10 @@ impl MeetingActor {
             .await;
         }
 
-        // Cancel all connections
+        // Close all connections; the close is flushed even though
+        // cancelling self cancels them too
         for managed in self.connections.values() {
-            managed.handle.cancel();
+            let _ = managed.handle.close(CloseReason::MeetingEnded).await;
         }
 
         // Cancel self (will trigger graceful shutdown)
@@ -1099,7 +1835,7 @@ impl MeetingActor {
         for (participant_id, participant) in &self.participants {
             if participant.status == ParticipantStatus::Disconnected {
                 if let Some(disconnected_at) = participant.disconnected_at {
-                    if now.duration_since(disconnected_at) >= DISCONNECT_GRACE_PERIOD {
+                    if now.duration_since(disconnected_at) >= self.disconnect_grace_period {
                         timed_out.push(participant_id.clone());
                     }
                 }
@@ -1117,6 +1853,12 @@ impl MeetingActor {
             if let Some(participant) = self.participants.remove(&participant_id) {
                 self.correlation_to_participant
                     .remove(&participant.correlation_id);
+                self.record_attendance(&participant, LeaveReason::Timeout);
+                self.interaction_limiter.remove(&participant_id);
+                self.data_channels.remove_participant(&participant_id);
+                self.data_channel_limiter.remove(&participant_id);
+                self.keyframe_cooldowns.remove_participant(&participant_id);
+                self.recording.remove_participant(&participant_id);
 
                 // Decrement participant count for GC heartbeat reporting
                 self.controller_metrics.decrement_participants();
@@ -1145,6 +1887,8 @@ impl MeetingActor {
 
         for conn_id in finished {
             if let Some(managed) = self.connections.remove(&conn_id) {
+                // Removed here, so `handle_disconnect` below will not count it
+                self.metrics.connection_closed();
                 match managed.task_handle.await {
                     Ok(()) => {
                         debug!(
@@ -1175,145 +1919,1075 @@ impl MeetingActor {
         }
     }
 
-    /// Broadcast an update to all participants except the source.
-    async fn broadcast_update(&self, except_participant_id: &str, update: ParticipantStateUpdate) {
-        for participant in self.participants.values() {
-            if participant.participant_id != except_participant_id {
-                if let Some(conn) = &participant.connection {
-                    let _ = conn.send_update(update.clone()).await;
+    /// Handle a poll or Q&A request.
+    ///
+    /// Applies the request, persists the new state, and broadcasts any
+    /// resulting event to every participant. Failures are reported only to
+    /// the requester.
+    async fn handle_interaction(&mut self, participant_id: &str, request: InteractionRequest) {
+        let Some(participant) = self.participants.get(participant_id) else {
+            warn!(
+                target: "mc.actor.meeting",
+                meeting_id = %self.meeting_id,
+                participant_id = %participant_id,
+                "Interaction request from unknown participant"
+            );
+            return;
+        };
+
+        let result = if !self
+            .interaction_limiter
+            .check(participant_id, Instant::now())
+        {
+            Err(McError::RateLimited)
+        } else if request.requires_host() && !participant.is_host {
+            Err(McError::PermissionDenied(
+                "Only hosts can manage polls and questions".to_string(),
+            ))
+        } else {
+            self.interactions
+                .apply(participant_id, &participant.user_id, request)
+        };
+
+        match result {
+            Ok(event) => {
+                self.persist_interactions().await;
+                if let Some(event) = event {
+                    self.broadcast_interaction(&event).await;
                 }
             }
+            Err(e) => {
+                debug!(
+                    target: "mc.actor.meeting",
+                    meeting_id = %self.meeting_id,
+                    participant_id = %participant_id,
+                    error_type = e.error_type_label(),
+                    "Interaction request rejected"
+                );
+                Self::send_error(participant, &e).await;
+            }
         }
     }
 
-    /// Perform graceful shutdown.
-    async fn graceful_shutdown(&mut self) {
-        info!(
-            target: "mc.actor.meeting",
-            meeting_id = %self.meeting_id,
-            participants = self.participants.len(),
-            connections = self.connections.len(),
-            "Performing graceful shutdown"
-        );
+    /// Send an interaction event to every connected participant.
+    async fn broadcast_interaction(&self, event: &InteractionEvent) {
+        let payload = raw_server_message(&encode_interaction_event(event));
+        for participant in self.participants.values() {
+            if let Some(conn) = &participant.connection {
+                self.deliver(conn, payload.clone()).await;
+            }
+        }
+    }
 
-        self.is_shutting_down = true;
+    /// Handle an application data channel request.
+    ///
+    /// Payloads are delivered to the channel's other subscribers; failures
+    /// are reported only to the requester.
+    async fn handle_data_channel(&mut self, participant_id: &str, request: DataChannelRequest) {
+        let Some(participant) = self.participants.get(participant_id) else {
+            warn!(
+                target: "mc.actor.meeting",
+                meeting_id = %self.meeting_id,
+                participant_id = %participant_id,
+                "Data channel request from unknown participant"
+            );
+            return;
+        };
 
-        // Cancel all connection actors
-        for managed in self.connections.values() {
-            managed.handle.cancel();
+        if !self
+            .data_channel_limiter
+            .check(participant_id, Instant::now())
+        {
+            Self::send_error(participant, &McError::RateLimited).await;
+            return;
         }
 
-        // Wait for connections to complete
-        for (conn_id, managed) in self.connections.drain() {
-            match tokio::time::timeout(Duration::from_secs(5), managed.task_handle).await {
-                Ok(Ok(())) => {
-                    debug!(
-                        target: "mc.actor.meeting",
-                        meeting_id = %self.meeting_id,
-                        connection_id = %conn_id,
-                        "Connection completed cleanly"
-                    );
-                }
-                Ok(Err(e)) => {
-                    warn!(
-                        target: "mc.actor.meeting",
-                        meeting_id = %self.meeting_id,
-                        connection_id = %conn_id,
-                        error = ?e,
-                        "Connection task panicked during shutdown"
-                    );
-                }
-                Err(_) => {
-                    warn!(
-                        target: "mc.actor.meeting",
-                        meeting_id = %self.meeting_id,
-                        connection_id = %conn_id,
-                        "Connection shutdown timed out"
-                    );
+        let result = match request {
+            DataChannelRequest::Subscribe { channel_id } => {
+                self.data_channels.subscribe(participant_id, &channel_id)
+            }
+            DataChannelRequest::Unsubscribe { channel_id } => {
+                self.data_channels.unsubscribe(participant_id, &channel_id);
+                Ok(())
+            }
+            DataChannelRequest::Send {
+                channel_id,
+                payload,
+            } => match self
+                .data_channels
+                .publish(participant_id, &channel_id, payload.len())
+            {
+                Ok(delivery) => {
+                    let message = raw_server_message(&encode_data_channel_message(
+                        &channel_id,
+                        participant_id,
+                        delivery.sequence,
+                        &payload,
+                    ));
+                    for recipient in &delivery.recipients {
+                        if let Some(conn) = self
+                            .participants
+                            .get(recipient)
+                            .and_then(|p| p.connection.as_ref())
+                        {
+                            self.deliver(conn, message.clone()).await;
+                        }
+                    }
+                    Ok(())
                 }
+                Err(e) => Err(e),
+            },
+        };
+
+        if let Err(e) = result {
+            debug!(
+                target: "mc.actor.meeting",
+                meeting_id = %self.meeting_id,
+                participant_id = %participant_id,
+                error_type = e.error_type_label(),
+                "Data channel request rejected"
+            );
+            Self::send_error(participant, &e).await;
+        }
+    }
+
+    /// Handle a keyframe request for a publisher's video stream.
+    ///
+    /// Forwarded to the publisher as `KeyframeRequested` at most once per
+    /// stream per cooldown. Requests for publishers that are not connected
+    /// are dropped: a reconnecting publisher starts with a keyframe anyway.
+    async fn handle_keyframe_request(
+        &mut self,
+        requester: &KeyframeRequester,
+        request: &KeyframeRequest,
+    ) {
+        let source = requester.as_str();
+        if let KeyframeRequester::Participant(participant_id) = requester {
+            if !self.participants.contains_key(participant_id) {
+                warn!(
+                    target: "mc.actor.meeting",
+                    meeting_id = %self.meeting_id,
+                    participant_id = %participant_id,
+                    "Keyframe request from unknown participant"
+                );
+                return;
             }
         }
 
-        info!(
+        let connected = self
+            .participants
+            .get(&request.publisher_id)
+            .is_some_and(|p| p.connection.is_some());
+        if !connected {
+            debug!(
+                target: "mc.actor.meeting",
+                meeting_id = %self.meeting_id,
+                publisher_id = %request.publisher_id,
+                source,
+                "Keyframe request for unavailable publisher dropped"
+            );
+            prom::record_keyframe_request(source, "no_publisher");
+            return;
+        }
+
+        if !self
+            .keyframe_cooldowns
+            .admit(&request.publisher_id, request.stream, Instant::now())
+        {
+            prom::record_keyframe_request(source, "suppressed");
+            return;
+        }
+
+        if let Some(conn) = self
+            .participants
+            .get(&request.publisher_id)
+            .and_then(|p| p.connection.as_ref())
+        {
+            let message = raw_server_message(&encode_keyframe_requested(request.stream));
+            self.deliver(conn, message).await;
+        }
+        debug!(
             target: "mc.actor.meeting",
             meeting_id = %self.meeting_id,
-            "Graceful shutdown complete"
+            publisher_id = %request.publisher_id,
+            stream = ?request.stream,
+            source,
+            "Keyframe request forwarded to publisher"
         );
+        prom::record_keyframe_request(source, "forwarded");
     }
-}
 
-#[cfg(test)]
-#[allow(clippy::unwrap_used, clippy::expect_used)]
-mod tests {
-    use super::*;
+    /// Handle a live stream request (host only).
+    ///
+    /// Status changes are broadcast; failures are reported only to the
+    /// requester.
+    async fn handle_live_stream(&mut self, participant_id: &str, request: LiveStreamRequest) {
+        let Some(is_host) = self.participants.get(participant_id).map(|p| p.is_host) else {
+            warn!(
+                target: "mc.actor.meeting",
+                meeting_id = %self.meeting_id,
+                participant_id = %participant_id,
+                "Live stream request from unknown participant"
+            );
+            return;
+        };
 
-    /// Test secret for session binding (32 bytes as required by ADR-0023).
-    fn test_secret() -> SecretBox<Vec<u8>> {
-        SecretBox::new(Box::new(vec![0u8; 32]))
+        let result = if !is_host {
+            Err(McError::PermissionDenied(
+                "Only hosts can manage live streams".to_string(),
+            ))
+        } else if self.e2e_enabled && matches!(request, LiveStreamRequest::Start { .. }) {
+            Err(McError::E2eUnsupported(
+                "Live streaming is unavailable in end-to-end encrypted meetings".to_string(),
+            ))
+        } else {
+            match request {
+                LiveStreamRequest::Start {
+                    tile_participant_ids,
+                    output,
+                } => self.start_live_stream(tile_participant_ids, output),
+                LiveStreamRequest::Stop => self.stop_live_stream(),
+            }
+        };
+
+        match result {
+            Ok(status) => self.broadcast_live_stream_status(&status).await,
+            Err(e) => {
+                debug!(
+                    target: "mc.actor.meeting",
+                    meeting_id = %self.meeting_id,
+                    participant_id = %participant_id,
+                    error_type = e.error_type_label(),
+                    "Live stream request rejected"
+                );
+                if let Some(participant) = self.participants.get(participant_id) {
+                    Self::send_error(participant, &e).await;
+                }
+            }
+        }
     }
 
-    #[tokio::test]
-    async fn test_meeting_actor_spawn() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
+    /// Validate a start request and call `StartEgress` in the background.
+    fn start_live_stream(
+        &mut self,
+        tile_participant_ids: Vec<String>,
+        output: LiveStreamOutput,
+    ) -> Result<LiveStreamStatus, McError> {
+        validate_start(&tile_participant_ids, &output, |id| {
+            self.participants.contains_key(id)
+        })?;
+        let (Some(client), Some(assignments)) =
+            (self.egress_client.clone(), self.mh_assignments.clone())
+        else {
+            return Err(McError::InvalidRequest(
+                "Live streaming is not available".to_string(),
+            ));
+        };
+        self.live_stream.begin_start()?;
+
+        let handle = self.self_handle.clone();
+        let meeting_id = self.meeting_id.clone();
+        // The MH assignment lookup is a Redis read
+        self.usage.record_redis_op();
+        tokio::spawn(async move {
+            let result = start_egress(
+                assignments.as_ref(),
+                client.as_ref(),
+                &meeting_id,
+                &tile_participant_ids,
+                &output,
+            )
+            .await;
+            let started = result.as_ref().ok().map(|(egress, _)| egress.clone());
+            if handle.live_stream_started(result).await.is_err() {
+                // The meeting ended while the MH was starting the egress
+                if let Some(egress) = started {
+                    stop_egress(client.as_ref(), &meeting_id, &egress).await;
+                }
+            }
+        });
 
-        let (handle, _task) = MeetingActor::spawn(
-            "meeting-123".to_string(),
-            cancel_token.clone(),
-            metrics,
-            controller_metrics,
-            test_secret(),
-        );
+        Ok(LiveStreamStatus::Starting)
+    }
 
-        assert_eq!(handle.meeting_id(), "meeting-123");
-        assert!(!handle.is_cancelled());
+    /// Stop the live stream and call `StopEgress` in the background.
+    fn stop_live_stream(&mut self) -> Result<LiveStreamStatus, McError> {
+        let egress = self.live_stream.stop(Instant::now())?;
+        self.spawn_stop_egress(egress);
+        Ok(LiveStreamStatus::Stopped)
+    }
 
-        handle.cancel();
-        assert!(handle.is_cancelled());
+    /// Apply the outcome of `StartEgress`.
+    async fn handle_live_stream_started(
+        &mut self,
+        result: Result<(ActiveEgress, String), McError>,
+    ) {
+        match result {
+            Ok((egress, playback_url)) => {
+                if self.is_shutting_down
+                    || !self.live_stream.started(
+                        egress.clone(),
+                        playback_url.clone(),
+                        Instant::now(),
+                    )
+                {
+                    self.spawn_stop_egress(egress);
+                    return;
+                }
+                info!(
+                    target: "mc.actor.meeting",
+                    meeting_id = %self.meeting_id,
+                    egress_id = %egress.egress_id,
+                    "Live stream started"
+                );
+                self.broadcast_live_stream_status(&LiveStreamStatus::Live { playback_url })
+                    .await;
+            }
+            Err(e) => {
+                self.live_stream.start_failed();
+                warn!(
+                    target: "mc.actor.meeting",
+                    meeting_id = %self.meeting_id,
+                    error = %e,
+                    "Failed to start live stream"
+                );
+                self.broadcast_live_stream_status(&LiveStreamStatus::Failed {
+                    error_message: "The live stream could not be started".to_string(),
+                })
+                .await;
+            }
+        }
     }
 
-    #[tokio::test]
-    async fn test_meeting_actor_join() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
+    /// Stop an egress on the MH without waiting for the result.
+    fn spawn_stop_egress(&self, egress: ActiveEgress) {
+        let Some(client) = self.egress_client.clone() else {
+            return;
+        };
+        let meeting_id = self.meeting_id.clone();
+        tokio::spawn(async move {
+            stop_egress(client.as_ref(), &meeting_id, &egress).await;
+        });
+    }
 
-        let (handle, _task) = MeetingActor::spawn(
-            "meeting-join-test".to_string(),
-            cancel_token.clone(),
-            metrics,
-            controller_metrics,
-            test_secret(),
-        );
+    /// Send a live stream status change to every connected participant.
+    async fn broadcast_live_stream_status(&self, status: &LiveStreamStatus) {
+        let payload = raw_server_message(&encode_live_stream_status(status));
+        for participant in self.participants.values() {
+            if let Some(conn) = &participant.connection {
+                self.deliver(conn, payload.clone()).await;
+            }
+        }
+    }
 
-        let result = handle
-            .connection_join(
-                "conn-1".to_string(),
-                "user-1".to_string(),
-                "part-1".to_string(),
-                false, // not host
-                None,
-            )
-            .await;
+    /// Handle a recording request (start/stop: host only) or consent answer.
+    ///
+    /// Start and stop send every participant their notice; failures are
+    /// reported only to the requester. A declined consent removes the
+    /// participant under the `RequireAck` policy.
+    async fn handle_recording(&mut self, participant_id: &str, request: RecordingRequest) {
+        let Some(is_host) = self.participants.get(participant_id).map(|p| p.is_host) else {
+            warn!(
+                target: "mc.actor.meeting",
+                meeting_id = %self.meeting_id,
+                participant_id = %participant_id,
+                "Recording request from unknown participant"
+            );
+            return;
+        };
 
-        assert!(result.is_ok());
-        let join_result = result.unwrap();
-        assert_eq!(join_result.participant_id, "part-1");
-        assert!(!join_result.correlation_id.is_empty());
-        // Binding token should be 64 hex chars (HMAC-SHA256)
-        assert_eq!(join_result.binding_token.len(), 64);
+        let result = match request {
+            RecordingRequest::Consent { accepted } => {
+                if self.recording.consent(participant_id, accepted) == ConsentOutcome::Declined {
+                    info!(
+                        target: "mc.actor.meeting",
+                        meeting_id = %self.meeting_id,
+                        participant_id = %participant_id,
+                        "Recording consent declined, removing participant"
+                    );
+                    self.remove_without_consent(participant_id, LeaveReason::Voluntary)
+                        .await;
+                }
+                return;
+            }
+            RecordingRequest::Start | RecordingRequest::Stop if !is_host => Err(
+                McError::PermissionDenied("Only hosts can start or stop recording".to_string()),
+            ),
+            RecordingRequest::Start if self.e2e_enabled => Err(McError::E2eUnsupported(
+                "Recording is unavailable in end-to-end encrypted meetings".to_string(),
+            )),
+            RecordingRequest::Start => self.recording.start(
+                participant_id,
+                self.participants.keys().map(String::as_str),
+                Instant::now(),
+                self.clock.timestamp_millis(),
+            ),
+            RecordingRequest::Stop => self.recording.stop(),
+        };
 
-        handle.cancel();
+        match result {
+            Ok(()) => {
+                info!(
+                    target: "mc.actor.meeting",
+                    meeting_id = %self.meeting_id,
+                    recording = self.recording.is_recording(),
+                    consent_mode = ?self.recording.policy().mode,
+                    "Recording state changed"
+                );
+                self.send_recording_notices().await;
+            }
+            Err(e) => {
+                debug!(
+                    target: "mc.actor.meeting",
+                    meeting_id = %self.meeting_id,
+                    participant_id = %participant_id,
+                    error_type = e.error_type_label(),
+                    "Recording request rejected"
+                );
+                if let Some(participant) = self.participants.get(participant_id) {
+                    Self::send_error(participant, &e).await;
+                }
+            }
+        }
     }
 
-    #[tokio::test]
-    async fn test_meeting_actor_duplicate_join() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
+    /// Send every connected participant their recording notice.
+    async fn send_recording_notices(&self) {
+        for participant in self.participants.values() {
+            if let Some(conn) = &participant.connection {
+                let notice = self
+                    .recording
+                    .notice_for(&participant.participant_id)
+                    .unwrap_or_else(RecordingNotice::stopped);
+                self.deliver(conn, raw_server_message(&encode_recording_notice(&notice)))
+                    .await;
+            }
+        }
+    }
 
-        let (handle, _task) = MeetingActor::spawn(
+    /// Remove participants whose recording consent deadline has passed.
+    async fn check_recording_consent_timeouts(&mut self) {
+        for participant_id in self.recording.take_expired(Instant::now()) {
+            info!(
+                target: "mc.actor.meeting",
+                meeting_id = %self.meeting_id,
+                participant_id = %participant_id,
+                "Recording consent not given in time, removing participant"
+            );
+            self.remove_without_consent(&participant_id, LeaveReason::Removed)
+                .await;
+        }
+    }
+
+    /// Tell a participant why they are being removed, then remove them.
+    async fn remove_without_consent(&mut self, participant_id: &str, reason: LeaveReason) {
+        if let Some(participant) = self.participants.get(participant_id) {
+            Self::send_error(
+                participant,
+                &McError::PermissionDenied(
+                    "Recording consent is required to stay in this meeting".to_string(),
+                ),
+            )
+            .await;
+        }
+        self.remove_participant(participant_id, reason).await;
+    }
+
+    /// Send a payload to one participant, counting its bytes against the
+    /// meeting.
+    async fn deliver(&self, conn: &ParticipantActorHandle, payload: SignalingPayload) {
+        if let SignalingPayload::Raw { data, .. } = &payload {
+            self.usage.record_bytes(data.len());
+        }
+        let _ = conn.send(payload).await;
+    }
+
+    /// Report a rejected request to the participant that made it.
+    async fn send_error(participant: &Participant, error: &McError) {
+        if let Some(conn) = &participant.connection {
+            let _ = conn
+                .send(raw_server_message(&encode_error_message(error)))
+                .await;
+        }
+    }
+
+    /// Load polls and Q&A state from the interaction store (MC failover).
+    ///
+    /// Starts with empty state if there is no store, no snapshot, or the
+    /// snapshot cannot be read.
+    async fn restore_interactions(&mut self) {
+        let Some(store) = &self.interaction_store else {
+            return;
+        };
+
+        self.usage.record_redis_op();
+        let loaded = tokio::time::timeout(
+            INTERACTION_STORE_TIMEOUT,
+            store.load_interactions(&self.meeting_id),
+        )
+        .await
+        .unwrap_or_else(|_| Err(McError::Redis("load interactions timed out".to_string())))
+        .and_then(|json| json.as_deref().map(InteractionState::from_json).transpose());
+
+        match loaded {
+            Ok(Some(state)) => {
+                info!(
+                    target: "mc.actor.meeting",
+                    meeting_id = %self.meeting_id,
+                    "Restored polls and Q&A state"
+                );
+                self.interactions = state;
+            }
+            Ok(None) => {}
+            Err(e) => {
+                warn!(
+                    target: "mc.actor.meeting",
+                    meeting_id = %self.meeting_id,
+                    error = %e,
+                    "Failed to restore polls and Q&A state"
+                );
+            }
+        }
+    }
+
+    /// Write polls and Q&A state to the interaction store.
+    ///
+    /// Failures are logged; in-memory state stays authoritative for this MC.
+    async fn persist_interactions(&self) {
+        let Some(store) = &self.interaction_store else {
+            return;
+        };
+
+        self.usage.record_redis_op();
+        let result = match self.interactions.to_json() {
+            Ok(json) => tokio::time::timeout(
+                INTERACTION_STORE_TIMEOUT,
+                store.store_interactions(&self.meeting_id, &json),
+            )
+            .await
+            .unwrap_or_else(|_| Err(McError::Redis("store interactions timed out".to_string()))),
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = result {
+            warn!(
+                target: "mc.actor.meeting",
+                meeting_id = %self.meeting_id,
+                error = %e,
+                "Failed to persist polls and Q&A state"
+            );
+        }
+    }
+
+    /// Rebuild the attendance log from the event journal.
+    ///
+    /// Sessions the journal shows as still open were on the previous MC;
+    /// their clients come back as new participants, so they are closed here
+    /// as `timeout` and journaled as left.
+    ///
+    /// A promoted standby starts from the entries it pre-loaded and reads
+    /// only the tail written since.
+    async fn restore_journal(&mut self) {
+        let preloaded = self.preloaded_journal.take().unwrap_or_default();
+        let Some(store) = &self.journal else {
+            return;
+        };
+
+        self.usage.record_redis_op();
+        let load = match preloaded.last() {
+            Some(last) => store.load_journal_after(&self.meeting_id, &last.id),
+            None => store.load_journal(&self.meeting_id),
+        };
+        let loaded = tokio::time::timeout(JOURNAL_TIMEOUT, load)
+            .await
+            .unwrap_or_else(|_| Err(McError::Redis("load journal timed out".to_string())));
+        let mut records = preloaded;
+        match loaded {
+            Ok(tail) => records.extend(tail),
+            Err(e) => {
+                warn!(
+                    target: "mc.actor.meeting",
+                    meeting_id = %self.meeting_id,
+                    error = %e,
+                    preloaded = records.len(),
+                    "Failed to restore from meeting journal"
+                );
+            }
+        }
+        if records.is_empty() {
+            return;
+        }
+
+        let rebuilt = journal::replay(&records);
+        let now_ms = self.clock.timestamp_millis();
+        let ended = rebuilt.ended.len();
+        let orphaned: Vec<String> = rebuilt
+            .open
+            .iter()
+            .map(|session| session.participant_id.clone())
+            .collect();
+        let entries = rebuilt.ended.into_iter().chain(
+            rebuilt
+                .open
+                .into_iter()
+                .map(|session| session.close(LeaveReason::Timeout, now_ms)),
+        );
+        let room = MAX_ATTENDANCE_ENTRIES.saturating_sub(self.attendance.len());
+        self.attendance.extend(entries.take(room));
+
+        for participant_id in &orphaned {
+            self.append_journal(JournalEvent::Left {
+                participant_id: participant_id.clone(),
+                reason: LeaveReason::Timeout.as_str().to_string(),
+                at_ms: now_ms,
+            })
+            .await;
+        }
+
+        info!(
+            target: "mc.actor.meeting",
+            meeting_id = %self.meeting_id,
+            ended_sessions = ended,
+            orphaned_sessions = orphaned.len(),
+            skipped_entries = rebuilt.skipped,
+            "Restored attendance from meeting journal"
+        );
+    }
+
+    /// Append an event to the meeting journal.
+    ///
+    /// Failures are logged; the journal is a recovery and audit aid and
+    /// never fails the operation it records.
+    async fn append_journal(&self, event: JournalEvent) {
+        let Some(store) = &self.journal else {
+            return;
+        };
+
+        self.usage.record_redis_op();
+        let result = match event.to_json() {
+            Ok(json) => tokio::time::timeout(
+                JOURNAL_TIMEOUT,
+                store.append_journal(&self.meeting_id, &json),
+            )
+            .await
+            .unwrap_or_else(|_| Err(McError::Redis("journal append timed out".to_string()))),
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = result {
+            warn!(
+                target: "mc.actor.meeting",
+                meeting_id = %self.meeting_id,
+                error = %e,
+                "Failed to append to meeting journal"
+            );
+        }
+    }
+
+    /// Compare the state this actor persists with Redis and rewrite Redis
+    /// where it differs (see [`state_check`]).
+    async fn check_state_divergence(&self) {
+        self.check_interactions_divergence().await;
+        self.check_roster_divergence().await;
+    }
+
+    /// Compare the polls and Q&A snapshot in Redis with memory.
+    async fn check_interactions_divergence(&self) {
+        let Some(store) = &self.interaction_store else {
+            return;
+        };
+
+        self.usage.record_redis_op();
+        let loaded = tokio::time::timeout(
+            INTERACTION_STORE_TIMEOUT,
+            store.load_interactions(&self.meeting_id),
+        )
+        .await
+        .unwrap_or_else(|_| Err(McError::Redis("load interactions timed out".to_string())));
+        let stored = match loaded {
+            Ok(stored) => stored,
+            Err(e) => {
+                debug!(
+                    target: "mc.actor.meeting",
+                    meeting_id = %self.meeting_id,
+                    error = %e,
+                    "State check skipped: failed to load polls and Q&A state"
+                );
+                return;
+            }
+        };
+        let Ok(expected) = self.interactions.checksum() else {
+            return;
+        };
+
+        // Re-serialized so an equal snapshot in an older field order still
+        // matches; one that no longer parses has diverged
+        let actual = match stored.as_deref() {
+            Some(json) => InteractionState::from_json(json).and_then(|state| state.checksum()),
+            None => InteractionState::new().checksum(),
+        };
+        if actual.ok().as_deref() == Some(expected.as_str()) {
+            return;
+        }
+
+        self.record_divergence(CheckedState::Interactions);
+        self.persist_interactions().await;
+    }
+
+    /// Compare the participants the journal shows as present with memory.
+    async fn check_roster_divergence(&self) {
+        let Some(store) = &self.journal else {
+            return;
+        };
+
+        self.usage.record_redis_op();
+        let loaded = tokio::time::timeout(JOURNAL_TIMEOUT, store.load_journal(&self.meeting_id))
+            .await
+            .unwrap_or_else(|_| Err(McError::Redis("load journal timed out".to_string())));
+        let records = match loaded {
+            Ok(records) => records,
+            Err(e) => {
+                debug!(
+                    target: "mc.actor.meeting",
+                    meeting_id = %self.meeting_id,
+                    error = %e,
+                    "State check skipped: failed to load meeting journal"
+                );
+                return;
+            }
+        };
+
+        let open = journal::replay(&records).open;
+        let journaled: HashSet<&str> = open
+            .iter()
+            .map(|session| session.participant_id.as_str())
+            .collect();
+        if state_check::roster_checksum(journaled.iter().copied())
+            == state_check::roster_checksum(self.participants.keys().map(String::as_str))
+        {
+            return;
+        }
+
+        self.record_divergence(CheckedState::Roster);
+        let now_ms = self.clock.timestamp_millis();
+        // Ghosts: their leave never reached the journal
+        for participant_id in journaled
+            .iter()
+            .filter(|id| !self.participants.contains_key(**id))
+        {
+            self.append_journal(JournalEvent::Left {
+                participant_id: (*participant_id).to_string(),
+                reason: LeaveReason::Timeout.as_str().to_string(),
+                at_ms: now_ms,
+            })
+            .await;
+        }
+        // Missing: the join never reached the journal, or was trimmed
+        for participant in self
+            .participants
+            .values()
+            .filter(|p| !journaled.contains(p.participant_id.as_str()))
+        {
+            self.append_journal(participant.to_joined_event()).await;
+        }
+    }
+
+    fn record_divergence(&self, state: CheckedState) {
+        warn!(
+            target: "mc.actor.meeting",
+            meeting_id = %self.meeting_id,
+            state = state.as_str(),
+            "Meeting state diverged from Redis, re-syncing"
+        );
+        prom::record_state_divergence(state.as_str());
+    }
+
+    /// Broadcast an update to all participants except the source.
+    async fn broadcast_update(&self, except_participant_id: &str, update: ParticipantStateUpdate) {
+        for participant in self.participants.values() {
+            if participant.participant_id != except_participant_id {
+                if let Some(conn) = &participant.connection {
+                    let _ = conn.send_update(update.clone()).await;
+                }
+            }
+        }
+    }
+
+    /// Perform graceful shutdown.
+    async fn graceful_shutdown(&mut self) {
+        info!(
+            target: "mc.actor.meeting",
+            meeting_id = %self.meeting_id,
+            participants = self.participants.len(),
+            connections = self.connections.len(),
+            "Performing graceful shutdown"
+        );
+
+        self.is_shutting_down = true;
+
+        // Cancel all connection actors
+        for managed in self.connections.values() {
+            managed.handle.cancel();
+        }
+
+        // Wait for connections to complete
+        for (conn_id, managed) in self.connections.drain() {
+            self.metrics.connection_closed();
+            match tokio::time::timeout(Duration::from_secs(5), managed.task_handle).await {
+                Ok(Ok(())) => {
+                    debug!(
+                        target: "mc.actor.meeting",
+                        meeting_id = %self.meeting_id,
+                        connection_id = %conn_id,
+                        "Connection completed cleanly"
+                    );
+                }
+                Ok(Err(e)) => {
+                    warn!(
+                        target: "mc.actor.meeting",
+                        meeting_id = %self.meeting_id,
+                        connection_id = %conn_id,
+                        error = ?e,
+                        "Connection task panicked during shutdown"
+                    );
+                }
+                Err(_) => {
+                    warn!(
+                        target: "mc.actor.meeting",
+                        meeting_id = %self.meeting_id,
+                        connection_id = %conn_id,
+                        "Connection shutdown timed out"
+                    );
+                }
+            }
+        }
+
+        if let Some(egress) = self.live_stream.finish(Instant::now()) {
+            self.spawn_stop_egress(egress);
+        }
+
+        self.flush_attendance();
+
+        // Participants still here leave with the meeting; stop reporting them to GC
+        for _ in self.participants.drain() {
+            self.controller_metrics.decrement_participants();
+        }
+
+        info!(
+            target: "mc.actor.meeting",
+            meeting_id = %self.meeting_id,
+            "Graceful shutdown complete"
+        );
+    }
+
+    /// Log a completed participant session.
+    fn record_attendance(&mut self, participant: &Participant, reason: LeaveReason) {
+        if self.attendance.len() >= MAX_ATTENDANCE_ENTRIES {
+            warn!(
+                target: "mc.actor.meeting",
+                meeting_id = %self.meeting_id,
+                "Attendance log full, dropping session"
+            );
+            return;
+        }
+        let left_at_ms = self.clock.timestamp_millis();
+        self.attendance
+            .push(participant.to_attendance(reason, left_at_ms));
+    }
+
+    /// Close open sessions and send the attendance log to the sink, with the
+    /// end summary if the meeting ended.
+    ///
+    /// Uses `try_send` so a backed-up sink never delays shutdown.
+    fn flush_attendance(&mut self) {
+        let left_at_ms = self.clock.timestamp_millis();
+        let open: Vec<AttendanceEntry> = self
+            .participants
+            .values()
+            .map(|p| p.to_attendance(LeaveReason::MeetingEnded, left_at_ms))
+            .collect();
+        for entry in open {
+            if self.attendance.len() >= MAX_ATTENDANCE_ENTRIES {
+                break;
+            }
+            self.attendance.push(entry);
+        }
+
+        let live_stream_seconds = self.live_stream.live_seconds(Instant::now());
+        if self.attendance.is_empty() && live_stream_seconds == 0 && !self.ended {
+            return;
+        }
+
+        let ended = self.ended.then(|| MeetingEndSummary {
+            peak_participants: u32::try_from(self.peak_participants).unwrap_or(u32::MAX),
+            join_failures: self.join_failures,
+        });
+        let report = MeetingAttendance {
+            meeting_id: self.meeting_id.to_string(),
+            records: std::mem::take(&mut self.attendance),
+            live_stream_seconds,
+            ended,
+        };
+        let record_count = report.records.len();
+        let Some(tx) = &self.attendance_tx else {
+            return;
+        };
+        if let Err(e) = tx.try_send(report) {
+            warn!(
+                target: "mc.actor.meeting",
+                meeting_id = %self.meeting_id,
+                record_count = record_count,
+                error = %e,
+                "Failed to queue attendance report"
+            );
+        }
+    }
+}
+
+/// Start an egress on the meeting's primary MH.
+async fn start_egress(
+    assignments: &dyn MhAssignmentStore,
+    client: &dyn MhEgressClient,
+    meeting_id: &MeetingId,
+    tile_participant_ids: &[String],
+    output: &LiveStreamOutput,
+) -> Result<(ActiveEgress, String), McError> {
+    let mh_grpc_endpoint = assignments
+        .get_mh_assignment(meeting_id)
+        .await?
+        .and_then(|assignment| assignment.handlers.into_iter().next())
+        .map(|handler| handler.grpc_endpoint)
+        .ok_or_else(|| McError::MhAssignmentMissing(meeting_id.to_string()))?;
+
+    let started = client
+        .start_egress(&mh_grpc_endpoint, meeting_id, tile_participant_ids, output)
+        .await?;
+    Ok((
+        ActiveEgress {
+            mh_grpc_endpoint,
+            egress_id: started.egress_id,
+        },
+        started.playback_url,
+    ))
+}
+
+/// Stop an egress, logging (not propagating) failures.
+async fn stop_egress(client: &dyn MhEgressClient, meeting_id: &str, egress: &ActiveEgress) {
+    match client
+        .stop_egress(&egress.mh_grpc_endpoint, meeting_id, &egress.egress_id)
+        .await
+    {
+        Ok(live_seconds) => info!(
+            target: "mc.actor.meeting",
+            meeting_id = %meeting_id,
+            egress_id = %egress.egress_id,
+            mh_live_seconds = live_seconds,
+            "Live stream stopped"
+        ),
+        Err(e) => warn!(
+            target: "mc.actor.meeting",
+            meeting_id = %meeting_id,
+            egress_id = %egress.egress_id,
+            error = %e,
+            "Failed to stop live stream egress"
+        ),
+    }
+}
+
+/// How a leaving participant's connection is closed: with the reason the
+/// client is shown, or `None` to just cancel it (the client asked to leave).
+fn close_reason(reason: LeaveReason) -> Option<CloseReason> {
+    match reason {
+        LeaveReason::Removed => Some(CloseReason::Kicked),
+        LeaveReason::MeetingEnded => Some(CloseReason::MeetingEnded),
+        LeaveReason::Replaced => Some(CloseReason::Takeover),
+        LeaveReason::Voluntary | LeaveReason::Timeout => None,
+    }
+}
+
+/// Wrap a `ServerMessage` for delivery through a `ParticipantActor`.
+///
+/// The participant actor forwards `data` to the stream as-is; `message_type`
+/// is not used on the outbound path.
+fn raw_server_message(message: &ServerMessage) -> SignalingPayload {
+    SignalingPayload::Raw {
+        message_type: 0,
+        data: message.encode_to_vec(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    /// Test secret for session binding (32 bytes as required by ADR-0023).
+    fn test_secret() -> SecretBox<Vec<u8>> {
+        SecretBox::new(Box::new(vec![0u8; 32]))
+    }
+
+    #[tokio::test]
+    async fn test_meeting_actor_spawn() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-123".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+        );
+
+        assert_eq!(handle.meeting_id(), "meeting-123");
+        assert!(!handle.is_cancelled());
+
+        handle.cancel();
+        assert!(handle.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_meeting_actor_join() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-join-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+        );
+
+        let result = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false, // not host
+                None,
+            )
+            .await;
+
+        assert!(result.is_ok());
+        let join_result = result.unwrap();
+        assert_eq!(join_result.participant_id, "part-1");
+        assert!(!join_result.correlation_id.is_empty());
+        // Binding token should be 64 hex chars (HMAC-SHA256)
+        assert_eq!(join_result.binding_token.len(), 64);
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_meeting_actor_duplicate_join() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
             "meeting-dup-test".to_string(),
             cancel_token.clone(),
             metrics,
@@ -1323,505 +2997,2379 @@ mod tests {
 
         let result = handle
             .connection_join(
-                "conn-1".to_string(),
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await;
+        assert!(result.is_ok());
+
+        let result = handle
+            .connection_join(
+                "conn-2".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await;
+        assert!(matches!(result, Err(McError::Conflict(_))));
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_meeting_actor_get_state() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-state-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+        );
+
+        // Join a participant
+        let _ = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await;
+
+        let state = handle.get_state().await;
+        assert!(state.is_ok());
+        let state = state.unwrap();
+        assert_eq!(state.meeting_id, "meeting-state-test");
+        assert_eq!(state.participants.len(), 1);
+        assert!(!state.is_shutting_down);
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_meeting_actor_leave() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-leave-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+        );
+
+        // Join a participant
+        let _ = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await;
+
+        // Leave
+        let result = handle.participant_leave("part-1".to_string()).await;
+        assert!(result.is_ok());
+
+        // Verify empty
+        let state = handle.get_state().await.unwrap();
+        assert_eq!(state.participants.len(), 0);
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_meeting_actor_reconnect() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-reconnect-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+        );
+
+        // Join
+        let join_result = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Disconnect
+        let _ = handle
+            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
+            .await;
+
+        // Reconnect with valid binding token
+        let result = handle
+            .connection_reconnect(
+                "conn-2".to_string(),
+                "user-1".to_string(),
+                join_result.correlation_id.clone(),
+                join_result.binding_token.clone(),
+                None,
+            )
+            .await;
+
+        assert!(result.is_ok());
+        let reconnect_result = result.unwrap();
+        assert_eq!(reconnect_result.participant_id, "part-1");
+        // New correlation ID should be different (rotation per ADR-0023)
+        assert_ne!(
+            reconnect_result.new_correlation_id,
+            join_result.correlation_id
+        );
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_meeting_actor_reconnect_invalid_token() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-reconnect-invalid".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+        );
+
+        // Join
+        let join_result = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Disconnect
+        let _ = handle
+            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
+            .await;
+
+        // Reconnect with invalid binding token
+        let result = handle
+            .connection_reconnect(
+                "conn-2".to_string(),
+                "user-1".to_string(),
+                join_result.correlation_id.clone(),
+                "invalid-token".to_string(),
+                None,
+            )
+            .await;
+
+        // Should fail with InvalidToken error
+        assert!(matches!(
+            result,
+            Err(McError::SessionBinding(
+                crate::errors::SessionBindingError::InvalidToken
+            ))
+        ));
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_meeting_actor_self_mute() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-mute-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+        );
+
+        // Join
+        let _ = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await;
+
+        // Update mute
+        let result = handle
+            .update_self_mute("part-1".to_string(), true, false)
+            .await;
+        assert!(result.is_ok());
+
+        // Check state
+        let state = handle.get_state().await.unwrap();
+        let participant = state
+            .participants
+            .iter()
+            .find(|p| p.participant_id == "part-1")
+            .unwrap();
+        assert!(participant.audio_self_muted);
+        assert!(!participant.video_self_muted);
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_meeting_actor_host_mute() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-host-mute-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+        );
+
+        // Join host (part-1) and non-host (part-2)
+        let _ = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                true, // host
+                None,
+            )
+            .await;
+        let _ = handle
+            .connection_join(
+                "conn-2".to_string(),
+                "user-2".to_string(),
+                "part-2".to_string(),
+                false, // not host
+                None,
+            )
+            .await;
+
+        // Host mutes part-2
+        let result = handle
+            .host_mute("part-2".to_string(), "part-1".to_string(), true, false)
+            .await;
+        assert!(result.is_ok());
+
+        // Check state
+        let state = handle.get_state().await.unwrap();
+        let participant = state
+            .participants
+            .iter()
+            .find(|p| p.participant_id == "part-2")
+            .unwrap();
+        assert!(participant.audio_host_muted);
+        assert!(!participant.video_host_muted);
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_meeting_actor_host_mute_denied_for_non_host() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-host-mute-denied".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+        );
+
+        // Join two non-host participants
+        let _ = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false, // not host
+                None,
+            )
+            .await;
+        let _ = handle
+            .connection_join(
+                "conn-2".to_string(),
+                "user-2".to_string(),
+                "part-2".to_string(),
+                false, // not host
+                None,
+            )
+            .await;
+
+        // Non-host tries to mute part-2 - should fail
+        let result = handle
+            .host_mute("part-2".to_string(), "part-1".to_string(), true, false)
+            .await;
+        assert!(matches!(result, Err(McError::PermissionDenied(_))));
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_meeting_actor_child_token() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-token-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+        );
+
+        let child = handle.child_token();
+        assert!(!child.is_cancelled());
+
+        handle.cancel();
+
+        // Give time for cancellation to propagate
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        assert!(child.is_cancelled());
+    }
+
+    /// Test that the 30-second disconnect grace period expires correctly.
+    /// Uses `tokio::time::pause()` to control time advancement.
+    #[tokio::test(start_paused = true)]
+    async fn test_disconnect_grace_period_expires() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-grace-period-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+        );
+
+        // Join a participant
+        let join_result = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(join_result.participant_id, "part-1");
+
+        // Verify participant is in the meeting
+        let state = handle.get_state().await.unwrap();
+        assert_eq!(state.participants.len(), 1);
+        assert_eq!(state.participants[0].status, ParticipantStatus::Connected);
+
+        // Disconnect the participant
+        let _ = handle
+            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
+            .await;
+
+        // Give actor time to process the disconnect message
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // Verify participant is disconnected but still in the meeting
+        let state = handle.get_state().await.unwrap();
+        assert_eq!(state.participants.len(), 1);
+        assert_eq!(
+            state.participants[0].status,
+            ParticipantStatus::Disconnected
+        );
+
+        // Advance time by 29 seconds - participant should still be present
+        tokio::time::advance(Duration::from_secs(29)).await;
+
+        // Give actor time for the grace check interval to tick
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let state = handle.get_state().await.unwrap();
+        assert_eq!(
+            state.participants.len(),
+            1,
+            "Participant should still be present before grace period expires"
+        );
+
+        // Advance time past the 30-second grace period (total now > 30s)
+        tokio::time::advance(Duration::from_secs(6)).await;
+
+        // Wait for the grace check interval (every 5 seconds) to process
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // Verify participant has been removed
+        let state = handle.get_state().await.unwrap();
+        assert_eq!(
+            state.participants.len(),
+            0,
+            "Participant should be removed after grace period expires"
+        );
+
+        handle.cancel();
+    }
+
+    /// Test that reconnection within grace period preserves participant.
+    #[tokio::test(start_paused = true)]
+    async fn test_reconnect_within_grace_period() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-reconnect-grace-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+        );
+
+        // Join a participant
+        let join_result = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Disconnect the participant
+        let _ = handle
+            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
+            .await;
+
+        // Give actor time to process
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // Advance time by 20 seconds (within grace period)
+        tokio::time::advance(Duration::from_secs(20)).await;
+
+        // Reconnect with valid token
+        let reconnect_result = handle
+            .connection_reconnect(
+                "conn-2".to_string(),
+                "user-1".to_string(),
+                join_result.correlation_id.clone(),
+                join_result.binding_token.clone(),
+                None,
+            )
+            .await;
+
+        assert!(reconnect_result.is_ok());
+        let reconnect_result = reconnect_result.unwrap();
+        assert_eq!(reconnect_result.participant_id, "part-1");
+
+        // Verify participant is connected again
+        let state = handle.get_state().await.unwrap();
+        assert_eq!(state.participants.len(), 1);
+        assert_eq!(state.participants[0].status, ParticipantStatus::Connected);
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_replaces_half_open_connection() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-half-open-test".to_string(),
+            cancel_token.clone(),
+            Arc::clone(&metrics),
+            controller_metrics,
+            test_secret(),
+        );
+
+        let join_result = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Reconnect before conn-1 was ever reported as disconnected
+        handle
+            .connection_reconnect(
+                "conn-2".to_string(),
+                "user-1".to_string(),
+                join_result.correlation_id.clone(),
+                join_result.binding_token.clone(),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(metrics.connection_count(), 1);
+
+        // The late disconnect of the replaced connection is ignored
+        let _ = handle
+            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
+            .await;
+        let state = handle.get_state().await.unwrap();
+        assert_eq!(state.participants[0].status, ParticipantStatus::Connected);
+        assert_eq!(metrics.connection_count(), 1);
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_releases_connection_and_participant_counts() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+
+        let (handle, task) = MeetingActor::spawn(
+            "meeting-shutdown-counts".to_string(),
+            cancel_token.clone(),
+            Arc::clone(&metrics),
+            Arc::clone(&controller_metrics),
+            test_secret(),
+        );
+
+        for n in 1..=2 {
+            handle
+                .connection_join(
+                    format!("conn-{n}"),
+                    format!("user-{n}"),
+                    format!("part-{n}"),
+                    false,
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+        assert_eq!(metrics.connection_count(), 2);
+        assert_eq!(controller_metrics.participants(), 2);
+
+        cancel_token.cancel();
+        task.await.unwrap();
+
+        assert_eq!(metrics.connection_count(), 0);
+        assert_eq!(controller_metrics.participants(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_rejects_other_user() {
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-reconnect-user".to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
+            test_secret(),
+        );
+        let join_result = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await
+            .unwrap();
+        let _ = handle
+            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
+            .await;
+
+        let result = handle
+            .connection_reconnect(
+                "conn-2".to_string(),
+                "user-2".to_string(),
+                join_result.correlation_id,
+                join_result.binding_token,
+                None,
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(McError::SessionBinding(
+                crate::errors::SessionBindingError::InvalidToken
+            ))
+        ));
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_join_resumes_session_or_falls_back_to_new_participant() {
+        let (handle, _task) = MeetingActor::spawn(
+            "meeting-resume-join".to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
+            test_secret(),
+        );
+        let first = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(!first.resumed);
+        let _ = handle
+            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
+            .await;
+
+        // Valid binding: same participant, rotated binding
+        let resumed = handle
+            .connection_join_or_resume(
+                "conn-2".to_string(),
+                "user-1".to_string(),
+                "part-ignored".to_string(),
+                false,
+                None,
+                Some(SessionResume {
+                    correlation_id: first.correlation_id.clone(),
+                    binding_token: first.binding_token.clone(),
+                    join_if_rejected: true,
+                }),
+            )
+            .await
+            .unwrap();
+        assert!(resumed.resumed);
+        assert_eq!(resumed.participant_id, "part-1");
+        assert_ne!(resumed.correlation_id, first.correlation_id);
+
+        // The old binding was rotated away, so it now joins as a new participant
+        let fresh = handle
+            .connection_join_or_resume(
+                "conn-3".to_string(),
+                "user-1".to_string(),
+                "part-3".to_string(),
+                false,
+                None,
+                Some(SessionResume {
+                    correlation_id: first.correlation_id.clone(),
+                    binding_token: first.binding_token.clone(),
+                    join_if_rejected: true,
+                }),
+            )
+            .await
+            .unwrap();
+        assert!(!fresh.resumed);
+        assert_eq!(fresh.participant_id, "part-3");
+
+        // A possibly replayed resume fails instead of joining
+        let replayed = handle
+            .connection_join_or_resume(
+                "conn-4".to_string(),
+                "user-1".to_string(),
+                "part-4".to_string(),
+                false,
+                None,
+                Some(SessionResume {
+                    correlation_id: first.correlation_id,
+                    binding_token: first.binding_token,
+                    join_if_rejected: false,
+                }),
+            )
+            .await;
+        assert!(matches!(replayed, Err(McError::SessionBinding(_))));
+
+        let state = handle.get_state().await.unwrap();
+        assert_eq!(state.participants.len(), 2);
+
+        handle.cancel();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reconnect_within_configured_grace_period() {
+        let (handle, _task) = MeetingActor::spawn_with_services(
+            "meeting-grace-config".to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
+            test_secret(),
+            MeetingServices {
+                disconnect_grace_period: Some(Duration::from_secs(120)),
+                ..Default::default()
+            },
+        );
+        let join_result = handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Well past the binding's issue time and the default grace period
+        tokio::time::advance(Duration::from_secs(300)).await;
+        let _ = handle
+            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
+            .await;
+        tokio::time::advance(Duration::from_secs(90)).await;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let reconnect = handle
+            .connection_reconnect(
+                "conn-2".to_string(),
+                "user-1".to_string(),
+                join_result.correlation_id,
+                join_result.binding_token,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(reconnect.participant_id, "part-1");
+
+        // Past the configured grace period the participant is released
+        let _ = handle
+            .connection_disconnected("conn-2".to_string(), "part-1".to_string())
+            .await;
+        tokio::time::advance(Duration::from_secs(126)).await;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let state = handle.get_state().await.unwrap();
+        assert!(state.participants.is_empty());
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_attendance_flushed_on_end_meeting() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+        let (attendance_tx, mut attendance_rx) = mpsc::channel(4);
+
+        let (handle, task) = MeetingActor::spawn_with_services(
+            "meeting-attendance-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+            MeetingServices {
+                attendance_tx: Some(attendance_tx),
+                ..Default::default()
+            },
+        );
+
+        for (conn, user, part) in [
+            ("conn-1", "user-1", "part-1"),
+            ("conn-2", "user-2", "part-2"),
+        ] {
+            handle
+                .connection_join(
+                    conn.to_string(),
+                    user.to_string(),
+                    part.to_string(),
+                    false,
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+        handle
+            .participant_leave("part-1".to_string())
+            .await
+            .unwrap();
+        handle.end_meeting("host ended".to_string()).await.unwrap();
+
+        let report = tokio::time::timeout(Duration::from_secs(5), attendance_rx.recv())
+            .await
+            .expect("attendance should be flushed")
+            .expect("attendance channel open");
+        let _ = task.await;
+
+        assert_eq!(report.meeting_id, "meeting-attendance-test");
+        let sessions: Vec<(&str, LeaveReason)> = report
+            .records
+            .iter()
+            .map(|r| (r.participant_id.as_str(), r.reason))
+            .collect();
+        assert_eq!(
+            sessions,
+            vec![
+                ("part-1", LeaveReason::Voluntary),
+                ("part-2", LeaveReason::MeetingEnded),
+            ]
+        );
+        assert!(report
+            .records
+            .iter()
+            .all(|r| r.left_at_ms >= r.joined_at_ms));
+        assert_eq!(report.records[0].user_id, "user-1");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_attendance_records_timeout_once_after_grace_period() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+        let (attendance_tx, mut attendance_rx) = mpsc::channel(4);
+
+        let (handle, _task) = MeetingActor::spawn_with_services(
+            "meeting-attendance-timeout-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+            MeetingServices {
+                attendance_tx: Some(attendance_tx),
+                ..Default::default()
+            },
+        );
+
+        handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await
+            .unwrap();
+        let _ = handle
+            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
+            .await;
+
+        tokio::time::advance(DISCONNECT_GRACE_PERIOD + Duration::from_secs(6)).await;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        handle.cancel();
+
+        let report = attendance_rx.recv().await.expect("attendance flushed");
+        assert_eq!(report.records.len(), 1);
+        assert_eq!(report.records[0].reason, LeaveReason::Timeout);
+        // Stopped with the MC, not ended: GC keeps the meeting assigned
+        assert_eq!(report.ended, None);
+    }
+
+    #[tokio::test]
+    async fn test_attendance_times_come_from_injected_clock() {
+        use common::time::MockClock;
+
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+        let (attendance_tx, mut attendance_rx) = mpsc::channel(4);
+        let clock = MockClock::at_timestamp(1_700_000_000);
+
+        let (handle, task) = MeetingActor::spawn_with_services(
+            "meeting-attendance-clock-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+            MeetingServices {
+                attendance_tx: Some(attendance_tx),
+                clock: Some(clock.shared()),
+                ..Default::default()
+            },
+        );
+
+        handle
+            .connection_join(
+                "conn-1".to_string(),
+                "user-1".to_string(),
+                "part-1".to_string(),
+                false,
+                None,
+            )
+            .await
+            .unwrap();
+        clock.advance(Duration::from_secs(90));
+        handle
+            .participant_leave("part-1".to_string())
+            .await
+            .unwrap();
+        handle.end_meeting("host ended".to_string()).await.unwrap();
+
+        let report = tokio::time::timeout(Duration::from_secs(5), attendance_rx.recv())
+            .await
+            .expect("attendance should be flushed")
+            .expect("attendance channel open");
+        let _ = task.await;
+
+        assert_eq!(report.records.len(), 1);
+        assert_eq!(report.records[0].joined_at_ms, 1_700_000_000_000);
+        assert_eq!(report.records[0].left_at_ms, 1_700_000_090_000);
+    }
+
+    #[tokio::test]
+    async fn test_attendance_not_sent_for_empty_meeting() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+        let (attendance_tx, mut attendance_rx) = mpsc::channel(4);
+
+        let (handle, task) = MeetingActor::spawn_with_services(
+            "meeting-attendance-empty-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+            MeetingServices {
+                attendance_tx: Some(attendance_tx),
+                ..Default::default()
+            },
+        );
+
+        handle.cancel();
+        let _ = task.await;
+
+        // Actor dropped its sender without sending anything
+        assert!(attendance_rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_end_summary_counts_peak_and_refused_joins() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+        let (attendance_tx, mut attendance_rx) = mpsc::channel(4);
+
+        let (handle, task) = MeetingActor::spawn_with_services(
+            "meeting-end-summary-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+            MeetingServices {
+                attendance_tx: Some(attendance_tx),
+                ..Default::default()
+            },
+        );
+
+        for (conn, user, part) in [
+            ("conn-1", "user-1", "part-1"),
+            ("conn-2", "user-2", "part-2"),
+            ("conn-3", "user-3", "part-3"),
+        ] {
+            handle
+                .connection_join(
+                    conn.to_string(),
+                    user.to_string(),
+                    part.to_string(),
+                    false,
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+        handle
+            .participant_leave("part-3".to_string())
+            .await
+            .unwrap();
+
+        // Refused: the participant is already in the meeting
+        let result = handle
+            .connection_join(
+                "conn-4".to_string(),
                 "user-1".to_string(),
                 "part-1".to_string(),
                 false,
                 None,
             )
             .await;
-        assert!(result.is_ok());
+        assert!(result.is_err());
 
-        let result = handle
+        handle.end_meeting("host ended".to_string()).await.unwrap();
+
+        let report = tokio::time::timeout(Duration::from_secs(5), attendance_rx.recv())
+            .await
+            .expect("attendance should be flushed")
+            .expect("attendance channel open");
+        let _ = task.await;
+
+        assert_eq!(
+            report.ended,
+            Some(MeetingEndSummary {
+                peak_participants: 3,
+                join_failures: 1,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_end_summary_sent_for_ended_empty_meeting() {
+        let metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let cancel_token = CancellationToken::new();
+        let (attendance_tx, mut attendance_rx) = mpsc::channel(4);
+
+        let (handle, task) = MeetingActor::spawn_with_services(
+            "meeting-end-summary-empty-test".to_string(),
+            cancel_token.clone(),
+            metrics,
+            controller_metrics,
+            test_secret(),
+            MeetingServices {
+                attendance_tx: Some(attendance_tx),
+                ..Default::default()
+            },
+        );
+
+        handle.end_meeting("host ended".to_string()).await.unwrap();
+        let _ = task.await;
+
+        // GC still has to hear that the meeting is over
+        let report = attendance_rx.recv().await.expect("end summary sent");
+        assert!(report.records.is_empty());
+        assert_eq!(report.ended, Some(MeetingEndSummary::default()));
+    }
+
+    // ========================================================================
+    // Event journal
+    // ========================================================================
+
+    /// In-memory journal store.
+    #[derive(Default)]
+    struct MemoryJournalStore {
+        records: Mutex<Vec<JournalRecord>>,
+    }
+
+    impl MemoryJournalStore {
+        fn with_events(events: &[JournalEvent]) -> Self {
+            let store = Self::default();
+            for event in events {
+                store.push(event.to_json().unwrap());
+            }
+            store
+        }
+
+        fn push(&self, event: String) {
+            let mut records = self.records.lock().unwrap();
+            let id = format!("0-{}", records.len());
+            records.push(JournalRecord {
+                id,
+                generation: 1,
+                event,
+            });
+        }
+
+        fn events(&self) -> Vec<JournalEvent> {
+            self.records
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|record| JournalEvent::from_json(&record.event).unwrap())
+                .collect()
+        }
+    }
+
+    impl JournalStore for MemoryJournalStore {
+        fn append_journal<'a>(
+            &'a self,
+            _meeting_id: &'a MeetingId,
+            event: &'a str,
+        ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), McError>> + Send + 'a>>
+        {
+            self.push(event.to_string());
+            Box::pin(async { Ok(()) })
+        }
+
+        fn load_journal<'a>(
+            &'a self,
+            _meeting_id: &'a MeetingId,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = Result<Vec<JournalRecord>, McError>> + Send + 'a>,
+        > {
+            let records = self.records.lock().unwrap().clone();
+            Box::pin(async move { Ok(records) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_journal_records_joins_and_leaves() {
+        let store = Arc::new(MemoryJournalStore::default());
+        let (handle, _task) = MeetingActor::spawn_with_services(
+            "meeting-journal-test".to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
+            test_secret(),
+            MeetingServices {
+                journal: Some(Arc::clone(&store) as Arc<dyn JournalStore>),
+                ..Default::default()
+            },
+        );
+
+        handle
             .connection_join(
-                "conn-2".to_string(),
+                "conn-1".to_string(),
                 "user-1".to_string(),
                 "part-1".to_string(),
-                false,
+                true,
                 None,
             )
-            .await;
-        assert!(matches!(result, Err(McError::Conflict(_))));
+            .await
+            .unwrap();
+        handle
+            .participant_leave("part-1".to_string())
+            .await
+            .unwrap();
+
+        let events = store.events();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            &events[0],
+            JournalEvent::Joined { participant_id, user_id, is_host: true, .. }
+                if participant_id == "part-1" && user_id == "user-1"
+        ));
+        assert!(matches!(
+            &events[1],
+            JournalEvent::Left { participant_id, reason, .. }
+                if participant_id == "part-1" && reason == "voluntary"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_journal_replay_rebuilds_attendance_on_start() {
+        let joined = |participant_id: &str, at_ms| JournalEvent::Joined {
+            participant_id: participant_id.to_string(),
+            user_id: format!("user-{participant_id}"),
+            display_name: "Participant 1".to_string(),
+            is_host: false,
+            at_ms,
+        };
+        // Written by the MC that owned the meeting before
+        let store = Arc::new(MemoryJournalStore::with_events(&[
+            joined("old-1", 1_000),
+            JournalEvent::Left {
+                participant_id: "old-1".to_string(),
+                reason: "voluntary".to_string(),
+                at_ms: 2_000,
+            },
+            joined("old-2", 3_000),
+        ]));
+        let (attendance_tx, mut attendance_rx) = mpsc::channel(4);
+
+        let (handle, task) = MeetingActor::spawn_with_services(
+            "meeting-journal-replay-test".to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
+            test_secret(),
+            MeetingServices {
+                attendance_tx: Some(attendance_tx),
+                journal: Some(Arc::clone(&store) as Arc<dyn JournalStore>),
+                ..Default::default()
+            },
+        );
+        handle.end_meeting("host ended".to_string()).await.unwrap();
+
+        let report = tokio::time::timeout(Duration::from_secs(5), attendance_rx.recv())
+            .await
+            .expect("attendance should be flushed")
+            .expect("attendance channel open");
+        let _ = task.await;
+
+        let sessions: Vec<(&str, LeaveReason, i64)> = report
+            .records
+            .iter()
+            .map(|r| (r.participant_id.as_str(), r.reason, r.joined_at_ms))
+            .collect();
+        assert_eq!(
+            sessions,
+            vec![
+                ("old-1", LeaveReason::Voluntary, 1_000),
+                ("old-2", LeaveReason::Timeout, 3_000),
+            ]
+        );
+        // The orphaned session is closed in the journal too
+        assert!(matches!(
+            store.events().last(),
+            Some(JournalEvent::Left { participant_id, reason, .. })
+                if participant_id == "old-2" && reason == "timeout"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_journal_replay_continues_from_preloaded_entries() {
+        let store = Arc::new(MemoryJournalStore::with_events(&[
+            JournalEvent::Joined {
+                participant_id: "old-1".to_string(),
+                user_id: "user-old-1".to_string(),
+                display_name: "Participant 1".to_string(),
+                is_host: false,
+                at_ms: 1_000,
+            },
+            JournalEvent::Left {
+                participant_id: "old-1".to_string(),
+                reason: "voluntary".to_string(),
+                at_ms: 2_000,
+            },
+            JournalEvent::Joined {
+                participant_id: "old-2".to_string(),
+                user_id: "user-old-2".to_string(),
+                display_name: "Participant 2".to_string(),
+                is_host: false,
+                at_ms: 3_000,
+            },
+        ]));
+        // A standby read the first two entries; the first has since been
+        // trimmed from the store
+        let preloaded: Vec<JournalRecord> = {
+            let mut records = store.records.lock().unwrap();
+            let preloaded = records.iter().take(2).cloned().collect();
+            records.remove(0);
+            preloaded
+        };
+        let (attendance_tx, mut attendance_rx) = mpsc::channel(4);
+
+        let (handle, task) = MeetingActor::spawn_with_services(
+            "meeting-journal-preload-test".to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
+            test_secret(),
+            MeetingServices {
+                attendance_tx: Some(attendance_tx),
+                journal: Some(Arc::clone(&store) as Arc<dyn JournalStore>),
+                preloaded_journal: Some(preloaded),
+                ..Default::default()
+            },
+        );
+        handle.end_meeting("host ended".to_string()).await.unwrap();
+
+        let report = tokio::time::timeout(Duration::from_secs(5), attendance_rx.recv())
+            .await
+            .expect("attendance should be flushed")
+            .expect("attendance channel open");
+        let _ = task.await;
+
+        let sessions: Vec<(&str, LeaveReason, i64)> = report
+            .records
+            .iter()
+            .map(|r| (r.participant_id.as_str(), r.reason, r.joined_at_ms))
+            .collect();
+        assert_eq!(
+            sessions,
+            vec![
+                ("old-1", LeaveReason::Voluntary, 1_000),
+                ("old-2", LeaveReason::Timeout, 3_000),
+            ]
+        );
+    }
+
+    // ========================================================================
+    // Polls and Q&A
+    // ========================================================================
+
+    use crate::actors::data_channels::MAX_DATA_CHANNEL_PAYLOAD;
+    use crate::actors::keyframes::VideoStream;
+    use proto_gen::dark_tower::signaling::v1::{self, server_message};
+    use std::sync::Mutex;
+
+    /// In-memory interaction store.
+    #[derive(Default)]
+    struct MemoryInteractionStore {
+        snapshots: Mutex<HashMap<String, String>>,
+    }
+
+    impl InteractionStore for MemoryInteractionStore {
+        fn load_interactions<'a>(
+            &'a self,
+            meeting_id: &'a MeetingId,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = Result<Option<String>, McError>> + Send + 'a>,
+        > {
+            let snapshot = self
+                .snapshots
+                .lock()
+                .unwrap()
+                .get(meeting_id.as_str())
+                .cloned();
+            Box::pin(async move { Ok(snapshot) })
+        }
+
+        fn store_interactions<'a>(
+            &'a self,
+            meeting_id: &'a MeetingId,
+            json: &'a str,
+        ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), McError>> + Send + 'a>>
+        {
+            self.snapshots
+                .lock()
+                .unwrap()
+                .insert(meeting_id.to_string(), json.to_string());
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    fn spawn_with_store(
+        meeting_id: &str,
+        store: Arc<MemoryInteractionStore>,
+    ) -> (MeetingActorHandle, JoinHandle<()>) {
+        MeetingActor::spawn_with_services(
+            meeting_id.to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
+            test_secret(),
+            MeetingServices {
+                interaction_store: Some(store),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Join with a wired stream and return its receiver.
+    async fn join_with_stream(
+        handle: &MeetingActorHandle,
+        n: u32,
+        is_host: bool,
+    ) -> mpsc::Receiver<bytes::Bytes> {
+        let (stream_tx, stream_rx) = mpsc::channel(64);
+        handle
+            .connection_join(
+                format!("conn-{n}"),
+                format!("user-{n}"),
+                format!("part-{n}"),
+                is_host,
+                Some(stream_tx),
+            )
+            .await
+            .unwrap();
+        stream_rx
+    }
+
+    /// Receive the next message, skipping participant updates.
+    async fn next_interaction_message(
+        stream_rx: &mut mpsc::Receiver<bytes::Bytes>,
+    ) -> server_message::Message {
+        loop {
+            let bytes = tokio::time::timeout(Duration::from_secs(1), stream_rx.recv())
+                .await
+                .expect("Timed out waiting for message")
+                .expect("Stream closed");
+            let message = ServerMessage::decode(bytes).unwrap().message.unwrap();
+            if !matches!(
+                message,
+                server_message::Message::ParticipantJoined(_)
+                    | server_message::Message::ParticipantLeft(_)
+            ) {
+                return message;
+            }
+        }
+    }
+
+    fn create_poll_request() -> InteractionRequest {
+        InteractionRequest::CreatePoll {
+            question: "Ship it?".to_string(),
+            options: vec!["Yes".to_string(), "No".to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_interaction_poll_broadcast_and_results() {
+        let (handle, _task) = spawn_with_store(
+            "meeting-poll-test",
+            Arc::new(MemoryInteractionStore::default()),
+        );
+        let mut host_rx = join_with_stream(&handle, 1, true).await;
+        let mut guest_rx = join_with_stream(&handle, 2, false).await;
+
+        handle
+            .interaction("part-1".to_string(), create_poll_request())
+            .await
+            .unwrap();
+
+        let mut poll_id = String::new();
+        for rx in [&mut host_rx, &mut guest_rx] {
+            match next_interaction_message(rx).await {
+                server_message::Message::PollCreated(created) => {
+                    poll_id = created.poll.unwrap().poll_id;
+                }
+                other => panic!("Expected PollCreated, got {other:?}"),
+            }
+        }
+
+        handle
+            .interaction(
+                "part-2".to_string(),
+                InteractionRequest::Vote {
+                    poll_id: poll_id.clone(),
+                    option_index: 0,
+                },
+            )
+            .await
+            .unwrap();
+        handle
+            .interaction(
+                "part-1".to_string(),
+                InteractionRequest::PublishResults { poll_id },
+            )
+            .await
+            .unwrap();
+
+        // The vote itself is not broadcast; the next message is the results
+        match next_interaction_message(&mut guest_rx).await {
+            server_message::Message::PollResults(results) => {
+                assert_eq!(results.vote_counts, vec![1, 0]);
+                assert_eq!(results.total_votes, 1);
+            }
+            other => panic!("Expected PollResults, got {other:?}"),
+        }
 
         handle.cancel();
     }
 
     #[tokio::test]
-    async fn test_meeting_actor_get_state() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
+    async fn test_usage_counts_messages_bytes_and_redis_ops() {
+        let registry = Arc::new(MeetingUsageRegistry::new());
+        let (handle, _task) = MeetingActor::spawn_with_services(
+            "meeting-usage".to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
+            test_secret(),
+            MeetingServices {
+                interaction_store: Some(Arc::new(MemoryInteractionStore::default())),
+                usage_registry: Some(Arc::clone(&registry)),
+                ..Default::default()
+            },
+        );
+        let mut host_rx = join_with_stream(&handle, 1, true).await;
+        let mut guest_rx = join_with_stream(&handle, 2, false).await;
+
+        handle
+            .interaction("part-1".to_string(), create_poll_request())
+            .await
+            .unwrap();
+        for rx in [&mut host_rx, &mut guest_rx] {
+            next_interaction_message(rx).await;
+        }
+        // Round trip so the poll message has been counted
+        handle.get_state().await.unwrap();
+
+        let rates = registry.sample();
+        assert_eq!(rates.len(), 1);
+        let totals = rates[0].totals;
+        assert_eq!(rates[0].meeting_id, "meeting-usage");
+        // Two joins and the poll
+        assert!(totals.messages >= 3, "{totals:?}");
+        // PollCreated fanned out to both participants
+        assert!(totals.bytes > 0, "{totals:?}");
+        // Restore on start and persist after the poll
+        assert_eq!(totals.redis_ops, 2);
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_interaction_host_only_rejected_for_participant() {
+        let (handle, _task) = spawn_with_store(
+            "meeting-poll-forbidden",
+            Arc::new(MemoryInteractionStore::default()),
+        );
+        let mut host_rx = join_with_stream(&handle, 1, true).await;
+        let mut guest_rx = join_with_stream(&handle, 2, false).await;
+
+        handle
+            .interaction("part-2".to_string(), create_poll_request())
+            .await
+            .unwrap();
+
+        match next_interaction_message(&mut guest_rx).await {
+            server_message::Message::Error(err) => {
+                assert_eq!(err.code, v1::ErrorCode::Forbidden as i32);
+            }
+            other => panic!("Expected Error, got {other:?}"),
+        }
+
+        // Nothing was broadcast to the host
+        handle
+            .interaction(
+                "part-2".to_string(),
+                InteractionRequest::AskQuestion {
+                    text: "Can I make polls?".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        assert!(matches!(
+            next_interaction_message(&mut host_rx).await,
+            server_message::Message::QuestionUpdated(_)
+        ));
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_interaction_rate_limited() {
+        let (handle, _task) = spawn_with_store(
+            "meeting-rate-limit",
+            Arc::new(MemoryInteractionStore::default()),
+        );
+        let mut rx = join_with_stream(&handle, 1, false).await;
+
+        for i in 0..=RATE_LIMIT_MAX_ACTIONS {
+            handle
+                .interaction(
+                    "part-1".to_string(),
+                    InteractionRequest::AskQuestion {
+                        text: format!("Question {i}"),
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        for _ in 0..RATE_LIMIT_MAX_ACTIONS {
+            assert!(matches!(
+                next_interaction_message(&mut rx).await,
+                server_message::Message::QuestionUpdated(_)
+            ));
+        }
+        match next_interaction_message(&mut rx).await {
+            server_message::Message::Error(err) => {
+                assert_eq!(err.code, v1::ErrorCode::RateLimited as i32);
+            }
+            other => panic!("Expected Error, got {other:?}"),
+        }
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_interactions_restored_and_sent_to_late_joiner() {
+        let store = Arc::new(MemoryInteractionStore::default());
+
+        // First MC: host creates a poll and a question
+        let (handle, _task) = spawn_with_store("meeting-restore", Arc::clone(&store));
+        let mut host_rx = join_with_stream(&handle, 1, true).await;
+        handle
+            .interaction("part-1".to_string(), create_poll_request())
+            .await
+            .unwrap();
+        handle
+            .interaction(
+                "part-1".to_string(),
+                InteractionRequest::AskQuestion {
+                    text: "Agenda?".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        next_interaction_message(&mut host_rx).await;
+        next_interaction_message(&mut host_rx).await;
+        handle.cancel();
+
+        // Replacement MC restores the snapshot and replays it to a new joiner
+        let (handle, _task) = spawn_with_store("meeting-restore", store);
+        let mut joiner_rx = join_with_stream(&handle, 2, false).await;
+
+        match next_interaction_message(&mut joiner_rx).await {
+            server_message::Message::PollCreated(created) => {
+                assert_eq!(created.poll.unwrap().question, "Ship it?");
+            }
+            other => panic!("Expected PollCreated, got {other:?}"),
+        }
+        match next_interaction_message(&mut joiner_rx).await {
+            server_message::Message::QuestionUpdated(updated) => {
+                assert_eq!(updated.question.unwrap().asked_by, "part-1");
+            }
+            other => panic!("Expected QuestionUpdated, got {other:?}"),
+        }
+
+        handle.cancel();
+    }
+
+    fn data_channel_send(channel_id: &str, payload: &[u8]) -> DataChannelRequest {
+        DataChannelRequest::Send {
+            channel_id: channel_id.to_string(),
+            payload: payload.to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_data_channel_routes_to_subscribers_in_order() {
+        let (handle, _task) = spawn_with_store(
+            "meeting-data-channel",
+            Arc::new(MemoryInteractionStore::default()),
+        );
+        let mut sender_rx = join_with_stream(&handle, 1, false).await;
+        let mut subscriber_rx = join_with_stream(&handle, 2, false).await;
+        let mut bystander_rx = join_with_stream(&handle, 3, false).await;
+
+        for n in [1, 2] {
+            handle
+                .data_channel(
+                    format!("part-{n}"),
+                    DataChannelRequest::Subscribe {
+                        channel_id: "whiteboard".to_string(),
+                    },
+                )
+                .await
+                .unwrap();
+        }
+        for payload in [b"stroke-1", b"stroke-2"] {
+            handle
+                .data_channel(
+                    "part-1".to_string(),
+                    data_channel_send("whiteboard", payload),
+                )
+                .await
+                .unwrap();
+        }
+
+        for (expected_sequence, expected_payload) in [(1, b"stroke-1"), (2, b"stroke-2")] {
+            match next_interaction_message(&mut subscriber_rx).await {
+                server_message::Message::DataChannelMessage(message) => {
+                    assert_eq!(message.channel_id, "whiteboard");
+                    assert_eq!(message.sender_participant_id, "part-1");
+                    assert_eq!(message.sequence, expected_sequence);
+                    assert_eq!(message.payload, expected_payload.to_vec());
+                }
+                other => panic!("Expected DataChannelMessage, got {other:?}"),
+            }
+        }
+
+        // Neither the sender nor the unsubscribed participant received anything
+        handle.cancel();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        for rx in [&mut sender_rx, &mut bystander_rx] {
+            while let Ok(bytes) = rx.try_recv() {
+                let message = ServerMessage::decode(bytes).unwrap().message.unwrap();
+                assert!(
+                    !matches!(message, server_message::Message::DataChannelMessage(_)),
+                    "Unexpected delivery: {message:?}"
+                );
+            }
+        }
+    }
 
-        let (handle, _task) = MeetingActor::spawn(
-            "meeting-state-test".to_string(),
-            cancel_token.clone(),
-            metrics,
-            controller_metrics,
-            test_secret(),
+    #[tokio::test]
+    async fn test_data_channel_oversized_payload_rejected() {
+        let (handle, _task) = spawn_with_store(
+            "meeting-data-channel-limit",
+            Arc::new(MemoryInteractionStore::default()),
         );
+        let mut rx = join_with_stream(&handle, 1, false).await;
 
-        // Join a participant
-        let _ = handle
-            .connection_join(
-                "conn-1".to_string(),
-                "user-1".to_string(),
+        let payload = vec![0u8; MAX_DATA_CHANNEL_PAYLOAD + 1];
+        handle
+            .data_channel(
                 "part-1".to_string(),
-                false,
-                None,
+                data_channel_send("whiteboard", &payload),
             )
-            .await;
+            .await
+            .unwrap();
 
-        let state = handle.get_state().await;
-        assert!(state.is_ok());
-        let state = state.unwrap();
-        assert_eq!(state.meeting_id, "meeting-state-test");
-        assert_eq!(state.participants.len(), 1);
-        assert!(!state.is_shutting_down);
+        match next_interaction_message(&mut rx).await {
+            server_message::Message::Error(err) => {
+                assert_eq!(err.code, v1::ErrorCode::InvalidRequest as i32);
+            }
+            other => panic!("Expected Error, got {other:?}"),
+        }
 
         handle.cancel();
     }
 
     #[tokio::test]
-    async fn test_meeting_actor_leave() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
-
-        let (handle, _task) = MeetingActor::spawn(
-            "meeting-leave-test".to_string(),
-            cancel_token.clone(),
-            metrics,
-            controller_metrics,
-            test_secret(),
+    async fn test_keyframe_requests_forwarded_once_per_cooldown() {
+        let (handle, _task) = spawn_with_store(
+            "meeting-keyframe",
+            Arc::new(MemoryInteractionStore::default()),
         );
+        let mut publisher_rx = join_with_stream(&handle, 1, false).await;
+        let _subscriber_rx = join_with_stream(&handle, 2, false).await;
 
-        // Join a participant
-        let _ = handle
-            .connection_join(
-                "conn-1".to_string(),
-                "user-1".to_string(),
-                "part-1".to_string(),
-                false,
-                None,
+        let request = KeyframeRequest {
+            publisher_id: "part-1".to_string(),
+            stream: VideoStream::Camera,
+        };
+        handle
+            .request_keyframe(
+                KeyframeRequester::Participant("part-2".to_string()),
+                request.clone(),
             )
-            .await;
-
-        // Leave
-        let result = handle.participant_leave("part-1".to_string()).await;
-        assert!(result.is_ok());
+            .await
+            .unwrap();
+        // Within the cooldown: dropped, whoever asks
+        handle
+            .request_keyframe(KeyframeRequester::MediaHandler, request)
+            .await
+            .unwrap();
 
-        // Verify empty
-        let state = handle.get_state().await.unwrap();
-        assert_eq!(state.participants.len(), 0);
+        match next_interaction_message(&mut publisher_rx).await {
+            server_message::Message::KeyframeRequested(requested) => {
+                assert_eq!(requested.stream_type(), v1::StreamType::VideoCamera);
+            }
+            other => panic!("Expected KeyframeRequested, got {other:?}"),
+        }
 
         handle.cancel();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        while let Ok(bytes) = publisher_rx.try_recv() {
+            let message = ServerMessage::decode(bytes).unwrap().message.unwrap();
+            assert!(
+                !matches!(message, server_message::Message::KeyframeRequested(_)),
+                "Unexpected second request: {message:?}"
+            );
+        }
     }
 
-    #[tokio::test]
-    async fn test_meeting_actor_reconnect() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
+    // ========================================================================
+    // State divergence checks
+    // ========================================================================
 
-        let (handle, _task) = MeetingActor::spawn(
-            "meeting-reconnect-test".to_string(),
-            cancel_token.clone(),
-            metrics,
-            controller_metrics,
+    const STATE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+    #[tokio::test(start_paused = true)]
+    async fn test_state_check_restores_lost_interaction_snapshot() {
+        let store = Arc::new(MemoryInteractionStore::default());
+        let (handle, _task) = MeetingActor::spawn_with_services(
+            "meeting-check-interactions".to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
             test_secret(),
+            MeetingServices {
+                interaction_store: Some(Arc::clone(&store) as Arc<dyn InteractionStore>),
+                state_check_interval: Some(STATE_CHECK_INTERVAL),
+                ..Default::default()
+            },
         );
-
-        // Join
-        let join_result = handle
-            .connection_join(
-                "conn-1".to_string(),
-                "user-1".to_string(),
-                "part-1".to_string(),
-                false,
-                None,
-            )
+        let _host_rx = join_with_stream(&handle, 1, true).await;
+        handle
+            .interaction("part-1".to_string(), create_poll_request())
             .await
             .unwrap();
+        handle.get_state().await.unwrap();
 
-        // Disconnect
-        let _ = handle
-            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
-            .await;
+        // The snapshot never made it to Redis
+        let persisted = store
+            .snapshots
+            .lock()
+            .unwrap()
+            .remove("meeting-check-interactions")
+            .expect("poll should be persisted");
 
-        // Reconnect with valid binding token
-        let result = handle
-            .connection_reconnect(
-                "conn-2".to_string(),
-                join_result.correlation_id.clone(),
-                join_result.binding_token.clone(),
-            )
-            .await;
+        tokio::time::sleep(STATE_CHECK_INTERVAL + Duration::from_secs(1)).await;
 
-        assert!(result.is_ok());
-        let reconnect_result = result.unwrap();
-        assert_eq!(reconnect_result.participant_id, "part-1");
-        // New correlation ID should be different (rotation per ADR-0023)
-        assert_ne!(
-            reconnect_result.new_correlation_id,
-            join_result.correlation_id
+        assert_eq!(
+            store
+                .snapshots
+                .lock()
+                .unwrap()
+                .get("meeting-check-interactions"),
+            Some(&persisted)
         );
-
         handle.cancel();
     }
 
-    #[tokio::test]
-    async fn test_meeting_actor_reconnect_invalid_token() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
-
-        let (handle, _task) = MeetingActor::spawn(
-            "meeting-reconnect-invalid".to_string(),
-            cancel_token.clone(),
-            metrics,
-            controller_metrics,
+    #[tokio::test(start_paused = true)]
+    async fn test_state_check_repairs_journal_roster() {
+        let store = Arc::new(MemoryJournalStore::default());
+        let (handle, _task) = MeetingActor::spawn_with_services(
+            "meeting-check-roster".to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
             test_secret(),
+            MeetingServices {
+                journal: Some(Arc::clone(&store) as Arc<dyn JournalStore>),
+                state_check_interval: Some(STATE_CHECK_INTERVAL),
+                ..Default::default()
+            },
+        );
+        let _rx = join_with_stream(&handle, 1, false).await;
+
+        // part-1's join was lost and a leave for ghost-1 never arrived
+        store.records.lock().unwrap().clear();
+        store.push(
+            JournalEvent::Joined {
+                participant_id: "ghost-1".to_string(),
+                user_id: "user-ghost".to_string(),
+                display_name: "Participant 2".to_string(),
+                is_host: false,
+                at_ms: 1_000,
+            }
+            .to_json()
+            .unwrap(),
         );
 
-        // Join
-        let join_result = handle
-            .connection_join(
-                "conn-1".to_string(),
-                "user-1".to_string(),
-                "part-1".to_string(),
-                false,
-                None,
-            )
-            .await
-            .unwrap();
-
-        // Disconnect
-        let _ = handle
-            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
-            .await;
-
-        // Reconnect with invalid binding token
-        let result = handle
-            .connection_reconnect(
-                "conn-2".to_string(),
-                join_result.correlation_id.clone(),
-                "invalid-token".to_string(),
-            )
-            .await;
+        tokio::time::sleep(STATE_CHECK_INTERVAL + Duration::from_secs(1)).await;
 
-        // Should fail with InvalidToken error
+        let records = store.records.lock().unwrap().clone();
+        let open: Vec<String> = journal::replay(&records)
+            .open
+            .into_iter()
+            .map(|session| session.participant_id)
+            .collect();
+        assert_eq!(open, vec!["part-1".to_string()]);
         assert!(matches!(
-            result,
-            Err(McError::SessionBinding(
-                crate::errors::SessionBindingError::InvalidToken
-            ))
+            &store.events()[1],
+            JournalEvent::Left { participant_id, reason, .. }
+                if participant_id == "ghost-1" && reason == "timeout"
         ));
 
+        // In step again: the next check appends nothing
+        let appended = store.events().len();
+        tokio::time::sleep(STATE_CHECK_INTERVAL).await;
+        assert_eq!(store.events().len(), appended);
         handle.cancel();
     }
 
-    #[tokio::test]
-    async fn test_meeting_actor_self_mute() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
-
-        let (handle, _task) = MeetingActor::spawn(
-            "meeting-mute-test".to_string(),
-            cancel_token.clone(),
-            metrics,
-            controller_metrics,
-            test_secret(),
-        );
+    // ========================================================================
+    // Live streaming
+    // ========================================================================
 
-        // Join
-        let _ = handle
-            .connection_join(
-                "conn-1".to_string(),
-                "user-1".to_string(),
-                "part-1".to_string(),
-                false,
-                None,
-            )
-            .await;
+    use crate::grpc::MhEgress;
+    use crate::redis::{MhAssignmentData, MhEndpointInfo};
 
-        // Update mute
-        let result = handle
-            .update_self_mute("part-1".to_string(), true, false)
-            .await;
-        assert!(result.is_ok());
+    /// Egress client that always starts and records stopped egress IDs.
+    #[derive(Default)]
+    struct MockEgressClient {
+        stopped: Mutex<Vec<String>>,
+    }
 
-        // Check state
-        let state = handle.get_state().await.unwrap();
-        let participant = state
-            .participants
-            .iter()
-            .find(|p| p.participant_id == "part-1")
-            .unwrap();
-        assert!(participant.audio_self_muted);
-        assert!(!participant.video_self_muted);
+    impl MhEgressClient for MockEgressClient {
+        fn start_egress<'a>(
+            &'a self,
+            _mh_grpc_endpoint: &'a str,
+            _meeting_id: &'a str,
+            _tile_participant_ids: &'a [String],
+            _output: &'a LiveStreamOutput,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = Result<MhEgress, McError>> + Send + 'a>,
+        > {
+            Box::pin(async {
+                Ok(MhEgress {
+                    egress_id: "egress-1".to_string(),
+                    playback_url: "https://mh-1/hls/egress-1/index.m3u8".to_string(),
+                })
+            })
+        }
 
-        handle.cancel();
+        fn stop_egress<'a>(
+            &'a self,
+            _mh_grpc_endpoint: &'a str,
+            _meeting_id: &'a str,
+            egress_id: &'a str,
+        ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<u64, McError>> + Send + 'a>>
+        {
+            self.stopped.lock().unwrap().push(egress_id.to_string());
+            Box::pin(async { Ok(0) })
+        }
     }
 
-    #[tokio::test]
-    async fn test_meeting_actor_host_mute() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
+    /// Assignment store that assigns every meeting to one MH.
+    struct SingleMhAssignment;
+
+    impl MhAssignmentStore for SingleMhAssignment {
+        fn get_mh_assignment<'a>(
+            &'a self,
+            _meeting_id: &'a MeetingId,
+        ) -> std::pin::Pin<
+            Box<
+                dyn std::future::Future<Output = Result<Option<MhAssignmentData>, McError>>
+                    + Send
+                    + 'a,
+            >,
+        > {
+            Box::pin(async {
+                Ok(Some(MhAssignmentData {
+                    handlers: vec![MhEndpointInfo {
+                        mh_id: "mh-1".to_string(),
+                        webtransport_endpoint: "https://mh-1:4434".to_string(),
+                        grpc_endpoint: "http://mh-1:50053".to_string(),
+                    }],
+                    assigned_at: String::new(),
+                }))
+            })
+        }
+    }
 
-        let (handle, _task) = MeetingActor::spawn(
-            "meeting-host-mute-test".to_string(),
-            cancel_token.clone(),
-            metrics,
-            controller_metrics,
+    fn spawn_with_egress(
+        meeting_id: &str,
+        egress_client: Arc<MockEgressClient>,
+    ) -> (MeetingActorHandle, JoinHandle<()>) {
+        MeetingActor::spawn_with_services(
+            meeting_id.to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
             test_secret(),
-        );
+            MeetingServices {
+                egress_client: Some(egress_client),
+                mh_assignments: Some(Arc::new(SingleMhAssignment)),
+                ..Default::default()
+            },
+        )
+    }
 
-        // Join host (part-1) and non-host (part-2)
-        let _ = handle
-            .connection_join(
-                "conn-1".to_string(),
-                "user-1".to_string(),
+    async fn next_live_stream_status(
+        stream_rx: &mut mpsc::Receiver<bytes::Bytes>,
+    ) -> v1::LiveStreamStatus {
+        match next_interaction_message(stream_rx).await {
+            server_message::Message::LiveStreamStatus(status) => status,
+            other => panic!("Expected LiveStreamStatus, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_live_stream_host_start_and_stop() {
+        let egress_client = Arc::new(MockEgressClient::default());
+        let (handle, _task) = spawn_with_egress("meeting-live", Arc::clone(&egress_client));
+        let _host_rx = join_with_stream(&handle, 1, true).await;
+        let mut guest_rx = join_with_stream(&handle, 2, false).await;
+
+        handle
+            .live_stream(
                 "part-1".to_string(),
-                true, // host
-                None,
-            )
-            .await;
-        let _ = handle
-            .connection_join(
-                "conn-2".to_string(),
-                "user-2".to_string(),
-                "part-2".to_string(),
-                false, // not host
-                None,
+                LiveStreamRequest::Start {
+                    tile_participant_ids: vec!["part-1".to_string(), "part-2".to_string()],
+                    output: LiveStreamOutput::Hls {
+                        segment_duration_seconds: 0,
+                    },
+                },
             )
-            .await;
+            .await
+            .unwrap();
 
-        // Host mutes part-2
-        let result = handle
-            .host_mute("part-2".to_string(), "part-1".to_string(), true, false)
-            .await;
-        assert!(result.is_ok());
+        let status = next_live_stream_status(&mut guest_rx).await;
+        assert_eq!(status.state, v1::LiveStreamState::Starting as i32);
+        let status = next_live_stream_status(&mut guest_rx).await;
+        assert_eq!(status.state, v1::LiveStreamState::Live as i32);
+        assert_eq!(status.playback_url, "https://mh-1/hls/egress-1/index.m3u8");
 
-        // Check state
-        let state = handle.get_state().await.unwrap();
-        let participant = state
-            .participants
-            .iter()
-            .find(|p| p.participant_id == "part-2")
+        // A late joiner learns the stream is live
+        let mut late_rx = join_with_stream(&handle, 3, false).await;
+        let status = next_live_stream_status(&mut late_rx).await;
+        assert_eq!(status.state, v1::LiveStreamState::Live as i32);
+
+        handle
+            .live_stream("part-1".to_string(), LiveStreamRequest::Stop)
+            .await
             .unwrap();
-        assert!(participant.audio_host_muted);
-        assert!(!participant.video_host_muted);
+        let status = next_live_stream_status(&mut guest_rx).await;
+        assert_eq!(status.state, v1::LiveStreamState::Stopped as i32);
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(
+            *egress_client.stopped.lock().unwrap(),
+            vec!["egress-1".to_string()]
+        );
 
         handle.cancel();
     }
 
     #[tokio::test]
-    async fn test_meeting_actor_host_mute_denied_for_non_host() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
-
-        let (handle, _task) = MeetingActor::spawn(
-            "meeting-host-mute-denied".to_string(),
-            cancel_token.clone(),
-            metrics,
-            controller_metrics,
-            test_secret(),
-        );
+    async fn test_live_stream_denied_for_non_host() {
+        let egress_client = Arc::new(MockEgressClient::default());
+        let (handle, _task) = spawn_with_egress("meeting-live-denied", egress_client);
+        let mut guest_rx = join_with_stream(&handle, 1, false).await;
 
-        // Join two non-host participants
-        let _ = handle
-            .connection_join(
-                "conn-1".to_string(),
-                "user-1".to_string(),
+        handle
+            .live_stream(
                 "part-1".to_string(),
-                false, // not host
-                None,
-            )
-            .await;
-        let _ = handle
-            .connection_join(
-                "conn-2".to_string(),
-                "user-2".to_string(),
-                "part-2".to_string(),
-                false, // not host
-                None,
+                LiveStreamRequest::Start {
+                    tile_participant_ids: Vec::new(),
+                    output: LiveStreamOutput::Rtmp {
+                        url: "rtmp://live.example.com/app/key".to_string(),
+                    },
+                },
             )
-            .await;
+            .await
+            .unwrap();
 
-        // Non-host tries to mute part-2 - should fail
-        let result = handle
-            .host_mute("part-2".to_string(), "part-1".to_string(), true, false)
-            .await;
-        assert!(matches!(result, Err(McError::PermissionDenied(_))));
+        match next_interaction_message(&mut guest_rx).await {
+            server_message::Message::Error(err) => {
+                assert_eq!(err.code, v1::ErrorCode::Forbidden as i32);
+            }
+            other => panic!("Expected Error, got {other:?}"),
+        }
 
         handle.cancel();
     }
 
-    #[tokio::test]
-    async fn test_meeting_actor_child_token() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
+    async fn next_recording_notice(
+        stream_rx: &mut mpsc::Receiver<bytes::Bytes>,
+    ) -> v1::RecordingStateChanged {
+        match next_interaction_message(stream_rx).await {
+            server_message::Message::RecordingStateChanged(state) => state,
+            other => panic!("Expected RecordingStateChanged, got {other:?}"),
+        }
+    }
 
-        let (handle, _task) = MeetingActor::spawn(
-            "meeting-token-test".to_string(),
-            cancel_token.clone(),
-            metrics,
-            controller_metrics,
+    fn spawn_default(meeting_id: &str) -> (MeetingActorHandle, JoinHandle<()>) {
+        MeetingActor::spawn(
+            meeting_id.to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
             test_secret(),
-        );
-
-        let child = handle.child_token();
-        assert!(!child.is_cancelled());
+        )
+    }
 
-        handle.cancel();
+    #[tokio::test]
+    async fn test_recording_consent_notice_and_decline() {
+        let (handle, _task) = spawn_default("meeting-recording");
+        let mut host_rx = join_with_stream(&handle, 1, true).await;
+        let mut guest_rx = join_with_stream(&handle, 2, false).await;
 
-        // Give time for cancellation to propagate
-        tokio::time::sleep(Duration::from_millis(10)).await;
+        handle
+            .recording("part-1".to_string(), RecordingRequest::Start)
+            .await
+            .unwrap();
 
-        assert!(child.is_cancelled());
-    }
+        let host_notice = next_recording_notice(&mut host_rx).await;
+        assert!(host_notice.recording);
+        assert!(!host_notice.consent_required);
+        let guest_notice = next_recording_notice(&mut guest_rx).await;
+        assert!(guest_notice.consent_required);
+        assert!(guest_notice.consent_deadline > guest_notice.started_at);
 
-    /// Test that the 30-second disconnect grace period expires correctly.
-    /// Uses `tokio::time::pause()` to control time advancement.
-    #[tokio::test(start_paused = true)]
-    async fn test_disconnect_grace_period_expires() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
+        // A participant joining mid-recording is asked too
+        let mut late_rx = join_with_stream(&handle, 3, false).await;
+        assert!(next_recording_notice(&mut late_rx).await.consent_required);
 
-        let (handle, _task) = MeetingActor::spawn(
-            "meeting-grace-period-test".to_string(),
-            cancel_token.clone(),
-            metrics,
-            controller_metrics,
-            test_secret(),
-        );
+        // Only hosts can stop recording
+        handle
+            .recording("part-3".to_string(), RecordingRequest::Stop)
+            .await
+            .unwrap();
+        match next_interaction_message(&mut late_rx).await {
+            server_message::Message::Error(err) => {
+                assert_eq!(err.code, v1::ErrorCode::Forbidden as i32);
+            }
+            other => panic!("Expected Error, got {other:?}"),
+        }
 
-        // Join a participant
-        let join_result = handle
-            .connection_join(
-                "conn-1".to_string(),
-                "user-1".to_string(),
-                "part-1".to_string(),
-                false,
-                None,
+        // Declining removes the participant
+        handle
+            .recording(
+                "part-2".to_string(),
+                RecordingRequest::Consent { accepted: false },
             )
             .await
             .unwrap();
-
-        assert_eq!(join_result.participant_id, "part-1");
-
-        // Verify participant is in the meeting
         let state = handle.get_state().await.unwrap();
-        assert_eq!(state.participants.len(), 1);
-        assert_eq!(state.participants[0].status, ParticipantStatus::Connected);
+        assert_eq!(state.participants.len(), 2);
+        assert!(state
+            .participants
+            .iter()
+            .all(|p| p.participant_id != "part-2"));
 
-        // Disconnect the participant
-        let _ = handle
-            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
-            .await;
+        handle.cancel();
+    }
 
-        // Give actor time to process the disconnect message
+    #[tokio::test(start_paused = true)]
+    async fn test_recording_consent_timeout_removes_participant() {
+        let (handle, _task) = spawn_default("meeting-recording-timeout");
+        let _host_rx = join_with_stream(&handle, 1, true).await;
+        let _guest_rx = join_with_stream(&handle, 2, false).await;
+        let _consenting_rx = join_with_stream(&handle, 3, false).await;
+
+        handle
+            .recording("part-1".to_string(), RecordingRequest::Start)
+            .await
+            .unwrap();
+        handle
+            .recording(
+                "part-3".to_string(),
+                RecordingRequest::Consent { accepted: true },
+            )
+            .await
+            .unwrap();
+
+        tokio::time::advance(Duration::from_secs(65)).await;
         tokio::time::sleep(Duration::from_millis(10)).await;
 
-        // Verify participant is disconnected but still in the meeting
         let state = handle.get_state().await.unwrap();
-        assert_eq!(state.participants.len(), 1);
-        assert_eq!(
-            state.participants[0].status,
-            ParticipantStatus::Disconnected
-        );
-
-        // Advance time by 29 seconds - participant should still be present
-        tokio::time::advance(Duration::from_secs(29)).await;
+        let mut remaining: Vec<&str> = state
+            .participants
+            .iter()
+            .map(|p| p.participant_id.as_str())
+            .collect();
+        remaining.sort_unstable();
+        assert_eq!(remaining, vec!["part-1", "part-3"]);
 
-        // Give actor time for the grace check interval to tick
-        tokio::time::sleep(Duration::from_millis(10)).await;
+        handle.cancel();
+    }
 
-        let state = handle.get_state().await.unwrap();
-        assert_eq!(
-            state.participants.len(),
-            1,
-            "Participant should still be present before grace period expires"
+    #[tokio::test]
+    async fn test_e2e_meeting_rejects_server_side_processing() {
+        let (handle, _task) = MeetingActor::spawn_with_services(
+            "meeting-e2e".to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
+            test_secret(),
+            MeetingServices {
+                settings: MeetingSettings {
+                    e2e_enabled: true,
+                    ..MeetingSettings::default()
+                },
+                ..MeetingServices::default()
+            },
         );
+        let mut host_rx = join_with_stream(&handle, 1, true).await;
 
-        // Advance time past the 30-second grace period (total now > 30s)
-        tokio::time::advance(Duration::from_secs(6)).await;
-
-        // Wait for the grace check interval (every 5 seconds) to process
-        tokio::time::sleep(Duration::from_millis(10)).await;
+        handle
+            .recording("part-1".to_string(), RecordingRequest::Start)
+            .await
+            .unwrap();
+        match next_interaction_message(&mut host_rx).await {
+            server_message::Message::Error(err) => {
+                assert_eq!(err.code, v1::ErrorCode::E2eUnsupported as i32);
+            }
+            other => panic!("Expected Error, got {other:?}"),
+        }
 
-        // Verify participant has been removed
-        let state = handle.get_state().await.unwrap();
-        assert_eq!(
-            state.participants.len(),
-            0,
-            "Participant should be removed after grace period expires"
-        );
+        handle
+            .live_stream(
+                "part-1".to_string(),
+                LiveStreamRequest::Start {
+                    tile_participant_ids: Vec::new(),
+                    output: LiveStreamOutput::Hls {
+                        segment_duration_seconds: 0,
+                    },
+                },
+            )
+            .await
+            .unwrap();
+        match next_interaction_message(&mut host_rx).await {
+            server_message::Message::Error(err) => {
+                assert_eq!(err.code, v1::ErrorCode::E2eUnsupported as i32);
+            }
+            other => panic!("Expected Error, got {other:?}"),
+        }
 
         handle.cancel();
     }
 
-    /// Test that reconnection within grace period preserves participant.
-    #[tokio::test(start_paused = true)]
-    async fn test_reconnect_within_grace_period() {
-        let metrics = ActorMetrics::new();
-        let controller_metrics = ControllerMetrics::new();
-        let cancel_token = CancellationToken::new();
+    // ========================================================================
+    // Duplicate joins
+    // ========================================================================
 
-        let (handle, _task) = MeetingActor::spawn(
-            "meeting-reconnect-grace-test".to_string(),
-            cancel_token.clone(),
-            metrics,
-            controller_metrics,
+    fn spawn_with_duplicate_policy(
+        meeting_id: &str,
+        policy: DuplicateJoinPolicy,
+    ) -> (MeetingActorHandle, JoinHandle<()>) {
+        MeetingActor::spawn_with_services(
+            meeting_id.to_string(),
+            CancellationToken::new(),
+            ActorMetrics::new(),
+            ControllerMetrics::new(),
             test_secret(),
-        );
+            MeetingServices {
+                settings: MeetingSettings {
+                    duplicate_join_policy: policy,
+                    ..MeetingSettings::default()
+                },
+                ..MeetingServices::default()
+            },
+        )
+    }
 
-        // Join a participant
-        let join_result = handle
+    /// Join as `user-same` from connection `n`.
+    async fn join_same_user(
+        handle: &MeetingActorHandle,
+        n: u32,
+    ) -> (Result<JoinResult, McError>, mpsc::Receiver<bytes::Bytes>) {
+        let (stream_tx, stream_rx) = mpsc::channel(64);
+        let result = handle
             .connection_join(
-                "conn-1".to_string(),
-                "user-1".to_string(),
-                "part-1".to_string(),
+                format!("conn-{n}"),
+                "user-same".to_string(),
+                format!("part-{n}"),
                 false,
-                None,
+                Some(stream_tx),
             )
-            .await
-            .unwrap();
-
-        // Disconnect the participant
-        let _ = handle
-            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
             .await;
+        (result, stream_rx)
+    }
 
-        // Give actor time to process
-        tokio::time::sleep(Duration::from_millis(10)).await;
+    #[test]
+    fn test_duplicate_join_policy_from_proto() {
+        use internal::DuplicateJoinPolicy as Proto;
+        assert_eq!(
+            DuplicateJoinPolicy::from_proto(Proto::Unspecified as i32),
+            DuplicateJoinPolicy::Allow
+        );
+        assert_eq!(
+            DuplicateJoinPolicy::from_proto(Proto::Takeover as i32),
+            DuplicateJoinPolicy::Takeover
+        );
+        assert_eq!(
+            DuplicateJoinPolicy::from_proto(Proto::Reject as i32),
+            DuplicateJoinPolicy::Reject
+        );
+        assert_eq!(
+            DuplicateJoinPolicy::from_proto(99),
+            DuplicateJoinPolicy::Allow
+        );
+    }
 
-        // Advance time by 20 seconds (within grace period)
-        tokio::time::advance(Duration::from_secs(20)).await;
+    #[tokio::test]
+    async fn test_duplicate_join_takeover_closes_earlier_connection() {
+        let (handle, _task) =
+            spawn_with_duplicate_policy("meeting-dup-takeover", DuplicateJoinPolicy::Takeover);
+        let mut observer_rx = join_with_stream(&handle, 9, false).await;
+        let (first, mut first_rx) = join_same_user(&handle, 1).await;
+        let first = first.unwrap();
+        let (second, _second_rx) = join_same_user(&handle, 2).await;
+        let second = second.unwrap();
+        assert!(second
+            .participants
+            .iter()
+            .all(|p| p.participant_id != "part-1"));
 
-        // Reconnect with valid token
-        let reconnect_result = handle
+        // The earlier connection is told why, then its stream closes
+        match next_interaction_message(&mut first_rx).await {
+            server_message::Message::Error(err) => {
+                assert_eq!(err.code, v1::ErrorCode::SessionReplaced as i32);
+                assert_eq!(err.dt_code, "DT-MC-2023");
+            }
+            other => panic!("Expected Error, got {other:?}"),
+        }
+        match next_interaction_message(&mut first_rx).await {
+            server_message::Message::ConnectionClosed(closed) => {
+                assert_eq!(closed.reason(), CloseReason::Takeover);
+            }
+            other => panic!("Expected ConnectionClosed, got {other:?}"),
+        }
+        let closed = tokio::time::timeout(Duration::from_secs(1), async {
+            while first_rx.recv().await.is_some() {}
+        })
+        .await;
+        assert!(closed.is_ok(), "replaced stream should close");
+
+        // Others see the earlier participant leave as replaced
+        let left = loop {
+            let bytes = tokio::time::timeout(Duration::from_secs(1), observer_rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            if let Some(server_message::Message::ParticipantLeft(left)) =
+                ServerMessage::decode(bytes).unwrap().message
+            {
+                break left;
+            }
+        };
+        assert_eq!(left.participant_id, first.participant_id);
+        assert_eq!(left.reason, v1::LeaveReason::Replaced as i32);
+
+        // Its binding no longer resumes anything
+        let reconnect = handle
             .connection_reconnect(
-                "conn-2".to_string(),
-                join_result.correlation_id.clone(),
-                join_result.binding_token.clone(),
+                "conn-3".to_string(),
+                "user-same".to_string(),
+                first.correlation_id,
+                first.binding_token,
+                None,
             )
             .await;
+        assert!(matches!(reconnect, Err(McError::SessionBinding(_))));
 
-        assert!(reconnect_result.is_ok());
-        let reconnect_result = reconnect_result.unwrap();
-        assert_eq!(reconnect_result.participant_id, "part-1");
+        let state = handle.get_state().await.unwrap();
+        let ids: Vec<_> = state
+            .participants
+            .iter()
+            .map(|p| p.participant_id.as_str())
+            .collect();
+        assert_eq!(ids.len(), 2);
+        assert!(ids.contains(&"part-2") && ids.contains(&"part-9"));
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_join_reject_refuses_while_connected() {
+        let (handle, _task) =
+            spawn_with_duplicate_policy("meeting-dup-reject", DuplicateJoinPolicy::Reject);
+        let (first, _first_rx) = join_same_user(&handle, 1).await;
+        first.unwrap();
+
+        let (second, _second_rx) = join_same_user(&handle, 2).await;
+        assert!(matches!(second, Err(McError::Conflict(_))));
+        assert_eq!(handle.get_state().await.unwrap().participants.len(), 1);
+
+        // Once the first connection drops, the user can join from elsewhere
+        // without waiting out the grace period
+        let _ = handle
+            .connection_disconnected("conn-1".to_string(), "part-1".to_string())
+            .await;
+        let (third, _third_rx) = join_same_user(&handle, 3).await;
+        assert_eq!(third.unwrap().participant_id, "part-3");
 
-        // Verify participant is connected again
         let state = handle.get_state().await.unwrap();
         assert_eq!(state.participants.len(), 1);
-        assert_eq!(state.participants[0].status, ParticipantStatus::Connected);
+        assert_eq!(state.participants[0].participant_id, "part-3");
+
+        handle.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_join_allowed_by_default() {
+        let (handle, _task) = spawn_default("meeting-dup-allow");
+        let (first, _first_rx) = join_same_user(&handle, 1).await;
+        first.unwrap();
+        let (second, _second_rx) = join_same_user(&handle, 2).await;
+        second.unwrap();
+
+        assert_eq!(handle.get_state().await.unwrap().participants.len(), 2);
 
         handle.cancel();
     }
+
+    #[tokio::test]
+    async fn test_end_meeting_closes_connections_with_reason() {
+        let (handle, _task) = spawn_default("meeting-end-close");
+        let mut stream_rx = join_with_stream(&handle, 1, true).await;
+
+        handle.end_meeting("host ended".to_string()).await.unwrap();
+
+        match next_interaction_message(&mut stream_rx).await {
+            server_message::Message::ConnectionClosed(closed) => {
+                assert_eq!(closed.reason(), CloseReason::MeetingEnded);
+            }
+            other => panic!("Expected ConnectionClosed, got {other:?}"),
+        }
+    }
 }
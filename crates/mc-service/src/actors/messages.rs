@@ -3,8 +3,16 @@
 //! All inter-actor communication uses strongly-typed message passing via `tokio::sync::mpsc`.
 //! Response patterns use `tokio::sync::oneshot` for request-reply semantics.
 
+use super::data_channels::DataChannelRequest;
+use super::interactions::InteractionRequest;
+use super::keyframes::{KeyframeRequest, KeyframeRequester};
+use super::live_stream::{ActiveEgress, LiveStreamRequest};
+use super::meeting::{MeetingActorHandle, MeetingSettings};
 use super::participant::ParticipantActorHandle;
+use super::recording::{RecordingLayout, RecordingRequest};
 use crate::errors::McError;
+use crate::redis::JournalRecord;
+use proto_gen::dark_tower::signaling::v1::CloseReason;
 use std::time::Duration;
 use tokio::sync::oneshot;
 
@@ -14,6 +22,10 @@ pub enum ControllerMessage {
     /// Create a new meeting actor for the given meeting ID.
     CreateMeeting {
         meeting_id: String,
+        /// Settings from the GC assignment.
+        settings: MeetingSettings,
+        /// Journal entries pre-loaded as a warm standby (`None` otherwise).
+        preloaded_journal: Option<Vec<JournalRecord>>,
         /// Response channel for the meeting actor handle or error.
         respond_to: oneshot::Sender<Result<(), McError>>,
     },
@@ -50,10 +62,32 @@ pub enum ControllerMessage {
         is_host: bool,
         /// Sender for writing framed protobuf bytes to the WebTransport stream.
         stream_tx: tokio::sync::mpsc::Sender<bytes::Bytes>,
+        /// Session the client asks to resume, if it is reconnecting.
+        resume: Option<SessionResume>,
         /// Response channel sent back to the WebTransport connection actor.
         respond_to: oneshot::Sender<Result<JoinResult, McError>>,
     },
 
+    /// Route an MH keyframe request to the meeting it belongs to.
+    ///
+    /// The controller only looks up the meeting; forwarding and
+    /// deduplication happen in the `MeetingActor`.
+    RequestKeyframe {
+        meeting_id: String,
+        request: KeyframeRequest,
+        /// Response channel; `MeetingNotFound` if the meeting is not here.
+        respond_to: oneshot::Sender<Result<(), McError>>,
+    },
+
+    /// Apply an operator directive pushed by GC on the heartbeat stream.
+    ApplyDirective {
+        /// GC's ID for the directive, for the audit log.
+        directive_id: String,
+        directive: OperatorDirective,
+        /// Response channel for the outcome.
+        respond_to: oneshot::Sender<Result<(), McError>>,
+    },
+
     /// Initiate graceful shutdown (SIGTERM received).
     Shutdown {
         /// Deadline for shutdown.
@@ -75,6 +109,9 @@ pub enum MeetingMessage {
         is_host: bool,
         /// Sender for writing framed protobuf bytes to the WebTransport stream.
         stream_tx: Option<tokio::sync::mpsc::Sender<bytes::Bytes>>,
+        /// Session to resume instead of joining fresh. An invalid or expired
+        /// binding falls back to a fresh join (ADR-0023 Section 1).
+        resume: Option<SessionResume>,
         /// Response channel for join result.
         respond_to: oneshot::Sender<Result<JoinResult, McError>>,
     },
@@ -88,8 +125,12 @@ pub enum MeetingMessage {
     /// A connection is attempting to reconnect.
     ConnectionReconnect {
         connection_id: String,
+        /// User ID from the fresh JWT; must match the original binding.
+        user_id: String,
         correlation_id: String,
         binding_token: String,
+        /// Sender for writing framed protobuf bytes to the WebTransport stream.
+        stream_tx: Option<tokio::sync::mpsc::Sender<bytes::Bytes>>,
         /// Response channel for reconnect result.
         respond_to: oneshot::Sender<Result<ReconnectResult, McError>>,
     },
@@ -107,6 +148,48 @@ pub enum MeetingMessage {
         message: SignalingPayload,
     },
 
+    /// Poll or Q&A request from a participant (fire-and-forget; the result is
+    /// broadcast, or an error is sent back to the requester).
+    Interaction {
+        participant_id: String,
+        request: InteractionRequest,
+    },
+
+    /// Application data channel request from a participant (fire-and-forget;
+    /// errors are sent back to the requester).
+    DataChannel {
+        participant_id: String,
+        request: DataChannelRequest,
+    },
+
+    /// Live stream request from a participant (fire-and-forget; status is
+    /// broadcast, or an error is sent back to the requester).
+    LiveStream {
+        participant_id: String,
+        request: LiveStreamRequest,
+    },
+
+    /// Recording request or consent answer from a participant
+    /// (fire-and-forget; state changes are sent as notices, or an error is
+    /// sent back to the requester).
+    Recording {
+        participant_id: String,
+        request: RecordingRequest,
+    },
+
+    /// Keyframe request for a publisher's video stream (fire-and-forget;
+    /// forwarded to the publisher unless the stream is in its cooldown).
+    RequestKeyframe {
+        requester: KeyframeRequester,
+        request: KeyframeRequest,
+    },
+
+    /// Outcome of a `StartEgress` call made on the actor's behalf: the
+    /// running egress and its playback URL.
+    LiveStreamStarted {
+        result: Result<(ActiveEgress, String), McError>,
+    },
+
     /// Get current meeting state (for debugging/health).
     GetState {
         /// Response channel for meeting state.
@@ -147,8 +230,8 @@ pub enum ParticipantMessage {
     /// Notify participant of a state change.
     ParticipantUpdate { update: ParticipantStateUpdate },
 
-    /// Close the participant actor gracefully.
-    Close { reason: String },
+    /// Close the participant actor gracefully, telling the client why.
+    Close { reason: CloseReason },
 
     /// Ping the participant actor to check liveness.
     Ping { respond_to: oneshot::Sender<()> },
@@ -184,6 +267,37 @@ pub struct ControllerStatus {
     pub mailbox_depth: usize,
 }
 
+/// An operator directive for the `MeetingControllerActor`.
+///
+/// Heartbeat interval changes are also pushed by GC but are applied by the
+/// GC task, which owns the heartbeat timers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OperatorDirective {
+    /// Stop (`true`) or resume (`false`) accepting new meetings.
+    Drain { draining: bool },
+    /// Limits below the configured ones (`None` = configured limit). New
+    /// meetings and fresh joins over a limit are rejected; nothing already
+    /// running is evicted.
+    SoftLimits {
+        max_meetings: Option<u32>,
+        max_participants: Option<u32>,
+    },
+    /// End a meeting on this MC.
+    EndMeeting { meeting_id: String, reason: String },
+}
+
+impl OperatorDirective {
+    /// Short name for logs.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Drain { .. } => "drain",
+            Self::SoftLimits { .. } => "soft_limits",
+            Self::EndMeeting { .. } => "end_meeting",
+        }
+    }
+}
+
 /// Result of a successful join.
 #[derive(Debug, Clone)]
 pub struct JoinResult {
@@ -199,6 +313,26 @@ pub struct JoinResult {
     pub fencing_generation: u64,
     /// Handle to the spawned ParticipantActor.
     pub participant_handle: ParticipantActorHandle,
+    /// Handle to the meeting, for forwarding post-join client requests.
+    pub meeting_handle: MeetingActorHandle,
+    /// Whether the meeting's media is end-to-end encrypted.
+    pub e2e_enabled: bool,
+    /// Recording layout for MH registration.
+    pub recording_layout: RecordingLayout,
+    /// Whether an existing session was resumed rather than a new one started.
+    pub resumed: bool,
+}
+
+/// Session a reconnecting client asks to resume, from its `JoinRequest`.
+#[derive(Debug, Clone)]
+pub struct SessionResume {
+    /// Correlation ID from the previous `JoinResponse`.
+    pub correlation_id: String,
+    /// Binding token from the previous `JoinResponse`.
+    pub binding_token: String,
+    /// Join as a new participant if the binding is rejected. Off for a
+    /// resume sent as 0-RTT data, which may replay one that already happened.
+    pub join_if_rejected: bool,
 }
 
 /// Result of a successful reconnection.
@@ -212,6 +346,33 @@ pub struct ReconnectResult {
     pub new_binding_token: String,
     /// Current participant list.
     pub participants: Vec<ParticipantInfo>,
+    /// Current fencing generation.
+    pub fencing_generation: u64,
+    /// Handle to the new ParticipantActor.
+    pub participant_handle: ParticipantActorHandle,
+    /// Handle to the meeting, for forwarding post-join client requests.
+    pub meeting_handle: MeetingActorHandle,
+    /// Whether the meeting's media is end-to-end encrypted.
+    pub e2e_enabled: bool,
+    /// Recording layout for MH registration.
+    pub recording_layout: RecordingLayout,
+}
+
+impl From<ReconnectResult> for JoinResult {
+    fn from(result: ReconnectResult) -> Self {
+        Self {
+            participant_id: result.participant_id,
+            correlation_id: result.new_correlation_id,
+            binding_token: result.new_binding_token,
+            participants: result.participants,
+            fencing_generation: result.fencing_generation,
+            participant_handle: result.participant_handle,
+            meeting_handle: result.meeting_handle,
+            e2e_enabled: result.e2e_enabled,
+            recording_layout: result.recording_layout,
+            resumed: true,
+        }
+    }
 }
 
 /// Information about a participant.
@@ -281,6 +442,76 @@ pub enum LeaveReason {
     Removed,
     /// Meeting ended.
     MeetingEnded,
+    /// Same user joined again from another connection (duplicate join takeover).
+    Replaced,
+}
+
+impl LeaveReason {
+    /// Wire name used in attendance reports to GC.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Voluntary => "voluntary",
+            Self::Timeout => "timeout",
+            Self::Removed => "removed",
+            Self::MeetingEnded => "meeting_ended",
+            Self::Replaced => "replaced",
+        }
+    }
+
+    /// Parse a wire name from [`LeaveReason::as_str`].
+    #[must_use]
+    pub fn from_wire(name: &str) -> Option<Self> {
+        [
+            Self::Voluntary,
+            Self::Timeout,
+            Self::Removed,
+            Self::MeetingEnded,
+            Self::Replaced,
+        ]
+        .into_iter()
+        .find(|reason| reason.as_str() == name)
+    }
+}
+
+/// One completed participant session within a meeting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttendanceEntry {
+    /// Participant ID.
+    pub participant_id: String,
+    /// User ID (from JWT).
+    pub user_id: String,
+    /// Display name at join time.
+    pub display_name: String,
+    /// Join time (Unix milliseconds).
+    pub joined_at_ms: i64,
+    /// Leave time (Unix milliseconds).
+    pub left_at_ms: i64,
+    /// Why the session ended.
+    pub reason: LeaveReason,
+}
+
+/// Attendance log flushed by a `MeetingActor` when it stops.
+#[derive(Debug, Clone)]
+pub struct MeetingAttendance {
+    /// Meeting ID.
+    pub meeting_id: String,
+    /// Completed sessions in leave order.
+    pub records: Vec<AttendanceEntry>,
+    /// Total whole seconds the meeting was live streamed.
+    pub live_stream_seconds: u64,
+    /// Set when the meeting ended, as opposed to the MC shutting down with
+    /// the meeting still live; GC is then told the meeting is over.
+    pub ended: Option<MeetingEndSummary>,
+}
+
+/// Meeting-level telemetry sent to GC with `NotifyMeetingEnded`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MeetingEndSummary {
+    /// Most participants in the meeting at once.
+    pub peak_participants: u32,
+    /// Join attempts the meeting refused.
+    pub join_failures: u32,
 }
 
 /// Current state of a meeting (for debugging/health).
@@ -336,6 +567,18 @@ mod tests {
         assert_ne!(LeaveReason::Voluntary, LeaveReason::Timeout);
     }
 
+    #[test]
+    fn test_leave_reason_wire_names() {
+        assert_eq!(LeaveReason::Voluntary.as_str(), "voluntary");
+        assert_eq!(LeaveReason::Timeout.as_str(), "timeout");
+        assert_eq!(LeaveReason::Removed.as_str(), "removed");
+        assert_eq!(LeaveReason::MeetingEnded.as_str(), "meeting_ended");
+        for reason in [LeaveReason::Voluntary, LeaveReason::Replaced] {
+            assert_eq!(LeaveReason::from_wire(reason.as_str()), Some(reason));
+        }
+        assert_eq!(LeaveReason::from_wire("kicked"), None);
+    }
+
     #[test]
     fn test_controller_status_default_values() {
         let status = ControllerStatus {
@@ -16,7 +16,12 @@
 //! - `MailboxMonitor` updates `mc_actor_mailbox_depth`, `mc_messages_dropped_total`
 //! - `ControllerMetrics` is for GC heartbeat reporting only (no Prometheus emission)
 
-use std::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
+// Unit tests built with `--cfg loom` swap in loom's model-checked atomics;
+// see `loom_tests` below.
+#[cfg(all(test, loom))]
+use loom::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+#[cfg(not(all(test, loom)))]
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use tracing::{debug, warn};
 
@@ -84,6 +89,13 @@ pub enum MailboxLevel {
     Critical,
 }
 
+/// Decrement `counter` without wrapping below zero, returning the new value.
+fn saturating_decrement(counter: &AtomicUsize) -> usize {
+    counter
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+        .map_or(0, |previous| previous - 1)
+}
+
 /// Mailbox monitor for tracking queue depth and emitting metrics.
 #[derive(Debug)]
 pub struct MailboxMonitor {
@@ -119,21 +131,10 @@ impl MailboxMonitor {
     ///
     /// Updates internal tracking and emits to Prometheus gauge.
     pub fn record_enqueue(&self) {
-        let new_depth = self.depth.fetch_add(1, Ordering::Relaxed) + 1;
+        let new_depth = self.depth.fetch_add(1, Ordering::SeqCst) + 1;
 
         // Update peak if necessary
-        let mut current_peak = self.peak_depth.load(Ordering::Relaxed);
-        while new_depth > current_peak {
-            match self.peak_depth.compare_exchange_weak(
-                current_peak,
-                new_depth,
-                Ordering::Relaxed,
-                Ordering::Relaxed,
-            ) {
-                Ok(_) => break,
-                Err(actual) => current_peak = actual,
-            }
-        }
+        self.peak_depth.fetch_max(new_depth, Ordering::SeqCst);
 
         // Emit to Prometheus
         prom::set_actor_mailbox_depth(self.actor_type.as_str(), new_depth);
@@ -166,7 +167,7 @@ impl MailboxMonitor {
     ///
     /// Updates internal tracking and emits to Prometheus gauge.
     pub fn record_dequeue(&self) {
-        let new_depth = self.depth.fetch_sub(1, Ordering::Relaxed).saturating_sub(1);
+        let new_depth = saturating_decrement(&self.depth);
         self.messages_processed.fetch_add(1, Ordering::Relaxed);
 
         // Emit to Prometheus
@@ -194,13 +195,13 @@ impl MailboxMonitor {
     /// Get the current mailbox depth.
     #[must_use]
     pub fn current_depth(&self) -> usize {
-        self.depth.load(Ordering::Relaxed)
+        self.depth.load(Ordering::SeqCst)
     }
 
     /// Get the peak mailbox depth.
     #[must_use]
     pub fn peak_depth(&self) -> usize {
-        self.peak_depth.load(Ordering::Relaxed)
+        self.peak_depth.load(Ordering::SeqCst)
     }
 
     /// Get total messages processed.
@@ -224,7 +225,11 @@ impl MailboxMonitor {
     /// Reset peak depth counter.
     pub fn reset_peak(&self) {
         self.peak_depth
-            .store(self.current_depth(), Ordering::Relaxed);
+            .store(self.current_depth(), Ordering::SeqCst);
+        // An enqueue between the read and the store may have seen the old,
+        // higher peak and skipped its update
+        self.peak_depth
+            .fetch_max(self.current_depth(), Ordering::SeqCst);
     }
 
     /// Determine mailbox level for a given depth.
@@ -243,13 +248,14 @@ impl MailboxMonitor {
 ///
 /// This struct is shared between the actor system (which updates values)
 /// and heartbeat tasks (which read values for reporting to GC).
-/// All fields are atomic for lock-free concurrent access.
+/// Both counts live in one atomic word, so a heartbeat never sees a
+/// participant counted before the meeting it joined, and a decrement at
+/// zero cannot wrap into the other count.
 #[derive(Debug, Default)]
 pub struct ControllerMetrics {
-    /// Current number of active meetings on this MC.
-    current_meetings: AtomicU32,
-    /// Current number of active participants across all meetings.
-    current_participants: AtomicU32,
+    /// Active meetings in the high 32 bits, active participants across all
+    /// meetings in the low 32 bits.
+    counts: AtomicU64,
 }
 
 /// Snapshot of controller metrics at a point in time.
@@ -261,6 +267,19 @@ pub struct ControllerMetricsSnapshot {
     pub participants: u32,
 }
 
+impl ControllerMetricsSnapshot {
+    fn unpack(counts: u64) -> Self {
+        Self {
+            meetings: (counts >> 32) as u32,
+            participants: counts as u32,
+        }
+    }
+
+    fn pack(self) -> u64 {
+        (u64::from(self.meetings) << 32) | u64::from(self.participants)
+    }
+}
+
 impl ControllerMetrics {
     /// Create a new shared metrics instance.
     #[must_use]
@@ -268,57 +287,100 @@ impl ControllerMetrics {
         Arc::new(Self::default())
     }
 
+    /// Apply `f` to both counts in one atomic step, returning the counts
+    /// from before.
+    fn update(
+        &self,
+        f: impl Fn(ControllerMetricsSnapshot) -> ControllerMetricsSnapshot,
+    ) -> ControllerMetricsSnapshot {
+        let previous = self
+            .counts
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |counts| {
+                Some(f(ControllerMetricsSnapshot::unpack(counts)).pack())
+            })
+            .unwrap_or_else(|counts| counts);
+        ControllerMetricsSnapshot::unpack(previous)
+    }
+
     /// Update the current meeting count.
     pub fn set_meetings(&self, count: u32) {
-        self.current_meetings.store(count, Ordering::SeqCst);
+        self.update(|counts| ControllerMetricsSnapshot {
+            meetings: count,
+            ..counts
+        });
     }
 
     /// Update the current participant count.
     pub fn set_participants(&self, count: u32) {
-        self.current_participants.store(count, Ordering::SeqCst);
+        self.update(|counts| ControllerMetricsSnapshot {
+            participants: count,
+            ..counts
+        });
     }
 
     /// Get the current meeting count.
     #[must_use]
     pub fn meetings(&self) -> u32 {
-        self.current_meetings.load(Ordering::SeqCst)
+        self.snapshot().meetings
     }
 
     /// Get the current participant count.
     #[must_use]
     pub fn participants(&self) -> u32 {
-        self.current_participants.load(Ordering::SeqCst)
+        self.snapshot().participants
     }
 
     /// Increment the meeting count atomically.
     pub fn increment_meetings(&self) {
-        self.current_meetings.fetch_add(1, Ordering::SeqCst);
+        self.update(|counts| ControllerMetricsSnapshot {
+            meetings: counts.meetings.saturating_add(1),
+            ..counts
+        });
     }
 
-    /// Decrement the meeting count atomically.
+    /// Decrement the meeting count atomically, stopping at zero.
     pub fn decrement_meetings(&self) {
-        self.current_meetings.fetch_sub(1, Ordering::SeqCst);
+        let previous = self.update(|counts| ControllerMetricsSnapshot {
+            meetings: counts.meetings.saturating_sub(1),
+            ..counts
+        });
+        if previous.meetings == 0 {
+            warn!(
+                target: "mc.actor.metrics",
+                "Meeting count decremented at zero; an increment was missed"
+            );
+        }
     }
 
     /// Increment the participant count atomically.
     pub fn increment_participants(&self) {
-        self.current_participants.fetch_add(1, Ordering::SeqCst);
+        self.update(|counts| ControllerMetricsSnapshot {
+            participants: counts.participants.saturating_add(1),
+            ..counts
+        });
     }
 
-    /// Decrement the participant count atomically.
+    /// Decrement the participant count atomically, stopping at zero.
     pub fn decrement_participants(&self) {
-        self.current_participants.fetch_sub(1, Ordering::SeqCst);
+        let previous = self.update(|counts| ControllerMetricsSnapshot {
+            participants: counts.participants.saturating_sub(1),
+            ..counts
+        });
+        if previous.participants == 0 {
+            warn!(
+                target: "mc.actor.metrics",
+                "Participant count decremented at zero; an increment was missed"
+            );
+        }
     }
 
     /// Take an atomic snapshot of current metrics.
     ///
-    /// This reads both counters atomically for consistent reporting in heartbeats.
+    /// Both counts come from a single load, so they are consistent with each
+    /// other for heartbeat reporting.
     #[must_use]
     pub fn snapshot(&self) -> ControllerMetricsSnapshot {
-        ControllerMetricsSnapshot {
-            meetings: self.current_meetings.load(Ordering::SeqCst),
-            participants: self.current_participants.load(Ordering::SeqCst),
-        }
+        ControllerMetricsSnapshot::unpack(self.counts.load(Ordering::SeqCst))
     }
 }
 
@@ -356,10 +418,7 @@ impl ActorMetrics {
     ///
     /// Updates internal counter and emits to Prometheus gauge.
     pub fn meeting_removed(&self) {
-        let count = self
-            .active_meetings
-            .fetch_sub(1, Ordering::Relaxed)
-            .saturating_sub(1);
+        let count = saturating_decrement(&self.active_meetings);
 
         // Emit to Prometheus
         prom::set_meetings_active(count as u64);
@@ -379,10 +438,7 @@ impl ActorMetrics {
     ///
     /// Updates internal counter and emits to Prometheus gauge.
     pub fn connection_closed(&self) {
-        let count = self
-            .active_connections
-            .fetch_sub(1, Ordering::Relaxed)
-            .saturating_sub(1);
+        let count = saturating_decrement(&self.active_connections);
 
         // Emit to Prometheus
         prom::set_connections_active(count as u64);
@@ -641,4 +697,168 @@ mod tests {
         assert_eq!(metrics.meetings(), 0);
         assert_eq!(metrics.participants(), 0);
     }
+
+    #[test]
+    fn test_controller_metrics_decrement_at_zero_saturates() {
+        let metrics = ControllerMetrics::new();
+        metrics.set_meetings(1);
+
+        // Must not wrap, nor borrow from the meeting count
+        metrics.decrement_participants();
+        assert_eq!(metrics.participants(), 0);
+        assert_eq!(metrics.meetings(), 1);
+
+        metrics.decrement_meetings();
+        metrics.decrement_meetings();
+        assert_eq!(metrics.meetings(), 0);
+        assert_eq!(metrics.participants(), 0);
+    }
+
+    #[test]
+    fn test_controller_metrics_counts_are_independent() {
+        let metrics = ControllerMetrics::new();
+        metrics.set_participants(u32::MAX);
+        metrics.increment_participants();
+        metrics.increment_meetings();
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.participants, u32::MAX);
+        assert_eq!(snapshot.meetings, 1);
+    }
+
+    #[test]
+    fn test_actor_metrics_decrement_at_zero_saturates() {
+        let metrics = ActorMetrics::new();
+
+        metrics.connection_closed();
+        metrics.meeting_removed();
+        assert_eq!(metrics.connection_count(), 0);
+        assert_eq!(metrics.meeting_count(), 0);
+
+        metrics.connection_created();
+        assert_eq!(metrics.connection_count(), 1);
+    }
+}
+
+/// Model-checked interleavings of the shared counters. Actor handles are
+/// cloned across tasks and threads, so every meeting actor, the controller
+/// and the heartbeat tasks update or read these concurrently.
+///
+/// Run with:
+/// `RUSTFLAGS="--cfg loom" cargo test -p mc-service --lib --release loom_tests`
+///
+/// Only these tests are meaningful in a loom build; loom atomics panic when
+/// used outside `loom::model`.
+#[cfg(all(test, loom))]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod loom_tests {
+    use super::*;
+    use loom::sync::Arc;
+    use loom::thread;
+
+    #[test]
+    fn heartbeat_snapshot_follows_update_order() {
+        loom::model(|| {
+            let metrics = Arc::new(ControllerMetrics::default());
+
+            // A meeting is created, a participant joins and leaves, the meeting ends
+            let actor = Arc::clone(&metrics);
+            let meeting = thread::spawn(move || {
+                actor.increment_meetings();
+                actor.increment_participants();
+                actor.decrement_participants();
+                actor.decrement_meetings();
+            });
+
+            let snapshot = metrics.snapshot();
+            assert!(
+                snapshot.participants <= snapshot.meetings,
+                "participant counted without its meeting: {snapshot:?}"
+            );
+
+            meeting.join().unwrap();
+            let snapshot = metrics.snapshot();
+            assert_eq!((snapshot.meetings, snapshot.participants), (0, 0));
+        });
+    }
+
+    #[test]
+    fn concurrent_joins_and_leaves_balance() {
+        loom::model(|| {
+            let metrics = Arc::new(ControllerMetrics::default());
+            metrics.increment_participants();
+
+            // Two meeting actors: one participant leaves, another joins and leaves
+            let leaver = Arc::clone(&metrics);
+            let leave = thread::spawn(move || leaver.decrement_participants());
+            let joiner = Arc::clone(&metrics);
+            let join = thread::spawn(move || {
+                joiner.increment_participants();
+                joiner.decrement_participants();
+            });
+
+            assert!(metrics.participants() <= 2);
+
+            leave.join().unwrap();
+            join.join().unwrap();
+            assert_eq!(metrics.participants(), 0);
+        });
+    }
+
+    #[test]
+    fn unmatched_decrement_never_wraps() {
+        loom::model(|| {
+            let metrics = Arc::new(ControllerMetrics::default());
+
+            let stray = Arc::clone(&metrics);
+            let leave = thread::spawn(move || stray.decrement_participants());
+            metrics.increment_meetings();
+
+            leave.join().unwrap();
+            let snapshot = metrics.snapshot();
+            assert_eq!(snapshot.participants, 0);
+            assert_eq!(snapshot.meetings, 1);
+        });
+    }
+
+    #[test]
+    fn connection_count_shared_across_actors() {
+        loom::model(|| {
+            let metrics = Arc::new(ActorMetrics::default());
+            metrics.connection_created();
+
+            // One actor's connection closes while another opens and closes one
+            let closing = Arc::clone(&metrics);
+            let close = thread::spawn(move || closing.connection_closed());
+            let cycling = Arc::clone(&metrics);
+            let cycle = thread::spawn(move || {
+                cycling.connection_created();
+                cycling.connection_closed();
+            });
+
+            assert!(metrics.connection_count() <= 2);
+
+            close.join().unwrap();
+            cycle.join().unwrap();
+            assert_eq!(metrics.connection_count(), 0);
+        });
+    }
+
+    #[test]
+    fn reset_peak_never_drops_below_depth() {
+        loom::model(|| {
+            let monitor = Arc::new(MailboxMonitor::new(ActorType::Meeting, "meeting-loom"));
+            monitor.record_enqueue();
+            monitor.record_enqueue();
+            monitor.record_dequeue();
+
+            let sender = Arc::clone(&monitor);
+            let enqueue = thread::spawn(move || sender.record_enqueue());
+            monitor.reset_peak();
+
+            enqueue.join().unwrap();
+            assert_eq!(monitor.current_depth(), 2);
+            assert!(monitor.peak_depth() >= monitor.current_depth());
+        });
+    }
 }
@@ -22,23 +22,44 @@
 //! # Modules
 //!
 //! - [`controller`] - `MeetingControllerActor` singleton that supervises meetings
+//! - [`data_channels`] - Opaque application data channels routed by the `MeetingActor`
 //! - [`meeting`] - `MeetingActor` per active meeting, owns meeting state
+//! - [`interactions`] - Polls and Q&A state owned by the `MeetingActor`
+//! - [`journal`] - Meeting event journal entries and their replay on recovery
+//! - [`keyframes`] - Keyframe request routing and per-stream deduplication
+//! - [`live_stream`] - Live streaming (MH egress) lifecycle owned by the `MeetingActor`
 //! - [`participant`] - `ParticipantActor` per participant in a meeting
+//! - [`recording`] - Recording state and consent enforcement owned by the `MeetingActor`
+//! - [`rate_limit`] - Per-participant request rate limiting
 //! - [`messages`] - Message types for actor communication
 //! - [`metrics`] - Mailbox monitoring and actor metrics
 //! - [`session`] - Session binding token generation and validation
+//! - [`state_check`] - Checksums comparing `MeetingActor` state with Redis
+//! - [`usage`] - Per-meeting resource accounting (messages, bytes, Redis operations)
 
 pub mod controller;
+pub mod data_channels;
+pub mod interactions;
+pub mod journal;
+pub mod keyframes;
+pub mod live_stream;
 pub mod meeting;
 pub mod messages;
 pub mod metrics;
 pub mod participant;
+pub mod rate_limit;
+pub mod recording;
 pub mod session;
+pub mod state_check;
+pub mod usage;
 
 // Re-export primary types
 pub use controller::{MeetingControllerActor, MeetingControllerActorHandle};
-pub use meeting::{MeetingActor, MeetingActorHandle};
+pub use meeting::{
+    DuplicateJoinPolicy, MeetingActor, MeetingActorHandle, MeetingServices, MeetingSettings,
+};
 pub use messages::*;
 pub use metrics::{ActorMetrics, ControllerMetrics, ControllerMetricsSnapshot, MailboxMonitor};
 pub use participant::{ParticipantActor, ParticipantActorHandle};
 pub use session::{SessionBindingManager, StoredBinding};
+pub use usage::{MeetingUsage, MeetingUsageRate, MeetingUsageRegistry};
@@ -16,13 +16,17 @@ use crate::errors::McError;
 use super::meeting::MeetingActorHandle;
 use super::messages::{ParticipantMessage, ParticipantStateUpdate, SignalingPayload};
 use super::metrics::{ActorMetrics, ActorType, MailboxMonitor};
+use crate::webtransport::handler::encode_connection_closed;
+
+use prost::Message;
+use proto_gen::dark_tower::signaling::v1::CloseReason;
 
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
-use tracing::{debug, info, instrument, warn};
+use tracing::{debug, debug_span, info, instrument, warn, Instrument};
 
 /// Default channel buffer size for the participant mailbox.
 const PARTICIPANT_CHANNEL_BUFFER: usize = 200;
@@ -65,8 +69,9 @@ impl ParticipantActorHandle {
             .map_err(|e| McError::Internal(format!("channel send failed: {e}")))
     }
 
-    /// Close the participant actor.
-    pub async fn close(&self, reason: String) -> Result<(), McError> {
+    /// Close the participant actor, sending the client `ConnectionClosed`
+    /// with `reason` as its last message.
+    pub async fn close(&self, reason: CloseReason) -> Result<(), McError> {
         self.sender
             .send(ParticipantMessage::Close { reason })
             .await
@@ -261,7 +266,8 @@ impl ParticipantActor {
                         connection_id = %self.connection_id,
                         "ParticipantActor received cancellation signal"
                     );
-                    self.graceful_close("cancelled").await;
+                    self.flush_mailbox().await;
+                    self.graceful_close(None).await;
                     break;
                 }
 
@@ -270,7 +276,10 @@ impl ParticipantActor {
                     match msg {
                         Some(message) => {
                             self.mailbox.record_enqueue();
-                            let should_exit = self.handle_message(message).await;
+                            let should_exit = self
+                                .handle_message(message)
+                                .instrument(debug_span!("mc.actor.participant.message"))
+                                .await;
                             self.mailbox.record_dequeue();
                             self.metrics.record_message_processed();
 
@@ -327,7 +336,7 @@ impl ParticipantActor {
             }
 
             ParticipantMessage::Close { reason } => {
-                self.graceful_close(&reason).await;
+                self.graceful_close(Some(reason)).await;
                 true
             }
 
@@ -409,7 +418,6 @@ impl ParticipantActor {
         // Encode to protobuf if it's a wire-visible update
         if let Some(server_msg) = crate::webtransport::handler::encode_participant_update(&update) {
             if let Some(tx) = &self.stream_tx {
-                use prost::Message;
                 let encoded = server_msg.encode_to_vec();
                 if tx.try_send(bytes::Bytes::from(encoded)).is_err() {
                     warn!(
@@ -422,10 +430,23 @@ impl ParticipantActor {
         }
     }
 
+    /// Handle messages already queued when the actor is cancelled, so
+    /// that a departing client still sees the last updates and the reason
+    /// its connection is closing.
+    async fn flush_mailbox(&mut self) {
+        while let Ok(message) = self.receiver.try_recv() {
+            if self.handle_message(message).await {
+                return;
+            }
+        }
+    }
+
     /// Gracefully close the participant actor.
     ///
-    /// Drops the stream sender to signal the WebTransport write task to close.
-    async fn graceful_close(&mut self, reason: &str) {
+    /// With a `reason`, queues `ConnectionClosed` as the client's last
+    /// message. Then drops the stream sender to signal the WebTransport
+    /// write task to close.
+    async fn graceful_close(&mut self, reason: Option<CloseReason>) {
         if self.is_closing {
             return;
         }
@@ -435,10 +456,21 @@ impl ParticipantActor {
         debug!(
             target: "mc.actor.participant",
             connection_id = %self.connection_id,
-            reason = %reason,
+            reason = reason.map_or("cancelled", |r| r.as_str_name()),
             "Closing participant actor"
         );
 
+        if let (Some(reason), Some(tx)) = (reason, &self.stream_tx) {
+            let closed = encode_connection_closed(reason).encode_to_vec();
+            if tx.try_send(bytes::Bytes::from(closed)).is_err() {
+                warn!(
+                    target: "mc.actor.participant",
+                    connection_id = %self.connection_id,
+                    "Stream outbound channel full or closed"
+                );
+            }
+        }
+
         // Drop the stream sender to signal the bridge loop to close
         self.stream_tx.take();
 
@@ -451,6 +483,7 @@ impl ParticipantActor {
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
+    use proto_gen::dark_tower::signaling::v1::{server_message, ServerMessage};
 
     #[tokio::test]
     async fn test_participant_actor_spawn() {
@@ -559,7 +592,7 @@ mod tests {
         );
 
         // Close the participant actor
-        let result = handle.close("test close".to_string()).await;
+        let result = handle.close(CloseReason::Kicked).await;
         assert!(result.is_ok());
 
         // Wait for task to complete
@@ -727,14 +760,54 @@ mod tests {
         );
 
         // Close the participant actor
-        handle.close("test close".to_string()).await.unwrap();
+        handle.close(CloseReason::Kicked).await.unwrap();
 
         // Wait for task to complete
         let result = tokio::time::timeout(Duration::from_secs(1), task).await;
         assert!(result.is_ok());
 
-        // Stream receiver should return None (sender dropped)
+        // The reason is the last message, then the sender is dropped
+        let closed = stream_rx.recv().await.expect("ConnectionClosed");
+        match ServerMessage::decode(closed.as_ref()).unwrap().message {
+            Some(server_message::Message::ConnectionClosed(closed)) => {
+                assert_eq!(closed.reason(), CloseReason::Kicked);
+            }
+            other => panic!("Expected ConnectionClosed, got {other:?}"),
+        }
         let received = stream_rx.recv().await;
         assert!(received.is_none(), "Expected None after stream_tx dropped");
     }
+
+    #[tokio::test]
+    async fn test_cancel_flushes_queued_close() {
+        let metrics = ActorMetrics::new();
+        let cancel_token = CancellationToken::new();
+        let (stream_tx, mut stream_rx) = tokio::sync::mpsc::channel::<bytes::Bytes>(16);
+
+        let (handle, task) = ParticipantActor::spawn_with_stream(
+            "conn-stream-cancel".to_string(),
+            "part-1".to_string(),
+            "meeting-1".to_string(),
+            cancel_token.clone(),
+            metrics,
+            stream_tx,
+        );
+
+        // Queued just before the meeting cancels its connections
+        handle.close(CloseReason::MeetingEnded).await.unwrap();
+        cancel_token.cancel();
+        tokio::time::timeout(Duration::from_secs(1), task)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let closed = stream_rx.recv().await.expect("ConnectionClosed");
+        match ServerMessage::decode(closed.as_ref()).unwrap().message {
+            Some(server_message::Message::ConnectionClosed(closed)) => {
+                assert_eq!(closed.reason(), CloseReason::MeetingEnded);
+            }
+            other => panic!("Expected ConnectionClosed, got {other:?}"),
+        }
+        assert!(stream_rx.recv().await.is_none());
+    }
 }
@@ -0,0 +1,70 @@
+//! Fixed-window rate limiting for client requests handled by the `MeetingActor`.
+//!
+//! Limiter state is in-memory only; after an MC failover every participant
+//! starts with a fresh budget.
+
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Fixed-window rate limiter keyed by participant ID.
+#[derive(Debug)]
+pub struct RateLimiter {
+    max_actions: u32,
+    window: Duration,
+    /// Window start and action count by key.
+    windows: HashMap<String, (Instant, u32)>,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `max_actions` per `window` per key.
+    #[must_use]
+    pub fn new(max_actions: u32, window: Duration) -> Self {
+        Self {
+            max_actions,
+            window,
+            windows: HashMap::new(),
+        }
+    }
+
+    /// Record an action for `key` at `now`, returning whether it is allowed.
+    pub fn check(&mut self, key: &str, now: Instant) -> bool {
+        let (start, count) = self.windows.entry(key.to_string()).or_insert((now, 0));
+        if now.duration_since(*start) >= self.window {
+            *start = now;
+            *count = 0;
+        }
+        if *count >= self.max_actions {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+
+    /// Forget a key (participant left).
+    pub fn remove(&mut self, key: &str) {
+        self.windows.remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_fixed_window() {
+        let mut limiter = RateLimiter::new(2, Duration::from_secs(10));
+        let start = Instant::now();
+
+        assert!(limiter.check("part-1", start));
+        assert!(limiter.check("part-1", start));
+        assert!(!limiter.check("part-1", start + Duration::from_secs(9)));
+        // Other participants have their own budget
+        assert!(limiter.check("part-2", start));
+        // Window resets
+        assert!(limiter.check("part-1", start + Duration::from_secs(10)));
+
+        limiter.remove("part-1");
+        assert!(!limiter.windows.contains_key("part-1"));
+    }
+}
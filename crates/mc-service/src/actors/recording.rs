@@ -0,0 +1,467 @@
+//! Recording state and consent enforcement owned by the `MeetingActor`.
+//!
+//! When a host starts recording, every participant is sent a
+//! `RecordingStateChanged` notice, and participants who join while recording
+//! get the same notice after their `JoinResponse`. The organization's
+//! [`RecordingConsentPolicy`] (sent by GC with the meeting assignment) decides
+//! what happens next:
+//!
+//! - `Notify`: the notice is informational.
+//! - `RequireAck`: each participant must answer with `RecordingConsent`
+//!   before their deadline. Declining, or not answering in time, removes the
+//!   participant from the meeting.
+//!
+//! The host who starts a recording has consented implicitly. Consent is kept
+//! per participant for the life of the recording, so reconnecting does not
+//! ask again; a new recording asks everyone again.
+
+use crate::errors::McError;
+
+use proto_gen::dark_tower::internal::v1 as internal;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Consent timeout when GC does not send one.
+pub const DEFAULT_CONSENT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Shortest consent timeout accepted from GC.
+pub const MIN_CONSENT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Longest consent timeout accepted from GC.
+pub const MAX_CONSENT_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// How recording consent is enforced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConsentMode {
+    /// Participants are notified; no answer is needed.
+    Notify,
+    /// Participants must consent or are removed.
+    #[default]
+    RequireAck,
+}
+
+/// Organization recording consent policy for one meeting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordingConsentPolicy {
+    /// Enforcement mode.
+    pub mode: ConsentMode,
+    /// Time a participant has to consent under `RequireAck`.
+    pub consent_timeout: Duration,
+}
+
+impl Default for RecordingConsentPolicy {
+    fn default() -> Self {
+        Self {
+            mode: ConsentMode::RequireAck,
+            consent_timeout: DEFAULT_CONSENT_TIMEOUT,
+        }
+    }
+}
+
+impl RecordingConsentPolicy {
+    /// Build the policy from GC's assignment request.
+    ///
+    /// A missing policy or unspecified mode requires consent; a zero timeout
+    /// uses the default and other timeouts are clamped to the allowed range.
+    #[must_use]
+    pub fn from_proto(policy: Option<&internal::RecordingConsentPolicy>) -> Self {
+        let Some(policy) = policy else {
+            return Self::default();
+        };
+        let mode = match internal::RecordingConsentMode::try_from(policy.mode) {
+            Ok(internal::RecordingConsentMode::Notify) => ConsentMode::Notify,
+            _ => ConsentMode::RequireAck,
+        };
+        let consent_timeout = match policy.consent_timeout_seconds {
+            0 => DEFAULT_CONSENT_TIMEOUT,
+            seconds => Duration::from_secs(u64::from(seconds))
+                .clamp(MIN_CONSENT_TIMEOUT, MAX_CONSENT_TIMEOUT),
+        };
+        Self {
+            mode,
+            consent_timeout,
+        }
+    }
+}
+
+/// Compositor layout for the meeting's recording, from GC's assignment.
+///
+/// The MC does not use it itself; it is passed to each MH in
+/// `RegisterMeeting`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecordingLayout {
+    /// Not set by GC (GCs that predate the setting); the MH default applies.
+    #[default]
+    Unspecified,
+    /// Every participant in equal tiles.
+    Grid,
+    /// The active speaker large, others in a filmstrip.
+    Speaker,
+}
+
+impl RecordingLayout {
+    /// Build the layout from GC's assignment request. Unknown values are
+    /// treated as unspecified.
+    #[must_use]
+    pub fn from_proto(layout: i32) -> Self {
+        match internal::RecordingLayout::try_from(layout) {
+            Ok(internal::RecordingLayout::Grid) => Self::Grid,
+            Ok(internal::RecordingLayout::Speaker) => Self::Speaker,
+            _ => Self::Unspecified,
+        }
+    }
+
+    /// Proto value for `RegisterMeetingRequest`.
+    #[must_use]
+    pub fn to_proto(self) -> internal::RecordingLayout {
+        match self {
+            Self::Unspecified => internal::RecordingLayout::Unspecified,
+            Self::Grid => internal::RecordingLayout::Grid,
+            Self::Speaker => internal::RecordingLayout::Speaker,
+        }
+    }
+}
+
+/// A recording request from a participant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingRequest {
+    /// Start recording (host only).
+    Start,
+    /// Stop recording (host only).
+    Stop,
+    /// Answer to a recording notice.
+    Consent { accepted: bool },
+}
+
+/// Recording notice sent to one participant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordingNotice {
+    /// Whether the meeting is being recorded.
+    pub recording: bool,
+    /// Recording start (Unix milliseconds, 0 when not recording).
+    pub started_at_ms: i64,
+    /// Whether this participant must answer.
+    pub consent_required: bool,
+    /// Answer deadline (Unix milliseconds, 0 when no answer is needed).
+    pub consent_deadline_ms: i64,
+}
+
+impl RecordingNotice {
+    /// Notice that recording has stopped.
+    #[must_use]
+    pub fn stopped() -> Self {
+        Self {
+            recording: false,
+            started_at_ms: 0,
+            consent_required: false,
+            consent_deadline_ms: 0,
+        }
+    }
+}
+
+/// Result of a participant's consent answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsentOutcome {
+    /// Consent recorded (or not needed).
+    Accepted,
+    /// The participant declined and must be removed.
+    Declined,
+}
+
+#[derive(Debug)]
+struct PendingConsent {
+    deadline: Instant,
+    deadline_ms: i64,
+}
+
+#[derive(Debug)]
+struct ActiveRecording {
+    started_at_ms: i64,
+    /// Participants who still have to answer.
+    pending: HashMap<String, PendingConsent>,
+    /// Participants who consented.
+    consented: HashSet<String>,
+}
+
+/// Recording state and outstanding consent for one meeting.
+#[derive(Debug, Default)]
+pub struct RecordingState {
+    policy: RecordingConsentPolicy,
+    active: Option<ActiveRecording>,
+}
+
+impl RecordingState {
+    /// Create idle state for a meeting with the given policy.
+    #[must_use]
+    pub fn new(policy: RecordingConsentPolicy) -> Self {
+        Self {
+            policy,
+            active: None,
+        }
+    }
+
+    /// The meeting's consent policy.
+    #[must_use]
+    pub fn policy(&self) -> RecordingConsentPolicy {
+        self.policy
+    }
+
+    /// Whether the meeting is being recorded.
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Start recording. The host has consented; everyone else in
+    /// `participant_ids` is asked (under `RequireAck`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Conflict` if the meeting is already being recorded.
+    pub fn start<'a>(
+        &mut self,
+        host_participant_id: &str,
+        participant_ids: impl IntoIterator<Item = &'a str>,
+        now: Instant,
+        now_ms: i64,
+    ) -> Result<(), McError> {
+        if self.active.is_some() {
+            return Err(McError::Conflict(
+                "The meeting is already being recorded".to_string(),
+            ));
+        }
+        let mut active = ActiveRecording {
+            started_at_ms: now_ms,
+            pending: HashMap::new(),
+            consented: HashSet::from([host_participant_id.to_string()]),
+        };
+        if self.policy.mode == ConsentMode::RequireAck {
+            for id in participant_ids {
+                if id != host_participant_id {
+                    active
+                        .pending
+                        .insert(id.to_string(), self.pending_consent(now, now_ms));
+                }
+            }
+        }
+        self.active = Some(active);
+        Ok(())
+    }
+
+    /// Stop recording and drop all outstanding consent.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::InvalidRequest` if the meeting is not being recorded.
+    pub fn stop(&mut self) -> Result<(), McError> {
+        self.active
+            .take()
+            .map(|_| ())
+            .ok_or_else(|| McError::InvalidRequest("The meeting is not being recorded".to_string()))
+    }
+
+    /// Ask a participant who joined mid-recording for consent.
+    pub fn participant_joined(&mut self, participant_id: &str, now: Instant, now_ms: i64) {
+        if self.policy.mode != ConsentMode::RequireAck {
+            return;
+        }
+        let pending = self.pending_consent(now, now_ms);
+        if let Some(active) = &mut self.active {
+            if !active.consented.contains(participant_id) {
+                active.pending.insert(participant_id.to_string(), pending);
+            }
+        }
+    }
+
+    /// Forget a participant who left.
+    pub fn remove_participant(&mut self, participant_id: &str) {
+        if let Some(active) = &mut self.active {
+            active.pending.remove(participant_id);
+            active.consented.remove(participant_id);
+        }
+    }
+
+    /// Apply a participant's answer.
+    ///
+    /// Answers while not recording, or under `Notify`, are accepted and
+    /// ignored. Declining under `RequireAck` means the participant must go.
+    pub fn consent(&mut self, participant_id: &str, accepted: bool) -> ConsentOutcome {
+        let Some(active) = &mut self.active else {
+            return ConsentOutcome::Accepted;
+        };
+        if self.policy.mode != ConsentMode::RequireAck {
+            return ConsentOutcome::Accepted;
+        }
+        active.pending.remove(participant_id);
+        if accepted {
+            active.consented.insert(participant_id.to_string());
+            ConsentOutcome::Accepted
+        } else {
+            ConsentOutcome::Declined
+        }
+    }
+
+    /// Participants whose consent deadline has passed, removed from the
+    /// pending set.
+    pub fn take_expired(&mut self, now: Instant) -> Vec<String> {
+        let Some(active) = &mut self.active else {
+            return Vec::new();
+        };
+        let expired: Vec<String> = active
+            .pending
+            .iter()
+            .filter(|(_, pending)| pending.deadline <= now)
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in &expired {
+            active.pending.remove(id);
+        }
+        expired
+    }
+
+    /// Notice for one participant (`None` when not recording).
+    #[must_use]
+    pub fn notice_for(&self, participant_id: &str) -> Option<RecordingNotice> {
+        let active = self.active.as_ref()?;
+        let pending = active.pending.get(participant_id);
+        Some(RecordingNotice {
+            recording: true,
+            started_at_ms: active.started_at_ms,
+            consent_required: pending.is_some(),
+            consent_deadline_ms: pending.map_or(0, |p| p.deadline_ms),
+        })
+    }
+
+    fn pending_consent(&self, now: Instant, now_ms: i64) -> PendingConsent {
+        let timeout_ms = i64::try_from(self.policy.consent_timeout.as_millis()).unwrap_or(i64::MAX);
+        PendingConsent {
+            deadline: now + self.policy.consent_timeout,
+            deadline_ms: now_ms.saturating_add(timeout_ms),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    const NOW_MS: i64 = 1_700_000_000_000;
+
+    fn require_ack() -> RecordingState {
+        RecordingState::new(RecordingConsentPolicy::default())
+    }
+
+    #[test]
+    fn test_policy_from_proto() {
+        assert_eq!(
+            RecordingConsentPolicy::from_proto(None),
+            RecordingConsentPolicy::default()
+        );
+
+        let notify = RecordingConsentPolicy::from_proto(Some(&internal::RecordingConsentPolicy {
+            mode: internal::RecordingConsentMode::Notify.into(),
+            consent_timeout_seconds: 0,
+        }));
+        assert_eq!(notify.mode, ConsentMode::Notify);
+        assert_eq!(notify.consent_timeout, DEFAULT_CONSENT_TIMEOUT);
+
+        let clamped = RecordingConsentPolicy::from_proto(Some(&internal::RecordingConsentPolicy {
+            mode: internal::RecordingConsentMode::Unspecified.into(),
+            consent_timeout_seconds: 1,
+        }));
+        assert_eq!(clamped.mode, ConsentMode::RequireAck);
+        assert_eq!(clamped.consent_timeout, MIN_CONSENT_TIMEOUT);
+    }
+
+    #[test]
+    fn test_recording_layout_proto_round_trip() {
+        for layout in [
+            RecordingLayout::Unspecified,
+            RecordingLayout::Grid,
+            RecordingLayout::Speaker,
+        ] {
+            assert_eq!(
+                RecordingLayout::from_proto(layout.to_proto().into()),
+                layout
+            );
+        }
+        assert_eq!(
+            RecordingLayout::from_proto(99),
+            RecordingLayout::Unspecified
+        );
+    }
+
+    #[test]
+    fn test_start_asks_everyone_but_host() {
+        let mut state = require_ack();
+        let now = Instant::now();
+        state
+            .start("host", ["host", "a", "b"], now, NOW_MS)
+            .unwrap();
+        assert!(matches!(
+            state.start("host", [], now, NOW_MS),
+            Err(McError::Conflict(_))
+        ));
+
+        let host = state.notice_for("host").unwrap();
+        assert!(host.recording);
+        assert!(!host.consent_required);
+
+        let guest = state.notice_for("a").unwrap();
+        assert!(guest.consent_required);
+        assert_eq!(guest.consent_deadline_ms, NOW_MS + 60_000);
+    }
+
+    #[test]
+    fn test_consent_and_decline() {
+        let mut state = require_ack();
+        let now = Instant::now();
+        state.start("host", ["a", "b"], now, NOW_MS).unwrap();
+
+        assert_eq!(state.consent("a", true), ConsentOutcome::Accepted);
+        assert_eq!(state.consent("b", false), ConsentOutcome::Declined);
+        assert!(!state.notice_for("a").unwrap().consent_required);
+        assert!(state.take_expired(now + DEFAULT_CONSENT_TIMEOUT).is_empty());
+
+        // Consent survives a reconnect but not a new recording
+        state.participant_joined("a", now, NOW_MS);
+        assert!(!state.notice_for("a").unwrap().consent_required);
+        state.stop().unwrap();
+        assert!(state.notice_for("a").is_none());
+        assert!(matches!(state.stop(), Err(McError::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_unanswered_consent_expires() {
+        let mut state = require_ack();
+        let now = Instant::now();
+        state.start("host", ["a"], now, NOW_MS).unwrap();
+
+        let later = now + Duration::from_secs(30);
+        state.participant_joined("late", later, NOW_MS + 30_000);
+
+        assert!(state.take_expired(now + Duration::from_secs(59)).is_empty());
+        assert_eq!(state.take_expired(now + DEFAULT_CONSENT_TIMEOUT), vec!["a"]);
+        assert_eq!(
+            state.take_expired(later + DEFAULT_CONSENT_TIMEOUT),
+            vec!["late"]
+        );
+    }
+
+    #[test]
+    fn test_notify_mode_never_enforces() {
+        let mut state = RecordingState::new(RecordingConsentPolicy {
+            mode: ConsentMode::Notify,
+            consent_timeout: DEFAULT_CONSENT_TIMEOUT,
+        });
+        let now = Instant::now();
+        state.start("host", ["a"], now, NOW_MS).unwrap();
+        state.participant_joined("b", now, NOW_MS);
+
+        assert!(!state.notice_for("a").unwrap().consent_required);
+        assert_eq!(state.consent("a", false), ConsentOutcome::Accepted);
+        assert!(state.take_expired(now + MAX_CONSENT_TIMEOUT).is_empty());
+    }
+}
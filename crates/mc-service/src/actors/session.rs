@@ -14,12 +14,13 @@
 //! - Binding tokens are defense-in-depth (also requires valid JWT)
 
 use common::secret::{ExposeSecret, SecretBox};
+use common::types::CorrelationId;
 use ring::{hkdf, hmac, rand};
-use std::time::{Duration, Instant};
-use uuid::Uuid;
+use std::time::Duration;
+use tokio::time::Instant;
 
 /// Default binding token TTL (ADR-0023: 30 seconds).
-const BINDING_TOKEN_TTL: Duration = Duration::from_secs(30);
+pub const BINDING_TOKEN_TTL: Duration = Duration::from_secs(30);
 
 /// Session binding token manager.
 ///
@@ -220,10 +221,10 @@ impl StoredBinding {
         self.created_at.elapsed() > BINDING_TOKEN_TTL
     }
 
-    /// Generate a new correlation ID.
+    /// Generate a new correlation ID (UUIDv7).
     #[must_use]
     pub fn generate_correlation_id() -> String {
-        Uuid::new_v4().to_string()
+        CorrelationId::new().to_string()
     }
 }
 
@@ -345,14 +346,31 @@ mod tests {
         assert!(!binding.is_expired());
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn test_stored_binding_expires_after_ttl() {
+        let binding = StoredBinding::new(
+            "corr-1".to_string(),
+            "part-1".to_string(),
+            "user-1".to_string(),
+            "nonce".to_string(),
+            "token".to_string(),
+        );
+
+        tokio::time::advance(BINDING_TOKEN_TTL).await;
+        assert!(!binding.is_expired());
+
+        tokio::time::advance(Duration::from_millis(1)).await;
+        assert!(binding.is_expired());
+    }
+
     #[test]
     fn test_generate_correlation_id() {
         let id1 = StoredBinding::generate_correlation_id();
         let id2 = StoredBinding::generate_correlation_id();
 
-        // Should be valid UUIDs
-        assert_eq!(id1.len(), 36);
-        assert_eq!(id2.len(), 36);
+        // Should be valid UUIDv7s
+        assert_eq!(id1.parse::<uuid::Uuid>().unwrap().get_version_num(), 7);
+        assert_eq!(id2.parse::<uuid::Uuid>().unwrap().get_version_num(), 7);
 
         // Should be unique
         assert_ne!(id1, id2);
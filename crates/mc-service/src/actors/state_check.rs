@@ -0,0 +1,72 @@
+//! Divergence checks between a meeting actor's memory and Redis.
+//!
+//! The `MeetingActor` is authoritative for its meeting, but a replacement MC
+//! only sees what reached Redis. A write lost to a timeout or a dropped
+//! connection is logged once and then forgotten, and the two copies drift
+//! apart until a failover surfaces the difference (a participant who left
+//! long ago showing up again, say). The actor therefore compares checksums
+//! of each piece of state it persists with what Redis holds, counts any
+//! mismatch in `mc_state_divergence_total`, and rewrites Redis from memory.
+
+use ring::digest;
+
+/// State covered by the divergence check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckedState {
+    /// Polls and Q&A snapshot.
+    Interactions,
+    /// Participants present in the meeting, as the event journal has them.
+    Roster,
+}
+
+impl CheckedState {
+    /// Label for `mc_state_divergence_total{state}`.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Interactions => "interactions",
+            Self::Roster => "roster",
+        }
+    }
+}
+
+/// SHA-256 of `bytes`, hex-encoded.
+#[must_use]
+pub fn checksum(bytes: &[u8]) -> String {
+    hex::encode(digest::digest(&digest::SHA256, bytes))
+}
+
+/// Checksum of a set of participant IDs, independent of their order.
+#[must_use]
+pub fn roster_checksum<'a>(participant_ids: impl IntoIterator<Item = &'a str>) -> String {
+    let mut ids: Vec<&str> = participant_ids.into_iter().collect();
+    ids.sort_unstable();
+    ids.dedup();
+    checksum(ids.join("\n").as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum_is_sha256_hex() {
+        assert_eq!(
+            checksum(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn test_roster_checksum_ignores_order() {
+        assert_eq!(
+            roster_checksum(["part-1", "part-2"]),
+            roster_checksum(["part-2", "part-1"])
+        );
+        assert_ne!(
+            roster_checksum(["part-1", "part-2"]),
+            roster_checksum(["part-1"])
+        );
+        assert_ne!(roster_checksum(["part-1"]), roster_checksum([]));
+    }
+}
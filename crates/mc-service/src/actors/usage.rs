@@ -0,0 +1,345 @@
+//! Per-meeting resource accounting.
+//!
+//! Each `MeetingActor` counts what its meeting costs this MC in a
+//! [`MeetingUsage`]: mailbox messages handled, payload bytes fanned out to
+//! participants, and Redis operations. The counters are plain atomics, so
+//! recording never blocks the actor.
+//!
+//! [`MeetingUsageRegistry`] tracks the counters of every live meeting.
+//! Sampling it turns the deltas since the previous sample into per-second
+//! rates. The MC exports only the top [`USAGE_TOP_N`] rates per resource as
+//! metrics (labelled by rank, never by meeting ID, to keep cardinality fixed)
+//! and serves the full report on `/debug/meetings`, which is where a noisy
+//! meeting's ID is found.
+//!
+//! The registry only holds weak references: a meeting drops out of the
+//! report once its actor is gone, including after a panic.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::time::{Duration, Instant};
+
+/// How often the MC samples meeting usage.
+pub const USAGE_SAMPLE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Number of meetings per resource exported as metrics.
+pub const USAGE_TOP_N: usize = 5;
+
+/// Resource counters for one meeting.
+#[derive(Debug, Default)]
+pub struct MeetingUsage {
+    messages: AtomicU64,
+    bytes: AtomicU64,
+    redis_ops: AtomicU64,
+}
+
+impl MeetingUsage {
+    /// Count one mailbox message handled by the meeting actor.
+    pub fn record_message(&self) {
+        self.messages.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count payload bytes sent to participants.
+    pub fn record_bytes(&self, bytes: usize) {
+        self.bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    /// Count one Redis operation made for the meeting.
+    pub fn record_redis_op(&self) {
+        self.redis_ops.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Totals since the meeting started.
+    #[must_use]
+    pub fn totals(&self) -> UsageTotals {
+        UsageTotals {
+            messages: self.messages.load(Ordering::Relaxed),
+            bytes: self.bytes.load(Ordering::Relaxed),
+            redis_ops: self.redis_ops.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Cumulative usage of one meeting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct UsageTotals {
+    /// Mailbox messages handled.
+    pub messages: u64,
+    /// Payload bytes sent to participants.
+    pub bytes: u64,
+    /// Redis operations.
+    pub redis_ops: u64,
+}
+
+/// Usage rates of one meeting over the last sample interval.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MeetingUsageRate {
+    /// Meeting ID.
+    pub meeting_id: String,
+    /// Mailbox messages handled per second.
+    pub messages_per_second: f64,
+    /// Payload bytes sent to participants per second.
+    pub bytes_per_second: f64,
+    /// Redis operations per second.
+    pub redis_ops_per_second: f64,
+    /// Totals since the meeting started.
+    pub totals: UsageTotals,
+}
+
+/// A resource tracked per meeting, used to rank meetings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageResource {
+    /// Mailbox messages per second.
+    Messages,
+    /// Payload bytes per second.
+    Bytes,
+    /// Redis operations per second.
+    RedisOps,
+}
+
+impl UsageResource {
+    /// Every resource, in export order.
+    pub const ALL: [Self; 3] = [Self::Messages, Self::Bytes, Self::RedisOps];
+
+    /// Returns the resource as a string for metric labels.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Messages => "messages",
+            Self::Bytes => "bytes",
+            Self::RedisOps => "redis_ops",
+        }
+    }
+
+    /// This resource's rate in `rate`.
+    #[must_use]
+    pub fn rate(&self, rate: &MeetingUsageRate) -> f64 {
+        match self {
+            Self::Messages => rate.messages_per_second,
+            Self::Bytes => rate.bytes_per_second,
+            Self::RedisOps => rate.redis_ops_per_second,
+        }
+    }
+}
+
+/// Meetings with the highest `resource` rate, busiest first, at most `n`.
+#[must_use]
+pub fn top_n(
+    rates: &[MeetingUsageRate],
+    resource: UsageResource,
+    n: usize,
+) -> Vec<&MeetingUsageRate> {
+    let mut ranked: Vec<&MeetingUsageRate> = rates.iter().collect();
+    ranked.sort_by(|a, b| resource.rate(b).total_cmp(&resource.rate(a)));
+    ranked.truncate(n);
+    ranked
+}
+
+/// Counters of every live meeting on this MC.
+#[derive(Debug)]
+pub struct MeetingUsageRegistry {
+    state: Mutex<RegistryState>,
+}
+
+#[derive(Debug)]
+struct RegistryState {
+    meetings: HashMap<String, TrackedMeeting>,
+    last_sampled: Instant,
+    latest: Vec<MeetingUsageRate>,
+}
+
+#[derive(Debug)]
+struct TrackedMeeting {
+    usage: Weak<MeetingUsage>,
+    /// Totals at the previous sample.
+    last: UsageTotals,
+}
+
+impl MeetingUsageRegistry {
+    /// Create an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(RegistryState {
+                meetings: HashMap::new(),
+                last_sampled: Instant::now(),
+                latest: Vec::new(),
+            }),
+        }
+    }
+
+    /// Start tracking a meeting and return its counters. The meeting stays
+    /// in the registry for as long as the returned `Arc` is alive.
+    #[must_use]
+    pub fn register(&self, meeting_id: &str) -> Arc<MeetingUsage> {
+        let usage = Arc::new(MeetingUsage::default());
+        self.lock().meetings.insert(
+            meeting_id.to_string(),
+            TrackedMeeting {
+                usage: Arc::downgrade(&usage),
+                last: UsageTotals::default(),
+            },
+        );
+        usage
+    }
+
+    /// Compute rates since the previous sample, busiest (by messages) first.
+    ///
+    /// The result is also kept as [`MeetingUsageRegistry::latest`]. Meetings
+    /// whose actor has stopped are dropped.
+    pub fn sample(&self) -> Vec<MeetingUsageRate> {
+        let mut state = self.lock();
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_sampled).as_secs_f64();
+        state.last_sampled = now;
+
+        state
+            .meetings
+            .retain(|_, tracked| tracked.usage.strong_count() > 0);
+
+        let mut rates: Vec<MeetingUsageRate> = state
+            .meetings
+            .iter_mut()
+            .filter_map(|(meeting_id, tracked)| {
+                let totals = tracked.usage.upgrade()?.totals();
+                let previous = std::mem::replace(&mut tracked.last, totals);
+                Some(MeetingUsageRate {
+                    meeting_id: meeting_id.clone(),
+                    messages_per_second: per_second(totals.messages, previous.messages, elapsed),
+                    bytes_per_second: per_second(totals.bytes, previous.bytes, elapsed),
+                    redis_ops_per_second: per_second(totals.redis_ops, previous.redis_ops, elapsed),
+                    totals,
+                })
+            })
+            .collect();
+        rates.sort_by(|a, b| b.messages_per_second.total_cmp(&a.messages_per_second));
+
+        state.latest.clone_from(&rates);
+        rates
+    }
+
+    /// The report from the most recent [`MeetingUsageRegistry::sample`].
+    #[must_use]
+    pub fn latest(&self) -> Vec<MeetingUsageRate> {
+        self.lock().latest.clone()
+    }
+
+    /// Lock the state, recovering from poison: the counters stay valid even
+    /// if a holder panicked.
+    fn lock(&self) -> MutexGuard<'_, RegistryState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Default for MeetingUsageRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn per_second(current: u64, previous: u64, elapsed_secs: f64) -> f64 {
+    if elapsed_secs <= 0.0 {
+        return 0.0;
+    }
+    // u64 to f64 conversion is safe for realistic per-interval deltas (< 2^53)
+    #[allow(clippy::cast_precision_loss)]
+    let delta = current.saturating_sub(previous) as f64;
+    delta / elapsed_secs
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn rate(meeting_id: &str, messages: f64, bytes: f64) -> MeetingUsageRate {
+        MeetingUsageRate {
+            meeting_id: meeting_id.to_string(),
+            messages_per_second: messages,
+            bytes_per_second: bytes,
+            redis_ops_per_second: 0.0,
+            totals: UsageTotals::default(),
+        }
+    }
+
+    #[test]
+    fn test_usage_counters() {
+        let usage = MeetingUsage::default();
+        usage.record_message();
+        usage.record_message();
+        usage.record_bytes(1500);
+        usage.record_redis_op();
+
+        assert_eq!(
+            usage.totals(),
+            UsageTotals {
+                messages: 2,
+                bytes: 1500,
+                redis_ops: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_sample_reports_deltas() {
+        let registry = MeetingUsageRegistry::new();
+        let busy = registry.register("meeting-busy");
+        let quiet = registry.register("meeting-quiet");
+
+        for _ in 0..10 {
+            busy.record_message();
+        }
+        quiet.record_message();
+        std::thread::sleep(Duration::from_millis(10));
+
+        let rates = registry.sample();
+        assert_eq!(rates.len(), 2);
+        assert_eq!(rates[0].meeting_id, "meeting-busy");
+        assert!(rates[0].messages_per_second > rates[1].messages_per_second);
+        assert_eq!(rates[0].totals.messages, 10);
+        assert_eq!(registry.latest(), rates);
+
+        // Nothing happened since: rates drop to zero, totals are kept
+        let rates = registry.sample();
+        assert!(rates.iter().all(|r| r.messages_per_second == 0.0));
+        assert_eq!(rates[0].totals.messages + rates[1].totals.messages, 11);
+    }
+
+    #[test]
+    fn test_stopped_meetings_are_dropped() {
+        let registry = MeetingUsageRegistry::new();
+        let kept = registry.register("meeting-kept");
+        let stopped = registry.register("meeting-stopped");
+        drop(stopped);
+
+        let rates = registry.sample();
+        assert_eq!(rates.len(), 1);
+        assert_eq!(rates[0].meeting_id, "meeting-kept");
+        drop(kept);
+    }
+
+    #[test]
+    fn test_top_n_ranks_by_resource() {
+        let rates = vec![
+            rate("a", 5.0, 100.0),
+            rate("b", 50.0, 10.0),
+            rate("c", 20.0, 1000.0),
+        ];
+
+        let by_messages: Vec<&str> = top_n(&rates, UsageResource::Messages, 2)
+            .iter()
+            .map(|r| r.meeting_id.as_str())
+            .collect();
+        assert_eq!(by_messages, ["b", "c"]);
+
+        let by_bytes: Vec<&str> = top_n(&rates, UsageResource::Bytes, 5)
+            .iter()
+            .map(|r| r.meeting_id.as_str())
+            .collect();
+        assert_eq!(by_bytes, ["c", "a", "b"]);
+    }
+}
@@ -0,0 +1,164 @@
+//! `mc-state-migrate`: move MC meeting state in Redis to the current key
+//! schema and check it (see `mc_service::redis::migrate`).
+//!
+//! ```text
+//! mc-state-migrate scan
+//! mc-state-migrate migrate [--live] [--dry-run]
+//! mc-state-migrate verify
+//! ```
+//!
+//! Connects to `REDIS_URL`, as MC does. Exits non-zero if anything could
+//! not be migrated or failed verification.
+
+use mc_service::redis::keys;
+use mc_service::redis::migrate::{MigrationMode, MigrationOutcome, StateMigrator};
+use std::collections::BTreeMap;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: mc-state-migrate <scan | migrate [--live] [--dry-run] | verify>";
+
+#[derive(Debug)]
+enum Command {
+    Scan,
+    Migrate { mode: MigrationMode, dry_run: bool },
+    Verify,
+}
+
+fn parse_args(args: &[String]) -> Option<Command> {
+    let (command, flags) = args.split_first()?;
+    let has = |flag: &str| flags.iter().any(|f| f == flag);
+    let known = |allowed: &[&str]| flags.iter().all(|f| allowed.contains(&f.as_str()));
+    match command.as_str() {
+        "scan" if flags.is_empty() => Some(Command::Scan),
+        "migrate" if known(&["--live", "--dry-run"]) => Some(Command::Migrate {
+            mode: if has("--live") {
+                MigrationMode::Live
+            } else {
+                MigrationMode::Offline
+            },
+            dry_run: has("--dry-run"),
+        }),
+        "verify" if flags.is_empty() => Some(Command::Verify),
+        _ => None,
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some(command) = parse_args(&args) else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+    let Ok(redis_url) = std::env::var("REDIS_URL") else {
+        eprintln!("REDIS_URL is not set");
+        return ExitCode::from(2);
+    };
+
+    match run(command, &redis_url).await {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run `command`; `Ok(false)` means it finished but found problems.
+async fn run(command: Command, redis_url: &str) -> Result<bool, mc_service::errors::McError> {
+    let migrator = StateMigrator::connect(redis_url).await?;
+    let meetings = migrator.scan().await?;
+
+    match command {
+        Command::Scan => {
+            let mut per_version: BTreeMap<u32, usize> = BTreeMap::new();
+            for meeting in &meetings {
+                println!("v{}\t{}", meeting.version, meeting.meeting_id);
+                *per_version.entry(meeting.version).or_default() += 1;
+            }
+            for (version, count) in per_version {
+                println!("# schema v{version}: {count} meeting(s)");
+            }
+            println!("# current schema: v{}", keys::SCHEMA_VERSION);
+            Ok(true)
+        }
+        Command::Migrate { mode, dry_run } => {
+            let mut ok = true;
+            for meeting in meetings.iter().filter(|m| m.version < keys::SCHEMA_VERSION) {
+                if dry_run {
+                    for rename in keys::migration(&meeting.meeting_id, meeting.version) {
+                        println!("{} -> {}", rename.from, rename.to);
+                    }
+                    continue;
+                }
+                match migrator.migrate(meeting, mode).await? {
+                    MigrationOutcome::Current => {}
+                    MigrationOutcome::Moved(moved) => {
+                        println!(
+                            "{}: moved {moved} key(s) from v{}",
+                            meeting.meeting_id, meeting.version
+                        );
+                    }
+                    MigrationOutcome::Contended => {
+                        println!(
+                            "{}: generation kept changing, run again",
+                            meeting.meeting_id
+                        );
+                        ok = false;
+                    }
+                }
+            }
+            Ok(ok)
+        }
+        Command::Verify => {
+            let mut ok = true;
+            for meeting in &meetings {
+                if meeting.version != keys::SCHEMA_VERSION {
+                    println!(
+                        "{}: still on schema v{}",
+                        meeting.meeting_id, meeting.version
+                    );
+                    ok = false;
+                    continue;
+                }
+                for issue in migrator.verify(&meeting.meeting_id).await? {
+                    println!("{}: {issue}", meeting.meeting_id);
+                    ok = false;
+                }
+            }
+            Ok(ok)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert!(matches!(parse_args(&args(&["scan"])), Some(Command::Scan)));
+        assert!(matches!(
+            parse_args(&args(&["migrate"])),
+            Some(Command::Migrate {
+                mode: MigrationMode::Offline,
+                dry_run: false
+            })
+        ));
+        assert!(matches!(
+            parse_args(&args(&["migrate", "--dry-run", "--live"])),
+            Some(Command::Migrate {
+                mode: MigrationMode::Live,
+                dry_run: true
+            })
+        ));
+        assert!(parse_args(&args(&["migrate", "--force"])).is_none());
+        assert!(parse_args(&args(&["verify", "--live"])).is_none());
+        assert!(parse_args(&args(&[])).is_none());
+    }
+}
@@ -10,11 +10,30 @@
 //! - `AC_ENDPOINT`: Authentication Controller endpoint (e.g., `https://ac.example.com`)
 //! - `MC_CLIENT_ID`: OAuth client ID for MC
 //! - `MC_CLIENT_SECRET`: OAuth client secret for MC
-
+//!
+//! ## Profiles
+//!
+//! With `DT_PROFILE=dev` every required variable has a preset pointing at the
+//! local stack (`docker-compose.yml` Redis, AC on `localhost:8082`, certs
+//! from `scripts/generate-dev-certs.sh`, run from the repository root), so
+//! `DT_PROFILE=dev cargo run -p mc-service` boots with nothing else set.
+//! Environment variables override presets; see [`common::config`].
+
+use crate::redis::keys::SCHEMA_VERSION;
+use crate::webtransport::keepalive::{
+    DEFAULT_IDLE_TIMEOUT_SECONDS, DEFAULT_KEEPALIVE_INTERVAL_SECONDS,
+};
+use crate::webtransport::protocol::{DEFAULT_MIN_PROTOCOL_VERSION, PROTOCOL_VERSION};
+
+use common::config::ConfigProfile;
+use common::listen::UnixSocketConfig;
+use common::media_socket::MediaSocketConfig;
+use common::media_token::MediaTokenKey;
 use common::secret::SecretString;
 use std::collections::HashMap;
 use std::env;
 use std::fmt;
+use std::path::PathBuf;
 use thiserror::Error;
 
 /// Default WebTransport bind address.
@@ -38,6 +57,9 @@ pub const DEFAULT_NONCE_GRACE_WINDOW_SECONDS: u64 = 5;
 /// Default participant disconnect grace period in seconds (ADR-0023).
 pub const DEFAULT_DISCONNECT_GRACE_PERIOD_SECONDS: u64 = 30;
 
+/// Default seconds between meeting state divergence checks.
+pub const DEFAULT_STATE_CHECK_INTERVAL_SECONDS: u64 = 60;
+
 /// Default MC instance ID prefix.
 pub const DEFAULT_MC_ID_PREFIX: &str = "mc";
 
@@ -52,15 +74,20 @@ pub struct Config {
     /// Protected by `SecretString` to prevent accidental logging.
     pub redis_url: SecretString,
 
-    /// WebTransport server bind address (default: "0.0.0.0:4433").
+    /// WebTransport server bind addresses, comma-separated (default: "0.0.0.0:4433").
     pub webtransport_bind_address: String,
 
-    /// gRPC server bind address for GC communication (default: "0.0.0.0:50052").
+    /// gRPC server bind addresses for GC communication, comma-separated
+    /// (default: "0.0.0.0:50052").
     pub grpc_bind_address: String,
 
-    /// Health endpoint bind address (default: "0.0.0.0:8081").
+    /// Health endpoint bind addresses, comma-separated (default: "0.0.0.0:8081").
     pub health_bind_address: String,
 
+    /// Unix socket for the health/metrics server (`MC_HEALTH_SOCKET_PATH`,
+    /// `MC_HEALTH_SOCKET_MODE`). When set, replaces `health_bind_address`.
+    pub health_socket: Option<UnixSocketConfig>,
+
     /// Deployment region identifier (e.g., "us-east-1").
     pub region: String,
 
@@ -70,6 +97,14 @@ pub struct Config {
     /// Unique identifier for this MC instance.
     pub mc_id: String,
 
+    /// Primary MC this one is the warm standby for (`MC_STANDBY_FOR`,
+    /// default: none). A standby takes no meetings until GC promotes it.
+    pub standby_for: Option<String>,
+
+    /// Capacity pool this MC takes meetings for (`MC_POOL`, default: GC's
+    /// shared default pool). Organizations with dedicated MCs name a pool.
+    pub pool: Option<String>,
+
     /// Maximum concurrent meetings this MC can handle.
     pub max_meetings: u32,
 
@@ -88,11 +123,58 @@ pub struct Config {
     /// Participant disconnect grace period in seconds (default: 30, per ADR-0023).
     pub disconnect_grace_period_seconds: u64,
 
+    /// Oldest signaling protocol version accepted at handshake (default: 1,
+    /// every client). Must not exceed the version this MC speaks.
+    pub min_protocol_version: u32,
+
+    /// Seconds between keepalive pings to clients that negotiated
+    /// `CAPABILITY_KEEPALIVE` (default: 15).
+    pub keepalive_interval_seconds: u64,
+
+    /// Seconds without any client message before a keepalive-capable
+    /// connection is closed (default: 45). Must exceed the ping interval.
+    pub idle_timeout_seconds: u64,
+
+    /// Directory for per-connection signaling captures
+    /// (`MC_SESSION_CAPTURE_DIR`, default: off). Captures hold meeting
+    /// content with secrets stripped; enable only while chasing a bug.
+    pub session_capture_dir: Option<PathBuf>,
+
+    /// Address for signaling over raw QUIC streams, for native clients that
+    /// skip WebTransport (`MC_RAW_QUIC_BIND_ADDRESS`, default: off).
+    /// Accepts the same comma-separated list as the WebTransport address.
+    pub raw_quic_bind_address: Option<String>,
+
+    /// Accept 0-RTT data from raw QUIC clients resuming a TLS session
+    /// (`MC_ZERO_RTT_ENABLED`, default: true). Only replay-safe messages are
+    /// acted on before the handshake completes.
+    pub zero_rtt_enabled: bool,
+
+    /// Older Redis key schema to keep writing and fall back to on reads
+    /// while a schema change rolls out (`MC_REDIS_DUAL_WRITE_SCHEMA_VERSION`,
+    /// default: off). Must be below the schema this MC writes.
+    pub redis_dual_write_schema_version: Option<u32>,
+
+    /// Keep a per-meeting event journal in Redis, trimmed to about this
+    /// many entries (`MC_MEETING_JOURNAL_MAX_LEN`, default: off).
+    pub meeting_journal_max_len: Option<usize>,
+
+    /// Seconds between checks of each meeting's in-memory state against
+    /// Redis (`MC_STATE_CHECK_INTERVAL_SECONDS`, default: 60; 0 turns the
+    /// check off).
+    pub state_check_interval_seconds: u64,
+
     /// Master secret for binding token HMAC (base64-encoded).
     /// Rotates on each deployment for defense-in-depth.
     /// Protected by `SecretString` to prevent accidental logging.
     pub binding_token_secret: SecretString,
 
+    /// Secret shared with MH for media tokens (`MC_MEDIA_TOKEN_SECRET`,
+    /// base64, at least 32 bytes; default: off). When set, every
+    /// `JoinResponse` carries a media token binding the client's MH
+    /// connections to its signaling session (see [`common::media_token`]).
+    pub media_token_secret: Option<SecretString>,
+
     /// Authentication Controller endpoint for OAuth token acquisition.
     /// Must use HTTPS in production (enforced by TokenManager::new_secure).
     pub ac_endpoint: String,
@@ -125,6 +207,10 @@ pub struct Config {
     /// This is the address GC uses to reach this MC pod (e.g., `https://10.244.0.5:4433`).
     /// Required environment variable: `MC_WEBTRANSPORT_ADVERTISE_ADDRESS`.
     pub webtransport_advertise_address: String,
+
+    /// WebTransport socket tuning (`MC_MEDIA_DSCP`, `MC_MEDIA_SEND_BUFFER_BYTES`,
+    /// `MC_MEDIA_RECV_BUFFER_BYTES`, `MC_MEDIA_GSO_ENABLED`).
+    pub media_socket: MediaSocketConfig,
 }
 
 /// Custom Debug implementation that redacts sensitive fields.
@@ -135,9 +221,12 @@ impl fmt::Debug for Config {
             .field("webtransport_bind_address", &self.webtransport_bind_address)
             .field("grpc_bind_address", &self.grpc_bind_address)
             .field("health_bind_address", &self.health_bind_address)
+            .field("health_socket", &self.health_socket)
             .field("region", &self.region)
             .field("gc_grpc_url", &self.gc_grpc_url)
             .field("mc_id", &self.mc_id)
+            .field("standby_for", &self.standby_for)
+            .field("pool", &self.pool)
             .field("max_meetings", &self.max_meetings)
             .field("max_participants", &self.max_participants)
             .field("binding_token_ttl_seconds", &self.binding_token_ttl_seconds)
@@ -150,7 +239,29 @@ impl fmt::Debug for Config {
                 "disconnect_grace_period_seconds",
                 &self.disconnect_grace_period_seconds,
             )
+            .field("min_protocol_version", &self.min_protocol_version)
+            .field(
+                "keepalive_interval_seconds",
+                &self.keepalive_interval_seconds,
+            )
+            .field("idle_timeout_seconds", &self.idle_timeout_seconds)
+            .field("session_capture_dir", &self.session_capture_dir)
+            .field("raw_quic_bind_address", &self.raw_quic_bind_address)
+            .field("zero_rtt_enabled", &self.zero_rtt_enabled)
+            .field(
+                "redis_dual_write_schema_version",
+                &self.redis_dual_write_schema_version,
+            )
+            .field("meeting_journal_max_len", &self.meeting_journal_max_len)
+            .field(
+                "state_check_interval_seconds",
+                &self.state_check_interval_seconds,
+            )
             .field("binding_token_secret", &"[REDACTED]")
+            .field(
+                "media_token_secret",
+                &self.media_token_secret.as_ref().map(|_| "[REDACTED]"),
+            )
             .field("ac_endpoint", &self.ac_endpoint)
             .field("client_id", &self.client_id)
             .field("client_secret", &"[REDACTED]")
@@ -162,10 +273,52 @@ impl fmt::Debug for Config {
                 "webtransport_advertise_address",
                 &self.webtransport_advertise_address,
             )
+            .field("media_socket", &self.media_socket)
             .finish()
     }
 }
 
+/// Presets `profile` layers under the environment.
+///
+/// Dev secrets are the development values from
+/// `infra/services/mc-service/secret.yaml`; staging and prod take every
+/// secret from the environment.
+pub fn profile_presets(profile: ConfigProfile) -> &'static [(&'static str, &'static str)] {
+    match profile {
+        ConfigProfile::Dev => &[
+            (
+                "REDIS_URL",
+                "redis://:dev_password_change_in_production@localhost:6379",
+            ),
+            (
+                "MC_BINDING_TOKEN_SECRET",
+                "ZGV2LWJpbmRpbmctdG9rZW4tc2VjcmV0LWNoYW5nZS1pbi1wcm9k",
+            ),
+            (
+                "MC_MEDIA_TOKEN_SECRET",
+                "ZGV2LW1lZGlhLXRva2VuLXNlY3JldC1jaGFuZ2UtaW4tcHJvZA==",
+            ),
+            ("AC_ENDPOINT", "http://localhost:8082"),
+            ("AC_JWKS_URL", "http://localhost:8082/.well-known/jwks.json"),
+            ("MC_CLIENT_ID", "meeting-controller"),
+            ("MC_CLIENT_SECRET", "meeting-controller-secret-dev-002"),
+            ("MC_TLS_CERT_PATH", "infra/docker/certs/mc-webtransport.crt"),
+            ("MC_TLS_KEY_PATH", "infra/docker/certs/mc-webtransport.key"),
+            ("MC_GRPC_ADVERTISE_ADDRESS", "http://localhost:50052"),
+            (
+                "MC_WEBTRANSPORT_ADVERTISE_ADDRESS",
+                "https://localhost:4433",
+            ),
+            ("GC_GRPC_URL", "http://localhost:50051"),
+            // Room for a paused debugger between keepalives
+            ("MC_IDLE_TIMEOUT_SECONDS", "300"),
+            ("MC_MAX_MEETINGS", "50"),
+            ("MC_MAX_PARTICIPANTS", "500"),
+        ],
+        ConfigProfile::Staging | ConfigProfile::Prod => &[],
+    }
+}
+
 #[derive(Debug, Error)]
 #[allow(dead_code)] // InvalidValue used in Phase 6b+
 pub enum ConfigError {
@@ -177,9 +330,12 @@ pub enum ConfigError {
 }
 
 impl Config {
-    /// Load configuration from environment variables.
+    /// Load configuration from environment variables, layered over the
+    /// `DT_PROFILE` presets.
     pub fn from_env() -> Result<Self, ConfigError> {
-        Self::from_vars(&env::vars().collect())
+        let vars: HashMap<String, String> = env::vars().collect();
+        let profile = ConfigProfile::from_vars(&vars).map_err(ConfigError::InvalidValue)?;
+        Self::from_vars(&ConfigProfile::layer(profile_presets(profile), &vars))
     }
 
     /// Load configuration from a `HashMap` (for testing).
@@ -196,6 +352,17 @@ impl Config {
                 .clone(),
         );
 
+        // Checked here so a bad secret fails startup, not every join
+        let media_token_secret = vars
+            .get("MC_MEDIA_TOKEN_SECRET")
+            .filter(|secret| !secret.is_empty())
+            .map(|secret| {
+                MediaTokenKey::from_base64(secret)
+                    .map(|_| SecretString::from(secret.clone()))
+                    .map_err(|e| ConfigError::InvalidValue(format!("MC_MEDIA_TOKEN_SECRET: {e}")))
+            })
+            .transpose()?;
+
         let ac_endpoint = vars
             .get("AC_ENDPOINT")
             .ok_or_else(|| ConfigError::MissingEnvVar("AC_ENDPOINT".to_string()))?
@@ -315,6 +482,90 @@ impl Config {
             .and_then(|s| s.parse().ok())
             .unwrap_or(DEFAULT_DISCONNECT_GRACE_PERIOD_SECONDS);
 
+        let min_protocol_version = vars
+            .get("MC_MIN_PROTOCOL_VERSION")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_MIN_PROTOCOL_VERSION);
+        // A minimum above what this MC speaks would reject every client
+        if min_protocol_version > PROTOCOL_VERSION {
+            return Err(ConfigError::InvalidValue(format!(
+                "MC_MIN_PROTOCOL_VERSION ({min_protocol_version}) exceeds supported protocol version {PROTOCOL_VERSION}"
+            )));
+        }
+
+        let keepalive_interval_seconds = vars
+            .get("MC_KEEPALIVE_INTERVAL_SECONDS")
+            .and_then(|s| s.parse().ok())
+            .filter(|&secs| secs > 0)
+            .unwrap_or(DEFAULT_KEEPALIVE_INTERVAL_SECONDS);
+
+        let idle_timeout_seconds = vars
+            .get("MC_IDLE_TIMEOUT_SECONDS")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_IDLE_TIMEOUT_SECONDS);
+        // A live client only proves itself by answering pings
+        if idle_timeout_seconds <= keepalive_interval_seconds {
+            return Err(ConfigError::InvalidValue(format!(
+                "MC_IDLE_TIMEOUT_SECONDS ({idle_timeout_seconds}) must exceed MC_KEEPALIVE_INTERVAL_SECONDS ({keepalive_interval_seconds})"
+            )));
+        }
+
+        let session_capture_dir = vars
+            .get("MC_SESSION_CAPTURE_DIR")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
+
+        let raw_quic_bind_address = vars
+            .get("MC_RAW_QUIC_BIND_ADDRESS")
+            .filter(|addr| !addr.is_empty())
+            .cloned();
+
+        let zero_rtt_enabled = vars
+            .get("MC_ZERO_RTT_ENABLED")
+            .map(|value| {
+                value.parse().map_err(|_| {
+                    ConfigError::InvalidValue(format!(
+                        "MC_ZERO_RTT_ENABLED: expected 'true' or 'false', got '{value}'"
+                    ))
+                })
+            })
+            .transpose()?
+            .unwrap_or(true);
+
+        // Unlike the tuning knobs above, a typo here must not silently turn
+        // dual-write off mid-rollout
+        let redis_dual_write_schema_version = vars
+            .get("MC_REDIS_DUAL_WRITE_SCHEMA_VERSION")
+            .filter(|version| !version.is_empty())
+            .map(|version| {
+                version
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|&version| version < SCHEMA_VERSION)
+                    .ok_or_else(|| {
+                        ConfigError::InvalidValue(format!(
+                            "MC_REDIS_DUAL_WRITE_SCHEMA_VERSION ({version}) must be an older schema than v{SCHEMA_VERSION}"
+                        ))
+                    })
+            })
+            .transpose()?;
+
+        let meeting_journal_max_len = vars
+            .get("MC_MEETING_JOURNAL_MAX_LEN")
+            .and_then(|s| s.parse().ok())
+            .filter(|&len| len > 0);
+
+        let state_check_interval_seconds = vars
+            .get("MC_STATE_CHECK_INTERVAL_SECONDS")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_STATE_CHECK_INTERVAL_SECONDS);
+
+        let health_socket =
+            UnixSocketConfig::from_vars(vars, "MC_HEALTH").map_err(ConfigError::InvalidValue)?;
+
+        let media_socket =
+            MediaSocketConfig::from_vars(vars, "MC").map_err(ConfigError::InvalidValue)?;
+
         // Generate MC instance ID
         let mc_id = vars.get("MC_ID").cloned().unwrap_or_else(|| {
             let hostname = std::env::var("HOSTNAME").unwrap_or_else(|_| "unknown".to_string());
@@ -323,21 +574,46 @@ impl Config {
             format!("{DEFAULT_MC_ID_PREFIX}-{hostname}-{short_suffix}")
         });
 
+        let standby_for = vars
+            .get("MC_STANDBY_FOR")
+            .filter(|primary| !primary.is_empty())
+            .cloned();
+        if standby_for.as_ref() == Some(&mc_id) {
+            return Err(ConfigError::InvalidValue(format!(
+                "MC_STANDBY_FOR ({mc_id}) must name another MC"
+            )));
+        }
+
+        let pool = vars.get("MC_POOL").filter(|pool| !pool.is_empty()).cloned();
+
         Ok(Config {
             redis_url,
             webtransport_bind_address,
             grpc_bind_address,
             health_bind_address,
+            health_socket,
             region,
             gc_grpc_url,
             mc_id,
+            standby_for,
+            pool,
             max_meetings,
             max_participants,
             binding_token_ttl_seconds,
             clock_skew_seconds,
             nonce_grace_window_seconds,
             disconnect_grace_period_seconds,
+            min_protocol_version,
+            keepalive_interval_seconds,
+            idle_timeout_seconds,
+            session_capture_dir,
+            raw_quic_bind_address,
+            zero_rtt_enabled,
+            redis_dual_write_schema_version,
+            meeting_journal_max_len,
+            state_check_interval_seconds,
             binding_token_secret,
+            media_token_secret,
             ac_endpoint,
             client_id,
             client_secret,
@@ -346,6 +622,7 @@ impl Config {
             tls_key_path,
             grpc_advertise_address,
             webtransport_advertise_address,
+            media_socket,
         })
     }
 }
@@ -392,6 +669,33 @@ mod tests {
         ])
     }
 
+    #[test]
+    fn test_dev_profile_presets_boot_without_env() {
+        // Only the cert files are environment-specific; point them at
+        // something that exists
+        let vars = HashMap::from([
+            ("MC_TLS_CERT_PATH".to_string(), "/dev/null".to_string()),
+            ("MC_TLS_KEY_PATH".to_string(), "/dev/null".to_string()),
+            ("MC_MAX_MEETINGS".to_string(), "5".to_string()),
+        ]);
+
+        let layered = ConfigProfile::layer(profile_presets(ConfigProfile::Dev), &vars);
+        let config = Config::from_vars(&layered).expect("Dev presets should load");
+
+        assert_eq!(config.ac_endpoint, "http://localhost:8082");
+        assert_eq!(config.client_id, "meeting-controller");
+        assert_eq!(config.idle_timeout_seconds, 300);
+        // Environment wins over the preset
+        assert_eq!(config.max_meetings, 5);
+    }
+
+    #[test]
+    fn test_prod_profile_has_no_presets() {
+        let layered = ConfigProfile::layer(profile_presets(ConfigProfile::Prod), &HashMap::new());
+        let result = Config::from_vars(&layered);
+        assert!(matches!(result, Err(ConfigError::MissingEnvVar(_))));
+    }
+
     #[test]
     fn test_from_vars_success_with_defaults() {
         let vars = base_vars();
@@ -422,6 +726,12 @@ mod tests {
             config.disconnect_grace_period_seconds,
             DEFAULT_DISCONNECT_GRACE_PERIOD_SECONDS
         );
+        assert_eq!(config.min_protocol_version, DEFAULT_MIN_PROTOCOL_VERSION);
+        assert_eq!(
+            config.keepalive_interval_seconds,
+            DEFAULT_KEEPALIVE_INTERVAL_SECONDS
+        );
+        assert_eq!(config.idle_timeout_seconds, DEFAULT_IDLE_TIMEOUT_SECONDS);
         // MC ID should be auto-generated
         assert!(config.mc_id.starts_with("mc-"));
         assert_eq!(
@@ -578,6 +888,255 @@ mod tests {
         assert!(matches!(result, Err(ConfigError::InvalidValue(msg)) if msg.contains("http")));
     }
 
+    #[test]
+    fn test_min_protocol_version() {
+        let mut vars = base_vars();
+        vars.insert(
+            "MC_MIN_PROTOCOL_VERSION".to_string(),
+            PROTOCOL_VERSION.to_string(),
+        );
+        let config = Config::from_vars(&vars).unwrap();
+        assert_eq!(config.min_protocol_version, PROTOCOL_VERSION);
+
+        vars.insert(
+            "MC_MIN_PROTOCOL_VERSION".to_string(),
+            (PROTOCOL_VERSION + 1).to_string(),
+        );
+        let result = Config::from_vars(&vars);
+        assert!(
+            matches!(result, Err(ConfigError::InvalidValue(msg)) if msg.contains("MC_MIN_PROTOCOL_VERSION"))
+        );
+    }
+
+    #[test]
+    fn test_media_token_secret() {
+        let mut vars = base_vars();
+        assert!(Config::from_vars(&vars)
+            .unwrap()
+            .media_token_secret
+            .is_none());
+
+        vars.insert(
+            "MC_MEDIA_TOKEN_SECRET".to_string(),
+            "dGVzdC1tZWRpYS10b2tlbi1zZWNyZXQtMDEyMzQ1Njc4OQ==".to_string(),
+        );
+        let config = Config::from_vars(&vars).unwrap();
+        assert_eq!(
+            config.media_token_secret.unwrap().expose_secret(),
+            "dGVzdC1tZWRpYS10b2tlbi1zZWNyZXQtMDEyMzQ1Njc4OQ=="
+        );
+    }
+
+    #[test]
+    fn test_media_token_secret_rejects_invalid() {
+        // Too short, and not base64
+        for secret in ["dGVzdA==", "not base64!"] {
+            let mut vars = base_vars();
+            vars.insert("MC_MEDIA_TOKEN_SECRET".to_string(), secret.to_string());
+            let result = Config::from_vars(&vars);
+            assert!(
+                matches!(result, Err(ConfigError::InvalidValue(msg)) if msg.contains("MC_MEDIA_TOKEN_SECRET"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_session_capture_dir() {
+        let mut vars = base_vars();
+        assert_eq!(Config::from_vars(&vars).unwrap().session_capture_dir, None);
+
+        vars.insert("MC_SESSION_CAPTURE_DIR".to_string(), String::new());
+        assert_eq!(Config::from_vars(&vars).unwrap().session_capture_dir, None);
+
+        vars.insert(
+            "MC_SESSION_CAPTURE_DIR".to_string(),
+            "/var/lib/mc/captures".to_string(),
+        );
+        assert_eq!(
+            Config::from_vars(&vars).unwrap().session_capture_dir,
+            Some(PathBuf::from("/var/lib/mc/captures"))
+        );
+    }
+
+    #[test]
+    fn test_raw_quic_bind_address() {
+        let mut vars = base_vars();
+        assert_eq!(
+            Config::from_vars(&vars).unwrap().raw_quic_bind_address,
+            None
+        );
+
+        vars.insert("MC_RAW_QUIC_BIND_ADDRESS".to_string(), String::new());
+        assert_eq!(
+            Config::from_vars(&vars).unwrap().raw_quic_bind_address,
+            None
+        );
+
+        vars.insert(
+            "MC_RAW_QUIC_BIND_ADDRESS".to_string(),
+            "0.0.0.0:4434".to_string(),
+        );
+        assert_eq!(
+            Config::from_vars(&vars).unwrap().raw_quic_bind_address,
+            Some("0.0.0.0:4434".to_string())
+        );
+    }
+
+    #[test]
+    fn test_zero_rtt_enabled() {
+        let mut vars = base_vars();
+        assert!(Config::from_vars(&vars).unwrap().zero_rtt_enabled);
+
+        vars.insert("MC_ZERO_RTT_ENABLED".to_string(), "false".to_string());
+        assert!(!Config::from_vars(&vars).unwrap().zero_rtt_enabled);
+
+        vars.insert("MC_ZERO_RTT_ENABLED".to_string(), "off".to_string());
+        assert!(matches!(
+            Config::from_vars(&vars),
+            Err(ConfigError::InvalidValue(msg)) if msg.contains("MC_ZERO_RTT_ENABLED")
+        ));
+    }
+
+    #[test]
+    fn test_redis_dual_write_schema_version() {
+        let mut vars = base_vars();
+        assert_eq!(
+            Config::from_vars(&vars)
+                .unwrap()
+                .redis_dual_write_schema_version,
+            None
+        );
+
+        vars.insert(
+            "MC_REDIS_DUAL_WRITE_SCHEMA_VERSION".to_string(),
+            "0".to_string(),
+        );
+        assert_eq!(
+            Config::from_vars(&vars)
+                .unwrap()
+                .redis_dual_write_schema_version,
+            Some(0)
+        );
+
+        for invalid in [SCHEMA_VERSION.to_string(), "v0".to_string()] {
+            vars.insert("MC_REDIS_DUAL_WRITE_SCHEMA_VERSION".to_string(), invalid);
+            let result = Config::from_vars(&vars);
+            assert!(
+                matches!(result, Err(ConfigError::InvalidValue(msg)) if msg.contains("MC_REDIS_DUAL_WRITE_SCHEMA_VERSION"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_meeting_journal_max_len() {
+        let mut vars = base_vars();
+        assert_eq!(
+            Config::from_vars(&vars).unwrap().meeting_journal_max_len,
+            None
+        );
+
+        vars.insert("MC_MEETING_JOURNAL_MAX_LEN".to_string(), "0".to_string());
+        assert_eq!(
+            Config::from_vars(&vars).unwrap().meeting_journal_max_len,
+            None
+        );
+
+        vars.insert("MC_MEETING_JOURNAL_MAX_LEN".to_string(), "5000".to_string());
+        assert_eq!(
+            Config::from_vars(&vars).unwrap().meeting_journal_max_len,
+            Some(5000)
+        );
+    }
+
+    #[test]
+    fn test_standby_for() {
+        let mut vars = base_vars();
+        vars.insert("MC_ID".to_string(), "mc-1".to_string());
+        assert_eq!(Config::from_vars(&vars).unwrap().standby_for, None);
+
+        vars.insert("MC_STANDBY_FOR".to_string(), String::new());
+        assert_eq!(Config::from_vars(&vars).unwrap().standby_for, None);
+
+        vars.insert("MC_STANDBY_FOR".to_string(), "mc-0".to_string());
+        assert_eq!(
+            Config::from_vars(&vars).unwrap().standby_for.as_deref(),
+            Some("mc-0")
+        );
+
+        vars.insert("MC_STANDBY_FOR".to_string(), "mc-1".to_string());
+        let result = Config::from_vars(&vars);
+        assert!(
+            matches!(result, Err(ConfigError::InvalidValue(msg)) if msg.contains("MC_STANDBY_FOR"))
+        );
+    }
+
+    #[test]
+    fn test_pool() {
+        let mut vars = base_vars();
+        assert_eq!(Config::from_vars(&vars).unwrap().pool, None);
+
+        vars.insert("MC_POOL".to_string(), String::new());
+        assert_eq!(Config::from_vars(&vars).unwrap().pool, None);
+
+        vars.insert("MC_POOL".to_string(), "enterprise-acme".to_string());
+        assert_eq!(
+            Config::from_vars(&vars).unwrap().pool.as_deref(),
+            Some("enterprise-acme")
+        );
+    }
+
+    #[test]
+    fn test_state_check_interval() {
+        let mut vars = base_vars();
+        assert_eq!(
+            Config::from_vars(&vars)
+                .unwrap()
+                .state_check_interval_seconds,
+            DEFAULT_STATE_CHECK_INTERVAL_SECONDS
+        );
+
+        vars.insert(
+            "MC_STATE_CHECK_INTERVAL_SECONDS".to_string(),
+            "0".to_string(),
+        );
+        assert_eq!(
+            Config::from_vars(&vars)
+                .unwrap()
+                .state_check_interval_seconds,
+            0
+        );
+
+        vars.insert(
+            "MC_STATE_CHECK_INTERVAL_SECONDS".to_string(),
+            "300".to_string(),
+        );
+        assert_eq!(
+            Config::from_vars(&vars)
+                .unwrap()
+                .state_check_interval_seconds,
+            300
+        );
+    }
+
+    #[test]
+    fn test_idle_timeout_must_exceed_keepalive_interval() {
+        let mut vars = base_vars();
+        vars.insert(
+            "MC_KEEPALIVE_INTERVAL_SECONDS".to_string(),
+            "10".to_string(),
+        );
+        vars.insert("MC_IDLE_TIMEOUT_SECONDS".to_string(), "25".to_string());
+        let config = Config::from_vars(&vars).unwrap();
+        assert_eq!(config.keepalive_interval_seconds, 10);
+        assert_eq!(config.idle_timeout_seconds, 25);
+
+        vars.insert("MC_IDLE_TIMEOUT_SECONDS".to_string(), "10".to_string());
+        let result = Config::from_vars(&vars);
+        assert!(
+            matches!(result, Err(ConfigError::InvalidValue(msg)) if msg.contains("MC_IDLE_TIMEOUT_SECONDS"))
+        );
+    }
+
     #[test]
     fn test_ac_jwks_url_http_allowed() {
         let mut vars = base_vars();
@@ -669,4 +1228,42 @@ mod tests {
             matches!(result, Err(ConfigError::MissingEnvVar(v)) if v == "MC_WEBTRANSPORT_ADVERTISE_ADDRESS")
         );
     }
+
+    #[test]
+    fn test_media_socket_config() {
+        let config = Config::from_vars(&base_vars()).expect("Config should load successfully");
+        assert_eq!(config.media_socket, MediaSocketConfig::default());
+
+        let mut vars = base_vars();
+        vars.insert("MC_MEDIA_DSCP".to_string(), "af41".to_string());
+        vars.insert("MC_MEDIA_GSO_ENABLED".to_string(), "false".to_string());
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(config.media_socket.dscp, Some(34));
+        assert!(!config.media_socket.gso_enabled);
+
+        vars.insert("MC_MEDIA_SEND_BUFFER_BYTES".to_string(), "0".to_string());
+        let result = Config::from_vars(&vars);
+        assert!(matches!(result, Err(ConfigError::InvalidValue(_))));
+    }
+
+    #[test]
+    fn test_health_socket_config() {
+        let config = Config::from_vars(&base_vars()).expect("Config should load successfully");
+        assert_eq!(config.health_socket, None);
+
+        let mut vars = base_vars();
+        vars.insert(
+            "MC_HEALTH_SOCKET_PATH".to_string(),
+            "/run/mc/health.sock".to_string(),
+        );
+        vars.insert("MC_HEALTH_SOCKET_MODE".to_string(), "0600".to_string());
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        let socket = config.health_socket.expect("health socket should be set");
+        assert_eq!(socket.path, std::path::PathBuf::from("/run/mc/health.sock"));
+        assert_eq!(socket.mode, 0o600);
+
+        vars.insert("MC_HEALTH_SOCKET_MODE".to_string(), "999".to_string());
+        let result = Config::from_vars(&vars);
+        assert!(matches!(result, Err(ConfigError::InvalidValue(_))));
+    }
 }
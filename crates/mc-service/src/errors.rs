@@ -3,16 +3,22 @@
 //! Error types map to appropriate signaling `ErrorCode` values for client responses.
 //! Internal details are logged server-side but not exposed to clients.
 
+use common::error::ErrorCode;
 use thiserror::Error;
 
 /// Meeting Controller error type.
 ///
 /// Maps to signaling `ErrorCode` values:
+/// - `InvalidRequest`, `MessageRejected`: `INVALID_REQUEST` (1)
 /// - `SessionBinding` errors: `UNAUTHORIZED` (2)
 /// - `NotFound`: `NOT_FOUND` (4)
 /// - `Conflict`: `CONFLICT` (5)
 /// - Internal, Redis, Config, Grpc: `INTERNAL_ERROR` (6)
 /// - `CapacityExceeded`: `CAPACITY_EXCEEDED` (7)
+/// - `RateLimited`: `RATE_LIMITED` (9)
+/// - `E2eUnsupported`: `E2E_UNSUPPORTED` (10)
+/// - `UnsupportedProtocolVersion`: `UNSUPPORTED_VERSION` (11)
+/// - `SessionReplaced`: `SESSION_REPLACED` (12)
 #[derive(Debug, Error)]
 #[allow(dead_code)] // Error types used in Phase 6b+
 pub enum McError {
@@ -83,6 +89,36 @@ pub enum McError {
     #[error("Permission denied: {0}")]
     PermissionDenied(String),
 
+    /// Client sent an invalid signaling request (message is client-safe).
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+
+    /// Client message failed schema validation before dispatch (message is
+    /// client-safe).
+    #[error("Message rejected: {0}")]
+    MessageRejected(String),
+
+    /// Client exceeded a per-participant rate limit.
+    #[error("Rate limited")]
+    RateLimited,
+
+    /// Feature needs server-side media processing, which an end-to-end
+    /// encrypted meeting does not allow (message is client-safe).
+    #[error("Unsupported in E2E meeting: {0}")]
+    E2eUnsupported(String),
+
+    /// Client protocol version is below this MC's minimum.
+    #[error("Unsupported protocol version {client_version} (minimum {min_version})")]
+    UnsupportedProtocolVersion {
+        client_version: u32,
+        min_version: u32,
+    },
+
+    /// The same user joined the meeting from another connection, which
+    /// took over under the meeting's duplicate join policy.
+    #[error("Session replaced by a newer join")]
+    SessionReplaced,
+
     /// MH assignment data missing from Redis during join flow.
     #[error("MH assignment missing: {0}")]
     MhAssignmentMissing(String),
@@ -142,14 +178,19 @@ impl McError {
             | McError::TokenAcquisitionTimeout => {
                 6 // INTERNAL_ERROR
             }
-            McError::SessionBinding(_) | McError::JwtValidation(_) => 2, // UNAUTHORIZED
-            McError::PermissionDenied(_) => 3,                           // FORBIDDEN
+            McError::InvalidRequest(_) | McError::MessageRejected(_) => 1, // INVALID_REQUEST
+            McError::SessionBinding(_) | McError::JwtValidation(_) => 2,   // UNAUTHORIZED
+            McError::PermissionDenied(_) => 3,                             // FORBIDDEN
             McError::MeetingNotFound(_) | McError::ParticipantNotFound(_) => 4, // NOT_FOUND
-            McError::Conflict(_) => 5,                                   // CONFLICT
+            McError::Conflict(_) => 5,                                     // CONFLICT
             McError::MeetingCapacityExceeded(_)
             | McError::McCapacityExceeded
             | McError::Draining
             | McError::Migrating { .. } => 7, // CAPACITY_EXCEEDED
+            McError::RateLimited => 9,                                     // RATE_LIMITED
+            McError::E2eUnsupported(_) => 10,                              // E2E_UNSUPPORTED
+            McError::UnsupportedProtocolVersion { .. } => 11,              // UNSUPPORTED_VERSION
+            McError::SessionReplaced => 12,                                // SESSION_REPLACED
         }
     }
 
@@ -161,11 +202,47 @@ impl McError {
     /// MC uses signaling codes (not HTTP status codes) since it communicates
     /// via WebTransport, not HTTP.
     #[allow(dead_code)] // Reserved; see doc-comment above.
-    #[allow(clippy::cast_sign_loss)] // error_code() returns well-known positive values 2-7
+    #[allow(clippy::cast_sign_loss)] // error_code() returns well-known positive values 1-10
     pub fn status_code(&self) -> u16 {
         self.error_code() as u16
     }
 
+    /// Returns the stable registry code sent alongside `error_code()`.
+    ///
+    /// Infrastructure failures are grouped so clients see a category, not
+    /// which backend failed.
+    pub fn dt_code(&self) -> ErrorCode {
+        match self {
+            McError::Redis(_) => ErrorCode::McStorageUnavailable,
+            McError::Grpc(_) => ErrorCode::McUpstreamUnavailable,
+            McError::TokenAcquisition(_) | McError::TokenAcquisitionTimeout => {
+                ErrorCode::McServiceAuthUnavailable
+            }
+            McError::Config(_) | McError::InvalidArgument(_) | McError::Internal(_) => {
+                ErrorCode::McInternal
+            }
+            McError::NotRegistered => ErrorCode::McNotRegistered,
+            McError::SessionBinding(_) => ErrorCode::McSessionBinding,
+            McError::MeetingNotFound(_) => ErrorCode::McMeetingNotFound,
+            McError::ParticipantNotFound(_) => ErrorCode::McParticipantNotFound,
+            McError::MeetingCapacityExceeded(_) => ErrorCode::McMeetingAtCapacity,
+            McError::McCapacityExceeded => ErrorCode::McAtCapacity,
+            McError::Draining => ErrorCode::McDraining,
+            McError::Migrating { .. } => ErrorCode::McMigrating,
+            McError::FencedOut(_) => ErrorCode::McFencedOut,
+            McError::Conflict(_) => ErrorCode::McConflict,
+            McError::JwtValidation(_) => ErrorCode::McInvalidToken,
+            McError::PermissionDenied(_) => ErrorCode::McPermissionDenied,
+            McError::InvalidRequest(_) => ErrorCode::McInvalidRequest,
+            McError::MessageRejected(_) => ErrorCode::McMessageRejected,
+            McError::RateLimited => ErrorCode::McRateLimited,
+            McError::E2eUnsupported(_) => ErrorCode::McE2eUnsupported,
+            McError::UnsupportedProtocolVersion { .. } => ErrorCode::McUnsupportedProtocolVersion,
+            McError::SessionReplaced => ErrorCode::McSessionReplaced,
+            McError::MhAssignmentMissing(_) => ErrorCode::McMhAssignmentMissing,
+        }
+    }
+
     /// Returns a bounded label string for the error variant (for metrics).
     ///
     /// Uses enum variant names, not error message content.
@@ -189,6 +266,12 @@ impl McError {
             McError::Conflict(_) => "conflict",
             McError::JwtValidation(_) => "jwt_validation",
             McError::PermissionDenied(_) => "permission_denied",
+            McError::InvalidRequest(_) => "invalid_request",
+            McError::MessageRejected(_) => "message_rejected",
+            McError::RateLimited => "rate_limited",
+            McError::E2eUnsupported(_) => "e2e_unsupported",
+            McError::UnsupportedProtocolVersion { .. } => "unsupported_protocol_version",
+            McError::SessionReplaced => "session_replaced",
             McError::MhAssignmentMissing(_) => "mh_assignment_missing",
             McError::Internal(_) => "internal",
             McError::TokenAcquisition(_) => "token_acquisition",
@@ -218,7 +301,19 @@ impl McError {
             McError::Migrating { .. } => "Meeting is being migrated, please reconnect".to_string(),
             McError::FencedOut(_) => "An internal error occurred".to_string(),
             McError::JwtValidation(_) => "Invalid or expired token".to_string(),
-            McError::Conflict(msg) | McError::PermissionDenied(msg) => msg.clone(),
+            McError::Conflict(msg)
+            | McError::PermissionDenied(msg)
+            | McError::InvalidRequest(msg)
+            | McError::MessageRejected(msg)
+            | McError::E2eUnsupported(msg) => msg.clone(),
+            McError::RateLimited => "Too many requests, please slow down".to_string(),
+            McError::SessionReplaced => "You joined this meeting from another device".to_string(),
+            McError::UnsupportedProtocolVersion {
+                client_version,
+                min_version,
+            } => format!(
+                "Protocol version {client_version} is not supported, minimum is {min_version}"
+            ),
         }
     }
 }
@@ -308,6 +403,26 @@ mod tests {
             5
         );
 
+        // Invalid request -> 1, rate limited -> 9, E2E unsupported -> 10
+        assert_eq!(
+            McError::InvalidRequest("bad poll".to_string()).error_code(),
+            1
+        );
+        assert_eq!(McError::RateLimited.error_code(), 9);
+        assert_eq!(
+            McError::E2eUnsupported("no recording".to_string()).error_code(),
+            10
+        );
+        assert_eq!(
+            McError::UnsupportedProtocolVersion {
+                client_version: 1,
+                min_version: 2,
+            }
+            .error_code(),
+            11
+        );
+        assert_eq!(McError::SessionReplaced.error_code(), 12);
+
         // Capacity exceeded -> 7
         assert_eq!(
             McError::MeetingCapacityExceeded("max 100".to_string()).error_code(),
@@ -324,6 +439,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dt_code_mapping() {
+        assert_eq!(
+            McError::FencedOut("stale".to_string()).dt_code().as_str(),
+            "DT-MC-2003"
+        );
+        assert_eq!(
+            McError::Redis("conn failed".to_string()).dt_code(),
+            ErrorCode::McStorageUnavailable
+        );
+        assert_eq!(
+            McError::TokenAcquisitionTimeout.dt_code(),
+            McError::TokenAcquisition("failed".to_string()).dt_code()
+        );
+        assert_eq!(
+            McError::SessionBinding(SessionBindingError::NonceReused).dt_code(),
+            ErrorCode::McSessionBinding
+        );
+        assert!(McError::Draining.dt_code().as_str().starts_with("DT-MC-"));
+    }
+
     #[test]
     fn test_client_messages_hide_internal_details() {
         // Internal errors should not leak details
@@ -431,11 +567,15 @@ mod tests {
             .status_code(),
             7
         );
+
+        // Invalid request -> 1, rate limited -> 9, E2E unsupported -> 10
+        assert_eq!(McError::InvalidRequest("test".to_string()).status_code(), 1);
+        assert_eq!(McError::RateLimited.status_code(), 9);
     }
 
     #[test]
     fn test_error_type_label_exhaustive() {
-        // Verify all 20 McError variants map to bounded &'static str labels
+        // Verify all 22 McError variants map to bounded &'static str labels
         assert_eq!(
             McError::Redis("test".to_string()).error_type_label(),
             "redis"
@@ -494,6 +634,15 @@ mod tests {
             McError::PermissionDenied("test".to_string()).error_type_label(),
             "permission_denied"
         );
+        assert_eq!(
+            McError::InvalidRequest("test".to_string()).error_type_label(),
+            "invalid_request"
+        );
+        assert_eq!(McError::RateLimited.error_type_label(), "rate_limited");
+        assert_eq!(
+            McError::E2eUnsupported("no recording".to_string()).error_type_label(),
+            "e2e_unsupported"
+        );
         assert_eq!(
             McError::MhAssignmentMissing("test".to_string()).error_type_label(),
             "mh_assignment_missing"
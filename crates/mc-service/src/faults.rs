@@ -0,0 +1,18 @@
+//! Fault injection points (`fault-injection` feature).
+//!
+//! Names accepted by `PUT /debug/faults/{operation}` on MC. See
+//! `common::fault_injection` for the endpoint and fault kinds.
+
+/// Fenced Redis writes (MH assignment, interactions, meeting state).
+/// `error` fails the write as a lost Redis connection does
+/// (`McError::Redis`); `drop` skips the write and reports success, as a
+/// write lost on a Redis failover would.
+pub const REDIS_WRITE: &str = "redis_write";
+
+/// Fast and comprehensive heartbeats to GC. `error` fails the RPC
+/// (`McError::Grpc`); `drop` skips it and reports success, so GC stops
+/// hearing from this MC while MC believes it is still registered.
+pub const GC_HEARTBEAT: &str = "gc_heartbeat";
+
+/// Every injection point MC has.
+pub const OPERATIONS: &[&str] = &[REDIS_WRITE, GC_HEARTBEAT];
@@ -2,8 +2,10 @@
 //!
 //! Provides a client for MC→GC communication per ADR-0023 Phase 6c:
 //! - Registration on startup
-//! - Fast heartbeat (10s) - capacity updates
+//! - Fast heartbeat (10s) - capacity updates, over a heartbeat stream when
+//!   GC supports one (unary otherwise)
 //! - Comprehensive heartbeat (30s) - full metrics
+//! - Attendance report on meeting end
 //!
 //! # Security (ADR-0010)
 //!
@@ -18,21 +20,45 @@
 //! The tonic `Channel` is designed to be cloned cheaply and used concurrently.
 //! From the docs: "Channel provides a Clone implementation that is cheap".
 //! No locking is needed - just clone the channel for each request.
+//!
+//! # Heartbeat Stream
+//!
+//! [`GcClient::open_heartbeat_stream`] opens a long-lived `StreamHeartbeat`
+//! call so fast heartbeats do not each cost a request. GC acknowledges every
+//! heartbeat with a [`HeartbeatDirective`]; `REREGISTER` is surfaced as
+//! `McError::NotRegistered`, the same as a unary heartbeat's `NOT_FOUND`.
+//! A GC that predates the stream answers `UNIMPLEMENTED`, after which the
+//! client stays on unary heartbeats until it next re-registers.
+//!
+//! Acks also carry operator directives ([`PushedDirective`]), each sent
+//! once. Heartbeat interval changes are applied by the caller's timers;
+//! the rest go to the `MeetingControllerActor`.
 
+use crate::actors::{MeetingAttendance, MeetingEndSummary, OperatorDirective};
 use crate::config::Config;
 use crate::errors::McError;
 use crate::observability::{record_gc_heartbeat, record_gc_heartbeat_latency};
+use crate::standby::StandbySnapshots;
+use common::request_id::{RequestId, REQUEST_ID_HEADER};
 use common::secret::ExposeSecret;
 use common::token_manager::TokenReceiver;
+use common::warn_throttled;
+use proto_gen::dark_tower::internal::v1::controller_directive::Action;
 use proto_gen::dark_tower::internal::v1::global_controller_service_client::GlobalControllerServiceClient;
 use proto_gen::dark_tower::internal::v1::{
-    ComprehensiveHeartbeatRequest, ControllerCapacity, FastHeartbeatRequest, HealthStatus,
-    RegisterMcRequest,
+    AttendanceRecord, BuildVersion, ComprehensiveHeartbeatRequest, ControllerCapacity,
+    ControllerDirective, FastHeartbeatRequest, HealthStatus, HeartbeatDirective, MeetingTelemetry,
+    NotifyMeetingEndedRequest, RegisterMcRequest, ReportAttendanceRequest, StreamHeartbeatRequest,
+    StreamHeartbeatResponse,
 };
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::transport::{Channel, Endpoint};
-use tonic::Request;
+use tonic::{Request, Streaming};
 use tracing::{debug, error, info, instrument, warn};
 
 /// Default timeout for GC RPC calls.
@@ -65,6 +91,96 @@ const BACKOFF_BASE: Duration = Duration::from_secs(1);
 /// Maximum backoff delay.
 const BACKOFF_MAX: Duration = Duration::from_secs(30);
 
+/// Heartbeats queued on a heartbeat stream before it counts as stalled.
+const HEARTBEAT_STREAM_BUFFER: usize = 4;
+
+/// Build reported at registration, checked against GC's minimum fleet version.
+fn build_version() -> BuildVersion {
+    let info = common::build_info!("mc-service");
+    BuildVersion {
+        version: info.version.to_string(),
+        git_sha: info.git_sha.to_string(),
+        proto_schema: proto_gen::SCHEMA_VERSION.to_string(),
+    }
+}
+
+/// An open heartbeat stream to GC.
+///
+/// Dropping it closes the stream.
+pub struct HeartbeatStream {
+    /// Outbound heartbeats.
+    sender: mpsc::Sender<StreamHeartbeatRequest>,
+    /// Acks from GC.
+    acks: Streaming<StreamHeartbeatResponse>,
+    /// Sequence of the next heartbeat.
+    next_sequence: u64,
+    /// Sequence and send time of heartbeats not yet acknowledged.
+    in_flight: VecDeque<(u64, Instant)>,
+}
+
+/// A heartbeat stream ack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeartbeatAck {
+    /// Directive for the MC as a whole.
+    pub directive: HeartbeatDirective,
+    /// Operator directives, oldest first.
+    pub pushed: Vec<PushedDirective>,
+    /// Active meetings of this MC's primary (empty unless it is a standby).
+    pub standby_meetings: Vec<String>,
+}
+
+/// An operator directive GC pushed on the heartbeat stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PushedDirective {
+    /// GC's ID for the directive, for the audit log.
+    pub directive_id: String,
+    /// What to do.
+    pub action: PushedAction,
+}
+
+/// What a [`PushedDirective`] asks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PushedAction {
+    /// New heartbeat intervals (`None` = unchanged).
+    HeartbeatIntervals {
+        fast: Option<Duration>,
+        comprehensive: Option<Duration>,
+    },
+    /// A directive for the `MeetingControllerActor`.
+    Controller(OperatorDirective),
+}
+
+impl PushedDirective {
+    /// Convert from the wire form, or `None` for an action this MC does
+    /// not know (added by a newer GC).
+    #[must_use]
+    pub fn from_proto(directive: ControllerDirective) -> Option<Self> {
+        let ms = |v: u64| (v > 0).then_some(Duration::from_millis(v));
+        let limit = |v: u32| (v > 0).then_some(v);
+        let action = match directive.action? {
+            Action::HeartbeatIntervals(intervals) => PushedAction::HeartbeatIntervals {
+                fast: ms(intervals.fast_heartbeat_interval_ms),
+                comprehensive: ms(intervals.comprehensive_heartbeat_interval_ms),
+            },
+            Action::SoftLimits(limits) => PushedAction::Controller(OperatorDirective::SoftLimits {
+                max_meetings: limit(limits.max_meetings),
+                max_participants: limit(limits.max_participants),
+            }),
+            Action::Drain(drain) => PushedAction::Controller(OperatorDirective::Drain {
+                draining: drain.draining,
+            }),
+            Action::EndMeeting(end) => PushedAction::Controller(OperatorDirective::EndMeeting {
+                meeting_id: end.meeting_id,
+                reason: end.reason,
+            }),
+        };
+        Some(Self {
+            directive_id: directive.directive_id,
+            action,
+        })
+    }
+}
+
 /// GC client with connection management.
 ///
 /// Uses a tonic `Channel` which is cheaply cloneable and handles
@@ -88,6 +204,10 @@ pub struct GcClient {
     fast_heartbeat_interval_ms: AtomicU64,
     /// Comprehensive heartbeat interval from GC (or default).
     comprehensive_heartbeat_interval_ms: AtomicU64,
+    /// Whether GC is believed to support the heartbeat stream.
+    heartbeat_stream_supported: AtomicBool,
+    /// Warm standby state, if this MC was started as one.
+    standby: Option<Arc<StandbySnapshots>>,
 }
 
 impl GcClient {
@@ -155,10 +275,28 @@ impl GcClient {
             comprehensive_heartbeat_interval_ms: AtomicU64::new(
                 DEFAULT_COMPREHENSIVE_HEARTBEAT_INTERVAL.as_millis() as u64,
             ),
+            heartbeat_stream_supported: AtomicBool::new(true),
+            standby: None,
         })
     }
 
-    /// Add authorization header to a request.
+    /// Register as a warm standby until `standby` is promoted; after that
+    /// the MC re-registers as an ordinary MC.
+    #[must_use]
+    pub fn with_standby(mut self, standby: Arc<StandbySnapshots>) -> Self {
+        self.standby = Some(standby);
+        self
+    }
+
+    /// The primary to register as the standby of (empty if none).
+    fn standby_for(&self) -> String {
+        match &self.standby {
+            Some(standby) if !standby.is_promoted() => standby.primary_id().to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// Add authorization and request ID headers to a request.
     ///
     /// Retrieves the current token from `TokenReceiver` which provides
     /// automatically refreshed tokens from the `TokenManager` background task.
@@ -176,6 +314,11 @@ impl GcClient {
                     McError::Config(format!("Authorization header parse failed: {e}"))
                 })?,
         );
+        if let Some(request_id) = RequestId::current() {
+            if let Ok(value) = request_id.as_str().parse() {
+                grpc_request.metadata_mut().insert(REQUEST_ID_HEADER, value);
+            }
+        }
         Ok(grpc_request)
     }
 
@@ -203,6 +346,9 @@ impl GcClient {
             webtransport_endpoint: self.config.webtransport_advertise_address.clone(),
             max_meetings: self.config.max_meetings,
             max_participants: self.config.max_participants,
+            standby_for: self.standby_for(),
+            pool: self.config.pool.clone().unwrap_or_default(),
+            build: Some(build_version()),
         };
 
         let mut retry_count = 0;
@@ -335,6 +481,11 @@ impl GcClient {
             return Ok(());
         }
 
+        #[cfg(feature = "fault-injection")]
+        if inject_heartbeat_fault().await? {
+            return Ok(());
+        }
+
         let request = FastHeartbeatRequest {
             controller_id: self.config.mc_id.clone(),
             capacity: Some(ControllerCapacity {
@@ -379,7 +530,7 @@ impl GcClient {
                 // Check for NOT_FOUND status - means GC doesn't recognize this MC
                 // (e.g., after GC restart or network partition)
                 if e.code() == tonic::Code::NotFound {
-                    warn!(
+                    warn_throttled!(
                         target: "mc.grpc.gc_client",
                         "GC returned NOT_FOUND - MC not registered"
                     );
@@ -387,7 +538,7 @@ impl GcClient {
                     return Err(McError::NotRegistered);
                 }
 
-                warn!(
+                warn_throttled!(
                     target: "mc.grpc.gc_client",
                     error = %e,
                     "Fast heartbeat failed"
@@ -398,6 +549,186 @@ impl GcClient {
         }
     }
 
+    /// Open a heartbeat stream to GC.
+    ///
+    /// Returns `Ok(None)` if the MC is not registered or GC does not
+    /// support the stream; send unary fast heartbeats instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Grpc` if the stream cannot be opened.
+    #[instrument(skip_all, fields(mc_id = %self.config.mc_id))]
+    pub async fn open_heartbeat_stream(&self) -> Result<Option<HeartbeatStream>, McError> {
+        if !self.is_registered.load(Ordering::SeqCst)
+            || !self.heartbeat_stream_supported.load(Ordering::SeqCst)
+        {
+            return Ok(None);
+        }
+
+        let (sender, receiver) = mpsc::channel(HEARTBEAT_STREAM_BUFFER);
+
+        // Clone the channel (cheap operation) for this request
+        let mut client = GlobalControllerServiceClient::new(self.channel.clone());
+        let grpc_request = self.add_auth(ReceiverStream::new(receiver))?;
+
+        match client.stream_heartbeat(grpc_request).await {
+            Ok(response) => {
+                info!(target: "mc.grpc.gc_client", "Heartbeat stream opened");
+                Ok(Some(HeartbeatStream {
+                    sender,
+                    acks: response.into_inner(),
+                    next_sequence: 0,
+                    in_flight: VecDeque::new(),
+                }))
+            }
+            Err(e) if e.code() == tonic::Code::Unimplemented => {
+                info!(
+                    target: "mc.grpc.gc_client",
+                    "GC does not support the heartbeat stream, using unary heartbeats"
+                );
+                self.heartbeat_stream_supported
+                    .store(false, Ordering::SeqCst);
+                Ok(None)
+            }
+            Err(e) => {
+                warn_throttled!(
+                    target: "mc.grpc.gc_client",
+                    error = %e,
+                    "Failed to open heartbeat stream"
+                );
+                Err(McError::Grpc(format!(
+                    "Failed to open heartbeat stream: {e}"
+                )))
+            }
+        }
+    }
+
+    /// Send a fast heartbeat on a heartbeat stream.
+    ///
+    /// The ack arrives later through [`GcClient::heartbeat_ack`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Grpc` if the stream is closed or GC has stopped
+    /// reading it; drop the stream and send unary heartbeats.
+    #[instrument(skip_all, fields(mc_id = %self.config.mc_id))]
+    pub async fn stream_fast_heartbeat(
+        &self,
+        stream: &mut HeartbeatStream,
+        current_meetings: u32,
+        current_participants: u32,
+        health: HealthStatus,
+    ) -> Result<(), McError> {
+        if !self.is_registered.load(Ordering::SeqCst) {
+            debug!(target: "mc.grpc.gc_client", "Skipping heartbeat - not registered");
+            return Ok(());
+        }
+
+        #[cfg(feature = "fault-injection")]
+        if inject_heartbeat_fault().await? {
+            return Ok(());
+        }
+
+        let sequence = stream.next_sequence;
+        let request = StreamHeartbeatRequest {
+            controller_id: self.config.mc_id.clone(),
+            capacity: Some(ControllerCapacity {
+                max_meetings: self.config.max_meetings,
+                current_meetings,
+                max_participants: self.config.max_participants,
+                current_participants,
+            }),
+            health: health.into(),
+            sequence,
+        };
+
+        // Never wait on a stalled stream: the unary path still works
+        stream.sender.try_send(request).map_err(|e| {
+            record_gc_heartbeat("error", "fast");
+            McError::Grpc(format!("Heartbeat stream unavailable: {e}"))
+        })?;
+        stream.next_sequence += 1;
+        stream.in_flight.push_back((sequence, Instant::now()));
+        Ok(())
+    }
+
+    /// Wait for the next ack on a heartbeat stream.
+    ///
+    /// Cancel-safe, so it can be polled in a `select!` loop.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::NotRegistered` if GC asks the MC to re-register;
+    /// the stream stays usable. Returns `McError::Grpc` if the stream ended.
+    pub async fn heartbeat_ack(
+        &self,
+        stream: &mut HeartbeatStream,
+    ) -> Result<HeartbeatAck, McError> {
+        let ack = match stream.acks.message().await {
+            Ok(Some(ack)) => ack,
+            Ok(None) => {
+                return Err(McError::Grpc("Heartbeat stream closed by GC".to_string()));
+            }
+            Err(e) => {
+                record_gc_heartbeat("error", "fast");
+                warn_throttled!(
+                    target: "mc.grpc.gc_client",
+                    error = %e,
+                    "Heartbeat stream failed"
+                );
+                return Err(McError::Grpc(format!("Heartbeat stream failed: {e}")));
+            }
+        };
+
+        // Acks come back in order; anything older than this one was lost
+        while let Some((sequence, sent_at)) = stream.in_flight.pop_front() {
+            if sequence == ack.sequence {
+                record_gc_heartbeat_latency("fast", sent_at.elapsed());
+                break;
+            }
+        }
+        record_gc_heartbeat("success", "fast");
+
+        // A directive added by a newer GC is ignored
+        let directive =
+            HeartbeatDirective::try_from(ack.directive).unwrap_or(HeartbeatDirective::Unspecified);
+        debug!(
+            target: "mc.grpc.gc_client",
+            sequence = ack.sequence,
+            timestamp = ack.timestamp,
+            directive = ?directive,
+            "Stream heartbeat acknowledged"
+        );
+
+        if directive == HeartbeatDirective::Reregister {
+            warn_throttled!(
+                target: "mc.grpc.gc_client",
+                "GC asked for re-registration - MC not registered"
+            );
+            self.is_registered.store(false, Ordering::SeqCst);
+            return Err(McError::NotRegistered);
+        }
+
+        let received = ack.directives.len();
+        let pushed: Vec<PushedDirective> = ack
+            .directives
+            .into_iter()
+            .filter_map(PushedDirective::from_proto)
+            .collect();
+        if pushed.len() < received {
+            warn!(
+                target: "mc.grpc.gc_client",
+                skipped = received - pushed.len(),
+                "Ignoring directives this MC does not support"
+            );
+        }
+        Ok(HeartbeatAck {
+            directive,
+            pushed,
+            standby_meetings: ack.standby_meeting_ids,
+        })
+    }
+
     /// Send a comprehensive heartbeat (full metrics).
     ///
     /// Called every 30 seconds (or interval specified by GC).
@@ -423,6 +754,11 @@ impl GcClient {
             return Ok(());
         }
 
+        #[cfg(feature = "fault-injection")]
+        if inject_heartbeat_fault().await? {
+            return Ok(());
+        }
+
         let request = ComprehensiveHeartbeatRequest {
             controller_id: self.config.mc_id.clone(),
             capacity: Some(ControllerCapacity {
@@ -471,7 +807,7 @@ impl GcClient {
                 // Check for NOT_FOUND status - means GC doesn't recognize this MC
                 // (e.g., after GC restart or network partition)
                 if e.code() == tonic::Code::NotFound {
-                    warn!(
+                    warn_throttled!(
                         target: "mc.grpc.gc_client",
                         "GC returned NOT_FOUND - MC not registered"
                     );
@@ -479,7 +815,7 @@ impl GcClient {
                     return Err(McError::NotRegistered);
                 }
 
-                warn!(
+                warn_throttled!(
                     target: "mc.grpc.gc_client",
                     error = %e,
                     "Comprehensive heartbeat failed"
@@ -512,6 +848,9 @@ impl GcClient {
             webtransport_endpoint: self.config.webtransport_advertise_address.clone(),
             max_meetings: self.config.max_meetings,
             max_participants: self.config.max_participants,
+            standby_for: self.standby_for(),
+            pool: self.config.pool.clone().unwrap_or_default(),
+            build: Some(build_version()),
         };
 
         match self.try_register(&request).await {
@@ -536,6 +875,10 @@ impl GcClient {
                     }
 
                     self.is_registered.store(true, Ordering::SeqCst);
+                    // The GC that answered may be newer than the one that
+                    // turned the heartbeat stream down
+                    self.heartbeat_stream_supported
+                        .store(true, Ordering::SeqCst);
                     Ok(())
                 } else {
                     warn!(
@@ -550,12 +893,133 @@ impl GcClient {
                 }
             }
             Err(e) => {
-                warn!(target: "mc.grpc.gc_client", error = %e, "Re-registration attempt failed");
+                warn_throttled!(target: "mc.grpc.gc_client", error = %e, "Re-registration attempt failed");
                 Err(e)
             }
         }
     }
 
+    /// Report a stopped meeting to GC: its attendance log (if any), then
+    /// `NotifyMeetingEnded` with the end summary if the meeting ended.
+    ///
+    /// Attendance goes first so GC's post-meeting report includes it.
+    /// Failures are logged by each RPC; reporting is best-effort.
+    pub async fn report_meeting(&self, mut report: MeetingAttendance) {
+        let ended = report.ended.take();
+        let meeting_id = report.meeting_id.clone();
+        if !report.records.is_empty() || report.live_stream_seconds > 0 {
+            let _ = self.report_attendance(report).await;
+        }
+        if let Some(summary) = ended {
+            let _ = self.notify_meeting_ended(meeting_id, summary).await;
+        }
+    }
+
+    /// Tell GC a meeting has ended on this MC, with its telemetry.
+    ///
+    /// GC ends the region's assignment; repeating the call is harmless.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Grpc` if the RPC fails.
+    #[instrument(skip_all, fields(mc_id = %self.config.mc_id, meeting_id = %meeting_id))]
+    pub async fn notify_meeting_ended(
+        &self,
+        meeting_id: String,
+        summary: MeetingEndSummary,
+    ) -> Result<(), McError> {
+        let request = NotifyMeetingEndedRequest {
+            meeting_id,
+            region: self.config.region.clone(),
+            telemetry: Some(MeetingTelemetry {
+                peak_participants: summary.peak_participants,
+                join_failures: summary.join_failures,
+                // The MC collects no QoE samples yet
+                avg_qoe_score: None,
+            }),
+        };
+
+        // Clone the channel (cheap operation) for this request
+        let mut client = GlobalControllerServiceClient::new(self.channel.clone());
+        let grpc_request = self.add_auth(request)?;
+
+        match client.notify_meeting_ended(grpc_request).await {
+            Ok(_) => {
+                info!(
+                    target: "mc.grpc.gc_client",
+                    peak_participants = summary.peak_participants,
+                    join_failures = summary.join_failures,
+                    "Meeting end reported to GC"
+                );
+                Ok(())
+            }
+            Err(e) => {
+                warn!(
+                    target: "mc.grpc.gc_client",
+                    error = %e,
+                    "NotifyMeetingEnded RPC failed"
+                );
+                Err(McError::Grpc(format!("NotifyMeetingEnded RPC failed: {e}")))
+            }
+        }
+    }
+
+    /// Report a finished meeting's attendance log to GC.
+    ///
+    /// GC ignores sessions it has already stored, so a caller may retry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Grpc` if the RPC fails.
+    #[instrument(skip_all, fields(mc_id = %self.config.mc_id, meeting_id = %attendance.meeting_id))]
+    pub async fn report_attendance(&self, attendance: MeetingAttendance) -> Result<u32, McError> {
+        let record_count = attendance.records.len();
+        let request = ReportAttendanceRequest {
+            meeting_id: attendance.meeting_id,
+            region: self.config.region.clone(),
+            records: attendance
+                .records
+                .into_iter()
+                .map(|r| AttendanceRecord {
+                    participant_id: r.participant_id,
+                    user_id: r.user_id,
+                    display_name: r.display_name,
+                    joined_at_ms: r.joined_at_ms,
+                    left_at_ms: r.left_at_ms,
+                    leave_reason: r.reason.as_str().to_string(),
+                })
+                .collect(),
+            live_stream_seconds: attendance.live_stream_seconds,
+            controller_id: self.config.mc_id.clone(),
+        };
+
+        // Clone the channel (cheap operation) for this request
+        let mut client = GlobalControllerServiceClient::new(self.channel.clone());
+        let grpc_request = self.add_auth(request)?;
+
+        match client.report_attendance(grpc_request).await {
+            Ok(response) => {
+                let inner = response.into_inner();
+                info!(
+                    target: "mc.grpc.gc_client",
+                    record_count = record_count,
+                    records_stored = inner.records_stored,
+                    "Attendance reported to GC"
+                );
+                Ok(inner.records_stored)
+            }
+            Err(e) => {
+                warn!(
+                    target: "mc.grpc.gc_client",
+                    error = %e,
+                    record_count = record_count,
+                    "ReportAttendance RPC failed"
+                );
+                Err(McError::Grpc(format!("ReportAttendance RPC failed: {e}")))
+            }
+        }
+    }
+
     /// Check if registered with GC.
     #[must_use]
     pub fn is_registered(&self) -> bool {
@@ -574,6 +1038,34 @@ impl GcClient {
         self.comprehensive_heartbeat_interval_ms
             .load(Ordering::SeqCst)
     }
+
+    /// Override the heartbeat intervals (`None` = unchanged), as a pushed
+    /// directive asks. Re-registration resets them to what GC returns.
+    pub fn set_heartbeat_intervals(&self, fast: Option<Duration>, comprehensive: Option<Duration>) {
+        let as_ms = |d: Duration| u64::try_from(d.as_millis()).unwrap_or(u64::MAX);
+        if let Some(fast) = fast {
+            self.fast_heartbeat_interval_ms
+                .store(as_ms(fast), Ordering::SeqCst);
+        }
+        if let Some(comprehensive) = comprehensive {
+            self.comprehensive_heartbeat_interval_ms
+                .store(as_ms(comprehensive), Ordering::SeqCst);
+        }
+    }
+}
+
+/// Apply an armed [`GC_HEARTBEAT`](crate::faults::GC_HEARTBEAT) fault.
+/// `Ok(true)` means the heartbeat was dropped and the caller should report
+/// success without sending it.
+#[cfg(feature = "fault-injection")]
+async fn inject_heartbeat_fault() -> Result<bool, McError> {
+    use common::fault_injection::{inject, InjectedFault};
+
+    match inject(crate::faults::GC_HEARTBEAT).await {
+        Ok(()) => Ok(false),
+        Err(InjectedFault::Drop(_)) => Ok(true),
+        Err(fault @ InjectedFault::Error(_)) => Err(McError::Grpc(fault.to_string())),
+    }
 }
 
 #[cfg(test)]
@@ -671,6 +1163,8 @@ mod tests {
 
         let config = Config {
             mc_id: "mc-test-001".to_string(),
+            standby_for: None,
+            pool: None,
             region: "us-east-1".to_string(),
             webtransport_bind_address: "0.0.0.0:4433".to_string(),
             grpc_bind_address: "0.0.0.0:50052".to_string(),
@@ -683,7 +1177,17 @@ mod tests {
             clock_skew_seconds: 5,
             nonce_grace_window_seconds: 5,
             disconnect_grace_period_seconds: 30,
+            min_protocol_version: 1,
+            keepalive_interval_seconds: 15,
+            idle_timeout_seconds: 45,
+            session_capture_dir: None,
+            raw_quic_bind_address: None,
+            zero_rtt_enabled: true,
+            redis_dual_write_schema_version: None,
+            meeting_journal_max_len: None,
+            state_check_interval_seconds: 0,
             binding_token_secret: SecretString::from("dGVzdC1zZWNyZXQ="),
+            media_token_secret: None,
             ac_endpoint: "https://ac.example.com".to_string(),
             client_id: "mc-service".to_string(),
             client_secret: SecretString::from("test-client-secret"),
@@ -692,6 +1196,8 @@ mod tests {
             tls_key_path: "/dev/null".to_string(),
             grpc_advertise_address: "http://localhost:50052".to_string(),
             webtransport_advertise_address: "https://localhost:4433".to_string(),
+            health_socket: None,
+            media_socket: common::media_socket::MediaSocketConfig::default(),
         };
 
         let token_rx = mock_token_receiver();
@@ -715,6 +1221,8 @@ mod tests {
 
         let config = Config {
             mc_id: "mc-test-001".to_string(),
+            standby_for: None,
+            pool: None,
             region: "us-east-1".to_string(),
             webtransport_bind_address: "0.0.0.0:4433".to_string(),
             grpc_bind_address: "0.0.0.0:50052".to_string(),
@@ -727,7 +1235,17 @@ mod tests {
             clock_skew_seconds: 5,
             nonce_grace_window_seconds: 5,
             disconnect_grace_period_seconds: 30,
+            min_protocol_version: 1,
+            keepalive_interval_seconds: 15,
+            idle_timeout_seconds: 45,
+            session_capture_dir: None,
+            raw_quic_bind_address: None,
+            zero_rtt_enabled: true,
+            redis_dual_write_schema_version: None,
+            meeting_journal_max_len: None,
+            state_check_interval_seconds: 0,
             binding_token_secret: SecretString::from("dGVzdC1zZWNyZXQ="),
+            media_token_secret: None,
             ac_endpoint: "https://ac.example.com".to_string(),
             client_id: "mc-service".to_string(),
             client_secret: SecretString::from("test-client-secret"),
@@ -736,6 +1254,8 @@ mod tests {
             tls_key_path: "/dev/null".to_string(),
             grpc_advertise_address: "http://localhost:50052".to_string(),
             webtransport_advertise_address: "https://localhost:4433".to_string(),
+            health_socket: None,
+            media_socket: common::media_socket::MediaSocketConfig::default(),
         };
 
         let token_rx = mock_token_receiver();
@@ -798,4 +1318,51 @@ mod tests {
             "Backoff should cap at BACKOFF_MAX after many iterations"
         );
     }
+
+    #[test]
+    fn test_pushed_directive_from_proto() {
+        use proto_gen::dark_tower::internal::v1::{
+            HeartbeatIntervalsDirective, SoftLimitsDirective,
+        };
+
+        let intervals = PushedDirective::from_proto(ControllerDirective {
+            directive_id: "d-1".to_string(),
+            action: Some(Action::HeartbeatIntervals(HeartbeatIntervalsDirective {
+                fast_heartbeat_interval_ms: 5000,
+                comprehensive_heartbeat_interval_ms: 0,
+            })),
+        })
+        .unwrap();
+        assert_eq!(intervals.directive_id, "d-1");
+        assert_eq!(
+            intervals.action,
+            PushedAction::HeartbeatIntervals {
+                fast: Some(Duration::from_secs(5)),
+                comprehensive: None,
+            }
+        );
+
+        let limits = PushedDirective::from_proto(ControllerDirective {
+            directive_id: "d-2".to_string(),
+            action: Some(Action::SoftLimits(SoftLimitsDirective {
+                max_meetings: 0,
+                max_participants: 100,
+            })),
+        })
+        .unwrap();
+        assert_eq!(
+            limits.action,
+            PushedAction::Controller(OperatorDirective::SoftLimits {
+                max_meetings: None,
+                max_participants: Some(100),
+            })
+        );
+
+        // Unknown oneof case from a newer GC
+        assert!(PushedDirective::from_proto(ControllerDirective {
+            directive_id: "d-3".to_string(),
+            action: None,
+        })
+        .is_none());
+    }
 }
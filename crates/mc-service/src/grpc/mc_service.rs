@@ -4,6 +4,7 @@
 //! Per ADR-0023 Phase 6c and ADR-0010 Section 4a:
 //!
 //! - `AssignMeetingWithMh` - Accept/reject meeting assignments from GC
+//! - `PromoteStandby` - Take over a failed primary's meetings (warm standby)
 //!
 //! # Accept/Reject Logic (ADR-0023 Section 5b)
 //!
@@ -21,13 +22,25 @@
 //! On rejection:
 //! - Return accepted=false with rejection reason
 //! - GC will retry with different MC
+//!
+//! # Standby Promotion
+//!
+//! Only an MC started as the warm standby of the named primary accepts a
+//! promotion. Each meeting is re-fenced by rewriting its MH assignment at a
+//! new generation (so the old primary's writes fail if it is still half
+//! alive), then started from the journal snapshot the standby kept.
 
-use crate::actors::MeetingControllerActorHandle;
+use crate::actors::recording::{RecordingConsentPolicy, RecordingLayout};
+use crate::actors::{DuplicateJoinPolicy, MeetingControllerActorHandle, MeetingSettings};
 use crate::errors::McError;
+use crate::ids::{McId, MeetingId};
 use crate::redis::FencedRedisClient;
+use crate::standby::StandbySnapshots;
 use proto_gen::dark_tower::internal::v1::meeting_controller_service_server::MeetingControllerService;
 use proto_gen::dark_tower::internal::v1::{
-    AssignMeetingWithMhRequest, AssignMeetingWithMhResponse, MhAssignment, RejectionReason,
+    AssignMeetingWithMhRequest, AssignMeetingWithMhResponse, MhAssignment, PromoteStandbyRequest,
+    PromoteStandbyResponse, PromotedMeeting, RecordingConsentPolicy as ProtoRecordingConsentPolicy,
+    RejectionReason,
 };
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
@@ -51,7 +64,7 @@ pub struct McAssignmentService {
     /// Redis client for state persistence.
     redis_client: Arc<FencedRedisClient>,
     /// MC ID for logging.
-    mc_id: String,
+    mc_id: McId,
     /// Maximum meetings this MC can handle.
     max_meetings: u32,
     /// Maximum participants this MC can handle.
@@ -62,6 +75,8 @@ pub struct McAssignmentService {
     current_participants: AtomicU32,
     /// Whether the MC is draining (graceful shutdown).
     is_draining: AtomicBool,
+    /// Journal snapshots, if this MC is a warm standby.
+    standby: Option<Arc<StandbySnapshots>>,
 }
 
 impl McAssignmentService {
@@ -78,7 +93,7 @@ impl McAssignmentService {
     pub fn new(
         controller_handle: Arc<MeetingControllerActorHandle>,
         redis_client: Arc<FencedRedisClient>,
-        mc_id: String,
+        mc_id: McId,
         max_meetings: u32,
         max_participants: u32,
     ) -> Self {
@@ -91,9 +106,24 @@ impl McAssignmentService {
             current_meetings: AtomicU32::new(0),
             current_participants: AtomicU32::new(0),
             is_draining: AtomicBool::new(false),
+            standby: None,
         }
     }
 
+    /// Make this MC a warm standby that accepts `PromoteStandby` for the
+    /// primary `standby` tracks.
+    #[must_use]
+    pub fn with_standby(mut self, standby: Arc<StandbySnapshots>) -> Self {
+        self.standby = Some(standby);
+        self
+    }
+
+    /// Journal snapshots, if this MC is a warm standby.
+    #[must_use]
+    pub fn standby(&self) -> Option<&Arc<StandbySnapshots>> {
+        self.standby.as_ref()
+    }
+
     /// Update current meeting count.
     pub fn set_meeting_count(&self, count: u32) {
         self.current_meetings.store(count, Ordering::SeqCst);
@@ -141,7 +171,7 @@ impl McAssignmentService {
     /// and stores them as active/active peers.
     async fn store_mh_assignments(
         &self,
-        meeting_id: &str,
+        meeting_id: &MeetingId,
         mh_assignments: &[MhAssignment],
     ) -> Result<(), McError> {
         if mh_assignments.is_empty() {
@@ -207,7 +237,7 @@ impl MeetingControllerService for McAssignmentService {
         request: Request<AssignMeetingWithMhRequest>,
     ) -> Result<Response<AssignMeetingWithMhResponse>, Status> {
         let inner = request.into_inner();
-        let meeting_id = &inner.meeting_id;
+        let meeting_id = MeetingId::from(&inner.meeting_id);
         let gc_id = &inner.requesting_gc_id;
 
         info!(
@@ -237,7 +267,7 @@ impl MeetingControllerService for McAssignmentService {
 
         // Store MH assignments in Redis
         if let Err(e) = self
-            .store_mh_assignments(meeting_id, &inner.mh_assignments)
+            .store_mh_assignments(&meeting_id, &inner.mh_assignments)
             .await
         {
             error!(
@@ -253,10 +283,17 @@ impl MeetingControllerService for McAssignmentService {
             }));
         }
 
-        // Create meeting actor
+        // Create meeting actor with the E2E flag, duplicate join policy,
+        // recording layout, and org recording consent policy
+        let settings = meeting_settings(
+            inner.recording_consent_policy.as_ref(),
+            inner.e2e_enabled,
+            inner.duplicate_join_policy,
+            inner.recording_layout,
+        );
         match self
             .controller_handle
-            .create_meeting(meeting_id.clone())
+            .create_meeting_with_settings(meeting_id.to_string(), settings)
             .await
         {
             Ok(()) => {
@@ -284,15 +321,136 @@ impl MeetingControllerService for McAssignmentService {
                 );
 
                 // Clean up MH assignments
-                let _ = self.redis_client.delete_mh_assignment(meeting_id).await;
+                let _ = self.redis_client.delete_mh_assignment(&meeting_id).await;
 
                 Ok(Response::new(AssignMeetingWithMhResponse {
                     accepted: false,
-                    rejection_reason: RejectionReason::Unhealthy.into(),
+                    rejection_reason: create_rejection_reason(&e).into(),
                 }))
             }
         }
     }
+
+    /// Take over a failed primary's meetings as its warm standby.
+    ///
+    /// Meetings that cannot be taken over are left out of the response; GC
+    /// leaves their assignments with the primary.
+    #[instrument(skip_all, fields(mc_id = %self.mc_id))]
+    async fn promote_standby(
+        &self,
+        request: Request<PromoteStandbyRequest>,
+    ) -> Result<Response<PromoteStandbyResponse>, Status> {
+        let inner = request.into_inner();
+        let Some(standby) = self
+            .standby
+            .as_ref()
+            .filter(|standby| standby.primary_id() == inner.primary_id)
+        else {
+            warn!(
+                target: "mc.grpc.mc_service",
+                primary_id = %inner.primary_id,
+                gc_id = %inner.requesting_gc_id,
+                "Refusing promotion: not a standby for this primary"
+            );
+            return Err(Status::failed_precondition(
+                "Not a standby for this primary",
+            ));
+        };
+
+        info!(
+            target: "mc.grpc.mc_service",
+            primary_id = %inner.primary_id,
+            gc_id = %inner.requesting_gc_id,
+            meeting_count = inner.meetings.len(),
+            "Promoting to primary"
+        );
+        standby.mark_promoted();
+
+        let mut promoted_meeting_ids = Vec::with_capacity(inner.meetings.len());
+        for meeting in inner.meetings {
+            if let Err(e) = self.take_over_meeting(standby, &meeting).await {
+                error!(
+                    target: "mc.grpc.mc_service",
+                    meeting_id = %meeting.meeting_id,
+                    error = %e,
+                    "Failed to take over meeting"
+                );
+                continue;
+            }
+            self.current_meetings.fetch_add(1, Ordering::SeqCst);
+            promoted_meeting_ids.push(meeting.meeting_id);
+        }
+
+        info!(
+            target: "mc.grpc.mc_service",
+            primary_id = %inner.primary_id,
+            promoted = promoted_meeting_ids.len(),
+            "Standby promotion complete"
+        );
+
+        Ok(Response::new(PromoteStandbyResponse {
+            promoted_meeting_ids,
+        }))
+    }
+}
+
+impl McAssignmentService {
+    /// Re-fence one meeting of the failed primary and start it here.
+    async fn take_over_meeting(
+        &self,
+        standby: &StandbySnapshots,
+        meeting: &PromotedMeeting,
+    ) -> Result<(), McError> {
+        let meeting_id = MeetingId::from(&meeting.meeting_id);
+
+        // Rewriting the assignment bumps the generation, fencing the primary
+        let assignment = self
+            .redis_client
+            .get_mh_assignment(&meeting_id)
+            .await?
+            .ok_or_else(|| McError::Internal("meeting has no MH assignment".to_string()))?;
+        self.redis_client
+            .store_mh_assignment(&meeting_id, &assignment.handlers)
+            .await?;
+
+        let settings = meeting_settings(
+            meeting.recording_consent_policy.as_ref(),
+            meeting.e2e_enabled,
+            meeting.duplicate_join_policy,
+            meeting.recording_layout,
+        );
+        let journal = standby.take(&meeting.meeting_id).await;
+        self.controller_handle
+            .create_promoted_meeting(meeting.meeting_id.clone(), settings, journal)
+            .await
+    }
+}
+
+/// Meeting settings from the policy fields GC sends with an assignment or
+/// promotion.
+fn meeting_settings(
+    recording_consent_policy: Option<&ProtoRecordingConsentPolicy>,
+    e2e_enabled: bool,
+    duplicate_join_policy: i32,
+    recording_layout: i32,
+) -> MeetingSettings {
+    MeetingSettings {
+        recording_policy: RecordingConsentPolicy::from_proto(recording_consent_policy),
+        e2e_enabled,
+        duplicate_join_policy: DuplicateJoinPolicy::from_proto(duplicate_join_policy),
+        recording_layout: RecordingLayout::from_proto(recording_layout),
+    }
+}
+
+/// Rejection reason for a failed meeting creation. The controller refuses
+/// new meetings itself while an operator directive drains it or caps its
+/// meetings.
+fn create_rejection_reason(error: &McError) -> RejectionReason {
+    match error {
+        McError::Draining => RejectionReason::Draining,
+        McError::McCapacityExceeded => RejectionReason::AtCapacity,
+        _ => RejectionReason::Unhealthy,
+    }
 }
 
 #[cfg(test)]
@@ -309,6 +467,22 @@ mod tests {
         assert_eq!(RejectionReason::Unhealthy as i32, 3);
     }
 
+    #[test]
+    fn test_create_rejection_reason() {
+        assert_eq!(
+            create_rejection_reason(&McError::Draining),
+            RejectionReason::Draining
+        );
+        assert_eq!(
+            create_rejection_reason(&McError::McCapacityExceeded),
+            RejectionReason::AtCapacity
+        );
+        assert_eq!(
+            create_rejection_reason(&McError::Conflict("exists".to_string())),
+            RejectionReason::Unhealthy
+        );
+    }
+
     #[test]
     fn test_estimated_participants_per_meeting_constant() {
         // Verify the constant is reasonable and documented
@@ -9,6 +9,9 @@
 //!   established a WebTransport connection to the MH.
 //! - `NotifyParticipantDisconnected` — MH informs MC that a participant's
 //!   WebTransport connection to the MH has dropped.
+//! - `RequestKeyframe` — MH asks for a keyframe on a publisher's video stream
+//!   after loss; routed to the meeting actor, which forwards it to the
+//!   publisher subject to the per-stream cooldown.
 //!
 //! # Security
 //!
@@ -16,13 +19,18 @@
 //! This handler only needs to validate request field constraints.
 //! Generic error messages prevent information leakage (ADR-0003).
 
+use crate::actors::keyframes::{KeyframeRequest, VideoStream};
+use crate::actors::MeetingControllerActorHandle;
+use crate::errors::McError;
 use crate::mh_connection_registry::{MhConnectionRegistry, MAX_ID_LENGTH};
 use crate::observability::metrics;
 use proto_gen::dark_tower::internal::v1::media_coordination_service_server::MediaCoordinationService;
 use proto_gen::dark_tower::internal::v1::{
     NotifyParticipantConnectedRequest, NotifyParticipantConnectedResponse,
     NotifyParticipantDisconnectedRequest, NotifyParticipantDisconnectedResponse,
+    RequestKeyframeRequest, RequestKeyframeResponse,
 };
+use proto_gen::dark_tower::signaling::v1::StreamType;
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
 use tracing::{debug, info, instrument, warn};
@@ -31,13 +39,26 @@ use tracing::{debug, info, instrument, warn};
 pub struct McMediaCoordinationService {
     /// Registry tracking participant-to-MH connection state.
     registry: Arc<MhConnectionRegistry>,
+    /// Controller routing keyframe requests to meetings (`RequestKeyframe`
+    /// is unavailable if `None`).
+    controller: Option<Arc<MeetingControllerActorHandle>>,
 }
 
 impl McMediaCoordinationService {
     /// Create a new media coordination service.
     #[must_use]
     pub fn new(registry: Arc<MhConnectionRegistry>) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            controller: None,
+        }
+    }
+
+    /// Route `RequestKeyframe` calls to meetings through `controller`.
+    #[must_use]
+    pub fn with_controller(mut self, controller: Arc<MeetingControllerActorHandle>) -> Self {
+        self.controller = Some(controller);
+        self
     }
 }
 
@@ -169,6 +190,72 @@ impl MediaCoordinationService for McMediaCoordinationService {
             acknowledged: true,
         }))
     }
+
+    /// Handle an MH request for a keyframe on a publisher's video stream.
+    ///
+    /// Acknowledged once the meeting is found, whether or not the request
+    /// is forwarded or falls in the stream's cooldown.
+    #[instrument(skip_all, name = "mc.grpc.media_coordination.keyframe")]
+    async fn request_keyframe(
+        &self,
+        request: Request<RequestKeyframeRequest>,
+    ) -> Result<Response<RequestKeyframeResponse>, Status> {
+        let inner = request.into_inner();
+
+        validate_id_field(&inner.meeting_id, "meeting_id")?;
+        validate_id_field(&inner.participant_id, "participant_id")?;
+        validate_id_field(&inner.handler_id, "handler_id")?;
+        let Some(stream) = StreamType::try_from(inner.stream_type)
+            .ok()
+            .and_then(VideoStream::from_stream_type)
+        else {
+            debug!(
+                target: "mc.grpc.media_coordination",
+                stream_type = inner.stream_type,
+                "Keyframe request for a non-video stream type"
+            );
+            return Err(Status::invalid_argument("Invalid request"));
+        };
+
+        let Some(controller) = &self.controller else {
+            return Err(Status::unavailable("Keyframe requests not available"));
+        };
+
+        metrics::record_mh_notification("keyframe");
+
+        let result = controller
+            .request_keyframe(
+                inner.meeting_id.clone(),
+                KeyframeRequest {
+                    publisher_id: inner.participant_id,
+                    stream,
+                },
+            )
+            .await;
+        match result {
+            Ok(()) => Ok(Response::new(RequestKeyframeResponse {
+                acknowledged: true,
+            })),
+            Err(McError::MeetingNotFound(_)) => {
+                debug!(
+                    target: "mc.grpc.media_coordination",
+                    meeting_id = %inner.meeting_id,
+                    handler_id = %inner.handler_id,
+                    "Keyframe request for a meeting not on this MC"
+                );
+                Err(Status::not_found("Meeting not found"))
+            }
+            Err(e) => {
+                warn!(
+                    target: "mc.grpc.media_coordination",
+                    meeting_id = %inner.meeting_id,
+                    error = %e,
+                    "Failed to route keyframe request"
+                );
+                Err(Status::internal("Internal error"))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -431,6 +518,35 @@ mod tests {
         assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
     }
 
+    fn keyframe_request(stream_type: StreamType) -> Request<RequestKeyframeRequest> {
+        Request::new(RequestKeyframeRequest {
+            meeting_id: "meeting-1".to_string(),
+            participant_id: "part-1".to_string(),
+            handler_id: "mh-1".to_string(),
+            stream_type: stream_type as i32,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_request_keyframe_rejects_audio() {
+        let svc = create_service();
+
+        let result = svc
+            .request_keyframe(keyframe_request(StreamType::Audio))
+            .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_request_keyframe_without_controller_unavailable() {
+        let svc = create_service();
+
+        let result = svc
+            .request_keyframe(keyframe_request(StreamType::VideoCamera))
+            .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::Unavailable);
+    }
+
     #[tokio::test]
     async fn test_validate_id_field_empty() {
         let result = validate_id_field("", "test_field");
@@ -2,6 +2,7 @@
 //!
 //! Provides a client for MC->MH communication:
 //! - `RegisterMeeting` - Notify MH about a new meeting assignment
+//! - `StartEgress` / `StopEgress` - Control a meeting's live stream
 //!
 //! # Security
 //!
@@ -14,15 +15,21 @@
 //! Unlike GcClient (singleton channel), MhClient creates a Channel per call
 //! because different meetings may be assigned to different MH instances.
 
+use crate::actors::live_stream::LiveStreamOutput;
+use crate::actors::recording::RecordingLayout;
 use crate::errors::McError;
 use crate::observability::metrics::record_register_meeting;
+use common::request_id::{RequestId, REQUEST_ID_HEADER};
 use common::secret::ExposeSecret;
 use common::token_manager::TokenReceiver;
 use proto_gen::dark_tower::internal::v1::media_handler_service_client::MediaHandlerServiceClient;
-use proto_gen::dark_tower::internal::v1::RegisterMeetingRequest;
+use proto_gen::dark_tower::internal::v1::{
+    start_egress_request, HlsEgressOutput, RegisterMeetingRequest, StartEgressRequest,
+    StopEgressRequest,
+};
 use std::pin::Pin;
 use std::time::{Duration, Instant};
-use tonic::transport::Endpoint;
+use tonic::transport::{Channel, Endpoint};
 use tonic::Request;
 use tracing::{debug, error, instrument, warn};
 
@@ -43,12 +50,45 @@ pub trait MhRegistrationClient: Send + Sync {
         &'a self,
         mh_grpc_endpoint: &'a str,
         meeting_id: &'a str,
+        e2e_enabled: bool,
+        recording_layout: RecordingLayout,
         mc_id: &'a str,
         mc_grpc_endpoint: &'a str,
     ) -> Pin<Box<dyn std::future::Future<Output = Result<(), McError>> + Send + 'a>>;
 }
 
-/// MH client for RegisterMeeting RPCs.
+/// An egress started on an MH.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MhEgress {
+    /// MH-assigned egress ID.
+    pub egress_id: String,
+    /// HLS playlist URL (empty for RTMP).
+    pub playback_url: String,
+}
+
+/// Trait for MC->MH live stream control.
+///
+/// Production code uses `MhClient`; tests can inject a mock.
+pub trait MhEgressClient: Send + Sync {
+    /// Start composing a meeting into a broadcast output.
+    fn start_egress<'a>(
+        &'a self,
+        mh_grpc_endpoint: &'a str,
+        meeting_id: &'a str,
+        tile_participant_ids: &'a [String],
+        output: &'a LiveStreamOutput,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<MhEgress, McError>> + Send + 'a>>;
+
+    /// Stop an egress. Returns the seconds the MH reports it was live.
+    fn stop_egress<'a>(
+        &'a self,
+        mh_grpc_endpoint: &'a str,
+        meeting_id: &'a str,
+        egress_id: &'a str,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<u64, McError>> + Send + 'a>>;
+}
+
+/// MH client for RegisterMeeting and egress RPCs.
 ///
 /// Holds a `TokenReceiver` for Bearer auth. Creates a new gRPC channel
 /// per call since MH endpoints vary per meeting assignment.
@@ -78,6 +118,8 @@ impl MhClient {
     ///
     /// * `mh_grpc_endpoint` - gRPC endpoint of the target MH
     /// * `meeting_id` - Meeting being registered
+    /// * `e2e_enabled` - Whether the meeting's media is end-to-end encrypted
+    /// * `recording_layout` - Recording compositor layout from the GC assignment
     /// * `mc_id` - This MC's identifier
     /// * `mc_grpc_endpoint` - This MC's gRPC endpoint (for MH->MC callbacks)
     ///
@@ -90,39 +132,20 @@ impl MhClient {
         &self,
         mh_grpc_endpoint: &str,
         meeting_id: &str,
+        e2e_enabled: bool,
+        recording_layout: RecordingLayout,
         mc_id: &str,
         mc_grpc_endpoint: &str,
     ) -> Result<(), McError> {
-        // Create channel to the specific MH endpoint
-        let channel = Endpoint::from_shared(mh_grpc_endpoint.to_string())
-            .map_err(|e| {
-                error!(
-                    target: "mc.grpc.mh_client",
-                    error = %e,
-                    "Invalid MH endpoint"
-                );
-                McError::Config(format!("Invalid MH endpoint: {e}"))
-            })?
-            .connect_timeout(MH_CONNECT_TIMEOUT)
-            .timeout(MH_RPC_TIMEOUT)
-            .connect()
-            .await
-            .map_err(|e| {
-                warn!(
-                    target: "mc.grpc.mh_client",
-                    error = %e,
-                    meeting_id = %meeting_id,
-                    "Failed to connect to MH"
-                );
-                McError::Grpc(format!("Failed to connect to MH: {e}"))
-            })?;
-
+        let channel = Self::connect(mh_grpc_endpoint, meeting_id).await?;
         let mut client = MediaHandlerServiceClient::new(channel);
 
         let request = RegisterMeetingRequest {
             meeting_id: meeting_id.to_string(),
             mc_id: mc_id.to_string(),
             mc_grpc_endpoint: mc_grpc_endpoint.to_string(),
+            recording_layout: recording_layout.to_proto().into(),
+            e2e_enabled,
         };
 
         let grpc_request = self.add_auth(request)?;
@@ -167,7 +190,128 @@ impl MhClient {
         }
     }
 
-    /// Add authorization header to a request.
+    /// Start a live streaming egress on an MH instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Config` if the endpoint is invalid.
+    /// Returns `McError::Grpc` if the connection or RPC fails.
+    #[instrument(skip_all, fields(meeting_id = %meeting_id), target = "mc.grpc.mh_client")]
+    pub async fn start_egress(
+        &self,
+        mh_grpc_endpoint: &str,
+        meeting_id: &str,
+        tile_participant_ids: &[String],
+        output: &LiveStreamOutput,
+    ) -> Result<MhEgress, McError> {
+        let channel = Self::connect(mh_grpc_endpoint, meeting_id).await?;
+        let mut client = MediaHandlerServiceClient::new(channel);
+
+        let output = match output {
+            LiveStreamOutput::Rtmp { url } => start_egress_request::Output::RtmpUrl(url.clone()),
+            LiveStreamOutput::Hls {
+                segment_duration_seconds,
+            } => start_egress_request::Output::Hls(HlsEgressOutput {
+                segment_duration_seconds: *segment_duration_seconds,
+            }),
+        };
+        let request = StartEgressRequest {
+            meeting_id: meeting_id.to_string(),
+            tile_participant_ids: tile_participant_ids.to_vec(),
+            output: Some(output),
+        };
+
+        let response = client
+            .start_egress(self.add_auth(request)?)
+            .await
+            .map_err(|e| {
+                warn!(
+                    target: "mc.grpc.mh_client",
+                    error = %e,
+                    meeting_id = %meeting_id,
+                    "StartEgress RPC failed"
+                );
+                McError::Grpc(format!("StartEgress RPC failed: {e}"))
+            })?
+            .into_inner();
+
+        debug!(
+            target: "mc.grpc.mh_client",
+            meeting_id = %meeting_id,
+            egress_id = %response.egress_id,
+            "MH started egress"
+        );
+        Ok(MhEgress {
+            egress_id: response.egress_id,
+            playback_url: response.playback_url,
+        })
+    }
+
+    /// Stop a live streaming egress on an MH instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Config` if the endpoint is invalid.
+    /// Returns `McError::Grpc` if the connection or RPC fails.
+    #[instrument(skip_all, fields(meeting_id = %meeting_id), target = "mc.grpc.mh_client")]
+    pub async fn stop_egress(
+        &self,
+        mh_grpc_endpoint: &str,
+        meeting_id: &str,
+        egress_id: &str,
+    ) -> Result<u64, McError> {
+        let channel = Self::connect(mh_grpc_endpoint, meeting_id).await?;
+        let mut client = MediaHandlerServiceClient::new(channel);
+
+        let request = StopEgressRequest {
+            meeting_id: meeting_id.to_string(),
+            egress_id: egress_id.to_string(),
+        };
+
+        let response = client
+            .stop_egress(self.add_auth(request)?)
+            .await
+            .map_err(|e| {
+                warn!(
+                    target: "mc.grpc.mh_client",
+                    error = %e,
+                    meeting_id = %meeting_id,
+                    "StopEgress RPC failed"
+                );
+                McError::Grpc(format!("StopEgress RPC failed: {e}"))
+            })?
+            .into_inner();
+
+        Ok(response.live_seconds)
+    }
+
+    /// Create a channel to the specific MH endpoint.
+    async fn connect(mh_grpc_endpoint: &str, meeting_id: &str) -> Result<Channel, McError> {
+        Endpoint::from_shared(mh_grpc_endpoint.to_string())
+            .map_err(|e| {
+                error!(
+                    target: "mc.grpc.mh_client",
+                    error = %e,
+                    "Invalid MH endpoint"
+                );
+                McError::Config(format!("Invalid MH endpoint: {e}"))
+            })?
+            .connect_timeout(MH_CONNECT_TIMEOUT)
+            .timeout(MH_RPC_TIMEOUT)
+            .connect()
+            .await
+            .map_err(|e| {
+                warn!(
+                    target: "mc.grpc.mh_client",
+                    error = %e,
+                    meeting_id = %meeting_id,
+                    "Failed to connect to MH"
+                );
+                McError::Grpc(format!("Failed to connect to MH: {e}"))
+            })
+    }
+
+    /// Add authorization and request ID headers to a request.
     fn add_auth<T>(&self, request: T) -> Result<Request<T>, McError> {
         let mut grpc_request = Request::new(request);
         let current_token = self.token_rx.token();
@@ -184,6 +328,11 @@ impl MhClient {
                     McError::Config(format!("Authorization header parse failed: {e}"))
                 })?,
         );
+        if let Some(request_id) = RequestId::current() {
+            if let Ok(value) = request_id.as_str().parse() {
+                grpc_request.metadata_mut().insert(REQUEST_ID_HEADER, value);
+            }
+        }
         Ok(grpc_request)
     }
 }
@@ -193,10 +342,40 @@ impl MhRegistrationClient for MhClient {
         &'a self,
         mh_grpc_endpoint: &'a str,
         meeting_id: &'a str,
+        e2e_enabled: bool,
+        recording_layout: RecordingLayout,
         mc_id: &'a str,
         mc_grpc_endpoint: &'a str,
     ) -> Pin<Box<dyn std::future::Future<Output = Result<(), McError>> + Send + 'a>> {
-        Box::pin(self.register_meeting(mh_grpc_endpoint, meeting_id, mc_id, mc_grpc_endpoint))
+        Box::pin(self.register_meeting(
+            mh_grpc_endpoint,
+            meeting_id,
+            e2e_enabled,
+            recording_layout,
+            mc_id,
+            mc_grpc_endpoint,
+        ))
+    }
+}
+
+impl MhEgressClient for MhClient {
+    fn start_egress<'a>(
+        &'a self,
+        mh_grpc_endpoint: &'a str,
+        meeting_id: &'a str,
+        tile_participant_ids: &'a [String],
+        output: &'a LiveStreamOutput,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<MhEgress, McError>> + Send + 'a>> {
+        Box::pin(self.start_egress(mh_grpc_endpoint, meeting_id, tile_participant_ids, output))
+    }
+
+    fn stop_egress<'a>(
+        &'a self,
+        mh_grpc_endpoint: &'a str,
+        meeting_id: &'a str,
+        egress_id: &'a str,
+    ) -> Pin<Box<dyn std::future::Future<Output = Result<u64, McError>> + Send + 'a>> {
+        Box::pin(self.stop_egress(mh_grpc_endpoint, meeting_id, egress_id))
     }
 }
 
@@ -239,7 +418,14 @@ mod tests {
         let client = MhClient::new(token_rx);
 
         let result = client
-            .register_meeting("", "meeting-1", "mc-1", "http://mc:50052")
+            .register_meeting(
+                "",
+                "meeting-1",
+                false,
+                RecordingLayout::Unspecified,
+                "mc-1",
+                "http://mc:50052",
+            )
             .await;
 
         assert!(
@@ -258,6 +444,8 @@ mod tests {
             .register_meeting(
                 "http://127.0.0.1:59998",
                 "meeting-1",
+                false,
+                RecordingLayout::Unspecified,
                 "mc-1",
                 "http://mc:50052",
             )
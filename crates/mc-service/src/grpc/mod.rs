@@ -3,8 +3,9 @@
 //! This module provides:
 //! - `gc_client` - Client for MC→GC communication (registration, heartbeat)
 //! - `mc_service` - Server for GC→MC communication (meeting assignment)
-//! - `mh_client` - Client for MC→MH communication (RegisterMeeting)
+//! - `mh_client` - Client for MC→MH communication (RegisterMeeting, Start/StopEgress)
 //! - `media_coordination` - Server for MH→MC communication (participant notifications)
+//! - `build_info_service` - Server for `GetVersion` (any caller type)
 //! - `auth_interceptor` - Authorization validation for incoming requests
 //!
 //! # Architecture (ADR-0023 Phase 6c)
@@ -12,7 +13,7 @@
 //! ```text
 //! MC → GC: RegisterMC, FastHeartbeat, ComprehensiveHeartbeat
 //! GC → MC: AssignMeetingWithMh (requires authorization)
-//! MC → MH: RegisterMeeting (authenticated via OAuth token)
+//! MC → MH: RegisterMeeting, StartEgress, StopEgress (authenticated via OAuth token)
 //! MH → MC: NotifyParticipantConnected/Disconnected (requires McAuthLayer, R-22)
 //! ```
 //!
@@ -21,13 +22,15 @@
 //! - All incoming gRPC calls pass through [`McAuthLayer`] (JWKS-based two-layer validation, ADR-0003)
 
 pub mod auth_interceptor;
+pub mod build_info_service;
 pub mod gc_client;
 pub mod mc_service;
 pub mod media_coordination;
 pub mod mh_client;
 
 pub use auth_interceptor::McAuthLayer;
-pub use gc_client::GcClient;
+pub use build_info_service::McBuildInfoService;
+pub use gc_client::{GcClient, HeartbeatAck, HeartbeatStream, PushedAction, PushedDirective};
 pub use mc_service::McAssignmentService;
 pub use media_coordination::McMediaCoordinationService;
-pub use mh_client::{MhClient, MhRegistrationClient};
+pub use mh_client::{MhClient, MhEgress, MhEgressClient, MhRegistrationClient};
@@ -0,0 +1,133 @@
+//! Typed IDs for values MC receives as strings.
+//!
+//! GC generates meeting IDs as UUIDv7 ([`common::types::MeetingId`]), but
+//! they reach MC as protobuf strings and MC never needs to parse them (test
+//! rigs use readable IDs like `meeting-123`). [`MeetingId`] keeps the string
+//! form while making it a compile error to build a `meeting:{id}:*` Redis
+//! key from a participant or correlation ID.
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+
+pub use common::types::McId;
+
+/// A meeting ID as MC received it.
+///
+/// Derefs to `str`, so it can be logged and compared as before; going the
+/// other way needs an explicit `MeetingId::from`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MeetingId(String);
+
+impl MeetingId {
+    /// Wrap a meeting ID.
+    #[must_use]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    /// The ID as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Unwrap into the underlying string.
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for MeetingId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for MeetingId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for MeetingId {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for MeetingId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
+impl From<&str> for MeetingId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl From<&String> for MeetingId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+
+impl From<common::types::MeetingId> for MeetingId {
+    fn from(id: common::types::MeetingId) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl From<MeetingId> for String {
+    fn from(id: MeetingId) -> Self {
+        id.0
+    }
+}
+
+impl PartialEq<str> for MeetingId {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for MeetingId {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for MeetingId {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
+    }
+}
+
+impl fmt::Display for MeetingId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meeting_id_keeps_wire_form() {
+        let generated = common::types::MeetingId::new();
+        let id = MeetingId::from(generated);
+        assert_eq!(id, generated.to_string());
+
+        let id = MeetingId::new("meeting-123");
+        assert_eq!(id, "meeting-123");
+        assert_eq!(format!("meeting:{id}:mh"), "meeting:meeting-123:mh");
+        assert_eq!(id.len(), "meeting-123".len());
+    }
+}
@@ -36,6 +36,9 @@
 //! - [`actors`] - Actor model implementation (Phase 6b)
 //! - [`config`] - Service configuration from environment
 //! - [`errors`] - Error types with appropriate error codes
+//! - [`ids`] - Typed IDs for meeting and controller identifiers
+//! - `faults` - Fault injection points (`fault-injection` feature)
+//! - [`standby`] - Journal snapshots kept by a warm standby MC
 //!
 //! # Reference
 //!
@@ -45,9 +48,13 @@ pub mod actors;
 pub mod auth;
 pub mod config;
 pub mod errors;
+#[cfg(feature = "fault-injection")]
+pub mod faults;
 pub mod grpc;
+pub mod ids;
 pub mod mh_connection_registry;
 pub mod observability;
 pub mod redis;
+pub mod standby;
 pub mod system_info;
 pub mod webtransport;
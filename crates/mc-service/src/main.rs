@@ -31,39 +31,57 @@
 //! 5. Initialize actor system (`MeetingControllerActorHandle`)
 //! 6. Start health HTTP server (liveness, readiness, metrics)
 //! 7. Start gRPC server for GC->MC communication
-//! 8. Create `GcClient` with `TokenReceiver` and spawn GC task (registration + heartbeats)
+//! 8. Create `GcClient` with `TokenReceiver` and spawn GC task (registration + heartbeats
+//!    + attendance and meeting end reports)
 //! 9. Wait for shutdown signal
 
 #![warn(clippy::pedantic)]
 #![allow(clippy::too_many_lines)] // main.rs orchestrates startup, naturally longer
 
-use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 
 use axum::Router;
+use common::build_info::version_router;
+use common::debug_auth::require_debug_scope;
+use common::listen::{bind_tcp_listeners, parse_bind_addresses, serve_unix, tcp_incoming};
+use common::log_level::log_level_router;
+use common::media_token::MediaTokenKey;
+use common::observability::runtime::{RuntimeSampler, RUNTIME_SAMPLE_INTERVAL};
+use common::request_id::RequestIdLayer;
 use common::secret::{ExposeSecret, SecretBox};
 use common::token_manager::{spawn_token_manager, TokenManagerConfig};
-use mc_service::actors::{ActorMetrics, ControllerMetrics, MeetingControllerActorHandle};
+use common::warn_throttled;
+use mc_service::actors::usage::USAGE_SAMPLE_INTERVAL;
+use mc_service::actors::{
+    ActorMetrics, ControllerMetrics, MeetingAttendance, MeetingControllerActorHandle,
+    MeetingServices, MeetingSettings, MeetingUsageRegistry,
+};
 use mc_service::auth::McJwtValidator;
 use mc_service::config::Config;
 use mc_service::errors::McError;
 use mc_service::grpc::{
-    GcClient, McAssignmentService, McAuthLayer, McMediaCoordinationService, MhClient,
-    MhRegistrationClient,
+    GcClient, HeartbeatAck, HeartbeatStream, McAssignmentService, McAuthLayer, McBuildInfoService,
+    McMediaCoordinationService, MhClient, MhEgressClient, MhRegistrationClient, PushedAction,
 };
+use mc_service::ids::McId;
 use mc_service::mh_connection_registry::MhConnectionRegistry;
-use mc_service::observability::{health_router, HealthState};
-use mc_service::redis::FencedRedisClient;
+use mc_service::observability::{health_router, meeting_usage_router, HealthState};
+use mc_service::redis::{
+    journal, FencedRedisClient, InteractionStore, JournalStore, MhAssignmentStore,
+};
+use mc_service::standby::StandbySnapshots;
 use mc_service::system_info::gather_system_info;
+use mc_service::webtransport::keepalive::KeepaliveConfig;
 use mc_service::webtransport::WebTransportServer;
+use proto_gen::dark_tower::internal::v1::build_info_service_server::BuildInfoServiceServer;
 use proto_gen::dark_tower::internal::v1::media_coordination_service_server::MediaCoordinationServiceServer;
 use proto_gen::dark_tower::internal::v1::meeting_controller_service_server::MeetingControllerServiceServer;
-use proto_gen::dark_tower::internal::v1::HealthStatus;
+use proto_gen::dark_tower::internal::v1::{HealthStatus, HeartbeatDirective};
 use tokio::signal;
+use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 /// Default timeout for initial token acquisition.
 const TOKEN_ACQUISITION_TIMEOUT: Duration = Duration::from_secs(30);
@@ -71,19 +89,31 @@ const TOKEN_ACQUISITION_TIMEOUT: Duration = Duration::from_secs(30);
 /// Minimum secret length for HMAC-SHA256 (32 bytes).
 const MIN_SECRET_LENGTH: usize = 32;
 
+/// Buffer for meeting attendance logs awaiting delivery to GC.
+const ATTENDANCE_CHANNEL_BUFFER: usize = 100;
+
+/// jemalloc avoids the fragmentation-driven RSS growth glibc malloc shows
+/// under long-lived, many-threaded workloads, and exposes heap stats.
+#[cfg(feature = "jemalloc")]
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize tracing with JSON structured logging
-    // JSON format enables robust parsing in Promtail without brittle regex
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "mc_service=debug,tower_http=debug".into()),
-        )
-        .with(tracing_subscriber::fmt::layer().json())
-        .init();
+    // Initialize tracing with JSON structured logging; the filter can be
+    // changed at runtime via /debug/log-level
+    let log_level = common::log_level::init_tracing("mc_service=debug,tower_http=debug");
 
-    info!("Starting Meeting Controller");
+    let build_info = common::build_info!(
+        "mc-service",
+        features = ["jemalloc", "cpu-profiling", "fault-injection"]
+    )
+    .with_proto_schema(proto_gen::SCHEMA_VERSION);
+    info!(
+        version = build_info.version,
+        git_sha = build_info.git_sha,
+        "Starting Meeting Controller"
+    );
 
     // Load configuration
     let config = Config::from_env().map_err(|e| {
@@ -124,6 +154,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             error!(error = %e, "Failed to connect to Redis");
             e
         })?;
+    let redis_client = match config.redis_dual_write_schema_version {
+        Some(version) => {
+            info!(
+                legacy_schema_version = version,
+                "Redis dual-write enabled for legacy key schema"
+            );
+            redis_client.with_dual_write(version)
+        }
+        None => redis_client,
+    };
+    let redis_client = match config.meeting_journal_max_len {
+        Some(max_len) => redis_client.with_journal_max_len(max_len),
+        None => redis_client,
+    };
     let redis_client = Arc::new(redis_client);
     info!("Redis connection established");
 
@@ -223,15 +267,60 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         SecretBox::new(Box::new(secret_bytes))
     };
 
+    // Media tokens bind clients' MH connections to their signaling session
+    let media_token_key = config
+        .media_token_secret
+        .as_ref()
+        .map(|secret| MediaTokenKey::from_base64(secret.expose_secret()).map(Arc::new))
+        .transpose()
+        .map_err(|e| {
+            error!(error = %e, "MC_MEDIA_TOKEN_SECRET is invalid");
+            format!("Invalid MC_MEDIA_TOKEN_SECRET: {e}")
+        })?;
+    if media_token_key.is_none() {
+        warn!("MC_MEDIA_TOKEN_SECRET not set; JoinResponses carry no media token");
+    }
+
     // Create MH connection registry for tracking participant→MH connections (R-18)
     let mh_connection_registry = Arc::new(MhConnectionRegistry::new());
 
-    let controller_handle = Arc::new(MeetingControllerActorHandle::new(
+    // Stopping meetings send attendance logs and end summaries here; the GC task reports them
+    let (attendance_tx, attendance_rx) = mpsc::channel(ATTENDANCE_CHANNEL_BUFFER);
+
+    // MC->MH client for RegisterMeeting (connection flow) and live stream egress (meetings)
+    let mh_client = Arc::new(MhClient::new(token_rx.clone()));
+
+    // Per-meeting resource counters, sampled for metrics and /debug/meetings
+    let usage_registry = Arc::new(MeetingUsageRegistry::new());
+
+    let meeting_services = MeetingServices {
+        attendance_tx: Some(attendance_tx),
+        // Polls and Q&A state is persisted in Redis so a replacement MC can restore it
+        interaction_store: Some(Arc::clone(&redis_client) as Arc<dyn InteractionStore>),
+        // Joins and leaves, replayed by a replacement MC and kept for audit
+        journal: config
+            .meeting_journal_max_len
+            .map(|_| Arc::clone(&redis_client) as Arc<dyn JournalStore>),
+        egress_client: Some(Arc::clone(&mh_client) as Arc<dyn MhEgressClient>),
+        mh_assignments: Some(Arc::clone(&redis_client) as Arc<dyn MhAssignmentStore>),
+        usage_registry: Some(Arc::clone(&usage_registry)),
+        disconnect_grace_period: Some(Duration::from_secs(config.disconnect_grace_period_seconds)),
+        state_check_interval: Some(config.state_check_interval_seconds)
+            .filter(|&secs| secs > 0)
+            .map(Duration::from_secs),
+        clock: None,
+        // Replaced per meeting with the settings GC sends in the assignment
+        settings: MeetingSettings::default(),
+        preloaded_journal: None,
+    };
+
+    let controller_handle = Arc::new(MeetingControllerActorHandle::with_services(
         config.mc_id.clone(),
         Arc::clone(&actor_metrics),
         Arc::clone(&controller_metrics),
         master_secret,
         Arc::clone(&mh_connection_registry),
+        meeting_services,
     ));
     info!("Actor system initialized");
 
@@ -241,11 +330,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Start health HTTP server (MUST succeed - fail startup if it doesn't)
     // This provides liveness/readiness probes and Prometheus /metrics endpoint
-    let health_addr: SocketAddr = config.health_bind_address.parse().map_err(|e| {
-        error!(error = %e, addr = %config.health_bind_address, "Invalid health bind address");
-        format!("Invalid health bind address: {e}")
-    })?;
-
     let health_router = health_router(Arc::clone(&health_state));
 
     // Add /metrics endpoint served by Prometheus exporter
@@ -257,48 +341,153 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }),
     );
 
-    let app = health_router.merge(metrics_router);
-
-    // Bind listener BEFORE spawning to fail fast on bind errors
-    let listener = tokio::net::TcpListener::bind(health_addr)
-        .await
+    // Debug endpoints (log filter, meeting usage, allocator, CPU profile),
+    // gated on the admin:debug scope
+    #[expect(
+        clippy::cast_possible_wrap,
+        reason = "clock_skew_seconds bounded to <=600, safe u64->i64"
+    )]
+    let debug_validator = Arc::new(common::jwt::JwtValidator::new(
+        Arc::clone(&jwks_client),
+        config.clock_skew_seconds as i64,
+    ));
+    let debug_routes =
+        log_level_router(log_level).merge(meeting_usage_router(Arc::clone(&usage_registry)));
+    #[cfg(feature = "jemalloc")]
+    let debug_routes = debug_routes.merge(common::allocator::allocator_router());
+    #[cfg(feature = "cpu-profiling")]
+    let debug_routes = debug_routes.merge(common::cpu_profile::cpu_profile_router());
+    #[cfg(feature = "fault-injection")]
+    let debug_routes = debug_routes.merge(common::fault_injection::fault_injection_router(
+        mc_service::faults::OPERATIONS,
+    ));
+    let debug_routes = debug_routes
+        .layer(axum::middleware::from_fn_with_state(
+            debug_validator,
+            require_debug_scope,
+        ))
+        .layer(RequestIdLayer);
+
+    let app = health_router
+        .merge(metrics_router)
+        .merge(version_router(build_info.clone()))
+        .merge(debug_routes);
+
+    if let Some(socket) = &config.health_socket {
+        // Unix socket instead of TCP (sidecar scraping)
+        let health_shutdown_token = shutdown_token.child_token();
+        let server = serve_unix(socket, app, async move {
+            health_shutdown_token.cancelled().await;
+            info!("Health server shutting down");
+        })
         .map_err(|e| {
-            error!(error = %e, addr = %health_addr, "Failed to bind health server");
-            format!("Failed to bind health server to {health_addr}: {e}")
+            error!(error = %e, "Failed to bind health server");
+            format!("Failed to bind health server: {e}")
         })?;
-    info!(addr = %health_addr, "Health server bound successfully");
+        tokio::spawn(server);
+        info!(path = %socket.path.display(), "Health server started");
+    } else {
+        let health_addrs = parse_bind_addresses(&config.health_bind_address).map_err(|e| {
+            error!(error = %e, addr = %config.health_bind_address, "Invalid health bind address");
+            format!("Invalid health bind address: {e}")
+        })?;
+
+        // Bind listeners BEFORE spawning to fail fast on bind errors
+        let listeners = bind_tcp_listeners(&health_addrs).map_err(|e| {
+            error!(error = %e, addrs = ?health_addrs, "Failed to bind health server");
+            format!("Failed to bind health server: {e}")
+        })?;
+        info!(addrs = ?health_addrs, "Health server bound successfully");
+
+        // Spawn one health server task per listener
+        for listener in listeners {
+            let app = app.clone();
+            let health_shutdown_token = shutdown_token.child_token();
+            tokio::spawn(async move {
+                let addr = listener.local_addr().ok();
+                info!(addr = ?addr, "Health server starting");
+                let server = axum::serve(listener, app).with_graceful_shutdown(async move {
+                    health_shutdown_token.cancelled().await;
+                    info!(addr = ?addr, "Health server shutting down");
+                });
+                if let Err(e) = server.await {
+                    error!(error = %e, "Health server failed");
+                }
+            });
+        }
+        info!(addrs = ?health_addrs, "Health server started");
+    }
 
-    // Spawn health server task
-    let health_shutdown_token = shutdown_token.child_token();
+    // Sample Tokio runtime health (worker saturation, schedule delay)
+    let runtime_token = shutdown_token.child_token();
+    let runtime_sampler = RuntimeSampler::new().run(
+        RUNTIME_SAMPLE_INTERVAL,
+        mc_service::observability::metrics::record_runtime_sample,
+    );
     tokio::spawn(async move {
-        info!(addr = %health_addr, "Health server starting");
-        let server = axum::serve(listener, app).with_graceful_shutdown(async move {
-            health_shutdown_token.cancelled().await;
-            info!("Health server shutting down");
-        });
-        if let Err(e) = server.await {
-            error!(error = %e, "Health server failed");
+        tokio::select! {
+            () = runtime_sampler => {}
+            () = runtime_token.cancelled() => {}
+        }
+    });
+
+    // Sample per-meeting usage and export the busiest meetings
+    let usage_token = shutdown_token.child_token();
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(USAGE_SAMPLE_INTERVAL);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    let rates = usage_registry.sample();
+                    mc_service::observability::metrics::record_meeting_usage(&rates);
+                }
+                () = usage_token.cancelled() => break,
+            }
         }
     });
-    info!(addr = %health_addr, "Health server started");
 
     // Start gRPC server BEFORE GC registration (correct ordering)
     // This prevents race condition where GC tries to call MC before server is ready
-    let grpc_addr = config.grpc_bind_address.parse().map_err(|e| {
+    let grpc_addrs = parse_bind_addresses(&config.grpc_bind_address).map_err(|e| {
         error!(error = %e, addr = %config.grpc_bind_address, "Invalid gRPC bind address");
-        e
+        format!("Invalid gRPC bind address: {e}")
+    })?;
+    let grpc_listeners = bind_tcp_listeners(&grpc_addrs).map_err(|e| {
+        error!(error = %e, addrs = ?grpc_addrs, "Failed to bind gRPC server");
+        format!("Failed to bind gRPC server: {e}")
     })?;
 
     let mc_assignment_service = McAssignmentService::new(
         Arc::clone(&controller_handle),
         Arc::clone(&redis_client),
-        config.mc_id.clone(),
+        McId::new(config.mc_id.clone()),
         config.max_meetings,
         config.max_participants,
     );
+    // A warm standby keeps its primary's journals loaded for a fast takeover
+    let mc_assignment_service = match &config.standby_for {
+        Some(primary_id) => {
+            let standby = Arc::new(StandbySnapshots::new(
+                primary_id.clone(),
+                config
+                    .meeting_journal_max_len
+                    .map(|_| Arc::clone(&redis_client) as Arc<dyn JournalStore>),
+                config
+                    .meeting_journal_max_len
+                    .unwrap_or(journal::DEFAULT_MAX_LEN),
+            ));
+            tokio::spawn(Arc::clone(&standby).run(shutdown_token.child_token()));
+            info!(primary_id = %primary_id, "Running as warm standby");
+            mc_assignment_service.with_standby(standby)
+        }
+        None => mc_assignment_service,
+    };
+    let mc_assignment_service = Arc::new(mc_assignment_service);
 
-    // Create MediaCoordinationService for MH→MC notifications (R-15)
-    let media_coord_service = McMediaCoordinationService::new(Arc::clone(&mh_connection_registry));
+    // Create MediaCoordinationService for MH→MC notifications (R-15) and
+    // keyframe requests
+    let media_coord_service = McMediaCoordinationService::new(Arc::clone(&mh_connection_registry))
+        .with_controller(Arc::clone(&controller_handle));
 
     // Create JWKS-based auth layer for gRPC service token validation (R-22)
     // Applied at the server level: validates JWT signature + expiry for ALL
@@ -313,22 +502,29 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let grpc_shutdown_token = shutdown_token.child_token();
     let grpc_server = tonic::transport::Server::builder()
+        // First layer is outermost: auth rejections carry the request ID too
+        .layer(RequestIdLayer)
         .layer(mc_auth_layer)
-        .add_service(MeetingControllerServiceServer::new(mc_assignment_service))
+        .add_service(MeetingControllerServiceServer::from_arc(Arc::clone(
+            &mc_assignment_service,
+        )))
         .add_service(MediaCoordinationServiceServer::new(media_coord_service))
-        .serve_with_shutdown(grpc_addr, async move {
+        .add_service(BuildInfoServiceServer::new(McBuildInfoService::new(
+            build_info,
+        )))
+        .serve_with_incoming_shutdown(tcp_incoming(grpc_listeners), async move {
             grpc_shutdown_token.cancelled().await;
             info!("gRPC server shutting down");
         });
 
     // Spawn gRPC server task
     tokio::spawn(async move {
-        info!(addr = %grpc_addr, "gRPC server starting");
+        info!("gRPC server starting");
         if let Err(e) = grpc_server.await {
             error!(error = %e, "gRPC server failed");
         }
     });
-    info!(addr = %grpc_addr, "gRPC server started");
+    info!(addrs = ?grpc_addrs, "gRPC server started");
 
     // Create GcClient with TokenReceiver and spawn unified GC task (Phase 6c, ADR-0010)
     // This task owns gc_client directly (no Arc needed)
@@ -339,21 +535,35 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             error!(error = %e, "Failed to connect to GC");
             e
         })?;
+    let gc_client = match mc_assignment_service.standby() {
+        Some(standby) => gc_client.with_standby(Arc::clone(standby)),
+        None => gc_client,
+    };
     info!("Connected to Global Controller");
 
-    // Spawn unified GC task (registration + dual heartbeats)
+    // Spawn unified GC task (registration + dual heartbeats + meeting reports)
     let gc_task_token = shutdown_token.child_token();
     let gc_task_metrics = Arc::clone(&controller_metrics);
     let gc_task_health = Arc::clone(&health_state);
+    let gc_task_controller = Arc::clone(&controller_handle);
     tokio::spawn(async move {
-        run_gc_task(gc_client, gc_task_metrics, gc_task_health, gc_task_token).await;
+        run_gc_task(
+            gc_client,
+            gc_task_metrics,
+            gc_task_health,
+            mc_assignment_service,
+            gc_task_controller,
+            attendance_rx,
+            gc_task_token,
+        )
+        .await;
     });
     info!("GC task started");
 
     info!("Meeting Controller Phase 6c: GC integration complete");
 
-    // Create MH registration client for async RegisterMeeting RPCs (R-12)
-    let mh_client: Arc<dyn MhRegistrationClient> = Arc::new(MhClient::new(token_rx.clone()));
+    // WebTransport connections use the MH client for async RegisterMeeting RPCs (R-12)
+    let mh_client: Arc<dyn MhRegistrationClient> = mh_client;
 
     // Start WebTransport server (R-5: HTTP/3 over QUIC with TLS 1.3 on port 4433)
     let wt_server = WebTransportServer::new(
@@ -362,28 +572,47 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         config.tls_key_path.clone(),
         Arc::clone(&controller_handle),
         jwt_validator,
-        Arc::clone(&redis_client) as Arc<dyn mc_service::redis::MhAssignmentStore>,
+        Arc::clone(&redis_client) as Arc<dyn MhAssignmentStore>,
         mh_client,
         config.mc_id.clone(),
         config.grpc_advertise_address.clone(),
         config.max_participants as usize,
         shutdown_token.child_token(),
-    );
+    )
+    .with_media_socket(config.media_socket)
+    .with_min_protocol_version(config.min_protocol_version)
+    .with_keepalive(KeepaliveConfig::from_secs(
+        config.keepalive_interval_seconds,
+        config.idle_timeout_seconds,
+    ))
+    .with_session_capture_dir(config.session_capture_dir.clone())
+    .with_raw_quic_bind_address(config.raw_quic_bind_address.clone())
+    .with_zero_rtt(config.zero_rtt_enabled)
+    .with_media_token_key(media_token_key);
 
     // Fail-fast: load TLS + bind endpoint BEFORE spawning the accept loop.
-    // If certs are missing/corrupt or the port is in use, crash startup immediately
+    // If certs are missing/corrupt or a port is in use, crash startup immediately
     // rather than running an MC that can never accept WebTransport connections.
-    let wt_endpoint = wt_server.bind().await.map_err(|e| {
+    let wt_endpoints = wt_server.bind().await.map_err(|e| {
         error!(error = %e, "WebTransport server failed to bind");
         Box::<dyn std::error::Error>::from(e.to_string())
     })?;
     info!(
         addr = %config.webtransport_bind_address,
-        "WebTransport endpoint bound"
+        "WebTransport endpoints bound"
     );
+    let raw_quic_endpoints = wt_server.bind_raw_quic().await.map_err(|e| {
+        error!(error = %e, "Raw QUIC signaling endpoint failed to bind");
+        Box::<dyn std::error::Error>::from(e.to_string())
+    })?;
+    if let Some(addr) = &config.raw_quic_bind_address {
+        info!(addr = %addr, "Raw QUIC signaling endpoints bound");
+    }
 
     tokio::spawn(async move {
-        wt_server.accept_loop(wt_endpoint).await;
+        wt_server
+            .accept_loop_with_raw_quic(wt_endpoints, raw_quic_endpoints)
+            .await;
         info!("WebTransport accept loop stopped");
     });
 
@@ -423,13 +652,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///
 /// Operational model:
 /// - Initial registration: Retry forever until success (with exponential backoff)
-/// - Dual heartbeats: Fast (10s) + comprehensive (30s) in single select loop
+/// - Dual heartbeats: Fast (10s) + comprehensive (30s) in single select loop.
+///   Fast heartbeats go over the GC heartbeat stream when it is open, unary
+///   otherwise; a stream that fails is reopened on the next tick
+/// - Directives: GC's `DRAIN` in a stream ack stops new meeting assignments
+///   (until an ack without it). Operator directives in an ack are applied
+///   in order: heartbeat intervals here, the rest by the controller actor
 /// - Re-registration: Detect `NOT_FOUND` from heartbeat, automatically re-register
+/// - Meeting end: Report stopped meetings' attendance logs, and tell GC which
+///   meetings ended (queued until registered)
 /// - Never exit: Protects active meetings during GC outages/restarts
 async fn run_gc_task(
     gc_client: GcClient,
     metrics: Arc<ControllerMetrics>,
     health_state: Arc<HealthState>,
+    mc_assignment_service: Arc<McAssignmentService>,
+    controller_handle: Arc<MeetingControllerActorHandle>,
+    mut attendance_rx: mpsc::Receiver<MeetingAttendance>,
     cancel_token: CancellationToken,
 ) {
     info!("GC task: Starting initial registration");
@@ -452,7 +691,7 @@ async fn run_gc_task(
                     Err(e) => {
                         // Log but never exit - keep retrying
                         // GC may be temporarily unavailable during rolling updates
-                        warn!(error = %e, "GC task: Initial registration failed, will retry");
+                        warn_throttled!(error = %e, "GC task: Initial registration failed, will retry");
                         tokio::time::sleep(Duration::from_secs(5)).await;
                     }
                 }
@@ -465,11 +704,9 @@ async fn run_gc_task(
     let comprehensive_interval =
         Duration::from_millis(gc_client.comprehensive_heartbeat_interval_ms());
 
-    let mut fast_ticker = tokio::time::interval(fast_interval);
-    fast_ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-
-    let mut comprehensive_ticker = tokio::time::interval(comprehensive_interval);
-    comprehensive_ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let now = tokio::time::Instant::now();
+    let mut fast_ticker = heartbeat_ticker(now, fast_interval);
+    let mut comprehensive_ticker = heartbeat_ticker(now, comprehensive_interval);
 
     info!(
         fast_interval_ms = fast_interval.as_millis(),
@@ -477,6 +714,9 @@ async fn run_gc_task(
         "GC task: Entering dual heartbeat loop"
     );
 
+    let mut heartbeat_stream: Option<HeartbeatStream> = None;
+    let mut draining = false;
+
     loop {
         tokio::select! {
             () = cancel_token.cancelled() => {
@@ -486,11 +726,96 @@ async fn run_gc_task(
             _ = fast_ticker.tick() => {
                 let snapshot = metrics.snapshot();
 
-                if let Err(e) = gc_client
-                    .fast_heartbeat(snapshot.meetings, snapshot.participants, HealthStatus::Healthy)
-                    .await
-                {
-                    handle_heartbeat_error(&gc_client, e).await;
+                if heartbeat_stream.is_none() {
+                    // Open failures are logged by the client; unary covers this tick
+                    heartbeat_stream = gc_client.open_heartbeat_stream().await.ok().flatten();
+                }
+                let streamed = match heartbeat_stream.as_mut() {
+                    Some(stream) => Some(
+                        gc_client
+                            .stream_fast_heartbeat(
+                                stream,
+                                snapshot.meetings,
+                                snapshot.participants,
+                                HealthStatus::Healthy,
+                            )
+                            .await,
+                    ),
+                    None => None,
+                };
+                let sent = match streamed {
+                    Some(Ok(())) => true,
+                    Some(Err(e)) => {
+                        warn_throttled!(error = %e, "Heartbeat stream failed, sending unary heartbeat");
+                        heartbeat_stream = None;
+                        false
+                    }
+                    None => false,
+                };
+
+                if !sent {
+                    if let Err(e) = gc_client
+                        .fast_heartbeat(snapshot.meetings, snapshot.participants, HealthStatus::Healthy)
+                        .await
+                    {
+                        handle_heartbeat_error(&gc_client, e).await;
+                    }
+                }
+            }
+            ack = next_heartbeat_ack(&gc_client, &mut heartbeat_stream) => {
+                match ack {
+                    Ok(HeartbeatAck { directive, pushed, standby_meetings }) => {
+                        if let Some(standby) = mc_assignment_service.standby() {
+                            standby.set_meetings(standby_meetings);
+                        }
+
+                        let drain = directive == HeartbeatDirective::Drain;
+                        if drain != draining {
+                            info!(draining = drain, "GC task: Drain directive changed");
+                            draining = drain;
+                            mc_assignment_service.set_draining(drain);
+                        }
+
+                        for directive in pushed {
+                            match directive.action {
+                                PushedAction::HeartbeatIntervals { fast, comprehensive } => {
+                                    gc_client.set_heartbeat_intervals(fast, comprehensive);
+                                    // Restarted a full period out, so the change
+                                    // does not itself send a heartbeat
+                                    let next = tokio::time::Instant::now();
+                                    if let Some(fast) = fast {
+                                        fast_ticker = heartbeat_ticker(next + fast, fast);
+                                    }
+                                    if let Some(comprehensive) = comprehensive {
+                                        comprehensive_ticker =
+                                            heartbeat_ticker(next + comprehensive, comprehensive);
+                                    }
+                                    info!(
+                                        target: "mc.audit.directive",
+                                        directive_id = %directive.directive_id,
+                                        kind = "heartbeat_intervals",
+                                        fast_interval_ms = fast.map(|d| d.as_millis()),
+                                        comprehensive_interval_ms = comprehensive.map(|d| d.as_millis()),
+                                        outcome = "applied",
+                                        "Operator directive applied"
+                                    );
+                                }
+                                PushedAction::Controller(action) => {
+                                    // The actor audit-logs the outcome
+                                    let _ = controller_handle
+                                        .apply_directive(directive.directive_id, action)
+                                        .await;
+                                }
+                            }
+                        }
+                    }
+                    Err(McError::NotRegistered) => {
+                        handle_heartbeat_error(&gc_client, McError::NotRegistered).await;
+                    }
+                    Err(e) => {
+                        warn_throttled!(error = %e, "Heartbeat stream ended, will reopen");
+                        heartbeat_stream = None;
+                    }
                 }
             }
             _ = comprehensive_ticker.tick() => {
@@ -516,12 +841,33 @@ async fn run_gc_task(
                     handle_heartbeat_error(&gc_client, e).await;
                 }
             }
+            Some(report) = attendance_rx.recv() => {
+                gc_client.report_meeting(report).await;
+            }
         }
     }
 
     info!("GC task: Stopped");
 }
 
+/// Heartbeat ticker with its first tick at `start`; missed ticks are skipped.
+fn heartbeat_ticker(start: tokio::time::Instant, period: Duration) -> tokio::time::Interval {
+    let mut ticker = tokio::time::interval_at(start, period);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    ticker
+}
+
+/// Next ack on the heartbeat stream; pending forever while none is open.
+async fn next_heartbeat_ack(
+    gc_client: &GcClient,
+    stream: &mut Option<HeartbeatStream>,
+) -> Result<HeartbeatAck, McError> {
+    match stream {
+        Some(stream) => gc_client.heartbeat_ack(stream).await,
+        None => std::future::pending().await,
+    }
+}
+
 /// Handle heartbeat errors, including re-registration on `NOT_FOUND`.
 ///
 /// Never exits - logs error and attempts re-registration if needed.
@@ -530,17 +876,19 @@ async fn handle_heartbeat_error(gc_client: &GcClient, error: McError) {
         McError::NotRegistered => {
             // GC doesn't recognize this MC (e.g., after GC restart)
             // Attempt re-registration (single attempt, task loop will retry)
-            warn!("Heartbeat failed: MC not registered with GC, attempting re-registration");
+            warn_throttled!(
+                "Heartbeat failed: MC not registered with GC, attempting re-registration"
+            );
 
             if let Err(e) = gc_client.attempt_reregistration().await {
-                warn!(error = %e, "Re-registration failed, will retry on next heartbeat");
+                warn_throttled!(error = %e, "Re-registration failed, will retry on next heartbeat");
             } else {
                 info!("Re-registration successful");
             }
         }
         other => {
             // Other errors (network, timeout, etc.) - log and continue
-            warn!(error = %other, "Heartbeat failed");
+            warn_throttled!(error = %other, "Heartbeat failed");
         }
     }
 }
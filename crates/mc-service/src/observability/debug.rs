@@ -0,0 +1,73 @@
+//! MC-specific debug endpoints.
+//!
+//! - `GET /debug/meetings` - Per-meeting resource usage from the latest
+//!   sample, busiest (by messages/sec) first
+//!
+//! The Prometheus `mc_meeting_usage_top` gauges only carry a rank; this
+//! endpoint is where the meeting IDs behind those ranks are looked up.
+//!
+//! The router is unauthenticated; `main.rs` mounts it with the other
+//! `/debug/*` routes behind the `admin:debug` scope check.
+
+use crate::actors::{MeetingUsageRate, MeetingUsageRegistry};
+use axum::{extract::State, routing::get, Json, Router};
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Path of the meeting usage endpoint.
+pub const MEETINGS_PATH: &str = "/debug/meetings";
+
+/// Response of `GET /debug/meetings`.
+#[derive(Debug, Serialize)]
+struct MeetingsResponse {
+    meetings: Vec<MeetingUsageRate>,
+}
+
+/// Create the router serving [`MEETINGS_PATH`].
+pub fn meeting_usage_router(registry: Arc<MeetingUsageRegistry>) -> Router {
+    Router::new()
+        .route(MEETINGS_PATH, get(meetings_handler))
+        .with_state(registry)
+}
+
+async fn meetings_handler(
+    State(registry): State<Arc<MeetingUsageRegistry>>,
+) -> Json<MeetingsResponse> {
+    Json(MeetingsResponse {
+        meetings: registry.latest(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::util::ServiceExt;
+
+    #[tokio::test]
+    async fn test_meetings_endpoint_returns_latest_sample() {
+        let registry = Arc::new(MeetingUsageRegistry::new());
+        let usage = registry.register("meeting-noisy");
+        usage.record_message();
+        usage.record_bytes(512);
+        registry.sample();
+
+        let request = Request::builder()
+            .uri(MEETINGS_PATH)
+            .body(Body::empty())
+            .expect("Failed to build request");
+        let response = meeting_usage_router(registry)
+            .oneshot(request)
+            .await
+            .expect("Failed to execute request");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("Failed to read body");
+        let body: serde_json::Value = serde_json::from_slice(&bytes).expect("Invalid JSON");
+        assert_eq!(body["meetings"][0]["meeting_id"], "meeting-noisy");
+        assert_eq!(body["meetings"][0]["totals"]["bytes"], 512);
+    }
+}
@@ -53,6 +53,15 @@ pub fn init_metrics_recorder() -> Result<PrometheusHandle, String> {
             &[0.010, 0.050, 0.100, 0.250, 0.500, 1.000, 2.500, 5.000],
         )
         .map_err(|e| format!("Failed to set token refresh buckets: {e}"))?
+        // End-to-end join latency buckets - extended to 60s (spans GC
+        // assignment, the client connecting, and the MC join)
+        .set_buckets_for_metric(
+            Matcher::Prefix("mc_join_latency".to_string()),
+            &[
+                0.100, 0.250, 0.500, 1.000, 2.000, 5.000, 10.000, 30.000, 60.000,
+            ],
+        )
+        .map_err(|e| format!("Failed to set join latency buckets: {e}"))?
         // Session join buckets - extended to 5s (join includes actor processing)
         .set_buckets_for_metric(
             Matcher::Prefix("mc_session_join".to_string()),
@@ -69,6 +78,14 @@ pub fn init_metrics_recorder() -> Result<PrometheusHandle, String> {
             ],
         )
         .map_err(|e| format!("Failed to set register meeting buckets: {e}"))?
+        // Runtime schedule delay buckets - healthy is sub-millisecond
+        .set_buckets_for_metric(
+            Matcher::Prefix("mc_runtime_schedule_delay".to_string()),
+            &[
+                0.0001, 0.0005, 0.001, 0.005, 0.010, 0.025, 0.050, 0.100, 0.250, 1.000,
+            ],
+        )
+        .map_err(|e| format!("Failed to set runtime schedule delay buckets: {e}"))?
         .install_recorder()
         .map_err(|e| format!("Failed to install Prometheus metrics recorder: {e}"))
 }
@@ -156,6 +173,30 @@ pub fn record_fenced_out(reason: &str) {
     counter!("mc_fenced_out_total", "reason" => reason.to_string()).increment(1);
 }
 
+/// Record a read served from the old key schema in dual-write mode.
+///
+/// Metric: `mc_redis_schema_fallback_reads_total`
+/// Labels: `key`
+///
+/// `key` is the key's last segment (`generation`, `mh`, `interactions`).
+/// Once this stays at zero, no meeting depends on the old layout.
+pub fn record_schema_fallback_read(key: &str) {
+    counter!("mc_redis_schema_fallback_reads_total", "key" => key.to_string()).increment(1);
+}
+
+/// Record a mismatch between a meeting actor's state and Redis.
+///
+/// Metric: `mc_state_divergence_total`
+/// Labels: `state` ("interactions", "roster")
+/// Cardinality: 2
+///
+/// Recorded by the meeting actor's periodic state check, which then
+/// rewrites Redis from memory. Any sustained rate means writes are being
+/// lost between the actor and Redis.
+pub fn record_state_divergence(state: &'static str) {
+    counter!("mc_state_divergence_total", "state" => state).increment(1);
+}
+
 // ============================================================================
 // Additional Operational Metrics
 // ============================================================================
@@ -268,6 +309,224 @@ pub fn record_webtransport_connection(status: &str) {
     .increment(1);
 }
 
+/// Record a raw QUIC signaling connection acceptance or rejection.
+///
+/// Metric: `mc_raw_quic_connections_total`
+/// Labels: `status`
+///
+/// Status values: "accepted", "rejected", "error"
+/// Cardinality: 3
+///
+/// Recorded in the accept loop (`server.rs`) for connections on the raw
+/// QUIC endpoints (`webtransport/raw_quic.rs`).
+pub fn record_raw_quic_connection(status: &str) {
+    counter!("mc_raw_quic_connections_total",
+        "status" => status.to_string()
+    )
+    .increment(1);
+}
+
+/// Record how a raw QUIC connection's TLS handshake completed.
+///
+/// Metric: `mc_raw_quic_handshakes_total`
+/// Labels: `zero_rtt`
+///
+/// Zero-RTT values: "accepted" (resumed session, 0-RTT data accepted),
+/// "none" (full handshake, or resumption without 0-RTT)
+/// Cardinality: 2
+///
+/// Recorded once per raw QUIC connection, when its session first sees the
+/// handshake complete (`webtransport/early_data.rs`); always "none" with
+/// `MC_ZERO_RTT_ENABLED=false`.
+pub fn record_raw_quic_handshake(zero_rtt: &str) {
+    counter!("mc_raw_quic_handshakes_total",
+        "zero_rtt" => zero_rtt.to_string()
+    )
+    .increment(1);
+}
+
+/// Record a client message read before a raw QUIC handshake completed.
+///
+/// Metric: `mc_zero_rtt_messages_total`
+/// Labels: `message_type`, `action`
+///
+/// Message types: `webtransport::validation::message_type_label` values plus
+/// "empty". Action values: "processed" (replay-safe, handled at once),
+/// "deferred" (held until the handshake completes)
+/// Cardinality: 29 x 2 = 58 (in practice "client_hello" and "join_request")
+///
+/// Recorded in `webtransport/early_data.rs`.
+pub fn record_zero_rtt_message(message_type: &str, action: &str) {
+    counter!("mc_zero_rtt_messages_total",
+        "message_type" => message_type.to_string(),
+        "action" => action.to_string()
+    )
+    .increment(1);
+}
+
+/// Record a client connection migrating to a new network path.
+///
+/// Metric: `mc_quic_path_migrations_total`
+/// Labels: `transport`, `kind`
+///
+/// Transport values: "webtransport", "raw_quic". Kind values: "rebinding"
+/// (same IP, new port), "address" (new IP; see `common::quic_path`)
+/// Cardinality: 2 x 2 = 4
+///
+/// Recorded in `webtransport/migration.rs` when a connection's validated
+/// peer address changes.
+pub fn record_quic_path_migration(transport: &str, kind: &str) {
+    counter!("mc_quic_path_migrations_total",
+        "transport" => transport.to_string(),
+        "kind" => kind.to_string()
+    )
+    .increment(1);
+}
+
+/// Record the signaling protocol version a client connected with.
+///
+/// Metric: `mc_client_protocol_version_total`
+/// Labels: `version`, `status`
+///
+/// Version values: "0" through the current `PROTOCOL_VERSION`, "newer" above
+/// it (see `webtransport::protocol::version_label`); clients without a
+/// `ClientHello` count as "1". Status values: "accepted", "rejected"
+/// Cardinality: 4 x 2 = 8 (grows by 2 per protocol version)
+///
+/// Recorded once per connection in the join handshake (`connection.rs`).
+/// Before raising `MC_MIN_PROTOCOL_VERSION`, check the accepted rate of
+/// older versions is zero.
+pub fn record_client_protocol_version(version: &str, status: &str) {
+    counter!("mc_client_protocol_version_total",
+        "version" => version.to_string(),
+        "status" => status.to_string()
+    )
+    .increment(1);
+}
+
+/// Record a post-join client message rejected by schema validation.
+///
+/// Metric: `mc_signaling_messages_rejected_total`
+/// Labels: `message_type`, `reason`
+///
+/// Message type values: the `ClientMessage` oneof field names (29) plus
+/// "empty"; reason values: "too_large", "field_too_long", "too_many_items",
+/// "invalid_enum"
+/// Cardinality: bounded at 30 x 4; in practice a handful of pairs
+///
+/// Recorded in the WebTransport bridge loop (`connection.rs`) before the
+/// message reaches the meeting actor.
+pub fn record_signaling_message_rejected(message_type: &str, reason: &str) {
+    counter!("mc_signaling_messages_rejected_total",
+        "message_type" => message_type.to_string(),
+        "reason" => reason.to_string()
+    )
+    .increment(1);
+}
+
+/// Record a signaling connection closed for client silence.
+///
+/// Metric: `mc_connection_idle_timeouts_total`
+/// Labels: none
+/// Cardinality: 1
+///
+/// Only keepalive-capable clients can time out. Recorded in the
+/// WebTransport bridge loop (`connection.rs`) when no client message arrives
+/// within the idle timeout.
+pub fn record_connection_idle_timeout() {
+    counter!("mc_connection_idle_timeouts_total").increment(1);
+}
+
+/// Record a join by a user who is already in the meeting.
+///
+/// Metric: `mc_duplicate_joins_total`
+/// Labels: `action` ("allowed", "replaced", "rejected")
+/// Cardinality: 3
+///
+/// The action follows the meeting's duplicate join policy from GC. Recorded
+/// by the meeting actor before the new participant is added.
+pub fn record_duplicate_join(action: &'static str) {
+    counter!("mc_duplicate_joins_total", "action" => action).increment(1);
+}
+
+/// Record the media socket options applied at bind time.
+///
+/// Metrics: `mc_media_socket_dscp`, `mc_media_socket_buffer_bytes`,
+/// `mc_media_socket_gso_segments`
+/// Labels: `direction` (send | recv) on the buffer gauge
+/// Cardinality: 2
+///
+/// Values are read back from the kernel, so they confirm what was applied
+/// rather than what was configured.
+///
+/// Recorded once in the WebTransport server (`server.rs`) at bind time.
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "socket buffer sizes << 2^52, no precision loss"
+)]
+pub fn record_media_socket_options(
+    applied: &common::media_socket::AppliedSocketOptions,
+    gso_segments: usize,
+) {
+    gauge!("mc_media_socket_dscp").set(f64::from(applied.dscp));
+    gauge!("mc_media_socket_buffer_bytes", "direction" => "send")
+        .set(applied.send_buffer_bytes as f64);
+    gauge!("mc_media_socket_buffer_bytes", "direction" => "recv")
+        .set(applied.recv_buffer_bytes as f64);
+    gauge!("mc_media_socket_gso_segments").set(gso_segments as f64);
+}
+
+/// Record a Tokio runtime health sample.
+///
+/// Metrics: `mc_runtime_workers`, `mc_runtime_alive_tasks`,
+/// `mc_runtime_global_queue_depth`, `mc_runtime_worker_busy_ratio`,
+/// `mc_runtime_schedule_delay_seconds`
+/// Labels: none
+/// Cardinality: 1 per metric
+///
+/// A busy ratio near 1.0 or a rising schedule delay means the workers are
+/// saturated and every actor and connection task is waiting its turn.
+///
+/// Recorded every `RUNTIME_SAMPLE_INTERVAL` by the sampler task in `main.rs`.
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "task and worker counts << 2^52, no precision loss"
+)]
+pub fn record_runtime_sample(sample: &common::observability::runtime::RuntimeSample) {
+    gauge!("mc_runtime_workers").set(sample.workers as f64);
+    gauge!("mc_runtime_alive_tasks").set(sample.alive_tasks as f64);
+    gauge!("mc_runtime_global_queue_depth").set(sample.global_queue_depth as f64);
+    gauge!("mc_runtime_worker_busy_ratio").set(sample.busy_ratio);
+    histogram!("mc_runtime_schedule_delay_seconds").record(sample.schedule_delay.as_secs_f64());
+}
+
+/// Export the busiest meetings from a usage sample.
+///
+/// Metric: `mc_meeting_usage_top`
+/// Labels: `resource` (messages, bytes, redis_ops), `rank` (1..=`USAGE_TOP_N`)
+/// Cardinality: 3 x 5 = 15
+///
+/// The value is the per-second rate of the meeting at that rank. Meeting IDs
+/// are deliberately not labels; `/debug/meetings` maps a rank back to its
+/// meeting. Ranks with no meeting are set to 0 so stale values do not linger.
+///
+/// Recorded every `USAGE_SAMPLE_INTERVAL` by the usage sampler in `main.rs`.
+pub fn record_meeting_usage(rates: &[crate::actors::MeetingUsageRate]) {
+    use crate::actors::usage::{top_n, UsageResource, USAGE_TOP_N};
+
+    for resource in UsageResource::ALL {
+        let top = top_n(rates, resource, USAGE_TOP_N);
+        for rank in 0..USAGE_TOP_N {
+            let value = top.get(rank).map_or(0.0, |rate| resource.rate(rate));
+            gauge!("mc_meeting_usage_top",
+                "resource" => resource.as_str(),
+                "rank" => (rank + 1).to_string()
+            )
+            .set(value);
+        }
+    }
+}
+
 /// Record a JWT validation attempt.
 ///
 /// Metric: `mc_jwt_validations_total`
@@ -323,6 +582,21 @@ pub fn record_session_join(status: &str, error_type: Option<&str>, duration: Dur
     }
 }
 
+/// Record end-to-end join latency measured from GC assignment.
+///
+/// Metric: `mc_join_latency_seconds`
+/// Labels: `stage` ("accepted", "first_roster")
+/// Cardinality: 2
+///
+/// The start is the timestamp in the client's join correlation ID, stamped
+/// by GC when it assigned the meeting. `accepted` ends at the WebTransport
+/// session accept; `first_roster` ends when the JoinResponse carrying the
+/// participant roster is sent. The join latency SLO is defined on
+/// `first_roster`.
+pub fn record_join_latency(stage: &'static str, latency: Duration) {
+    histogram!("mc_join_latency_seconds", "stage" => stage).record(latency.as_secs_f64());
+}
+
 // ============================================================================
 // MH Registration Metrics
 // ============================================================================
@@ -355,11 +629,11 @@ pub fn record_register_meeting(status: &str, duration: Duration) {
 /// Metric: `mc_mh_notifications_received_total`
 /// Labels: `event_type`
 ///
-/// Event type values: "connected", "disconnected"
-/// Cardinality: 2
+/// Event type values: "connected", "disconnected", "keyframe"
+/// Cardinality: 3
 ///
-/// Recorded in `media_coordination.rs` when MH notifies MC
-/// of participant connection/disconnection events.
+/// Recorded in `media_coordination.rs` when MH notifies MC of participant
+/// connection/disconnection events or asks for a keyframe.
 pub fn record_mh_notification(event_type: &str) {
     counter!("mc_mh_notifications_received_total",
         "event_type" => event_type.to_string()
@@ -367,6 +641,27 @@ pub fn record_mh_notification(event_type: &str) {
     .increment(1);
 }
 
+/// Record a keyframe request handled by a meeting actor.
+///
+/// Metric: `mc_keyframe_requests_total`
+/// Labels: `source`, `outcome`
+///
+/// Source values: "client", "media_handler"; outcome values: "forwarded",
+/// "suppressed" (publisher stream in its cooldown), "no_publisher"
+/// (publisher not connected)
+/// Cardinality: 2 x 3 = 6
+///
+/// Recorded in the `MeetingActor` (`actors/meeting.rs`). A high suppressed
+/// share is expected during join bursts; forwarded requests cost the
+/// publisher a keyframe each.
+pub fn record_keyframe_request(source: &str, outcome: &str) {
+    counter!("mc_keyframe_requests_total",
+        "source" => source.to_string(),
+        "outcome" => outcome.to_string()
+    )
+    .increment(1);
+}
+
 // ============================================================================
 // gRPC Auth Layer 2 Metrics (ADR-0003)
 // ============================================================================
@@ -461,6 +756,19 @@ mod tests {
         record_fenced_out("concurrent_write");
     }
 
+    #[test]
+    fn test_record_schema_fallback_read() {
+        record_schema_fallback_read("generation");
+        record_schema_fallback_read("mh");
+        record_schema_fallback_read("interactions");
+    }
+
+    #[test]
+    fn test_record_state_divergence() {
+        record_state_divergence("interactions");
+        record_state_divergence("roster");
+    }
+
     #[test]
     fn test_record_actor_panic() {
         // Test with all actor types
@@ -527,6 +835,166 @@ mod tests {
         record_webtransport_connection("error");
     }
 
+    #[test]
+    fn test_record_raw_quic_connection() {
+        // Test all 3 bounded status values
+        record_raw_quic_connection("accepted");
+        record_raw_quic_connection("rejected");
+        record_raw_quic_connection("error");
+    }
+
+    #[test]
+    fn test_record_raw_quic_handshake() {
+        let snap = MetricAssertion::snapshot();
+        record_raw_quic_handshake("accepted");
+        record_raw_quic_handshake("none");
+        record_raw_quic_handshake("none");
+
+        snap.counter("mc_raw_quic_handshakes_total")
+            .with_labels(&[("zero_rtt", "accepted")])
+            .assert_delta(1);
+        snap.counter("mc_raw_quic_handshakes_total")
+            .with_labels(&[("zero_rtt", "none")])
+            .assert_delta(2);
+    }
+
+    #[test]
+    fn test_record_zero_rtt_message() {
+        let snap = MetricAssertion::snapshot();
+        record_zero_rtt_message("join_request", "processed");
+        record_zero_rtt_message("poll_vote", "deferred");
+
+        snap.counter("mc_zero_rtt_messages_total")
+            .with_labels(&[("message_type", "join_request"), ("action", "processed")])
+            .assert_delta(1);
+        snap.counter("mc_zero_rtt_messages_total")
+            .with_labels(&[("message_type", "poll_vote"), ("action", "deferred")])
+            .assert_delta(1);
+        snap.counter("mc_zero_rtt_messages_total")
+            .with_labels(&[("message_type", "join_request"), ("action", "deferred")])
+            .assert_delta(0);
+    }
+
+    #[test]
+    fn test_record_quic_path_migration() {
+        let snap = MetricAssertion::snapshot();
+        record_quic_path_migration("raw_quic", "rebinding");
+        record_quic_path_migration("webtransport", "address");
+        record_quic_path_migration("webtransport", "address");
+
+        snap.counter("mc_quic_path_migrations_total")
+            .with_labels(&[("transport", "raw_quic"), ("kind", "rebinding")])
+            .assert_delta(1);
+        snap.counter("mc_quic_path_migrations_total")
+            .with_labels(&[("transport", "webtransport"), ("kind", "address")])
+            .assert_delta(2);
+        snap.counter("mc_quic_path_migrations_total")
+            .with_labels(&[("transport", "webtransport"), ("kind", "rebinding")])
+            .assert_delta(0);
+    }
+
+    #[test]
+    fn test_record_client_protocol_version() {
+        let snap = MetricAssertion::snapshot();
+        record_client_protocol_version("2", "accepted");
+        record_client_protocol_version("1", "rejected");
+
+        snap.counter("mc_client_protocol_version_total")
+            .with_labels(&[("version", "2"), ("status", "accepted")])
+            .assert_delta(1);
+        snap.counter("mc_client_protocol_version_total")
+            .with_labels(&[("version", "1"), ("status", "rejected")])
+            .assert_delta(1);
+        snap.counter("mc_client_protocol_version_total")
+            .with_labels(&[("version", "1"), ("status", "accepted")])
+            .assert_delta(0);
+    }
+
+    #[test]
+    fn test_record_connection_idle_timeout() {
+        let snap = MetricAssertion::snapshot();
+        record_connection_idle_timeout();
+        record_connection_idle_timeout();
+
+        snap.counter("mc_connection_idle_timeouts_total")
+            .assert_delta(2);
+    }
+
+    #[test]
+    fn test_record_duplicate_join() {
+        let snap = MetricAssertion::snapshot();
+        record_duplicate_join("replaced");
+        record_duplicate_join("replaced");
+        record_duplicate_join("rejected");
+
+        snap.counter("mc_duplicate_joins_total")
+            .with_labels(&[("action", "replaced")])
+            .assert_delta(2);
+        snap.counter("mc_duplicate_joins_total")
+            .with_labels(&[("action", "rejected")])
+            .assert_delta(1);
+        snap.counter("mc_duplicate_joins_total")
+            .with_labels(&[("action", "allowed")])
+            .assert_delta(0);
+    }
+
+    #[test]
+    fn test_record_signaling_message_rejected() {
+        let snap = MetricAssertion::snapshot();
+        record_signaling_message_rejected("create_poll", "too_many_items");
+        record_signaling_message_rejected("create_poll", "too_many_items");
+        record_signaling_message_rejected("publish_stream", "invalid_enum");
+
+        snap.counter("mc_signaling_messages_rejected_total")
+            .with_labels(&[
+                ("message_type", "create_poll"),
+                ("reason", "too_many_items"),
+            ])
+            .assert_delta(2);
+        snap.counter("mc_signaling_messages_rejected_total")
+            .with_labels(&[
+                ("message_type", "publish_stream"),
+                ("reason", "invalid_enum"),
+            ])
+            .assert_delta(1);
+    }
+
+    #[test]
+    fn test_record_runtime_sample() {
+        let sample = common::observability::runtime::RuntimeSample {
+            workers: 4,
+            alive_tasks: 120,
+            global_queue_depth: 0,
+            busy_ratio: 0.35,
+            schedule_delay: Duration::from_micros(80),
+        };
+        record_runtime_sample(&sample);
+    }
+
+    #[test]
+    fn test_record_meeting_usage() {
+        let rate = crate::actors::MeetingUsageRate {
+            meeting_id: "meeting-1".to_string(),
+            messages_per_second: 42.0,
+            bytes_per_second: 65_536.0,
+            redis_ops_per_second: 0.5,
+            totals: crate::actors::usage::UsageTotals::default(),
+        };
+        record_meeting_usage(&[rate]);
+        record_meeting_usage(&[]);
+    }
+
+    #[test]
+    fn test_record_media_socket_options() {
+        let applied = common::media_socket::AppliedSocketOptions {
+            dscp: 46,
+            send_buffer_bytes: 4_194_304,
+            recv_buffer_bytes: 8_388_608,
+        };
+        record_media_socket_options(&applied, 64);
+        record_media_socket_options(&applied, 1);
+    }
+
     #[test]
     fn test_record_jwt_validation() {
         // Test all bounded combinations (2 results x 3 token types x representative reasons)
@@ -565,11 +1033,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_record_join_latency() {
+        let snap = MetricAssertion::snapshot();
+        record_join_latency("accepted", Duration::from_millis(400));
+        record_join_latency("first_roster", Duration::from_millis(650));
+
+        snap.histogram("mc_join_latency_seconds")
+            .with_labels(&[("stage", "accepted")])
+            .assert_observation_count(1);
+        snap.histogram("mc_join_latency_seconds")
+            .with_labels(&[("stage", "first_roster")])
+            .assert_observation_count(1);
+    }
+
     #[test]
     fn test_record_mh_notification() {
-        // Test all 2 bounded event values
+        // Test all 3 bounded event values
         record_mh_notification("connected");
         record_mh_notification("disconnected");
+        record_mh_notification("keyframe");
+    }
+
+    #[test]
+    fn test_record_keyframe_request() {
+        let snap = MetricAssertion::snapshot();
+        record_keyframe_request("client", "forwarded");
+        record_keyframe_request("client", "suppressed");
+        record_keyframe_request("client", "suppressed");
+        record_keyframe_request("media_handler", "no_publisher");
+
+        snap.counter("mc_keyframe_requests_total")
+            .with_labels(&[("source", "client"), ("outcome", "suppressed")])
+            .assert_delta(2);
+        snap.counter("mc_keyframe_requests_total")
+            .with_labels(&[("source", "media_handler"), ("outcome", "no_publisher")])
+            .assert_delta(1);
+        snap.counter("mc_keyframe_requests_total")
+            .with_labels(&[("source", "media_handler"), ("outcome", "forwarded")])
+            .assert_delta(0);
     }
 
     #[test]
@@ -616,6 +1118,7 @@ mod tests {
         let valid_connection_statuses = ["accepted", "rejected", "error"];
         for status in &valid_connection_statuses {
             record_webtransport_connection(status);
+            record_raw_quic_connection(status);
         }
 
         let valid_jwt_results = ["success", "failure"];
@@ -804,6 +1307,11 @@ mod tests {
             .with_labels(&[("status", "error")])
             .assert_delta(1);
 
+        record_raw_quic_connection("accepted");
+        snap.counter("mc_raw_quic_connections_total")
+            .with_labels(&[("status", "accepted")])
+            .assert_delta(1);
+
         record_jwt_validation("success", "meeting", "none");
         record_jwt_validation("failure", "meeting", "signature_invalid");
         snap.counter("mc_jwt_validations_total")
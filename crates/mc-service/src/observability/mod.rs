@@ -21,14 +21,24 @@
 //! | `mc_actor_mailbox_depth` | Gauge | `actor_type` | Backpressure indicator per actor type |
 //! | `mc_redis_latency_seconds` | Histogram | `operation` | Redis operation latency |
 //! | `mc_fenced_out_total` | Counter | `reason` | Split-brain fencing events |
+//! | `mc_redis_schema_fallback_reads_total` | Counter | `key` | Dual-write reads served from the old key schema |
+//! | `mc_state_divergence_total` | Counter | `state` | Meeting state found out of step with Redis and re-synced |
 //! | `mc_token_refresh_total` | Counter | `status` | Token refresh attempts |
 //! | `mc_token_refresh_duration_seconds` | Histogram | none | Token refresh latency |
 //! | `mc_token_refresh_failures_total` | Counter | `error_type` | Token refresh failures by type |
+//! | `mc_client_protocol_version_total` | Counter | `version`, `status` | Client signaling protocol versions at handshake |
+//! | `mc_signaling_messages_rejected_total` | Counter | `message_type`, `reason` | Client messages failing schema validation |
+//! | `mc_connection_idle_timeouts_total` | Counter | none | Connections closed by the keepalive idle timeout |
+//! | `mc_duplicate_joins_total` | Counter | `action` | Joins by a user already in the meeting |
+//! | `mc_join_latency_seconds` | Histogram | `stage` | Join latency from GC assignment to accept and first roster |
+//! | `mc_meeting_usage_top` | Gauge | `resource`, `rank` | Busiest meetings per resource (top 5) |
 
+pub mod debug;
 pub mod health;
 pub mod metrics;
 
 // Re-exports for convenience
+pub use debug::meeting_usage_router;
 pub use health::{health_router, HealthState};
 pub use metrics::{
     init_metrics_recorder, record_actor_panic, record_fenced_out, record_gc_heartbeat,
@@ -4,9 +4,22 @@
 //!
 //! # Key Patterns
 //!
-//! - `meeting:{id}:generation` - Fencing generation (monotonic counter)
-//! - `meeting:{id}:mh` - MH assignment data (JSON)
-//! - `meeting:{id}:state` - Meeting metadata (HASH)
+//! Built by [`keys`](crate::redis::keys), prefixed with the schema version:
+//!
+//! - `v1:meeting:{id}:generation` - Fencing generation (monotonic counter)
+//! - `v1:meeting:{id}:mh` - MH assignment data (JSON)
+//! - `v1:meeting:{id}:state` - Meeting metadata (HASH)
+//! - `v1:meeting:{id}:interactions` - Polls and Q&A state (JSON)
+//! - `v1:meeting:{id}:journal` - Membership event journal (STREAM)
+//!
+//! # Dual-Write Mode
+//!
+//! While a schema change rolls out, MCs on the new release are started
+//! with [`FencedRedisClient::with_dual_write`]: every fenced write also goes
+//! to the old layout, fenced on the higher of the two generations, and reads
+//! fall back to the old layout when the new key is missing. Each fallback
+//! that finds data is counted in `mc_redis_schema_fallback_reads_total`;
+//! once that stays at zero the old layout can be dropped.
 //!
 //! # Connection Pattern
 //!
@@ -25,22 +38,29 @@
 //!     webtransport_endpoint: "wt://mh-1:4433".to_string(),
 //!     grpc_endpoint: "http://mh-1:50053".to_string(),
 //! }];
-//! client.store_mh_assignment("meeting-123", handlers).await?;
+//! let meeting_id = MeetingId::new("meeting-123");
+//! client.store_mh_assignment(&meeting_id, handlers).await?;
 //!
 //! // Get current generation
-//! let gen = client.get_generation("meeting-123").await?;
+//! let gen = client.get_generation(&meeting_id).await?;
 //! ```
 
 use crate::errors::McError;
-use crate::observability::metrics::{record_fenced_out, record_redis_latency};
+use crate::ids::MeetingId;
+use crate::observability::metrics::{
+    record_fenced_out, record_redis_latency, record_schema_fallback_read,
+};
+use crate::redis::journal::{self, JournalRecord};
+use crate::redis::keys::{self, MeetingKey};
 use crate::redis::lua_scripts;
+use common::warn_throttled;
 use redis::aio::MultiplexedConnection;
 use redis::{AsyncCommands, Client, Script};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::time::Instant;
 use tokio::sync::RwLock;
-use tracing::{debug, error, instrument, warn};
+use tracing::{debug, error, info, instrument, warn};
 
 /// Information about a single MH endpoint (active/active peers).
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,7 +91,7 @@ pub trait MhAssignmentStore: Send + Sync {
     /// Read MH assignment data for a meeting.
     fn get_mh_assignment<'a>(
         &'a self,
-        meeting_id: &'a str,
+        meeting_id: &'a MeetingId,
     ) -> std::pin::Pin<
         Box<
             dyn std::future::Future<Output = Result<Option<MhAssignmentData>, McError>> + Send + 'a,
@@ -79,6 +99,67 @@ pub trait MhAssignmentStore: Send + Sync {
     >;
 }
 
+/// Trait for persisting meeting interaction state (polls and Q&A).
+///
+/// The `MeetingActor` owns the state and writes a JSON snapshot after each
+/// change so a replacement MC can restore it. Tests can inject an in-memory mock.
+pub trait InteractionStore: Send + Sync {
+    /// Read the interaction snapshot for a meeting.
+    fn load_interactions<'a>(
+        &'a self,
+        meeting_id: &'a MeetingId,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Option<String>, McError>> + Send + 'a>,
+    >;
+
+    /// Replace the interaction snapshot for a meeting.
+    fn store_interactions<'a>(
+        &'a self,
+        meeting_id: &'a MeetingId,
+        json: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), McError>> + Send + 'a>>;
+}
+
+/// Trait for the per-meeting event journal (see [`journal`](crate::redis::journal)).
+///
+/// The `MeetingActor` appends membership events as they happen and replays
+/// the journal on start. Tests can inject an in-memory mock.
+pub trait JournalStore: Send + Sync {
+    /// Append an event (JSON) at the meeting's current generation.
+    fn append_journal<'a>(
+        &'a self,
+        meeting_id: &'a MeetingId,
+        event: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), McError>> + Send + 'a>>;
+
+    /// Read the meeting's journal, oldest first.
+    fn load_journal<'a>(
+        &'a self,
+        meeting_id: &'a MeetingId,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Vec<JournalRecord>, McError>> + Send + 'a>,
+    >;
+
+    /// Read the entries appended after `after_id`, oldest first (the whole
+    /// journal if `after_id` is gone). Defaults to filtering
+    /// [`load_journal`](Self::load_journal).
+    fn load_journal_after<'a>(
+        &'a self,
+        meeting_id: &'a MeetingId,
+        after_id: &'a str,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Vec<JournalRecord>, McError>> + Send + 'a>,
+    > {
+        Box::pin(async move {
+            let mut records = self.load_journal(meeting_id).await?;
+            if let Some(position) = records.iter().position(|record| record.id == after_id) {
+                records.drain(..=position);
+            }
+            Ok(records)
+        })
+    }
+}
+
 /// Fenced Redis client for Meeting Controller.
 ///
 /// All write operations use fencing tokens to prevent split-brain.
@@ -106,6 +187,13 @@ pub struct FencedRedisClient {
     fenced_hset_script: Script,
     fenced_delete_script: Script,
     increment_gen_script: Script,
+    migrate_keys_script: Script,
+    fenced_xadd_script: Script,
+    /// Approximate number of entries kept per meeting journal.
+    journal_max_len: usize,
+    /// Older schema version also written, and read as a fallback
+    /// (see [`FencedRedisClient::with_dual_write`]).
+    dual_write_version: Option<u32>,
 }
 
 impl FencedRedisClient {
@@ -155,29 +243,82 @@ impl FencedRedisClient {
             fenced_hset_script: Script::new(lua_scripts::FENCED_HSET),
             fenced_delete_script: Script::new(lua_scripts::FENCED_DELETE),
             increment_gen_script: Script::new(lua_scripts::INCREMENT_GENERATION),
+            migrate_keys_script: Script::new(lua_scripts::MIGRATE_KEYS),
+            fenced_xadd_script: Script::new(lua_scripts::FENCED_XADD),
+            journal_max_len: journal::DEFAULT_MAX_LEN,
+            dual_write_version: None,
         })
     }
 
+    /// Also write every key in schema `legacy_version`, and read it when
+    /// the current key is missing, so MCs still on the release that uses
+    /// that layout keep working.
+    ///
+    /// Meetings are not migrated on assignment in this mode, since that
+    /// would move their keys away from the older MCs.
+    #[must_use]
+    pub fn with_dual_write(mut self, legacy_version: u32) -> Self {
+        self.dual_write_version =
+            Some(legacy_version).filter(|version| *version != keys::SCHEMA_VERSION);
+        self
+    }
+
+    /// Trim each meeting journal to about `max_len` entries.
+    #[must_use]
+    pub fn with_journal_max_len(mut self, max_len: usize) -> Self {
+        self.journal_max_len = max_len;
+        self
+    }
+
+    /// Generation and `data` keys for a fenced script: the current schema,
+    /// then the dual-write schema if enabled.
+    fn fenced_keys(&self, data: MeetingKey, meeting_id: &MeetingId) -> Vec<String> {
+        let mut fenced_keys = vec![keys::generation(meeting_id), data.build(meeting_id)];
+        if let Some(version) = self.dual_write_version {
+            fenced_keys.push(MeetingKey::Generation.at_version(version, meeting_id));
+            fenced_keys.push(data.at_version(version, meeting_id));
+        }
+        fenced_keys
+    }
+
+    /// `GET` a key, falling back to the dual-write schema if it is missing.
+    async fn get_with_fallback(
+        &self,
+        key: MeetingKey,
+        meeting_id: &MeetingId,
+    ) -> Result<Option<String>, redis::RedisError> {
+        let mut conn = self.connection.clone();
+        let value: Option<String> = conn.get(key.build(meeting_id)).await?;
+        let Some(version) = self.dual_write_version.filter(|_| value.is_none()) else {
+            return Ok(value);
+        };
+
+        let legacy: Option<String> = conn.get(key.at_version(version, meeting_id)).await?;
+        if legacy.is_some() {
+            record_schema_fallback_read(key.suffix());
+        }
+        Ok(legacy)
+    }
+
     /// Get the current generation for a meeting.
     ///
     /// Returns 0 if no generation exists (new meeting).
     #[instrument(skip_all, fields(meeting_id = %meeting_id))]
-    pub async fn get_generation(&self, meeting_id: &str) -> Result<u64, McError> {
-        // Clone the connection (cheap operation) for this request
-        let mut conn = self.connection.clone();
-        let key = format!("meeting:{meeting_id}:generation");
-
+    pub async fn get_generation(&self, meeting_id: &MeetingId) -> Result<u64, McError> {
         let start = Instant::now();
-        let result: Option<String> = conn.get(&key).await.map_err(|e| {
-            record_redis_latency("get", start.elapsed());
-            warn!(
-                target: "mc.redis.client",
-                error = %e,
-                meeting_id = %meeting_id,
-                "Failed to get generation"
-            );
-            McError::Redis(format!("Failed to get generation: {e}"))
-        })?;
+        let result = self
+            .get_with_fallback(MeetingKey::Generation, meeting_id)
+            .await
+            .map_err(|e| {
+                record_redis_latency("get", start.elapsed());
+                warn_throttled!(
+                    target: "mc.redis.client",
+                    error = %e,
+                    meeting_id = %meeting_id,
+                    "Failed to get generation"
+                );
+                McError::Redis(format!("Failed to get generation: {e}"))
+            })?;
         record_redis_latency("get", start.elapsed());
 
         Ok(result.and_then(|s| s.parse().ok()).unwrap_or(0))
@@ -185,27 +326,26 @@ impl FencedRedisClient {
 
     /// Increment the generation for a meeting and return new value.
     #[instrument(skip_all, fields(meeting_id = %meeting_id))]
-    pub async fn increment_generation(&self, meeting_id: &str) -> Result<u64, McError> {
+    pub async fn increment_generation(&self, meeting_id: &MeetingId) -> Result<u64, McError> {
         // Clone the connection (cheap operation) for this request
         let mut conn = self.connection.clone();
-        let key = format!("meeting:{meeting_id}:generation");
+        let mut invocation = self.increment_gen_script.prepare_invoke();
+        invocation.key(keys::generation(meeting_id));
+        if let Some(version) = self.dual_write_version {
+            invocation.key(MeetingKey::Generation.at_version(version, meeting_id));
+        }
 
         let start = Instant::now();
-        let new_gen: i64 = self
-            .increment_gen_script
-            .key(&key)
-            .invoke_async(&mut conn)
-            .await
-            .map_err(|e| {
-                record_redis_latency("incr", start.elapsed());
-                warn!(
-                    target: "mc.redis.client",
-                    error = %e,
-                    meeting_id = %meeting_id,
-                    "Failed to increment generation"
-                );
-                McError::Redis(format!("Failed to increment generation: {e}"))
-            })?;
+        let new_gen: i64 = invocation.invoke_async(&mut conn).await.map_err(|e| {
+            record_redis_latency("incr", start.elapsed());
+            warn_throttled!(
+                target: "mc.redis.client",
+                error = %e,
+                meeting_id = %meeting_id,
+                "Failed to increment generation"
+            );
+            McError::Redis(format!("Failed to increment generation: {e}"))
+        })?;
         record_redis_latency("incr", start.elapsed());
 
         let new_gen = new_gen as u64;
@@ -251,9 +391,21 @@ impl FencedRedisClient {
     #[instrument(skip_all, fields(meeting_id = %meeting_id, handler_count = handlers.len()))]
     pub async fn store_mh_assignment(
         &self,
-        meeting_id: &str,
+        meeting_id: &MeetingId,
         handlers: &[MhEndpointInfo],
     ) -> Result<(), McError> {
+        #[cfg(feature = "fault-injection")]
+        if inject_write_fault().await? {
+            return Ok(());
+        }
+
+        // A meeting last written by an MC on an older key schema keeps its
+        // generation only if its keys move before we increment it. In
+        // dual-write mode the old keys stay put and are fenced alongside.
+        if self.dual_write_version.is_none() {
+            self.migrate_meeting_keys(meeting_id).await?;
+        }
+
         // Get current generation and increment
         let generation = self.increment_generation(meeting_id).await?;
 
@@ -273,21 +425,20 @@ impl FencedRedisClient {
 
         // Clone the connection (cheap operation) for this request
         let mut conn = self.connection.clone();
-        let gen_key = format!("meeting:{meeting_id}:generation");
-        let data_key = format!("meeting:{meeting_id}:mh");
+        let mut invocation = self.fenced_write_script.prepare_invoke();
+        for key in self.fenced_keys(MeetingKey::MhAssignment, meeting_id) {
+            invocation.key(key);
+        }
 
         let start = Instant::now();
-        let result: i64 = self
-            .fenced_write_script
-            .key(&gen_key)
-            .key(&data_key)
+        let result: i64 = invocation
             .arg(generation)
             .arg(&json)
             .invoke_async(&mut conn)
             .await
             .map_err(|e| {
                 record_redis_latency("eval", start.elapsed());
-                warn!(
+                warn_throttled!(
                     target: "mc.redis.client",
                     error = %e,
                     meeting_id = %meeting_id,
@@ -331,26 +482,70 @@ impl FencedRedisClient {
         }
     }
 
-    /// Get MH assignment for a meeting.
+    /// Move a meeting's keys from every older schema version to
+    /// [`keys::SCHEMA_VERSION`].
+    ///
+    /// Keys already in the current schema are left alone. Returns the number
+    /// of keys moved.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Redis` for connection errors.
     #[instrument(skip_all, fields(meeting_id = %meeting_id))]
-    pub async fn get_mh_assignment(
-        &self,
-        meeting_id: &str,
-    ) -> Result<Option<MhAssignmentData>, McError> {
-        let mut conn = self.connection.clone();
-        let key = format!("meeting:{meeting_id}:mh");
+    pub async fn migrate_meeting_keys(&self, meeting_id: &MeetingId) -> Result<u64, McError> {
+        let mut invocation = self.migrate_keys_script.prepare_invoke();
+        for version in 0..keys::SCHEMA_VERSION {
+            for rename in keys::migration(meeting_id, version) {
+                invocation.key(rename.from).key(rename.to);
+            }
+        }
 
+        let mut conn = self.connection.clone();
         let start = Instant::now();
-        let result: Option<String> = conn.get(&key).await.map_err(|e| {
-            record_redis_latency("get", start.elapsed());
-            warn!(
+        let moved: u64 = invocation.invoke_async(&mut conn).await.map_err(|e| {
+            record_redis_latency("eval", start.elapsed());
+            warn_throttled!(
                 target: "mc.redis.client",
                 error = %e,
                 meeting_id = %meeting_id,
-                "Failed to get MH assignment"
+                "Failed to migrate meeting keys"
             );
-            McError::Redis(format!("Failed to get MH assignment: {e}"))
+            McError::Redis(format!("Failed to migrate meeting keys: {e}"))
         })?;
+        record_redis_latency("eval", start.elapsed());
+
+        if moved > 0 {
+            info!(
+                target: "mc.redis.client",
+                meeting_id = %meeting_id,
+                moved = moved,
+                schema_version = keys::SCHEMA_VERSION,
+                "Migrated meeting keys to current schema"
+            );
+        }
+        Ok(moved)
+    }
+
+    /// Get MH assignment for a meeting.
+    #[instrument(skip_all, fields(meeting_id = %meeting_id))]
+    pub async fn get_mh_assignment(
+        &self,
+        meeting_id: &MeetingId,
+    ) -> Result<Option<MhAssignmentData>, McError> {
+        let start = Instant::now();
+        let result = self
+            .get_with_fallback(MeetingKey::MhAssignment, meeting_id)
+            .await
+            .map_err(|e| {
+                record_redis_latency("get", start.elapsed());
+                warn_throttled!(
+                    target: "mc.redis.client",
+                    error = %e,
+                    meeting_id = %meeting_id,
+                    "Failed to get MH assignment"
+                );
+                McError::Redis(format!("Failed to get MH assignment: {e}"))
+            })?;
         record_redis_latency("get", start.elapsed());
 
         match result {
@@ -372,25 +567,24 @@ impl FencedRedisClient {
 
     /// Delete MH assignment for a meeting.
     #[instrument(skip_all, fields(meeting_id = %meeting_id))]
-    pub async fn delete_mh_assignment(&self, meeting_id: &str) -> Result<(), McError> {
+    pub async fn delete_mh_assignment(&self, meeting_id: &MeetingId) -> Result<(), McError> {
         // Get current generation
         let generation = self.get_generation(meeting_id).await?;
 
         let mut conn = self.connection.clone();
-        let gen_key = format!("meeting:{meeting_id}:generation");
-        let data_key = format!("meeting:{meeting_id}:mh");
+        let mut invocation = self.fenced_delete_script.prepare_invoke();
+        for key in self.fenced_keys(MeetingKey::MhAssignment, meeting_id) {
+            invocation.key(key);
+        }
 
         let start = Instant::now();
-        let result: i64 = self
-            .fenced_delete_script
-            .key(&gen_key)
-            .key(&data_key)
+        let result: i64 = invocation
             .arg(generation + 1) // Use next generation for delete
             .invoke_async(&mut conn)
             .await
             .map_err(|e| {
                 record_redis_latency("eval", start.elapsed());
-                warn!(
+                warn_throttled!(
                     target: "mc.redis.client",
                     error = %e,
                     meeting_id = %meeting_id,
@@ -418,6 +612,111 @@ impl FencedRedisClient {
         }
     }
 
+    /// Get the interaction snapshot (polls and Q&A) for a meeting.
+    #[instrument(skip_all, fields(meeting_id = %meeting_id))]
+    pub async fn get_interactions(
+        &self,
+        meeting_id: &MeetingId,
+    ) -> Result<Option<String>, McError> {
+        let start = Instant::now();
+        let result = self
+            .get_with_fallback(MeetingKey::Interactions, meeting_id)
+            .await
+            .map_err(|e| {
+                record_redis_latency("get", start.elapsed());
+                warn_throttled!(
+                    target: "mc.redis.client",
+                    error = %e,
+                    meeting_id = %meeting_id,
+                    "Failed to get interactions"
+                );
+                McError::Redis(format!("Failed to get interactions: {e}"))
+            })?;
+        record_redis_latency("get", start.elapsed());
+
+        Ok(result)
+    }
+
+    /// Store the interaction snapshot (polls and Q&A) for a meeting.
+    ///
+    /// Written at the meeting's current generation, so a write from an MC that
+    /// has lost the meeting to a newer generation is rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::FencedOut` if the generation has moved past the one read.
+    /// Returns `McError::Redis` for connection errors.
+    #[instrument(skip_all, fields(meeting_id = %meeting_id))]
+    pub async fn store_interactions(
+        &self,
+        meeting_id: &MeetingId,
+        json: &str,
+    ) -> Result<(), McError> {
+        #[cfg(feature = "fault-injection")]
+        if inject_write_fault().await? {
+            return Ok(());
+        }
+
+        let generation = self.get_generation(meeting_id).await?;
+
+        let mut conn = self.connection.clone();
+        let mut invocation = self.fenced_write_script.prepare_invoke();
+        for key in self.fenced_keys(MeetingKey::Interactions, meeting_id) {
+            invocation.key(key);
+        }
+
+        let start = Instant::now();
+        let result: i64 = invocation
+            .arg(generation)
+            .arg(json)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| {
+                record_redis_latency("eval", start.elapsed());
+                warn_throttled!(
+                    target: "mc.redis.client",
+                    error = %e,
+                    meeting_id = %meeting_id,
+                    "Failed to store interactions"
+                );
+                McError::Redis(format!("Failed to store interactions: {e}"))
+            })?;
+        record_redis_latency("eval", start.elapsed());
+
+        match result {
+            1 => {
+                debug!(
+                    target: "mc.redis.client",
+                    meeting_id = %meeting_id,
+                    generation = generation,
+                    "Stored interactions"
+                );
+                Ok(())
+            }
+            0 => {
+                record_fenced_out("stale_generation");
+                warn!(
+                    target: "mc.redis.client",
+                    meeting_id = %meeting_id,
+                    generation = generation,
+                    "Fenced out when storing interactions"
+                );
+                Err(McError::FencedOut(format!(
+                    "Generation {generation} is stale"
+                )))
+            }
+            _ => {
+                error!(
+                    target: "mc.redis.client",
+                    meeting_id = %meeting_id,
+                    result = result,
+                    "Invalid generation format in Redis"
+                );
+                Err(McError::Redis("Invalid generation format".to_string()))
+            }
+        }
+    }
+
     /// Store meeting state with fencing.
     ///
     /// # Arguments
@@ -428,21 +727,24 @@ impl FencedRedisClient {
     #[instrument(skip_all, fields(meeting_id = %meeting_id, generation = generation))]
     pub async fn store_meeting_state(
         &self,
-        meeting_id: &str,
+        meeting_id: &MeetingId,
         generation: u64,
         fields: &[(&str, &str)],
     ) -> Result<(), McError> {
+        #[cfg(feature = "fault-injection")]
+        if inject_write_fault().await? {
+            return Ok(());
+        }
+
         let mut conn = self.connection.clone();
-        let gen_key = format!("meeting:{meeting_id}:generation");
-        let state_key = format!("meeting:{meeting_id}:state");
+        let state_keys = self.fenced_keys(MeetingKey::State, meeting_id);
 
         // Use redis cmd to invoke the script directly
         let mut cmd = redis::cmd("EVALSHA");
         let script_hash = self.fenced_hset_script.get_hash();
         cmd.arg(script_hash)
-            .arg(2) // number of keys
-            .arg(&gen_key)
-            .arg(&state_key)
+            .arg(state_keys.len()) // number of keys
+            .arg(&state_keys)
             .arg(generation);
 
         // Add field/value pairs as additional args
@@ -463,9 +765,8 @@ impl FencedRedisClient {
                 let mut eval_cmd = redis::cmd("EVAL");
                 eval_cmd
                     .arg(lua_scripts::FENCED_HSET)
-                    .arg(2)
-                    .arg(&gen_key)
-                    .arg(&state_key)
+                    .arg(state_keys.len())
+                    .arg(&state_keys)
                     .arg(generation);
 
                 for (field, value) in fields {
@@ -475,7 +776,7 @@ impl FencedRedisClient {
                 let eval_start = Instant::now();
                 let eval_result = eval_cmd.query_async(&mut conn).await.map_err(|e| {
                     record_redis_latency("hset", eval_start.elapsed());
-                    warn!(
+                    warn_throttled!(
                         target: "mc.redis.client",
                         error = %e,
                         meeting_id = %meeting_id,
@@ -488,7 +789,7 @@ impl FencedRedisClient {
             }
             Err(e) => {
                 record_redis_latency("hset", start.elapsed());
-                warn!(
+                warn_throttled!(
                     target: "mc.redis.client",
                     error = %e,
                     meeting_id = %meeting_id,
@@ -535,23 +836,148 @@ impl FencedRedisClient {
         }
     }
 
+    /// Append an event to the meeting's journal at its current generation.
+    ///
+    /// Only the current schema has a journal, so this ignores dual-write
+    /// mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::FencedOut` if the generation has moved past the one read.
+    /// Returns `McError::Redis` for connection errors.
+    #[instrument(skip_all, fields(meeting_id = %meeting_id))]
+    pub async fn append_journal(&self, meeting_id: &MeetingId, event: &str) -> Result<(), McError> {
+        #[cfg(feature = "fault-injection")]
+        if inject_write_fault().await? {
+            return Ok(());
+        }
+
+        let generation = self.get_generation(meeting_id).await?;
+
+        let mut conn = self.connection.clone();
+        let start = Instant::now();
+        let result: i64 = self
+            .fenced_xadd_script
+            .key(keys::generation(meeting_id))
+            .key(keys::journal(meeting_id))
+            .arg(generation)
+            .arg(self.journal_max_len)
+            .arg(event)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| {
+                record_redis_latency("eval", start.elapsed());
+                warn_throttled!(
+                    target: "mc.redis.client",
+                    error = %e,
+                    meeting_id = %meeting_id,
+                    "Failed to append to meeting journal"
+                );
+                McError::Redis(format!("Failed to append to meeting journal: {e}"))
+            })?;
+        record_redis_latency("eval", start.elapsed());
+
+        match result {
+            1 => Ok(()),
+            0 => {
+                record_fenced_out("stale_generation");
+                warn!(
+                    target: "mc.redis.client",
+                    meeting_id = %meeting_id,
+                    generation = generation,
+                    "Fenced out when appending to meeting journal"
+                );
+                Err(McError::FencedOut(format!(
+                    "Generation {generation} is stale"
+                )))
+            }
+            _ => {
+                error!(
+                    target: "mc.redis.client",
+                    meeting_id = %meeting_id,
+                    result = result,
+                    "Invalid generation format in Redis"
+                );
+                Err(McError::Redis("Invalid generation format".to_string()))
+            }
+        }
+    }
+
+    /// Read the meeting's journal, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Redis` for connection errors.
+    #[instrument(skip_all, fields(meeting_id = %meeting_id))]
+    pub async fn load_journal(
+        &self,
+        meeting_id: &MeetingId,
+    ) -> Result<Vec<JournalRecord>, McError> {
+        self.read_journal(meeting_id, None).await
+    }
+
+    /// Read the meeting's journal entries appended after `after_id`,
+    /// oldest first (the whole journal if `after_id` has been trimmed).
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Redis` for connection errors.
+    #[instrument(skip_all, fields(meeting_id = %meeting_id))]
+    pub async fn load_journal_after(
+        &self,
+        meeting_id: &MeetingId,
+        after_id: &str,
+    ) -> Result<Vec<JournalRecord>, McError> {
+        self.read_journal(meeting_id, Some(after_id)).await
+    }
+
+    async fn read_journal(
+        &self,
+        meeting_id: &MeetingId,
+        after_id: Option<&str>,
+    ) -> Result<Vec<JournalRecord>, McError> {
+        let mut conn = self.connection.clone();
+        let key = keys::journal(meeting_id);
+
+        let start = Instant::now();
+        let records = match after_id {
+            Some(after_id) => journal::read_after(&mut conn, &key, after_id).await,
+            None => journal::read(&mut conn, &key).await,
+        };
+        let records = records.map_err(|e| {
+            record_redis_latency("xrange", start.elapsed());
+            warn_throttled!(
+                target: "mc.redis.client",
+                error = %e,
+                meeting_id = %meeting_id,
+                "Failed to read meeting journal"
+            );
+            McError::Redis(format!("Failed to read meeting journal: {e}"))
+        })?;
+        record_redis_latency("xrange", start.elapsed());
+
+        Ok(records)
+    }
+
     /// Delete all meeting data (cleanup on meeting end).
     #[instrument(skip_all, fields(meeting_id = %meeting_id))]
-    pub async fn delete_meeting(&self, meeting_id: &str) -> Result<(), McError> {
+    pub async fn delete_meeting(&self, meeting_id: &MeetingId) -> Result<(), McError> {
         let mut conn = self.connection.clone();
 
-        // Delete all meeting keys
-        let keys = vec![
-            format!("meeting:{meeting_id}:generation"),
-            format!("meeting:{meeting_id}:mh"),
-            format!("meeting:{meeting_id}:state"),
-            format!("meeting:{meeting_id}:participants"),
-        ];
+        // Delete all meeting keys, in both layouts when dual-writing
+        let mut meeting_keys = keys::meeting_keys(meeting_id);
+        if let Some(version) = self.dual_write_version {
+            meeting_keys.extend(
+                MeetingKey::ALL
+                    .iter()
+                    .map(|key| key.at_version(version, meeting_id)),
+            );
+        }
 
         let start = Instant::now();
-        let _: () = conn.del(&keys).await.map_err(|e| {
+        let _: () = conn.del(&meeting_keys).await.map_err(|e| {
             record_redis_latency("del", start.elapsed());
-            warn!(
+            warn_throttled!(
                 target: "mc.redis.client",
                 error = %e,
                 meeting_id = %meeting_id,
@@ -564,7 +990,7 @@ impl FencedRedisClient {
         // Clear local cache
         {
             let mut cache = self.local_generation.write().await;
-            cache.remove(meeting_id);
+            cache.remove(meeting_id.as_str());
         }
 
         debug!(
@@ -577,10 +1003,24 @@ impl FencedRedisClient {
     }
 }
 
+/// Apply an armed [`REDIS_WRITE`](crate::faults::REDIS_WRITE) fault.
+/// `Ok(true)` means the write was dropped and the caller should report
+/// success without writing.
+#[cfg(feature = "fault-injection")]
+async fn inject_write_fault() -> Result<bool, McError> {
+    use common::fault_injection::{inject, InjectedFault};
+
+    match inject(crate::faults::REDIS_WRITE).await {
+        Ok(()) => Ok(false),
+        Err(InjectedFault::Drop(_)) => Ok(true),
+        Err(fault @ InjectedFault::Error(_)) => Err(McError::Redis(fault.to_string())),
+    }
+}
+
 impl MhAssignmentStore for FencedRedisClient {
     fn get_mh_assignment<'a>(
         &'a self,
-        meeting_id: &'a str,
+        meeting_id: &'a MeetingId,
     ) -> std::pin::Pin<
         Box<
             dyn std::future::Future<Output = Result<Option<MhAssignmentData>, McError>> + Send + 'a,
@@ -590,6 +1030,54 @@ impl MhAssignmentStore for FencedRedisClient {
     }
 }
 
+impl InteractionStore for FencedRedisClient {
+    fn load_interactions<'a>(
+        &'a self,
+        meeting_id: &'a MeetingId,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Option<String>, McError>> + Send + 'a>,
+    > {
+        Box::pin(self.get_interactions(meeting_id))
+    }
+
+    fn store_interactions<'a>(
+        &'a self,
+        meeting_id: &'a MeetingId,
+        json: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), McError>> + Send + 'a>> {
+        Box::pin(self.store_interactions(meeting_id, json))
+    }
+}
+
+impl JournalStore for FencedRedisClient {
+    fn append_journal<'a>(
+        &'a self,
+        meeting_id: &'a MeetingId,
+        event: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), McError>> + Send + 'a>> {
+        Box::pin(self.append_journal(meeting_id, event))
+    }
+
+    fn load_journal<'a>(
+        &'a self,
+        meeting_id: &'a MeetingId,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Vec<JournalRecord>, McError>> + Send + 'a>,
+    > {
+        Box::pin(self.load_journal(meeting_id))
+    }
+
+    fn load_journal_after<'a>(
+        &'a self,
+        meeting_id: &'a MeetingId,
+        after_id: &'a str,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Vec<JournalRecord>, McError>> + Send + 'a>,
+    > {
+        Box::pin(self.load_journal_after(meeting_id, after_id))
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -717,19 +1205,25 @@ mod tests {
     #[test]
     fn test_redis_key_format() {
         // Verify key format used by the client
-        let meeting_id = "meeting-123";
+        let meeting_id = MeetingId::new("meeting-123");
+
+        let gen_key = keys::generation(&meeting_id);
+        assert_eq!(gen_key, "v1:meeting:meeting-123:generation");
+
+        let mh_key = keys::mh_assignment(&meeting_id);
+        assert_eq!(mh_key, "v1:meeting:meeting-123:mh");
 
-        let gen_key = format!("meeting:{meeting_id}:generation");
-        assert_eq!(gen_key, "meeting:meeting-123:generation");
+        let state_key = keys::state(&meeting_id);
+        assert_eq!(state_key, "v1:meeting:meeting-123:state");
 
-        let mh_key = format!("meeting:{meeting_id}:mh");
-        assert_eq!(mh_key, "meeting:meeting-123:mh");
+        let participants_key = keys::participants(&meeting_id);
+        assert_eq!(participants_key, "v1:meeting:meeting-123:participants");
 
-        let state_key = format!("meeting:{meeting_id}:state");
-        assert_eq!(state_key, "meeting:meeting-123:state");
+        let interactions_key = keys::interactions(&meeting_id);
+        assert_eq!(interactions_key, "v1:meeting:meeting-123:interactions");
 
-        let participants_key = format!("meeting:{meeting_id}:participants");
-        assert_eq!(participants_key, "meeting:meeting-123:participants");
+        let journal_key = keys::journal(&meeting_id);
+        assert_eq!(journal_key, "v1:meeting:meeting-123:journal");
     }
 
     #[test]
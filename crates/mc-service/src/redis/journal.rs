@@ -0,0 +1,71 @@
+//! Per-meeting event journal (`v1:meeting:{id}:journal`).
+//!
+//! An append-only Redis stream of membership events. Each entry carries the
+//! fencing generation of the MC that wrote it, so a reader can tell which
+//! MC's view of the meeting an event came from. The stream is only trimmed
+//! by length (`MAXLEN ~`); nothing expires it when the meeting ends, so
+//! support can still audit the tail afterwards.
+//!
+//! Events are opaque JSON here; the `MeetingActor` defines and replays
+//! them (`actors::journal`).
+
+use redis::aio::MultiplexedConnection;
+use std::collections::HashMap;
+
+/// Entries kept per meeting unless configured otherwise.
+pub const DEFAULT_MAX_LEN: usize = 1000;
+
+/// One journal entry as stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalRecord {
+    /// Stream entry ID (`{ms}-{seq}`), assigned by Redis.
+    pub id: String,
+    /// Fencing generation the entry was written at.
+    pub generation: u64,
+    /// Event (JSON).
+    pub event: String,
+}
+
+/// Read a meeting's whole journal, oldest first.
+pub(crate) async fn read(
+    conn: &mut MultiplexedConnection,
+    key: &str,
+) -> Result<Vec<JournalRecord>, redis::RedisError> {
+    read_from(conn, key, "-").await
+}
+
+/// Read the entries appended after `after_id`, oldest first. If `after_id`
+/// has been trimmed away this is everything still in the journal.
+pub(crate) async fn read_after(
+    conn: &mut MultiplexedConnection,
+    key: &str,
+    after_id: &str,
+) -> Result<Vec<JournalRecord>, redis::RedisError> {
+    // `(` makes the XRANGE start exclusive
+    read_from(conn, key, &format!("({after_id}")).await
+}
+
+async fn read_from(
+    conn: &mut MultiplexedConnection,
+    key: &str,
+    start: &str,
+) -> Result<Vec<JournalRecord>, redis::RedisError> {
+    let entries: Vec<(String, HashMap<String, String>)> = redis::cmd("XRANGE")
+        .arg(key)
+        .arg(start)
+        .arg("+")
+        .query_async(conn)
+        .await?;
+
+    Ok(entries
+        .into_iter()
+        .map(|(id, mut fields)| JournalRecord {
+            id,
+            generation: fields
+                .get("generation")
+                .and_then(|generation| generation.parse().ok())
+                .unwrap_or(0),
+            event: fields.remove("event").unwrap_or_default(),
+        })
+        .collect())
+}
@@ -0,0 +1,234 @@
+//! Redis key schema (ADR-0023 Section 6).
+//!
+//! Every key MC reads or writes is built here rather than formatted at the
+//! call site. Keys start with the schema version (`v1:meeting:{id}:mh`), so
+//! a release that changes the layout bumps [`SCHEMA_VERSION`] and moves the
+//! keys of live meetings with [`migration`] instead of reading both layouts.
+//!
+//! Version 0 is the unprefixed layout (`meeting:{id}:mh`) written before
+//! keys were versioned.
+
+use crate::ids::MeetingId;
+
+/// Schema version of the keys this MC writes.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// A per-meeting key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeetingKey {
+    /// Fencing generation (monotonic counter).
+    Generation,
+    /// MH assignment data (JSON).
+    MhAssignment,
+    /// Meeting metadata (HASH).
+    State,
+    /// Participant list (ZSET by join time).
+    Participants,
+    /// Polls and Q&A state (JSON).
+    Interactions,
+    /// Membership event journal (STREAM).
+    Journal,
+}
+
+impl MeetingKey {
+    /// Every per-meeting key.
+    pub const ALL: [Self; 6] = [
+        Self::Generation,
+        Self::MhAssignment,
+        Self::State,
+        Self::Participants,
+        Self::Interactions,
+        Self::Journal,
+    ];
+
+    /// Last segment of the key (`generation`, `mh`, ...), the same in every
+    /// schema version.
+    #[must_use]
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Self::Generation => "generation",
+            Self::MhAssignment => "mh",
+            Self::State => "state",
+            Self::Participants => "participants",
+            Self::Interactions => "interactions",
+            Self::Journal => "journal",
+        }
+    }
+
+    /// This key for `meeting_id` in the current schema.
+    #[must_use]
+    pub fn build(self, meeting_id: &MeetingId) -> String {
+        self.at_version(SCHEMA_VERSION, meeting_id)
+    }
+
+    /// This key for `meeting_id` as schema `version` lays it out.
+    #[must_use]
+    pub fn at_version(self, version: u32, meeting_id: &MeetingId) -> String {
+        let suffix = self.suffix();
+        match version {
+            0 => format!("meeting:{meeting_id}:{suffix}"),
+            _ => format!("v{version}:meeting:{meeting_id}:{suffix}"),
+        }
+    }
+}
+
+/// `v1:meeting:{id}:generation`
+#[must_use]
+pub fn generation(meeting_id: &MeetingId) -> String {
+    MeetingKey::Generation.build(meeting_id)
+}
+
+/// `v1:meeting:{id}:mh`
+#[must_use]
+pub fn mh_assignment(meeting_id: &MeetingId) -> String {
+    MeetingKey::MhAssignment.build(meeting_id)
+}
+
+/// `v1:meeting:{id}:state`
+#[must_use]
+pub fn state(meeting_id: &MeetingId) -> String {
+    MeetingKey::State.build(meeting_id)
+}
+
+/// `v1:meeting:{id}:participants`
+#[must_use]
+pub fn participants(meeting_id: &MeetingId) -> String {
+    MeetingKey::Participants.build(meeting_id)
+}
+
+/// `v1:meeting:{id}:interactions`
+#[must_use]
+pub fn interactions(meeting_id: &MeetingId) -> String {
+    MeetingKey::Interactions.build(meeting_id)
+}
+
+/// `v1:meeting:{id}:journal`
+#[must_use]
+pub fn journal(meeting_id: &MeetingId) -> String {
+    MeetingKey::Journal.build(meeting_id)
+}
+
+/// All of a meeting's keys in the current schema.
+#[must_use]
+pub fn meeting_keys(meeting_id: &MeetingId) -> Vec<String> {
+    MeetingKey::ALL
+        .iter()
+        .map(|key| key.build(meeting_id))
+        .collect()
+}
+
+/// `SCAN` pattern matching every meeting's generation key, in any schema.
+pub const GENERATION_KEY_PATTERN: &str = "*meeting:*:generation";
+
+/// The schema version and meeting of a generation key, or `None` if `key`
+/// is not one.
+#[must_use]
+pub fn parse_generation_key(key: &str) -> Option<(u32, MeetingId)> {
+    let (version, rest) = match key.strip_prefix('v') {
+        Some(versioned) => {
+            let (version, rest) = versioned.split_once(':')?;
+            (version.parse().ok().filter(|v| *v > 0)?, rest)
+        }
+        None => (0, key),
+    };
+    let meeting_id = rest.strip_prefix("meeting:")?.strip_suffix(":generation")?;
+    if meeting_id.is_empty() {
+        return None;
+    }
+    Some((version, MeetingId::new(meeting_id)))
+}
+
+/// One key moving between schema versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRename {
+    /// Key in the old schema.
+    pub from: String,
+    /// Key in the current schema.
+    pub to: String,
+}
+
+/// Renames that move a meeting's keys from schema `from_version` to
+/// [`SCHEMA_VERSION`]. Empty if `from_version` is already current.
+#[must_use]
+pub fn migration(meeting_id: &MeetingId, from_version: u32) -> Vec<KeyRename> {
+    if from_version == SCHEMA_VERSION {
+        return Vec::new();
+    }
+    MeetingKey::ALL
+        .iter()
+        .map(|key| KeyRename {
+            from: key.at_version(from_version, meeting_id),
+            to: key.build(meeting_id),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_carry_schema_version() {
+        let meeting_id = MeetingId::new("meeting-123");
+
+        assert_eq!(generation(&meeting_id), "v1:meeting:meeting-123:generation");
+        assert_eq!(mh_assignment(&meeting_id), "v1:meeting:meeting-123:mh");
+        assert_eq!(state(&meeting_id), "v1:meeting:meeting-123:state");
+        assert_eq!(
+            participants(&meeting_id),
+            "v1:meeting:meeting-123:participants"
+        );
+        assert_eq!(
+            interactions(&meeting_id),
+            "v1:meeting:meeting-123:interactions"
+        );
+        assert_eq!(journal(&meeting_id), "v1:meeting:meeting-123:journal");
+        assert_eq!(meeting_keys(&meeting_id).len(), MeetingKey::ALL.len());
+    }
+
+    #[test]
+    fn test_version_zero_is_unprefixed() {
+        let meeting_id = MeetingId::new("meeting-123");
+        assert_eq!(
+            MeetingKey::MhAssignment.at_version(0, &meeting_id),
+            "meeting:meeting-123:mh"
+        );
+    }
+
+    #[test]
+    fn test_migration_renames_every_key() {
+        let meeting_id = MeetingId::new("meeting-123");
+
+        let renames = migration(&meeting_id, 0);
+        assert_eq!(renames.len(), MeetingKey::ALL.len());
+        assert!(renames.contains(&KeyRename {
+            from: "meeting:meeting-123:generation".to_string(),
+            to: "v1:meeting:meeting-123:generation".to_string(),
+        }));
+
+        assert!(migration(&meeting_id, SCHEMA_VERSION).is_empty());
+    }
+
+    #[test]
+    fn test_parse_generation_key_round_trips() {
+        let meeting_id = MeetingId::new("meeting-123");
+        for version in 0..=SCHEMA_VERSION {
+            let key = MeetingKey::Generation.at_version(version, &meeting_id);
+            assert_eq!(
+                parse_generation_key(&key),
+                Some((version, meeting_id.clone()))
+            );
+        }
+
+        assert_eq!(parse_generation_key("v1:meeting:meeting-123:mh"), None);
+        assert_eq!(
+            parse_generation_key("v0:meeting:meeting-123:generation"),
+            None
+        );
+        assert_eq!(
+            parse_generation_key("vx:meeting:meeting-123:generation"),
+            None
+        );
+        assert_eq!(parse_generation_key("meeting::generation"), None);
+    }
+}
@@ -14,8 +14,10 @@
 /// Lua script for fenced write operation.
 ///
 /// Arguments:
-/// - KEYS[1]: Generation key (e.g., `meeting:{id}:generation`)
-/// - KEYS[2]: Data key (e.g., `meeting:{id}:mh`)
+/// - KEYS[1]: Generation key (e.g., `v1:meeting:{id}:generation`)
+/// - KEYS[2]: Data key (e.g., `v1:meeting:{id}:mh`)
+/// - KEYS[3], KEYS[4]: Optional generation and data key in a second schema
+///   (dual-write mode); fenced on the higher generation, written together
 /// - ARGV[1]: Expected generation (fencing token)
 /// - ARGV[2]: Data to write (JSON string)
 ///
@@ -24,42 +26,44 @@
 /// - 0: Fenced out (stale generation)
 /// - -1: Error (invalid generation format)
 pub const FENCED_WRITE: &str = r#"
--- Get current generation
-local current_gen = redis.call('GET', KEYS[1])
 local expected_gen = tonumber(ARGV[1])
 
 if expected_gen == nil then
     return -1
 end
 
-if current_gen == nil or current_gen == false then
-    -- No generation set yet, this is the first write
-    redis.call('SET', KEYS[1], expected_gen)
-    redis.call('SET', KEYS[2], ARGV[2])
-    return 1
-end
-
-local current = tonumber(current_gen)
-if current == nil then
-    return -1
+for i = 1, #KEYS, 2 do
+    -- Get current generation
+    local current_gen = redis.call('GET', KEYS[i])
+    if current_gen == nil or current_gen == false then
+        -- No generation set yet, this is the first write
+    else
+        local current = tonumber(current_gen)
+        if current == nil then
+            return -1
+        end
+        if expected_gen >= current then
+            -- Valid generation for this schema
+        else
+            -- Stale generation, reject
+            return 0
+        end
+    end
 end
 
-if expected_gen >= current then
-    -- Valid generation, update both
-    redis.call('SET', KEYS[1], expected_gen)
-    redis.call('SET', KEYS[2], ARGV[2])
-    return 1
-else
-    -- Stale generation, reject
-    return 0
+for i = 1, #KEYS, 2 do
+    redis.call('SET', KEYS[i], expected_gen)
+    redis.call('SET', KEYS[i + 1], ARGV[2])
 end
+return 1
 "#;
 
 /// Lua script for fenced hash write operation.
 ///
 /// Arguments:
-/// - KEYS[1]: Generation key (e.g., `meeting:{id}:generation`)
-/// - KEYS[2]: Hash key (e.g., `meeting:{id}:state`)
+/// - KEYS[1]: Generation key (e.g., `v1:meeting:{id}:generation`)
+/// - KEYS[2]: Hash key (e.g., `v1:meeting:{id}:state`)
+/// - KEYS[3], KEYS[4]: Optional second schema, as for [`FENCED_WRITE`]
 /// - ARGV[1]: Expected generation (fencing token)
 /// - ARGV[2..]: Hash field-value pairs
 ///
@@ -68,61 +72,65 @@ end
 /// - 0: Fenced out (stale generation)
 /// - -1: Error (invalid generation format)
 pub const FENCED_HSET: &str = r#"
--- Get current generation
-local current_gen = redis.call('GET', KEYS[1])
 local expected_gen = tonumber(ARGV[1])
 
 if expected_gen == nil then
     return -1
 end
 
-if current_gen == nil or current_gen == false then
-    -- No generation set yet, this is the first write
-    redis.call('SET', KEYS[1], expected_gen)
-    -- Write hash fields (ARGV[2] onwards are field/value pairs)
-    for i = 2, #ARGV, 2 do
-        redis.call('HSET', KEYS[2], ARGV[i], ARGV[i+1])
+for k = 1, #KEYS, 2 do
+    -- Get current generation
+    local current_gen = redis.call('GET', KEYS[k])
+    if current_gen == nil or current_gen == false then
+        -- No generation set yet, this is the first write
+    else
+        local current = tonumber(current_gen)
+        if current == nil then
+            return -1
+        end
+        if expected_gen >= current then
+            -- Valid generation for this schema
+        else
+            -- Stale generation, reject
+            return 0
+        end
     end
-    return 1
-end
-
-local current = tonumber(current_gen)
-if current == nil then
-    return -1
 end
 
-if expected_gen >= current then
-    -- Valid generation, update
-    redis.call('SET', KEYS[1], expected_gen)
+for k = 1, #KEYS, 2 do
+    redis.call('SET', KEYS[k], expected_gen)
+    -- Write hash fields (ARGV[2] onwards are field/value pairs)
     for i = 2, #ARGV, 2 do
-        redis.call('HSET', KEYS[2], ARGV[i], ARGV[i+1])
+        redis.call('HSET', KEYS[k + 1], ARGV[i], ARGV[i+1])
     end
-    return 1
-else
-    -- Stale generation, reject
-    return 0
 end
+return 1
 "#;
 
 /// Lua script to increment generation and return new value.
 ///
 /// Arguments:
-/// - KEYS[1]: Generation key
+/// - KEYS[1..]: Generation key, plus the same key in a second schema in
+///   dual-write mode; all are set to one past the highest
 ///
 /// Returns:
 /// - New generation value
 pub const INCREMENT_GENERATION: &str = r#"
-local current = redis.call('GET', KEYS[1])
 local new_gen = 1
 
-if current ~= nil and current ~= false then
-    local val = tonumber(current)
-    if val ~= nil then
-        new_gen = val + 1
+for i = 1, #KEYS do
+    local current = redis.call('GET', KEYS[i])
+    if current ~= nil and current ~= false then
+        local val = tonumber(current)
+        if val ~= nil and val + 1 > new_gen then
+            new_gen = val + 1
+        end
     end
 end
 
-redis.call('SET', KEYS[1], new_gen)
+for i = 1, #KEYS do
+    redis.call('SET', KEYS[i], new_gen)
+end
 return new_gen
 "#;
 
@@ -131,6 +139,7 @@ return new_gen
 /// Arguments:
 /// - KEYS[1]: Generation key
 /// - KEYS[2]: Data key to delete
+/// - KEYS[3], KEYS[4]: Optional second schema, as for [`FENCED_WRITE`]
 /// - ARGV[1]: Expected generation (fencing token)
 ///
 /// Returns:
@@ -138,7 +147,54 @@ return new_gen
 /// - 0: Fenced out (stale generation)
 /// - -1: Error (invalid generation format)
 pub const FENCED_DELETE: &str = r#"
--- Get current generation
+local expected_gen = tonumber(ARGV[1])
+
+if expected_gen == nil then
+    return -1
+end
+
+local present = {}
+for i = 1, #KEYS, 2 do
+    -- Get current generation
+    local current_gen = redis.call('GET', KEYS[i])
+    if current_gen == nil or current_gen == false then
+        -- No generation set, nothing to delete
+    else
+        local current = tonumber(current_gen)
+        if current == nil then
+            return -1
+        end
+        if expected_gen >= current then
+            present[#present + 1] = i
+        else
+            -- Stale generation, reject
+            return 0
+        end
+    end
+end
+
+-- Valid generation, delete and bump generation
+for _, i in ipairs(present) do
+    redis.call('SET', KEYS[i], expected_gen)
+    redis.call('DEL', KEYS[i + 1])
+end
+return 1
+"#;
+
+/// Lua script for a fenced append to a meeting's event journal.
+///
+/// Arguments:
+/// - KEYS[1]: Generation key
+/// - KEYS[2]: Journal stream key
+/// - ARGV[1]: Expected generation (fencing token), recorded in the entry
+/// - ARGV[2]: Approximate maximum stream length (`MAXLEN ~`)
+/// - ARGV[3]: Event (JSON string)
+///
+/// Returns:
+/// - 1: Success (entry appended)
+/// - 0: Fenced out (stale generation)
+/// - -1: Error (invalid generation format)
+pub const FENCED_XADD: &str = r#"
 local current_gen = redis.call('GET', KEYS[1])
 local expected_gen = tonumber(ARGV[1])
 
@@ -146,25 +202,68 @@ if expected_gen == nil then
     return -1
 end
 
-if current_gen == nil or current_gen == false then
-    -- No generation set, nothing to delete
-    return 1
+if current_gen ~= nil and current_gen ~= false then
+    local current = tonumber(current_gen)
+    if current == nil then
+        return -1
+    end
+    if expected_gen < current then
+        -- Stale generation, reject
+        return 0
+    end
+end
+
+redis.call('XADD', KEYS[2], 'MAXLEN', '~', ARGV[2], '*',
+    'generation', ARGV[1], 'event', ARGV[3])
+return 1
+"#;
+
+/// Lua script moving keys to a new schema version (see `redis::keys`).
+///
+/// Arguments:
+/// - KEYS[2n-1]: Key in the old schema
+/// - KEYS[2n]: Same key in the new schema
+///
+/// Each old key is renamed unless it is missing or the new key already
+/// exists, so running it twice is harmless.
+///
+/// Returns:
+/// - Number of keys moved
+pub const MIGRATE_KEYS: &str = r#"
+local moved = 0
+for i = 1, #KEYS, 2 do
+    if redis.call('EXISTS', KEYS[i]) == 1 and redis.call('EXISTS', KEYS[i + 1]) == 0 then
+        redis.call('RENAME', KEYS[i], KEYS[i + 1])
+        moved = moved + 1
+    end
 end
+return moved
+"#;
 
-local current = tonumber(current_gen)
-if current == nil then
+/// [`MIGRATE_KEYS`] that first checks the meeting's generation, for
+/// migrating while MCs are running (`mc-state-migrate migrate --live`).
+///
+/// Arguments:
+/// - KEYS[1]: Generation key in the old schema (also the first rename)
+/// - KEYS[2n-1], KEYS[2n]: Old and new key, as for [`MIGRATE_KEYS`]
+/// - ARGV[1]: Generation read when the meeting was scanned
+///
+/// Returns:
+/// - Number of keys moved
+/// - -1: Generation changed since the scan (an MC wrote meanwhile)
+pub const FENCED_MIGRATE_KEYS: &str = r#"
+if redis.call('GET', KEYS[1]) ~= ARGV[1] then
     return -1
 end
 
-if expected_gen >= current then
-    -- Valid generation, delete and bump generation
-    redis.call('SET', KEYS[1], expected_gen)
-    redis.call('DEL', KEYS[2])
-    return 1
-else
-    -- Stale generation, reject
-    return 0
+local moved = 0
+for i = 1, #KEYS, 2 do
+    if redis.call('EXISTS', KEYS[i]) == 1 and redis.call('EXISTS', KEYS[i + 1]) == 0 then
+        redis.call('RENAME', KEYS[i], KEYS[i + 1])
+        moved = moved + 1
+    end
 end
+return moved
 "#;
 
 #[cfg(test)]
@@ -184,6 +283,13 @@ mod tests {
         assert!(INCREMENT_GENERATION.contains("tonumber"));
 
         assert!(FENCED_DELETE.contains("DEL"));
+
+        assert!(FENCED_XADD.contains("XADD"));
+        assert!(FENCED_XADD.contains("MAXLEN"));
+
+        assert!(MIGRATE_KEYS.contains("RENAME"));
+        assert!(FENCED_MIGRATE_KEYS.contains("RENAME"));
+        assert!(FENCED_MIGRATE_KEYS.contains("return -1"));
     }
 
     #[test]
@@ -297,6 +403,18 @@ mod tests {
         assert!(FENCED_DELETE.contains("if expected_gen == nil then"));
     }
 
+    #[test]
+    fn test_fenced_scripts_check_every_schema_before_writing() {
+        // Dual-write mode passes a second (generation, data) pair; a stale
+        // generation in either must reject the write before anything is set
+        for script in [FENCED_WRITE, FENCED_HSET, FENCED_DELETE] {
+            let check = script.find("return 0").unwrap();
+            let write = script.rfind("redis.call('SET'").unwrap();
+            assert!(check < write);
+        }
+        assert!(INCREMENT_GENERATION.contains("for i = 1, #KEYS do"));
+    }
+
     #[test]
     fn test_scripts_validate_current_generation() {
         // All fenced operations should validate current generation is a number
@@ -0,0 +1,273 @@
+//! Moving MC state between Redis key schemas (`mc-state-migrate`).
+//!
+//! MCs move a meeting's keys themselves when the meeting is next assigned
+//! (`FencedRedisClient::migrate_meeting_keys`). Meetings that stay on one
+//! MC through an upgrade keep their old keys until [`StateMigrator`] moves
+//! them, and a layout change that needs more than a rename (the
+//! participant ZSET encoding, say) has to be applied here before any MC
+//! reads the new schema.
+//!
+//! # Modes
+//!
+//! - [`MigrationMode::Offline`]: no MC is running; keys are renamed as found.
+//! - [`MigrationMode::Live`]: every MC already runs the release that reads
+//!   the new schema. Each meeting moves in one script that first checks its
+//!   generation is still the one scanned, so a meeting an MC wrote in
+//!   between is retried instead of moved from under it.
+
+use crate::errors::McError;
+use crate::ids::MeetingId;
+use crate::redis::keys::{self, MeetingKey};
+use crate::redis::{lua_scripts, MhAssignmentData};
+use redis::aio::MultiplexedConnection;
+use redis::{AsyncCommands, Client, Script};
+use std::fmt;
+
+/// Keys fetched per `SCAN` call.
+const SCAN_COUNT: u64 = 500;
+
+/// Attempts per meeting in live mode before giving up on it.
+const LIVE_ATTEMPTS: u32 = 3;
+
+/// How keys are moved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationMode {
+    /// No MC is running.
+    Offline,
+    /// MCs are running; moves are fenced on the meeting's generation.
+    Live,
+}
+
+/// A meeting found by [`StateMigrator::scan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScannedMeeting {
+    /// Meeting ID.
+    pub meeting_id: MeetingId,
+    /// Schema version of its generation key.
+    pub version: u32,
+}
+
+/// Result of migrating one meeting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationOutcome {
+    /// Already on the current schema.
+    Current,
+    /// Keys moved.
+    Moved(u64),
+    /// Live mode only: the generation kept changing; run again later.
+    Contended,
+}
+
+/// A problem [`StateMigrator::verify`] found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrityIssue {
+    /// Key with the problem.
+    pub key: String,
+    /// What is wrong with it.
+    pub problem: String,
+}
+
+impl fmt::Display for IntegrityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.problem)
+    }
+}
+
+/// Scans, migrates and verifies meeting state in Redis.
+pub struct StateMigrator {
+    connection: MultiplexedConnection,
+    migrate_script: Script,
+    fenced_migrate_script: Script,
+}
+
+impl StateMigrator {
+    /// Connect to Redis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Redis` if the connection fails.
+    pub async fn connect(redis_url: &str) -> Result<Self, McError> {
+        // Do not include the URL in errors: it may carry credentials
+        let client = Client::open(redis_url)
+            .map_err(|e| McError::Redis(format!("Failed to open Redis client: {e}")))?;
+        let connection = client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| McError::Redis(format!("Failed to connect to Redis: {e}")))?;
+        Ok(Self {
+            connection,
+            migrate_script: Script::new(lua_scripts::MIGRATE_KEYS),
+            fenced_migrate_script: Script::new(lua_scripts::FENCED_MIGRATE_KEYS),
+        })
+    }
+
+    /// Every meeting with a generation key, in any schema version. A
+    /// meeting with keys in two versions is listed once per version.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Redis` for connection errors.
+    pub async fn scan(&self) -> Result<Vec<ScannedMeeting>, McError> {
+        let mut conn = self.connection.clone();
+        let mut meetings = Vec::new();
+        let mut cursor: u64 = 0;
+        loop {
+            let (next, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(keys::GENERATION_KEY_PATTERN)
+                .arg("COUNT")
+                .arg(SCAN_COUNT)
+                .query_async(&mut conn)
+                .await
+                .map_err(|e| McError::Redis(format!("SCAN failed: {e}")))?;
+            meetings.extend(batch.iter().filter_map(|key| {
+                keys::parse_generation_key(key).map(|(version, meeting_id)| ScannedMeeting {
+                    meeting_id,
+                    version,
+                })
+            }));
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+        meetings.sort_by(|a, b| (&a.meeting_id, a.version).cmp(&(&b.meeting_id, b.version)));
+        meetings.dedup();
+        Ok(meetings)
+    }
+
+    /// Move one meeting's keys to [`keys::SCHEMA_VERSION`].
+    ///
+    /// A key whose new name already exists is left in place and reported by
+    /// [`StateMigrator::verify`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Redis` for connection errors.
+    pub async fn migrate(
+        &self,
+        meeting: &ScannedMeeting,
+        mode: MigrationMode,
+    ) -> Result<MigrationOutcome, McError> {
+        let renames = keys::migration(&meeting.meeting_id, meeting.version);
+        if renames.is_empty() {
+            return Ok(MigrationOutcome::Current);
+        }
+
+        let mut conn = self.connection.clone();
+        match mode {
+            MigrationMode::Offline => {
+                let mut invocation = self.migrate_script.prepare_invoke();
+                for rename in &renames {
+                    invocation.key(&rename.from).key(&rename.to);
+                }
+                let moved: u64 = invocation
+                    .invoke_async(&mut conn)
+                    .await
+                    .map_err(|e| McError::Redis(format!("Failed to migrate keys: {e}")))?;
+                Ok(MigrationOutcome::Moved(moved))
+            }
+            MigrationMode::Live => {
+                let generation_key =
+                    MeetingKey::Generation.at_version(meeting.version, &meeting.meeting_id);
+                for _ in 0..LIVE_ATTEMPTS {
+                    let generation: Option<String> = conn
+                        .get(&generation_key)
+                        .await
+                        .map_err(|e| McError::Redis(format!("Failed to read generation: {e}")))?;
+                    let Some(generation) = generation else {
+                        // An MC moved the meeting itself since the scan
+                        return Ok(MigrationOutcome::Moved(0));
+                    };
+
+                    let mut invocation = self.fenced_migrate_script.prepare_invoke();
+                    for rename in &renames {
+                        invocation.key(&rename.from).key(&rename.to);
+                    }
+                    invocation.arg(&generation);
+                    let moved: i64 = invocation
+                        .invoke_async(&mut conn)
+                        .await
+                        .map_err(|e| McError::Redis(format!("Failed to migrate keys: {e}")))?;
+                    if let Ok(moved) = u64::try_from(moved) {
+                        return Ok(MigrationOutcome::Moved(moved));
+                    }
+                }
+                Ok(MigrationOutcome::Contended)
+            }
+        }
+    }
+
+    /// Check a meeting's keys in the current schema: the generation is a
+    /// positive integer, JSON values parse, keys have the expected Redis
+    /// type, and no keys are left behind in older schemas.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Redis` for connection errors.
+    pub async fn verify(&self, meeting_id: &MeetingId) -> Result<Vec<IntegrityIssue>, McError> {
+        let mut conn = self.connection.clone();
+        let mut issues = Vec::new();
+        let redis_err = |e: redis::RedisError| McError::Redis(format!("Verify failed: {e}"));
+
+        let generation_key = keys::generation(meeting_id);
+        let generation: Option<String> = conn.get(&generation_key).await.map_err(redis_err)?;
+        match generation.as_deref().map(str::parse::<u64>) {
+            Some(Ok(generation)) if generation > 0 => {}
+            Some(_) => issues.push(issue(&generation_key, "not a positive integer")),
+            None => issues.push(issue(&generation_key, "missing")),
+        }
+
+        let mh_key = keys::mh_assignment(meeting_id);
+        let mh: Option<String> = conn.get(&mh_key).await.map_err(redis_err)?;
+        if let Some(Err(e)) = mh.as_deref().map(serde_json::from_str::<MhAssignmentData>) {
+            issues.push(issue(&mh_key, &format!("not an MH assignment: {e}")));
+        }
+
+        let interactions_key = keys::interactions(meeting_id);
+        let interactions: Option<String> = conn.get(&interactions_key).await.map_err(redis_err)?;
+        if let Some(Err(e)) = interactions
+            .as_deref()
+            .map(serde_json::from_str::<serde_json::Value>)
+        {
+            issues.push(issue(&interactions_key, &format!("not JSON: {e}")));
+        }
+
+        for (key, expected) in [
+            (keys::state(meeting_id), "hash"),
+            (keys::participants(meeting_id), "zset"),
+            (keys::journal(meeting_id), "stream"),
+        ] {
+            let actual: String = redis::cmd("TYPE")
+                .arg(&key)
+                .query_async(&mut conn)
+                .await
+                .map_err(redis_err)?;
+            if actual != "none" && actual != expected {
+                issues.push(issue(&key, &format!("expected {expected}, found {actual}")));
+            }
+        }
+
+        for version in 0..keys::SCHEMA_VERSION {
+            for rename in keys::migration(meeting_id, version) {
+                let left_behind: bool = conn.exists(&rename.from).await.map_err(redis_err)?;
+                if left_behind {
+                    issues.push(issue(
+                        &rename.from,
+                        &format!("left behind in schema v{version}"),
+                    ));
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+}
+
+fn issue(key: &str, problem: &str) -> IntegrityIssue {
+    IntegrityIssue {
+        key: key.to_string(),
+        problem: problem.to_string(),
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides:
 //! - `FencedRedisClient` - Redis client with fencing token validation
 //! - Lua scripts for atomic fenced operations
+//! - `StateMigrator` - Key schema migration (`mc-state-migrate`)
 //!
 //! # Fencing Token (ADR-0023 Section 3)
 //!
@@ -13,16 +14,24 @@
 //!
 //! # State Storage (ADR-0023 Section 6)
 //!
-//! Meeting state in Redis:
-//! - `meeting:{id}:generation` - Current fencing generation
-//! - `meeting:{id}:mh` - MH assignment data (JSON)
-//! - `meeting:{id}:participants` - Participant list (ZSET by join time)
-//! - `meeting:{id}:state` - Meeting metadata (HASH)
+//! Meeting state in Redis (keys built by [`keys`], schema version 1):
+//! - `v1:meeting:{id}:generation` - Current fencing generation
+//! - `v1:meeting:{id}:mh` - MH assignment data (JSON)
+//! - `v1:meeting:{id}:participants` - Participant list (ZSET by join time)
+//! - `v1:meeting:{id}:state` - Meeting metadata (HASH)
+//! - `v1:meeting:{id}:interactions` - Polls and Q&A state (JSON)
+//! - `v1:meeting:{id}:journal` - Membership event journal (STREAM)
 
 pub mod client;
+pub mod journal;
+pub mod keys;
 pub mod lua_scripts;
+pub mod migrate;
 
 pub use client::FencedRedisClient;
+pub use client::InteractionStore;
+pub use client::JournalStore;
 pub use client::MhAssignmentData;
 pub use client::MhAssignmentStore;
 pub use client::MhEndpointInfo;
+pub use journal::JournalRecord;
@@ -0,0 +1,309 @@
+//! Warm standby journal snapshots.
+//!
+//! An MC started with `MC_STANDBY_FOR` takes no meetings of its own. GC
+//! lists its primary's active meetings in every heartbeat ack, and this
+//! module keeps a copy of each of those meetings' event journals, reading
+//! only the entries appended since the last refresh. When GC promotes the
+//! standby (`PromoteStandby`), each taken-over meeting starts from its
+//! snapshot and loads just the tail it has not seen, instead of replaying
+//! the whole journal on the failover path.
+
+use crate::ids::MeetingId;
+use crate::redis::{JournalRecord, JournalStore};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{watch, Mutex};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+/// How often the snapshots catch up with the primary's journal appends.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Bound on one journal read, so a slow Redis cannot stall the refresh loop.
+const LOAD_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Journal snapshots of the meetings a warm standby may take over.
+pub struct StandbySnapshots {
+    /// MC this one stands by for.
+    primary_id: String,
+    /// Journal store (`None` if journaling is off; nothing is snapshotted).
+    store: Option<Arc<dyn JournalStore>>,
+    /// Entries kept per meeting, matching the journal's own trim length.
+    max_len: usize,
+    /// The primary's active meetings, as last reported by GC.
+    meetings: watch::Sender<Vec<String>>,
+    /// Snapshot per meeting, oldest entry first.
+    journals: Mutex<HashMap<String, Vec<JournalRecord>>>,
+    /// Set once GC promotes this MC; it then registers as an ordinary MC.
+    promoted: AtomicBool,
+}
+
+impl StandbySnapshots {
+    /// Create snapshots for the standby of `primary_id`.
+    #[must_use]
+    pub fn new(primary_id: String, store: Option<Arc<dyn JournalStore>>, max_len: usize) -> Self {
+        Self {
+            primary_id,
+            store,
+            max_len,
+            meetings: watch::Sender::new(Vec::new()),
+            journals: Mutex::new(HashMap::new()),
+            promoted: AtomicBool::new(false),
+        }
+    }
+
+    /// The primary this MC stands by for.
+    #[must_use]
+    pub fn primary_id(&self) -> &str {
+        &self.primary_id
+    }
+
+    /// Record that GC promoted this MC.
+    pub fn mark_promoted(&self) {
+        self.promoted.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether GC has promoted this MC.
+    #[must_use]
+    pub fn is_promoted(&self) -> bool {
+        self.promoted.load(Ordering::SeqCst)
+    }
+
+    /// Record the primary's active meetings from a heartbeat ack. A change
+    /// wakes [`run`](Self::run) straight away.
+    pub fn set_meetings(&self, meeting_ids: Vec<String>) {
+        self.meetings.send_if_modified(|current| {
+            if *current == meeting_ids {
+                return false;
+            }
+            *current = meeting_ids;
+            true
+        });
+    }
+
+    /// Bring the snapshots up to date with `meeting_ids`: meetings no longer
+    /// listed are dropped, new ones are read in full, and the rest read only
+    /// their new entries. A failed read keeps the old snapshot.
+    pub async fn refresh(&self, meeting_ids: &[String]) {
+        let Some(store) = &self.store else {
+            return;
+        };
+
+        let mut journals = self.journals.lock().await;
+        journals.retain(|meeting_id, _| meeting_ids.contains(meeting_id));
+
+        for meeting_id in meeting_ids {
+            let id = MeetingId::from(meeting_id);
+            let records = journals.entry(meeting_id.clone()).or_default();
+            let load = match records.last() {
+                Some(last) => store.load_journal_after(&id, &last.id),
+                None => store.load_journal(&id),
+            };
+            match tokio::time::timeout(LOAD_TIMEOUT, load).await {
+                Ok(Ok(tail)) => {
+                    records.extend(tail);
+                    let excess = records.len().saturating_sub(self.max_len);
+                    records.drain(..excess);
+                }
+                Ok(Err(e)) => {
+                    warn!(
+                        target: "mc.standby",
+                        meeting_id = %meeting_id,
+                        error = %e,
+                        "Failed to refresh standby journal snapshot"
+                    );
+                }
+                Err(_) => {
+                    warn!(
+                        target: "mc.standby",
+                        meeting_id = %meeting_id,
+                        "Standby journal snapshot refresh timed out"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Take a meeting's snapshot on promotion (empty if there is none).
+    pub async fn take(&self, meeting_id: &str) -> Vec<JournalRecord> {
+        self.journals
+            .lock()
+            .await
+            .remove(meeting_id)
+            .unwrap_or_default()
+    }
+
+    /// Refresh the snapshots whenever GC reports a different meeting list,
+    /// and every [`REFRESH_INTERVAL`] otherwise, until `cancel` fires.
+    pub async fn run(self: Arc<Self>, cancel: CancellationToken) {
+        let mut meetings = self.meetings.subscribe();
+        let mut ticker = tokio::time::interval(REFRESH_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                () = cancel.cancelled() => break,
+                _ = meetings.changed() => {}
+                _ = ticker.tick() => {}
+            }
+            let meeting_ids = meetings.borrow_and_update().clone();
+            self.refresh(&meeting_ids).await;
+        }
+
+        debug!(
+            target: "mc.standby",
+            primary_id = %self.primary_id,
+            "Standby snapshot loop stopped"
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::errors::McError;
+    use std::sync::Mutex as StdMutex;
+
+    /// In-memory journal store, keyed by meeting.
+    #[derive(Default)]
+    struct MemoryJournalStore {
+        journals: StdMutex<HashMap<String, Vec<JournalRecord>>>,
+        loads: StdMutex<Vec<String>>,
+    }
+
+    impl MemoryJournalStore {
+        fn push(&self, meeting_id: &str, event: &str) {
+            let mut journals = self.journals.lock().unwrap();
+            let records = journals.entry(meeting_id.to_string()).or_default();
+            let id = format!("0-{}", records.len());
+            records.push(JournalRecord {
+                id,
+                generation: 1,
+                event: event.to_string(),
+            });
+        }
+    }
+
+    impl JournalStore for MemoryJournalStore {
+        fn append_journal<'a>(
+            &'a self,
+            meeting_id: &'a MeetingId,
+            event: &'a str,
+        ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), McError>> + Send + 'a>>
+        {
+            self.push(meeting_id.as_str(), event);
+            Box::pin(async { Ok(()) })
+        }
+
+        fn load_journal<'a>(
+            &'a self,
+            meeting_id: &'a MeetingId,
+        ) -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = Result<Vec<JournalRecord>, McError>> + Send + 'a>,
+        > {
+            self.loads.lock().unwrap().push(meeting_id.to_string());
+            let records = self
+                .journals
+                .lock()
+                .unwrap()
+                .get(meeting_id.as_str())
+                .cloned()
+                .unwrap_or_default();
+            Box::pin(async move { Ok(records) })
+        }
+    }
+
+    fn events(records: &[JournalRecord]) -> Vec<&str> {
+        records.iter().map(|record| record.event.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_refresh_appends_new_entries() {
+        let store = Arc::new(MemoryJournalStore::default());
+        store.push("meeting-1", "a");
+        store.push("meeting-1", "b");
+        let snapshots = StandbySnapshots::new(
+            "mc-0".to_string(),
+            Some(Arc::clone(&store) as Arc<dyn JournalStore>),
+            100,
+        );
+        let meetings = vec!["meeting-1".to_string()];
+
+        snapshots.refresh(&meetings).await;
+        store.push("meeting-1", "c");
+        snapshots.refresh(&meetings).await;
+
+        assert_eq!(events(&snapshots.take("meeting-1").await), ["a", "b", "c"]);
+        // Taken snapshots are gone
+        assert!(snapshots.take("meeting-1").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_drops_ended_meetings_and_trims() {
+        let store = Arc::new(MemoryJournalStore::default());
+        for event in ["a", "b", "c"] {
+            store.push("meeting-1", event);
+        }
+        store.push("meeting-2", "x");
+        let snapshots = StandbySnapshots::new(
+            "mc-0".to_string(),
+            Some(Arc::clone(&store) as Arc<dyn JournalStore>),
+            2,
+        );
+
+        snapshots
+            .refresh(&["meeting-1".to_string(), "meeting-2".to_string()])
+            .await;
+        snapshots.refresh(&["meeting-1".to_string()]).await;
+
+        assert_eq!(events(&snapshots.take("meeting-1").await), ["b", "c"]);
+        assert!(snapshots.take("meeting-2").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_without_store_nothing_is_snapshotted() {
+        let snapshots = StandbySnapshots::new("mc-0".to_string(), None, 100);
+        snapshots.refresh(&["meeting-1".to_string()]).await;
+
+        assert_eq!(snapshots.primary_id(), "mc-0");
+        assert!(snapshots.take("meeting-1").await.is_empty());
+
+        assert!(!snapshots.is_promoted());
+        snapshots.mark_promoted();
+        assert!(snapshots.is_promoted());
+    }
+
+    #[tokio::test]
+    async fn test_set_meetings_wakes_run() {
+        let store = Arc::new(MemoryJournalStore::default());
+        store.push("meeting-1", "a");
+        let snapshots = Arc::new(StandbySnapshots::new(
+            "mc-0".to_string(),
+            Some(Arc::clone(&store) as Arc<dyn JournalStore>),
+            100,
+        ));
+        let cancel = CancellationToken::new();
+        let task = tokio::spawn(Arc::clone(&snapshots).run(cancel.clone()));
+
+        snapshots.set_meetings(vec!["meeting-1".to_string()]);
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !store
+                .loads
+                .lock()
+                .unwrap()
+                .contains(&"meeting-1".to_string())
+            {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("meeting journal should be loaded");
+
+        cancel.cancel();
+        task.await.unwrap();
+        assert_eq!(events(&snapshots.take("meeting-1").await), ["a"]);
+    }
+}
@@ -0,0 +1,518 @@
+//! Opt-in wire capture of client signaling sessions.
+//!
+//! With `MC_SESSION_CAPTURE_DIR` set, each WebTransport connection writes
+//! every signaling frame it reads or writes to
+//! `{dir}/{connection_id}.dtcap`. `mc_test_utils::replay` feeds such a
+//! capture back into a test MC, turning a production bug into a regression
+//! test.
+//!
+//! Secrets are stripped before a frame leaves the connection task: join and
+//! binding tokens are blanked and RTMP ingest URLs (which embed the stream
+//! key) cleared. A frame that does not decode cannot be checked, so it is
+//! recorded with an empty payload.
+//!
+//! Capture is best-effort. Frames go to a background writer over a bounded
+//! channel and are dropped if it falls behind; a write error ends the
+//! capture, never the connection.
+//!
+//! # File format
+//!
+//! ```text
+//! magic    8 bytes  "DTSCAP01"
+//! frame*   direction  u8      0 = client → MC, 1 = MC → client
+//!          elapsed    u64 BE  microseconds since the session was accepted
+//!          length     u32 BE
+//!          payload    `length` bytes: ClientMessage or ServerMessage
+//! ```
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use prost::Message;
+use proto_gen::dark_tower::signaling::v1::{
+    client_message, server_message, start_live_stream, ClientMessage, ServerMessage,
+};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tracing::{debug, warn};
+
+/// First bytes of every capture file.
+pub const CAPTURE_MAGIC: &[u8; 8] = b"DTSCAP01";
+
+/// File extension of capture files.
+pub const CAPTURE_EXTENSION: &str = "dtcap";
+
+/// Frames buffered for the writer before new ones are dropped.
+const CAPTURE_CHANNEL_BUFFER: usize = 1024;
+
+/// Which side sent a captured frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// A `ClientMessage` read from the client.
+    ClientToServer,
+    /// A `ServerMessage` written to the client.
+    ServerToClient,
+}
+
+impl Direction {
+    fn to_byte(self) -> u8 {
+        match self {
+            Direction::ClientToServer => 0,
+            Direction::ServerToClient => 1,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Direction::ClientToServer),
+            1 => Some(Direction::ServerToClient),
+            _ => None,
+        }
+    }
+}
+
+/// One signaling frame in a capture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedFrame {
+    /// Which side sent the frame.
+    pub direction: Direction,
+    /// Time since the session was accepted.
+    pub elapsed: Duration,
+    /// Encoded protobuf message, secrets stripped.
+    pub payload: Bytes,
+}
+
+impl CapturedFrame {
+    /// Append the frame in capture file format.
+    ///
+    /// Payloads are signaling frames (at most 64KB), so the length always
+    /// fits the `u32` field.
+    pub fn encode(&self, buf: &mut BytesMut) {
+        let elapsed = u64::try_from(self.elapsed.as_micros()).unwrap_or(u64::MAX);
+        let len = u32::try_from(self.payload.len()).unwrap_or(u32::MAX);
+        buf.reserve(1 + 8 + 4 + self.payload.len());
+        buf.put_u8(self.direction.to_byte());
+        buf.put_u64(elapsed);
+        buf.put_u32(len);
+        buf.put_slice(&self.payload);
+    }
+}
+
+/// Errors reading a capture file.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CaptureError {
+    /// The data does not start with [`CAPTURE_MAGIC`].
+    #[error("not a session capture")]
+    BadMagic,
+
+    /// A frame has a direction byte other than 0 or 1.
+    #[error("unknown frame direction {direction} at offset {offset}")]
+    UnknownDirection { direction: u8, offset: usize },
+
+    /// The data ends inside a frame.
+    #[error("truncated frame at offset {offset}")]
+    Truncated { offset: usize },
+}
+
+/// Parse a capture file into its frames, in recorded order.
+///
+/// # Errors
+///
+/// Returns [`CaptureError`] if the magic is missing or a frame is malformed
+/// or cut short.
+pub fn parse_capture(data: &[u8]) -> Result<Vec<CapturedFrame>, CaptureError> {
+    let mut buf = data
+        .strip_prefix(CAPTURE_MAGIC.as_slice())
+        .ok_or(CaptureError::BadMagic)?;
+
+    let mut frames = Vec::new();
+    while buf.has_remaining() {
+        let offset = data.len() - buf.remaining();
+        if buf.remaining() < 1 + 8 + 4 {
+            return Err(CaptureError::Truncated { offset });
+        }
+        let direction_byte = buf.get_u8();
+        let direction =
+            Direction::from_byte(direction_byte).ok_or(CaptureError::UnknownDirection {
+                direction: direction_byte,
+                offset,
+            })?;
+        let elapsed = Duration::from_micros(buf.get_u64());
+        let len = buf.get_u32() as usize;
+        if buf.remaining() < len {
+            return Err(CaptureError::Truncated { offset });
+        }
+        let payload = Bytes::copy_from_slice(buf.get(..len).unwrap_or_default());
+        buf.advance(len);
+
+        frames.push(CapturedFrame {
+            direction,
+            elapsed,
+            payload,
+        });
+    }
+    Ok(frames)
+}
+
+/// Blank the secrets a client can send: the meeting JWT and binding token
+/// in `JoinRequest`, and the RTMP ingest URL in `StartLiveStream`.
+pub fn strip_client_secrets(message: &mut ClientMessage) {
+    match &mut message.message {
+        Some(client_message::Message::JoinRequest(join)) => {
+            join.join_token.clear();
+            join.binding_token.clear();
+        }
+        Some(client_message::Message::StartLiveStream(start)) => {
+            if let Some(start_live_stream::Output::RtmpUrl(url)) = &mut start.output {
+                url.clear();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Blank the secrets MC sends: the binding and media tokens in `JoinResponse`.
+pub fn strip_server_secrets(message: &mut ServerMessage) {
+    if let Some(server_message::Message::JoinResponse(join)) = &mut message.message {
+        join.binding_token.clear();
+        join.media_token.clear();
+    }
+}
+
+/// Re-encode `payload` with its secrets stripped, or empty if it does not
+/// decode.
+fn sanitize(direction: Direction, payload: &[u8]) -> Bytes {
+    let stripped = match direction {
+        Direction::ClientToServer => ClientMessage::decode(payload).map(|mut message| {
+            strip_client_secrets(&mut message);
+            message.encode_to_vec()
+        }),
+        Direction::ServerToClient => ServerMessage::decode(payload).map(|mut message| {
+            strip_server_secrets(&mut message);
+            message.encode_to_vec()
+        }),
+    };
+    stripped.map(Bytes::from).unwrap_or_default()
+}
+
+/// Per-connection capture handle. Cheap to clone; a disabled recorder
+/// ignores every frame.
+#[derive(Debug, Clone, Default)]
+pub struct SessionRecorder {
+    inner: Option<RecorderInner>,
+}
+
+#[derive(Debug, Clone)]
+struct RecorderInner {
+    tx: mpsc::Sender<CapturedFrame>,
+    started: Instant,
+}
+
+impl SessionRecorder {
+    /// A recorder that captures nothing.
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Start capturing to `{dir}/{connection_id}.dtcap`.
+    ///
+    /// Spawns the writer task, which finishes the file once every clone of
+    /// the recorder is dropped. Must be called from a Tokio runtime.
+    #[must_use]
+    pub fn start(dir: &Path, connection_id: &str) -> Self {
+        let path = dir.join(format!("{connection_id}.{CAPTURE_EXTENSION}"));
+        let (tx, rx) = mpsc::channel(CAPTURE_CHANNEL_BUFFER);
+        tokio::spawn(write_capture(path, rx));
+        Self {
+            inner: Some(RecorderInner {
+                tx,
+                started: Instant::now(),
+            }),
+        }
+    }
+
+    /// Whether frames are being captured.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    /// Capture one frame payload (without its length prefix).
+    pub fn record(&self, direction: Direction, payload: &[u8]) {
+        let Some(inner) = &self.inner else {
+            return;
+        };
+        let frame = CapturedFrame {
+            direction,
+            elapsed: inner.started.elapsed(),
+            payload: sanitize(direction, payload),
+        };
+        if inner.tx.try_send(frame).is_err() {
+            debug!(
+                target: "mc.webtransport.capture",
+                "Capture writer behind or stopped, frame dropped"
+            );
+        }
+    }
+}
+
+/// Write frames from `rx` to a new capture file at `path` until every
+/// sender is dropped.
+async fn write_capture(path: PathBuf, mut rx: mpsc::Receiver<CapturedFrame>) {
+    // Captures hold meeting content, so only the MC user may read them
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    let file = match options.open(&path).await {
+        Ok(file) => file,
+        Err(e) => {
+            warn!(
+                target: "mc.webtransport.capture",
+                path = %path.display(),
+                error = %e,
+                "Failed to create session capture file"
+            );
+            return;
+        }
+    };
+    let mut writer = BufWriter::new(file);
+
+    let mut buf = BytesMut::from(CAPTURE_MAGIC.as_slice());
+    loop {
+        if let Err(e) = writer.write_all(&buf).await {
+            warn!(
+                target: "mc.webtransport.capture",
+                path = %path.display(),
+                error = %e,
+                "Session capture write failed, capture stopped"
+            );
+            return;
+        }
+        buf.clear();
+        match rx.recv().await {
+            Some(frame) => frame.encode(&mut buf),
+            None => break,
+        }
+    }
+
+    if let Err(e) = writer.flush().await {
+        warn!(
+            target: "mc.webtransport.capture",
+            path = %path.display(),
+            error = %e,
+            "Failed to flush session capture"
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use proto_gen::dark_tower::signaling::v1::{JoinRequest, JoinResponse, Ping, StartLiveStream};
+
+    fn client(message: client_message::Message) -> ClientMessage {
+        ClientMessage {
+            message: Some(message),
+            trace_parent: String::new(),
+            trace_state: String::new(),
+        }
+    }
+
+    fn join_request() -> ClientMessage {
+        client(client_message::Message::JoinRequest(JoinRequest {
+            meeting_id: "meeting-1".to_string(),
+            join_token: "eyJhbGciOiJFZERTQSJ9.claims.signature".to_string(),
+            participant_name: "Alice".to_string(),
+            capabilities: None,
+            correlation_id: "corr-1".to_string(),
+            binding_token: "binding-secret".to_string(),
+            join_id: String::new(),
+        }))
+    }
+
+    fn frame(direction: Direction, micros: u64, payload: &[u8]) -> CapturedFrame {
+        CapturedFrame {
+            direction,
+            elapsed: Duration::from_micros(micros),
+            payload: Bytes::copy_from_slice(payload),
+        }
+    }
+
+    fn encode_all(frames: &[CapturedFrame]) -> Vec<u8> {
+        let mut buf = BytesMut::from(CAPTURE_MAGIC.as_slice());
+        for frame in frames {
+            frame.encode(&mut buf);
+        }
+        buf.to_vec()
+    }
+
+    #[test]
+    fn test_capture_roundtrip() {
+        let frames = vec![
+            frame(Direction::ClientToServer, 0, b"\x0a\x00"),
+            frame(Direction::ServerToClient, 1_500, b"\x12\x02\x08\x01"),
+            frame(Direction::ClientToServer, 30_000_000, b""),
+        ];
+        assert_eq!(parse_capture(&encode_all(&frames)).unwrap(), frames);
+    }
+
+    #[test]
+    fn test_parse_capture_empty_session() {
+        assert_eq!(parse_capture(CAPTURE_MAGIC).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_parse_capture_rejects_bad_magic() {
+        assert_eq!(parse_capture(b"DTSCAP99"), Err(CaptureError::BadMagic));
+        assert_eq!(parse_capture(b""), Err(CaptureError::BadMagic));
+    }
+
+    #[test]
+    fn test_parse_capture_rejects_truncated_frame() {
+        let mut data = encode_all(&[
+            frame(Direction::ClientToServer, 0, b"ok"),
+            frame(Direction::ServerToClient, 5, b"cut short"),
+        ]);
+        data.truncate(data.len() - 3);
+        assert_eq!(
+            parse_capture(&data),
+            Err(CaptureError::Truncated { offset: 23 })
+        );
+    }
+
+    #[test]
+    fn test_parse_capture_rejects_unknown_direction() {
+        let mut data = encode_all(&[frame(Direction::ClientToServer, 0, b"x")]);
+        data[8] = 7;
+        assert_eq!(
+            parse_capture(&data),
+            Err(CaptureError::UnknownDirection {
+                direction: 7,
+                offset: 8
+            })
+        );
+    }
+
+    #[test]
+    fn test_join_request_secrets_stripped() {
+        let payload = sanitize(Direction::ClientToServer, &join_request().encode_to_vec());
+        let message = ClientMessage::decode(payload).unwrap();
+        let Some(client_message::Message::JoinRequest(join)) = message.message else {
+            panic!("expected JoinRequest");
+        };
+        assert!(join.join_token.is_empty());
+        assert!(join.binding_token.is_empty());
+        // Everything needed to reproduce the session is kept
+        assert_eq!(join.meeting_id, "meeting-1");
+        assert_eq!(join.participant_name, "Alice");
+        assert_eq!(join.correlation_id, "corr-1");
+    }
+
+    #[test]
+    fn test_rtmp_url_stripped() {
+        let start = client(client_message::Message::StartLiveStream(StartLiveStream {
+            tile_participant_ids: vec!["p-1".to_string()],
+            output: Some(start_live_stream::Output::RtmpUrl(
+                "rtmps://live.example/app/stream-key".to_string(),
+            )),
+        }));
+        let payload = sanitize(Direction::ClientToServer, &start.encode_to_vec());
+        let message = ClientMessage::decode(payload).unwrap();
+        let Some(client_message::Message::StartLiveStream(start)) = message.message else {
+            panic!("expected StartLiveStream");
+        };
+        assert_eq!(
+            start.output,
+            Some(start_live_stream::Output::RtmpUrl(String::new()))
+        );
+        assert_eq!(start.tile_participant_ids, vec!["p-1".to_string()]);
+    }
+
+    #[test]
+    fn test_join_response_tokens_stripped() {
+        let response = ServerMessage {
+            message: Some(server_message::Message::JoinResponse(JoinResponse {
+                participant_id: "p-1".to_string(),
+                correlation_id: "corr-1".to_string(),
+                binding_token: "binding-secret".to_string(),
+                media_token: "media-secret".to_string(),
+                ..Default::default()
+            })),
+            trace_parent: String::new(),
+            trace_state: String::new(),
+        };
+        let payload = sanitize(Direction::ServerToClient, &response.encode_to_vec());
+        let message = ServerMessage::decode(payload).unwrap();
+        let Some(server_message::Message::JoinResponse(join)) = message.message else {
+            panic!("expected JoinResponse");
+        };
+        assert!(join.binding_token.is_empty());
+        assert!(join.media_token.is_empty());
+        assert_eq!(join.participant_id, "p-1");
+    }
+
+    #[test]
+    fn test_messages_without_secrets_unchanged() {
+        let ping = client(client_message::Message::Ping(Ping { sequence: 9 })).encode_to_vec();
+        assert_eq!(
+            sanitize(Direction::ClientToServer, &ping).as_ref(),
+            ping.as_slice()
+        );
+    }
+
+    #[test]
+    fn test_undecodable_frame_recorded_empty() {
+        assert!(sanitize(Direction::ClientToServer, b"\xff\xff\xff").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_recorder_writes_stripped_frames() {
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = SessionRecorder::start(dir.path(), "conn-1");
+        assert!(recorder.is_enabled());
+
+        recorder.record(Direction::ClientToServer, &join_request().encode_to_vec());
+        let ping = client(client_message::Message::Ping(Ping { sequence: 1 })).encode_to_vec();
+        recorder.record(Direction::ClientToServer, &ping);
+        drop(recorder);
+
+        // The writer finishes the file once the last recorder is dropped
+        let path = dir.path().join("conn-1.dtcap");
+        let frames = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Ok(frames) = parse_capture(&tokio::fs::read(&path).await.unwrap_or_default())
+                {
+                    if frames.len() == 2 {
+                        return frames;
+                    }
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("capture file should be written");
+
+        assert!(frames
+            .iter()
+            .all(|frame| frame.direction == Direction::ClientToServer));
+        assert!(frames[0].elapsed <= frames[1].elapsed);
+        assert_eq!(frames[1].payload.as_ref(), ping.as_slice());
+
+        let raw = tokio::fs::read(&path).await.unwrap();
+        let raw = String::from_utf8_lossy(&raw);
+        assert!(!raw.contains("binding-secret"));
+        assert!(!raw.contains("signature"));
+    }
+
+    #[test]
+    fn test_disabled_recorder_ignores_frames() {
+        let recorder = SessionRecorder::disabled();
+        assert!(!recorder.is_enabled());
+        recorder.record(Direction::ServerToClient, b"anything");
+    }
+}
@@ -1,33 +1,67 @@
 //! `ConnectionActor` - owns WebTransport streams and bridge loop.
 //!
+//! The same pipeline serves raw QUIC signaling connections
+//! ([`handle_raw_quic_connection`]); only accepting the stream differs.
+//!
 //! This actor lives in the webtransport layer (not the actor hierarchy) and:
 //! 1. Receives a `JoinResult` from the meeting actor via oneshot
 //! 2. Sends `JoinResponse` to the client over the WebTransport stream
 //! 3. Runs the bridge loop forwarding `ParticipantUpdate` messages to the client
-//! 4. Notifies the meeting when the connection drops (via `MeetingActorHandle`)
+//!    and poll/Q&A and data channel requests from the client to the meeting
+//! 4. Pings keepalive-capable clients and closes the connection when they go
+//!    silent past the idle timeout
+//! 5. Notifies the meeting when the connection drops (via `MeetingActorHandle`)
+//! 6. Mirrors every frame to a session capture when one is configured
+//!    (see [`super::capture`])
+//!
+//! Clients may migrate to a new network path mid-connection; the actor keeps
+//! running and [`super::migration`] records the change.
 
-use crate::actors::messages::JoinResult;
-use crate::actors::MeetingControllerActorHandle;
+use crate::actors::keyframes::KeyframeRequester;
+use crate::actors::messages::{JoinResult, SessionResume};
+use crate::actors::recording::RecordingLayout;
+use crate::actors::{MeetingActorHandle, MeetingControllerActorHandle};
 use crate::auth::McJwtValidator;
 use crate::errors::McError;
 use crate::grpc::MhRegistrationClient;
+use crate::ids::MeetingId;
 use crate::observability::metrics;
 use crate::redis::{MhAssignmentData, MhAssignmentStore};
+use crate::webtransport::capture::{Direction, SessionRecorder};
+use crate::webtransport::early_data::EarlyData;
+use crate::webtransport::handler::{
+    decode_client_request, encode_connection_closed, encode_error_message, encode_ping,
+    encode_pong, encode_server_hello, ClientRequest,
+};
+use crate::webtransport::keepalive::{Keepalive, KeepaliveConfig, KeepaliveEvent};
+use crate::webtransport::migration;
+use crate::webtransport::protocol::{
+    self, version_label, NegotiatedProtocol, LEGACY_PROTOCOL_VERSION,
+};
+use crate::webtransport::raw_quic;
+use crate::webtransport::validation::{message_type_label, validate_client_message};
 
 use bytes::{BufMut, BytesMut};
+use common::error::ErrorCode;
 use common::jwt::MeetingRole;
+use common::media_token::{MediaTokenClaims, MediaTokenKey, MEDIA_TOKEN_TTL};
+use common::types::ParticipantId;
+use media_protocol::frame::user_id_for_subject;
 use prost::Message;
 use proto_gen::dark_tower::signaling::v1::{
-    self, client_message, server_message, ClientMessage, ErrorMessage, JoinResponse,
-    MediaServerInfo, Participant, ServerMessage,
+    self, client_message, server_message, Capability, ClientMessage, CloseReason, ErrorMessage,
+    JoinResponse, MediaServerInfo, Participant, ServerMessage,
 };
+use std::future::Future;
+use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, instrument, warn, Instrument};
 use wtransport::endpoint::IncomingSession;
-use wtransport::stream::{RecvStream, SendStream};
+use wtransport::stream::SendStream;
 
 /// Maximum size for a single framed message (64KB).
 const MAX_MESSAGE_SIZE: usize = 64 * 1024;
@@ -38,30 +72,73 @@ const MAX_PARTICIPANT_NAME_LEN: usize = 256;
 /// Channel buffer for outbound messages from ParticipantActor to WebTransport stream.
 const OUTBOUND_CHANNEL_BUFFER: usize = 100;
 
+/// Longest GC-assignment-to-roster time counted as join latency. Older join
+/// IDs come from a client reusing a stale GC join response.
+const MAX_JOIN_LATENCY: Duration = Duration::from_secs(300);
+
 /// Maximum number of attempts for RegisterMeeting RPC per MH.
 const MAX_REGISTER_ATTEMPTS: u32 = 3;
 
 /// Backoff delays between RegisterMeeting retry attempts.
 const REGISTER_BACKOFF_DELAYS: [Duration; 2] = [Duration::from_secs(1), Duration::from_secs(2)];
 
+/// Per-connection dependencies, shared by both signaling transports.
+#[derive(Clone)]
+pub struct SessionContext {
+    /// Handle to the meeting controller actor.
+    pub controller_handle: Arc<MeetingControllerActorHandle>,
+    /// JWT validator for meeting tokens.
+    pub jwt_validator: Arc<McJwtValidator>,
+    /// Redis client for reading MH assignment data during join.
+    pub redis_client: Arc<dyn MhAssignmentStore>,
+    /// MH registration client for async RegisterMeeting RPCs.
+    pub mh_client: Arc<dyn MhRegistrationClient>,
+    /// This MC's identifier.
+    pub mc_id: String,
+    /// This MC's gRPC advertise address (for MH->MC callbacks).
+    pub mc_grpc_endpoint: String,
+    /// Oldest signaling protocol version accepted at handshake.
+    pub min_protocol_version: u32,
+    /// Ping interval and idle timeout for keepalive-capable clients.
+    pub keepalive_config: KeepaliveConfig,
+    /// Directory for per-connection session captures (`None` = off).
+    pub session_capture_dir: Option<PathBuf>,
+    /// Signs the media token in each `JoinResponse` (`None` = off).
+    pub media_token_key: Option<Arc<MediaTokenKey>>,
+}
+
+/// Send half of a signaling stream.
+///
+/// Framing only needs `AsyncWrite`; closing also waits until the client has
+/// the final frame, which each QUIC stack exposes differently.
+pub trait SignalingSendStream: AsyncWrite + Unpin + Send {
+    /// Finish the stream, waiting until everything written is delivered.
+    fn finish_and_flush(&mut self) -> impl Future<Output = ()> + Send;
+}
+
+impl SignalingSendStream for SendStream {
+    async fn finish_and_flush(&mut self) {
+        let _ = self.finish().await;
+    }
+}
+
+impl SignalingSendStream for quinn::SendStream {
+    async fn finish_and_flush(&mut self) {
+        if self.finish().is_ok() {
+            let _ = self.stopped().await;
+        }
+    }
+}
+
 /// Handle an incoming WebTransport connection.
 ///
-/// This is the thin entry point: accept session, accept stream, read JoinRequest,
-/// validate JWT, fire JoinConnection to controller, then hand off to the
-/// connection run loop which owns the streams until disconnect.
+/// This is the thin entry point: accept session and stream, then hand off to
+/// [`run_session`], which reads the JoinRequest, validates the JWT, fires
+/// JoinConnection to the controller, and owns the streams until disconnect.
 #[instrument(skip_all, name = "mc.webtransport.connection", fields(connection_id = tracing::field::Empty))]
-#[expect(
-    clippy::too_many_arguments,
-    reason = "Connection handler wiring; all params are distinct dependencies"
-)]
 pub async fn handle_connection(
     incoming: IncomingSession,
-    controller_handle: Arc<MeetingControllerActorHandle>,
-    jwt_validator: Arc<McJwtValidator>,
-    redis_client: Arc<dyn MhAssignmentStore>,
-    mh_client: Arc<dyn MhRegistrationClient>,
-    mc_id: String,
-    mc_grpc_endpoint: String,
+    ctx: SessionContext,
     cancel_token: CancellationToken,
 ) -> Result<(), McError> {
     // Step 1: Accept the WebTransport session
@@ -85,6 +162,7 @@ pub async fn handle_connection(
 
     // Start join duration timer after session accept (excludes QUIC handshake)
     let join_start = Instant::now();
+    let accepted_at = SystemTime::now();
 
     let connection_id = uuid::Uuid::new_v4().to_string();
     tracing::Span::current().record("connection_id", connection_id.as_str());
@@ -96,7 +174,7 @@ pub async fn handle_connection(
     );
 
     // Step 2: Accept bidirectional stream
-    let (mut send_stream, mut recv_stream) = connection.accept_bi().await.map_err(|e| {
+    let (send_stream, recv_stream) = connection.accept_bi().await.map_err(|e| {
         warn!(
             target: "mc.webtransport.connection",
             connection_id = %connection_id,
@@ -112,27 +190,62 @@ pub async fn handle_connection(
         err
     })?;
 
-    // Step 3: Read length-prefixed ClientMessage (max 64KB)
-    let client_msg = match read_framed_message(&mut recv_stream).await {
-        Ok(msg) => msg,
-        Err(e) => {
-            metrics::record_session_join(
-                "failure",
-                Some(e.error_type_label()),
-                join_start.elapsed(),
-            );
-            return Err(e);
-        }
-    };
+    // The session survives migration; the tracker only records it
+    let migration = migration::track(
+        || connection.remote_address(),
+        "webtransport",
+        &connection_id,
+    );
+    let session = run_session(
+        send_stream,
+        recv_stream,
+        EarlyData::none(),
+        ctx,
+        cancel_token,
+        connection_id.clone(),
+        join_start,
+        accepted_at,
+    );
+    tokio::select! {
+        result = session => result,
+        () = migration => Ok(()),
+    }
+}
+
+/// Handle an incoming raw QUIC signaling connection (see [`super::raw_quic`]).
+///
+/// Same flow as [`handle_connection`] without the HTTP/3 layer: the client
+/// opens one bidirectional stream and speaks the same length-prefixed
+/// protobuf framing on it. The stream may open in 0-RTT data, before the
+/// handshake completes (see [`super::early_data`]).
+#[instrument(skip_all, name = "mc.raw_quic.connection", fields(connection_id = tracing::field::Empty))]
+pub async fn handle_raw_quic_connection(
+    incoming: quinn::Incoming,
+    ctx: SessionContext,
+    cancel_token: CancellationToken,
+) -> Result<(), McError> {
+    let (connection, early_data) = raw_quic::accept(incoming).await?;
+
+    let join_start = Instant::now();
+    let accepted_at = SystemTime::now();
 
-    let client_message = ClientMessage::decode(client_msg.as_ref()).map_err(|e| {
+    let connection_id = uuid::Uuid::new_v4().to_string();
+    tracing::Span::current().record("connection_id", connection_id.as_str());
+
+    debug!(
+        target: "mc.raw_quic.connection",
+        connection_id = %connection_id,
+        "Raw QUIC connection accepted"
+    );
+
+    let (send_stream, recv_stream) = connection.accept_bi().await.map_err(|e| {
         warn!(
-            target: "mc.webtransport.connection",
+            target: "mc.raw_quic.connection",
             connection_id = %connection_id,
             error = %e,
-            "Failed to decode ClientMessage"
+            "Failed to accept bidirectional stream"
         );
-        let err = McError::Internal("Invalid message format".to_string());
+        let err = McError::Internal(format!("BiStream accept failed: {e}"));
         metrics::record_session_join(
             "failure",
             Some(err.error_type_label()),
@@ -141,6 +254,150 @@ pub async fn handle_connection(
         err
     })?;
 
+    let migration = migration::track(|| connection.remote_address(), "raw_quic", &connection_id);
+    let session = run_session(
+        send_stream,
+        recv_stream,
+        early_data,
+        ctx,
+        cancel_token,
+        connection_id.clone(),
+        join_start,
+        accepted_at,
+    );
+    tokio::select! {
+        result = session => result,
+        () = migration => Ok(()),
+    }
+}
+
+/// Run a signaling session on an accepted bidirectional stream: join
+/// handshake, JoinResponse, then the bridge loop until disconnect.
+///
+/// Join messages that may be 0-RTT data pass through `early_data` first;
+/// the bridge loop only starts once the transport handshake is complete.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Session wiring; all params are distinct per-connection state"
+)]
+async fn run_session<S, R>(
+    mut send_stream: S,
+    mut recv_stream: R,
+    mut early_data: EarlyData,
+    ctx: SessionContext,
+    cancel_token: CancellationToken,
+    connection_id: String,
+    join_start: Instant,
+    accepted_at: SystemTime,
+) -> Result<(), McError>
+where
+    S: SignalingSendStream,
+    R: AsyncRead + Unpin + Send,
+{
+    let SessionContext {
+        controller_handle,
+        jwt_validator,
+        redis_client,
+        mh_client,
+        mc_id,
+        mc_grpc_endpoint,
+        min_protocol_version,
+        keepalive_config,
+        session_capture_dir,
+        media_token_key,
+    } = ctx;
+
+    let recorder = session_capture_dir
+        .as_deref()
+        .map_or_else(SessionRecorder::disabled, |dir| {
+            SessionRecorder::start(dir, &connection_id)
+        });
+
+    // Step 3: Read length-prefixed ClientMessage (max 64KB)
+    let first = match read_client_message(&mut recv_stream, &recorder, &connection_id).await {
+        Ok(msg) => early_data.admit(&msg).await.map(|()| msg),
+        Err(e) => Err(e),
+    };
+    let mut client_message = match first {
+        Ok(msg) => msg,
+        Err(e) => {
+            metrics::record_session_join(
+                "failure",
+                Some(e.error_type_label()),
+                join_start.elapsed(),
+            );
+            return Err(e);
+        }
+    };
+
+    // Step 3b: Protocol negotiation. An optional ClientHello comes first;
+    // clients without one are legacy (version 1).
+    let hello = match &client_message.message {
+        Some(client_message::Message::ClientHello(hello)) => Some(hello),
+        _ => None,
+    };
+    let client_version = hello.map_or(LEGACY_PROTOCOL_VERSION, |h| h.protocol_version);
+    let negotiated = match hello {
+        Some(hello) => protocol::negotiate(hello, min_protocol_version),
+        None => protocol::check_min_version(client_version, min_protocol_version)
+            .map(|()| NegotiatedProtocol::legacy()),
+    };
+    let sent_hello = hello.is_some();
+    let negotiated = match negotiated {
+        Ok(negotiated) => {
+            metrics::record_client_protocol_version(version_label(client_version), "accepted");
+            negotiated
+        }
+        Err(e) => {
+            warn!(
+                target: "mc.webtransport.connection",
+                connection_id = %connection_id,
+                client_version,
+                min_version = min_protocol_version,
+                "Client protocol version below minimum"
+            );
+            metrics::record_client_protocol_version(version_label(client_version), "rejected");
+            let _ = send_error(
+                &mut send_stream,
+                &recorder,
+                e.error_code(),
+                e.dt_code(),
+                &e.client_message(),
+            )
+            .await;
+            metrics::record_session_join(
+                "failure",
+                Some(e.error_type_label()),
+                join_start.elapsed(),
+            );
+            return Err(e);
+        }
+    };
+
+    if sent_hello {
+        let server_hello = encode_server_hello(&negotiated, min_protocol_version);
+        let next = match write_framed_message(&mut send_stream, &recorder, &server_hello).await {
+            Ok(()) => {
+                match read_client_message(&mut recv_stream, &recorder, &connection_id).await {
+                    Ok(msg) => early_data.admit(&msg).await.map(|()| msg),
+                    Err(e) => Err(e),
+                }
+            }
+            Err(e) => Err(e),
+        };
+        client_message = match next {
+            Ok(msg) => msg,
+            Err(e) => {
+                metrics::record_session_join(
+                    "failure",
+                    Some(e.error_type_label()),
+                    join_start.elapsed(),
+                );
+                return Err(e);
+            }
+        };
+    }
+
     // Step 4: Extract JoinRequest
     let join_request = match client_message.message {
         Some(client_message::Message::JoinRequest(req)) => req,
@@ -152,7 +409,9 @@ pub async fn handle_connection(
             );
             let _ = send_error(
                 &mut send_stream,
+                &recorder,
                 v1::ErrorCode::InvalidRequest as i32,
+                ErrorCode::McInvalidRequest,
                 "First message must be JoinRequest",
             )
             .await;
@@ -178,7 +437,9 @@ pub async fn handle_connection(
         );
         let _ = send_error(
             &mut send_stream,
+            &recorder,
             v1::ErrorCode::InvalidRequest as i32,
+            ErrorCode::McInvalidRequest,
             "Participant name too long",
         )
         .await;
@@ -195,6 +456,8 @@ pub async fn handle_connection(
         target: "mc.webtransport.connection",
         connection_id = %connection_id,
         meeting_id = %meeting_id,
+        protocol_version = negotiated.version,
+        capabilities = negotiated.capabilities,
         "Received JoinRequest"
     );
 
@@ -215,7 +478,9 @@ pub async fn handle_connection(
             metrics::record_jwt_validation("failure", "meeting", "signature_invalid");
             let _ = send_error(
                 &mut send_stream,
+                &recorder,
                 v1::ErrorCode::Unauthorized as i32,
+                ErrorCode::McInvalidToken,
                 "Invalid or expired token",
             )
             .await;
@@ -247,7 +512,9 @@ pub async fn handle_connection(
         );
         let _ = send_error(
             &mut send_stream,
+            &recorder,
             v1::ErrorCode::Unauthorized as i32,
+            ErrorCode::McInvalidToken,
             "Invalid or expired token",
         )
         .await;
@@ -262,9 +529,20 @@ pub async fn handle_connection(
 
     // Step 7: Create outbound channel BEFORE join so ParticipantActor is spawned with stream wired
     let is_host = claims.role == MeetingRole::Host;
-    let participant_id = uuid::Uuid::new_v4().to_string();
+    let participant_id = ParticipantId::new().to_string();
     let (outbound_tx, mut outbound_rx) = mpsc::channel::<bytes::Bytes>(OUTBOUND_CHANNEL_BUFFER);
 
+    // A client reconnecting within the grace period presents the binding
+    // from its last JoinResponse (ADR-0023). Sent as 0-RTT data it may be a
+    // replay, so a rejected binding must not fall back to a fresh join.
+    let resume = (!join_request.correlation_id.is_empty()
+        && !join_request.binding_token.is_empty())
+    .then(|| SessionResume {
+        correlation_id: join_request.correlation_id.clone(),
+        binding_token: join_request.binding_token.clone(),
+        join_if_rejected: !early_data.is_pending(),
+    });
+
     let join_rx = match controller_handle
         .join_connection(
             meeting_id.clone(),
@@ -273,6 +551,7 @@ pub async fn handle_connection(
             participant_id.clone(),
             is_host,
             outbound_tx,
+            resume,
         )
         .await
     {
@@ -287,7 +566,14 @@ pub async fn handle_connection(
                 error = %e,
                 "Failed to send join to controller"
             );
-            let _ = send_error(&mut send_stream, error_code, &client_msg).await;
+            let _ = send_error(
+                &mut send_stream,
+                &recorder,
+                error_code,
+                e.dt_code(),
+                &client_msg,
+            )
+            .await;
             metrics::record_session_join(
                 "failure",
                 Some(e.error_type_label()),
@@ -310,7 +596,14 @@ pub async fn handle_connection(
                 error = %e,
                 "Join failed"
             );
-            let _ = send_error(&mut send_stream, error_code, &client_msg).await;
+            let _ = send_error(
+                &mut send_stream,
+                &recorder,
+                error_code,
+                e.dt_code(),
+                &client_msg,
+            )
+            .await;
             metrics::record_session_join(
                 "failure",
                 Some(e.error_type_label()),
@@ -327,7 +620,9 @@ pub async fn handle_connection(
             );
             let _ = send_error(
                 &mut send_stream,
+                &recorder,
                 v1::ErrorCode::InternalError as i32,
+                ErrorCode::McInternal,
                 "Internal error",
             )
             .await;
@@ -346,38 +641,73 @@ pub async fn handle_connection(
         connection_id = %connection_id,
         meeting_id = %meeting_id,
         participant_id = %join_result.participant_id,
+        resumed = join_result.resumed,
         "Join succeeded"
     );
 
+    // E2E media cannot be processed server-side, so video effects only work
+    // for clients that apply them before encoding
+    let client_side_effects = join_request
+        .capabilities
+        .as_ref()
+        .is_some_and(|c| c.client_side_effects);
+    if join_result.e2e_enabled && !client_side_effects {
+        debug!(
+            target: "mc.webtransport.connection",
+            connection_id = %connection_id,
+            meeting_id = %meeting_id,
+            "Client does not apply effects locally; video effects unavailable in E2E meeting"
+        );
+    }
+
     // Step 8: Build and send JoinResponse (reads MH assignment data from Redis)
-    let (join_response, mh_data) =
-        match build_join_response(&join_result, redis_client.as_ref(), &meeting_id).await {
-            Ok(resp) => resp,
-            Err(e) => {
-                warn!(
-                    target: "mc.webtransport.connection",
-                    connection_id = %connection_id,
-                    meeting_id = %meeting_id,
-                    error = %e,
-                    "Failed to build JoinResponse"
-                );
-                join_result.participant_handle.cancel();
-                let _ = send_error(&mut send_stream, e.error_code(), &e.client_message()).await;
-                metrics::record_session_join(
-                    "failure",
-                    Some(e.error_type_label()),
-                    join_start.elapsed(),
-                );
-                return Err(e);
-            }
-        };
+    let (mut join_response, mh_data) = match build_join_response(
+        &join_result,
+        redis_client.as_ref(),
+        &MeetingId::from(&meeting_id),
+    )
+    .await
+    {
+        Ok(resp) => resp,
+        Err(e) => {
+            warn!(
+                target: "mc.webtransport.connection",
+                connection_id = %connection_id,
+                meeting_id = %meeting_id,
+                error = %e,
+                "Failed to build JoinResponse"
+            );
+            join_result.participant_handle.cancel();
+            let _ = send_error(
+                &mut send_stream,
+                &recorder,
+                e.error_code(),
+                e.dt_code(),
+                &e.client_message(),
+            )
+            .await;
+            metrics::record_session_join(
+                "failure",
+                Some(e.error_type_label()),
+                join_start.elapsed(),
+            );
+            return Err(e);
+        }
+    };
+    // MH only accepts frames published under this ID
+    join_response.user_id = user_id_for_subject(&claims.sub);
+    // Bind the client's MH connections to this session
+    if let Some(key) = &media_token_key {
+        join_response.media_token =
+            mint_media_token(key, &meeting_id, &claims.sub, &join_result.participant_id);
+    }
     let server_msg = ServerMessage {
         message: Some(server_message::Message::JoinResponse(join_response)),
         trace_parent: String::new(),
         trace_state: String::new(),
     };
 
-    if let Err(e) = write_framed_message(&mut send_stream, &server_msg).await {
+    if let Err(e) = write_framed_message(&mut send_stream, &recorder, &server_msg).await {
         warn!(
             target: "mc.webtransport.connection",
             connection_id = %connection_id,
@@ -392,14 +722,20 @@ pub async fn handle_connection(
     // Join flow complete — record success metrics
     metrics::record_session_join("success", None, join_start.elapsed());
 
+    // A resumed session carries the join ID of its original join, if any
+    if !join_result.resumed {
+        record_join_latency(&join_request.join_id, accepted_at, &connection_id);
+    }
+
     debug!(
         target: "mc.webtransport.connection",
         connection_id = %connection_id,
         "JoinResponse sent"
     );
 
-    // Step 9: [ASYNC, first participant only] Fire RegisterMeeting to each MH (R-12)
-    let is_first_participant = join_result.participants.is_empty();
+    // Step 9: [ASYNC, first participant only] Fire RegisterMeeting to each MH (R-12).
+    // A resumed session was already registered when it first joined.
+    let is_first_participant = !join_result.resumed && join_result.participants.is_empty();
     if is_first_participant {
         debug!(
             target: "mc.webtransport.connection",
@@ -409,6 +745,8 @@ pub async fn handle_connection(
         );
         let reg_mh_client = Arc::clone(&mh_client);
         let reg_meeting_id = meeting_id.clone();
+        let reg_e2e_enabled = join_result.e2e_enabled;
+        let reg_recording_layout = join_result.recording_layout;
         let reg_mc_id = mc_id.clone();
         let reg_mc_grpc_endpoint = mc_grpc_endpoint.clone();
         let reg_cancel_token = cancel_token.child_token();
@@ -423,6 +761,8 @@ pub async fn handle_connection(
                     reg_mh_client.as_ref(),
                     &mh_data,
                     &reg_meeting_id,
+                    reg_e2e_enabled,
+                    reg_recording_layout,
                     &reg_mc_id,
                     &reg_mc_grpc_endpoint,
                     &reg_cancel_token,
@@ -443,42 +783,89 @@ pub async fn handle_connection(
     // Step 10: Run bridge loop — forward ParticipantActor updates to client
     // outbound_tx was passed through the join flow and is now owned by ParticipantActor.
     // outbound_rx receives encoded protobuf bytes written by ParticipantActor.
+    // Messages the client pipelined behind its join may still be 0-RTT data,
+    // so none are read before the handshake completes.
+    if let Err(e) = early_data.handshake_complete().await {
+        join_result.participant_handle.cancel();
+        return Err(e);
+    }
+    let mut keepalive =
+        Keepalive::new(keepalive_config, negotiated.supports(Capability::Keepalive));
     let bridge_result = run_bridge_loop(
         &mut send_stream,
         &mut recv_stream,
+        &recorder,
         &mut outbound_rx,
+        &join_result.meeting_handle,
+        &join_result.participant_id,
+        &mut keepalive,
         &cancel_token,
         &connection_id,
     )
     .await;
 
+    // Tell the client why, when the close is ours to explain
+    let close_reason = match bridge_result {
+        Ok(Some(reason)) => {
+            let closed = encode_connection_closed(reason);
+            if write_framed_message(&mut send_stream, &recorder, &closed)
+                .await
+                .is_ok()
+            {
+                send_stream.finish_and_flush().await;
+            }
+            Some(reason)
+        }
+        _ => None,
+    };
+
     // Cancel the ParticipantActor — it will notify the meeting of disconnect on exit
     info!(
         target: "mc.webtransport.connection",
         connection_id = %connection_id,
         meeting_id = %meeting_id,
         participant_id = %join_result.participant_id,
+        close_reason = close_reason.map_or("none", |r| r.as_str_name()),
         "Connection closing, cancelling ParticipantActor"
     );
     join_result.participant_handle.cancel();
 
-    bridge_result
+    bridge_result.map(|_| ())
 }
 
-/// Run the bridge loop: forward outbound messages to the WebTransport stream.
+/// Run the bridge loop: forward outbound messages to the WebTransport stream
+/// and meeting requests (polls, Q&A, data channels) from the client to the meeting.
 ///
 /// Exits when:
-/// - Cancellation token is triggered
-/// - Outbound channel is closed (ParticipantActor stopped)
+/// - Cancellation token is triggered (`SERVER_DRAIN`)
+/// - Outbound channel is closed (ParticipantActor stopped; it already sent
+///   any reason)
 /// - WebTransport stream errors
 /// - Client closes their end of the stream
-async fn run_bridge_loop(
-    send_stream: &mut SendStream,
-    recv_stream: &mut RecvStream,
+/// - A keepalive-capable client is silent past the idle timeout (`IDLE_TIMEOUT`)
+/// - Client framing is invalid (`PROTOCOL_ERROR`)
+/// - The meeting is gone (`MEETING_ENDED`)
+///
+/// Returns the reason to send the client in `ConnectionClosed`, if any.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bridge loop wiring; all params are distinct per-connection state"
+)]
+async fn run_bridge_loop<S, R>(
+    send_stream: &mut S,
+    recv_stream: &mut R,
+    recorder: &SessionRecorder,
     outbound_rx: &mut mpsc::Receiver<bytes::Bytes>,
+    meeting_handle: &MeetingActorHandle,
+    participant_id: &str,
+    keepalive: &mut Keepalive,
     cancel_token: &CancellationToken,
     connection_id: &str,
-) -> Result<(), McError> {
+) -> Result<Option<CloseReason>, McError>
+where
+    S: SignalingSendStream,
+    R: AsyncRead + Unpin + Send,
+{
     loop {
         tokio::select! {
             () = cancel_token.cancelled() => {
@@ -487,13 +874,13 @@ async fn run_bridge_loop(
                     connection_id = %connection_id,
                     "Bridge loop cancelled"
                 );
-                break;
+                return Ok(Some(CloseReason::ServerDrain));
             }
 
             msg = outbound_rx.recv() => {
                 match msg {
                     Some(data) => {
-                        if let Err(e) = write_raw_framed(send_stream, &data).await {
+                        if let Err(e) = write_raw_framed(send_stream, recorder, &data).await {
                             warn!(
                                 target: "mc.webtransport.connection",
                                 connection_id = %connection_id,
@@ -509,16 +896,113 @@ async fn run_bridge_loop(
                             connection_id = %connection_id,
                             "Outbound channel closed, ending bridge loop"
                         );
-                        break;
+                        // On shutdown the ParticipantActor can stop first
+                        if cancel_token.is_cancelled() {
+                            return Ok(Some(CloseReason::ServerDrain));
+                        }
+                        return Ok(None);
+                    }
+                }
+            }
+
+            event = keepalive.next_event() => {
+                match event {
+                    KeepaliveEvent::Ping(sequence) => {
+                        let ping = encode_ping(sequence);
+                        if let Err(e) = write_framed_message(send_stream, recorder, &ping).await {
+                            warn!(
+                                target: "mc.webtransport.connection",
+                                connection_id = %connection_id,
+                                error = %e,
+                                "Failed to write keepalive ping"
+                            );
+                            return Err(e);
+                        }
+                    }
+                    KeepaliveEvent::IdleTimeout => {
+                        warn!(
+                            target: "mc.webtransport.connection",
+                            connection_id = %connection_id,
+                            "Client idle past keepalive timeout, closing connection"
+                        );
+                        metrics::record_connection_idle_timeout();
+                        return Ok(Some(CloseReason::IdleTimeout));
                     }
                 }
             }
 
             // Read client messages (framed protobuf)
-            result = read_framed_message(recv_stream) => {
+            result = read_framed_message(recv_stream, recorder) => {
                 match result {
                     Ok(data) => {
-                        handle_client_message(&data, connection_id);
+                        keepalive.record_activity();
+                        let action = match handle_client_message(&data, connection_id) {
+                            Ok(action) => action,
+                            Err(e) => {
+                                let error = encode_error_message(&e);
+                                if let Err(e) = write_framed_message(send_stream, recorder, &error).await {
+                                    warn!(
+                                        target: "mc.webtransport.connection",
+                                        connection_id = %connection_id,
+                                        error = %e,
+                                        "Failed to write rejection error"
+                                    );
+                                    return Err(e);
+                                }
+                                continue;
+                            }
+                        };
+                        let request = match action {
+                            ClientAction::Forward(request) => request,
+                            ClientAction::Pong(sequence) => {
+                                let pong = encode_pong(sequence);
+                                if let Err(e) = write_framed_message(send_stream, recorder, &pong).await {
+                                    warn!(
+                                        target: "mc.webtransport.connection",
+                                        connection_id = %connection_id,
+                                        error = %e,
+                                        "Failed to write keepalive pong"
+                                    );
+                                    return Err(e);
+                                }
+                                continue;
+                            }
+                            ClientAction::Ignore => continue,
+                        };
+                        let participant_id = participant_id.to_string();
+                        let forwarded = match request {
+                            ClientRequest::Interaction(request) => {
+                                meeting_handle.interaction(participant_id, request).await
+                            }
+                            ClientRequest::DataChannel(request) => {
+                                meeting_handle.data_channel(participant_id, request).await
+                            }
+                            ClientRequest::LiveStream(request) => {
+                                meeting_handle.live_stream(participant_id, request).await
+                            }
+                            ClientRequest::Recording(request) => {
+                                meeting_handle.recording(participant_id, request).await
+                            }
+                            ClientRequest::Keyframe(request) => {
+                                meeting_handle
+                                    .request_keyframe(
+                                        KeyframeRequester::Participant(participant_id),
+                                        request,
+                                    )
+                                    .await
+                            }
+                        };
+                        if forwarded.is_err() {
+                            debug!(
+                                target: "mc.webtransport.connection",
+                                connection_id = %connection_id,
+                                "Meeting gone, ending bridge loop"
+                            );
+                            return Ok(Some(CloseReason::MeetingEnded));
+                        }
+                    }
+                    Err(McError::MessageRejected(_)) => {
+                        return Ok(Some(CloseReason::ProtocolError));
                     }
                     Err(_) => {
                         debug!(
@@ -526,33 +1010,77 @@ async fn run_bridge_loop(
                             connection_id = %connection_id,
                             "Client stream closed or read error"
                         );
-                        break;
+                        return Ok(None);
                     }
                 }
             }
         }
     }
+}
 
-    Ok(())
+/// What the bridge loop does with a post-join client message.
+#[derive(Debug, PartialEq)]
+enum ClientAction {
+    /// Forward to the meeting.
+    Forward(ClientRequest),
+    /// Answer a client `Ping` with this sequence.
+    Pong(u64),
+    /// Nothing beyond the activity it signals.
+    Ignore,
 }
 
 /// Handle a post-join client message in the bridge loop.
 ///
+/// Every decoded message is checked by
+/// [`validate_client_message`] first; a rejected message is counted and
+/// returned as `Err` for the caller to report to the client.
+///
 /// Currently handles:
+/// - Poll, Q&A, data channel, live stream, recording, and keyframe request
+///   messages: returned as `ClientAction::Forward` for the caller to send to
+///   the meeting.
+/// - `Ping`: answered with a `Pong`; `Pong`: debug log only (the caller has
+///   already counted the frame as keepalive activity).
 /// - `MediaConnectionUpdate` (browser-client-join Task #2 stub): no-op
 ///   debug log; per-MH state recording deferred to Task #6.
 /// - All other messages: Ignored (logged at debug level).
-fn handle_client_message(data: &[u8], connection_id: &str) {
+fn handle_client_message(data: &[u8], connection_id: &str) -> Result<ClientAction, McError> {
     let Ok(client_message) = ClientMessage::decode(data) else {
         debug!(
             target: "mc.webtransport.connection",
             connection_id = %connection_id,
             "Failed to decode post-join client message, ignoring"
         );
-        return;
+        return Ok(ClientAction::Ignore);
     };
 
-    match client_message.message {
+    if let Err(e) = validate_client_message(&client_message, data.len()) {
+        let message_type = client_message
+            .message
+            .as_ref()
+            .map_or("empty", message_type_label);
+        debug!(
+            target: "mc.webtransport.connection",
+            connection_id = %connection_id,
+            message_type,
+            reason = e.reason_label(),
+            "Rejected post-join client message"
+        );
+        metrics::record_signaling_message_rejected(message_type, e.reason_label());
+        return Err(e.into());
+    }
+
+    let action = match client_message.message {
+        Some(client_message::Message::Ping(ping)) => ClientAction::Pong(ping.sequence),
+        Some(client_message::Message::Pong(pong)) => {
+            debug!(
+                target: "mc.webtransport.connection",
+                connection_id = %connection_id,
+                sequence = pong.sequence,
+                "Keepalive pong received"
+            );
+            ClientAction::Ignore
+        }
         Some(client_message::Message::MediaConnectionUpdate(msg)) => {
             debug!(
                 target: "mc.webtransport.connection",
@@ -569,29 +1097,57 @@ fn handle_client_message(data: &[u8], connection_id: &str) {
             //   `MediaConnectionFailed.media_handler_url` / `error_reason` at
             //   this site) — these are client-controlled strings and must not
             //   be logged unbounded.
+            ClientAction::Ignore
         }
-        Some(_) => {
-            debug!(
-                target: "mc.webtransport.connection",
-                connection_id = %connection_id,
-                "Received unhandled post-join client message, ignoring"
-            );
-        }
+        Some(message) => match decode_client_request(message) {
+            Some(request) => ClientAction::Forward(request),
+            None => {
+                debug!(
+                    target: "mc.webtransport.connection",
+                    connection_id = %connection_id,
+                    "Received unhandled post-join client message, ignoring"
+                );
+                ClientAction::Ignore
+            }
+        },
         None => {
             debug!(
                 target: "mc.webtransport.connection",
                 connection_id = %connection_id,
                 "Received empty client message, ignoring"
             );
+            ClientAction::Ignore
         }
-    }
+    };
+    Ok(action)
 }
 
-/// Read a length-prefixed protobuf message from a `RecvStream`.
+/// Read and decode one `ClientMessage` during the join handshake.
+async fn read_client_message<R: AsyncRead + Unpin>(
+    stream: &mut R,
+    recorder: &SessionRecorder,
+    connection_id: &str,
+) -> Result<ClientMessage, McError> {
+    let data = read_framed_message(stream, recorder).await?;
+    ClientMessage::decode(data.as_ref()).map_err(|e| {
+        warn!(
+            target: "mc.webtransport.connection",
+            connection_id = %connection_id,
+            error = %e,
+            "Failed to decode ClientMessage"
+        );
+        McError::Internal("Invalid message format".to_string())
+    })
+}
+
+/// Read a length-prefixed protobuf message from the client's stream.
 ///
 /// Wire format: 4-byte big-endian length prefix + protobuf bytes.
 /// Enforces `MAX_MESSAGE_SIZE` (64KB) to prevent abuse.
-async fn read_framed_message(stream: &mut RecvStream) -> Result<bytes::Bytes, McError> {
+async fn read_framed_message<R: AsyncRead + Unpin>(
+    stream: &mut R,
+    recorder: &SessionRecorder,
+) -> Result<bytes::Bytes, McError> {
     let mut len_buf = [0u8; 4];
     stream.read_exact(&mut len_buf).await.map_err(|e| {
         warn!(
@@ -611,11 +1167,11 @@ async fn read_framed_message(stream: &mut RecvStream) -> Result<bytes::Bytes, Mc
             max = MAX_MESSAGE_SIZE,
             "Message exceeds maximum size"
         );
-        return Err(McError::Internal("Message too large".to_string()));
+        return Err(McError::MessageRejected("Message too large".to_string()));
     }
 
     if msg_len == 0 {
-        return Err(McError::Internal("Empty message".to_string()));
+        return Err(McError::MessageRejected("Empty message".to_string()));
     }
 
     let mut buf = vec![0u8; msg_len];
@@ -629,17 +1185,26 @@ async fn read_framed_message(stream: &mut RecvStream) -> Result<bytes::Bytes, Mc
         McError::Internal("Failed to read message body".to_string())
     })?;
 
+    recorder.record(Direction::ClientToServer, &buf);
     Ok(bytes::Bytes::from(buf))
 }
 
-/// Write a length-prefixed protobuf message to a `SendStream`.
-async fn write_framed_message(stream: &mut SendStream, msg: &ServerMessage) -> Result<(), McError> {
+/// Write a length-prefixed protobuf message to the client's stream.
+async fn write_framed_message<S: AsyncWrite + Unpin>(
+    stream: &mut S,
+    recorder: &SessionRecorder,
+    msg: &ServerMessage,
+) -> Result<(), McError> {
     let encoded = msg.encode_to_vec();
-    write_raw_framed(stream, &encoded).await
+    write_raw_framed(stream, recorder, &encoded).await
 }
 
 /// Write raw bytes with 4-byte big-endian length prefix.
-async fn write_raw_framed(stream: &mut SendStream, data: &[u8]) -> Result<(), McError> {
+async fn write_raw_framed<S: AsyncWrite + Unpin>(
+    stream: &mut S,
+    recorder: &SessionRecorder,
+    data: &[u8],
+) -> Result<(), McError> {
     let len: u32 = data
         .len()
         .try_into()
@@ -651,16 +1216,20 @@ async fn write_raw_framed(stream: &mut SendStream, data: &[u8]) -> Result<(), Mc
     stream
         .write_all(&frame)
         .await
-        .map_err(|e| McError::Internal(format!("Stream write failed: {e}")))
+        .map_err(|e| McError::Internal(format!("Stream write failed: {e}")))?;
+    recorder.record(Direction::ServerToClient, data);
+    Ok(())
 }
 
 /// Send an error message to the client before closing.
 ///
 /// Finishes the stream after writing to ensure data is flushed
 /// before the function returns and the stream is dropped.
-async fn send_error(
-    stream: &mut SendStream,
+async fn send_error<S: SignalingSendStream>(
+    stream: &mut S,
+    recorder: &SessionRecorder,
     error_code: i32,
+    dt_code: ErrorCode,
     message: &str,
 ) -> Result<(), McError> {
     let server_msg = ServerMessage {
@@ -668,16 +1237,80 @@ async fn send_error(
             code: error_code,
             message: message.to_string(),
             details: Default::default(),
+            dt_code: dt_code.to_string(),
         })),
         trace_parent: String::new(),
         trace_state: String::new(),
     };
-    let result = write_framed_message(stream, &server_msg).await;
+    let result = write_framed_message(stream, recorder, &server_msg).await;
     // Finish the stream to flush buffered data before the caller drops it
-    let _ = stream.finish().await;
+    stream.finish_and_flush().await;
     result
 }
 
+/// GC assignment time carried in a UUIDv7 join correlation ID.
+fn join_assigned_at(join_id: &str) -> Option<SystemTime> {
+    let join_id = uuid::Uuid::parse_str(join_id).ok()?;
+    if join_id.get_version_num() != 7 {
+        return None;
+    }
+    let (secs, nanos) = join_id.get_timestamp()?.to_unix();
+    Some(UNIX_EPOCH + Duration::new(secs, nanos))
+}
+
+/// Record join latency from GC assignment to session accept and to the
+/// first roster (the JoinResponse just sent).
+///
+/// Skipped when the client sent no usable join ID, when GC and MC clocks
+/// disagree enough to put the accept before the assignment, or when the
+/// join ID is too old to be from this join.
+fn record_join_latency(join_id: &str, accepted_at: SystemTime, connection_id: &str) {
+    let Some(assigned_at) = join_assigned_at(join_id) else {
+        return;
+    };
+    let (Ok(accepted), Ok(first_roster)) = (
+        accepted_at.duration_since(assigned_at),
+        SystemTime::now().duration_since(assigned_at),
+    ) else {
+        return;
+    };
+    if first_roster > MAX_JOIN_LATENCY {
+        return;
+    }
+
+    metrics::record_join_latency("accepted", accepted);
+    metrics::record_join_latency("first_roster", first_roster);
+
+    debug!(
+        target: "mc.webtransport.connection",
+        connection_id = %connection_id,
+        join_id = %join_id,
+        accepted_ms = accepted.as_millis() as u64,
+        first_roster_ms = first_roster.as_millis() as u64,
+        "Join latency recorded"
+    );
+}
+
+/// Mint the media token returned in a `JoinResponse` (see
+/// [`common::media_token`]), valid for [`MEDIA_TOKEN_TTL`].
+fn mint_media_token(
+    key: &MediaTokenKey,
+    meeting_id: &str,
+    sub: &str,
+    participant_id: &str,
+) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    key.sign(&MediaTokenClaims {
+        meeting_id: meeting_id.to_string(),
+        sub: sub.to_string(),
+        participant_id: participant_id.to_string(),
+        exp: i64::try_from(now + MEDIA_TOKEN_TTL.as_secs()).unwrap_or(i64::MAX),
+    })
+}
+
 /// Build a protobuf `JoinResponse` from the actor's `JoinResult`.
 ///
 /// Reads MH assignment data from Redis to populate `media_servers`.
@@ -688,7 +1321,7 @@ async fn send_error(
 async fn build_join_response(
     result: &JoinResult,
     redis_client: &dyn MhAssignmentStore,
-    meeting_id: &str,
+    meeting_id: &MeetingId,
 ) -> Result<(JoinResponse, MhAssignmentData), McError> {
     let existing_participants = result
         .participants
@@ -726,12 +1359,16 @@ async fn build_join_response(
     Ok((
         JoinResponse {
             participant_id: result.participant_id.clone(),
+            // Set by the caller from the JWT subject
             user_id: 0,
             existing_participants,
             media_servers,
             encryption_keys: None,
             correlation_id: result.correlation_id.clone(),
             binding_token: result.binding_token.clone(),
+            e2e_enabled: result.e2e_enabled,
+            // Minted by `run_session` when media tokens are enabled
+            media_token: String::new(),
         },
         mh_data,
     ))
@@ -745,10 +1382,16 @@ async fn build_join_response(
 ///
 /// This function handles all errors internally (log + continue) since it runs
 /// as a fire-and-forget spawned task with no caller to propagate errors to.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Mirrors the RegisterMeeting request fields plus the MH list and cancel token"
+)]
 async fn register_meeting_with_handlers(
     mh_client: &dyn MhRegistrationClient,
     mh_data: &MhAssignmentData,
     meeting_id: &str,
+    e2e_enabled: bool,
+    recording_layout: RecordingLayout,
     mc_id: &str,
     mc_grpc_endpoint: &str,
     cancel_token: &CancellationToken,
@@ -766,7 +1409,14 @@ async fn register_meeting_with_handlers(
                 return;
             }
             match mh_client
-                .register_meeting(grpc_endpoint, meeting_id, mc_id, mc_grpc_endpoint)
+                .register_meeting(
+                    grpc_endpoint,
+                    meeting_id,
+                    e2e_enabled,
+                    recording_layout,
+                    mc_id,
+                    mc_grpc_endpoint,
+                )
                 .await
             {
                 Ok(()) => {
@@ -846,14 +1496,20 @@ mod tests {
         };
         let data = msg.encode_to_vec();
         // Should not panic -- exercises the Some(_) branch
-        handle_client_message(&data, "test-conn-3");
+        assert!(matches!(
+            handle_client_message(&data, "test-conn-3"),
+            Ok(ClientAction::Ignore)
+        ));
     }
 
     #[test]
     fn test_handle_client_message_invalid_data() {
         let garbage = vec![0xFF, 0xFE, 0xFD, 0xFC, 0xFB];
         // Should not panic -- exercises the decode error branch
-        handle_client_message(&garbage, "test-conn-4");
+        assert!(matches!(
+            handle_client_message(&garbage, "test-conn-4"),
+            Ok(ClientAction::Ignore)
+        ));
     }
 
     #[test]
@@ -865,7 +1521,68 @@ mod tests {
         };
         let data = msg.encode_to_vec();
         // Should not panic -- exercises the None branch
-        handle_client_message(&data, "test-conn-5");
+        assert!(matches!(
+            handle_client_message(&data, "test-conn-5"),
+            Ok(ClientAction::Ignore)
+        ));
+    }
+
+    #[test]
+    fn test_handle_client_message_interaction_forwarded() {
+        let msg = ClientMessage {
+            message: Some(client_message::Message::AskQuestion(v1::AskQuestion {
+                text: "Is this recorded?".to_string(),
+            })),
+            trace_parent: String::new(),
+            trace_state: String::new(),
+        };
+        let data = msg.encode_to_vec();
+        assert_eq!(
+            handle_client_message(&data, "test-conn-6").unwrap(),
+            ClientAction::Forward(ClientRequest::Interaction(
+                crate::actors::interactions::InteractionRequest::AskQuestion {
+                    text: "Is this recorded?".to_string(),
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_handle_client_message_keepalive() {
+        let ping = ClientMessage {
+            message: Some(client_message::Message::Ping(v1::Ping { sequence: 42 })),
+            trace_parent: String::new(),
+            trace_state: String::new(),
+        };
+        assert_eq!(
+            handle_client_message(&ping.encode_to_vec(), "test-conn-8").unwrap(),
+            ClientAction::Pong(42)
+        );
+
+        let pong = ClientMessage {
+            message: Some(client_message::Message::Pong(v1::Pong { sequence: 3 })),
+            trace_parent: String::new(),
+            trace_state: String::new(),
+        };
+        assert_eq!(
+            handle_client_message(&pong.encode_to_vec(), "test-conn-9").unwrap(),
+            ClientAction::Ignore
+        );
+    }
+
+    #[test]
+    fn test_handle_client_message_rejects_invalid_message() {
+        let msg = ClientMessage {
+            message: Some(client_message::Message::AskQuestion(v1::AskQuestion {
+                text: "?".repeat(crate::actors::interactions::MAX_QUESTION_LEN + 1),
+            })),
+            trace_parent: String::new(),
+            trace_state: String::new(),
+        };
+        let data = msg.encode_to_vec();
+        let err = handle_client_message(&data, "test-conn-7").unwrap_err();
+        assert!(matches!(err, McError::MessageRejected(_)));
+        assert_eq!(err.error_code(), 1);
     }
 
     // ========================================================================
@@ -882,6 +1599,7 @@ mod tests {
     struct MockRegClient {
         results: Mutex<VecDeque<Result<(), McError>>>,
         call_count: Mutex<u32>,
+        e2e_flags: Mutex<Vec<bool>>,
     }
 
     impl MockRegClient {
@@ -889,6 +1607,7 @@ mod tests {
             Self {
                 results: Mutex::new(VecDeque::from(results)),
                 call_count: Mutex::new(0),
+                e2e_flags: Mutex::new(Vec::new()),
             }
         }
 
@@ -902,10 +1621,13 @@ mod tests {
             &'a self,
             _mh_grpc_endpoint: &'a str,
             _meeting_id: &'a str,
+            e2e_enabled: bool,
+            _recording_layout: RecordingLayout,
             _mc_id: &'a str,
             _mc_grpc_endpoint: &'a str,
         ) -> Pin<Box<dyn std::future::Future<Output = Result<(), McError>> + Send + 'a>> {
             *self.call_count.lock().unwrap() += 1;
+            self.e2e_flags.lock().unwrap().push(e2e_enabled);
             let result = self.results.lock().unwrap().pop_front().unwrap_or(Ok(()));
             Box::pin(async move { result })
         }
@@ -928,8 +1650,17 @@ mod tests {
         }]);
         let cancel = CancellationToken::new();
 
-        register_meeting_with_handlers(&client, &mh_data, "m1", "mc1", "http://mc:50052", &cancel)
-            .await;
+        register_meeting_with_handlers(
+            &client,
+            &mh_data,
+            "m1",
+            false,
+            RecordingLayout::Unspecified,
+            "mc1",
+            "http://mc:50052",
+            &cancel,
+        )
+        .await;
 
         assert_eq!(client.call_count(), 2, "Should succeed on 2nd attempt");
     }
@@ -948,8 +1679,17 @@ mod tests {
         }]);
         let cancel = CancellationToken::new();
 
-        register_meeting_with_handlers(&client, &mh_data, "m1", "mc1", "http://mc:50052", &cancel)
-            .await;
+        register_meeting_with_handlers(
+            &client,
+            &mh_data,
+            "m1",
+            false,
+            RecordingLayout::Unspecified,
+            "mc1",
+            "http://mc:50052",
+            &cancel,
+        )
+        .await;
 
         assert_eq!(
             client.call_count(),
@@ -981,8 +1721,17 @@ mod tests {
         ]);
         let cancel = CancellationToken::new();
 
-        register_meeting_with_handlers(&client, &mh_data, "m1", "mc1", "http://mc:50052", &cancel)
-            .await;
+        register_meeting_with_handlers(
+            &client,
+            &mh_data,
+            "m1",
+            false,
+            RecordingLayout::Unspecified,
+            "mc1",
+            "http://mc:50052",
+            &cancel,
+        )
+        .await;
 
         assert_eq!(
             client.call_count(),
@@ -990,4 +1739,64 @@ mod tests {
             "1 call for handler 1 (success) + 3 calls for handler 2 (exhausted)"
         );
     }
+
+    #[tokio::test]
+    async fn test_register_forwards_e2e_flag() {
+        let client = MockRegClient::new(vec![]);
+        let mh_data = make_mh_data(vec![MhEndpointInfo {
+            mh_id: "mh-1".to_string(),
+            webtransport_endpoint: "wt://mh-1:4433".to_string(),
+            grpc_endpoint: "http://mh-1:50053".to_string(),
+        }]);
+        let cancel = CancellationToken::new();
+
+        register_meeting_with_handlers(
+            &client,
+            &mh_data,
+            "m1",
+            true,
+            RecordingLayout::Unspecified,
+            "mc1",
+            "http://mc:50052",
+            &cancel,
+        )
+        .await;
+
+        assert_eq!(*client.e2e_flags.lock().unwrap(), vec![true]);
+    }
+
+    #[test]
+    fn test_join_assigned_at_reads_uuid_v7_timestamp() {
+        let before = SystemTime::now() - Duration::from_millis(1);
+        let join_id = uuid::Uuid::now_v7().to_string();
+
+        let assigned_at = join_assigned_at(&join_id).expect("v7 join ID has a timestamp");
+        assert!(assigned_at >= before);
+        assert!(assigned_at <= SystemTime::now());
+    }
+
+    #[test]
+    fn test_join_assigned_at_rejects_unusable_ids() {
+        assert!(join_assigned_at("").is_none());
+        assert!(join_assigned_at("not-a-uuid").is_none());
+        assert!(join_assigned_at(&uuid::Uuid::new_v4().to_string()).is_none());
+    }
+    #[test]
+    fn test_mint_media_token_binds_session() {
+        let key = MediaTokenKey::from_secret(&[7; 32]).unwrap();
+        let token = mint_media_token(&key, "meeting-1", "user-1", "participant-1");
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        let claims = key.verify(&token, now).unwrap();
+        assert_eq!(claims.meeting_id, "meeting-1");
+        assert_eq!(claims.sub, "user-1");
+        assert_eq!(claims.participant_id, "participant-1");
+        assert!(claims.exp <= now + MEDIA_TOKEN_TTL.as_secs() as i64);
+        assert!(key
+            .verify(&token, now + MEDIA_TOKEN_TTL.as_secs() as i64 + 1)
+            .is_err());
+    }
 }
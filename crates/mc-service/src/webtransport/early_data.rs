@@ -0,0 +1,195 @@
+//! 0-RTT admission for raw QUIC signaling connections.
+//!
+//! A native client reconnecting after a network switch can resume its TLS
+//! session and send its first messages as 0-RTT data, saving a round trip.
+//! 0-RTT data can be replayed by an attacker, so only messages that are
+//! safe to act on twice are handled before the handshake completes (see
+//! [`is_replay_safe`]); anything else waits for the handshake, which a
+//! replayed flight can never finish.
+//!
+//! WebTransport sessions never carry 0-RTT data (`wtransport` does not
+//! expose it) and use [`EarlyData::none`].
+
+use crate::errors::McError;
+use crate::observability::metrics;
+use crate::webtransport::validation::message_type_label;
+
+use proto_gen::dark_tower::signaling::v1::{client_message, ClientMessage};
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::Poll;
+use tracing::{debug, warn};
+
+/// Whether `message` may be acted on while it may still be 0-RTT data.
+///
+/// Safe messages change nothing when repeated:
+/// - `ClientHello`, `Ping`, `Pong` only ask for a reply
+/// - A session recovery `JoinRequest` (with `correlation_id` and
+///   `binding_token`) re-attaches the participant the binding names. Each
+///   binding resumes once, and an early resume does not fall back to a
+///   fresh join (see `SessionResume::join_if_rejected`), so a replay
+///   changes nothing; a fresh join would create a participant and is not
+///   safe
+#[must_use]
+pub fn is_replay_safe(message: &ClientMessage) -> bool {
+    use client_message::Message;
+    match &message.message {
+        Some(Message::ClientHello(_) | Message::Ping(_) | Message::Pong(_)) => true,
+        Some(Message::JoinRequest(join)) => {
+            !join.correlation_id.is_empty() && !join.binding_token.is_empty()
+        }
+        _ => false,
+    }
+}
+
+/// Handshake state of a connection that may have received 0-RTT data.
+pub struct EarlyData {
+    /// Connection and its handshake completion until the handshake is seen
+    /// to finish; `None` afterwards, or for connections without 0-RTT.
+    pending: Option<(quinn::Connection, quinn::ZeroRttAccepted)>,
+}
+
+impl EarlyData {
+    /// A connection whose handshake is already complete.
+    #[must_use]
+    pub fn none() -> Self {
+        Self { pending: None }
+    }
+
+    /// A raw QUIC connection accepted before its handshake completed.
+    #[must_use]
+    pub fn pending(connection: quinn::Connection, accepted: quinn::ZeroRttAccepted) -> Self {
+        Self {
+            pending: Some((connection, accepted)),
+        }
+    }
+
+    /// Whether messages admitted now may still be 0-RTT data.
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Wait until `message` may be acted on: immediately if the handshake is
+    /// complete or the message is replay-safe, otherwise once the handshake
+    /// completes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Internal` if the connection closes before the
+    /// handshake completes.
+    pub async fn admit(&mut self, message: &ClientMessage) -> Result<(), McError> {
+        let Some((_, accepted)) = &mut self.pending else {
+            return Ok(());
+        };
+        // Data read after the handshake completed was not 0-RTT
+        if let Poll::Ready(zero_rtt) =
+            poll_fn(|cx| Poll::Ready(Pin::new(&mut *accepted).poll(cx))).await
+        {
+            return self.finish(zero_rtt);
+        }
+
+        let message_type = message.message.as_ref().map_or("empty", message_type_label);
+        if is_replay_safe(message) {
+            metrics::record_zero_rtt_message(message_type, "processed");
+            return Ok(());
+        }
+
+        debug!(
+            target: "mc.raw_quic.connection",
+            message_type,
+            "Holding replay-unsafe message until the handshake completes"
+        );
+        metrics::record_zero_rtt_message(message_type, "deferred");
+        self.handshake_complete().await
+    }
+
+    /// Wait for the handshake to complete.
+    ///
+    /// # Errors
+    ///
+    /// Returns `McError::Internal` if the connection closes first.
+    pub async fn handshake_complete(&mut self) -> Result<(), McError> {
+        let Some((_, accepted)) = &mut self.pending else {
+            return Ok(());
+        };
+        let zero_rtt = accepted.await;
+        self.finish(zero_rtt)
+    }
+
+    /// Record the completed handshake; `zero_rtt` is what
+    /// [`quinn::ZeroRttAccepted`] resolved to.
+    fn finish(&mut self, zero_rtt: bool) -> Result<(), McError> {
+        let Some((connection, _)) = self.pending.take() else {
+            return Ok(());
+        };
+
+        // `false` both when 0-RTT was refused and when the connection was
+        // lost mid-handshake
+        if let Some(reason) = connection.close_reason() {
+            warn!(
+                target: "mc.raw_quic.connection",
+                error = %reason,
+                "Raw QUIC connection closed before the handshake completed"
+            );
+            return Err(McError::Internal(format!(
+                "Connection closed during handshake: {reason}"
+            )));
+        }
+        metrics::record_raw_quic_handshake(if zero_rtt { "accepted" } else { "none" });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proto_gen::dark_tower::signaling::v1::{ClientHello, JoinRequest, Ping, PollVote};
+
+    fn wrap(message: client_message::Message) -> ClientMessage {
+        ClientMessage {
+            message: Some(message),
+            ..Default::default()
+        }
+    }
+
+    fn join(correlation_id: &str, binding_token: &str) -> ClientMessage {
+        wrap(client_message::Message::JoinRequest(JoinRequest {
+            meeting_id: "meeting-1".to_string(),
+            join_token: "token".to_string(),
+            correlation_id: correlation_id.to_string(),
+            binding_token: binding_token.to_string(),
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn test_is_replay_safe_allows_idempotent_messages() {
+        assert!(is_replay_safe(&wrap(client_message::Message::ClientHello(
+            ClientHello::default()
+        ))));
+        assert!(is_replay_safe(&wrap(client_message::Message::Ping(
+            Ping::default()
+        ))));
+        assert!(is_replay_safe(&join("corr-1", "binding")));
+    }
+
+    #[test]
+    fn test_is_replay_safe_rejects_state_changes() {
+        // A fresh join creates a participant
+        assert!(!is_replay_safe(&join("", "")));
+        assert!(!is_replay_safe(&join("corr-1", "")));
+        assert!(!is_replay_safe(&wrap(client_message::Message::PollVote(
+            PollVote::default()
+        ))));
+        assert!(!is_replay_safe(&ClientMessage::default()));
+    }
+
+    #[tokio::test]
+    async fn test_admit_without_early_data_is_immediate() {
+        let mut early_data = EarlyData::none();
+        assert!(!early_data.is_pending());
+        early_data.admit(&join("", "")).await.unwrap();
+        early_data.handshake_complete().await.unwrap();
+    }
+}
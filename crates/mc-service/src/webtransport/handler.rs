@@ -1,12 +1,31 @@
 //! Shared encoding utilities for WebTransport signaling messages.
 
+use crate::actors::data_channels::DataChannelRequest;
+use crate::actors::interactions::{InteractionEvent, InteractionRequest};
+use crate::actors::keyframes::{KeyframeRequest, VideoStream};
+use crate::actors::live_stream::{LiveStreamOutput, LiveStreamRequest, LiveStreamStatus};
 use crate::actors::messages::{LeaveReason, ParticipantStateUpdate};
+use crate::actors::recording::{RecordingNotice, RecordingRequest};
+use crate::errors::McError;
+use crate::webtransport::protocol::{NegotiatedProtocol, PROTOCOL_VERSION};
 
 use proto_gen::dark_tower::signaling::v1::{
-    self, server_message, Participant, ParticipantJoined, ParticipantLeft, ServerMessage,
+    self, client_message, server_message, start_live_stream, CloseReason, ConnectionClosed,
+    DataChannelMessage, ErrorMessage, KeyframeRequested, LiveStreamState, Participant,
+    ParticipantJoined, ParticipantLeft, Ping, Poll, PollCreated, PollResults, Pong, Question,
+    QuestionUpdated, RecordingStateChanged, ServerHello, ServerMessage,
 };
 use tracing::debug;
 
+/// Wrap a `server_message::Message` in a `ServerMessage` envelope.
+fn envelope(message: server_message::Message) -> ServerMessage {
+    ServerMessage {
+        message: Some(message),
+        trace_parent: String::new(),
+        trace_state: String::new(),
+    }
+}
+
 /// Encode a `ParticipantStateUpdate` as a `ServerMessage`.
 ///
 /// Only `ParticipantJoined` and `ParticipantLeft` are serialized to the wire.
@@ -20,15 +39,11 @@ pub fn encode_participant_update(update: &ParticipantStateUpdate) -> Option<Serv
                 streams: Vec::new(),
                 joined_at: 0,
             };
-            Some(ServerMessage {
-                message: Some(server_message::Message::ParticipantJoined(
-                    ParticipantJoined {
-                        participant: Some(participant),
-                    },
-                )),
-                trace_parent: String::new(),
-                trace_state: String::new(),
-            })
+            Some(envelope(server_message::Message::ParticipantJoined(
+                ParticipantJoined {
+                    participant: Some(participant),
+                },
+            )))
         }
         ParticipantStateUpdate::Left {
             participant_id,
@@ -39,15 +54,14 @@ pub fn encode_participant_update(update: &ParticipantStateUpdate) -> Option<Serv
                 LeaveReason::Timeout => v1::LeaveReason::Timeout,
                 LeaveReason::Removed => v1::LeaveReason::Kicked,
                 LeaveReason::MeetingEnded => v1::LeaveReason::MeetingEnded,
+                LeaveReason::Replaced => v1::LeaveReason::Replaced,
             };
-            Some(ServerMessage {
-                message: Some(server_message::Message::ParticipantLeft(ParticipantLeft {
+            Some(envelope(server_message::Message::ParticipantLeft(
+                ParticipantLeft {
                     participant_id: participant_id.clone(),
                     reason: proto_reason as i32,
-                })),
-                trace_parent: String::new(),
-                trace_state: String::new(),
-            })
+                },
+            )))
         }
         ParticipantStateUpdate::MuteChanged { participant_id, .. } => {
             debug!(
@@ -76,6 +90,257 @@ pub fn encode_participant_update(update: &ParticipantStateUpdate) -> Option<Serv
     }
 }
 
+/// Encode a poll or Q&A `InteractionEvent` as a `ServerMessage`.
+pub fn encode_interaction_event(event: &InteractionEvent) -> ServerMessage {
+    let message = match event {
+        InteractionEvent::PollCreated {
+            poll_id,
+            question,
+            options,
+            closed,
+        } => server_message::Message::PollCreated(PollCreated {
+            poll: Some(Poll {
+                poll_id: poll_id.clone(),
+                question: question.clone(),
+                options: options.clone(),
+                closed: *closed,
+            }),
+        }),
+        InteractionEvent::PollResults {
+            poll_id,
+            vote_counts,
+            total_votes,
+        } => server_message::Message::PollResults(PollResults {
+            poll_id: poll_id.clone(),
+            vote_counts: vote_counts.clone(),
+            total_votes: *total_votes,
+        }),
+        InteractionEvent::QuestionUpdated {
+            question_id,
+            text,
+            asked_by,
+            upvotes,
+            answered,
+        } => server_message::Message::QuestionUpdated(QuestionUpdated {
+            question: Some(Question {
+                question_id: question_id.clone(),
+                text: text.clone(),
+                asked_by: asked_by.clone(),
+                upvotes: *upvotes,
+                answered: *answered,
+            }),
+        }),
+    };
+    envelope(message)
+}
+
+/// Encode the `ServerHello` answering a client's `ClientHello`.
+pub fn encode_server_hello(negotiated: &NegotiatedProtocol, min_version: u32) -> ServerMessage {
+    envelope(server_message::Message::ServerHello(ServerHello {
+        protocol_version: negotiated.version,
+        capabilities: negotiated.capabilities,
+        min_protocol_version: min_version,
+        max_protocol_version: PROTOCOL_VERSION,
+    }))
+}
+
+/// Encode a keepalive `Ping`.
+pub fn encode_ping(sequence: u64) -> ServerMessage {
+    envelope(server_message::Message::Ping(Ping { sequence }))
+}
+
+/// Encode the `Pong` answering a client's `Ping`.
+pub fn encode_pong(sequence: u64) -> ServerMessage {
+    envelope(server_message::Message::Pong(Pong { sequence }))
+}
+
+/// Encode the `ConnectionClosed` sent as the last message on a stream the
+/// MC is closing.
+pub fn encode_connection_closed(reason: CloseReason) -> ServerMessage {
+    envelope(server_message::Message::ConnectionClosed(
+        ConnectionClosed {
+            reason: reason as i32,
+        },
+    ))
+}
+
+/// Encode an `McError` as a client-safe `ErrorMessage`.
+pub fn encode_error_message(error: &McError) -> ServerMessage {
+    envelope(server_message::Message::Error(ErrorMessage {
+        code: error.error_code(),
+        message: error.client_message(),
+        details: Default::default(),
+        dt_code: error.dt_code().to_string(),
+    }))
+}
+
+/// Encode a data channel payload for delivery to a subscriber.
+pub fn encode_data_channel_message(
+    channel_id: &str,
+    sender_participant_id: &str,
+    sequence: u64,
+    payload: &[u8],
+) -> ServerMessage {
+    envelope(server_message::Message::DataChannelMessage(
+        DataChannelMessage {
+            channel_id: channel_id.to_string(),
+            sender_participant_id: sender_participant_id.to_string(),
+            sequence,
+            payload: payload.to_vec(),
+        },
+    ))
+}
+
+/// Encode a keyframe request forwarded to a publisher.
+pub fn encode_keyframe_requested(stream: VideoStream) -> ServerMessage {
+    envelope(server_message::Message::KeyframeRequested(
+        KeyframeRequested {
+            stream_type: stream.stream_type() as i32,
+        },
+    ))
+}
+
+/// Encode a live stream status change.
+pub fn encode_live_stream_status(status: &LiveStreamStatus) -> ServerMessage {
+    let (state, playback_url, error_message) = match status {
+        LiveStreamStatus::Starting => (LiveStreamState::Starting, String::new(), String::new()),
+        LiveStreamStatus::Live { playback_url } => {
+            (LiveStreamState::Live, playback_url.clone(), String::new())
+        }
+        LiveStreamStatus::Stopped => (LiveStreamState::Stopped, String::new(), String::new()),
+        LiveStreamStatus::Failed { error_message } => (
+            LiveStreamState::Failed,
+            String::new(),
+            error_message.clone(),
+        ),
+    };
+    envelope(server_message::Message::LiveStreamStatus(
+        v1::LiveStreamStatus {
+            state: state as i32,
+            playback_url,
+            error_message,
+        },
+    ))
+}
+
+/// Encode a recording notice for one participant.
+pub fn encode_recording_notice(notice: &RecordingNotice) -> ServerMessage {
+    envelope(server_message::Message::RecordingStateChanged(
+        RecordingStateChanged {
+            recording: notice.recording,
+            started_at: notice.started_at_ms,
+            consent_required: notice.consent_required,
+            consent_deadline: notice.consent_deadline_ms,
+        },
+    ))
+}
+
+/// A post-join client request forwarded to the `MeetingActor`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientRequest {
+    /// Poll or Q&A request.
+    Interaction(InteractionRequest),
+    /// Application data channel request.
+    DataChannel(DataChannelRequest),
+    /// Live stream request.
+    LiveStream(LiveStreamRequest),
+    /// Recording request or consent answer.
+    Recording(RecordingRequest),
+    /// Keyframe request for another participant's video stream.
+    Keyframe(KeyframeRequest),
+}
+
+/// Decode a client message that the `MeetingActor` handles.
+///
+/// Returns `None` for all other message types.
+pub fn decode_client_request(message: client_message::Message) -> Option<ClientRequest> {
+    let request = match message {
+        client_message::Message::CreatePoll(msg) => {
+            ClientRequest::Interaction(InteractionRequest::CreatePoll {
+                question: msg.question,
+                options: msg.options,
+            })
+        }
+        client_message::Message::PollVote(msg) => {
+            ClientRequest::Interaction(InteractionRequest::Vote {
+                poll_id: msg.poll_id,
+                option_index: msg.option_index,
+            })
+        }
+        client_message::Message::PublishPollResults(msg) => {
+            ClientRequest::Interaction(InteractionRequest::PublishResults {
+                poll_id: msg.poll_id,
+            })
+        }
+        client_message::Message::AskQuestion(msg) => {
+            ClientRequest::Interaction(InteractionRequest::AskQuestion { text: msg.text })
+        }
+        client_message::Message::UpvoteQuestion(msg) => {
+            ClientRequest::Interaction(InteractionRequest::UpvoteQuestion {
+                question_id: msg.question_id,
+            })
+        }
+        client_message::Message::MarkQuestionAnswered(msg) => {
+            ClientRequest::Interaction(InteractionRequest::MarkAnswered {
+                question_id: msg.question_id,
+            })
+        }
+        client_message::Message::DataChannelSubscribe(msg) => {
+            ClientRequest::DataChannel(DataChannelRequest::Subscribe {
+                channel_id: msg.channel_id,
+            })
+        }
+        client_message::Message::DataChannelUnsubscribe(msg) => {
+            ClientRequest::DataChannel(DataChannelRequest::Unsubscribe {
+                channel_id: msg.channel_id,
+            })
+        }
+        client_message::Message::DataChannelSend(msg) => {
+            ClientRequest::DataChannel(DataChannelRequest::Send {
+                channel_id: msg.channel_id,
+                payload: msg.payload,
+            })
+        }
+        client_message::Message::StartLiveStream(msg) => {
+            let output = match msg.output? {
+                start_live_stream::Output::RtmpUrl(url) => LiveStreamOutput::Rtmp { url },
+                start_live_stream::Output::Hls(hls) => LiveStreamOutput::Hls {
+                    segment_duration_seconds: hls.segment_duration_seconds,
+                },
+            };
+            ClientRequest::LiveStream(LiveStreamRequest::Start {
+                tile_participant_ids: msg.tile_participant_ids,
+                output,
+            })
+        }
+        client_message::Message::StopLiveStream(_) => {
+            ClientRequest::LiveStream(LiveStreamRequest::Stop)
+        }
+        client_message::Message::StartRecording(_) => {
+            ClientRequest::Recording(RecordingRequest::Start)
+        }
+        client_message::Message::StopRecording(_) => {
+            ClientRequest::Recording(RecordingRequest::Stop)
+        }
+        client_message::Message::RecordingConsent(msg) => {
+            ClientRequest::Recording(RecordingRequest::Consent {
+                accepted: msg.accepted,
+            })
+        }
+        client_message::Message::RequestKeyframe(msg) => {
+            // Audio has no keyframes; validation has already rejected
+            // unknown stream types
+            let stream = VideoStream::from_stream_type(msg.stream_type())?;
+            ClientRequest::Keyframe(KeyframeRequest {
+                publisher_id: msg.participant_id,
+                stream,
+            })
+        }
+        _ => return None,
+    };
+    Some(request)
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
@@ -83,7 +348,7 @@ mod tests {
     use crate::actors::messages::{
         LeaveReason, ParticipantInfo, ParticipantStateUpdate, ParticipantStatus,
     };
-    use proto_gen::dark_tower::signaling::v1::{self, server_message};
+    use proto_gen::dark_tower::signaling::v1::{self, client_message, server_message};
 
     fn make_participant_info(id: &str, name: &str) -> ParticipantInfo {
         ParticipantInfo {
@@ -185,6 +450,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_encode_participant_left_replaced() {
+        let update = ParticipantStateUpdate::Left {
+            participant_id: "part-6".to_string(),
+            reason: LeaveReason::Replaced,
+        };
+
+        let msg = encode_participant_update(&update).unwrap();
+        match msg.message.unwrap() {
+            server_message::Message::ParticipantLeft(left) => {
+                assert_eq!(left.reason, v1::LeaveReason::Replaced as i32);
+            }
+            other => panic!("Expected ParticipantLeft, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_encode_mute_changed_returns_none() {
         let update = ParticipantStateUpdate::MuteChanged {
@@ -205,6 +486,320 @@ mod tests {
         assert!(encode_participant_update(&update).is_none());
     }
 
+    #[test]
+    fn test_encode_interaction_poll_created() {
+        let event = InteractionEvent::PollCreated {
+            poll_id: "poll-1".to_string(),
+            question: "Lunch?".to_string(),
+            options: vec!["Pizza".to_string(), "Tacos".to_string()],
+            closed: false,
+        };
+
+        match encode_interaction_event(&event).message.unwrap() {
+            server_message::Message::PollCreated(created) => {
+                let poll = created.poll.unwrap();
+                assert_eq!(poll.poll_id, "poll-1");
+                assert_eq!(poll.options, vec!["Pizza", "Tacos"]);
+                assert!(!poll.closed);
+            }
+            other => panic!("Expected PollCreated, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_encode_interaction_poll_results() {
+        let event = InteractionEvent::PollResults {
+            poll_id: "poll-1".to_string(),
+            vote_counts: vec![2, 1],
+            total_votes: 3,
+        };
+
+        match encode_interaction_event(&event).message.unwrap() {
+            server_message::Message::PollResults(results) => {
+                assert_eq!(results.vote_counts, vec![2, 1]);
+                assert_eq!(results.total_votes, 3);
+            }
+            other => panic!("Expected PollResults, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_encode_interaction_question_updated() {
+        let event = InteractionEvent::QuestionUpdated {
+            question_id: "q-1".to_string(),
+            text: "When?".to_string(),
+            asked_by: "part-1".to_string(),
+            upvotes: 4,
+            answered: true,
+        };
+
+        match encode_interaction_event(&event).message.unwrap() {
+            server_message::Message::QuestionUpdated(updated) => {
+                let question = updated.question.unwrap();
+                assert_eq!(question.asked_by, "part-1");
+                assert_eq!(question.upvotes, 4);
+                assert!(question.answered);
+            }
+            other => panic!("Expected QuestionUpdated, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_encode_error_message_is_client_safe() {
+        match encode_error_message(&McError::RateLimited).message.unwrap() {
+            server_message::Message::Error(err) => {
+                assert_eq!(err.code, v1::ErrorCode::RateLimited as i32);
+                assert_eq!(err.message, "Too many requests, please slow down");
+                assert_eq!(err.dt_code, "DT-MC-2013");
+            }
+            other => panic!("Expected Error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_encode_server_hello() {
+        let negotiated = NegotiatedProtocol {
+            version: 2,
+            capabilities: 0b110,
+        };
+        match encode_server_hello(&negotiated, 1).message.unwrap() {
+            server_message::Message::ServerHello(hello) => {
+                assert_eq!(hello.protocol_version, 2);
+                assert_eq!(hello.capabilities, 0b110);
+                assert_eq!(hello.min_protocol_version, 1);
+                assert_eq!(hello.max_protocol_version, PROTOCOL_VERSION);
+            }
+            other => panic!("Expected ServerHello, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_encode_keepalive() {
+        assert_eq!(
+            encode_ping(7).message,
+            Some(server_message::Message::Ping(Ping { sequence: 7 }))
+        );
+        assert_eq!(
+            encode_pong(7).message,
+            Some(server_message::Message::Pong(Pong { sequence: 7 }))
+        );
+    }
+
+    #[test]
+    fn test_encode_connection_closed() {
+        match encode_connection_closed(CloseReason::Takeover).message {
+            Some(server_message::Message::ConnectionClosed(closed)) => {
+                assert_eq!(closed.reason(), CloseReason::Takeover);
+            }
+            other => panic!("Expected ConnectionClosed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_decode_interaction_requests() {
+        let request = decode_client_request(client_message::Message::PollVote(v1::PollVote {
+            poll_id: "poll-1".to_string(),
+            option_index: 2,
+        }));
+        assert_eq!(
+            request,
+            Some(ClientRequest::Interaction(InteractionRequest::Vote {
+                poll_id: "poll-1".to_string(),
+                option_index: 2,
+            }))
+        );
+
+        let request = decode_client_request(client_message::Message::MarkQuestionAnswered(
+            v1::MarkQuestionAnswered {
+                question_id: "q-1".to_string(),
+            },
+        ));
+        assert_eq!(
+            request,
+            Some(ClientRequest::Interaction(
+                InteractionRequest::MarkAnswered {
+                    question_id: "q-1".to_string(),
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_decode_data_channel_requests() {
+        let request = decode_client_request(client_message::Message::DataChannelSend(
+            v1::DataChannelSend {
+                channel_id: "whiteboard".to_string(),
+                payload: vec![1, 2, 3],
+            },
+        ));
+        assert_eq!(
+            request,
+            Some(ClientRequest::DataChannel(DataChannelRequest::Send {
+                channel_id: "whiteboard".to_string(),
+                payload: vec![1, 2, 3],
+            }))
+        );
+
+        let request = decode_client_request(client_message::Message::DataChannelSubscribe(
+            v1::DataChannelSubscribe {
+                channel_id: "whiteboard".to_string(),
+            },
+        ));
+        assert_eq!(
+            request,
+            Some(ClientRequest::DataChannel(DataChannelRequest::Subscribe {
+                channel_id: "whiteboard".to_string(),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_decode_live_stream_requests() {
+        let request = decode_client_request(client_message::Message::StartLiveStream(
+            v1::StartLiveStream {
+                tile_participant_ids: vec!["part-1".to_string()],
+                output: Some(v1::start_live_stream::Output::Hls(v1::HlsOutputOptions {
+                    segment_duration_seconds: 6,
+                })),
+            },
+        ));
+        assert_eq!(
+            request,
+            Some(ClientRequest::LiveStream(LiveStreamRequest::Start {
+                tile_participant_ids: vec!["part-1".to_string()],
+                output: LiveStreamOutput::Hls {
+                    segment_duration_seconds: 6,
+                },
+            }))
+        );
+
+        // A start without an output is ignored
+        let request = decode_client_request(client_message::Message::StartLiveStream(
+            v1::StartLiveStream {
+                tile_participant_ids: Vec::new(),
+                output: None,
+            },
+        ));
+        assert!(request.is_none());
+
+        let request = decode_client_request(client_message::Message::StopLiveStream(
+            v1::StopLiveStream {},
+        ));
+        assert_eq!(
+            request,
+            Some(ClientRequest::LiveStream(LiveStreamRequest::Stop))
+        );
+    }
+
+    #[test]
+    fn test_encode_live_stream_status() {
+        let msg = encode_live_stream_status(&LiveStreamStatus::Live {
+            playback_url: "https://mh-1/hls/e-1/index.m3u8".to_string(),
+        });
+        match msg.message.unwrap() {
+            server_message::Message::LiveStreamStatus(status) => {
+                assert_eq!(status.state, v1::LiveStreamState::Live as i32);
+                assert_eq!(status.playback_url, "https://mh-1/hls/e-1/index.m3u8");
+                assert!(status.error_message.is_empty());
+            }
+            other => panic!("Expected LiveStreamStatus, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_keyframe_request_and_notice() {
+        let request = decode_client_request(client_message::Message::RequestKeyframe(
+            v1::RequestKeyframe {
+                participant_id: "part-1".to_string(),
+                stream_type: v1::StreamType::VideoScreen as i32,
+            },
+        ));
+        assert_eq!(
+            request,
+            Some(ClientRequest::Keyframe(KeyframeRequest {
+                publisher_id: "part-1".to_string(),
+                stream: VideoStream::Screen,
+            }))
+        );
+
+        // Audio has no keyframes
+        let request = decode_client_request(client_message::Message::RequestKeyframe(
+            v1::RequestKeyframe {
+                participant_id: "part-1".to_string(),
+                stream_type: v1::StreamType::Audio as i32,
+            },
+        ));
+        assert!(request.is_none());
+
+        match encode_keyframe_requested(VideoStream::Camera)
+            .message
+            .unwrap()
+        {
+            server_message::Message::KeyframeRequested(msg) => {
+                assert_eq!(msg.stream_type, v1::StreamType::VideoCamera as i32);
+            }
+            other => panic!("Expected KeyframeRequested, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_recording_requests_and_notice() {
+        let request = decode_client_request(client_message::Message::RecordingConsent(
+            v1::RecordingConsent { accepted: false },
+        ));
+        assert_eq!(
+            request,
+            Some(ClientRequest::Recording(RecordingRequest::Consent {
+                accepted: false
+            }))
+        );
+        let request = decode_client_request(client_message::Message::StartRecording(
+            v1::StartRecording {},
+        ));
+        assert_eq!(
+            request,
+            Some(ClientRequest::Recording(RecordingRequest::Start))
+        );
+
+        let msg = encode_recording_notice(&RecordingNotice {
+            recording: true,
+            started_at_ms: 1_700_000_000_000,
+            consent_required: true,
+            consent_deadline_ms: 1_700_000_060_000,
+        });
+        match msg.message.unwrap() {
+            server_message::Message::RecordingStateChanged(state) => {
+                assert!(state.recording && state.consent_required);
+                assert_eq!(state.consent_deadline, 1_700_000_060_000);
+            }
+            other => panic!("Expected RecordingStateChanged, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_decode_unhandled_returns_none() {
+        let request =
+            decode_client_request(client_message::Message::MuteRequest(v1::MuteRequest {
+                audio_muted: true,
+                video_muted: false,
+            }));
+        assert!(request.is_none());
+    }
+
+    #[test]
+    fn test_encode_data_channel_message() {
+        let msg = encode_data_channel_message("whiteboard", "part-1", 7, b"stroke");
+        match msg.message.unwrap() {
+            server_message::Message::DataChannelMessage(dc) => {
+                assert_eq!(dc.channel_id, "whiteboard");
+                assert_eq!(dc.sender_participant_id, "part-1");
+                assert_eq!(dc.sequence, 7);
+                assert_eq!(dc.payload, b"stroke");
+            }
+            other => panic!("Expected DataChannelMessage, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_encode_reconnected_returns_none() {
         let update = ParticipantStateUpdate::Reconnected {
@@ -0,0 +1,174 @@
+//! Application-level keepalive for signaling connections.
+//!
+//! QUIC notices a peer that vanishes, but not a client whose stream is
+//! stuck behind a wedged tab or a middlebox that keeps the path alive. For
+//! clients that negotiated `CAPABILITY_KEEPALIVE`, the bridge loop sends a
+//! `Ping` every [`KeepaliveConfig::interval`] and closes the connection when
+//! nothing at all has arrived from the client for
+//! [`KeepaliveConfig::idle_timeout`]. Closing goes through the normal
+//! disconnect path, so the participant gets the usual reconnect grace
+//! period before its state is released.
+//!
+//! Clients without the capability are never pinged or timed out here.
+
+use std::pin::Pin;
+use std::time::Duration;
+use tokio::time::{Instant, Interval, MissedTickBehavior, Sleep};
+
+/// Default seconds between pings (`MC_KEEPALIVE_INTERVAL_SECONDS`).
+pub const DEFAULT_KEEPALIVE_INTERVAL_SECONDS: u64 = 15;
+
+/// Default seconds of client silence before closing
+/// (`MC_IDLE_TIMEOUT_SECONDS`). Three missed pings.
+pub const DEFAULT_IDLE_TIMEOUT_SECONDS: u64 = 45;
+
+/// Keepalive timing for signaling connections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeepaliveConfig {
+    /// Time between pings.
+    pub interval: Duration,
+    /// Client silence after which the connection is closed.
+    pub idle_timeout: Duration,
+}
+
+impl KeepaliveConfig {
+    /// Build from the `MC_KEEPALIVE_INTERVAL_SECONDS` and
+    /// `MC_IDLE_TIMEOUT_SECONDS` config values.
+    #[must_use]
+    pub const fn from_secs(interval_seconds: u64, idle_timeout_seconds: u64) -> Self {
+        Self {
+            interval: Duration::from_secs(interval_seconds),
+            idle_timeout: Duration::from_secs(idle_timeout_seconds),
+        }
+    }
+}
+
+impl Default for KeepaliveConfig {
+    fn default() -> Self {
+        Self::from_secs(
+            DEFAULT_KEEPALIVE_INTERVAL_SECONDS,
+            DEFAULT_IDLE_TIMEOUT_SECONDS,
+        )
+    }
+}
+
+/// What the keepalive timers ask the bridge loop to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeepaliveEvent {
+    /// Send a `Ping` with this sequence number.
+    Ping(u64),
+    /// The client has been silent for the idle timeout.
+    IdleTimeout,
+}
+
+/// Per-connection keepalive timers.
+#[derive(Debug)]
+pub struct Keepalive {
+    config: KeepaliveConfig,
+    enabled: bool,
+    sequence: u64,
+    ping_timer: Interval,
+    idle_deadline: Pin<Box<Sleep>>,
+}
+
+impl Keepalive {
+    /// Start the timers. With `enabled` false (client did not negotiate
+    /// keepalive), [`Keepalive::next_event`] never completes.
+    #[must_use]
+    pub fn new(config: KeepaliveConfig, enabled: bool) -> Self {
+        let now = Instant::now();
+        let mut ping_timer = tokio::time::interval_at(now + config.interval, config.interval);
+        // A stalled write delays the next ping rather than bursting
+        ping_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Self {
+            config,
+            enabled,
+            sequence: 0,
+            ping_timer,
+            idle_deadline: Box::pin(tokio::time::sleep_until(now + config.idle_timeout)),
+        }
+    }
+
+    /// Whether this connection is pinged and timed out.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Note that something arrived from the client, pushing back the idle
+    /// deadline.
+    pub fn record_activity(&mut self) {
+        self.idle_deadline
+            .as_mut()
+            .reset(Instant::now() + self.config.idle_timeout);
+    }
+
+    /// Wait for the next ping or the idle timeout.
+    ///
+    /// Cancel-safe, so it can sit in a `tokio::select!` next to the stream
+    /// reads.
+    pub async fn next_event(&mut self) -> KeepaliveEvent {
+        if !self.enabled {
+            return std::future::pending().await;
+        }
+        tokio::select! {
+            _ = self.ping_timer.tick() => {
+                self.sequence += 1;
+                KeepaliveEvent::Ping(self.sequence)
+            }
+            () = self.idle_deadline.as_mut() => KeepaliveEvent::IdleTimeout,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn config() -> KeepaliveConfig {
+        KeepaliveConfig::from_secs(10, 25)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_pings_until_idle_timeout() {
+        let mut keepalive = Keepalive::new(config(), true);
+
+        assert_eq!(keepalive.next_event().await, KeepaliveEvent::Ping(1));
+        assert_eq!(keepalive.next_event().await, KeepaliveEvent::Ping(2));
+        // Nothing received for 25s
+        assert_eq!(keepalive.next_event().await, KeepaliveEvent::IdleTimeout);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_activity_pushes_back_idle_deadline() {
+        let mut keepalive = Keepalive::new(config(), true);
+
+        for sequence in 1..=5 {
+            assert_eq!(keepalive.next_event().await, KeepaliveEvent::Ping(sequence));
+            keepalive.record_activity();
+        }
+        let start = Instant::now();
+        keepalive.next_event().await;
+        keepalive.next_event().await;
+        assert_eq!(keepalive.next_event().await, KeepaliveEvent::IdleTimeout);
+        // Timed out 25s after the last activity, not 25s after connecting
+        assert_eq!(start.elapsed(), Duration::from_secs(25));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_disabled_keepalive_never_fires() {
+        let mut keepalive = Keepalive::new(config(), false);
+        assert!(!keepalive.is_enabled());
+
+        let event = tokio::time::timeout(Duration::from_secs(3600), keepalive.next_event()).await;
+        assert!(event.is_err());
+    }
+
+    #[test]
+    fn test_default_config() {
+        let config = KeepaliveConfig::default();
+        assert_eq!(config.interval, Duration::from_secs(15));
+        assert!(config.idle_timeout > config.interval);
+    }
+}
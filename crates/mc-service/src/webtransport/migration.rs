@@ -0,0 +1,45 @@
+//! QUIC connection migration for signaling connections.
+//!
+//! Both endpoints allow clients to migrate: when a mobile client moves from
+//! Wi-Fi to LTE, quinn validates the new path and carries on with the same
+//! connection, streams and session. The `ConnectionActor` never sees the
+//! change; [`track`] runs beside it to log and count each migration (see
+//! [`common::quic_path`]).
+
+use crate::observability::metrics;
+
+use common::quic_path::{PeerPath, PATH_CHECK_INTERVAL};
+use std::net::SocketAddr;
+use tokio::time::MissedTickBehavior;
+use tracing::info;
+
+/// Watch a connection's remote address and record each migration.
+///
+/// `remote_address` reads the connection's current validated peer address.
+/// Never returns; run it alongside the session and drop it when the session
+/// ends.
+pub async fn track(
+    remote_address: impl Fn() -> SocketAddr,
+    transport: &'static str,
+    connection_id: &str,
+) {
+    let mut path = PeerPath::new(remote_address());
+    let mut interval = tokio::time::interval(PATH_CHECK_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+        if let Some(migration) = path.observe(remote_address()) {
+            // Peer addresses are PII; log the kind only
+            info!(
+                target: "mc.webtransport.connection",
+                connection_id = %connection_id,
+                transport,
+                kind = migration.as_str(),
+                migrations = path.migrations(),
+                "Client connection migrated to a new path"
+            );
+            metrics::record_quic_path_migration(transport, migration.as_str());
+        }
+    }
+}
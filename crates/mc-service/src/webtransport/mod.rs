@@ -1,12 +1,26 @@
 //! WebTransport server and connection handler for client signaling.
 //!
 //! This module implements the client-facing WebTransport entry point:
+//! - [`capture`] - Opt-in recording of signaling frames for offline replay
 //! - [`server`] - Accept loop with TLS 1.3 termination via `wtransport`
 //! - [`connection`] - Per-connection actor: owns streams, sends JoinResponse, runs bridge loop
+//! - [`early_data`] - Holds replay-unsafe messages sent as 0-RTT data until the handshake completes
 //! - [`handler`] - Shared protobuf encoding utilities (encode_participant_update, etc.)
+//! - [`keepalive`] - Ping interval and idle timeout for keepalive-capable clients
+//! - [`migration`] - Logs and counts clients moving to a new network path mid-connection
+//! - [`protocol`] - `ClientHello`/`ServerHello` version and capability negotiation
+//! - [`raw_quic`] - Same signaling over raw QUIC streams for native clients (ALPN `dt-signaling/1`)
+//! - [`validation`] - Per-message-type size, length, and enum checks before dispatch
 
+pub mod capture;
 pub mod connection;
+pub mod early_data;
 pub mod handler;
+pub mod keepalive;
+pub mod migration;
+pub mod protocol;
+pub mod raw_quic;
 pub mod server;
+pub mod validation;
 
 pub use server::WebTransportServer;
@@ -0,0 +1,198 @@
+//! Signaling protocol version negotiation.
+//!
+//! A client may open the signaling stream with `ClientHello` carrying the
+//! highest protocol version it speaks and a capability bitmap. The MC picks
+//! `min(client, PROTOCOL_VERSION)`, intersects the capabilities with its own,
+//! and answers with `ServerHello` before the client sends `JoinRequest`.
+//!
+//! Clients that skip the hello and open with `JoinRequest` predate the
+//! handshake and are treated as [`LEGACY_PROTOCOL_VERSION`] with no
+//! capabilities. Either way, a version below the configured minimum
+//! (`MC_MIN_PROTOCOL_VERSION`) is rejected with `UNSUPPORTED_VERSION`.
+//!
+//! Version history:
+//! - 1: initial protocol, no handshake
+//! - 2: `ClientHello`/`ServerHello`
+
+use crate::errors::McError;
+
+use proto_gen::dark_tower::signaling::v1::{Capability, ClientHello};
+
+/// Newest protocol version this MC speaks.
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// Version assumed for clients that open with `JoinRequest`.
+pub const LEGACY_PROTOCOL_VERSION: u32 = 1;
+
+/// Default minimum version: accept every client, including legacy ones.
+pub const DEFAULT_MIN_PROTOCOL_VERSION: u32 = LEGACY_PROTOCOL_VERSION;
+
+/// Metric label per known version, indexed by version. Versions above
+/// [`PROTOCOL_VERSION`] are labelled "newer"; keep one entry per version.
+const VERSION_LABELS: [&str; PROTOCOL_VERSION as usize + 1] = ["0", "1", "2"];
+
+/// Capabilities this MC supports.
+const SERVER_CAPABILITIES: [Capability; 7] = [
+    Capability::SessionRecovery,
+    Capability::PollsAndQa,
+    Capability::DataChannels,
+    Capability::LiveStreaming,
+    Capability::Recording,
+    Capability::Keepalive,
+    Capability::KeyframeRequests,
+];
+
+/// Outcome of a successful negotiation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegotiatedProtocol {
+    /// Version both sides speak.
+    pub version: u32,
+    /// Capabilities both sides support (bitmap of `1 << Capability`).
+    pub capabilities: u64,
+}
+
+impl NegotiatedProtocol {
+    /// Protocol of a client that sent no `ClientHello`.
+    #[must_use]
+    pub const fn legacy() -> Self {
+        Self {
+            version: LEGACY_PROTOCOL_VERSION,
+            capabilities: 0,
+        }
+    }
+
+    /// Whether both sides support `capability`.
+    #[must_use]
+    pub fn supports(&self, capability: Capability) -> bool {
+        self.capabilities & capability_bit(capability) != 0
+    }
+}
+
+/// Bit of `capability` in a capability bitmap.
+#[must_use]
+pub fn capability_bit(capability: Capability) -> u64 {
+    1u64.checked_shl(capability as u32).unwrap_or(0)
+}
+
+/// Bitmap of every capability this MC supports.
+#[must_use]
+pub fn server_capabilities() -> u64 {
+    SERVER_CAPABILITIES
+        .iter()
+        .fold(0, |bits, &capability| bits | capability_bit(capability))
+}
+
+/// Negotiate the protocol for a client's `ClientHello`.
+///
+/// # Errors
+///
+/// Returns `McError::UnsupportedProtocolVersion` if the client's version is
+/// below `min_version`.
+pub fn negotiate(hello: &ClientHello, min_version: u32) -> Result<NegotiatedProtocol, McError> {
+    check_min_version(hello.protocol_version, min_version)?;
+    Ok(NegotiatedProtocol {
+        version: hello.protocol_version.min(PROTOCOL_VERSION),
+        capabilities: hello.capabilities & server_capabilities(),
+    })
+}
+
+/// Reject `client_version` if it is below `min_version`.
+///
+/// # Errors
+///
+/// Returns `McError::UnsupportedProtocolVersion` for a version below the
+/// minimum.
+pub fn check_min_version(client_version: u32, min_version: u32) -> Result<(), McError> {
+    if client_version < min_version {
+        return Err(McError::UnsupportedProtocolVersion {
+            client_version,
+            min_version,
+        });
+    }
+    Ok(())
+}
+
+/// Bounded metric label for a client protocol version.
+#[must_use]
+pub fn version_label(version: u32) -> &'static str {
+    usize::try_from(version)
+        .ok()
+        .and_then(|index| VERSION_LABELS.get(index).copied())
+        .unwrap_or("newer")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn hello(protocol_version: u32, capabilities: &[Capability]) -> ClientHello {
+        ClientHello {
+            protocol_version,
+            capabilities: capabilities
+                .iter()
+                .fold(0, |bits, &c| bits | capability_bit(c)),
+        }
+    }
+
+    #[test]
+    fn test_negotiates_lowest_common_version() {
+        let negotiated = negotiate(&hello(2, &[]), 1).unwrap();
+        assert_eq!(negotiated.version, 2);
+
+        // A newer client is talked down to what this MC speaks
+        let negotiated = negotiate(&hello(PROTOCOL_VERSION + 3, &[]), 1).unwrap();
+        assert_eq!(negotiated.version, PROTOCOL_VERSION);
+    }
+
+    #[test]
+    fn test_capabilities_are_intersected() {
+        let client = hello(2, &[Capability::DataChannels, Capability::Recording]);
+        // Unknown bits from a newer client are dropped
+        let client = ClientHello {
+            capabilities: client.capabilities | (1 << 40),
+            ..client
+        };
+
+        let negotiated = negotiate(&client, 1).unwrap();
+        assert!(negotiated.supports(Capability::DataChannels));
+        assert!(negotiated.supports(Capability::Recording));
+        assert!(!negotiated.supports(Capability::LiveStreaming));
+        assert_eq!(negotiated.capabilities & (1 << 40), 0);
+    }
+
+    #[test]
+    fn test_rejects_versions_below_minimum() {
+        let err = negotiate(&hello(1, &[]), 2).unwrap_err();
+        assert!(matches!(
+            err,
+            McError::UnsupportedProtocolVersion {
+                client_version: 1,
+                min_version: 2,
+            }
+        ));
+        assert_eq!(err.error_code(), 11);
+
+        // An unset version (0) never passes
+        assert!(negotiate(&hello(0, &[]), DEFAULT_MIN_PROTOCOL_VERSION).is_err());
+
+        assert!(check_min_version(LEGACY_PROTOCOL_VERSION, DEFAULT_MIN_PROTOCOL_VERSION).is_ok());
+        assert!(check_min_version(LEGACY_PROTOCOL_VERSION, 2).is_err());
+    }
+
+    #[test]
+    fn test_version_labels() {
+        assert_eq!(version_label(0), "0");
+        assert_eq!(version_label(LEGACY_PROTOCOL_VERSION), "1");
+        assert_eq!(version_label(PROTOCOL_VERSION), "2");
+        assert_eq!(version_label(PROTOCOL_VERSION + 1), "newer");
+        assert_eq!(version_label(u32::MAX), "newer");
+    }
+
+    #[test]
+    fn test_server_capabilities_exclude_unspecified() {
+        let bits = server_capabilities();
+        assert_eq!(bits & capability_bit(Capability::Unspecified), 0);
+        assert_eq!(bits.count_ones(), 6);
+    }
+}
@@ -0,0 +1,151 @@
+//! Signaling over raw QUIC streams, for native clients without WebTransport.
+//!
+//! Native mobile clients can skip the HTTP/3 and WebTransport layers and
+//! open a bidirectional QUIC stream directly. The stream carries the same
+//! length-prefixed `ClientMessage`/`ServerMessage` framing as WebTransport
+//! and runs through the same connection pipeline (see
+//! [`super::connection::handle_raw_quic_connection`]).
+//!
+//! Clients select this mode with the [`SIGNALING_ALPN`] protocol in the TLS
+//! handshake. The raw QUIC endpoint listens on its own address
+//! (`MC_RAW_QUIC_BIND_ADDRESS`) because `wtransport` owns the HTTP/3
+//! endpoint and only speaks `h3`; a connection that does not negotiate
+//! [`SIGNALING_ALPN`] is refused.
+//!
+//! The endpoint issues TLS session tickets so reconnecting clients can
+//! resume, and with `MC_ZERO_RTT_ENABLED` (the default) accepts 0-RTT data
+//! on resumption. Tickets live in this process's session cache and each is
+//! accepted for 0-RTT once, so a replayed flight is refused at the TLS
+//! layer; [`super::early_data`] additionally keeps replay-unsafe messages
+//! waiting for the handshake.
+
+use crate::errors::McError;
+use crate::webtransport::early_data::EarlyData;
+
+use quinn::crypto::rustls::{HandshakeData, QuicServerConfig};
+use quinn::rustls;
+use std::sync::Arc;
+use tracing::warn;
+use wtransport::Identity;
+
+/// ALPN protocol ID for length-prefixed protobuf signaling over raw QUIC.
+pub const SIGNALING_ALPN: &[u8] = b"dt-signaling/1";
+
+/// Resumable sessions kept per process. Sized for a full MC's reconnects;
+/// older sessions fall back to a full handshake.
+const SESSION_CACHE_SIZE: usize = 16 * 1024;
+
+/// Build the QUIC server config: TLS 1.3 with the MC certificate, offering
+/// only [`SIGNALING_ALPN`], resumable sessions, 0-RTT if `zero_rtt`, and
+/// client connection migration.
+///
+/// # Errors
+///
+/// Returns an error if the certificate or key cannot be loaded or rustls
+/// rejects them.
+pub async fn server_config(
+    tls_cert_path: &str,
+    tls_key_path: &str,
+    gso_enabled: bool,
+    zero_rtt: bool,
+) -> Result<quinn::ServerConfig, String> {
+    // Same PEM loading as the WebTransport endpoint
+    let identity = Identity::load_pemfiles(tls_cert_path, tls_key_path)
+        .await
+        .map_err(|e| format!("Failed to load TLS certificate: {e}"))?;
+
+    let cert_chain = identity
+        .certificate_chain()
+        .as_slice()
+        .iter()
+        .map(|cert| rustls::pki_types::CertificateDer::from(cert.der().to_vec()))
+        .collect();
+    let private_key =
+        rustls::pki_types::PrivateKeyDer::try_from(identity.private_key().secret_der().to_vec())
+            .map_err(|e| format!("Invalid TLS private key: {e}"))?;
+
+    let mut tls = rustls::ServerConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_protocol_versions(&[&rustls::version::TLS13])
+    .map_err(|e| format!("Invalid TLS configuration: {e}"))?
+    .with_no_client_auth()
+    .with_single_cert(cert_chain, private_key)
+    .map_err(|e| format!("Invalid TLS certificate: {e}"))?;
+    tls.alpn_protocols = vec![SIGNALING_ALPN.to_vec()];
+    tls.session_storage = rustls::server::ServerSessionMemoryCache::new(SESSION_CACHE_SIZE);
+    // QUIC carries early data in its own packets; rustls only takes 0 or
+    // u32::MAX here
+    tls.max_early_data_size = if zero_rtt { u32::MAX } else { 0 };
+
+    let crypto =
+        QuicServerConfig::try_from(tls).map_err(|e| format!("Invalid QUIC TLS config: {e}"))?;
+    let mut config = quinn::ServerConfig::with_crypto(Arc::new(crypto));
+    // Clients keep their session across network changes (see
+    // `super::migration`)
+    config.migration(true);
+    if !gso_enabled {
+        let mut transport = quinn::TransportConfig::default();
+        transport.enable_segmentation_offload(false);
+        config.transport_config(Arc::new(transport));
+    }
+    Ok(config)
+}
+
+/// Accept an incoming connection once it has negotiated [`SIGNALING_ALPN`].
+///
+/// Returns as soon as the client's first flight is processed, before the
+/// handshake completes, so 0-RTT data can be read at once; the returned
+/// [`EarlyData`] gates what may be acted on until then.
+///
+/// # Errors
+///
+/// Returns `McError::Internal` if the handshake fails or the client did not
+/// offer the signaling protocol.
+pub async fn accept(incoming: quinn::Incoming) -> Result<(quinn::Connection, EarlyData), McError> {
+    let handshake_failed = |e: quinn::ConnectionError| {
+        warn!(
+            target: "mc.raw_quic.connection",
+            error = %e,
+            "Raw QUIC handshake failed"
+        );
+        McError::Internal(format!("QUIC handshake failed: {e}"))
+    };
+    let mut connecting = incoming.accept().map_err(handshake_failed)?;
+    let handshake_data = connecting
+        .handshake_data()
+        .await
+        .map_err(handshake_failed)?;
+
+    // rustls accepts clients that send no ALPN at all; require it so other
+    // QUIC protocols can never be mistaken for signaling
+    let protocol = handshake_data
+        .downcast::<HandshakeData>()
+        .ok()
+        .and_then(|data| data.protocol);
+    if protocol.as_deref() != Some(SIGNALING_ALPN) {
+        warn!(
+            target: "mc.raw_quic.connection",
+            "Raw QUIC client did not negotiate the signaling ALPN"
+        );
+        if let Ok((connection, _)) = connecting.into_0rtt() {
+            connection.close(0u32.into(), b"unsupported protocol");
+        }
+        return Err(McError::Internal(
+            "Client did not negotiate signaling ALPN".to_string(),
+        ));
+    }
+
+    // Always possible for a server; whether the client actually sent 0-RTT
+    // data is only known once the handshake completes
+    match connecting.into_0rtt() {
+        Ok((connection, accepted)) => {
+            let early_data = EarlyData::pending(connection.clone(), accepted);
+            Ok((connection, early_data))
+        }
+        Err(connecting) => {
+            let connection = connecting.await.map_err(handshake_failed)?;
+            Ok((connection, EarlyData::none()))
+        }
+    }
+}
@@ -1,7 +1,11 @@
 //! WebTransport accept loop with TLS 1.3 termination.
 //!
-//! Binds a QUIC/HTTP3 endpoint using `wtransport`, accepts WebTransport sessions,
-//! and spawns per-connection handler tasks.
+//! Binds a QUIC/HTTP3 endpoint per bind address using `wtransport`, accepts
+//! WebTransport sessions from all of them, and spawns per-connection handler
+//! tasks. When a raw QUIC bind address is configured, the same loop also
+//! accepts signaling connections from native clients on plain `quinn`
+//! endpoints (see [`super::raw_quic`]), resuming with 0-RTT (see
+//! [`super::early_data`]); both share the connection limit.
 //!
 //! # Graceful Shutdown
 //!
@@ -15,18 +19,29 @@ use crate::grpc::MhRegistrationClient;
 use crate::observability::metrics;
 use crate::redis::MhAssignmentStore;
 
+use common::listen::{ipv6_only, parse_bind_addresses};
+use common::media_socket::{bind_media_socket, MediaSocketConfig};
+use common::media_token::MediaTokenKey;
+use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use tokio::task::JoinSet;
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
+use wtransport::config::QuicTransportConfig;
 use wtransport::endpoint::endpoint_side::Server;
+use wtransport::endpoint::IncomingSession;
 use wtransport::{Endpoint, Identity, ServerConfig};
 
-use super::connection;
+use super::connection::{self, SessionContext};
+use super::keepalive::KeepaliveConfig;
+use super::protocol::DEFAULT_MIN_PROTOCOL_VERSION;
+use super::raw_quic;
 
 /// WebTransport server that accepts client connections.
 pub struct WebTransportServer {
-    /// Bind address for the WebTransport endpoint.
+    /// Bind address list (comma-separated) for the WebTransport endpoints.
     bind_address: String,
     /// Path to TLS certificate (PEM).
     tls_cert_path: String,
@@ -48,6 +63,20 @@ pub struct WebTransportServer {
     max_connections: usize,
     /// Active connection count.
     active_connections: Arc<AtomicUsize>,
+    /// UDP socket tuning (DSCP, buffer sizes, GSO).
+    media_socket: MediaSocketConfig,
+    /// Oldest signaling protocol version accepted at handshake.
+    min_protocol_version: u32,
+    /// Ping interval and idle timeout for keepalive-capable clients.
+    keepalive: KeepaliveConfig,
+    /// Directory for per-connection session captures (`None` = off).
+    session_capture_dir: Option<PathBuf>,
+    /// Bind address list for raw QUIC signaling endpoints (`None` = off).
+    raw_quic_bind_address: Option<String>,
+    /// Accept 0-RTT data from resuming raw QUIC clients.
+    zero_rtt_enabled: bool,
+    /// Signs media tokens for `JoinResponse` (`None` = off).
+    media_token_key: Option<Arc<MediaTokenKey>>,
     /// Cancellation token for graceful shutdown.
     cancel_token: CancellationToken,
 }
@@ -84,21 +113,87 @@ impl WebTransportServer {
             mc_grpc_endpoint,
             max_connections,
             active_connections: Arc::new(AtomicUsize::new(0)),
+            media_socket: MediaSocketConfig::default(),
+            min_protocol_version: DEFAULT_MIN_PROTOCOL_VERSION,
+            keepalive: KeepaliveConfig::default(),
+            session_capture_dir: None,
+            raw_quic_bind_address: None,
+            zero_rtt_enabled: true,
+            media_token_key: None,
             cancel_token,
         }
     }
 
-    /// Load TLS identity and bind the QUIC/HTTP3 endpoint.
+    /// Apply socket tuning to the endpoint (defaults leave the OS settings).
+    #[must_use]
+    pub fn with_media_socket(mut self, media_socket: MediaSocketConfig) -> Self {
+        self.media_socket = media_socket;
+        self
+    }
+
+    /// Reject clients below this protocol version (default: accept all).
+    #[must_use]
+    pub fn with_min_protocol_version(mut self, min_protocol_version: u32) -> Self {
+        self.min_protocol_version = min_protocol_version;
+        self
+    }
+
+    /// Set the keepalive ping interval and idle timeout.
+    #[must_use]
+    pub fn with_keepalive(mut self, keepalive: KeepaliveConfig) -> Self {
+        self.keepalive = keepalive;
+        self
+    }
+
+    /// Capture every connection's signaling frames under `dir`
+    /// (see [`super::capture`]).
+    #[must_use]
+    pub fn with_session_capture_dir(mut self, dir: Option<PathBuf>) -> Self {
+        self.session_capture_dir = dir;
+        self
+    }
+
+    /// Also accept raw QUIC signaling connections on these addresses
+    /// (comma-separated; see [`super::raw_quic`]).
+    #[must_use]
+    pub fn with_raw_quic_bind_address(mut self, bind_address: Option<String>) -> Self {
+        self.raw_quic_bind_address = bind_address;
+        self
+    }
+
+    /// Accept or refuse 0-RTT data on raw QUIC resumption (default: accept;
+    /// see [`super::early_data`]).
+    #[must_use]
+    pub fn with_zero_rtt(mut self, enabled: bool) -> Self {
+        self.zero_rtt_enabled = enabled;
+        self
+    }
+
+    /// Mint a media token with this key in every `JoinResponse` (default:
+    /// off; see [`common::media_token`]).
+    #[must_use]
+    pub fn with_media_token_key(mut self, key: Option<Arc<MediaTokenKey>>) -> Self {
+        self.media_token_key = key;
+        self
+    }
+
+    /// Load TLS identity and bind a QUIC/HTTP3 endpoint per bind address.
+    ///
+    /// The bind address may list several addresses (comma-separated); an
+    /// IPv6 wildcard listens dual-stack unless an IPv4 address in the list
+    /// shares its port.
     ///
     /// Call this **before** spawning the accept loop so that TLS or bind
     /// failures are fail-fast (crash the process) rather than silent.
     ///
     /// # Errors
     ///
-    /// Returns an error if the TLS certificate cannot be loaded or the
-    /// endpoint fails to bind.
-    pub async fn bind(&self) -> Result<Endpoint<Server>, Box<dyn std::error::Error + Send + Sync>> {
-        let bind_addr: std::net::SocketAddr = self.bind_address.parse().map_err(|e| {
+    /// Returns an error if a bind address is invalid, the TLS certificate
+    /// cannot be loaded, or an endpoint fails to bind.
+    pub async fn bind(
+        &self,
+    ) -> Result<Vec<Endpoint<Server>>, Box<dyn std::error::Error + Send + Sync>> {
+        let bind_addrs = parse_bind_addresses(&self.bind_address).map_err(|e| {
             error!(
                 target: "mc.webtransport",
                 error = %e,
@@ -111,6 +206,27 @@ impl WebTransportServer {
             )
         })?;
 
+        let mut endpoints = Vec::with_capacity(bind_addrs.len());
+        for bind_addr in &bind_addrs {
+            let endpoint = self
+                .bind_endpoint(*bind_addr, ipv6_only(bind_addr, &bind_addrs))
+                .await?;
+            info!(
+                target: "mc.webtransport",
+                bind_address = %bind_addr,
+                "WebTransport endpoint bound successfully"
+            );
+            endpoints.push(endpoint);
+        }
+
+        Ok(endpoints)
+    }
+
+    async fn bind_endpoint(
+        &self,
+        bind_addr: SocketAddr,
+        ipv6_only: bool,
+    ) -> Result<Endpoint<Server>, Box<dyn std::error::Error + Send + Sync>> {
         let identity = Identity::load_pemfiles(&self.tls_cert_path, &self.tls_key_path)
             .await
             .map_err(|e| {
@@ -124,34 +240,181 @@ impl WebTransportServer {
                 format!("Failed to load TLS certificate: {e}")
             })?;
 
-        let config = ServerConfig::builder()
-            .with_bind_address(bind_addr)
-            .with_identity(identity)
-            .build();
+        let (socket, applied) = bind_media_socket(bind_addr, ipv6_only, &self.media_socket)
+            .map_err(|e| {
+                error!(
+                    target: "mc.webtransport",
+                    error = %e,
+                    addr = %bind_addr,
+                    "Failed to bind WebTransport socket"
+                );
+                format!("Failed to bind WebTransport socket {bind_addr}: {e}")
+            })?;
+
+        // GSO is used where the kernel supports it unless disabled by config
+        let gso_segments = if self.media_socket.gso_enabled {
+            quinn::udp::UdpSocketState::new((&socket).into())
+                .map_or(1, |state| state.max_gso_segments())
+        } else {
+            1
+        };
+        metrics::record_media_socket_options(&applied, gso_segments);
+        info!(
+            target: "mc.webtransport",
+            addr = %bind_addr,
+            dscp = applied.dscp,
+            send_buffer_bytes = applied.send_buffer_bytes,
+            recv_buffer_bytes = applied.recv_buffer_bytes,
+            gso_segments,
+            "WebTransport socket options applied"
+        );
 
-        let endpoint = Endpoint::server(config).map_err(|e| {
+        // Clients keep their session across network changes (see
+        // `super::migration`)
+        let builder = ServerConfig::builder().with_bind_socket(socket);
+        let config = if self.media_socket.gso_enabled {
+            builder
+                .with_identity(identity)
+                .allow_migration(true)
+                .build()
+        } else {
+            let mut transport = QuicTransportConfig::default();
+            transport.enable_segmentation_offload(false);
+            builder
+                .with_custom_transport(identity, transport)
+                .allow_migration(true)
+                .build()
+        };
+
+        Endpoint::server(config).map_err(|e| {
             error!(
                 target: "mc.webtransport",
                 error = %e,
+                addr = %bind_addr,
                 "Failed to create WebTransport endpoint"
             );
-            format!("Failed to create WebTransport endpoint: {e}")
+            format!("Failed to create WebTransport endpoint: {e}").into()
+        })
+    }
+
+    /// Bind a raw QUIC endpoint per raw QUIC bind address.
+    ///
+    /// Returns no endpoints when raw QUIC is not configured. Like
+    /// [`Self::bind()`], call this before spawning the accept loop so
+    /// failures stop startup.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a bind address is invalid, the TLS certificate
+    /// cannot be loaded, or an endpoint fails to bind.
+    pub async fn bind_raw_quic(
+        &self,
+    ) -> Result<Vec<quinn::Endpoint>, Box<dyn std::error::Error + Send + Sync>> {
+        let Some(bind_address) = &self.raw_quic_bind_address else {
+            return Ok(Vec::new());
+        };
+        let bind_addrs = parse_bind_addresses(bind_address).map_err(|e| {
+            error!(
+                target: "mc.webtransport",
+                error = %e,
+                addr = %bind_address,
+                "Invalid raw QUIC bind address"
+            );
+            format!("Invalid raw QUIC bind address '{bind_address}': {e}")
         })?;
 
-        info!(
-            target: "mc.webtransport",
-            bind_address = %self.bind_address,
-            "WebTransport endpoint bound successfully"
-        );
+        let server_config = raw_quic::server_config(
+            &self.tls_cert_path,
+            &self.tls_key_path,
+            self.media_socket.gso_enabled,
+            self.zero_rtt_enabled,
+        )
+        .await
+        .map_err(|e| {
+            error!(
+                target: "mc.webtransport",
+                error = %e,
+                cert_path = %self.tls_cert_path,
+                key_path = %self.tls_key_path,
+                "Failed to build raw QUIC TLS config"
+            );
+            e
+        })?;
+
+        let runtime = quinn::default_runtime().ok_or("No async runtime for raw QUIC endpoint")?;
+        let mut endpoints = Vec::with_capacity(bind_addrs.len());
+        for bind_addr in &bind_addrs {
+            let (socket, applied) = bind_media_socket(
+                *bind_addr,
+                ipv6_only(bind_addr, &bind_addrs),
+                &self.media_socket,
+            )
+            .map_err(|e| {
+                error!(
+                    target: "mc.webtransport",
+                    error = %e,
+                    addr = %bind_addr,
+                    "Failed to bind raw QUIC socket"
+                );
+                format!("Failed to bind raw QUIC socket {bind_addr}: {e}")
+            })?;
+            info!(
+                target: "mc.webtransport",
+                addr = %bind_addr,
+                dscp = applied.dscp,
+                "Raw QUIC socket options applied"
+            );
 
-        Ok(endpoint)
+            let endpoint = quinn::Endpoint::new(
+                quinn::EndpointConfig::default(),
+                Some(server_config.clone()),
+                socket,
+                Arc::clone(&runtime),
+            )
+            .map_err(|e| {
+                error!(
+                    target: "mc.webtransport",
+                    error = %e,
+                    addr = %bind_addr,
+                    "Failed to create raw QUIC endpoint"
+                );
+                format!("Failed to create raw QUIC endpoint: {e}")
+            })?;
+            info!(
+                target: "mc.webtransport",
+                bind_address = %bind_addr,
+                "Raw QUIC endpoint bound successfully"
+            );
+            endpoints.push(endpoint);
+        }
+
+        Ok(endpoints)
     }
 
     /// Run the accept loop until the cancellation token is triggered.
     ///
-    /// Individual connection errors do not stop the loop.
-    /// Call [`Self::bind()`] first to obtain the endpoint.
-    pub async fn accept_loop(&self, endpoint: Endpoint<Server>) {
+    /// Accepts from every endpoint returned by [`Self::bind()`]; the
+    /// connection limit is shared across them. Individual connection errors
+    /// do not stop the loop.
+    pub async fn accept_loop(&self, endpoints: Vec<Endpoint<Server>>) {
+        self.accept_loop_with_raw_quic(endpoints, Vec::new()).await;
+    }
+
+    /// [`Self::accept_loop()`], also accepting raw QUIC signaling
+    /// connections from the endpoints returned by [`Self::bind_raw_quic()`].
+    pub async fn accept_loop_with_raw_quic(
+        &self,
+        endpoints: Vec<Endpoint<Server>>,
+        raw_quic_endpoints: Vec<quinn::Endpoint>,
+    ) {
+        let mut accepts = JoinSet::new();
+        for endpoint in endpoints {
+            spawn_accept(&mut accepts, Arc::new(endpoint));
+        }
+        for endpoint in raw_quic_endpoints {
+            spawn_raw_quic_accept(&mut accepts, endpoint);
+        }
+
         loop {
             tokio::select! {
                 () = self.cancel_token.cancelled() => {
@@ -162,8 +425,31 @@ impl WebTransportServer {
                     break;
                 }
 
-                incoming = endpoint.accept() => {
-                    let incoming_session = incoming;
+                Some(accepted) = accepts.join_next() => {
+                    let accepted = match accepted {
+                        Ok(accepted) => accepted,
+                        Err(e) => {
+                            error!(
+                                target: "mc.webtransport",
+                                error = %e,
+                                "WebTransport accept task failed; endpoint dropped"
+                            );
+                            continue;
+                        }
+                    };
+                    let connection = match accepted {
+                        Accepted::WebTransport(endpoint, incoming_session) => {
+                            spawn_accept(&mut accepts, endpoint);
+                            Incoming::WebTransport(incoming_session)
+                        }
+                        Accepted::RawQuic(endpoint, Some(incoming)) => {
+                            spawn_raw_quic_accept(&mut accepts, endpoint);
+                            Incoming::RawQuic(incoming)
+                        }
+                        // Only happens once the endpoint is closed
+                        Accepted::RawQuic(_, None) => continue,
+                    };
+                    let record_connection = connection.metric_recorder();
 
                     // Capacity check: reject before allocating handler resources
                     let current = self.active_connections.load(Ordering::Relaxed);
@@ -174,39 +460,34 @@ impl WebTransportServer {
                             max = self.max_connections,
                             "Connection rejected: at capacity"
                         );
-                        // Drop incoming_session without accepting — client sees connection refused
-                        metrics::record_webtransport_connection("rejected");
+                        // Drop the connection without accepting — client sees connection refused
+                        connection.refuse();
+                        record_connection("rejected");
                         continue;
                     }
 
                     self.active_connections.fetch_add(1, Ordering::Relaxed);
-                    metrics::record_webtransport_connection("accepted");
+                    record_connection("accepted");
                     let active_connections = Arc::clone(&self.active_connections);
-                    let controller_handle = Arc::clone(&self.controller_handle);
-                    let jwt_validator = Arc::clone(&self.jwt_validator);
-                    let redis_client = Arc::clone(&self.redis_client);
-                    let mh_client = Arc::clone(&self.mh_client);
-                    let mc_id = self.mc_id.clone();
-                    let mc_grpc_endpoint = self.mc_grpc_endpoint.clone();
+                    let ctx = self.session_context();
                     let connection_token = self.cancel_token.child_token();
 
                     tokio::spawn(async move {
-                        let result = connection::handle_connection(
-                            incoming_session,
-                            controller_handle,
-                            jwt_validator,
-                            redis_client,
-                            mh_client,
-                            mc_id,
-                            mc_grpc_endpoint,
-                            connection_token,
-                        )
-                        .await;
+                        let result = match connection {
+                            Incoming::WebTransport(incoming_session) => {
+                                connection::handle_connection(incoming_session, ctx, connection_token)
+                                    .await
+                            }
+                            Incoming::RawQuic(incoming) => {
+                                connection::handle_raw_quic_connection(incoming, ctx, connection_token)
+                                    .await
+                            }
+                        };
 
                         active_connections.fetch_sub(1, Ordering::Relaxed);
 
                         if let Err(e) = result {
-                            metrics::record_webtransport_connection("error");
+                            record_connection("error");
                             warn!(
                                 target: "mc.webtransport",
                                 error = %e,
@@ -218,4 +499,69 @@ impl WebTransportServer {
             }
         }
     }
+
+    /// Dependencies for a new connection's handler.
+    fn session_context(&self) -> SessionContext {
+        SessionContext {
+            controller_handle: Arc::clone(&self.controller_handle),
+            jwt_validator: Arc::clone(&self.jwt_validator),
+            redis_client: Arc::clone(&self.redis_client),
+            mh_client: Arc::clone(&self.mh_client),
+            mc_id: self.mc_id.clone(),
+            mc_grpc_endpoint: self.mc_grpc_endpoint.clone(),
+            min_protocol_version: self.min_protocol_version,
+            keepalive_config: self.keepalive,
+            session_capture_dir: self.session_capture_dir.clone(),
+            media_token_key: self.media_token_key.clone(),
+        }
+    }
+}
+
+/// A connection waiting to be accepted or refused.
+enum Incoming {
+    WebTransport(IncomingSession),
+    RawQuic(quinn::Incoming),
+}
+
+impl Incoming {
+    /// The connections counter for this transport.
+    fn metric_recorder(&self) -> fn(&str) {
+        match self {
+            Self::WebTransport(_) => metrics::record_webtransport_connection,
+            Self::RawQuic(_) => metrics::record_raw_quic_connection,
+        }
+    }
+
+    /// Turn the connection away without a handshake.
+    fn refuse(self) {
+        match self {
+            // Dropping the session refuses it
+            Self::WebTransport(_) => {}
+            Self::RawQuic(incoming) => incoming.refuse(),
+        }
+    }
+}
+
+/// The next connection from one endpoint, with the endpoint to accept from
+/// again.
+enum Accepted {
+    WebTransport(Arc<Endpoint<Server>>, IncomingSession),
+    /// `None` once the endpoint is closed.
+    RawQuic(quinn::Endpoint, Option<quinn::Incoming>),
+}
+
+/// Wait for the next session on `endpoint` in the background.
+fn spawn_accept(accepts: &mut JoinSet<Accepted>, endpoint: Arc<Endpoint<Server>>) {
+    accepts.spawn(async move {
+        let incoming_session = endpoint.accept().await;
+        Accepted::WebTransport(endpoint, incoming_session)
+    });
+}
+
+/// Wait for the next raw QUIC connection on `endpoint` in the background.
+fn spawn_raw_quic_accept(accepts: &mut JoinSet<Accepted>, endpoint: quinn::Endpoint) {
+    accepts.spawn(async move {
+        let incoming = endpoint.accept().await;
+        Accepted::RawQuic(endpoint, incoming)
+    });
 }
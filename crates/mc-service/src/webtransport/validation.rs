@@ -0,0 +1,462 @@
+//! Schema validation for post-join client signaling messages.
+//!
+//! Every decoded `ClientMessage` passes through [`validate_client_message`]
+//! before the bridge loop dispatches it to the `MeetingActor`. The checks are
+//! purely structural and cheap:
+//!
+//! - the encoded size against a per-message-type limit (well under the 64KB
+//!   frame limit for everything except data channel payloads);
+//! - string and repeated-field lengths against the limits the actors enforce
+//!   (reusing their constants, so the two cannot drift apart);
+//! - enum fields against the values this build knows.
+//!
+//! Rejecting here keeps oversized or malformed requests out of actor mailboxes.
+//! The actors still apply their semantic checks (host-only, rate limits,
+//! minimum poll options, ...). Rejections are returned to the client as
+//! `INVALID_REQUEST` errors with code `DT-MC-2021` and counted in
+//! `mc_signaling_messages_rejected_total`.
+
+use crate::actors::data_channels::{MAX_CHANNEL_ID_LEN, MAX_DATA_CHANNEL_PAYLOAD};
+use crate::actors::interactions::{
+    MAX_POLL_OPTIONS, MAX_POLL_OPTION_LEN, MAX_POLL_QUESTION_LEN, MAX_QUESTION_LEN,
+};
+use crate::actors::live_stream::{MAX_LIVE_STREAM_TILES, MAX_RTMP_URL_LEN};
+use crate::errors::McError;
+
+use proto_gen::dark_tower::signaling::v1::{
+    client_message, start_live_stream, ClientMessage, ConnectionState, LayoutType, StreamType,
+};
+use thiserror::Error;
+
+/// Maximum length of an ID field (poll, question, participant, stream).
+pub const MAX_ID_LEN: usize = 128;
+
+/// Maximum length of free-text fields not covered by an actor limit
+/// (host mute reason, codec names, MH failure reasons).
+pub const MAX_TEXT_LEN: usize = 256;
+
+/// Maximum length of an MH URL in a `MediaConnectionUpdate`.
+pub const MAX_MH_URL_LEN: usize = 2048;
+
+/// Maximum MH statuses in one `MediaConnectionUpdate`.
+pub const MAX_MH_STATUSES: usize = 16;
+
+/// Maximum stream IDs in a layout subscription.
+pub const MAX_LAYOUT_STREAMS: usize = 64;
+
+/// Maximum `traceparent` length (W3C version 00 is 55 bytes).
+pub const MAX_TRACE_PARENT_LEN: usize = 128;
+
+/// Maximum `tracestate` length (W3C recommends propagating at least 512).
+pub const MAX_TRACE_STATE_LEN: usize = 512;
+
+/// Size limit for small control messages (votes, mute, recording, ...).
+const CONTROL_MESSAGE_MAX_BYTES: usize = 1024;
+
+/// Allowance for the envelope and non-payload fields of a message.
+const ENVELOPE_OVERHEAD_BYTES: usize = 1024;
+
+/// Why a client message was rejected.
+///
+/// The `Display` text is client-safe: it names fields and limits, never
+/// echoes values.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ValidationError {
+    /// The encoded message exceeds its type's size limit.
+    #[error("Message too large ({size} bytes, limit {limit})")]
+    TooLarge { size: usize, limit: usize },
+
+    /// A string or bytes field exceeds its length limit.
+    #[error("Field {field} exceeds {limit} bytes")]
+    FieldTooLong { field: &'static str, limit: usize },
+
+    /// A repeated field has too many entries.
+    #[error("Field {field} exceeds {limit} entries")]
+    TooManyItems { field: &'static str, limit: usize },
+
+    /// An enum field holds a value this server does not know.
+    #[error("Field {field} has an unknown value")]
+    InvalidEnum { field: &'static str },
+}
+
+impl ValidationError {
+    /// Bounded label for the rejection counter.
+    #[must_use]
+    pub const fn reason_label(&self) -> &'static str {
+        match self {
+            Self::TooLarge { .. } => "too_large",
+            Self::FieldTooLong { .. } => "field_too_long",
+            Self::TooManyItems { .. } => "too_many_items",
+            Self::InvalidEnum { .. } => "invalid_enum",
+        }
+    }
+}
+
+impl From<ValidationError> for McError {
+    fn from(error: ValidationError) -> Self {
+        McError::MessageRejected(error.to_string())
+    }
+}
+
+/// Bounded label naming a client message type (the `oneof` field name).
+#[must_use]
+pub fn message_type_label(message: &client_message::Message) -> &'static str {
+    use client_message::Message;
+    match message {
+        Message::JoinRequest(_) => "join_request",
+        Message::PublishStream(_) => "publish_stream",
+        Message::SubscribeToLayout(_) => "subscribe_to_layout",
+        Message::UpdateLayout(_) => "update_layout",
+        Message::UnsubscribeLayout(_) => "unsubscribe_layout",
+        Message::StreamQualityUpdate(_) => "stream_quality_update",
+        Message::MuteRequest(_) => "mute_request",
+        Message::HostMuteRequest(_) => "host_mute_request",
+        Message::UnmuteRequest(_) => "unmute_request",
+        Message::UnmuteResponse(_) => "unmute_response",
+        Message::MediaConnectionUpdate(_) => "media_connection_update",
+        Message::CreatePoll(_) => "create_poll",
+        Message::PollVote(_) => "poll_vote",
+        Message::PublishPollResults(_) => "publish_poll_results",
+        Message::AskQuestion(_) => "ask_question",
+        Message::UpvoteQuestion(_) => "upvote_question",
+        Message::MarkQuestionAnswered(_) => "mark_question_answered",
+        Message::DataChannelSubscribe(_) => "data_channel_subscribe",
+        Message::DataChannelUnsubscribe(_) => "data_channel_unsubscribe",
+        Message::DataChannelSend(_) => "data_channel_send",
+        Message::StartLiveStream(_) => "start_live_stream",
+        Message::StopLiveStream(_) => "stop_live_stream",
+        Message::StartRecording(_) => "start_recording",
+        Message::StopRecording(_) => "stop_recording",
+        Message::RecordingConsent(_) => "recording_consent",
+        Message::ClientHello(_) => "client_hello",
+        Message::Ping(_) => "ping",
+        Message::Pong(_) => "pong",
+        Message::RequestKeyframe(_) => "request_keyframe",
+    }
+}
+
+/// Maximum encoded size of a message of the given type.
+#[must_use]
+pub fn max_message_size(message: &client_message::Message) -> usize {
+    use client_message::Message;
+    match message {
+        // Carries a JWT
+        Message::JoinRequest(_) => 8 * 1024,
+        Message::DataChannelSend(_) => MAX_DATA_CHANNEL_PAYLOAD + ENVELOPE_OVERHEAD_BYTES,
+        Message::CreatePoll(_) => {
+            MAX_POLL_QUESTION_LEN + MAX_POLL_OPTIONS * MAX_POLL_OPTION_LEN + ENVELOPE_OVERHEAD_BYTES
+        }
+        Message::AskQuestion(_) => MAX_QUESTION_LEN + ENVELOPE_OVERHEAD_BYTES,
+        Message::StartLiveStream(_) => {
+            MAX_RTMP_URL_LEN + MAX_LIVE_STREAM_TILES * MAX_ID_LEN + ENVELOPE_OVERHEAD_BYTES
+        }
+        Message::MediaConnectionUpdate(_) => 8 * 1024,
+        Message::PublishStream(_) | Message::SubscribeToLayout(_) | Message::UpdateLayout(_) => {
+            4 * 1024
+        }
+        _ => CONTROL_MESSAGE_MAX_BYTES,
+    }
+}
+
+/// Validate a decoded client message whose encoding was `encoded_len` bytes.
+///
+/// # Errors
+///
+/// Returns the first [`ValidationError`] found.
+pub fn validate_client_message(
+    message: &ClientMessage,
+    encoded_len: usize,
+) -> Result<(), ValidationError> {
+    check_len("trace_parent", &message.trace_parent, MAX_TRACE_PARENT_LEN)?;
+    check_len("trace_state", &message.trace_state, MAX_TRACE_STATE_LEN)?;
+
+    let Some(inner) = &message.message else {
+        return Ok(());
+    };
+
+    let limit = max_message_size(inner);
+    if encoded_len > limit {
+        return Err(ValidationError::TooLarge {
+            size: encoded_len,
+            limit,
+        });
+    }
+
+    validate_fields(inner)
+}
+
+fn validate_fields(message: &client_message::Message) -> Result<(), ValidationError> {
+    use client_message::Message;
+    match message {
+        Message::PublishStream(msg) => {
+            check_len("stream_id", &msg.stream_id, MAX_ID_LEN)?;
+            check_enum::<StreamType>("stream_type", msg.stream_type)?;
+            if let Some(metadata) = &msg.metadata {
+                check_len("codec", &metadata.codec, MAX_TEXT_LEN)?;
+            }
+        }
+        Message::SubscribeToLayout(msg) => {
+            check_enum::<LayoutType>("layout_type", msg.layout_type)?;
+            check_count("stream_ids", msg.stream_ids.len(), MAX_LAYOUT_STREAMS)?;
+        }
+        Message::StreamQualityUpdate(msg) => {
+            check_len("stream_id", &msg.stream_id, MAX_ID_LEN)?;
+        }
+        Message::HostMuteRequest(msg) => {
+            check_len("participant_id", &msg.participant_id, MAX_ID_LEN)?;
+            check_len("reason", &msg.reason, MAX_TEXT_LEN)?;
+        }
+        Message::UnmuteResponse(msg) => {
+            check_len("participant_id", &msg.participant_id, MAX_ID_LEN)?;
+        }
+        Message::RequestKeyframe(msg) => {
+            check_len("participant_id", &msg.participant_id, MAX_ID_LEN)?;
+            check_enum::<StreamType>("stream_type", msg.stream_type)?;
+        }
+        Message::MediaConnectionUpdate(msg) => {
+            check_count("statuses", msg.statuses.len(), MAX_MH_STATUSES)?;
+            for status in &msg.statuses {
+                check_len("mh_url", &status.mh_url, MAX_MH_URL_LEN)?;
+                check_enum::<ConnectionState>("state", status.state)?;
+                if let Some(reason) = &status.failure_reason {
+                    check_len("failure_reason", reason, MAX_TEXT_LEN)?;
+                }
+                if let Some(code) = &status.failure_code {
+                    check_len("failure_code", code, MAX_TEXT_LEN)?;
+                }
+            }
+        }
+        Message::CreatePoll(msg) => {
+            check_len("question", &msg.question, MAX_POLL_QUESTION_LEN)?;
+            check_count("options", msg.options.len(), MAX_POLL_OPTIONS)?;
+            for option in &msg.options {
+                check_len("options", option, MAX_POLL_OPTION_LEN)?;
+            }
+        }
+        Message::PollVote(msg) => check_len("poll_id", &msg.poll_id, MAX_ID_LEN)?,
+        Message::PublishPollResults(msg) => check_len("poll_id", &msg.poll_id, MAX_ID_LEN)?,
+        Message::AskQuestion(msg) => check_len("text", &msg.text, MAX_QUESTION_LEN)?,
+        Message::UpvoteQuestion(msg) => check_len("question_id", &msg.question_id, MAX_ID_LEN)?,
+        Message::MarkQuestionAnswered(msg) => {
+            check_len("question_id", &msg.question_id, MAX_ID_LEN)?;
+        }
+        Message::DataChannelSubscribe(msg) => {
+            check_len("channel_id", &msg.channel_id, MAX_CHANNEL_ID_LEN)?;
+        }
+        Message::DataChannelUnsubscribe(msg) => {
+            check_len("channel_id", &msg.channel_id, MAX_CHANNEL_ID_LEN)?;
+        }
+        Message::DataChannelSend(msg) => {
+            check_len("channel_id", &msg.channel_id, MAX_CHANNEL_ID_LEN)?;
+            if msg.payload.len() > MAX_DATA_CHANNEL_PAYLOAD {
+                return Err(ValidationError::FieldTooLong {
+                    field: "payload",
+                    limit: MAX_DATA_CHANNEL_PAYLOAD,
+                });
+            }
+        }
+        Message::StartLiveStream(msg) => {
+            check_count(
+                "tile_participant_ids",
+                msg.tile_participant_ids.len(),
+                MAX_LIVE_STREAM_TILES,
+            )?;
+            for id in &msg.tile_participant_ids {
+                check_len("tile_participant_ids", id, MAX_ID_LEN)?;
+            }
+            if let Some(start_live_stream::Output::RtmpUrl(url)) = &msg.output {
+                check_len("rtmp_url", url, MAX_RTMP_URL_LEN)?;
+            }
+        }
+        // No free-form fields beyond the size limit
+        Message::JoinRequest(_)
+        | Message::UpdateLayout(_)
+        | Message::UnsubscribeLayout(_)
+        | Message::MuteRequest(_)
+        | Message::UnmuteRequest(_)
+        | Message::StopLiveStream(_)
+        | Message::StartRecording(_)
+        | Message::StopRecording(_)
+        | Message::RecordingConsent(_)
+        | Message::ClientHello(_)
+        | Message::Ping(_)
+        | Message::Pong(_) => {}
+    }
+    Ok(())
+}
+
+fn check_len(field: &'static str, value: &str, limit: usize) -> Result<(), ValidationError> {
+    if value.len() > limit {
+        return Err(ValidationError::FieldTooLong { field, limit });
+    }
+    Ok(())
+}
+
+fn check_count(field: &'static str, count: usize, limit: usize) -> Result<(), ValidationError> {
+    if count > limit {
+        return Err(ValidationError::TooManyItems { field, limit });
+    }
+    Ok(())
+}
+
+fn check_enum<E: TryFrom<i32>>(field: &'static str, value: i32) -> Result<(), ValidationError> {
+    E::try_from(value)
+        .map(|_| ())
+        .map_err(|_| ValidationError::InvalidEnum { field })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use prost::Message as _;
+    use proto_gen::dark_tower::signaling::v1::{
+        AskQuestion, CreatePoll, DataChannelSend, MediaConnectionUpdate, MhConnectionStatus,
+        PollVote, PublishStream,
+    };
+
+    fn envelope(message: client_message::Message) -> ClientMessage {
+        ClientMessage {
+            message: Some(message),
+            trace_parent: String::new(),
+            trace_state: String::new(),
+        }
+    }
+
+    fn validate(message: &ClientMessage) -> Result<(), ValidationError> {
+        validate_client_message(message, message.encoded_len())
+    }
+
+    #[test]
+    fn test_valid_messages_pass() {
+        let poll = envelope(client_message::Message::CreatePoll(CreatePoll {
+            question: "Lunch?".to_string(),
+            options: vec!["Pizza".to_string(), "Tacos".to_string()],
+        }));
+        assert_eq!(validate(&poll), Ok(()));
+
+        let send = envelope(client_message::Message::DataChannelSend(DataChannelSend {
+            channel_id: "cursors".to_string(),
+            payload: vec![0u8; MAX_DATA_CHANNEL_PAYLOAD],
+        }));
+        assert_eq!(validate(&send), Ok(()));
+
+        let empty = ClientMessage {
+            message: None,
+            trace_parent: String::new(),
+            trace_state: String::new(),
+        };
+        assert_eq!(validate(&empty), Ok(()));
+    }
+
+    #[test]
+    fn test_string_length_limits() {
+        let question = envelope(client_message::Message::AskQuestion(AskQuestion {
+            text: "q".repeat(MAX_QUESTION_LEN + 1),
+        }));
+        assert_eq!(
+            validate(&question),
+            Err(ValidationError::FieldTooLong {
+                field: "text",
+                limit: MAX_QUESTION_LEN,
+            })
+        );
+
+        let vote = envelope(client_message::Message::PollVote(PollVote {
+            poll_id: "p".repeat(MAX_ID_LEN + 1),
+            option_index: 0,
+        }));
+        assert_eq!(
+            validate(&vote).unwrap_err().reason_label(),
+            "field_too_long"
+        );
+    }
+
+    #[test]
+    fn test_too_many_poll_options() {
+        let poll = envelope(client_message::Message::CreatePoll(CreatePoll {
+            question: "Pick one".to_string(),
+            options: vec!["x".to_string(); MAX_POLL_OPTIONS + 1],
+        }));
+        assert_eq!(
+            validate(&poll),
+            Err(ValidationError::TooManyItems {
+                field: "options",
+                limit: MAX_POLL_OPTIONS,
+            })
+        );
+    }
+
+    #[test]
+    fn test_per_type_size_limit() {
+        // Small control messages get a small budget, whatever the content
+        let vote = envelope(client_message::Message::PollVote(PollVote {
+            poll_id: "poll-1".to_string(),
+            option_index: 0,
+        }));
+        let err = validate_client_message(&vote, CONTROL_MESSAGE_MAX_BYTES + 1).unwrap_err();
+        assert_eq!(err.reason_label(), "too_large");
+
+        // The same size is fine for a data channel payload
+        let send = envelope(client_message::Message::DataChannelSend(DataChannelSend {
+            channel_id: "c".to_string(),
+            payload: vec![1, 2, 3],
+        }));
+        assert_eq!(
+            validate_client_message(&send, CONTROL_MESSAGE_MAX_BYTES + 1),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_unknown_enum_values_rejected() {
+        let publish = envelope(client_message::Message::PublishStream(PublishStream {
+            stream_id: "s1".to_string(),
+            stream_type: 42,
+            metadata: None,
+        }));
+        assert_eq!(
+            validate(&publish),
+            Err(ValidationError::InvalidEnum {
+                field: "stream_type",
+            })
+        );
+
+        let update = envelope(client_message::Message::MediaConnectionUpdate(
+            MediaConnectionUpdate {
+                statuses: vec![MhConnectionStatus {
+                    mh_url: "https://mh-1.example.com".to_string(),
+                    state: 99,
+                    failure_reason: None,
+                    failure_code: None,
+                    observed_at: None,
+                }],
+            },
+        ));
+        assert_eq!(
+            validate(&update),
+            Err(ValidationError::InvalidEnum { field: "state" })
+        );
+    }
+
+    #[test]
+    fn test_trace_context_limits() {
+        let mut message = envelope(client_message::Message::StopRecording(Default::default()));
+        message.trace_state = "k=v,".repeat(MAX_TRACE_STATE_LEN);
+        assert_eq!(
+            validate(&message),
+            Err(ValidationError::FieldTooLong {
+                field: "trace_state",
+                limit: MAX_TRACE_STATE_LEN,
+            })
+        );
+    }
+
+    #[test]
+    fn test_rejection_maps_to_invalid_request() {
+        let error: McError = ValidationError::InvalidEnum { field: "state" }.into();
+        assert_eq!(error.error_code(), 1);
+        assert_eq!(error.error_type_label(), "message_rejected");
+        assert_eq!(error.client_message(), "Field state has an unknown value");
+    }
+}
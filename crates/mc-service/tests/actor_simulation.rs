@@ -0,0 +1,59 @@
+//! Seeded simulation of the actor system (`mc_test_utils::simulation`).
+//!
+//! Each run drives thousands of joins, leaves, drops, reconnects,
+//! migrations and faults through the real controller on virtual time and
+//! checks the actors against the model after every event. A failure names
+//! its seed; rerun that seed to reproduce it.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::time::Duration;
+
+use mc_test_utils::simulation::{Simulation, SimulationConfig, SimulationReport};
+
+async fn run(config: SimulationConfig) -> SimulationReport {
+    let report = Simulation::new(config).run().await;
+
+    // A run that never reached these paths proved nothing about them
+    assert!(report.resumed > 0, "no session resumed: {report:?}");
+    assert!(report.rejoined > 0, "no late reconnect: {report:?}");
+    assert!(report.expired > 0, "no grace period expired: {report:?}");
+    assert!(report.count("migrate") > 0, "no migration: {report:?}");
+    assert!(
+        report.count("end_meeting") > 0,
+        "no meeting ended: {report:?}"
+    );
+    report
+}
+
+#[tokio::test(start_paused = true)]
+async fn simulation_seed_1() {
+    run(SimulationConfig::with_seed(1)).await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn simulation_seed_42() {
+    run(SimulationConfig::with_seed(42)).await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn simulation_seed_20261016() {
+    run(SimulationConfig::with_seed(20_261_016)).await;
+}
+
+/// Crowded meetings, a short grace period and frequent faults.
+#[tokio::test(start_paused = true)]
+async fn simulation_under_heavy_faults() {
+    let report = run(SimulationConfig {
+        seed: 7,
+        steps: 3000,
+        meetings: 2,
+        users: 4,
+        disconnect_grace_period: Duration::from_secs(5),
+        fault_per_mille: 400,
+    })
+    .await;
+
+    assert!(report.count("forged_binding") > 0);
+    assert!(report.count("reconnect_over_live") > 0);
+}
@@ -49,7 +49,9 @@ use mc_service::actors::MeetingControllerActorHandle;
 use mc_service::auth::McJwtValidator;
 use mc_service::grpc::MhRegistrationClient;
 use mc_service::redis::MhAssignmentStore;
+use mc_service::webtransport::keepalive::KeepaliveConfig;
 use mc_service::webtransport::WebTransportServer;
+use mc_test_utils::crypto_fixtures::test_media_token_key;
 use tempfile::TempDir;
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
@@ -60,6 +62,12 @@ pub struct AcceptLoopRig {
     pub url: String,
     /// Bound local address of the accept endpoint.
     pub addr: SocketAddr,
+    /// Bound local address of the raw QUIC signaling endpoint, when started
+    /// with [`AcceptLoopRig::start_with_raw_quic`].
+    pub raw_quic_addr: Option<SocketAddr>,
+    /// DER of the self-signed server certificate, for clients that build
+    /// their own root store (raw QUIC).
+    pub cert_der: Vec<u8>,
     /// Controller handle shared with the accept loop — tests assert actor state via this.
     pub controller_handle: Arc<MeetingControllerActorHandle>,
     /// Cancellation token wired into the accept loop.
@@ -105,7 +113,114 @@ impl AcceptLoopRig {
         mc_grpc_endpoint: String,
         max_connections: usize,
     ) -> Self {
-        let (tempdir, cert_path, key_path) = Self::write_self_signed_pems();
+        Self::start_with_keepalive(
+            controller_handle,
+            jwt_validator,
+            mh_store,
+            mh_reg_client,
+            mc_id,
+            mc_grpc_endpoint,
+            max_connections,
+            KeepaliveConfig::default(),
+        )
+        .await
+    }
+
+    /// Start with explicit keepalive timing — used by idle-timeout tests,
+    /// which cannot wait out the production defaults.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn start_with_keepalive(
+        controller_handle: Arc<MeetingControllerActorHandle>,
+        jwt_validator: Arc<McJwtValidator>,
+        mh_store: Arc<dyn MhAssignmentStore>,
+        mh_reg_client: Arc<dyn MhRegistrationClient>,
+        mc_id: String,
+        mc_grpc_endpoint: String,
+        max_connections: usize,
+        keepalive: KeepaliveConfig,
+    ) -> Self {
+        Self::start_inner(
+            controller_handle,
+            jwt_validator,
+            mh_store,
+            mh_reg_client,
+            mc_id,
+            mc_grpc_endpoint,
+            max_connections,
+            keepalive,
+            None,
+            false,
+            true,
+        )
+        .await
+    }
+
+    /// Start with session capture into `capture_dir` — used by capture and
+    /// replay tests.
+    pub async fn start_with_session_capture(
+        controller_handle: Arc<MeetingControllerActorHandle>,
+        jwt_validator: Arc<McJwtValidator>,
+        mh_store: Arc<dyn MhAssignmentStore>,
+        mh_reg_client: Arc<dyn MhRegistrationClient>,
+        capture_dir: PathBuf,
+    ) -> Self {
+        Self::start_inner(
+            controller_handle,
+            jwt_validator,
+            mh_store,
+            mh_reg_client,
+            "mc-test".to_string(),
+            "http://mc-test:50052".to_string(),
+            32,
+            KeepaliveConfig::default(),
+            Some(capture_dir),
+            false,
+            true,
+        )
+        .await
+    }
+
+    /// Start with a raw QUIC signaling endpoint on `127.0.0.1:0` alongside
+    /// the WebTransport one, accepting 0-RTT if `zero_rtt` — used by raw
+    /// QUIC transport tests.
+    pub async fn start_with_raw_quic(
+        controller_handle: Arc<MeetingControllerActorHandle>,
+        jwt_validator: Arc<McJwtValidator>,
+        mh_store: Arc<dyn MhAssignmentStore>,
+        mh_reg_client: Arc<dyn MhRegistrationClient>,
+        zero_rtt: bool,
+    ) -> Self {
+        Self::start_inner(
+            controller_handle,
+            jwt_validator,
+            mh_store,
+            mh_reg_client,
+            "mc-test".to_string(),
+            "http://mc-test:50052".to_string(),
+            32,
+            KeepaliveConfig::default(),
+            None,
+            true,
+            zero_rtt,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn start_inner(
+        controller_handle: Arc<MeetingControllerActorHandle>,
+        jwt_validator: Arc<McJwtValidator>,
+        mh_store: Arc<dyn MhAssignmentStore>,
+        mh_reg_client: Arc<dyn MhRegistrationClient>,
+        mc_id: String,
+        mc_grpc_endpoint: String,
+        max_connections: usize,
+        keepalive: KeepaliveConfig,
+        session_capture_dir: Option<PathBuf>,
+        raw_quic: bool,
+        zero_rtt: bool,
+    ) -> Self {
+        let (tempdir, cert_path, key_path, cert_der) = Self::write_self_signed_pems();
 
         let cancel_token = CancellationToken::new();
         let server = WebTransportServer::new(
@@ -120,21 +235,40 @@ impl AcceptLoopRig {
             mc_grpc_endpoint,
             max_connections,
             cancel_token.clone(),
-        );
+        )
+        .with_keepalive(keepalive)
+        .with_session_capture_dir(session_capture_dir)
+        .with_raw_quic_bind_address(raw_quic.then(|| "127.0.0.1:0".to_string()))
+        .with_zero_rtt(zero_rtt)
+        .with_media_token_key(Some(Arc::new(test_media_token_key())));
 
-        // Byte-identical to `main.rs:376-388` — real `bind()` then real
-        // `accept_loop()` on the returned endpoint.
-        let endpoint = server
+        // Byte-identical to `main.rs:376-388` — real `bind()` and
+        // `bind_raw_quic()` then real `accept_loop_with_raw_quic()` on the
+        // returned endpoints.
+        let endpoints = server
             .bind()
             .await
             .expect("WebTransportServer::bind() failed in accept-loop rig");
-        let addr = endpoint
+        let addr = endpoints
+            .first()
+            .expect("bind() must return at least one endpoint")
             .local_addr()
             .expect("endpoint local_addr() must be available after bind()");
         let url = format!("https://127.0.0.1:{}", addr.port());
+        let raw_quic_endpoints = server
+            .bind_raw_quic()
+            .await
+            .expect("WebTransportServer::bind_raw_quic() failed in accept-loop rig");
+        let raw_quic_addr = raw_quic_endpoints.first().map(|endpoint| {
+            endpoint
+                .local_addr()
+                .expect("raw QUIC endpoint local_addr() must be available after bind")
+        });
 
         let accept_loop_handle = tokio::spawn(async move {
-            server.accept_loop(endpoint).await;
+            server
+                .accept_loop_with_raw_quic(endpoints, raw_quic_endpoints)
+                .await;
         });
 
         // Give the accept loop a moment to reach its `endpoint.accept()` await.
@@ -143,6 +277,8 @@ impl AcceptLoopRig {
         Self {
             url,
             addr,
+            raw_quic_addr,
+            cert_der,
             controller_handle,
             cancel_token,
             accept_loop_handle: Some(accept_loop_handle),
@@ -151,7 +287,7 @@ impl AcceptLoopRig {
     }
 
     /// Generate a self-signed Ed25519 cert (SAN `["localhost", "127.0.0.1"]`)
-    /// and write PEMs into a temp dir.
+    /// and write PEMs into a temp dir. Also returns the certificate DER.
     ///
     /// Ported from `crates/mh-service/tests/common/accept_loop_rig.rs`. Uses
     /// `rcgen` (dev-dep) because `wtransport::Identity::self_signed` returns
@@ -162,7 +298,7 @@ impl AcceptLoopRig {
     ///
     /// Consolidate to a shared test-utils crate after AC + GC backfills land
     /// in ADR-0032 Steps 4-5 (when three call sites exist).
-    fn write_self_signed_pems() -> (TempDir, String, String) {
+    fn write_self_signed_pems() -> (TempDir, String, String, Vec<u8>) {
         let cert = rcgen::generate_simple_self_signed(vec![
             "localhost".to_string(),
             "127.0.0.1".to_string(),
@@ -185,7 +321,7 @@ impl AcceptLoopRig {
             .expect("key path must be UTF-8")
             .to_string();
 
-        (tempdir, cert_path_s, key_path_s)
+        (tempdir, cert_path_s, key_path_s, cert.cert.der().to_vec())
     }
 }
 
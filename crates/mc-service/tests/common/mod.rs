@@ -21,13 +21,17 @@ use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use ::common::jwt::JwksClient;
-use ::common::secret::SecretBox;
-use mc_service::actors::{ActorMetrics, ControllerMetrics, MeetingControllerActorHandle};
+use mc_service::actors::recording::RecordingLayout;
+use mc_service::actors::{
+    ActorMetrics, ControllerMetrics, MeetingControllerActorHandle, MeetingSettings,
+};
 use mc_service::auth::McJwtValidator;
 use mc_service::errors::McError;
 use mc_service::grpc::MhRegistrationClient;
+use mc_service::ids::MeetingId;
 use mc_service::mh_connection_registry::MhConnectionRegistry;
 use mc_service::redis::{MhAssignmentData, MhAssignmentStore, MhEndpointInfo};
+use mc_test_utils::crypto_fixtures::test_binding_secret;
 use mc_test_utils::jwt_test::{mount_jwks_mock, TestKeypair};
 use tokio::sync::Notify;
 use wiremock::MockServer;
@@ -64,7 +68,7 @@ impl Default for MockMhAssignmentStore {
 impl MhAssignmentStore for MockMhAssignmentStore {
     fn get_mh_assignment<'a>(
         &'a self,
-        meeting_id: &'a str,
+        meeting_id: &'a MeetingId,
     ) -> Pin<
         Box<
             dyn std::future::Future<Output = Result<Option<MhAssignmentData>, McError>> + Send + 'a,
@@ -74,7 +78,7 @@ impl MhAssignmentStore for MockMhAssignmentStore {
             .data
             .lock()
             .expect("MockMhAssignmentStore mutex poisoned")
-            .get(meeting_id)
+            .get(meeting_id.as_str())
             .cloned();
         Box::pin(async move { Ok(result) })
     }
@@ -88,6 +92,8 @@ impl MhAssignmentStore for MockMhAssignmentStore {
 pub struct RegisterMeetingCall {
     pub mh_grpc_endpoint: String,
     pub meeting_id: String,
+    pub e2e_enabled: bool,
+    pub recording_layout: RecordingLayout,
     pub mc_id: String,
     pub mc_grpc_endpoint: String,
 }
@@ -167,6 +173,8 @@ impl MhRegistrationClient for MockMhRegistrationClient {
         &'a self,
         mh_grpc_endpoint: &'a str,
         meeting_id: &'a str,
+        e2e_enabled: bool,
+        recording_layout: RecordingLayout,
         mc_id: &'a str,
         mc_grpc_endpoint: &'a str,
     ) -> Pin<Box<dyn std::future::Future<Output = Result<(), McError>> + Send + 'a>> {
@@ -176,6 +184,8 @@ impl MhRegistrationClient for MockMhRegistrationClient {
             .push(RegisterMeetingCall {
                 mh_grpc_endpoint: mh_grpc_endpoint.to_string(),
                 meeting_id: meeting_id.to_string(),
+                e2e_enabled,
+                recording_layout,
                 mc_id: mc_id.to_string(),
                 mc_grpc_endpoint: mc_grpc_endpoint.to_string(),
             });
@@ -213,7 +223,7 @@ pub struct TestStackHandles {
 ///
 /// `keypair_label` is the only meaningful axis of variation across callers
 /// (test logs cite the label on JWT-validation paths). Everything else is
-/// fixed: 300s clock skew on the validator, zero-byte master secret,
+/// fixed: 300s clock skew on the validator, fixture binding secret,
 /// fresh `MhConnectionRegistry`, mc_id `"mc-test"`.
 pub async fn build_test_stack(keypair_label: &str) -> TestStackHandles {
     let mock_server = MockServer::start().await;
@@ -223,7 +233,7 @@ pub async fn build_test_stack(keypair_label: &str) -> TestStackHandles {
     let jwks_client = Arc::new(JwksClient::new(jwks_url).expect("JwksClient::new"));
     let jwt_validator = Arc::new(McJwtValidator::new(jwks_client, 300));
 
-    let master_secret = SecretBox::new(Box::new(vec![0u8; 32]));
+    let master_secret = test_binding_secret();
     let metrics = ActorMetrics::new();
     let controller_metrics = ControllerMetrics::new();
     let controller_handle = Arc::new(MeetingControllerActorHandle::new(
@@ -285,6 +295,27 @@ pub async fn seed_meeting_with_handlers(
         .expect("create_meeting");
 }
 
+/// Like [`seed_meeting_with_mh`], but the meeting enforces `settings`
+/// as if GC had sent them with the assignment.
+pub async fn seed_meeting_with_settings(
+    handles: &TestStackHandles,
+    meeting_id: &str,
+    settings: MeetingSettings,
+) {
+    handles.mh_store.insert(
+        meeting_id,
+        MhAssignmentData {
+            handlers: vec![mh_handler("mh-test-1")],
+            assigned_at: "2026-04-25T00:00:00Z".to_string(),
+        },
+    );
+    handles
+        .controller_handle
+        .create_meeting_with_settings(meeting_id.to_string(), settings)
+        .await
+        .expect("create_meeting_with_settings");
+}
+
 /// Seed an MH assignment for `meeting_id` with a single default handler
 /// (`mh-test-1`) and create the meeting on the controller actor.
 ///
@@ -0,0 +1,200 @@
+// Every `#[tokio::test]` in this file is pinned to `flavor = "current_thread"`:
+// the migration counter is emitted from the spawned connection handler, and
+// `MetricAssertion` only sees emissions on the test thread. See the header
+// of `webtransport_accept_loop_integration.rs`.
+//
+//! Resilience tests for QUIC connection migration on signaling connections.
+//!
+//! A raw QUIC client joins, then rebinds its endpoint to a new UDP socket,
+//! which is what a phone moving from Wi-Fi to LTE looks like to the MC: the
+//! same connection arriving from a new source address. Covers:
+//! - The server validates the new path and the session keeps working on
+//!   the same stream, with no new join
+//! - The migration is counted as
+//!   `mc_quic_path_migrations_total{transport="raw_quic", kind="rebinding"}`
+//! - A client that stays put is never counted
+
+#![allow(clippy::expect_used, clippy::unwrap_used, clippy::panic)]
+
+#[path = "common/mod.rs"]
+mod test_common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use ::common::observability::testing::MetricAssertion;
+use ::common::quic_path::PATH_CHECK_INTERVAL;
+use bytes::{BufMut, BytesMut};
+use mc_service::grpc::MhRegistrationClient;
+use mc_service::redis::MhAssignmentStore;
+use mc_service::webtransport::raw_quic::SIGNALING_ALPN;
+use mc_test_utils::jwt_test::make_meeting_claims;
+use prost::Message;
+use proto_gen::dark_tower::signaling::v1::{
+    client_message, server_message, ClientMessage, JoinRequest, Ping, ServerMessage,
+};
+use quinn::crypto::rustls::QuicClientConfig;
+use quinn::rustls;
+
+use test_common::accept_loop_rig::AcceptLoopRig;
+use test_common::{build_test_stack, seed_meeting_with_mh, TestStackHandles};
+
+async fn start_rig() -> (AcceptLoopRig, TestStackHandles) {
+    let stack = build_test_stack("mc-migration-test").await;
+    let rig = AcceptLoopRig::start_with_raw_quic(
+        Arc::clone(&stack.controller_handle),
+        Arc::clone(&stack.jwt_validator),
+        Arc::clone(&stack.mh_store) as Arc<dyn MhAssignmentStore>,
+        Arc::clone(&stack.mh_reg_client) as Arc<dyn MhRegistrationClient>,
+        true,
+    )
+    .await;
+    (rig, stack)
+}
+
+/// QUIC client endpoint trusting the rig's self-signed certificate.
+fn build_client(cert_der: &[u8]) -> quinn::Endpoint {
+    let mut roots = rustls::RootCertStore::empty();
+    roots
+        .add(rustls::pki_types::CertificateDer::from(cert_der.to_vec()))
+        .expect("add rig certificate to root store");
+    let mut tls = rustls::ClientConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_protocol_versions(&[&rustls::version::TLS13])
+    .expect("TLS 1.3 client config")
+    .with_root_certificates(roots)
+    .with_no_client_auth();
+    tls.alpn_protocols = vec![SIGNALING_ALPN.to_vec()];
+
+    let crypto = QuicClientConfig::try_from(tls).expect("QUIC client config");
+    let mut endpoint =
+        quinn::Endpoint::client("127.0.0.1:0".parse().unwrap()).expect("client endpoint");
+    endpoint.set_default_client_config(quinn::ClientConfig::new(Arc::new(crypto)));
+    endpoint
+}
+
+fn frame(message: client_message::Message) -> BytesMut {
+    let msg = ClientMessage {
+        message: Some(message),
+        trace_parent: String::new(),
+        trace_state: String::new(),
+    };
+    let encoded = msg.encode_to_vec();
+    let mut frame = BytesMut::with_capacity(4 + encoded.len());
+    frame.put_u32(encoded.len() as u32);
+    frame.put_slice(&encoded);
+    frame
+}
+
+/// Read one server message; `None` once the server has closed the stream.
+async fn read(stream: &mut quinn::RecvStream) -> Option<ServerMessage> {
+    let read = async {
+        let mut len_buf = [0u8; 4];
+        stream.read_exact(&mut len_buf).await.ok()?;
+        let mut buf = vec![0u8; u32::from_be_bytes(len_buf) as usize];
+        stream.read_exact(&mut buf).await.ok()?;
+        Some(ServerMessage::decode(buf.as_slice()).expect("decode ServerMessage"))
+    };
+    tokio::time::timeout(Duration::from_secs(5), read)
+        .await
+        .expect("Timeout waiting for server message")
+}
+
+/// Connect and join `meeting_id` over raw QUIC.
+async fn join(
+    client: &quinn::Endpoint,
+    rig: &AcceptLoopRig,
+    stack: &TestStackHandles,
+    meeting_id: &str,
+) -> (quinn::Connection, quinn::SendStream, quinn::RecvStream) {
+    let token = stack.keypair.sign_token(&make_meeting_claims(meeting_id));
+    let conn = client
+        .connect(
+            rig.raw_quic_addr
+                .expect("rig started without a raw QUIC endpoint"),
+            "localhost",
+        )
+        .expect("client connect")
+        .await
+        .expect("QUIC handshake");
+    let (mut send, mut recv) = conn.open_bi().await.expect("open_bi");
+    send.write_all(&frame(client_message::Message::JoinRequest(JoinRequest {
+        meeting_id: meeting_id.to_string(),
+        join_token: token,
+        participant_name: "MigrationTester".to_string(),
+        capabilities: None,
+        correlation_id: String::new(),
+        binding_token: String::new(),
+        join_id: String::new(),
+    })))
+    .await
+    .expect("write JoinRequest");
+    match read(&mut recv).await.map(|msg| msg.message) {
+        Some(Some(server_message::Message::JoinResponse(_))) => {}
+        other => panic!("expected JoinResponse, got {other:?}"),
+    }
+    (conn, send, recv)
+}
+
+/// Send a `Ping` and expect its `Pong` on the same stream.
+async fn ping(send: &mut quinn::SendStream, recv: &mut quinn::RecvStream, sequence: u64) {
+    send.write_all(&frame(client_message::Message::Ping(Ping { sequence })))
+        .await
+        .expect("write Ping");
+    match read(recv).await.map(|msg| msg.message) {
+        Some(Some(server_message::Message::Pong(pong))) => assert_eq!(pong.sequence, sequence),
+        other => panic!("expected Pong, got {other:?}"),
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn session_survives_client_address_change() {
+    let (rig, stack) = start_rig().await;
+    seed_meeting_with_mh(&stack, "meeting-migration").await;
+
+    let snap = MetricAssertion::snapshot();
+    let client = build_client(&rig.cert_der);
+    let (conn, mut send, mut recv) = join(&client, &rig, &stack, "meeting-migration").await;
+    let before = client.local_addr().expect("client local address");
+
+    // Simulated network switch: same connection, new source port
+    let socket = std::net::UdpSocket::bind("127.0.0.1:0").expect("bind new client socket");
+    client.rebind(socket).expect("rebind client endpoint");
+    assert_ne!(client.local_addr().expect("client local address"), before);
+
+    // The session carries on over the new path without a new join
+    ping(&mut send, &mut recv, 7).await;
+    assert!(conn.close_reason().is_none(), "connection must survive");
+
+    // Give the tracker a check past the migration
+    tokio::time::sleep(PATH_CHECK_INTERVAL * 2).await;
+    ping(&mut send, &mut recv, 8).await;
+
+    snap.counter("mc_quic_path_migrations_total")
+        .with_labels(&[("transport", "raw_quic"), ("kind", "rebinding")])
+        .assert_delta(1);
+    snap.counter("mc_quic_path_migrations_total")
+        .with_labels(&[("transport", "raw_quic"), ("kind", "address")])
+        .assert_delta(0);
+    snap.counter("mc_raw_quic_connections_total")
+        .with_labels(&[("status", "accepted")])
+        .assert_delta(1);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn stationary_client_records_no_migration() {
+    let (rig, stack) = start_rig().await;
+    seed_meeting_with_mh(&stack, "meeting-no-migration").await;
+
+    let snap = MetricAssertion::snapshot();
+    let client = build_client(&rig.cert_der);
+    let (_conn, mut send, mut recv) = join(&client, &rig, &stack, "meeting-no-migration").await;
+
+    tokio::time::sleep(PATH_CHECK_INTERVAL * 2).await;
+    ping(&mut send, &mut recv, 1).await;
+
+    snap.counter("mc_quic_path_migrations_total")
+        .with_labels(&[("transport", "raw_quic"), ("kind", "rebinding")])
+        .assert_delta(0);
+}
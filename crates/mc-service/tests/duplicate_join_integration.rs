@@ -0,0 +1,201 @@
+// Every `#[tokio::test]` in this file is pinned to `flavor = "current_thread"`:
+// the duplicate-join metric is emitted from the meeting actor, and
+// `MetricAssertion` only sees emissions on the test thread. See the header of
+// `webtransport_accept_loop_integration.rs`.
+//
+//! Component tests for duplicate joins through the real
+//! `WebTransportServer::accept_loop`. Both clients join with tokens for the
+//! same user.
+//!
+//! Covers:
+//! - `takeover`: the earlier stream gets `SESSION_REPLACED` and is closed
+//!   with `CLOSE_REASON_TAKEOVER`, counted as `mc_duplicate_joins_total{action="replaced"}`
+//! - `reject`: the second join gets `CONFLICT`, counted as
+//!   `mc_duplicate_joins_total{action="rejected"}`
+
+#![allow(clippy::expect_used, clippy::unwrap_used, clippy::panic)]
+
+#[path = "common/mod.rs"]
+mod test_common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use ::common::observability::testing::MetricAssertion;
+use bytes::{BufMut, BytesMut};
+use mc_service::actors::{DuplicateJoinPolicy, MeetingSettings};
+use mc_service::grpc::MhRegistrationClient;
+use mc_service::redis::MhAssignmentStore;
+use mc_test_utils::jwt_test::make_meeting_claims;
+use prost::Message;
+use proto_gen::dark_tower::signaling::v1::{
+    client_message, server_message, ClientMessage, CloseReason, ErrorCode, JoinRequest,
+    ServerMessage,
+};
+use wtransport::stream::{RecvStream, SendStream};
+use wtransport::{ClientConfig, Endpoint};
+
+use test_common::accept_loop_rig::AcceptLoopRig;
+use test_common::{build_test_stack, seed_meeting_with_settings};
+
+async fn start_rig(meeting_id: &str, policy: DuplicateJoinPolicy) -> (AcceptLoopRig, String) {
+    let stack = build_test_stack("mc-duplicate-join-test").await;
+    seed_meeting_with_settings(
+        &stack,
+        meeting_id,
+        MeetingSettings {
+            duplicate_join_policy: policy,
+            ..MeetingSettings::default()
+        },
+    )
+    .await;
+    let token = stack.keypair.sign_token(&make_meeting_claims(meeting_id));
+    let rig = AcceptLoopRig::start_with(
+        Arc::clone(&stack.controller_handle),
+        Arc::clone(&stack.jwt_validator),
+        Arc::clone(&stack.mh_store) as Arc<dyn MhAssignmentStore>,
+        Arc::clone(&stack.mh_reg_client) as Arc<dyn MhRegistrationClient>,
+        "mc-test".to_string(),
+        "http://mc-test:50052".to_string(),
+        8,
+    )
+    .await;
+    (rig, token)
+}
+
+/// Read one server message; `None` once the server has closed the stream.
+async fn read(stream: &mut RecvStream) -> Option<ServerMessage> {
+    let read = async {
+        let mut len_buf = [0u8; 4];
+        stream.read_exact(&mut len_buf).await.ok()?;
+        let mut buf = vec![0u8; u32::from_be_bytes(len_buf) as usize];
+        stream.read_exact(&mut buf).await.ok()?;
+        Some(ServerMessage::decode(buf.as_slice()).expect("decode ServerMessage"))
+    };
+    tokio::time::timeout(Duration::from_secs(5), read)
+        .await
+        .expect("Timeout waiting for server message")
+}
+
+/// Connect and send a `JoinRequest`, returning the first reply.
+async fn join(
+    url: &str,
+    meeting_id: &str,
+    token: String,
+) -> (
+    wtransport::Connection,
+    SendStream,
+    RecvStream,
+    server_message::Message,
+) {
+    let cfg = ClientConfig::builder()
+        .with_bind_default()
+        .with_no_cert_validation()
+        .build();
+    let client = Endpoint::client(cfg).expect("client endpoint");
+    let conn = client.connect(url).await.expect("client connect");
+    let (mut send_stream, mut recv_stream) = conn
+        .open_bi()
+        .await
+        .expect("open_bi")
+        .await
+        .expect("bi stream ready");
+
+    let msg = ClientMessage {
+        message: Some(client_message::Message::JoinRequest(JoinRequest {
+            meeting_id: meeting_id.to_string(),
+            join_token: token,
+            participant_name: "DuplicateTester".to_string(),
+            capabilities: None,
+            correlation_id: String::new(),
+            binding_token: String::new(),
+            join_id: String::new(),
+        })),
+        trace_parent: String::new(),
+        trace_state: String::new(),
+    };
+    let encoded = msg.encode_to_vec();
+    let mut frame = BytesMut::with_capacity(4 + encoded.len());
+    frame.put_u32(encoded.len() as u32);
+    frame.put_slice(&encoded);
+    send_stream.write_all(&frame).await.expect("write frame");
+
+    let reply = read(&mut recv_stream)
+        .await
+        .and_then(|m| m.message)
+        .expect("reply to JoinRequest");
+    (conn, send_stream, recv_stream, reply)
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn takeover_replaces_earlier_session() {
+    let meeting_id = "meeting-duplicate-takeover";
+    let (rig, token) = start_rig(meeting_id, DuplicateJoinPolicy::Takeover).await;
+    let snap = MetricAssertion::snapshot();
+
+    let (_first_conn, _first_send, mut first_recv, reply) =
+        join(&rig.url, meeting_id, token.clone()).await;
+    assert!(matches!(reply, server_message::Message::JoinResponse(_)));
+
+    let (_second_conn, _second_send, _second_recv, reply) = join(&rig.url, meeting_id, token).await;
+    let server_message::Message::JoinResponse(response) = reply else {
+        panic!("Expected JoinResponse, got {reply:?}");
+    };
+    assert!(response.existing_participants.is_empty());
+
+    // The earlier stream is told why, then closed
+    let mut replaced = false;
+    let mut close_reason = None;
+    while let Some(msg) = read(&mut first_recv).await {
+        match msg.message {
+            Some(server_message::Message::Error(err)) => {
+                assert_eq!(err.code, ErrorCode::SessionReplaced as i32);
+                assert_eq!(err.dt_code, "DT-MC-2023");
+                replaced = true;
+            }
+            Some(server_message::Message::ConnectionClosed(closed)) => {
+                close_reason = Some(closed.reason());
+            }
+            _ => {}
+        }
+    }
+    assert!(replaced, "expected SESSION_REPLACED before close");
+    assert_eq!(close_reason, Some(CloseReason::Takeover));
+
+    snap.counter("mc_duplicate_joins_total")
+        .with_labels(&[("action", "replaced")])
+        .assert_delta(1);
+
+    let meeting = rig
+        .controller_handle
+        .get_meeting(meeting_id.to_string())
+        .await
+        .unwrap();
+    assert_eq!(meeting.participant_count, 1);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn reject_refuses_second_session() {
+    let meeting_id = "meeting-duplicate-reject";
+    let (rig, token) = start_rig(meeting_id, DuplicateJoinPolicy::Reject).await;
+    let snap = MetricAssertion::snapshot();
+
+    let (_first_conn, _first_send, _first_recv, reply) =
+        join(&rig.url, meeting_id, token.clone()).await;
+    assert!(matches!(reply, server_message::Message::JoinResponse(_)));
+
+    let (_second_conn, _second_send, _second_recv, reply) = join(&rig.url, meeting_id, token).await;
+    match reply {
+        server_message::Message::Error(err) => {
+            assert_eq!(err.code, ErrorCode::Conflict as i32);
+        }
+        other => panic!("Expected Error, got {other:?}"),
+    }
+
+    snap.counter("mc_duplicate_joins_total")
+        .with_labels(&[("action", "rejected")])
+        .assert_delta(1);
+    snap.counter("mc_duplicate_joins_total")
+        .with_labels(&[("action", "replaced")])
+        .assert_delta(0);
+}
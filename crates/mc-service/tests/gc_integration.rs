@@ -1,7 +1,7 @@
 //! Integration tests for MC-GC communication.
 //!
-//! Tests the registration, heartbeat, and assignment flows between
-//! Meeting Controller and Global Controller.
+//! Tests the registration, heartbeat, assignment, and meeting end flows
+//! between Meeting Controller and Global Controller.
 
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 
@@ -10,26 +10,33 @@ use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
-use mc_service::actors::{ActorMetrics, ControllerMetrics, MeetingControllerActorHandle};
+use mc_service::actors::{
+    ActorMetrics, ControllerMetrics, MeetingActor, MeetingControllerActorHandle, MeetingServices,
+    OperatorDirective,
+};
 use mc_service::config::Config;
 use mc_service::errors::McError;
-use mc_service::grpc::GcClient;
+use mc_service::grpc::{GcClient, PushedAction, PushedDirective};
 use mc_service::mh_connection_registry::MhConnectionRegistry;
 
 use common::secret::{SecretBox, SecretString};
 use common::token_manager::TokenReceiver;
+use proto_gen::dark_tower::internal::v1::controller_directive::Action;
 use proto_gen::dark_tower::internal::v1::global_controller_service_server::{
     GlobalControllerService, GlobalControllerServiceServer,
 };
 use proto_gen::dark_tower::internal::v1::{
-    ComprehensiveHeartbeatRequest, ComprehensiveHeartbeatResponse, FastHeartbeatRequest,
-    FastHeartbeatResponse, HealthStatus, NotifyMeetingEndedRequest, NotifyMeetingEndedResponse,
-    RegisterMcRequest, RegisterMcResponse,
+    ComprehensiveHeartbeatRequest, ComprehensiveHeartbeatResponse, ControllerDirective,
+    DrainDirective, FastHeartbeatRequest, FastHeartbeatResponse, HealthStatus, HeartbeatDirective,
+    HeartbeatIntervalsDirective, NotifyMeetingEndedRequest, NotifyMeetingEndedResponse,
+    RegisterMcRequest, RegisterMcResponse, ReportAttendanceRequest, ReportAttendanceResponse,
+    StreamHeartbeatRequest, StreamHeartbeatResponse,
 };
 use tokio::sync::{mpsc, watch};
+use tokio_stream::wrappers::ReceiverStream;
 use tokio_util::sync::CancellationToken;
 use tonic::transport::Server;
-use tonic::{Request, Response, Status};
+use tonic::{Request, Response, Status, Streaming};
 
 // ============================================================================
 // Mock GC Server
@@ -46,6 +53,10 @@ enum MockBehavior {
     NotFound,
     /// Return NOT_FOUND for first heartbeat, then accept (simulates re-registration).
     NotFoundThenAccept,
+    /// Accept, with a DRAIN directive on every stream heartbeat ack.
+    Drain,
+    /// Accept, but answer UNIMPLEMENTED for the heartbeat stream (GC that predates it).
+    NoStream,
 }
 
 /// Mock GC server for testing MC registration and heartbeats.
@@ -68,6 +79,12 @@ struct MockGcServer {
     fast_heartbeat_tx: Option<mpsc::Sender<FastHeartbeatRequest>>,
     /// Channel to notify when comprehensive heartbeat received.
     comprehensive_heartbeat_tx: Option<mpsc::Sender<ComprehensiveHeartbeatRequest>>,
+    /// Channel to notify when a stream heartbeat received.
+    stream_heartbeat_tx: Option<mpsc::Sender<StreamHeartbeatRequest>>,
+    /// Channel to notify when a meeting end notification received.
+    meeting_ended_tx: Option<mpsc::Sender<NotifyMeetingEndedRequest>>,
+    /// Operator directives to push in the first stream heartbeat ack.
+    directives: Vec<ControllerDirective>,
 }
 
 impl MockGcServer {
@@ -82,6 +99,9 @@ impl MockGcServer {
             registration_tx: None,
             fast_heartbeat_tx: None,
             comprehensive_heartbeat_tx: None,
+            stream_heartbeat_tx: None,
+            meeting_ended_tx: None,
+            directives: Vec::new(),
         }
     }
 
@@ -118,6 +138,21 @@ impl MockGcServer {
         self
     }
 
+    fn with_stream_heartbeat_channel(mut self, tx: mpsc::Sender<StreamHeartbeatRequest>) -> Self {
+        self.stream_heartbeat_tx = Some(tx);
+        self
+    }
+
+    fn with_meeting_ended_channel(mut self, tx: mpsc::Sender<NotifyMeetingEndedRequest>) -> Self {
+        self.meeting_ended_tx = Some(tx);
+        self
+    }
+
+    fn with_directives(mut self, directives: Vec<ControllerDirective>) -> Self {
+        self.directives = directives;
+        self
+    }
+
     fn with_heartbeat_intervals(mut self, fast_ms: u64, comprehensive_ms: u64) -> Self {
         self.fast_heartbeat_interval_ms = fast_ms;
         self.comprehensive_heartbeat_interval_ms = comprehensive_ms;
@@ -139,14 +174,16 @@ impl GlobalControllerService for MockGcServer {
         }
 
         match self.behavior {
-            MockBehavior::Accept | MockBehavior::NotFound | MockBehavior::NotFoundThenAccept => {
-                Ok(Response::new(RegisterMcResponse {
-                    accepted: true,
-                    message: "Registration accepted".to_string(),
-                    fast_heartbeat_interval_ms: self.fast_heartbeat_interval_ms,
-                    comprehensive_heartbeat_interval_ms: self.comprehensive_heartbeat_interval_ms,
-                }))
-            }
+            MockBehavior::Accept
+            | MockBehavior::NotFound
+            | MockBehavior::NotFoundThenAccept
+            | MockBehavior::Drain
+            | MockBehavior::NoStream => Ok(Response::new(RegisterMcResponse {
+                accepted: true,
+                message: "Registration accepted".to_string(),
+                fast_heartbeat_interval_ms: self.fast_heartbeat_interval_ms,
+                comprehensive_heartbeat_interval_ms: self.comprehensive_heartbeat_interval_ms,
+            })),
             MockBehavior::Reject => Ok(Response::new(RegisterMcResponse {
                 accepted: false,
                 message: "Registration rejected by mock".to_string(),
@@ -183,12 +220,13 @@ impl GlobalControllerService for MockGcServer {
                     }))
                 }
             }
-            MockBehavior::Accept | MockBehavior::Reject => {
-                Ok(Response::new(FastHeartbeatResponse {
-                    acknowledged: true,
-                    timestamp: chrono::Utc::now().timestamp() as u64,
-                }))
-            }
+            MockBehavior::Accept
+            | MockBehavior::Reject
+            | MockBehavior::Drain
+            | MockBehavior::NoStream => Ok(Response::new(FastHeartbeatResponse {
+                acknowledged: true,
+                timestamp: chrono::Utc::now().timestamp() as u64,
+            })),
         }
     }
 
@@ -221,23 +259,79 @@ impl GlobalControllerService for MockGcServer {
                     }))
                 }
             }
-            MockBehavior::Accept | MockBehavior::Reject => {
-                Ok(Response::new(ComprehensiveHeartbeatResponse {
-                    acknowledged: true,
+            MockBehavior::Accept
+            | MockBehavior::Reject
+            | MockBehavior::Drain
+            | MockBehavior::NoStream => Ok(Response::new(ComprehensiveHeartbeatResponse {
+                acknowledged: true,
+                timestamp: chrono::Utc::now().timestamp() as u64,
+            })),
+        }
+    }
+
+    type StreamHeartbeatStream = ReceiverStream<Result<StreamHeartbeatResponse, Status>>;
+
+    async fn stream_heartbeat(
+        &self,
+        request: Request<Streaming<StreamHeartbeatRequest>>,
+    ) -> Result<Response<Self::StreamHeartbeatStream>, Status> {
+        let directive = match self.behavior {
+            MockBehavior::NoStream => return Err(Status::unimplemented("StreamHeartbeat")),
+            MockBehavior::NotFound => HeartbeatDirective::Reregister,
+            MockBehavior::Drain => HeartbeatDirective::Drain,
+            MockBehavior::Accept | MockBehavior::Reject | MockBehavior::NotFoundThenAccept => {
+                HeartbeatDirective::Unspecified
+            }
+        };
+
+        let mut inbound = request.into_inner();
+        let heartbeat_tx = self.stream_heartbeat_tx.clone();
+        let mut directives = self.directives.clone();
+        let (tx, rx) = mpsc::channel(4);
+        tokio::spawn(async move {
+            while let Ok(Some(heartbeat)) = inbound.message().await {
+                let ack = StreamHeartbeatResponse {
+                    sequence: heartbeat.sequence,
                     timestamp: chrono::Utc::now().timestamp() as u64,
-                }))
+                    directive: directive.into(),
+                    // Each directive is sent once
+                    directives: std::mem::take(&mut directives),
+                    standby_meeting_ids: Vec::new(),
+                };
+                if let Some(heartbeat_tx) = &heartbeat_tx {
+                    let _ = heartbeat_tx.send(heartbeat).await;
+                }
+                if tx.send(Ok(ack)).await.is_err() {
+                    break;
+                }
             }
-        }
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
     }
 
     async fn notify_meeting_ended(
         &self,
-        _request: Request<NotifyMeetingEndedRequest>,
+        request: Request<NotifyMeetingEndedRequest>,
     ) -> Result<Response<NotifyMeetingEndedResponse>, Status> {
+        if let Some(tx) = &self.meeting_ended_tx {
+            let _ = tx.send(request.into_inner()).await;
+        }
         Ok(Response::new(NotifyMeetingEndedResponse {
             acknowledged: true,
         }))
     }
+
+    async fn report_attendance(
+        &self,
+        request: Request<ReportAttendanceRequest>,
+    ) -> Result<Response<ReportAttendanceResponse>, Status> {
+        let records_stored = request.into_inner().records.len() as u32;
+        Ok(Response::new(ReportAttendanceResponse {
+            acknowledged: true,
+            records_stored,
+        }))
+    }
 }
 
 // ============================================================================
@@ -247,6 +341,8 @@ impl GlobalControllerService for MockGcServer {
 fn test_config(gc_url: &str) -> Config {
     Config {
         mc_id: "mc-test-001".to_string(),
+        standby_for: None,
+        pool: None,
         region: "us-east-1".to_string(),
         webtransport_bind_address: "0.0.0.0:4433".to_string(),
         grpc_bind_address: "0.0.0.0:50052".to_string(),
@@ -259,7 +355,17 @@ fn test_config(gc_url: &str) -> Config {
         clock_skew_seconds: 5,
         nonce_grace_window_seconds: 5,
         disconnect_grace_period_seconds: 30,
+        min_protocol_version: 1,
+        keepalive_interval_seconds: 15,
+        idle_timeout_seconds: 45,
+        session_capture_dir: None,
+        raw_quic_bind_address: None,
+        zero_rtt_enabled: true,
+        redis_dual_write_schema_version: None,
+        meeting_journal_max_len: None,
+        state_check_interval_seconds: 0,
         binding_token_secret: SecretString::from("dGVzdC1zZWNyZXQ="),
+        media_token_secret: None,
         ac_endpoint: "https://ac.example.com".to_string(),
         client_id: "mc-service".to_string(),
         client_secret: SecretString::from("test-client-secret"),
@@ -268,6 +374,8 @@ fn test_config(gc_url: &str) -> Config {
         tls_key_path: "/dev/null".to_string(),
         grpc_advertise_address: "http://localhost:50052".to_string(),
         webtransport_advertise_address: "https://localhost:4433".to_string(),
+        health_socket: None,
+        media_socket: common::media_socket::MediaSocketConfig::default(),
     }
 }
 
@@ -383,6 +491,10 @@ async fn test_gc_client_registration_content() {
         config.webtransport_advertise_address
     );
 
+    let build = request.build.unwrap();
+    assert_eq!(build.version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(build.proto_schema, proto_gen::SCHEMA_VERSION);
+
     cancel_token.cancel();
 }
 
@@ -502,6 +614,212 @@ async fn test_gc_client_heartbeat_skipped_when_not_registered() {
     cancel_token.cancel();
 }
 
+// ============================================================================
+// Heartbeat Stream Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_gc_client_stream_heartbeat() {
+    let (heartbeat_tx, mut heartbeat_rx) = mpsc::channel(2);
+    let mock_gc = MockGcServer::accepting().with_stream_heartbeat_channel(heartbeat_tx);
+    let (addr, cancel_token) = start_mock_gc_server(mock_gc).await;
+
+    let gc_url = format!("http://{}", addr);
+    let config = test_config(&gc_url);
+    let token_rx = mock_token_receiver();
+
+    let gc_client = GcClient::new(gc_url, token_rx, config.clone())
+        .await
+        .unwrap();
+    gc_client.register().await.unwrap();
+
+    let mut stream = gc_client
+        .open_heartbeat_stream()
+        .await
+        .unwrap()
+        .expect("mock GC supports the heartbeat stream");
+
+    for (meetings, participants) in [(5, 50), (6, 60)] {
+        gc_client
+            .stream_fast_heartbeat(&mut stream, meetings, participants, HealthStatus::Healthy)
+            .await
+            .unwrap();
+        let ack = gc_client.heartbeat_ack(&mut stream).await.unwrap();
+        assert_eq!(ack.directive, HeartbeatDirective::Unspecified);
+        assert!(ack.pushed.is_empty());
+    }
+
+    let first = heartbeat_rx.recv().await.unwrap();
+    let second = heartbeat_rx.recv().await.unwrap();
+    assert_eq!(first.controller_id, config.mc_id);
+    assert_eq!(first.capacity.unwrap().current_meetings, 5);
+    assert_eq!((first.sequence, second.sequence), (0, 1));
+    assert_eq!(second.capacity.unwrap().current_participants, 60);
+
+    cancel_token.cancel();
+}
+
+#[tokio::test]
+async fn test_gc_client_stream_heartbeat_drain_directive() {
+    let mock_gc = MockGcServer::new_with_behavior(MockBehavior::Drain);
+    let (addr, cancel_token) = start_mock_gc_server(mock_gc).await;
+
+    let gc_url = format!("http://{}", addr);
+    let config = test_config(&gc_url);
+    let gc_client = GcClient::new(gc_url, mock_token_receiver(), config)
+        .await
+        .unwrap();
+    gc_client.register().await.unwrap();
+
+    let mut stream = gc_client.open_heartbeat_stream().await.unwrap().unwrap();
+    gc_client
+        .stream_fast_heartbeat(&mut stream, 1, 10, HealthStatus::Healthy)
+        .await
+        .unwrap();
+
+    let ack = gc_client.heartbeat_ack(&mut stream).await.unwrap();
+    assert_eq!(ack.directive, HeartbeatDirective::Drain);
+    assert!(gc_client.is_registered());
+
+    cancel_token.cancel();
+}
+
+#[tokio::test]
+async fn test_gc_client_stream_heartbeat_pushes_operator_directives() {
+    let mock_gc = MockGcServer::accepting().with_directives(vec![
+        ControllerDirective {
+            directive_id: "d-1".to_string(),
+            action: Some(Action::HeartbeatIntervals(HeartbeatIntervalsDirective {
+                fast_heartbeat_interval_ms: 2_000,
+                comprehensive_heartbeat_interval_ms: 0,
+            })),
+        },
+        ControllerDirective {
+            directive_id: "d-2".to_string(),
+            action: Some(Action::Drain(DrainDirective { draining: true })),
+        },
+        // Unknown to this MC
+        ControllerDirective {
+            directive_id: "d-3".to_string(),
+            action: None,
+        },
+    ]);
+    let (addr, cancel_token) = start_mock_gc_server(mock_gc).await;
+
+    let gc_url = format!("http://{}", addr);
+    let config = test_config(&gc_url);
+    let gc_client = GcClient::new(gc_url, mock_token_receiver(), config)
+        .await
+        .unwrap();
+    gc_client.register().await.unwrap();
+
+    let mut stream = gc_client.open_heartbeat_stream().await.unwrap().unwrap();
+    gc_client
+        .stream_fast_heartbeat(&mut stream, 1, 10, HealthStatus::Healthy)
+        .await
+        .unwrap();
+    let ack = gc_client.heartbeat_ack(&mut stream).await.unwrap();
+    assert_eq!(
+        ack.pushed,
+        vec![
+            PushedDirective {
+                directive_id: "d-1".to_string(),
+                action: PushedAction::HeartbeatIntervals {
+                    fast: Some(Duration::from_secs(2)),
+                    comprehensive: None,
+                },
+            },
+            PushedDirective {
+                directive_id: "d-2".to_string(),
+                action: PushedAction::Controller(OperatorDirective::Drain { draining: true }),
+            },
+        ]
+    );
+
+    // Not repeated on the next ack
+    gc_client
+        .stream_fast_heartbeat(&mut stream, 1, 10, HealthStatus::Healthy)
+        .await
+        .unwrap();
+    assert!(gc_client
+        .heartbeat_ack(&mut stream)
+        .await
+        .unwrap()
+        .pushed
+        .is_empty());
+
+    // The interval override is what the heartbeat loop reads
+    gc_client.set_heartbeat_intervals(Some(Duration::from_secs(2)), None);
+    assert_eq!(gc_client.fast_heartbeat_interval_ms(), 2_000);
+    assert_eq!(gc_client.comprehensive_heartbeat_interval_ms(), 30_000);
+
+    cancel_token.cancel();
+}
+
+#[tokio::test]
+async fn test_gc_client_stream_heartbeat_reregister_directive() {
+    let mock_gc = MockGcServer::new_with_behavior(MockBehavior::NotFound);
+    let (addr, cancel_token) = start_mock_gc_server(mock_gc).await;
+
+    let gc_url = format!("http://{}", addr);
+    let config = test_config(&gc_url);
+    let gc_client = GcClient::new(gc_url, mock_token_receiver(), config)
+        .await
+        .unwrap();
+    gc_client.register().await.unwrap();
+
+    let mut stream = gc_client.open_heartbeat_stream().await.unwrap().unwrap();
+    gc_client
+        .stream_fast_heartbeat(&mut stream, 1, 10, HealthStatus::Healthy)
+        .await
+        .unwrap();
+
+    let result = gc_client.heartbeat_ack(&mut stream).await;
+    assert!(matches!(result, Err(McError::NotRegistered)));
+    assert!(!gc_client.is_registered());
+
+    // Re-registration does not need a new stream
+    gc_client.attempt_reregistration().await.unwrap();
+    gc_client
+        .stream_fast_heartbeat(&mut stream, 1, 10, HealthStatus::Healthy)
+        .await
+        .unwrap();
+    assert!(matches!(
+        gc_client.heartbeat_ack(&mut stream).await,
+        Err(McError::NotRegistered)
+    ));
+
+    cancel_token.cancel();
+}
+
+#[tokio::test]
+async fn test_gc_client_stream_unsupported_falls_back_to_unary() {
+    let (heartbeat_tx, mut heartbeat_rx) = mpsc::channel(1);
+    let mock_gc = MockGcServer::new_with_behavior(MockBehavior::NoStream)
+        .with_fast_heartbeat_channel(heartbeat_tx);
+    let (addr, cancel_token) = start_mock_gc_server(mock_gc).await;
+
+    let gc_url = format!("http://{}", addr);
+    let config = test_config(&gc_url);
+    let gc_client = GcClient::new(gc_url, mock_token_receiver(), config)
+        .await
+        .unwrap();
+    gc_client.register().await.unwrap();
+
+    assert!(gc_client.open_heartbeat_stream().await.unwrap().is_none());
+    // Not retried until the next re-registration
+    assert!(gc_client.open_heartbeat_stream().await.unwrap().is_none());
+
+    gc_client
+        .fast_heartbeat(5, 50, HealthStatus::Healthy)
+        .await
+        .unwrap();
+    let request = heartbeat_rx.recv().await.unwrap();
+    assert_eq!(request.capacity.unwrap().current_meetings, 5);
+
+    cancel_token.cancel();
+}
+
 #[tokio::test]
 async fn test_gc_client_heartbeat_intervals_from_gc() {
     let mock_gc = MockGcServer::accepting().with_heartbeat_intervals(5000, 15000);
@@ -565,6 +883,87 @@ async fn test_controller_metrics_concurrent_updates() {
     assert_eq!(metrics.participants(), 200);
 }
 
+// ============================================================================
+// Meeting End Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_ended_meeting_reports_telemetry_to_gc() {
+    let (meeting_ended_tx, mut meeting_ended_rx) = mpsc::channel(1);
+    let mock_gc = MockGcServer::accepting().with_meeting_ended_channel(meeting_ended_tx);
+    let (addr, cancel_token) = start_mock_gc_server(mock_gc).await;
+
+    let gc_url = format!("http://{}", addr);
+    let config = test_config(&gc_url);
+    let token_rx = mock_token_receiver();
+
+    let gc_client = GcClient::new(gc_url, token_rx, config.clone())
+        .await
+        .unwrap();
+    gc_client.register().await.unwrap();
+
+    let (attendance_tx, mut attendance_rx) = mpsc::channel(4);
+    let (meeting, _task) = MeetingActor::spawn_with_services(
+        "meeting-telemetry".to_string(),
+        CancellationToken::new(),
+        ActorMetrics::new(),
+        ControllerMetrics::new(),
+        SecretBox::new(Box::new(vec![0u8; 32])),
+        MeetingServices {
+            attendance_tx: Some(attendance_tx),
+            ..Default::default()
+        },
+    );
+
+    for (conn, user, part) in [
+        ("conn-1", "user-1", "part-1"),
+        ("conn-2", "user-2", "part-2"),
+    ] {
+        meeting
+            .connection_join(
+                conn.to_string(),
+                user.to_string(),
+                part.to_string(),
+                false,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+    // Refused: the participant is already in the meeting
+    assert!(meeting
+        .connection_join(
+            "conn-3".to_string(),
+            "user-2".to_string(),
+            "part-2".to_string(),
+            false,
+            None,
+        )
+        .await
+        .is_err());
+    meeting.end_meeting("host ended".to_string()).await.unwrap();
+
+    // Same path as the GC task: the flushed report goes to GC
+    let report = tokio::time::timeout(Duration::from_secs(5), attendance_rx.recv())
+        .await
+        .expect("meeting should flush its report")
+        .unwrap();
+    gc_client.report_meeting(report).await;
+
+    let request = tokio::time::timeout(Duration::from_secs(5), meeting_ended_rx.recv())
+        .await
+        .expect("GC should be told the meeting ended")
+        .unwrap();
+    assert_eq!(request.meeting_id, "meeting-telemetry");
+    assert_eq!(request.region, config.region);
+    let telemetry = request.telemetry.unwrap();
+    assert_eq!(telemetry.peak_participants, 2);
+    assert_eq!(telemetry.join_failures, 1);
+    assert_eq!(telemetry.avg_qoe_score, None);
+
+    cancel_token.cancel();
+}
+
 // ============================================================================
 // Actor Handle Tests
 // ============================================================================
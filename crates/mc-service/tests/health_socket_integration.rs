@@ -0,0 +1,104 @@
+//! Integration tests for serving the health router on a Unix domain socket.
+//!
+//! Mirrors the `MC_HEALTH_SOCKET_PATH` branch of `main.rs`: the real
+//! `health_router` is served through `common::listen::serve_unix`, and
+//! probes are sent the way a sidecar would (raw HTTP/1.1 over the socket).
+
+#![cfg(unix)]
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::sync::Arc;
+
+use common::listen::{serve_unix, UnixSocketConfig};
+use mc_service::observability::{health_router, HealthState};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::UnixStream;
+use tokio_util::sync::CancellationToken;
+
+/// Send `GET {uri}` over the socket and return the status line.
+async fn probe(path: &Path, uri: &str) -> String {
+    let mut stream = UnixStream::connect(path).await.unwrap();
+    stream
+        .write_all(
+            format!("GET {uri} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+                .as_bytes(),
+        )
+        .await
+        .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response.lines().next().unwrap_or_default().to_string()
+}
+
+#[tokio::test]
+async fn test_health_router_served_on_unix_socket() {
+    let dir = tempfile::tempdir().unwrap();
+    let socket = UnixSocketConfig {
+        path: dir.path().join("health.sock"),
+        mode: 0o660,
+    };
+    let health_state = Arc::new(HealthState::new());
+    let shutdown = CancellationToken::new();
+
+    let server = serve_unix(
+        &socket,
+        health_router(Arc::clone(&health_state)),
+        shutdown.clone().cancelled_owned(),
+    )
+    .unwrap();
+    let server = tokio::spawn(server);
+
+    let mode = std::fs::metadata(&socket.path)
+        .unwrap()
+        .permissions()
+        .mode();
+    assert_eq!(mode & 0o777, 0o660);
+
+    assert_eq!(probe(&socket.path, "/health").await, "HTTP/1.1 200 OK");
+    assert_eq!(
+        probe(&socket.path, "/ready").await,
+        "HTTP/1.1 503 Service Unavailable"
+    );
+    health_state.set_ready();
+    assert_eq!(probe(&socket.path, "/ready").await, "HTTP/1.1 200 OK");
+
+    shutdown.cancel();
+    server.await.unwrap();
+    assert!(!socket.path.exists(), "socket file should be removed");
+}
+
+#[tokio::test]
+async fn test_second_server_cannot_take_over_live_socket() {
+    let dir = tempfile::tempdir().unwrap();
+    let socket = UnixSocketConfig {
+        path: dir.path().join("health.sock"),
+        mode: 0o660,
+    };
+    let shutdown = CancellationToken::new();
+
+    let server = serve_unix(
+        &socket,
+        health_router(Arc::new(HealthState::new())),
+        shutdown.clone().cancelled_owned(),
+    )
+    .unwrap();
+    let server = tokio::spawn(server);
+
+    let second = serve_unix(
+        &socket,
+        health_router(Arc::new(HealthState::new())),
+        std::future::pending(),
+    );
+    assert_eq!(
+        second.err().map(|e| e.kind()),
+        Some(std::io::ErrorKind::AddrInUse)
+    );
+
+    // The original server is unaffected
+    assert_eq!(probe(&socket.path, "/health").await, "HTTP/1.1 200 OK");
+
+    shutdown.cancel();
+    server.await.unwrap();
+}
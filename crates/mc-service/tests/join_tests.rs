@@ -23,12 +23,15 @@ mod test_common;
 use std::sync::Arc;
 use std::time::Duration;
 
-use ::common::secret::SecretBox;
 use bytes::{BufMut, BytesMut};
-use mc_service::actors::{ActorMetrics, ControllerMetrics, MeetingControllerActorHandle};
+use mc_service::actors::recording::RecordingLayout;
+use mc_service::actors::{
+    ActorMetrics, ControllerMetrics, MeetingControllerActorHandle, MeetingSettings,
+};
 use mc_service::grpc::MhRegistrationClient;
 use mc_service::mh_connection_registry::MhConnectionRegistry;
 use mc_service::redis::MhAssignmentStore;
+use mc_test_utils::crypto_fixtures::{test_binding_secret, test_media_token_key};
 use mc_test_utils::jwt_test::{make_expired_meeting_claims, make_meeting_claims, TestKeypair};
 use prost::Message;
 use proto_gen::dark_tower::signaling::v1::{
@@ -40,7 +43,7 @@ use mc_service::redis::MhEndpointInfo;
 use test_common::accept_loop_rig::AcceptLoopRig;
 use test_common::{
     build_test_stack, mh_handler, seed_meeting_with_handlers, seed_meeting_with_mh,
-    TestStackHandles,
+    seed_meeting_with_settings, TestStackHandles,
 };
 
 // ============================================================================
@@ -195,6 +198,7 @@ async fn join_and_read_response(
             capabilities: None,
             correlation_id: String::new(),
             binding_token: String::new(),
+            join_id: String::new(),
         })),
     };
 
@@ -256,6 +260,30 @@ async fn test_join_success_returns_join_response() {
     }
 }
 
+#[tokio::test]
+async fn test_join_response_carries_media_token_for_session() {
+    let server = TestServer::start().await;
+    server.create_meeting("meeting-media-token").await;
+
+    let claims = make_meeting_claims("meeting-media-token");
+    let token = server.sign_token(&claims);
+
+    let response =
+        join_and_read_response(&server.url(), "meeting-media-token", &token, "Alice").await;
+
+    match &response.message {
+        Some(server_message::Message::JoinResponse(join)) => {
+            let media = test_media_token_key()
+                .verify(&join.media_token, chrono::Utc::now().timestamp())
+                .expect("media token should verify with the MH shared secret");
+            assert_eq!(media.meeting_id, "meeting-media-token");
+            assert_eq!(media.sub, claims.sub);
+            assert_eq!(media.participant_id, join.participant_id);
+        }
+        other => panic!("Expected JoinResponse, got {other:?}"),
+    }
+}
+
 #[tokio::test]
 async fn test_join_success_first_participant_has_empty_roster() {
     let server = TestServer::start().await;
@@ -490,7 +518,7 @@ async fn test_join_wrong_signing_key_returns_unauthorized() {
 
 #[tokio::test]
 async fn test_actor_level_join_success() {
-    let master_secret = SecretBox::new(Box::new(vec![0u8; 32]));
+    let master_secret = test_binding_secret();
     let metrics = ActorMetrics::new();
     let controller_metrics = ControllerMetrics::new();
     let controller = MeetingControllerActorHandle::new(
@@ -516,6 +544,7 @@ async fn test_actor_level_join_success() {
             "part-1".to_string(),
             false,
             outbound_tx,
+            None,
         )
         .await
         .expect("join_connection should succeed");
@@ -539,7 +568,7 @@ async fn test_actor_level_join_success() {
 
 #[tokio::test]
 async fn test_actor_level_join_meeting_not_found() {
-    let master_secret = SecretBox::new(Box::new(vec![0u8; 32]));
+    let master_secret = test_binding_secret();
     let metrics = ActorMetrics::new();
     let controller_metrics = ControllerMetrics::new();
     let controller = MeetingControllerActorHandle::new(
@@ -560,6 +589,7 @@ async fn test_actor_level_join_meeting_not_found() {
             "part-1".to_string(),
             false,
             outbound_tx,
+            None,
         )
         .await;
 
@@ -586,7 +616,7 @@ async fn test_actor_level_join_meeting_not_found() {
 
 #[tokio::test]
 async fn test_actor_level_second_joiner_sees_first_in_roster() {
-    let master_secret = SecretBox::new(Box::new(vec![0u8; 32]));
+    let master_secret = test_binding_secret();
     let metrics = ActorMetrics::new();
     let controller_metrics = ControllerMetrics::new();
     let controller = MeetingControllerActorHandle::new(
@@ -612,6 +642,7 @@ async fn test_actor_level_second_joiner_sees_first_in_roster() {
             "part-1".to_string(),
             false,
             tx1,
+            None,
         )
         .await
         .unwrap();
@@ -634,6 +665,7 @@ async fn test_actor_level_second_joiner_sees_first_in_roster() {
             "part-2".to_string(),
             false,
             tx2,
+            None,
         )
         .await
         .unwrap();
@@ -685,6 +717,7 @@ async fn test_participant_joined_notification_via_bridge() {
             capabilities: None,
             correlation_id: String::new(),
             binding_token: String::new(),
+            join_id: String::new(),
         })),
     };
     send1
@@ -727,6 +760,7 @@ async fn test_participant_joined_notification_via_bridge() {
             capabilities: None,
             correlation_id: String::new(),
             binding_token: String::new(),
+            join_id: String::new(),
         })),
     };
     send2
@@ -819,6 +853,41 @@ async fn test_first_participant_triggers_register_meeting() {
     assert_eq!(calls[0].mc_id, "mc-test");
     assert_eq!(calls[0].mc_grpc_endpoint, "http://mc-test:50052");
     assert_eq!(calls[0].mh_grpc_endpoint, "http://mh-test-1:50053");
+    assert_eq!(calls[0].recording_layout, RecordingLayout::Unspecified);
+}
+
+#[tokio::test]
+async fn test_register_meeting_carries_assigned_recording_layout() {
+    let server = TestServer::start().await;
+    seed_meeting_with_settings(
+        &server.stack,
+        "meeting-layout",
+        MeetingSettings {
+            recording_layout: RecordingLayout::Speaker,
+            ..MeetingSettings::default()
+        },
+    )
+    .await;
+
+    let claims = make_meeting_claims("meeting-layout");
+    let token = server.sign_token(&claims);
+
+    let response = join_and_read_response(&server.url(), "meeting-layout", &token, "Alice").await;
+    assert!(
+        matches!(
+            &response.message,
+            Some(server_message::Message::JoinResponse(_))
+        ),
+        "Expected JoinResponse"
+    );
+
+    let calls = server
+        .stack
+        .mh_reg_client
+        .wait_for_calls(1, Duration::from_secs(5))
+        .await;
+    assert_eq!(calls[0].meeting_id, "meeting-layout");
+    assert_eq!(calls[0].recording_layout, RecordingLayout::Speaker);
 }
 
 // ============================================================================
@@ -0,0 +1,214 @@
+// Every `#[tokio::test]` in this file is pinned to `flavor = "current_thread"`:
+// the idle-timeout metric is emitted from the spawned connection handler, and
+// `MetricAssertion` only sees emissions on the test thread. See the header of
+// `webtransport_accept_loop_integration.rs`.
+//
+//! Component tests for signaling keepalive through the real
+//! `WebTransportServer::accept_loop`, with a 1s ping interval and a 2s idle
+//! timeout so the timers fire within the test.
+//!
+//! Covers:
+//! - a client that negotiated keepalive and answers pings stays connected
+//!   past the idle timeout, and its own `Ping` is answered with `Pong`
+//! - a silent client is closed with `CLOSE_REASON_IDLE_TIMEOUT` and counted
+//!   in `mc_connection_idle_timeouts_total`
+
+#![allow(clippy::expect_used, clippy::unwrap_used, clippy::panic)]
+
+#[path = "common/mod.rs"]
+mod test_common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use ::common::observability::testing::MetricAssertion;
+use bytes::{BufMut, BytesMut};
+use mc_service::grpc::MhRegistrationClient;
+use mc_service::redis::MhAssignmentStore;
+use mc_service::webtransport::keepalive::KeepaliveConfig;
+use mc_service::webtransport::protocol::{capability_bit, PROTOCOL_VERSION};
+use mc_test_utils::jwt_test::make_meeting_claims;
+use prost::Message;
+use proto_gen::dark_tower::signaling::v1::{
+    client_message, server_message, Capability, ClientHello, ClientMessage, CloseReason,
+    JoinRequest, Ping, Pong, ServerMessage,
+};
+use wtransport::stream::{RecvStream, SendStream};
+use wtransport::{ClientConfig, Endpoint};
+
+use test_common::accept_loop_rig::AcceptLoopRig;
+use test_common::{build_test_stack, seed_meeting_with_mh};
+
+const KEEPALIVE: KeepaliveConfig = KeepaliveConfig::from_secs(1, 2);
+
+async fn start_rig(meeting_id: &str) -> (AcceptLoopRig, String) {
+    let stack = build_test_stack("mc-keepalive-test").await;
+    seed_meeting_with_mh(&stack, meeting_id).await;
+    let token = stack.keypair.sign_token(&make_meeting_claims(meeting_id));
+    let rig = AcceptLoopRig::start_with_keepalive(
+        Arc::clone(&stack.controller_handle),
+        Arc::clone(&stack.jwt_validator),
+        Arc::clone(&stack.mh_store) as Arc<dyn MhAssignmentStore>,
+        Arc::clone(&stack.mh_reg_client) as Arc<dyn MhRegistrationClient>,
+        "mc-test".to_string(),
+        "http://mc-test:50052".to_string(),
+        8,
+        KEEPALIVE,
+    )
+    .await;
+    (rig, token)
+}
+
+async fn send(stream: &mut SendStream, message: client_message::Message) {
+    let msg = ClientMessage {
+        message: Some(message),
+        trace_parent: String::new(),
+        trace_state: String::new(),
+    };
+    let encoded = msg.encode_to_vec();
+    let mut frame = BytesMut::with_capacity(4 + encoded.len());
+    frame.put_u32(encoded.len() as u32);
+    frame.put_slice(&encoded);
+    stream.write_all(&frame).await.expect("write frame");
+}
+
+/// Read one server message; `None` once the server has closed the stream.
+async fn read(stream: &mut RecvStream) -> Option<ServerMessage> {
+    let read = async {
+        let mut len_buf = [0u8; 4];
+        stream.read_exact(&mut len_buf).await.ok()?;
+        let mut buf = vec![0u8; u32::from_be_bytes(len_buf) as usize];
+        stream.read_exact(&mut buf).await.ok()?;
+        Some(ServerMessage::decode(buf.as_slice()).expect("decode ServerMessage"))
+    };
+    tokio::time::timeout(Duration::from_secs(5), read)
+        .await
+        .expect("Timeout waiting for server message")
+}
+
+/// Connect, negotiate keepalive, and join.
+async fn join(
+    url: &str,
+    meeting_id: &str,
+    token: String,
+) -> (wtransport::Connection, SendStream, RecvStream) {
+    let cfg = ClientConfig::builder()
+        .with_bind_default()
+        .with_no_cert_validation()
+        .build();
+    let client = Endpoint::client(cfg).expect("client endpoint");
+    let conn = client.connect(url).await.expect("client connect");
+    let (mut send_stream, mut recv_stream) = conn
+        .open_bi()
+        .await
+        .expect("open_bi")
+        .await
+        .expect("bi stream ready");
+
+    send(
+        &mut send_stream,
+        client_message::Message::ClientHello(ClientHello {
+            protocol_version: PROTOCOL_VERSION,
+            capabilities: capability_bit(Capability::Keepalive),
+        }),
+    )
+    .await;
+    match read(&mut recv_stream).await.and_then(|m| m.message) {
+        Some(server_message::Message::ServerHello(hello)) => {
+            assert_eq!(hello.capabilities, capability_bit(Capability::Keepalive));
+        }
+        other => panic!("Expected ServerHello, got {other:?}"),
+    }
+
+    send(
+        &mut send_stream,
+        client_message::Message::JoinRequest(JoinRequest {
+            meeting_id: meeting_id.to_string(),
+            join_token: token,
+            participant_name: "KeepaliveTester".to_string(),
+            capabilities: None,
+            correlation_id: String::new(),
+            binding_token: String::new(),
+            join_id: String::new(),
+        }),
+    )
+    .await;
+    match read(&mut recv_stream).await.and_then(|m| m.message) {
+        Some(server_message::Message::JoinResponse(_)) => {}
+        other => panic!("Expected JoinResponse, got {other:?}"),
+    }
+
+    (conn, send_stream, recv_stream)
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn responsive_client_outlives_idle_timeout() {
+    let (rig, token) = start_rig("meeting-keepalive-alive").await;
+    let snap = MetricAssertion::snapshot();
+    let (_conn, mut send_stream, mut recv_stream) =
+        join(&rig.url, "meeting-keepalive-alive", token).await;
+
+    // Answer three pings: 3s of connection, past the 2s idle timeout
+    for expected in 1..=3 {
+        match read(&mut recv_stream).await.and_then(|m| m.message) {
+            Some(server_message::Message::Ping(ping)) => {
+                assert_eq!(ping.sequence, expected);
+                send(
+                    &mut send_stream,
+                    client_message::Message::Pong(Pong {
+                        sequence: ping.sequence,
+                    }),
+                )
+                .await;
+            }
+            other => panic!("Expected Ping, got {other:?}"),
+        }
+    }
+
+    // Client-initiated ping is answered
+    send(
+        &mut send_stream,
+        client_message::Message::Ping(Ping { sequence: 99 }),
+    )
+    .await;
+    match read(&mut recv_stream).await.and_then(|m| m.message) {
+        Some(server_message::Message::Pong(pong)) => assert_eq!(pong.sequence, 99),
+        other => panic!("Expected Pong, got {other:?}"),
+    }
+
+    snap.counter("mc_connection_idle_timeouts_total")
+        .assert_delta(0);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn silent_client_is_closed_after_idle_timeout() {
+    let (rig, token) = start_rig("meeting-keepalive-idle").await;
+    let snap = MetricAssertion::snapshot();
+    let (_conn, _send_stream, mut recv_stream) =
+        join(&rig.url, "meeting-keepalive-idle", token).await;
+
+    // Never answer: pings arrive until the server says why it is closing
+    let mut pings = 0;
+    let closed = loop {
+        match read(&mut recv_stream).await.and_then(|m| m.message) {
+            Some(server_message::Message::Ping(_)) => pings += 1,
+            Some(server_message::Message::ConnectionClosed(closed)) => break closed,
+            other => panic!("Expected Ping or ConnectionClosed, got {other:?}"),
+        }
+    };
+    assert!(pings >= 1, "expected a ping before the idle timeout");
+    assert_eq!(closed.reason(), CloseReason::IdleTimeout);
+    assert!(read(&mut recv_stream).await.is_none());
+
+    snap.counter("mc_connection_idle_timeouts_total")
+        .assert_delta(1);
+
+    // The participant is held for the reconnect grace period, not removed
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let meeting = rig
+        .controller_handle
+        .get_meeting("meeting-keepalive-idle".to_string())
+        .await
+        .unwrap();
+    assert_eq!(meeting.participant_count, 1);
+}
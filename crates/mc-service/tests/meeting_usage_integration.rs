@@ -0,0 +1,41 @@
+//! Tests for the per-meeting usage gauges.
+//!
+//! Records through a real `MeetingUsageRegistry` sample so the ranking and
+//! the zero-fill of empty ranks are exercised end to end.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use ::common::observability::testing::MetricAssertion;
+use mc_service::actors::MeetingUsageRegistry;
+use mc_service::observability::metrics::record_meeting_usage;
+
+#[test]
+fn meeting_usage_exports_top_meetings_by_rank() {
+    let snap = MetricAssertion::snapshot();
+    let registry = MeetingUsageRegistry::new();
+
+    let noisy = registry.register("meeting-noisy");
+    let quiet = registry.register("meeting-quiet");
+    for _ in 0..100 {
+        noisy.record_message();
+    }
+    quiet.record_message();
+    quiet.record_redis_op();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+
+    record_meeting_usage(&registry.sample());
+
+    snap.gauge("mc_meeting_usage_top")
+        .with_labels(&[("resource", "messages"), ("rank", "1")])
+        .assert_value_in_range(1.0..=f64::MAX);
+    snap.gauge("mc_meeting_usage_top")
+        .with_labels(&[("resource", "redis_ops"), ("rank", "1")])
+        .assert_value_in_range(1.0..=f64::MAX);
+    // Only two meetings: the remaining ranks are zeroed
+    snap.gauge("mc_meeting_usage_top")
+        .with_labels(&[("resource", "messages"), ("rank", "3")])
+        .assert_value(0.0);
+    snap.gauge("mc_meeting_usage_top")
+        .with_labels(&[("resource", "bytes"), ("rank", "1")])
+        .assert_value(0.0);
+}
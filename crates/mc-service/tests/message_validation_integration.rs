@@ -0,0 +1,60 @@
+//! Tests for `mc_signaling_messages_rejected_total`.
+//!
+//! Runs real client messages through `validate_client_message` and records
+//! the rejection with the same labels the bridge loop uses, so the label
+//! values come from the validation layer rather than test literals.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use ::common::observability::testing::MetricAssertion;
+use mc_service::observability::metrics::record_signaling_message_rejected;
+use mc_service::webtransport::validation::{message_type_label, validate_client_message};
+use prost::Message;
+use proto_gen::dark_tower::signaling::v1::{client_message, ClientMessage, CreatePoll, PollVote};
+
+fn reject(message: &ClientMessage) {
+    let err = validate_client_message(message, message.encoded_len())
+        .expect_err("message should be rejected");
+    let message_type = message_type_label(message.message.as_ref().unwrap());
+    record_signaling_message_rejected(message_type, err.reason_label());
+}
+
+#[test]
+fn rejected_messages_are_counted_by_type_and_reason() {
+    let snap = MetricAssertion::snapshot();
+
+    let poll = ClientMessage {
+        message: Some(client_message::Message::CreatePoll(CreatePoll {
+            question: "Too many options".to_string(),
+            options: vec!["x".to_string(); 11],
+        })),
+        trace_parent: String::new(),
+        trace_state: String::new(),
+    };
+    let vote = ClientMessage {
+        message: Some(client_message::Message::PollVote(PollVote {
+            poll_id: "p".repeat(500),
+            option_index: 0,
+        })),
+        trace_parent: String::new(),
+        trace_state: String::new(),
+    };
+
+    reject(&poll);
+    reject(&vote);
+    reject(&vote);
+
+    snap.counter("mc_signaling_messages_rejected_total")
+        .with_labels(&[
+            ("message_type", "create_poll"),
+            ("reason", "too_many_items"),
+        ])
+        .assert_delta(1);
+    snap.counter("mc_signaling_messages_rejected_total")
+        .with_labels(&[("message_type", "poll_vote"), ("reason", "field_too_long")])
+        .assert_delta(2);
+    // Label-swap guard: the reasons stay with their own message types
+    snap.counter("mc_signaling_messages_rejected_total")
+        .with_labels(&[("message_type", "poll_vote"), ("reason", "too_many_items")])
+        .assert_delta(0);
+}
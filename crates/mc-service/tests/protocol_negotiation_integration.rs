@@ -0,0 +1,220 @@
+// Every `#[tokio::test]` in this file is pinned to `flavor = "current_thread"`:
+// the handshake metrics are emitted from the spawned connection handler, and
+// `MetricAssertion` only sees emissions on the test thread. See the header of
+// `webtransport_accept_loop_integration.rs`.
+//
+//! Component tests for the `ClientHello`/`ServerHello` handshake through the
+//! real `WebTransportServer::accept_loop`.
+//!
+//! Covers the three handshake outcomes and their
+//! `mc_client_protocol_version_total` emissions:
+//! - `ClientHello` at the current version: `ServerHello`, then `JoinResponse`
+//! - no `ClientHello` (legacy client): straight to `JoinResponse`, counted
+//!   as version 1
+//! - `ClientHello` below the minimum: `UNSUPPORTED_VERSION` error
+
+#![allow(clippy::expect_used, clippy::unwrap_used, clippy::panic)]
+
+#[path = "common/mod.rs"]
+mod test_common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use ::common::observability::testing::MetricAssertion;
+use bytes::{BufMut, BytesMut};
+use mc_service::grpc::MhRegistrationClient;
+use mc_service::redis::MhAssignmentStore;
+use mc_service::webtransport::protocol::{capability_bit, PROTOCOL_VERSION};
+use mc_test_utils::jwt_test::make_meeting_claims;
+use prost::Message;
+use proto_gen::dark_tower::signaling::v1::{
+    self, client_message, server_message, Capability, ClientHello, ClientMessage, JoinRequest,
+    ServerMessage,
+};
+use wtransport::stream::{RecvStream, SendStream};
+use wtransport::{ClientConfig, Endpoint};
+
+use test_common::accept_loop_rig::AcceptLoopRig;
+use test_common::{build_test_stack, seed_meeting_with_mh, TestStackHandles};
+
+struct RigBundle {
+    rig: AcceptLoopRig,
+    stack: TestStackHandles,
+}
+
+async fn start_rig() -> RigBundle {
+    let stack = build_test_stack("mc-protocol-test").await;
+    let rig = AcceptLoopRig::start_with(
+        Arc::clone(&stack.controller_handle),
+        Arc::clone(&stack.jwt_validator),
+        Arc::clone(&stack.mh_store) as Arc<dyn MhAssignmentStore>,
+        Arc::clone(&stack.mh_reg_client) as Arc<dyn MhRegistrationClient>,
+        "mc-test".to_string(),
+        "http://mc-test:50052".to_string(),
+        8,
+    )
+    .await;
+    RigBundle { rig, stack }
+}
+
+async fn open_stream(url: &str) -> (wtransport::Connection, SendStream, RecvStream) {
+    let cfg = ClientConfig::builder()
+        .with_bind_default()
+        .with_no_cert_validation()
+        .build();
+    let client = Endpoint::client(cfg).expect("client endpoint");
+    let conn = client.connect(url).await.expect("client connect");
+    let (send, recv) = conn
+        .open_bi()
+        .await
+        .expect("open_bi")
+        .await
+        .expect("bi stream ready");
+    (conn, send, recv)
+}
+
+async fn send(stream: &mut SendStream, message: client_message::Message) {
+    let msg = ClientMessage {
+        message: Some(message),
+        trace_parent: String::new(),
+        trace_state: String::new(),
+    };
+    let encoded = msg.encode_to_vec();
+    let mut frame = BytesMut::with_capacity(4 + encoded.len());
+    frame.put_u32(encoded.len() as u32);
+    frame.put_slice(&encoded);
+    stream.write_all(&frame).await.expect("write frame");
+}
+
+async fn read(stream: &mut RecvStream) -> ServerMessage {
+    let read = async {
+        let mut len_buf = [0u8; 4];
+        stream.read_exact(&mut len_buf).await.expect("read length");
+        let mut buf = vec![0u8; u32::from_be_bytes(len_buf) as usize];
+        stream.read_exact(&mut buf).await.expect("read body");
+        ServerMessage::decode(buf.as_slice()).expect("decode ServerMessage")
+    };
+    tokio::time::timeout(Duration::from_secs(5), read)
+        .await
+        .expect("Timeout waiting for server message")
+}
+
+fn join_request(meeting_id: &str, token: String) -> client_message::Message {
+    client_message::Message::JoinRequest(JoinRequest {
+        meeting_id: meeting_id.to_string(),
+        join_token: token,
+        participant_name: "ProtocolTester".to_string(),
+        capabilities: None,
+        correlation_id: String::new(),
+        binding_token: String::new(),
+        join_id: String::new(),
+    })
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn client_hello_is_answered_before_join() {
+    let bundle = start_rig().await;
+    seed_meeting_with_mh(&bundle.stack, "meeting-hello").await;
+    let token = bundle
+        .stack
+        .keypair
+        .sign_token(&make_meeting_claims("meeting-hello"));
+
+    let snap = MetricAssertion::snapshot();
+    let (_conn, mut send_stream, mut recv_stream) = open_stream(&bundle.rig.url).await;
+
+    let capabilities = capability_bit(Capability::DataChannels) | (1 << 50);
+    send(
+        &mut send_stream,
+        client_message::Message::ClientHello(ClientHello {
+            protocol_version: PROTOCOL_VERSION,
+            capabilities,
+        }),
+    )
+    .await;
+
+    match read(&mut recv_stream).await.message {
+        Some(server_message::Message::ServerHello(hello)) => {
+            assert_eq!(hello.protocol_version, PROTOCOL_VERSION);
+            assert_eq!(hello.max_protocol_version, PROTOCOL_VERSION);
+            assert_eq!(hello.min_protocol_version, 1);
+            // Unknown bits are dropped
+            assert_eq!(hello.capabilities, capability_bit(Capability::DataChannels));
+        }
+        other => panic!("Expected ServerHello, got {other:?}"),
+    }
+
+    send(&mut send_stream, join_request("meeting-hello", token)).await;
+    match read(&mut recv_stream).await.message {
+        Some(server_message::Message::JoinResponse(_)) => {}
+        other => panic!("Expected JoinResponse, got {other:?}"),
+    }
+
+    snap.counter("mc_client_protocol_version_total")
+        .with_labels(&[("version", "2"), ("status", "accepted")])
+        .assert_delta(1);
+    snap.counter("mc_client_protocol_version_total")
+        .with_labels(&[("version", "1"), ("status", "accepted")])
+        .assert_delta(0);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn legacy_client_without_hello_joins_as_version_one() {
+    let bundle = start_rig().await;
+    seed_meeting_with_mh(&bundle.stack, "meeting-legacy").await;
+    let token = bundle
+        .stack
+        .keypair
+        .sign_token(&make_meeting_claims("meeting-legacy"));
+
+    let snap = MetricAssertion::snapshot();
+    let (_conn, mut send_stream, mut recv_stream) = open_stream(&bundle.rig.url).await;
+
+    send(&mut send_stream, join_request("meeting-legacy", token)).await;
+    match read(&mut recv_stream).await.message {
+        Some(server_message::Message::JoinResponse(_)) => {}
+        other => panic!("Expected JoinResponse, got {other:?}"),
+    }
+
+    snap.counter("mc_client_protocol_version_total")
+        .with_labels(&[("version", "1"), ("status", "accepted")])
+        .assert_delta(1);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn client_below_minimum_version_is_rejected() {
+    let bundle = start_rig().await;
+
+    let snap = MetricAssertion::snapshot();
+    let (_conn, mut send_stream, mut recv_stream) = open_stream(&bundle.rig.url).await;
+
+    // Version 0 (unset) is below every minimum
+    send(
+        &mut send_stream,
+        client_message::Message::ClientHello(ClientHello {
+            protocol_version: 0,
+            capabilities: 0,
+        }),
+    )
+    .await;
+
+    match read(&mut recv_stream).await.message {
+        Some(server_message::Message::Error(err)) => {
+            assert_eq!(err.code, v1::ErrorCode::UnsupportedVersion as i32);
+            assert_eq!(err.dt_code, "DT-MC-2022");
+        }
+        other => panic!("Expected Error, got {other:?}"),
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    snap.counter("mc_session_join_failures_total")
+        .with_labels(&[("error_type", "unsupported_protocol_version")])
+        .assert_delta(1);
+    snap.counter("mc_client_protocol_version_total")
+        .with_labels(&[("version", "0"), ("status", "rejected")])
+        .assert_delta(1);
+    snap.counter("mc_client_protocol_version_total")
+        .with_labels(&[("version", "0"), ("status", "accepted")])
+        .assert_delta(0);
+}
@@ -0,0 +1,325 @@
+// Every `#[tokio::test]` in this file is pinned to `flavor = "current_thread"`:
+// the accept-path counter and the spawned connection handler emit from
+// `tokio::spawn` tasks, and `MetricAssertion` only sees emissions on the test
+// thread. See the header of `webtransport_accept_loop_integration.rs`.
+//
+//! Component tests for signaling over raw QUIC streams through the real
+//! `WebTransportServer::accept_loop_with_raw_quic`.
+//!
+//! Covers:
+//! - A client negotiating `dt-signaling/1` joins through the same pipeline
+//!   as WebTransport, counted as `mc_raw_quic_connections_total{status="accepted"}`
+//! - A client offering any other ALPN is refused, counted as
+//!   `mc_raw_quic_connections_total{status="error"}`
+//! - A reconnecting client resumes its TLS session and sends its session
+//!   recovery `JoinRequest` as 0-RTT data, counted as
+//!   `mc_raw_quic_handshakes_total{zero_rtt="accepted"}`
+//! - With 0-RTT disabled, resumed sessions offer no early data
+
+#![allow(clippy::expect_used, clippy::unwrap_used, clippy::panic)]
+
+#[path = "common/mod.rs"]
+mod test_common;
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use ::common::observability::testing::MetricAssertion;
+use bytes::{BufMut, BytesMut};
+use mc_service::grpc::MhRegistrationClient;
+use mc_service::redis::MhAssignmentStore;
+use mc_service::webtransport::raw_quic::SIGNALING_ALPN;
+use mc_test_utils::jwt_test::make_meeting_claims;
+use prost::Message;
+use proto_gen::dark_tower::signaling::v1::{
+    client_message, server_message, ClientMessage, JoinRequest, ServerMessage,
+};
+use quinn::crypto::rustls::QuicClientConfig;
+use quinn::rustls;
+
+use test_common::accept_loop_rig::AcceptLoopRig;
+use test_common::{build_test_stack, seed_meeting_with_mh, TestStackHandles};
+
+async fn start_rig() -> (AcceptLoopRig, TestStackHandles) {
+    start_rig_with_zero_rtt(true).await
+}
+
+async fn start_rig_with_zero_rtt(zero_rtt: bool) -> (AcceptLoopRig, TestStackHandles) {
+    let stack = build_test_stack("mc-raw-quic-test").await;
+    let rig = AcceptLoopRig::start_with_raw_quic(
+        Arc::clone(&stack.controller_handle),
+        Arc::clone(&stack.jwt_validator),
+        Arc::clone(&stack.mh_store) as Arc<dyn MhAssignmentStore>,
+        Arc::clone(&stack.mh_reg_client) as Arc<dyn MhRegistrationClient>,
+        zero_rtt,
+    )
+    .await;
+    (rig, stack)
+}
+
+/// QUIC client endpoint trusting the rig's self-signed certificate and
+/// offering `alpn`.
+fn build_client(cert_der: &[u8], alpn: &[u8]) -> quinn::Endpoint {
+    let mut roots = rustls::RootCertStore::empty();
+    roots
+        .add(rustls::pki_types::CertificateDer::from(cert_der.to_vec()))
+        .expect("add rig certificate to root store");
+    let mut tls = rustls::ClientConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_protocol_versions(&[&rustls::version::TLS13])
+    .expect("TLS 1.3 client config")
+    .with_root_certificates(roots)
+    .with_no_client_auth();
+    tls.alpn_protocols = vec![alpn.to_vec()];
+    // Session tickets are kept per endpoint, so a second connection from
+    // the same endpoint can resume
+    tls.enable_early_data = true;
+
+    let crypto = QuicClientConfig::try_from(tls).expect("QUIC client config");
+    let mut endpoint =
+        quinn::Endpoint::client("127.0.0.1:0".parse().unwrap()).expect("client endpoint");
+    endpoint.set_default_client_config(quinn::ClientConfig::new(Arc::new(crypto)));
+    endpoint
+}
+
+fn join_frame(meeting_id: &str, token: String) -> BytesMut {
+    rejoin_frame(meeting_id, token, "", "")
+}
+
+/// A session recovery `JoinRequest` presenting a previous `JoinResponse`'s
+/// binding (a fresh join when both are empty).
+fn rejoin_frame(
+    meeting_id: &str,
+    token: String,
+    correlation_id: &str,
+    binding_token: &str,
+) -> BytesMut {
+    let msg = ClientMessage {
+        message: Some(client_message::Message::JoinRequest(JoinRequest {
+            meeting_id: meeting_id.to_string(),
+            join_token: token,
+            participant_name: "RawQuicTester".to_string(),
+            capabilities: None,
+            correlation_id: correlation_id.to_string(),
+            binding_token: binding_token.to_string(),
+            join_id: String::new(),
+        })),
+        trace_parent: String::new(),
+        trace_state: String::new(),
+    };
+    let encoded = msg.encode_to_vec();
+    let mut frame = BytesMut::with_capacity(4 + encoded.len());
+    frame.put_u32(encoded.len() as u32);
+    frame.put_slice(&encoded);
+    frame
+}
+
+/// Read one server message; `None` once the server has closed the stream.
+async fn read(stream: &mut quinn::RecvStream) -> Option<ServerMessage> {
+    let read = async {
+        let mut len_buf = [0u8; 4];
+        stream.read_exact(&mut len_buf).await.ok()?;
+        let mut buf = vec![0u8; u32::from_be_bytes(len_buf) as usize];
+        stream.read_exact(&mut buf).await.ok()?;
+        Some(ServerMessage::decode(buf.as_slice()).expect("decode ServerMessage"))
+    };
+    tokio::time::timeout(Duration::from_secs(5), read)
+        .await
+        .expect("Timeout waiting for server message")
+}
+
+fn raw_quic_addr(rig: &AcceptLoopRig) -> SocketAddr {
+    rig.raw_quic_addr
+        .expect("rig started without a raw QUIC endpoint")
+}
+
+/// Join `meeting_id` over a full handshake and disconnect, leaving `client`
+/// holding a session ticket. Returns the participant ID and the
+/// `JoinResponse` binding.
+async fn join_and_disconnect(
+    client: &quinn::Endpoint,
+    rig: &AcceptLoopRig,
+    stack: &TestStackHandles,
+    meeting_id: &str,
+) -> (String, String, String) {
+    let token = stack.keypair.sign_token(&make_meeting_claims(meeting_id));
+    let conn = client
+        .connect(raw_quic_addr(rig), "localhost")
+        .expect("client connect")
+        .await
+        .expect("QUIC handshake");
+    let (mut send, mut recv) = conn.open_bi().await.expect("open_bi");
+    send.write_all(&join_frame(meeting_id, token))
+        .await
+        .expect("write JoinRequest");
+    let binding = match read(&mut recv).await.map(|msg| msg.message) {
+        Some(Some(server_message::Message::JoinResponse(join))) => {
+            (join.participant_id, join.correlation_id, join.binding_token)
+        }
+        other => panic!("expected JoinResponse, got {other:?}"),
+    };
+
+    conn.close(0u32.into(), b"network switch");
+    // Bounded window for the MC to notice the disconnect
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    binding
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn raw_quic_client_joins_through_signaling_pipeline() {
+    let (rig, stack) = start_rig().await;
+    seed_meeting_with_mh(&stack, "meeting-raw-quic").await;
+    let token = stack
+        .keypair
+        .sign_token(&make_meeting_claims("meeting-raw-quic"));
+
+    let snap = MetricAssertion::snapshot();
+    let client = build_client(&rig.cert_der, SIGNALING_ALPN);
+    let conn = client
+        .connect(raw_quic_addr(&rig), "localhost")
+        .expect("client connect")
+        .await
+        .expect("QUIC handshake");
+    let (mut send, mut recv) = conn.open_bi().await.expect("open_bi");
+    send.write_all(&join_frame("meeting-raw-quic", token))
+        .await
+        .expect("write JoinRequest");
+
+    match read(&mut recv).await.map(|msg| msg.message) {
+        Some(Some(server_message::Message::JoinResponse(join))) => {
+            assert!(!join.participant_id.is_empty());
+        }
+        other => panic!("expected JoinResponse, got {other:?}"),
+    }
+
+    snap.counter("mc_raw_quic_connections_total")
+        .with_labels(&[("status", "accepted")])
+        .assert_delta(1);
+    snap.counter("mc_raw_quic_connections_total")
+        .with_labels(&[("status", "error")])
+        .assert_delta(0);
+    // The raw QUIC endpoint must not be counted as WebTransport
+    snap.counter("mc_webtransport_connections_total")
+        .with_labels(&[("status", "accepted")])
+        .assert_delta(0);
+    snap.counter("mc_session_joins_total")
+        .with_labels(&[("status", "success")])
+        .assert_delta(1);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn raw_quic_client_without_signaling_alpn_is_refused() {
+    let (rig, _stack) = start_rig().await;
+
+    let snap = MetricAssertion::snapshot();
+    let client = build_client(&rig.cert_der, b"h3");
+    let result = client
+        .connect(raw_quic_addr(&rig), "localhost")
+        .expect("client connect")
+        .await;
+    assert!(
+        result.is_err(),
+        "handshake without signaling ALPN must fail"
+    );
+
+    // Bounded window for the spawned handler to observe the failed handshake
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    snap.counter("mc_raw_quic_connections_total")
+        .with_labels(&[("status", "error")])
+        .assert_delta(1);
+    snap.counter("mc_session_joins_total")
+        .with_labels(&[("status", "success")])
+        .assert_delta(0);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn raw_quic_reconnect_resumes_session_with_zero_rtt() {
+    let (rig, stack) = start_rig().await;
+    seed_meeting_with_mh(&stack, "meeting-raw-quic-0rtt").await;
+    let client = build_client(&rig.cert_der, SIGNALING_ALPN);
+    let (participant_id, correlation_id, binding_token) =
+        join_and_disconnect(&client, &rig, &stack, "meeting-raw-quic-0rtt").await;
+
+    let snap = MetricAssertion::snapshot();
+    let (conn, zero_rtt_accepted) = client
+        .connect(raw_quic_addr(&rig), "localhost")
+        .expect("client connect")
+        .into_0rtt()
+        .unwrap_or_else(|_| panic!("client should hold a 0-RTT capable session ticket"));
+    // Sent before the handshake completes
+    let token = stack
+        .keypair
+        .sign_token(&make_meeting_claims("meeting-raw-quic-0rtt"));
+    let (mut send, mut recv) = conn.open_bi().await.expect("open_bi");
+    send.write_all(&rejoin_frame(
+        "meeting-raw-quic-0rtt",
+        token,
+        &correlation_id,
+        &binding_token,
+    ))
+    .await
+    .expect("write JoinRequest");
+
+    match read(&mut recv).await.map(|msg| msg.message) {
+        Some(Some(server_message::Message::JoinResponse(join))) => {
+            assert_eq!(join.participant_id, participant_id, "session resumed");
+        }
+        other => panic!("expected JoinResponse, got {other:?}"),
+    }
+    assert!(zero_rtt_accepted.await, "server should accept 0-RTT data");
+
+    // Bounded window for the spawned handler to record the handshake
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    snap.counter("mc_raw_quic_handshakes_total")
+        .with_labels(&[("zero_rtt", "accepted")])
+        .assert_delta(1);
+    snap.counter("mc_session_joins_total")
+        .with_labels(&[("status", "success")])
+        .assert_delta(1);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn raw_quic_without_zero_rtt_resumes_with_full_round_trip() {
+    let (rig, stack) = start_rig_with_zero_rtt(false).await;
+    seed_meeting_with_mh(&stack, "meeting-raw-quic-1rtt").await;
+    let client = build_client(&rig.cert_der, SIGNALING_ALPN);
+    join_and_disconnect(&client, &rig, &stack, "meeting-raw-quic-1rtt").await;
+
+    let snap = MetricAssertion::snapshot();
+    let connecting = client
+        .connect(raw_quic_addr(&rig), "localhost")
+        .expect("client connect");
+    // Tickets from an MC with 0-RTT disabled allow no early data
+    let connecting = match connecting.into_0rtt() {
+        Ok(_) => panic!("0-RTT must be unavailable when disabled"),
+        Err(connecting) => connecting,
+    };
+    let conn = connecting.await.expect("QUIC handshake");
+    let token = stack
+        .keypair
+        .sign_token(&make_meeting_claims("meeting-raw-quic-1rtt"));
+    let (mut send, mut recv) = conn.open_bi().await.expect("open_bi");
+    send.write_all(&join_frame("meeting-raw-quic-1rtt", token))
+        .await
+        .expect("write JoinRequest");
+    assert!(matches!(
+        read(&mut recv).await.map(|msg| msg.message),
+        Some(Some(server_message::Message::JoinResponse(_)))
+    ));
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    snap.counter("mc_raw_quic_handshakes_total")
+        .with_labels(&[("zero_rtt", "none")])
+        .assert_delta(1);
+    snap.counter("mc_raw_quic_handshakes_total")
+        .with_labels(&[("zero_rtt", "accepted")])
+        .assert_delta(0);
+    snap.counter("mc_zero_rtt_messages_total")
+        .with_labels(&[("message_type", "join_request"), ("action", "deferred")])
+        .assert_delta(0);
+}
@@ -18,18 +18,22 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 
 use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use ::common::observability::testing::MetricAssertion;
 use ::common::secret::SecretString;
 use ::common::token_manager::TokenReceiver;
+use mc_service::actors::recording::RecordingLayout;
 use mc_service::grpc::MhClient;
 use proto_gen::dark_tower::internal::v1::media_handler_service_server::{
     MediaHandlerService, MediaHandlerServiceServer,
 };
 use proto_gen::dark_tower::internal::v1::{
-    RegisterMeetingRequest, RegisterMeetingResponse, RegisterRequest, RegisterResponse,
-    RouteMediaRequest, RouteMediaResponse, StreamTelemetryRequest, StreamTelemetryResponse,
+    RecordingLayout as ProtoRecordingLayout, RegisterMeetingRequest, RegisterMeetingResponse,
+    RegisterRequest, RegisterResponse, RouteMediaRequest, RouteMediaResponse, StartEgressRequest,
+    StartEgressResponse, StopEgressRequest, StopEgressResponse, StreamTelemetryRequest,
+    StreamTelemetryResponse,
 };
 use tokio::net::TcpListener;
 use tokio::sync::watch;
@@ -42,6 +46,8 @@ use tonic::{Request, Response, Status, Streaming};
 
 struct StubMediaHandler {
     accept: bool,
+    /// `RegisterMeeting` requests received, for asserting on the wire fields.
+    received: Arc<Mutex<Vec<RegisterMeetingRequest>>>,
 }
 
 #[tonic::async_trait]
@@ -55,8 +61,9 @@ impl MediaHandlerService for StubMediaHandler {
 
     async fn register_meeting(
         &self,
-        _request: Request<RegisterMeetingRequest>,
+        request: Request<RegisterMeetingRequest>,
     ) -> Result<Response<RegisterMeetingResponse>, Status> {
+        self.received.lock().unwrap().push(request.into_inner());
         Ok(Response::new(RegisterMeetingResponse {
             accepted: self.accept,
         }))
@@ -75,14 +82,32 @@ impl MediaHandlerService for StubMediaHandler {
     ) -> Result<Response<StreamTelemetryResponse>, Status> {
         Err(Status::unimplemented("stub"))
     }
+
+    async fn start_egress(
+        &self,
+        _request: Request<StartEgressRequest>,
+    ) -> Result<Response<StartEgressResponse>, Status> {
+        Err(Status::unimplemented("stub"))
+    }
+
+    async fn stop_egress(
+        &self,
+        _request: Request<StopEgressRequest>,
+    ) -> Result<Response<StopEgressResponse>, Status> {
+        Err(Status::unimplemented("stub"))
+    }
 }
 
-async fn start_stub_mh(accept: bool) -> SocketAddr {
+async fn start_stub_mh(accept: bool) -> (SocketAddr, Arc<Mutex<Vec<RegisterMeetingRequest>>>) {
     let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
     let addr = listener.local_addr().expect("local_addr");
     let stream = tokio_stream::wrappers::TcpListenerStream::new(listener);
 
-    let svc = MediaHandlerServiceServer::new(StubMediaHandler { accept });
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let svc = MediaHandlerServiceServer::new(StubMediaHandler {
+        accept,
+        received: Arc::clone(&received),
+    });
     tokio::spawn(async move {
         let _ = tonic::transport::Server::builder()
             .add_service(svc)
@@ -92,7 +117,7 @@ async fn start_stub_mh(accept: bool) -> SocketAddr {
 
     // Brief settle so the server is ready to accept connections.
     tokio::time::sleep(Duration::from_millis(50)).await;
-    addr
+    (addr, received)
 }
 
 fn make_token_rx() -> TokenReceiver {
@@ -107,7 +132,7 @@ fn make_token_rx() -> TokenReceiver {
 
 #[tokio::test(flavor = "current_thread")]
 async fn register_meeting_success_emits_status_success_and_duration_observation() {
-    let addr = start_stub_mh(true).await;
+    let (addr, received) = start_stub_mh(true).await;
     let endpoint = format!("http://{addr}");
     let client = MhClient::new(make_token_rx());
 
@@ -116,12 +141,23 @@ async fn register_meeting_success_emits_status_success_and_duration_observation(
         .register_meeting(
             &endpoint,
             "meeting-success",
+            false,
+            RecordingLayout::Speaker,
             "mc-test",
             "http://mc-test:50052",
         )
         .await;
     assert!(result.is_ok(), "expected Ok, got {result:?}");
 
+    // The meeting's layout from the GC assignment reaches the MH
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(
+        received[0].recording_layout,
+        i32::from(ProtoRecordingLayout::Speaker)
+    );
+    drop(received);
+
     // Histogram first (drain-on-read).
     snap.histogram("mc_register_meeting_duration_seconds")
         .assert_observation_count_at_least(1);
@@ -136,7 +172,7 @@ async fn register_meeting_success_emits_status_success_and_duration_observation(
 #[tokio::test(flavor = "current_thread")]
 async fn register_meeting_mh_rejects_emits_status_error() {
     // Stub responds with `accepted: false` → mh_client.rs:144 records "error".
-    let addr = start_stub_mh(false).await;
+    let (addr, _received) = start_stub_mh(false).await;
     let endpoint = format!("http://{addr}");
     let client = MhClient::new(make_token_rx());
 
@@ -145,6 +181,8 @@ async fn register_meeting_mh_rejects_emits_status_error() {
         .register_meeting(
             &endpoint,
             "meeting-rejected",
+            false,
+            RecordingLayout::Unspecified,
             "mc-test",
             "http://mc-test:50052",
         )
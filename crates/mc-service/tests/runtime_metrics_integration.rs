@@ -0,0 +1,33 @@
+// Pinned to `flavor = "current_thread"`: `MetricAssertion` binds a
+// per-thread recorder, and the sampler's probe task must run on the test
+// thread for the emissions to be captured.
+//
+//! Tests for the Tokio runtime gauges and schedule-delay histogram.
+//!
+//! Drives a real `RuntimeSampler` on the test runtime and records through
+//! the production wrapper, so the values asserted are ones Tokio actually
+//! reports rather than hand-built samples.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use ::common::observability::runtime::RuntimeSampler;
+use ::common::observability::testing::MetricAssertion;
+use mc_service::observability::metrics::record_runtime_sample;
+
+#[tokio::test(flavor = "current_thread")]
+async fn runtime_sample_records_gauges_and_schedule_delay() {
+    let snap = MetricAssertion::snapshot();
+    let mut sampler = RuntimeSampler::new();
+
+    record_runtime_sample(&sampler.sample().await);
+
+    snap.histogram("mc_runtime_schedule_delay_seconds")
+        .assert_observation_count(1);
+    snap.gauge("mc_runtime_workers").assert_value(1.0);
+    snap.gauge("mc_runtime_alive_tasks")
+        .assert_value_in_range(0.0..=f64::MAX);
+    snap.gauge("mc_runtime_global_queue_depth")
+        .assert_value_in_range(0.0..=f64::MAX);
+    snap.gauge("mc_runtime_worker_busy_ratio")
+        .assert_value_in_range(0.0..=1.0);
+}
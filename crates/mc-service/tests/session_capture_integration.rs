@@ -0,0 +1,241 @@
+//! Component tests for session capture (`MC_SESSION_CAPTURE_DIR`) and
+//! replay through the real `WebTransportServer::accept_loop`.
+//!
+//! A session against a capturing MC must produce a capture with every
+//! frame in order and no secrets, and replaying that capture against a
+//! fresh MC must draw the same answers.
+
+#![allow(clippy::expect_used, clippy::unwrap_used, clippy::panic)]
+
+#[path = "common/mod.rs"]
+mod test_common;
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use mc_service::grpc::MhRegistrationClient;
+use mc_service::redis::MhAssignmentStore;
+use mc_service::webtransport::capture::{Direction, CAPTURE_EXTENSION};
+use mc_service::webtransport::protocol::{capability_bit, PROTOCOL_VERSION};
+use mc_test_utils::jwt_test::make_meeting_claims;
+use mc_test_utils::mock_webtransport::MockWebTransport;
+use mc_test_utils::replay::{Replay, SessionCapture};
+use proto_gen::dark_tower::signaling::v1::{
+    client_message, server_message, Capability, ClientHello, JoinRequest, Ping,
+};
+
+use test_common::accept_loop_rig::AcceptLoopRig;
+use test_common::{build_test_stack, seed_meeting_with_mh, TestStackHandles};
+
+const MEETING_ID: &str = "meeting-capture";
+
+async fn start_rig(stack: &TestStackHandles, capture_dir: Option<PathBuf>) -> AcceptLoopRig {
+    let controller_handle = Arc::clone(&stack.controller_handle);
+    let jwt_validator = Arc::clone(&stack.jwt_validator);
+    let mh_store = Arc::clone(&stack.mh_store) as Arc<dyn MhAssignmentStore>;
+    let mh_reg_client = Arc::clone(&stack.mh_reg_client) as Arc<dyn MhRegistrationClient>;
+    match capture_dir {
+        Some(dir) => {
+            AcceptLoopRig::start_with_session_capture(
+                controller_handle,
+                jwt_validator,
+                mh_store,
+                mh_reg_client,
+                dir,
+            )
+            .await
+        }
+        None => {
+            AcceptLoopRig::start(controller_handle, jwt_validator, mh_store, mh_reg_client).await
+        }
+    }
+}
+
+/// Wait for the capture file in `dir` to hold `frames` frames.
+async fn wait_for_capture(dir: &Path, frames: usize) -> (PathBuf, SessionCapture) {
+    let wait = async {
+        loop {
+            let found = std::fs::read_dir(dir)
+                .unwrap()
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .find(|path| path.extension().is_some_and(|ext| ext == CAPTURE_EXTENSION));
+            if let Some(path) = found {
+                let data = std::fs::read(&path).unwrap();
+                if let Ok(capture) = SessionCapture::from_bytes(&data) {
+                    if capture.frames().len() >= frames {
+                        return (path, capture);
+                    }
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(5), wait)
+        .await
+        .expect("Timeout waiting for session capture")
+}
+
+/// Run a handshake, join and ping against `url`.
+async fn run_session(url: &str, token: String) {
+    let mut client = MockWebTransport::connect(url).await.unwrap();
+
+    client
+        .send(client_message::Message::ClientHello(ClientHello {
+            protocol_version: PROTOCOL_VERSION,
+            capabilities: capability_bit(Capability::DataChannels),
+        }))
+        .await
+        .unwrap();
+    match client.recv().await.unwrap().message {
+        Some(server_message::Message::ServerHello(_)) => {}
+        other => panic!("Expected ServerHello, got {other:?}"),
+    }
+
+    client
+        .send(client_message::Message::JoinRequest(JoinRequest {
+            meeting_id: MEETING_ID.to_string(),
+            join_token: token,
+            participant_name: "Capture Tester".to_string(),
+            capabilities: None,
+            correlation_id: String::new(),
+            binding_token: "stale-binding-secret".to_string(),
+            join_id: String::new(),
+        }))
+        .await
+        .unwrap();
+    match client.recv().await.unwrap().message {
+        Some(server_message::Message::JoinResponse(_)) => {}
+        other => panic!("Expected JoinResponse, got {other:?}"),
+    }
+
+    client
+        .send(client_message::Message::Ping(Ping { sequence: 7 }))
+        .await
+        .unwrap();
+    match client.recv().await.unwrap().message {
+        Some(server_message::Message::Pong(pong)) => assert_eq!(pong.sequence, 7),
+        other => panic!("Expected Pong, got {other:?}"),
+    }
+
+    client.finish().await.unwrap();
+}
+
+#[tokio::test]
+async fn session_is_captured_in_order_without_secrets() {
+    let stack = build_test_stack("mc-capture-test").await;
+    seed_meeting_with_mh(&stack, MEETING_ID).await;
+    let capture_dir = tempfile::tempdir().unwrap();
+    let rig = start_rig(&stack, Some(capture_dir.path().to_path_buf())).await;
+
+    let token = stack.keypair.sign_token(&make_meeting_claims(MEETING_ID));
+    run_session(&rig.url, token.clone()).await;
+
+    let (path, capture) = wait_for_capture(capture_dir.path(), 6).await;
+
+    let directions: Vec<Direction> = capture.frames().iter().map(|f| f.direction).collect();
+    assert_eq!(
+        directions,
+        [
+            Direction::ClientToServer,
+            Direction::ServerToClient,
+            Direction::ClientToServer,
+            Direction::ServerToClient,
+            Direction::ClientToServer,
+            Direction::ServerToClient,
+        ]
+    );
+    assert!(capture
+        .frames()
+        .windows(2)
+        .all(|pair| pair[0].elapsed <= pair[1].elapsed));
+
+    let client_messages = capture.client_messages();
+    let Some(client_message::Message::JoinRequest(join)) = &client_messages[1].message else {
+        panic!(
+            "Expected captured JoinRequest, got {:?}",
+            client_messages[1]
+        );
+    };
+    assert_eq!(join.meeting_id, MEETING_ID);
+    assert!(join.join_token.is_empty());
+    assert!(join.binding_token.is_empty());
+
+    let server_messages = capture.server_messages();
+    let Some(server_message::Message::JoinResponse(response)) = &server_messages[1].message else {
+        panic!(
+            "Expected captured JoinResponse, got {:?}",
+            server_messages[1]
+        );
+    };
+    assert!(response.binding_token.is_empty());
+    assert!(!response.correlation_id.is_empty());
+
+    // Nothing secret reaches disk in any form
+    let raw = std::fs::read(&path).unwrap();
+    let raw = String::from_utf8_lossy(&raw);
+    assert!(!raw.contains(&token));
+    assert!(!raw.contains("stale-binding-secret"));
+}
+
+#[tokio::test]
+async fn replayed_capture_draws_the_same_answers() {
+    // Record against one MC...
+    let recording_stack = build_test_stack("mc-capture-record").await;
+    seed_meeting_with_mh(&recording_stack, MEETING_ID).await;
+    let capture_dir = tempfile::tempdir().unwrap();
+    let recording_rig = start_rig(&recording_stack, Some(capture_dir.path().to_path_buf())).await;
+    let token = recording_stack
+        .keypair
+        .sign_token(&make_meeting_claims(MEETING_ID));
+    run_session(&recording_rig.url, token).await;
+    let (_, capture) = wait_for_capture(capture_dir.path(), 6).await;
+
+    // ...and replay against a fresh one, which needs its own token
+    let replay_stack = build_test_stack("mc-capture-replay").await;
+    seed_meeting_with_mh(&replay_stack, MEETING_ID).await;
+    let replay_rig = start_rig(&replay_stack, None).await;
+    let token = replay_stack
+        .keypair
+        .sign_token(&make_meeting_claims(MEETING_ID));
+
+    let mut client = MockWebTransport::connect(&replay_rig.url).await.unwrap();
+    let outcome = Replay::new(&capture)
+        .with_join_token(token)
+        .run(&mut client)
+        .await;
+
+    assert_eq!(
+        outcome.recorded_types(),
+        ["server_hello", "join_response", "pong"]
+    );
+    assert_eq!(outcome.replayed_types(), outcome.recorded_types());
+
+    // The replayed session is a real join on the fresh MC
+    let status = replay_stack
+        .controller_handle
+        .get_meeting(MEETING_ID.to_string())
+        .await
+        .expect("meeting should exist");
+    assert_eq!(status.participant_count, 1);
+}
+
+#[tokio::test]
+async fn replay_without_a_join_token_is_rejected() {
+    let recording_stack = build_test_stack("mc-capture-stripped").await;
+    seed_meeting_with_mh(&recording_stack, MEETING_ID).await;
+    let capture_dir = tempfile::tempdir().unwrap();
+    let rig = start_rig(&recording_stack, Some(capture_dir.path().to_path_buf())).await;
+    let token = recording_stack
+        .keypair
+        .sign_token(&make_meeting_claims(MEETING_ID));
+    run_session(&rig.url, token).await;
+    let (_, capture) = wait_for_capture(capture_dir.path(), 6).await;
+
+    // The stripped capture alone must not be able to join anything
+    let mut client = MockWebTransport::connect(&rig.url).await.unwrap();
+    let outcome = Replay::new(&capture).run(&mut client).await;
+
+    assert_eq!(outcome.replayed_types(), ["server_hello", "error"]);
+}
@@ -96,6 +96,19 @@ async fn open_bi_send_join(
     wtransport::Connection,
     wtransport::stream::SendStream,
     wtransport::stream::RecvStream,
+) {
+    open_bi_send_join_with_id(rig_url, meeting_id, token, "").await
+}
+
+async fn open_bi_send_join_with_id(
+    rig_url: &str,
+    meeting_id: &str,
+    token: &str,
+    join_id: &str,
+) -> (
+    wtransport::Connection,
+    wtransport::stream::SendStream,
+    wtransport::stream::RecvStream,
 ) {
     let client = build_client();
     let conn = client.connect(rig_url).await.expect("client connect");
@@ -116,6 +129,7 @@ async fn open_bi_send_join(
             capabilities: None,
             correlation_id: String::new(),
             binding_token: String::new(),
+            join_id: join_id.to_string(),
         })),
     };
     let encoded = msg.encode_to_vec();
@@ -185,6 +199,41 @@ async fn accept_loop_emits_accepted_status_and_session_join_observation_on_happy
         .assert_delta(1);
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn accept_loop_records_join_latency_from_gc_join_id() {
+    // A JoinRequest carrying the GC join ID (UUIDv7 stamped at assignment)
+    // records both `mc_join_latency_seconds` stages; one without records
+    // neither.
+    let bundle = start_rig(2).await;
+    create_meeting_with_mh(&bundle, "meeting-join-latency").await;
+
+    let snap = MetricAssertion::snapshot();
+    let claims = make_meeting_claims("meeting-join-latency");
+    let token = bundle.stack.keypair.sign_token(&claims);
+    let join_id = uuid::Uuid::now_v7().to_string();
+    let (_conn, _send, _recv) =
+        open_bi_send_join_with_id(&bundle.rig.url, "meeting-join-latency", &token, &join_id).await;
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    snap.histogram("mc_join_latency_seconds")
+        .with_labels(&[("stage", "accepted")])
+        .assert_observation_count(1);
+    snap.histogram("mc_join_latency_seconds")
+        .with_labels(&[("stage", "first_roster")])
+        .assert_observation_count(1);
+
+    let snap = MetricAssertion::snapshot();
+    let (_conn2, _send2, _recv2) =
+        open_bi_send_join(&bundle.rig.url, "meeting-join-latency", &token).await;
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    snap.histogram("mc_join_latency_seconds")
+        .with_labels(&[("stage", "first_roster")])
+        .assert_unobserved();
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn accept_loop_emits_rejected_status_when_at_capacity() {
     // `max_connections = 1`. Open conn #1 (valid JWT, pre-registered meeting,
@@ -438,3 +487,25 @@ async fn accept_loop_records_session_join_failure_internal_for_wrong_first_messa
             .assert_delta(0);
     }
 }
+
+// ---------------------------------------------------------------------------
+// Media socket options recorded at bind
+// ---------------------------------------------------------------------------
+
+#[tokio::test(flavor = "current_thread")]
+async fn bind_records_media_socket_gauges() {
+    // `bind()` runs inline in `start_with`, so the gauges are set before the
+    // rig returns. Default config leaves packets unmarked; buffer sizes are
+    // whatever the kernel reports, which is never zero.
+    let snap = MetricAssertion::snapshot();
+    let _bundle = start_rig(4).await;
+
+    snap.gauge("mc_media_socket_dscp").assert_value(0.0);
+    for direction in ["send", "recv"] {
+        snap.gauge("mc_media_socket_buffer_bytes")
+            .with_labels(&[("direction", direction)])
+            .assert_value_in_range(1.0..=f64::MAX);
+    }
+    snap.gauge("mc_media_socket_gso_segments")
+        .assert_value_in_range(1.0..=f64::MAX);
+}
@@ -0,0 +1,192 @@
+//! Deterministic cryptographic fixtures for session binding tests
+//!
+//! Provides fixed binding-token master secrets and precomputed tokens so
+//! binding tests are reproducible and don't depend on per-test keys.
+//!
+//! The precomputed tokens follow ADR-0023:
+//! ```text
+//! meeting_key   = HKDF-SHA256(master_secret, salt=meeting_id, info="session-binding")
+//! binding_token = HMAC-SHA256(meeting_key, correlation_id || participant_id || nonce)
+//! ```
+//! over [`TEST_BINDING_MEETING_ID`], [`TEST_BINDING_CORRELATION_ID`],
+//! [`TEST_BINDING_PARTICIPANT_ID`] and [`TEST_BINDING_NONCE`]. If the
+//! derivation in `SessionBindingManager` changes, these vectors stop
+//! validating and the tests below fail.
+
+use common::media_token::MediaTokenKey;
+use common::secret::SecretBox;
+use mc_service::actors::session::BINDING_TOKEN_TTL;
+use mc_service::actors::{SessionBindingManager, StoredBinding};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Fixed 32-byte binding-token master secret (`0x00..=0x1f`).
+pub const TEST_BINDING_SECRET: [u8; 32] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+
+/// [`TEST_BINDING_SECRET`] base64-encoded, as `MC_BINDING_TOKEN_SECRET` expects.
+pub const TEST_BINDING_SECRET_B64: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+
+/// A second fixed master secret (`0x20..=0x3f`), for wrong-key tests.
+pub const OTHER_BINDING_SECRET: [u8; 32] = [
+    0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3a, 0x3b, 0x3c, 0x3d, 0x3e, 0x3f,
+];
+
+/// Fixed 32-byte media token secret (`0x40..=0x5f`), shared by the MC
+/// accept-loop rig and MH tests that accept its tokens.
+pub const TEST_MEDIA_TOKEN_SECRET: [u8; 32] = [
+    0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+    0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x5b, 0x5c, 0x5d, 0x5e, 0x5f,
+];
+
+/// Media token key derived from [`TEST_MEDIA_TOKEN_SECRET`].
+#[must_use]
+pub fn test_media_token_key() -> MediaTokenKey {
+    match MediaTokenKey::from_secret(&TEST_MEDIA_TOKEN_SECRET) {
+        Ok(key) => key,
+        Err(e) => unreachable!("fixture secret is 32 bytes: {e}"),
+    }
+}
+
+/// Meeting ID the precomputed tokens are bound to.
+pub const TEST_BINDING_MEETING_ID: &str = "meeting-binding-fixture";
+
+/// Correlation ID the precomputed tokens are bound to.
+pub const TEST_BINDING_CORRELATION_ID: &str = "01920000-0000-7000-8000-000000000001";
+
+/// Participant ID the precomputed tokens are bound to.
+pub const TEST_BINDING_PARTICIPANT_ID: &str = "part-binding-fixture";
+
+/// User ID stored alongside the fixture bindings.
+pub const TEST_BINDING_USER_ID: &str = "user-binding-fixture";
+
+/// Nonce the precomputed tokens are bound to (16 bytes, hex-encoded).
+pub const TEST_BINDING_NONCE: &str = "000102030405060708090a0b0c0d0e0f";
+
+/// Valid token for the fixture inputs under [`TEST_BINDING_SECRET`].
+pub const VALID_BINDING_TOKEN: &str =
+    "3adbca8a479e615f5423185b8d2cf250a008e97885ad731f25385b258bbffcdb";
+
+/// Token for the fixture inputs under [`OTHER_BINDING_SECRET`].
+///
+/// Well-formed, but invalid for a manager built from [`TEST_BINDING_SECRET`].
+pub const WRONG_KEY_BINDING_TOKEN: &str =
+    "3cea98657b6e6fdd2a67021ff01cb044b523d6c80ee4d0bed800d0f694e53b16";
+
+/// [`VALID_BINDING_TOKEN`] with its last hex digit changed.
+pub const TAMPERED_BINDING_TOKEN: &str =
+    "3adbca8a479e615f5423185b8d2cf250a008e97885ad731f25385b258bbffcdc";
+
+/// [`VALID_BINDING_TOKEN`] cut to 16 bytes.
+pub const TRUNCATED_BINDING_TOKEN: &str = "3adbca8a479e615f5423185b8d2cf250";
+
+/// A 64-character token that is not hex.
+pub const NON_HEX_BINDING_TOKEN: &str =
+    "zzdbca8a479e615f5423185b8d2cf250a008e97885ad731f25385b258bbffcdb";
+
+/// Every invalid token fixture, labelled for assertion messages.
+pub const INVALID_BINDING_TOKENS: &[(&str, &str)] = &[
+    ("wrong key", WRONG_KEY_BINDING_TOKEN),
+    ("tampered", TAMPERED_BINDING_TOKEN),
+    ("truncated", TRUNCATED_BINDING_TOKEN),
+    ("non-hex", NON_HEX_BINDING_TOKEN),
+    ("empty", ""),
+];
+
+/// [`TEST_BINDING_SECRET`] wrapped for `SessionBindingManager` and
+/// `MeetingControllerActorHandle`.
+#[must_use]
+pub fn test_binding_secret() -> SecretBox<Vec<u8>> {
+    SecretBox::new(Box::new(TEST_BINDING_SECRET.to_vec()))
+}
+
+/// Binding manager over [`TEST_BINDING_SECRET`].
+///
+/// Validates [`VALID_BINDING_TOKEN`] and rejects [`INVALID_BINDING_TOKENS`].
+#[must_use]
+pub fn test_binding_manager() -> SessionBindingManager {
+    SessionBindingManager::new(test_binding_secret())
+}
+
+/// Stored binding for the fixture inputs, created now.
+#[must_use]
+pub fn valid_stored_binding() -> StoredBinding {
+    StoredBinding::new(
+        TEST_BINDING_CORRELATION_ID.to_string(),
+        TEST_BINDING_PARTICIPANT_ID.to_string(),
+        TEST_BINDING_USER_ID.to_string(),
+        TEST_BINDING_NONCE.to_string(),
+        VALID_BINDING_TOKEN.to_string(),
+    )
+}
+
+/// Stored binding for the fixture inputs whose nonce is past the binding TTL.
+///
+/// The token itself still validates; only the binding's age makes it
+/// unusable for reconnection. Works under paused tokio time.
+#[must_use]
+pub fn expired_stored_binding() -> StoredBinding {
+    let mut binding = valid_stored_binding();
+    binding.created_at = Instant::now()
+        .checked_sub(BINDING_TOKEN_TTL + Duration::from_secs(1))
+        .expect("clock should be past the binding TTL");
+    binding
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::engine::general_purpose;
+    use base64::Engine;
+
+    fn validate(manager: &SessionBindingManager, token: &str) -> bool {
+        manager.validate_token(
+            TEST_BINDING_MEETING_ID,
+            TEST_BINDING_CORRELATION_ID,
+            TEST_BINDING_PARTICIPANT_ID,
+            TEST_BINDING_NONCE,
+            token,
+        )
+    }
+
+    #[test]
+    fn test_valid_token_validates() {
+        assert!(validate(&test_binding_manager(), VALID_BINDING_TOKEN));
+    }
+
+    #[test]
+    fn test_invalid_tokens_rejected() {
+        let manager = test_binding_manager();
+        for (label, token) in INVALID_BINDING_TOKENS {
+            assert!(
+                !validate(&manager, token),
+                "{label} token should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_wrong_key_token_validates_under_other_secret() {
+        let manager =
+            SessionBindingManager::new(SecretBox::new(Box::new(OTHER_BINDING_SECRET.to_vec())));
+        assert!(validate(&manager, WRONG_KEY_BINDING_TOKEN));
+        assert!(!validate(&manager, VALID_BINDING_TOKEN));
+    }
+
+    #[test]
+    fn test_secret_b64_matches_secret() {
+        let decoded = general_purpose::STANDARD
+            .decode(TEST_BINDING_SECRET_B64)
+            .unwrap();
+        assert_eq!(decoded, TEST_BINDING_SECRET);
+    }
+
+    #[tokio::test]
+    async fn test_stored_binding_expiry() {
+        assert!(!valid_stored_binding().is_expired());
+        assert!(expired_stored_binding().is_expired());
+    }
+}
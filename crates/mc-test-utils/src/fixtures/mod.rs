@@ -12,6 +12,7 @@
 // pub mod binding_tokens;
 // pub mod messages;
 
+use common::types::{CorrelationId, ParticipantId};
 use uuid::Uuid;
 
 /// Test meeting fixture.
@@ -137,9 +138,9 @@ impl TestBindingToken {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            correlation_id: format!("corr-{}", Uuid::new_v4()),
+            correlation_id: CorrelationId::new().to_string(),
             user_id: format!("user-{}", Uuid::new_v4()),
-            participant_id: format!("part-{}", Uuid::new_v4()),
+            participant_id: ParticipantId::new().to_string(),
             nonce: format!("nonce-{}", Uuid::new_v4()),
             // In a real implementation, this would be HMAC-SHA256
             // For testing, we use a placeholder
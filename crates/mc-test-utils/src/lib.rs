@@ -12,7 +12,10 @@
 //! - `mock_redis` - In-memory Redis mock for state testing
 //! - `mock_webtransport` - Mock WebTransport client for signaling tests
 //! - `fixtures` - Pre-configured test data (meetings, participants, tokens)
+//! - `crypto_fixtures` - Fixed binding-token secrets and precomputed tokens
 //! - `assertions` - State verification helpers
+//! - `simulation` - Seeded, virtual-time simulation of the actor system
+//! - `replay` - Replay of MC session captures through `mock_webtransport`
 //!
 //! ## Usage
 //!
@@ -72,16 +75,19 @@
 // pub mod mock_gc;
 // pub mod mock_mh;
 // pub mod mock_redis;
-// pub mod mock_webtransport;
+pub mod simulation;
 // pub mod fixtures;
 // pub mod assertions;
 
 // Placeholder modules for skeleton
+pub mod crypto_fixtures;
 pub mod fixtures;
 pub mod jwt_test;
 pub mod mock_gc;
 pub mod mock_mh;
 pub mod mock_redis;
+pub mod mock_webtransport;
+pub mod replay;
 
 // Re-export commonly used items
 pub use fixtures::*;
@@ -5,6 +5,10 @@
 //! - Meeting state (participants, subscriptions)
 //! - Fencing token validation (generation-based)
 //!
+//! Meeting keys are laid out by `mc_service::redis::keys`, the same as
+//! `FencedRedisClient`, so tests can inspect them with [`MockRedis::get`]
+//! and exercise [`MockRedis::migrate_meeting_keys`].
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -26,6 +30,9 @@
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
+use mc_service::ids::MeetingId;
+use mc_service::redis::keys;
+
 // TODO (Phase 6b): Full implementation with async traits
 
 /// Mock Redis for testing MC state management.
@@ -36,10 +43,8 @@ pub struct MockRedis {
 
 #[derive(Debug, Default)]
 struct MockRedisInner {
-    /// Key-value store for simple values
+    /// Key-value store, including each meeting's generation key
     kv: HashMap<String, String>,
-    /// Fencing generation per meeting
-    fencing_generations: HashMap<String, u64>,
     /// Session binding state per correlation_id
     sessions: HashMap<String, SessionState>,
     /// Used nonces (for replay prevention)
@@ -72,6 +77,15 @@ pub enum MockRedisError {
     NonceReused,
 }
 
+impl MockRedisInner {
+    /// The meeting's generation, read from its generation key.
+    fn generation(&self, meeting_id: &str) -> Option<u64> {
+        self.kv
+            .get(&keys::generation(&MeetingId::from(meeting_id)))
+            .and_then(|value| value.parse().ok())
+    }
+}
+
 impl Default for MockRedis {
     fn default() -> Self {
         Self::new()
@@ -104,9 +118,10 @@ impl MockRedis {
     pub fn with_fencing_generation(self, meeting_id: &str, generation: u64) -> Self {
         {
             let mut inner = self.inner.lock().unwrap();
-            inner
-                .fencing_generations
-                .insert(meeting_id.to_string(), generation);
+            inner.kv.insert(
+                keys::generation(&MeetingId::from(meeting_id)),
+                generation.to_string(),
+            );
         }
         self
     }
@@ -132,13 +147,13 @@ impl MockRedis {
     /// Get the current fencing generation for a meeting.
     pub fn get_fencing_generation(&self, meeting_id: &str) -> Option<u64> {
         let inner = self.inner.lock().unwrap();
-        inner.fencing_generations.get(meeting_id).copied()
+        inner.generation(meeting_id)
     }
 
     /// Validate fencing generation (returns error if stale).
     pub fn validate_fencing(&self, meeting_id: &str, expected: u64) -> Result<(), MockRedisError> {
         let inner = self.inner.lock().unwrap();
-        if let Some(&current) = inner.fencing_generations.get(meeting_id) {
+        if let Some(current) = inner.generation(meeting_id) {
             if current > expected {
                 return Err(MockRedisError::FencedOut {
                     expected,
@@ -160,7 +175,7 @@ impl MockRedis {
         let mut inner = self.inner.lock().unwrap();
 
         // Validate fencing
-        if let Some(&current) = inner.fencing_generations.get(meeting_id) {
+        if let Some(current) = inner.generation(meeting_id) {
             if current > expected_generation {
                 return Err(MockRedisError::FencedOut {
                     expected: expected_generation,
@@ -203,11 +218,31 @@ impl MockRedis {
         inner.kv.insert(key.to_string(), value.to_string());
     }
 
+    /// Move a meeting's keys from every older schema version to the
+    /// current one, as `FencedRedisClient::migrate_meeting_keys` does.
+    /// Returns the number of keys moved.
+    pub fn migrate_meeting_keys(&self, meeting_id: &str) -> u64 {
+        let meeting_id = MeetingId::from(meeting_id);
+        let mut inner = self.inner.lock().unwrap();
+        let mut moved = 0;
+        for version in 0..keys::SCHEMA_VERSION {
+            for rename in keys::migration(&meeting_id, version) {
+                if inner.kv.contains_key(&rename.to) {
+                    continue;
+                }
+                if let Some(value) = inner.kv.remove(&rename.from) {
+                    inner.kv.insert(rename.to, value);
+                    moved += 1;
+                }
+            }
+        }
+        moved
+    }
+
     /// Clear all state.
     pub fn clear(&self) {
         let mut inner = self.inner.lock().unwrap();
         inner.kv.clear();
-        inner.fencing_generations.clear();
         inner.sessions.clear();
         inner.used_nonces.clear();
     }
@@ -301,4 +336,28 @@ mod tests {
         assert!(redis.get_session("corr-1").is_some());
         assert_eq!(redis.get_fencing_generation("meeting-1"), Some(10));
     }
+
+    #[test]
+    fn test_generation_uses_client_key_schema() {
+        let redis = MockRedis::new().with_fencing_generation("meeting-1", 3);
+        assert_eq!(
+            redis.get(&keys::generation(&MeetingId::new("meeting-1"))),
+            Some("3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_migrate_meeting_keys_moves_legacy_keys() {
+        let redis = MockRedis::new();
+        redis.set("meeting:meeting-1:generation", "7");
+        redis.set("meeting:meeting-1:mh", "{}");
+
+        assert_eq!(redis.migrate_meeting_keys("meeting-1"), 2);
+        assert_eq!(redis.get_fencing_generation("meeting-1"), Some(7));
+        assert!(redis.get("meeting:meeting-1:mh").is_none());
+        assert_eq!(redis.get("v1:meeting:meeting-1:mh"), Some("{}".to_string()));
+
+        // Already current: nothing to move
+        assert_eq!(redis.migrate_meeting_keys("meeting-1"), 0);
+    }
 }
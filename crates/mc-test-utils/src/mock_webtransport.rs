@@ -0,0 +1,142 @@
+//! Mock WebTransport client for signaling tests.
+//!
+//! Connects to a real MC WebTransport endpoint (certificate validation off,
+//! for the self-signed certs test rigs use), opens the signaling stream and
+//! speaks MC's framing: a 4-byte big-endian length prefix, then a protobuf
+//! `ClientMessage` or `ServerMessage`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use mc_test_utils::mock_webtransport::MockWebTransport;
+//!
+//! let mut client = MockWebTransport::connect(&rig.url).await?;
+//! client.send(client_message::Message::JoinRequest(join)).await?;
+//! let response = client.recv().await?;
+//! ```
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use bytes::{BufMut, Bytes, BytesMut};
+use prost::Message;
+use proto_gen::dark_tower::signaling::v1::{client_message, ClientMessage, ServerMessage};
+use wtransport::stream::{RecvStream, SendStream};
+use wtransport::{ClientConfig, Connection, Endpoint};
+
+/// How long [`MockWebTransport::recv`] waits for a frame by default.
+pub const DEFAULT_RECV_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A signaling client on one WebTransport bidirectional stream.
+pub struct MockWebTransport {
+    // Dropping the connection closes the streams
+    _connection: Connection,
+    send: SendStream,
+    recv: RecvStream,
+    recv_timeout: Duration,
+}
+
+impl MockWebTransport {
+    /// Connect to `url` (e.g. `https://127.0.0.1:4433`) and open the
+    /// signaling stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the endpoint cannot be created, the connection
+    /// fails, or the stream cannot be opened.
+    pub async fn connect(url: &str) -> anyhow::Result<Self> {
+        let config = ClientConfig::builder()
+            .with_bind_default()
+            .with_no_cert_validation()
+            .build();
+        let endpoint = Endpoint::client(config).context("client endpoint")?;
+        let connection = endpoint.connect(url).await.context("connect")?;
+        let (send, recv) = connection
+            .open_bi()
+            .await
+            .context("open bidirectional stream")?
+            .await
+            .context("bidirectional stream ready")?;
+
+        Ok(Self {
+            _connection: connection,
+            send,
+            recv,
+            recv_timeout: DEFAULT_RECV_TIMEOUT,
+        })
+    }
+
+    /// Set how long receives wait before giving up.
+    #[must_use]
+    pub fn with_recv_timeout(mut self, recv_timeout: Duration) -> Self {
+        self.recv_timeout = recv_timeout;
+        self
+    }
+
+    /// Send `message` in a `ClientMessage` envelope.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream write fails.
+    pub async fn send(&mut self, message: client_message::Message) -> anyhow::Result<()> {
+        let envelope = ClientMessage {
+            message: Some(message),
+            trace_parent: String::new(),
+            trace_state: String::new(),
+        };
+        self.send_raw(&envelope.encode_to_vec()).await
+    }
+
+    /// Send one frame with `payload` as is, valid protobuf or not.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload does not fit the length prefix or
+    /// the stream write fails.
+    pub async fn send_raw(&mut self, payload: &[u8]) -> anyhow::Result<()> {
+        let len = u32::try_from(payload.len()).context("payload too large to frame")?;
+        let mut frame = BytesMut::with_capacity(4 + payload.len());
+        frame.put_u32(len);
+        frame.put_slice(payload);
+        self.send.write_all(&frame).await.context("write frame")
+    }
+
+    /// Receive and decode the next `ServerMessage`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no frame arrives within the receive timeout, the
+    /// stream ends, or the frame does not decode.
+    pub async fn recv(&mut self) -> anyhow::Result<ServerMessage> {
+        let payload = self
+            .recv_raw()
+            .await
+            .ok_or_else(|| anyhow!("no server frame within {:?}", self.recv_timeout))?;
+        ServerMessage::decode(payload).context("decode ServerMessage")
+    }
+
+    /// Receive the next frame's payload, or `None` if the stream ended or
+    /// nothing arrived within the receive timeout.
+    pub async fn recv_raw(&mut self) -> Option<Bytes> {
+        let read = async {
+            let mut len_buf = [0u8; 4];
+            self.recv.read_exact(&mut len_buf).await.ok()?;
+            let mut payload = vec![0u8; u32::from_be_bytes(len_buf) as usize];
+            self.recv.read_exact(&mut payload).await.ok()?;
+            Some(Bytes::from(payload))
+        };
+        tokio::time::timeout(self.recv_timeout, read)
+            .await
+            .ok()
+            .flatten()
+    }
+
+    /// Close the client's side of the stream, as a client leaving does.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream cannot be finished.
+    pub async fn finish(&mut self) -> anyhow::Result<()> {
+        self.send.finish().await.context("finish stream")
+    }
+}
@@ -0,0 +1,272 @@
+//! Replay of MC session captures against a test MC.
+//!
+//! An MC started with `MC_SESSION_CAPTURE_DIR` records each signaling
+//! session (`mc_service::webtransport::capture`). [`Replay`] sends the
+//! client's frames from such a capture to a test MC through a
+//! [`MockWebTransport`], keeping them in the same order relative to MC's
+//! answers: before each client frame it waits until the test MC has sent as
+//! many frames as the original had at that point. The [`ReplayOutcome`]
+//! holds what MC sent then next to what it sends now.
+//!
+//! Captures have the join token stripped, so the test MC needs one it will
+//! accept ([`Replay::with_join_token`]). IDs MC generates (participant,
+//! correlation) differ between runs; compare message types or specific
+//! fields rather than whole messages.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use mc_test_utils::mock_webtransport::MockWebTransport;
+//! use mc_test_utils::replay::{Replay, SessionCapture};
+//!
+//! let capture = SessionCapture::load("tests/captures/duplicate-join.dtcap");
+//! let mut client = MockWebTransport::connect(&rig.url).await?;
+//! let outcome = Replay::new(&capture)
+//!     .with_join_token(keypair.sign_token(&claims))
+//!     .run(&mut client)
+//!     .await;
+//! assert_eq!(outcome.replayed_types(), outcome.recorded_types());
+//! ```
+
+use std::path::Path;
+use std::time::Duration;
+
+use bytes::Bytes;
+use mc_service::webtransport::capture::{parse_capture, CaptureError, CapturedFrame, Direction};
+use prost::Message;
+use proto_gen::dark_tower::signaling::v1::{
+    client_message, server_message, ClientMessage, ServerMessage,
+};
+
+use crate::mock_webtransport::MockWebTransport;
+
+/// A parsed session capture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionCapture {
+    frames: Vec<CapturedFrame>,
+}
+
+impl SessionCapture {
+    /// Load a capture file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file cannot be read or is not a valid capture.
+    #[must_use]
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let data = std::fs::read(path)
+            .unwrap_or_else(|e| panic!("failed to read capture {}: {e}", path.display()));
+        Self::from_bytes(&data)
+            .unwrap_or_else(|e| panic!("invalid capture {}: {e}", path.display()))
+    }
+
+    /// Parse capture file contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CaptureError`] if the data is not a valid capture.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, CaptureError> {
+        parse_capture(data).map(|frames| Self { frames })
+    }
+
+    /// All frames, in recorded order.
+    #[must_use]
+    pub fn frames(&self) -> &[CapturedFrame] {
+        &self.frames
+    }
+
+    /// The client's messages, in order. Frames that did not decode when
+    /// captured are skipped.
+    #[must_use]
+    pub fn client_messages(&self) -> Vec<ClientMessage> {
+        self.decoded(Direction::ClientToServer)
+    }
+
+    /// MC's messages, in order.
+    #[must_use]
+    pub fn server_messages(&self) -> Vec<ServerMessage> {
+        self.decoded(Direction::ServerToClient)
+    }
+
+    fn decoded<M: Message + Default>(&self, direction: Direction) -> Vec<M> {
+        self.frames
+            .iter()
+            .filter(|frame| frame.direction == direction && !frame.payload.is_empty())
+            .filter_map(|frame| M::decode(frame.payload.clone()).ok())
+            .collect()
+    }
+}
+
+/// Replays a [`SessionCapture`]'s client frames.
+#[derive(Debug, Clone)]
+pub struct Replay<'a> {
+    capture: &'a SessionCapture,
+    join_token: Option<String>,
+    original_timing: bool,
+}
+
+impl<'a> Replay<'a> {
+    /// Replay `capture` as fast as the test MC answers.
+    #[must_use]
+    pub fn new(capture: &'a SessionCapture) -> Self {
+        Self {
+            capture,
+            join_token: None,
+            original_timing: false,
+        }
+    }
+
+    /// Put `token` in every replayed `JoinRequest`.
+    #[must_use]
+    pub fn with_join_token(mut self, token: impl Into<String>) -> Self {
+        self.join_token = Some(token.into());
+        self
+    }
+
+    /// Also wait out the recorded gap before each client frame, for bugs
+    /// that depend on timing (keepalive, grace periods). Pair with a paused
+    /// Tokio clock to keep the test fast.
+    #[must_use]
+    pub fn with_original_timing(mut self) -> Self {
+        self.original_timing = true;
+        self
+    }
+
+    /// Send the capture's client frames through `client` and collect what
+    /// the test MC sends back.
+    ///
+    /// Stops sending once the MC closes the stream, since the session has
+    /// diverged from the capture by then.
+    pub async fn run(self, client: &mut MockWebTransport) -> ReplayOutcome {
+        let mut outcome = ReplayOutcome::default();
+        let mut recorded_so_far = 0;
+        let mut last_sent_at = Duration::ZERO;
+        let mut closed = false;
+
+        for frame in &self.capture.frames {
+            match frame.direction {
+                Direction::ServerToClient => {
+                    recorded_so_far += 1;
+                    outcome.recorded.push(decode_server(&frame.payload));
+                }
+                Direction::ClientToServer => {
+                    if !closed {
+                        closed = !receive_until(client, &mut outcome, recorded_so_far).await;
+                    }
+                    if closed {
+                        continue;
+                    }
+                    if self.original_timing {
+                        tokio::time::sleep(frame.elapsed.saturating_sub(last_sent_at)).await;
+                    }
+                    last_sent_at = frame.elapsed;
+                    closed = client
+                        .send_raw(&self.prepare(&frame.payload))
+                        .await
+                        .is_err();
+                }
+            }
+        }
+
+        if !closed {
+            receive_until(client, &mut outcome, recorded_so_far).await;
+        }
+        outcome
+    }
+
+    /// The frame to send for a captured client payload.
+    fn prepare(&self, payload: &Bytes) -> Bytes {
+        let Some(token) = &self.join_token else {
+            return payload.clone();
+        };
+        match ClientMessage::decode(payload.clone()) {
+            Ok(mut message) => {
+                if let Some(client_message::Message::JoinRequest(join)) = &mut message.message {
+                    join.join_token.clone_from(token);
+                }
+                Bytes::from(message.encode_to_vec())
+            }
+            // Sent as captured so MC sees the same malformed frame
+            Err(_) => payload.clone(),
+        }
+    }
+}
+
+/// Receive from `client` until `outcome.replayed` has `count` messages.
+/// Returns `false` if the stream ended or went quiet first.
+async fn receive_until(
+    client: &mut MockWebTransport,
+    outcome: &mut ReplayOutcome,
+    count: usize,
+) -> bool {
+    while outcome.replayed.len() < count {
+        match client.recv_raw().await {
+            Some(payload) => outcome.replayed.push(decode_server(&payload)),
+            None => return false,
+        }
+    }
+    true
+}
+
+fn decode_server(payload: &Bytes) -> Option<ServerMessage> {
+    ServerMessage::decode(payload.clone()).ok()
+}
+
+/// What MC sent in the captured session and in the replay, in order.
+/// `None` marks a frame that did not decode.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReplayOutcome {
+    /// MC's messages in the capture.
+    pub recorded: Vec<Option<ServerMessage>>,
+    /// The test MC's messages during the replay.
+    pub replayed: Vec<Option<ServerMessage>>,
+}
+
+impl ReplayOutcome {
+    /// Message type of each recorded MC message.
+    #[must_use]
+    pub fn recorded_types(&self) -> Vec<&'static str> {
+        self.recorded.iter().map(message_type).collect()
+    }
+
+    /// Message type of each replayed MC message.
+    #[must_use]
+    pub fn replayed_types(&self) -> Vec<&'static str> {
+        self.replayed.iter().map(message_type).collect()
+    }
+}
+
+/// Short name of a server message's type.
+#[must_use]
+pub fn message_type(message: &Option<ServerMessage>) -> &'static str {
+    use server_message::Message;
+
+    let Some(message) = message else {
+        return "undecodable";
+    };
+    match &message.message {
+        None => "empty",
+        Some(Message::JoinResponse(_)) => "join_response",
+        Some(Message::ParticipantJoined(_)) => "participant_joined",
+        Some(Message::ParticipantLeft(_)) => "participant_left",
+        Some(Message::StreamPublished(_)) => "stream_published",
+        Some(Message::StreamAssignments(_)) => "stream_assignments",
+        Some(Message::StreamQualityUpdate(_)) => "stream_quality_update",
+        Some(Message::Error(_)) => "error",
+        Some(Message::ParticipantMuteUpdate(_)) => "participant_mute_update",
+        Some(Message::UnmuteRequest(_)) => "unmute_request",
+        Some(Message::UnmuteResponse(_)) => "unmute_response",
+        Some(Message::RedirectToMc(_)) => "redirect_to_mc",
+        Some(Message::PollCreated(_)) => "poll_created",
+        Some(Message::PollResults(_)) => "poll_results",
+        Some(Message::QuestionUpdated(_)) => "question_updated",
+        Some(Message::DataChannelMessage(_)) => "data_channel_message",
+        Some(Message::LiveStreamStatus(_)) => "live_stream_status",
+        Some(Message::RecordingStateChanged(_)) => "recording_state_changed",
+        Some(Message::ServerHello(_)) => "server_hello",
+        Some(Message::Ping(_)) => "ping",
+        Some(Message::Pong(_)) => "pong",
+        Some(Message::ConnectionClosed(_)) => "connection_closed",
+    }
+}
@@ -0,0 +1,991 @@
+//! Seeded simulation of the MC actor system.
+//!
+//! Drives a real [`MeetingControllerActorHandle`] through thousands of
+//! random joins, leaves, dropped connections, reconnects, meeting
+//! migrations and meeting ends, mixed with faults: forged, rotated and
+//! borrowed binding tokens, reconnects over a live connection, joins to
+//! meetings this MC does not host, repeated leaves, and clock jumps past
+//! the reconnect grace period. After every event the actors are checked
+//! against a model of what each client believes, and the run panics with
+//! the seed, step and event of the first mismatch.
+//!
+//! Time is virtual. Run the simulation from a
+//! `#[tokio::test(start_paused = true)]` test so the grace period and the
+//! meeting's grace check fire instantly and in order; the seed fixes the
+//! sequence of events.
+//!
+//! MC keeps the participant roster in memory (Redis holds only fencing and
+//! MH assignment state), so the counts checked are the ones MC reports
+//! outward: the `ControllerMetrics` sent in GC heartbeats, the controller
+//! status, and `get_meeting`.
+//!
+//! # Invariants
+//!
+//! - No ghost participants: every participant a meeting reports is one the
+//!   model still expects, with the same connected/disconnected status.
+//! - A disconnected participant is held for the grace period and removed
+//!   within one grace check after it.
+//! - A reconnect inside the grace period resumes the session, one after it
+//!   starts a new session, and a bad binding never resumes anything.
+//! - Heartbeat, `get_meeting` and connection counts match the actors.
+//! - Live connections stay open; closed ones are closed with the right
+//!   `CloseReason`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use mc_test_utils::simulation::{Simulation, SimulationConfig};
+//!
+//! #[tokio::test(start_paused = true)]
+//! async fn test_actor_simulation() {
+//!     let report = Simulation::new(SimulationConfig::with_seed(7)).run().await;
+//!     assert!(report.resumed > 0);
+//! }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
+use crate::crypto_fixtures::test_binding_secret;
+use mc_service::actors::{
+    ActorMetrics, ControllerMetrics, JoinResult, MeetingActorHandle, MeetingControllerActorHandle,
+    MeetingServices, ParticipantActorHandle, ParticipantStatus, SessionResume,
+};
+use mc_service::errors::McError;
+use mc_service::mh_connection_registry::MhConnectionRegistry;
+use prost::Message;
+use proto_gen::dark_tower::signaling::v1::{server_message, CloseReason, ServerMessage};
+use tokio::sync::mpsc::{self, error::TryRecvError};
+use tokio::time::Instant;
+
+/// Virtual time given to the actors after each event. Covers a participant
+/// close (50ms flush) plus the meeting's wait for the closed task.
+const SETTLE: Duration = Duration::from_millis(250);
+
+/// How often the meeting actor checks disconnect grace periods.
+const GRACE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Outbound buffer of each simulated connection.
+const STREAM_BUFFER: usize = 256;
+
+/// Departed participant IDs remembered per meeting for repeated leaves.
+const MAX_DEPARTED: usize = 16;
+
+/// Panic with the seed, step and event that reproduce the failure.
+macro_rules! violation {
+    ($sim:expr, $($arg:tt)*) => {
+        panic!(
+            "simulation invariant violated (seed {}, step {}, {}): {}",
+            $sim.config.seed,
+            $sim.step,
+            $sim.event,
+            format_args!($($arg)*)
+        )
+    };
+}
+
+/// Simulation parameters.
+#[derive(Debug, Clone)]
+pub struct SimulationConfig {
+    /// Seed for the event sequence.
+    pub seed: u64,
+    /// Number of events to run.
+    pub steps: usize,
+    /// Meeting slots. Each slot's meeting is created, migrated away or
+    /// ended, and created again over the run.
+    pub meetings: usize,
+    /// Distinct users joining.
+    pub users: usize,
+    /// How long a disconnected participant is held for reconnection.
+    pub disconnect_grace_period: Duration,
+    /// Chance per step, in thousandths, that the event is a fault.
+    pub fault_per_mille: u32,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            steps: 2000,
+            meetings: 4,
+            users: 12,
+            disconnect_grace_period: Duration::from_secs(30),
+            fault_per_mille: 100,
+        }
+    }
+}
+
+impl SimulationConfig {
+    /// Default parameters with the given seed.
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            ..Self::default()
+        }
+    }
+}
+
+/// What a completed run exercised.
+#[derive(Debug, Clone, Default)]
+pub struct SimulationReport {
+    /// Seed the run used.
+    pub seed: u64,
+    /// Events run, by name.
+    pub events: BTreeMap<&'static str, usize>,
+    /// Reconnects that resumed the earlier session.
+    pub resumed: usize,
+    /// Reconnects that started a new session.
+    pub rejoined: usize,
+    /// Participants the actors removed after the grace period.
+    pub expired: usize,
+    /// Most participants hosted at once.
+    pub peak_participants: usize,
+}
+
+impl SimulationReport {
+    /// How many times the named event ran.
+    #[must_use]
+    pub fn count(&self, event: &str) -> usize {
+        self.events.get(event).copied().unwrap_or(0)
+    }
+}
+
+/// SplitMix64: tiny, seedable, and stable across platforms and releases.
+#[derive(Debug, Clone)]
+struct SimRng(u64);
+
+impl SimRng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`; `n` must be non-zero.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    fn per_mille(&mut self, chance: u32) -> bool {
+        self.next_u64() % 1000 < u64::from(chance)
+    }
+
+    fn duration_up_to(&mut self, max: Duration) -> Duration {
+        Duration::from_millis(self.next_u64() % (max.as_millis() as u64 + 1))
+    }
+}
+
+/// A client connection the model believes is open.
+struct SimConnection {
+    handle: ParticipantActorHandle,
+    stream_rx: mpsc::Receiver<Bytes>,
+}
+
+/// A session as its client sees it.
+struct SimSession {
+    user_id: String,
+    participant_id: String,
+    correlation_id: String,
+    binding_token: String,
+    /// Binding replaced by the last resume; it must not resume anything.
+    retired: Option<(String, String)>,
+    connection: Option<SimConnection>,
+    disconnected_at: Option<Instant>,
+}
+
+impl SimSession {
+    fn resume(&self) -> SessionResume {
+        SessionResume {
+            correlation_id: self.correlation_id.clone(),
+            binding_token: self.binding_token.clone(),
+        }
+    }
+}
+
+struct SimMeeting {
+    meeting_id: String,
+    active: bool,
+    handle: Option<MeetingActorHandle>,
+    sessions: Vec<SimSession>,
+    departed: Vec<String>,
+}
+
+/// A connection the actors were asked to close, checked after settling.
+struct PendingClose {
+    participant_id: String,
+    stream_rx: mpsc::Receiver<Bytes>,
+    expected: Option<CloseReason>,
+}
+
+/// Seeded driver for the actor system. See the module docs.
+pub struct Simulation {
+    config: SimulationConfig,
+    rng: SimRng,
+    controller: MeetingControllerActorHandle,
+    actor_metrics: Arc<ActorMetrics>,
+    controller_metrics: Arc<ControllerMetrics>,
+    meetings: Vec<SimMeeting>,
+    closing: Vec<PendingClose>,
+    next_id: u64,
+    step: usize,
+    event: String,
+    report: SimulationReport,
+}
+
+impl Simulation {
+    /// Spawn a controller with the configured grace period and no hosted
+    /// meetings. Must be called inside a Tokio runtime.
+    #[must_use]
+    pub fn new(config: SimulationConfig) -> Self {
+        let actor_metrics = ActorMetrics::new();
+        let controller_metrics = ControllerMetrics::new();
+        let controller = MeetingControllerActorHandle::with_services(
+            "mc-simulation".to_string(),
+            Arc::clone(&actor_metrics),
+            Arc::clone(&controller_metrics),
+            test_binding_secret(),
+            Arc::new(MhConnectionRegistry::new()),
+            MeetingServices {
+                disconnect_grace_period: Some(config.disconnect_grace_period),
+                ..MeetingServices::default()
+            },
+        );
+        let meetings = (0..config.meetings.max(1))
+            .map(|n| SimMeeting {
+                meeting_id: format!("sim-meeting-{n}"),
+                active: false,
+                handle: None,
+                sessions: Vec::new(),
+                departed: Vec::new(),
+            })
+            .collect();
+
+        Self {
+            rng: SimRng::new(config.seed),
+            report: SimulationReport {
+                seed: config.seed,
+                ..SimulationReport::default()
+            },
+            config,
+            controller,
+            actor_metrics,
+            controller_metrics,
+            meetings,
+            closing: Vec::new(),
+            next_id: 0,
+            step: 0,
+            event: "start".to_string(),
+        }
+    }
+
+    /// Run every step, checking the invariants after each one.
+    ///
+    /// # Panics
+    ///
+    /// On the first invariant violation, naming the seed, step and event.
+    pub async fn run(mut self) -> SimulationReport {
+        for step in 0..self.config.steps {
+            self.step = step;
+            self.next_event().await;
+            self.settle().await;
+            self.check_closes();
+            self.check_invariants().await;
+        }
+        self.controller.cancel();
+        self.report
+    }
+
+    async fn next_event(&mut self) {
+        if self.rng.per_mille(self.config.fault_per_mille) {
+            match self.rng.below(7) {
+                0 => self.bad_resume(BadResume::Forged).await,
+                1 => self.bad_resume(BadResume::Retired).await,
+                2 => self.bad_resume(BadResume::OtherUser).await,
+                3 => self.reconnect_over_live().await,
+                4 => self.join_unhosted().await,
+                5 => self.repeat_leave().await,
+                _ => self.clock_jump().await,
+            }
+        } else {
+            match self.rng.below(100) {
+                0..=37 => self.join().await,
+                38..=49 => self.leave().await,
+                50..=64 => self.disconnect().await,
+                65..=81 => self.reconnect().await,
+                82..=95 => self.advance_time().await,
+                96..=97 => self.migrate().await,
+                _ => self.end_meeting().await,
+            }
+        }
+    }
+
+    fn begin(&mut self, name: &'static str, detail: impl Display) {
+        *self.report.events.entry(name).or_default() += 1;
+        self.event = format!("{name} {detail}");
+    }
+
+    /// Pick a session in a hosted meeting matching `filter`.
+    fn pick_session(&mut self, filter: impl Fn(&SimSession) -> bool) -> Option<(usize, usize)> {
+        let candidates: Vec<(usize, usize)> = self
+            .meetings
+            .iter()
+            .enumerate()
+            .filter(|(_, meeting)| meeting.active)
+            .flat_map(|(m, meeting)| {
+                meeting
+                    .sessions
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, session)| filter(session))
+                    .map(move |(s, _)| (m, s))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        if candidates.is_empty() {
+            return None;
+        }
+        Some(candidates[self.rng.below(candidates.len())])
+    }
+
+    fn pick_active_meeting(&mut self) -> Option<usize> {
+        let active: Vec<usize> = (0..self.meetings.len())
+            .filter(|&m| self.meetings[m].active)
+            .collect();
+        if active.is_empty() {
+            return None;
+        }
+        Some(active[self.rng.below(active.len())])
+    }
+
+    /// Join `meeting_id` through the controller on a fresh connection.
+    async fn connect(
+        &mut self,
+        meeting_id: String,
+        user_id: String,
+        resume: Option<SessionResume>,
+    ) -> (Result<JoinResult, McError>, mpsc::Receiver<Bytes>) {
+        self.next_id += 1;
+        let n = self.next_id;
+        let (stream_tx, stream_rx) = mpsc::channel(STREAM_BUFFER);
+        let result = match self
+            .controller
+            .join_connection(
+                meeting_id,
+                format!("sim-conn-{n}"),
+                user_id,
+                format!("sim-part-{n}"),
+                false,
+                stream_tx,
+                resume,
+            )
+            .await
+        {
+            Ok(rx) => rx
+                .await
+                .unwrap_or_else(|_| Err(McError::Internal("join response dropped".to_string()))),
+            Err(e) => Err(e),
+        };
+        (result, stream_rx)
+    }
+
+    fn add_session(
+        &mut self,
+        m: usize,
+        user_id: String,
+        result: JoinResult,
+        stream_rx: mpsc::Receiver<Bytes>,
+    ) {
+        let meeting = &mut self.meetings[m];
+        meeting.handle = Some(result.meeting_handle);
+        meeting.sessions.push(SimSession {
+            user_id,
+            participant_id: result.participant_id,
+            correlation_id: result.correlation_id,
+            binding_token: result.binding_token,
+            retired: None,
+            connection: Some(SimConnection {
+                handle: result.participant_handle,
+                stream_rx,
+            }),
+            disconnected_at: None,
+        });
+    }
+
+    /// Move session `s` onto a resumed connection, returning the one it
+    /// replaced.
+    fn resume_session(
+        &mut self,
+        m: usize,
+        s: usize,
+        result: JoinResult,
+        stream_rx: mpsc::Receiver<Bytes>,
+    ) -> Option<SimConnection> {
+        let session = &mut self.meetings[m].sessions[s];
+        let correlation_id = std::mem::replace(&mut session.correlation_id, result.correlation_id);
+        let binding_token = std::mem::replace(&mut session.binding_token, result.binding_token);
+        session.retired = Some((correlation_id, binding_token));
+        session.disconnected_at = None;
+        session.connection.replace(SimConnection {
+            handle: result.participant_handle,
+            stream_rx,
+        })
+    }
+
+    /// Stop hosting meeting `m` in the model, expecting its open
+    /// connections to close with `reason`.
+    fn deactivate(&mut self, m: usize, reason: Option<CloseReason>) {
+        let meeting = &mut self.meetings[m];
+        meeting.active = false;
+        meeting.handle = None;
+        meeting.departed.clear();
+        for session in meeting.sessions.drain(..) {
+            if let Some(connection) = session.connection {
+                self.closing.push(PendingClose {
+                    participant_id: session.participant_id,
+                    stream_rx: connection.stream_rx,
+                    expected: reason,
+                });
+            }
+        }
+    }
+
+    async fn join(&mut self) {
+        let m = self.rng.below(self.meetings.len());
+        let meeting_id = self.meetings[m].meeting_id.clone();
+        if !self.meetings[m].active {
+            self.begin("create_meeting", &meeting_id);
+            if let Err(e) = self.controller.create_meeting(meeting_id).await {
+                violation!(self, "create_meeting failed: {e:?}");
+            }
+            self.meetings[m].active = true;
+            return;
+        }
+
+        let user_id = format!("sim-user-{}", self.rng.below(self.config.users.max(1)));
+        self.begin("join", format!("{user_id} -> {meeting_id}"));
+        let (result, stream_rx) = self.connect(meeting_id, user_id.clone(), None).await;
+        let result = result.unwrap_or_else(|e| violation!(self, "join failed: {e:?}"));
+        if result.resumed {
+            violation!(self, "a join without a binding resumed a session");
+        }
+        self.add_session(m, user_id, result, stream_rx);
+    }
+
+    async fn leave(&mut self) {
+        let Some((m, s)) = self.pick_session(|session| session.connection.is_some()) else {
+            return self.join().await;
+        };
+        let participant_id = self.meetings[m].sessions[s].participant_id.clone();
+        self.begin("leave", &participant_id);
+
+        let Some(handle) = self.meetings[m].handle.clone() else {
+            violation!(self, "meeting with participants has no handle");
+        };
+        if let Err(e) = handle.participant_leave(participant_id.clone()).await {
+            violation!(self, "leave failed: {e:?}");
+        }
+
+        let meeting = &mut self.meetings[m];
+        let session = meeting.sessions.remove(s);
+        if meeting.departed.len() >= MAX_DEPARTED {
+            meeting.departed.remove(0);
+        }
+        meeting.departed.push(participant_id.clone());
+        if let Some(connection) = session.connection {
+            self.closing.push(PendingClose {
+                participant_id,
+                stream_rx: connection.stream_rx,
+                expected: None,
+            });
+        }
+    }
+
+    async fn disconnect(&mut self) {
+        let Some((m, s)) = self.pick_session(|session| session.connection.is_some()) else {
+            return self.join().await;
+        };
+        let participant_id = self.meetings[m].sessions[s].participant_id.clone();
+        self.begin("disconnect", participant_id);
+
+        let session = &mut self.meetings[m].sessions[s];
+        if let Some(connection) = session.connection.take() {
+            connection.handle.cancel();
+        }
+        session.disconnected_at = Some(Instant::now());
+    }
+
+    async fn reconnect(&mut self) {
+        let Some((m, s)) = self.pick_session(|session| session.connection.is_none()) else {
+            return self.join().await;
+        };
+        let session = &self.meetings[m].sessions[s];
+        let participant_id = session.participant_id.clone();
+        let user_id = session.user_id.clone();
+        let resume = session.resume();
+        let elapsed = session
+            .disconnected_at
+            .map(|at| Instant::now().saturating_duration_since(at))
+            .unwrap_or_default();
+        self.begin("reconnect", format!("{participant_id} after {elapsed:?}"));
+
+        let meeting_id = self.meetings[m].meeting_id.clone();
+        let (result, stream_rx) = self
+            .connect(meeting_id, user_id.clone(), Some(resume))
+            .await;
+        let result = result.unwrap_or_else(|e| violation!(self, "reconnect failed: {e:?}"));
+
+        // The actor's disconnect time trails the model's by at most SETTLE
+        let grace = self.config.disconnect_grace_period;
+        if result.resumed {
+            if result.participant_id != participant_id {
+                violation!(self, "resumed as {}", result.participant_id);
+            }
+            if elapsed >= grace + SETTLE {
+                violation!(self, "resumed past the {grace:?} grace period");
+            }
+            self.report.resumed += 1;
+            self.resume_session(m, s, result, stream_rx);
+        } else {
+            if elapsed < grace {
+                violation!(self, "not resumed inside the {grace:?} grace period");
+            }
+            self.report.rejoined += 1;
+            self.add_session(m, user_id, result, stream_rx);
+        }
+    }
+
+    async fn advance_time(&mut self) {
+        let by = self
+            .rng
+            .duration_up_to(self.config.disconnect_grace_period * 3 / 2);
+        self.begin("advance_time", format!("{by:?}"));
+        tokio::time::sleep(by).await;
+    }
+
+    async fn migrate(&mut self) {
+        let Some(m) = self.pick_active_meeting() else {
+            return self.join().await;
+        };
+        let meeting_id = self.meetings[m].meeting_id.clone();
+        self.begin("migrate", &meeting_id);
+
+        if let Err(e) = self.controller.remove_meeting(meeting_id).await {
+            violation!(self, "remove_meeting failed: {e:?}");
+        }
+        // Cancelled with the meeting; over WebTransport the bridge loop
+        // reports SERVER_DRAIN
+        self.deactivate(m, None);
+    }
+
+    async fn end_meeting(&mut self) {
+        let Some(m) = self.pick_active_meeting() else {
+            return self.join().await;
+        };
+        let Some(handle) = self.meetings[m].handle.clone() else {
+            // No participant has joined yet, so there is no handle to end it with
+            return self.migrate().await;
+        };
+        let meeting_id = self.meetings[m].meeting_id.clone();
+        self.begin("end_meeting", &meeting_id);
+
+        if let Err(e) = handle.end_meeting("simulation".to_string()).await {
+            violation!(self, "end_meeting failed: {e:?}");
+        }
+        self.deactivate(m, Some(CloseReason::MeetingEnded));
+    }
+
+    /// Resume with a binding that must not resume anything; the connection
+    /// joins as a new participant instead.
+    async fn bad_resume(&mut self, kind: BadResume) {
+        let picked = match kind {
+            BadResume::Retired => self.pick_session(|session| session.retired.is_some()),
+            BadResume::Forged | BadResume::OtherUser => self.pick_session(|_| true),
+        };
+        let Some((m, s)) = picked else {
+            return self.join().await;
+        };
+        let session = &self.meetings[m].sessions[s];
+        let participant_id = session.participant_id.clone();
+        let mut user_id = session.user_id.clone();
+        let mut resume = session.resume();
+        match kind {
+            BadResume::Forged => {
+                let first = if resume.binding_token.starts_with('0') {
+                    "1"
+                } else {
+                    "0"
+                };
+                resume.binding_token.replace_range(..1, first);
+            }
+            BadResume::Retired => {
+                if let Some((correlation_id, binding_token)) = &session.retired {
+                    resume = SessionResume {
+                        correlation_id: correlation_id.clone(),
+                        binding_token: binding_token.clone(),
+                    };
+                }
+            }
+            BadResume::OtherUser => user_id = "sim-intruder".to_string(),
+        }
+        self.begin(kind.name(), &participant_id);
+
+        let meeting_id = self.meetings[m].meeting_id.clone();
+        let (result, stream_rx) = self
+            .connect(meeting_id, user_id.clone(), Some(resume))
+            .await;
+        let result = result.unwrap_or_else(|e| violation!(self, "join failed: {e:?}"));
+        if result.resumed {
+            violation!(self, "resumed {} with a bad binding", result.participant_id);
+        }
+        self.add_session(m, user_id, result, stream_rx);
+    }
+
+    /// Resume a session whose connection is still open, as a client does
+    /// when it reconnects before its old connection times out.
+    async fn reconnect_over_live(&mut self) {
+        let Some((m, s)) = self.pick_session(|session| session.connection.is_some()) else {
+            return self.join().await;
+        };
+        let session = &self.meetings[m].sessions[s];
+        let participant_id = session.participant_id.clone();
+        let user_id = session.user_id.clone();
+        let resume = session.resume();
+        self.begin("reconnect_over_live", &participant_id);
+
+        let meeting_id = self.meetings[m].meeting_id.clone();
+        let (result, stream_rx) = self.connect(meeting_id, user_id, Some(resume)).await;
+        let result = result.unwrap_or_else(|e| violation!(self, "reconnect failed: {e:?}"));
+        if !result.resumed || result.participant_id != participant_id {
+            violation!(self, "connected session was not resumed");
+        }
+        if let Some(stale) = self.resume_session(m, s, result, stream_rx) {
+            self.closing.push(PendingClose {
+                participant_id,
+                stream_rx: stale.stream_rx,
+                expected: Some(CloseReason::Takeover),
+            });
+        }
+    }
+
+    async fn join_unhosted(&mut self) {
+        self.begin("join_unhosted", "sim-meeting-unhosted");
+        let (result, _stream_rx) = self
+            .connect(
+                "sim-meeting-unhosted".to_string(),
+                "sim-user-0".to_string(),
+                None,
+            )
+            .await;
+        if !matches!(result, Err(McError::MeetingNotFound(_))) {
+            violation!(self, "expected MeetingNotFound, got {result:?}");
+        }
+    }
+
+    async fn repeat_leave(&mut self) {
+        let candidates: Vec<usize> = (0..self.meetings.len())
+            .filter(|&m| self.meetings[m].active && !self.meetings[m].departed.is_empty())
+            .collect();
+        if candidates.is_empty() {
+            return self.join().await;
+        }
+        let m = candidates[self.rng.below(candidates.len())];
+        let departed = &self.meetings[m].departed;
+        let participant_id = departed[self.rng.below(departed.len())].clone();
+        self.begin("repeat_leave", &participant_id);
+
+        let Some(handle) = self.meetings[m].handle.clone() else {
+            violation!(self, "meeting with departures has no handle");
+        };
+        let result = handle.participant_leave(participant_id).await;
+        if !matches!(result, Err(McError::ParticipantNotFound(_))) {
+            violation!(self, "expected ParticipantNotFound, got {result:?}");
+        }
+    }
+
+    /// Jump past the grace period and the grace check after it; every
+    /// disconnected participant must be gone afterwards.
+    async fn clock_jump(&mut self) {
+        let by = self.config.disconnect_grace_period + GRACE_CHECK_INTERVAL + SETTLE;
+        self.begin("clock_jump", format!("{by:?}"));
+        tokio::time::sleep(by).await;
+    }
+
+    /// Let the actors react, then drain every open connection. Nothing
+    /// the model believes is open may have been closed.
+    async fn settle(&mut self) {
+        tokio::time::sleep(SETTLE).await;
+
+        for meeting in &mut self.meetings {
+            for session in &mut meeting.sessions {
+                let Some(connection) = &mut session.connection else {
+                    continue;
+                };
+                loop {
+                    match connection.stream_rx.try_recv() {
+                        Ok(frame) => {
+                            if let Some(reason) = close_reason(&frame) {
+                                violation!(
+                                    self,
+                                    "open connection of {} was sent {reason:?}",
+                                    session.participant_id
+                                );
+                            }
+                        }
+                        Err(TryRecvError::Empty) => break,
+                        Err(TryRecvError::Disconnected) => violation!(
+                            self,
+                            "open connection of {} was closed",
+                            session.participant_id
+                        ),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Every connection the actors were asked to close is closed, and
+    /// was told why when there is a reason to give.
+    fn check_closes(&mut self) {
+        for pending in std::mem::take(&mut self.closing) {
+            let PendingClose {
+                participant_id,
+                mut stream_rx,
+                expected,
+            } = pending;
+            let mut reason = None;
+            loop {
+                match stream_rx.try_recv() {
+                    Ok(frame) => reason = close_reason(&frame).or(reason),
+                    Err(TryRecvError::Empty) => {
+                        violation!(self, "connection of {participant_id} was not closed")
+                    }
+                    Err(TryRecvError::Disconnected) => break,
+                }
+            }
+            if reason != expected {
+                violation!(
+                    self,
+                    "connection of {participant_id} closed with {reason:?}, expected {expected:?}"
+                );
+            }
+        }
+    }
+
+    async fn check_invariants(&mut self) {
+        // The controller reaps a meeting that ended itself only when it next
+        // handles a message, so the first status may still count it
+        let _ = self.controller.get_status().await;
+        let status = self
+            .controller
+            .get_status()
+            .await
+            .unwrap_or_else(|e| violation!(self, "get_status failed: {e:?}"));
+
+        let now = Instant::now();
+        let grace = self.config.disconnect_grace_period;
+        let linger = grace + GRACE_CHECK_INTERVAL + SETTLE * 2;
+        let mut hosted = 0;
+        let mut participants = 0;
+        let mut connections = 0;
+
+        for m in 0..self.meetings.len() {
+            let meeting_id = self.meetings[m].meeting_id.clone();
+            let info = self.controller.get_meeting(meeting_id.clone()).await;
+            if !self.meetings[m].active {
+                if !matches!(info, Err(McError::MeetingNotFound(_))) {
+                    violation!(self, "{meeting_id} should not be hosted, got {info:?}");
+                }
+                continue;
+            }
+            hosted += 1;
+            let info = info.unwrap_or_else(|e| violation!(self, "{meeting_id} missing: {e:?}"));
+
+            let Some(handle) = self.meetings[m].handle.clone() else {
+                if info.participant_count != 0 {
+                    violation!(self, "{meeting_id} has participants nobody joined");
+                }
+                continue;
+            };
+            let state = handle
+                .get_state()
+                .await
+                .unwrap_or_else(|e| violation!(self, "{meeting_id} get_state failed: {e:?}"));
+            if info.participant_count != state.participants.len() {
+                violation!(
+                    self,
+                    "{meeting_id} get_meeting reports {} participants, actor has {}",
+                    info.participant_count,
+                    state.participants.len()
+                );
+            }
+            let reported: HashMap<String, ParticipantStatus> = state
+                .participants
+                .into_iter()
+                .map(|p| (p.participant_id, p.status))
+                .collect();
+
+            let sessions = &self.meetings[m].sessions;
+            for (participant_id, status) in &reported {
+                let Some(session) = sessions
+                    .iter()
+                    .find(|s| &s.participant_id == participant_id)
+                else {
+                    violation!(self, "ghost participant {participant_id} in {meeting_id}");
+                };
+                let expected = if session.connection.is_some() {
+                    ParticipantStatus::Connected
+                } else {
+                    ParticipantStatus::Disconnected
+                };
+                if *status != expected {
+                    violation!(
+                        self,
+                        "{participant_id} is {status:?}, expected {expected:?}"
+                    );
+                }
+            }
+
+            let mut expired = Vec::new();
+            for (s, session) in sessions.iter().enumerate() {
+                let present = reported.contains_key(&session.participant_id);
+                match session.disconnected_at {
+                    None if !present => {
+                        violation!(self, "connected {} was removed", session.participant_id);
+                    }
+                    None => {}
+                    Some(at) => {
+                        let elapsed = now.saturating_duration_since(at);
+                        if present && elapsed > linger {
+                            violation!(
+                                self,
+                                "{} still held {elapsed:?} after disconnecting",
+                                session.participant_id
+                            );
+                        }
+                        if !present {
+                            if elapsed < grace {
+                                violation!(
+                                    self,
+                                    "{} removed {elapsed:?} after disconnecting",
+                                    session.participant_id
+                                );
+                            }
+                            expired.push(s);
+                        }
+                    }
+                }
+            }
+            connections += sessions.iter().filter(|s| s.connection.is_some()).count();
+            participants += reported.len();
+
+            for s in expired.into_iter().rev() {
+                self.meetings[m].sessions.remove(s);
+                self.report.expired += 1;
+            }
+        }
+
+        if status.meeting_count != hosted || self.actor_metrics.meeting_count() != hosted {
+            violation!(
+                self,
+                "hosting {hosted} meetings, status reports {} and actor metrics {}",
+                status.meeting_count,
+                self.actor_metrics.meeting_count()
+            );
+        }
+        let heartbeat = self.controller_metrics.snapshot();
+        if heartbeat.meetings as usize != hosted {
+            violation!(
+                self,
+                "heartbeat reports {} meetings, hosting {hosted}",
+                heartbeat.meetings
+            );
+        }
+        if heartbeat.participants as usize != participants {
+            violation!(
+                self,
+                "heartbeat reports {} participants, meetings hold {participants}",
+                heartbeat.participants
+            );
+        }
+        if status.connection_count != connections {
+            violation!(
+                self,
+                "status reports {} connections, {connections} are open",
+                status.connection_count
+            );
+        }
+        self.report.peak_participants = self.report.peak_participants.max(participants);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BadResume {
+    /// Binding token with one hex digit changed.
+    Forged,
+    /// Binding replaced by an earlier resume.
+    Retired,
+    /// Valid binding presented by another user.
+    OtherUser,
+}
+
+impl BadResume {
+    fn name(self) -> &'static str {
+        match self {
+            BadResume::Forged => "forged_binding",
+            BadResume::Retired => "retired_binding",
+            BadResume::OtherUser => "other_user_binding",
+        }
+    }
+}
+
+/// The reason in a `ConnectionClosed` frame, if `frame` is one.
+fn close_reason(frame: &Bytes) -> Option<CloseReason> {
+    match ServerMessage::decode(frame.as_ref()).ok()?.message? {
+        server_message::Message::ConnectionClosed(closed) => Some(closed.reason()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_reproducible() {
+        let mut a = SimRng::new(42);
+        let mut b = SimRng::new(42);
+        let mut c = SimRng::new(43);
+        let first: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        let second: Vec<u64> = (0..8).map(|_| b.next_u64()).collect();
+        let other: Vec<u64> = (0..8).map(|_| c.next_u64()).collect();
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn test_rng_below_stays_in_range() {
+        let mut rng = SimRng::new(7);
+        let mut seen = [false; 5];
+        for _ in 0..1000 {
+            seen[rng.below(5)] = true;
+        }
+        assert!(seen.iter().all(|&hit| hit));
+    }
+
+    #[test]
+    fn test_config_with_seed_keeps_defaults() {
+        let config = SimulationConfig::with_seed(9);
+        assert_eq!(config.seed, 9);
+        assert_eq!(config.steps, SimulationConfig::default().steps);
+    }
+}
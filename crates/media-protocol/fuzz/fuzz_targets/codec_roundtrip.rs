@@ -20,6 +20,7 @@ fuzz_target!(|data: &[u8]| {
                 assert_eq!(frame.stream_id, frame2.stream_id);
                 assert_eq!(frame.timestamp, frame2.timestamp);
                 assert_eq!(frame.sequence, frame2.sequence);
+                assert_eq!(frame.key_epoch, frame2.key_epoch);
                 assert_eq!(frame.payload, frame2.payload);
             }
         }
@@ -59,8 +59,11 @@ pub fn encode_frame(frame: &MediaFrame) -> Result<Bytes, CodecError> {
     // Flags (2 bytes)
     buf.put_u16(frame.flags.to_u16());
 
-    // Reserved (6 bytes)
-    buf.put_bytes(0, 6);
+    // Key Epoch (4 bytes)
+    buf.put_u32(frame.key_epoch);
+
+    // Reserved (2 bytes)
+    buf.put_bytes(0, 2);
 
     // Payload
     buf.extend_from_slice(&frame.payload);
@@ -111,8 +114,11 @@ pub fn decode_frame(data: &mut impl Buf) -> Result<MediaFrame, CodecError> {
     // Flags (2 bytes)
     let flags = FrameFlags::from_u16(data.get_u16());
 
-    // Reserved (6 bytes) - skip
-    data.advance(6);
+    // Key Epoch (4 bytes)
+    let key_epoch = data.get_u32();
+
+    // Reserved (2 bytes) - skip
+    data.advance(2);
 
     // Check if we have enough data for the payload
     if data.remaining() < payload_len {
@@ -132,6 +138,7 @@ pub fn decode_frame(data: &mut impl Buf) -> Result<MediaFrame, CodecError> {
         timestamp,
         sequence,
         flags,
+        key_epoch,
         payload,
     })
 }
@@ -1,6 +1,7 @@
 //! Media frame types and serialization.
 
 use bytes::Bytes;
+use ring::digest;
 
 /// Type of media frame
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -14,16 +15,73 @@ pub enum FrameType {
     VideoDelta = 0x02,
 }
 
+/// Forwarding priority of a frame, carried in flag bits 2-3.
+///
+/// Under congestion MH drops lower priorities first. Publishers mark audio
+/// and keyframes [`Critical`](Self::Critical); senders that predate the
+/// field encode `0` and read back as [`Low`](Self::Low).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum FramePriority {
+    /// Enhancement layers and other frames a receiver can do without
+    #[default]
+    Low = 0,
+    /// Ordinary video frames
+    Normal = 1,
+    /// Reference frames later frames depend on
+    High = 2,
+    /// Audio and keyframes
+    Critical = 3,
+}
+
+impl FramePriority {
+    /// All priorities, lowest first.
+    pub const ALL: [Self; 4] = [Self::Low, Self::Normal, Self::High, Self::Critical];
+
+    /// Parse a priority from its 2-bit value; higher bits are ignored.
+    #[must_use]
+    pub const fn from_bits(value: u16) -> Self {
+        match value & 0x3 {
+            0 => Self::Low,
+            1 => Self::Normal,
+            2 => Self::High,
+            _ => Self::Critical,
+        }
+    }
+
+    /// Metric label for this priority.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Normal => "normal",
+            Self::High => "high",
+            Self::Critical => "critical",
+        }
+    }
+}
+
 /// Frame flags
+///
+/// - Bit 0: end of frame
+/// - Bit 1: discardable
+/// - Bits 2-3: [`FramePriority`]
+/// - Bits 4-15: reserved
 #[derive(Debug, Clone, Copy, Default)]
 pub struct FrameFlags {
     /// End of frame marker
     pub end_of_frame: bool,
-    /// Frame can be discarded without affecting others
+    /// Frame can be discarded without affecting others (non-reference
+    /// frame); eligible to be dropped under congestion
     pub discardable: bool,
+    /// Forwarding priority under congestion
+    pub priority: FramePriority,
 }
 
 impl FrameFlags {
+    /// Bit offset of the priority field.
+    const PRIORITY_SHIFT: u16 = 2;
+
     /// Convert flags to u16
     #[must_use]
     pub const fn to_u16(self) -> u16 {
@@ -34,6 +92,7 @@ impl FrameFlags {
         if self.discardable {
             flags |= 0x0002;
         }
+        flags |= (self.priority as u16) << Self::PRIORITY_SHIFT;
         flags
     }
 
@@ -43,6 +102,7 @@ impl FrameFlags {
         Self {
             end_of_frame: (value & 0x0001) != 0,
             discardable: (value & 0x0002) != 0,
+            priority: FramePriority::from_bits(value >> Self::PRIORITY_SHIFT),
         }
     }
 }
@@ -58,7 +118,8 @@ impl FrameFlags {
 /// - Sequence Number: 8 bytes
 /// - Payload Length: 4 bytes
 /// - Flags: 2 bytes
-/// - Reserved: 6 bytes
+/// - Key Epoch: 4 bytes (media key epoch the payload is encrypted under)
+/// - Reserved: 2 bytes
 /// - Payload: variable (`SFrame` encrypted)
 #[derive(Debug, Clone)]
 pub struct MediaFrame {
@@ -76,6 +137,8 @@ pub struct MediaFrame {
     pub sequence: u64,
     /// Frame flags
     pub flags: FrameFlags,
+    /// Media key epoch of the payload (see [`crate::keys`])
+    pub key_epoch: u32,
     /// Frame payload (encrypted)
     pub payload: Bytes,
 }
@@ -87,3 +150,73 @@ impl MediaFrame {
     /// Current protocol version
     pub const VERSION: u8 = 1;
 }
+
+/// Media `user_id` of the participant authenticated as `subject`.
+///
+/// MC returns this in the `JoinResponse` for the subject of the meeting JWT,
+/// and MH derives it from the same JWT to reject frames published under
+/// another participant's ID. It is the first 8 bytes (big-endian) of the
+/// SHA-256 of the subject.
+#[must_use]
+pub fn user_id_for_subject(subject: &str) -> u64 {
+    let hash = digest::digest(&digest::SHA256, subject.as_bytes());
+    let mut prefix = [0u8; 8];
+    for (byte, hashed) in prefix.iter_mut().zip(hash.as_ref()) {
+        *byte = *hashed;
+    }
+    u64::from_be_bytes(prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flags_round_trip() {
+        for priority in FramePriority::ALL {
+            for (end_of_frame, discardable) in [(false, false), (true, false), (false, true)] {
+                let flags = FrameFlags {
+                    end_of_frame,
+                    discardable,
+                    priority,
+                };
+                let parsed = FrameFlags::from_u16(flags.to_u16());
+                assert_eq!(parsed.end_of_frame, end_of_frame);
+                assert_eq!(parsed.discardable, discardable);
+                assert_eq!(parsed.priority, priority);
+            }
+        }
+    }
+
+    #[test]
+    fn test_priority_bits() {
+        let flags = FrameFlags {
+            end_of_frame: true,
+            discardable: false,
+            priority: FramePriority::High,
+        };
+        assert_eq!(flags.to_u16(), 0b1001);
+        // Senders that predate priority read back as low
+        assert_eq!(FrameFlags::from_u16(0x0003).priority, FramePriority::Low);
+        // Reserved bits are ignored
+        assert_eq!(
+            FrameFlags::from_u16(0xFFF0 | 0b0100).priority,
+            FramePriority::Normal
+        );
+    }
+
+    #[test]
+    fn test_user_id_for_subject() {
+        assert_eq!(user_id_for_subject("user-1"), user_id_for_subject("user-1"));
+        assert_ne!(user_id_for_subject("user-1"), user_id_for_subject("user-2"));
+        // SHA-256("") = e3b0c442 98fc1c14 ...
+        assert_eq!(user_id_for_subject(""), 0xe3b0_c442_98fc_1c14);
+    }
+
+    #[test]
+    fn test_priority_order() {
+        assert!(FramePriority::Low < FramePriority::Normal);
+        assert!(FramePriority::High < FramePriority::Critical);
+        assert_eq!(FramePriority::default(), FramePriority::Low);
+    }
+}
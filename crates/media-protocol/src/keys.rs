@@ -0,0 +1,353 @@
+//! Per-stream media key schedule.
+//!
+//! Media payloads are encrypted end to end with keys that MH never holds.
+//! Clients receive a meeting media secret over signaling, tagged with a key
+//! epoch; every frame names the epoch it was encrypted under (see
+//! [`MediaFrame::key_epoch`](crate::frame::MediaFrame::key_epoch)). Each
+//! published stream gets its own key so that nonces never repeat across
+//! streams.
+//!
+//! # Derivation
+//!
+//! ```text
+//! epoch_prk  = HKDF-Extract(salt = "dark-tower media v1", media secret)
+//! stream_key = HKDF-Expand(epoch_prk, "key"  || epoch || user_id || source, 32)
+//! salt       = HKDF-Expand(epoch_prk, "salt" || epoch || user_id || source, 12)
+//! nonce      = salt XOR sequence (big-endian, right-aligned)
+//! ```
+//!
+//! Integers are big-endian; `source` is one byte. Streams are identified by
+//! publisher and [`StreamSource`] rather than by the frame's `stream_id`,
+//! which each subscriber chooses for itself.
+//!
+//! # Rotation
+//!
+//! Rotating installs the secret for a newer epoch. Senders switch to it
+//! immediately; receivers keep the previous epoch as well so frames already
+//! in flight still decrypt. Anything older is dropped.
+
+use common::secret::{ExposeSecret, SecretBox};
+use ring::hkdf;
+use std::collections::VecDeque;
+use std::fmt;
+use thiserror::Error;
+
+/// Stream key length in bytes (AES-256-GCM).
+pub const KEY_LEN: usize = 32;
+
+/// Nonce salt length in bytes (AES-GCM nonce size).
+pub const SALT_LEN: usize = 12;
+
+/// Minimum media secret length in bytes.
+pub const MIN_SECRET_LEN: usize = 32;
+
+/// Epochs kept for decryption: the current one and the one before it.
+pub const RETAINED_EPOCHS: usize = 2;
+
+/// HKDF salt for the epoch secret.
+const EXTRACT_SALT: &[u8] = b"dark-tower media v1";
+
+/// Errors from the media key schedule.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum KeyError {
+    /// The media secret is shorter than [`MIN_SECRET_LEN`].
+    #[error("Media secret must be at least 32 bytes")]
+    SecretTooShort,
+
+    /// Rotation to an epoch that is not newer than the current one.
+    #[error("Key epoch {requested} is not newer than current epoch {current}")]
+    StaleEpoch {
+        /// Current epoch.
+        current: u32,
+        /// Epoch passed to [`KeySchedule::rotate`].
+        requested: u32,
+    },
+
+    /// A frame names an epoch this schedule does not hold.
+    #[error("Unknown key epoch: {0}")]
+    UnknownEpoch(u32),
+}
+
+/// Which of a publisher's streams a key belongs to.
+///
+/// Values match the signaling `StreamType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum StreamSource {
+    /// Microphone audio
+    Audio = 0,
+    /// Camera video
+    Camera = 1,
+    /// Screen share video
+    Screen = 2,
+}
+
+/// Key and nonce salt for one stream in one epoch.
+pub struct StreamKey {
+    key: [u8; KEY_LEN],
+    salt: [u8; SALT_LEN],
+}
+
+impl fmt::Debug for StreamKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamKey")
+            .field("key", &"[REDACTED]")
+            .field("salt", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl StreamKey {
+    /// Raw key bytes for the AEAD.
+    #[must_use]
+    pub fn key(&self) -> &[u8; KEY_LEN] {
+        &self.key
+    }
+
+    /// Nonce for the frame with `sequence`.
+    #[must_use]
+    pub fn nonce(&self, sequence: u64) -> [u8; SALT_LEN] {
+        let mut nonce = self.salt;
+        for (n, s) in nonce
+            .iter_mut()
+            .skip(SALT_LEN - 8)
+            .zip(sequence.to_be_bytes())
+        {
+            *n ^= s;
+        }
+        nonce
+    }
+}
+
+/// Per-meeting key schedule holding the secrets of the retained epochs.
+pub struct KeySchedule {
+    /// Newest epoch last.
+    epochs: VecDeque<(u32, hkdf::Prk)>,
+}
+
+impl fmt::Debug for KeySchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let epochs: Vec<u32> = self.epochs.iter().map(|(epoch, _)| *epoch).collect();
+        f.debug_struct("KeySchedule")
+            .field("epochs", &epochs)
+            .finish()
+    }
+}
+
+impl KeySchedule {
+    /// Start a schedule at `epoch` with that epoch's media secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KeyError::SecretTooShort`] if `secret` is shorter than
+    /// [`MIN_SECRET_LEN`].
+    pub fn new(epoch: u32, secret: &SecretBox<Vec<u8>>) -> Result<Self, KeyError> {
+        let mut epochs = VecDeque::with_capacity(RETAINED_EPOCHS);
+        epochs.push_back((epoch, extract(secret)?));
+        Ok(Self { epochs })
+    }
+
+    /// Epoch senders encrypt under.
+    #[must_use]
+    pub fn current_epoch(&self) -> u32 {
+        self.epochs.back().map_or(0, |(epoch, _)| *epoch)
+    }
+
+    /// Install the secret for a newer `epoch` and make it current.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KeyError::StaleEpoch`] if `epoch` is not newer than the
+    /// current epoch, or [`KeyError::SecretTooShort`] for a short secret.
+    pub fn rotate(&mut self, epoch: u32, secret: &SecretBox<Vec<u8>>) -> Result<(), KeyError> {
+        let current = self.current_epoch();
+        if epoch <= current {
+            return Err(KeyError::StaleEpoch {
+                current,
+                requested: epoch,
+            });
+        }
+        let prk = extract(secret)?;
+        if self.epochs.len() == RETAINED_EPOCHS {
+            self.epochs.pop_front();
+        }
+        self.epochs.push_back((epoch, prk));
+        Ok(())
+    }
+
+    /// Derive the key for the `source` stream published by `user_id` in
+    /// `epoch`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KeyError::UnknownEpoch`] if `epoch` is not retained.
+    pub fn stream_key(
+        &self,
+        epoch: u32,
+        user_id: u64,
+        source: StreamSource,
+    ) -> Result<StreamKey, KeyError> {
+        let (_, prk) = self
+            .epochs
+            .iter()
+            .find(|(e, _)| *e == epoch)
+            .ok_or(KeyError::UnknownEpoch(epoch))?;
+
+        let context = [
+            epoch.to_be_bytes().as_slice(),
+            &user_id.to_be_bytes(),
+            &[source as u8],
+        ]
+        .concat();
+
+        let mut key = [0u8; KEY_LEN];
+        let mut salt = [0u8; SALT_LEN];
+        expand(prk, b"key", &context, &mut key);
+        expand(prk, b"salt", &context, &mut salt);
+        Ok(StreamKey { key, salt })
+    }
+}
+
+fn extract(secret: &SecretBox<Vec<u8>>) -> Result<hkdf::Prk, KeyError> {
+    let secret = secret.expose_secret();
+    if secret.len() < MIN_SECRET_LEN {
+        return Err(KeyError::SecretTooShort);
+    }
+    Ok(hkdf::Salt::new(hkdf::HKDF_SHA256, EXTRACT_SALT).extract(secret))
+}
+
+/// HKDF output length for [`expand`].
+struct OutputLen(usize);
+
+impl hkdf::KeyType for OutputLen {
+    fn len(&self) -> usize {
+        self.0
+    }
+}
+
+/// The `expect` calls are unreachable: both outputs are far below the
+/// HKDF-SHA256 limit of 8160 bytes and `fill` gets a buffer of the
+/// requested length.
+#[allow(clippy::expect_used)]
+fn expand(prk: &hkdf::Prk, label: &[u8], context: &[u8], out: &mut [u8]) {
+    prk.expand(&[label, context], OutputLen(out.len()))
+        .expect("HKDF expand of at most 32 bytes cannot fail")
+        .fill(out)
+        .expect("fill with the requested length cannot fail");
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn secret(byte: u8) -> SecretBox<Vec<u8>> {
+        SecretBox::new(Box::new(vec![byte; 32]))
+    }
+
+    #[test]
+    fn test_keys_are_per_stream_and_epoch() {
+        let mut schedule = KeySchedule::new(1, &secret(1)).unwrap();
+        let a = schedule.stream_key(1, 10, StreamSource::Audio).unwrap();
+        assert_eq!(
+            a.key(),
+            schedule
+                .stream_key(1, 10, StreamSource::Audio)
+                .unwrap()
+                .key()
+        );
+        assert_ne!(
+            a.key(),
+            schedule
+                .stream_key(1, 11, StreamSource::Audio)
+                .unwrap()
+                .key()
+        );
+        assert_ne!(
+            a.key(),
+            schedule
+                .stream_key(1, 10, StreamSource::Camera)
+                .unwrap()
+                .key()
+        );
+
+        // Same secret, new epoch: still a different key
+        schedule.rotate(2, &secret(1)).unwrap();
+        assert_ne!(
+            a.key(),
+            schedule
+                .stream_key(2, 10, StreamSource::Audio)
+                .unwrap()
+                .key()
+        );
+    }
+
+    #[test]
+    fn test_same_secret_same_keys() {
+        // Every participant derives the same keys from the signaled secret
+        let sender = KeySchedule::new(3, &secret(9)).unwrap();
+        let receiver = KeySchedule::new(3, &secret(9)).unwrap();
+        let (tx, rx) = (
+            sender.stream_key(3, 42, StreamSource::Screen).unwrap(),
+            receiver.stream_key(3, 42, StreamSource::Screen).unwrap(),
+        );
+        assert_eq!(tx.key(), rx.key());
+        assert_eq!(tx.nonce(5), rx.nonce(5));
+    }
+
+    #[test]
+    fn test_nonce_varies_with_sequence() {
+        let key = KeySchedule::new(0, &secret(1))
+            .unwrap()
+            .stream_key(0, 1, StreamSource::Camera)
+            .unwrap();
+        assert_ne!(key.nonce(0), key.nonce(1));
+        assert_ne!(key.nonce(1), key.nonce(1 << 32));
+        assert_eq!(key.nonce(0), key.salt);
+    }
+
+    #[test]
+    fn test_rotation_keeps_previous_epoch_only() {
+        let mut schedule = KeySchedule::new(1, &secret(1)).unwrap();
+        schedule.rotate(2, &secret(2)).unwrap();
+        assert_eq!(schedule.current_epoch(), 2);
+        assert!(schedule.stream_key(1, 1, StreamSource::Camera).is_ok());
+
+        schedule.rotate(5, &secret(5)).unwrap();
+        assert!(schedule.stream_key(2, 1, StreamSource::Camera).is_ok());
+        assert_eq!(
+            schedule.stream_key(1, 1, StreamSource::Camera).unwrap_err(),
+            KeyError::UnknownEpoch(1)
+        );
+    }
+
+    #[test]
+    fn test_rotation_rejects_stale_epoch() {
+        let mut schedule = KeySchedule::new(4, &secret(1)).unwrap();
+        assert_eq!(
+            schedule.rotate(4, &secret(2)).unwrap_err(),
+            KeyError::StaleEpoch {
+                current: 4,
+                requested: 4
+            }
+        );
+        assert_eq!(schedule.current_epoch(), 4);
+    }
+
+    #[test]
+    fn test_short_secret_rejected() {
+        let short = SecretBox::new(Box::new(vec![0; MIN_SECRET_LEN - 1]));
+        assert_eq!(
+            KeySchedule::new(0, &short).unwrap_err(),
+            KeyError::SecretTooShort
+        );
+    }
+
+    #[test]
+    fn test_debug_redacts_keys() {
+        let schedule = KeySchedule::new(1, &secret(1)).unwrap();
+        let key = schedule.stream_key(1, 1, StreamSource::Camera).unwrap();
+        assert!(format!("{key:?}").contains("[REDACTED]"));
+        assert_eq!(format!("{schedule:?}"), "KeySchedule { epochs: [1] }");
+    }
+}
@@ -7,4 +7,5 @@
 
 pub mod codec;
 pub mod frame;
+pub mod keys;
 pub mod stream;
@@ -1,4 +1,23 @@
 //! Media stream management.
+//!
+//! # Replay protection
+//!
+//! Every frame on a stream carries a sequence number that the publisher
+//! increments by one per frame and never restarts, including across key
+//! rotations. [`MediaStream::receive`] runs each frame through a
+//! [`ReplayWindow`] and drops any sequence number it has already seen, so
+//! frames duplicated by the network or replayed by an attacker are never
+//! forwarded twice.
+//!
+//! The window tracks the highest sequence seen and a bitmap of the
+//! [`REPLAY_WINDOW`] sequences below it. Frames that arrive out of order
+//! within the window are accepted once; frames older than the window are
+//! dropped, since they can no longer be told apart from replays.
+
+use crate::frame::MediaFrame;
+
+/// Number of sequence numbers below the highest seen that are tracked.
+pub const REPLAY_WINDOW: u64 = 128;
 
 /// Media stream configuration
 #[derive(Debug, Clone)]
@@ -13,6 +32,86 @@ pub struct StreamConfig {
     pub is_audio: bool,
 }
 
+/// Outcome of checking a frame's sequence number against a [`ReplayWindow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayCheck {
+    /// Not seen before; the frame should be processed.
+    Accepted,
+    /// Already seen within the window.
+    Duplicate,
+    /// Older than the window; cannot be checked and is dropped.
+    TooOld,
+}
+
+impl ReplayCheck {
+    /// Metric label for a dropped frame.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Accepted => "accepted",
+            Self::Duplicate => "duplicate",
+            Self::TooOld => "too_old",
+        }
+    }
+}
+
+/// Sliding window of recently seen sequence numbers.
+#[derive(Debug, Clone, Default)]
+pub struct ReplayWindow {
+    /// Highest sequence accepted, `None` before the first frame.
+    highest: Option<u64>,
+    /// Bit `n` is set if `highest - n` has been accepted.
+    seen: u128,
+}
+
+impl ReplayWindow {
+    /// Create an empty window.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            highest: None,
+            seen: 0,
+        }
+    }
+
+    /// Highest sequence number accepted so far.
+    #[must_use]
+    pub const fn highest(&self) -> Option<u64> {
+        self.highest
+    }
+
+    /// Check `sequence` and, if it is new, record it as seen.
+    pub fn check(&mut self, sequence: u64) -> ReplayCheck {
+        let Some(highest) = self.highest else {
+            self.highest = Some(sequence);
+            self.seen = 1;
+            return ReplayCheck::Accepted;
+        };
+
+        if sequence > highest {
+            let shift = sequence - highest;
+            self.seen = if shift >= REPLAY_WINDOW {
+                1
+            } else {
+                (self.seen << shift) | 1
+            };
+            self.highest = Some(sequence);
+            return ReplayCheck::Accepted;
+        }
+
+        let offset = highest - sequence;
+        if offset >= REPLAY_WINDOW {
+            return ReplayCheck::TooOld;
+        }
+        let bit = 1u128 << offset;
+        if self.seen & bit != 0 {
+            return ReplayCheck::Duplicate;
+        }
+        self.seen |= bit;
+        ReplayCheck::Accepted
+    }
+}
+
 /// Media stream state
 #[derive(Debug)]
 pub struct MediaStream {
@@ -24,6 +123,12 @@ pub struct MediaStream {
     pub frames_received: u64,
     /// Number of bytes received
     pub bytes_received: u64,
+    /// Frames dropped because their sequence number was already seen
+    pub frames_duplicate: u64,
+    /// Frames dropped because they were older than the replay window
+    pub frames_too_old: u64,
+    /// Sequence numbers seen on this stream
+    replay: ReplayWindow,
 }
 
 impl MediaStream {
@@ -35,6 +140,9 @@ impl MediaStream {
             next_sequence: 0,
             frames_received: 0,
             bytes_received: 0,
+            frames_duplicate: 0,
+            frames_too_old: 0,
+            replay: ReplayWindow::new(),
         }
     }
 
@@ -49,4 +157,148 @@ impl MediaStream {
     pub const fn stream_id(&self) -> u32 {
         self.config.stream_id
     }
+
+    /// Record a frame received on this stream.
+    ///
+    /// Only frames that return [`ReplayCheck::Accepted`] should be
+    /// forwarded; the others are counted as dropped and leave the receive
+    /// counters unchanged.
+    pub fn receive(&mut self, frame: &MediaFrame) -> ReplayCheck {
+        let check = self.replay.check(frame.sequence);
+        match check {
+            ReplayCheck::Accepted => {
+                self.frames_received = self.frames_received.saturating_add(1);
+                self.bytes_received = self
+                    .bytes_received
+                    .saturating_add(frame.payload.len() as u64);
+                if let Some(highest) = self.replay.highest() {
+                    self.next_sequence = highest.saturating_add(1);
+                }
+            }
+            ReplayCheck::Duplicate => {
+                self.frames_duplicate = self.frames_duplicate.saturating_add(1);
+            }
+            ReplayCheck::TooOld => {
+                self.frames_too_old = self.frames_too_old.saturating_add(1);
+            }
+        }
+        check
+    }
+
+    /// Total frames dropped by replay protection.
+    #[must_use]
+    pub const fn frames_dropped(&self) -> u64 {
+        self.frames_duplicate.saturating_add(self.frames_too_old)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::{FrameFlags, FrameType};
+    use bytes::Bytes;
+
+    fn stream() -> MediaStream {
+        MediaStream::new(StreamConfig {
+            user_id: 1,
+            stream_id: 2,
+            max_bitrate: 1_000_000,
+            is_audio: false,
+        })
+    }
+
+    fn frame(sequence: u64) -> MediaFrame {
+        MediaFrame {
+            version: MediaFrame::VERSION,
+            user_id: 1,
+            stream_id: 2,
+            frame_type: FrameType::VideoKey,
+            timestamp: 0,
+            sequence,
+            flags: FrameFlags::default(),
+            key_epoch: 0,
+            payload: Bytes::from_static(b"payload"),
+        }
+    }
+
+    #[test]
+    fn test_in_order_frames_accepted() {
+        let mut window = ReplayWindow::new();
+        for sequence in 0..300 {
+            assert_eq!(window.check(sequence), ReplayCheck::Accepted);
+        }
+        assert_eq!(window.highest(), Some(299));
+    }
+
+    #[test]
+    fn test_duplicate_rejected() {
+        let mut window = ReplayWindow::new();
+        assert_eq!(window.check(10), ReplayCheck::Accepted);
+        assert_eq!(window.check(10), ReplayCheck::Duplicate);
+        assert_eq!(window.check(11), ReplayCheck::Accepted);
+        assert_eq!(window.check(10), ReplayCheck::Duplicate);
+    }
+
+    #[test]
+    fn test_reordered_frames_accepted_once() {
+        let mut window = ReplayWindow::new();
+        for sequence in [5, 8, 6, 7] {
+            assert_eq!(window.check(sequence), ReplayCheck::Accepted);
+        }
+        for sequence in [5, 6, 7, 8] {
+            assert_eq!(window.check(sequence), ReplayCheck::Duplicate);
+        }
+    }
+
+    #[test]
+    fn test_window_edges() {
+        let mut window = ReplayWindow::new();
+        assert_eq!(window.check(1000), ReplayCheck::Accepted);
+        // Oldest tracked sequence is still checked
+        assert_eq!(
+            window.check(1000 - (REPLAY_WINDOW - 1)),
+            ReplayCheck::Accepted
+        );
+        assert_eq!(
+            window.check(1000 - (REPLAY_WINDOW - 1)),
+            ReplayCheck::Duplicate
+        );
+        assert_eq!(window.check(1000 - REPLAY_WINDOW), ReplayCheck::TooOld);
+    }
+
+    #[test]
+    fn test_large_jump_resets_window() {
+        let mut window = ReplayWindow::new();
+        assert_eq!(window.check(1), ReplayCheck::Accepted);
+        assert_eq!(window.check(1 + REPLAY_WINDOW * 3), ReplayCheck::Accepted);
+        assert_eq!(window.check(1), ReplayCheck::TooOld);
+        assert_eq!(
+            window.check(1 + REPLAY_WINDOW * 3 - 1),
+            ReplayCheck::Accepted
+        );
+        assert_eq!(window.check(u64::MAX), ReplayCheck::Accepted);
+        assert_eq!(window.check(u64::MAX), ReplayCheck::Duplicate);
+    }
+
+    #[test]
+    fn test_stream_counts_drops() {
+        let mut stream = stream();
+        assert_eq!(stream.receive(&frame(0)), ReplayCheck::Accepted);
+        assert_eq!(stream.receive(&frame(200)), ReplayCheck::Accepted);
+        assert_eq!(stream.receive(&frame(200)), ReplayCheck::Duplicate);
+        assert_eq!(stream.receive(&frame(0)), ReplayCheck::TooOld);
+
+        assert_eq!(stream.frames_received, 2);
+        assert_eq!(stream.bytes_received, 14);
+        assert_eq!(stream.next_sequence, 201);
+        assert_eq!(stream.frames_duplicate, 1);
+        assert_eq!(stream.frames_too_old, 1);
+        assert_eq!(stream.frames_dropped(), 2);
+    }
+
+    #[test]
+    fn test_drop_labels() {
+        assert_eq!(ReplayCheck::Duplicate.as_str(), "duplicate");
+        assert_eq!(ReplayCheck::TooOld.as_str(), "too_old");
+    }
 }
@@ -0,0 +1,286 @@
+//! Decoded video frames in planar I420 (YUV 4:2:0).
+//!
+//! Chroma planes are `ceil(width / 2) x ceil(height / 2)`. Scaling is
+//! nearest-neighbour: recordings are encoded at a fixed output size, and
+//! tiles are small enough that filtering is not worth the CPU.
+
+use super::layout::TileRect;
+use super::CompositorError;
+
+/// Black in limited-range BT.601 (Y, U, V).
+pub const BLACK: (u8, u8, u8) = (16, 128, 128);
+
+/// A decoded I420 video frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VideoFrame {
+    width: u32,
+    height: u32,
+    y: Vec<u8>,
+    u: Vec<u8>,
+    v: Vec<u8>,
+}
+
+/// Chroma plane dimensions for a luma size.
+fn chroma_size(width: u32, height: u32) -> (u32, u32) {
+    (width.div_ceil(2), height.div_ceil(2))
+}
+
+/// Number of bytes in a `width x height` plane.
+fn plane_len(width: u32, height: u32) -> usize {
+    width as usize * height as usize
+}
+
+impl VideoFrame {
+    /// Build a frame from decoded planes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CompositorError::InvalidFrame` if a dimension is zero or a
+    /// plane does not match the frame size.
+    pub fn from_planes(
+        width: u32,
+        height: u32,
+        y: Vec<u8>,
+        u: Vec<u8>,
+        v: Vec<u8>,
+    ) -> Result<Self, CompositorError> {
+        if width == 0 || height == 0 {
+            return Err(CompositorError::InvalidFrame("zero dimension"));
+        }
+        let (cw, ch) = chroma_size(width, height);
+        if y.len() != plane_len(width, height) {
+            return Err(CompositorError::InvalidFrame("luma plane size mismatch"));
+        }
+        if u.len() != plane_len(cw, ch) || v.len() != plane_len(cw, ch) {
+            return Err(CompositorError::InvalidFrame("chroma plane size mismatch"));
+        }
+        Ok(Self {
+            width,
+            height,
+            y,
+            u,
+            v,
+        })
+    }
+
+    /// Build a frame filled with a single `(Y, U, V)` color.
+    #[must_use]
+    pub fn filled(width: u32, height: u32, color: (u8, u8, u8)) -> Self {
+        let (cw, ch) = chroma_size(width, height);
+        Self {
+            width,
+            height,
+            y: vec![color.0; plane_len(width, height)],
+            u: vec![color.1; plane_len(cw, ch)],
+            v: vec![color.2; plane_len(cw, ch)],
+        }
+    }
+
+    /// Frame width in pixels.
+    #[must_use]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Frame height in pixels.
+    #[must_use]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Luma plane.
+    #[must_use]
+    pub fn y_plane(&self) -> &[u8] {
+        &self.y
+    }
+
+    /// Cb chroma plane.
+    #[must_use]
+    pub fn u_plane(&self) -> &[u8] {
+        &self.u
+    }
+
+    /// Cr chroma plane.
+    #[must_use]
+    pub fn v_plane(&self) -> &[u8] {
+        &self.v
+    }
+
+    /// Overwrite the whole frame with a single color.
+    pub fn fill(&mut self, color: (u8, u8, u8)) {
+        self.y.fill(color.0);
+        self.u.fill(color.1);
+        self.v.fill(color.2);
+    }
+
+    /// Draw `src` into `tile`, scaled to fit while keeping its aspect ratio.
+    ///
+    /// The image is centered in the tile; the uncovered bars keep whatever
+    /// was already there (normally the background). Parts of the tile outside
+    /// this frame are clipped.
+    pub fn blit_fit(&mut self, src: &VideoFrame, tile: &TileRect) {
+        let (x, y, width, height) = fit_rect(src.width, src.height, tile);
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let dst = Plane {
+            width: self.width,
+            height: self.height,
+        };
+        blit_plane(
+            &mut self.y,
+            dst,
+            &src.y,
+            Plane {
+                width: src.width,
+                height: src.height,
+            },
+            (x, y, width, height),
+        );
+
+        let (cw, ch) = chroma_size(self.width, self.height);
+        let (src_cw, src_ch) = chroma_size(src.width, src.height);
+        let chroma_rect = (
+            x / 2,
+            y / 2,
+            (x + width).div_ceil(2) - x / 2,
+            (y + height).div_ceil(2) - y / 2,
+        );
+        let dst = Plane {
+            width: cw,
+            height: ch,
+        };
+        let src_plane = Plane {
+            width: src_cw,
+            height: src_ch,
+        };
+        blit_plane(&mut self.u, dst, &src.u, src_plane, chroma_rect);
+        blit_plane(&mut self.v, dst, &src.v, src_plane, chroma_rect);
+    }
+}
+
+/// Dimensions of one image plane.
+#[derive(Debug, Clone, Copy)]
+struct Plane {
+    width: u32,
+    height: u32,
+}
+
+/// Largest rect with the source's aspect ratio, centered in `tile`.
+fn fit_rect(src_width: u32, src_height: u32, tile: &TileRect) -> (u32, u32, u32, u32) {
+    if src_width == 0 || src_height == 0 {
+        return (tile.x, tile.y, 0, 0);
+    }
+    // Compare aspect ratios in u64 to avoid overflow on large frames.
+    let wide = u64::from(src_width) * u64::from(tile.height)
+        >= u64::from(tile.width) * u64::from(src_height);
+    let (width, height) = if wide {
+        let h = u64::from(tile.width) * u64::from(src_height) / u64::from(src_width);
+        (tile.width, u32::try_from(h).unwrap_or(tile.height))
+    } else {
+        let w = u64::from(tile.height) * u64::from(src_width) / u64::from(src_height);
+        (u32::try_from(w).unwrap_or(tile.width), tile.height)
+    };
+    (
+        tile.x + (tile.width - width) / 2,
+        tile.y + (tile.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Nearest-neighbour scale `src` into the `(x, y, width, height)` rect of `dst`.
+fn blit_plane(
+    dst: &mut [u8],
+    dst_dims: Plane,
+    src: &[u8],
+    src_dims: Plane,
+    (x, y, width, height): (u32, u32, u32, u32),
+) {
+    let dst_stride = dst_dims.width as usize;
+    let src_stride = src_dims.width as usize;
+    let visible_width = width.min(dst_dims.width.saturating_sub(x));
+    let visible_height = height.min(dst_dims.height.saturating_sub(y));
+
+    for row in 0..visible_height {
+        let src_row = u64::from(row) * u64::from(src_dims.height) / u64::from(height);
+        let src_start = usize::try_from(src_row)
+            .unwrap_or(usize::MAX)
+            .saturating_mul(src_stride);
+        let Some(src_line) = src.get(src_start..src_start.saturating_add(src_stride)) else {
+            continue;
+        };
+        let dst_start = (y + row) as usize * dst_stride + x as usize;
+        let Some(dst_line) = dst.get_mut(dst_start..dst_start + visible_width as usize) else {
+            continue;
+        };
+        for (col, out) in (0u64..).zip(dst_line.iter_mut()) {
+            let src_col = col * u64::from(src_dims.width) / u64::from(width);
+            if let Some(pixel) = usize::try_from(src_col).ok().and_then(|c| src_line.get(c)) {
+                *out = *pixel;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn tile(x: u32, y: u32, width: u32, height: u32) -> TileRect {
+        TileRect {
+            participant_id: "part-1".to_string(),
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_from_planes_validates_sizes() {
+        assert!(VideoFrame::from_planes(4, 2, vec![0; 8], vec![0; 2], vec![0; 2]).is_ok());
+        // Odd sizes round chroma up
+        assert!(VideoFrame::from_planes(3, 3, vec![0; 9], vec![0; 4], vec![0; 4]).is_ok());
+        assert_eq!(
+            VideoFrame::from_planes(4, 2, vec![0; 7], vec![0; 2], vec![0; 2]),
+            Err(CompositorError::InvalidFrame("luma plane size mismatch"))
+        );
+        assert_eq!(
+            VideoFrame::from_planes(0, 2, vec![], vec![], vec![]),
+            Err(CompositorError::InvalidFrame("zero dimension"))
+        );
+    }
+
+    #[test]
+    fn test_blit_fit_scales_into_tile() {
+        let mut canvas = VideoFrame::filled(8, 4, BLACK);
+        let src = VideoFrame::filled(2, 2, (200, 50, 60));
+
+        // Square source in a 4x4 tile on the right half
+        canvas.blit_fit(&src, &tile(4, 0, 4, 4));
+
+        for row in canvas.y_plane().chunks(8) {
+            assert_eq!(row, [16, 16, 16, 16, 200, 200, 200, 200]);
+        }
+        for row in canvas.u_plane().chunks(4) {
+            assert_eq!(row, [128, 128, 50, 50]);
+        }
+        assert!(canvas.v_plane().contains(&60));
+    }
+
+    #[test]
+    fn test_blit_fit_letterboxes_and_clips() {
+        // 16:9 source in a square tile leaves bars above and below
+        assert_eq!(fit_rect(1280, 720, &tile(0, 0, 160, 160)), (0, 35, 160, 90));
+        // Portrait source is pillarboxed
+        assert_eq!(fit_rect(720, 1280, &tile(0, 0, 160, 160)), (35, 0, 90, 160));
+
+        // A tile hanging off the canvas is clipped rather than panicking
+        let mut canvas = VideoFrame::filled(4, 4, BLACK);
+        canvas.blit_fit(&VideoFrame::filled(2, 2, (99, 1, 2)), &tile(2, 2, 4, 4));
+        assert_eq!(canvas.y_plane().iter().filter(|&&p| p == 99).count(), 4);
+    }
+}
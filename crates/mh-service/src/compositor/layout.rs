@@ -0,0 +1,164 @@
+//! Tile placement for composited output.
+//!
+//! Layouts are pure functions of the participant list and the output size,
+//! so they are shared by recordings and live stream egress.
+
+/// Placement of one participant's video in the composed frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TileRect {
+    /// Participant whose video fills this tile.
+    pub participant_id: String,
+    /// Left edge in pixels.
+    pub x: u32,
+    /// Top edge in pixels.
+    pub y: u32,
+    /// Tile width in pixels.
+    pub width: u32,
+    /// Tile height in pixels.
+    pub height: u32,
+}
+
+/// Fraction of the output height given to the speaker view filmstrip (1/5).
+const FILMSTRIP_HEIGHT_DIVISOR: u32 = 5;
+
+/// Lay tiles out in the smallest square-ish grid that fits them.
+///
+/// Tiles fill rows left to right; a partially filled last row is centered.
+/// An empty list yields an empty layout.
+#[must_use]
+pub fn grid_layout(participant_ids: &[String], width: u32, height: u32) -> Vec<TileRect> {
+    let count = u32::try_from(participant_ids.len()).unwrap_or(u32::MAX);
+    if count == 0 {
+        return Vec::new();
+    }
+
+    let mut cols: u32 = 1;
+    while cols.saturating_mul(cols) < count {
+        cols += 1;
+    }
+    let rows = count.div_ceil(cols);
+    let tile_width = width / cols;
+    let tile_height = height / rows;
+
+    (0u32..)
+        .zip(participant_ids)
+        .map(|(index, participant_id)| {
+            let row = index / cols;
+            let col = index % cols;
+            let in_row = cols.min(count - row * cols);
+            let row_offset = (cols - in_row) * tile_width / 2;
+            TileRect {
+                participant_id: participant_id.clone(),
+                x: row_offset + col * tile_width,
+                y: row * tile_height,
+                width: tile_width,
+                height: tile_height,
+            }
+        })
+        .collect()
+}
+
+/// Give the active speaker the main area above a filmstrip of the others.
+///
+/// The filmstrip is one row of 16:9 tiles along the bottom, centered; others
+/// beyond what fits are left out. Without a speaker (or when they are not in
+/// `participant_ids`) the first participant takes the main area. A lone
+/// participant fills the frame.
+#[must_use]
+pub fn speaker_layout(
+    participant_ids: &[String],
+    active_speaker: Option<&str>,
+    width: u32,
+    height: u32,
+) -> Vec<TileRect> {
+    let speaker = active_speaker
+        .and_then(|s| participant_ids.iter().find(|id| id.as_str() == s))
+        .or_else(|| participant_ids.first());
+    let Some(speaker) = speaker else {
+        return Vec::new();
+    };
+    let others: Vec<&String> = participant_ids.iter().filter(|id| *id != speaker).collect();
+    if others.is_empty() {
+        return vec![TileRect {
+            participant_id: speaker.clone(),
+            x: 0,
+            y: 0,
+            width,
+            height,
+        }];
+    }
+
+    let strip_height = height / FILMSTRIP_HEIGHT_DIVISOR;
+    let strip_tile_width = (strip_height * 16 / 9).max(1);
+    let slots = (width / strip_tile_width).max(1);
+    let shown = slots.min(u32::try_from(others.len()).unwrap_or(u32::MAX));
+    let strip_offset = (width - shown * strip_tile_width.min(width)) / 2;
+    let strip_y = height - strip_height;
+
+    let mut tiles = vec![TileRect {
+        participant_id: speaker.clone(),
+        x: 0,
+        y: 0,
+        width,
+        height: strip_y,
+    }];
+    tiles.extend((0u32..shown).zip(others).map(|(i, id)| TileRect {
+        participant_id: id.clone(),
+        x: strip_offset + i * strip_tile_width,
+        y: strip_y,
+        width: strip_tile_width.min(width),
+        height: strip_height,
+    }));
+    tiles
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn ids(n: usize) -> Vec<String> {
+        (1..=n).map(|i| format!("part-{i}")).collect()
+    }
+
+    #[test]
+    fn test_grid_layout_scales_to_output() {
+        let four = grid_layout(&ids(4), 1280, 720);
+        assert!(four.iter().all(|t| t.width == 640 && t.height == 360));
+        assert_eq!((four[3].x, four[3].y), (640, 360));
+    }
+
+    #[test]
+    fn test_speaker_layout_main_and_filmstrip() {
+        let tiles = speaker_layout(&ids(3), Some("part-2"), 1920, 1080);
+        assert_eq!(tiles.len(), 3);
+        assert_eq!(tiles[0].participant_id, "part-2");
+        assert_eq!((tiles[0].width, tiles[0].height), (1920, 864));
+
+        // Two 384x216 filmstrip tiles centered under the speaker
+        assert_eq!(tiles[1].participant_id, "part-1");
+        assert_eq!(
+            (tiles[1].x, tiles[1].y, tiles[1].width, tiles[1].height),
+            (576, 864, 384, 216)
+        );
+        assert_eq!(
+            (tiles[2].participant_id.as_str(), tiles[2].x),
+            ("part-3", 960)
+        );
+    }
+
+    #[test]
+    fn test_speaker_layout_fallbacks() {
+        assert!(speaker_layout(&[], Some("part-1"), 1920, 1080).is_empty());
+
+        // Unknown speaker falls back to the first participant
+        let tiles = speaker_layout(&ids(2), Some("ghost"), 1920, 1080);
+        assert_eq!(tiles[0].participant_id, "part-1");
+
+        let alone = speaker_layout(&ids(1), None, 1920, 1080);
+        assert_eq!((alone[0].width, alone[0].height), (1920, 1080));
+
+        // Filmstrip holds five tiles at 1080p; the rest are dropped
+        assert_eq!(speaker_layout(&ids(10), None, 1920, 1080).len(), 6);
+    }
+}
@@ -0,0 +1,376 @@
+//! Layout compositor for meeting recordings.
+//!
+//! Rather than handing the recorder raw per-participant segments, the
+//! compositor keeps the latest decoded frame of each participant and renders
+//! them into one fixed-size frame per tick, which is pushed to a
+//! [`RecorderSink`]. The layout is chosen per meeting when the MC registers
+//! it (`RegisterMeetingRequest.recording_layout`):
+//!
+//! - **Grid**: every participant (up to [`MAX_GRID_TILES`]) in equal tiles
+//! - **Speaker**: the active speaker large, others in a bottom filmstrip
+//!
+//! # Current Status
+//!
+//! Layout and pixel composition are implemented, and each registered
+//! meeting's [`LayoutConfig`] is kept by the session manager
+//! (`SessionManagerHandle::get_layout_config`). MH does not decode media
+//! yet, so no recorder pipeline feeds a compositor today.
+
+pub mod frame;
+pub mod layout;
+
+pub use frame::VideoFrame;
+pub use layout::{grid_layout, speaker_layout, TileRect};
+
+use std::collections::HashMap;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Maximum tiles in the grid layout (3x3).
+pub const MAX_GRID_TILES: usize = 9;
+
+/// Default recording width in pixels.
+pub const DEFAULT_WIDTH: u32 = 1920;
+
+/// Default recording height in pixels.
+pub const DEFAULT_HEIGHT: u32 = 1080;
+
+/// Compositor errors.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CompositorError {
+    /// A decoded frame's planes do not match its dimensions.
+    #[error("Invalid video frame: {0}")]
+    InvalidFrame(&'static str),
+
+    /// The recorder sink stopped accepting frames.
+    #[error("Recorder sink closed")]
+    SinkClosed,
+}
+
+/// How participants are arranged in a recording.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RecordingLayout {
+    /// Equal tiles for all participants.
+    #[default]
+    Grid,
+    /// Active speaker in the main area, others in a filmstrip.
+    Speaker,
+}
+
+impl RecordingLayout {
+    /// Map the proto enum value; unspecified or unknown values use the default.
+    #[must_use]
+    pub fn from_proto(value: i32) -> Self {
+        use proto_gen::dark_tower::internal::v1::RecordingLayout as Proto;
+        match Proto::try_from(value) {
+            Ok(Proto::Speaker) => RecordingLayout::Speaker,
+            Ok(Proto::Grid | Proto::Unspecified) | Err(_) => RecordingLayout::Grid,
+        }
+    }
+
+    /// Bounded layout name for logging and metrics.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RecordingLayout::Grid => "grid",
+            RecordingLayout::Speaker => "speaker",
+        }
+    }
+}
+
+/// Per-meeting compositor configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutConfig {
+    /// Tile arrangement.
+    pub layout: RecordingLayout,
+    /// Output width in pixels.
+    pub width: u32,
+    /// Output height in pixels.
+    pub height: u32,
+}
+
+impl LayoutConfig {
+    /// Configuration for a meeting registered with `recording_layout` (the
+    /// proto enum value), at the default output size.
+    #[must_use]
+    pub fn from_proto(recording_layout: i32) -> Self {
+        Self {
+            layout: RecordingLayout::from_proto(recording_layout),
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            layout: RecordingLayout::default(),
+            width: DEFAULT_WIDTH,
+            height: DEFAULT_HEIGHT,
+        }
+    }
+}
+
+/// Destination for composited frames (the recording encoder).
+pub trait RecorderSink: Send {
+    /// Accept one composited frame at `timestamp` from the recording start.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CompositorError::SinkClosed` once the sink is finished.
+    fn write_frame(
+        &mut self,
+        frame: &VideoFrame,
+        timestamp: Duration,
+    ) -> Result<(), CompositorError>;
+}
+
+/// Composes the latest frame of each participant into a single stream.
+#[derive(Debug)]
+pub struct Compositor {
+    config: LayoutConfig,
+    /// Participants in join order (grid and filmstrip order).
+    participants: Vec<String>,
+    /// Latest decoded frame per participant; absent until video arrives.
+    frames: HashMap<String, VideoFrame>,
+    active_speaker: Option<String>,
+    canvas: VideoFrame,
+}
+
+impl Compositor {
+    /// Create a compositor with a black canvas.
+    #[must_use]
+    pub fn new(config: LayoutConfig) -> Self {
+        Self {
+            config,
+            participants: Vec::new(),
+            frames: HashMap::new(),
+            active_speaker: None,
+            canvas: VideoFrame::filled(config.width, config.height, frame::BLACK),
+        }
+    }
+
+    /// Configuration in use.
+    #[must_use]
+    pub fn config(&self) -> LayoutConfig {
+        self.config
+    }
+
+    /// Add a participant (without video yet). Idempotent.
+    pub fn add_participant(&mut self, participant_id: &str) {
+        if !self.participants.iter().any(|p| p == participant_id) {
+            self.participants.push(participant_id.to_string());
+        }
+    }
+
+    /// Replace a participant's latest decoded frame, adding them if new.
+    pub fn update_frame(&mut self, participant_id: &str, frame: VideoFrame) {
+        self.add_participant(participant_id);
+        self.frames.insert(participant_id.to_string(), frame);
+    }
+
+    /// Drop a participant and their last frame.
+    pub fn remove_participant(&mut self, participant_id: &str) {
+        self.participants.retain(|p| p != participant_id);
+        self.frames.remove(participant_id);
+        if self.active_speaker.as_deref() == Some(participant_id) {
+            self.active_speaker = None;
+        }
+    }
+
+    /// Set the current active speaker (`None` when nobody is speaking).
+    pub fn set_active_speaker(&mut self, participant_id: Option<&str>) {
+        self.active_speaker = participant_id.map(str::to_string);
+    }
+
+    /// Tiles for the current participants and speaker.
+    ///
+    /// When the grid is full, the active speaker replaces the last visible
+    /// tile so whoever is talking is always on screen.
+    #[must_use]
+    pub fn layout(&self) -> Vec<TileRect> {
+        let LayoutConfig {
+            layout,
+            width,
+            height,
+        } = self.config;
+        match layout {
+            RecordingLayout::Grid => {
+                let mut visible: Vec<String> = self
+                    .participants
+                    .iter()
+                    .take(MAX_GRID_TILES)
+                    .cloned()
+                    .collect();
+                if let Some(speaker) = &self.active_speaker {
+                    if self.participants.contains(speaker) && !visible.contains(speaker) {
+                        if let Some(last) = visible.last_mut() {
+                            last.clone_from(speaker);
+                        }
+                    }
+                }
+                grid_layout(&visible, width, height)
+            }
+            RecordingLayout::Speaker => speaker_layout(
+                &self.participants,
+                self.active_speaker.as_deref(),
+                width,
+                height,
+            ),
+        }
+    }
+
+    /// Render the current frames into the canvas and return it.
+    ///
+    /// Participants without video leave their tile black.
+    pub fn compose(&mut self) -> &VideoFrame {
+        let tiles = self.layout();
+        self.canvas.fill(frame::BLACK);
+        for tile in &tiles {
+            if let Some(frame) = self.frames.get(&tile.participant_id) {
+                self.canvas.blit_fit(frame, tile);
+            }
+        }
+        &self.canvas
+    }
+
+    /// Compose a frame and hand it to `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Propagates the sink's error.
+    pub fn render_to(
+        &mut self,
+        sink: &mut dyn RecorderSink,
+        timestamp: Duration,
+    ) -> Result<(), CompositorError> {
+        let frame = self.compose();
+        sink.write_frame(frame, timestamp)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct CaptureSink {
+        frames: Vec<(Duration, VideoFrame)>,
+        closed: bool,
+    }
+
+    impl RecorderSink for CaptureSink {
+        fn write_frame(
+            &mut self,
+            frame: &VideoFrame,
+            timestamp: Duration,
+        ) -> Result<(), CompositorError> {
+            if self.closed {
+                return Err(CompositorError::SinkClosed);
+            }
+            self.frames.push((timestamp, frame.clone()));
+            Ok(())
+        }
+    }
+
+    fn small(layout: RecordingLayout) -> LayoutConfig {
+        LayoutConfig {
+            layout,
+            width: 8,
+            height: 4,
+        }
+    }
+
+    #[test]
+    fn test_recording_layout_from_proto() {
+        assert_eq!(RecordingLayout::from_proto(0), RecordingLayout::Grid);
+        assert_eq!(RecordingLayout::from_proto(1), RecordingLayout::Grid);
+        assert_eq!(RecordingLayout::from_proto(2), RecordingLayout::Speaker);
+        assert_eq!(RecordingLayout::from_proto(99), RecordingLayout::Grid);
+
+        let config = LayoutConfig::from_proto(2);
+        assert_eq!(config.layout, RecordingLayout::Speaker);
+        assert_eq!(
+            (config.width, config.height),
+            (DEFAULT_WIDTH, DEFAULT_HEIGHT)
+        );
+    }
+
+    #[test]
+    fn test_grid_composes_each_participant() {
+        let mut compositor = Compositor::new(small(RecordingLayout::Grid));
+        compositor.update_frame("part-1", VideoFrame::filled(2, 2, (100, 128, 128)));
+        compositor.update_frame("part-2", VideoFrame::filled(2, 2, (200, 128, 128)));
+
+        let frame = compositor.compose();
+        assert_eq!((frame.width(), frame.height()), (8, 4));
+        // Two 4x4 tiles side by side
+        for row in frame.y_plane().chunks(8) {
+            assert_eq!(row, [100, 100, 100, 100, 200, 200, 200, 200]);
+        }
+
+        // A participant leaving clears their pixels on the next frame
+        compositor.remove_participant("part-2");
+        let frame = compositor.compose();
+        assert!(!frame.y_plane().contains(&200));
+    }
+
+    #[test]
+    fn test_participant_without_video_stays_black() {
+        let mut compositor = Compositor::new(small(RecordingLayout::Grid));
+        compositor.add_participant("part-1");
+        assert!(compositor
+            .compose()
+            .y_plane()
+            .iter()
+            .all(|&p| p == frame::BLACK.0));
+    }
+
+    #[test]
+    fn test_full_grid_keeps_active_speaker_visible() {
+        let mut compositor = Compositor::new(LayoutConfig::default());
+        for i in 1..=12 {
+            compositor.add_participant(&format!("part-{i}"));
+        }
+        compositor.set_active_speaker(Some("part-11"));
+
+        let tiles = compositor.layout();
+        assert_eq!(tiles.len(), MAX_GRID_TILES);
+        assert!(tiles.iter().any(|t| t.participant_id == "part-11"));
+        assert!(!tiles.iter().any(|t| t.participant_id == "part-9"));
+    }
+
+    #[test]
+    fn test_speaker_layout_follows_active_speaker() {
+        let mut compositor = Compositor::new(LayoutConfig {
+            layout: RecordingLayout::Speaker,
+            ..LayoutConfig::default()
+        });
+        compositor.add_participant("part-1");
+        compositor.add_participant("part-2");
+        compositor.set_active_speaker(Some("part-2"));
+        assert_eq!(compositor.layout()[0].participant_id, "part-2");
+
+        compositor.remove_participant("part-2");
+        assert_eq!(compositor.layout()[0].participant_id, "part-1");
+    }
+
+    #[test]
+    fn test_render_to_sink() {
+        let mut compositor = Compositor::new(small(RecordingLayout::Grid));
+        let mut sink = CaptureSink::default();
+
+        compositor
+            .render_to(&mut sink, Duration::from_millis(33))
+            .unwrap();
+        assert_eq!(sink.frames.len(), 1);
+        assert_eq!(sink.frames[0].0, Duration::from_millis(33));
+
+        sink.closed = true;
+        assert_eq!(
+            compositor.render_to(&mut sink, Duration::from_millis(66)),
+            Err(CompositorError::SinkClosed)
+        );
+    }
+}
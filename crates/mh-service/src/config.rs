@@ -11,6 +11,8 @@
 //! - `MH_CLIENT_ID`: OAuth client ID for MH
 //! - `MH_CLIENT_SECRET`: OAuth client secret for MH
 
+use common::media_socket::MediaSocketConfig;
+use common::media_token::MediaTokenKey;
 use common::secret::SecretString;
 use std::collections::HashMap;
 use std::env;
@@ -45,19 +47,23 @@ pub const MAX_REGISTER_MEETING_TIMEOUT_SECONDS: u64 = 300;
 /// Default maximum concurrent WebTransport connections.
 pub const DEFAULT_MAX_CONNECTIONS: usize = 10_000;
 
+/// Default maximum concurrent live streaming egresses.
+pub const DEFAULT_MAX_EGRESS_SESSIONS: usize = 4;
+
 /// Media Handler configuration.
 ///
 /// Loaded from environment variables with sensible defaults.
 /// Sensitive fields are redacted in Debug output.
 #[derive(Clone)]
 pub struct Config {
-    /// gRPC server bind address for MC→MH communication (default: "0.0.0.0:50053").
+    /// gRPC server bind addresses for MC→MH communication, comma-separated
+    /// (default: "0.0.0.0:50053").
     pub grpc_bind_address: String,
 
-    /// Health endpoint bind address (default: "0.0.0.0:8083").
+    /// Health endpoint bind addresses, comma-separated (default: "0.0.0.0:8083").
     pub health_bind_address: String,
 
-    /// WebTransport server bind address (default: "0.0.0.0:4434").
+    /// WebTransport server bind addresses, comma-separated (default: "0.0.0.0:4434").
     pub webtransport_bind_address: String,
 
     /// Deployment region identifier (e.g., "us-east-1").
@@ -82,6 +88,12 @@ pub struct Config {
     /// Protected by `SecretString` to prevent accidental logging.
     pub client_secret: SecretString,
 
+    /// Secret shared with MC for media tokens (`MH_MEDIA_TOKEN_SECRET`,
+    /// base64, at least 32 bytes; default: off). When set, clients must
+    /// present the media token from their `JoinResponse` in
+    /// `MhConnectRequest` (see [`common::media_token`]).
+    pub media_token_secret: Option<SecretString>,
+
     /// Path to TLS certificate file (PEM) for WebTransport server.
     pub tls_cert_path: String,
 
@@ -109,6 +121,17 @@ pub struct Config {
 
     /// Maximum concurrent WebTransport connections (default: 10000).
     pub max_connections: usize,
+
+    /// Public URL prefix HLS egress playlists are served under
+    /// (e.g., `https://mh-1.example.com/hls`). HLS egress is disabled when unset.
+    pub hls_base_url: Option<String>,
+
+    /// Maximum concurrent live streaming egresses (default: 4).
+    pub max_egress_sessions: usize,
+
+    /// Media socket tuning (`MH_MEDIA_DSCP`, `MH_MEDIA_SEND_BUFFER_BYTES`,
+    /// `MH_MEDIA_RECV_BUFFER_BYTES`, `MH_MEDIA_GSO_ENABLED`).
+    pub media_socket: MediaSocketConfig,
 }
 
 /// Custom Debug implementation that redacts sensitive fields.
@@ -125,6 +148,10 @@ impl fmt::Debug for Config {
             .field("ac_endpoint", &self.ac_endpoint)
             .field("client_id", &self.client_id)
             .field("client_secret", &"[REDACTED]")
+            .field(
+                "media_token_secret",
+                &self.media_token_secret.as_ref().map(|_| "[REDACTED]"),
+            )
             .field("tls_cert_path", &self.tls_cert_path)
             .field("tls_key_path", &self.tls_key_path)
             .field("grpc_advertise_address", &self.grpc_advertise_address)
@@ -138,6 +165,9 @@ impl fmt::Debug for Config {
                 &self.register_meeting_timeout_seconds,
             )
             .field("max_connections", &self.max_connections)
+            .field("hls_base_url", &self.hls_base_url)
+            .field("max_egress_sessions", &self.max_egress_sessions)
+            .field("media_socket", &self.media_socket)
             .finish()
     }
 }
@@ -189,6 +219,17 @@ impl Config {
                 .clone(),
         );
 
+        // Checked here so a bad secret fails startup, not every connection
+        let media_token_secret = vars
+            .get("MH_MEDIA_TOKEN_SECRET")
+            .filter(|secret| !secret.is_empty())
+            .map(|secret| {
+                MediaTokenKey::from_base64(secret)
+                    .map(|_| SecretString::from(secret.clone()))
+                    .map_err(|e| ConfigError::InvalidValue(format!("MH_MEDIA_TOKEN_SECRET: {e}")))
+            })
+            .transpose()?;
+
         let tls_cert_path = vars
             .get("MH_TLS_CERT_PATH")
             .ok_or_else(|| ConfigError::MissingEnvVar("MH_TLS_CERT_PATH".to_string()))?
@@ -276,6 +317,23 @@ impl Config {
             .and_then(|s| s.parse().ok())
             .unwrap_or(DEFAULT_MAX_CONNECTIONS);
 
+        let hls_base_url = vars.get("MH_HLS_BASE_URL").cloned();
+        if let Some(url) = &hls_base_url {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return Err(ConfigError::InvalidValue(
+                    "MH_HLS_BASE_URL must start with http:// or https://".to_string(),
+                ));
+            }
+        }
+
+        let max_egress_sessions = vars
+            .get("MH_MAX_EGRESS_SESSIONS")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_MAX_EGRESS_SESSIONS);
+
+        let media_socket =
+            MediaSocketConfig::from_vars(vars, "MH").map_err(ConfigError::InvalidValue)?;
+
         // Generate MH instance ID
         let handler_id = vars.get("MH_HANDLER_ID").cloned().unwrap_or_else(|| {
             let hostname = std::env::var("HOSTNAME").unwrap_or_else(|_| "unknown".to_string());
@@ -295,6 +353,7 @@ impl Config {
             ac_endpoint,
             client_id,
             client_secret,
+            media_token_secret,
             tls_cert_path,
             tls_key_path,
             grpc_advertise_address,
@@ -302,6 +361,9 @@ impl Config {
             ac_jwks_url,
             register_meeting_timeout_seconds,
             max_connections,
+            hls_base_url,
+            max_egress_sessions,
+            media_socket,
         })
     }
 }
@@ -609,4 +671,88 @@ mod tests {
         let config = Config::from_vars(&vars).expect("Config should load successfully");
         assert_eq!(config.max_connections, 5000);
     }
+
+    #[test]
+    fn test_egress_config_defaults_and_custom_values() {
+        let config = Config::from_vars(&base_vars()).expect("Config should load successfully");
+        assert!(config.hls_base_url.is_none());
+        assert_eq!(config.max_egress_sessions, DEFAULT_MAX_EGRESS_SESSIONS);
+
+        let mut vars = base_vars();
+        vars.insert(
+            "MH_HLS_BASE_URL".to_string(),
+            "https://mh-1.example.com/hls".to_string(),
+        );
+        vars.insert("MH_MAX_EGRESS_SESSIONS".to_string(), "2".to_string());
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(
+            config.hls_base_url.as_deref(),
+            Some("https://mh-1.example.com/hls")
+        );
+        assert_eq!(config.max_egress_sessions, 2);
+    }
+
+    #[test]
+    fn test_from_vars_invalid_hls_base_url_scheme() {
+        let mut vars = base_vars();
+        vars.insert("MH_HLS_BASE_URL".to_string(), "ftp://mh-1/hls".to_string());
+
+        let result = Config::from_vars(&vars);
+        assert!(matches!(result, Err(ConfigError::InvalidValue(_))));
+    }
+
+    #[test]
+    fn test_media_token_secret() {
+        let mut vars = base_vars();
+        assert!(Config::from_vars(&vars)
+            .unwrap()
+            .media_token_secret
+            .is_none());
+
+        vars.insert(
+            "MH_MEDIA_TOKEN_SECRET".to_string(),
+            "dGVzdC1tZWRpYS10b2tlbi1zZWNyZXQtMDEyMzQ1Njc4OQ==".to_string(),
+        );
+        let config = Config::from_vars(&vars).unwrap();
+        assert_eq!(
+            config.media_token_secret.unwrap().expose_secret(),
+            "dGVzdC1tZWRpYS10b2tlbi1zZWNyZXQtMDEyMzQ1Njc4OQ=="
+        );
+    }
+
+    #[test]
+    fn test_media_token_secret_rejects_invalid() {
+        // Too short, and not base64
+        for secret in ["dGVzdA==", "not base64!"] {
+            let mut vars = base_vars();
+            vars.insert("MH_MEDIA_TOKEN_SECRET".to_string(), secret.to_string());
+            let result = Config::from_vars(&vars);
+            assert!(
+                matches!(result, Err(ConfigError::InvalidValue(msg)) if msg.contains("MH_MEDIA_TOKEN_SECRET"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_media_socket_config() {
+        let config = Config::from_vars(&base_vars()).expect("Config should load successfully");
+        assert_eq!(config.media_socket, MediaSocketConfig::default());
+
+        let mut vars = base_vars();
+        vars.insert("MH_MEDIA_DSCP".to_string(), "ef".to_string());
+        vars.insert(
+            "MH_MEDIA_RECV_BUFFER_BYTES".to_string(),
+            "8388608".to_string(),
+        );
+        let config = Config::from_vars(&vars).expect("Config should load successfully");
+        assert_eq!(
+            config.media_socket.dscp,
+            Some(common::media_socket::DSCP_EF)
+        );
+        assert_eq!(config.media_socket.recv_buffer_bytes, Some(8_388_608));
+
+        vars.insert("MH_MEDIA_DSCP".to_string(), "64".to_string());
+        let result = Config::from_vars(&vars);
+        assert!(matches!(result, Err(ConfigError::InvalidValue(_))));
+    }
 }
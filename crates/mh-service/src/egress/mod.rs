@@ -0,0 +1,590 @@
+//! Live streaming egress for Media Handler.
+//!
+//! An egress composes selected participants' video tiles and the mixed
+//! meeting audio into a single broadcast output:
+//!
+//! - **RTMP push** to an external ingest (YouTube, Twitch, a CDN)
+//! - **HLS** segments served from this MH under `MH_HLS_BASE_URL`
+//!
+//! Egress is started and stopped by the MC on behalf of the meeting host
+//! (`StartEgress` / `StopEgress`). At most one egress runs per meeting, and
+//! each MH caps concurrent egresses (`MH_MAX_EGRESS_SESSIONS`) because
+//! composition is far more expensive than forwarding.
+//!
+//! # Current Status: Stub
+//!
+//! Session lifecycle, validation, and tile layout are implemented. Like the
+//! rest of MH, no media is decoded yet, so no output is actually produced.
+//!
+//! # Actor Pattern (ADR-0001)
+//!
+//! `EgressManagerHandle` sends messages to an `EgressManagerActor` task that
+//! owns all egress state; see [`crate::session`] for the same structure.
+//!
+//! # Security
+//!
+//! RTMP URLs embed the destination's stream key. They are held as
+//! `SecretString` and never logged.
+
+use crate::compositor::grid_layout;
+pub use crate::compositor::TileRect;
+use common::secret::{ExposeSecret, SecretString};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::sync::{mpsc, oneshot};
+
+/// Maximum video tiles in a composed output (3x3 grid).
+pub const MAX_EGRESS_TILES: usize = 9;
+
+/// Default HLS segment duration in seconds.
+pub const DEFAULT_HLS_SEGMENT_SECONDS: u32 = 4;
+
+/// Minimum HLS segment duration in seconds.
+pub const MIN_HLS_SEGMENT_SECONDS: u32 = 2;
+
+/// Maximum HLS segment duration in seconds.
+pub const MAX_HLS_SEGMENT_SECONDS: u32 = 10;
+
+/// Composed output width in pixels.
+pub const OUTPUT_WIDTH: u32 = 1920;
+
+/// Composed output height in pixels.
+pub const OUTPUT_HEIGHT: u32 = 1080;
+
+/// Maximum RTMP URL length.
+const MAX_RTMP_URL_LENGTH: usize = 2048;
+
+/// Maximum participant ID length in a tile list.
+const MAX_PARTICIPANT_ID_LENGTH: usize = 256;
+
+/// Channel buffer size for the egress manager actor mailbox.
+const EGRESS_CHANNEL_BUFFER: usize = 64;
+
+/// Egress request errors.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum EgressError {
+    /// Output target or tile list is malformed.
+    #[error("Invalid egress request: {0}")]
+    InvalidRequest(&'static str),
+
+    /// The meeting already has an active egress.
+    #[error("Meeting already has an active egress")]
+    AlreadyActive,
+
+    /// This MH is running its maximum number of egresses.
+    #[error("Egress capacity exhausted")]
+    CapacityExhausted,
+
+    /// No matching egress for the meeting.
+    #[error("Egress not found")]
+    NotFound,
+
+    /// HLS requested but no HLS base URL is configured.
+    #[error("HLS egress is not configured")]
+    HlsUnavailable,
+
+    /// The egress manager actor has stopped.
+    #[error("Egress manager unavailable")]
+    Unavailable,
+}
+
+/// Broadcast output target.
+#[derive(Debug)]
+pub enum EgressOutput {
+    /// RTMP push; the URL includes the stream key.
+    Rtmp { url: SecretString },
+    /// HLS segments served by this MH (0 = default segment duration).
+    Hls { segment_duration_seconds: u32 },
+}
+
+impl EgressOutput {
+    /// Bounded output kind for logging and metrics.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            EgressOutput::Rtmp { .. } => "rtmp",
+            EgressOutput::Hls { .. } => "hls",
+        }
+    }
+}
+
+/// A request to start an egress.
+#[derive(Debug)]
+pub struct EgressRequest {
+    /// Meeting to compose.
+    pub meeting_id: String,
+    /// Participants whose video is composed, in layout order
+    /// (empty = active speaker).
+    pub tile_participant_ids: Vec<String>,
+    /// Output target.
+    pub output: EgressOutput,
+}
+
+/// A started egress.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EgressStarted {
+    /// Egress identifier (needed to stop it).
+    pub egress_id: String,
+    /// HLS playlist URL (`None` for RTMP).
+    pub playback_url: Option<String>,
+}
+
+/// Lay tiles out in the smallest square-ish grid that fits the output.
+///
+/// An empty list yields an empty layout (the active speaker fills the frame).
+#[must_use]
+pub fn compose_layout(tile_participant_ids: &[String]) -> Vec<TileRect> {
+    grid_layout(tile_participant_ids, OUTPUT_WIDTH, OUTPUT_HEIGHT)
+}
+
+/// Validate a request and compute its layout.
+///
+/// # Errors
+///
+/// Returns `EgressError::InvalidRequest` for a malformed tile list or output.
+pub fn validate_request(request: &EgressRequest) -> Result<Vec<TileRect>, EgressError> {
+    if request.tile_participant_ids.len() > MAX_EGRESS_TILES {
+        return Err(EgressError::InvalidRequest("too many video tiles"));
+    }
+    if request
+        .tile_participant_ids
+        .iter()
+        .any(|id| id.is_empty() || id.len() > MAX_PARTICIPANT_ID_LENGTH)
+    {
+        return Err(EgressError::InvalidRequest("invalid tile participant_id"));
+    }
+    for (i, id) in request.tile_participant_ids.iter().enumerate() {
+        if request
+            .tile_participant_ids
+            .iter()
+            .skip(i + 1)
+            .any(|o| o == id)
+        {
+            return Err(EgressError::InvalidRequest("duplicate tile participant_id"));
+        }
+    }
+
+    match &request.output {
+        EgressOutput::Rtmp { url } => validate_rtmp_url(url)?,
+        EgressOutput::Hls {
+            segment_duration_seconds,
+        } => {
+            if *segment_duration_seconds != 0
+                && !(MIN_HLS_SEGMENT_SECONDS..=MAX_HLS_SEGMENT_SECONDS)
+                    .contains(segment_duration_seconds)
+            {
+                return Err(EgressError::InvalidRequest(
+                    "HLS segment duration must be 2-10 seconds",
+                ));
+            }
+        }
+    }
+
+    Ok(compose_layout(&request.tile_participant_ids))
+}
+
+/// Check an RTMP ingest URL without exposing it in the error.
+fn validate_rtmp_url(url: &SecretString) -> Result<(), EgressError> {
+    let url = url.expose_secret();
+    let rest = url
+        .strip_prefix("rtmp://")
+        .or_else(|| url.strip_prefix("rtmps://"))
+        .ok_or(EgressError::InvalidRequest(
+            "RTMP URL must use rtmp:// or rtmps://",
+        ))?;
+
+    let host = rest.split(['/', ':']).next().unwrap_or_default();
+    if host.is_empty()
+        || url.len() > MAX_RTMP_URL_LENGTH
+        || url.chars().any(|c| c.is_whitespace() || c.is_control())
+    {
+        return Err(EgressError::InvalidRequest("malformed RTMP URL"));
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Messages
+// ---------------------------------------------------------------------------
+
+/// Messages sent to `EgressManagerActor`.
+#[derive(Debug)]
+enum EgressMessage {
+    /// Start an egress for a meeting.
+    Start {
+        request: EgressRequest,
+        respond_to: oneshot::Sender<Result<EgressStarted, EgressError>>,
+    },
+    /// Stop a meeting's egress. Returns how long it was live.
+    Stop {
+        meeting_id: String,
+        egress_id: String,
+        respond_to: oneshot::Sender<Result<Duration, EgressError>>,
+    },
+    /// Number of running egresses.
+    ActiveCount { respond_to: oneshot::Sender<usize> },
+}
+
+// ---------------------------------------------------------------------------
+// Actor (task)
+// ---------------------------------------------------------------------------
+
+/// A running egress.
+#[derive(Debug)]
+struct EgressSession {
+    egress_id: String,
+    output: EgressOutput,
+    layout: Vec<TileRect>,
+    started_at: Instant,
+}
+
+/// Actor that owns egress state and processes messages sequentially.
+struct EgressManagerActor {
+    receiver: mpsc::Receiver<EgressMessage>,
+    /// Running egresses: `meeting_id` -> session.
+    sessions: HashMap<String, EgressSession>,
+    max_sessions: usize,
+    hls_base_url: Option<String>,
+}
+
+impl EgressManagerActor {
+    async fn run(mut self) {
+        while let Some(msg) = self.receiver.recv().await {
+            match msg {
+                EgressMessage::Start {
+                    request,
+                    respond_to,
+                } => {
+                    let _ = respond_to.send(self.handle_start(request));
+                }
+                EgressMessage::Stop {
+                    meeting_id,
+                    egress_id,
+                    respond_to,
+                } => {
+                    let _ = respond_to.send(self.handle_stop(&meeting_id, &egress_id));
+                }
+                EgressMessage::ActiveCount { respond_to } => {
+                    let _ = respond_to.send(self.sessions.len());
+                }
+            }
+        }
+        tracing::debug!(
+            target: "mh.egress",
+            "EgressManagerActor stopped (channel closed)"
+        );
+    }
+
+    fn handle_start(&mut self, request: EgressRequest) -> Result<EgressStarted, EgressError> {
+        let layout = validate_request(&request)?;
+
+        if self.sessions.contains_key(&request.meeting_id) {
+            return Err(EgressError::AlreadyActive);
+        }
+        if self.sessions.len() >= self.max_sessions {
+            return Err(EgressError::CapacityExhausted);
+        }
+
+        let egress_id = uuid::Uuid::new_v4().to_string();
+        let playback_url = match &request.output {
+            EgressOutput::Rtmp { .. } => None,
+            EgressOutput::Hls { .. } => {
+                let base = self
+                    .hls_base_url
+                    .as_deref()
+                    .ok_or(EgressError::HlsUnavailable)?;
+                Some(format!(
+                    "{}/{egress_id}/index.m3u8",
+                    base.trim_end_matches('/')
+                ))
+            }
+        };
+
+        tracing::info!(
+            target: "mh.egress",
+            meeting_id = %request.meeting_id,
+            egress_id = %egress_id,
+            output = request.output.kind(),
+            tile_count = layout.len(),
+            "Egress started (stub: no media pipeline)"
+        );
+
+        self.sessions.insert(
+            request.meeting_id,
+            EgressSession {
+                egress_id: egress_id.clone(),
+                output: request.output,
+                layout,
+                started_at: Instant::now(),
+            },
+        );
+
+        Ok(EgressStarted {
+            egress_id,
+            playback_url,
+        })
+    }
+
+    fn handle_stop(&mut self, meeting_id: &str, egress_id: &str) -> Result<Duration, EgressError> {
+        match self.sessions.get(meeting_id) {
+            Some(session) if session.egress_id == egress_id => {}
+            _ => return Err(EgressError::NotFound),
+        }
+        let session = self
+            .sessions
+            .remove(meeting_id)
+            .ok_or(EgressError::NotFound)?;
+        let live = session.started_at.elapsed();
+
+        tracing::info!(
+            target: "mh.egress",
+            meeting_id = %meeting_id,
+            egress_id = %egress_id,
+            output = session.output.kind(),
+            tile_count = session.layout.len(),
+            live_seconds = live.as_secs(),
+            "Egress stopped"
+        );
+
+        Ok(live)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Handle (public API)
+// ---------------------------------------------------------------------------
+
+/// Handle to the `EgressManagerActor`.
+#[derive(Debug, Clone)]
+pub struct EgressManagerHandle {
+    sender: mpsc::Sender<EgressMessage>,
+}
+
+impl EgressManagerHandle {
+    /// Spawn an egress manager.
+    ///
+    /// `hls_base_url` is the public URL prefix HLS playlists are served
+    /// under; HLS requests are rejected when it is `None`.
+    #[must_use]
+    pub fn new(max_sessions: usize, hls_base_url: Option<String>) -> Self {
+        let (sender, receiver) = mpsc::channel(EGRESS_CHANNEL_BUFFER);
+        let actor = EgressManagerActor {
+            receiver,
+            sessions: HashMap::new(),
+            max_sessions,
+            hls_base_url,
+        };
+        tokio::spawn(actor.run());
+        Self { sender }
+    }
+
+    /// Start an egress.
+    ///
+    /// # Errors
+    ///
+    /// See [`EgressError`].
+    pub async fn start(&self, request: EgressRequest) -> Result<EgressStarted, EgressError> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(EgressMessage::Start {
+                request,
+                respond_to: tx,
+            })
+            .await
+            .map_err(|_| EgressError::Unavailable)?;
+        rx.await.map_err(|_| EgressError::Unavailable)?
+    }
+
+    /// Stop a meeting's egress and return how long it was live.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EgressError::NotFound` if the meeting has no egress with this ID.
+    pub async fn stop(&self, meeting_id: &str, egress_id: &str) -> Result<Duration, EgressError> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(EgressMessage::Stop {
+                meeting_id: meeting_id.to_string(),
+                egress_id: egress_id.to_string(),
+                respond_to: tx,
+            })
+            .await
+            .map_err(|_| EgressError::Unavailable)?;
+        rx.await.map_err(|_| EgressError::Unavailable)?
+    }
+
+    /// Number of running egresses.
+    pub async fn active_count(&self) -> usize {
+        let (tx, rx) = oneshot::channel();
+        if self
+            .sender
+            .send(EgressMessage::ActiveCount { respond_to: tx })
+            .await
+            .is_err()
+        {
+            return 0;
+        }
+        rx.await.unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn ids(n: usize) -> Vec<String> {
+        (1..=n).map(|i| format!("part-{i}")).collect()
+    }
+
+    fn rtmp_request(meeting_id: &str, url: &str) -> EgressRequest {
+        EgressRequest {
+            meeting_id: meeting_id.to_string(),
+            tile_participant_ids: ids(2),
+            output: EgressOutput::Rtmp {
+                url: SecretString::from(url),
+            },
+        }
+    }
+
+    fn hls_request(meeting_id: &str) -> EgressRequest {
+        EgressRequest {
+            meeting_id: meeting_id.to_string(),
+            tile_participant_ids: Vec::new(),
+            output: EgressOutput::Hls {
+                segment_duration_seconds: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn test_compose_layout_grid_sizes() {
+        assert!(compose_layout(&[]).is_empty());
+
+        let single = compose_layout(&ids(1));
+        assert_eq!(
+            single,
+            vec![TileRect {
+                participant_id: "part-1".to_string(),
+                x: 0,
+                y: 0,
+                width: OUTPUT_WIDTH,
+                height: OUTPUT_HEIGHT,
+            }]
+        );
+
+        let four = compose_layout(&ids(4));
+        assert_eq!(four.len(), 4);
+        assert!(four.iter().all(|t| t.width == 960 && t.height == 540));
+        assert_eq!((four[3].x, four[3].y), (960, 540));
+
+        let nine = compose_layout(&ids(9));
+        assert!(nine.iter().all(|t| t.width == 640 && t.height == 360));
+    }
+
+    #[test]
+    fn test_compose_layout_centers_partial_last_row() {
+        // 3 tiles: 2x2 grid, last row holds one centered tile
+        let three = compose_layout(&ids(3));
+        assert_eq!((three[0].x, three[0].y), (0, 0));
+        assert_eq!((three[1].x, three[1].y), (960, 0));
+        assert_eq!((three[2].x, three[2].y), (480, 540));
+    }
+
+    #[test]
+    fn test_validate_request_rejects_bad_input() {
+        let too_many = EgressRequest {
+            tile_participant_ids: ids(MAX_EGRESS_TILES + 1),
+            ..rtmp_request("m", "rtmp://live.example.com/app/key")
+        };
+        let duplicate = EgressRequest {
+            tile_participant_ids: vec!["a".to_string(), "a".to_string()],
+            ..rtmp_request("m", "rtmp://live.example.com/app/key")
+        };
+        let bad_segment = EgressRequest {
+            output: EgressOutput::Hls {
+                segment_duration_seconds: 30,
+            },
+            ..hls_request("m")
+        };
+
+        for request in [
+            too_many,
+            duplicate,
+            bad_segment,
+            rtmp_request("m", "http://live.example.com/app/key"),
+            rtmp_request("m", "rtmp:///app/key"),
+            rtmp_request("m", "rtmp://live.example.com/app/key with space"),
+        ] {
+            assert!(
+                matches!(
+                    validate_request(&request),
+                    Err(EgressError::InvalidRequest(_))
+                ),
+                "{request:?} should be rejected"
+            );
+        }
+
+        assert!(
+            validate_request(&rtmp_request("m", "rtmps://live.example.com:443/app/key")).is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_start_stop_lifecycle() {
+        let manager = EgressManagerHandle::new(4, None);
+
+        let started = manager
+            .start(rtmp_request("meeting-1", "rtmp://live.example.com/app/key"))
+            .await
+            .unwrap();
+        assert!(started.playback_url.is_none());
+        assert_eq!(manager.active_count().await, 1);
+
+        assert_eq!(
+            manager.stop("meeting-1", "wrong-id").await,
+            Err(EgressError::NotFound)
+        );
+        manager.stop("meeting-1", &started.egress_id).await.unwrap();
+        assert_eq!(manager.active_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_one_egress_per_meeting_and_capacity() {
+        let manager = EgressManagerHandle::new(2, None);
+        let url = "rtmp://live.example.com/app/key";
+
+        manager.start(rtmp_request("meeting-1", url)).await.unwrap();
+        assert_eq!(
+            manager.start(rtmp_request("meeting-1", url)).await,
+            Err(EgressError::AlreadyActive)
+        );
+
+        manager.start(rtmp_request("meeting-2", url)).await.unwrap();
+        assert_eq!(
+            manager.start(rtmp_request("meeting-3", url)).await,
+            Err(EgressError::CapacityExhausted)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hls_requires_base_url() {
+        let disabled = EgressManagerHandle::new(4, None);
+        assert_eq!(
+            disabled.start(hls_request("meeting-1")).await,
+            Err(EgressError::HlsUnavailable)
+        );
+
+        let enabled =
+            EgressManagerHandle::new(4, Some("https://mh-1.example.com/hls/".to_string()));
+        let started = enabled.start(hls_request("meeting-1")).await.unwrap();
+        assert_eq!(
+            started.playback_url,
+            Some(format!(
+                "https://mh-1.example.com/hls/{}/index.m3u8",
+                started.egress_id
+            ))
+        );
+    }
+}
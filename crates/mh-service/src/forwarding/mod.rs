@@ -0,0 +1,376 @@
+//! Per-subscriber forwarding queue with priority-aware congestion drops.
+//!
+//! Each subscriber connection gets a [`ForwardQueue`] bounded in bytes.
+//! While the subscriber keeps up, frames pass through in order. When a new
+//! frame does not fit, the queue makes room by dropping the frames that
+//! matter least, ranked by:
+//!
+//! 1. Discardable (non-reference) frames before frames others depend on
+//! 2. Lower [`FramePriority`] before higher
+//! 3. Older before newer
+//!
+//! Only frames ranked strictly below the incoming frame are dropped for it;
+//! if nothing qualifies, the incoming frame is dropped instead. Audio and
+//! keyframes, which publishers mark [`FramePriority::Critical`] and not
+//! discardable, are therefore the last to go.
+//!
+//! Every drop is counted in `mh_media_frames_dropped_total` by priority.
+//!
+//! Published frames are checked by the publisher connection's
+//! [`FrameIngest`] before anything else sees them: a frame must carry the
+//! connection's authenticated `user_id`, open at most
+//! [`MAX_STREAMS_PER_CONNECTION`] streams, and pass its stream's replay
+//! window. Rejections are counted in `mh_media_frames_rejected_total` and
+//! `mh_media_frames_replayed_total`.
+//!
+//! # Current Status
+//!
+//! Placeholder: MH has no subscriber fan-out yet. Connections run every
+//! published frame through [`FrameIngest`] and then discard the accepted
+//! ones; nothing pushes to a [`ForwardQueue`] outside tests.
+
+use crate::observability::metrics;
+use media_protocol::frame::{FramePriority, FrameType, MediaFrame};
+use media_protocol::stream::{MediaStream, ReplayCheck, StreamConfig};
+use std::collections::{HashMap, VecDeque};
+
+/// Default per-subscriber queue capacity in payload bytes.
+pub const DEFAULT_FORWARD_QUEUE_BYTES: usize = 256 * 1024;
+
+/// Drop rank of a frame; lower ranks are dropped first.
+fn rank(frame: &MediaFrame) -> (bool, FramePriority) {
+    (!frame.flags.discardable, frame.flags.priority)
+}
+
+/// Bounded queue of frames waiting to be sent to one subscriber.
+#[derive(Debug)]
+pub struct ForwardQueue {
+    frames: VecDeque<MediaFrame>,
+    bytes: usize,
+    capacity_bytes: usize,
+    dropped: [u64; FramePriority::ALL.len()],
+}
+
+impl ForwardQueue {
+    /// Create a queue holding at most `capacity_bytes` of payload.
+    #[must_use]
+    pub fn new(capacity_bytes: usize) -> Self {
+        Self {
+            frames: VecDeque::new(),
+            bytes: 0,
+            capacity_bytes,
+            dropped: [0; FramePriority::ALL.len()],
+        }
+    }
+
+    /// Frames currently queued.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Whether no frames are queued.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Payload bytes currently queued.
+    #[must_use]
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Frames of `priority` dropped so far.
+    #[must_use]
+    pub fn dropped(&self, priority: FramePriority) -> u64 {
+        self.dropped
+            .get(priority as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Queue `frame`, dropping lower-ranked frames if it does not fit.
+    ///
+    /// Returns `false` if `frame` itself was dropped.
+    pub fn push(&mut self, frame: MediaFrame) -> bool {
+        let size = frame.payload.len();
+        if size > self.capacity_bytes {
+            self.record_drop(&frame);
+            return false;
+        }
+
+        let incoming = rank(&frame);
+        while self.bytes + size > self.capacity_bytes {
+            let victim = self
+                .frames
+                .iter()
+                .enumerate()
+                .filter(|(_, queued)| rank(queued) < incoming)
+                .min_by_key(|(_, queued)| rank(queued))
+                .map(|(index, _)| index);
+            let Some(dropped) = victim.and_then(|index| self.frames.remove(index)) else {
+                self.record_drop(&frame);
+                return false;
+            };
+            self.bytes -= dropped.payload.len();
+            self.record_drop(&dropped);
+        }
+
+        self.bytes += size;
+        self.frames.push_back(frame);
+        true
+    }
+
+    /// Take the oldest queued frame for sending.
+    pub fn pop(&mut self) -> Option<MediaFrame> {
+        let frame = self.frames.pop_front()?;
+        self.bytes -= frame.payload.len();
+        Some(frame)
+    }
+
+    fn record_drop(&mut self, frame: &MediaFrame) {
+        let priority = frame.flags.priority;
+        if let Some(count) = self.dropped.get_mut(priority as usize) {
+            *count = count.saturating_add(1);
+        }
+        metrics::record_media_frame_dropped(priority.as_str(), frame.flags.discardable);
+    }
+}
+
+impl Default for ForwardQueue {
+    fn default() -> Self {
+        Self::new(DEFAULT_FORWARD_QUEUE_BYTES)
+    }
+}
+
+/// Maximum streams one publisher connection may open.
+///
+/// Covers audio, simulcast video layers and screen share with room to
+/// spare; frames for further stream IDs are rejected so a publisher cannot
+/// grow [`FrameIngest`] without bound.
+pub const MAX_STREAMS_PER_CONNECTION: usize = 16;
+
+/// Receive side of one publisher's connection.
+///
+/// Bound to the `user_id` the connection authenticated as (see
+/// [`media_protocol::frame::user_id_for_subject`]). Tracks a
+/// [`MediaStream`] per `stream_id` so every frame is checked against its
+/// stream's replay window.
+#[derive(Debug)]
+pub struct FrameIngest {
+    user_id: u64,
+    streams: HashMap<u32, MediaStream>,
+}
+
+impl FrameIngest {
+    /// Create an ingest for a connection authenticated as `user_id`.
+    #[must_use]
+    pub fn new(user_id: u64) -> Self {
+        Self {
+            user_id,
+            streams: HashMap::new(),
+        }
+    }
+
+    /// Streams seen so far.
+    #[must_use]
+    pub fn stream_count(&self) -> usize {
+        self.streams.len()
+    }
+
+    /// Check `frame` against the connection's identity, the stream limit and
+    /// its stream's replay window.
+    ///
+    /// Returns the frame if it should be forwarded, or `None` if it was
+    /// rejected (and counted).
+    pub fn accept(&mut self, frame: MediaFrame) -> Option<MediaFrame> {
+        if frame.user_id != self.user_id {
+            metrics::record_media_frame_rejected("user_mismatch");
+            return None;
+        }
+        if !self.streams.contains_key(&frame.stream_id)
+            && self.streams.len() >= MAX_STREAMS_PER_CONNECTION
+        {
+            metrics::record_media_frame_rejected("stream_limit");
+            return None;
+        }
+        let stream = self.streams.entry(frame.stream_id).or_insert_with(|| {
+            MediaStream::new(StreamConfig {
+                user_id: frame.user_id,
+                stream_id: frame.stream_id,
+                max_bitrate: 0,
+                is_audio: frame.frame_type == FrameType::Audio,
+            })
+        });
+        match stream.receive(&frame) {
+            ReplayCheck::Accepted => Some(frame),
+            check @ (ReplayCheck::Duplicate | ReplayCheck::TooOld) => {
+                metrics::record_media_frame_replayed(check.as_str());
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use media_protocol::frame::FrameFlags;
+
+    fn frame(sequence: u64, priority: FramePriority, discardable: bool) -> MediaFrame {
+        MediaFrame {
+            version: MediaFrame::VERSION,
+            user_id: 1,
+            stream_id: 1,
+            frame_type: FrameType::VideoDelta,
+            timestamp: 0,
+            sequence,
+            flags: FrameFlags {
+                end_of_frame: true,
+                discardable,
+                priority,
+            },
+            key_epoch: 0,
+            payload: Bytes::from(vec![0u8; 100]),
+        }
+    }
+
+    fn sequences(queue: &mut ForwardQueue) -> Vec<u64> {
+        std::iter::from_fn(|| queue.pop().map(|f| f.sequence)).collect()
+    }
+
+    #[test]
+    fn test_fifo_under_capacity() {
+        let mut queue = ForwardQueue::new(1000);
+        for sequence in 0..5 {
+            assert!(queue.push(frame(sequence, FramePriority::Normal, false)));
+        }
+        assert_eq!(queue.bytes(), 500);
+        assert_eq!(sequences(&mut queue), vec![0, 1, 2, 3, 4]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.bytes(), 0);
+    }
+
+    #[test]
+    fn test_discardable_dropped_first() {
+        let mut queue = ForwardQueue::new(300);
+        assert!(queue.push(frame(0, FramePriority::Low, false)));
+        assert!(queue.push(frame(1, FramePriority::High, true)));
+        assert!(queue.push(frame(2, FramePriority::Normal, false)));
+
+        // A discardable frame goes before any frame others depend on,
+        // whatever its priority
+        assert!(queue.push(frame(3, FramePriority::Normal, false)));
+        assert_eq!(queue.dropped(FramePriority::High), 1);
+        assert_eq!(sequences(&mut queue), vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn test_lowest_priority_oldest_dropped() {
+        let mut queue = ForwardQueue::new(300);
+        assert!(queue.push(frame(0, FramePriority::Normal, false)));
+        assert!(queue.push(frame(1, FramePriority::Low, false)));
+        assert!(queue.push(frame(2, FramePriority::Low, false)));
+
+        assert!(queue.push(frame(3, FramePriority::Critical, false)));
+        assert!(queue.push(frame(4, FramePriority::Critical, false)));
+        assert_eq!(queue.dropped(FramePriority::Low), 2);
+        assert_eq!(sequences(&mut queue), vec![0, 3, 4]);
+    }
+
+    #[test]
+    fn test_incoming_dropped_when_nothing_ranks_lower() {
+        let mut queue = ForwardQueue::new(200);
+        assert!(queue.push(frame(0, FramePriority::Critical, false)));
+        assert!(queue.push(frame(1, FramePriority::Normal, false)));
+
+        // Equal rank: the queued frame is kept
+        assert!(!queue.push(frame(2, FramePriority::Normal, false)));
+        assert!(!queue.push(frame(3, FramePriority::Low, true)));
+        assert_eq!(queue.dropped(FramePriority::Normal), 1);
+        assert_eq!(queue.dropped(FramePriority::Low), 1);
+        assert_eq!(sequences(&mut queue), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_oversized_frame_dropped() {
+        let mut queue = ForwardQueue::new(50);
+        assert!(!queue.push(frame(0, FramePriority::Critical, false)));
+        assert_eq!(queue.dropped(FramePriority::Critical), 1);
+        assert!(queue.is_empty());
+    }
+
+    fn accepted(ingest: &mut FrameIngest, frame: MediaFrame) -> Option<u64> {
+        ingest.accept(frame).map(|f| f.sequence)
+    }
+
+    #[test]
+    fn test_ingest_rejects_replayed_frame() {
+        let mut ingest = FrameIngest::new(1);
+        assert_eq!(
+            accepted(&mut ingest, frame(7, FramePriority::Normal, false)),
+            Some(7)
+        );
+
+        // Same stream and sequence again: a replay, never forwarded
+        assert_eq!(
+            accepted(&mut ingest, frame(7, FramePriority::Normal, false)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ingest_rejects_frame_older_than_window() {
+        let mut ingest = FrameIngest::new(1);
+        assert_eq!(
+            accepted(&mut ingest, frame(500, FramePriority::Normal, false)),
+            Some(500)
+        );
+        assert_eq!(
+            accepted(&mut ingest, frame(1, FramePriority::Normal, false)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ingest_tracks_streams_separately() {
+        let mut ingest = FrameIngest::new(1);
+        let mut other = frame(7, FramePriority::Normal, false);
+        other.stream_id = 2;
+        assert!(ingest
+            .accept(frame(7, FramePriority::Normal, false))
+            .is_some());
+        assert!(ingest.accept(other).is_some());
+        assert_eq!(ingest.stream_count(), 2);
+    }
+
+    #[test]
+    fn test_ingest_rejects_other_user_id() {
+        let mut ingest = FrameIngest::new(2);
+        // frame() publishes as user 1
+        assert!(ingest
+            .accept(frame(7, FramePriority::Normal, false))
+            .is_none());
+        assert_eq!(ingest.stream_count(), 0);
+    }
+
+    #[test]
+    fn test_ingest_caps_streams_per_connection() {
+        let mut ingest = FrameIngest::new(1);
+        let stream_limit = u32::try_from(MAX_STREAMS_PER_CONNECTION).unwrap();
+        for stream_id in 0..=stream_limit {
+            let mut f = frame(1, FramePriority::Normal, false);
+            f.stream_id = stream_id;
+            let forwarded = ingest.accept(f).is_some();
+            assert_eq!(forwarded, stream_id < stream_limit, "stream {stream_id}");
+        }
+        assert_eq!(ingest.stream_count(), MAX_STREAMS_PER_CONNECTION);
+
+        // Streams already open keep flowing
+        assert!(ingest
+            .accept(frame(2, FramePriority::Normal, false))
+            .is_some());
+    }
+}
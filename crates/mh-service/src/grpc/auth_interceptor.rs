@@ -39,6 +39,9 @@ use tower::{Layer, Service};
 /// Required scope for MC→MH gRPC operations (ADR-0003).
 const REQUIRED_SCOPE: &str = "service.write.mh";
 
+/// gRPC path prefix of `BuildInfoService`, open to every caller type.
+const BUILD_INFO_GRPC_PREFIX: &str = "/dark_tower.internal.v1.BuildInfoService/";
+
 /// Map a `JwtError` to a bounded `failure_reason` label for the
 /// `mh_jwt_validations_total` metric.
 fn classify_jwt_error(err: &JwtError) -> &'static str {
@@ -219,6 +222,15 @@ where
             // Layer 2: service_type routing (ADR-0003)
             // Match the gRPC service path to the expected caller service_type.
             let grpc_path = request.uri().path();
+
+            // BuildInfoService only returns build metadata: any authenticated
+            // service may call it, so it skips caller-type routing.
+            if grpc_path.starts_with(BUILD_INFO_GRPC_PREFIX) {
+                let mut request = request;
+                request.extensions_mut().insert(claims);
+                return inner.call(request).await;
+            }
+
             let expected_type =
                 if grpc_path.starts_with("/dark_tower.internal.v1.MediaHandlerService/") {
                     "meeting-controller"
@@ -637,6 +649,28 @@ mod tests {
         assert_permission_denied(&response, "no service_type (fail closed)");
     }
 
+    #[tokio::test]
+    async fn test_layer2_allows_any_caller_type_for_build_info() {
+        let (_mock_server, keypair, layer) = setup_auth_layer().await;
+        let mut svc = layer.layer(NoopService);
+
+        let claims = make_service_claims("service.write.mh", Some("global-controller"));
+        let token = keypair.sign_token(&claims);
+
+        let request = http::Request::builder()
+            .uri("/dark_tower.internal.v1.BuildInfoService/GetVersion")
+            .header("authorization", format!("Bearer {token}"))
+            .body(BoxBody::default())
+            .unwrap();
+
+        let response = svc.ready().await.unwrap().call(request).await.unwrap();
+        let status = tonic::Status::from_header_map(response.headers());
+        assert!(
+            status.is_none(),
+            "BuildInfoService should accept any caller type, got: {status:?}"
+        );
+    }
+
     #[tokio::test]
     async fn test_layer2_rejects_unknown_grpc_path() {
         let (_mock_server, keypair, layer) = setup_auth_layer().await;
@@ -0,0 +1,71 @@
+//! Build Info gRPC Service.
+//!
+//! Implements `BuildInfoService.GetVersion`, the gRPC twin of the HTTP
+//! `/version` endpoint, for callers (GC and MC) that only hold a gRPC channel.
+//!
+//! # Security
+//!
+//! The auth layer still requires a valid token with this server's scope but
+//! skips caller-type routing: the response is the same build metadata
+//! `/version` serves without authentication.
+
+use common::build_info::BuildInfo;
+use proto_gen::dark_tower::internal::v1::build_info_service_server::BuildInfoService;
+use proto_gen::dark_tower::internal::v1::{GetVersionRequest, GetVersionResponse};
+use tonic::{Request, Response, Status};
+use tracing::instrument;
+
+/// Serves this binary's [`BuildInfo`].
+#[derive(Debug, Clone)]
+pub struct MhBuildInfoService {
+    info: BuildInfo,
+}
+
+impl MhBuildInfoService {
+    /// Create a build info service.
+    #[must_use]
+    pub fn new(info: BuildInfo) -> Self {
+        Self { info }
+    }
+}
+
+#[tonic::async_trait]
+impl BuildInfoService for MhBuildInfoService {
+    #[instrument(skip_all, name = "mh.grpc.get_version")]
+    async fn get_version(
+        &self,
+        _request: Request<GetVersionRequest>,
+    ) -> Result<Response<GetVersionResponse>, Status> {
+        Ok(Response::new(GetVersionResponse {
+            service: self.info.service.to_string(),
+            version: self.info.version.to_string(),
+            git_sha: self.info.git_sha.to_string(),
+            build_time: self.info.build_time.to_string(),
+            proto_schema: self.info.proto_schema.unwrap_or_default().to_string(),
+            features: self.info.features.iter().map(ToString::to_string).collect(),
+        }))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_version_reports_build_info() {
+        let info = common::build_info!("mh-service").with_proto_schema(proto_gen::SCHEMA_VERSION);
+        let service = MhBuildInfoService::new(info);
+
+        let response = service
+            .get_version(Request::new(GetVersionRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(response.service, "mh-service");
+        assert_eq!(response.version, env!("CARGO_PKG_VERSION"));
+        assert!(!response.git_sha.is_empty());
+        assert_eq!(response.proto_schema, proto_gen::SCHEMA_VERSION);
+    }
+}
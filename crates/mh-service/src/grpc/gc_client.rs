@@ -3,6 +3,7 @@
 //! Provides a client for MH→GC communication per ADR-0010:
 //! - Registration on startup (`RegisterMH`)
 //! - Periodic load reports (`SendLoadReport`)
+//! - Finished recording metadata (`ReportRecording`)
 //!
 //! # Security (ADR-0003)
 //!
@@ -17,10 +18,13 @@
 use crate::config::Config;
 use crate::errors::MhError;
 use crate::observability::metrics;
+use common::request_id::{RequestId, REQUEST_ID_HEADER};
 use common::secret::ExposeSecret;
 use common::token_manager::TokenReceiver;
 use proto_gen::dark_tower::internal::v1::media_handler_registry_service_client::MediaHandlerRegistryServiceClient;
-use proto_gen::dark_tower::internal::v1::{RegisterMhRequest, SendLoadReportRequest};
+use proto_gen::dark_tower::internal::v1::{
+    BuildVersion, RegisterMhRequest, ReportRecordingRequest, SendLoadReportRequest,
+};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 use tonic::transport::{Channel, Endpoint};
@@ -42,6 +46,16 @@ const BACKOFF_BASE: Duration = Duration::from_secs(1);
 /// Maximum backoff delay.
 const BACKOFF_MAX: Duration = Duration::from_secs(30);
 
+/// Build reported at registration, checked against GC's minimum fleet version.
+fn build_version() -> BuildVersion {
+    let info = common::build_info!("mh-service");
+    BuildVersion {
+        version: info.version.to_string(),
+        git_sha: info.git_sha.to_string(),
+        proto_schema: proto_gen::SCHEMA_VERSION.to_string(),
+    }
+}
+
 /// GC client for MH→GC communication.
 ///
 /// Uses a tonic `Channel` which is cheaply cloneable and handles
@@ -106,7 +120,7 @@ impl GcClient {
         })
     }
 
-    /// Add authorization header to a request.
+    /// Add authorization and request ID headers to a request.
     fn add_auth<T>(&self, request: T) -> Result<Request<T>, MhError> {
         let mut grpc_request = Request::new(request);
         let current_token = self.token_rx.token();
@@ -119,6 +133,11 @@ impl GcClient {
                     MhError::Config(format!("Authorization header parse failed: {e}"))
                 })?,
         );
+        if let Some(request_id) = RequestId::current() {
+            if let Ok(value) = request_id.as_str().parse() {
+                grpc_request.metadata_mut().insert(REQUEST_ID_HEADER, value);
+            }
+        }
         Ok(grpc_request)
     }
 
@@ -137,6 +156,7 @@ impl GcClient {
             webtransport_endpoint: self.config.webtransport_advertise_address.clone(),
             grpc_endpoint: self.config.grpc_advertise_address.clone(),
             max_streams: self.config.max_streams,
+            build: Some(build_version()),
         };
 
         let mut delay = BACKOFF_BASE;
@@ -290,6 +310,7 @@ impl GcClient {
             webtransport_endpoint: self.config.webtransport_advertise_address.clone(),
             grpc_endpoint: self.config.grpc_advertise_address.clone(),
             max_streams: self.config.max_streams,
+            build: Some(build_version()),
         };
 
         match self.try_register(&request).await {
@@ -310,6 +331,50 @@ impl GcClient {
         }
     }
 
+    /// Report a finished recording to GC once its upload has completed.
+    ///
+    /// `handler_id` is filled in from config. The recorder chooses the
+    /// `recording_id`, so retrying after a timeout is safe.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MhError::Grpc` if GC rejects the report or the RPC fails.
+    #[instrument(skip_all, fields(handler_id = %self.config.handler_id, meeting_id = %request.meeting_id))]
+    pub async fn report_recording(
+        &self,
+        mut request: ReportRecordingRequest,
+    ) -> Result<(), MhError> {
+        request.handler_id.clone_from(&self.config.handler_id);
+        let recording_id = request.recording_id.clone();
+        let grpc_request = self.add_auth(request)?;
+
+        let mut client = MediaHandlerRegistryServiceClient::new(self.channel.clone());
+
+        let response = client
+            .report_recording(grpc_request)
+            .await
+            .map_err(|status| {
+                warn!(
+                    target: "mh.grpc.gc_client",
+                    recording_id = %recording_id,
+                    error = %status,
+                    "ReportRecording RPC failed"
+                );
+                MhError::Grpc(format!("ReportRecording failed: {status}"))
+            })?;
+
+        if !response.into_inner().accepted {
+            return Err(MhError::Grpc("GC rejected recording report".to_string()));
+        }
+
+        info!(
+            target: "mh.grpc.gc_client",
+            recording_id = %recording_id,
+            "Recording reported to GC"
+        );
+        Ok(())
+    }
+
     /// Get the load report interval in milliseconds.
     #[must_use]
     pub fn load_report_interval_ms(&self) -> u64 {
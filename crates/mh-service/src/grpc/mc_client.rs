@@ -5,6 +5,8 @@
 //!   a WebTransport connection to this MH
 //! - `NotifyParticipantDisconnected` — inform MC when a participant's
 //!   WebTransport connection drops
+//! - `RequestKeyframe` — ask MC to have a publisher send a keyframe after
+//!   loss on one of its video streams
 //!
 //! # Security (ADR-0003)
 //!
@@ -20,11 +22,12 @@
 
 use crate::errors::MhError;
 use crate::observability::metrics;
+use common::request_id::{RequestId, REQUEST_ID_HEADER};
 use common::secret::ExposeSecret;
 use common::token_manager::TokenReceiver;
 use proto_gen::dark_tower::internal::v1::media_coordination_service_client::MediaCoordinationServiceClient;
 use proto_gen::dark_tower::internal::v1::{
-    NotifyParticipantConnectedRequest, NotifyParticipantDisconnectedRequest,
+    NotifyParticipantConnectedRequest, NotifyParticipantDisconnectedRequest, RequestKeyframeRequest,
 };
 use std::time::Duration;
 use tonic::transport::Endpoint;
@@ -152,6 +155,61 @@ impl McClient {
         .await
     }
 
+    /// Ask MC to have a publisher send a keyframe on one of its video streams.
+    ///
+    /// Sent once, without retry: by the time a retry succeeded the publisher's
+    /// next keyframe is usually already on its way, and MC drops repeats for
+    /// the same stream within its cooldown anyway.
+    ///
+    /// # Arguments
+    ///
+    /// * `mc_grpc_endpoint` - gRPC endpoint of the target MC
+    /// * `meeting_id` - Meeting the stream belongs to
+    /// * `participant_id` - Participant publishing the stream
+    /// * `handler_id` - This MH instance's identifier
+    /// * `stream_type` - Video stream (proto `StreamType` enum value)
+    ///
+    /// # Errors
+    ///
+    /// Returns `MhError::Config` if the endpoint is invalid.
+    /// Returns `MhError::Grpc` if the connection or RPC fails.
+    #[instrument(skip_all, fields(meeting_id = %meeting_id), target = "mh.grpc.mc_client")]
+    pub async fn request_keyframe(
+        &self,
+        mc_grpc_endpoint: &str,
+        meeting_id: &str,
+        participant_id: &str,
+        handler_id: &str,
+        stream_type: i32,
+    ) -> Result<(), MhError> {
+        let request = RequestKeyframeRequest {
+            meeting_id: meeting_id.to_string(),
+            participant_id: participant_id.to_string(),
+            handler_id: handler_id.to_string(),
+            stream_type,
+        };
+
+        let result = self
+            .try_send(mc_grpc_endpoint, &request, &|mut client, req| {
+                Box::pin(async move { client.request_keyframe(req).await })
+            })
+            .await;
+
+        match &result {
+            Ok(()) => metrics::record_mc_notification("keyframe", "success"),
+            Err(e) => {
+                debug!(
+                    target: "mh.grpc.mc_client",
+                    error = %e,
+                    meeting_id = %meeting_id,
+                    "Keyframe request to MC failed"
+                );
+                metrics::record_mc_notification("keyframe", "error");
+            }
+        }
+        result
+    }
+
     /// Send an RPC with retry and exponential backoff.
     ///
     /// Retries up to `MAX_RETRY_ATTEMPTS` times with delays of 1s, 2s, 4s.
@@ -295,7 +353,7 @@ impl McClient {
         Ok(())
     }
 
-    /// Add authorization header to a request.
+    /// Add authorization and request ID headers to a request.
     fn add_auth<T>(&self, request: T) -> Result<Request<T>, MhError> {
         let mut grpc_request = Request::new(request);
         let current_token = self.token_rx.token();
@@ -312,6 +370,11 @@ impl McClient {
                     MhError::Config(format!("Authorization header parse failed: {e}"))
                 })?,
         );
+        if let Some(request_id) = RequestId::current() {
+            if let Ok(value) = request_id.as_str().parse() {
+                grpc_request.metadata_mut().insert(REQUEST_ID_HEADER, value);
+            }
+        }
         Ok(grpc_request)
     }
 }
@@ -430,6 +493,21 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_request_keyframe_unreachable_endpoint() {
+        let token_rx = mock_token_receiver();
+        let client = McClient::new(token_rx);
+
+        let result = client
+            .request_keyframe("http://127.0.0.1:59995", "meeting-1", "user-1", "mh-1", 2)
+            .await;
+
+        assert!(
+            matches!(&result, Err(MhError::Grpc(msg)) if msg.contains("Failed to connect")),
+            "Expected Grpc connection error, got: {result:?}"
+        );
+    }
+
     #[test]
     fn test_is_auth_error_jwt_validation() {
         let err = MhError::JwtValidation("MC rejected service token".to_string());
@@ -1,8 +1,9 @@
 //! `MediaHandlerService` gRPC server implementation.
 //!
 //! Implements the MC→MH gRPC service from `internal.proto`.
-//! `register_meeting` is fully integrated with `SessionManagerHandle`;
-//! other handlers remain stubs to unblock end-to-end join flow testing.
+//! `register_meeting` is fully integrated with `SessionManagerHandle` and
+//! `start_egress`/`stop_egress` with `EgressManagerHandle`; other handlers
+//! remain stubs to unblock end-to-end join flow testing.
 //!
 //! # Security
 //!
@@ -11,12 +12,17 @@
 
 use std::time::Instant;
 
+use crate::compositor::LayoutConfig;
+use crate::egress::{EgressError, EgressManagerHandle, EgressOutput, EgressRequest};
 use crate::observability::metrics;
 use crate::session::{MeetingRegistration, SessionManagerHandle};
+use common::secret::SecretString;
 use proto_gen::dark_tower::internal::v1::media_handler_service_server::MediaHandlerService;
 use proto_gen::dark_tower::internal::v1::{
-    RegisterMeetingRequest, RegisterMeetingResponse, RegisterRequest, RegisterResponse,
-    RouteMediaRequest, RouteMediaResponse, StreamTelemetryRequest, StreamTelemetryResponse,
+    start_egress_request, RegisterMeetingRequest, RegisterMeetingResponse, RegisterRequest,
+    RegisterResponse, RouteMediaRequest, RouteMediaResponse, StartEgressRequest,
+    StartEgressResponse, StopEgressRequest, StopEgressResponse, StreamTelemetryRequest,
+    StreamTelemetryResponse,
 };
 use tonic::{Request, Response, Status, Streaming};
 use tracing::instrument;
@@ -41,13 +47,44 @@ const MAX_ENDPOINT_LENGTH: usize = 2048;
 /// `SessionManagerHandle`; other handlers remain stubs.
 pub struct MhMediaService {
     session_manager: SessionManagerHandle,
+    /// Live streaming egress manager (`None` = egress disabled).
+    egress: Option<EgressManagerHandle>,
 }
 
 impl MhMediaService {
     /// Create a new media handler service with the given session manager handle.
     #[must_use]
     pub fn new(session_manager: SessionManagerHandle) -> Self {
-        Self { session_manager }
+        Self {
+            session_manager,
+            egress: None,
+        }
+    }
+
+    /// Enable live streaming egress using the given manager.
+    #[must_use]
+    pub fn with_egress(mut self, egress: EgressManagerHandle) -> Self {
+        self.egress = Some(egress);
+        self
+    }
+
+    fn egress_manager(&self, rpc: &'static str) -> Result<&EgressManagerHandle, Status> {
+        self.egress.as_ref().ok_or_else(|| {
+            metrics::record_grpc_request(rpc, "error");
+            Status::unavailable("Egress is not enabled on this media handler")
+        })
+    }
+}
+
+/// Map an egress error to a gRPC status.
+fn egress_status(err: &EgressError) -> Status {
+    match err {
+        EgressError::InvalidRequest(msg) => Status::invalid_argument(*msg),
+        EgressError::AlreadyActive => Status::already_exists(err.to_string()),
+        EgressError::CapacityExhausted => Status::resource_exhausted(err.to_string()),
+        EgressError::NotFound => Status::not_found(err.to_string()),
+        EgressError::HlsUnavailable => Status::failed_precondition(err.to_string()),
+        EgressError::Unavailable => Status::unavailable(err.to_string()),
     }
 }
 
@@ -151,6 +188,7 @@ impl MediaHandlerService for MhMediaService {
             ));
         }
 
+        let layout_config = LayoutConfig::from_proto(req.recording_layout);
         let promoted = self
             .session_manager
             .register_meeting(
@@ -159,6 +197,8 @@ impl MediaHandlerService for MhMediaService {
                     mc_id: req.mc_id.clone(),
                     mc_grpc_endpoint: req.mc_grpc_endpoint.clone(),
                     registered_at: Instant::now(),
+                    layout_config,
+                    e2e_enabled: req.e2e_enabled,
                 },
             )
             .await;
@@ -170,6 +210,8 @@ impl MediaHandlerService for MhMediaService {
             meeting_id = %req.meeting_id,
             mc_id = %req.mc_id,
             promoted_pending_count = promoted_count,
+            recording_layout = layout_config.layout.as_str(),
+            e2e_enabled = req.e2e_enabled,
             "Meeting registered"
         );
 
@@ -256,6 +298,97 @@ impl MediaHandlerService for MhMediaService {
 
         Ok(Response::new(StreamTelemetryResponse { received: true }))
     }
+
+    /// Start a live streaming egress for a registered meeting.
+    ///
+    /// Called by MC when the meeting host starts a live stream.
+    #[instrument(skip_all)]
+    async fn start_egress(
+        &self,
+        request: Request<StartEgressRequest>,
+    ) -> Result<Response<StartEgressResponse>, Status> {
+        let egress = self.egress_manager("start_egress")?;
+        let req = request.into_inner();
+
+        if req.meeting_id.is_empty() || req.meeting_id.len() > MAX_ID_LENGTH {
+            metrics::record_grpc_request("start_egress", "error");
+            return Err(Status::invalid_argument("meeting_id is invalid"));
+        }
+        let output = match req.output {
+            Some(start_egress_request::Output::RtmpUrl(url)) => EgressOutput::Rtmp {
+                url: SecretString::from(url),
+            },
+            Some(start_egress_request::Output::Hls(hls)) => EgressOutput::Hls {
+                segment_duration_seconds: hls.segment_duration_seconds,
+            },
+            None => {
+                metrics::record_grpc_request("start_egress", "error");
+                return Err(Status::invalid_argument("output is required"));
+            }
+        };
+
+        if !self
+            .session_manager
+            .is_meeting_registered(&req.meeting_id)
+            .await
+        {
+            metrics::record_grpc_request("start_egress", "error");
+            return Err(Status::failed_precondition(
+                "Meeting is not registered with this media handler",
+            ));
+        }
+        if self.session_manager.is_meeting_e2e(&req.meeting_id).await {
+            metrics::record_grpc_request("start_egress", "error");
+            return Err(Status::failed_precondition(
+                "Egress is unavailable for end-to-end encrypted meetings",
+            ));
+        }
+
+        let started = egress
+            .start(EgressRequest {
+                meeting_id: req.meeting_id,
+                tile_participant_ids: req.tile_participant_ids,
+                output,
+            })
+            .await
+            .map_err(|e| {
+                metrics::record_grpc_request("start_egress", "error");
+                egress_status(&e)
+            })?;
+
+        metrics::record_grpc_request("start_egress", "success");
+
+        Ok(Response::new(StartEgressResponse {
+            egress_id: started.egress_id,
+            playback_url: started.playback_url.unwrap_or_default(),
+        }))
+    }
+
+    /// Stop a meeting's live streaming egress.
+    ///
+    /// Returns how many whole seconds the stream was live.
+    #[instrument(skip_all)]
+    async fn stop_egress(
+        &self,
+        request: Request<StopEgressRequest>,
+    ) -> Result<Response<StopEgressResponse>, Status> {
+        let egress = self.egress_manager("stop_egress")?;
+        let req = request.into_inner();
+
+        let live = egress
+            .stop(&req.meeting_id, &req.egress_id)
+            .await
+            .map_err(|e| {
+                metrics::record_grpc_request("stop_egress", "error");
+                egress_status(&e)
+            })?;
+
+        metrics::record_grpc_request("stop_egress", "success");
+
+        Ok(Response::new(StopEgressResponse {
+            live_seconds: live.as_secs(),
+        }))
+    }
 }
 
 /// Helper to get next item from a streaming request.
@@ -275,6 +408,7 @@ async fn stream_next(
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
+    use crate::compositor::{RecordingLayout, DEFAULT_HEIGHT, DEFAULT_WIDTH};
     use crate::session::PendingConnection;
 
     fn make_service() -> (MhMediaService, SessionManagerHandle) {
@@ -292,6 +426,8 @@ mod tests {
             meeting_id: meeting_id.to_string(),
             mc_id: mc_id.to_string(),
             mc_grpc_endpoint: mc_grpc_endpoint.to_string(),
+            recording_layout: 0,
+            e2e_enabled: false,
         })
     }
 
@@ -314,6 +450,28 @@ mod tests {
             sm.get_mc_endpoint("meeting-1").await.unwrap(),
             "http://mc:50052"
         );
+        assert_eq!(
+            sm.get_layout_config("meeting-1").await,
+            Some(LayoutConfig::default())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_register_meeting_stores_recording_layout() {
+        let (svc, sm) = make_service();
+
+        let mut request = make_register_request("meeting-1", "mc-1", "http://mc:50052");
+        request.get_mut().recording_layout =
+            proto_gen::dark_tower::internal::v1::RecordingLayout::Speaker.into();
+        svc.register_meeting(request).await.unwrap();
+
+        let config = sm.get_layout_config("meeting-1").await.unwrap();
+        assert_eq!(config.layout, RecordingLayout::Speaker);
+        assert_eq!(
+            (config.width, config.height),
+            (DEFAULT_WIDTH, DEFAULT_HEIGHT)
+        );
+        assert_eq!(sm.get_layout_config("meeting-2").await, None);
     }
 
     #[tokio::test]
@@ -446,6 +604,7 @@ mod tests {
             connection_id: "conn-1".to_string(),
             meeting_id: "meeting-1".to_string(),
             participant_id: "user-1".to_string(),
+            peer_address: "192.0.2.1:4433".parse().unwrap(),
             connected_at: Instant::now(),
         })
         .await;
@@ -454,6 +613,7 @@ mod tests {
             connection_id: "conn-2".to_string(),
             meeting_id: "meeting-1".to_string(),
             participant_id: "user-2".to_string(),
+            peer_address: "192.0.2.1:4433".parse().unwrap(),
             connected_at: Instant::now(),
         })
         .await;
@@ -522,4 +682,99 @@ mod tests {
 
         assert!(resp.into_inner().accepted);
     }
+
+    fn make_egress_service() -> (MhMediaService, SessionManagerHandle) {
+        let sm = SessionManagerHandle::new();
+        let svc = MhMediaService::new(sm.clone()).with_egress(EgressManagerHandle::new(
+            4,
+            Some("https://mh-1.example.com/hls".to_string()),
+        ));
+        (svc, sm)
+    }
+
+    fn make_start_egress_request(meeting_id: &str) -> Request<StartEgressRequest> {
+        Request::new(StartEgressRequest {
+            meeting_id: meeting_id.to_string(),
+            tile_participant_ids: vec!["user-1".to_string(), "user-2".to_string()],
+            output: Some(start_egress_request::Output::Hls(
+                proto_gen::dark_tower::internal::v1::HlsEgressOutput {
+                    segment_duration_seconds: 0,
+                },
+            )),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_start_stop_egress_for_registered_meeting() {
+        let (svc, _sm) = make_egress_service();
+        svc.register_meeting(make_register_request(
+            "meeting-1",
+            "mc-1",
+            "http://mc:50052",
+        ))
+        .await
+        .unwrap();
+
+        let started = svc
+            .start_egress(make_start_egress_request("meeting-1"))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(started
+            .playback_url
+            .starts_with("https://mh-1.example.com/hls/"));
+
+        let err = svc
+            .start_egress(make_start_egress_request("meeting-1"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::AlreadyExists);
+
+        svc.stop_egress(Request::new(StopEgressRequest {
+            meeting_id: "meeting-1".to_string(),
+            egress_id: started.egress_id,
+        }))
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_start_egress_unregistered_meeting_rejected() {
+        let (svc, _sm) = make_egress_service();
+
+        let err = svc
+            .start_egress(make_start_egress_request("meeting-1"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+    }
+
+    #[tokio::test]
+    async fn test_start_egress_e2e_meeting_rejected() {
+        let (svc, sm) = make_egress_service();
+        let mut request = make_register_request("meeting-1", "mc-1", "http://mc:50052");
+        request.get_mut().e2e_enabled = true;
+        svc.register_meeting(request).await.unwrap();
+        assert!(sm.is_meeting_e2e("meeting-1").await);
+
+        let err = svc
+            .start_egress(make_start_egress_request("meeting-1"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+    }
+
+    #[tokio::test]
+    async fn test_start_egress_disabled_returns_unavailable() {
+        let (svc, _sm) = make_service();
+
+        let err = svc
+            .start_egress(make_start_egress_request("meeting-1"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code(), tonic::Code::Unavailable);
+    }
 }
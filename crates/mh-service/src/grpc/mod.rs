@@ -4,6 +4,7 @@
 //! - `gc_client` - Client for MH→GC communication (registration, load reports)
 //! - `mc_client` - Client for MH→MC communication (participant notifications)
 //! - `mh_service` - Server for MC→MH communication (register, route, telemetry) — stub
+//! - `build_info_service` - Server for `GetVersion` (any caller type)
 //! - `auth_interceptor` - Authorization validation for incoming MC requests
 //!
 //! # Architecture
@@ -21,11 +22,13 @@
 //! (ADR-0003). This provides defense-in-depth beyond transport-level security.
 
 pub mod auth_interceptor;
+pub mod build_info_service;
 pub mod gc_client;
 pub mod mc_client;
 pub mod mh_service;
 
 pub use auth_interceptor::MhAuthLayer;
+pub use build_info_service::MhBuildInfoService;
 pub use gc_client::GcClient;
 pub use mc_client::McClient;
 pub use mh_service::MhMediaService;
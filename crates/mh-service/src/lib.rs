@@ -17,7 +17,7 @@
 //! # Architecture (ADR-0010, ADR-0023)
 //!
 //! ```text
-//! MC → MH: Register, RouteMedia, StreamTelemetry (gRPC)
+//! MC → MH: Register, RouteMedia, StreamTelemetry, Start/StopEgress (gRPC)
 //! MH → GC: RegisterMH, SendLoadReport (gRPC)
 //! Client → MH: WebTransport media streams (stub: accept + log)
 //! ```
@@ -25,8 +25,11 @@
 #![warn(clippy::pedantic)]
 
 pub mod auth;
+pub mod compositor;
 pub mod config;
+pub mod egress;
 pub mod errors;
+pub mod forwarding;
 pub mod grpc;
 pub mod observability;
 pub mod session;
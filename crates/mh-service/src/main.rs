@@ -22,42 +22,57 @@
 #![warn(clippy::pedantic)]
 #![allow(clippy::too_many_lines)]
 
-use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 
 use axum::Router;
+use common::build_info::version_router;
+use common::debug_auth::require_debug_scope;
 use common::jwt::JwksClient;
+use common::listen::{bind_tcp_listeners, parse_bind_addresses, tcp_incoming};
+use common::log_level::log_level_router;
+use common::media_token::MediaTokenKey;
+use common::observability::runtime::{RuntimeSampler, RUNTIME_SAMPLE_INTERVAL};
+use common::request_id::RequestIdLayer;
+use common::secret::ExposeSecret;
 use common::token_manager::{spawn_token_manager, TokenManagerConfig};
+use common::warn_throttled;
 use mh_service::auth::MhJwtValidator;
 use mh_service::config::Config;
+use mh_service::egress::EgressManagerHandle;
 use mh_service::errors::MhError;
-use mh_service::grpc::{GcClient, McClient, MhAuthLayer, MhMediaService};
+use mh_service::grpc::{GcClient, McClient, MhAuthLayer, MhBuildInfoService, MhMediaService};
 use mh_service::observability::{health_router, HealthState};
 use mh_service::session::SessionManagerHandle;
 use mh_service::webtransport::WebTransportServer;
+use proto_gen::dark_tower::internal::v1::build_info_service_server::BuildInfoServiceServer;
 use proto_gen::dark_tower::internal::v1::media_handler_service_server::MediaHandlerServiceServer;
 use tokio::signal;
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 /// Default timeout for initial token acquisition.
 const TOKEN_ACQUISITION_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// jemalloc avoids the fragmentation-driven RSS growth glibc malloc shows
+/// under long-lived, many-threaded workloads, and exposes heap stats.
+#[cfg(feature = "jemalloc")]
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize tracing with JSON structured logging
-    // JSON format enables robust parsing in Promtail without brittle regex
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "mh_service=debug".into()),
-        )
-        .with(tracing_subscriber::fmt::layer().json())
-        .init();
-
-    info!("Starting Media Handler");
+    // Initialize tracing with JSON structured logging; the filter can be
+    // changed at runtime via /debug/log-level
+    let log_level = common::log_level::init_tracing("mh_service=debug");
+
+    let build_info = common::build_info!("mh-service", features = ["jemalloc", "cpu-profiling"])
+        .with_proto_schema(proto_gen::SCHEMA_VERSION);
+    info!(
+        version = build_info.version,
+        git_sha = build_info.git_sha,
+        "Starting Media Handler"
+    );
 
     // Load configuration
     let config = Config::from_env().map_err(|e| {
@@ -75,6 +90,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         webtransport_advertise_address = %config.webtransport_advertise_address,
         max_streams = config.max_streams,
         max_connections = config.max_connections,
+        max_egress_sessions = config.max_egress_sessions,
+        hls_egress_enabled = config.hls_base_url.is_some(),
         register_meeting_timeout_seconds = config.register_meeting_timeout_seconds,
         "Configuration loaded successfully"
     );
@@ -155,7 +172,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("MC notification client created");
 
     // Start health HTTP server (MUST succeed - fail startup if it doesn't)
-    let health_addr: SocketAddr = config.health_bind_address.parse().map_err(|e| {
+    let health_addrs = parse_bind_addresses(&config.health_bind_address).map_err(|e| {
         error!(error = %e, addr = %config.health_bind_address, "Invalid health bind address");
         format!("Invalid health bind address: {e}")
     })?;
@@ -171,58 +188,119 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }),
     );
 
-    let app = health_router.merge(metrics_router);
-
-    // Bind listener BEFORE spawning to fail fast on bind errors
-    let listener = tokio::net::TcpListener::bind(health_addr)
-        .await
-        .map_err(|e| {
-            error!(error = %e, addr = %health_addr, "Failed to bind health server");
-            format!("Failed to bind health server to {health_addr}: {e}")
-        })?;
-    info!(addr = %health_addr, "Health server bound successfully");
+    // Debug endpoints (log filter, allocator, CPU profile), gated on the
+    // admin:debug scope
+    let debug_validator = Arc::new(common::jwt::JwtValidator::new(
+        Arc::clone(&jwks_client),
+        300,
+    ));
+    let debug_routes = log_level_router(log_level);
+    #[cfg(feature = "jemalloc")]
+    let debug_routes = debug_routes.merge(common::allocator::allocator_router());
+    #[cfg(feature = "cpu-profiling")]
+    let debug_routes = debug_routes.merge(common::cpu_profile::cpu_profile_router());
+    let debug_routes = debug_routes
+        .layer(axum::middleware::from_fn_with_state(
+            debug_validator,
+            require_debug_scope,
+        ))
+        .layer(RequestIdLayer);
+
+    let app = health_router
+        .merge(metrics_router)
+        .merge(version_router(build_info.clone()))
+        .merge(debug_routes);
+
+    // Bind listeners BEFORE spawning to fail fast on bind errors
+    let listeners = bind_tcp_listeners(&health_addrs).map_err(|e| {
+        error!(error = %e, addrs = ?health_addrs, "Failed to bind health server");
+        format!("Failed to bind health server: {e}")
+    })?;
+    info!(addrs = ?health_addrs, "Health server bound successfully");
+
+    // Spawn one health server task per listener
+    for listener in listeners {
+        let app = app.clone();
+        let health_shutdown_token = shutdown_token.child_token();
+        tokio::spawn(async move {
+            let addr = listener.local_addr().ok();
+            info!(addr = ?addr, "Health server starting");
+            let server = axum::serve(listener, app).with_graceful_shutdown(async move {
+                health_shutdown_token.cancelled().await;
+                info!(addr = ?addr, "Health server shutting down");
+            });
+            if let Err(e) = server.await {
+                error!(error = %e, "Health server failed");
+            }
+        });
+    }
+    info!(addrs = ?health_addrs, "Health server started");
 
-    // Spawn health server task
-    let health_shutdown_token = shutdown_token.child_token();
+    // Sample Tokio runtime health (worker saturation, schedule delay)
+    let runtime_token = shutdown_token.child_token();
+    let runtime_sampler = RuntimeSampler::new().run(
+        RUNTIME_SAMPLE_INTERVAL,
+        mh_service::observability::metrics::record_runtime_sample,
+    );
     tokio::spawn(async move {
-        info!(addr = %health_addr, "Health server starting");
-        let server = axum::serve(listener, app).with_graceful_shutdown(async move {
-            health_shutdown_token.cancelled().await;
-            info!("Health server shutting down");
-        });
-        if let Err(e) = server.await {
-            error!(error = %e, "Health server failed");
+        tokio::select! {
+            () = runtime_sampler => {}
+            () = runtime_token.cancelled() => {}
         }
     });
-    info!(addr = %health_addr, "Health server started");
 
     // Start gRPC server BEFORE GC registration (correct ordering)
     // This prevents race condition where MC tries to call MH before server is ready
-    let grpc_addr: SocketAddr = config.grpc_bind_address.parse().map_err(|e| {
+    let grpc_addrs = parse_bind_addresses(&config.grpc_bind_address).map_err(|e| {
         error!(error = %e, addr = %config.grpc_bind_address, "Invalid gRPC bind address");
         format!("Invalid gRPC bind address: {e}")
     })?;
+    let grpc_listeners = bind_tcp_listeners(&grpc_addrs).map_err(|e| {
+        error!(error = %e, addrs = ?grpc_addrs, "Failed to bind gRPC server");
+        format!("Failed to bind gRPC server: {e}")
+    })?;
 
-    let mh_media_service = MhMediaService::new(session_manager.clone());
+    let egress_manager =
+        EgressManagerHandle::new(config.max_egress_sessions, config.hls_base_url.clone());
+    let mh_media_service = MhMediaService::new(session_manager.clone()).with_egress(egress_manager);
     let auth_layer = MhAuthLayer::new(Arc::clone(&jwks_client), 300);
 
     let grpc_shutdown_token = shutdown_token.child_token();
     let grpc_server = tonic::transport::Server::builder()
+        // First layer is outermost: auth rejections carry the request ID too
+        .layer(RequestIdLayer)
         .layer(auth_layer)
         .add_service(MediaHandlerServiceServer::new(mh_media_service))
-        .serve_with_shutdown(grpc_addr, async move {
+        .add_service(BuildInfoServiceServer::new(MhBuildInfoService::new(
+            build_info,
+        )))
+        .serve_with_incoming_shutdown(tcp_incoming(grpc_listeners), async move {
             grpc_shutdown_token.cancelled().await;
             info!("gRPC server shutting down");
         });
 
     // Spawn gRPC server task
     tokio::spawn(async move {
-        info!(addr = %grpc_addr, "gRPC server starting");
+        info!("gRPC server starting");
         if let Err(e) = grpc_server.await {
             error!(error = %e, "gRPC server failed");
         }
     });
-    info!(addr = %grpc_addr, "gRPC server started");
+    info!(addrs = ?grpc_addrs, "gRPC server started");
+
+    // Media tokens bind clients' MH connections to their MC signaling session
+    let media_token_key = config
+        .media_token_secret
+        .as_ref()
+        .map(|secret| MediaTokenKey::from_base64(secret.expose_secret()).map(Arc::new))
+        .transpose()
+        .map_err(|e| {
+            error!(error = %e, "MH_MEDIA_TOKEN_SECRET is invalid");
+            format!("Invalid MH_MEDIA_TOKEN_SECRET: {e}")
+        })?;
+    if media_token_key.is_none() {
+        warn!("MH_MEDIA_TOKEN_SECRET not set; clients connect with the meeting JWT alone");
+    }
 
     // Start WebTransport server BEFORE GC registration (ADR-0010 ordering)
     // This ensures MH can accept client connections before GC starts routing traffic here
@@ -237,9 +315,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Duration::from_secs(config.register_meeting_timeout_seconds),
         config.max_connections,
         shutdown_token.child_token(),
-    );
+    )
+    .with_media_socket(config.media_socket)
+    .with_media_token_key(media_token_key);
 
-    let wt_endpoint = wt_server.bind().await.map_err(|e| {
+    let wt_endpoints = wt_server.bind().await.map_err(|e| {
         error!(error = %e, "Failed to bind WebTransport server");
         format!("WebTransport bind failed: {e}")
     })?;
@@ -250,7 +330,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     tokio::spawn(async move {
-        wt_server.accept_loop(wt_endpoint).await;
+        wt_server.accept_loop(wt_endpoints).await;
     });
     info!(
         addr = %config.webtransport_bind_address,
@@ -324,7 +404,7 @@ async fn run_gc_task(
                         break;
                     }
                     Err(e) => {
-                        warn!(error = %e, "GC task: Initial registration failed, will retry");
+                        warn_throttled!(error = %e, "GC task: Initial registration failed, will retry");
                         tokio::time::sleep(Duration::from_secs(5)).await;
                     }
                 }
@@ -354,15 +434,15 @@ async fn run_gc_task(
                 if let Err(e) = gc_client.send_load_report().await {
                     match e {
                         MhError::NotRegistered => {
-                            warn!("Load report failed: MH not registered with GC, attempting re-registration");
+                            warn_throttled!("Load report failed: MH not registered with GC, attempting re-registration");
                             if let Err(re) = gc_client.attempt_reregistration().await {
-                                warn!(error = %re, "Re-registration failed, will retry on next heartbeat");
+                                warn_throttled!(error = %re, "Re-registration failed, will retry on next heartbeat");
                             } else {
                                 info!("Re-registration successful");
                             }
                         }
                         other => {
-                            warn!(error = %other, "Load report failed");
+                            warn_throttled!(error = %other, "Load report failed");
                         }
                     }
                 }
@@ -56,6 +56,14 @@ pub fn init_metrics_recorder() -> Result<PrometheusHandle, String> {
             ],
         )
         .map_err(|e| format!("Failed to set WebTransport handshake buckets: {e}"))?
+        // Runtime schedule delay buckets - healthy is sub-millisecond
+        .set_buckets_for_metric(
+            Matcher::Prefix("mh_runtime_schedule_delay".to_string()),
+            &[
+                0.0001, 0.0005, 0.001, 0.005, 0.010, 0.025, 0.050, 0.100, 0.250, 1.000,
+            ],
+        )
+        .map_err(|e| format!("Failed to set runtime schedule delay buckets: {e}"))?
         .install_recorder()
         .map_err(|e| format!("Failed to install Prometheus recorder: {e}"))
 }
@@ -166,6 +174,61 @@ pub fn record_webtransport_connection(status: &str) {
     counter!("mh_webtransport_connections_total", "status" => status.to_string()).increment(1);
 }
 
+/// Record a client connection migrating to a new network path.
+///
+/// Metric: `mh_quic_path_migrations_total`
+/// Labels: `kind` (rebinding | address; see `common::quic_path`)
+/// Cardinality: 2
+///
+/// Recorded in `webtransport/connection.rs` when a held connection's
+/// validated peer address changes.
+pub fn record_quic_path_migration(kind: &str) {
+    counter!("mh_quic_path_migrations_total", "kind" => kind.to_string()).increment(1);
+}
+
+/// Record a media frame dropped by a subscriber's forward queue under
+/// congestion.
+///
+/// Metric: `mh_media_frames_dropped_total`
+/// Labels: `priority` (low | normal | high | critical), `discardable`
+/// (true | false)
+/// Cardinality: 8
+///
+/// Recorded by `forwarding::ForwardQueue` for each frame it evicts or
+/// refuses. Drops of non-discardable critical frames mean a subscriber is
+/// losing audio or keyframes.
+pub fn record_media_frame_dropped(priority: &'static str, discardable: bool) {
+    counter!(
+        "mh_media_frames_dropped_total",
+        "priority" => priority,
+        "discardable" => if discardable { "true" } else { "false" }
+    )
+    .increment(1);
+}
+
+/// Record a media frame rejected by its stream's replay window.
+///
+/// Metric: `mh_media_frames_replayed_total`
+/// Labels: `reason` (duplicate | `too_old`)
+/// Cardinality: 2
+///
+/// Recorded by `forwarding::FrameIngest` before a frame is forwarded.
+pub fn record_media_frame_replayed(reason: &'static str) {
+    counter!("mh_media_frames_replayed_total", "reason" => reason).increment(1);
+}
+
+/// Record a media frame rejected before its replay window is checked.
+///
+/// Metric: `mh_media_frames_rejected_total`
+/// Labels: `reason` (`user_mismatch` | `stream_limit`)
+/// Cardinality: 2
+///
+/// Recorded by `forwarding::FrameIngest` for frames published under another
+/// participant's `user_id` or beyond the connection's stream limit.
+pub fn record_media_frame_rejected(reason: &'static str) {
+    counter!("mh_media_frames_rejected_total", "reason" => reason).increment(1);
+}
+
 /// Record WebTransport handshake duration (R-26).
 ///
 /// Metric: `mh_webtransport_handshake_duration_seconds`
@@ -183,6 +246,57 @@ pub fn set_active_connections(count: f64) {
     gauge!("mh_active_connections").set(count);
 }
 
+/// Record the media socket options applied at bind time.
+///
+/// Metrics: `mh_media_socket_dscp`, `mh_media_socket_buffer_bytes`,
+/// `mh_media_socket_gso_segments`
+/// Labels: `direction` (send | recv) on the buffer gauge
+/// Cardinality: 2
+///
+/// Values are read back from the kernel, so they confirm what was applied
+/// rather than what was configured.
+///
+/// Recorded once in `WebTransportServer::bind()`.
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "socket buffer sizes << 2^52, no precision loss"
+)]
+pub fn record_media_socket_options(
+    applied: &common::media_socket::AppliedSocketOptions,
+    gso_segments: usize,
+) {
+    gauge!("mh_media_socket_dscp").set(f64::from(applied.dscp));
+    gauge!("mh_media_socket_buffer_bytes", "direction" => "send")
+        .set(applied.send_buffer_bytes as f64);
+    gauge!("mh_media_socket_buffer_bytes", "direction" => "recv")
+        .set(applied.recv_buffer_bytes as f64);
+    gauge!("mh_media_socket_gso_segments").set(gso_segments as f64);
+}
+
+/// Record a Tokio runtime health sample.
+///
+/// Metrics: `mh_runtime_workers`, `mh_runtime_alive_tasks`,
+/// `mh_runtime_global_queue_depth`, `mh_runtime_worker_busy_ratio`,
+/// `mh_runtime_schedule_delay_seconds`
+/// Labels: none
+/// Cardinality: 1 per metric
+///
+/// A busy ratio near 1.0 or a rising schedule delay means the workers are
+/// saturated and every actor and connection task is waiting its turn.
+///
+/// Recorded every `RUNTIME_SAMPLE_INTERVAL` by the sampler task in `main.rs`.
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "task and worker counts << 2^52, no precision loss"
+)]
+pub fn record_runtime_sample(sample: &common::observability::runtime::RuntimeSample) {
+    gauge!("mh_runtime_workers").set(sample.workers as f64);
+    gauge!("mh_runtime_alive_tasks").set(sample.alive_tasks as f64);
+    gauge!("mh_runtime_global_queue_depth").set(sample.global_queue_depth as f64);
+    gauge!("mh_runtime_worker_busy_ratio").set(sample.busy_ratio);
+    histogram!("mh_runtime_schedule_delay_seconds").record(sample.schedule_delay.as_secs_f64());
+}
+
 /// Record a `RegisterMeeting` provisional-accept timeout (R-26).
 ///
 /// Metric: `mh_register_meeting_timeouts_total`
@@ -209,8 +323,8 @@ pub fn record_register_meeting_timeout() {
 /// Record an MC notification delivery attempt (R-16/R-17).
 ///
 /// Metric: `mh_mc_notifications_total`
-/// Labels: `event_type` (connected | disconnected), `status` (success | error)
-/// Cardinality: 4 (2 event types x 2 statuses)
+/// Labels: `event_type` (connected | disconnected | keyframe), `status` (success | error)
+/// Cardinality: 6 (3 event types x 2 statuses)
 pub fn record_mc_notification(event_type: &str, status: &str) {
     counter!(
         "mh_mc_notifications_total",
@@ -226,10 +340,11 @@ pub fn record_mc_notification(event_type: &str, status: &str) {
 /// Labels: `result`, `token_type`, `failure_reason`
 ///
 /// Result values: "success", "failure"
-/// Token type values: "meeting", "service"
+/// Token type values: "meeting", "service", "media"
 /// Failure reason values: `none` (success), `signature_invalid`, `expired`,
-///   `scope_mismatch`, `malformed`, `validation_failed`
-/// Cardinality: bounded (2 x 2 x 6 = 24 max, but most combos are sparse in practice)
+///   `scope_mismatch`, `malformed`, `validation_failed`, `missing`,
+///   `binding_mismatch`
+/// Cardinality: bounded (2 x 3 x 8 = 48 max, but most combos are sparse in practice)
 pub fn record_jwt_validation(result: &str, token_type: &str, failure_reason: &str) {
     counter!("mh_jwt_validations_total",
         "result" => result.to_string(),
@@ -284,6 +399,24 @@ mod tests {
         record_gc_registration_latency(Duration::from_secs(2));
     }
 
+    #[test]
+    fn test_record_media_frame_dropped() {
+        record_media_frame_dropped("low", true);
+        record_media_frame_dropped("critical", false);
+    }
+
+    #[test]
+    fn test_record_media_frame_replayed() {
+        record_media_frame_replayed("duplicate");
+        record_media_frame_replayed("too_old");
+    }
+
+    #[test]
+    fn test_record_media_frame_rejected() {
+        record_media_frame_rejected("user_mismatch");
+        record_media_frame_rejected("stream_limit");
+    }
+
     #[test]
     fn test_record_gc_heartbeat() {
         record_gc_heartbeat("success");
@@ -341,6 +474,23 @@ mod tests {
         record_webtransport_connection("error");
     }
 
+    #[test]
+    fn test_record_quic_path_migration() {
+        use common::observability::testing::MetricAssertion;
+
+        let snap = MetricAssertion::snapshot();
+        record_quic_path_migration("rebinding");
+        record_quic_path_migration("address");
+        record_quic_path_migration("address");
+
+        snap.counter("mh_quic_path_migrations_total")
+            .with_labels(&[("kind", "rebinding")])
+            .assert_delta(1);
+        snap.counter("mh_quic_path_migrations_total")
+            .with_labels(&[("kind", "address")])
+            .assert_delta(2);
+    }
+
     #[test]
     fn test_record_webtransport_handshake_duration() {
         record_webtransport_handshake_duration(Duration::from_millis(50));
@@ -355,6 +505,29 @@ mod tests {
         set_active_connections(0.0);
     }
 
+    #[test]
+    fn test_record_runtime_sample() {
+        let sample = common::observability::runtime::RuntimeSample {
+            workers: 4,
+            alive_tasks: 120,
+            global_queue_depth: 0,
+            busy_ratio: 0.35,
+            schedule_delay: Duration::from_micros(80),
+        };
+        record_runtime_sample(&sample);
+    }
+
+    #[test]
+    fn test_record_media_socket_options() {
+        let applied = common::media_socket::AppliedSocketOptions {
+            dscp: 46,
+            send_buffer_bytes: 4_194_304,
+            recv_buffer_bytes: 8_388_608,
+        };
+        record_media_socket_options(&applied, 64);
+        record_media_socket_options(&applied, 1);
+    }
+
     #[test]
     fn test_record_jwt_validation() {
         record_jwt_validation("success", "meeting", "none");
@@ -379,11 +552,13 @@ mod tests {
 
     #[test]
     fn test_record_mc_notification() {
-        // All 4 combinations: 2 events x 2 statuses
+        // All 6 combinations: 3 events x 2 statuses
         record_mc_notification("connected", "success");
         record_mc_notification("connected", "error");
         record_mc_notification("disconnected", "success");
         record_mc_notification("disconnected", "error");
+        record_mc_notification("keyframe", "success");
+        record_mc_notification("keyframe", "error");
     }
 
     #[test]
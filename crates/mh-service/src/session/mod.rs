@@ -12,13 +12,23 @@
 //!   `mpsc::Sender<SessionMessage>`. Uses `oneshot` for request-reply.
 //! - `SessionMessage` (enum): One variant per operation.
 //!
+//! # Peer addresses
+//!
+//! Each connection records its client's current address. Clients may
+//! migrate to a new network path mid-connection (see `common::quic_path`);
+//! the connection handler reports the new address with
+//! [`SessionManagerHandle::update_peer_address`] and the connection itself
+//! stays registered.
+//!
 //! # Notification
 //!
 //! Uses `tokio::sync::Notify` per meeting to wake pending connections
 //! when `RegisterMeeting` arrives. The actor owns the Notify; callers
 //! receive an `Arc<Notify>` clone for awaiting.
 
+use crate::compositor::LayoutConfig;
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Instant;
 use tokio::sync::{mpsc, oneshot, Notify};
@@ -42,6 +52,10 @@ pub struct MeetingRegistration {
     pub mc_grpc_endpoint: String,
     /// When the meeting was registered.
     pub registered_at: Instant,
+    /// Compositor configuration for the meeting's recording.
+    pub layout_config: LayoutConfig,
+    /// Media is end-to-end encrypted and must not be decoded (no egress).
+    pub e2e_enabled: bool,
 }
 
 /// An active participant connection.
@@ -51,6 +65,8 @@ pub struct ConnectionEntry {
     pub connection_id: String,
     /// Participant ID (from JWT `sub` claim).
     pub participant_id: String,
+    /// Client's current validated address; changes on connection migration.
+    pub peer_address: SocketAddr,
     /// When the connection was established.
     pub connected_at: Instant,
 }
@@ -64,6 +80,8 @@ pub struct PendingConnection {
     pub meeting_id: String,
     /// Participant ID (from JWT `sub` claim).
     pub participant_id: String,
+    /// Client's current validated address; changes on connection migration.
+    pub peer_address: SocketAddr,
     /// When the connection was established.
     pub connected_at: Instant,
 }
@@ -94,6 +112,17 @@ enum SessionMessage {
         meeting_id: String,
         respond_to: oneshot::Sender<Option<String>>,
     },
+    /// Get the compositor configuration (the layout the MC registered the
+    /// meeting with, at the default output size) for a registered meeting.
+    GetLayoutConfig {
+        meeting_id: String,
+        respond_to: oneshot::Sender<Option<LayoutConfig>>,
+    },
+    /// Check if a registered meeting is end-to-end encrypted.
+    IsMeetingE2e {
+        meeting_id: String,
+        respond_to: oneshot::Sender<bool>,
+    },
     /// Add an active connection (fire-and-forget).
     AddConnection {
         meeting_id: String,
@@ -116,6 +145,18 @@ enum SessionMessage {
         connection_id: String,
         respond_to: oneshot::Sender<bool>,
     },
+    /// Record a migrated connection's new peer address (fire-and-forget).
+    UpdatePeerAddress {
+        meeting_id: String,
+        connection_id: String,
+        peer_address: SocketAddr,
+    },
+    /// Get a connection's current peer address (active or pending).
+    GetPeerAddress {
+        meeting_id: String,
+        connection_id: String,
+        respond_to: oneshot::Sender<Option<SocketAddr>>,
+    },
     /// Get the total count of active connections across all meetings.
     ActiveConnectionCount { respond_to: oneshot::Sender<usize> },
 }
@@ -155,6 +196,7 @@ impl SessionManagerActor {
     }
 
     /// Main run loop. Processes messages until the channel closes.
+    #[tracing::instrument(skip_all, name = "mh.actor.session_manager")]
     async fn run(mut self) {
         while let Some(msg) = self.receiver.recv().await {
             self.handle_message(msg);
@@ -193,6 +235,28 @@ impl SessionManagerActor {
                     .map(|r| r.mc_grpc_endpoint.clone());
                 let _ = respond_to.send(result);
             }
+            SessionMessage::GetLayoutConfig {
+                meeting_id,
+                respond_to,
+            } => {
+                let result = self
+                    .state
+                    .registered_meetings
+                    .get(&meeting_id)
+                    .map(|r| r.layout_config);
+                let _ = respond_to.send(result);
+            }
+            SessionMessage::IsMeetingE2e {
+                meeting_id,
+                respond_to,
+            } => {
+                let result = self
+                    .state
+                    .registered_meetings
+                    .get(&meeting_id)
+                    .is_some_and(|r| r.e2e_enabled);
+                let _ = respond_to.send(result);
+            }
             SessionMessage::AddConnection { meeting_id, entry } => {
                 self.handle_add_connection(meeting_id, entry);
             }
@@ -219,6 +283,21 @@ impl SessionManagerActor {
                 let result = self.handle_remove_pending_connection(&meeting_id, &connection_id);
                 let _ = respond_to.send(result);
             }
+            SessionMessage::UpdatePeerAddress {
+                meeting_id,
+                connection_id,
+                peer_address,
+            } => {
+                self.handle_update_peer_address(&meeting_id, &connection_id, peer_address);
+            }
+            SessionMessage::GetPeerAddress {
+                meeting_id,
+                connection_id,
+                respond_to,
+            } => {
+                let result = self.handle_get_peer_address(&meeting_id, &connection_id);
+                let _ = respond_to.send(result);
+            }
             SessionMessage::ActiveConnectionCount { respond_to } => {
                 let count = self
                     .state
@@ -277,6 +356,7 @@ impl SessionManagerActor {
             participant_conns.push(ConnectionEntry {
                 connection_id: conn.connection_id.clone(),
                 participant_id: conn.participant_id.clone(),
+                peer_address: conn.peer_address,
                 connected_at: conn.connected_at,
             });
         }
@@ -345,6 +425,57 @@ impl SessionManagerActor {
         }
         false
     }
+
+    /// Update the peer address of an active or pending connection. Unknown
+    /// connections are ignored: the connection may have closed meanwhile.
+    fn handle_update_peer_address(
+        &mut self,
+        meeting_id: &str,
+        connection_id: &str,
+        peer_address: SocketAddr,
+    ) {
+        let active = self
+            .state
+            .active_connections
+            .get_mut(meeting_id)
+            .into_iter()
+            .flat_map(|m| m.values_mut().flatten())
+            .find(|c| c.connection_id == connection_id);
+        if let Some(entry) = active {
+            entry.peer_address = peer_address;
+            return;
+        }
+        let pending = self
+            .state
+            .pending_connections
+            .get_mut(meeting_id)
+            .into_iter()
+            .flatten()
+            .find(|c| c.connection_id == connection_id);
+        if let Some(pending) = pending {
+            pending.peer_address = peer_address;
+        }
+    }
+
+    fn handle_get_peer_address(&self, meeting_id: &str, connection_id: &str) -> Option<SocketAddr> {
+        let active = self
+            .state
+            .active_connections
+            .get(meeting_id)
+            .into_iter()
+            .flat_map(|m| m.values().flatten())
+            .find(|c| c.connection_id == connection_id)
+            .map(|c| c.peer_address);
+        active.or_else(|| {
+            self.state
+                .pending_connections
+                .get(meeting_id)
+                .into_iter()
+                .flatten()
+                .find(|c| c.connection_id == connection_id)
+                .map(|c| c.peer_address)
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -437,6 +568,44 @@ impl SessionManagerHandle {
         rx.await.unwrap_or(None)
     }
 
+    /// Get the compositor configuration for a registered meeting.
+    pub async fn get_layout_config(&self, meeting_id: &str) -> Option<LayoutConfig> {
+        let (tx, rx) = oneshot::channel();
+        if self
+            .sender
+            .send(SessionMessage::GetLayoutConfig {
+                meeting_id: meeting_id.to_string(),
+                respond_to: tx,
+            })
+            .await
+            .is_err()
+        {
+            tracing::warn!(target: "mh.session", "SessionManagerActor channel closed on get_layout_config");
+            return None;
+        }
+        rx.await.unwrap_or(None)
+    }
+
+    /// Check if a registered meeting is end-to-end encrypted.
+    ///
+    /// Returns `false` for unregistered meetings.
+    pub async fn is_meeting_e2e(&self, meeting_id: &str) -> bool {
+        let (tx, rx) = oneshot::channel();
+        if self
+            .sender
+            .send(SessionMessage::IsMeetingE2e {
+                meeting_id: meeting_id.to_string(),
+                respond_to: tx,
+            })
+            .await
+            .is_err()
+        {
+            tracing::warn!(target: "mh.session", "SessionManagerActor channel closed on is_meeting_e2e");
+            return false;
+        }
+        rx.await.unwrap_or(false)
+    }
+
     /// Add an active connection for a registered meeting.
     ///
     /// Fire-and-forget: the caller does not need confirmation.
@@ -514,6 +683,48 @@ impl SessionManagerHandle {
         rx.await.unwrap_or(false)
     }
 
+    /// Record that a connection migrated to a new peer address.
+    ///
+    /// Fire-and-forget: the caller does not need confirmation.
+    pub async fn update_peer_address(
+        &self,
+        meeting_id: &str,
+        connection_id: &str,
+        peer_address: SocketAddr,
+    ) {
+        if self
+            .sender
+            .send(SessionMessage::UpdatePeerAddress {
+                meeting_id: meeting_id.to_string(),
+                connection_id: connection_id.to_string(),
+                peer_address,
+            })
+            .await
+            .is_err()
+        {
+            tracing::warn!(target: "mh.session", "SessionManagerActor channel closed on update_peer_address");
+        }
+    }
+
+    /// Get a connection's current peer address, active or pending.
+    pub async fn peer_address(&self, meeting_id: &str, connection_id: &str) -> Option<SocketAddr> {
+        let (tx, rx) = oneshot::channel();
+        if self
+            .sender
+            .send(SessionMessage::GetPeerAddress {
+                meeting_id: meeting_id.to_string(),
+                connection_id: connection_id.to_string(),
+                respond_to: tx,
+            })
+            .await
+            .is_err()
+        {
+            tracing::warn!(target: "mh.session", "SessionManagerActor channel closed on peer_address");
+            return None;
+        }
+        rx.await.unwrap_or(None)
+    }
+
     /// Get count of active connections across all meetings.
     pub async fn active_connection_count(&self) -> usize {
         let (tx, rx) = oneshot::channel();
@@ -546,6 +757,8 @@ mod tests {
             mc_id: mc_id.to_string(),
             mc_grpc_endpoint: endpoint.to_string(),
             registered_at: Instant::now(),
+            layout_config: LayoutConfig::default(),
+            e2e_enabled: false,
         }
     }
 
@@ -553,6 +766,7 @@ mod tests {
         ConnectionEntry {
             connection_id: conn_id.to_string(),
             participant_id: participant_id.to_string(),
+            peer_address: "192.0.2.1:4433".parse().unwrap(),
             connected_at: Instant::now(),
         }
     }
@@ -562,6 +776,7 @@ mod tests {
             connection_id: conn_id.to_string(),
             meeting_id: meeting_id.to_string(),
             participant_id: participant_id.to_string(),
+            peer_address: "192.0.2.1:4433".parse().unwrap(),
             connected_at: Instant::now(),
         }
     }
@@ -740,6 +955,65 @@ mod tests {
         assert_eq!(handle.active_connection_count().await, 1);
     }
 
+    #[tokio::test]
+    async fn test_update_peer_address_keeps_connection() {
+        let handle = SessionManagerHandle::new();
+        handle
+            .register_meeting(
+                "meeting-1".to_string(),
+                make_registration("mc-1", "http://mc:50052"),
+            )
+            .await;
+        handle
+            .add_connection("meeting-1", make_connection("conn-1", "user-1"))
+            .await;
+
+        let migrated: SocketAddr = "198.51.100.7:5000".parse().unwrap();
+        handle
+            .update_peer_address("meeting-1", "conn-1", migrated)
+            .await;
+
+        assert_eq!(
+            handle.peer_address("meeting-1", "conn-1").await,
+            Some(migrated)
+        );
+        assert_eq!(handle.active_connection_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_pending_peer_address_survives_promotion() {
+        let handle = SessionManagerHandle::new();
+        let _notify = handle
+            .add_pending_connection(make_pending("conn-1", "meeting-1", "user-1"))
+            .await;
+
+        let migrated: SocketAddr = "[2001:db8::1]:4433".parse().unwrap();
+        handle
+            .update_peer_address("meeting-1", "conn-1", migrated)
+            .await;
+        handle
+            .register_meeting(
+                "meeting-1".to_string(),
+                make_registration("mc-1", "http://mc:50052"),
+            )
+            .await;
+
+        assert_eq!(
+            handle.peer_address("meeting-1", "conn-1").await,
+            Some(migrated)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_peer_address_unknown_connection_ignored() {
+        let handle = SessionManagerHandle::new();
+        handle
+            .update_peer_address("meeting-1", "conn-999", "192.0.2.9:1".parse().unwrap())
+            .await;
+        assert!(handle.peer_address("meeting-1", "conn-999").await.is_none());
+        assert_eq!(handle.active_connection_count().await, 0);
+    }
+
     #[tokio::test]
     async fn test_get_mc_endpoint_unregistered() {
         let handle = SessionManagerHandle::new();
@@ -4,24 +4,32 @@
 //! 1. Accept WebTransport session
 //! 2. Accept bidirectional stream
 //! 3. Read meeting JWT from first length-prefixed message
-//! 4. Validate JWT via `MhJwtValidator`
+//! 4. Validate JWT via `MhJwtValidator`, and the MC-minted media token
+//!    when media tokens are enabled (see [`common::media_token`])
 //! 5. Check meeting registration status:
 //!    - Registered: add connection, notify MC, hold open
 //!    - Not registered: provisional accept with configurable timeout
-//! 6. Monitor for disconnect or cancellation
+//! 6. Monitor for disconnect or cancellation, and record the client's new
+//!    address when it migrates to another network path
 //! 7. On disconnect: notify MC, clean up session
 
 use crate::auth::MhJwtValidator;
 use crate::errors::MhError;
+use crate::forwarding::FrameIngest;
 use crate::grpc::McClient;
 use crate::observability::metrics;
 use crate::session::{ConnectionEntry, PendingConnection, SessionManagerHandle};
 
+use common::media_token::{MediaTokenClaims, MediaTokenKey};
+use common::quic_path::{PeerPath, PATH_CHECK_INTERVAL};
+use media_protocol::codec::decode_frame;
+use media_protocol::frame::user_id_for_subject;
 use prost::Message;
 use proto_gen::dark_tower::signaling::v1::{mh_client_message, MhClientMessage};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::Notify;
+use tokio::time::MissedTickBehavior;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 use wtransport::endpoint::IncomingSession;
@@ -122,9 +130,14 @@ async fn await_meeting_registration(
     clippy::too_many_lines,
     reason = "Connection lifecycle is sequential; splitting would fragment the accept-validate-register-notify-hold flow"
 )]
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Per-connection dependencies are handed over by the accept loop"
+)]
 pub async fn handle_connection(
     incoming: IncomingSession,
     jwt_validator: Arc<MhJwtValidator>,
+    media_token_key: Option<Arc<MediaTokenKey>>,
     session_manager: SessionManagerHandle,
     mc_client: Arc<McClient>,
     handler_id: String,
@@ -188,8 +201,8 @@ pub async fn handle_connection(
     // generic client-facing error: in all three the JWT was never extracted, so
     // validation never ran — splitting labels here would be a distinction without
     // a difference for the accept_loop's status=error observable.
-    let token = match envelope.message {
-        Some(mh_client_message::Message::ConnectRequest(req)) => req.join_token,
+    let (token, media_token) = match envelope.message {
+        Some(mh_client_message::Message::ConnectRequest(req)) => (req.join_token, req.media_token),
         None => {
             warn!(
                 target: "mh.webtransport.connection",
@@ -230,10 +243,45 @@ pub async fn handle_connection(
         "JWT validation succeeded"
     );
 
+    // The JWT alone does not prove the client joined through MC
+    if let Some(key) = &media_token_key {
+        match check_media_token(
+            key,
+            &media_token,
+            meeting_id,
+            participant_id,
+            chrono::Utc::now().timestamp(),
+        ) {
+            Ok(media) => {
+                metrics::record_jwt_validation("success", "media", "none");
+                debug!(
+                    target: "mh.webtransport.connection",
+                    connection_id = %connection_id,
+                    mc_participant_id = %media.participant_id,
+                    "Media token verified"
+                );
+            }
+            Err(reason) => {
+                warn!(
+                    target: "mh.webtransport.connection",
+                    connection_id = %connection_id,
+                    meeting_id = %meeting_id,
+                    reason,
+                    "Media token rejected"
+                );
+                metrics::record_jwt_validation("failure", "media", reason);
+                return Err(MhError::JwtValidation(
+                    "The media token is invalid".to_string(),
+                ));
+            }
+        }
+    }
+
     // Record handshake duration (session accept through JWT validation)
     metrics::record_webtransport_handshake_duration(handshake_start.elapsed());
 
     // Step 5: Check meeting registration status and notify MC
+    let mut peer_path = PeerPath::new(connection.remote_address());
     if session_manager.is_meeting_registered(meeting_id).await {
         // Meeting already registered — add as active connection
         session_manager
@@ -242,6 +290,7 @@ pub async fn handle_connection(
                 ConnectionEntry {
                     connection_id: connection_id.clone(),
                     participant_id: participant_id.clone(),
+                    peer_address: peer_path.address(),
                     connected_at: Instant::now(),
                 },
             )
@@ -278,6 +327,7 @@ pub async fn handle_connection(
             connection_id: connection_id.clone(),
             meeting_id: meeting_id.clone(),
             participant_id: participant_id.clone(),
+            peer_address: peer_path.address(),
             connected_at: Instant::now(),
         };
 
@@ -315,15 +365,67 @@ pub async fn handle_connection(
         }
     }
 
-    // Step 6: Hold connection open — monitor for disconnect or cancellation
-    // The connection stays open for future media frame forwarding (separate story).
-    // For now, we monitor the recv stream for closure and the cancellation token.
+    // Step 6: Hold connection open — check published media frames and
+    // monitor for disconnect or cancellation.
+    // Every datagram must carry this participant's user_id and pass its
+    // stream's replay window (`FrameIngest`). Placeholder: MH has no
+    // subscriber fan-out yet, so accepted frames are discarded here.
+    // QUIC validates a migrating client's new path itself; the connection
+    // survives and we only update the session's peer address.
     //
-    // Track the disconnect reason for MC notification
+    // Track the disconnect reason for MC notification. The stream arm decides
+    // it: a datagram error only means the connection is going away, and the
+    // stream read reports whether the client closed it or it failed.
     let disconnect_reason;
+    let mut ingest = FrameIngest::new(user_id_for_subject(participant_id));
+    let mut datagrams_open = true;
     let mut probe_buf = [0u8; 1];
+    let mut path_check = tokio::time::interval(PATH_CHECK_INTERVAL);
+    path_check.set_missed_tick_behavior(MissedTickBehavior::Delay);
     loop {
         tokio::select! {
+            _ = path_check.tick() => {
+                if let Some(migration) = peer_path.observe(connection.remote_address()) {
+                    // Peer addresses are PII; log the kind only
+                    info!(
+                        target: "mh.webtransport.connection",
+                        connection_id = %connection_id,
+                        meeting_id = %meeting_id,
+                        kind = migration.as_str(),
+                        migrations = peer_path.migrations(),
+                        "Client connection migrated to a new path"
+                    );
+                    metrics::record_quic_path_migration(migration.as_str());
+                    session_manager
+                        .update_peer_address(meeting_id, &connection_id, peer_path.address())
+                        .await;
+                }
+            }
+            result = connection.receive_datagram(), if datagrams_open => {
+                let Ok(datagram) = result else {
+                    debug!(
+                        target: "mh.webtransport.connection",
+                        connection_id = %connection_id,
+                        "Datagram receive ended, waiting for stream close"
+                    );
+                    datagrams_open = false;
+                    continue;
+                };
+                match decode_frame(&mut datagram.payload()) {
+                    Ok(frame) => {
+                        // Placeholder: nothing to forward accepted frames to yet
+                        let _accepted = ingest.accept(frame);
+                    }
+                    Err(e) => {
+                        debug!(
+                            target: "mh.webtransport.connection",
+                            connection_id = %connection_id,
+                            error = %e,
+                            "Dropping undecodable media datagram"
+                        );
+                    }
+                }
+            }
             () = cancel_token.cancelled() => {
                 debug!(
                     target: "mh.webtransport.connection",
@@ -408,6 +510,24 @@ pub async fn handle_connection(
     Ok(())
 }
 
+/// Verify a client's media token and check that it was minted for the
+/// meeting and subject of the JWT it came with.
+///
+/// Returns the `failure_reason` metric label on rejection.
+fn check_media_token(
+    key: &MediaTokenKey,
+    token: &str,
+    meeting_id: &str,
+    sub: &str,
+    now: i64,
+) -> Result<MediaTokenClaims, &'static str> {
+    let claims = key.verify(token, now).map_err(|e| e.as_str())?;
+    if claims.meeting_id != meeting_id || claims.sub != sub {
+        return Err("binding_mismatch");
+    }
+    Ok(claims)
+}
+
 /// Spawn a best-effort `NotifyParticipantConnected` notification to MC.
 ///
 /// Looks up the MC endpoint from `SessionManager`. If found, spawns the
@@ -560,6 +680,7 @@ mod tests {
                 connection_id: connection_id.to_string(),
                 meeting_id: meeting_id.to_string(),
                 participant_id: "user-1".to_string(),
+                peer_address: "192.0.2.1:4433".parse().unwrap(),
                 connected_at: Instant::now(),
             })
             .await
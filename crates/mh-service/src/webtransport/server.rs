@@ -1,7 +1,8 @@
 //! WebTransport accept loop with TLS 1.3 termination.
 //!
-//! Binds a QUIC/HTTP3 endpoint using `wtransport`, accepts WebTransport sessions,
-//! and spawns per-connection handler tasks.
+//! Binds a QUIC/HTTP3 endpoint per bind address using `wtransport`, accepts
+//! WebTransport sessions from all of them, and spawns per-connection handler
+//! tasks.
 //!
 //! # Graceful Shutdown
 //!
@@ -14,19 +15,26 @@ use crate::grpc::McClient;
 use crate::observability::metrics;
 use crate::session::SessionManagerHandle;
 
+use common::listen::{ipv6_only, parse_bind_addresses};
+use common::media_socket::{bind_media_socket, MediaSocketConfig};
+use common::media_token::MediaTokenKey;
+use std::net::SocketAddr;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::task::JoinSet;
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
+use wtransport::config::QuicTransportConfig;
 use wtransport::endpoint::endpoint_side::Server;
+use wtransport::endpoint::IncomingSession;
 use wtransport::{Endpoint, Identity, ServerConfig};
 
 use super::connection;
 
 /// WebTransport server that accepts client connections.
 pub struct WebTransportServer {
-    /// Bind address for the WebTransport endpoint.
+    /// Bind address list (comma-separated) for the WebTransport endpoints.
     bind_address: String,
     /// Path to TLS certificate (PEM).
     tls_cert_path: String,
@@ -34,6 +42,8 @@ pub struct WebTransportServer {
     tls_key_path: String,
     /// JWT validator for meeting tokens.
     jwt_validator: Arc<MhJwtValidator>,
+    /// Key for MC-minted media tokens; `None` accepts the meeting JWT alone.
+    media_token_key: Option<Arc<MediaTokenKey>>,
     /// Session manager handle for meeting registration and connection tracking.
     session_manager: SessionManagerHandle,
     /// MC notification client for MH→MC participant notifications.
@@ -46,6 +56,8 @@ pub struct WebTransportServer {
     max_connections: usize,
     /// Active connection count.
     active_connections: Arc<AtomicUsize>,
+    /// UDP socket tuning (DSCP, buffer sizes, GSO).
+    media_socket: MediaSocketConfig,
     /// Cancellation token for graceful shutdown.
     cancel_token: CancellationToken,
 }
@@ -74,27 +86,50 @@ impl WebTransportServer {
             tls_cert_path,
             tls_key_path,
             jwt_validator,
+            media_token_key: None,
             session_manager,
             mc_client,
             handler_id,
             register_meeting_timeout,
             max_connections,
             active_connections: Arc::new(AtomicUsize::new(0)),
+            media_socket: MediaSocketConfig::default(),
             cancel_token,
         }
     }
 
-    /// Load TLS identity and bind the QUIC/HTTP3 endpoint.
+    /// Apply socket tuning to the endpoint (defaults leave the OS settings).
+    #[must_use]
+    pub fn with_media_socket(mut self, media_socket: MediaSocketConfig) -> Self {
+        self.media_socket = media_socket;
+        self
+    }
+
+    /// Require clients to present a media token verified with `key`
+    /// (default: `None`, the meeting JWT alone is accepted).
+    #[must_use]
+    pub fn with_media_token_key(mut self, key: Option<Arc<MediaTokenKey>>) -> Self {
+        self.media_token_key = key;
+        self
+    }
+
+    /// Load TLS identity and bind a QUIC/HTTP3 endpoint per bind address.
+    ///
+    /// The bind address may list several addresses (comma-separated); an
+    /// IPv6 wildcard listens dual-stack unless an IPv4 address in the list
+    /// shares its port.
     ///
     /// Call this **before** spawning the accept loop so that TLS or bind
     /// failures are fail-fast (crash the process) rather than silent.
     ///
     /// # Errors
     ///
-    /// Returns an error if the TLS certificate cannot be loaded or the
-    /// endpoint fails to bind.
-    pub async fn bind(&self) -> Result<Endpoint<Server>, Box<dyn std::error::Error + Send + Sync>> {
-        let bind_addr: std::net::SocketAddr = self.bind_address.parse().map_err(|e| {
+    /// Returns an error if a bind address is invalid, the TLS certificate
+    /// cannot be loaded, or an endpoint fails to bind.
+    pub async fn bind(
+        &self,
+    ) -> Result<Vec<Endpoint<Server>>, Box<dyn std::error::Error + Send + Sync>> {
+        let bind_addrs = parse_bind_addresses(&self.bind_address).map_err(|e| {
             error!(
                 target: "mh.webtransport",
                 error = %e,
@@ -107,6 +142,27 @@ impl WebTransportServer {
             )
         })?;
 
+        let mut endpoints = Vec::with_capacity(bind_addrs.len());
+        for bind_addr in &bind_addrs {
+            let endpoint = self
+                .bind_endpoint(*bind_addr, ipv6_only(bind_addr, &bind_addrs))
+                .await?;
+            info!(
+                target: "mh.webtransport",
+                bind_address = %bind_addr,
+                "WebTransport endpoint bound successfully"
+            );
+            endpoints.push(endpoint);
+        }
+
+        Ok(endpoints)
+    }
+
+    async fn bind_endpoint(
+        &self,
+        bind_addr: SocketAddr,
+        ipv6_only: bool,
+    ) -> Result<Endpoint<Server>, Box<dyn std::error::Error + Send + Sync>> {
         let identity = Identity::load_pemfiles(&self.tls_cert_path, &self.tls_key_path)
             .await
             .map_err(|e| {
@@ -120,34 +176,74 @@ impl WebTransportServer {
                 format!("Failed to load TLS certificate: {e}")
             })?;
 
-        let config = ServerConfig::builder()
-            .with_bind_address(bind_addr)
-            .with_identity(identity)
-            .build();
+        let (socket, applied) = bind_media_socket(bind_addr, ipv6_only, &self.media_socket)
+            .map_err(|e| {
+                error!(
+                    target: "mh.webtransport",
+                    error = %e,
+                    addr = %bind_addr,
+                    "Failed to bind WebTransport socket"
+                );
+                format!("Failed to bind WebTransport socket {bind_addr}: {e}")
+            })?;
 
-        let endpoint = Endpoint::server(config).map_err(|e| {
+        // GSO is used where the kernel supports it unless disabled by config
+        let gso_segments = if self.media_socket.gso_enabled {
+            quinn::udp::UdpSocketState::new((&socket).into())
+                .map_or(1, |state| state.max_gso_segments())
+        } else {
+            1
+        };
+        metrics::record_media_socket_options(&applied, gso_segments);
+        info!(
+            target: "mh.webtransport",
+            addr = %bind_addr,
+            dscp = applied.dscp,
+            send_buffer_bytes = applied.send_buffer_bytes,
+            recv_buffer_bytes = applied.recv_buffer_bytes,
+            gso_segments,
+            "WebTransport socket options applied"
+        );
+
+        // Clients keep their connection across network changes; the
+        // connection handler records the new peer address
+        let builder = ServerConfig::builder().with_bind_socket(socket);
+        let config = if self.media_socket.gso_enabled {
+            builder
+                .with_identity(identity)
+                .allow_migration(true)
+                .build()
+        } else {
+            let mut transport = QuicTransportConfig::default();
+            transport.enable_segmentation_offload(false);
+            builder
+                .with_custom_transport(identity, transport)
+                .allow_migration(true)
+                .build()
+        };
+
+        Endpoint::server(config).map_err(|e| {
             error!(
                 target: "mh.webtransport",
                 error = %e,
+                addr = %bind_addr,
                 "Failed to create WebTransport endpoint"
             );
-            format!("Failed to create WebTransport endpoint: {e}")
-        })?;
-
-        info!(
-            target: "mh.webtransport",
-            bind_address = %self.bind_address,
-            "WebTransport endpoint bound successfully"
-        );
-
-        Ok(endpoint)
+            format!("Failed to create WebTransport endpoint: {e}").into()
+        })
     }
 
     /// Run the accept loop until the cancellation token is triggered.
     ///
-    /// Individual connection errors do not stop the loop.
-    /// Call [`Self::bind()`] first to obtain the endpoint.
-    pub async fn accept_loop(&self, endpoint: Endpoint<Server>) {
+    /// Accepts from every endpoint returned by [`Self::bind()`]; the
+    /// connection limit is shared across them. Individual connection errors
+    /// do not stop the loop.
+    pub async fn accept_loop(&self, endpoints: Vec<Endpoint<Server>>) {
+        let mut accepts = JoinSet::new();
+        for endpoint in endpoints {
+            spawn_accept(&mut accepts, Arc::new(endpoint));
+        }
+
         loop {
             tokio::select! {
                 () = self.cancel_token.cancelled() => {
@@ -158,8 +254,19 @@ impl WebTransportServer {
                     break;
                 }
 
-                incoming = endpoint.accept() => {
-                    let incoming_session = incoming;
+                Some(accepted) = accepts.join_next() => {
+                    let (endpoint, incoming_session) = match accepted {
+                        Ok(accepted) => accepted,
+                        Err(e) => {
+                            error!(
+                                target: "mh.webtransport",
+                                error = %e,
+                                "WebTransport accept task failed; endpoint dropped"
+                            );
+                            continue;
+                        }
+                    };
+                    spawn_accept(&mut accepts, endpoint);
 
                     // Capacity check: reject before allocating handler resources
                     let current = self.active_connections.load(Ordering::Relaxed);
@@ -179,6 +286,7 @@ impl WebTransportServer {
                     metrics::record_webtransport_connection("accepted");
                     let active_connections = Arc::clone(&self.active_connections);
                     let jwt_validator = Arc::clone(&self.jwt_validator);
+                    let media_token_key = self.media_token_key.clone();
                     let session_manager = self.session_manager.clone();
                     let mc_client = Arc::clone(&self.mc_client);
                     let handler_id = self.handler_id.clone();
@@ -189,6 +297,7 @@ impl WebTransportServer {
                         let result = connection::handle_connection(
                             incoming_session,
                             jwt_validator,
+                            media_token_key,
                             session_manager,
                             mc_client,
                             handler_id,
@@ -220,3 +329,13 @@ impl WebTransportServer {
         }
     }
 }
+
+type AcceptResult = (Arc<Endpoint<Server>>, IncomingSession);
+
+/// Wait for the next session on `endpoint` in the background.
+fn spawn_accept(accepts: &mut JoinSet<AcceptResult>, endpoint: Arc<Endpoint<Server>>) {
+    accepts.spawn(async move {
+        let incoming_session = endpoint.accept().await;
+        (endpoint, incoming_session)
+    });
+}
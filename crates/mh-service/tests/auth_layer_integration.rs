@@ -60,6 +60,8 @@ fn req_with_bearer(token: Option<&str>) -> Request<RegisterMeetingRequest> {
         meeting_id: "meeting-auth-test".to_string(),
         mc_id: "mc-auth-test".to_string(),
         mc_grpc_endpoint: "http://mc-auth-test:50052".to_string(),
+        recording_layout: 0,
+        e2e_enabled: false,
     });
     if let Some(t) = token {
         let value: MetadataValue<_> = format!("Bearer {t}")
@@ -36,6 +36,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
+use common::media_token::MediaTokenKey;
 use mh_service::auth::MhJwtValidator;
 use mh_service::grpc::McClient;
 use mh_service::session::SessionManagerHandle;
@@ -88,12 +89,79 @@ impl AcceptLoopRig {
         handler_id: String,
         max_connections: usize,
         register_meeting_timeout: Duration,
+    ) -> Self {
+        Self::start_on(
+            "127.0.0.1:0",
+            jwt_validator,
+            session_manager,
+            mc_client,
+            handler_id,
+            max_connections,
+            register_meeting_timeout,
+        )
+        .await
+    }
+
+    /// Start on an explicit bind address (e.g. `[::]:0` for dual-stack).
+    /// `url` and `addr` describe the first bound endpoint.
+    pub async fn start_on(
+        bind_address: &str,
+        jwt_validator: Arc<MhJwtValidator>,
+        session_manager: SessionManagerHandle,
+        mc_client: Arc<McClient>,
+        handler_id: String,
+        max_connections: usize,
+        register_meeting_timeout: Duration,
+    ) -> Self {
+        Self::start_inner(
+            bind_address,
+            jwt_validator,
+            session_manager,
+            mc_client,
+            handler_id,
+            max_connections,
+            register_meeting_timeout,
+            None,
+        )
+        .await
+    }
+
+    /// Start with media tokens required, verified with `media_token_key`.
+    pub async fn start_with_media_token_key(
+        jwt_validator: Arc<MhJwtValidator>,
+        session_manager: SessionManagerHandle,
+        mc_client: Arc<McClient>,
+        handler_id: String,
+        media_token_key: Arc<MediaTokenKey>,
+    ) -> Self {
+        Self::start_inner(
+            "127.0.0.1:0",
+            jwt_validator,
+            session_manager,
+            mc_client,
+            handler_id,
+            32,
+            Duration::from_secs(30),
+            Some(media_token_key),
+        )
+        .await
+    }
+
+    async fn start_inner(
+        bind_address: &str,
+        jwt_validator: Arc<MhJwtValidator>,
+        session_manager: SessionManagerHandle,
+        mc_client: Arc<McClient>,
+        handler_id: String,
+        max_connections: usize,
+        register_meeting_timeout: Duration,
+        media_token_key: Option<Arc<MediaTokenKey>>,
     ) -> Self {
         let (tempdir, cert_path, key_path) = Self::write_self_signed_pems();
 
         let cancel_token = CancellationToken::new();
         let server = WebTransportServer::new(
-            "127.0.0.1:0".to_string(),
+            bind_address.to_string(),
             cert_path,
             key_path,
             jwt_validator,
@@ -103,21 +171,24 @@ impl AcceptLoopRig {
             register_meeting_timeout,
             max_connections,
             cancel_token.clone(),
-        );
+        )
+        .with_media_token_key(media_token_key);
 
         // Byte-identical to `main.rs:258-260` — real `bind()` then real
-        // `accept_loop()` on the returned endpoint.
-        let endpoint = server
+        // `accept_loop()` on the returned endpoints.
+        let endpoints = server
             .bind()
             .await
             .expect("WebTransportServer::bind() failed in accept-loop rig");
-        let addr = endpoint
+        let addr = endpoints
+            .first()
+            .expect("bind() must return at least one endpoint")
             .local_addr()
             .expect("endpoint local_addr() must be available after bind()");
         let url = format!("https://127.0.0.1:{}", addr.port());
 
         let accept_loop_handle = tokio::spawn(async move {
-            server.accept_loop(endpoint).await;
+            server.accept_loop(endpoints).await;
         });
 
         // Give the accept loop a moment to reach its `endpoint.accept()` await.
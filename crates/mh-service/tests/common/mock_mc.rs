@@ -17,6 +17,7 @@ use proto_gen::dark_tower::internal::v1::media_coordination_service_server::{
 use proto_gen::dark_tower::internal::v1::{
     NotifyParticipantConnectedRequest, NotifyParticipantConnectedResponse,
     NotifyParticipantDisconnectedRequest, NotifyParticipantDisconnectedResponse,
+    RequestKeyframeRequest, RequestKeyframeResponse,
 };
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
@@ -136,6 +137,19 @@ impl MediaCoordinationService for MockMcServer {
             acknowledged: true,
         }))
     }
+
+    async fn request_keyframe(
+        &self,
+        _request: Request<RequestKeyframeRequest>,
+    ) -> Result<Response<RequestKeyframeResponse>, Status> {
+        if let Some(status) = self.should_fail() {
+            return Err(status);
+        }
+
+        Ok(Response::new(RequestKeyframeResponse {
+            acknowledged: true,
+        }))
+    }
 }
 
 /// RAII handle to a running mock MC gRPC server.
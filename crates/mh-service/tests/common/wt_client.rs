@@ -59,11 +59,21 @@ pub async fn write_framed(
 pub async fn write_mh_connect(
     send: &mut wtransport::stream::SendStream,
     jwt: &str,
+) -> Result<(), wtransport::error::StreamWriteError> {
+    write_mh_connect_with_media_token(send, jwt, "").await
+}
+
+/// Like [`write_mh_connect`], also presenting the MC-minted `media_token`.
+pub async fn write_mh_connect_with_media_token(
+    send: &mut wtransport::stream::SendStream,
+    jwt: &str,
+    media_token: &str,
 ) -> Result<(), wtransport::error::StreamWriteError> {
     let envelope = MhClientMessage {
         message: Some(mh_client_message::Message::ConnectRequest(
             MhConnectRequest {
                 join_token: jwt.to_string(),
+                media_token: media_token.to_string(),
             },
         )),
         trace_parent: String::new(),
@@ -20,7 +20,8 @@ use proto_gen::dark_tower::internal::v1::media_handler_registry_service_server::
     MediaHandlerRegistryService, MediaHandlerRegistryServiceServer,
 };
 use proto_gen::dark_tower::internal::v1::{
-    RegisterMhRequest, RegisterMhResponse, SendLoadReportRequest, SendLoadReportResponse,
+    RegisterMhRequest, RegisterMhResponse, ReportRecordingRequest, ReportRecordingResponse,
+    SendLoadReportRequest, SendLoadReportResponse,
 };
 use tokio::sync::{mpsc, watch};
 use tokio_util::sync::CancellationToken;
@@ -50,6 +51,7 @@ struct MockGcServer {
     load_report_count: AtomicU32,
     registration_tx: Option<mpsc::Sender<RegisterMhRequest>>,
     load_report_tx: Option<mpsc::Sender<SendLoadReportRequest>>,
+    recording_tx: Option<mpsc::Sender<ReportRecordingRequest>>,
 }
 
 impl MockGcServer {
@@ -61,6 +63,7 @@ impl MockGcServer {
             load_report_count: AtomicU32::new(0),
             registration_tx: None,
             load_report_tx: None,
+            recording_tx: None,
         }
     }
 
@@ -92,6 +95,11 @@ impl MockGcServer {
         self
     }
 
+    fn with_recording_channel(mut self, tx: mpsc::Sender<ReportRecordingRequest>) -> Self {
+        self.recording_tx = Some(tx);
+        self
+    }
+
     fn with_load_report_interval(mut self, interval_ms: u64) -> Self {
         self.load_report_interval_ms = interval_ms;
         self
@@ -148,6 +156,22 @@ impl MediaHandlerRegistryService for MockGcServer {
             }
         }
     }
+
+    async fn report_recording(
+        &self,
+        request: Request<ReportRecordingRequest>,
+    ) -> Result<Response<ReportRecordingResponse>, Status> {
+        if let Some(tx) = &self.recording_tx {
+            let _ = tx.send(request.into_inner()).await;
+        }
+
+        match self.behavior {
+            MockBehavior::NotFound => Err(Status::not_found("Meeting not found")),
+            MockBehavior::Accept | MockBehavior::Reject => {
+                Ok(Response::new(ReportRecordingResponse { accepted: true }))
+            }
+        }
+    }
 }
 
 // ============================================================================
@@ -166,6 +190,7 @@ fn test_config(gc_url: &str) -> Config {
         ac_endpoint: "https://ac.example.com".to_string(),
         client_id: "media-handler".to_string(),
         client_secret: SecretString::from("test-client-secret"),
+        media_token_secret: None,
         tls_cert_path: "/dev/null".to_string(),
         tls_key_path: "/dev/null".to_string(),
         grpc_advertise_address: "grpc://localhost:50053".to_string(),
@@ -173,6 +198,9 @@ fn test_config(gc_url: &str) -> Config {
         ac_jwks_url: "http://localhost:8082/.well-known/jwks.json".to_string(),
         register_meeting_timeout_seconds: 15,
         max_connections: 10_000,
+        hls_base_url: None,
+        max_egress_sessions: 4,
+        media_socket: common::media_socket::MediaSocketConfig::default(),
     }
 }
 
@@ -302,6 +330,10 @@ async fn test_gc_client_registration_content() {
         config.webtransport_advertise_address
     );
 
+    let build = request.build.unwrap();
+    assert_eq!(build.version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(build.proto_schema, proto_gen::SCHEMA_VERSION);
+
     cancel_token.cancel();
 }
 
@@ -418,3 +450,62 @@ async fn test_gc_client_load_report_interval_from_gc() {
 
     cancel_token.cancel();
 }
+
+// ============================================================================
+// Recording Report Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_gc_client_report_recording_fills_handler_id() {
+    let (recording_tx, mut recording_rx) = mpsc::channel(1);
+    let mock_gc = MockGcServer::accepting().with_recording_channel(recording_tx);
+    let (addr, cancel_token) = start_mock_gc_server(mock_gc).await;
+
+    let gc_url = format!("http://{addr}");
+    let config = test_config(&gc_url);
+    let token_rx = mock_token_receiver();
+
+    let gc_client = GcClient::new(gc_url, token_rx, config.clone())
+        .await
+        .unwrap();
+
+    gc_client
+        .report_recording(ReportRecordingRequest {
+            meeting_id: "meeting-123".to_string(),
+            recording_id: "rec-1".to_string(),
+            object_key: "recordings/meeting-123/rec-1.webm".to_string(),
+            content_type: "video/webm".to_string(),
+            size_bytes: 4096,
+            duration_seconds: 60,
+            started_at: 1_700_000_000,
+            completed_at: 1_700_000_060,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let request = recording_rx.recv().await.unwrap();
+    assert_eq!(request.handler_id, config.handler_id);
+    assert_eq!(request.recording_id, "rec-1");
+
+    cancel_token.cancel();
+}
+
+#[tokio::test]
+async fn test_gc_client_report_recording_error() {
+    let mock_gc = MockGcServer::not_found();
+    let (addr, cancel_token) = start_mock_gc_server(mock_gc).await;
+
+    let gc_url = format!("http://{addr}");
+    let config = test_config(&gc_url);
+    let token_rx = mock_token_receiver();
+
+    let gc_client = GcClient::new(gc_url, token_rx, config).await.unwrap();
+
+    let result = gc_client
+        .report_recording(ReportRecordingRequest::default())
+        .await;
+    assert!(matches!(result, Err(MhError::Grpc(_))));
+
+    cancel_token.cancel();
+}
@@ -0,0 +1,169 @@
+// The replay test drives a live connection, whose ingest runs in a
+// `tokio::spawn` task; it is pinned to `current_thread` so the per-thread
+// `MetricAssertion` recorder sees those emissions.
+//
+//! Integration coverage for the media forwarding counters:
+//!
+//! - `mh_media_frames_dropped_total{priority, discardable}`, recorded by
+//!   `forwarding::ForwardQueue` when a congested subscriber queue drops
+//!   frames.
+//! - `mh_media_frames_replayed_total{reason}`, recorded by
+//!   `forwarding::FrameIngest` when a frame fails its stream's replay window.
+//! - `mh_media_frames_rejected_total{reason}`, recorded by
+//!   `forwarding::FrameIngest` when a frame names another participant.
+//!
+//! Drives a real queue past its capacity rather than calling the metric
+//! wrapper directly, so the labels asserted are the ones the drop policy
+//! actually produces. Replays and impostor frames are sent as datagrams over
+//! a real connection accepted by the production accept loop.
+
+#![expect(
+    clippy::expect_used,
+    reason = "component test; panics on setup failure (rig start, token mint, client connect) are intentional"
+)]
+
+#[path = "common/mod.rs"]
+mod test_common;
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use ::common::observability::testing::MetricAssertion;
+use bytes::Bytes;
+use media_protocol::codec::encode_frame;
+use media_protocol::frame::{
+    user_id_for_subject, FrameFlags, FramePriority, FrameType, MediaFrame,
+};
+use mh_service::auth::MhJwtValidator;
+use mh_service::forwarding::ForwardQueue;
+use mh_service::grpc::McClient;
+use mh_service::session::{MeetingRegistration, SessionManagerHandle};
+
+use test_common::accept_loop_rig::AcceptLoopRig;
+use test_common::jwks_rig::JwksRig;
+use test_common::test_token_receiver;
+use test_common::tokens::mint_meeting_token;
+use test_common::wt_client::{connect_and_open_bi, write_mh_connect};
+
+fn frame(sequence: u64, priority: FramePriority, discardable: bool) -> MediaFrame {
+    MediaFrame {
+        version: MediaFrame::VERSION,
+        user_id: 7,
+        stream_id: 1,
+        frame_type: FrameType::VideoDelta,
+        timestamp: 0,
+        sequence,
+        flags: FrameFlags {
+            end_of_frame: true,
+            discardable,
+            priority,
+        },
+        key_epoch: 0,
+        payload: Bytes::from(vec![0u8; 100]),
+    }
+}
+
+#[test]
+fn congestion_drops_are_counted_by_priority() {
+    let snap = MetricAssertion::snapshot();
+    let mut queue = ForwardQueue::new(200);
+
+    assert!(queue.push(frame(0, FramePriority::Low, true)));
+    assert!(queue.push(frame(1, FramePriority::Normal, false)));
+    // Evicts the discardable low-priority frame
+    assert!(queue.push(frame(2, FramePriority::Critical, false)));
+    // Nothing ranks below it, so the incoming frame is dropped
+    assert!(!queue.push(frame(3, FramePriority::Normal, false)));
+
+    snap.counter("mh_media_frames_dropped_total")
+        .with_labels(&[("priority", "low"), ("discardable", "true")])
+        .assert_delta(1);
+    snap.counter("mh_media_frames_dropped_total")
+        .with_labels(&[("priority", "normal"), ("discardable", "false")])
+        .assert_delta(1);
+    snap.counter("mh_media_frames_dropped_total")
+        .with_labels(&[("priority", "critical"), ("discardable", "false")])
+        .assert_delta(0);
+}
+
+#[test]
+fn uncongested_queue_drops_nothing() {
+    let snap = MetricAssertion::snapshot();
+    let mut queue = ForwardQueue::new(1000);
+
+    for sequence in 0..5 {
+        assert!(queue.push(frame(sequence, FramePriority::Normal, false)));
+    }
+    while queue.pop().is_some() {}
+
+    snap.counter("mh_media_frames_dropped_total")
+        .with_labels(&[("priority", "normal"), ("discardable", "false")])
+        .assert_delta(0);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn replayed_and_impostor_datagrams_are_counted() {
+    let jwks = JwksRig::start(1, "mh-replay").await;
+    let session_manager = SessionManagerHandle::new();
+    let jwt_validator = Arc::new(MhJwtValidator::new(jwks.jwks_client(), 300));
+    let rig = AcceptLoopRig::start(
+        jwt_validator,
+        session_manager.clone(),
+        Arc::new(McClient::new(test_token_receiver())),
+        "mh-replay-test".to_string(),
+    )
+    .await;
+
+    session_manager
+        .register_meeting(
+            "meeting-replay".to_string(),
+            MeetingRegistration {
+                mc_id: "mc-replay".to_string(),
+                mc_grpc_endpoint: "http://localhost:1".to_string(),
+                registered_at: Instant::now(),
+                layout_config: mh_service::compositor::LayoutConfig::default(),
+                e2e_enabled: false,
+            },
+        )
+        .await;
+
+    let token = mint_meeting_token(&jwks.keypair, "meeting-replay", "user-replay");
+    let (conn, mut send, _recv) = connect_and_open_bi(&rig.url).await;
+    write_mh_connect(&mut send, &token)
+        .await
+        .expect("failed to write MhClientMessage frame");
+
+    let stop = Instant::now() + Duration::from_secs(3);
+    while session_manager.active_connection_count().await != 1 {
+        assert!(
+            Instant::now() < stop,
+            "connection was not promoted within 3s"
+        );
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    let snap = MetricAssertion::snapshot();
+    let mut published = frame(1, FramePriority::Normal, false);
+    published.user_id = user_id_for_subject("user-replay");
+    let datagram = encode_frame(&published).expect("failed to encode frame");
+    conn.send_datagram(datagram.clone())
+        .expect("failed to send first datagram");
+    conn.send_datagram(datagram)
+        .expect("failed to send replayed datagram");
+    // Published under another participant's user_id
+    let impostor =
+        encode_frame(&frame(2, FramePriority::Normal, false)).expect("failed to encode frame");
+    conn.send_datagram(impostor)
+        .expect("failed to send impostor datagram");
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    snap.counter("mh_media_frames_replayed_total")
+        .with_labels(&[("reason", "duplicate")])
+        .assert_delta(1);
+    snap.counter("mh_media_frames_replayed_total")
+        .with_labels(&[("reason", "too_old")])
+        .assert_delta(0);
+    snap.counter("mh_media_frames_rejected_total")
+        .with_labels(&[("reason", "user_mismatch")])
+        .assert_delta(1);
+}
@@ -54,6 +54,8 @@ fn authed_request(
         meeting_id: meeting_id.to_string(),
         mc_id: mc_id.to_string(),
         mc_grpc_endpoint: mc_grpc_endpoint.to_string(),
+        recording_layout: 0,
+        e2e_enabled: false,
     });
     let value: MetadataValue<_> = format!("Bearer {token}")
         .parse()
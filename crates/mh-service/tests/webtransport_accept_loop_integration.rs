@@ -126,6 +126,8 @@ async fn accept_loop_emits_accepted_status_and_handshake_observation_on_happy_pa
                 mc_id: "mc-accept-ok".to_string(),
                 mc_grpc_endpoint: "http://localhost:1".to_string(),
                 registered_at: Instant::now(),
+                layout_config: mh_service::compositor::LayoutConfig::default(),
+                e2e_enabled: false,
             },
         )
         .await;
@@ -171,6 +173,8 @@ async fn accept_loop_emits_rejected_status_when_at_capacity() {
                 mc_id: "mc-rejected".to_string(),
                 mc_grpc_endpoint: "http://localhost:1".to_string(),
                 registered_at: Instant::now(),
+                layout_config: mh_service::compositor::LayoutConfig::default(),
+                e2e_enabled: false,
             },
         )
         .await;
@@ -239,3 +243,75 @@ async fn accept_loop_emits_error_status_when_handler_returns_err() {
         "no connection should have been promoted on handler error",
     );
 }
+
+// ---------------------------------------------------------------------------
+// Dual-stack bind
+// ---------------------------------------------------------------------------
+
+#[tokio::test(flavor = "current_thread")]
+async fn accept_loop_accepts_ipv4_and_ipv6_clients_on_dual_stack_endpoint() {
+    if std::net::UdpSocket::bind("[::1]:0").is_err() {
+        eprintln!("skipping: IPv6 unavailable");
+        return;
+    }
+    let jwks = JwksRig::start(4, "mh-accept-loop-dual-stack").await;
+    let session_manager = SessionManagerHandle::new();
+    let jwt_validator = Arc::new(MhJwtValidator::new(jwks.jwks_client(), 300));
+    let rig = AcceptLoopRig::start_on(
+        "[::]:0",
+        jwt_validator,
+        session_manager.clone(),
+        make_mc_client(),
+        "mh-accept-loop-test".to_string(),
+        8,
+        Duration::from_secs(30),
+    )
+    .await;
+
+    session_manager
+        .register_meeting(
+            "meeting-dual-stack".to_string(),
+            MeetingRegistration {
+                mc_id: "mc-dual-stack".to_string(),
+                mc_grpc_endpoint: "http://localhost:1".to_string(),
+                registered_at: Instant::now(),
+                layout_config: mh_service::compositor::LayoutConfig::default(),
+                e2e_enabled: false,
+            },
+        )
+        .await;
+
+    let port = rig.addr.port();
+    let v4_token = mint_meeting_token(&jwks.keypair, "meeting-dual-stack", "user-v4");
+    let v6_token = mint_meeting_token(&jwks.keypair, "meeting-dual-stack", "user-v6");
+    let _v4 = connect_and_send_jwt(&format!("https://127.0.0.1:{port}"), &v4_token).await;
+    let _v6 = connect_and_send_jwt(&format!("https://[::1]:{port}"), &v6_token).await;
+
+    assert!(
+        wait_for_active_count(&session_manager, 2, Duration::from_secs(3)).await,
+        "dual-stack endpoint did not promote both IPv4 and IPv6 connections within 3s",
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Media socket options recorded at bind
+// ---------------------------------------------------------------------------
+
+#[tokio::test(flavor = "current_thread")]
+async fn bind_records_media_socket_gauges() {
+    // `bind()` runs inline in `start_with`, so the gauges are set before the
+    // rig returns. Default config leaves packets unmarked; buffer sizes are
+    // whatever the kernel reports, which is never zero.
+    let jwks = JwksRig::start(5, "mh-accept-loop-media-socket").await;
+    let snap = MetricAssertion::snapshot();
+    let _rig = start_rig(SessionManagerHandle::new(), &jwks, 2).await;
+
+    snap.gauge("mh_media_socket_dscp").assert_value(0.0);
+    for direction in ["send", "recv"] {
+        snap.gauge("mh_media_socket_buffer_bytes")
+            .with_labels(&[("direction", direction)])
+            .assert_value_in_range(1.0..=f64::MAX);
+    }
+    snap.gauge("mh_media_socket_gso_segments")
+        .assert_value_in_range(1.0..=f64::MAX);
+}
@@ -63,6 +63,7 @@ mod test_common;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use common::media_token::{MediaTokenClaims, MediaTokenKey, MEDIA_TOKEN_TTL};
 use common::observability::testing::MetricAssertion;
 use mh_service::auth::MhJwtValidator;
 use mh_service::grpc::McClient;
@@ -77,7 +78,9 @@ use test_common::test_token_receiver;
 use test_common::tokens::{
     mint_expired_meeting_token, mint_meeting_token, mint_wrong_token_type_token,
 };
-use test_common::wt_client::{connect_and_open_bi, write_framed, write_mh_connect};
+use test_common::wt_client::{
+    connect_and_open_bi, write_framed, write_mh_connect, write_mh_connect_with_media_token,
+};
 
 // ---------------------------------------------------------------------------
 // Rig builder
@@ -173,6 +176,8 @@ async fn valid_meeting_jwt_connection_accepted_and_tracked() {
                 mc_id: "mc-wt-test".to_string(),
                 mc_grpc_endpoint: "http://localhost:1".to_string(),
                 registered_at: Instant::now(),
+                layout_config: mh_service::compositor::LayoutConfig::default(),
+                e2e_enabled: false,
             },
         )
         .await;
@@ -411,6 +416,8 @@ async fn wrong_token_type_guest_rejected_on_wt_accept_path() {
                 mc_id: "mc-wt-guest".to_string(),
                 mc_grpc_endpoint: "http://localhost:1".to_string(),
                 registered_at: Instant::now(),
+                layout_config: mh_service::compositor::LayoutConfig::default(),
+                e2e_enabled: false,
             },
         )
         .await;
@@ -443,6 +450,138 @@ async fn wrong_token_type_guest_rejected_on_wt_accept_path() {
     );
 }
 
+// ---------------------------------------------------------------------------
+// Media token enforcement on the accept path
+// ---------------------------------------------------------------------------
+
+/// Shared MC/MH secret for the media token tests.
+const MEDIA_TOKEN_SECRET: [u8; 32] = [7; 32];
+
+fn media_token_key() -> MediaTokenKey {
+    MediaTokenKey::from_secret(&MEDIA_TOKEN_SECRET).unwrap()
+}
+
+/// Mint the media token MC would return in the `JoinResponse`.
+fn mint_media_token(meeting_id: &str, sub: &str) -> String {
+    let exp = chrono::Utc::now().timestamp() + i64::try_from(MEDIA_TOKEN_TTL.as_secs()).unwrap();
+    media_token_key().sign(&MediaTokenClaims {
+        meeting_id: meeting_id.to_string(),
+        sub: sub.to_string(),
+        participant_id: "mc-participant".to_string(),
+        exp,
+    })
+}
+
+/// Start an accept loop that requires media tokens, with `meeting_id`
+/// already registered.
+async fn start_media_token_suite(meeting_id: &str) -> (JwksRig, AcceptLoopRig) {
+    let jwks = JwksRig::start(42, "mh-wt-media-01").await;
+    let session_manager = SessionManagerHandle::new();
+    session_manager
+        .register_meeting(
+            meeting_id.to_string(),
+            mh_service::session::MeetingRegistration {
+                mc_id: "mc-wt-media".to_string(),
+                mc_grpc_endpoint: "http://localhost:1".to_string(),
+                registered_at: Instant::now(),
+                layout_config: mh_service::compositor::LayoutConfig::default(),
+                e2e_enabled: false,
+            },
+        )
+        .await;
+    let wt = AcceptLoopRig::start_with_media_token_key(
+        Arc::new(MhJwtValidator::new(jwks.jwks_client(), 300)),
+        session_manager,
+        make_mc_client(),
+        "mh-test-001".to_string(),
+        Arc::new(media_token_key()),
+    )
+    .await;
+    (jwks, wt)
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn valid_media_token_connection_accepted() {
+    let (jwks, wt) = start_media_token_suite("meeting-wt-media").await;
+
+    let snap = MetricAssertion::snapshot();
+    let token = mint_meeting_token(&jwks.keypair, "meeting-wt-media", "user-media");
+    let media_token = mint_media_token("meeting-wt-media", "user-media");
+    let (_conn, mut send, _recv) = connect_and_open_bi(&wt.url).await;
+    write_mh_connect_with_media_token(&mut send, &token, &media_token)
+        .await
+        .expect("failed to write MhClientMessage frame");
+
+    assert!(
+        wait_for_active_count(&wt.session_manager, 1, Duration::from_secs(3)).await,
+        "connection with a valid media token was not registered within 3s",
+    );
+    snap.counter("mh_jwt_validations_total")
+        .with_labels(&[
+            ("result", "success"),
+            ("token_type", "media"),
+            ("failure_reason", "none"),
+        ])
+        .assert_delta(1);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn media_token_rejections_on_wt_accept_path() {
+    // A valid meeting JWT is not enough once media tokens are required: the
+    // client must also show it joined this meeting through MC, as this subject.
+    let cases = [
+        ("missing", String::new()),
+        (
+            "binding_mismatch",
+            mint_media_token("meeting-wt-other", "user-media"),
+        ),
+        (
+            "binding_mismatch",
+            mint_media_token("meeting-wt-media-reject", "user-other"),
+        ),
+        (
+            "signature_invalid",
+            MediaTokenKey::from_secret(&[8; 32])
+                .unwrap()
+                .sign(&MediaTokenClaims {
+                    meeting_id: "meeting-wt-media-reject".to_string(),
+                    sub: "user-media".to_string(),
+                    participant_id: "mc-participant".to_string(),
+                    exp: chrono::Utc::now().timestamp() + 60,
+                }),
+        ),
+    ];
+
+    for (reason, media_token) in cases {
+        let (jwks, wt) = start_media_token_suite("meeting-wt-media-reject").await;
+
+        let snap = MetricAssertion::snapshot();
+        let token = mint_meeting_token(&jwks.keypair, "meeting-wt-media-reject", "user-media");
+        let (_conn, mut send, _recv) = connect_and_open_bi(&wt.url).await;
+        write_mh_connect_with_media_token(&mut send, &token, &media_token)
+            .await
+            .expect("failed to write MhClientMessage frame");
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        snap.counter("mh_jwt_validations_total")
+            .with_labels(&[
+                ("result", "failure"),
+                ("token_type", "media"),
+                ("failure_reason", reason),
+            ])
+            .assert_delta(1);
+        snap.counter("mh_webtransport_connections_total")
+            .with_labels(&[("status", "error")])
+            .assert_delta(1);
+        assert_eq!(
+            wt.session_manager.active_connection_count().await,
+            0,
+            "connection without a valid media token ({reason}) reached the session manager",
+        );
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Provisional-timeout enforcement
 // ---------------------------------------------------------------------------
@@ -516,6 +655,8 @@ async fn provisional_connection_survives_when_register_meeting_arrives_within_wi
                 mc_id: "mc-wt-survive".to_string(),
                 mc_grpc_endpoint: "http://localhost:1".to_string(),
                 registered_at: Instant::now(),
+                layout_config: mh_service::compositor::LayoutConfig::default(),
+                e2e_enabled: false,
             },
         )
         .await;
@@ -562,6 +703,8 @@ async fn mc_notify_connected_fires_on_join_and_disconnected_fires_on_client_drop
                 mc_id: "mc-wt-notify".to_string(),
                 mc_grpc_endpoint: format!("http://{}", mc.addr),
                 registered_at: Instant::now(),
+                layout_config: mh_service::compositor::LayoutConfig::default(),
+                e2e_enabled: false,
             },
         )
         .await;
@@ -12,17 +12,28 @@
 // (`/usr/include/google/protobuf/`) — provided by the `libprotobuf-dev`
 // package in `infra/devloop/Dockerfile`.
 
+//
+// The build also exports `DT_PROTO_SCHEMA_HASH`, an FNV-1a fingerprint of the
+// `.proto` sources, as `proto_gen::SCHEMA_VERSION`. FNV keeps the build script
+// free of extra dependencies; it only needs to tell schemas apart.
+
+const PROTOS: &[&str] = &[
+    "../../proto/dark_tower/signaling/v1/signaling.proto",
+    "../../proto/dark_tower/internal/v1/internal.proto",
+];
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    tonic_build::configure().compile_protos(
-        &[
-            "../../proto/dark_tower/signaling/v1/signaling.proto",
-            "../../proto/dark_tower/internal/v1/internal.proto",
-        ],
-        &["../../proto/"],
-    )?;
+    tonic_build::configure().compile_protos(PROTOS, &["../../proto/"])?;
 
-    println!("cargo:rerun-if-changed=../../proto/dark_tower/signaling/v1/signaling.proto");
-    println!("cargo:rerun-if-changed=../../proto/dark_tower/internal/v1/internal.proto");
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for proto in PROTOS {
+        for byte in std::fs::read(proto)? {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        println!("cargo:rerun-if-changed={proto}");
+    }
+    println!("cargo:rustc-env=DT_PROTO_SCHEMA_HASH={hash:016x}");
 
     Ok(())
 }
@@ -23,6 +23,12 @@ pub use prost::Message;
 // Re-export tonic for gRPC service traits
 pub use tonic;
 
+/// Fingerprint of the `.proto` sources this crate was generated from.
+///
+/// Services report it from `/version` and `BuildInfoService.GetVersion`;
+/// two services with the same value were built against the same schema.
+pub const SCHEMA_VERSION: &str = env!("DT_PROTO_SCHEMA_HASH");
+
 // Generated protobuf modules — paths mirror proto package hierarchy.
 pub mod dark_tower {
     pub mod signaling {
@@ -285,7 +285,13 @@ build_image() {
     fi
     local OLD_IMAGE_ID
     OLD_IMAGE_ID=$(${CONTAINER_CMD} images -q "$TAG" 2>/dev/null || true)
-    ${CONTAINER_CMD} build -t "$TAG" -f "$DOCKERFILE" "$CONTEXT"
+    # Build metadata for /version (see common::build_info)
+    local GIT_SHA
+    GIT_SHA=$(git -C "$CONTEXT" rev-parse --short=12 HEAD 2>/dev/null || echo unknown)
+    ${CONTAINER_CMD} build -t "$TAG" -f "$DOCKERFILE" \
+        --build-arg DT_GIT_SHA="$GIT_SHA" \
+        --build-arg DT_BUILD_TIME="$(date -u +%Y-%m-%dT%H:%M:%SZ)" \
+        "$CONTEXT"
     if [ -n "$OLD_IMAGE_ID" ] && [ "$OLD_IMAGE_ID" != "$(${CONTAINER_CMD} images -q "$TAG")" ]; then
         ${CONTAINER_CMD} rmi "$OLD_IMAGE_ID" 2>/dev/null || true
     fi
@@ -447,6 +453,7 @@ seed_test_data() {
     # Credentials:
     #   global-controller / global-controller-secret-dev-001
     #   meeting-controller / meeting-controller-secret-dev-002
+    #   mc-0, mc-1 / meeting-controller-secret-dev-002 (one per MC instance)
     #   media-handler / media-handler-secret-dev-003
     #   test-client / test-client-secret-dev-999
 
@@ -454,8 +461,10 @@ seed_test_data() {
     ${KUBECTL} exec -n dark-tower postgres-0 -- psql -U darktower -d dark_tower -c "
 INSERT INTO service_credentials (client_id, client_secret_hash, service_type, region, scopes, is_active)
 VALUES
-    ('global-controller', '\$2b\$12\$Gcm3fKCVQzVeCKBkVumWeu9MpAqayxTo08p4aS7xScQTCK8Fi6nBu', 'global-controller', 'us-west-2', ARRAY['service.write.mc', 'internal:meeting-token'], true),
+    ('global-controller', '\$2b\$12\$Gcm3fKCVQzVeCKBkVumWeu9MpAqayxTo08p4aS7xScQTCK8Fi6nBu', 'global-controller', 'us-west-2', ARRAY['service.write.mc', 'internal:meeting-token', 'delegated:meeting.moderate'], true),
     ('meeting-controller', '\$2b\$12\$BX5OkdvGLfsj6eTM89qkGe/mPpU2nf2aAXDK7v5sedsndrwUmG6dm', 'meeting-controller', 'us-west-2', ARRAY['service.write.mh', 'service.write.gc'], true),
+    ('mc-0', '\$2b\$12\$BX5OkdvGLfsj6eTM89qkGe/mPpU2nf2aAXDK7v5sedsndrwUmG6dm', 'meeting-controller', 'us-west-2', ARRAY['service.write.mh', 'service.write.gc'], true),
+    ('mc-1', '\$2b\$12\$BX5OkdvGLfsj6eTM89qkGe/mPpU2nf2aAXDK7v5sedsndrwUmG6dm', 'meeting-controller', 'us-west-2', ARRAY['service.write.mh', 'service.write.gc'], true),
     ('media-handler', '\$2b\$12\$DpQDslp37I3UFi.IBC24NOCnMWcPKkdiDO96FEACLVoXqVyYEhyZa', 'media-handler', 'us-west-2', ARRAY['service.write.mc', 'service.write.gc'], true),
     ('test-client', '\$2b\$12\$DpBLvWIsdO2j3a8dhx0VwOd8kLdZ4/szjsuZVm.TX.z4fxjlWzOny', 'global-controller', NULL, ARRAY['test:all'], true)
 ON CONFLICT (client_id) DO UPDATE SET
@@ -607,6 +616,7 @@ create_mh_secrets() {
 
     ${KUBECTL} create secret generic mh-service-secrets \
         --from-literal=MH_CLIENT_SECRET="media-handler-secret-dev-003" \
+        --from-literal=MH_MEDIA_TOKEN_SECRET="ZGV2LW1lZGlhLXRva2VuLXNlY3JldC1jaGFuZ2UtaW4tcHJvZA==" \
         -n dark-tower \
         --dry-run=client -o yaml | ${KUBECTL} apply -f -
 
@@ -694,6 +704,7 @@ setup_port_forwards() {
     local PF_POSTGRES="${POSTGRES_PORT:-5432}"
     local PF_AC="${AC_HTTP_PORT:-8082}"
     local PF_GC="${GC_HTTP_PORT:-8080}"
+    local PF_MC="${MC_HEALTH_PORT:-8081}"
     local PF_MH="${MH_HEALTH_PORT:-8083}"
     local PF_PROMETHEUS="${PROMETHEUS_PORT:-9090}"
     local PF_GRAFANA="${GRAFANA_PORT:-3000}"
@@ -703,6 +714,7 @@ setup_port_forwards() {
     ${KUBECTL} port-forward -n dark-tower svc/postgres "${PF_POSTGRES}:5432" &>/dev/null &
     ${KUBECTL} port-forward -n dark-tower svc/ac-service "${PF_AC}:8082" &>/dev/null &
     ${KUBECTL} port-forward -n dark-tower svc/gc-service "${PF_GC}:8080" &>/dev/null &
+    ${KUBECTL} port-forward -n dark-tower svc/mc-service "${PF_MC}:8081" &>/dev/null &
     ${KUBECTL} port-forward -n dark-tower svc/mh-service "${PF_MH}:8083" &>/dev/null &
     ${KUBECTL} port-forward -n dark-tower-observability svc/prometheus "${PF_PROMETHEUS}:9090" &>/dev/null &
     ${KUBECTL} port-forward -n dark-tower-observability svc/grafana "${PF_GRAFANA}:3000" &>/dev/null &
@@ -716,6 +728,7 @@ setup_port_forwards() {
 print_access_info() {
     local p_ac="${AC_HTTP_PORT:-8082}"
     local p_gc="${GC_HTTP_PORT:-8080}"
+    local p_mc="${MC_HEALTH_PORT:-8081}"
     local p_mh="${MH_HEALTH_PORT:-8083}"
     local p_grafana="${GRAFANA_PORT:-3000}"
     local p_prometheus="${PROMETHEUS_PORT:-9090}"
@@ -744,7 +757,7 @@ print_access_info() {
     echo "      mc-service-0: https://localhost:4433"
     echo "      mc-service-1: https://localhost:4435"
     echo "    gRPC: localhost:50052 (cluster-internal)"
-    echo "    Health: localhost:8081 (cluster-internal)"
+    echo "    Health: http://localhost:${p_mc}"
     echo "    TLS: Self-signed (CA at infra/docker/certs/ca.crt)"
     echo ""
     echo "  MH Service (Media Handler) — 2 per-instance Deployments:"
@@ -775,7 +788,7 @@ print_access_info() {
     echo "OAuth 2.0 Service Credentials (pre-seeded, per ADR-0010):"
     echo ""
     echo "  global-controller / global-controller-secret-dev-001  (used by GC)"
-    echo "  meeting-controller / meeting-controller-secret-dev-002  (used by MC)"
+    echo "  mc-0, mc-1 / meeting-controller-secret-dev-002  (used by MC instances)"
     echo "  media-handler / media-handler-secret-dev-003  (used by MH)"
     echo "  test-client / test-client-secret-dev-999  (for testing)"
     echo ""
@@ -0,0 +1,32 @@
+-- Transactional outbox for GC meeting lifecycle events
+-- Rows are inserted in the same transaction as the meeting mutation they describe
+-- and published to the in-process event bus by the GC outbox relay task.
+-- Guarantees no lost events when GC crashes between commit and notify.
+
+CREATE TABLE IF NOT EXISTS event_outbox (
+    outbox_id BIGSERIAL PRIMARY KEY,
+    event_type VARCHAR(64) NOT NULL,
+    meeting_id UUID NOT NULL,
+    payload JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    published_at TIMESTAMPTZ
+);
+
+-- Relay scans unpublished rows in insertion order
+CREATE INDEX IF NOT EXISTS idx_event_outbox_unpublished
+ON event_outbox(outbox_id) WHERE published_at IS NULL;
+
+-- Retention cleanup scans published rows by publish time
+CREATE INDEX IF NOT EXISTS idx_event_outbox_published_at
+ON event_outbox(published_at) WHERE published_at IS NOT NULL;
+
+-- Comments for documentation
+COMMENT ON TABLE event_outbox IS 'Transactional outbox of GC meeting lifecycle events awaiting relay';
+COMMENT ON COLUMN event_outbox.event_type IS 'Bounded event name (meeting_created, meeting_settings_updated, ...)';
+COMMENT ON COLUMN event_outbox.payload IS 'Serialized GcEvent (internally tagged by "type")';
+COMMENT ON COLUMN event_outbox.published_at IS 'When the relay published the event (NULL = pending)';
+
+-- DOWN migration (manual rollback):
+-- DROP INDEX IF EXISTS idx_event_outbox_published_at;
+-- DROP INDEX IF EXISTS idx_event_outbox_unpublished;
+-- DROP TABLE IF EXISTS event_outbox;
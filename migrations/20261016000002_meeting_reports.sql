@@ -0,0 +1,38 @@
+-- Post-meeting analytics rollups
+-- MCs report per-region telemetry with NotifyMeetingEnded; it is stored on the
+-- assignment row and rolled up into meeting_reports by the GC report task once
+-- every regional assignment for the meeting has ended.
+
+-- Per-region telemetry reported by the MC at meeting end (NULL = not reported)
+ALTER TABLE meeting_assignments ADD COLUMN IF NOT EXISTS peak_participants INTEGER;
+ALTER TABLE meeting_assignments ADD COLUMN IF NOT EXISTS join_failures INTEGER;
+ALTER TABLE meeting_assignments ADD COLUMN IF NOT EXISTS avg_qoe_score REAL;
+
+CREATE TABLE IF NOT EXISTS meeting_reports (
+    meeting_id UUID PRIMARY KEY REFERENCES meetings(meeting_id) ON DELETE CASCADE,
+    started_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ NOT NULL,
+    duration_seconds BIGINT NOT NULL,
+    peak_participants INTEGER NOT NULL DEFAULT 0,
+    total_participants INTEGER NOT NULL DEFAULT 0,
+    join_failures INTEGER NOT NULL DEFAULT 0,
+    avg_qoe_score DOUBLE PRECISION,
+    computed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Comments for documentation
+COMMENT ON COLUMN meeting_assignments.peak_participants IS 'Max concurrent participants reported by the MC at meeting end';
+COMMENT ON COLUMN meeting_assignments.join_failures IS 'Failed join attempts reported by the MC at meeting end';
+COMMENT ON COLUMN meeting_assignments.avg_qoe_score IS 'Average QoE score (0.0-5.0) reported by the MC at meeting end';
+COMMENT ON TABLE meeting_reports IS 'Post-meeting analytics rollups computed by the GC report task';
+COMMENT ON COLUMN meeting_reports.started_at IS 'Earliest regional assignment time';
+COMMENT ON COLUMN meeting_reports.ended_at IS 'Latest regional assignment end time';
+COMMENT ON COLUMN meeting_reports.peak_participants IS 'Sum of per-region peak concurrent participants';
+COMMENT ON COLUMN meeting_reports.total_participants IS 'Participant sessions recorded for the meeting (rejoins count separately)';
+COMMENT ON COLUMN meeting_reports.avg_qoe_score IS 'Mean of per-region QoE scores (NULL = no samples)';
+
+-- DOWN migration (manual rollback):
+-- DROP TABLE IF EXISTS meeting_reports;
+-- ALTER TABLE meeting_assignments DROP COLUMN IF EXISTS avg_qoe_score;
+-- ALTER TABLE meeting_assignments DROP COLUMN IF EXISTS join_failures;
+-- ALTER TABLE meeting_assignments DROP COLUMN IF EXISTS peak_participants;
@@ -0,0 +1,35 @@
+-- Per-participant attendance records
+-- MCs track join/leave times for each participant session and flush them to GC
+-- via ReportAttendance when a meeting ends. Hosts export them as CSV.
+
+CREATE TABLE IF NOT EXISTS meeting_attendance (
+    attendance_id BIGSERIAL PRIMARY KEY,
+    meeting_id UUID NOT NULL REFERENCES meetings(meeting_id) ON DELETE CASCADE,
+    participant_id VARCHAR(255) NOT NULL,
+    user_id VARCHAR(255) NOT NULL,
+    display_name VARCHAR(255) NOT NULL,
+    joined_at TIMESTAMPTZ NOT NULL,
+    left_at TIMESTAMPTZ NOT NULL,
+    leave_reason VARCHAR(32) NOT NULL,
+    region VARCHAR(50) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT valid_attendance_interval CHECK (left_at >= joined_at),
+    CONSTRAINT valid_attendance_leave_reason CHECK (leave_reason IN ('voluntary', 'timeout', 'removed', 'meeting_ended')),
+    -- MC retries of the same flush must not duplicate sessions
+    CONSTRAINT meeting_attendance_session_unique UNIQUE (meeting_id, participant_id, joined_at)
+);
+
+-- Export reads a meeting's sessions in join order
+CREATE INDEX IF NOT EXISTS idx_meeting_attendance_meeting
+ON meeting_attendance(meeting_id, joined_at);
+
+-- Comments for documentation
+COMMENT ON TABLE meeting_attendance IS 'Per-participant join/leave sessions reported by MCs at meeting end';
+COMMENT ON COLUMN meeting_attendance.user_id IS 'Join token subject (guest ID for anonymous guests)';
+COMMENT ON COLUMN meeting_attendance.leave_reason IS 'Why the session ended: voluntary, timeout, removed, or meeting_ended';
+COMMENT ON COLUMN meeting_attendance.region IS 'Region of the MC that hosted the session';
+
+-- DOWN migration (manual rollback):
+-- DROP INDEX IF EXISTS idx_meeting_attendance_meeting;
+-- DROP TABLE IF EXISTS meeting_attendance;
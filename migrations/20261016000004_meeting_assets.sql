@@ -0,0 +1,31 @@
+-- Meeting asset metadata
+-- Clients upload files directly to the object store using pre-signed URLs
+-- issued by GC and share them through data channels. GC records who
+-- requested each upload so access can be audited and objects cleaned up.
+
+CREATE TABLE IF NOT EXISTS meeting_assets (
+    asset_id UUID PRIMARY KEY,
+    meeting_id UUID NOT NULL REFERENCES meetings(meeting_id) ON DELETE CASCADE,
+    uploaded_by_user_id UUID NOT NULL,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(127) NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    object_key VARCHAR(512) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT valid_asset_size CHECK (size_bytes > 0)
+);
+
+-- Assets are listed and cleaned up per meeting
+CREATE INDEX IF NOT EXISTS idx_meeting_assets_meeting
+ON meeting_assets(meeting_id, created_at);
+
+-- Comments for documentation
+COMMENT ON TABLE meeting_assets IS 'Files uploaded to the object store for a meeting via pre-signed URLs';
+COMMENT ON COLUMN meeting_assets.uploaded_by_user_id IS 'User who requested the upload URL (not a foreign key; external participants belong to other orgs)';
+COMMENT ON COLUMN meeting_assets.size_bytes IS 'Declared size at request time; the upload itself is not verified by GC';
+COMMENT ON COLUMN meeting_assets.object_key IS 'Object store key: meetings/{meeting_id}/{asset_id}';
+
+-- DOWN migration (manual rollback):
+-- DROP INDEX IF EXISTS idx_meeting_assets_meeting;
+-- DROP TABLE IF EXISTS meeting_assets;
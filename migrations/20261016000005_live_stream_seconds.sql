@@ -0,0 +1,15 @@
+-- Live streaming time in meeting reports
+-- MCs include the time a meeting was live streamed (MH egress) in their
+-- ReportAttendance flush. It is stored per region on the assignment row and
+-- summed into meeting_reports by the GC report task.
+
+ALTER TABLE meeting_assignments ADD COLUMN IF NOT EXISTS live_stream_seconds BIGINT;
+ALTER TABLE meeting_reports ADD COLUMN IF NOT EXISTS live_stream_seconds BIGINT NOT NULL DEFAULT 0;
+
+-- Comments for documentation
+COMMENT ON COLUMN meeting_assignments.live_stream_seconds IS 'Seconds the meeting was live streamed from this region (NULL = not reported)';
+COMMENT ON COLUMN meeting_reports.live_stream_seconds IS 'Sum of per-region live streaming time';
+
+-- DOWN migration (manual rollback):
+-- ALTER TABLE meeting_reports DROP COLUMN IF EXISTS live_stream_seconds;
+-- ALTER TABLE meeting_assignments DROP COLUMN IF EXISTS live_stream_seconds;
@@ -0,0 +1,51 @@
+-- Meeting recording metadata
+-- The MH recorder uploads the composited recording to the object store and
+-- then reports it to GC (ReportRecording RPC). Hosts list recordings and get
+-- pre-signed download URLs from GC. Deleting a recording hides it at once
+-- (deleted_at); the GC retention task removes the object and the row, and
+-- also expires recordings older than GC_RECORDING_RETENTION_DAYS.
+
+CREATE TABLE IF NOT EXISTS meeting_recordings (
+    recording_id UUID PRIMARY KEY,
+    meeting_id UUID NOT NULL REFERENCES meetings(meeting_id) ON DELETE CASCADE,
+    media_handler_id VARCHAR(255) NOT NULL,
+    object_key VARCHAR(512) NOT NULL UNIQUE,
+    content_type VARCHAR(127) NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    duration_seconds BIGINT NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL,
+    completed_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    deleted_by_user_id UUID,
+
+    CONSTRAINT valid_recording_size CHECK (size_bytes > 0),
+    CONSTRAINT valid_recording_duration CHECK (duration_seconds >= 0),
+    CONSTRAINT valid_recording_times CHECK (completed_at >= started_at)
+);
+
+-- Hosts list a meeting's visible recordings in start order
+CREATE INDEX IF NOT EXISTS idx_meeting_recordings_meeting
+ON meeting_recordings(meeting_id, started_at)
+WHERE deleted_at IS NULL;
+
+-- Retention task scans by age and for pending deletions
+CREATE INDEX IF NOT EXISTS idx_meeting_recordings_completed
+ON meeting_recordings(completed_at);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_recordings_deleted
+ON meeting_recordings(deleted_at)
+WHERE deleted_at IS NOT NULL;
+
+-- Comments for documentation
+COMMENT ON TABLE meeting_recordings IS 'Recordings reported by MH recorders after upload to the object store';
+COMMENT ON COLUMN meeting_recordings.recording_id IS 'Chosen by the recorder; repeated reports of the same recording are ignored';
+COMMENT ON COLUMN meeting_recordings.object_key IS 'Object store key: recordings/{meeting_id}/...';
+COMMENT ON COLUMN meeting_recordings.deleted_at IS 'Set when a host deletes the recording; the object is removed by the retention task';
+COMMENT ON COLUMN meeting_recordings.deleted_by_user_id IS 'Host who deleted the recording (not a foreign key, mirrors audit_logs)';
+
+-- DOWN migration (manual rollback):
+-- DROP INDEX IF EXISTS idx_meeting_recordings_deleted;
+-- DROP INDEX IF EXISTS idx_meeting_recordings_completed;
+-- DROP INDEX IF EXISTS idx_meeting_recordings_meeting;
+-- DROP TABLE IF EXISTS meeting_recordings;
@@ -0,0 +1,23 @@
+-- Organization recording consent policy
+-- GC sends the policy to the MC with each meeting assignment. When a meeting
+-- starts recording, the MC notifies every participant; with 'require_ack'
+-- participants who do not accept within the timeout are removed, with
+-- 'notify' the notice alone is sufficient.
+
+ALTER TABLE organizations ADD COLUMN IF NOT EXISTS recording_consent_mode VARCHAR(20) NOT NULL DEFAULT 'require_ack';
+ALTER TABLE organizations ADD COLUMN IF NOT EXISTS recording_consent_timeout_seconds INTEGER NOT NULL DEFAULT 60;
+
+ALTER TABLE organizations ADD CONSTRAINT valid_recording_consent_mode
+    CHECK (recording_consent_mode IN ('notify', 'require_ack'));
+ALTER TABLE organizations ADD CONSTRAINT valid_recording_consent_timeout
+    CHECK (recording_consent_timeout_seconds BETWEEN 10 AND 600);
+
+-- Comments for documentation
+COMMENT ON COLUMN organizations.recording_consent_mode IS 'notify = notice only, require_ack = participants must accept or are removed';
+COMMENT ON COLUMN organizations.recording_consent_timeout_seconds IS 'Time a participant has to accept recording before removal (require_ack only)';
+
+-- DOWN migration (manual rollback):
+-- ALTER TABLE organizations DROP CONSTRAINT IF EXISTS valid_recording_consent_timeout;
+-- ALTER TABLE organizations DROP CONSTRAINT IF EXISTS valid_recording_consent_mode;
+-- ALTER TABLE organizations DROP COLUMN IF EXISTS recording_consent_timeout_seconds;
+-- ALTER TABLE organizations DROP COLUMN IF EXISTS recording_consent_mode;
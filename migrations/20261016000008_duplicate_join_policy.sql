@@ -0,0 +1,26 @@
+-- Per-meeting duplicate join policy
+-- GC sends the policy to the MC with each meeting assignment. It decides what
+-- happens when a user who is already in the meeting joins again from another
+-- device or tab: 'takeover' closes the earlier connection, 'reject' refuses
+-- the new join, 'allow' keeps both as separate participants.
+
+ALTER TABLE meetings ADD COLUMN IF NOT EXISTS duplicate_join_policy VARCHAR(20) NOT NULL DEFAULT 'takeover';
+
+ALTER TABLE meetings ADD CONSTRAINT valid_duplicate_join_policy
+    CHECK (duplicate_join_policy IN ('allow', 'takeover', 'reject'));
+
+-- Sessions closed by a takeover are reported as 'replaced'
+ALTER TABLE meeting_attendance DROP CONSTRAINT IF EXISTS valid_attendance_leave_reason;
+ALTER TABLE meeting_attendance ADD CONSTRAINT valid_attendance_leave_reason
+    CHECK (leave_reason IN ('voluntary', 'timeout', 'removed', 'meeting_ended', 'replaced'));
+
+-- Comments for documentation
+COMMENT ON COLUMN meetings.duplicate_join_policy IS 'Same user joining twice: takeover = close earlier connection, reject = refuse new join, allow = keep both';
+COMMENT ON COLUMN meeting_attendance.leave_reason IS 'Why the session ended: voluntary, timeout, removed, meeting_ended, or replaced';
+
+-- DOWN migration (manual rollback):
+-- ALTER TABLE meeting_attendance DROP CONSTRAINT IF EXISTS valid_attendance_leave_reason;
+-- ALTER TABLE meeting_attendance ADD CONSTRAINT valid_attendance_leave_reason
+--     CHECK (leave_reason IN ('voluntary', 'timeout', 'removed', 'meeting_ended'));
+-- ALTER TABLE meetings DROP CONSTRAINT IF EXISTS valid_duplicate_join_policy;
+-- ALTER TABLE meetings DROP COLUMN IF EXISTS duplicate_join_policy;
@@ -0,0 +1,57 @@
+-- Operational directives for Meeting Controllers
+-- Operators insert a row to change a running MC without restarting it. GC
+-- hands pending directives to the MC in its next heartbeat stream ack and
+-- stamps delivered_at, so the table doubles as the audit trail of what was
+-- asked of which MC, by whom, and when it reached the MC.
+--
+-- kind and its columns:
+--   'heartbeat_intervals' - fast_heartbeat_interval_ms and/or
+--                           comprehensive_heartbeat_interval_ms (NULL = unchanged)
+--   'soft_limits'         - max_meetings and/or max_participants below the
+--                           MC's configured limits (NULL = configured limit;
+--                           use 'drain' rather than a limit of 0)
+--   'drain'               - draining (true stops new meetings, false resumes)
+--   'end_meeting'         - meeting_id, optional reason
+
+CREATE TABLE IF NOT EXISTS mc_directives (
+    directive_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    controller_id VARCHAR(255) NOT NULL REFERENCES meeting_controllers(controller_id) ON DELETE CASCADE,
+    kind VARCHAR(32) NOT NULL,
+    fast_heartbeat_interval_ms BIGINT,
+    comprehensive_heartbeat_interval_ms BIGINT,
+    max_meetings INTEGER,
+    max_participants INTEGER,
+    draining BOOLEAN,
+    meeting_id VARCHAR(255),
+    reason VARCHAR(255),
+    created_by VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMPTZ,
+
+    CONSTRAINT valid_mc_directive_kind
+        CHECK (kind IN ('heartbeat_intervals', 'soft_limits', 'drain', 'end_meeting')),
+    CONSTRAINT valid_mc_directive_fields CHECK (
+        (kind = 'heartbeat_intervals'
+            AND COALESCE(fast_heartbeat_interval_ms, comprehensive_heartbeat_interval_ms) IS NOT NULL
+            AND COALESCE(fast_heartbeat_interval_ms, 1) > 0
+            AND COALESCE(comprehensive_heartbeat_interval_ms, 1) > 0)
+        OR (kind = 'soft_limits'
+            AND COALESCE(max_meetings, 1) > 0
+            AND COALESCE(max_participants, 1) > 0)
+        OR (kind = 'drain' AND draining IS NOT NULL)
+        OR (kind = 'end_meeting' AND meeting_id IS NOT NULL)
+    )
+);
+
+-- GC looks up an MC's undelivered directives on every stream heartbeat
+CREATE INDEX IF NOT EXISTS idx_mc_directives_pending
+ON mc_directives(controller_id, created_at)
+WHERE delivered_at IS NULL;
+
+-- Comments for documentation
+COMMENT ON TABLE mc_directives IS 'Operational directives pushed to MCs on the heartbeat stream; also the audit trail of them';
+COMMENT ON COLUMN mc_directives.created_by IS 'Operator (or tool) that issued the directive';
+COMMENT ON COLUMN mc_directives.delivered_at IS 'When GC sent the directive to the MC; NULL while pending';
+
+-- DOWN migration (manual rollback):
+-- DROP TABLE IF EXISTS mc_directives;
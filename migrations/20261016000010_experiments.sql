@@ -0,0 +1,47 @@
+-- A/B experiments bucketed at meeting join
+-- GC hashes each joiner into one of an experiment's variants (SHA-256 of the
+-- experiment salt and the org or user ID), returns the variants in the join
+-- response, and records them so analysis can join on (experiment, variant).
+-- Changing an experiment's salt reshuffles everyone; add a new experiment
+-- instead of editing variants of a running one.
+
+CREATE TABLE IF NOT EXISTS experiments (
+    name VARCHAR(64) PRIMARY KEY,
+    salt VARCHAR(64) NOT NULL,
+    unit VARCHAR(16) NOT NULL,
+    variants TEXT[] NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT true,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT valid_experiment_unit CHECK (unit IN ('org', 'user')),
+    CONSTRAINT valid_experiment_variants CHECK (cardinality(variants) > 0)
+);
+
+CREATE TABLE IF NOT EXISTS experiment_assignments (
+    experiment VARCHAR(64) NOT NULL REFERENCES experiments(name) ON DELETE CASCADE,
+    meeting_id UUID NOT NULL REFERENCES meetings(meeting_id) ON DELETE CASCADE,
+    subject_id VARCHAR(255) NOT NULL,
+    subject_type VARCHAR(16) NOT NULL,
+    variant TEXT NOT NULL,
+    assigned_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT valid_experiment_subject_type CHECK (subject_type IN ('user', 'guest')),
+    -- Rejoins keep the first assignment
+    PRIMARY KEY (experiment, meeting_id, subject_id)
+);
+
+-- Analysis reads one experiment's assignments by variant
+CREATE INDEX IF NOT EXISTS idx_experiment_assignments_variant
+ON experiment_assignments(experiment, variant);
+
+-- Comments for documentation
+COMMENT ON TABLE experiments IS 'A/B experiments; enabled ones are bucketed on every meeting join';
+COMMENT ON COLUMN experiments.unit IS 'What is bucketed: org (the meeting''s organization, so a meeting agrees) or user (each joiner)';
+COMMENT ON COLUMN experiments.variants IS 'Variant names, equally weighted; repeat a name to weight it';
+COMMENT ON TABLE experiment_assignments IS 'Variant each joiner got per meeting, the analysis key for experiments';
+COMMENT ON COLUMN experiment_assignments.subject_id IS 'User ID, or guest ID for anonymous guests';
+
+-- DOWN migration (manual rollback):
+-- DROP INDEX IF EXISTS idx_experiment_assignments_variant;
+-- DROP TABLE IF EXISTS experiment_assignments;
+-- DROP TABLE IF EXISTS experiments;
@@ -0,0 +1,28 @@
+-- Warm standby MCs
+-- An MC registered with standby_for is the warm standby of that primary. GC
+-- assigns it no meetings; each heartbeat ack lists the primary's active
+-- meetings so the standby can pre-load their journals from Redis. When the
+-- primary's heartbeat stream breaks and its gRPC endpoint does not answer,
+-- GC promotes the standby: it takes the meetings over at a bumped fencing
+-- generation, GC moves their assignments, and standby_for is cleared so the
+-- standby serves meetings like any other MC from then on.
+
+ALTER TABLE meeting_controllers
+ADD COLUMN IF NOT EXISTS standby_for VARCHAR(255);
+
+-- No foreign key: a standby may register before its primary
+ALTER TABLE meeting_controllers
+ADD CONSTRAINT standby_not_self CHECK (standby_for IS NULL OR standby_for <> controller_id);
+
+-- Standby lookup by primary on failover, and standby exclusion from load balancing
+CREATE INDEX IF NOT EXISTS idx_meeting_controllers_standby_for
+ON meeting_controllers(standby_for)
+WHERE standby_for IS NOT NULL;
+
+-- Comments for documentation
+COMMENT ON COLUMN meeting_controllers.standby_for IS 'Primary MC this one is a warm standby for (NULL = takes meetings itself); cleared on promotion';
+
+-- DOWN migration (manual rollback):
+-- DROP INDEX IF EXISTS idx_meeting_controllers_standby_for;
+-- ALTER TABLE meeting_controllers DROP CONSTRAINT IF EXISTS standby_not_self;
+-- ALTER TABLE meeting_controllers DROP COLUMN IF EXISTS standby_for;
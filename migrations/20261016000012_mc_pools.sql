@@ -0,0 +1,25 @@
+-- MC capacity pools
+-- Each MC registers into a pool ('default' unless configured). An
+-- organization with mc_pool set has its meetings placed on MCs in that pool,
+-- falling back to the default pool when the dedicated pool has no capacity;
+-- meetings of other organizations are only placed on default-pool MCs, so
+-- dedicated capacity is never shared.
+
+ALTER TABLE meeting_controllers
+ADD COLUMN IF NOT EXISTS pool VARCHAR(64) NOT NULL DEFAULT 'default';
+
+ALTER TABLE organizations
+ADD COLUMN IF NOT EXISTS mc_pool VARCHAR(64);
+
+-- Candidate selection filters by region and pool
+CREATE INDEX IF NOT EXISTS idx_meeting_controllers_region_pool
+ON meeting_controllers(region, pool);
+
+-- Comments for documentation
+COMMENT ON COLUMN meeting_controllers.pool IS 'Capacity pool the MC registered into (default = shared capacity)';
+COMMENT ON COLUMN organizations.mc_pool IS 'Dedicated MC pool for this organization''s meetings (NULL = default pool)';
+
+-- DOWN migration (manual rollback):
+-- DROP INDEX IF EXISTS idx_meeting_controllers_region_pool;
+-- ALTER TABLE organizations DROP COLUMN IF EXISTS mc_pool;
+-- ALTER TABLE meeting_controllers DROP COLUMN IF EXISTS pool;
@@ -0,0 +1,19 @@
+-- Cordoning MCs and MHs for maintenance
+-- A cordoned controller or handler takes no new meeting assignments, while
+-- the meetings it already serves run to completion. Operators cordon and
+-- uncordon through the GC admin API; re-registration leaves the flag alone,
+-- so a restart during a maintenance window stays cordoned.
+
+ALTER TABLE meeting_controllers
+ADD COLUMN IF NOT EXISTS cordoned_at TIMESTAMPTZ;
+
+ALTER TABLE media_handlers
+ADD COLUMN IF NOT EXISTS cordoned_at TIMESTAMPTZ;
+
+-- Comments for documentation
+COMMENT ON COLUMN meeting_controllers.cordoned_at IS 'When the MC was cordoned (NULL = takes new meetings)';
+COMMENT ON COLUMN media_handlers.cordoned_at IS 'When the MH was cordoned (NULL = takes new meetings)';
+
+-- DOWN migration (manual rollback):
+-- ALTER TABLE media_handlers DROP COLUMN IF EXISTS cordoned_at;
+-- ALTER TABLE meeting_controllers DROP COLUMN IF EXISTS cordoned_at;
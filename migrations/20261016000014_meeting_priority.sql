@@ -0,0 +1,19 @@
+-- Meeting priority classes
+-- Set at creation and used by GC when placing the meeting on an MC. Under
+-- capacity pressure lower priorities stop being placed on busy MCs first,
+-- leaving the headroom to higher ones: 'test' meetings go first, then
+-- 'standard'; 'webinar' meetings may use any capacity. Meetings that find no
+-- MC with headroom are placed in the 'spillover' MC pool, or rejected when it
+-- is full too.
+
+ALTER TABLE meetings ADD COLUMN IF NOT EXISTS priority VARCHAR(20) NOT NULL DEFAULT 'standard';
+
+ALTER TABLE meetings ADD CONSTRAINT valid_meeting_priority
+    CHECK (priority IN ('webinar', 'standard', 'test'));
+
+-- Comments for documentation
+COMMENT ON COLUMN meetings.priority IS 'Placement priority under capacity pressure: webinar > standard > test';
+
+-- DOWN migration (manual rollback):
+-- ALTER TABLE meetings DROP CONSTRAINT IF EXISTS valid_meeting_priority;
+-- ALTER TABLE meetings DROP COLUMN IF EXISTS priority;
@@ -0,0 +1,58 @@
+-- Bulk meeting import
+-- Org admins migrating from another platform submit a CSV or JSON list of
+-- meetings. GC validates every record up front and stores them on an import
+-- job. The GC import worker claims running jobs and creates all of a job's
+-- meetings in one transaction: a job either creates every meeting or none,
+-- and a job interrupted by a GC restart is simply picked up again. Imported
+-- meetings keep a link to their job and the caller's external ID so the job
+-- status can map old meetings to new meeting codes.
+
+CREATE TABLE IF NOT EXISTS meeting_import_jobs (
+    job_id UUID PRIMARY KEY,
+    org_id UUID NOT NULL REFERENCES organizations(org_id) ON DELETE CASCADE,
+    created_by_user_id UUID NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'running',
+    total_records INTEGER NOT NULL,
+    records JSONB,
+    imported_records INTEGER NOT NULL DEFAULT 0,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ,
+
+    CONSTRAINT valid_meeting_import_status CHECK (status IN ('running', 'succeeded', 'failed')),
+    CONSTRAINT valid_meeting_import_counts
+        CHECK (total_records > 0 AND imported_records >= 0 AND imported_records <= total_records)
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_import_jobs_org
+ON meeting_import_jobs(org_id, created_at);
+
+-- Import worker claims the oldest running job
+CREATE INDEX IF NOT EXISTS idx_meeting_import_jobs_running
+ON meeting_import_jobs(created_at)
+WHERE status = 'running';
+
+ALTER TABLE meetings ADD COLUMN IF NOT EXISTS import_job_id UUID
+    REFERENCES meeting_import_jobs(job_id) ON DELETE SET NULL;
+ALTER TABLE meetings ADD COLUMN IF NOT EXISTS import_external_id VARCHAR(255);
+
+-- Job status lists the meetings an import created
+CREATE INDEX IF NOT EXISTS idx_meetings_import_job
+ON meetings(import_job_id)
+WHERE import_job_id IS NOT NULL;
+
+-- Comments for documentation
+COMMENT ON TABLE meeting_import_jobs IS 'Bulk meeting imports; each job creates all of its meetings or none';
+COMMENT ON COLUMN meeting_import_jobs.created_by_user_id IS 'Org admin who submitted the import; owns the imported meetings';
+COMMENT ON COLUMN meeting_import_jobs.records IS 'Validated records awaiting import; cleared when the job finishes';
+COMMENT ON COLUMN meeting_import_jobs.error IS 'Why a failed import created nothing';
+COMMENT ON COLUMN meetings.import_job_id IS 'Import job that created the meeting, NULL for meetings created via the API';
+COMMENT ON COLUMN meetings.import_external_id IS 'Meeting ID on the source platform, supplied by the importer';
+
+-- DOWN migration (manual rollback):
+-- DROP INDEX IF EXISTS idx_meetings_import_job;
+-- ALTER TABLE meetings DROP COLUMN IF EXISTS import_external_id;
+-- ALTER TABLE meetings DROP COLUMN IF EXISTS import_job_id;
+-- DROP INDEX IF EXISTS idx_meeting_import_jobs_running;
+-- DROP INDEX IF EXISTS idx_meeting_import_jobs_org;
+-- DROP TABLE IF EXISTS meeting_import_jobs;
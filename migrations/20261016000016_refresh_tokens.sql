@@ -0,0 +1,34 @@
+-- Refresh tokens for AC token issuance
+-- A refresh token lets a service or user get a new access token without
+-- presenting credentials again. Tokens rotate on use: redeeming one marks it
+-- used and issues a successor in the same family. Presenting a used token
+-- again means it leaked, so the whole family is revoked.
+-- Only a SHA-256 hash of each token is stored.
+
+CREATE TABLE IF NOT EXISTS refresh_tokens (
+    token_id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    token_hash VARCHAR(64) UNIQUE NOT NULL,
+    family_id UUID NOT NULL,
+    credential_id UUID REFERENCES service_credentials(credential_id) ON DELETE CASCADE,
+    user_id UUID REFERENCES users(user_id) ON DELETE CASCADE,
+    scopes TEXT[] NOT NULL DEFAULT '{}',
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT refresh_token_has_one_subject CHECK ((credential_id IS NULL) <> (user_id IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_family ON refresh_tokens(family_id);
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_expires_at ON refresh_tokens(expires_at);
+
+-- Comments for documentation
+COMMENT ON TABLE refresh_tokens IS 'Rotating refresh tokens for service and user access tokens';
+COMMENT ON COLUMN refresh_tokens.token_hash IS 'Hex SHA-256 of the refresh token; the token itself is never stored';
+COMMENT ON COLUMN refresh_tokens.family_id IS 'Rotation chain; reuse of a used token revokes every token in the family';
+COMMENT ON COLUMN refresh_tokens.used_at IS 'When the token was redeemed (NULL = unused)';
+COMMENT ON COLUMN refresh_tokens.revoked_at IS 'When the family was revoked after reuse (NULL = not revoked)';
+
+-- DOWN migration (manual rollback):
+-- DROP TABLE IF EXISTS refresh_tokens;
@@ -0,0 +1,10 @@
+-- Audit token validation failures in auth_events
+-- A token that fails validation has no trusted subject, so these events may
+-- have neither a user nor a credential, like the key lifecycle events.
+
+ALTER TABLE auth_events DROP CONSTRAINT IF EXISTS event_has_subject;
+ALTER TABLE auth_events ADD CONSTRAINT event_has_subject CHECK (
+    user_id IS NOT NULL OR credential_id IS NOT NULL OR event_type IN (
+        'key_generated', 'key_rotated', 'key_expired', 'token_validation_failed'
+    )
+);
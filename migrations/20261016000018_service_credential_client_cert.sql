@@ -0,0 +1,20 @@
+-- Client certificate pinning for service credentials
+-- When AC runs with AC_MTLS_MODE=optional or required, a service client can
+-- authenticate with a TLS client certificate instead of (or as well as) its
+-- client_secret. The certificate is matched by the SHA-256 of its
+-- SubjectPublicKeyInfo, so a renewed certificate for the same key keeps
+-- working without an update here.
+
+ALTER TABLE service_credentials
+    ADD COLUMN IF NOT EXISTS client_cert_spki_sha256 VARCHAR(64);
+
+ALTER TABLE service_credentials
+    ADD CONSTRAINT service_credentials_client_cert_spki_sha256_format
+    CHECK (client_cert_spki_sha256 IS NULL OR client_cert_spki_sha256 ~ '^[0-9a-f]{64}$');
+
+-- One key identifies at most one credential
+CREATE UNIQUE INDEX IF NOT EXISTS idx_service_credentials_client_cert
+    ON service_credentials(client_cert_spki_sha256)
+    WHERE client_cert_spki_sha256 IS NOT NULL;
+
+COMMENT ON COLUMN service_credentials.client_cert_spki_sha256 IS 'Lowercase hex SHA-256 of the pinned client certificate SubjectPublicKeyInfo (NULL = no certificate)';
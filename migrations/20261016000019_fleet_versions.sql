@@ -0,0 +1,28 @@
+-- Fleet version tracking
+-- MCs and MHs report the build they run when they register. GC stores it
+-- with the registration so operators can see version skew during a rolling
+-- deploy (admin API and the gc_fleet_versions gauge), and can refuse
+-- registrations older than GC_MIN_FLEET_VERSION. Binaries that predate
+-- version reporting register with all three columns NULL.
+
+ALTER TABLE meeting_controllers
+ADD COLUMN IF NOT EXISTS version VARCHAR(64),
+ADD COLUMN IF NOT EXISTS git_sha VARCHAR(64),
+ADD COLUMN IF NOT EXISTS proto_schema VARCHAR(64);
+
+ALTER TABLE media_handlers
+ADD COLUMN IF NOT EXISTS version VARCHAR(64),
+ADD COLUMN IF NOT EXISTS git_sha VARCHAR(64),
+ADD COLUMN IF NOT EXISTS proto_schema VARCHAR(64);
+
+-- Comments for documentation
+COMMENT ON COLUMN meeting_controllers.version IS 'Crate version the MC reported at registration (NULL = not reported)';
+COMMENT ON COLUMN meeting_controllers.git_sha IS 'Commit the MC binary was built from';
+COMMENT ON COLUMN meeting_controllers.proto_schema IS 'Protobuf schema fingerprint the MC was compiled against';
+COMMENT ON COLUMN media_handlers.version IS 'Crate version the MH reported at registration (NULL = not reported)';
+COMMENT ON COLUMN media_handlers.git_sha IS 'Commit the MH binary was built from';
+COMMENT ON COLUMN media_handlers.proto_schema IS 'Protobuf schema fingerprint the MH was compiled against';
+
+-- DOWN migration (manual rollback):
+-- ALTER TABLE media_handlers DROP COLUMN IF EXISTS proto_schema, DROP COLUMN IF EXISTS git_sha, DROP COLUMN IF EXISTS version;
+-- ALTER TABLE meeting_controllers DROP COLUMN IF EXISTS proto_schema, DROP COLUMN IF EXISTS git_sha, DROP COLUMN IF EXISTS version;
@@ -0,0 +1,24 @@
+-- Optional TOTP second factor for user logins
+-- A user enrolls with POST /api/v1/auth/user/totp, which stores a new secret
+-- unconfirmed, and confirms it with a code from their authenticator app.
+-- Only confirmed secrets are required at login. Secrets are encrypted with
+-- the AC master key, like signing keys.
+
+CREATE TABLE IF NOT EXISTS user_totp (
+    user_id UUID PRIMARY KEY REFERENCES users(user_id) ON DELETE CASCADE,
+    secret_encrypted BYTEA NOT NULL,
+    secret_nonce BYTEA NOT NULL,
+    secret_tag BYTEA NOT NULL,
+    confirmed_at TIMESTAMPTZ,
+    last_used_step BIGINT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Comments for documentation
+COMMENT ON TABLE user_totp IS 'TOTP (RFC 6238) secrets for user second-factor authentication';
+COMMENT ON COLUMN user_totp.secret_encrypted IS 'AES-256-GCM encrypted TOTP secret (master key)';
+COMMENT ON COLUMN user_totp.confirmed_at IS 'When the user proved possession of the secret (NULL = enrollment pending, not required at login)';
+COMMENT ON COLUMN user_totp.last_used_step IS 'Time step of the last accepted code; codes from this step or earlier are rejected as replays';
+
+-- DOWN migration (manual rollback):
+-- DROP TABLE IF EXISTS user_totp;
@@ -0,0 +1,57 @@
+-- OIDC federation: org users sign in through an external identity provider
+-- An org admin configures one upstream OIDC provider per org. Users start at
+-- GET /api/v1/auth/oidc/authorize, which redirects to the provider, and the
+-- provider redirects back to GET /api/v1/auth/oidc/callback, where AC
+-- validates the ID token and issues a Dark Tower user token. Client secrets
+-- are encrypted with the AC master key, like signing keys.
+
+CREATE TABLE IF NOT EXISTS org_oidc_providers (
+    org_id UUID PRIMARY KEY REFERENCES organizations(org_id) ON DELETE CASCADE,
+    issuer_url TEXT NOT NULL,
+    client_id TEXT NOT NULL,
+    client_secret_encrypted BYTEA NOT NULL,
+    client_secret_nonce BYTEA NOT NULL,
+    client_secret_tag BYTEA NOT NULL,
+    redirect_uri TEXT NOT NULL,
+    is_enabled BOOLEAN NOT NULL DEFAULT true,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- In-flight authorization requests, consumed by the callback
+CREATE TABLE IF NOT EXISTS oidc_login_states (
+    state_hash TEXT PRIMARY KEY,
+    org_id UUID NOT NULL REFERENCES organizations(org_id) ON DELETE CASCADE,
+    nonce TEXT NOT NULL,
+    code_verifier TEXT NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_oidc_login_states_expires_at
+    ON oidc_login_states(expires_at);
+
+-- Links an IdP subject to the AC user it signs in as
+CREATE TABLE IF NOT EXISTS user_oidc_identities (
+    issuer_url TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    user_id UUID NOT NULL REFERENCES users(user_id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (issuer_url, subject)
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_oidc_identities_user_id
+    ON user_oidc_identities(user_id);
+
+-- Comments for documentation
+COMMENT ON TABLE org_oidc_providers IS 'Upstream OIDC identity provider for each org using SSO';
+COMMENT ON COLUMN org_oidc_providers.client_secret_encrypted IS 'AES-256-GCM encrypted OAuth client secret (master key)';
+COMMENT ON COLUMN org_oidc_providers.redirect_uri IS 'Callback URL registered with the provider; must point at /api/v1/auth/oidc/callback on the org subdomain';
+COMMENT ON TABLE oidc_login_states IS 'Pending OIDC authorization requests (state, nonce, PKCE verifier); single use';
+COMMENT ON COLUMN oidc_login_states.state_hash IS 'Hex SHA-256 of the state parameter sent to the provider';
+COMMENT ON TABLE user_oidc_identities IS 'IdP identities (issuer + sub) linked to AC users';
+
+-- DOWN migration (manual rollback):
+-- DROP TABLE IF EXISTS user_oidc_identities;
+-- DROP TABLE IF EXISTS oidc_login_states;
+-- DROP TABLE IF EXISTS org_oidc_providers;
@@ -0,0 +1,19 @@
+-- Consecutive failed authentications per service credential
+-- AC backs off exponentially after repeated client_secret failures: once a
+-- credential has failed a few times in a row, further attempts are refused
+-- with 429 and a growing Retry-After until the delay has passed, before any
+-- bcrypt work is done. A successful authentication deletes the row.
+
+CREATE TABLE IF NOT EXISTS service_credential_failures (
+    credential_id UUID PRIMARY KEY REFERENCES service_credentials(credential_id) ON DELETE CASCADE,
+    consecutive_failures INTEGER NOT NULL CHECK (consecutive_failures > 0),
+    last_failure_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Comments for documentation
+COMMENT ON TABLE service_credential_failures IS 'Consecutive failed service token requests per credential, for exponential backoff';
+COMMENT ON COLUMN service_credential_failures.consecutive_failures IS 'Failures since the last successful authentication';
+COMMENT ON COLUMN service_credential_failures.last_failure_at IS 'When the most recent failure was recorded; the backoff delay runs from here';
+
+-- DOWN migration (manual rollback):
+-- DROP TABLE IF EXISTS service_credential_failures;
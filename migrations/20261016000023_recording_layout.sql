@@ -0,0 +1,17 @@
+-- Per-meeting recording layout
+-- GC sends the layout to the MC with each meeting assignment, and the MC
+-- passes it to every MH when it registers the meeting. It picks how the MH
+-- compositor arranges participants in the recording: 'grid' tiles everyone
+-- equally, 'speaker' shows the active speaker large with a filmstrip.
+
+ALTER TABLE meetings ADD COLUMN IF NOT EXISTS recording_layout VARCHAR(20) NOT NULL DEFAULT 'grid';
+
+ALTER TABLE meetings ADD CONSTRAINT valid_recording_layout
+    CHECK (recording_layout IN ('grid', 'speaker'));
+
+-- Comments for documentation
+COMMENT ON COLUMN meetings.recording_layout IS 'Recording compositor layout: grid = equal tiles, speaker = active speaker with filmstrip';
+
+-- DOWN migration (manual rollback):
+-- ALTER TABLE meetings DROP CONSTRAINT IF EXISTS valid_recording_layout;
+-- ALTER TABLE meetings DROP COLUMN IF EXISTS recording_layout;
@@ -345,6 +345,7 @@ declare -A CANONICAL_SERVICES=(
     [gc]="gc-service:gc-service"
     [mc]="mc-service:mc-service"
     [mh]="mh-service:mh-service"
+    [canary]="canary-service:canary-service"
 )
 
 # =============================================================================